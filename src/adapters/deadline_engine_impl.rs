@@ -20,11 +20,15 @@ const SHORT_PERIOD_THRESHOLD: i32 = 11;
 /// Computes deadlines according to the Federal Rules of Civil
 /// Procedure, Rule 6(a), with proper handling of short vs. long
 /// periods, service method adjustments, and federal holidays.
-pub struct FrcpDeadlineEngine;
+/// District closure dates are treated like holidays.
+pub struct FrcpDeadlineEngine {
+    closures: Vec<NaiveDate>,
+}
 
 impl FrcpDeadlineEngine {
-    pub fn new() -> Self {
-        Self
+    /// Create an engine that also treats the given closure dates as holidays
+    pub fn with_closures(closures: Vec<NaiveDate>) -> Self {
+        Self { closures }
     }
 }
 
@@ -104,13 +108,17 @@ impl DeadlineEngine for FrcpDeadlineEngine {
         holidays.iter().any(|h| h.date == date)
     }
 
+    fn is_court_closure(&self, date: NaiveDate) -> bool {
+        self.closures.contains(&date)
+    }
+
     fn is_weekend(&self, date: NaiveDate) -> bool {
         matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
     }
 
     fn next_business_day(&self, date: NaiveDate) -> NaiveDate {
         let mut current = date;
-        while self.is_weekend(current) || self.is_federal_holiday(current) {
+        while !self.is_business_day(current) {
             current = current.succ_opt().unwrap_or(current);
         }
         current
@@ -193,7 +201,7 @@ fn count_business_days(engine: &FrcpDeadlineEngine, start: NaiveDate, days: i32)
     let mut current = start;
 
     while counted < days {
-        if !engine.is_weekend(current) && !engine.is_federal_holiday(current) {
+        if engine.is_business_day(current) {
             counted += 1;
         }
         if counted < days {
@@ -292,7 +300,7 @@ mod tests {
     use crate::domain::deadline_calc::ServiceMethod;
 
     fn engine() -> FrcpDeadlineEngine {
-        FrcpDeadlineEngine::new()
+        FrcpDeadlineEngine::with_closures(Vec::new())
     }
 
     fn date(year: i32, month: u32, day: u32) -> NaiveDate {
@@ -506,6 +514,18 @@ mod tests {
         assert_eq!(result.due_date, date(2025, 12, 26));
     }
 
+    #[test]
+    fn long_period_landing_on_closure() {
+        // 14-day period from Wed Oct 1, 2025 lands on Wed Oct 15; a snow
+        // closure that day moves it to Thu Oct 16
+        let req = make_request(date(2025, 10, 1), 14, ServiceMethod::Electronic);
+        assert_eq!(engine().compute_deadline(&req).unwrap().due_date, date(2025, 10, 15));
+
+        let closed = FrcpDeadlineEngine::with_closures(vec![date(2025, 10, 15)]);
+        assert!(closed.is_court_closure(date(2025, 10, 15)));
+        assert_eq!(closed.compute_deadline(&req).unwrap().due_date, date(2025, 10, 16));
+    }
+
     // --- Edge cases ---
 
     #[test]
@@ -6,6 +6,7 @@
 pub mod deadline_engine_impl;
 pub mod pdf_writer_adapter;
 pub mod store_utils;
+pub mod unit_of_work;
 pub mod spin_kv_attorney_repository;
 pub mod spin_kv_case_repository;
 pub mod spin_kv_config_repository;
@@ -18,6 +19,8 @@ pub mod rules_engine_impl;
 pub mod spin_kv_rules_repository;
 pub mod spin_kv_sentencing_repository;
 pub mod spin_kv_signature_repository;
+pub mod spin_kv_webhook_repository;
+pub mod spin_http_webhook_delivery;
 pub mod toml_config_loader;
 pub mod privacy_engine_impl;
 pub mod unified_config_feature_repository;
\ No newline at end of file
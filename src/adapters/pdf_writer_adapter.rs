@@ -2,18 +2,32 @@ use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str};
 use async_trait::async_trait;
 use crate::domain::document::{
     CourtDocument, GeneratedDocument, DocumentError, DocumentMetadata,
-    CaseNumber, JudgeName, District, ElectronicSignature
+    CaseNumber, JudgeName, District, ElectronicSignature, PageLayout, DefendantNames
 };
+use crate::domain::deadline::{ComplianceReportDetail, DeadlineType};
+use crate::domain::weekly_docket::{WeeklyDocket, WeeklyDocketItemKind};
 use crate::ports::document_generator::{DocumentGenerator, PdfRenderer};
+use chrono::{DateTime, Utc};
 
-pub struct PdfWriterAdapter;
+pub struct PdfWriterAdapter {
+    layout: PageLayout,
+}
 
 impl PdfWriterAdapter {
     pub fn new() -> Self {
-        Self
+        Self::with_layout(PageLayout::default())
+    }
+
+    /// Adapter rendering onto the given paper size and margins
+    pub fn with_layout(layout: PageLayout) -> Self {
+        Self { layout }
     }
 
-    fn create_pdf_structure() -> (Pdf, Ref, Ref) {
+    fn frame(&self) -> Frame {
+        Frame::new(&self.layout)
+    }
+
+    fn create_pdf_structure(&self) -> (Pdf, Ref, Ref) {
         let mut pdf = Pdf::new();
         let page_tree_id = Ref::new(2);
         let page_id = Ref::new(3);
@@ -27,9 +41,7 @@ impl PdfWriterAdapter {
         let mut page = pdf.page(page_id);
         page.parent(page_tree_id);
         page.contents(content_id);
-        // 8.5 x 11 inches in points (72 points per inch)
-        let width = 612.0;  // 8.5 * 72
-        let height = 792.0; // 11 * 72
+        let (width, height) = self.layout.dimensions();
         page.media_box(Rect::new(0.0, 0.0, width, height));
         page.resources().fonts().pair(font_name, font_id);
         page.finish();
@@ -40,6 +52,32 @@ impl PdfWriterAdapter {
         (pdf, page_id, content_id)
     }
 
+    /// Assemble a PDF with one page per content stream
+    fn assemble_pages(&self, pages: Vec<Content>) -> Vec<u8> {
+        let (width, height) = self.layout.dimensions();
+        let mut pdf = Pdf::new();
+        let page_tree_id = Ref::new(2);
+        let font_id = Ref::new(3);
+        let page_ids: Vec<Ref> = (0..pages.len()).map(|i| Ref::new(4 + 2 * i as i32)).collect();
+
+        pdf.catalog(Ref::new(1)).pages(page_tree_id);
+        pdf.pages(page_tree_id).kids(page_ids.iter().copied()).count(page_ids.len() as i32);
+
+        for (page_id, content) in page_ids.iter().zip(pages) {
+            let content_id = Ref::new(page_id.get() + 1);
+            let mut page = pdf.page(*page_id);
+            page.parent(page_tree_id);
+            page.contents(content_id);
+            page.media_box(Rect::new(0.0, 0.0, width, height));
+            page.resources().fonts().pair(Name(b"F1"), font_id);
+            page.finish();
+            pdf.stream(content_id, &content.finish());
+        }
+
+        pdf.type1_font(font_id).base_font(Name(b"Times-Roman"));
+        pdf.finish()
+    }
+
     fn add_header(content: &mut Content, district: &str) -> f32 {
         // Start with 1 inch margin from top (72 points)
         let mut y_position = 720.0; // 792 - 72 = 720
@@ -68,7 +106,7 @@ impl PdfWriterAdapter {
         y_position - 40.0
     }
 
-    fn add_case_caption(content: &mut Content, case_number: &str, defendant_names: &str, mut y_position: f32) -> f32 {
+    fn add_case_caption(content: &mut Content, case_number: &str, defendants: &DefendantNames, mut y_position: f32) -> f32 {
         y_position -= 48.0; // Double-spacing before caption
 
         // Left margin at 1 inch (72 points)
@@ -101,15 +139,28 @@ impl PdfWriterAdapter {
         y_position -= 24.0;
         content.begin_text();
         content.next_line(left_margin, y_position);
-        content.show(Str(defendant_names.as_bytes()));
+        content.show(Str(defendants.caption_name().as_bytes()));
         content.end_text();
 
         y_position -= 24.0;
         content.begin_text();
         content.next_line(left_margin + 20.0, y_position);
-        content.show(Str(b"Defendant(s)."));
+        content.show(Str(defendants.designation().as_bytes()));
         content.end_text();
 
+        // Defendants shortened to "et al." are listed in full below
+        if let Some(continuation) = defendants.continuation() {
+            y_position -= 10.0;
+            for line in wrap_text(&continuation, 80) {
+                y_position -= 14.0;
+                content.begin_text();
+                content.set_font(Name(b"F1"), 10.0);
+                content.next_line(left_margin, y_position);
+                content.show(Str(line.as_bytes()));
+                content.end_text();
+            }
+        }
+
         y_position - 48.0
     }
 
@@ -207,6 +258,154 @@ impl PdfWriterAdapter {
     }
 }
 
+/// Where the letter-size design frame lands on the requested page
+///
+/// Renderers lay out for US Letter with one-inch margins. Each page's
+/// content is translated so the top-left of that frame sits at the
+/// requested top and left margins. A taller page or smaller bottom margin
+/// leaves more room below, which [`PageFlow`] fills before breaking, and
+/// wrapped text is reflowed to the width between the margins.
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    dx: f32,
+    dy: f32,
+    /// The requested bottom margin, in frame coordinates
+    bottom: f32,
+    /// Text width between the margins relative to the letter frame's
+    width_scale: f32,
+}
+
+impl Frame {
+    const TOP: f32 = 720.0;
+    const LEFT: f32 = 72.0;
+    const WIDTH: f32 = 468.0;
+
+    fn new(layout: &PageLayout) -> Self {
+        let (_, height) = layout.dimensions();
+        let dy = height - layout.margins.top - Self::TOP;
+        Self {
+            dx: layout.margins.left - Self::LEFT,
+            dy,
+            bottom: layout.margins.bottom - dy,
+            width_scale: layout.text_width() / Self::WIDTH,
+        }
+    }
+
+    /// Empty page content, shifted onto the requested page
+    fn content(&self) -> Content {
+        let mut content = Content::new();
+        if self.dx != 0.0 || self.dy != 0.0 {
+            content.transform([1.0, 0.0, 0.0, 1.0, self.dx, self.dy]);
+        }
+        content
+    }
+
+    /// Characters per line for text wrapped at `letter_chars` on the letter frame
+    fn wrap_chars(&self, letter_chars: usize) -> usize {
+        ((letter_chars as f32 * self.width_scale) as usize).max(20)
+    }
+}
+
+/// Lays out lines top to bottom, starting a new page at the bottom margin
+struct PageFlow {
+    pages: Vec<Content>,
+    content: Content,
+    y_position: f32,
+    frame: Frame,
+    /// Line stamped above the top margin of every page
+    running_head: Option<String>,
+}
+
+impl PageFlow {
+    fn new(content: Content, y_position: f32, frame: Frame) -> Self {
+        Self { pages: Vec::new(), content, y_position, frame, running_head: None }
+    }
+
+    /// Stamp `text` at the top of every page when the flow is finished
+    fn with_running_head(mut self, text: String) -> Self {
+        self.running_head = Some(text);
+        self
+    }
+
+    fn new_page(&mut self) {
+        let finished = std::mem::replace(&mut self.content, self.frame.content());
+        self.pages.push(finished);
+        self.y_position = Frame::TOP;
+    }
+
+    /// Start a new page unless `height` points fit above the bottom margin
+    fn reserve(&mut self, height: f32) {
+        if self.y_position - height < self.frame.bottom {
+            self.new_page();
+        }
+    }
+
+    /// Move down by `leading` and write each `(x, text)` cell on the new line
+    fn row(&mut self, leading: f32, size: f32, cells: &[(f32, &str)]) {
+        if self.y_position - leading < self.frame.bottom {
+            self.new_page();
+        }
+        self.y_position -= leading;
+        for (x, text) in cells {
+            self.content.begin_text();
+            self.content.set_font(Name(b"F1"), size);
+            self.content.next_line(*x, self.y_position);
+            self.content.show(Str(text.as_bytes()));
+            self.content.end_text();
+        }
+    }
+
+    /// Finish the last page and number every page
+    fn finish(mut self) -> Vec<Content> {
+        self.pages.push(self.content);
+        let total = self.pages.len();
+        for (i, page) in self.pages.iter_mut().enumerate() {
+            // Inside the narrowest top margin, so it stays on the page
+            if let Some(head) = &self.running_head {
+                page.begin_text();
+                page.set_font(Name(b"F1"), 9.0);
+                page.next_line(400.0, Frame::TOP + 14.0);
+                page.show(Str(head.as_bytes()));
+                page.end_text();
+            }
+            page.begin_text();
+            page.set_font(Name(b"F1"), 9.0);
+            page.next_line(270.0, self.frame.bottom - 30.0);
+            page.show(Str(format!("Page {} of {}", i + 1, total).as_bytes()));
+            page.end_text();
+        }
+        self.pages
+    }
+}
+
+/// Space `add_electronic_signature` draws below its starting position
+const ELECTRONIC_SIGNATURE_HEIGHT: f32 = 170.0;
+
+/// Space `add_standard_signature` draws below its starting position
+const STANDARD_SIGNATURE_HEIGHT: f32 = 95.0;
+
+/// Number of pages in a rendered PDF, read from its page tree
+fn page_count(pdf: &[u8]) -> usize {
+    const COUNT: &[u8] = b"/Count ";
+    pdf.windows(COUNT.len())
+        .position(|window| window == COUNT)
+        .map(|start| {
+            pdf[start + COUNT.len()..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .fold(0, |n, b| n * 10 + (b - b'0') as usize)
+        })
+        .unwrap_or(1)
+}
+
+/// Readable name for a deadline type, e.g. "notice of appeal"
+fn deadline_type_label(deadline_type: &DeadlineType) -> String {
+    serde_json::to_value(deadline_type)
+        .ok()
+        .and_then(|v| v.as_str().map(|s| s.replace('_', " ")))
+        .unwrap_or_else(|| format!("{:?}", deadline_type))
+}
+
 fn extract_initials(name: &str) -> String {
     name.split_whitespace()
         .filter_map(|word| word.chars().next())
@@ -250,13 +449,13 @@ impl PdfRenderer for PdfWriterAdapter {
     fn render_rule16b(
         &self,
         case_number: &CaseNumber,
-        defendant_names: &str,
+        defendant_names: &DefendantNames,
         judge_name: &JudgeName,
         district: &District,
         signature: Option<&ElectronicSignature>
     ) -> Result<Vec<u8>, DocumentError> {
-        let (mut pdf, _page_id, content_id) = Self::create_pdf_structure();
-        let mut content = Content::new();
+        let (mut pdf, _page_id, content_id) = self.create_pdf_structure();
+        let mut content = self.frame().content();
 
         let mut y_position = Self::add_header(&mut content, district.as_str());
         y_position = Self::add_case_caption(&mut content, case_number.as_str(), defendant_names, y_position);
@@ -324,63 +523,48 @@ impl PdfRenderer for PdfWriterAdapter {
     fn render_court_order(
         &self,
         case_number: &CaseNumber,
-        defendant_names: &str,
+        defendant_names: &DefendantNames,
         judge_name: &JudgeName,
         district: &District,
         order_title: &str,
         order_content: &str,
         signature: Option<&ElectronicSignature>
     ) -> Result<Vec<u8>, DocumentError> {
-        let (mut pdf, _page_id, content_id) = Self::create_pdf_structure();
-        let mut content = Content::new();
-
-        let mut y_position = Self::add_header(&mut content, district.as_str());
-        y_position = Self::add_case_caption(&mut content, case_number.as_str(), defendant_names, y_position);
-
-        y_position -= 20.0;
-        content.begin_text();
-        content.set_font(Name(b"F1"), 14.0);
-        content.next_line(200.0, y_position);
-        content.show(Str(order_title.as_bytes()));
-        content.end_text();
-
-        y_position -= 30.0;
-        for line in order_content.lines() {
-            content.begin_text();
-            content.set_font(Name(b"F1"), 11.0);
-            content.next_line(50.0, y_position);
-            content.show(Str(line.as_bytes()));
-            content.end_text();
-            y_position -= 15.0;
+        let mut content = self.frame().content();
+        let y_position = Self::add_header(&mut content, district.as_str());
+        let y_position = Self::add_case_caption(&mut content, case_number.as_str(), defendant_names, y_position);
+        let mut flow = PageFlow::new(content, y_position, self.frame());
+
+        flow.row(20.0, 14.0, &[(200.0, order_title)]);
+        flow.y_position -= 15.0;
+        for line in wrap_text(order_content, self.frame().wrap_chars(85)) {
+            flow.row(15.0, 11.0, &[(72.0, &line)]);
         }
+        flow.row(35.0, 11.0, &[(72.0, "IT IS SO ORDERED.")]);
 
-        y_position -= 20.0;
-        content.begin_text();
-        content.set_font(Name(b"F1"), 11.0);
-        content.next_line(50.0, y_position);
-        content.show(Str(b"IT IS SO ORDERED."));
-        content.end_text();
-
+        // Keep the signature block on one page with room below the ordering line
         if let Some(sig) = signature {
-            Self::add_electronic_signature(&mut content, sig, judge_name.as_str(), y_position);
+            flow.reserve(ELECTRONIC_SIGNATURE_HEIGHT);
+            Self::add_electronic_signature(&mut flow.content, sig, judge_name.as_str(), flow.y_position);
         } else {
-            Self::add_standard_signature(&mut content, judge_name.as_str(), y_position);
+            flow.reserve(STANDARD_SIGNATURE_HEIGHT);
+            Self::add_standard_signature(&mut flow.content, judge_name.as_str(), flow.y_position);
         }
 
-        pdf.stream(content_id, &content.finish());
-        Ok(pdf.finish())
+        Ok(self.assemble_pages(flow.finish()))
     }
 
     fn render_minute_entry(
         &self,
         case_number: &CaseNumber,
-        defendant_names: &str,
+        defendant_names: &DefendantNames,
         judge_name: &JudgeName,
         district: &District,
-        minute_text: &str
+        minute_text: &str,
+        signature: Option<&ElectronicSignature>
     ) -> Result<Vec<u8>, DocumentError> {
-        let (mut pdf, _page_id, content_id) = Self::create_pdf_structure();
-        let mut content = Content::new();
+        let (mut pdf, _page_id, content_id) = self.create_pdf_structure();
+        let mut content = self.frame().content();
 
         let mut y_position = Self::add_header(&mut content, district.as_str());
         y_position = Self::add_case_caption(&mut content, case_number.as_str(), defendant_names, y_position);
@@ -404,7 +588,7 @@ impl PdfRenderer for PdfWriterAdapter {
         // Wrap text to fit within margins (approximately 80-85 characters per line)
         // With 1-inch margins on 8.5" paper, we have 6.5" = 468 points of usable width
         // At 11pt font, we can fit about 85 characters comfortably
-        let wrapped_lines = wrap_text(minute_text, 85);
+        let wrapped_lines = wrap_text(minute_text, self.frame().wrap_chars(85));
         let left_margin = 72.0; // 1 inch margin
 
         for line in wrapped_lines {
@@ -424,6 +608,11 @@ impl PdfRenderer for PdfWriterAdapter {
         content.show(Str(clerk_text.as_bytes()));
         content.end_text();
 
+        // Only a signed minute entry carries the judge's signature block
+        if let Some(sig) = signature {
+            Self::add_electronic_signature(&mut content, sig, judge_name.as_str(), y_position);
+        }
+
         pdf.stream(content_id, &content.finish());
         Ok(pdf.finish())
     }
@@ -435,8 +624,8 @@ impl PdfRenderer for PdfWriterAdapter {
         district: &District,
         charges: &str
     ) -> Result<Vec<u8>, DocumentError> {
-        let (mut pdf, _page_id, content_id) = Self::create_pdf_structure();
-        let mut content = Content::new();
+        let (mut pdf, _page_id, content_id) = self.create_pdf_structure();
+        let mut content = self.frame().content();
 
         let mut y_position = Self::add_header(&mut content, district.as_str());
 
@@ -547,11 +736,11 @@ impl PdfRenderer for PdfWriterAdapter {
         judge_name: &JudgeName,
         conditions: &[String]
     ) -> Result<Vec<u8>, DocumentError> {
-        let (mut pdf, _page_id, content_id) = Self::create_pdf_structure();
-        let mut content = Content::new();
+        let (mut pdf, _page_id, content_id) = self.create_pdf_structure();
+        let mut content = self.frame().content();
 
         let mut y_position = Self::add_header(&mut content, district.as_str());
-        y_position = Self::add_case_caption(&mut content, case_number.as_str(), defendant_name, y_position);
+        y_position = Self::add_case_caption(&mut content, case_number.as_str(), &DefendantNames::new([defendant_name]), y_position);
 
         y_position -= 20.0;
         content.begin_text();
@@ -617,11 +806,11 @@ impl PdfRenderer for PdfWriterAdapter {
         counts: &str,
         sentence: &str
     ) -> Result<Vec<u8>, DocumentError> {
-        let (mut pdf, _page_id, content_id) = Self::create_pdf_structure();
-        let mut content = Content::new();
+        let (mut pdf, _page_id, content_id) = self.create_pdf_structure();
+        let mut content = self.frame().content();
 
         let mut y_position = Self::add_header(&mut content, district.as_str());
-        y_position = Self::add_case_caption(&mut content, case_number.as_str(), defendant_name, y_position);
+        y_position = Self::add_case_caption(&mut content, case_number.as_str(), &DefendantNames::new([defendant_name]), y_position);
 
         y_position -= 20.0;
         content.begin_text();
@@ -698,13 +887,440 @@ impl PdfRenderer for PdfWriterAdapter {
         pdf.stream(content_id, &content.finish());
         Ok(pdf.finish())
     }
+
+    fn render_summons(
+        &self,
+        case_number: &CaseNumber,
+        district: &District,
+        party_name: &str,
+        party_address: Option<&str>,
+        response_days: u32
+    ) -> Result<Vec<u8>, DocumentError> {
+        let (mut pdf, _page_id, content_id) = self.create_pdf_structure();
+        let mut content = self.frame().content();
+
+        let mut y_position = Self::add_header(&mut content, district.as_str());
+        y_position = Self::add_case_caption(&mut content, case_number.as_str(), &DefendantNames::new([party_name]), y_position);
+
+        y_position -= 20.0;
+        content.begin_text();
+        content.set_font(Name(b"F1"), 14.0);
+        content.next_line(220.0, y_position);
+        content.show(Str(b"SUMMONS"));
+        content.end_text();
+
+        y_position -= 30.0;
+        content.begin_text();
+        content.set_font(Name(b"F1"), 11.0);
+        content.next_line(50.0, y_position);
+        let to_line = format!("To: {}", party_name);
+        content.show(Str(to_line.as_bytes()));
+        content.end_text();
+
+        if let Some(address) = party_address {
+            for line in address.lines() {
+                y_position -= 15.0;
+                content.begin_text();
+                content.next_line(70.0, y_position);
+                content.show(Str(line.as_bytes()));
+                content.end_text();
+            }
+        }
+
+        y_position -= 30.0;
+        content.begin_text();
+        content.next_line(50.0, y_position);
+        content.show(Str(b"A proceeding has been commenced against you in this Court."));
+        content.end_text();
+
+        y_position -= 20.0;
+        let notice = format!(
+            "Within {} days after service of this summons on you (not counting the day you received it), \
+             you must respond as directed by the Court and serve your response on the opposing party \
+             or its attorney.",
+            response_days
+        );
+        for line in wrap_text(&notice, self.frame().wrap_chars(80)) {
+            content.begin_text();
+            content.next_line(50.0, y_position);
+            content.show(Str(line.as_bytes()));
+            content.end_text();
+            y_position -= 15.0;
+        }
+
+        y_position -= 15.0;
+        content.begin_text();
+        content.next_line(50.0, y_position);
+        content.show(Str(b"If you fail to respond, relief may be granted against you without further notice."));
+        content.end_text();
+
+        y_position -= 50.0;
+        content.begin_text();
+        content.next_line(300.0, y_position);
+        content.show(Str(b"_______________________________"));
+        content.end_text();
+
+        y_position -= 15.0;
+        content.begin_text();
+        content.next_line(300.0, y_position);
+        content.show(Str(b"CLERK OF COURT"));
+        content.end_text();
+
+        content.begin_text();
+        content.next_line(50.0, y_position);
+        content.show(Str(b"Date: ________________"));
+        content.end_text();
+
+        pdf.stream(content_id, &content.finish());
+        Ok(pdf.finish())
+    }
+
+    fn render_subpoena(
+        &self,
+        case_number: &CaseNumber,
+        district: &District,
+        recipient_name: &str,
+        appearance_date: &DateTime<Utc>,
+        location: &str,
+        judge_name: &JudgeName
+    ) -> Result<Vec<u8>, DocumentError> {
+        let (mut pdf, _page_id, content_id) = self.create_pdf_structure();
+        let mut content = self.frame().content();
+
+        let mut y_position = Self::add_header(&mut content, district.as_str());
+
+        y_position -= 20.0;
+        content.begin_text();
+        content.set_font(Name(b"F1"), 14.0);
+        content.next_line(130.0, y_position);
+        content.show(Str(b"SUBPOENA TO APPEAR AND TESTIFY"));
+        content.end_text();
+
+        y_position -= 30.0;
+        content.begin_text();
+        content.set_font(Name(b"F1"), 11.0);
+        content.next_line(50.0, y_position);
+        content.show(Str(b"Case Number:"));
+        content.end_text();
+
+        content.begin_text();
+        content.next_line(150.0, y_position);
+        content.show(Str(case_number.as_str().as_bytes()));
+        content.end_text();
+
+        y_position -= 30.0;
+        content.begin_text();
+        content.next_line(50.0, y_position);
+        let to_line = format!("To: {}", recipient_name);
+        content.show(Str(to_line.as_bytes()));
+        content.end_text();
+
+        y_position -= 30.0;
+        let command = "YOU ARE COMMANDED to appear in the United States District Court at the place, \
+                       date, and time set forth below to testify in this case. You must remain at the \
+                       court until the judge or a court officer allows you to leave.";
+        for line in wrap_text(command, self.frame().wrap_chars(80)) {
+            content.begin_text();
+            content.next_line(50.0, y_position);
+            content.show(Str(line.as_bytes()));
+            content.end_text();
+            y_position -= 15.0;
+        }
+
+        y_position -= 15.0;
+        let place = format!("Place of appearance: {}", location);
+        let time = format!("Date and time: {}", appearance_date.format("%m/%d/%Y at %I:%M %p UTC"));
+        let judge = format!("Before: {}", judge_name.as_str());
+        for line in [place, time, judge] {
+            content.begin_text();
+            content.next_line(70.0, y_position);
+            content.show(Str(line.as_bytes()));
+            content.end_text();
+            y_position -= 15.0;
+        }
+
+        y_position -= 50.0;
+        content.begin_text();
+        content.next_line(300.0, y_position);
+        content.show(Str(b"_______________________________"));
+        content.end_text();
+
+        y_position -= 15.0;
+        content.begin_text();
+        content.next_line(300.0, y_position);
+        content.show(Str(b"CLERK OF COURT"));
+        content.end_text();
+
+        content.begin_text();
+        content.next_line(50.0, y_position);
+        content.show(Str(b"Date: ________________"));
+        content.end_text();
+
+        pdf.stream(content_id, &content.finish());
+        Ok(pdf.finish())
+    }
+
+    fn render_weekly_docket(
+        &self,
+        district: &District,
+        docket: &WeeklyDocket
+    ) -> Result<Vec<u8>, DocumentError> {
+        let (mut pdf, _page_id, content_id) = self.create_pdf_structure();
+        let mut content = self.frame().content();
+
+        let mut y_position = Self::add_header(&mut content, district.as_str());
+
+        content.begin_text();
+        content.set_font(Name(b"F1"), 14.0);
+        content.next_line(50.0, y_position);
+        let title = format!("WEEKLY DOCKET - {}", docket.judge_name);
+        content.show(Str(title.as_bytes()));
+        content.end_text();
+
+        y_position -= 18.0;
+        content.begin_text();
+        content.set_font(Name(b"F1"), 11.0);
+        content.next_line(50.0, y_position);
+        let week = format!(
+            "Week of {} through {}",
+            docket.week_start.format("%B %-d, %Y"),
+            docket.week_end.format("%B %-d, %Y")
+        );
+        content.show(Str(week.as_bytes()));
+        content.end_text();
+
+        // Single-page sheet: stop above the bottom margin and note what was left off
+        let bottom_margin = 72.0;
+        let total_items: usize = docket.days.iter().map(|d| d.items.len()).sum();
+        let mut printed = 0;
+
+        'days: for day in &docket.days {
+            if y_position - 40.0 < bottom_margin {
+                break;
+            }
+            y_position -= 28.0;
+            content.begin_text();
+            content.set_font(Name(b"F1"), 12.0);
+            content.next_line(50.0, y_position);
+            let heading = format!("{} {}", day.weekday, day.date.format("%m/%d/%Y"));
+            content.show(Str(heading.as_bytes()));
+            content.end_text();
+
+            content.set_font(Name(b"F1"), 10.0);
+            if day.items.is_empty() {
+                y_position -= 14.0;
+                content.begin_text();
+                content.next_line(70.0, y_position);
+                content.show(Str(b"No matters scheduled"));
+                content.end_text();
+                continue;
+            }
+
+            for item in &day.items {
+                let label = match item.kind {
+                    WeeklyDocketItemKind::Event => format!(
+                        "{}  {}  Courtroom {}",
+                        item.local_time,
+                        item.item_type.replace('_', " "),
+                        item.courtroom.as_deref().unwrap_or("-")
+                    ),
+                    WeeklyDocketItemKind::Deadline => format!(
+                        "{}  DEADLINE: {}",
+                        item.local_time,
+                        item.item_type.replace('_', " ")
+                    ),
+                };
+                let line = format!("{} - {} (case {})", label, item.description, item.case_id);
+                for text in wrap_text(&line, self.frame().wrap_chars(90)) {
+                    if y_position - 14.0 < bottom_margin {
+                        break 'days;
+                    }
+                    y_position -= 14.0;
+                    content.begin_text();
+                    content.set_font(Name(b"F1"), 10.0);
+                    content.next_line(70.0, y_position);
+                    content.show(Str(text.as_bytes()));
+                    content.end_text();
+                }
+                printed += 1;
+            }
+        }
+
+        if printed < total_items {
+            content.begin_text();
+            content.set_font(Name(b"F1"), 10.0);
+            content.next_line(50.0, bottom_margin - 20.0);
+            let more = format!("{} more item(s) not shown; see the JSON weekly docket", total_items - printed);
+            content.show(Str(more.as_bytes()));
+            content.end_text();
+        }
+
+        pdf.stream(content_id, &content.finish());
+        Ok(pdf.finish())
+    }
+
+    fn render_compliance_report(
+        &self,
+        district: &District,
+        report: &ComplianceReportDetail
+    ) -> Result<Vec<u8>, DocumentError> {
+        let mut content = self.frame().content();
+        let y_position = Self::add_header(&mut content, district.as_str());
+        let mut flow = PageFlow::new(content, y_position, self.frame());
+
+        flow.row(0.0, 14.0, &[(170.0, "DEADLINE COMPLIANCE REPORT")]);
+        let period = format!(
+            "Reporting period: {} through {}",
+            report.period_start.format("%B %-d, %Y"),
+            report.period_end.format("%B %-d, %Y")
+        );
+        flow.row(20.0, 11.0, &[(72.0, &period)]);
+
+        // Summary table
+        flow.row(30.0, 12.0, &[(72.0, "SUMMARY BY DEADLINE TYPE")]);
+        let columns = [72.0, 300.0, 360.0, 420.0, 480.0];
+        flow.row(18.0, 10.0, &[
+            (columns[0], "Deadline type"),
+            (columns[1], "Tracked"),
+            (columns[2], "On time"),
+            (columns[3], "Missed"),
+            (columns[4], "Rate"),
+        ]);
+        for category in &report.categories {
+            let label = deadline_type_label(&category.deadline_type);
+            let tracked = category.tracked.to_string();
+            let on_time = category.completed_on_time.to_string();
+            let missed = category.missed.to_string();
+            let rate = format!("{:.1}%", category.compliance_rate);
+            flow.row(14.0, 10.0, &[
+                (columns[0], &label),
+                (columns[1], &tracked),
+                (columns[2], &on_time),
+                (columns[3], &missed),
+                (columns[4], &rate),
+            ]);
+        }
+        if report.categories.is_empty() {
+            flow.row(14.0, 10.0, &[(columns[0], "No deadlines fell due in this period")]);
+        }
+        let tracked = report.deadlines_tracked.to_string();
+        let on_time = report.completed_on_time.to_string();
+        let missed: usize = report.categories.iter().map(|c| c.missed).sum();
+        let missed = missed.to_string();
+        let rate = format!("{:.1}%", report.compliance_rate);
+        flow.row(18.0, 10.0, &[
+            (columns[0], "All deadlines"),
+            (columns[1], &tracked),
+            (columns[2], &on_time),
+            (columns[3], &missed),
+            (columns[4], &rate),
+        ]);
+
+        // Trend
+        let trend = &report.trend;
+        flow.row(30.0, 12.0, &[(72.0, "TREND")]);
+        let prior = format!(
+            "Prior period ({} through {}): {} deadlines, {:.1}% on time",
+            trend.prior_period_start.format("%m/%d/%Y"),
+            trend.prior_period_end.format("%m/%d/%Y"),
+            trend.prior_deadlines_tracked,
+            trend.prior_compliance_rate
+        );
+        flow.row(16.0, 10.0, &[(72.0, &prior)]);
+        let change = format!("Change: {:+.1} percentage points", trend.change);
+        flow.row(14.0, 10.0, &[(72.0, &change)]);
+        let appendix_note = format!(
+            "Missed jurisdictional deadlines: {} (see Appendix A)",
+            report.missed_jurisdictional.len()
+        );
+        flow.row(20.0, 10.0, &[(72.0, &appendix_note)]);
+
+        // Detail appendix always starts on its own page
+        flow.new_page();
+        flow.row(0.0, 12.0, &[(72.0, "APPENDIX A - MISSED JURISDICTIONAL DEADLINES")]);
+        if report.missed_jurisdictional.is_empty() {
+            flow.row(20.0, 10.0, &[(72.0, "No jurisdictional deadlines were missed in this period.")]);
+        } else {
+            flow.row(20.0, 10.0, &[
+                (72.0, "Due date"),
+                (150.0, "Rule"),
+                (270.0, "Deadline type"),
+                (420.0, "Responsible party"),
+            ]);
+        }
+        for missed in &report.missed_jurisdictional {
+            let due = missed.due_date.format("%m/%d/%Y").to_string();
+            let label = deadline_type_label(&missed.deadline_type);
+            flow.row(16.0, 10.0, &[
+                (72.0, &due),
+                (150.0, &missed.applicable_rule),
+                (270.0, &label),
+                (420.0, &missed.responsible_party),
+            ]);
+            let detail = format!(
+                "Case {}  Deadline {}  {}",
+                missed.case_id,
+                missed.deadline_id,
+                missed
+                    .completion_date
+                    .map(|date| format!("Met late on {}", date.format("%m/%d/%Y")))
+                    .unwrap_or_else(|| "Not met".to_string())
+            );
+            flow.row(12.0, 8.0, &[(90.0, &detail)]);
+        }
+
+        Ok(self.assemble_pages(flow.finish()))
+    }
+
+    fn render_slip_opinion(
+        &self,
+        case_number: &CaseNumber,
+        district: &District,
+        citation: &str,
+        case_name: &str,
+        title: &str,
+        judge_name: &JudgeName,
+        syllabus: &str,
+        content: &str,
+        published_at: &DateTime<Utc>
+    ) -> Result<Vec<u8>, DocumentError> {
+        let mut page = self.frame().content();
+        let y_position = Self::add_header(&mut page, district.as_str());
+        let mut flow = PageFlow::new(page, y_position, self.frame()).with_running_head(citation.to_string());
+
+        flow.row(0.0, 14.0, &[(72.0, case_name)]);
+        let docket = format!("No. {}", case_number.as_str());
+        flow.row(18.0, 11.0, &[(72.0, &docket)]);
+        let published = format!("Published {}", published_at.format("%B %-d, %Y"));
+        flow.row(14.0, 11.0, &[(72.0, &published)]);
+        flow.row(14.0, 11.0, &[(72.0, citation)]);
+
+        flow.row(30.0, 12.0, &[(72.0, title)]);
+        if !syllabus.is_empty() {
+            flow.row(24.0, 11.0, &[(72.0, "SYLLABUS")]);
+            for line in wrap_text(syllabus, self.frame().wrap_chars(90)) {
+                flow.row(13.0, 10.0, &[(72.0, &line)]);
+            }
+        }
+
+        flow.row(24.0, 11.0, &[(72.0, "OPINION")]);
+        let author = format!("{}, United States District Judge:", judge_name.as_str());
+        flow.row(16.0, 11.0, &[(72.0, &author)]);
+        for line in wrap_text(content, self.frame().wrap_chars(85)) {
+            flow.row(14.0, 11.0, &[(72.0, &line)]);
+        }
+
+        Ok(self.assemble_pages(flow.finish()))
+    }
 }
 
 impl PdfWriterAdapter {
     pub fn generate_document_sync(&self, document: CourtDocument) -> Result<GeneratedDocument, DocumentError> {
+        document.layout.validate()?;
+        let renderer = Self::with_layout(document.layout);
         let pdf_data = match &document.metadata {
             DocumentMetadata::Rule16b { defendant_names, judge_name, signature } => {
-                self.render_rule16b(
+                renderer.render_rule16b(
                     &document.case_number,
                     defendant_names,
                     judge_name,
@@ -713,7 +1329,7 @@ impl PdfWriterAdapter {
                 )?
             },
             DocumentMetadata::CourtOrder { defendant_names, judge_name, order_title, order_content, signature } => {
-                self.render_court_order(
+                renderer.render_court_order(
                     &document.case_number,
                     defendant_names,
                     judge_name,
@@ -723,17 +1339,18 @@ impl PdfWriterAdapter {
                     signature.as_ref()
                 )?
             },
-            DocumentMetadata::MinuteEntry { defendant_names, judge_name, minute_text } => {
-                self.render_minute_entry(
+            DocumentMetadata::MinuteEntry { defendant_names, judge_name, minute_text, signature } => {
+                renderer.render_minute_entry(
                     &document.case_number,
                     defendant_names,
                     judge_name,
                     &document.district,
-                    minute_text
+                    minute_text,
+                    signature.as_ref()
                 )?
             },
             DocumentMetadata::WaiverIndictment { defendant_name, charges } => {
-                self.render_waiver_indictment(
+                renderer.render_waiver_indictment(
                     &document.case_number,
                     defendant_name,
                     &document.district,
@@ -741,7 +1358,7 @@ impl PdfWriterAdapter {
                 )?
             },
             DocumentMetadata::ConditionsRelease { defendant_name, judge_name, conditions } => {
-                self.render_conditions_release(
+                renderer.render_conditions_release(
                     &document.case_number,
                     defendant_name,
                     &document.district,
@@ -750,7 +1367,7 @@ impl PdfWriterAdapter {
                 )?
             },
             DocumentMetadata::CriminalJudgment { defendant_name, judge_name, plea, counts, sentence } => {
-                self.render_criminal_judgment(
+                renderer.render_criminal_judgment(
                     &document.case_number,
                     defendant_name,
                     &document.district,
@@ -760,6 +1377,41 @@ impl PdfWriterAdapter {
                     sentence
                 )?
             },
+            DocumentMetadata::Summons { party_name, party_address, response_days } => {
+                renderer.render_summons(
+                    &document.case_number,
+                    &document.district,
+                    party_name,
+                    party_address.as_deref(),
+                    *response_days
+                )?
+            },
+            DocumentMetadata::Subpoena { recipient_name, appearance_date, location, judge_name } => {
+                renderer.render_subpoena(
+                    &document.case_number,
+                    &document.district,
+                    recipient_name,
+                    appearance_date,
+                    location,
+                    judge_name
+                )?
+            },
+            DocumentMetadata::ComplianceReport { report } => {
+                renderer.render_compliance_report(&document.district, report)?
+            },
+            DocumentMetadata::SlipOpinion { citation, case_name, title, judge_name, syllabus, content, published_at } => {
+                renderer.render_slip_opinion(
+                    &document.case_number,
+                    &document.district,
+                    citation,
+                    case_name,
+                    title,
+                    judge_name,
+                    syllabus,
+                    content,
+                    published_at
+                )?
+            },
         };
 
         let filename = format!("{}-{}.pdf",
@@ -770,12 +1422,17 @@ impl PdfWriterAdapter {
                 crate::domain::document::DocumentType::WaiverIndictment => "waiver-indictment",
                 crate::domain::document::DocumentType::ConditionsRelease => "conditions-release",
                 crate::domain::document::DocumentType::CriminalJudgment => "criminal-judgment",
+                crate::domain::document::DocumentType::Summons => "summons",
+                crate::domain::document::DocumentType::Subpoena => "subpoena",
+                crate::domain::document::DocumentType::ComplianceReport => "compliance-report",
+                crate::domain::document::DocumentType::SlipOpinion => "slip-opinion",
             },
             document.case_number.as_str()
         );
 
         Ok(GeneratedDocument {
             document,
+            page_count: page_count(&pdf_data),
             pdf_data,
             filename,
         })
@@ -799,4 +1456,142 @@ impl DocumentGenerator for PdfWriterAdapter {
     async fn generate_batch(&self, documents: Vec<CourtDocument>) -> Result<Vec<GeneratedDocument>, DocumentError> {
         self.generate_batch_sync(documents)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::document::{DocumentId, DocumentType, Margins, PageSize};
+
+    fn court_order(order_content: String) -> CourtDocument {
+        CourtDocument {
+            id: DocumentId::new(),
+            case_number: CaseNumber::new("1:26-cr-00077".to_string()).unwrap(),
+            document_type: DocumentType::CourtOrder,
+            district: District::new("SDNY".to_string()).unwrap(),
+            created_at: Utc::now(),
+            metadata: DocumentMetadata::CourtOrder {
+                defendant_names: DefendantNames::new(["John Doe"]),
+                judge_name: JudgeName::new("Hon. Maria Alvarez".to_string()).unwrap(),
+                order_title: "ORDER ON PRETRIAL MOTIONS".to_string(),
+                order_content,
+                signature: None,
+            },
+            layout: PageLayout::default(),
+        }
+    }
+
+    fn long_order() -> String {
+        let paragraph = "The Court has considered the parties' submissions, the record, and the applicable law, \
+            and for the reasons stated on the record the motion is granted in part and denied in part.";
+        vec![paragraph; 40].join("\n")
+    }
+
+    fn contains(pdf: &[u8], text: &str) -> bool {
+        pdf.windows(text.len()).any(|w| w == text.as_bytes())
+    }
+
+    #[test]
+    fn test_caption_lists_many_defendants_on_continuation_line() {
+        let mut document = court_order("The motion is granted.".to_string());
+        if let DocumentMetadata::CourtOrder { defendant_names, .. } = &mut document.metadata {
+            *defendant_names = DefendantNames::new(["Smith", "Jones", "Brown", "Garcia", "Lee"]);
+        }
+        let generated = PdfWriterAdapter::new().generate_document_sync(document).unwrap();
+
+        assert!(contains(&generated.pdf_data, "(Smith, et al.)"));
+        assert!(contains(&generated.pdf_data, "Defendants: Smith; Jones; Brown; Garcia; Lee"));
+        assert!(contains(&generated.pdf_data, "(Defendants.)"));
+
+        let single = PdfWriterAdapter::new()
+            .generate_document_sync(court_order("The motion is granted.".to_string()))
+            .unwrap();
+        assert!(contains(&single.pdf_data, "(John Doe)"));
+        assert!(contains(&single.pdf_data, "(Defendant.)"));
+        assert!(!contains(&single.pdf_data, "Defendants:"));
+    }
+
+    #[test]
+    fn test_short_order_fits_one_page() {
+        let generated = PdfWriterAdapter::new()
+            .generate_document_sync(court_order("The motion is granted.".to_string()))
+            .unwrap();
+        assert_eq!(generated.page_count, 1);
+    }
+
+    #[test]
+    fn test_long_order_continues_onto_numbered_pages() {
+        let generated = PdfWriterAdapter::new().generate_document_sync(court_order(long_order())).unwrap();
+
+        assert!(generated.page_count > 1, "expected several pages, got {}", generated.page_count);
+        let footer = format!("Page {} of {}", generated.page_count, generated.page_count);
+        assert!(generated.pdf_data.windows(footer.len()).any(|w| w == footer.as_bytes()));
+    }
+
+    #[test]
+    fn test_legal_size_reflows_onto_fewer_pages() {
+        let letter = PdfWriterAdapter::new().generate_document_sync(court_order(long_order())).unwrap();
+
+        let mut document = court_order(long_order());
+        document.layout.page_size = PageSize::Legal;
+        let legal = PdfWriterAdapter::new().generate_document_sync(document).unwrap();
+
+        assert_ne!(letter.pdf_data, legal.pdf_data);
+        assert!(contains(&legal.pdf_data, "0 0 612 1008"), "legal media box is 14 inches tall");
+        assert!(contains(&letter.pdf_data, "0 0 612 792"));
+        assert!(
+            legal.page_count < letter.page_count,
+            "legal {} pages, letter {} pages",
+            legal.page_count,
+            letter.page_count
+        );
+    }
+
+    #[test]
+    fn test_wider_margins_wrap_more_lines() {
+        let default = PdfWriterAdapter::new().generate_document_sync(court_order(long_order())).unwrap();
+
+        let mut document = court_order(long_order());
+        document.layout.margins = Margins { left: 144.0, right: 144.0, ..Margins::default() };
+        let narrow = PdfWriterAdapter::new().generate_document_sync(document).unwrap();
+
+        assert!(narrow.page_count > default.page_count);
+    }
+
+    #[test]
+    fn test_out_of_range_margin_is_rejected() {
+        let mut document = court_order("The motion is granted.".to_string());
+        document.layout.margins.top = 4.0;
+        assert!(matches!(
+            PdfWriterAdapter::new().generate_document_sync(document),
+            Err(DocumentError::InvalidLayout(_))
+        ));
+    }
+
+    #[test]
+    fn test_slip_opinion_stamps_citation_on_every_page() {
+        let document = CourtDocument {
+            id: DocumentId::new(),
+            case_number: CaseNumber::new("1:24-cv-00042".to_string()).unwrap(),
+            document_type: DocumentType::SlipOpinion,
+            district: District::new("SDNY".to_string()).unwrap(),
+            created_at: Utc::now(),
+            metadata: DocumentMetadata::SlipOpinion {
+                citation: "2024 USDC SDNY 7".to_string(),
+                case_name: "Smith v. Jones".to_string(),
+                title: "Opinion and Order".to_string(),
+                judge_name: JudgeName::new("Hon. Maria Alvarez".to_string()).unwrap(),
+                syllabus: "Summary judgment for the defendant.".to_string(),
+                content: long_order(),
+                published_at: Utc::now(),
+            },
+            layout: PageLayout::default(),
+        };
+        let generated = PdfWriterAdapter::new().generate_document_sync(document).unwrap();
+
+        assert!(generated.page_count > 1);
+        assert_eq!(generated.filename, "slip-opinion-1:24-cv-00042.pdf");
+        let stamps = generated.pdf_data.windows(16).filter(|w| *w == b"2024 USDC SDNY 7").count();
+        // One running head per page plus the citation line under the caption
+        assert_eq!(stamps, generated.page_count + 1);
+    }
+}
@@ -0,0 +1,24 @@
+//! Outbound HTTP adapter for webhook delivery
+//!
+//! Sends notifications with Spin's outbound HTTP. Target hosts must be
+//! permitted by the component's `allowed_outbound_hosts`.
+
+use crate::ports::webhook_delivery::{OutboundWebhook, WebhookDelivery};
+use spin_sdk::http::{Method, Request, Response};
+
+pub struct SpinHttpWebhookDelivery;
+
+impl WebhookDelivery for SpinHttpWebhookDelivery {
+    fn deliver(&self, webhook: &OutboundWebhook) -> Result<u16, String> {
+        let mut builder = Request::builder();
+        builder.method(Method::Post).uri(webhook.target_url.as_str());
+        for (name, value) in &webhook.headers {
+            builder.header(name.as_str(), value.as_str());
+        }
+        let request = builder.body(webhook.body.clone()).build();
+
+        let response: Response = spin_sdk::http::run(spin_sdk::http::send(request))
+            .map_err(|e| format!("Failed to reach {}: {}", webhook.target_url, e))?;
+        Ok(*response.status())
+    }
+}
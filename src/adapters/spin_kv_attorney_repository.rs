@@ -2,22 +2,22 @@
 //!
 //! Implements attorney and party persistence using Spin's key-value store.
 
-use crate::adapters::store_utils::open_validated_store;
+use crate::adapters::store_utils::{open_validated_store, TenantStore};
 use crate::domain::attorney::{
     Attorney, AttorneyStatus, Party, PartyStatus, AttorneyRepresentation,
     ConflictCheck, ServiceRecord, AttorneyMetrics, BarAdmission, FederalAdmission,
-    ProHacViceAdmission, CJAAppointment, ECFRegistration, DisciplinaryAction,
-    ServiceMethod, VoucherStatus, RepresentationStatus
+    ProHacViceAdmission, CJAAppointment, CJAVoucherSummary, ECFRegistration, DisciplinaryAction,
+    ServiceMethod, VoucherStatus, RepresentationStatus, ExpiringProHacVice
 };
 use crate::ports::attorney_repository::AttorneyRepository;
+use crate::utils::name_match::{self, NameMatch};
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use serde_json;
-use spin_sdk::key_value::Store;
 use uuid::Uuid;
 
 pub struct SpinKvAttorneyRepository {
-    store: Store,
+    store: TenantStore,
 }
 
 impl SpinKvAttorneyRepository {
@@ -137,6 +137,11 @@ impl AttorneyRepository for SpinKvAttorneyRepository {
         self.list_with_prefix("attorney:")
     }
 
+    fn count(&self) -> Result<usize> {
+        let keys = self.store.get_keys()?;
+        Ok(keys.iter().filter(|key| key.starts_with("attorney:")).count())
+    }
+
     fn update_attorney(&self, attorney: Attorney) -> Result<Attorney> {
         if attorney.id.is_empty() {
             return Err(anyhow::anyhow!("Cannot update attorney without ID"));
@@ -281,11 +286,12 @@ impl AttorneyRepository for SpinKvAttorneyRepository {
 
     fn find_active_pro_hac_vice(&self) -> Result<Vec<ProHacViceAdmission>> {
         let attorneys = self.find_all_attorneys()?;
+        let now = Utc::now();
         let mut admissions = Vec::new();
 
         for attorney in attorneys {
             for phv in attorney.pro_hac_vice_admissions {
-                if matches!(phv.status, crate::domain::attorney::ProHacViceStatus::Granted) {
+                if phv.is_active(now) {
                     admissions.push(phv);
                 }
             }
@@ -309,6 +315,29 @@ impl AttorneyRepository for SpinKvAttorneyRepository {
         Ok(admissions)
     }
 
+    fn find_pro_hac_vice_expiring(&self, before: DateTime<Utc>) -> Result<Vec<ExpiringProHacVice>> {
+        let now = Utc::now();
+        let mut expiring = Vec::new();
+
+        for attorney in self.find_all_attorneys()? {
+            let attorney_name = format!("{} {}", attorney.first_name, attorney.last_name);
+            for phv in attorney.pro_hac_vice_admissions {
+                let Some(expires) = phv.expiration_date else { continue };
+                if phv.is_active(now) && expires <= before {
+                    expiring.push(ExpiringProHacVice {
+                        attorney_id: attorney.id.clone(),
+                        attorney_name: attorney_name.clone(),
+                        days_remaining: (expires - now).num_days(),
+                        admission: phv,
+                    });
+                }
+            }
+        }
+
+        expiring.sort_by_key(|e| e.admission.expiration_date);
+        Ok(expiring)
+    }
+
     fn add_to_cja_panel(&self, attorney_id: &str, district: &str) -> Result<()> {
         if let Some(mut attorney) = self.find_attorney_by_id(attorney_id)? {
             attorney.cja_panel_member = true;
@@ -354,19 +383,39 @@ impl AttorneyRepository for SpinKvAttorneyRepository {
         }
     }
 
-    fn find_pending_cja_vouchers(&self) -> Result<Vec<CJAAppointment>> {
+    fn update_cja_appointment(&self, attorney_id: &str, appointment: CJAAppointment) -> Result<bool> {
+        let Some(mut attorney) = self.find_attorney_by_id(attorney_id)? else {
+            return Ok(false);
+        };
+        let Some(existing) = attorney.cja_appointments.iter_mut().find(|a| a.id == appointment.id) else {
+            return Ok(false);
+        };
+        *existing = appointment;
+        self.save_attorney(attorney)?;
+        Ok(true)
+    }
+
+    fn find_pending_cja_vouchers(&self) -> Result<Vec<CJAVoucherSummary>> {
         let attorneys = self.find_all_attorneys()?;
-        let mut appointments = Vec::new();
+        let mut vouchers = Vec::new();
 
         for attorney in attorneys {
-            for appt in attorney.cja_appointments {
-                if matches!(appt.voucher_status, VoucherStatus::Submitted | VoucherStatus::UnderReview) {
-                    appointments.push(appt);
+            for appt in &attorney.cja_appointments {
+                if appt.voucher_status == VoucherStatus::Submitted {
+                    vouchers.push(CJAVoucherSummary::new(&attorney, appt.clone()));
                 }
             }
         }
 
-        Ok(appointments)
+        Ok(vouchers)
+    }
+
+    fn get_cja_rotation_cursor(&self, district: &str) -> Result<Option<String>> {
+        self.get_json(&self.get_key("cja_rotation", district))
+    }
+
+    fn set_cja_rotation_cursor(&self, district: &str, attorney_id: &str) -> Result<()> {
+        self.save_json(&self.get_key("cja_rotation", district), &attorney_id)
     }
 
     fn update_ecf_registration(&self, attorney_id: &str, registration: ECFRegistration) -> Result<()> {
@@ -394,14 +443,6 @@ impl AttorneyRepository for SpinKvAttorneyRepository {
         Ok(())
     }
 
-    fn add_disciplinary_action(&self, attorney_id: &str, action: DisciplinaryAction) -> Result<()> {
-        if let Some(mut attorney) = self.find_attorney_by_id(attorney_id)? {
-            attorney.discipline_history.push(action);
-            self.save_attorney(attorney)?;
-        }
-        Ok(())
-    }
-
     fn find_disciplinary_history(&self, attorney_id: &str) -> Result<Vec<DisciplinaryAction>> {
         if let Some(attorney) = self.find_attorney_by_id(attorney_id)? {
             Ok(attorney.discipline_history)
@@ -550,7 +591,13 @@ impl AttorneyRepository for SpinKvAttorneyRepository {
     }
 
     fn find_active_representations(&self, attorney_id: &str) -> Result<Vec<AttorneyRepresentation>> {
-        let prefix = format!("idx:rep:attorney:{}", attorney_id);
+        let mut reps = self.find_representations_by_attorney(attorney_id)?;
+        reps.retain(|rep| rep.end_date.is_none());
+        Ok(reps)
+    }
+
+    fn find_representations_by_attorney(&self, attorney_id: &str) -> Result<Vec<AttorneyRepresentation>> {
+        let prefix = format!("idx:rep:attorney:{}:", attorney_id);
         let keys = self.store.get_keys()?;
         let mut reps = Vec::new();
 
@@ -559,9 +606,7 @@ impl AttorneyRepository for SpinKvAttorneyRepository {
                 if let Some(id_bytes) = self.store.get(key)? {
                     let id = String::from_utf8(id_bytes)?;
                     if let Some(rep) = self.find_representation_by_id(&id)? {
-                        if rep.end_date.is_none() {
-                            reps.push(rep);
-                        }
+                        reps.push(rep);
                     }
                 }
             }
@@ -698,20 +743,25 @@ impl AttorneyRepository for SpinKvAttorneyRepository {
         Ok(checks)
     }
 
-    fn find_conflicts_for_parties(&self, attorney_id: &str, party_names: Vec<String>) -> Result<Vec<ConflictCheck>> {
+    fn find_all_conflict_checks(&self) -> Result<Vec<ConflictCheck>> {
+        self.list_with_prefix::<ConflictCheck>("conflict:")
+    }
+
+    fn find_conflicts_for_parties(
+        &self,
+        attorney_id: &str,
+        party_names: Vec<String>,
+        threshold: f64,
+    ) -> Result<Vec<(ConflictCheck, Vec<NameMatch>)>> {
         let checks = self.find_conflict_checks_by_attorney(attorney_id)?;
-        let party_names_lower: Vec<String> = party_names.iter().map(|n| n.to_lowercase()).collect();
 
         Ok(checks.into_iter()
-            .filter(|check| {
-                check.party_names.iter().any(|name| {
-                    let name_lower = name.to_lowercase();
-                    party_names_lower.iter().any(|pn| name_lower.contains(pn) || pn.contains(&name_lower))
-                }) ||
-                check.adverse_parties.iter().any(|name| {
-                    let name_lower = name.to_lowercase();
-                    party_names_lower.iter().any(|pn| name_lower.contains(pn) || pn.contains(&name_lower))
-                })
+            .filter_map(|check| {
+                let matches: Vec<NameMatch> = check.party_names.iter()
+                    .chain(&check.adverse_parties)
+                    .filter_map(|name| name_match::best_match(party_names.iter().map(String::as_str), name, threshold))
+                    .collect();
+                (!matches.is_empty()).then_some((check, matches))
             })
             .collect())
     }
@@ -721,6 +771,7 @@ impl AttorneyRepository for SpinKvAttorneyRepository {
         if let Some(mut check) = self.get_json::<ConflictCheck>(&key)? {
             check.cleared = true;
             check.waiver_obtained = waiver_obtained;
+            check.cleared_at = Some(Utc::now());
             self.save_json(&key, &check)?;
         }
         Ok(())
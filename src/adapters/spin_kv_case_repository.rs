@@ -3,19 +3,26 @@
 //! This adapter implements the CaseRepository trait using Spin's
 //! built-in key-value store for persistence.
 
-use crate::adapters::store_utils::open_validated_store;
+use crate::adapters::store_utils::{open_validated_store, TenantStore};
+use crate::domain::case_archive::{CaseArchive, CaseTombstone};
+use crate::domain::common::DateRange;
 use crate::domain::criminal_case::{CaseStatus, CasePriority, CriminalCase};
-use crate::ports::case_repository::{CaseRepository, CaseQuery, CaseQueryRepository, CaseStatistics};
+use crate::ports::case_repository::{
+    CaseArchiveRepository, CaseNumberCounterRepository, CaseRepository, CaseQuery, CaseQueryRepository,
+    CaseStatistics,
+};
 use anyhow::Result;
-use spin_sdk::key_value::Store;
 use uuid::Uuid;
 
 const CASE_KEY_PREFIX: &str = "case-";
 const CASE_INDEX_PREFIX: &str = "case-idx-";
+const ARCHIVE_KEY_PREFIX: &str = "archive:case:";
+const TOMBSTONE_KEY_PREFIX: &str = "archive:tombstone:";
+const CASE_NUMBER_COUNTER_PREFIX: &str = "case-num-seq:";
 
 /// Spin KV implementation of the CaseRepository
 pub struct SpinKvCaseRepository {
-    store: Store,
+    store: TenantStore,
 }
 
 impl SpinKvCaseRepository {
@@ -23,6 +30,11 @@ impl SpinKvCaseRepository {
     pub fn with_store(store_name: String) -> Self {
         let store = open_validated_store(&store_name)
             .expect(&format!("Failed to open store: {}", store_name));
+        Self::with_tenant_store(store)
+    }
+
+    /// Wrap an opened store
+    pub(crate) fn with_tenant_store(store: TenantStore) -> Self {
         Self { store }
     }
 
@@ -36,6 +48,14 @@ impl SpinKvCaseRepository {
         format!("{}case-num-{}", CASE_INDEX_PREFIX, case_number)
     }
 
+    fn build_archive_key(id: Uuid) -> String {
+        format!("{}{}", ARCHIVE_KEY_PREFIX, id)
+    }
+
+    fn build_tombstone_key(id: Uuid) -> String {
+        format!("{}{}", TOMBSTONE_KEY_PREFIX, id)
+    }
+
 }
 
 impl CaseRepository for SpinKvCaseRepository {
@@ -123,6 +143,50 @@ impl CaseRepository for SpinKvCaseRepository {
         let cases = self.find_by_status(status)?;
         Ok(cases.len())
     }
+
+    fn count(&self) -> Result<usize> {
+        Ok(self.store.count_records(CASE_KEY_PREFIX)?)
+    }
+}
+
+impl CaseArchiveRepository for SpinKvCaseRepository {
+    fn save_archive(&self, archive: &CaseArchive) -> Result<()> {
+        self.store.set_json(Self::build_archive_key(archive.case_id), archive)?;
+        self.store.set_json(
+            Self::build_tombstone_key(archive.case_id),
+            &CaseTombstone::from_archive(archive),
+        )?;
+        Ok(())
+    }
+
+    fn find_archive(&self, case_id: Uuid) -> Result<Option<CaseArchive>> {
+        self.store.get_json::<CaseArchive>(&Self::build_archive_key(case_id))
+    }
+
+    fn find_tombstone(&self, case_id: Uuid) -> Result<Option<CaseTombstone>> {
+        self.store.get_json::<CaseTombstone>(&Self::build_tombstone_key(case_id))
+    }
+
+    fn delete_archive(&self, case_id: Uuid) -> Result<bool> {
+        let key = Self::build_archive_key(case_id);
+        let exists = self.store.exists(&key)?;
+        self.store.delete(Self::build_tombstone_key(case_id))?;
+        if exists {
+            self.store.delete(&key)?;
+        }
+        Ok(exists)
+    }
+
+    fn count_archives(&self) -> Result<usize> {
+        Ok(self.store.count_records(ARCHIVE_KEY_PREFIX)?)
+    }
+}
+
+impl CaseNumberCounterRepository for SpinKvCaseRepository {
+    fn next_counter_value(&self, key: &str) -> Result<u64> {
+        let value = self.store.increment(format!("{}{}", CASE_NUMBER_COUNTER_PREFIX, key), 1)?;
+        Ok(u64::try_from(value)?)
+    }
 }
 
 impl CaseQueryRepository for SpinKvCaseRepository {
@@ -130,8 +194,8 @@ impl CaseQueryRepository for SpinKvCaseRepository {
         let mut cases = self.find_all_cases()?;
 
         // Apply filters
-        if let Some(status) = query.status {
-            cases.retain(|c| c.status == status);
+        if !query.statuses.is_empty() {
+            cases.retain(|c| query.statuses.contains(&c.status));
         }
 
         if let Some(priority) = query.priority {
@@ -146,6 +210,15 @@ impl CaseQueryRepository for SpinKvCaseRepository {
             cases.retain(|c| c.is_active() == is_active);
         }
 
+        if let Some(search_text) = query.search_text {
+            let search_lower = search_text.to_lowercase();
+            cases.retain(|c| {
+                c.case_number.to_lowercase().contains(&search_lower) ||
+                c.title.to_lowercase().contains(&search_lower) ||
+                c.description.to_lowercase().contains(&search_lower)
+            });
+        }
+
         // Sort by updated_at (most recent first)
         cases.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
 
@@ -162,10 +235,14 @@ impl CaseQueryRepository for SpinKvCaseRepository {
         Ok((paginated, total))
     }
 
-    fn get_statistics(&self) -> Result<CaseStatistics> {
-        let cases = self.find_all_cases()?;
+    fn get_statistics(&self, range: DateRange) -> Result<CaseStatistics> {
+        let cases: Vec<CriminalCase> = self.find_all_cases()?
+            .into_iter()
+            .filter(|c| range.contains(c.opened_at))
+            .collect();
 
         let stats = CaseStatistics {
+            range,
             total_cases: cases.len(),
             open_cases: cases.iter().filter(|c| matches!(c.status, CaseStatus::Filed | CaseStatus::Arraigned)).count(),
             under_investigation: cases.iter().filter(|c| matches!(c.status, CaseStatus::Discovery | CaseStatus::PretrialMotions)).count(),
@@ -178,3 +255,71 @@ impl CaseQueryRepository for SpinKvCaseRepository {
         Ok(stats)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::store_utils::testing::MemoryBackend;
+    use crate::domain::case_number::CaseNumberFormat;
+    use crate::services::case_number_service::CaseNumberGenerator;
+    use std::collections::HashSet;
+    use std::thread;
+
+    fn repo(backend: &MemoryBackend) -> SpinKvCaseRepository {
+        SpinKvCaseRepository::with_tenant_store(TenantStore::with_backend("sdny", backend.clone()))
+    }
+
+    #[test]
+    fn test_counter_values_are_never_handed_out_twice() {
+        let backend = MemoryBackend::default();
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let repo = repo(&backend);
+                thread::spawn(move || {
+                    (0..50).map(|_| repo.next_counter_value("SDNY:24:cr").unwrap()).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut seen = HashSet::new();
+        for handle in handles {
+            for value in handle.join().unwrap() {
+                assert!(seen.insert(value), "counter value {} handed out twice", value);
+            }
+        }
+        assert_eq!(seen, (1..=400).collect::<HashSet<u64>>());
+    }
+
+    #[test]
+    fn test_concurrent_creates_get_distinct_case_numbers() {
+        let backend = MemoryBackend::default();
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let repo = repo(&backend);
+                thread::spawn(move || {
+                    let generator = CaseNumberGenerator::new(&repo, CaseNumberFormat::default());
+                    (0..25).map(|_| generator.next("SDNY", "criminal", 2024).unwrap()).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut seen = HashSet::new();
+        for handle in handles {
+            for number in handle.join().unwrap() {
+                assert!(seen.insert(number.clone()), "case number {} handed out twice", number);
+            }
+        }
+        assert_eq!(seen.len(), 200);
+    }
+
+    #[test]
+    fn test_counters_are_kept_per_tenant() {
+        let backend = MemoryBackend::default();
+        let sdny = repo(&backend);
+        let edny = SpinKvCaseRepository::with_tenant_store(TenantStore::with_backend("edny", backend.clone()));
+
+        assert_eq!(sdny.next_counter_value("SDNY:24:cr").unwrap(), 1);
+        assert_eq!(sdny.next_counter_value("SDNY:24:cr").unwrap(), 2);
+        assert_eq!(edny.next_counter_value("SDNY:24:cr").unwrap(), 1);
+    }
+}
@@ -3,13 +3,13 @@
 //! This adapter implements the ConfigRepository trait using Spin's Key-Value store,
 //! providing persistent storage for district and judge configuration overrides.
 
-use crate::adapters::store_utils::open_validated_store;
+use crate::adapters::store_utils::{open_validated_store, TenantStore};
 use crate::adapters::toml_config_loader::TomlConfigLoader;
-use crate::domain::config::{Configuration, ConfigOverride};
+use crate::domain::config::{Configuration, ConfigOverride, ConfigOverrideVersion, CourtClosure};
 use crate::error::ApiError;
 use crate::ports::config_repository::ConfigRepository;
 use async_trait::async_trait;
-use spin_sdk::key_value::{Error as KvError, Store};
+use spin_sdk::key_value::Error as KvError;
 
 /// Spin KV implementation of the configuration repository
 pub struct SpinKvConfigRepository {
@@ -46,7 +46,7 @@ impl SpinKvConfigRepository {
     }
 
     /// Get the KV store instance
-    fn get_store(&self) -> Result<Store, ApiError> {
+    fn get_store(&self) -> Result<TenantStore, ApiError> {
         open_validated_store(&self.store_name).map_err(|e| match e.downcast_ref::<KvError>() {
             Some(KvError::NoSuchStore) => ApiError::NotFound(format!("Store '{}' not found. Ensure the tenant is configured.", self.store_name)),
             Some(KvError::AccessDenied) => ApiError::Forbidden(format!("Access denied to store '{}'", self.store_name)),
@@ -63,6 +63,21 @@ impl SpinKvConfigRepository {
     fn judge_key(&self, district_id: &str, judge_id: &str) -> String {
         format!("config:judge:{}:{}", district_id, judge_id)
     }
+
+    /// Key prefix for the versions of a district's overrides
+    fn district_history_prefix(&self, district_id: &str) -> String {
+        format!("config:district_history:{}:", district_id)
+    }
+
+    /// Generate key for a version of district overrides, padded so keys sort by version
+    fn district_version_key(&self, district_id: &str, version: u32) -> String {
+        format!("{}{:08}", self.district_history_prefix(district_id), version)
+    }
+
+    /// Generate key for district closure dates
+    fn closures_key(&self, district_id: &str) -> String {
+        format!("config:closures:{}", district_id)
+    }
 }
 
 #[async_trait]
@@ -150,6 +165,67 @@ impl ConfigRepository for SpinKvConfigRepository {
 
         Ok(())
     }
+
+    async fn save_district_override_version(&self, district_id: &str, version: &ConfigOverrideVersion) -> Result<(), ApiError> {
+        let store = self.get_store()?;
+        let key = self.district_version_key(district_id, version.version);
+
+        let json = serde_json::to_vec(version)
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to serialize override version: {}", e)))?;
+
+        store.set(&key, &json)
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to save override version: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn list_district_override_versions(&self, district_id: &str) -> Result<Vec<ConfigOverrideVersion>, ApiError> {
+        let store = self.get_store()?;
+        let prefix = self.district_history_prefix(district_id);
+
+        let keys = store.get_keys()
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to list override versions: {}", e)))?;
+
+        let mut versions = Vec::new();
+        for key in keys.iter().filter(|k| k.starts_with(&prefix)) {
+            match store.get(key) {
+                Ok(Some(data)) => versions.push(
+                    serde_json::from_slice::<ConfigOverrideVersion>(&data)
+                        .map_err(|e| ApiError::InternalServerError(format!("Failed to deserialize override version: {}", e)))?,
+                ),
+                Ok(None) => {}
+                Err(e) => return Err(ApiError::InternalServerError(format!("Failed to get override version: {}", e))),
+            }
+        }
+        versions.sort_by_key(|v| v.version);
+
+        Ok(versions)
+    }
+
+    async fn get_closures(&self, district_id: &str) -> Result<Vec<CourtClosure>, ApiError> {
+        let store = self.get_store()?;
+        let key = self.closures_key(district_id);
+
+        match store.get(&key) {
+            Ok(Some(data)) => serde_json::from_slice(&data)
+                .map_err(|e| ApiError::InternalServerError(format!("Failed to deserialize closures: {}", e))),
+            Ok(None) => Ok(Vec::new()),
+            Err(e) => Err(ApiError::InternalServerError(format!("Failed to get closures: {}", e))),
+        }
+    }
+
+    async fn save_closures(&self, district_id: &str, closures: &[CourtClosure]) -> Result<(), ApiError> {
+        let store = self.get_store()?;
+        let key = self.closures_key(district_id);
+
+        let json = serde_json::to_vec(closures)
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to serialize closures: {}", e)))?;
+
+        store.set(&key, &json)
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to save closures: {}", e)))?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -162,5 +238,7 @@ mod tests {
 
         assert_eq!(repo.district_key("SDNY"), "config:district:SDNY");
         assert_eq!(repo.judge_key("SDNY", "judge-123"), "config:judge:SDNY:judge-123");
+        assert_eq!(repo.closures_key("SDNY"), "config:closures:SDNY");
+        assert_eq!(repo.district_version_key("SDNY", 12), "config:district_history:SDNY:00000012");
     }
 }
\ No newline at end of file
@@ -3,19 +3,19 @@
 //! This adapter implements the DeadlineRepository traits using Spin's
 //! built-in key-value store for persistence.
 
-use crate::adapters::store_utils::open_validated_store;
+use crate::adapters::store_utils::{open_validated_store, TenantStore};
 use crate::domain::deadline::{
-    Deadline, DeadlineType, DeadlineStatus, ExtensionRequest,
+    Deadline, DeadlineRule, DeadlineType, DeadlineStatus, ExtensionRequest,
     ExtensionStatus, DeadlineReminder, DeadlineMonitor
 };
 use crate::ports::deadline_repository::{
-    DeadlineRepository, ExtensionRepository, ReminderRepository,
-    DeadlineQuery, DeadlineComplianceRepository, ComplianceStatistics,
+    DeadlineRepository, ExtensionRepository, ReminderRepository, DeadlineRuleRepository,
+    DeadlineQuery, DeadlineSortField, DeadlineComplianceRepository, ComplianceStatistics,
     ComplianceReport, PerformanceMetrics
 };
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use spin_sdk::key_value::Store;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use std::collections::HashMap;
 
@@ -23,10 +23,23 @@ const DEADLINE_KEY_PREFIX: &str = "deadline-";
 const EXTENSION_KEY_PREFIX: &str = "extension-";
 const REMINDER_KEY_PREFIX: &str = "reminder-";
 const INDEX_KEY_PREFIX: &str = "idx-";
+const DEADLINE_RULE_KEY_PREFIX: &str = "deadline_rule-";
+const DUE_DATE_INDEX_KEY: &str = "idx-deadline-due";
+
+/// Entry in the tenant's due-date-ordered deadline index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DueDateIndexEntry {
+    due_date: DateTime<Utc>,
+    id: Uuid,
+    /// When the deadline was first saved; unknown for deadlines saved
+    /// before the index existed
+    #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
+}
 
 /// Spin KV implementation of the DeadlineRepository
 pub struct SpinKvDeadlineRepository {
-    store: Store,
+    store: TenantStore,
 }
 
 impl SpinKvDeadlineRepository {
@@ -34,13 +47,51 @@ impl SpinKvDeadlineRepository {
     pub fn with_store(store_name: String) -> Self {
         let store = open_validated_store(&store_name)
             .expect(&format!("Failed to open store: {}", store_name));
+        Self::with_tenant_store(store)
+    }
+
+    /// Wrap an opened store
+    pub(crate) fn with_tenant_store(store: TenantStore) -> Self {
         Self { store }
     }
 
+    /// The due-date index, ordered by due date then ID
+    ///
+    /// A store written before the index existed has it built from every
+    /// deadline the first time it is needed.
+    fn due_date_index(&self) -> Result<Vec<DueDateIndexEntry>> {
+        if let Some(index) = self.store.get_json::<Vec<DueDateIndexEntry>>(DUE_DATE_INDEX_KEY)? {
+            return Ok(index);
+        }
+        let mut index: Vec<DueDateIndexEntry> = self.find_all_deadlines()?
+            .into_iter()
+            .map(|d| DueDateIndexEntry { due_date: d.due_date, id: d.id, created_at: None })
+            .collect();
+        index.sort_by_key(|e| (e.due_date, e.id));
+        self.store.set_json(DUE_DATE_INDEX_KEY, &index)?;
+        Ok(index)
+    }
+
+    /// Place a saved deadline in the due-date index, keeping its creation time
+    fn index_due_date(&self, deadline: &Deadline) -> Result<()> {
+        let mut index = self.due_date_index()?;
+        let created_at = match index.iter().position(|e| e.id == deadline.id) {
+            Some(i) => index.remove(i).created_at,
+            None => Some(Utc::now()),
+        };
+        let at = index.partition_point(|e| (e.due_date, e.id) < (deadline.due_date, deadline.id));
+        index.insert(at, DueDateIndexEntry { due_date: deadline.due_date, id: deadline.id, created_at });
+        self.store.set_json(DUE_DATE_INDEX_KEY, &index)
+    }
+
     fn build_deadline_key(id: Uuid) -> String {
         format!("{}{}", DEADLINE_KEY_PREFIX, id)
     }
 
+    fn build_deadline_rule_key(id: &str) -> String {
+        format!("{}{}", DEADLINE_RULE_KEY_PREFIX, id)
+    }
+
     fn build_extension_key(deadline_id: Uuid, extension_id: Uuid) -> String {
         format!("{}{}-{}", EXTENSION_KEY_PREFIX, deadline_id, extension_id)
     }
@@ -61,18 +112,35 @@ impl DeadlineRepository for SpinKvDeadlineRepository {
         // Update case deadline index
         let index_key = Self::build_case_deadline_index_key(deadline.case_id);
         let mut deadlines = self.find_deadlines_by_case(deadline.case_id)?;
-        if !deadlines.iter().any(|d| d.id == deadline.id) {
-            deadlines.push(deadline.clone());
+        match deadlines.iter_mut().find(|d| d.id == deadline.id) {
+            Some(existing) => *existing = deadline.clone(),
+            None => deadlines.push(deadline.clone()),
         }
         self.store.set_json(&index_key, &deadlines)?;
 
-        Ok(())
+        self.index_due_date(deadline)
     }
 
     fn find_deadline_by_id(&self, id: Uuid) -> Result<Option<Deadline>> {        let key = Self::build_deadline_key(id);
         self.store.get_json::<Deadline>(&key)
     }
 
+    fn find_all_deadlines(&self) -> Result<Vec<Deadline>> {
+        let deadlines: Vec<Deadline> = self.store
+            .get_keys()?
+            .iter()
+            .filter(|key| key.starts_with(DEADLINE_KEY_PREFIX))
+            .filter_map(|key| self.store.get_json::<Deadline>(key.as_str()).ok())
+            .flatten()
+            .collect();
+
+        Ok(deadlines)
+    }
+
+    fn count(&self) -> Result<usize> {
+        Ok(self.store.count_records(DEADLINE_KEY_PREFIX)?)
+    }
+
     fn find_deadlines_by_case(&self, case_id: Uuid) -> Result<Vec<Deadline>> {        let index_key = Self::build_case_deadline_index_key(case_id);
 
         match self.store.get_json::<Vec<Deadline>>(&index_key)? {
@@ -148,13 +216,22 @@ impl DeadlineRepository for SpinKvDeadlineRepository {
 
     fn delete_deadline(&self, id: Uuid) -> Result<bool> {        let key = Self::build_deadline_key(id);
 
-        let exists = self.store.exists(&key)?;
-        if exists {
-            self.store.delete(&key)?;
-            Ok(true)
-        } else {
-            Ok(false)
-        }
+        let Some(deadline) = self.store.get_json::<Deadline>(&key)? else {
+            return Ok(false);
+        };
+        self.store.delete(&key)?;
+
+        // Drop it from the case deadline index
+        let index_key = Self::build_case_deadline_index_key(deadline.case_id);
+        let mut deadlines = self.find_deadlines_by_case(deadline.case_id)?;
+        deadlines.retain(|d| d.id != id);
+        self.store.set_json(&index_key, &deadlines)?;
+
+        let mut index = self.due_date_index()?;
+        index.retain(|e| e.id != id);
+        self.store.set_json(DUE_DATE_INDEX_KEY, &index)?;
+
+        Ok(true)
     }
 }
 
@@ -213,24 +290,18 @@ impl ExtensionRepository for SpinKvDeadlineRepository {
         Ok(pending)
     }
 
-    fn update_extension_status(&self, id: Uuid, status: ExtensionStatus) -> Result<()> {
-        // Find and update the extension
+    fn find_deadline_by_extension(&self, extension_id: Uuid) -> Result<Option<Deadline>> {
         for key in self.store.get_keys()? {
             if key.starts_with(DEADLINE_KEY_PREFIX) {
-                if let Ok(Some(mut deadline)) = self.store.get_json::<Deadline>(&key) {
-                    for extension in &mut deadline.extension_requests {
-                        if extension.id == id {
-                            extension.status = status;
-                            extension.ruling_date = Some(Utc::now());
-                            self.save_deadline(&deadline)?;
-                            return Ok(());
-                        }
+                if let Ok(Some(deadline)) = self.store.get_json::<Deadline>(&key) {
+                    if deadline.extension_requests.iter().any(|e| e.id == extension_id) {
+                        return Ok(Some(deadline));
                     }
                 }
             }
         }
 
-        Ok(())
+        Ok(None)
     }
 }
 
@@ -285,7 +356,7 @@ impl ReminderRepository for SpinKvDeadlineRepository {
         Ok(())
     }
 
-    fn get_pending_reminders(&self) -> Result<Vec<DeadlineReminder>> {        let mut all_deadlines = Vec::new();
+    fn get_pending_reminders(&self, schedule_days: &[i64]) -> Result<Vec<DeadlineReminder>> {        let mut all_deadlines = Vec::new();
 
         for key in self.store.get_keys()? {
             if key.starts_with(DEADLINE_KEY_PREFIX) {
@@ -295,51 +366,64 @@ impl ReminderRepository for SpinKvDeadlineRepository {
             }
         }
 
-        Ok(DeadlineMonitor::generate_reminders(&all_deadlines, Utc::now()))
+        Ok(DeadlineMonitor::generate_reminders_on_schedule(&all_deadlines, Utc::now(), schedule_days))
     }
 }
 
-impl DeadlineComplianceRepository for SpinKvDeadlineRepository {
-    fn search_deadlines(&self, query: DeadlineQuery) -> Result<(Vec<Deadline>, usize)> {
-        let mut deadlines: Vec<Deadline> = if let Some(case_id) = query.case_id {
-            self.find_deadlines_by_case(case_id)?
-        } else {
-            self.store
-                .get_keys()?
-                .iter()
-                .filter(|key| key.starts_with(DEADLINE_KEY_PREFIX))
-                .filter_map(|key| self.store.get_json::<Deadline>(key.as_str()).ok())
-                .filter_map(|deadline| deadline)
-                .collect()
-        };
-
-        // Apply filters
-        if let Some(deadline_type) = query.deadline_type {
-            deadlines.retain(|d| std::mem::discriminant(&d.deadline_type) == std::mem::discriminant(&deadline_type));
-        }
-
-        if let Some(status) = query.status {
-            deadlines.retain(|d| d.status == status);
-        }
+impl DeadlineRuleRepository for SpinKvDeadlineRepository {
+    fn save_deadline_rule(&self, rule: &DeadlineRule) -> Result<()> {
+        let key = Self::build_deadline_rule_key(&rule.id);
+        self.store.set_json(&key, rule)?;
+        Ok(())
+    }
 
-        if let Some(party) = query.responsible_party {
-            deadlines.retain(|d| d.responsible_party.to_lowercase().contains(&party.to_lowercase()));
-        }
+    fn find_deadline_rule(&self, id: &str) -> Result<Option<DeadlineRule>> {
+        let key = Self::build_deadline_rule_key(id);
+        self.store.get_json::<DeadlineRule>(&key)
+    }
 
-        if let Some(jurisdictional) = query.is_jurisdictional {
-            deadlines.retain(|d| d.is_jurisdictional == jurisdictional);
+    fn find_all_deadline_rules(&self) -> Result<Vec<DeadlineRule>> {
+        let mut rules = Vec::new();
+        for key in self.store.get_keys()? {
+            if key.starts_with(DEADLINE_RULE_KEY_PREFIX) {
+                if let Some(rule) = self.store.get_json::<DeadlineRule>(&key)? {
+                    rules.push(rule);
+                }
+            }
         }
+        rules.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(rules)
+    }
+}
 
-        if let Some(date_from) = query.due_date_from {
-            deadlines.retain(|d| d.due_date >= date_from);
-        }
+impl DeadlineComplianceRepository for SpinKvDeadlineRepository {
+    fn search_deadlines(&self, query: DeadlineQuery) -> Result<(Vec<Deadline>, usize)> {
+        let needs_index = query.case_ids.is_empty() || query.sort_by == DeadlineSortField::Created;
+        let index = if needs_index { self.due_date_index()? } else { Vec::new() };
 
-        if let Some(date_to) = query.due_date_to {
-            deadlines.retain(|d| d.due_date <= date_to);
-        }
+        let mut deadlines: Vec<Deadline> = if !query.case_ids.is_empty() {
+            let mut deadlines = Vec::new();
+            for case_id in &query.case_ids {
+                deadlines.extend(self.find_deadlines_by_case(*case_id)?);
+            }
+            deadlines
+        } else {
+            // Only deadlines due within the range are loaded
+            let start = query.due_date_from.map_or(0, |from| index.partition_point(|e| e.due_date < from));
+            let end = query.due_date_to.map_or(index.len(), |to| index.partition_point(|e| e.due_date <= to));
+            let mut deadlines = Vec::new();
+            for entry in index.get(start..end).unwrap_or_default() {
+                deadlines.extend(self.find_deadline_by_id(entry.id)?);
+            }
+            deadlines
+        };
 
-        // Sort by due date
-        deadlines.sort_by_key(|d| d.due_date);
+        deadlines.retain(|d| query.matches(d));
+        let created: HashMap<Uuid, DateTime<Utc>> = index
+            .iter()
+            .filter_map(|e| e.created_at.map(|at| (e.id, at)))
+            .collect();
+        query.sort(&mut deadlines, |id| created.get(&id).copied());
 
         // Get total count before pagination
         let total = deadlines.len();
@@ -369,17 +453,19 @@ impl DeadlineComplianceRepository for SpinKvDeadlineRepository {
         let total = deadlines.len();
         let completed_on_time = deadlines.iter().filter(|d| {
             d.status == DeadlineStatus::Completed &&
-            d.completion_date.map_or(false, |cd| cd <= d.due_date)
+            d.completion_date.is_some_and(|cd| cd <= d.effective_due_date())
         }).count();
 
         let completed_late = deadlines.iter().filter(|d| {
             d.status == DeadlineStatus::Completed &&
-            d.completion_date.map_or(false, |cd| cd > d.due_date)
+            d.completion_date.is_some_and(|cd| cd > d.effective_due_date())
         }).count();
 
         let pending = deadlines.iter().filter(|d| d.status == DeadlineStatus::Pending || d.status == DeadlineStatus::Approaching).count();
         let overdue = deadlines.iter().filter(|d| d.status == DeadlineStatus::Overdue).count();
-        let extended = deadlines.iter().filter(|d| d.status == DeadlineStatus::Extended).count();
+        let extended = deadlines.iter().filter(|d| {
+            d.status == DeadlineStatus::Extended || !d.due_date_history.is_empty()
+        }).count();
         let waived = deadlines.iter().filter(|d| d.status == DeadlineStatus::Waived).count();
 
         let compliance_rate = if total > 0 {
@@ -391,7 +477,7 @@ impl DeadlineComplianceRepository for SpinKvDeadlineRepository {
         let average_days_early: f32 = deadlines.iter()
             .filter_map(|d| {
                 if d.status == DeadlineStatus::Completed {
-                    d.completion_date.map(|cd| (d.due_date - cd).num_days() as f32)
+                    d.completion_date.map(|cd| (d.effective_due_date() - cd).num_days() as f32)
                 } else {
                     None
                 }
@@ -431,7 +517,7 @@ impl DeadlineComplianceRepository for SpinKvDeadlineRepository {
             .filter(|key| key.starts_with(DEADLINE_KEY_PREFIX))
             .filter_map(|key| self.store.get_json::<Deadline>(key.as_str()).ok())
             .filter_map(|deadline| deadline)
-            .filter(|d| d.due_date >= start_date && d.due_date <= end_date)
+            .filter(|d| d.effective_due_date() >= start_date && d.effective_due_date() <= end_date)
             .collect();
 
         let total_cases = deadlines.iter().map(|d| d.case_id).collect::<std::collections::HashSet<_>>().len();
@@ -439,7 +525,7 @@ impl DeadlineComplianceRepository for SpinKvDeadlineRepository {
 
         let completed_on_time = deadlines.iter().filter(|d| {
             d.status == DeadlineStatus::Completed &&
-            d.completion_date.map_or(false, |cd| cd <= d.due_date)
+            d.completion_date.is_some_and(|cd| cd <= d.effective_due_date())
         }).count();
 
         let compliance_rate = if deadlines_tracked > 0 {
@@ -507,7 +593,7 @@ impl DeadlineComplianceRepository for SpinKvDeadlineRepository {
         let total = deadlines.len();
         let on_time = deadlines.iter().filter(|d| {
             d.status == DeadlineStatus::Completed &&
-            d.completion_date.map_or(false, |cd| cd <= d.due_date)
+            d.completion_date.is_some_and(|cd| cd <= d.effective_due_date())
         }).count();
 
         let on_time_percentage = if total > 0 {
@@ -556,4 +642,123 @@ impl DeadlineComplianceRepository for SpinKvDeadlineRepository {
             trending,
         })
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::store_utils::testing::MemoryBackend;
+    use crate::domain::deadline::DeadlineCalculator;
+    use crate::ports::deadline_repository::SortDirection;
+    use chrono::{Duration, TimeZone};
+
+    fn repo() -> SpinKvDeadlineRepository {
+        SpinKvDeadlineRepository::with_tenant_store(TenantStore::with_backend("sdny", MemoryBackend::default()))
+    }
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap()
+    }
+
+    fn deadline(case_id: Uuid, due_in_days: i64, party: &str, rule: &str) -> Deadline {
+        let rules = DeadlineCalculator::default_rule_set();
+        let mut deadline = DeadlineCalculator::calculate_from_rules(&rules, "complaint_filed", start(), &[]).remove(0);
+        deadline.id = Uuid::new_v4();
+        deadline.case_id = case_id;
+        deadline.due_date = start() + Duration::days(due_in_days);
+        deadline.responsible_party = party.to_string();
+        deadline.applicable_rule = rule.to_string();
+        deadline.status = DeadlineStatus::Pending;
+        deadline
+    }
+
+    #[test]
+    fn test_due_date_index_stays_ordered_through_updates_and_deletes() {
+        let repo = repo();
+        let case = Uuid::new_v4();
+        let mut late = deadline(case, 30, "Defendant", "FRCP 12(a)(1)(A)");
+        let early = deadline(case, 10, "Defendant", "FRCP 12(a)(1)(A)");
+        let middle = deadline(case, 20, "Plaintiff", "FRCP 26(f)");
+        for d in [&late, &early, &middle] {
+            repo.save_deadline(d).unwrap();
+        }
+        let ids = |repo: &SpinKvDeadlineRepository| repo.due_date_index().unwrap().iter().map(|e| e.id).collect::<Vec<_>>();
+        assert_eq!(ids(&repo), vec![early.id, middle.id, late.id]);
+
+        let created = repo.due_date_index().unwrap()[2].created_at;
+        late.due_date = start() + Duration::days(5);
+        repo.save_deadline(&late).unwrap();
+        assert_eq!(ids(&repo), vec![late.id, early.id, middle.id]);
+        assert_eq!(repo.due_date_index().unwrap()[0].created_at, created, "Updates keep the creation time");
+
+        repo.delete_deadline(early.id).unwrap();
+        assert_eq!(ids(&repo), vec![late.id, middle.id]);
+    }
+
+    #[test]
+    fn test_missing_index_is_rebuilt_from_stored_deadlines() {
+        let repo = repo();
+        let d = deadline(Uuid::new_v4(), 10, "Defendant", "FRCP 12(a)(1)(A)");
+        repo.save_deadline(&d).unwrap();
+        repo.store.delete(DUE_DATE_INDEX_KEY).unwrap();
+
+        let index = repo.due_date_index().unwrap();
+        assert_eq!(index.len(), 1);
+        assert_eq!(index[0].id, d.id);
+        assert_eq!(index[0].created_at, None);
+    }
+
+    #[test]
+    fn test_search_combines_three_filters_with_pagination() {
+        let repo = repo();
+        let case = Uuid::new_v4();
+        let matching: Vec<Deadline> = (1..=5).map(|day| deadline(case, day * 3, "Defendant Smith", "FRCP 12(a)(1)(A)")).collect();
+        let others = [
+            deadline(case, 4, "Plaintiff", "FRCP 12(a)(1)(A)"),
+            deadline(case, 5, "Defendant Smith", "FRCP 26(f)"),
+            deadline(case, 60, "Defendant Smith", "FRCP 12(a)(1)(A)"),
+        ];
+        for d in matching.iter().rev().chain(others.iter()) {
+            repo.save_deadline(d).unwrap();
+        }
+
+        let query = |offset, direction| DeadlineQuery {
+            responsible_party: Some("defendant".to_string()),
+            applicable_rule: Some("12(a)".to_string()),
+            due_date_to: Some(start() + Duration::days(30)),
+            direction,
+            offset,
+            limit: 2,
+            ..Default::default()
+        };
+
+        let (page, total) = repo.search_deadlines(query(0, SortDirection::Asc)).unwrap();
+        assert_eq!(total, 5);
+        assert_eq!(page.iter().map(|d| d.id).collect::<Vec<_>>(), vec![matching[0].id, matching[1].id]);
+
+        let (page, total) = repo.search_deadlines(query(4, SortDirection::Asc)).unwrap();
+        assert_eq!(total, 5);
+        assert_eq!(page.iter().map(|d| d.id).collect::<Vec<_>>(), vec![matching[4].id]);
+
+        let (page, _) = repo.search_deadlines(query(0, SortDirection::Desc)).unwrap();
+        assert_eq!(page.iter().map(|d| d.id).collect::<Vec<_>>(), vec![matching[4].id, matching[3].id]);
+    }
+
+    #[test]
+    fn test_sort_by_created_follows_save_order() {
+        let repo = repo();
+        let case = Uuid::new_v4();
+        let first = deadline(case, 30, "Defendant", "FRCP 12(a)(1)(A)");
+        let second = deadline(case, 10, "Defendant", "FRCP 12(a)(1)(A)");
+        repo.save_deadline(&first).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        repo.save_deadline(&second).unwrap();
+
+        let (page, _) = repo.search_deadlines(DeadlineQuery {
+            case_ids: vec![case],
+            sort_by: DeadlineSortField::Created,
+            limit: 10,
+            ..Default::default()
+        }).unwrap();
+        assert_eq!(page.iter().map(|d| d.id).collect::<Vec<_>>(), vec![first.id, second.id]);
+    }
+}
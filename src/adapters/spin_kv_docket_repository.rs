@@ -3,10 +3,10 @@
 //! This adapter implements the DocketRepository traits using Spin's
 //! built-in key-value store for persistence.
 
-use crate::adapters::store_utils::open_validated_store;
+use crate::adapters::store_utils::{open_validated_store, TenantStore};
 use crate::domain::docket::{
     DocketEntry, CalendarEntry, SpeedyTrialClock, DocketEntryType,
-    EventStatus, CalendarService, SpeedyTrialService
+    EventStatus, CalendarService, SpeedyTrialService, CaseAttachmentSize
 };
 use crate::ports::docket_repository::{
     DocketRepository, CalendarRepository, SpeedyTrialRepository,
@@ -14,8 +14,7 @@ use crate::ports::docket_repository::{
     CalendarSchedulingRepository, FilingStatistics, CourtroomUtilization
 };
 use anyhow::Result;
-use chrono::{DateTime, Duration, Utc};
-use spin_sdk::key_value::Store;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use uuid::Uuid;
 use std::collections::HashMap;
 
@@ -26,7 +25,7 @@ const INDEX_KEY_PREFIX: &str = "idx-";
 
 /// Spin KV implementation of the DocketRepository
 pub struct SpinKvDocketRepository {
-    store: Store,
+    store: TenantStore,
 }
 
 impl SpinKvDocketRepository {
@@ -34,9 +33,21 @@ impl SpinKvDocketRepository {
     pub fn with_store(store_name: String) -> Self {
         let store = open_validated_store(&store_name)
             .expect(&format!("Failed to open store: {}", store_name));
+        Self::with_tenant_store(store)
+    }
+
+    /// Wrap an opened store
+    pub(crate) fn with_tenant_store(store: TenantStore) -> Self {
         Self { store }
     }
 
+    /// Run `f` against this repository, committing all its writes together
+    ///
+    /// See [`TenantStore::transaction`].
+    pub fn transaction<T, E: From<anyhow::Error>>(&self, f: impl FnOnce(&Self) -> Result<T, E>) -> Result<T, E> {
+        self.store.transaction(|| f(self))
+    }
+
     fn build_docket_key(id: Uuid) -> String {
         format!("{}{}", DOCKET_KEY_PREFIX, id)
     }
@@ -56,6 +67,28 @@ impl SpinKvDocketRepository {
     fn build_entry_number_key(case_id: Uuid) -> String {
         format!("{}entry-number-{}", INDEX_KEY_PREFIX, case_id)
     }
+
+    fn build_docket_version_key(case_id: Uuid) -> String {
+        format!("{}docket-version-{}", INDEX_KEY_PREFIX, case_id)
+    }
+
+    fn build_calendar_version_key(judge_id: Uuid) -> String {
+        format!("{}calendar-version-{}", INDEX_KEY_PREFIX, judge_id)
+    }
+
+    fn read_version(&self, key: &str) -> Result<u64> {
+        Ok(self.store.get(key)?
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .and_then(|s| s.parse::<u64>().ok())
+            .unwrap_or(0))
+    }
+
+    /// Bump a collection's version so cached reads of it are revalidated
+    fn bump_version(&self, key: &str) -> Result<()> {
+        let next = self.read_version(key)? + 1;
+        self.store.set(key, next.to_string().as_bytes())?;
+        Ok(())
+    }
 }
 
 impl DocketRepository for SpinKvDocketRepository {
@@ -69,12 +102,14 @@ impl DocketRepository for SpinKvDocketRepository {
         let key = Self::build_docket_key(entry.id);
         self.store.set_json(&key, &entry)?;
 
-        // Update case docket index
+        // Update case docket index, replacing any earlier copy of the entry
         let index_key = Self::build_case_docket_index_key(entry.case_id);
         let mut entries = self.find_entries_by_case(entry.case_id)?;
+        entries.retain(|e| e.id != entry.id);
         entries.push(entry.clone());
         entries.sort_by_key(|e| e.entry_number);
         self.store.set_json(&index_key, &entries)?;
+        self.bump_version(&Self::build_docket_version_key(entry.case_id))?;
 
         Ok(())
     }
@@ -83,6 +118,18 @@ impl DocketRepository for SpinKvDocketRepository {
         self.store.get_json::<DocketEntry>(&key)
     }
 
+    fn find_all_entries(&self) -> Result<Vec<DocketEntry>> {
+        let entries: Vec<DocketEntry> = self.store
+            .get_keys()?
+            .iter()
+            .filter(|key| key.starts_with(DOCKET_KEY_PREFIX))
+            .filter_map(|key| self.store.get_json::<DocketEntry>(key.as_str()).ok())
+            .flatten()
+            .collect();
+
+        Ok(entries)
+    }
+
     fn find_entries_by_case(&self, case_id: Uuid) -> Result<Vec<DocketEntry>> {        let index_key = Self::build_case_docket_index_key(case_id);
 
         match self.store.get_json::<Vec<DocketEntry>>(&index_key)? {
@@ -130,19 +177,48 @@ impl DocketRepository for SpinKvDocketRepository {
 
     fn delete_entry(&self, id: Uuid) -> Result<bool> {        let key = Self::build_docket_key(id);
 
-        let exists = self.store.exists(&key)?;
-        if exists {
-            self.store.delete(&key)?;
-            Ok(true)
-        } else {
-            Ok(false)
-        }
+        let Some(entry) = self.store.get_json::<DocketEntry>(&key)? else {
+            return Ok(false);
+        };
+        self.store.delete(&key)?;
+
+        // Drop it from the case docket index; entry numbers are never reused
+        let index_key = Self::build_case_docket_index_key(entry.case_id);
+        let mut entries = self.find_entries_by_case(entry.case_id)?;
+        entries.retain(|e| e.id != id);
+        self.store.set_json(&index_key, &entries)?;
+        self.bump_version(&Self::build_docket_version_key(entry.case_id))?;
+
+        Ok(true)
+    }
+
+    fn docket_version(&self, case_id: Uuid) -> Result<u64> {
+        self.read_version(&Self::build_docket_version_key(case_id))
+    }
+
+    fn case_attachment_size(&self, case_id: Uuid) -> Result<CaseAttachmentSize> {
+        let entries = self.find_entries_by_case(case_id)?;
+        let attachments = entries.iter().flat_map(|e| &e.attachments);
+
+        Ok(CaseAttachmentSize {
+            case_id,
+            attachment_count: attachments.clone().count(),
+            total_bytes: attachments.clone().map(|a| a.file_size_bytes).sum(),
+            total_pages: attachments.map(|a| u64::from(a.page_count)).sum(),
+        })
     }
 }
 
 impl CalendarRepository for SpinKvDocketRepository {
     fn save_event(&self, event: &CalendarEntry) -> Result<()> {        let key = Self::build_calendar_key(event.id);
+        // A reassigned event also leaves the previous judge's calendar
+        if let Some(previous) = self.store.get_json::<CalendarEntry>(&key)? {
+            if previous.judge_id != event.judge_id {
+                self.bump_version(&Self::build_calendar_version_key(previous.judge_id))?;
+            }
+        }
         self.store.set_json(&key, event)?;
+        self.bump_version(&Self::build_calendar_version_key(event.judge_id))?;
         Ok(())
     }
 
@@ -150,6 +226,18 @@ impl CalendarRepository for SpinKvDocketRepository {
         self.store.get_json::<CalendarEntry>(&key)
     }
 
+    fn find_all_events(&self) -> Result<Vec<CalendarEntry>> {
+        let events: Vec<CalendarEntry> = self.store
+            .get_keys()?
+            .iter()
+            .filter(|key| key.starts_with(CALENDAR_KEY_PREFIX))
+            .filter_map(|key| self.store.get_json::<CalendarEntry>(key.as_str()).ok())
+            .flatten()
+            .collect();
+
+        Ok(events)
+    }
+
     fn find_events_by_case(&self, case_id: Uuid) -> Result<Vec<CalendarEntry>> {
         let events: Vec<CalendarEntry> = self.store
             .get_keys()?
@@ -228,13 +316,16 @@ impl CalendarRepository for SpinKvDocketRepository {
 
     fn delete_event(&self, id: Uuid) -> Result<bool> {        let key = Self::build_calendar_key(id);
 
-        let exists = self.store.exists(&key)?;
-        if exists {
-            self.store.delete(&key)?;
-            Ok(true)
-        } else {
-            Ok(false)
-        }
+        let Some(event) = self.store.get_json::<CalendarEntry>(&key)? else {
+            return Ok(false);
+        };
+        self.store.delete(&key)?;
+        self.bump_version(&Self::build_calendar_version_key(event.judge_id))?;
+        Ok(true)
+    }
+
+    fn judge_calendar_version(&self, judge_id: Uuid) -> Result<u64> {
+        self.read_version(&Self::build_calendar_version_key(judge_id))
     }
 }
 
@@ -277,6 +368,16 @@ impl SpeedyTrialRepository for SpinKvDocketRepository {
     fn update_clock(&self, _case_id: Uuid, clock: &SpeedyTrialClock) -> Result<()> {
         self.save_clock(clock)
     }
+
+    fn delete_clock(&self, case_id: Uuid) -> Result<bool> {
+        let key = Self::build_speedy_key(case_id);
+
+        let exists = self.store.exists(&key)?;
+        if exists {
+            self.store.delete(&key)?;
+        }
+        Ok(exists)
+    }
 }
 
 impl DocketQueryRepository for SpinKvDocketRepository {
@@ -294,8 +395,9 @@ impl DocketQueryRepository for SpinKvDocketRepository {
         };
 
         // Apply filters
-        if let Some(entry_type) = query.entry_type {
-            entries.retain(|e| std::mem::discriminant(&e.entry_type) == std::mem::discriminant(&entry_type));
+        if !query.entry_types.is_empty() {
+            entries.retain(|e| query.entry_types.iter()
+                .any(|t| std::mem::discriminant(&e.entry_type) == std::mem::discriminant(t)));
         }
 
         if let Some(filed_by) = query.filed_by {
@@ -306,6 +408,14 @@ impl DocketQueryRepository for SpinKvDocketRepository {
             entries.retain(|e| e.is_sealed);
         }
 
+        if !query.include_sealed {
+            entries.retain(|e| !e.is_sealed);
+        }
+
+        if !query.include_ex_parte {
+            entries.retain(|e| !e.is_ex_parte);
+        }
+
         if let Some(date_from) = query.date_from {
             entries.retain(|e| e.date_filed >= date_from);
         }
@@ -381,8 +491,9 @@ impl DocketQueryRepository for SpinKvDocketRepository {
         })
     }
 
-    fn generate_docket_sheet(&self, case_id: Uuid) -> Result<String> {
-        let entries = self.find_entries_by_case(case_id)?;
+    fn generate_docket_sheet(&self, case_id: Uuid, include_sealed: bool, include_ex_parte: bool) -> Result<String> {
+        let mut entries = self.find_entries_by_case(case_id)?;
+        entries.retain(|e| (include_sealed || !e.is_sealed) && (include_ex_parte || !e.is_ex_parte));
         let mut docket_sheet = String::new();
 
         docket_sheet.push_str(&format!("DOCKET SHEET - Case ID: {}\n", case_id));
@@ -467,9 +578,15 @@ impl CalendarSchedulingRepository for SpinKvDocketRepository {
         Ok((paginated, total))
     }
 
-    fn find_available_slot(&self, judge_id: Uuid, duration_minutes: u32, earliest: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    fn find_available_slot(
+        &self,
+        judge_id: Uuid,
+        duration_minutes: u32,
+        earliest: DateTime<Utc>,
+        closures: &[NaiveDate],
+    ) -> Result<DateTime<Utc>> {
         let events = self.find_events_by_judge(judge_id)?;
-        Ok(CalendarService::find_next_available_slot(&events, judge_id, duration_minutes, earliest))
+        Ok(CalendarService::find_next_available_slot(&events, judge_id, duration_minutes, earliest, closures))
     }
 
     fn get_judge_schedule(&self, judge_id: Uuid, date: DateTime<Utc>) -> Result<Vec<CalendarEntry>> {
@@ -545,3 +662,89 @@ impl CalendarSchedulingRepository for SpinKvDocketRepository {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::store_utils::testing::{FailingBackend, MemoryBackend};
+    use crate::adapters::store_utils::KvBackend;
+    use crate::adapters::unit_of_work::recovery_delay;
+    use crate::domain::docket::{CalendarEventType, DocketAttachment, DocketService};
+
+    fn repo(backend: impl KvBackend + 'static) -> SpinKvDocketRepository {
+        SpinKvDocketRepository::with_tenant_store(TenantStore::with_backend("sdny", backend))
+    }
+
+    #[test]
+    fn test_event_and_minute_entry_survive_or_vanish_together() {
+        let mut outcomes = Vec::new();
+        for n in 0..12 {
+            let backend = MemoryBackend::default();
+            let event = CalendarService::schedule_event(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                CalendarEventType::StatusConference,
+                Utc::now() + Duration::days(7),
+                30,
+                "4A".to_string(),
+            );
+            let entry = DocketService::generate_minute_entry(&event);
+
+            let crashed = repo(FailingBackend::crash_at(backend.clone(), n));
+            let _ = crashed.transaction(|repo| -> Result<()> {
+                repo.save_event(&event)?;
+                repo.save_entry(&entry)
+            });
+
+            let recovered = repo(backend.clone());
+            recovered.store.recover_journals_at(Utc::now() + recovery_delay()).unwrap();
+
+            let has_event = recovered.find_event_by_id(event.id).unwrap().is_some();
+            let has_entry = recovered.find_entry_by_id(entry.id).unwrap().is_some();
+            let indexed = recovered.find_entries_by_case(event.case_id).unwrap().iter().any(|e| e.id == entry.id);
+            assert_eq!(has_event, has_entry, "crash at write {}", n);
+            assert_eq!(has_entry, indexed, "crash at write {}", n);
+            assert!(backend.journal_keys().is_empty(), "crash at write {}", n);
+            outcomes.push(has_event);
+        }
+        assert!(!outcomes[0], "a crash before the journal lands writes nothing");
+        assert!(outcomes[11], "enough writes for the whole commit");
+    }
+
+    #[test]
+    fn test_case_attachment_size_counts_each_entry_once() {
+        let repo = repo(MemoryBackend::default());
+        let case_id = Uuid::new_v4();
+        let attachment = |number: u32, pages: u32, bytes: u64| DocketAttachment {
+            id: Uuid::new_v4(),
+            attachment_number: number,
+            description: format!("Exhibit {}", number),
+            page_count: pages,
+            file_size_bytes: bytes,
+            file_name: None,
+            content_type: None,
+            sha256: None,
+        };
+
+        let motion = DocketService::create_entry(case_id, DocketEntryType::Motion, "Motion".to_string(), None);
+        repo.save_entry(&motion).unwrap();
+        let mut motion = repo.find_entry_by_id(motion.id).unwrap().unwrap();
+        motion.attachments.push(attachment(1, 10, 2_000));
+        repo.save_entry(&motion).unwrap();
+        motion.attachments.push(attachment(2, 5, 500));
+        repo.save_entry(&motion).unwrap();
+
+        let mut order = DocketService::create_entry(case_id, DocketEntryType::Order, "Order".to_string(), None);
+        order.attachments.push(attachment(1, 1, 100));
+        repo.save_entry(&order).unwrap();
+        repo.save_entry(&DocketService::create_entry(Uuid::new_v4(), DocketEntryType::Motion, "Other".to_string(), None))
+            .unwrap();
+
+        assert_eq!(repo.find_entries_by_case(case_id).unwrap().len(), 2);
+        assert_eq!(
+            repo.case_attachment_size(case_id).unwrap(),
+            CaseAttachmentSize { case_id, attachment_count: 3, total_bytes: 2_600, total_pages: 16 }
+        );
+        assert_eq!(repo.case_attachment_size(Uuid::new_v4()).unwrap().attachment_count, 0);
+    }
+}
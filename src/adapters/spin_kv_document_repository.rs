@@ -2,21 +2,25 @@
 //!
 //! This module provides a Spin KV Store implementation of the DocumentRepository trait.
 
-use crate::adapters::store_utils::open_validated_store;
+use crate::adapters::store_utils::{open_validated_store, TenantStore};
+use crate::domain::common::DateRange;
+use crate::domain::docket::DocketAttachment;
+use crate::domain::document::DocumentIndexEntry;
 use crate::domain::order::{JudicialOrder, OrderTemplate, OrderType, OrderStatus};
-use crate::domain::opinion::{JudicialOpinion, OpinionDraft};
+use crate::domain::opinion::{CitationEdge, JudicialOpinion, OpinionDraft, RankedOpinion};
 use crate::error::{ApiError, ApiResult};
 use crate::ports::document_repository::{
-    DocumentRepository, OrderFilter, OpinionFilter, OrderStatistics,
-    OpinionStatistics, CitationStatistics, CaseCitation
+    AttachmentContentRepository, StoredAttachment, CitationCounterRepository, DocumentIndexRepository, DocumentRepository,
+    OrderFilter, OpinionFilter, OrderStatistics, OpinionStatistics, CitationStatistics, CaseCitation, SlipOpinionRepository
 };
 use chrono::{DateTime, Datelike, Utc};
-use spin_sdk::key_value::Store;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use uuid::Uuid;
 
 /// Spin KV implementation of DocumentRepository
 pub struct SpinKvDocumentRepository {
-    store: Store,
+    store: TenantStore,
 }
 
 impl SpinKvDocumentRepository {
@@ -36,6 +40,11 @@ impl SpinKvDocumentRepository {
     pub fn with_store(store_name: String) -> Self {
         let store = open_validated_store(&store_name)
             .expect(&format!("Failed to open store: {}", store_name));
+        Self::with_tenant_store(store)
+    }
+
+    /// Wrap an opened store
+    pub(crate) fn with_tenant_store(store: TenantStore) -> Self {
         Self { store }
     }
 
@@ -54,6 +63,116 @@ impl SpinKvDocumentRepository {
     fn draft_key(id: &str) -> String {
         format!("draft:{}", id)
     }
+
+    fn cited_by_key(id: &str) -> String {
+        format!("index:opinions:cited_by:{}", id)
+    }
+
+    fn indexed_document_key(id: &str) -> String {
+        format!("document-index:{}", id)
+    }
+
+    fn attachment_key(id: Uuid) -> String {
+        format!("attachment:{}", id)
+    }
+
+    fn attachment_content_key(id: Uuid) -> String {
+        format!("attachment-content:{}", id)
+    }
+
+    fn citation_counter_key(key: &str) -> String {
+        format!("citation-seq:{}", key)
+    }
+
+    fn slip_opinion_key(opinion_id: &str) -> String {
+        format!("slip-opinion:{}", opinion_id)
+    }
+}
+
+impl CitationCounterRepository for SpinKvDocumentRepository {
+    fn next_citation_counter_value(&self, key: &str) -> ApiResult<u64> {
+        let value = self.store.increment(Self::citation_counter_key(key), 1)
+            .map_err(|e| ApiError::StorageError(format!("Failed to advance citation counter: {}", e)))?;
+        u64::try_from(value)
+            .map_err(|_| ApiError::StorageError(format!("Citation counter {} is negative", key)))
+    }
+}
+
+impl SlipOpinionRepository for SpinKvDocumentRepository {
+    fn save_slip_opinion(&self, opinion_id: &str, pdf: &[u8]) -> ApiResult<()> {
+        self.store.set(Self::slip_opinion_key(opinion_id), pdf)
+            .map_err(|e| ApiError::StorageError(format!("Failed to store slip opinion: {}", e)))
+    }
+
+    fn find_slip_opinion(&self, opinion_id: &str) -> ApiResult<Option<Vec<u8>>> {
+        self.store.get(Self::slip_opinion_key(opinion_id))
+            .map_err(|e| ApiError::StorageError(format!("Failed to get slip opinion: {}", e)))
+    }
+}
+
+/// Attachment metadata, stored apart from the file bytes
+#[derive(Serialize, Deserialize)]
+struct AttachmentRecord {
+    entry_id: Uuid,
+    attachment: DocketAttachment,
+}
+
+impl AttachmentContentRepository for SpinKvDocumentRepository {
+    fn save_attachment_content(&self, content: &StoredAttachment) -> ApiResult<()> {
+        let id = content.attachment.id;
+        // Bytes first, so metadata never points at missing content
+        self.store.set(Self::attachment_content_key(id), &content.content)
+            .map_err(|e| ApiError::StorageError(format!("Failed to store attachment content: {}", e)))?;
+
+        let record = AttachmentRecord { entry_id: content.entry_id, attachment: content.attachment.clone() };
+        self.store.set_json(Self::attachment_key(id), &record)
+            .map_err(|e| ApiError::StorageError(format!("Failed to store attachment metadata: {}", e)))
+    }
+
+    fn find_attachment_content(&self, attachment_id: Uuid) -> ApiResult<Option<StoredAttachment>> {
+        let record: Option<AttachmentRecord> = self.store.get_json(Self::attachment_key(attachment_id))
+            .map_err(|e| ApiError::StorageError(format!("Failed to get attachment metadata: {}", e)))?;
+        let Some(record) = record else {
+            return Ok(None);
+        };
+
+        let content = self.store.get(Self::attachment_content_key(attachment_id))
+            .map_err(|e| ApiError::StorageError(format!("Failed to get attachment content: {}", e)))?
+            .ok_or_else(|| ApiError::StorageError(format!("Content of attachment {} is missing", attachment_id)))?;
+
+        Ok(Some(StoredAttachment { entry_id: record.entry_id, attachment: record.attachment, content }))
+    }
+}
+
+impl DocumentIndexRepository for SpinKvDocumentRepository {
+    fn index_document(&self, entry: &DocumentIndexEntry) -> ApiResult<()> {
+        self.store.set_json(Self::indexed_document_key(&entry.document_id), entry)
+            .map_err(|e| ApiError::Internal(format!("Failed to index document: {}", e)))?;
+
+        let mut document_ids: Vec<String> = self.store.get_json("index:documents")
+            .map_err(|e| ApiError::Internal(format!("Failed to get document index: {}", e)))?
+            .unwrap_or_default();
+        if !document_ids.contains(&entry.document_id) {
+            document_ids.push(entry.document_id.clone());
+            self.store.set_json("index:documents", &document_ids)
+                .map_err(|e| ApiError::Internal(format!("Failed to update document index: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    fn list_indexed_documents(&self) -> ApiResult<Vec<DocumentIndexEntry>> {
+        let document_ids: Vec<String> = self.store.get_json("index:documents")
+            .map_err(|e| ApiError::Internal(format!("Failed to get document index: {}", e)))?
+            .unwrap_or_default();
+
+        let mut entries = Vec::new();
+        for id in document_ids {
+            let entry: Option<DocumentIndexEntry> = self.store.get_json(Self::indexed_document_key(&id))
+                .map_err(|e| ApiError::Internal(format!("Failed to get indexed document: {}", e)))?;
+            entries.extend(entry);
+        }
+        Ok(entries)
+    }
 }
 
 
@@ -129,6 +248,13 @@ impl DocumentRepository for SpinKvDocumentRepository {
         Ok(orders.into_iter().skip(offset).take(limit).collect())
     }
 
+    fn count_orders(&self) -> ApiResult<usize> {
+        let order_ids: Vec<String> = self.store.get_json("index:orders")
+            .map_err(|e| ApiError::Internal(format!("Failed to get order index: {}", e)))?
+            .unwrap_or_default();
+        Ok(order_ids.len())
+    }
+
     fn find_orders_by_case(&self, case_id: &str) -> ApiResult<Vec<JudicialOrder>> {
         self.list_orders(OrderFilter {
             case_id: Some(case_id.to_string()),
@@ -241,6 +367,7 @@ impl DocumentRepository for SpinKvDocumentRepository {
         
         // Update indices
         self.update_opinion_indices(&opinion)?;
+        self.sync_cited_by_index(&opinion.id, &[], &opinion.citation_edges())?;
         
         Ok(opinion)
     }
@@ -252,23 +379,31 @@ impl DocumentRepository for SpinKvDocumentRepository {
     }
 
     fn update_opinion(&self, opinion: JudicialOpinion) -> ApiResult<JudicialOpinion> {        let key = Self::opinion_key(&opinion.id);
+        let previous_edges = self.get_opinion(&opinion.id)?
+            .map(|o| o.citation_edges())
+            .unwrap_or_default();
         
         self.store.set_json(&key, &opinion)
             .map_err(|e| ApiError::Internal(format!("Failed to update opinion: {}", e)))?;
         
         // Update indices
         self.update_opinion_indices(&opinion)?;
+        self.sync_cited_by_index(&opinion.id, &previous_edges, &opinion.citation_edges())?;
         
         Ok(opinion)
     }
 
     fn delete_opinion(&self, opinion_id: &str) -> ApiResult<()> {        let key = Self::opinion_key(opinion_id);
+        let previous_edges = self.get_opinion(opinion_id)?
+            .map(|o| o.citation_edges())
+            .unwrap_or_default();
         
         self.store.delete(&key)
             .map_err(|e| ApiError::Internal(format!("Failed to delete opinion: {}", e)))?;
         
         // Remove from indices
         self.remove_opinion_from_indices(opinion_id)?;
+        self.sync_cited_by_index(opinion_id, &previous_edges, &[])?;
         
         Ok(())
     }
@@ -310,6 +445,13 @@ impl DocumentRepository for SpinKvDocumentRepository {
         Ok(opinions.into_iter().skip(offset).take(limit).collect())
     }
 
+    fn count_opinions(&self) -> ApiResult<usize> {
+        let opinion_ids: Vec<String> = self.store.get_json("index:opinions")
+            .map_err(|e| ApiError::Internal(format!("Failed to get opinion index: {}", e)))?
+            .unwrap_or_default();
+        Ok(opinion_ids.len())
+    }
+
     fn find_opinions_by_case(&self, case_id: &str) -> ApiResult<Vec<JudicialOpinion>> {
         self.list_opinions(OpinionFilter {
             case_id: Some(case_id.to_string()),
@@ -341,6 +483,24 @@ impl DocumentRepository for SpinKvDocumentRepository {
         })
     }
 
+    fn search_opinions_ranked(&self, query: &str, limit: usize, min_score: u32) -> ApiResult<Vec<RankedOpinion>> {
+        let min_score = min_score.max(1);
+        // Score every opinion, not just the most recent page
+        let all = OpinionFilter { limit: Some(usize::MAX), ..Default::default() };
+        let mut results: Vec<RankedOpinion> = self.list_opinions(all)?
+            .into_iter()
+            .map(|opinion| RankedOpinion { score: opinion.relevance_score(query), opinion })
+            .filter(|ranked| ranked.score >= min_score)
+            .collect();
+
+        // Ties go to the most recently updated opinion
+        results.sort_by(|a, b| {
+            b.score.cmp(&a.score).then_with(|| b.opinion.updated_at.cmp(&a.opinion.updated_at))
+        });
+        results.truncate(limit);
+        Ok(results)
+    }
+
     fn find_precedential_opinions(&self) -> ApiResult<Vec<JudicialOpinion>> {
         self.list_opinions(OpinionFilter {
             is_published: Some(true),
@@ -349,6 +509,12 @@ impl DocumentRepository for SpinKvDocumentRepository {
         })
     }
 
+    fn find_citing_opinions(&self, opinion_id: &str) -> ApiResult<Vec<CitationEdge>> {
+        self.store.get_json(Self::cited_by_key(opinion_id))
+            .map_err(|e| ApiError::Internal(format!("Failed to get cited-by index: {}", e)))
+            .map(Option::unwrap_or_default)
+    }
+
     // Draft operations
     fn create_draft(&self, draft: OpinionDraft) -> ApiResult<OpinionDraft> {        let key = Self::draft_key(&draft.id);
         
@@ -419,13 +585,16 @@ impl DocumentRepository for SpinKvDocumentRepository {
     }
 
     // Statistics
-    fn get_order_statistics(&self, judge_id: Option<&str>) -> ApiResult<OrderStatistics> {
+    fn get_order_statistics(&self, judge_id: Option<&str>, range: DateRange) -> ApiResult<OrderStatistics> {
         let filter = OrderFilter {
             judge_id: judge_id.map(|j| j.to_string()),
             ..Default::default()
         };
         
-        let orders = self.list_orders(filter)?;
+        let orders: Vec<JudicialOrder> = self.list_orders(filter)?
+            .into_iter()
+            .filter(|o| range.contains(o.created_at))
+            .collect();
         let total = orders.len();
         
         let mut by_type: HashMap<String, usize> = HashMap::new();
@@ -474,6 +643,7 @@ impl DocumentRepository for SpinKvDocumentRepository {
         }
         
         Ok(OrderStatistics {
+            range,
             total_orders: total,
             orders_by_type: by_type.into_iter().collect(),
             orders_by_status: by_status.into_iter().collect(),
@@ -487,13 +657,16 @@ impl DocumentRepository for SpinKvDocumentRepository {
         })
     }
 
-    fn get_opinion_statistics(&self, judge_id: Option<&str>) -> ApiResult<OpinionStatistics> {
+    fn get_opinion_statistics(&self, judge_id: Option<&str>, range: DateRange) -> ApiResult<OpinionStatistics> {
         let filter = OpinionFilter {
             author_judge_id: judge_id.map(|j| j.to_string()),
             ..Default::default()
         };
         
-        let opinions = self.list_opinions(filter)?;
+        let opinions: Vec<JudicialOpinion> = self.list_opinions(filter)?
+            .into_iter()
+            .filter(|o| range.contains(o.created_at))
+            .collect();
         let total = opinions.len();
         
         let mut by_type: HashMap<String, usize> = HashMap::new();
@@ -547,6 +720,7 @@ impl DocumentRepository for SpinKvDocumentRepository {
         let avg_citations = if total > 0 { total_citations as f64 / total as f64 } else { 0.0 };
         
         Ok(OpinionStatistics {
+            range,
             total_opinions: total,
             opinions_by_type: by_type.into_iter().collect(),
             opinions_by_disposition: by_disposition.into_iter().collect(),
@@ -681,4 +855,56 @@ impl SpinKvDocumentRepository {
         
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Replace the edges contributed by `citing_id` in the cited opinions' reverse indices
+    fn sync_cited_by_index(&self, citing_id: &str, previous: &[CitationEdge], current: &[CitationEdge]) -> ApiResult<()> {
+        let mut targets: Vec<&str> = previous.iter().chain(current.iter())
+            .map(|e| e.cited_opinion_id.as_str())
+            .collect();
+        targets.sort_unstable();
+        targets.dedup();
+
+        for target in targets {
+            let index_key = Self::cited_by_key(target);
+            let mut entries = self.find_citing_opinions(target)?;
+            entries.retain(|e| e.citing_opinion_id != citing_id);
+            entries.extend(current.iter().filter(|e| e.cited_opinion_id == target).cloned());
+
+            self.store.set_json(&index_key, &entries)
+                .map_err(|e| ApiError::Internal(format!("Failed to update cited-by index: {}", e)))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::store_utils::testing::MemoryBackend;
+    use crate::services::opinion_publication_service::NeutralCitationGenerator;
+    use std::collections::HashSet;
+    use std::thread;
+
+    #[test]
+    fn test_concurrent_publications_get_distinct_citations() {
+        let backend = MemoryBackend::default();
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let repo = SpinKvDocumentRepository::with_tenant_store(TenantStore::with_backend("sdny", backend.clone()));
+                thread::spawn(move || {
+                    let generator = NeutralCitationGenerator::new(&repo);
+                    (0..25).map(|_| generator.next("SDNY", 2024).unwrap()).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut seen = HashSet::new();
+        for handle in handles {
+            for citation in handle.join().unwrap() {
+                assert!(seen.insert(citation.clone()), "citation {} handed out twice", citation);
+            }
+        }
+        assert_eq!(seen.len(), 200);
+    }
+}
@@ -3,18 +3,21 @@
 //! This adapter implements the JudgeRepository traits using Spin's
 //! built-in key-value store for persistence.
 
-use crate::adapters::store_utils::open_validated_store;
+use crate::adapters::store_utils::{open_validated_store, TenantStore};
+use crate::domain::judge_unavailability::UnavailabilityType;
 use crate::domain::judge::{
-    Judge, CaseAssignment, RecusalMotion, JudgeStatus,
+    Judge, CaseAssignment, CaseType, RecusalMotion, JudgeStatus,
     ConflictOfInterest, RecusalStatus
 };
 use crate::ports::judge_repository::{
     JudgeRepository, CaseAssignmentRepository, RecusalRepository,
-    ConflictRepository, JudgeQuery, JudgeQueryRepository, WorkloadStatistics
+    ConflictRepository, JudgeQuery, JudgeQueryRepository, WorkloadStatistics,
+    CaseTypeWorkload
 };
+use crate::utils::name_match::{self, NameMatch};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use spin_sdk::key_value::Store;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 const JUDGE_KEY_PREFIX: &str = "judge-";
@@ -22,10 +25,11 @@ const ASSIGNMENT_KEY_PREFIX: &str = "assignment-";
 const RECUSAL_KEY_PREFIX: &str = "recusal-";
 const CONFLICT_KEY_PREFIX: &str = "conflict-";
 const INDEX_KEY_PREFIX: &str = "idx-";
+const ROTATION_KEY_PREFIX: &str = "rotation-";
 
 /// Spin KV implementation of the JudgeRepository
 pub struct SpinKvJudgeRepository {
-    store: Store,
+    store: TenantStore,
 }
 
 impl SpinKvJudgeRepository {
@@ -33,9 +37,21 @@ impl SpinKvJudgeRepository {
     pub fn with_store(store_name: String) -> Self {
         let store = open_validated_store(&store_name)
             .expect(&format!("Failed to open store: {}", store_name));
+        Self::with_tenant_store(store)
+    }
+
+    /// Wrap an opened store
+    pub(crate) fn with_tenant_store(store: TenantStore) -> Self {
         Self { store }
     }
 
+    /// Run `f` against this repository, committing all its writes together
+    ///
+    /// See [`TenantStore::transaction`].
+    pub fn transaction<T, E: From<anyhow::Error>>(&self, f: impl FnOnce(&Self) -> Result<T, E>) -> Result<T, E> {
+        self.store.transaction(|| f(self))
+    }
+
     fn build_judge_key(id: Uuid) -> String {
         format!("{}{}", JUDGE_KEY_PREFIX, id)
     }
@@ -59,6 +75,54 @@ impl SpinKvJudgeRepository {
     fn build_judge_assignment_index_key(judge_id: Uuid) -> String {
         format!("{}judge-assignments-{}", INDEX_KEY_PREFIX, judge_id)
     }
+
+    /// Statistics over `judges`, counting `caseload(judge)` cases each
+    ///
+    /// Overload and spare capacity always use a judge's whole caseload,
+    /// since their capacity is shared across case types.
+    fn workload_statistics<'a>(
+        judges: impl Iterator<Item = &'a Judge>,
+        caseload: impl Fn(&Judge) -> u32,
+    ) -> WorkloadStatistics {
+        let judges: Vec<&Judge> = judges.collect();
+
+        let active_judges = judges.iter().filter(|j| j.status == JudgeStatus::Active).count();
+        let senior_judges = judges.iter().filter(|j| j.status == JudgeStatus::Senior).count();
+
+        let total_cases: u32 = judges.iter().map(|j| caseload(j)).sum();
+        let average_caseload = if !judges.is_empty() {
+            total_cases as f32 / judges.len() as f32
+        } else {
+            0.0
+        };
+
+        let overloaded_judges = judges.iter().filter(|j| {
+            (j.current_caseload as f32 / j.max_caseload as f32) > 0.9
+        }).count();
+
+        let available_capacity: u32 = judges
+            .iter()
+            .map(|j| j.max_caseload.saturating_sub(j.current_caseload))
+            .sum();
+
+        WorkloadStatistics {
+            total_judges: judges.len(),
+            active_judges,
+            senior_judges,
+            average_caseload,
+            total_cases: total_cases as usize,
+            overloaded_judges,
+            available_capacity: available_capacity as usize,
+        }
+    }
+
+    fn build_rotation_key(case_type: &CaseType) -> String {
+        let panel = match case_type {
+            CaseType::Criminal => "criminal",
+            CaseType::Civil => "civil",
+        };
+        format!("{}{}", ROTATION_KEY_PREFIX, panel)
+    }
 }
 
 impl JudgeRepository for SpinKvJudgeRepository {
@@ -83,6 +147,10 @@ impl JudgeRepository for SpinKvJudgeRepository {
         Ok(judges)
     }
 
+    fn count(&self) -> Result<usize> {
+        Ok(self.store.count_records(JUDGE_KEY_PREFIX)?)
+    }
+
     fn find_judges_by_status(&self, status: JudgeStatus) -> Result<Vec<Judge>> {
         let judges = self.find_all_judges()?;
         Ok(judges.into_iter().filter(|j| j.status == status).collect())
@@ -122,13 +190,16 @@ impl CaseAssignmentRepository for SpinKvJudgeRepository {
         let key = Self::build_assignment_key(assignment.id);
         self.store.set_json(&key, assignment)?;
 
-        // Update case index
+        // Update case index, unless a replacement assignment already holds it
         let case_index_key = Self::build_case_assignment_index_key(assignment.case_id);
-        self.store.set(&case_index_key, assignment.id.to_string().as_bytes())?;
+        if assignment.superseded_by.is_none() || self.store.get(&case_index_key)?.is_none() {
+            self.store.set(&case_index_key, assignment.id.to_string().as_bytes())?;
+        }
 
-        // Update judge assignments index
+        // Update judge assignments index, replacing an earlier copy
         let judge_index_key = Self::build_judge_assignment_index_key(assignment.judge_id);
         let mut assignments = self.find_assignments_by_judge(assignment.judge_id)?;
+        assignments.retain(|a| a.id != assignment.id);
         assignments.push(assignment.clone());
         self.store.set_json(&judge_index_key, &assignments)?;
 
@@ -179,6 +250,21 @@ impl CaseAssignmentRepository for SpinKvJudgeRepository {
             Ok(false)
         }
     }
+
+    fn get_rotation_cursor(&self, case_type: &CaseType) -> Result<Option<Uuid>> {
+        let key = Self::build_rotation_key(case_type);
+
+        match self.store.get(&key)? {
+            Some(id_bytes) => Ok(Some(Uuid::parse_str(&String::from_utf8(id_bytes)?)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn set_rotation_cursor(&self, case_type: &CaseType, judge_id: Uuid) -> Result<()> {
+        let key = Self::build_rotation_key(case_type);
+        self.store.set(&key, judge_id.to_string().as_bytes())?;
+        Ok(())
+    }
 }
 
 impl RecusalRepository for SpinKvJudgeRepository {
@@ -254,15 +340,19 @@ impl ConflictRepository for SpinKvJudgeRepository {
         }
     }
 
-    fn find_conflicts_by_party(&self, party_name: &str) -> Result<Vec<(Uuid, ConflictOfInterest)>> {
+    fn find_conflicts_by_party(
+        &self,
+        party_name: &str,
+        threshold: f64,
+    ) -> Result<Vec<(Uuid, ConflictOfInterest, NameMatch)>> {
         let judges = self.find_all_judges()?;
         let mut conflicts = Vec::new();
 
         for judge in judges {
             for conflict in &judge.conflicts_of_interest {
                 if let Some(ref name) = conflict.party_name {
-                    if name.to_lowercase().contains(&party_name.to_lowercase()) {
-                        conflicts.push((judge.id, conflict.clone()));
+                    if let Some(found) = name_match::match_name(party_name, name, threshold) {
+                        conflicts.push((judge.id, conflict.clone(), found));
                     }
                 }
             }
@@ -329,6 +419,14 @@ impl JudgeQueryRepository for SpinKvJudgeRepository {
             });
         }
 
+        if let Some(search_text) = query.search_text {
+            let search_lower = search_text.to_lowercase();
+            judges.retain(|j| {
+                j.name.to_lowercase().contains(&search_lower) ||
+                j.courtroom.to_lowercase().contains(&search_lower)
+            });
+        }
+
         // Sort by caseload (ascending)
         judges.sort_by_key(|j| j.current_caseload);
 
@@ -347,35 +445,32 @@ impl JudgeQueryRepository for SpinKvJudgeRepository {
 
     fn get_workload_statistics(&self) -> Result<WorkloadStatistics> {
         let judges = self.find_all_judges()?;
+        Ok(Self::workload_statistics(judges.iter(), |j| j.current_caseload))
+    }
 
-        let active_judges = judges.iter().filter(|j| j.status == JudgeStatus::Active).count();
-        let senior_judges = judges.iter().filter(|j| j.status == JudgeStatus::Senior).count();
-
-        let total_cases: u32 = judges.iter().map(|j| j.current_caseload).sum();
-        let average_caseload = if !judges.is_empty() {
-            total_cases as f32 / judges.len() as f32
-        } else {
-            0.0
-        };
-
-        let overloaded_judges = judges.iter().filter(|j| {
-            (j.current_caseload as f32 / j.max_caseload as f32) > 0.9
-        }).count();
+    fn workload_by_type(&self) -> Result<Vec<CaseTypeWorkload>> {
+        let judges = self.find_all_judges()?;
 
-        let available_capacity: u32 = judges
-            .iter()
-            .map(|j| j.max_caseload.saturating_sub(j.current_caseload))
-            .sum();
+        // Cases each judge currently holds, by the type they were assigned under
+        let mut held: HashMap<(Uuid, CaseType), u32> = HashMap::new();
+        for judge in &judges {
+            for assignment in self.find_assignments_by_judge(judge.id)? {
+                if let (None, Some(case_type)) = (assignment.superseded_date, assignment.case_type) {
+                    *held.entry((judge.id, case_type)).or_default() += 1;
+                }
+            }
+        }
 
-        Ok(WorkloadStatistics {
-            total_judges: judges.len(),
-            active_judges,
-            senior_judges,
-            average_caseload,
-            total_cases: total_cases as usize,
-            overloaded_judges,
-            available_capacity: available_capacity as usize,
-        })
+        Ok([CaseType::Criminal, CaseType::Civil]
+            .into_iter()
+            .map(|case_type| CaseTypeWorkload {
+                statistics: Self::workload_statistics(
+                    judges.iter().filter(|j| j.accepts_case_type(&case_type)),
+                    |j| held.get(&(j.id, case_type)).copied().unwrap_or(0),
+                ),
+                case_type,
+            })
+            .collect())
     }
 
     fn find_judges_on_vacation(&self, start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> Result<Vec<Judge>> {
@@ -384,7 +479,143 @@ impl JudgeQueryRepository for SpinKvJudgeRepository {
         Ok(judges.into_iter().filter(|j| {
             j.availability.vacation_dates.iter().any(|vacation| {
                 vacation.start <= end_date && vacation.end >= start_date
+            }) || j.availability.unavailability.iter().any(|entry| {
+                entry.unavailability_type == UnavailabilityType::Vacation
+                    && entry.overlaps(start_date.date_naive(), end_date.date_naive())
             })
         }).collect())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::store_utils::testing::{FailingBackend, MemoryBackend};
+    use crate::adapters::store_utils::KvBackend;
+    use crate::adapters::unit_of_work::recovery_delay;
+    use crate::domain::judge::{
+        AssignmentType, JudgeAssignmentService, JudgeTitle, RecusalReason,
+    };
+    use crate::error::ApiResult;
+
+    fn repo(backend: impl KvBackend + 'static) -> SpinKvJudgeRepository {
+        SpinKvJudgeRepository::with_tenant_store(TenantStore::with_backend("sdny", backend))
+    }
+
+    fn judge(name: &str) -> Judge {
+        Judge::new(name.to_string(), JudgeTitle::DistrictJudge, "SDNY".to_string(), "4A".to_string())
+    }
+
+    /// A case assigned to `recused`, with a pending motion against them
+    fn seed(repo: &SpinKvJudgeRepository, recused: &mut Judge, replacement: &Judge) -> RecusalMotion {
+        let case_id = Uuid::new_v4();
+        recused.assign_case().unwrap();
+        repo.save_judge(recused).unwrap();
+        repo.save_judge(replacement).unwrap();
+        repo.save_assignment(&JudgeAssignmentService::create_assignment(
+            case_id,
+            recused.id,
+            AssignmentType::Random,
+            "Random draw".to_string(),
+            None,
+            Some(CaseType::Civil),
+        ))
+        .unwrap();
+
+        let motion = RecusalMotion {
+            id: Uuid::new_v4(),
+            case_id,
+            judge_id: recused.id,
+            filed_by: "Defense".to_string(),
+            filed_date: Utc::now(),
+            reason: RecusalReason::ConflictOfInterest,
+            detailed_grounds: "Prior representation of a party".to_string(),
+            status: RecusalStatus::Pending,
+            ruling_date: None,
+            replacement_judge_id: None,
+            reassignment_status: None,
+        };
+        repo.save_recusal(&motion).unwrap();
+        motion
+    }
+
+    /// Record a case of `case_type` assigned to `judge`, or once assigned and since reassigned
+    fn hold(repo: &SpinKvJudgeRepository, judge: &mut Judge, case_type: CaseType, superseded: bool) {
+        let mut assignment = JudgeAssignmentService::create_assignment(
+            Uuid::new_v4(),
+            judge.id,
+            AssignmentType::Random,
+            "Random draw".to_string(),
+            None,
+            Some(case_type),
+        );
+        if superseded {
+            assignment.superseded_date = Some(Utc::now());
+        } else {
+            judge.assign_case().unwrap();
+        }
+        repo.save_assignment(&assignment).unwrap();
+        repo.save_judge(judge).unwrap();
+    }
+
+    #[test]
+    fn test_workload_by_type_counts_cases_held_of_each_type() {
+        let repo = repo(MemoryBackend::default());
+        let mut both = judge("Judge Both");
+        let mut civil_only = judge("Judge Civil");
+        civil_only.availability.accepts_criminal_cases = false;
+
+        hold(&repo, &mut both, CaseType::Criminal, false);
+        hold(&repo, &mut both, CaseType::Criminal, true);
+        hold(&repo, &mut both, CaseType::Civil, false);
+        for _ in 0..3 {
+            hold(&repo, &mut civil_only, CaseType::Civil, false);
+        }
+
+        let buckets = repo.workload_by_type().unwrap();
+        let criminal = &buckets[0].statistics;
+        assert_eq!(buckets[0].case_type, CaseType::Criminal);
+        assert_eq!((criminal.total_judges, criminal.total_cases), (1, 1));
+        assert_eq!(criminal.average_caseload, 1.0);
+
+        let civil = &buckets[1].statistics;
+        assert_eq!((civil.total_judges, civil.total_cases), (2, 4));
+        assert_eq!(civil.average_caseload, 2.0);
+    }
+
+    #[test]
+    fn test_recusal_ruling_and_reassignment_survive_or_vanish_together() {
+        let mut outcomes = Vec::new();
+        for n in 0..10 {
+            let backend = MemoryBackend::default();
+            let (mut recused, replacement) = (judge("Judge Recused"), judge("Judge Replacement"));
+            let mut motion = seed(&repo(backend.clone()), &mut recused, &replacement);
+
+            let crashed = repo(FailingBackend::crash_at(backend.clone(), n));
+            let _ = crashed.transaction(|repo| -> ApiResult<()> {
+                motion.status = RecusalStatus::Granted;
+                JudgeAssignmentService::execute_reassignment(repo, &mut motion, Some(replacement.id), "Recusal granted")?;
+                repo.save_recusal(&motion)?;
+                Ok(())
+            });
+
+            let recovered = repo(backend.clone());
+            recovered.store.recover_journals_at(Utc::now() + recovery_delay()).unwrap();
+
+            let ruled = recovered.find_recusal_by_id(motion.id).unwrap().unwrap().status == RecusalStatus::Granted;
+            let assigned_to = recovered.find_assignment_by_case(motion.case_id).unwrap().unwrap().judge_id;
+            let caseloads = (
+                recovered.find_judge_by_id(recused.id).unwrap().unwrap().current_caseload,
+                recovered.find_judge_by_id(replacement.id).unwrap().unwrap().current_caseload,
+            );
+            if ruled {
+                assert_eq!((assigned_to, caseloads), (replacement.id, (0, 1)), "crash at write {}", n);
+            } else {
+                assert_eq!((assigned_to, caseloads), (recused.id, (1, 0)), "crash at write {}", n);
+            }
+            assert!(backend.journal_keys().is_empty(), "crash at write {}", n);
+            outcomes.push(ruled);
+        }
+        assert!(!outcomes[0], "a crash before the journal lands writes nothing");
+        assert!(outcomes[9], "enough writes for the whole commit");
+    }
+}
@@ -3,18 +3,17 @@
 //! This adapter implements the RulesRepository traits using Spin's
 //! built-in key-value store for persistence.
 
-use crate::adapters::store_utils::open_validated_store;
+use crate::adapters::store_utils::{open_validated_store, TenantStore};
 use crate::domain::rule::{Rule, RuleCategory, RuleStatus, TriggerEvent};
 use crate::ports::rules_repository::{RulesRepository, RuleQuery, RuleQueryRepository};
 use anyhow::Result;
-use spin_sdk::key_value::Store;
 use uuid::Uuid;
 
 const RULE_KEY_PREFIX: &str = "rule-";
 
 /// Spin KV implementation of the RulesRepository
 pub struct SpinKvRulesRepository {
-    store: Store,
+    store: TenantStore,
 }
 
 impl SpinKvRulesRepository {
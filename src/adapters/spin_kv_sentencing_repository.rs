@@ -1,14 +1,15 @@
 //! Spin KV implementation of sentencing repository
 
-use crate::adapters::store_utils::open_validated_store;
+use crate::adapters::store_utils::{open_validated_store, TenantStore};
+use crate::domain::common::DateRange;
 use crate::domain::sentencing::*;
 use crate::ports::sentencing_repository::SentencingRepository;
 use crate::{ApiError, ApiResult};
 use chrono::{Utc, NaiveDate};
-use spin_sdk::key_value::{Error as KvError, Store};
+use spin_sdk::key_value::Error as KvError;
 
 pub struct SpinKvSentencingRepository {
-    store: Store,
+    store: TenantStore,
 }
 
 impl SpinKvSentencingRepository {
@@ -39,6 +40,15 @@ impl SpinKvSentencingRepository {
         Ok(sentencings)
     }
 
+    /// Sentencings dated within the range; records not yet sentenced are
+    /// dated by when they were opened
+    fn sentencings_in(&self, range: DateRange) -> ApiResult<Vec<Sentencing>> {
+        Ok(self.get_all_sentencings()?
+            .into_iter()
+            .filter(|s| range.contains(s.sentencing_date.unwrap_or(s.created_at)))
+            .collect())
+    }
+
     fn save_sentencing(&self, sentencing: &Sentencing) -> ApiResult<()> {
         let key = format!("sentencing:{}", sentencing.id);
         let value = serde_json::to_vec(&sentencing)
@@ -95,6 +105,17 @@ impl SentencingRepository for SpinKvSentencingRepository {
         Ok(())
     }
 
+    fn find_all_sentencings(&self) -> ApiResult<Vec<Sentencing>> {
+        self.get_all_sentencings()
+    }
+
+    fn count(&self) -> ApiResult<usize> {
+        let keys = self.store
+            .get_keys()
+            .map_err(|e| ApiError::StorageError(format!("Failed to get keys: {:?}", e)))?;
+        Ok(keys.iter().filter(|key| key.starts_with("sentencing:") && !key.contains("::")).count())
+    }
+
     fn find_by_case(&self, case_id: &str) -> ApiResult<Vec<Sentencing>> {
         let sentencings = self.get_all_sentencings()?;
         Ok(sentencings.into_iter()
@@ -256,76 +277,22 @@ impl SentencingRepository for SpinKvSentencingRepository {
         })
     }
 
-    fn get_departure_rates(&self) -> ApiResult<SentencingStatistics> {
-        let sentencings = self.get_all_sentencings()?;
-        let total = sentencings.len() as i32;
-
-        let with_departures = sentencings.iter()
-            .filter(|s| !s.departures.is_empty())
-            .count() as i32;
-
-        let upward = sentencings.iter()
-            .filter(|s| s.departures.iter()
-                .any(|d| matches!(d.direction, DepartureDirection::Upward)))
-            .count() as i32;
-
-        let downward = sentencings.iter()
-            .filter(|s| s.departures.iter()
-                .any(|d| matches!(d.direction, DepartureDirection::Downward)))
-            .count() as i32;
-
-        let substantial_assistance = sentencings.iter()
-            .filter(|s| s.substantial_assistance.as_ref()
-                .map(|sa| sa.departure_granted).unwrap_or(false))
-            .count() as i32;
-
-        Ok(SentencingStatistics {
-            total_cases: total,
-            within_guidelines: total - with_departures,
-            upward_departures: upward,
-            downward_departures: downward,
-            upward_variances: 0,
-            downward_variances: 0,
-            government_sponsored_below: substantial_assistance,
-            substantial_assistance,
-            average_sentence_months: 0.0,
-            median_sentence_months: 0.0,
-            trial_penalty_percentage: 0.0,
-        })
+    fn get_departure_rates(&self, range: DateRange) -> ApiResult<SentencingStatistics> {
+        Ok(departure_statistics(&self.sentencings_in(range)?, range))
     }
 
-    fn get_variance_rates(&self) -> ApiResult<SentencingStatistics> {
-        let sentencings = self.get_all_sentencings()?;
-        let total = sentencings.len() as i32;
-
-        let with_variance = sentencings.iter()
-            .filter(|s| s.variance.is_some())
-            .count() as i32;
-
-        let upward = sentencings.iter()
-            .filter(|s| s.variance.as_ref()
-                .map(|v| matches!(v.direction, VarianceDirection::Above))
-                .unwrap_or(false))
-            .count() as i32;
+    fn get_variance_rates(&self, range: DateRange) -> ApiResult<SentencingStatistics> {
+        Ok(variance_statistics(&self.sentencings_in(range)?, range))
+    }
 
-        let downward = sentencings.iter()
-            .filter(|s| s.variance.as_ref()
-                .map(|v| matches!(v.direction, VarianceDirection::Below))
-                .unwrap_or(false))
-            .count() as i32;
+    fn get_adjustment_stats(&self, range: DateRange) -> ApiResult<AdjustmentStatistics> {
+        let sentencings = self.sentencings_in(range)?;
 
-        Ok(SentencingStatistics {
-            total_cases: total,
-            within_guidelines: total - with_variance,
-            upward_departures: 0,
-            downward_departures: 0,
-            upward_variances: upward,
-            downward_variances: downward,
-            government_sponsored_below: 0,
-            substantial_assistance: 0,
-            average_sentence_months: 0.0,
-            median_sentence_months: 0.0,
-            trial_penalty_percentage: 0.0,
+        Ok(AdjustmentStatistics {
+            range,
+            departures: departure_statistics(&sentencings, range),
+            variances: variance_statistics(&sentencings, range),
+            net_effect: adjustment_net_effect(&sentencings),
         })
     }
 
@@ -410,8 +377,8 @@ impl SentencingRepository for SpinKvSentencingRepository {
             .collect())
     }
 
-    fn get_judge_sentencing_stats(&self, judge_id: &str) -> ApiResult<SentencingStatistics> {
-        let sentencings = self.get_all_sentencings()?;
+    fn get_judge_sentencing_stats(&self, judge_id: &str, range: DateRange) -> ApiResult<SentencingStatistics> {
+        let sentencings = self.sentencings_in(range)?;
         let judge_sentencings: Vec<_> = sentencings.into_iter()
             .filter(|s| s.judge_id == judge_id)
             .collect();
@@ -437,6 +404,7 @@ impl SentencingRepository for SpinKvSentencingRepository {
         };
 
         Ok(SentencingStatistics {
+            range,
             total_cases: total,
             within_guidelines: judge_sentencings.iter()
                 .filter(|s| s.departures.is_empty() && s.variance.is_none())
@@ -469,11 +437,27 @@ impl SentencingRepository for SpinKvSentencingRepository {
             average_sentence_months: if total > 0 { total_months / total as f64 } else { 0.0 },
             median_sentence_months: median,
             trial_penalty_percentage: 0.0, // Would need trial vs plea data
+            guideline_compliance: GuidelineCompliance::of(&judge_sentencings),
+            by_offense_type: GuidelineCompliance::by_offense_type(&judge_sentencings),
         })
     }
 
-    fn get_district_stats(&self) -> ApiResult<SentencingStatistics> {
-        let sentencings = self.get_all_sentencings()?;
+    fn get_judge_comparison(&self, judge_id: &str, range: DateRange) -> ApiResult<JudgeSentencingComparison> {
+        let sentencings = self.sentencings_in(range)?;
+        let judge = GuidelineCompliance::of(sentencings.iter().filter(|s| s.judge_id == judge_id));
+        let district = GuidelineCompliance::of(&sentencings);
+
+        Ok(JudgeSentencingComparison {
+            range,
+            judge_id: judge_id.to_string(),
+            deltas: ComplianceDeltas::between(&judge, &district),
+            judge,
+            district,
+        })
+    }
+
+    fn get_district_stats(&self, range: DateRange) -> ApiResult<SentencingStatistics> {
+        let sentencings = self.sentencings_in(range)?;
         let total = sentencings.len() as i32;
 
         let mut total_months = 0.0;
@@ -484,6 +468,7 @@ impl SentencingRepository for SpinKvSentencingRepository {
         }
 
         Ok(SentencingStatistics {
+            range,
             total_cases: total,
             within_guidelines: sentencings.iter()
                 .filter(|s| s.departures.is_empty() && s.variance.is_none())
@@ -516,22 +501,26 @@ impl SentencingRepository for SpinKvSentencingRepository {
             average_sentence_months: if total > 0 { total_months / total as f64 } else { 0.0 },
             median_sentence_months: 0.0,
             trial_penalty_percentage: 0.0,
+            guideline_compliance: GuidelineCompliance::of(&sentencings),
+            by_offense_type: GuidelineCompliance::by_offense_type(&sentencings),
         })
     }
 
-    fn get_offense_type_stats(&self, offense_type: &str) -> ApiResult<SentencingStatistics> {
+    fn get_offense_type_stats(&self, offense_type: &str, range: DateRange) -> ApiResult<SentencingStatistics> {
         // Filter by offense type in statute
-        let sentencings = self.get_all_sentencings()?;
+        let sentencings = self.sentencings_in(range)?;
         let filtered: Vec<_> = sentencings.into_iter()
             .filter(|s| {
-                // Check if any adjustment mentions the offense type
-                s.offense_level.specific_offense_characteristics.iter()
-                    .any(|c| c.guideline_section.contains(offense_type))
+                s.primary_offense_type.as_deref() == Some(offense_type)
+                    // Otherwise check if any adjustment mentions the offense type
+                    || s.offense_level.specific_offense_characteristics.iter()
+                        .any(|c| c.guideline_section.contains(offense_type))
             })
             .collect();
 
         let total = filtered.len() as i32;
         Ok(SentencingStatistics {
+            range,
             total_cases: total,
             within_guidelines: filtered.iter()
                 .filter(|s| s.departures.is_empty() && s.variance.is_none())
@@ -545,13 +534,15 @@ impl SentencingRepository for SpinKvSentencingRepository {
             average_sentence_months: 0.0,
             median_sentence_months: 0.0,
             trial_penalty_percentage: 0.0,
+            guideline_compliance: GuidelineCompliance::of(&filtered),
+            by_offense_type: GuidelineCompliance::by_offense_type(&filtered),
         })
     }
 
-    fn get_trial_penalty_analysis(&self) -> ApiResult<SentencingStatistics> {
+    fn get_trial_penalty_analysis(&self, range: DateRange) -> ApiResult<SentencingStatistics> {
         // Would need to compare sentences after trial vs guilty pleas
         // This is a simplified version
-        let sentencings = self.get_all_sentencings()?;
+        let sentencings = self.sentencings_in(range)?;
 
         let with_trial: Vec<_> = sentencings.iter()
             .filter(|s| s.offense_level.acceptance_of_responsibility == 0)
@@ -586,6 +577,7 @@ impl SentencingRepository for SpinKvSentencingRepository {
         };
 
         Ok(SentencingStatistics {
+            range,
             total_cases: sentencings.len() as i32,
             within_guidelines: 0,
             upward_departures: 0,
@@ -597,6 +589,8 @@ impl SentencingRepository for SpinKvSentencingRepository {
             average_sentence_months: 0.0,
             median_sentence_months: 0.0,
             trial_penalty_percentage: penalty_percentage,
+            guideline_compliance: GuidelineCompliance::of(&sentencings),
+            by_offense_type: GuidelineCompliance::by_offense_type(&sentencings),
         })
     }
 
@@ -618,15 +612,11 @@ impl SentencingRepository for SpinKvSentencingRepository {
         Ok(sentencing)
     }
 
-    fn calculate_criminal_history_points(&self, sentencing_id: &str) -> ApiResult<i32> {
+    fn calculate_criminal_history_points(&self, sentencing_id: &str) -> ApiResult<CriminalHistoryPointsBreakdown> {
         let sentencing = self.get_sentencing(sentencing_id)?
             .ok_or_else(|| ApiError::NotFound("Sentencing not found".to_string()))?;
 
-        let total_points = sentencing.criminal_history.points
-            + sentencing.criminal_history.status_points
-            + sentencing.criminal_history.recency_points;
-
-        Ok(total_points)
+        Ok(sentencing.criminal_history_points_breakdown())
     }
 
     fn find_upcoming_sentencings(&self, days: i32) -> ApiResult<Vec<Sentencing>> {
@@ -660,4 +650,122 @@ impl SentencingRepository for SpinKvSentencingRepository {
             })
             .collect())
     }
-}
\ No newline at end of file
+}
+
+fn departure_statistics(sentencings: &[Sentencing], range: DateRange) -> SentencingStatistics {
+    let total = sentencings.len() as i32;
+
+    let with_departures = sentencings.iter()
+        .filter(|s| !s.departures.is_empty())
+        .count() as i32;
+
+    let upward = sentencings.iter()
+        .filter(|s| s.departures.iter()
+            .any(|d| matches!(d.direction, DepartureDirection::Upward)))
+        .count() as i32;
+
+    let downward = sentencings.iter()
+        .filter(|s| s.departures.iter()
+            .any(|d| matches!(d.direction, DepartureDirection::Downward)))
+        .count() as i32;
+
+    let substantial_assistance = sentencings.iter()
+        .filter(|s| s.substantial_assistance.as_ref()
+            .map(|sa| sa.departure_granted).unwrap_or(false))
+        .count() as i32;
+
+    SentencingStatistics {
+        range,
+        total_cases: total,
+        within_guidelines: total - with_departures,
+        upward_departures: upward,
+        downward_departures: downward,
+        upward_variances: 0,
+        downward_variances: 0,
+        government_sponsored_below: substantial_assistance,
+        substantial_assistance,
+        average_sentence_months: 0.0,
+        median_sentence_months: 0.0,
+        trial_penalty_percentage: 0.0,
+        guideline_compliance: GuidelineCompliance::of(sentencings),
+        by_offense_type: GuidelineCompliance::by_offense_type(sentencings),
+    }
+}
+
+fn variance_statistics(sentencings: &[Sentencing], range: DateRange) -> SentencingStatistics {
+    let total = sentencings.len() as i32;
+
+    let with_variance = sentencings.iter()
+        .filter(|s| s.variance.is_some())
+        .count() as i32;
+
+    let upward = sentencings.iter()
+        .filter(|s| s.variance.as_ref()
+            .map(|v| matches!(v.direction, VarianceDirection::Above))
+            .unwrap_or(false))
+        .count() as i32;
+
+    let downward = sentencings.iter()
+        .filter(|s| s.variance.as_ref()
+            .map(|v| matches!(v.direction, VarianceDirection::Below))
+            .unwrap_or(false))
+        .count() as i32;
+
+    SentencingStatistics {
+        range,
+        total_cases: total,
+        within_guidelines: total - with_variance,
+        upward_departures: 0,
+        downward_departures: 0,
+        upward_variances: upward,
+        downward_variances: downward,
+        government_sponsored_below: 0,
+        substantial_assistance: 0,
+        average_sentence_months: 0.0,
+        median_sentence_months: 0.0,
+        trial_penalty_percentage: 0.0,
+        guideline_compliance: GuidelineCompliance::of(sentencings),
+        by_offense_type: GuidelineCompliance::by_offense_type(sentencings),
+    }
+}
+
+fn adjustment_net_effect(sentencings: &[Sentencing]) -> AdjustmentNetEffect {
+    let net_departure_levels = sentencings.iter()
+        .flat_map(|s| &s.departures)
+        .map(|d| match d.direction {
+            DepartureDirection::Upward => d.levels.abs(),
+            DepartureDirection::Downward => -d.levels.abs(),
+        })
+        .sum();
+
+    let net_variance_months = sentencings.iter()
+        .filter_map(|s| s.variance.as_ref())
+        .map(|v| v.to_months - v.from_months)
+        .sum();
+
+    let outside_range: Vec<i32> = sentencings.iter()
+        .filter_map(|s| s.imposed_sentence.as_ref().map(|imposed| {
+            let range = &s.guidelines_range;
+            if imposed.custody_months < range.minimum_months {
+                imposed.custody_months - range.minimum_months
+            } else if imposed.custody_months > range.maximum_months {
+                imposed.custody_months - range.maximum_months
+            } else {
+                0
+            }
+        }))
+        .collect();
+    let net_months_from_range: i32 = outside_range.iter().sum();
+
+    AdjustmentNetEffect {
+        net_departure_levels,
+        net_variance_months,
+        imposed_sentences: outside_range.len() as i32,
+        net_months_from_range,
+        average_months_from_range: if outside_range.is_empty() {
+            0.0
+        } else {
+            net_months_from_range as f64 / outside_range.len() as f64
+        },
+    }
+}
@@ -0,0 +1,178 @@
+//! Spin KV Store implementation of the webhook repository
+//!
+//! Subscriptions live under `webhook:{id}` and queued notifications under
+//! `webhook-pending:{id}`, each with an ID index. Delivery history is kept
+//! per subscription under `webhook-deliveries:{subscription_id}`, trimmed to
+//! the most recent attempts. The district's own webhook is a single record
+//! under `district-webhook`.
+
+use crate::adapters::store_utils::{open_validated_store, TenantStore};
+use crate::domain::webhook::{DeliveryAttempt, DistrictWebhook, PendingDelivery, WebhookSubscription};
+use crate::error::{ApiError, ApiResult};
+use crate::ports::webhook_repository::WebhookRepository;
+use uuid::Uuid;
+
+const SUBSCRIPTION_INDEX_KEY: &str = "index:webhooks";
+const PENDING_INDEX_KEY: &str = "index:webhook-pending";
+const DISTRICT_WEBHOOK_KEY: &str = "district-webhook";
+
+/// Delivery attempts kept per subscription
+const MAX_ATTEMPTS_KEPT: usize = 100;
+
+pub struct SpinKvWebhookRepository {
+    store: TenantStore,
+}
+
+impl SpinKvWebhookRepository {
+    /// Create repository with specific store name for multi-tenancy
+    pub fn with_store(store_name: String) -> Self {
+        let store = open_validated_store(&store_name)
+            .unwrap_or_else(|e| panic!("Failed to open store {}: {}", store_name, e));
+        Self { store }
+    }
+
+    fn subscription_key(id: Uuid) -> String {
+        format!("webhook:{}", id)
+    }
+
+    fn pending_key(id: Uuid) -> String {
+        format!("webhook-pending:{}", id)
+    }
+
+    fn attempts_key(subscription_id: Uuid) -> String {
+        format!("webhook-deliveries:{}", subscription_id)
+    }
+
+    fn get_index(&self, key: &str) -> ApiResult<Vec<Uuid>> {
+        self.store.get_json(key)
+            .map_err(|e| ApiError::Internal(format!("Failed to get {}: {}", key, e)))
+            .map(Option::unwrap_or_default)
+    }
+
+    fn set_index(&self, key: &str, ids: &[Uuid]) -> ApiResult<()> {
+        self.store.set_json(key, &ids)
+            .map_err(|e| ApiError::Internal(format!("Failed to update {}: {}", key, e)))
+    }
+
+    fn add_to_index(&self, key: &str, id: Uuid) -> ApiResult<()> {
+        let mut ids = self.get_index(key)?;
+        if !ids.contains(&id) {
+            ids.push(id);
+            self.set_index(key, &ids)?;
+        }
+        Ok(())
+    }
+
+    fn remove_from_index(&self, key: &str, id: Uuid) -> ApiResult<()> {
+        let mut ids = self.get_index(key)?;
+        let before = ids.len();
+        ids.retain(|existing| *existing != id);
+        if ids.len() != before {
+            self.set_index(key, &ids)?;
+        }
+        Ok(())
+    }
+}
+
+impl WebhookRepository for SpinKvWebhookRepository {
+    fn save_subscription(&self, subscription: &WebhookSubscription) -> ApiResult<()> {
+        self.store.set_json(Self::subscription_key(subscription.id), subscription)
+            .map_err(|e| ApiError::Internal(format!("Failed to store webhook: {}", e)))?;
+        self.add_to_index(SUBSCRIPTION_INDEX_KEY, subscription.id)
+    }
+
+    fn find_subscription(&self, id: Uuid) -> ApiResult<Option<WebhookSubscription>> {
+        self.store.get_json(Self::subscription_key(id))
+            .map_err(|e| ApiError::Internal(format!("Failed to get webhook: {}", e)))
+    }
+
+    fn list_subscriptions(&self) -> ApiResult<Vec<WebhookSubscription>> {
+        let mut subscriptions = Vec::new();
+        for id in self.get_index(SUBSCRIPTION_INDEX_KEY)? {
+            subscriptions.extend(self.find_subscription(id)?);
+        }
+        Ok(subscriptions)
+    }
+
+    fn delete_subscription(&self, id: Uuid) -> ApiResult<bool> {
+        let key = Self::subscription_key(id);
+        let exists = self.store.exists(&key)
+            .map_err(|e| ApiError::Internal(format!("Failed to check webhook: {}", e)))?;
+        if !exists {
+            return Ok(false);
+        }
+
+        self.store.delete(&key)
+            .map_err(|e| ApiError::Internal(format!("Failed to delete webhook: {}", e)))?;
+        self.remove_from_index(SUBSCRIPTION_INDEX_KEY, id)?;
+
+        // Drop anything still queued for the subscription
+        for delivery in self.pending_deliveries()? {
+            if delivery.subscription_id == Some(id) {
+                self.remove_delivery(delivery.id)?;
+            }
+        }
+        Ok(true)
+    }
+
+    fn enqueue_delivery(&self, delivery: &PendingDelivery) -> ApiResult<()> {
+        self.update_delivery(delivery)?;
+        self.add_to_index(PENDING_INDEX_KEY, delivery.id)
+    }
+
+    fn update_delivery(&self, delivery: &PendingDelivery) -> ApiResult<()> {
+        self.store.set_json(Self::pending_key(delivery.id), delivery)
+            .map_err(|e| ApiError::Internal(format!("Failed to store webhook delivery: {}", e)))
+    }
+
+    fn remove_delivery(&self, id: Uuid) -> ApiResult<()> {
+        self.store.delete(Self::pending_key(id))
+            .map_err(|e| ApiError::Internal(format!("Failed to delete webhook delivery: {}", e)))?;
+        self.remove_from_index(PENDING_INDEX_KEY, id)
+    }
+
+    fn pending_deliveries(&self) -> ApiResult<Vec<PendingDelivery>> {
+        let mut deliveries = Vec::new();
+        for id in self.get_index(PENDING_INDEX_KEY)? {
+            let delivery: Option<PendingDelivery> = self.store.get_json(Self::pending_key(id))
+                .map_err(|e| ApiError::Internal(format!("Failed to get webhook delivery: {}", e)))?;
+            deliveries.extend(delivery);
+        }
+        Ok(deliveries)
+    }
+
+    fn record_attempt(&self, attempt: &DeliveryAttempt) -> ApiResult<()> {
+        let key = Self::attempts_key(attempt.subscription_id);
+        let mut attempts: Vec<DeliveryAttempt> = self.store.get_json(&key)
+            .map_err(|e| ApiError::Internal(format!("Failed to get webhook deliveries: {}", e)))?
+            .unwrap_or_default();
+
+        attempts.insert(0, attempt.clone());
+        attempts.truncate(MAX_ATTEMPTS_KEPT);
+
+        self.store.set_json(&key, &attempts)
+            .map_err(|e| ApiError::Internal(format!("Failed to record webhook delivery: {}", e)))
+    }
+
+    fn recent_attempts(&self, subscription_id: Uuid, limit: usize) -> ApiResult<Vec<DeliveryAttempt>> {
+        let attempts: Vec<DeliveryAttempt> = self.store.get_json(Self::attempts_key(subscription_id))
+            .map_err(|e| ApiError::Internal(format!("Failed to get webhook deliveries: {}", e)))?
+            .unwrap_or_default();
+        Ok(attempts.into_iter().take(limit).collect())
+    }
+
+    fn get_district_webhook(&self) -> ApiResult<Option<DistrictWebhook>> {
+        self.store.get_json(DISTRICT_WEBHOOK_KEY)
+            .map_err(|e| ApiError::Internal(format!("Failed to get district webhook: {}", e)))
+    }
+
+    fn save_district_webhook(&self, webhook: &DistrictWebhook) -> ApiResult<()> {
+        self.store.set_json(DISTRICT_WEBHOOK_KEY, webhook)
+            .map_err(|e| ApiError::Internal(format!("Failed to store district webhook: {}", e)))
+    }
+
+    fn delete_district_webhook(&self) -> ApiResult<()> {
+        self.store.delete(DISTRICT_WEBHOOK_KEY)
+            .map_err(|e| ApiError::Internal(format!("Failed to delete district webhook: {}", e)))
+    }
+}
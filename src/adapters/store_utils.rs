@@ -2,33 +2,377 @@
 //!
 //! This module provides shared functionality for working with
 //! Spin's key-value stores, particularly for multi-tenant scenarios.
+//!
+//! Every tenant already has its own store, but as a second line of defense
+//! adapters never touch a [`Store`] directly: [`open_validated_store`] hands
+//! out a [`TenantStore`] that prefixes every key with the tenant ID, so a
+//! store opened under the wrong name still can't read another tenant's keys.
+//! Keys written before the prefix existed are moved under it the first time
+//! the store is opened (see [`TenantStore::migrate_legacy_keys`]).
+
+use crate::adapters::unit_of_work::{self, UnitOfWork, JOURNAL_KEY_PREFIX};
+use serde::{de::DeserializeOwned, Serialize};
+use spin_sdk::key_value::{Error as KvError, Store};
+use spin_sdk::wit::wasi::keyvalue as wasi_kv;
+use anyhow::{Result, anyhow, Context};
+use chrono::{DateTime, Utc};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use uuid::Uuid;
+
+/// Separator between the tenant ID and the key
+const TENANT_KEY_SEPARATOR: char = ':';
+
+/// Key prefixes the adapters wrote before keys carried the tenant ID
+///
+/// Frozen: keys introduced since are always written prefixed. Raw keys that
+/// other code keeps in the same store, such as the feature flags and tenant
+/// registry in `default`, match none of these and are left where they are.
+const LEGACY_KEY_PREFIXES: &[&str] = &[
+    "archive:", "assignment-", "attachment-content:", "attachment:", "attorney:", "calendar-", "case-",
+    "cja_rotation:", "config:", "conflict-", "conflict:", "deadline-", "deadline_rule-", "deadline_rule:",
+    "docket-", "document-index:", "draft:", "extension-", "idx-", "idx:", "index:", "judge-", "opinion:",
+    "order:", "party:", "recusal-", "reminder-", "representation:", "rotation-", "rule-", "seed:",
+    "sentencing:", "service:", "signature_", "speedy-", "template:", "webhook-deliveries:",
+    "webhook-pending:", "webhook:",
+];
+
+/// Set, under the tenant prefix, once a store's legacy keys have been moved
+const LEGACY_KEYS_MIGRATED_KEY: &str = "migrations:tenant-key-prefix";
+
+/// Key under which a tenant's value is stored
+pub fn scoped_key(tenant_id: &str, key: &str) -> String {
+    format!("{}{}{}", tenant_id, TENANT_KEY_SEPARATOR, key)
+}
+
+/// The tenant's own key for a stored key, or `None` if it belongs to another tenant
+pub fn unscoped_key<'a>(tenant_id: &str, stored_key: &'a str) -> Option<&'a str> {
+    stored_key
+        .strip_prefix(tenant_id)
+        .and_then(|rest| rest.strip_prefix(TENANT_KEY_SEPARATOR))
+}
+
+/// Whether a stored key was written by an adapter before keys were prefixed
+fn is_legacy_key(tenant_id: &str, stored_key: &str) -> bool {
+    unscoped_key(tenant_id, stored_key).is_none()
+        && LEGACY_KEY_PREFIXES.iter().any(|prefix| stored_key.starts_with(prefix))
+}
+
+/// Whether a key is `{prefix}{uuid}`, the key of a record rather than an index
+pub fn is_record_key(key: &str, prefix: &str) -> bool {
+    key.strip_prefix(prefix)
+        .is_some_and(|id| Uuid::parse_str(id).is_ok())
+}
+
+/// The raw key-value operations a [`TenantStore`] is built on
+///
+/// Implemented by Spin's [`Store`]; tests substitute an in-memory map.
+pub trait KvBackend: Send + Sync {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, KvError>;
+    fn set(&self, key: &str, value: &[u8]) -> Result<(), KvError>;
+    fn delete(&self, key: &str) -> Result<(), KvError>;
+    fn exists(&self, key: &str) -> Result<bool, KvError>;
+    fn get_keys(&self) -> Result<Vec<String>, KvError>;
+
+    /// Atomically add `delta` to the counter at `key`, which starts at 0,
+    /// and return its new value
+    fn increment(&self, key: &str, delta: i64) -> Result<i64, KvError>;
+}
+
+/// Spin's [`Store`] and the label it was opened under
+///
+/// Spin's key-value interface has no atomic operations, so [`increment`]
+/// opens the same store through `wasi:keyvalue/atomics`.
+///
+/// [`increment`]: KvBackend::increment
+struct SpinStore {
+    store: Store,
+    label: String,
+}
+
+impl KvBackend for SpinStore {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, KvError> {
+        self.store.get(key)
+    }
+
+    fn set(&self, key: &str, value: &[u8]) -> Result<(), KvError> {
+        self.store.set(key, value)
+    }
+
+    fn delete(&self, key: &str) -> Result<(), KvError> {
+        self.store.delete(key)
+    }
+
+    fn exists(&self, key: &str) -> Result<bool, KvError> {
+        self.store.exists(key)
+    }
+
+    fn get_keys(&self) -> Result<Vec<String>, KvError> {
+        self.store.get_keys()
+    }
+
+    fn increment(&self, key: &str, delta: i64) -> Result<i64, KvError> {
+        let bucket = wasi_kv::store::open(&self.label).map_err(wasi_kv_error)?;
+        wasi_kv::atomics::increment(&bucket, key, delta).map_err(wasi_kv_error)
+    }
+}
+
+fn wasi_kv_error(error: wasi_kv::store::Error) -> KvError {
+    match error {
+        wasi_kv::store::Error::NoSuchStore => KvError::NoSuchStore,
+        wasi_kv::store::Error::AccessDenied => KvError::AccessDenied,
+        wasi_kv::store::Error::Other(message) => KvError::Other(message),
+    }
+}
+
+/// A KV store whose keys are confined to one tenant
+///
+/// Mirrors the [`Store`] methods the adapters use. Keys passed in are
+/// prefixed with the tenant ID and keys listed come back without it; keys
+/// outside the tenant's prefix are never returned.
+///
+/// Inside [`transaction`](Self::transaction) writes are held back, and
+/// reads see them as if they had already landed.
+pub struct TenantStore {
+    store: Box<dyn KvBackend>,
+    tenant_id: String,
+    unit_of_work: Mutex<Option<UnitOfWork>>,
+}
+
+impl TenantStore {
+    #[cfg(test)]
+    pub(crate) fn with_backend(tenant_id: &str, backend: impl KvBackend + 'static) -> Self {
+        Self {
+            store: Box::new(backend),
+            tenant_id: tenant_id.to_string(),
+            unit_of_work: Mutex::new(None),
+        }
+    }
+
+    /// The open transaction's writes, if one is open
+    fn unit_of_work(&self) -> MutexGuard<'_, Option<UnitOfWork>> {
+        self.unit_of_work.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn get(&self, key: impl AsRef<str>) -> Result<Option<Vec<u8>>, KvError> {
+        let key = scoped_key(&self.tenant_id, key.as_ref());
+        if let Some(uow) = self.unit_of_work().as_ref() {
+            if let Some(pending) = uow.pending(&key) {
+                return Ok(pending.map(<[u8]>::to_vec));
+            }
+        }
+        self.store.get(&key)
+    }
+
+    pub fn set(&self, key: impl AsRef<str>, value: &[u8]) -> Result<(), KvError> {
+        let key = scoped_key(&self.tenant_id, key.as_ref());
+        match self.unit_of_work().as_mut() {
+            Some(uow) => {
+                uow.set(key, value.to_vec());
+                Ok(())
+            }
+            None => self.store.set(&key, value),
+        }
+    }
+
+    pub fn delete(&self, key: impl AsRef<str>) -> Result<(), KvError> {
+        let key = scoped_key(&self.tenant_id, key.as_ref());
+        match self.unit_of_work().as_mut() {
+            Some(uow) => {
+                uow.delete(key);
+                Ok(())
+            }
+            None => self.store.delete(&key),
+        }
+    }
+
+    pub fn exists(&self, key: impl AsRef<str>) -> Result<bool, KvError> {
+        let key = scoped_key(&self.tenant_id, key.as_ref());
+        if let Some(uow) = self.unit_of_work().as_ref() {
+            if let Some(pending) = uow.pending(&key) {
+                return Ok(pending.is_some());
+            }
+        }
+        self.store.exists(&key)
+    }
+
+    /// Atomically add `delta` to a counter and return its new value
+    ///
+    /// Counters start at 0. The increment lands at once, even inside a
+    /// [`transaction`](Self::transaction), so a value it hands out is never
+    /// handed out again; a transaction that fails leaves a gap instead.
+    pub fn increment(&self, key: impl AsRef<str>, delta: i64) -> Result<i64, KvError> {
+        self.store.increment(&scoped_key(&self.tenant_id, key.as_ref()), delta)
+    }
+
+    /// The tenant's keys, without the tenant prefix
+    pub fn get_keys(&self) -> Result<Vec<String>, KvError> {
+        let mut keys: Vec<String> = self
+            .store
+            .get_keys()?
+            .iter()
+            .filter_map(|key| unscoped_key(&self.tenant_id, key))
+            .map(str::to_string)
+            .collect();
+        if let Some(uow) = self.unit_of_work().as_ref() {
+            for (key, is_set) in uow.keys() {
+                let Some(key) = unscoped_key(&self.tenant_id, key) else { continue };
+                let listed = keys.iter().position(|k| k == key);
+                match (is_set, listed) {
+                    (true, None) => keys.push(key.to_string()),
+                    (false, Some(i)) => { keys.remove(i); }
+                    _ => {}
+                }
+            }
+        }
+        Ok(keys)
+    }
+
+    pub fn get_json<T: DeserializeOwned>(&self, key: impl AsRef<str>) -> Result<Option<T>> {
+        match self.get(key)? {
+            Some(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn set_json<T: Serialize>(&self, key: impl AsRef<str>, value: &T) -> Result<()> {
+        Ok(self.set(key, &serde_json::to_vec(value)?)?)
+    }
+
+    /// Run `f`, committing every write it makes together
+    ///
+    /// Writes are held back until `f` returns `Ok`, then committed through a
+    /// journal entry (see [`unit_of_work`]); if `f` fails they are dropped.
+    /// A transaction begun inside another joins the outer one.
+    pub fn transaction<T, E: From<anyhow::Error>>(&self, f: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
+        if self.unit_of_work().is_some() {
+            return f();
+        }
+
+        *self.unit_of_work() = Some(UnitOfWork::default());
+        let result = f();
+        let uow = self.unit_of_work().take().unwrap_or_default();
+        let value = result?;
+
+        let journal_key = scoped_key(&self.tenant_id, &format!("{}{}", JOURNAL_KEY_PREFIX, Uuid::new_v4()));
+        uow.commit(self.store.as_ref(), &journal_key, Utc::now())?;
+        Ok(value)
+    }
+
+    /// Resolve journal entries of commits that stopped partway
+    ///
+    /// Returns how many were resolved; entries too recent to be abandoned
+    /// are left for a later call.
+    pub fn recover_journals(&self) -> Result<usize> {
+        self.recover_journals_at(Utc::now())
+    }
+
+    pub(crate) fn recover_journals_at(&self, now: DateTime<Utc>) -> Result<usize> {
+        let mut recovered = 0;
+        for key in self.get_keys()? {
+            if key.starts_with(JOURNAL_KEY_PREFIX)
+                && unit_of_work::recover(self.store.as_ref(), &scoped_key(&self.tenant_id, &key), now)?
+            {
+                recovered += 1;
+            }
+        }
+        Ok(recovered)
+    }
 
-use spin_sdk::key_value::Store;
-use anyhow::{Result, anyhow};
+    /// Move keys written before keys were tenant-prefixed under the prefix
+    ///
+    /// Runs once per store: afterwards a marker key makes it a single read.
+    /// A legacy key whose prefixed form already exists was superseded by a
+    /// later write and is only deleted. Each key is copied before its legacy
+    /// form is deleted, so a run that stops partway is finished by the next
+    /// one. Returns how many keys were moved.
+    pub(crate) fn migrate_legacy_keys(&self) -> Result<usize, KvError> {
+        let marker = scoped_key(&self.tenant_id, LEGACY_KEYS_MIGRATED_KEY);
+        if self.store.exists(&marker)? {
+            return Ok(0);
+        }
+
+        let mut moved = 0;
+        for key in self.store.get_keys()? {
+            if !is_legacy_key(&self.tenant_id, &key) {
+                continue;
+            }
+            let scoped = scoped_key(&self.tenant_id, &key);
+            if !self.store.exists(&scoped)? {
+                if let Some(value) = self.store.get(&key)? {
+                    self.store.set(&scoped, &value)?;
+                    moved += 1;
+                }
+            }
+            self.store.delete(&key)?;
+        }
+        self.store.set(&marker, Utc::now().to_rfc3339().as_bytes())?;
+        Ok(moved)
+    }
+
+    /// Number of records stored as `{prefix}{uuid}`, without reading them
+    pub fn count_records(&self, prefix: &str) -> Result<usize, KvError> {
+        Ok(self.get_keys()?.iter().filter(|key| is_record_key(key, prefix)).count())
+    }
+
+    /// Approximate bytes the tenant occupies: its stored keys plus their values
+    ///
+    /// Reads every value, so it costs a full scan of the tenant. Overhead the
+    /// store itself adds per entry is not counted.
+    pub fn footprint_bytes(&self) -> Result<u64, KvError> {
+        let mut bytes = 0u64;
+        for key in self.store.get_keys()? {
+            if unscoped_key(&self.tenant_id, &key).is_none() {
+                continue;
+            }
+            let value_len = self.store.get(&key)?.map_or(0, |value| value.len());
+            bytes += (key.len() + value_len) as u64;
+        }
+        Ok(bytes)
+    }
+}
 
 /// Validates the store name and opens the store
 ///
 /// This function ensures that:
-/// 1. The store name is not empty
-/// 2. The store name is not the special "tenant_not_specified" value
-/// 3. The store can be successfully opened
-pub fn open_validated_store(store_name: &str) -> Result<Store> {
+/// 1. The store name is a known tenant, not empty, "tenant_not_specified"
+///    or "UNKNOWN_TENANT_xxx"
+/// 2. The store can be successfully opened
+///
+/// The store name is the validated tenant ID, and every key is prefixed
+/// with it; keys from before the prefix are migrated on the first open. A failure to open keeps the underlying [`KvError`], so callers
+/// can still `downcast_ref` it.
+pub fn open_validated_store(store_name: &str) -> Result<TenantStore> {
     // Check for invalid store names
     if store_name.is_empty() {
         return Err(anyhow!("Store name cannot be empty"));
     }
 
-    if store_name == "tenant_not_specified" || store_name == "TENANT_NOT_SPECIFIED" {
+    if !is_valid_store_name(store_name) {
         return Err(anyhow!(
             "Missing required header: X-Court-District or X-Tenant-ID. \
              Please specify a valid district identifier."
         ));
     }
 
+    if store_name.starts_with("UNKNOWN_TENANT_") {
+        return Err(anyhow!("Invalid district specified"));
+    }
+
     // Attempt to open the store
-    Store::open(store_name).map_err(|e| {
-        anyhow!("Failed to open store '{}': {}", store_name, e)
-    })
+    let store = Store::open(store_name)
+        .with_context(|| format!("Failed to open store '{}'", store_name))?;
+
+    let store = TenantStore {
+        store: Box::new(SpinStore { store, label: store_name.to_string() }),
+        tenant_id: store_name.to_string(),
+        unit_of_work: Mutex::new(None),
+    };
+    match store.migrate_legacy_keys() {
+        Ok(0) => {}
+        Ok(moved) => crate::request_log!("tenant store {}: moved {} legacy keys under the tenant prefix", store_name, moved),
+        Err(e) => crate::request_log!("tenant store {}: legacy key migration failed: {:?}", store_name, e),
+    }
+    Ok(store)
 }
 
 /// Checks if a store name is valid for opening
@@ -36,4 +380,266 @@ pub fn is_valid_store_name(store_name: &str) -> bool {
     !store_name.is_empty()
         && store_name != "tenant_not_specified"
         && store_name != "TENANT_NOT_SPECIFIED"
-}
\ No newline at end of file
+}
+
+/// In-memory backends for exercising adapters without a Spin host
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// A map shared between clones, so a test keeps a handle on what a
+    /// store it handed off has written
+    #[derive(Clone, Default)]
+    pub struct MemoryBackend(Arc<Mutex<BTreeMap<String, Vec<u8>>>>);
+
+    impl MemoryBackend {
+        fn map(&self) -> MutexGuard<'_, BTreeMap<String, Vec<u8>>> {
+            self.0.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        pub fn insert(&self, key: &str, value: &[u8]) {
+            self.map().insert(key.to_string(), value.to_vec());
+        }
+
+        pub fn value(&self, key: &str) -> Option<Vec<u8>> {
+            self.map().get(key).cloned()
+        }
+
+        /// Journal entries left in the store
+        pub fn journal_keys(&self) -> Vec<String> {
+            self.map().keys().filter(|key| key.contains(JOURNAL_KEY_PREFIX)).cloned().collect()
+        }
+    }
+
+    impl KvBackend for MemoryBackend {
+        fn get(&self, key: &str) -> Result<Option<Vec<u8>>, KvError> {
+            Ok(self.value(key))
+        }
+
+        fn set(&self, key: &str, value: &[u8]) -> Result<(), KvError> {
+            self.insert(key, value);
+            Ok(())
+        }
+
+        fn delete(&self, key: &str) -> Result<(), KvError> {
+            self.map().remove(key);
+            Ok(())
+        }
+
+        fn exists(&self, key: &str) -> Result<bool, KvError> {
+            Ok(self.map().contains_key(key))
+        }
+
+        fn get_keys(&self) -> Result<Vec<String>, KvError> {
+            Ok(self.map().keys().cloned().collect())
+        }
+
+        fn increment(&self, key: &str, delta: i64) -> Result<i64, KvError> {
+            let mut map = self.map();
+            let current = match map.get(key) {
+                Some(bytes) => i64::from_le_bytes(
+                    bytes.as_slice().try_into().map_err(|_| KvError::Other(format!("'{}' is not a counter", key)))?,
+                ),
+                None => 0,
+            };
+            let value = current + delta;
+            map.insert(key.to_string(), value.to_le_bytes().to_vec());
+            Ok(value)
+        }
+    }
+
+    /// Wraps a [`MemoryBackend`] and fails its Nth write (set or delete,
+    /// counted from 0), either once or from then on as if the process died
+    pub struct FailingBackend {
+        inner: MemoryBackend,
+        writes: AtomicUsize,
+        fail_at: usize,
+        recovers: bool,
+    }
+
+    impl FailingBackend {
+        /// Fails write `n` and every write after it
+        pub fn crash_at(inner: MemoryBackend, n: usize) -> Self {
+            Self { inner, writes: AtomicUsize::new(0), fail_at: n, recovers: false }
+        }
+
+        /// Fails write `n` only
+        pub fn fail_once_at(inner: MemoryBackend, n: usize) -> Self {
+            Self { inner, writes: AtomicUsize::new(0), fail_at: n, recovers: true }
+        }
+
+        fn write(&self) -> Result<(), KvError> {
+            let n = self.writes.fetch_add(1, Ordering::SeqCst);
+            if n == self.fail_at || (n > self.fail_at && !self.recovers) {
+                return Err(KvError::Other(format!("injected failure at write {}", n)));
+            }
+            Ok(())
+        }
+    }
+
+    impl KvBackend for FailingBackend {
+        fn get(&self, key: &str) -> Result<Option<Vec<u8>>, KvError> {
+            self.inner.get(key)
+        }
+
+        fn set(&self, key: &str, value: &[u8]) -> Result<(), KvError> {
+            self.write()?;
+            self.inner.set(key, value)
+        }
+
+        fn delete(&self, key: &str) -> Result<(), KvError> {
+            self.write()?;
+            self.inner.delete(key)
+        }
+
+        fn exists(&self, key: &str) -> Result<bool, KvError> {
+            self.inner.exists(key)
+        }
+
+        fn get_keys(&self) -> Result<Vec<String>, KvError> {
+            self.inner.get_keys()
+        }
+
+        fn increment(&self, key: &str, delta: i64) -> Result<i64, KvError> {
+            self.write()?;
+            self.inner.increment(key, delta)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::testing::{FailingBackend, MemoryBackend};
+
+    #[test]
+    fn test_keys_are_prefixed_with_tenant() {
+        assert_eq!(scoped_key("sdny", "case-123"), "sdny:case-123");
+        assert_eq!(unscoped_key("sdny", "sdny:case-123"), Some("case-123"));
+    }
+
+    #[test]
+    fn test_other_tenants_keys_are_not_visible() {
+        assert_eq!(unscoped_key("sdny", "edny:case-123"), None);
+        assert_eq!(unscoped_key("sdny", "case-123"), None, "unprefixed keys belong to no tenant");
+        // A tenant whose ID starts with another's must not see its keys
+        assert_eq!(unscoped_key("district1", "district12:case-123"), None);
+        assert_eq!(unscoped_key("district12", "district12:case-123"), Some("case-123"));
+    }
+
+    #[test]
+    fn test_record_keys_exclude_indexes() {
+        let id = Uuid::new_v4();
+        assert!(is_record_key(&format!("case-{}", id), "case-"));
+        assert!(!is_record_key("case-idx-case-num-1:24-cr-00001", "case-"));
+        assert!(!is_record_key(&format!("judge-{}", id), "case-"));
+    }
+
+    #[test]
+    fn test_key_with_separator_round_trips() {
+        let stored = scoped_key("district9", "document-index:abc");
+        assert_eq!(unscoped_key("district9", &stored), Some("document-index:abc"));
+    }
+
+    #[test]
+    fn test_legacy_keys_are_moved_under_the_tenant_prefix() {
+        let backend = MemoryBackend::default();
+        backend.insert("case-123", b"old case");
+        backend.insert("idx:bar:NY-1", b"attorney");
+        backend.insert("judge-7", b"stale judge");
+        backend.insert("sdny:judge-7", b"current judge");
+        backend.insert("features:manager", b"raw");
+        backend.insert("edny:case-9", b"other tenant");
+        let store = TenantStore::with_backend("sdny", backend.clone());
+
+        assert_eq!(store.migrate_legacy_keys().unwrap(), 2);
+        assert_eq!(store.get("case-123").unwrap(), Some(b"old case".to_vec()));
+        assert_eq!(store.get("idx:bar:NY-1").unwrap(), Some(b"attorney".to_vec()));
+        assert_eq!(store.get("judge-7").unwrap(), Some(b"current judge".to_vec()), "prefixed key wins");
+        assert_eq!(backend.value("case-123"), None);
+        assert_eq!(backend.value("judge-7"), None);
+        assert_eq!(backend.value("features:manager"), Some(b"raw".to_vec()), "raw keys stay put");
+        assert_eq!(backend.value("edny:case-9"), Some(b"other tenant".to_vec()));
+
+        backend.insert("case-456", b"written by an old build");
+        assert_eq!(store.migrate_legacy_keys().unwrap(), 0, "runs once per store");
+        assert_eq!(backend.value("case-456"), Some(b"written by an old build".to_vec()));
+    }
+
+    #[test]
+    fn test_interrupted_migration_finishes_on_next_run() {
+        let backend = MemoryBackend::default();
+        backend.insert("case-1", b"a");
+        backend.insert("case-2", b"b");
+        let crashed = TenantStore::with_backend("sdny", FailingBackend::crash_at(backend.clone(), 1));
+        assert!(crashed.migrate_legacy_keys().is_err());
+        assert_eq!(backend.value("sdny:case-1"), Some(b"a".to_vec()));
+        assert_eq!(backend.value("case-1"), Some(b"a".to_vec()), "stopped before the delete");
+
+        let store = TenantStore::with_backend("sdny", backend.clone());
+        assert_eq!(store.migrate_legacy_keys().unwrap(), 1);
+        assert_eq!(store.get_keys().unwrap().len(), 3, "two cases and the marker");
+        assert_eq!(backend.value("case-1"), None);
+        assert_eq!(store.get("case-2").unwrap(), Some(b"b".to_vec()));
+    }
+
+    #[test]
+    fn test_transaction_reads_its_own_writes() {
+        let backend = MemoryBackend::default();
+        backend.insert("sdny:gone", b"x");
+        let store = TenantStore::with_backend("sdny", backend.clone());
+
+        store
+            .transaction(|| -> Result<()> {
+                store.set("new", b"1")?;
+                store.delete("gone")?;
+                assert_eq!(store.get("new")?, Some(b"1".to_vec()));
+                assert!(!store.exists("gone")?);
+                assert_eq!(store.get_keys()?, vec!["new".to_string()]);
+                assert_eq!(backend.value("sdny:new"), None, "nothing lands before commit");
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(backend.value("sdny:new"), Some(b"1".to_vec()));
+        assert_eq!(backend.value("sdny:gone"), None);
+        assert!(backend.journal_keys().is_empty());
+    }
+
+    #[test]
+    fn test_failed_transaction_writes_nothing() {
+        let backend = MemoryBackend::default();
+        let store = TenantStore::with_backend("sdny", backend.clone());
+
+        let result = store.transaction(|| -> Result<()> {
+            store.set("new", b"1")?;
+            Err(anyhow!("validation failed"))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(store.get("new").unwrap(), None);
+        assert!(backend.journal_keys().is_empty());
+    }
+
+    #[test]
+    fn test_recover_journals_rolls_back_interrupted_commit() {
+        let backend = MemoryBackend::default();
+        let crashed = TenantStore::with_backend("sdny", FailingBackend::crash_at(backend.clone(), 2));
+        let _ = crashed.transaction(|| -> Result<()> {
+            crashed.set("a", b"1")?;
+            crashed.set("b", b"2")?;
+            Ok(())
+        });
+        assert_eq!(backend.value("sdny:a"), Some(b"1".to_vec()), "commit stopped after the first write");
+
+        let store = TenantStore::with_backend("sdny", backend.clone());
+        assert_eq!(store.recover_journals().unwrap(), 0, "too recent to be abandoned");
+        let later = Utc::now() + unit_of_work::recovery_delay();
+        assert_eq!(store.recover_journals_at(later).unwrap(), 1);
+        assert_eq!(backend.value("sdny:a"), None);
+        assert!(backend.journal_keys().is_empty());
+    }
+}
@@ -12,7 +12,7 @@ use crate::ports::feature_repository::{
 use async_trait::async_trait;
 use chrono::Utc;
 use serde_json::{json, Value};
-use spin_sdk::key_value::Store;
+use crate::adapters::store_utils::{open_validated_store, TenantStore};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -34,8 +34,8 @@ impl UnifiedConfigFeatureRepository {
     }
 
     /// Get the KV store for audit and analytics
-    fn get_store(&self) -> Result<Store, ApiError> {
-        Store::open(&self.store_name).map_err(|e| {
+    fn get_store(&self) -> Result<TenantStore, ApiError> {
+        open_validated_store(&self.store_name).map_err(|e| {
             ApiError::StorageError(format!("Failed to open store '{}': {}", self.store_name, e))
         })
     }
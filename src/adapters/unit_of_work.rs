@@ -0,0 +1,284 @@
+//! Journaled multi-key writes for the KV adapters
+//!
+//! Spin's key-value store has no transactions, so a change that spans
+//! several records (a calendar event and its minute entry, a recusal ruling
+//! and the reassignment it causes) is collected into a [`UnitOfWork`] and
+//! committed in three steps: the writes, with each key's value before them,
+//! are saved as a journal entry; the writes are applied; the journal entry
+//! is deleted.
+//!
+//! A journal entry left behind means a commit stopped partway. [`recover`]
+//! finishes it: a commit whose writes all landed only loses its journal,
+//! anything less is rolled back, since the request that made it already
+//! failed. Keys changed again since the commit are left alone. Finding the
+//! entries takes a scan of the tenant's keys, so recovery runs from
+//! `POST /api/admin/recover-journals` on a schedule rather than per request.
+
+use crate::adapters::store_utils::KvBackend;
+use anyhow::{anyhow, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Prefix of journal entry keys
+pub const JOURNAL_KEY_PREFIX: &str = "journal-";
+
+/// How old a journal entry must be before recovery touches it, so a commit
+/// still running in another request isn't rolled back under it
+pub fn recovery_delay() -> Duration {
+    Duration::seconds(60)
+}
+
+/// Writes held back to be committed together
+///
+/// Keys are the backend's own keys. Writing a key twice keeps only the
+/// later value, in the position of the first write.
+#[derive(Debug, Default)]
+pub struct UnitOfWork {
+    writes: Vec<(String, Option<Vec<u8>>)>,
+}
+
+/// A journal entry: every write of one commit with its key's prior value
+#[derive(Debug, Serialize, Deserialize)]
+struct Journal {
+    started_at: DateTime<Utc>,
+    writes: Vec<JournalWrite>,
+}
+
+/// Base64 values; `None` is an absent key
+#[derive(Debug, Serialize, Deserialize)]
+struct JournalWrite {
+    key: String,
+    before: Option<String>,
+    after: Option<String>,
+}
+
+impl JournalWrite {
+    fn before(&self) -> Result<Option<Vec<u8>>> {
+        decode(&self.before)
+    }
+
+    fn after(&self) -> Result<Option<Vec<u8>>> {
+        decode(&self.after)
+    }
+}
+
+impl UnitOfWork {
+    pub fn set(&mut self, key: String, value: Vec<u8>) {
+        self.record(key, Some(value));
+    }
+
+    pub fn delete(&mut self, key: String) {
+        self.record(key, None);
+    }
+
+    fn record(&mut self, key: String, value: Option<Vec<u8>>) {
+        match self.writes.iter_mut().find(|(k, _)| *k == key) {
+            Some(write) => write.1 = value,
+            None => self.writes.push((key, value)),
+        }
+    }
+
+    /// The value a key will have: `Some(None)` if it's deleted, `None` if
+    /// the unit of work doesn't touch it
+    pub fn pending(&self, key: &str) -> Option<Option<&[u8]>> {
+        self.writes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_deref())
+    }
+
+    /// Keys written, with whether each is set rather than deleted
+    pub fn keys(&self) -> impl Iterator<Item = (&str, bool)> {
+        self.writes.iter().map(|(key, value)| (key.as_str(), value.is_some()))
+    }
+
+    /// Apply the writes, journaled under `journal_key`
+    ///
+    /// On failure, writes already applied are undone before the error is
+    /// returned; if undoing them fails too, the journal entry stays for
+    /// [`recover`]. A journal entry that can't be deleted after every write
+    /// landed is logged and left for recovery to clear.
+    pub fn commit(self, backend: &dyn KvBackend, journal_key: &str, now: DateTime<Utc>) -> Result<()> {
+        if self.writes.is_empty() {
+            return Ok(());
+        }
+
+        let mut journal = Journal { started_at: now, writes: Vec::with_capacity(self.writes.len()) };
+        for (key, value) in &self.writes {
+            journal.writes.push(JournalWrite {
+                key: key.clone(),
+                before: backend.get(key)?.map(|v| STANDARD.encode(v)),
+                after: value.as_ref().map(|v| STANDARD.encode(v)),
+            });
+        }
+        backend
+            .set(journal_key, &serde_json::to_vec(&journal)?)
+            .context("Failed to write the journal entry")?;
+
+        for (applied, (key, value)) in self.writes.iter().enumerate() {
+            if let Err(e) = apply(backend, key, value.as_deref()) {
+                let undone = journal.writes[..applied]
+                    .iter()
+                    .rev()
+                    .all(|write| write.before().and_then(|before| apply(backend, &write.key, before.as_deref())).is_ok());
+                if undone {
+                    let _ = backend.delete(journal_key);
+                }
+                return Err(e.context(format!("Failed to write '{}'", key)));
+            }
+        }
+
+        if let Err(e) = backend.delete(journal_key) {
+            crate::request_log!("unit of work: journal entry {} not cleared: {:?}", journal_key, e);
+        }
+        Ok(())
+    }
+}
+
+/// Finish the commit journaled under `journal_key`, if it's old enough
+///
+/// Returns whether the journal entry was resolved and removed.
+pub fn recover(backend: &dyn KvBackend, journal_key: &str, now: DateTime<Utc>) -> Result<bool> {
+    let Some(bytes) = backend.get(journal_key)? else {
+        return Ok(false);
+    };
+    let journal: Journal = serde_json::from_slice(&bytes)
+        .with_context(|| format!("Unreadable journal entry '{}'", journal_key))?;
+    if now - journal.started_at < recovery_delay() {
+        return Ok(false);
+    }
+
+    let mut landed = Vec::with_capacity(journal.writes.len());
+    for write in &journal.writes {
+        landed.push(backend.get(&write.key)? == write.after()?);
+    }
+
+    if landed.iter().any(|landed| !landed) {
+        for (write, landed) in journal.writes.iter().zip(landed) {
+            if landed {
+                apply(backend, &write.key, write.before()?.as_deref())?;
+            }
+        }
+        crate::request_log!("unit of work: rolled back interrupted commit {}", journal_key);
+    }
+
+    backend.delete(journal_key)?;
+    Ok(true)
+}
+
+fn apply(backend: &dyn KvBackend, key: &str, value: Option<&[u8]>) -> Result<()> {
+    match value {
+        Some(value) => backend.set(key, value)?,
+        None => backend.delete(key)?,
+    }
+    Ok(())
+}
+
+fn decode(value: &Option<String>) -> Result<Option<Vec<u8>>> {
+    value
+        .as_ref()
+        .map(|v| STANDARD.decode(v).map_err(|e| anyhow!("Corrupt journal value: {}", e)))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::store_utils::testing::{FailingBackend, MemoryBackend};
+
+    fn unit_of_work() -> UnitOfWork {
+        let mut uow = UnitOfWork::default();
+        uow.set("a".to_string(), b"1".to_vec());
+        uow.set("b".to_string(), b"2".to_vec());
+        uow.delete("c".to_string());
+        uow
+    }
+
+    fn seeded() -> MemoryBackend {
+        let backend = MemoryBackend::default();
+        backend.insert("b", b"old");
+        backend.insert("c", b"doomed");
+        backend
+    }
+
+    /// Either every write landed or none did, and no journal is left
+    fn assert_all_or_nothing(backend: &MemoryBackend, context: &str) {
+        let state = (backend.value("a"), backend.value("b"), backend.value("c"));
+        let committed = (Some(b"1".to_vec()), Some(b"2".to_vec()), None);
+        let untouched = (None, Some(b"old".to_vec()), Some(b"doomed".to_vec()));
+        assert!(state == committed || state == untouched, "{}: partial state {:?}", context, state);
+        assert!(backend.journal_keys().is_empty(), "{}: journal left behind", context);
+    }
+
+    #[test]
+    fn test_later_write_to_a_key_replaces_earlier() {
+        let mut uow = unit_of_work();
+        uow.delete("a".to_string());
+        uow.set("c".to_string(), b"3".to_vec());
+        assert_eq!(uow.pending("a"), Some(None));
+        assert_eq!(uow.pending("c"), Some(Some(&b"3"[..])));
+        assert_eq!(uow.pending("z"), None);
+        assert_eq!(uow.keys().collect::<Vec<_>>(), vec![("a", false), ("b", true), ("c", true)]);
+    }
+
+    #[test]
+    fn test_commit_applies_every_write_and_clears_journal() {
+        let backend = seeded();
+        unit_of_work().commit(&backend, "journal-1", Utc::now()).unwrap();
+        assert_eq!(backend.value("a"), Some(b"1".to_vec()));
+        assert_eq!(backend.value("b"), Some(b"2".to_vec()));
+        assert_eq!(backend.value("c"), None);
+        assert!(backend.journal_keys().is_empty());
+    }
+
+    #[test]
+    fn test_failed_write_is_undone_before_returning() {
+        // journal, a, b, c, journal delete
+        for n in 0..5 {
+            let backend = seeded();
+            let failing = FailingBackend::fail_once_at(backend.clone(), n);
+            let result = unit_of_work().commit(&failing, "journal-1", Utc::now());
+            assert_eq!(result.is_err(), n < 4, "write {}", n);
+            if n < 4 {
+                assert_eq!(backend.value("b"), Some(b"old".to_vec()), "write {}", n);
+            }
+            recover(&backend, "journal-1", Utc::now() + recovery_delay()).unwrap();
+            assert_all_or_nothing(&backend, &format!("write {}", n));
+        }
+    }
+
+    #[test]
+    fn test_recovery_after_crash_is_all_or_nothing() {
+        for n in 0..5 {
+            let backend = seeded();
+            let crashed = FailingBackend::crash_at(backend.clone(), n);
+            let _ = unit_of_work().commit(&crashed, "journal-1", Utc::now());
+            recover(&backend, "journal-1", Utc::now() + recovery_delay()).unwrap();
+            assert_all_or_nothing(&backend, &format!("crash at write {}", n));
+        }
+    }
+
+    #[test]
+    fn test_recovery_waits_out_commits_in_progress() {
+        let backend = seeded();
+        let now = Utc::now();
+        let _ = unit_of_work().commit(&FailingBackend::crash_at(backend.clone(), 2), "journal-1", now);
+
+        assert!(!recover(&backend, "journal-1", now + Duration::seconds(5)).unwrap());
+        assert_eq!(backend.journal_keys().len(), 1);
+        assert!(recover(&backend, "journal-1", now + recovery_delay()).unwrap());
+        assert_all_or_nothing(&backend, "after delay");
+    }
+
+    #[test]
+    fn test_rollback_leaves_keys_changed_since_the_commit() {
+        let backend = seeded();
+        let _ = unit_of_work().commit(&FailingBackend::crash_at(backend.clone(), 3), "journal-1", Utc::now());
+        backend.insert("a", b"newer");
+
+        recover(&backend, "journal-1", Utc::now() + recovery_delay()).unwrap();
+        assert_eq!(backend.value("a"), Some(b"newer".to_vec()));
+        assert_eq!(backend.value("b"), Some(b"old".to_vec()));
+    }
+}
@@ -0,0 +1,256 @@
+//! Criminal appeals
+//!
+//! A notice of appeal from a judgment or appealable order is recorded on the
+//! case as an [`Appeal`]. Under FRAP 4(b)(1) a defendant has 14 days to file
+//! the notice and the government 30, each counted from the later of the
+//! judgment's entry and the other side's notice of appeal from it. A late
+//! notice is still recorded, flagged as untimely, since the district court
+//! may extend the time for excusable neglect (FRAP 4(b)(4)). The case has a
+//! pending appeal until the court of appeals' mandate issues.
+
+use super::deadline::DeadlineCalculator;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+/// Side filing the notice of appeal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AppealFilingParty {
+    Defendant,
+    Government,
+}
+
+impl AppealFilingParty {
+    /// Days after the judgment the side has to file its notice
+    pub fn notice_period_days(&self) -> i64 {
+        match self {
+            Self::Defendant => 14,
+            Self::Government => 30,
+        }
+    }
+
+    pub fn notice_rule(&self) -> &'static str {
+        match self {
+            Self::Defendant => "FRAP 4(b)(1)(A)",
+            Self::Government => "FRAP 4(b)(1)(B)",
+        }
+    }
+
+    /// Party name used on deadlines and docket entries
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Defendant => "Defendant",
+            Self::Government => "Government",
+        }
+    }
+
+    pub fn opposing(&self) -> Self {
+        match self {
+            Self::Defendant => Self::Government,
+            Self::Government => Self::Defendant,
+        }
+    }
+}
+
+/// Stage of an appeal in the court of appeals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AppealStatus {
+    /// Notice filed in the district court
+    Filed,
+    Docketed,
+    Briefed,
+    Decided,
+    /// The court of appeals' mandate has issued, ending the appeal
+    MandateIssued,
+}
+
+impl AppealStatus {
+    fn stage(&self) -> u8 {
+        match self {
+            Self::Filed => 0,
+            Self::Docketed => 1,
+            Self::Briefed => 2,
+            Self::Decided => 3,
+            Self::MandateIssued => 4,
+        }
+    }
+
+    /// Whether the appeal is still before the court of appeals
+    pub fn is_pending(&self) -> bool {
+        !matches!(self, Self::MandateIssued)
+    }
+}
+
+/// A status an appeal moved to, and when
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AppealStatusChange {
+    pub status: AppealStatus,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// A notice of appeal as filed, before it is recorded
+#[derive(Debug, Clone)]
+pub struct NoticeOfAppeal {
+    pub filing_party: AppealFilingParty,
+    /// Name of the filer, e.g. the defendant or the AUSA
+    pub filed_by: Option<String>,
+    pub filed_date: DateTime<Utc>,
+    /// Judgment or order appealed from, e.g. "Judgment (ECF No. 88)"
+    pub appealed_from: String,
+    pub judgment_entered_date: DateTime<Utc>,
+}
+
+/// An appeal recorded on a case
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Appeal {
+    pub id: Uuid,
+    pub case_id: Uuid,
+    pub filing_party: AppealFilingParty,
+    pub filed_by: Option<String>,
+    pub filed_date: DateTime<Utc>,
+    pub appealed_from: String,
+    pub judgment_entered_date: DateTime<Utc>,
+    /// Last day to file the notice under [`applicable_rule`](Self::applicable_rule)
+    pub notice_deadline: DateTime<Utc>,
+    pub applicable_rule: String,
+    /// Whether the notice was filed by its deadline
+    pub timely: bool,
+    pub status: AppealStatus,
+    /// Statuses after `filed`, oldest first
+    #[serde(default)]
+    pub status_history: Vec<AppealStatusChange>,
+    /// Docket entry recording the notice
+    pub docket_entry_id: Option<Uuid>,
+    /// Record and briefing deadlines created for the appeal
+    #[serde(default)]
+    pub deadline_ids: Vec<Uuid>,
+}
+
+impl Appeal {
+    /// Record a notice of appeal, computing its FRAP 4(b) deadline
+    ///
+    /// `opposing_notices` are the filing dates of the other side's notices
+    /// from the same judgment; the latest of them restarts the clock when it
+    /// falls after the judgment's entry. `closures` are district closure dates.
+    pub fn file(
+        case_id: Uuid,
+        notice: NoticeOfAppeal,
+        opposing_notices: impl IntoIterator<Item = DateTime<Utc>>,
+        closures: &[NaiveDate],
+    ) -> Self {
+        let party = notice.filing_party;
+        let trigger = opposing_notices
+            .into_iter()
+            .fold(notice.judgment_entered_date, DateTime::max);
+        let notice_deadline =
+            DeadlineCalculator::calculate_deadline(trigger, party.notice_period_days(), true, 0, closures);
+
+        Self {
+            id: Uuid::new_v4(),
+            case_id,
+            filing_party: party,
+            filed_by: notice.filed_by,
+            filed_date: notice.filed_date,
+            appealed_from: notice.appealed_from,
+            judgment_entered_date: notice.judgment_entered_date,
+            notice_deadline,
+            applicable_rule: party.notice_rule().to_string(),
+            // A deadline runs to the end of its day
+            timely: notice.filed_date.date_naive() <= notice_deadline.date_naive(),
+            status: AppealStatus::Filed,
+            status_history: Vec::new(),
+            docket_entry_id: None,
+            deadline_ids: Vec::new(),
+        }
+    }
+
+    /// Move the appeal to a later stage; stages may be skipped but not revisited
+    pub fn advance(&mut self, status: AppealStatus, now: DateTime<Utc>) -> Result<(), String> {
+        if status.stage() <= self.status.stage() {
+            return Err(format!(
+                "Appeal {} is {:?}; it can only move to a later status, not {:?}",
+                self.id, self.status, status
+            ));
+        }
+        self.status = status;
+        self.status_history.push(AppealStatusChange { status, changed_at: now });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn on(month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2099, month, day, 15, 0, 0).unwrap()
+    }
+
+    fn notice(filing_party: AppealFilingParty, filed_date: DateTime<Utc>) -> NoticeOfAppeal {
+        NoticeOfAppeal {
+            filing_party,
+            filed_by: None,
+            filed_date,
+            appealed_from: "Judgment (ECF No. 88)".to_string(),
+            judgment_entered_date: on(6, 1),
+        }
+    }
+
+    #[test]
+    fn test_defendant_and_government_periods() {
+        let defendant = Appeal::file(Uuid::new_v4(), notice(AppealFilingParty::Defendant, on(6, 15)), [], &[]);
+        assert_eq!(defendant.notice_deadline, on(6, 15));
+        assert_eq!(defendant.applicable_rule, "FRAP 4(b)(1)(A)");
+        assert!(defendant.timely);
+
+        let late = Appeal::file(Uuid::new_v4(), notice(AppealFilingParty::Defendant, on(6, 16)), [], &[]);
+        assert!(!late.timely);
+
+        let government = Appeal::file(Uuid::new_v4(), notice(AppealFilingParty::Government, on(6, 16)), [], &[]);
+        assert_eq!(government.notice_deadline, on(7, 1));
+        assert_eq!(government.applicable_rule, "FRAP 4(b)(1)(B)");
+        assert!(government.timely);
+    }
+
+    #[test]
+    fn test_opposing_notice_restarts_the_clock() {
+        let defendant = Appeal::file(
+            Uuid::new_v4(),
+            notice(AppealFilingParty::Defendant, on(6, 20)),
+            [on(6, 10), on(5, 1)],
+            &[],
+        );
+        assert_eq!(defendant.notice_deadline, on(6, 24));
+        assert!(defendant.timely);
+
+        // Day 14 is Saturday, July 4, so the deadline rolls to Monday
+        let rolled = Appeal::file(
+            Uuid::new_v4(),
+            notice(AppealFilingParty::Defendant, on(7, 6)),
+            [on(6, 20)],
+            &[],
+        );
+        assert_eq!(rolled.notice_deadline, on(7, 6));
+        assert!(rolled.timely);
+    }
+
+    #[test]
+    fn test_status_only_moves_forward() {
+        let mut appeal = Appeal::file(Uuid::new_v4(), notice(AppealFilingParty::Defendant, on(6, 2)), [], &[]);
+        assert!(appeal.status.is_pending());
+
+        appeal.advance(AppealStatus::Docketed, on(6, 5)).unwrap();
+        appeal.advance(AppealStatus::Decided, on(9, 1)).unwrap();
+        assert!(appeal.advance(AppealStatus::Briefed, on(9, 2)).is_err());
+        assert!(appeal.advance(AppealStatus::Decided, on(9, 2)).is_err());
+
+        appeal.advance(AppealStatus::MandateIssued, on(10, 1)).unwrap();
+        assert!(!appeal.status.is_pending());
+        let statuses: Vec<AppealStatus> = appeal.status_history.iter().map(|c| c.status).collect();
+        assert_eq!(statuses, [AppealStatus::Docketed, AppealStatus::Decided, AppealStatus::MandateIssued]);
+    }
+}
@@ -3,8 +3,8 @@
 //! This module handles attorney registration, CJA panel management,
 //! pro hac vice admissions, and party representation.
 
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -106,8 +106,89 @@ pub struct ProHacViceAdmission {
     pub expiration_date: Option<DateTime<Utc>>,
     pub local_counsel: String,
     pub local_counsel_bar_number: String,
+    /// Attorney record of the local counsel sponsoring the admission
+    #[serde(default)]
+    pub sponsoring_local_attorney_id: Option<String>,
     pub status: ProHacViceStatus,
     pub fee_paid: bool,
+    /// Renewals granted, oldest first
+    #[serde(default)]
+    pub renewals: Vec<ProHacViceRenewal>,
+}
+
+/// Term of a pro hac vice admission before it must be renewed
+pub const PRO_HAC_VICE_TERM_DAYS: i64 = 365;
+
+/// One renewal of a pro hac vice admission
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProHacViceRenewal {
+    pub renewed_at: DateTime<Utc>,
+    pub previous_expiration: Option<DateTime<Utc>>,
+    pub new_expiration: DateTime<Utc>,
+    pub fee_paid: bool,
+    pub notes: Option<String>,
+}
+
+/// Request to renew a pro hac vice admission
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct RenewProHacViceRequest {
+    /// New expiration; defaults to one term past the later of now and the current expiration
+    #[serde(default)]
+    pub expiration_date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub fee_paid: bool,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+/// A pro hac vice admission nearing expiration, for the clerk's office
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExpiringProHacVice {
+    pub attorney_id: String,
+    pub attorney_name: String,
+    pub days_remaining: i64,
+    pub admission: ProHacViceAdmission,
+}
+
+impl ProHacViceAdmission {
+    /// Granted and not past its expiration; admissions recorded before
+    /// expirations were tracked have none and stay active
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        matches!(self.status, ProHacViceStatus::Granted)
+            && self.expiration_date.map_or(true, |expires| expires > now)
+    }
+
+    /// Extend the admission, logging the renewal
+    ///
+    /// Only granted or expired admissions can be renewed, and the new
+    /// expiration must be later than both now and the current expiration.
+    pub fn renew(&mut self, request: RenewProHacViceRequest, now: DateTime<Utc>) -> Result<(), String> {
+        if !matches!(self.status, ProHacViceStatus::Granted | ProHacViceStatus::Expired) {
+            return Err(format!("A {:?} pro hac vice admission cannot be renewed", self.status));
+        }
+
+        let current = self.expiration_date.filter(|expires| *expires > now).unwrap_or(now);
+        let new_expiration = request
+            .expiration_date
+            .unwrap_or(current + chrono::Duration::days(PRO_HAC_VICE_TERM_DAYS));
+        if new_expiration <= current {
+            return Err(format!(
+                "Renewal must extend the admission past {}",
+                current.format("%Y-%m-%d")
+            ));
+        }
+
+        self.renewals.push(ProHacViceRenewal {
+            renewed_at: now,
+            previous_expiration: self.expiration_date,
+            new_expiration,
+            fee_paid: request.fee_paid,
+            notes: request.notes,
+        });
+        self.expiration_date = Some(new_expiration);
+        self.status = ProHacViceStatus::Granted;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
@@ -164,8 +245,23 @@ pub struct CJAAppointment {
     pub hours_claimed: f64,
     pub amount_approved: Option<f64>,
     pub voucher_status: VoucherStatus,
+    /// Time and expenses claimed on the voucher
+    #[serde(default)]
+    pub line_items: Vec<VoucherLineItem>,
+    #[serde(default)]
+    pub submitted_at: Option<DateTime<Utc>>,
+    /// Judge who approved or reduced the voucher
+    #[serde(default)]
+    pub approved_by: Option<String>,
+    #[serde(default)]
+    pub approved_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub paid_at: Option<DateTime<Utc>>,
 }
 
+/// Default cap on open CJA appointments an attorney may carry
+pub const DEFAULT_CJA_OPEN_APPOINTMENT_CAP: usize = 10;
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub enum CJAAppointmentType {
     TrialLevel,
@@ -184,16 +280,218 @@ pub enum CompensationStatus {
     Paid,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+/// CJA voucher workflow: Draft -> Submitted -> CourtApproved or Reduced -> Paid
+///
+/// Aliases read vouchers stored under the earlier status names.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub enum VoucherStatus {
-    NotSubmitted,
+    #[serde(alias = "NotSubmitted")]
+    Draft,
+    #[serde(alias = "UnderReview")]
     Submitted,
-    UnderReview,
-    Approved,
-    Rejected,
+    /// Approved for the full claimed amount
+    #[serde(alias = "Approved")]
+    CourtApproved,
+    /// Approved for less than the claimed amount
+    #[serde(alias = "Rejected")]
+    Reduced,
     Paid,
 }
 
+/// Whether time on a voucher was spent in or out of court
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub enum CJARateCategory {
+    InCourt,
+    OutOfCourt,
+}
+
+/// Statutory hourly rate for non-capital CJA representations
+pub const CJA_HOURLY_RATE: f64 = 172.0;
+
+/// Maximum hourly rate for capital CJA representations
+pub const CJA_CAPITAL_HOURLY_RATE: f64 = 220.0;
+
+impl CJARateCategory {
+    /// Hourly rate for time in this category on an appointment of `appointment_type`
+    ///
+    /// In-court and out-of-court time have been paid at the same rate since
+    /// the statutory rates were unified; the category is kept for the
+    /// voucher's breakdown.
+    pub fn hourly_rate(&self, appointment_type: &CJAAppointmentType) -> f64 {
+        match appointment_type {
+            CJAAppointmentType::CapitalCase => CJA_CAPITAL_HOURLY_RATE,
+            _ => CJA_HOURLY_RATE,
+        }
+    }
+}
+
+/// One entry of time or expenses on a CJA voucher
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct VoucherLineItem {
+    pub id: String,
+    pub service_date: NaiveDate,
+    pub description: String,
+    pub hours: f64,
+    pub rate_category: CJARateCategory,
+    /// Hourly rate in effect when the item was added
+    pub hourly_rate: f64,
+    /// Out-of-pocket expenses such as travel or transcripts
+    #[serde(default)]
+    pub expenses: f64,
+}
+
+impl VoucherLineItem {
+    pub fn amount(&self) -> f64 {
+        self.hours * self.hourly_rate + self.expenses
+    }
+}
+
+/// Request to add a line item to a CJA voucher
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddVoucherLineItemRequest {
+    pub service_date: NaiveDate,
+    pub description: String,
+    pub hours: f64,
+    pub rate_category: CJARateCategory,
+    #[serde(default)]
+    pub expenses: f64,
+}
+
+/// Request to approve a submitted CJA voucher, in full or reduced
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApproveVoucherRequest {
+    pub judge_id: String,
+    /// Amount approved; at most the claimed total
+    pub approved_amount: f64,
+}
+
+/// A CJA voucher with its computed totals
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CJAVoucherSummary {
+    pub attorney_id: String,
+    pub attorney_name: String,
+    pub appointment: CJAAppointment,
+    pub claimed_total: f64,
+    /// Statutory case-compensation maximum, `None` where there is none
+    pub compensation_cap: Option<f64>,
+    pub exceeds_cap: bool,
+}
+
+impl CJAVoucherSummary {
+    pub fn new(attorney: &Attorney, appointment: CJAAppointment) -> Self {
+        Self {
+            attorney_id: attorney.id.clone(),
+            attorney_name: format!("{} {}", attorney.first_name, attorney.last_name),
+            claimed_total: appointment.claimed_total(),
+            compensation_cap: appointment.appointment_type.compensation_cap(),
+            exceeds_cap: appointment.exceeds_cap(),
+            appointment,
+        }
+    }
+}
+
+impl CJAAppointmentType {
+    /// Statutory maximum compensation per representation
+    ///
+    /// Capital cases have no statutory maximum. Complex litigation is
+    /// capped as a felony; amounts above it need circuit approval.
+    pub fn compensation_cap(&self) -> Option<f64> {
+        match self {
+            CJAAppointmentType::TrialLevel | CJAAppointmentType::ComplexLitigation => Some(13_300.0),
+            CJAAppointmentType::Appellate | CJAAppointmentType::Habeas => Some(9_400.0),
+            CJAAppointmentType::CapitalCase => None,
+        }
+    }
+}
+
+impl CJAAppointment {
+    /// Hours and expenses claimed across all line items, to the cent
+    pub fn claimed_total(&self) -> f64 {
+        let total: f64 = self.line_items.iter().map(VoucherLineItem::amount).sum();
+        (total * 100.0).round() / 100.0
+    }
+
+    /// Whether the claimed total is above the statutory cap for the case type
+    pub fn exceeds_cap(&self) -> bool {
+        self.appointment_type
+            .compensation_cap()
+            .is_some_and(|cap| self.claimed_total() > cap)
+    }
+
+    /// Add a line item to a draft voucher
+    pub fn add_line_item(&mut self, request: AddVoucherLineItemRequest) -> Result<&VoucherLineItem, String> {
+        if self.voucher_status != VoucherStatus::Draft {
+            return Err(format!("Voucher is {:?}; line items can only be added to a draft", self.voucher_status));
+        }
+        if request.hours < 0.0 || request.expenses < 0.0 {
+            return Err("Hours and expenses must not be negative".to_string());
+        }
+        if request.hours == 0.0 && request.expenses == 0.0 {
+            return Err("A line item must claim hours or expenses".to_string());
+        }
+        self.line_items.push(VoucherLineItem {
+            id: Uuid::new_v4().to_string(),
+            service_date: request.service_date,
+            description: request.description,
+            hours: request.hours,
+            hourly_rate: request.rate_category.hourly_rate(&self.appointment_type),
+            rate_category: request.rate_category,
+            expenses: request.expenses,
+        });
+        self.hours_claimed = self.line_items.iter().map(|item| item.hours).sum();
+        Ok(self.line_items.last().expect("line item was just added"))
+    }
+
+    /// Submit a draft voucher for court approval
+    pub fn submit_voucher(&mut self, now: DateTime<Utc>) -> Result<(), String> {
+        if self.voucher_status != VoucherStatus::Draft {
+            return Err(format!("Voucher is {:?}; only a draft can be submitted", self.voucher_status));
+        }
+        if self.line_items.is_empty() {
+            return Err("Voucher has no line items".to_string());
+        }
+        self.voucher_status = VoucherStatus::Submitted;
+        self.submitted_at = Some(now);
+        Ok(())
+    }
+
+    /// Approve a submitted voucher, reducing it when less than the claim is approved
+    pub fn approve_voucher(&mut self, judge_id: &str, approved_amount: f64, now: DateTime<Utc>) -> Result<(), String> {
+        if self.voucher_status != VoucherStatus::Submitted {
+            return Err(format!("Voucher is {:?}; only a submitted voucher can be approved", self.voucher_status));
+        }
+        let claimed = self.claimed_total();
+        if approved_amount < 0.0 {
+            return Err("Approved amount must not be negative".to_string());
+        }
+        if approved_amount > claimed {
+            return Err(format!("Approved amount {:.2} exceeds the claimed {:.2}", approved_amount, claimed));
+        }
+        let (voucher_status, compensation_status) = if approved_amount < claimed {
+            (VoucherStatus::Reduced, CompensationStatus::PartiallyApproved)
+        } else {
+            (VoucherStatus::CourtApproved, CompensationStatus::Approved)
+        };
+        self.voucher_status = voucher_status;
+        self.compensation_status = compensation_status;
+        self.amount_approved = Some(approved_amount);
+        self.approved_by = Some(judge_id.to_string());
+        self.approved_at = Some(now);
+        Ok(())
+    }
+
+    /// Record payment of an approved or reduced voucher
+    pub fn mark_voucher_paid(&mut self, now: DateTime<Utc>) -> Result<(), String> {
+        if !matches!(self.voucher_status, VoucherStatus::CourtApproved | VoucherStatus::Reduced) {
+            return Err(format!("Voucher is {:?}; only an approved voucher can be paid", self.voucher_status));
+        }
+        self.voucher_status = VoucherStatus::Paid;
+        self.compensation_status = CompensationStatus::Paid;
+        self.paid_at = Some(now);
+        Ok(())
+    }
+}
+
 /// Practice areas
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub enum PracticeArea {
@@ -227,17 +525,64 @@ pub struct DisciplinaryAction {
     pub public_record: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+/// Kind of disciplinary action
+///
+/// A suspension runs from `effective_date` to `end_date`, or indefinitely
+/// without one; an interim suspension holds pending a final decision. A
+/// disbarment lasts until a reinstatement order.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
 pub enum DisciplineType {
     Warning,
     Reprimand,
     Probation,
     Suspension,
+    InterimSuspension,
     Disbarment,
     Reinstatement,
     Other,
 }
 
+impl DisciplineType {
+    /// Whether the action bars the attorney from practice while in effect
+    pub fn restricts_practice(&self) -> bool {
+        matches!(self, DisciplineType::Suspension | DisciplineType::InterimSuspension | DisciplineType::Disbarment)
+    }
+}
+
+impl DisciplinaryAction {
+    /// Whether the action has begun and its term, if it has one, hasn't run out
+    pub fn is_in_effect(&self, now: DateTime<Utc>) -> bool {
+        self.effective_date <= now && self.end_date.map_or(true, |end| now < end)
+    }
+}
+
+/// Request to reinstate a suspended or disbarred attorney
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReinstatementRequest {
+    /// Number of the reinstating order
+    pub order_number: String,
+    pub jurisdiction: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// A representation ended because its attorney was suspended or disbarred
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AffectedCase {
+    pub case_id: String,
+    pub party_id: String,
+    pub representation_id: String,
+}
+
+/// Result of recording a disciplinary action
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DisciplinaryActionResponse {
+    pub action: DisciplinaryAction,
+    pub attorney_status: AttorneyStatus,
+    /// Cases whose representation the action ended, for the clerk to follow up
+    pub affected_cases: Vec<AffectedCase>,
+}
+
 /// Status of disciplinary action
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub enum DisciplineStatus {
@@ -404,6 +749,69 @@ pub enum WithdrawalReason {
     Other,
 }
 
+/// Continuity points lost for each attorney substitution
+pub const SUBSTITUTION_PENALTY: u32 = 25;
+
+/// Continuity points lost for each withdrawal that was not a substitution
+pub const WITHDRAWAL_PENALTY: u32 = 15;
+
+/// Attorney changes at which a case is flagged for excessive churn
+pub const EXCESSIVE_CHURN_THRESHOLD: usize = 2;
+
+/// Representation continuity for a case
+///
+/// A substitution is a representation that ended and was followed by new
+/// counsel for the same party; a withdrawal ended without a successor.
+/// Representations that ended because the work was completed are neither.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RepresentationContinuity {
+    pub case_id: String,
+    pub total_representations: usize,
+    pub active_representations: usize,
+    pub substitutions: usize,
+    pub withdrawals: usize,
+    /// 100 for a case that kept its counsel throughout, lower with each change
+    pub continuity_score: u32,
+    pub excessive_churn: bool,
+}
+
+impl RepresentationContinuity {
+    /// Compute continuity from a case's representation history
+    pub fn from_representations(case_id: &str, representations: &[Representation]) -> Self {
+        let case_reps: Vec<&Representation> = representations.iter().filter(|r| r.case_id == case_id).collect();
+        let mut substitutions = 0;
+        let mut withdrawals = 0;
+
+        for rep in &case_reps {
+            let Some(ended) = rep.end_date else { continue };
+            if matches!(rep.withdrawal_reason, Some(WithdrawalReason::CompletedRepresentation)) {
+                continue;
+            }
+
+            let succeeded = case_reps
+                .iter()
+                .any(|other| other.id != rep.id && other.party_id == rep.party_id && other.start_date >= ended);
+            if succeeded {
+                substitutions += 1;
+            } else {
+                withdrawals += 1;
+            }
+        }
+
+        let penalty = substitutions as u32 * SUBSTITUTION_PENALTY + withdrawals as u32 * WITHDRAWAL_PENALTY;
+
+        Self {
+            case_id: case_id.to_string(),
+            total_representations: case_reps.len(),
+            active_representations: case_reps.iter().filter(|r| r.end_date.is_none()).count(),
+            substitutions,
+            withdrawals,
+            continuity_score: 100u32.saturating_sub(penalty),
+            excessive_churn: substitutions + withdrawals >= EXCESSIVE_CHURN_THRESHOLD,
+        }
+    }
+}
+
 /// Service of process record
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ServiceRecord {
@@ -473,6 +881,57 @@ pub struct ConflictCheck {
     pub cleared: bool,
     pub waiver_obtained: bool,
     pub notes: Option<String>,
+    /// When the check was cleared; checks cleared before this was tracked have none
+    #[serde(default)]
+    pub cleared_at: Option<DateTime<Utc>>,
+}
+
+/// Turnaround statistics for conflict checks opened in a period
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConflictCheckTurnaround {
+    pub period_start: Option<DateTime<Utc>>,
+    pub period_end: Option<DateTime<Utc>>,
+    pub total_checks: usize,
+    pub cleared_checks: usize,
+    pub outstanding_checks: usize,
+    /// Average hours from check creation to clearance, over cleared checks with a clearance time
+    pub average_turnaround_hours: Option<f64>,
+}
+
+impl ConflictCheckTurnaround {
+    /// Compute turnaround over checks whose `check_date` falls within the period
+    pub fn from_checks(
+        checks: &[ConflictCheck],
+        period_start: Option<DateTime<Utc>>,
+        period_end: Option<DateTime<Utc>>,
+    ) -> Self {
+        let in_period: Vec<&ConflictCheck> = checks
+            .iter()
+            .filter(|c| period_start.map_or(true, |start| c.check_date >= start))
+            .filter(|c| period_end.map_or(true, |end| c.check_date <= end))
+            .collect();
+
+        let turnarounds: Vec<f64> = in_period
+            .iter()
+            .filter(|c| c.cleared)
+            .filter_map(|c| c.cleared_at.map(|at| (at - c.check_date).num_seconds() as f64 / 3600.0))
+            .collect();
+
+        let average_turnaround_hours = if turnarounds.is_empty() {
+            None
+        } else {
+            Some(turnarounds.iter().sum::<f64>() / turnarounds.len() as f64)
+        };
+
+        Self {
+            period_start,
+            period_end,
+            total_checks: in_period.len(),
+            cleared_checks: in_period.iter().filter(|c| c.cleared).count(),
+            outstanding_checks: in_period.iter().filter(|c| !c.cleared).count(),
+            average_turnaround_hours,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -545,23 +1004,143 @@ impl Attorney {
     }
 
     /// Check if attorney is in good standing
+    ///
+    /// A suspension, interim suspension or disbarment in effect means the
+    /// attorney is not, whatever their recorded status.
     pub fn is_in_good_standing(&self) -> bool {
-        self.status == AttorneyStatus::Active
+        let now = Utc::now();
+        self.status_at(now) == AttorneyStatus::Active
+            && self.practice_restriction(now).is_none()
             && self.bar_admissions.iter()
                 .any(|a| a.status == AdmissionStatus::Active)
     }
 
+    /// The suspension or disbarment barring the attorney from practice, if any
+    ///
+    /// A reinstatement lifts every restriction that took effect before it.
+    pub fn practice_restriction(&self, now: DateTime<Utc>) -> Option<&DisciplinaryAction> {
+        self.discipline_history.iter()
+            .filter(|a| a.action_type.restricts_practice() && a.is_in_effect(now))
+            .find(|restriction| !self.discipline_history.iter().any(|r| {
+                r.action_type == DisciplineType::Reinstatement
+                    && r.effective_date >= restriction.effective_date
+                    && r.effective_date <= now
+            }))
+    }
+
+    /// Status as of `now`
+    ///
+    /// A suspension recorded as a disciplinary action lifts when its term
+    /// ends; any other status, including a suspension set directly, holds
+    /// until changed.
+    pub fn status_at(&self, now: DateTime<Utc>) -> AttorneyStatus {
+        let term_served = self.status == AttorneyStatus::Suspended
+            && self.discipline_history.iter().any(|a| {
+                matches!(a.action_type, DisciplineType::Suspension | DisciplineType::InterimSuspension)
+            })
+            && self.practice_restriction(now).is_none();
+        if term_served {
+            AttorneyStatus::Active
+        } else {
+            self.status.clone()
+        }
+    }
+
+    /// Record a disciplinary action, suspending or disbarring the attorney
+    /// if it restricts practice and has taken effect
+    ///
+    /// Returns whether it did, in which case the attorney's representations
+    /// should end. A suspension never replaces a disbarment.
+    pub fn impose_discipline(&mut self, action: DisciplinaryAction, now: DateTime<Utc>) -> bool {
+        let restricts = action.action_type.restricts_practice() && action.is_in_effect(now);
+        if restricts {
+            if action.action_type == DisciplineType::Disbarment {
+                self.status = AttorneyStatus::Disbarred;
+            } else if self.status != AttorneyStatus::Disbarred {
+                self.status = AttorneyStatus::Suspended;
+            }
+        }
+        self.discipline_history.push(action);
+        self.updated_at = now;
+        restricts
+    }
+
+    /// Record the order reinstating a suspended or disbarred attorney and
+    /// restore them to active status
+    pub fn reinstate(&mut self, request: ReinstatementRequest, now: DateTime<Utc>) -> Result<DisciplinaryAction, String> {
+        let restricted = matches!(self.status, AttorneyStatus::Suspended | AttorneyStatus::Disbarred)
+            || self.practice_restriction(now).is_some();
+        if !restricted {
+            return Err(format!("Attorney is not suspended or disbarred (status {:?})", self.status));
+        }
+        if request.order_number.trim().is_empty() {
+            return Err("Reinstatement requires the reinstating order number".to_string());
+        }
+
+        let action = DisciplinaryAction {
+            id: Uuid::new_v4().to_string(),
+            date: now,
+            jurisdiction: request.jurisdiction,
+            action_type: DisciplineType::Reinstatement,
+            description: request.description
+                .unwrap_or_else(|| format!("Reinstated by order {}", request.order_number)),
+            case_number: Some(request.order_number),
+            effective_date: now,
+            end_date: None,
+            public_record: true,
+        };
+        self.discipline_history.push(action.clone());
+        self.status = AttorneyStatus::Active;
+        self.updated_at = now;
+        Ok(action)
+    }
+
     /// Check if attorney can practice in federal court
-    pub fn can_practice_federal(&self, court: &str) -> bool {
+    ///
+    /// With a case, an active pro hac vice admission to that court for that
+    /// case also qualifies.
+    pub fn can_practice_federal(&self, court: &str, case_id: Option<&str>) -> bool {
         self.federal_admissions.iter()
             .any(|a| a.court == court && a.status == AdmissionStatus::Active)
+            || case_id.is_some_and(|case_id| {
+                let now = Utc::now();
+                self.pro_hac_vice_admissions.iter()
+                    .any(|phv| phv.case_id == case_id && phv.court == court && phv.is_active(now))
+            })
     }
 
     /// Check if attorney has ECF filing privileges
+    ///
+    /// Suspended for the duration of any suspension or disbarment.
     pub fn has_ecf_privileges(&self) -> bool {
         self.ecf_registration.as_ref()
             .map(|ecf| ecf.active && ecf.training_completed)
             .unwrap_or(false)
+            && self.practice_restriction(Utc::now()).is_none()
+    }
+
+    /// Number of CJA appointments whose vouchers have not been approved or paid
+    pub fn open_cja_appointments(&self) -> usize {
+        self.cja_appointments.iter()
+            .filter(|a| !matches!(a.voucher_status, VoucherStatus::CourtApproved | VoucherStatus::Reduced | VoucherStatus::Paid))
+            .count()
+    }
+
+    /// Reason the attorney cannot take a CJA appointment, or `None` if eligible
+    pub fn cja_ineligibility(&self, district: &str, case_id: &str, cap: usize) -> Option<String> {
+        if self.status != AttorneyStatus::Active {
+            return Some(format!("Attorney status is {:?}", self.status));
+        }
+        if !self.cja_panel_member || !self.cja_panel_districts.iter().any(|d| d == district) {
+            return Some(format!("Not on the CJA panel for {}", district));
+        }
+        if self.cja_appointments.iter().any(|a| a.case_id == case_id) {
+            return Some("Already appointed on this case".to_string());
+        }
+        if self.open_cja_appointments() >= cap {
+            return Some(format!("At cap of {} open CJA appointments", cap));
+        }
+        None
     }
 
     /// Calculate win rate
@@ -572,6 +1151,49 @@ impl Attorney {
             None
         }
     }
+
+    /// Apply a merge patch, touching only the fields it supplies
+    ///
+    /// Bar number uniqueness and email format are checked by the caller.
+    pub fn apply_patch(&mut self, patch: PatchAttorneyRequest) {
+        if let Some(bar_number) = patch.bar_number {
+            self.bar_number = bar_number;
+        }
+        if let Some(first_name) = patch.first_name {
+            self.first_name = first_name;
+        }
+        if let Some(last_name) = patch.last_name {
+            self.last_name = last_name;
+        }
+        if let Some(middle_name) = patch.middle_name {
+            self.middle_name = middle_name;
+        }
+        if let Some(firm_name) = patch.firm_name {
+            self.firm_name = firm_name;
+        }
+        if let Some(email) = patch.email {
+            self.email = email;
+        }
+        if let Some(phone) = patch.phone {
+            self.phone = phone;
+        }
+        if let Some(fax) = patch.fax {
+            self.fax = fax;
+        }
+        if let Some(address) = patch.address {
+            self.address = address;
+        }
+        if let Some(practice_areas) = patch.practice_areas {
+            self.practice_areas = practice_areas;
+        }
+        if let Some(languages_spoken) = patch.languages_spoken {
+            self.languages_spoken = languages_spoken;
+        }
+        if let Some(status) = patch.status {
+            self.status = status;
+        }
+        self.updated_at = Utc::now();
+    }
 }
 
 impl Party {
@@ -687,6 +1309,127 @@ pub struct UpdateAttorneyRequest {
     pub avg_case_duration_days: Option<i32>,
 }
 
+impl UpdateAttorneyRequest {
+    /// Collection fields a full replacement leaves out
+    ///
+    /// These have their own endpoints; a PUT that omits them keeps whatever
+    /// is stored, so the body is not really a full replacement.
+    pub fn missing_collections(&self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if self.bar_admissions.is_none() {
+            missing.push("bar_admissions");
+        }
+        if self.federal_admissions.is_none() {
+            missing.push("federal_admissions");
+        }
+        if self.pro_hac_vice_admissions.is_none() {
+            missing.push("pro_hac_vice_admissions");
+        }
+        if self.cja_appointments.is_none() {
+            missing.push("cja_appointments");
+        }
+        if self.discipline_history.is_none() {
+            missing.push("discipline_history");
+        }
+        missing
+    }
+}
+
+/// JSON Merge Patch (RFC 7396) for an attorney's own fields
+///
+/// Absent fields are left alone and `null` clears an optional field.
+/// Admissions, CJA appointments and disciplinary actions are managed through
+/// their own endpoints and are rejected here as unknown fields.
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct PatchAttorneyRequest {
+    pub bar_number: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    #[serde(deserialize_with = "nullable")]
+    #[schema(value_type = Option<String>, nullable)]
+    pub middle_name: Option<Option<String>>,
+    #[serde(deserialize_with = "nullable")]
+    #[schema(value_type = Option<String>, nullable)]
+    pub firm_name: Option<Option<String>>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    #[serde(deserialize_with = "nullable")]
+    #[schema(value_type = Option<String>, nullable)]
+    pub fax: Option<Option<String>>,
+    pub address: Option<Address>,
+    pub practice_areas: Option<Vec<PracticeArea>>,
+    pub languages_spoken: Option<Vec<String>>,
+    pub status: Option<AttorneyStatus>,
+}
+
+/// Distinguish an absent field (`None`) from an explicit `null` (`Some(None)`)
+fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Order a CJA panel for rotation, starting after the last appointed attorney
+///
+/// Panel members are ordered by name (then ID for stability) and rotated so
+/// that the attorney following `last_appointed` is first in line.
+pub fn cja_rotation_order(mut panel: Vec<Attorney>, last_appointed: Option<&str>) -> Vec<Attorney> {
+    panel.sort_by(|a, b| {
+        (&a.last_name, &a.first_name, &a.id).cmp(&(&b.last_name, &b.first_name, &b.id))
+    });
+
+    if let Some(position) = last_appointed.and_then(|id| panel.iter().position(|a| a.id == id)) {
+        panel.rotate_left(position + 1);
+    }
+
+    panel
+}
+
+/// Request to appoint several CJA panel attorneys to one case
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkCJAAppointmentRequest {
+    pub district: String,
+    pub case_id: String,
+    pub case_caption: String,
+    /// Number of attorneys to appoint
+    pub count: usize,
+    pub appointment_type: CJAAppointmentType,
+    /// Parties (typically the defendants) checked for conflicts against each candidate
+    #[serde(default)]
+    pub parties: Vec<String>,
+    #[serde(default)]
+    pub adverse_parties: Vec<String>,
+    /// Maximum open appointments per attorney (defaults to DEFAULT_CJA_OPEN_APPOINTMENT_CAP)
+    pub max_open_appointments: Option<usize>,
+}
+
+/// Attorney appointed by a bulk CJA appointment
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CJAAppointee {
+    pub attorney_id: String,
+    pub attorney_name: String,
+    pub appointment: CJAAppointment,
+}
+
+/// Panel attorney passed over during a bulk CJA appointment
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SkippedCJAAttorney {
+    pub attorney_id: String,
+    pub attorney_name: String,
+    pub reason: String,
+}
+
+/// Result of a bulk CJA appointment
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkCJAAppointmentResponse {
+    pub case_id: String,
+    pub appointed: Vec<CJAAppointee>,
+    pub skipped: Vec<SkippedCJAAttorney>,
+}
+
 /// Request DTO for creating a new party
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreatePartyRequest {
@@ -708,4 +1451,442 @@ pub struct CreatePartyRequest {
     pub address: Option<Address>,
     pub phone: Option<String>,
     pub email: Option<String>,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn check(opened: DateTime<Utc>, cleared_after_hours: Option<i64>) -> ConflictCheck {
+        ConflictCheck {
+            id: Uuid::new_v4().to_string(),
+            attorney_id: "atty-1".to_string(),
+            check_date: opened,
+            case_id: None,
+            party_names: vec!["Acme Corp".to_string()],
+            adverse_parties: Vec::new(),
+            conflicts_found: Vec::new(),
+            cleared: cleared_after_hours.is_some(),
+            waiver_obtained: false,
+            notes: None,
+            cleared_at: cleared_after_hours.map(|h| opened + Duration::hours(h)),
+        }
+    }
+
+    #[test]
+    fn test_conflict_turnaround_averages_cleared_and_counts_outstanding() {
+        let opened = Utc.with_ymd_and_hms(2025, 3, 3, 9, 0, 0).unwrap();
+        let checks = vec![
+            check(opened, Some(24)),
+            check(opened, Some(72)),
+            check(opened, None),
+            check(opened - Duration::days(60), Some(1)),
+        ];
+
+        let turnaround = ConflictCheckTurnaround::from_checks(
+            &checks,
+            Some(Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2025, 3, 31, 23, 59, 59).unwrap()),
+        );
+
+        assert_eq!(turnaround.total_checks, 3);
+        assert_eq!(turnaround.cleared_checks, 2);
+        assert_eq!(turnaround.outstanding_checks, 1);
+        assert_eq!(turnaround.average_turnaround_hours, Some(48.0));
+    }
+
+    #[test]
+    fn test_conflict_turnaround_without_cleared_checks() {
+        let opened = Utc.with_ymd_and_hms(2025, 3, 3, 9, 0, 0).unwrap();
+        let turnaround = ConflictCheckTurnaround::from_checks(&[check(opened, None)], None, None);
+
+        assert_eq!(turnaround.outstanding_checks, 1);
+        assert_eq!(turnaround.average_turnaround_hours, None);
+    }
+
+    fn representation(id: &str, party_id: &str, start: DateTime<Utc>, end: Option<DateTime<Utc>>) -> Representation {
+        Representation {
+            id: id.to_string(),
+            attorney_id: format!("atty-{}", id),
+            party_id: party_id.to_string(),
+            case_id: "case-1".to_string(),
+            representation_type: RepresentationType::General,
+            status: RepresentationStatus::Active,
+            start_date: start,
+            end_date: end,
+            lead_counsel: true,
+            local_counsel: false,
+            limited_appearance: false,
+            scope_of_representation: None,
+            withdrawal_reason: None,
+            court_appointed: false,
+            cja_appointment_id: None,
+            notes: None,
+        }
+    }
+
+    #[test]
+    fn test_continuity_counts_substitutions_and_withdrawals() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap();
+        let reps = vec![
+            representation("a", "defendant", start, Some(start + Duration::days(30))),
+            representation("b", "defendant", start + Duration::days(30), Some(start + Duration::days(60))),
+            representation("c", "defendant", start + Duration::days(60), None),
+            // Co-defendant's counsel withdrew with no replacement
+            representation("d", "co-defendant", start, Some(start + Duration::days(10))),
+        ];
+
+        let continuity = RepresentationContinuity::from_representations("case-1", &reps);
+
+        assert_eq!(continuity.total_representations, 4);
+        assert_eq!(continuity.active_representations, 1);
+        assert_eq!(continuity.substitutions, 2);
+        assert_eq!(continuity.withdrawals, 1);
+        assert_eq!(continuity.continuity_score, 100 - 2 * SUBSTITUTION_PENALTY - WITHDRAWAL_PENALTY);
+        assert!(continuity.excessive_churn);
+    }
+
+    #[test]
+    fn test_continuity_ignores_completed_representation() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap();
+        let mut completed = representation("a", "defendant", start, Some(start + Duration::days(90)));
+        completed.withdrawal_reason = Some(WithdrawalReason::CompletedRepresentation);
+
+        let continuity = RepresentationContinuity::from_representations("case-1", &[completed]);
+
+        assert_eq!(continuity.substitutions, 0);
+        assert_eq!(continuity.withdrawals, 0);
+        assert_eq!(continuity.continuity_score, 100);
+        assert!(!continuity.excessive_churn);
+    }
+
+    #[test]
+    fn test_patch_applies_only_supplied_fields() {
+        let mut attorney = Attorney::new(
+            "NY123".to_string(),
+            "Ada".to_string(),
+            "Lovelace".to_string(),
+            "ada@law.com".to_string(),
+            "555-0100".to_string(),
+            Address {
+                street1: "1 Main St".to_string(),
+                street2: None,
+                city: "New York".to_string(),
+                state: "NY".to_string(),
+                zip_code: "10007".to_string(),
+                country: "USA".to_string(),
+            },
+        );
+        attorney.middle_name = Some("Byron".to_string());
+        attorney.firm_name = Some("Analytical LLP".to_string());
+        attorney.bar_admissions.push(BarAdmission {
+            state: "NY".to_string(),
+            bar_number: "NY123".to_string(),
+            admission_date: Utc::now(),
+            status: AdmissionStatus::Active,
+            expiration_date: None,
+        });
+
+        let patch: PatchAttorneyRequest =
+            serde_json::from_str(r#"{"middle_name": null, "phone": "555-0199"}"#).unwrap();
+        attorney.apply_patch(patch);
+
+        assert_eq!(attorney.middle_name, None, "null clears the field");
+        assert_eq!(attorney.firm_name.as_deref(), Some("Analytical LLP"), "absent fields are kept");
+        assert_eq!(attorney.phone, "555-0199");
+        assert_eq!(attorney.bar_admissions.len(), 1);
+
+        assert!(serde_json::from_str::<PatchAttorneyRequest>(r#"{"bar_admissions": []}"#).is_err());
+    }
+
+    fn pro_hac_vice(expiration_date: Option<DateTime<Utc>>) -> ProHacViceAdmission {
+        ProHacViceAdmission {
+            case_id: "case-1".to_string(),
+            case_caption: "United States v. Doe".to_string(),
+            court: "S.D.N.Y.".to_string(),
+            admission_date: Utc::now() - Duration::days(300),
+            expiration_date,
+            local_counsel: "Grace Hopper".to_string(),
+            local_counsel_bar_number: "NY456".to_string(),
+            sponsoring_local_attorney_id: Some("atty-local".to_string()),
+            status: ProHacViceStatus::Granted,
+            fee_paid: true,
+            renewals: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_pro_hac_vice_renewal_extends_and_logs() {
+        let now = Utc::now();
+        let expires = now + Duration::days(20);
+        let mut admission = pro_hac_vice(Some(expires));
+
+        admission.renew(RenewProHacViceRequest { fee_paid: true, ..Default::default() }, now).unwrap();
+
+        assert_eq!(admission.expiration_date, Some(expires + Duration::days(PRO_HAC_VICE_TERM_DAYS)));
+        assert_eq!(admission.renewals.len(), 1);
+        assert_eq!(admission.renewals[0].previous_expiration, Some(expires));
+
+        let earlier = RenewProHacViceRequest { expiration_date: Some(expires), ..Default::default() };
+        assert!(admission.renew(earlier, now).is_err());
+
+        admission.status = ProHacViceStatus::Withdrawn;
+        assert!(admission.renew(RenewProHacViceRequest::default(), now).is_err());
+    }
+
+    #[test]
+    fn test_expired_admission_renews_from_today_and_reactivates() {
+        let now = Utc::now();
+        let mut admission = pro_hac_vice(Some(now - Duration::days(5)));
+        admission.status = ProHacViceStatus::Expired;
+        assert!(!admission.is_active(now));
+
+        admission.renew(RenewProHacViceRequest::default(), now).unwrap();
+
+        assert!(admission.is_active(now));
+        assert_eq!(admission.expiration_date, Some(now + Duration::days(PRO_HAC_VICE_TERM_DAYS)));
+    }
+
+    #[test]
+    fn test_unexpired_pro_hac_vice_qualifies_for_its_case_only() {
+        let mut attorney = Attorney::new(
+            "CA789".to_string(),
+            "Alan".to_string(),
+            "Turing".to_string(),
+            "alan@law.com".to_string(),
+            "555-0101".to_string(),
+            Address {
+                street1: "2 Market St".to_string(),
+                street2: None,
+                city: "San Francisco".to_string(),
+                state: "CA".to_string(),
+                zip_code: "94105".to_string(),
+                country: "USA".to_string(),
+            },
+        );
+        attorney.pro_hac_vice_admissions.push(pro_hac_vice(Some(Utc::now() + Duration::days(10))));
+
+        assert!(attorney.can_practice_federal("S.D.N.Y.", Some("case-1")));
+        assert!(!attorney.can_practice_federal("S.D.N.Y.", Some("case-2")));
+        assert!(!attorney.can_practice_federal("S.D.N.Y.", None));
+
+        attorney.pro_hac_vice_admissions[0].expiration_date = Some(Utc::now() - Duration::days(1));
+        assert!(!attorney.can_practice_federal("S.D.N.Y.", Some("case-1")));
+    }
+
+    fn appointment(appointment_type: CJAAppointmentType) -> CJAAppointment {
+        CJAAppointment {
+            id: "appt-1".to_string(),
+            case_id: "case-1".to_string(),
+            case_caption: "United States v. Doe".to_string(),
+            appointment_date: Utc.with_ymd_and_hms(2025, 2, 3, 9, 0, 0).unwrap(),
+            appointment_type,
+            compensation_status: CompensationStatus::Pending,
+            hours_claimed: 0.0,
+            amount_approved: None,
+            voucher_status: VoucherStatus::Draft,
+            line_items: Vec::new(),
+            submitted_at: None,
+            approved_by: None,
+            approved_at: None,
+            paid_at: None,
+        }
+    }
+
+    fn item(hours: f64, rate_category: CJARateCategory, expenses: f64) -> AddVoucherLineItemRequest {
+        AddVoucherLineItemRequest {
+            service_date: NaiveDate::from_ymd_opt(2025, 2, 10).unwrap(),
+            description: "Detention hearing".to_string(),
+            hours,
+            rate_category,
+            expenses,
+        }
+    }
+
+    #[test]
+    fn test_voucher_totals_and_cap() {
+        let mut appt = appointment(CJAAppointmentType::Appellate);
+        appt.add_line_item(item(10.0, CJARateCategory::InCourt, 0.0)).unwrap();
+        appt.add_line_item(item(2.5, CJARateCategory::OutOfCourt, 45.20)).unwrap();
+
+        assert_eq!(appt.claimed_total(), 2195.20);
+        assert_eq!(appt.hours_claimed, 12.5);
+        assert!(!appt.exceeds_cap());
+
+        appt.add_line_item(item(50.0, CJARateCategory::OutOfCourt, 0.0)).unwrap();
+        assert!(appt.exceeds_cap(), "62.5 hours is over the appellate cap");
+
+        let mut capital = appointment(CJAAppointmentType::CapitalCase);
+        capital.add_line_item(item(500.0, CJARateCategory::InCourt, 0.0)).unwrap();
+        assert_eq!(capital.claimed_total(), 500.0 * CJA_CAPITAL_HOURLY_RATE);
+        assert!(!capital.exceeds_cap(), "Capital cases have no statutory cap");
+    }
+
+    #[test]
+    fn test_voucher_workflow() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 3, 9, 0, 0).unwrap();
+        let mut appt = appointment(CJAAppointmentType::TrialLevel);
+        assert!(appt.submit_voucher(now).is_err(), "Empty voucher cannot be submitted");
+
+        appt.add_line_item(item(4.0, CJARateCategory::InCourt, 0.0)).unwrap();
+        appt.submit_voucher(now).unwrap();
+        assert_eq!(appt.voucher_status, VoucherStatus::Submitted);
+        assert!(appt.add_line_item(item(1.0, CJARateCategory::InCourt, 0.0)).is_err());
+        assert!(appt.mark_voucher_paid(now).is_err(), "Unapproved voucher cannot be paid");
+
+        let claimed = appt.claimed_total();
+        assert!(appt.approve_voucher("judge-1", claimed + 0.01, now).is_err());
+        appt.approve_voucher("judge-1", claimed - 100.0, now).unwrap();
+        assert_eq!(appt.voucher_status, VoucherStatus::Reduced);
+        assert_eq!(appt.amount_approved, Some(claimed - 100.0));
+        assert_eq!(appt.approved_by.as_deref(), Some("judge-1"));
+
+        appt.mark_voucher_paid(now).unwrap();
+        assert_eq!(appt.voucher_status, VoucherStatus::Paid);
+        assert!(matches!(appt.compensation_status, CompensationStatus::Paid));
+    }
+
+    #[test]
+    fn test_full_approval_and_legacy_status_names() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 3, 9, 0, 0).unwrap();
+        let mut appt = appointment(CJAAppointmentType::TrialLevel);
+        appt.add_line_item(item(1.0, CJARateCategory::OutOfCourt, 0.0)).unwrap();
+        appt.submit_voucher(now).unwrap();
+        appt.approve_voucher("judge-1", appt.claimed_total(), now).unwrap();
+        assert_eq!(appt.voucher_status, VoucherStatus::CourtApproved);
+
+        let legacy: VoucherStatus = serde_json::from_str("\"NotSubmitted\"").unwrap();
+        assert_eq!(legacy, VoucherStatus::Draft);
+        let legacy: VoucherStatus = serde_json::from_str("\"Approved\"").unwrap();
+        assert_eq!(legacy, VoucherStatus::CourtApproved);
+    }
+
+    /// An attorney in good standing with ECF access
+    fn admitted_attorney() -> Attorney {
+        let mut attorney = Attorney::new(
+            "NY456".to_string(),
+            "Grace".to_string(),
+            "Hopper".to_string(),
+            "grace@law.com".to_string(),
+            "555-0101".to_string(),
+            Address {
+                street1: "1 Main St".to_string(),
+                street2: None,
+                city: "New York".to_string(),
+                state: "NY".to_string(),
+                zip_code: "10007".to_string(),
+                country: "USA".to_string(),
+            },
+        );
+        attorney.bar_admissions.push(BarAdmission {
+            state: "NY".to_string(),
+            bar_number: "NY456".to_string(),
+            admission_date: Utc::now() - Duration::days(3650),
+            status: AdmissionStatus::Active,
+            expiration_date: None,
+        });
+        attorney.ecf_registration = Some(ECFRegistration {
+            login_id: "ghopper".to_string(),
+            registration_date: Utc::now() - Duration::days(365),
+            primary_email: "grace@law.com".to_string(),
+            secondary_emails: Vec::new(),
+            filing_privileges: Vec::new(),
+            training_completed: true,
+            last_login: None,
+            active: true,
+        });
+        attorney
+    }
+
+    fn discipline(action_type: DisciplineType, effective: DateTime<Utc>, end: Option<DateTime<Utc>>) -> DisciplinaryAction {
+        DisciplinaryAction {
+            id: Uuid::new_v4().to_string(),
+            date: effective,
+            jurisdiction: "New York".to_string(),
+            action_type,
+            description: "Misappropriation of client funds".to_string(),
+            case_number: Some("M-2025-17".to_string()),
+            effective_date: effective,
+            end_date: end,
+            public_record: true,
+        }
+    }
+
+    #[test]
+    fn test_active_suspension_removes_good_standing_and_ecf() {
+        let now = Utc::now();
+        let mut attorney = admitted_attorney();
+        assert!(attorney.is_in_good_standing() && attorney.has_ecf_privileges());
+
+        let suspension = discipline(DisciplineType::Suspension, now - Duration::days(10), Some(now + Duration::days(80)));
+        assert!(attorney.impose_discipline(suspension, now));
+
+        assert_eq!(attorney.status, AttorneyStatus::Suspended);
+        assert!(!attorney.is_in_good_standing());
+        assert!(!attorney.has_ecf_privileges());
+    }
+
+    #[test]
+    fn test_expired_suspension_restores_good_standing() {
+        let now = Utc::now();
+        let mut attorney = admitted_attorney();
+        let suspension = discipline(DisciplineType::Suspension, now - Duration::days(90), Some(now - Duration::days(1)));
+
+        assert!(!attorney.impose_discipline(suspension.clone(), now), "already served");
+        assert_eq!(attorney.status, AttorneyStatus::Active);
+        assert!(attorney.is_in_good_standing());
+
+        // Recorded while in effect, the suspension lifts once its term ends
+        let mut attorney = admitted_attorney();
+        assert!(attorney.impose_discipline(suspension, now - Duration::days(30)));
+        assert_eq!(attorney.status, AttorneyStatus::Suspended);
+        assert_eq!(attorney.status_at(now), AttorneyStatus::Active);
+        assert!(attorney.is_in_good_standing());
+        assert!(attorney.has_ecf_privileges());
+    }
+
+    #[test]
+    fn test_reprimand_and_future_suspension_do_not_change_status() {
+        let now = Utc::now();
+        let mut attorney = admitted_attorney();
+
+        assert!(!attorney.impose_discipline(discipline(DisciplineType::Reprimand, now, None), now));
+        let pending = discipline(DisciplineType::Suspension, now + Duration::days(14), None);
+        assert!(!attorney.impose_discipline(pending, now));
+
+        assert_eq!(attorney.status, AttorneyStatus::Active);
+        assert!(attorney.is_in_good_standing());
+        assert!(attorney.practice_restriction(now + Duration::days(15)).is_some());
+    }
+
+    #[test]
+    fn test_suspension_does_not_replace_disbarment() {
+        let now = Utc::now();
+        let mut attorney = admitted_attorney();
+        attorney.impose_discipline(discipline(DisciplineType::Disbarment, now - Duration::days(1), None), now);
+        attorney.impose_discipline(discipline(DisciplineType::InterimSuspension, now, None), now);
+        assert_eq!(attorney.status, AttorneyStatus::Disbarred);
+    }
+
+    #[test]
+    fn test_reinstatement_lifts_restrictions_and_records_order() {
+        let now = Utc::now();
+        let mut attorney = admitted_attorney();
+        let request = ReinstatementRequest {
+            order_number: "R-2025-3".to_string(),
+            jurisdiction: "New York".to_string(),
+            description: None,
+        };
+        assert!(attorney.reinstate(request.clone(), now).is_err(), "nothing to reinstate from");
+
+        attorney.impose_discipline(discipline(DisciplineType::Disbarment, now - Duration::days(400), None), now);
+        let order = attorney.reinstate(request, now).unwrap();
+
+        assert_eq!(order.action_type, DisciplineType::Reinstatement);
+        assert_eq!(order.case_number.as_deref(), Some("R-2025-3"));
+        assert_eq!(attorney.status, AttorneyStatus::Active);
+        assert!(attorney.practice_restriction(now).is_none());
+        assert!(attorney.is_in_good_standing());
+    }
+}
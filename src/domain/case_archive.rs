@@ -0,0 +1,91 @@
+//! Cold-storage archives of closed cases
+//!
+//! A case in a terminal status can be moved out of the live store into a
+//! single archive document holding the case and every record filed under
+//! it. A small tombstone stays behind so lookups of the case can point to
+//! the archive instead of reporting it missing.
+
+use crate::domain::criminal_case::{CaseStatus, CriminalCase};
+use crate::domain::deadline::Deadline;
+use crate::domain::docket::{CalendarEntry, DocketEntry, SpeedyTrialClock};
+use crate::domain::judge::CaseAssignment;
+use crate::domain::opinion::JudicialOpinion;
+use crate::domain::order::JudicialOrder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A closed case and everything filed under it
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CaseArchive {
+    pub case_id: Uuid,
+    pub case_number: String,
+    pub archived_at: DateTime<Utc>,
+    pub case: CriminalCase,
+    pub docket_entries: Vec<DocketEntry>,
+    pub calendar_events: Vec<CalendarEntry>,
+    pub speedy_trial: Option<SpeedyTrialClock>,
+    pub deadlines: Vec<Deadline>,
+    /// Current and superseded judge assignments
+    pub assignments: Vec<CaseAssignment>,
+    pub orders: Vec<JudicialOrder>,
+    /// Opinions stay in the live store as published law; only references are kept
+    pub opinions: Vec<OpinionReference>,
+}
+
+/// Pointer to an opinion issued in an archived case
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OpinionReference {
+    pub opinion_id: String,
+    pub title: String,
+    pub is_published: bool,
+}
+
+impl From<&JudicialOpinion> for OpinionReference {
+    fn from(opinion: &JudicialOpinion) -> Self {
+        Self {
+            opinion_id: opinion.id.clone(),
+            title: opinion.title.clone(),
+            is_published: opinion.is_published,
+        }
+    }
+}
+
+/// What remains in the live store for an archived case
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CaseTombstone {
+    pub case_id: Uuid,
+    pub case_number: String,
+    pub status: CaseStatus,
+    pub archived_at: DateTime<Utc>,
+}
+
+impl CaseTombstone {
+    pub fn from_archive(archive: &CaseArchive) -> Self {
+        Self {
+            case_id: archive.case_id,
+            case_number: archive.case_number.clone(),
+            status: archive.case.status.clone(),
+            archived_at: archive.archived_at,
+        }
+    }
+
+    /// Path of the endpoint returning the archive
+    pub fn archive_path(&self) -> String {
+        format!("/api/cases/{}/archive", self.case_id)
+    }
+}
+
+impl CaseArchive {
+    /// Check a case may be archived: only dismissed or sentenced cases are closed
+    pub fn ensure_archivable(case: &CriminalCase) -> Result<(), String> {
+        if case.is_active() {
+            return Err(format!(
+                "Case {} is {:?}; only dismissed or sentenced cases can be archived",
+                case.case_number, case.status
+            ));
+        }
+        Ok(())
+    }
+}
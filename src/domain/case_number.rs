@@ -0,0 +1,254 @@
+//! Server-generated case numbers
+//!
+//! New cases are numbered `{YY}-{type}-{sequence}`, e.g. `24-cr-00123`, with
+//! the sequence counting up per district, year and case type. Districts can
+//! change the sequence padding and the abbreviation used for each case type
+//! through their configuration overrides.
+//!
+//! Clients write the same number many ways (`24-CR-1`, `24-cr-00001`,
+//! `2024-cr-1`), so cases are stored and looked up under a canonical form
+//! with a lowercase type and the sequence padded to the district's width.
+//! The number as first written is kept on the case for display.
+
+use crate::domain::config::Configuration;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+/// Case type used when a request doesn't name one
+pub const DEFAULT_CASE_TYPE: &str = "criminal";
+
+/// How a district formats generated case numbers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "padding": 5,
+    "type_abbreviations": {"criminal": "cr", "magistrate": "mj", "miscellaneous": "mc", "petty_offense": "po"}
+}))]
+pub struct CaseNumberFormat {
+    /// Minimum digits in the sequence, zero-padded
+    pub padding: usize,
+    /// Abbreviation for each case type, e.g. `criminal` => `cr`
+    pub type_abbreviations: BTreeMap<String, String>,
+}
+
+impl Default for CaseNumberFormat {
+    fn default() -> Self {
+        let type_abbreviations = [
+            ("criminal", "cr"),
+            ("magistrate", "mj"),
+            ("miscellaneous", "mc"),
+            ("petty_offense", "po"),
+        ]
+        .into_iter()
+        .map(|(case_type, abbreviation)| (case_type.to_string(), abbreviation.to_string()))
+        .collect();
+
+        Self { padding: 5, type_abbreviations }
+    }
+}
+
+impl CaseNumberFormat {
+    /// Configuration path holding the sequence padding
+    pub const PADDING_CONFIG_PATH: &'static str = "case_numbering.padding";
+    /// Configuration path holding the map of case type abbreviations
+    pub const TYPES_CONFIG_PATH: &'static str = "case_numbering.types";
+    /// Allowed range of the sequence padding
+    pub const PADDING_RANGE: std::ops::RangeInclusive<usize> = 1..=10;
+
+    /// The district's format, with defaults for anything unset or invalid
+    pub fn from_config(config: &Configuration) -> Self {
+        let mut format = Self::default();
+        if let Some(padding) = config
+            .get_i64(Self::PADDING_CONFIG_PATH)
+            .and_then(|p| usize::try_from(p).ok())
+            .filter(|p| Self::PADDING_RANGE.contains(p))
+        {
+            format.padding = padding;
+        }
+        if let Some(types) = config.get(Self::TYPES_CONFIG_PATH).and_then(|v| v.as_object()) {
+            for (case_type, abbreviation) in types {
+                if let Some(abbreviation) = abbreviation.as_str().filter(|a| Self::valid_abbreviation(a)) {
+                    format.type_abbreviations.insert(case_type.clone(), abbreviation.to_string());
+                }
+            }
+        }
+        format
+    }
+
+    /// Check the padding and abbreviations a district wants to use
+    pub fn validate(&self) -> Result<(), String> {
+        if !Self::PADDING_RANGE.contains(&self.padding) {
+            return Err(format!(
+                "padding must be between {} and {}, got {}",
+                Self::PADDING_RANGE.start(),
+                Self::PADDING_RANGE.end(),
+                self.padding
+            ));
+        }
+        if self.type_abbreviations.is_empty() {
+            return Err("type_abbreviations must name at least one case type".to_string());
+        }
+        for (case_type, abbreviation) in &self.type_abbreviations {
+            if case_type.trim().is_empty() {
+                return Err("case types must not be empty".to_string());
+            }
+            if !Self::valid_abbreviation(abbreviation) {
+                return Err(format!(
+                    "abbreviation for {} must be 1 to 4 letters, got {:?}",
+                    case_type, abbreviation
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether an abbreviation is 1 to 4 ASCII letters
+    pub fn valid_abbreviation(abbreviation: &str) -> bool {
+        (1..=4).contains(&abbreviation.len()) && abbreviation.chars().all(|c| c.is_ascii_alphabetic())
+    }
+
+    /// Abbreviation for a case type, if the district numbers that type
+    pub fn abbreviation(&self, case_type: &str) -> Option<&str> {
+        self.type_abbreviations.get(case_type).map(String::as_str)
+    }
+
+    /// Case number for the given year, type abbreviation and sequence
+    pub fn format(&self, year: i32, abbreviation: &str, sequence: u64) -> String {
+        format!(
+            "{:02}-{}-{:0width$}",
+            year.rem_euclid(100),
+            abbreviation,
+            sequence,
+            width = self.padding
+        )
+    }
+
+    /// Canonical form of a case number written any of the accepted ways
+    ///
+    /// Accepts an optional office prefix (`1:`), a two or four digit year,
+    /// a type of 1 to 4 letters in any case and a sequence with or without
+    /// leading zeros, with spaces anywhere.
+    pub fn normalize(&self, case_number: &str) -> Result<String, String> {
+        let invalid = || {
+            format!(
+                "Case number must look like YY-type-sequence, e.g. 24-cr-00123, got '{}'",
+                case_number.trim()
+            )
+        };
+
+        let compact: String = case_number.chars().filter(|c| !c.is_whitespace()).collect();
+        let (office, number) = match compact.split_once(':') {
+            Some((office, number)) => (Some(office), number),
+            None => (None, compact.as_str()),
+        };
+        if office.is_some_and(|o| !(1..=2).contains(&o.len()) || !o.chars().all(|c| c.is_ascii_digit())) {
+            return Err(invalid());
+        }
+
+        let parts: Vec<&str> = number.split('-').collect();
+        let [year, abbreviation, sequence] = parts[..] else {
+            return Err(invalid());
+        };
+        if !matches!(year.len(), 2 | 4) || !year.chars().all(|c| c.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        if !Self::valid_abbreviation(abbreviation) {
+            return Err(invalid());
+        }
+        if !(1..=20).contains(&sequence.len()) || !sequence.chars().all(|c| c.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let year: i32 = year.parse().map_err(|_| invalid())?;
+        let sequence: u64 = sequence.parse().map_err(|_| invalid())?;
+        if sequence == 0 {
+            return Err(invalid());
+        }
+
+        let canonical = self.format(year, &abbreviation.to_ascii_lowercase(), sequence);
+        Ok(match office {
+            Some(office) => format!("{}:{}", office, canonical),
+            None => canonical,
+        })
+    }
+
+    /// Key of the sequence counter for a district, year and type abbreviation
+    pub fn counter_key(district_code: &str, year: i32, abbreviation: &str) -> String {
+        format!("{}:{:02}:{}", district_code.to_uppercase(), year.rem_euclid(100), abbreviation.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_default_format() {
+        let format = CaseNumberFormat::default();
+        assert_eq!(format.format(2024, "cr", 123), "24-cr-00123");
+        assert_eq!(format.format(2030, "mj", 1), "30-mj-00001");
+        assert_eq!(format.format(2024, "cr", 1234567), "24-cr-1234567");
+        assert_eq!(format.abbreviation("criminal"), Some("cr"));
+        assert_eq!(format.abbreviation("civil"), None);
+    }
+
+    #[test]
+    fn test_from_config_applies_overrides() {
+        let mut config = Configuration::new();
+        config.set(CaseNumberFormat::PADDING_CONFIG_PATH, json!(3));
+        config.set(CaseNumberFormat::TYPES_CONFIG_PATH, json!({"criminal": "CR", "petty_offense": "not valid"}));
+
+        let format = CaseNumberFormat::from_config(&config);
+        assert_eq!(format.padding, 3);
+        assert_eq!(format.abbreviation("criminal"), Some("CR"));
+        assert_eq!(format.abbreviation("petty_offense"), Some("po"), "invalid abbreviation keeps the default");
+        assert_eq!(format.format(2024, "CR", 7), "24-CR-007");
+
+        config.set(CaseNumberFormat::PADDING_CONFIG_PATH, json!(0));
+        assert_eq!(CaseNumberFormat::from_config(&config).padding, 5);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(CaseNumberFormat::default().validate().is_ok());
+
+        let format = CaseNumberFormat { padding: 11, ..CaseNumberFormat::default() };
+        assert!(format.validate().unwrap_err().contains("padding"));
+
+        let mut format = CaseNumberFormat::default();
+        format.type_abbreviations.insert("criminal".to_string(), "cr-1".to_string());
+        assert!(format.validate().unwrap_err().contains("criminal"));
+
+        let mut format = CaseNumberFormat::default();
+        format.type_abbreviations.clear();
+        assert!(format.validate().is_err());
+    }
+
+    #[test]
+    fn test_normalize_equivalent_inputs() {
+        let format = CaseNumberFormat::default();
+        for input in ["24-cr-00001", "24-CR-1", "24-Cr-001", " 24 - cr - 1 ", "2024-cr-00001", "24-cr-0000000001"] {
+            assert_eq!(format.normalize(input).unwrap(), "24-cr-00001", "{}", input);
+        }
+        assert_eq!(format.normalize("1:24-CR-101").unwrap(), "1:24-cr-00101");
+        assert_eq!(format.normalize("24-mj-1234567").unwrap(), "24-mj-1234567");
+
+        let format = CaseNumberFormat { padding: 3, ..CaseNumberFormat::default() };
+        assert_eq!(format.normalize("24-CR-00001").unwrap(), "24-cr-001");
+    }
+
+    #[test]
+    fn test_normalize_rejects_other_shapes() {
+        let format = CaseNumberFormat::default();
+        for input in [
+            "", "24-cr", "24-cr-1-2", "4-cr-1", "24-crim1-1", "24-cr-0", "24-cr-x1", "ab-cr-1", "123:24-cr-1", "CV-2024-001",
+        ] {
+            assert!(format.normalize(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_counter_key() {
+        assert_eq!(CaseNumberFormat::counter_key("sdny", 2024, "CR"), "SDNY:24:cr");
+    }
+}
@@ -4,7 +4,7 @@
 //! between attorney, order, document, and case domains. Each type is
 //! defined once here to eliminate duplication and ensure consistency.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -240,3 +240,52 @@ impl std::fmt::Display for MotionStatus {
         }
     }
 }
+
+/// Inclusive date range that statistics are computed over
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct DateRange {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+impl DateRange {
+    /// Span covered when a request gives no range
+    pub const DEFAULT_MONTHS: u32 = 12;
+    /// Longest span a request may ask for
+    pub const MAX_YEARS: u32 = 10;
+
+    /// Build a range, rejecting one that is reversed or longer than [`Self::MAX_YEARS`]
+    pub fn new(from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Self, String> {
+        if from > to {
+            return Err("`from` must not be after `to`".to_string());
+        }
+        let earliest = to
+            .checked_sub_months(chrono::Months::new(Self::MAX_YEARS * 12))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        if from < earliest {
+            return Err(format!("Date range cannot span more than {} years", Self::MAX_YEARS));
+        }
+        Ok(Self { from, to })
+    }
+
+    /// The [`Self::DEFAULT_MONTHS`] months ending at `to`
+    pub fn ending_at(to: DateTime<Utc>) -> Self {
+        let from = to
+            .checked_sub_months(chrono::Months::new(Self::DEFAULT_MONTHS))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        Self { from, to }
+    }
+
+    /// Federal fiscal year `year`: October 1 of the prior year through September 30
+    pub fn fiscal_year(year: i32) -> Option<Self> {
+        let from = NaiveDate::from_ymd_opt(year - 1, 10, 1)?.and_time(NaiveTime::MIN).and_utc();
+        let end_of_day = NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999)?;
+        let to = NaiveDate::from_ymd_opt(year, 9, 30)?.and_time(end_of_day).and_utc();
+        Some(Self { from, to })
+    }
+
+    /// Whether `at` falls within the range, both ends included
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        self.from <= at && at <= self.to
+    }
+}
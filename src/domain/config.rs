@@ -3,9 +3,10 @@
 //! Supports base configuration from TOML with district and judge-level overrides
 //! stored in KV stores. Configuration values are flexible using serde_json::Value.
 
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use utoipa::ToSchema;
 
 /// Configuration structure that matches our TOML format
@@ -250,6 +251,78 @@ impl Configuration {
         }
     }
 
+    /// Remove a value by dot-notation path, returning it if it was set
+    pub fn remove(&mut self, path: &str) -> Option<Value> {
+        match path.rsplit_once('.') {
+            None => self.values.remove(path),
+            Some((parent, key)) => match self.get_mut(parent)? {
+                Value::Object(map) => map.remove(key),
+                _ => None,
+            },
+        }
+    }
+
+    fn get_mut(&mut self, path: &str) -> Option<&mut Value> {
+        let mut parts = path.split('.');
+        let mut current = self.values.get_mut(parts.next()?)?;
+        for part in parts {
+            current = current.as_object_mut()?.get_mut(part)?;
+        }
+        Some(current)
+    }
+
+    /// Keys whose values differ between this configuration and `other`
+    ///
+    /// Keys are dot-notation paths to leaf values; arrays compare as a
+    /// whole. A null leaf counts as unset, so a key set to null in `other`
+    /// shows as removed.
+    pub fn diff(&self, other: &Configuration) -> BTreeMap<String, ConfigValueChange> {
+        let before = self.leaves();
+        let after = other.leaves();
+
+        let mut changes = BTreeMap::new();
+        for (path, from) in &before {
+            match after.get(path) {
+                Some(to) if to == from => {}
+                Some(to) => {
+                    changes.insert(path.clone(), ConfigValueChange::changed((*from).clone(), (*to).clone()));
+                }
+                None => {
+                    changes.insert(path.clone(), ConfigValueChange::removed((*from).clone()));
+                }
+            }
+        }
+        for (path, to) in &after {
+            if !before.contains_key(path) {
+                changes.insert(path.clone(), ConfigValueChange::added((*to).clone()));
+            }
+        }
+        changes
+    }
+
+    /// Every non-null leaf value keyed by its dot-notation path
+    fn leaves(&self) -> BTreeMap<String, &Value> {
+        fn collect<'a>(prefix: String, value: &'a Value, out: &mut BTreeMap<String, &'a Value>) {
+            match value {
+                Value::Null => {}
+                Value::Object(map) => {
+                    for (key, child) in map {
+                        collect(format!("{}.{}", prefix, key), child, out);
+                    }
+                }
+                _ => {
+                    out.insert(prefix, value);
+                }
+            }
+        }
+
+        let mut out = BTreeMap::new();
+        for (key, value) in &self.values {
+            collect(key.clone(), value, &mut out);
+        }
+        out
+    }
+
     /// Merge another configuration into this one (other takes precedence)
     pub fn merge(&mut self, other: &Configuration) {
         for (key, value) in &other.values {
@@ -355,6 +428,31 @@ impl Default for ConfigOverride {
     }
 }
 
+/// What produced a version of a district's overrides
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigVersionAction {
+    /// Overrides saved before history was kept, recorded on the first change
+    Baseline,
+    Update,
+    Clear,
+    Rollback,
+}
+
+/// A saved version of a district's configuration overrides
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConfigOverrideVersion {
+    /// Starts at 1 and increases with every change
+    pub version: u32,
+    pub saved_at: chrono::DateTime<chrono::Utc>,
+    pub action: ConfigVersionAction,
+    /// The version a rollback restored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restored_from: Option<u32>,
+    /// The overrides in effect after the change
+    pub overrides: ConfigOverride,
+}
+
 /// Metadata about a configuration (for API responses)
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ConfigMetadata {
@@ -383,9 +481,81 @@ pub struct ConfigResponse {
     pub metadata: ConfigMetadata,
 }
 
+/// How a single configuration key changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigChangeKind {
+    /// Not set before, set after
+    Added,
+    /// Set before, not set after
+    Removed,
+    /// Set before and after to different values
+    Changed,
+}
+
+/// One key's value before and after a change; `from` is null for added
+/// keys and `to` is null for removed ones
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ConfigValueChange {
+    pub kind: ConfigChangeKind,
+    pub from: Option<Value>,
+    pub to: Option<Value>,
+}
+
+impl ConfigValueChange {
+    pub fn added(to: Value) -> Self {
+        Self { kind: ConfigChangeKind::Added, from: None, to: Some(to) }
+    }
+
+    pub fn removed(from: Value) -> Self {
+        Self { kind: ConfigChangeKind::Removed, from: Some(from), to: None }
+    }
+
+    pub fn changed(from: Value, to: Value) -> Self {
+        Self { kind: ConfigChangeKind::Changed, from: Some(from), to: Some(to) }
+    }
+}
+
+/// What a configuration preview would change (for API responses)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConfigDiff {
+    /// Changed keys by dot-notation path
+    pub changes: BTreeMap<String, ConfigValueChange>,
+    /// Metadata about the previewed configuration
+    #[serde(rename = "_metadata")]
+    pub metadata: ConfigMetadata,
+}
+
+/// A day a district's court is closed outside the federal holiday calendar
+///
+/// Closures are treated like holidays when rolling deadlines forward,
+/// counting notice days and finding open calendar slots.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CourtClosure {
+    pub date: NaiveDate,
+    /// Why the court is closed, e.g. weather or an emergency
+    pub reason: String,
+}
+
+impl CourtClosure {
+    /// Add a closure to a district's list, keeping it sorted by date
+    ///
+    /// Fails if the date is already on the list.
+    pub fn insert(closures: &mut Vec<CourtClosure>, closure: CourtClosure) -> Result<(), String> {
+        match closures.binary_search_by_key(&closure.date, |c| c.date) {
+            Ok(_) => Err(format!("{} is already a court closure", closure.date)),
+            Err(position) => {
+                closures.insert(position, closure);
+                Ok(())
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Datelike;
     use serde_json::json;
 
     #[test]
@@ -399,6 +569,44 @@ mod tests {
         assert_eq!(config.get_bool("case_assignment.auto_generate_pdf"), Some(true));
     }
 
+    #[test]
+    fn test_remove_values() {
+        let mut config = Configuration::new();
+        config.set("deadlines.default_response_days", json!(21));
+        config.set("timezone", json!("America/New_York"));
+
+        assert_eq!(config.remove("deadlines.default_response_days"), Some(json!(21)));
+        assert_eq!(config.remove("timezone"), Some(json!("America/New_York")));
+        assert_eq!(config.remove("deadlines.missing.path"), None);
+        assert!(config.get("deadlines.default_response_days").is_none());
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_changed_keys() {
+        let mut current = Configuration::new();
+        current.set("deadlines.default_response_days", json!(21));
+        current.set("deadlines.appeal_window_days", json!(30));
+        current.set("case_assignment.strategy", json!("random_draw"));
+        current.set("holidays", json!(["2024-01-01"]));
+
+        let mut preview = current.clone();
+        preview.set("deadlines.default_response_days", json!(14));
+        preview.set("case_assignment.strategy", Value::Null);
+        preview.set("features.core.basic_docket", json!(true));
+        preview.set("deadlines.appeal_window_days", json!(30));
+
+        let diff = current.diff(&preview);
+
+        assert_eq!(diff.len(), 3);
+        assert_eq!(diff["deadlines.default_response_days"], ConfigValueChange::changed(json!(21), json!(14)));
+        assert_eq!(diff["case_assignment.strategy"], ConfigValueChange::removed(json!("random_draw")));
+        assert_eq!(diff["features.core.basic_docket"], ConfigValueChange::added(json!(true)));
+        assert!(current.diff(&current).is_empty());
+
+        let serialized = serde_json::to_value(&diff["features.core.basic_docket"]).unwrap();
+        assert_eq!(serialized, json!({"kind": "added", "from": null, "to": true}));
+    }
+
     #[test]
     fn test_merge_configurations() {
         let mut base = Configuration::new();
@@ -426,4 +634,21 @@ mod tests {
 
         assert_eq!(config.get_bool("workflow.auto_docket_on_filing"), Some(true));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_closures_stay_sorted_and_reject_duplicates() {
+        let closure = |day: u32, reason: &str| CourtClosure {
+            date: NaiveDate::from_ymd_opt(2025, 2, day).unwrap(),
+            reason: reason.to_string(),
+        };
+
+        let mut closures = Vec::new();
+        CourtClosure::insert(&mut closures, closure(20, "Snow")).unwrap();
+        CourtClosure::insert(&mut closures, closure(3, "Power outage")).unwrap();
+        assert_eq!(closures.iter().map(|c| c.date.day()).collect::<Vec<_>>(), vec![3, 20]);
+
+        let err = CourtClosure::insert(&mut closures, closure(20, "Ice")).unwrap_err();
+        assert!(err.contains("2025-02-20"), "{}", err);
+        assert_eq!(closures.len(), 2);
+    }
+}
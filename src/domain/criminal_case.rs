@@ -3,13 +3,15 @@
 //! This module defines the core business logic for criminal case management
 //! in the federal court system.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Months, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use utoipa::ToSchema;
 
+use super::appeal::Appeal;
 use super::common::MotionStatus;
 use super::defendant::{CreateDefendantRequest, Defendant, PleaType};
+use super::magistrate_consent::{ConsentStatus, ConsentSummary, MagistrateConsent, GOVERNMENT_PARTY};
 use super::docket::{DocketEntry, DocketEntryType, DocketService, SpeedyTrialClock, ExcludableDelay};
 
 /// Status of a federal criminal case
@@ -79,8 +81,11 @@ pub enum CrimeType {
 pub struct CriminalCase {
     /// Unique case identifier
     pub id: Uuid,
-    /// Federal case number (e.g., "SDNY:26-CR-00123-JMS")
+    /// Federal case number in canonical form (e.g., "26-cr-00123")
     pub case_number: String,
+    /// Case number as first written (e.g., "26-CR-123"), when it differs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_case_number: Option<String>,
     /// Case title/description
     pub title: String,
     /// Detailed description of the case
@@ -135,6 +140,18 @@ pub struct CriminalCase {
     /// CVRA victims associated with this case
     #[serde(default)]
     pub victims: Vec<super::victim::Victim>,
+    /// Parties' consent to a magistrate judge presiding (28 U.S.C. § 636(c))
+    #[serde(default)]
+    pub magistrate_consent: MagistrateConsent,
+    /// Statute of limitations on the offense, when its date and statute are known
+    #[serde(default)]
+    pub limitations: Option<LimitationsTracker>,
+    /// Notices of appeal filed in the case
+    #[serde(default)]
+    pub appeals: Vec<Appeal>,
+    /// Whether an appeal awaits its mandate; blocks deletion and archival
+    #[serde(default)]
+    pub has_pending_appeal: bool,
 }
 
 /// A note added to a case
@@ -288,15 +305,15 @@ impl CriminalCase {
         crime_type: CrimeType,
         district_code: String,
         assigned_judge_id: Option<Uuid>,
-        judge_initials: &str,
+        case_number: String,
         location: String,
     ) -> Self {
         let now = Utc::now();
-        let case_number = Self::generate_case_number(&district_code, "CR", judge_initials);
 
         Self {
             id: Uuid::new_v4(),
             case_number,
+            display_case_number: None,
             title,
             description,
             crime_type,
@@ -320,17 +337,21 @@ impl CriminalCase {
             seal_reason: None,
             speedy_trial: None,
             victims: Vec::new(),
+            magistrate_consent: MagistrateConsent::default(),
+            limitations: None,
+            appeals: Vec::new(),
+            has_pending_appeal: false,
         }
     }
 
-    /// Generate a federal case number
-    ///
-    /// Format: `{district}:{yy}-{type}-{seq}-{judge_initials}`
-    /// Example: `SDNY:26-CR-00123-JMS`
-    fn generate_case_number(district_code: &str, case_type: &str, judge_initials: &str) -> String {
-        let year = Utc::now().format("%y");
-        let seq: u32 = rand::random::<u32>() % 100000;
-        format!("{}:{}-{}-{:05}-{}", district_code, year, case_type, seq, judge_initials)
+    /// Keep the case number as the client wrote it for display
+    pub fn set_display_case_number(&mut self, display: String) {
+        self.display_case_number = (display != self.case_number).then_some(display);
+    }
+
+    /// Case number to show: as first written, or the canonical form
+    pub fn display_number(&self) -> &str {
+        self.display_case_number.as_deref().unwrap_or(&self.case_number)
     }
 
     /// Add a defendant to the case from a request
@@ -635,6 +656,51 @@ impl CriminalCase {
         id
     }
 
+    /// Parties whose consent a magistrate judge needs: the government and
+    /// every defendant
+    pub fn consent_parties(&self) -> Vec<String> {
+        std::iter::once(GOVERNMENT_PARTY.to_string())
+            .chain(self.defendants.iter().map(|d| d.name.clone()))
+            .collect()
+    }
+
+    /// Record a party's consent to magistrate judge jurisdiction
+    ///
+    /// The party must be the government, a defendant, or already have a
+    /// consent record; the soliciting docket entry must be on this case.
+    pub fn record_magistrate_consent(
+        &mut self,
+        party: &str,
+        status: ConsentStatus,
+        solicitation_entry_id: Option<Uuid>,
+    ) -> Result<ConsentSummary, String> {
+        let parties = self.consent_parties();
+        let party = party.trim();
+        let party = parties
+            .iter()
+            .chain(self.magistrate_consent.parties.iter().map(|p| &p.party))
+            .find(|p| p.eq_ignore_ascii_case(party))
+            .cloned()
+            .ok_or_else(|| {
+                format!("{} is not a party to this case; expected one of: {}", party, parties.join(", "))
+            })?;
+        if let Some(entry_id) = solicitation_entry_id {
+            if !self.docket_entries.iter().any(|e| e.id == entry_id) {
+                return Err(format!("Docket entry {} not found in this case", entry_id));
+            }
+        }
+
+        let now = Utc::now();
+        self.magistrate_consent.record(&party, status, solicitation_entry_id, now);
+        self.updated_at = now;
+        Ok(self.magistrate_consent.summary(&parties))
+    }
+
+    /// Whether every party has consented to a magistrate judge
+    pub fn magistrate_consent_summary(&self) -> ConsentSummary {
+        self.magistrate_consent.summary(&self.consent_parties())
+    }
+
     /// Send a notification to a victim
     pub fn send_victim_notification(
         &mut self,
@@ -664,4 +730,311 @@ impl CriminalCase {
         self.updated_at = Utc::now();
         Ok(id)
     }
+
+    // ========================================================================
+    // Appeal Methods
+    // ========================================================================
+
+    /// Recompute `has_pending_appeal` from the case's appeals
+    pub fn refresh_pending_appeal(&mut self) {
+        self.has_pending_appeal = self.appeals.iter().any(|a| a.status.is_pending());
+    }
+}
+
+// ============================================================================
+// Statute of limitations
+// ============================================================================
+
+/// Years to indict in when no statute-specific period applies
+pub const DEFAULT_LIMITATIONS_YEARS: u32 = 5;
+
+/// Statute setting the default period
+pub const DEFAULT_LIMITATIONS_AUTHORITY: &str = "18 U.S.C. § 3282";
+
+/// Limitations period for an offense
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitationsPeriod {
+    /// Years to indict in; `None` when the offense may be indicted at any time
+    pub years: Option<u32>,
+    /// Statute setting the period
+    pub authority: &'static str,
+}
+
+/// An offense statute whose limitations period differs from the default
+struct LimitationsException {
+    title: u32,
+    section: &'static str,
+    period: LimitationsPeriod,
+}
+
+const fn exception(title: u32, section: &'static str, years: Option<u32>, authority: &'static str) -> LimitationsException {
+    LimitationsException { title, section, period: LimitationsPeriod { years, authority } }
+}
+
+/// Common exceptions to the five-year period, by title and section of the
+/// U.S. Code
+const LIMITATIONS_EXCEPTIONS: &[LimitationsException] = &[
+    // Capital offenses may be indicted at any time
+    exception(18, "794", None, "18 U.S.C. § 3281"),
+    exception(18, "1111", None, "18 U.S.C. § 3281"),
+    exception(18, "1114", None, "18 U.S.C. § 3281"),
+    exception(18, "2381", None, "18 U.S.C. § 3281"),
+    // Sexual exploitation and abuse of children
+    exception(18, "1591", None, "18 U.S.C. § 3299"),
+    exception(18, "2241", None, "18 U.S.C. § 3299"),
+    exception(18, "2242", None, "18 U.S.C. § 3299"),
+    exception(18, "2251", None, "18 U.S.C. § 3299"),
+    exception(18, "2252", None, "18 U.S.C. § 3299"),
+    exception(18, "2252A", None, "18 U.S.C. § 3299"),
+    exception(18, "2422", None, "18 U.S.C. § 3299"),
+    exception(18, "2423", None, "18 U.S.C. § 3299"),
+    // Terrorism
+    exception(18, "2332b", Some(8), "18 U.S.C. § 3286(a)"),
+    exception(18, "2339A", Some(8), "18 U.S.C. § 3286(a)"),
+    exception(18, "2339B", Some(8), "18 U.S.C. § 3286(a)"),
+    // Offenses against financial institutions
+    exception(18, "656", Some(10), "18 U.S.C. § 3293"),
+    exception(18, "657", Some(10), "18 U.S.C. § 3293"),
+    exception(18, "1014", Some(10), "18 U.S.C. § 3293"),
+    exception(18, "1344", Some(10), "18 U.S.C. § 3293"),
+    // Securities and commodities fraud
+    exception(18, "1348", Some(6), "18 U.S.C. § 3301"),
+    // Arson
+    exception(18, "844", Some(10), "18 U.S.C. § 3295"),
+    // Naturalization and passport offenses
+    exception(18, "1425", Some(10), "18 U.S.C. § 3291"),
+    exception(18, "1426", Some(10), "18 U.S.C. § 3291"),
+    exception(18, "1542", Some(10), "18 U.S.C. § 3291"),
+    // Theft of major artwork
+    exception(18, "668", Some(20), "18 U.S.C. § 3294"),
+    // Tax offenses
+    exception(26, "7201", Some(6), "26 U.S.C. § 6531"),
+    exception(26, "7202", Some(6), "26 U.S.C. § 6531"),
+    exception(26, "7203", Some(6), "26 U.S.C. § 6531"),
+    exception(26, "7206", Some(6), "26 U.S.C. § 6531"),
+];
+
+impl LimitationsPeriod {
+    /// Period for an offense statute such as "18 U.S.C. § 1344(1)"; statutes
+    /// without an exception get the five-year default
+    pub fn for_statute(statute: &str) -> Self {
+        statute_section(statute)
+            .and_then(|(title, section)| {
+                LIMITATIONS_EXCEPTIONS
+                    .iter()
+                    .find(|e| e.title == title && e.section.eq_ignore_ascii_case(&section))
+            })
+            .map_or(
+                Self { years: Some(DEFAULT_LIMITATIONS_YEARS), authority: DEFAULT_LIMITATIONS_AUTHORITY },
+                |e| e.period,
+            )
+    }
+}
+
+/// Title and section of a U.S. Code citation, ignoring any subsection
+fn statute_section(statute: &str) -> Option<(u32, String)> {
+    let cleaned: String = statute
+        .chars()
+        .filter(|c| !matches!(c, '.' | '§' | ','))
+        .collect::<String>()
+        .to_lowercase();
+    let words: Vec<&str> = cleaned.split_whitespace().collect();
+    let code = words.iter().position(|w| *w == "usc")?;
+    let title = words.get(code.checked_sub(1)?)?.parse().ok()?;
+    let section = words.get(code + 1)?.split('(').next()?.to_string();
+    (!section.is_empty()).then_some((title, section))
+}
+
+/// A period the limitations clock was suspended, e.g. while the defendant
+/// was a fugitive (18 U.S.C. § 3290) or evidence was sought abroad (§ 3292)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TollingPeriod {
+    pub id: Uuid,
+    pub reason: String,
+    pub start_date: DateTime<Utc>,
+    /// End of the suspension, exclusive
+    pub end_date: DateTime<Utc>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Statute of limitations on a case's offense
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LimitationsTracker {
+    pub offense_date: DateTime<Utc>,
+    pub offense_statute: String,
+    /// Years to indict in; none when the offense may be indicted at any time
+    pub period_years: Option<u32>,
+    /// Statute setting the period
+    pub authority: String,
+    /// Suspensions of the clock, in the order recorded
+    #[serde(default)]
+    pub tolling: Vec<TollingPeriod>,
+    /// Last moment to indict, extended by tolling; none without a period
+    pub deadline: Option<DateTime<Utc>>,
+    /// Jurisdictional deadline kept in step with `deadline`
+    #[serde(default)]
+    pub deadline_id: Option<Uuid>,
+}
+
+impl LimitationsTracker {
+    /// Start tracking an offense committed on `offense_date`
+    pub fn new(offense_date: DateTime<Utc>, offense_statute: String) -> Self {
+        let period = LimitationsPeriod::for_statute(&offense_statute);
+        let mut tracker = Self {
+            offense_date,
+            offense_statute,
+            period_years: period.years,
+            authority: period.authority.to_string(),
+            tolling: Vec::new(),
+            deadline: None,
+            deadline_id: None,
+        };
+        tracker.deadline = tracker.calculate_deadline();
+        tracker
+    }
+
+    /// Days the clock was suspended, counting days covered by overlapping
+    /// periods once
+    pub fn tolled_days(&self) -> i64 {
+        let mut periods: Vec<(DateTime<Utc>, DateTime<Utc>)> =
+            self.tolling.iter().map(|t| (t.start_date, t.end_date)).collect();
+        periods.sort();
+
+        let mut total = Duration::zero();
+        let mut covered_until: Option<DateTime<Utc>> = None;
+        for (start, end) in periods {
+            let start = covered_until.map_or(start, |until| start.max(until));
+            if end > start {
+                total += end - start;
+                covered_until = Some(end);
+            }
+        }
+        total.num_days()
+    }
+
+    /// Offense date plus the period plus tolled days
+    pub fn calculate_deadline(&self) -> Option<DateTime<Utc>> {
+        let years = self.period_years?;
+        let untolled = self.offense_date.checked_add_months(Months::new(years * 12))?;
+        Some(untolled + Duration::days(self.tolled_days()))
+    }
+
+    /// Record a suspension of the clock and move the deadline
+    pub fn toll(
+        &mut self,
+        reason: String,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> Result<&TollingPeriod, String> {
+        if reason.trim().is_empty() {
+            return Err("A tolling reason is required".to_string());
+        }
+        if end_date <= start_date {
+            return Err("Tolling period must end after it starts".to_string());
+        }
+        if start_date < self.offense_date {
+            return Err("Tolling period cannot start before the offense date".to_string());
+        }
+        let Some(deadline) = self.deadline else {
+            return Err(format!("{} sets no limitations period to toll", self.authority));
+        };
+        if start_date >= deadline {
+            return Err("Limitations period had already run when the tolling period began".to_string());
+        }
+
+        self.tolling.push(TollingPeriod {
+            id: Uuid::new_v4(),
+            reason,
+            start_date,
+            end_date,
+            recorded_at: Utc::now(),
+        });
+        self.deadline = self.calculate_deadline();
+        Ok(self.tolling.last().expect("tolling period was just recorded"))
+    }
+
+    /// Whole days until the deadline, negative once it has passed
+    pub fn days_remaining(&self, now: DateTime<Utc>) -> Option<i64> {
+        self.deadline.map(|deadline| (deadline - now).num_days())
+    }
+}
+
+/// A case whose limitations deadline nears without an indictment
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LimitationsAtRisk {
+    pub case_id: Uuid,
+    pub case_number: String,
+    pub title: String,
+    pub offense_statute: String,
+    pub deadline: DateTime<Utc>,
+    pub days_remaining: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn date(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_default_period_is_five_years() {
+        let tracker = LimitationsTracker::new(date(2021, 3, 15), "18 U.S.C. § 1343".to_string());
+        assert_eq!(tracker.period_years, Some(5));
+        assert_eq!(tracker.authority, DEFAULT_LIMITATIONS_AUTHORITY);
+        assert_eq!(tracker.deadline, Some(date(2026, 3, 15)));
+
+        // Unrecognized citations get the default too
+        assert_eq!(LimitationsPeriod::for_statute("wire fraud").years, Some(5));
+    }
+
+    #[test]
+    fn test_statute_overrides() {
+        let bank_fraud = LimitationsTracker::new(date(2020, 2, 29), "18 U.S.C. § 1344(2)".to_string());
+        assert_eq!(bank_fraud.period_years, Some(10));
+        assert_eq!(bank_fraud.authority, "18 U.S.C. § 3293");
+        assert_eq!(bank_fraud.deadline, Some(date(2030, 2, 28)));
+
+        assert_eq!(LimitationsPeriod::for_statute("26 USC 7201").years, Some(6));
+        assert_eq!(LimitationsPeriod::for_statute("18 u.s.c. 2252a(a)(2)").authority, "18 U.S.C. § 3299");
+
+        let capital = LimitationsTracker::new(date(2001, 1, 1), "18 U.S.C. § 1111".to_string());
+        assert_eq!(capital.period_years, None);
+        assert_eq!(capital.deadline, None);
+    }
+
+    #[test]
+    fn test_tolling_extends_deadline() {
+        let mut tracker = LimitationsTracker::new(date(2021, 1, 1), "18 U.S.C. § 1343".to_string());
+
+        tracker.toll("Defendant a fugitive".to_string(), date(2022, 1, 1), date(2022, 1, 31)).unwrap();
+        assert_eq!(tracker.tolled_days(), 30);
+        assert_eq!(tracker.deadline, Some(date(2026, 1, 31)));
+
+        // Overlapping periods count each day once
+        tracker.toll("Official request for foreign evidence".to_string(), date(2022, 1, 21), date(2022, 2, 10)).unwrap();
+        assert_eq!(tracker.tolled_days(), 40);
+        assert_eq!(tracker.deadline, Some(date(2026, 2, 10)));
+
+        // A separate period adds in full
+        tracker.toll("Defendant a fugitive".to_string(), date(2023, 6, 1), date(2023, 6, 11)).unwrap();
+        assert_eq!(tracker.tolled_days(), 50);
+        assert_eq!(tracker.deadline, Some(date(2026, 2, 20)));
+        assert_eq!(tracker.days_remaining(date(2026, 2, 10)), Some(10));
+    }
+
+    #[test]
+    fn test_invalid_tolling_is_rejected() {
+        let mut tracker = LimitationsTracker::new(date(2021, 1, 1), "18 U.S.C. § 1343".to_string());
+        assert!(tracker.toll("Fugitive".to_string(), date(2022, 2, 1), date(2022, 1, 1)).is_err());
+        assert!(tracker.toll("Fugitive".to_string(), date(2020, 1, 1), date(2022, 1, 1)).is_err());
+        assert!(tracker.toll(" ".to_string(), date(2022, 1, 1), date(2022, 2, 1)).is_err());
+        assert!(tracker.toll("Fugitive".to_string(), date(2026, 1, 2), date(2026, 2, 1)).is_err());
+        assert!(tracker.tolling.is_empty());
+
+        let mut capital = LimitationsTracker::new(date(2021, 1, 1), "18 U.S.C. § 1111".to_string());
+        assert!(capital.toll("Fugitive".to_string(), date(2022, 1, 1), date(2022, 2, 1)).is_err());
+    }
 }
@@ -0,0 +1,413 @@
+//! Daily courtroom calendar
+//!
+//! The calendar clerks print each morning: one day's proceedings, grouped by
+//! courtroom and in start order, with the case and presiding judge named.
+//! Idle time between proceedings is noted and double-bookings are flagged,
+//! whether two proceedings share a courtroom or a judge.
+
+use super::docket::{CalendarEntry, EventStatus};
+use super::weekly_docket::WeeklyDocket;
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+/// Case number and caption shown for a proceeding
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CaseCaption {
+    pub case_number: String,
+    pub caption: String,
+}
+
+/// One proceeding on the daily calendar
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DailyCalendarEvent {
+    pub event_id: Uuid,
+    pub case_id: Uuid,
+    /// `None` when the case is not on file in the district
+    pub case_number: Option<String>,
+    pub caption: Option<String>,
+    pub judge_id: Uuid,
+    /// `None` when the judge is not on the district's roster
+    pub judge_name: Option<String>,
+    /// Event type, e.g. `status_conference`
+    pub event_type: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// Start in district local time (HH:MM)
+    pub local_time: String,
+    pub duration_minutes: u32,
+    pub description: String,
+    pub status: String,
+    /// Idle minutes since the courtroom's previous proceeding ended; `None`
+    /// for the first proceeding and for one that overlaps an earlier one
+    pub gap_minutes: Option<i64>,
+    /// Overlaps another proceeding in the same courtroom or before the same judge
+    pub conflict: bool,
+    /// Proceedings it overlaps, including any in other courtrooms
+    pub conflicts_with: Vec<Uuid>,
+}
+
+/// A courtroom's proceedings for the day
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CourtroomCalendar {
+    pub courtroom: String,
+    /// Proceedings, earliest first
+    pub events: Vec<DailyCalendarEvent>,
+    pub conflict_count: usize,
+}
+
+/// One day's proceedings, grouped by courtroom
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DailyCalendar {
+    pub date: NaiveDate,
+    pub weekday: String,
+    /// District offset from UTC used for the day's bounds and local times
+    pub utc_offset_hours: i32,
+    /// Courtroom the calendar was limited to, if any
+    pub courtroom: Option<String>,
+    pub event_count: usize,
+    pub conflict_count: usize,
+    /// Courtrooms in name order; only those with proceedings unless one was asked for
+    pub courtrooms: Vec<CourtroomCalendar>,
+}
+
+/// Column widths of the printed calendar
+const TIME_WIDTH: usize = 8;
+const CASE_NUMBER_WIDTH: usize = 16;
+const CAPTION_WIDTH: usize = 30;
+const PROCEEDING_WIDTH: usize = 22;
+const JUDGE_WIDTH: usize = 20;
+const COLUMN_WIDTHS: [usize; 5] = [TIME_WIDTH, CASE_NUMBER_WIDTH, CAPTION_WIDTH, PROCEEDING_WIDTH, JUDGE_WIDTH];
+const LINE_WIDTH: usize = TIME_WIDTH + CASE_NUMBER_WIDTH + CAPTION_WIDTH + PROCEEDING_WIDTH + JUDGE_WIDTH + 8;
+
+impl DailyCalendar {
+    /// UTC bounds of the local day, end exclusive
+    pub fn day_range(date: NaiveDate, offset: FixedOffset) -> (DateTime<Utc>, DateTime<Utc>) {
+        (
+            WeeklyDocket::local_midnight(date, offset),
+            WeeklyDocket::local_midnight(date + Duration::days(1), offset),
+        )
+    }
+
+    /// Build the calendar for `date` from the day's events
+    ///
+    /// Cancelled events are left off. Conflicts are found among every
+    /// courtroom's events before `courtroom` narrows the calendar, so a judge
+    /// booked in two rooms at once is flagged in both.
+    pub fn build(
+        date: NaiveDate,
+        offset: FixedOffset,
+        courtroom: Option<&str>,
+        events: &[CalendarEntry],
+        captions: &HashMap<Uuid, CaseCaption>,
+        judge_names: &HashMap<Uuid, String>,
+    ) -> Self {
+        let (day_start, day_end) = Self::day_range(date, offset);
+        let mut day: Vec<&CalendarEntry> = events
+            .iter()
+            .filter(|e| !matches!(e.status, EventStatus::Cancelled))
+            .filter(|e| e.scheduled_date >= day_start && e.scheduled_date < day_end)
+            .collect();
+        day.sort_by_key(|e| (e.scheduled_date, event_end(e)));
+
+        let mut rooms: BTreeMap<String, Vec<DailyCalendarEvent>> = BTreeMap::new();
+        if let Some(courtroom) = courtroom {
+            rooms.insert(courtroom.to_string(), Vec::new());
+        }
+
+        for event in &day {
+            if courtroom.is_some_and(|c| c != event.courtroom) {
+                continue;
+            }
+            let conflicts_with: Vec<Uuid> = day
+                .iter()
+                .filter(|other| other.id != event.id)
+                .filter(|other| other.courtroom == event.courtroom || other.judge_id == event.judge_id)
+                .filter(|other| other.scheduled_date < event_end(event) && event.scheduled_date < event_end(other))
+                .map(|other| other.id)
+                .collect();
+
+            let room = rooms.entry(event.courtroom.clone()).or_default();
+            let previous_end = room.iter().map(|e| e.end).max();
+            let caption = captions.get(&event.case_id);
+            room.push(DailyCalendarEvent {
+                event_id: event.id,
+                case_id: event.case_id,
+                case_number: caption.map(|c| c.case_number.clone()),
+                caption: caption.map(|c| c.caption.clone()),
+                judge_id: event.judge_id,
+                judge_name: judge_names.get(&event.judge_id).cloned(),
+                event_type: event.event_type.config_key(),
+                start: event.scheduled_date,
+                end: event_end(event),
+                local_time: event.scheduled_date.with_timezone(&offset).format("%H:%M").to_string(),
+                duration_minutes: event.duration_minutes,
+                description: event.description.clone(),
+                status: snake_name(&event.status),
+                gap_minutes: previous_end
+                    .filter(|end| *end <= event.scheduled_date)
+                    .map(|end| (event.scheduled_date - end).num_minutes()),
+                conflict: !conflicts_with.is_empty(),
+                conflicts_with,
+            });
+        }
+
+        let courtrooms: Vec<CourtroomCalendar> = rooms
+            .into_iter()
+            .map(|(courtroom, events)| CourtroomCalendar {
+                conflict_count: events.iter().filter(|e| e.conflict).count(),
+                courtroom,
+                events,
+            })
+            .collect();
+
+        Self {
+            date,
+            weekday: date.format("%A").to_string(),
+            utc_offset_hours: offset.local_minus_utc() / 3600,
+            courtroom: courtroom.map(str::to_string),
+            event_count: courtrooms.iter().map(|c| c.events.len()).sum(),
+            conflict_count: courtrooms.iter().map(|c| c.conflict_count).sum(),
+            courtrooms,
+        }
+    }
+
+    /// The calendar in the traditional fixed-width printed layout
+    pub fn render_text(&self) -> String {
+        let mut out = String::new();
+        for title in [
+            "UNITED STATES DISTRICT COURT".to_string(),
+            "DAILY CALENDAR".to_string(),
+            self.date.format("%A, %B %-d, %Y").to_string(),
+        ] {
+            out.push_str(format!("{:^width$}", title, width = LINE_WIDTH).trim_end());
+            out.push('\n');
+        }
+
+        if self.courtrooms.is_empty() {
+            out.push_str("\nNo proceedings scheduled.\n");
+        }
+
+        for room in &self.courtrooms {
+            out.push_str(&format!("\nCOURTROOM {}\n", room.courtroom));
+            out.push_str(&row(["TIME", "CASE NUMBER", "CAPTION", "PROCEEDING", "JUDGE"]));
+            let rules = COLUMN_WIDTHS.map(|width| "-".repeat(width));
+            out.push_str(&row(rules.each_ref().map(String::as_str)));
+
+            if room.events.is_empty() {
+                out.push_str("No proceedings scheduled.\n");
+            }
+            for event in &room.events {
+                if let Some(gap) = event.gap_minutes.filter(|gap| *gap > 0) {
+                    out.push_str(&format!("{:w$}  -- open {} min --\n", "", gap, w = TIME_WIDTH));
+                }
+                let time = event
+                    .start
+                    .with_timezone(&FixedOffset::east_opt(self.utc_offset_hours * 3600).unwrap_or(utc()))
+                    .format("%I:%M %p")
+                    .to_string();
+                let proceeding = title_case(&event.event_type);
+                out.push_str(&row([
+                    &time,
+                    event.case_number.as_deref().unwrap_or("(not on file)"),
+                    event.caption.as_deref().unwrap_or(""),
+                    &proceeding,
+                    event.judge_name.as_deref().unwrap_or("(unknown)"),
+                ]));
+                if event.conflict {
+                    out.push_str(&format!("{:w$}  ** DOUBLE-BOOKED **\n", "", w = TIME_WIDTH));
+                }
+            }
+        }
+        out
+    }
+}
+
+fn event_end(event: &CalendarEntry) -> DateTime<Utc> {
+    event.scheduled_date + Duration::minutes(event.duration_minutes as i64)
+}
+
+fn utc() -> FixedOffset {
+    FixedOffset::east_opt(0).expect("zero offset is valid")
+}
+
+/// One line of the printed calendar, each field cut to its column
+fn row(fields: [&str; 5]) -> String {
+    let cells: Vec<String> = fields
+        .iter()
+        .zip(COLUMN_WIDTHS)
+        .map(|(field, width)| format!("{:width$}", field.chars().take(width).collect::<String>(), width = width))
+        .collect();
+    format!("{}\n", cells.join("  ").trim_end())
+}
+
+/// `status_conference` becomes `Status Conference`
+pub(crate) fn title_case(snake: &str) -> String {
+    snake
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|c| c.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Serialized snake_case name of a unit enum variant
+fn snake_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::docket::CalendarEventType;
+    use chrono::TimeZone;
+
+    fn event(courtroom: &str, judge_id: Uuid, hour: u32, minute: u32, duration_minutes: u32) -> CalendarEntry {
+        CalendarEntry {
+            id: Uuid::new_v4(),
+            case_id: Uuid::new_v4(),
+            judge_id,
+            event_type: CalendarEventType::StatusConference,
+            scheduled_date: Utc.with_ymd_and_hms(2024, 6, 3, hour, minute, 0).unwrap(),
+            duration_minutes,
+            courtroom: courtroom.to_string(),
+            description: String::new(),
+            participants: vec![],
+            court_reporter: None,
+            is_public: true,
+            call_time: None,
+            actual_start: None,
+            actual_end: None,
+            status: EventStatus::Scheduled,
+            notes: String::new(),
+            minutes: None,
+        }
+    }
+
+    fn june_3() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 3).unwrap()
+    }
+
+    #[test]
+    fn test_events_group_by_courtroom_in_start_order() {
+        let (judge_a, judge_b) = (Uuid::new_v4(), Uuid::new_v4());
+        let late = event("12A", judge_a, 15, 0, 30);
+        let early = event("12A", judge_a, 9, 0, 60);
+        let other_room = event("9B", judge_b, 10, 0, 30);
+        let mut cancelled = event("12A", judge_a, 11, 0, 30);
+        cancelled.status = EventStatus::Cancelled;
+        let mut next_day = event("12A", judge_a, 9, 0, 30);
+        next_day.scheduled_date += Duration::days(1);
+
+        let captions = HashMap::from([(
+            early.case_id,
+            CaseCaption { case_number: "1:24-cr-00001".to_string(), caption: "United States v. Smith".to_string() },
+        )]);
+        let judges = HashMap::from([(judge_a, "Hon. Ada Park".to_string())]);
+        let events = [late.clone(), other_room.clone(), cancelled, next_day, early.clone()];
+
+        let calendar = DailyCalendar::build(june_3(), utc(), None, &events, &captions, &judges);
+
+        assert_eq!(calendar.weekday, "Monday");
+        assert_eq!(calendar.event_count, 3);
+        assert_eq!(calendar.conflict_count, 0);
+        let rooms: Vec<&str> = calendar.courtrooms.iter().map(|c| c.courtroom.as_str()).collect();
+        assert_eq!(rooms, vec!["12A", "9B"]);
+
+        let room = &calendar.courtrooms[0];
+        assert_eq!(room.events.iter().map(|e| e.event_id).collect::<Vec<_>>(), vec![early.id, late.id]);
+        assert_eq!(room.events[0].case_number.as_deref(), Some("1:24-cr-00001"));
+        assert_eq!(room.events[0].judge_name.as_deref(), Some("Hon. Ada Park"));
+        assert_eq!(room.events[0].gap_minutes, None);
+        assert_eq!(room.events[1].gap_minutes, Some(300), "10:00 to 15:00 open");
+        assert_eq!(calendar.courtrooms[1].events[0].judge_name, None);
+    }
+
+    #[test]
+    fn test_overlapping_events_are_flagged_in_pairs() {
+        let (judge_a, judge_b) = (Uuid::new_v4(), Uuid::new_v4());
+        let first = event("12A", judge_a, 9, 0, 60);
+        let second = event("12A", judge_b, 9, 30, 30);
+        let back_to_back = event("12A", judge_b, 10, 0, 30);
+        // Judge A can't also be in 9B at 9:45
+        let elsewhere = event("9B", judge_a, 9, 45, 30);
+        let events = [first.clone(), second.clone(), back_to_back.clone(), elsewhere.clone()];
+
+        let calendar = DailyCalendar::build(june_3(), utc(), None, &events, &HashMap::new(), &HashMap::new());
+        let flagged = |id: Uuid| {
+            calendar.courtrooms.iter().flat_map(|c| &c.events).find(|e| e.event_id == id).unwrap().clone()
+        };
+
+        assert!(flagged(first.id).conflict);
+        assert_eq!(flagged(first.id).conflicts_with.len(), 2);
+        assert!(flagged(second.id).conflict);
+        assert_eq!(flagged(second.id).gap_minutes, None, "starts before the first ends");
+        assert!(!flagged(back_to_back.id).conflict, "starting as another ends is no conflict");
+        assert_eq!(flagged(back_to_back.id).gap_minutes, Some(0));
+        assert_eq!(flagged(elsewhere.id).conflicts_with, vec![first.id]);
+        assert_eq!(calendar.conflict_count, 3);
+    }
+
+    #[test]
+    fn test_courtroom_filter_keeps_conflicts_from_other_rooms() {
+        let judge = Uuid::new_v4();
+        let here = event("12A", judge, 9, 0, 60);
+        let there = event("9B", judge, 9, 30, 30);
+
+        let calendar =
+            DailyCalendar::build(june_3(), utc(), Some("12A"), &[here, there], &HashMap::new(), &HashMap::new());
+        assert_eq!(calendar.courtrooms.len(), 1);
+        assert!(calendar.courtrooms[0].events[0].conflict);
+
+        let empty = DailyCalendar::build(june_3(), utc(), Some("3C"), &[], &HashMap::new(), &HashMap::new());
+        assert_eq!(empty.courtrooms.len(), 1, "an asked-for courtroom is listed even when empty");
+    }
+
+    #[test]
+    fn test_day_follows_district_local_time() {
+        let eastern = WeeklyDocket::offset(-4).unwrap();
+        // 02:00 UTC on June 4 is 22:00 June 3 Eastern
+        let mut evening = event("12A", Uuid::new_v4(), 2, 0, 30);
+        evening.scheduled_date += Duration::days(1);
+
+        let calendar = DailyCalendar::build(june_3(), eastern, None, &[evening], &HashMap::new(), &HashMap::new());
+        assert_eq!(calendar.event_count, 1);
+        assert_eq!(calendar.courtrooms[0].events[0].local_time, "22:00");
+    }
+
+    #[test]
+    fn test_text_layout_has_fixed_columns() {
+        let judge = Uuid::new_v4();
+        let first = event("12A", judge, 9, 0, 60);
+        let captions = HashMap::from([(
+            first.case_id,
+            CaseCaption {
+                case_number: "1:24-cr-00001".to_string(),
+                caption: "United States v. Bartholomew Montgomery-Smithson".to_string(),
+            },
+        )]);
+        let judges = HashMap::from([(judge, "Hon. Ada Park".to_string())]);
+        let events = [first, event("12A", Uuid::new_v4(), 9, 30, 30), event("12A", judge, 13, 0, 30)];
+
+        let text = DailyCalendar::build(june_3(), utc(), None, &events, &captions, &judges).render_text();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert!(lines[0].trim() == "UNITED STATES DISTRICT COURT");
+        assert!(lines[2].trim() == "Monday, June 3, 2024");
+        assert!(text.contains("\nCOURTROOM 12A\n"));
+        let first_row = lines.iter().find(|l| l.starts_with("09:00 AM")).unwrap();
+        assert_eq!(first_row.find("1:24-cr-00001"), Some(TIME_WIDTH + 2));
+        assert!(first_row.contains("United States v. Bartholomew M  "), "caption cut to its column");
+        assert!(first_row.contains("Status Conference"));
+        assert_eq!(text.matches("** DOUBLE-BOOKED **").count(), 2);
+        assert!(text.contains("-- open 180 min --"));
+    }
+}
@@ -25,10 +25,20 @@ pub struct Deadline {
     pub completion_date: Option<DateTime<Utc>>,
     pub extension_requests: Vec<ExtensionRequest>,
     pub reminders_sent: Vec<DateTime<Utc>>,
+    /// Due date changes made by granted extensions and dependency
+    /// recalculations, oldest first
+    #[serde(default)]
+    pub due_date_history: Vec<DueDateChange>,
+    /// Deadline this one is counted from, e.g. the response brief for a reply
+    #[serde(default)]
+    pub depends_on: Option<Uuid>,
+    /// Days after the parent's completion or due date that this deadline falls
+    #[serde(default)]
+    pub offset_days: Option<i64>,
 }
 
 /// Types of deadlines in federal court
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum DeadlineType {
     // Filing Deadlines
@@ -40,6 +50,7 @@ pub enum DeadlineType {
 
     // Appeal Deadlines
     NoticeOfAppeal,
+    RecordOnAppeal,
     AppellateBrief,
     ReplyBrief,
     PetitionForRehearing,
@@ -48,6 +59,7 @@ pub enum DeadlineType {
     SpeedyTrial,
     Sentencing,
     PretrialMotions,
+    StatuteOfLimitations,
 
     // Discovery Deadlines
     InitialDisclosures,
@@ -82,6 +94,13 @@ pub enum DeadlineStatus {
     Moot,
 }
 
+impl DeadlineStatus {
+    /// Whether a deadline in this status needs immediate attention
+    pub fn is_urgent(&self) -> bool {
+        matches!(self, DeadlineStatus::Overdue | DeadlineStatus::Due | DeadlineStatus::Approaching)
+    }
+}
+
 /// Extension request for a deadline
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ExtensionRequest {
@@ -106,6 +125,194 @@ pub enum ExtensionStatus {
     Withdrawn,
 }
 
+/// Record of a due date moved by a granted extension or by a change to the
+/// deadline it depends on
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DueDateChange {
+    pub previous_due_date: DateTime<Utc>,
+    pub new_due_date: DateTime<Utc>,
+    /// Extension whose grant moved the date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extension_id: Option<Uuid>,
+    /// Parent deadline whose new due or completion date moved this one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recalculated_from: Option<Uuid>,
+    /// When the ruling or recalculation took effect
+    pub ruling_date: DateTime<Utc>,
+}
+
+impl Deadline {
+    /// Due date after any granted extensions
+    ///
+    /// Granted extensions normally move `due_date` directly; this also covers
+    /// records whose extension was granted before the date was adjusted.
+    pub fn effective_due_date(&self) -> DateTime<Utc> {
+        self.extension_requests
+            .iter()
+            .filter(|e| e.status == ExtensionStatus::Granted)
+            .max_by_key(|e| e.ruling_date)
+            .map_or(self.due_date, |e| e.new_due_date.max(self.due_date))
+    }
+
+    /// Record a ruling on one of this deadline's extension requests
+    ///
+    /// Granting moves the due date to the extension's new date, appends a
+    /// history entry, and recomputes the status. Any other ruling leaves the
+    /// deadline itself untouched.
+    pub fn rule_on_extension(
+        &mut self,
+        extension_id: Uuid,
+        status: ExtensionStatus,
+        order_text: Option<String>,
+        ruling_date: DateTime<Utc>,
+    ) -> Result<(), String> {
+        let extension = self
+            .extension_requests
+            .iter_mut()
+            .find(|e| e.id == extension_id)
+            .ok_or_else(|| "Extension request not found".to_string())?;
+
+        if extension.status != ExtensionStatus::Pending {
+            return Err("Extension request has already been ruled on".to_string());
+        }
+
+        extension.status = status.clone();
+        extension.ruling_date = Some(ruling_date);
+        extension.order_text = order_text;
+        let new_due_date = extension.new_due_date;
+
+        if status == ExtensionStatus::Granted {
+            self.due_date_history.push(DueDateChange {
+                previous_due_date: self.due_date,
+                new_due_date,
+                extension_id: Some(extension_id),
+                recalculated_from: None,
+                ruling_date,
+            });
+            self.due_date = new_due_date;
+            DeadlineMonitor::update_deadline_statuses(std::slice::from_mut(self), ruling_date);
+        }
+
+        Ok(())
+    }
+
+    /// Date dependents are counted from: completion once completed, otherwise
+    /// the due date
+    pub fn dependency_anchor(&self) -> DateTime<Utc> {
+        match (self.status == DeadlineStatus::Completed, self.completion_date) {
+            (true, Some(completed)) => completed,
+            _ => self.due_date,
+        }
+    }
+
+    /// Whether the deadline no longer moves: completed, waived or moot
+    pub fn is_closed(&self) -> bool {
+        matches!(self.status, DeadlineStatus::Completed | DeadlineStatus::Waived | DeadlineStatus::Moot)
+    }
+}
+
+/// Dependency chains between the deadlines of a case
+///
+/// A dependent deadline falls `offset_days` calendar days after its parent's
+/// completion or due date, rolled forward to the next court day. When the
+/// parent moves, every transitive dependent is moved with it.
+pub struct DeadlineChain;
+
+impl DeadlineChain {
+    /// Due date of a deadline `offset_days` after `parent`
+    pub fn due_date_after(parent: &Deadline, offset_days: i64, closures: &[NaiveDate]) -> DateTime<Utc> {
+        DeadlineCalculator::calculate_deadline(parent.dependency_anchor(), offset_days, true, 0, closures)
+    }
+
+    /// Check that `deadline_id` may depend on `parent_id` without a cycle
+    pub fn check_dependency(deadline_id: Uuid, parent_id: Uuid, deadlines: &[Deadline]) -> Result<(), String> {
+        if deadline_id == parent_id {
+            return Err(format!("Deadline {} cannot depend on itself", deadline_id));
+        }
+
+        let mut path = vec![parent_id];
+        let mut current = parent_id;
+        while let Some(next) = deadlines.iter().find(|d| d.id == current).and_then(|d| d.depends_on) {
+            if next == deadline_id {
+                let chain: Vec<String> = path.iter().map(Uuid::to_string).collect();
+                return Err(format!(
+                    "Deadline {} cannot depend on {}: that would form a cycle ({} -> {} -> {})",
+                    deadline_id,
+                    parent_id,
+                    deadline_id,
+                    chain.join(" -> "),
+                    deadline_id
+                ));
+            }
+            if path.contains(&next) {
+                break;
+            }
+            path.push(next);
+            current = next;
+        }
+        Ok(())
+    }
+
+    /// Transitive dependents of a deadline, nearest first
+    pub fn dependents(deadline_id: Uuid, deadlines: &[Deadline]) -> Vec<&Deadline> {
+        let mut found: Vec<&Deadline> = Vec::new();
+        let mut queue = std::collections::VecDeque::from([deadline_id]);
+        while let Some(current) = queue.pop_front() {
+            for dependent in deadlines.iter().filter(|d| d.depends_on == Some(current)) {
+                if dependent.id != deadline_id && !found.iter().any(|f| f.id == dependent.id) {
+                    found.push(dependent);
+                    queue.push_back(dependent.id);
+                }
+            }
+        }
+        found
+    }
+
+    /// Move every transitive dependent of `parent_id` to its recalculated due
+    /// date, recording each move in the dependent's history
+    ///
+    /// Closed dependents keep their dates. Returns the ids of the deadlines
+    /// that moved, nearest first.
+    pub fn recalculate_dependents(
+        parent_id: Uuid,
+        deadlines: &mut [Deadline],
+        closures: &[NaiveDate],
+        now: DateTime<Utc>,
+    ) -> Vec<Uuid> {
+        let order: Vec<Uuid> = Self::dependents(parent_id, deadlines).iter().map(|d| d.id).collect();
+        let mut moved = Vec::new();
+
+        for id in order {
+            let Some(index) = deadlines.iter().position(|d| d.id == id) else {
+                continue;
+            };
+            let (Some(depends_on), Some(offset)) = (deadlines[index].depends_on, deadlines[index].offset_days) else {
+                continue;
+            };
+            let Some(parent) = deadlines.iter().find(|d| d.id == depends_on) else {
+                continue;
+            };
+            let new_due_date = Self::due_date_after(parent, offset, closures);
+
+            let dependent = &mut deadlines[index];
+            if dependent.is_closed() || dependent.due_date == new_due_date {
+                continue;
+            }
+            dependent.due_date_history.push(DueDateChange {
+                previous_due_date: dependent.due_date,
+                new_due_date,
+                extension_id: None,
+                recalculated_from: Some(depends_on),
+                ruling_date: now,
+            });
+            dependent.due_date = new_due_date;
+            DeadlineMonitor::update_deadline_statuses(std::slice::from_mut(dependent), now);
+            moved.push(id);
+        }
+        moved
+    }
+}
+
 /// Federal Rules of Civil/Criminal Procedure
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FederalRule {
@@ -117,41 +324,63 @@ pub struct FederalRule {
     pub service_adds_days: i64, // Additional days for service method
 }
 
+/// A rule mapping a triggering event to a computed deadline
+///
+/// Districts hold their own copy (seeded at tenant initialization) so local
+/// practice can adjust periods without code changes.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DeadlineRule {
+    /// Stable identifier, e.g. `frcp-12-a-1-a`
+    pub id: String,
+    pub triggering_event: String,
+    pub deadline_type: DeadlineType,
+    pub days: i64,
+    /// Count calendar days rather than court days
+    pub count_weekends: bool,
+    /// Extra days added for the method of service
+    pub service_days: i64,
+    pub applicable_rule: String,
+    pub description: String,
+    pub responsible_party: String,
+    pub is_jurisdictional: bool,
+    pub is_extendable: bool,
+}
+
 /// Service for calculating deadlines
 pub struct DeadlineCalculator;
 
 impl DeadlineCalculator {
     /// Calculate a deadline based on federal rules
+    ///
+    /// `closures` are district closure dates, treated like federal holidays.
     pub fn calculate_deadline(
         triggering_date: DateTime<Utc>,
         days: i64,
         count_weekends: bool,
         add_service_days: i64,
+        closures: &[NaiveDate],
     ) -> DateTime<Utc> {
         let mut deadline = if count_weekends {
             triggering_date + Duration::days(days + add_service_days)
         } else {
-            Self::add_court_days(triggering_date, days + add_service_days)
+            Self::add_court_days(triggering_date, days + add_service_days, closures)
         };
 
-        // If deadline falls on weekend/holiday, move to next court day
-        deadline = Self::next_court_day(deadline);
+        // If deadline falls on weekend/holiday/closure, move to next court day
+        deadline = Self::next_court_day(deadline, closures);
 
         deadline
     }
 
-    /// Add court days (excluding weekends and holidays)
-    fn add_court_days(start: DateTime<Utc>, days: i64) -> DateTime<Utc> {
+    /// Add court days (excluding weekends, holidays and closures)
+    fn add_court_days(start: DateTime<Utc>, days: i64, closures: &[NaiveDate]) -> DateTime<Utc> {
         let mut current = start;
         let mut days_remaining = days;
 
         while days_remaining > 0 {
             current = current + Duration::days(1);
 
-            // Skip weekends
-            if current.weekday() != Weekday::Sat &&
-               current.weekday() != Weekday::Sun &&
-               !Self::is_federal_holiday(&current) {
+            if Self::is_court_day(&current, closures) {
                 days_remaining -= 1;
             }
         }
@@ -159,19 +388,25 @@ impl DeadlineCalculator {
         current
     }
 
-    /// Get next court day if date falls on weekend/holiday
-    fn next_court_day(date: DateTime<Utc>) -> DateTime<Utc> {
+    /// Get next court day if date falls on weekend/holiday/closure
+    fn next_court_day(date: DateTime<Utc>, closures: &[NaiveDate]) -> DateTime<Utc> {
         let mut result = date;
 
-        while result.weekday() == Weekday::Sat ||
-              result.weekday() == Weekday::Sun ||
-              Self::is_federal_holiday(&result) {
+        while !Self::is_court_day(&result, closures) {
             result = result + Duration::days(1);
         }
 
         result
     }
 
+    /// Check that a date is not a weekend, federal holiday or closure
+    fn is_court_day(date: &DateTime<Utc>, closures: &[NaiveDate]) -> bool {
+        date.weekday() != Weekday::Sat &&
+            date.weekday() != Weekday::Sun &&
+            !Self::is_federal_holiday(date) &&
+            !closures.contains(&date.date_naive())
+    }
+
     /// Check if date is a federal holiday
     fn is_federal_holiday(date: &DateTime<Utc>) -> bool {
         let year = date.year();
@@ -241,78 +476,108 @@ impl DeadlineCalculator {
         date
     }
 
-    /// Calculate common FRCP deadlines
-    pub fn calculate_frcp_deadlines(triggering_event: &str, triggering_date: DateTime<Utc>) -> Vec<Deadline> {
-        let mut deadlines = Vec::new();
-
-        match triggering_event {
-            "complaint_filed" => {
-                // 21 days to answer (FRCP 12)
-                deadlines.push(Deadline {
-                    id: Uuid::new_v4(),
-                    case_id: Uuid::new_v4(),
-                    deadline_type: DeadlineType::Answer,
-                    due_date: Self::calculate_deadline(triggering_date, 21, false, 0),
-                    triggering_event: triggering_event.to_string(),
-                    triggering_date,
-                    applicable_rule: "FRCP 12(a)(1)(A)".to_string(),
-                    description: "Deadline to file answer to complaint".to_string(),
-                    responsible_party: "Defendant".to_string(),
-                    is_jurisdictional: false,
-                    is_extendable: true,
-                    status: DeadlineStatus::Pending,
-                    completion_date: None,
-                    extension_requests: Vec::new(),
-                    reminders_sent: Vec::new(),
-                });
-            }
-            "scheduling_order" => {
-                // Various discovery deadlines
-                deadlines.push(Deadline {
-                    id: Uuid::new_v4(),
-                    case_id: Uuid::new_v4(),
-                    deadline_type: DeadlineType::InitialDisclosures,
-                    due_date: Self::calculate_deadline(triggering_date, 14, false, 0),
-                    triggering_event: triggering_event.to_string(),
-                    triggering_date,
-                    applicable_rule: "FRCP 26(a)(1)".to_string(),
-                    description: "Initial disclosures due".to_string(),
-                    responsible_party: "All parties".to_string(),
-                    is_jurisdictional: false,
-                    is_extendable: true,
-                    status: DeadlineStatus::Pending,
-                    completion_date: None,
-                    extension_requests: Vec::new(),
-                    reminders_sent: Vec::new(),
-                });
-            }
-            "judgment_entered" => {
-                // 30 days to appeal (FRAP 4)
-                deadlines.push(Deadline {
-                    id: Uuid::new_v4(),
-                    case_id: Uuid::new_v4(),
-                    deadline_type: DeadlineType::NoticeOfAppeal,
-                    due_date: Self::calculate_deadline(triggering_date, 30, false, 0),
-                    triggering_event: triggering_event.to_string(),
+    /// Calculate the deadlines produced by `rules` for a triggering event
+    pub fn calculate_from_rules(
+        rules: &[DeadlineRule],
+        triggering_event: &str,
+        triggering_date: DateTime<Utc>,
+        closures: &[NaiveDate],
+    ) -> Vec<Deadline> {
+        rules
+            .iter()
+            .filter(|rule| rule.triggering_event == triggering_event)
+            .map(|rule| Deadline {
+                id: Uuid::new_v4(),
+                case_id: Uuid::new_v4(),
+                deadline_type: rule.deadline_type.clone(),
+                due_date: Self::calculate_deadline(
                     triggering_date,
-                    applicable_rule: "FRAP 4(a)(1)(A)".to_string(),
-                    description: "Deadline to file notice of appeal".to_string(),
-                    responsible_party: "Appellant".to_string(),
-                    is_jurisdictional: true,
-                    is_extendable: false,
-                    status: DeadlineStatus::Pending,
-                    completion_date: None,
-                    extension_requests: Vec::new(),
-                    reminders_sent: Vec::new(),
-                });
-            }
-            _ => {}
-        }
+                    rule.days,
+                    rule.count_weekends,
+                    rule.service_days,
+                    closures,
+                ),
+                triggering_event: triggering_event.to_string(),
+                triggering_date,
+                applicable_rule: rule.applicable_rule.clone(),
+                description: rule.description.clone(),
+                responsible_party: rule.responsible_party.clone(),
+                is_jurisdictional: rule.is_jurisdictional,
+                is_extendable: rule.is_extendable,
+                status: DeadlineStatus::Pending,
+                completion_date: None,
+                extension_requests: Vec::new(),
+                reminders_sent: Vec::new(),
+                due_date_history: Vec::new(),
+                depends_on: None,
+                offset_days: None,
+            })
+            .collect()
+    }
 
-        deadlines
+    /// FRCP/FRCrP/FRAP deadline rules every district starts with
+    pub fn default_rule_set() -> Vec<DeadlineRule> {
+        let rule = |id: &str,
+                    triggering_event: &str,
+                    deadline_type: DeadlineType,
+                    days: i64,
+                    count_weekends: bool,
+                    applicable_rule: &str,
+                    description: &str,
+                    responsible_party: &str,
+                    is_jurisdictional: bool| DeadlineRule {
+            id: id.to_string(),
+            triggering_event: triggering_event.to_string(),
+            deadline_type,
+            days,
+            count_weekends,
+            service_days: 0,
+            applicable_rule: applicable_rule.to_string(),
+            description: description.to_string(),
+            responsible_party: responsible_party.to_string(),
+            is_jurisdictional,
+            is_extendable: !is_jurisdictional,
+        };
+
+        vec![
+            rule("frcp-12-a-1-a", "complaint_filed", DeadlineType::Answer, 21, false,
+                "FRCP 12(a)(1)(A)", "Deadline to file answer to complaint", "Defendant", false),
+            rule("frcp-26-a-1", "scheduling_order", DeadlineType::InitialDisclosures, 14, false,
+                "FRCP 26(a)(1)", "Initial disclosures due", "All parties", false),
+            rule("frap-4-a-1-a", "judgment_entered", DeadlineType::NoticeOfAppeal, 30, false,
+                "FRAP 4(a)(1)(A)", "Deadline to file notice of appeal", "Appellant", true),
+            rule("frcrp-speedy-trial", "indictment_filed", DeadlineType::SpeedyTrial, 70, true,
+                "18 U.S.C. § 3161(c)(1)", "Trial must commence under the Speedy Trial Act",
+                "Government", false),
+            rule("frcrp-29-c-1", "verdict_entered", DeadlineType::Motion, 14, true,
+                "FRCrP 29(c)(1)", "Deadline to move for judgment of acquittal", "Defendant", false),
+            rule("frcrp-33-b-2", "verdict_entered", DeadlineType::Motion, 14, true,
+                "FRCrP 33(b)(2)", "Deadline to move for a new trial", "Defendant", false),
+            rule("frap-4-b-1-a", "criminal_judgment_entered", DeadlineType::NoticeOfAppeal, 14, true,
+                "FRAP 4(b)(1)(A)", "Deadline for defendant to file notice of appeal", "Defendant", true),
+            rule("frap-4-b-1-b", "criminal_judgment_entered", DeadlineType::NoticeOfAppeal, 30, true,
+                "FRAP 4(b)(1)(B)", "Deadline for the government to file notice of appeal", "Government", true),
+            rule("frap-11-b", "notice_of_appeal_filed", DeadlineType::RecordOnAppeal, 30, true,
+                "FRAP 11(b)", "Record on appeal must be transmitted", "District Court Clerk", false),
+            rule("frap-31-a-1-appellant", "record_on_appeal_filed", DeadlineType::AppellateBrief, 40, true,
+                "FRAP 31(a)(1)", "Appellant's brief due", "Appellant", false),
+            rule("frap-31-a-1-appellee", "appellant_brief_filed", DeadlineType::AppellateBrief, 30, true,
+                "FRAP 31(a)(1)", "Appellee's brief due", "Appellee", false),
+            rule("frap-31-a-1-reply", "appellee_brief_filed", DeadlineType::ReplyBrief, 21, true,
+                "FRAP 31(a)(1)", "Reply brief due", "Appellant", false),
+        ]
     }
 }
 
+/// A deadline whose status changed when statuses were recomputed
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DeadlineStatusTransition {
+    pub deadline_id: Uuid,
+    pub case_id: Uuid,
+    pub from: DeadlineStatus,
+    pub to: DeadlineStatus,
+}
+
 /// Service for monitoring and alerting on deadlines
 pub struct DeadlineMonitor;
 
@@ -337,19 +602,45 @@ impl DeadlineMonitor {
         }
     }
 
+    /// Recompute statuses and report the deadlines whose status changed
+    pub fn refresh_statuses(deadlines: &mut [Deadline], current_date: DateTime<Utc>) -> Vec<DeadlineStatusTransition> {
+        let previous: Vec<DeadlineStatus> = deadlines.iter().map(|d| d.status.clone()).collect();
+        Self::update_deadline_statuses(deadlines, current_date);
+
+        deadlines
+            .iter()
+            .zip(previous)
+            .filter(|(deadline, from)| deadline.status != *from)
+            .map(|(deadline, from)| DeadlineStatusTransition {
+                deadline_id: deadline.id,
+                case_id: deadline.case_id,
+                from,
+                to: deadline.status.clone(),
+            })
+            .collect()
+    }
+
     /// Get deadlines requiring immediate attention
     pub fn get_urgent_deadlines(deadlines: &[Deadline]) -> Vec<&Deadline> {
         deadlines
             .iter()
-            .filter(|d| matches!(
-                d.status,
-                DeadlineStatus::Overdue | DeadlineStatus::Due | DeadlineStatus::Approaching
-            ))
+            .filter(|d| d.status.is_urgent())
             .collect()
     }
 
-    /// Generate reminder notifications
-    pub fn generate_reminders(deadlines: &[Deadline], current_date: DateTime<Utc>) -> Vec<DeadlineReminder> {
+    /// Days before a due date on which reminders go out by default
+    pub const DEFAULT_REMINDER_DAYS: [i64; 4] = [14, 7, 3, 1];
+
+    /// Configuration path holding a district's reminder schedule
+    pub const REMINDER_DAYS_CONFIG_PATH: &'static str = "deadlines.reminder_days";
+
+    /// Generate reminder notifications for deadlines due in one of
+    /// `schedule_days` days, plus any that are overdue
+    pub fn generate_reminders_on_schedule(
+        deadlines: &[Deadline],
+        current_date: DateTime<Utc>,
+        schedule_days: &[i64],
+    ) -> Vec<DeadlineReminder> {
         let mut reminders = Vec::new();
 
         for deadline in deadlines {
@@ -360,12 +651,7 @@ impl DeadlineMonitor {
 
             let days_until = (deadline.due_date - current_date).num_days();
 
-            // Generate reminders at 14, 7, 3, and 1 days before
-            let should_remind = match days_until {
-                14 | 7 | 3 | 1 => true,
-                d if d < 0 => true, // Overdue
-                _ => false,
-            };
+            let should_remind = days_until < 0 || schedule_days.contains(&days_until);
 
             if should_remind {
                 // Check if reminder already sent today
@@ -431,4 +717,274 @@ pub struct DeadlineReminder {
     pub days_until: i64,
     pub is_jurisdictional: bool,
     pub message: String,
-}
\ No newline at end of file
+}
+/// Compliance for one deadline type over a reporting period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CategoryCompliance {
+    pub deadline_type: DeadlineType,
+    pub tracked: usize,
+    pub completed_on_time: usize,
+    pub missed: usize,
+    /// Percent completed on time
+    pub compliance_rate: f32,
+}
+
+/// A jurisdictional deadline that was missed during the period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct MissedJurisdictionalDeadline {
+    pub deadline_id: Uuid,
+    pub case_id: Uuid,
+    pub deadline_type: DeadlineType,
+    pub applicable_rule: String,
+    pub responsible_party: String,
+    pub due_date: DateTime<Utc>,
+    /// When it was eventually met, if it was
+    pub completion_date: Option<DateTime<Utc>>,
+}
+
+/// Compliance in the period compared with the period of equal length before it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ComplianceTrend {
+    pub prior_period_start: DateTime<Utc>,
+    pub prior_period_end: DateTime<Utc>,
+    pub prior_deadlines_tracked: usize,
+    pub prior_compliance_rate: f32,
+    /// Percentage points gained (positive) or lost since the prior period
+    pub change: f32,
+}
+
+/// Compliance report as submitted to the Administrative Office
+///
+/// This is the model the PDF is rendered from, so it can be checked without
+/// rendering anything.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ComplianceReportDetail {
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub deadlines_tracked: usize,
+    pub completed_on_time: usize,
+    pub compliance_rate: f32,
+    /// One row per deadline type with deadlines in the period
+    pub categories: Vec<CategoryCompliance>,
+    /// Oldest first
+    pub missed_jurisdictional: Vec<MissedJurisdictionalDeadline>,
+    pub trend: ComplianceTrend,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deadline_with_extensions(due_in_days: i64, extension_days: &[i64]) -> Deadline {
+        let now = Utc::now();
+        let rules = DeadlineCalculator::default_rule_set();
+        let mut deadline = DeadlineCalculator::calculate_from_rules(&rules, "complaint_filed", now, &[]).remove(0);
+        deadline.due_date = now + Duration::days(due_in_days);
+        deadline.status = DeadlineStatus::Approaching;
+        deadline.extension_requests = extension_days
+            .iter()
+            .map(|days| ExtensionRequest {
+                id: Uuid::new_v4(),
+                requested_date: now,
+                requested_by: "Defendant".to_string(),
+                new_due_date: now + Duration::days(*days),
+                reason: "Additional time needed".to_string(),
+                opposed_by: Vec::new(),
+                status: ExtensionStatus::Pending,
+                ruling_date: None,
+                order_text: None,
+            })
+            .collect();
+        deadline
+    }
+
+    #[test]
+    fn test_granted_extension_moves_due_date() {
+        let mut deadline = deadline_with_extensions(3, &[30]);
+        let original_due = deadline.due_date;
+        let extension = deadline.extension_requests[0].clone();
+
+        deadline
+            .rule_on_extension(extension.id, ExtensionStatus::Granted, None, Utc::now())
+            .unwrap();
+
+        assert_eq!(deadline.due_date, extension.new_due_date);
+        assert_eq!(deadline.status, DeadlineStatus::Pending);
+        assert_eq!(deadline.due_date_history.len(), 1);
+        assert_eq!(deadline.due_date_history[0].previous_due_date, original_due);
+        assert_eq!(deadline.due_date_history[0].extension_id, Some(extension.id));
+        assert_eq!(deadline.extension_requests[0].status, ExtensionStatus::Granted);
+    }
+
+    #[test]
+    fn test_denied_extension_leaves_deadline_untouched() {
+        let mut deadline = deadline_with_extensions(3, &[30]);
+        let original_due = deadline.due_date;
+        let extension_id = deadline.extension_requests[0].id;
+
+        deadline
+            .rule_on_extension(extension_id, ExtensionStatus::Denied, Some("Denied".to_string()), Utc::now())
+            .unwrap();
+
+        assert_eq!(deadline.due_date, original_due);
+        assert_eq!(deadline.status, DeadlineStatus::Approaching);
+        assert!(deadline.due_date_history.is_empty());
+        assert_eq!(deadline.extension_requests[0].status, ExtensionStatus::Denied);
+    }
+
+    #[test]
+    fn test_sequential_extensions_build_history() {
+        let mut deadline = deadline_with_extensions(3, &[20, 45]);
+        let first = deadline.extension_requests[0].clone();
+        let second = deadline.extension_requests[1].clone();
+
+        deadline.rule_on_extension(first.id, ExtensionStatus::Granted, None, Utc::now()).unwrap();
+        deadline.rule_on_extension(second.id, ExtensionStatus::Granted, None, Utc::now()).unwrap();
+
+        assert_eq!(deadline.due_date, second.new_due_date);
+        assert_eq!(deadline.effective_due_date(), second.new_due_date);
+        assert_eq!(deadline.due_date_history.len(), 2);
+        assert_eq!(deadline.due_date_history[1].previous_due_date, first.new_due_date);
+
+        // A second ruling on the same request is rejected
+        assert!(deadline.rule_on_extension(first.id, ExtensionStatus::Denied, None, Utc::now()).is_err());
+    }
+
+    fn on(month: u32, day: u32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2030, month, day).unwrap().and_hms_opt(17, 0, 0).unwrap().and_utc()
+    }
+
+    /// Response brief due Monday, March 4, 2030; reply 7 days after it and
+    /// a hearing memo 14 days after the reply
+    fn briefing_chain() -> Vec<Deadline> {
+        let mut response = deadline_with_extensions(0, &[]);
+        response.due_date = on(3, 4);
+        let mut reply = deadline_with_extensions(0, &[]);
+        reply.depends_on = Some(response.id);
+        reply.offset_days = Some(7);
+        reply.due_date = DeadlineChain::due_date_after(&response, 7, &[]);
+        let mut memo = deadline_with_extensions(0, &[]);
+        memo.depends_on = Some(reply.id);
+        memo.offset_days = Some(14);
+        memo.due_date = DeadlineChain::due_date_after(&reply, 14, &[]);
+        vec![response, reply, memo]
+    }
+
+    #[test]
+    fn test_three_level_chain_follows_parent_extension() {
+        let mut chain = briefing_chain();
+        assert_eq!(chain[1].due_date, on(3, 11));
+        assert_eq!(chain[2].due_date, on(3, 25));
+
+        let dependents: Vec<Uuid> = DeadlineChain::dependents(chain[0].id, &chain).iter().map(|d| d.id).collect();
+        assert_eq!(dependents, vec![chain[1].id, chain[2].id]);
+
+        chain[0].due_date = on(3, 18);
+        let now = on(3, 1);
+        let moved = DeadlineChain::recalculate_dependents(chain[0].id, &mut chain, &[], now);
+
+        assert_eq!(moved, vec![chain[1].id, chain[2].id]);
+        assert_eq!(chain[1].due_date, on(3, 25));
+        assert_eq!(chain[2].due_date, on(4, 8));
+        assert_eq!(chain[2].due_date_history.len(), 1);
+        assert_eq!(chain[2].due_date_history[0].previous_due_date, on(3, 25));
+        assert_eq!(chain[2].due_date_history[0].recalculated_from, Some(chain[1].id));
+        assert_eq!(chain[2].due_date_history[0].extension_id, None);
+    }
+
+    #[test]
+    fn test_completed_parent_anchors_on_completion() {
+        let mut chain = briefing_chain();
+        chain[0].status = DeadlineStatus::Completed;
+        chain[0].completion_date = Some(on(2, 25));
+
+        DeadlineChain::recalculate_dependents(chain[0].id, &mut chain, &[], on(2, 25));
+        assert_eq!(chain[1].due_date, on(3, 4));
+        assert_eq!(chain[2].due_date, on(3, 18));
+    }
+
+    #[test]
+    fn test_cycle_is_rejected() {
+        let chain = briefing_chain();
+        let (response, memo) = (chain[0].id, chain[2].id);
+
+        let error = DeadlineChain::check_dependency(response, memo, &chain).unwrap_err();
+        assert!(error.contains("cycle"), "{}", error);
+        assert!(DeadlineChain::check_dependency(response, response, &chain).is_err());
+        assert!(DeadlineChain::check_dependency(memo, response, &chain).is_ok());
+    }
+
+    #[test]
+    fn test_refresh_reports_only_changed_statuses() {
+        let now = Utc::now();
+        let mut overdue = deadline_with_extensions(-2, &[]);
+        overdue.status = DeadlineStatus::Pending;
+        let mut unchanged = deadline_with_extensions(30, &[]);
+        unchanged.status = DeadlineStatus::Pending;
+        let mut completed = deadline_with_extensions(-5, &[]);
+        completed.status = DeadlineStatus::Completed;
+        let mut deadlines = vec![overdue, unchanged, completed];
+
+        let transitions = DeadlineMonitor::refresh_statuses(&mut deadlines, now);
+
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(transitions[0].deadline_id, deadlines[0].id);
+        assert_eq!(transitions[0].from, DeadlineStatus::Pending);
+        assert_eq!(transitions[0].to, DeadlineStatus::Overdue);
+        assert_eq!(deadlines[2].status, DeadlineStatus::Completed);
+    }
+
+    #[test]
+    fn test_default_rule_set_has_unique_ids() {
+        let rules = DeadlineCalculator::default_rule_set();
+        let mut ids: Vec<&str> = rules.iter().map(|r| r.id.as_str()).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), rules.len());
+    }
+
+    #[test]
+    fn test_calculate_from_rules_matches_triggering_event() {
+        let date = Utc::now();
+        let rules = DeadlineCalculator::default_rule_set();
+
+        let verdict = DeadlineCalculator::calculate_from_rules(&rules, "verdict_entered", date, &[]);
+        assert_eq!(verdict.len(), 2);
+        assert!(verdict.iter().all(|d| d.triggering_event == "verdict_entered"));
+
+        let answer = DeadlineCalculator::calculate_from_rules(&rules, "complaint_filed", date, &[]);
+        assert_eq!(answer.len(), 1);
+        assert_eq!(answer[0].applicable_rule, "FRCP 12(a)(1)(A)");
+
+        assert!(DeadlineCalculator::calculate_from_rules(&[], "complaint_filed", date, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_closure_rolls_deadline_forward() {
+        // Monday, March 3, 2025 + 14 calendar days lands on Monday, March 17
+        let date = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap()
+            .and_hms_opt(12, 0, 0).unwrap()
+            .and_utc();
+        let closed = NaiveDate::from_ymd_opt(2025, 3, 17).unwrap();
+
+        let open = DeadlineCalculator::calculate_deadline(date, 14, true, 0, &[]);
+        assert_eq!(open.date_naive(), closed);
+
+        let rolled = DeadlineCalculator::calculate_deadline(date, 14, true, 0, &[closed]);
+        assert_eq!(rolled.date_naive(), NaiveDate::from_ymd_opt(2025, 3, 18).unwrap());
+    }
+
+    #[test]
+    fn test_reminders_follow_schedule() {
+        let now = Utc::now();
+        let mut deadline = deadline_with_extensions(5, &[]);
+        deadline.due_date = now + Duration::days(5) + Duration::hours(1);
+        let deadlines = vec![deadline];
+
+        assert!(DeadlineMonitor::generate_reminders_on_schedule(&deadlines, now, &DeadlineMonitor::DEFAULT_REMINDER_DAYS).is_empty());
+
+        let reminders = DeadlineMonitor::generate_reminders_on_schedule(&deadlines, now, &[10, 5]);
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(reminders[0].days_until, 5);
+    }
+}
@@ -20,7 +20,7 @@ pub enum CitizenshipStatus {
 }
 
 /// Custody status for a defendant
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum CustodyStatus {
     InCustody,
@@ -32,6 +32,13 @@ pub enum CustodyStatus {
     Unknown,
 }
 
+impl CustodyStatus {
+    /// Released on conditions set by a release order
+    pub fn is_conditional_release(&self) -> bool {
+        matches!(self, CustodyStatus::ReleasedOnBond | CustodyStatus::HomeConfinement)
+    }
+}
+
 /// Type of bail/bond set for a defendant
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
@@ -114,6 +121,15 @@ pub struct Defendant {
     pub date_of_birth: Option<NaiveDate>,
     pub citizenship_status: CitizenshipStatus,
     pub custody_status: CustodyStatus,
+    /// Facility holding the defendant while in custody
+    #[serde(default)]
+    pub detention_facility: Option<String>,
+    /// Date the current detention began
+    #[serde(default)]
+    pub detention_date: Option<NaiveDate>,
+    /// Order or docket entry setting the conditions of release
+    #[serde(default)]
+    pub release_conditions_ref: Option<String>,
     pub bond_info: Option<BondInfo>,
     /// Counts/charges against this defendant
     pub counts: Vec<Count>,
@@ -145,6 +161,31 @@ pub struct AddCountRequest {
     pub statutory_min_months: Option<u32>,
 }
 
+/// Request to change a defendant's custody status
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct UpdateCustodyRequest {
+    pub custody_status: CustodyStatus,
+    pub detention_facility: Option<String>,
+    /// Defaults to today when a defendant is taken into custody
+    pub detention_date: Option<NaiveDate>,
+    /// Required when releasing on bond or to home confinement
+    pub release_conditions_ref: Option<String>,
+}
+
+/// A detained defendant in the custody report
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CustodyReportEntry {
+    pub case_id: Uuid,
+    pub case_number: String,
+    pub defendant_id: Uuid,
+    pub defendant_name: String,
+    pub custody_status: CustodyStatus,
+    pub detention_facility: Option<String>,
+    pub detention_date: Option<NaiveDate>,
+    /// Days since `detention_date`, when known
+    pub days_in_custody: Option<i64>,
+}
+
 /// Request to enter a plea for a specific count
 #[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct EnterCountPleaRequest {
@@ -167,6 +208,9 @@ impl Defendant {
             date_of_birth: None,
             citizenship_status: CitizenshipStatus::Unknown,
             custody_status: CustodyStatus::Unknown,
+            detention_facility: None,
+            detention_date: None,
+            release_conditions_ref: None,
             bond_info: None,
             counts: Vec::new(),
             created_at: now,
@@ -176,11 +220,7 @@ impl Defendant {
 
     /// Create a defendant from a full request
     pub fn from_request(case_id: Uuid, request: CreateDefendantRequest) -> Self {
-        let now = Utc::now();
         Self {
-            id: Uuid::new_v4(),
-            case_id,
-            name: request.name,
             aliases: request.aliases,
             usm_number: request.usm_number,
             fbi_number: request.fbi_number,
@@ -188,9 +228,7 @@ impl Defendant {
             citizenship_status: request.citizenship_status.unwrap_or(CitizenshipStatus::Unknown),
             custody_status: request.custody_status.unwrap_or(CustodyStatus::Unknown),
             bond_info: request.bond_info,
-            counts: Vec::new(),
-            created_at: now,
-            updated_at: now,
+            ..Self::new(case_id, request.name)
         }
     }
 
@@ -210,6 +248,57 @@ impl Defendant {
         }
     }
 
+    /// Change custody status, recording detention or release details
+    ///
+    /// A defendant can only be released on bond or to home confinement with a
+    /// reference to the conditions of release; release on recognizance is the
+    /// explicit exception. Release clears the detention details.
+    pub fn update_custody(&mut self, request: UpdateCustodyRequest, today: NaiveDate) -> Result<(), String> {
+        let conditions_ref = request
+            .release_conditions_ref
+            .map(|r| r.trim().to_string())
+            .filter(|r| !r.is_empty());
+
+        match request.custody_status {
+            CustodyStatus::InCustody => {
+                if request.detention_facility.is_some() {
+                    self.detention_facility = request.detention_facility;
+                }
+                self.detention_date = request.detention_date.or(self.detention_date).or(Some(today));
+            }
+            ref status if status.is_conditional_release() => {
+                let Some(conditions_ref) = conditions_ref.or_else(|| self.release_conditions_ref.clone()) else {
+                    return Err(
+                        "A release conditions reference is required to release a defendant on conditions; \
+                         use released_on_own_recognizance for release without conditions"
+                            .to_string(),
+                    );
+                };
+                self.release_conditions_ref = Some(conditions_ref);
+                self.detention_facility = None;
+                self.detention_date = None;
+            }
+            CustodyStatus::ReleasedOnOwnRecognizance => {
+                self.release_conditions_ref = conditions_ref;
+                self.detention_facility = None;
+                self.detention_date = None;
+            }
+            _ => {}
+        }
+
+        self.custody_status = request.custody_status;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Days in custody as of `today`, when the detention date is known
+    pub fn days_in_custody(&self, today: NaiveDate) -> Option<i64> {
+        if self.custody_status != CustodyStatus::InCustody {
+            return None;
+        }
+        self.detention_date.map(|date| (today - date).num_days())
+    }
+
     /// Add a count/charge to this defendant
     pub fn add_count(&mut self, request: AddCountRequest) {
         let count = Count {
@@ -227,3 +316,50 @@ impl Defendant {
         self.updated_at = Utc::now();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custody(status: CustodyStatus, conditions_ref: Option<&str>) -> UpdateCustodyRequest {
+        UpdateCustodyRequest {
+            custody_status: status,
+            detention_facility: Some("MDC Brooklyn".to_string()),
+            detention_date: None,
+            release_conditions_ref: conditions_ref.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_detention_defaults_to_today_and_counts_days() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let mut defendant = Defendant::new(Uuid::new_v4(), "John Doe".to_string());
+
+        defendant.update_custody(custody(CustodyStatus::InCustody, None), today).unwrap();
+
+        assert_eq!(defendant.detention_date, Some(today));
+        assert_eq!(defendant.detention_facility.as_deref(), Some("MDC Brooklyn"));
+        assert_eq!(defendant.days_in_custody(today + chrono::Duration::days(12)), Some(12));
+    }
+
+    #[test]
+    fn test_release_requires_conditions_reference_or_recognizance() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let mut defendant = Defendant::new(Uuid::new_v4(), "John Doe".to_string());
+        defendant.update_custody(custody(CustodyStatus::InCustody, None), today).unwrap();
+
+        assert!(defendant.update_custody(custody(CustodyStatus::ReleasedOnBond, None), today).is_err());
+        assert!(defendant.update_custody(custody(CustodyStatus::ReleasedOnBond, Some("  ")), today).is_err());
+        assert_eq!(defendant.custody_status, CustodyStatus::InCustody);
+
+        defendant
+            .update_custody(custody(CustodyStatus::ReleasedOnBond, Some("ECF 14")), today)
+            .unwrap();
+        assert_eq!(defendant.release_conditions_ref.as_deref(), Some("ECF 14"));
+        assert_eq!(defendant.detention_date, None);
+        assert_eq!(defendant.days_in_custody(today), None);
+
+        let mut ror = Defendant::new(Uuid::new_v4(), "Jane Roe".to_string());
+        assert!(ror.update_custody(custody(CustodyStatus::ReleasedOnOwnRecognizance, None), today).is_ok());
+    }
+}
@@ -0,0 +1,387 @@
+//! Delay attribution for criminal cases
+//!
+//! Combines continuances, Speedy Trial excludable delays and granted deadline
+//! extensions into a single account of which side caused a case's delay.
+
+use super::common::MotionStatus;
+use super::criminal_case::{Motion, MotionType};
+use super::deadline::Deadline;
+use super::docket::{CalendarEntry, DelayReason, EventStatus, ExcludableDelay};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+/// Party a period of delay is attributed to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DelayParty {
+    Defense,
+    Prosecution,
+    Court,
+    Neutral,
+}
+
+impl DelayParty {
+    /// Map a free-text party label such as "Defense Counsel" or "AUSA Smith"
+    pub fn from_party_label(label: &str) -> Self {
+        let label = label.to_lowercase();
+        let has_word = |words: &[&str]| {
+            label
+                .split(|c: char| !c.is_alphanumeric())
+                .any(|token| words.contains(&token))
+        };
+        if label.contains("defen") {
+            DelayParty::Defense
+        } else if ["government", "prosecut", "united states", "u.s. attorney"]
+            .iter()
+            .any(|p| label.contains(p))
+            || has_word(&["usa", "ausa"])
+        {
+            DelayParty::Prosecution
+        } else if ["court", "judge", "chambers", "sua sponte"]
+            .iter()
+            .any(|p| label.contains(p))
+        {
+            DelayParty::Court
+        } else {
+            DelayParty::Neutral
+        }
+    }
+
+    /// Party responsible for a Speedy Trial Act exclusion
+    ///
+    /// Unavailability and competency proceedings stem from the defendant;
+    /// pending motions and ends-of-justice continuances rest on the court's
+    /// own findings. Appeals, co-defendant proceedings and diversion are
+    /// structural and count as neutral.
+    pub fn for_delay_reason(reason: &DelayReason) -> Self {
+        match reason {
+            DelayReason::DefendantUnavailable | DelayReason::CompetencyProceedings => DelayParty::Defense,
+            DelayReason::PretrialMotions | DelayReason::ContinuanceInInterestOfJustice => DelayParty::Court,
+            DelayReason::InterlocutoryAppeal
+            | DelayReason::CoDefendantProceedings
+            | DelayReason::DeferredProsecution
+            | DelayReason::Other => DelayParty::Neutral,
+        }
+    }
+}
+
+/// Kind of record a delay item was derived from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DelaySource {
+    Continuance,
+    ExcludableDelay,
+    Extension,
+}
+
+/// A single attributed period of delay
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DelayItem {
+    pub source: DelaySource,
+    pub party: DelayParty,
+    pub days: i64,
+    pub start_date: DateTime<Utc>,
+    pub description: String,
+}
+
+/// Days of delay per party
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PartyDelayTotals {
+    pub defense: i64,
+    pub prosecution: i64,
+    pub court: i64,
+    pub neutral: i64,
+}
+
+impl PartyDelayTotals {
+    fn add(&mut self, party: DelayParty, days: i64) {
+        match party {
+            DelayParty::Defense => self.defense += days,
+            DelayParty::Prosecution => self.prosecution += days,
+            DelayParty::Court => self.court += days,
+            DelayParty::Neutral => self.neutral += days,
+        }
+    }
+}
+
+/// Total delay in a case broken down by responsible party
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DelayAttribution {
+    pub case_id: Uuid,
+    pub total_days: i64,
+    pub by_party: PartyDelayTotals,
+    /// Individual delay periods, oldest first
+    pub items: Vec<DelayItem>,
+}
+
+impl DelayAttribution {
+    /// Attribute a case's continuances, excludable delays and granted extensions
+    pub fn compute(
+        case_id: Uuid,
+        motions: &[Motion],
+        events: &[CalendarEntry],
+        excludable_delays: &[ExcludableDelay],
+        deadlines: &[Deadline],
+    ) -> Self {
+        let mut items = continuance_items(motions, events);
+        items.extend(excludable_delays.iter().map(|delay| DelayItem {
+            source: DelaySource::ExcludableDelay,
+            party: DelayParty::for_delay_reason(&delay.reason),
+            days: delay.days_excluded,
+            start_date: delay.start_date,
+            description: format!("Excludable delay under {}", delay.statutory_reference),
+        }));
+        items.extend(extension_items(deadlines));
+        items.sort_by_key(|item| item.start_date);
+
+        let mut by_party = PartyDelayTotals::default();
+        for item in &items {
+            by_party.add(item.party, item.days);
+        }
+
+        Self {
+            case_id,
+            total_days: items.iter().map(|item| item.days).sum(),
+            by_party,
+            items,
+        }
+    }
+}
+
+/// Delay from each continued calendar event until its rescheduled setting
+///
+/// The rescheduled setting is the next event of the same type that was not
+/// cancelled. A continuance is attributed to whoever filed the latest granted
+/// continuance motion before the original date, or to the court when it was
+/// continued without a motion.
+fn continuance_items(motions: &[Motion], events: &[CalendarEntry]) -> Vec<DelayItem> {
+    let granted_motions: Vec<&Motion> = motions
+        .iter()
+        .filter(|m| matches!(m.motion_type, MotionType::Continuance) && m.status == MotionStatus::Granted)
+        .collect();
+
+    events
+        .iter()
+        .filter(|event| event.status == EventStatus::Continued)
+        .filter_map(|event| {
+            let event_type = event.event_type.config_key();
+            let reset = events
+                .iter()
+                .filter(|e| e.status != EventStatus::Cancelled)
+                .filter(|e| e.event_type.config_key() == event_type && e.scheduled_date > event.scheduled_date)
+                .min_by_key(|e| e.scheduled_date)?;
+
+            let party = granted_motions
+                .iter()
+                .filter(|m| m.filed_date <= event.scheduled_date)
+                .max_by_key(|m| m.filed_date)
+                .map(|m| DelayParty::from_party_label(&m.filed_by))
+                .unwrap_or(DelayParty::Court);
+
+            Some(DelayItem {
+                source: DelaySource::Continuance,
+                party,
+                days: (reset.scheduled_date - event.scheduled_date).num_days(),
+                start_date: event.scheduled_date,
+                description: format!(
+                    "{} continued to {}",
+                    event_type,
+                    reset.scheduled_date.format("%Y-%m-%d")
+                ),
+            })
+        })
+        .collect()
+}
+
+/// Delay added by each due date change from a granted extension
+///
+/// Changes that only follow a parent deadline are not counted again.
+fn extension_items(deadlines: &[Deadline]) -> Vec<DelayItem> {
+    deadlines
+        .iter()
+        .flat_map(|deadline| {
+            deadline.due_date_history.iter().filter_map(move |change| {
+                let extension_id = change.extension_id?;
+                let days = (change.new_due_date - change.previous_due_date).num_days();
+                if days <= 0 {
+                    return None;
+                }
+                let requested_by = deadline
+                    .extension_requests
+                    .iter()
+                    .find(|e| e.id == extension_id)
+                    .map(|e| e.requested_by.as_str())
+                    .unwrap_or_default();
+                Some(DelayItem {
+                    source: DelaySource::Extension,
+                    party: DelayParty::from_party_label(requested_by),
+                    days,
+                    start_date: change.previous_due_date,
+                    description: format!("Extension of {}", deadline.description),
+                })
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::deadline::{DeadlineStatus, DeadlineType, DueDateChange, ExtensionRequest, ExtensionStatus};
+    use crate::domain::docket::CalendarEventType;
+    use chrono::{Duration, TimeZone};
+
+    fn date(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, day, 10, 0, 0).unwrap()
+    }
+
+    fn status_conference(day: u32, status: EventStatus) -> CalendarEntry {
+        CalendarEntry {
+            id: Uuid::new_v4(),
+            case_id: Uuid::nil(),
+            judge_id: Uuid::nil(),
+            event_type: CalendarEventType::StatusConference,
+            scheduled_date: date(day),
+            duration_minutes: 30,
+            courtroom: "4A".to_string(),
+            description: "Status conference".to_string(),
+            participants: vec![],
+            court_reporter: None,
+            is_public: true,
+            call_time: None,
+            actual_start: None,
+            actual_end: None,
+            status,
+            notes: String::new(),
+            minutes: None,
+        }
+    }
+
+    fn continuance_motion(filed_by: &str, status: MotionStatus) -> Motion {
+        Motion {
+            id: Uuid::new_v4(),
+            motion_type: MotionType::Continuance,
+            filed_by: filed_by.to_string(),
+            description: "Motion to continue".to_string(),
+            filed_date: date(1),
+            status,
+            ruling_date: Some(date(2)),
+        }
+    }
+
+    fn excludable(reason: DelayReason, days: i64) -> ExcludableDelay {
+        ExcludableDelay {
+            start_date: date(3),
+            end_date: Some(date(3) + Duration::days(days)),
+            reason,
+            statutory_reference: "18 U.S.C. § 3161(h)".to_string(),
+            days_excluded: days,
+            order_reference: None,
+        }
+    }
+
+    #[test]
+    fn test_party_labels_map_to_sides() {
+        assert_eq!(DelayParty::from_party_label("Defense Counsel"), DelayParty::Defense);
+        assert_eq!(DelayParty::from_party_label("AUSA Jane Smith"), DelayParty::Prosecution);
+        assert_eq!(DelayParty::from_party_label("United States"), DelayParty::Prosecution);
+        assert_eq!(DelayParty::from_party_label("Court (sua sponte)"), DelayParty::Court);
+        assert_eq!(DelayParty::from_party_label("Probation Office"), DelayParty::Neutral);
+    }
+
+    #[test]
+    fn test_continuance_without_motion_is_attributed_to_court() {
+        let events = vec![
+            status_conference(10, EventStatus::Continued),
+            status_conference(24, EventStatus::Scheduled),
+        ];
+
+        let result = DelayAttribution::compute(Uuid::nil(), &[], &events, &[], &[]);
+
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.items[0].source, DelaySource::Continuance);
+        assert_eq!(result.by_party.court, 14);
+        assert_eq!(result.total_days, 14);
+    }
+
+    #[test]
+    fn test_continuance_follows_granted_motion_filer() {
+        let events = vec![
+            status_conference(10, EventStatus::Continued),
+            status_conference(17, EventStatus::Scheduled),
+        ];
+        let motions = vec![
+            continuance_motion("Government", MotionStatus::Denied),
+            continuance_motion("Defense Counsel", MotionStatus::Granted),
+        ];
+
+        let result = DelayAttribution::compute(Uuid::nil(), &motions, &events, &[], &[]);
+
+        assert_eq!(result.by_party.defense, 7);
+        assert_eq!(result.by_party.prosecution, 0);
+    }
+
+    #[test]
+    fn test_continued_event_without_reset_is_ignored() {
+        let events = vec![status_conference(10, EventStatus::Continued)];
+        let result = DelayAttribution::compute(Uuid::nil(), &[], &events, &[], &[]);
+        assert!(result.items.is_empty());
+    }
+
+    #[test]
+    fn test_combines_all_sources() {
+        let extension_id = Uuid::new_v4();
+        let deadline = Deadline {
+            id: Uuid::new_v4(),
+            case_id: Uuid::nil(),
+            deadline_type: DeadlineType::PretrialMotions,
+            due_date: date(20),
+            triggering_event: "Arraignment".to_string(),
+            triggering_date: date(1),
+            applicable_rule: "FRCrP 12(c)".to_string(),
+            description: "Pretrial motions".to_string(),
+            responsible_party: "Defense".to_string(),
+            is_jurisdictional: false,
+            is_extendable: true,
+            status: DeadlineStatus::Extended,
+            completion_date: None,
+            extension_requests: vec![ExtensionRequest {
+                id: extension_id,
+                requested_date: date(5),
+                requested_by: "AUSA Smith".to_string(),
+                new_due_date: date(20),
+                reason: "Voluminous discovery".to_string(),
+                opposed_by: vec![],
+                status: ExtensionStatus::Granted,
+                ruling_date: Some(date(6)),
+                order_text: None,
+            }],
+            reminders_sent: vec![],
+            due_date_history: vec![DueDateChange {
+                previous_due_date: date(15),
+                new_due_date: date(20),
+                extension_id: Some(extension_id),
+                recalculated_from: None,
+                ruling_date: date(6),
+            }],
+            depends_on: None,
+            offset_days: None,
+        };
+        let events = vec![
+            status_conference(10, EventStatus::Continued),
+            status_conference(20, EventStatus::Scheduled),
+        ];
+        let delays = vec![
+            excludable(DelayReason::DefendantUnavailable, 21),
+            excludable(DelayReason::InterlocutoryAppeal, 30),
+        ];
+
+        let result = DelayAttribution::compute(Uuid::nil(), &[], &events, &delays, &[deadline]);
+
+        assert_eq!(result.by_party, PartyDelayTotals { defense: 21, prosecution: 5, court: 10, neutral: 30 });
+        assert_eq!(result.total_days, 66);
+        assert!(result.items.windows(2).all(|w| w[0].start_date <= w[1].start_date));
+    }
+}
@@ -0,0 +1,338 @@
+//! Canonical registry of court districts
+//!
+//! Every tenant is keyed by a district code. The registry holds the 94
+//! federal judicial districts, the other courts and test districts this
+//! deployment has always served, and any custom tenants registered through
+//! `POST /api/admin/init-tenant`. A requested code is trimmed and uppercased
+//! before lookup; anything else about it must already be canonical, so a
+//! typo is rejected with the closest codes instead of quietly becoming a
+//! new tenant.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+
+/// Longest district code accepted
+pub const MAX_CODE_LEN: usize = 50;
+
+/// Most close matches suggested for an unknown code
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Largest edit distance still suggested as a close match
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// A federal judicial district
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+pub struct FederalDistrict {
+    /// Canonical code, e.g. `SDNY`
+    pub code: &'static str,
+    pub name: &'static str,
+    /// Circuit the district sits in, e.g. `2nd` or `D.C.`
+    pub circuit: &'static str,
+}
+
+const fn district(code: &'static str, name: &'static str, circuit: &'static str) -> FederalDistrict {
+    FederalDistrict { code, name, circuit }
+}
+
+/// The 94 federal judicial districts
+pub const FEDERAL_DISTRICTS: [FederalDistrict; 94] = [
+    district("DME", "District of Maine", "1st"),
+    district("DMA", "District of Massachusetts", "1st"),
+    district("DNH", "District of New Hampshire", "1st"),
+    district("DRI", "District of Rhode Island", "1st"),
+    district("DPR", "District of Puerto Rico", "1st"),
+    district("DCT", "District of Connecticut", "2nd"),
+    district("EDNY", "Eastern District of New York", "2nd"),
+    district("NDNY", "Northern District of New York", "2nd"),
+    district("SDNY", "Southern District of New York", "2nd"),
+    district("WDNY", "Western District of New York", "2nd"),
+    district("DVT", "District of Vermont", "2nd"),
+    district("DDE", "District of Delaware", "3rd"),
+    district("DNJ", "District of New Jersey", "3rd"),
+    district("EDPA", "Eastern District of Pennsylvania", "3rd"),
+    district("MDPA", "Middle District of Pennsylvania", "3rd"),
+    district("WDPA", "Western District of Pennsylvania", "3rd"),
+    district("DVI", "District of the Virgin Islands", "3rd"),
+    district("DMD", "District of Maryland", "4th"),
+    district("EDNC", "Eastern District of North Carolina", "4th"),
+    district("MDNC", "Middle District of North Carolina", "4th"),
+    district("WDNC", "Western District of North Carolina", "4th"),
+    district("DSC", "District of South Carolina", "4th"),
+    district("EDVA", "Eastern District of Virginia", "4th"),
+    district("WDVA", "Western District of Virginia", "4th"),
+    district("NDWV", "Northern District of West Virginia", "4th"),
+    district("SDWV", "Southern District of West Virginia", "4th"),
+    district("EDLA", "Eastern District of Louisiana", "5th"),
+    district("MDLA", "Middle District of Louisiana", "5th"),
+    district("WDLA", "Western District of Louisiana", "5th"),
+    district("NDMS", "Northern District of Mississippi", "5th"),
+    district("SDMS", "Southern District of Mississippi", "5th"),
+    district("EDTX", "Eastern District of Texas", "5th"),
+    district("NDTX", "Northern District of Texas", "5th"),
+    district("SDTX", "Southern District of Texas", "5th"),
+    district("WDTX", "Western District of Texas", "5th"),
+    district("EDKY", "Eastern District of Kentucky", "6th"),
+    district("WDKY", "Western District of Kentucky", "6th"),
+    district("EDMI", "Eastern District of Michigan", "6th"),
+    district("WDMI", "Western District of Michigan", "6th"),
+    district("NDOH", "Northern District of Ohio", "6th"),
+    district("SDOH", "Southern District of Ohio", "6th"),
+    district("EDTN", "Eastern District of Tennessee", "6th"),
+    district("MDTN", "Middle District of Tennessee", "6th"),
+    district("WDTN", "Western District of Tennessee", "6th"),
+    district("CDIL", "Central District of Illinois", "7th"),
+    district("NDIL", "Northern District of Illinois", "7th"),
+    district("SDIL", "Southern District of Illinois", "7th"),
+    district("NDIN", "Northern District of Indiana", "7th"),
+    district("SDIN", "Southern District of Indiana", "7th"),
+    district("EDWI", "Eastern District of Wisconsin", "7th"),
+    district("WDWI", "Western District of Wisconsin", "7th"),
+    district("EDAR", "Eastern District of Arkansas", "8th"),
+    district("WDAR", "Western District of Arkansas", "8th"),
+    district("NDIA", "Northern District of Iowa", "8th"),
+    district("SDIA", "Southern District of Iowa", "8th"),
+    district("DMN", "District of Minnesota", "8th"),
+    district("EDMO", "Eastern District of Missouri", "8th"),
+    district("WDMO", "Western District of Missouri", "8th"),
+    district("DNE", "District of Nebraska", "8th"),
+    district("DND", "District of North Dakota", "8th"),
+    district("DSD", "District of South Dakota", "8th"),
+    district("DAK", "District of Alaska", "9th"),
+    district("DAZ", "District of Arizona", "9th"),
+    district("CDCA", "Central District of California", "9th"),
+    district("EDCA", "Eastern District of California", "9th"),
+    district("NDCA", "Northern District of California", "9th"),
+    district("SDCA", "Southern District of California", "9th"),
+    district("DHI", "District of Hawaii", "9th"),
+    district("DID", "District of Idaho", "9th"),
+    district("DMT", "District of Montana", "9th"),
+    district("DNV", "District of Nevada", "9th"),
+    district("DOR", "District of Oregon", "9th"),
+    district("EDWA", "Eastern District of Washington", "9th"),
+    district("WDWA", "Western District of Washington", "9th"),
+    district("DGU", "District of Guam", "9th"),
+    district("DNMI", "District of the Northern Mariana Islands", "9th"),
+    district("DCO", "District of Colorado", "10th"),
+    district("DKS", "District of Kansas", "10th"),
+    district("DNM", "District of New Mexico", "10th"),
+    district("EDOK", "Eastern District of Oklahoma", "10th"),
+    district("NDOK", "Northern District of Oklahoma", "10th"),
+    district("WDOK", "Western District of Oklahoma", "10th"),
+    district("DUT", "District of Utah", "10th"),
+    district("DWY", "District of Wyoming", "10th"),
+    district("MDAL", "Middle District of Alabama", "11th"),
+    district("NDAL", "Northern District of Alabama", "11th"),
+    district("SDAL", "Southern District of Alabama", "11th"),
+    district("MDFL", "Middle District of Florida", "11th"),
+    district("NDFL", "Northern District of Florida", "11th"),
+    district("SDFL", "Southern District of Florida", "11th"),
+    district("MDGA", "Middle District of Georgia", "11th"),
+    district("NDGA", "Northern District of Georgia", "11th"),
+    district("SDGA", "Southern District of Georgia", "11th"),
+    district("DDC", "District of Columbia", "D.C."),
+];
+
+/// Tenants served alongside the federal districts: other courts, the
+/// shared stores and the test districts, with their names
+pub const BUILT_IN_TENANTS: &[(&str, &str)] = &[
+    ("NYBK", "Bankruptcy Court for the Southern District of New York"),
+    ("FISA", "Foreign Intelligence Surveillance Court"),
+    ("TAX", "United States Tax Court"),
+    ("GENERIC", "Generic court"),
+    ("DEFAULT", "Default tenant"),
+    ("TEST", "Test tenant"),
+    ("DISTRICT9", "Test district"),
+    ("DISTRICT12", "Test district"),
+];
+
+/// A custom tenant registered through `init_tenant`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RegisteredTenant {
+    /// Canonical code
+    pub code: String,
+    pub registered_at: DateTime<Utc>,
+}
+
+/// Where a registry entry comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TenantKind {
+    Federal,
+    BuiltIn,
+    Custom,
+}
+
+/// Why a requested district code was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DistrictCodeError {
+    /// No code was given
+    Missing,
+    /// The code has characters other than letters, digits, `-` and `_`,
+    /// or is too long
+    NotCanonical { code: String, suggestions: Vec<String> },
+    /// The code is well formed but not in the registry
+    Unknown { code: String, suggestions: Vec<String> },
+}
+
+impl DistrictCodeError {
+    /// Close matches for the requested code, closest first
+    pub fn suggestions(&self) -> &[String] {
+        match self {
+            Self::Missing => &[],
+            Self::NotCanonical { suggestions, .. } | Self::Unknown { suggestions, .. } => suggestions,
+        }
+    }
+}
+
+impl fmt::Display for DistrictCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (message, suggestions) = match self {
+            Self::Missing => return write!(f, "Missing required header: X-Court-District or X-Tenant-ID"),
+            Self::NotCanonical { code, suggestions } => (
+                format!("District code '{}' may only contain letters, digits, '-' and '_', up to {} characters", code, MAX_CODE_LEN),
+                suggestions,
+            ),
+            Self::Unknown { code, suggestions } => (format!("Unknown district '{}'", code), suggestions),
+        };
+        match suggestions.is_empty() {
+            true => write!(f, "{}", message),
+            false => write!(f, "{}; did you mean {}?", message, suggestions.join(", ")),
+        }
+    }
+}
+
+/// Trim and uppercase a requested code, rejecting one that still isn't
+/// canonical
+pub fn normalize_code(raw: &str) -> Result<String, DistrictCodeError> {
+    let code = raw.trim().to_uppercase();
+    if code.is_empty() {
+        return Err(DistrictCodeError::Missing);
+    }
+    let canonical = code.len() <= MAX_CODE_LEN
+        && code.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !canonical {
+        let stripped: String = code.chars().filter(|c| c.is_ascii_alphanumeric()).collect();
+        let suggestions = close_matches(&stripped, known_codes());
+        return Err(DistrictCodeError::NotCanonical { code, suggestions });
+    }
+    Ok(code)
+}
+
+/// The federal district with a canonical code
+pub fn federal_district(code: &str) -> Option<&'static FederalDistrict> {
+    FEDERAL_DISTRICTS.iter().find(|d| d.code == code)
+}
+
+/// The name of a built-in tenant
+pub fn built_in_tenant(code: &str) -> Option<&'static str> {
+    BUILT_IN_TENANTS.iter().find(|(c, _)| *c == code).map(|(_, name)| *name)
+}
+
+/// Codes of the federal districts and built-in tenants
+pub fn known_codes() -> impl Iterator<Item = &'static str> {
+    FEDERAL_DISTRICTS.iter().map(|d| d.code).chain(BUILT_IN_TENANTS.iter().map(|(code, _)| *code))
+}
+
+/// Resolve a requested code to its canonical form
+///
+/// `custom` is only consulted for codes that aren't federal districts or
+/// built-in tenants.
+pub fn resolve(raw: &str, custom: &[RegisteredTenant]) -> Result<String, DistrictCodeError> {
+    let code = normalize_code(raw)?;
+    if federal_district(&code).is_some()
+        || built_in_tenant(&code).is_some()
+        || custom.iter().any(|t| t.code == code)
+    {
+        return Ok(code);
+    }
+    let mut candidates: Vec<&str> = known_codes().collect();
+    candidates.extend(custom.iter().map(|t| t.code.as_str()));
+    let suggestions = close_matches(&code, candidates.into_iter());
+    Err(DistrictCodeError::Unknown { code, suggestions })
+}
+
+/// Known codes within a small edit distance of `code`, closest first
+pub fn close_matches<'a>(code: &str, candidates: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut matches: Vec<(usize, &str)> = candidates
+        .map(|candidate| (edit_distance(code, candidate), candidate))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .collect();
+    matches.sort();
+    matches.dedup();
+    matches.into_iter().take(MAX_SUGGESTIONS).map(|(_, c)| c.to_string()).collect()
+}
+
+/// Levenshtein distance between two codes
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_registry_has_the_94_districts_once_each() {
+        let codes: HashSet<&str> = known_codes().collect();
+        assert_eq!(codes.len(), FEDERAL_DISTRICTS.len() + BUILT_IN_TENANTS.len());
+        assert_eq!(federal_district("SDNY").unwrap().circuit, "2nd");
+        assert_eq!(federal_district("DDC").unwrap().circuit, "D.C.");
+        assert_eq!(FEDERAL_DISTRICTS.iter().filter(|d| d.circuit == "9th").count(), 15);
+    }
+
+    #[test]
+    fn test_codes_are_trimmed_and_uppercased() {
+        assert_eq!(resolve("SDNy ", &[]).unwrap(), "SDNY");
+        assert_eq!(resolve(" district9", &[]).unwrap(), "DISTRICT9");
+        assert_eq!(resolve("  ", &[]), Err(DistrictCodeError::Missing));
+    }
+
+    #[test]
+    fn test_non_canonical_codes_are_rejected_with_matches() {
+        let error = resolve("S.D.N.Y.", &[]).unwrap_err();
+        assert!(matches!(error, DistrictCodeError::NotCanonical { .. }));
+        assert_eq!(error.suggestions().first().map(String::as_str), Some("SDNY"));
+        assert!(matches!(resolve(&"A".repeat(51), &[]), Err(DistrictCodeError::NotCanonical { .. })));
+    }
+
+    #[test]
+    fn test_unknown_codes_suggest_close_matches() {
+        let error = resolve("SDNX", &[]).unwrap_err();
+        assert_eq!(error.suggestions()[0], "SDNY");
+        assert!(error.suggestions().len() <= MAX_SUGGESTIONS);
+        assert!(error.to_string().starts_with("Unknown district 'SDNX'; did you mean SDNY"));
+
+        let error = resolve("ZZZZZZZZ", &[]).unwrap_err();
+        assert!(error.suggestions().is_empty());
+        assert_eq!(error.to_string(), "Unknown district 'ZZZZZZZZ'");
+    }
+
+    #[test]
+    fn test_custom_tenants_resolve_once_registered() {
+        let custom = vec![RegisteredTenant { code: "CAFC".to_string(), registered_at: Utc::now() }];
+        assert!(matches!(resolve("cafc", &[]), Err(DistrictCodeError::Unknown { .. })));
+        assert_eq!(resolve("cafc", &custom).unwrap(), "CAFC");
+        assert_eq!(resolve("CAFD", &custom).unwrap_err().suggestions()[0], "CAFC");
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("SDNY", "SDNY"), 0);
+        assert_eq!(edit_distance("SDNY", "EDNY"), 1);
+        assert_eq!(edit_distance("SDN", "SDNY"), 1);
+        assert_eq!(edit_distance("", "DDC"), 3);
+    }
+}
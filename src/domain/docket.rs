@@ -3,7 +3,7 @@
 //! This module handles docket entries, court calendar, and scheduling
 //! following Lexodus conventions.
 
-use chrono::{DateTime, Duration, Utc, Weekday, Datelike, Timelike};
+use chrono::{DateTime, Duration, NaiveDate, Utc, Weekday, Datelike, Timelike};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use utoipa::ToSchema;
@@ -47,6 +47,7 @@ pub enum DocketEntryType {
 
     // Orders
     Order,
+    ProposedOrder,
     MinuteOrder,
     SchedulingOrder,
     ProtectiveOrder,
@@ -100,6 +101,24 @@ pub struct DocketAttachment {
     pub description: String,
     pub page_count: u32,
     pub file_size_bytes: u64,
+    /// File name offered on download, once content is uploaded
+    #[serde(default)]
+    pub file_name: Option<String>,
+    /// Media type of the uploaded content
+    #[serde(default)]
+    pub content_type: Option<String>,
+    /// Hex SHA-256 digest of the uploaded content, checked on every download
+    #[serde(default)]
+    pub sha256: Option<String>,
+}
+
+/// Total size of the attachments on a case's docket
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CaseAttachmentSize {
+    pub case_id: Uuid,
+    pub attachment_count: usize,
+    pub total_bytes: u64,
+    pub total_pages: u64,
 }
 
 /// Court calendar entry
@@ -113,7 +132,7 @@ pub struct CalendarEntry {
     pub duration_minutes: u32,
     pub courtroom: String,
     pub description: String,
-    pub participants: Vec<String>,
+    pub participants: Vec<EventParticipant>,
     pub court_reporter: Option<String>,
     pub is_public: bool,
     pub call_time: Option<DateTime<Utc>>,
@@ -121,6 +140,188 @@ pub struct CalendarEntry {
     pub actual_end: Option<DateTime<Utc>>,
     pub status: EventStatus,
     pub notes: String,
+    /// Hearing outcome recorded when the event completed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minutes: Option<HearingMinutes>,
+}
+
+/// Someone who appeared at a hearing
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct Appearance {
+    /// Attorney id, checked against the district's attorneys when given
+    #[serde(default)]
+    pub attorney_id: Option<String>,
+    pub name: String,
+    /// Whom they appeared for, e.g. "the United States"
+    #[serde(default)]
+    pub appearing_for: Option<String>,
+}
+
+/// What happened at a hearing, as a minute entry records it
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct HearingMinutes {
+    #[serde(default)]
+    pub appearances: Vec<Appearance>,
+    #[serde(default)]
+    pub court_reporter: Option<String>,
+    /// Rulings made from the bench
+    #[serde(default)]
+    pub rulings: Vec<String>,
+    #[serde(default)]
+    pub exhibits_admitted: Vec<String>,
+    /// When the matter is next on the calendar
+    #[serde(default)]
+    pub next_scheduled_date: Option<DateTime<Utc>>,
+}
+
+impl HearingMinutes {
+    /// Attorney ids given for appearances
+    pub fn attorney_ids(&self) -> impl Iterator<Item = &str> {
+        self.appearances.iter().filter_map(|a| a.attorney_id.as_deref())
+    }
+
+    /// The minutes as sentences for a minute entry, leaving out empty fields
+    pub fn sentences(&self) -> Vec<String> {
+        let mut sentences = Vec::new();
+        if !self.appearances.is_empty() {
+            let appearances: Vec<String> = self
+                .appearances
+                .iter()
+                .map(|a| match &a.appearing_for {
+                    Some(party) => format!("{} for {}", a.name, party),
+                    None => a.name.clone(),
+                })
+                .collect();
+            sentences.push(format!("Appearances: {}.", appearances.join("; ")));
+        }
+        if let Some(reporter) = &self.court_reporter {
+            sentences.push(format!("Court Reporter: {}.", reporter));
+        }
+        if !self.rulings.is_empty() {
+            sentences.push(format!("Rulings: {}.", self.rulings.join("; ")));
+        }
+        if !self.exhibits_admitted.is_empty() {
+            sentences.push(format!("Exhibits admitted: {}.", self.exhibits_admitted.join(", ")));
+        }
+        if let Some(next) = self.next_scheduled_date {
+            sentences.push(format!("Next hearing set for {}.", next.format("%m/%d/%Y %I:%M %p")));
+        }
+        sentences
+    }
+}
+
+/// Kind of participant in a court event
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParticipantKind {
+    /// Attorney, identified by attorney id
+    Attorney,
+    /// Party to the case, identified by party id
+    Party,
+    /// Anyone else, known only by name
+    Other,
+}
+
+/// Participant in a court event
+///
+/// Events stored before participants were structured hold plain names;
+/// those deserialize as required participants of kind `other` with no id.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+#[serde(from = "EventParticipantRecord")]
+pub struct EventParticipant {
+    pub kind: ParticipantKind,
+    /// Attorney or party id, for those kinds
+    pub id: Option<String>,
+    pub name: String,
+    /// Whether the participant must attend
+    pub required: bool,
+}
+
+impl EventParticipant {
+    /// Participant known only by name
+    pub fn named(name: impl Into<String>) -> Self {
+        Self { kind: ParticipantKind::Other, id: None, name: name.into(), required: true }
+    }
+}
+
+/// Stored or submitted form of a participant: a legacy name or the full record
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EventParticipantRecord {
+    Name(String),
+    Full {
+        kind: ParticipantKind,
+        #[serde(default)]
+        id: Option<String>,
+        #[serde(default)]
+        name: String,
+        #[serde(default = "required_by_default")]
+        required: bool,
+    },
+}
+
+fn required_by_default() -> bool {
+    true
+}
+
+impl From<EventParticipantRecord> for EventParticipant {
+    fn from(record: EventParticipantRecord) -> Self {
+        match record {
+            EventParticipantRecord::Name(name) => Self::named(name),
+            EventParticipantRecord::Full { kind, id, name, required } => Self { kind, id, name, required },
+        }
+    }
+}
+
+/// How a participant is to be told of a change to an event
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationDelivery {
+    /// Electronic notice to the email on file
+    Email,
+    /// Service by mail, for parties without counsel or an email address
+    Postal,
+    /// Nothing on file to reach the participant; the clerk must follow up
+    Unresolved,
+}
+
+/// One entry on an event's notification list
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NotificationRecipient {
+    pub kind: ParticipantKind,
+    pub id: Option<String>,
+    pub name: String,
+    pub required: bool,
+    pub delivery: NotificationDelivery,
+    pub email: Option<String>,
+    pub mailing_address: Option<crate::domain::attorney::Address>,
+    /// Why the recipient needs more than electronic notice
+    pub note: Option<String>,
+}
+
+/// Who to inform of a court event and how
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EventNotificationList {
+    pub event_id: Uuid,
+    pub case_id: Uuid,
+    pub recipients: Vec<NotificationRecipient>,
+    /// Recipients that must be served by mail
+    pub postal_service_count: usize,
+    /// Recipients the clerk must locate before notice can go out
+    pub unresolved_count: usize,
+}
+
+impl EventNotificationList {
+    pub fn new(event: &CalendarEntry, recipients: Vec<NotificationRecipient>) -> Self {
+        let count = |delivery: NotificationDelivery| recipients.iter().filter(|r| r.delivery == delivery).count();
+        Self {
+            event_id: event.id,
+            case_id: event.case_id,
+            postal_service_count: count(NotificationDelivery::Postal),
+            unresolved_count: count(NotificationDelivery::Unresolved),
+            recipients,
+        }
+    }
 }
 
 /// Types of calendar events
@@ -157,6 +358,50 @@ pub enum CalendarEventType {
     VideoConference,
 }
 
+impl CalendarEventType {
+    /// Configuration path holding per-event-type minimum notice, in business days
+    pub const MINIMUM_NOTICE_CONFIG_PATH: &'static str = "scheduling.minimum_notice_days";
+
+    /// Snake-case name used for this event type in configuration keys
+    pub fn config_key(&self) -> String {
+        serde_json::to_value(self)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+
+    /// Business days of notice required before this event by default
+    ///
+    /// Contested hearings and trials follow the 14-day motion notice of
+    /// FRCP 6(c)(1) (roughly ten business days); appearances that must happen
+    /// promptly, such as initial appearances and emergency matters, need none.
+    pub fn default_minimum_notice_days(&self) -> i64 {
+        match self {
+            CalendarEventType::MotionHearing
+            | CalendarEventType::EvidentiaryHearing
+            | CalendarEventType::Sentencing
+            | CalendarEventType::TrialDate
+            | CalendarEventType::JurySelection
+            | CalendarEventType::JuryTrial
+            | CalendarEventType::BenchTrial => 10,
+            CalendarEventType::ShowCauseHearing
+            | CalendarEventType::ContemptHearing
+            | CalendarEventType::ViolationHearing
+            | CalendarEventType::SchedulingConference
+            | CalendarEventType::SettlementConference
+            | CalendarEventType::PretrialConference => 5,
+            CalendarEventType::InitialAppearance
+            | CalendarEventType::Arraignment
+            | CalendarEventType::BailHearing
+            | CalendarEventType::PleaHearing
+            | CalendarEventType::StatusConference
+            | CalendarEventType::EmergencyHearing
+            | CalendarEventType::Telephonic
+            | CalendarEventType::VideoConference => 0,
+        }
+    }
+}
+
 /// Event status
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -186,6 +431,20 @@ pub struct SpeedyTrialClock {
     pub waived: bool,
 }
 
+/// Docket entry awaiting a ruling from the assigned judge
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct JudicialActionItem {
+    pub entry: DocketEntry,
+    pub days_pending: i64,
+}
+
+/// Speedy Trial clock past its deadline, paired with how far over it is
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SpeedyTrialViolation {
+    pub clock: SpeedyTrialClock,
+    pub days_over: i64,
+}
+
 /// Excludable delay periods under Speedy Trial Act
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ExcludableDelay {
@@ -240,13 +499,28 @@ impl DocketService {
         }
     }
 
-    /// Generate automatic minute entry for an event
-    pub fn generate_minute_entry(event: &CalendarEntry) -> DocketEntry {
-        let description = format!(
+    /// Text of the minute entry for an event, with the hearing's minutes
+    /// once recorded
+    pub fn minute_text(event: &CalendarEntry) -> String {
+        let mut text = format!(
             "Minute Entry for proceedings held before Judge on {}. {}",
             event.scheduled_date.format("%m/%d/%Y"),
             event.description
         );
+        if let Some(minutes) = &event.minutes {
+            for sentence in minutes.sentences() {
+                if !text.ends_with(' ') {
+                    text.push(' ');
+                }
+                text.push_str(&sentence);
+            }
+        }
+        text
+    }
+
+    /// Generate automatic minute entry for an event
+    pub fn generate_minute_entry(event: &CalendarEntry) -> DocketEntry {
+        let description = Self::minute_text(event);
 
         DocketEntry {
             id: Uuid::new_v4(),
@@ -263,7 +537,7 @@ impl DocketService {
             page_count: None,
             attachments: Vec::new(),
             related_entries: Vec::new(),
-            service_list: event.participants.clone(),
+            service_list: event.participants.iter().map(|p| p.name.clone()).collect(),
         }
     }
 
@@ -278,6 +552,61 @@ impl DocketService {
             DocketEntryType::HearingNotice
         )
     }
+
+    /// Check if a filing awaits a ruling from the judge
+    pub fn requires_judicial_action(entry_type: &DocketEntryType) -> bool {
+        matches!(
+            entry_type,
+            DocketEntryType::Motion |
+            DocketEntryType::ProposedOrder
+        )
+    }
+
+    /// Check if an entry is a ruling that can dispose of pending filings
+    pub fn is_judicial_ruling(entry_type: &DocketEntryType) -> bool {
+        matches!(
+            entry_type,
+            DocketEntryType::Order |
+            DocketEntryType::MinuteOrder |
+            DocketEntryType::SchedulingOrder |
+            DocketEntryType::ProtectiveOrder |
+            DocketEntryType::SealingOrder |
+            DocketEntryType::Judgment
+        )
+    }
+
+    /// Entries on a case docket that require judicial action and have not
+    /// been addressed by a ruling referencing them in `related_entries`
+    pub fn pending_judicial_action(entries: &[DocketEntry]) -> Vec<&DocketEntry> {
+        entries
+            .iter()
+            .filter(|e| Self::requires_judicial_action(&e.entry_type))
+            .filter(|e| {
+                !entries.iter().any(|ruling| {
+                    Self::is_judicial_ruling(&ruling.entry_type)
+                        && ruling.related_entries.contains(&e.entry_number)
+                })
+            })
+            .collect()
+    }
+
+    /// Build a judge's action queue from the dockets of their cases, oldest first
+    pub fn judicial_action_queue(
+        dockets: &[Vec<DocketEntry>],
+        as_of: DateTime<Utc>,
+    ) -> Vec<JudicialActionItem> {
+        let mut queue: Vec<JudicialActionItem> = dockets
+            .iter()
+            .flat_map(|entries| Self::pending_judicial_action(entries))
+            .map(|entry| JudicialActionItem {
+                days_pending: (as_of - entry.date_filed).num_days(),
+                entry: entry.clone(),
+            })
+            .collect();
+
+        queue.sort_by_key(|item| item.entry.date_filed);
+        queue
+    }
 }
 
 /// Service for calendar management
@@ -310,9 +639,26 @@ impl CalendarService {
             actual_end: None,
             status: EventStatus::Scheduled,
             notes: String::new(),
+            minutes: None,
         }
     }
 
+    /// Business days of notice given by scheduling an event for `scheduled`
+    /// on `today`: the business days after today up to and including the
+    /// event day
+    pub fn business_days_of_notice(
+        today: NaiveDate,
+        scheduled: NaiveDate,
+        is_business_day: impl Fn(NaiveDate) -> bool,
+    ) -> i64 {
+        today
+            .iter_days()
+            .skip(1)
+            .take_while(|day| *day <= scheduled)
+            .filter(|day| is_business_day(*day))
+            .count() as i64
+    }
+
     /// Check for scheduling conflicts
     pub fn check_conflicts(
         existing_events: &[CalendarEntry],
@@ -347,11 +693,17 @@ impl CalendarService {
         judge_id: Uuid,
         duration_minutes: u32,
         earliest_date: DateTime<Utc>,
+        closures: &[NaiveDate],
     ) -> DateTime<Utc> {
+        let is_closed = |date: DateTime<Utc>| {
+            date.weekday() == Weekday::Sat
+                || date.weekday() == Weekday::Sun
+                || closures.contains(&date.date_naive())
+        };
         let mut candidate_date = earliest_date;
 
-        // Skip weekends
-        while candidate_date.weekday() == Weekday::Sat || candidate_date.weekday() == Weekday::Sun {
+        // Skip weekends and court closures
+        while is_closed(candidate_date) {
             candidate_date = candidate_date + Duration::days(1);
         }
 
@@ -382,6 +734,7 @@ impl CalendarService {
                 actual_end: None,
                 status: EventStatus::Scheduled,
                 notes: String::new(),
+                minutes: None,
             };
 
             let conflicts = Self::check_conflicts(existing_events, &test_event);
@@ -402,8 +755,8 @@ impl CalendarService {
                     .and_local_timezone(Utc)
                     .unwrap();
 
-                // Skip weekends
-                while candidate_date.weekday() == Weekday::Sat || candidate_date.weekday() == Weekday::Sun {
+                // Skip weekends and court closures
+                while is_closed(candidate_date) {
                     candidate_date = candidate_date + Duration::days(1);
                 }
             }
@@ -457,4 +810,245 @@ impl SpeedyTrialService {
     pub fn is_deadline_violated(clock: &SpeedyTrialClock) -> bool {
         clock.days_remaining < 0 && !clock.waived
     }
-}
\ No newline at end of file
+
+    /// Days past the deadline, or zero if the clock is not in violation
+    pub fn days_over(clock: &SpeedyTrialClock) -> i64 {
+        if Self::is_deadline_violated(clock) {
+            -clock.days_remaining
+        } else {
+            0
+        }
+    }
+
+    /// Build a violations report, most overdue first
+    pub fn violation_report(
+        clocks: Vec<SpeedyTrialClock>,
+        min_days_over: i64,
+    ) -> Vec<SpeedyTrialViolation> {
+        let mut violations: Vec<SpeedyTrialViolation> = clocks
+            .into_iter()
+            .filter(Self::is_deadline_violated)
+            .map(|clock| SpeedyTrialViolation {
+                days_over: Self::days_over(&clock),
+                clock,
+            })
+            .filter(|v| v.days_over >= min_days_over)
+            .collect();
+
+        violations.sort_by_key(|v| std::cmp::Reverse(v.days_over));
+        violations
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn entry(entry_number: u32, entry_type: DocketEntryType, days_ago: i64) -> DocketEntry {
+        let mut entry = DocketService::create_entry(
+            Uuid::nil(),
+            entry_type,
+            format!("Entry {}", entry_number),
+            None,
+        );
+        entry.entry_number = entry_number;
+        entry.date_filed = Utc::now() - Duration::days(days_ago);
+        entry
+    }
+
+    #[test]
+    fn test_pending_judicial_action_excludes_informational_and_ruled() {
+        let mut order = entry(4, DocketEntryType::Order, 1);
+        order.related_entries = vec![2];
+        let docket = vec![
+            entry(1, DocketEntryType::Notice, 10),
+            entry(2, DocketEntryType::Motion, 8),
+            entry(3, DocketEntryType::ProposedOrder, 5),
+            order,
+        ];
+
+        let pending: Vec<u32> = DocketService::pending_judicial_action(&docket)
+            .iter()
+            .map(|e| e.entry_number)
+            .collect();
+        assert_eq!(pending, vec![3]);
+    }
+
+    #[test]
+    fn test_available_slot_skips_closures() {
+        // Friday, March 14, 2025 at 10:00 is closed; so is Monday the 17th
+        let earliest = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap()
+            .and_hms_opt(10, 0, 0).unwrap()
+            .and_utc();
+        let closures = [
+            NaiveDate::from_ymd_opt(2025, 3, 14).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 3, 17).unwrap(),
+        ];
+
+        let slot = CalendarService::find_next_available_slot(&[], Uuid::new_v4(), 60, earliest, &closures);
+        assert_eq!(slot.date_naive(), NaiveDate::from_ymd_opt(2025, 3, 18).unwrap());
+    }
+
+    #[test]
+    fn test_judicial_action_queue_oldest_first() {
+        let dockets = vec![
+            vec![entry(1, DocketEntryType::Motion, 3)],
+            vec![entry(1, DocketEntryType::Motion, 20), entry(2, DocketEntryType::Notice, 30)],
+        ];
+
+        let queue = DocketService::judicial_action_queue(&dockets, Utc::now());
+        let ages: Vec<i64> = queue.iter().map(|item| item.days_pending).collect();
+        assert_eq!(ages, vec![20, 3]);
+    }
+
+    fn clock_with_remaining(days_remaining: i64, waived: bool) -> SpeedyTrialClock {
+        SpeedyTrialClock {
+            case_id: Uuid::new_v4(),
+            arrest_date: None,
+            indictment_date: None,
+            arraignment_date: None,
+            trial_start_deadline: Utc::now(),
+            excludable_delays: Vec::new(),
+            days_elapsed: 70 - days_remaining,
+            days_remaining,
+            is_tolled: false,
+            waived,
+        }
+    }
+
+    #[test]
+    fn test_days_over() {
+        assert_eq!(SpeedyTrialService::days_over(&clock_with_remaining(-12, false)), 12);
+        assert_eq!(SpeedyTrialService::days_over(&clock_with_remaining(5, false)), 0);
+        assert_eq!(SpeedyTrialService::days_over(&clock_with_remaining(-12, true)), 0);
+    }
+
+    #[test]
+    fn test_violation_report_sorted_and_filtered() {
+        let clocks = vec![
+            clock_with_remaining(-3, false),
+            clock_with_remaining(-40, false),
+            clock_with_remaining(10, false),
+            clock_with_remaining(-15, false),
+            clock_with_remaining(-90, true),
+        ];
+
+        let report = SpeedyTrialService::violation_report(clocks.clone(), 0);
+        let days: Vec<i64> = report.iter().map(|v| v.days_over).collect();
+        assert_eq!(days, vec![40, 15, 3]);
+
+        let report = SpeedyTrialService::violation_report(clocks, 15);
+        let days: Vec<i64> = report.iter().map(|v| v.days_over).collect();
+        assert_eq!(days, vec![40, 15]);
+    }
+
+    #[test]
+    fn test_business_days_of_notice_skips_non_business_days() {
+        let weekday = |d: NaiveDate| d.weekday() != Weekday::Sat && d.weekday() != Weekday::Sun;
+        // Friday 2030-03-01 to Friday 2030-03-08: Mon-Fri of the following week
+        let friday = NaiveDate::from_ymd_opt(2030, 3, 1).unwrap();
+        let next_friday = NaiveDate::from_ymd_opt(2030, 3, 8).unwrap();
+
+        assert_eq!(CalendarService::business_days_of_notice(friday, next_friday, weekday), 5);
+        assert_eq!(CalendarService::business_days_of_notice(friday, friday, weekday), 0);
+        assert_eq!(CalendarService::business_days_of_notice(next_friday, friday, weekday), 0);
+    }
+
+    #[test]
+    fn test_calendar_event_type_config_key() {
+        assert_eq!(CalendarEventType::MotionHearing.config_key(), "motion_hearing");
+        assert_eq!(CalendarEventType::EmergencyHearing.default_minimum_notice_days(), 0);
+    }
+
+    #[test]
+    fn test_participants_accept_legacy_names() {
+        let participants: Vec<EventParticipant> = serde_json::from_value(serde_json::json!([
+            "Government",
+            {"kind": "attorney", "id": "atty-1", "name": "Jane Roe", "required": false},
+            {"kind": "party", "id": "party-1"}
+        ]))
+        .unwrap();
+
+        assert_eq!(participants[0], EventParticipant::named("Government"));
+        assert_eq!(participants[1].kind, ParticipantKind::Attorney);
+        assert!(!participants[1].required);
+        assert_eq!(participants[2].id.as_deref(), Some("party-1"));
+        assert!(participants[2].required);
+
+        // Structured participants survive a round trip unchanged
+        let stored = serde_json::to_value(&participants).unwrap();
+        assert_eq!(serde_json::from_value::<Vec<EventParticipant>>(stored).unwrap(), participants);
+    }
+
+    #[test]
+    fn test_minute_entry_without_minutes_is_bare() {
+        let mut event = CalendarService::schedule_event(
+            Uuid::nil(),
+            Uuid::nil(),
+            CalendarEventType::StatusConference,
+            Utc.with_ymd_and_hms(2025, 3, 14, 15, 0, 0).unwrap(),
+            30,
+            "4A".to_string(),
+        );
+        event.description = "Status conference held.".to_string();
+
+        let entry = DocketService::generate_minute_entry(&event);
+        assert_eq!(
+            entry.description,
+            "Minute Entry for proceedings held before Judge on 03/14/2025. Status conference held."
+        );
+        assert!(matches!(entry.entry_type, DocketEntryType::MinuteOrder));
+    }
+
+    #[test]
+    fn test_minute_entry_includes_hearing_minutes() {
+        let mut event = CalendarService::schedule_event(
+            Uuid::nil(),
+            Uuid::nil(),
+            CalendarEventType::MotionHearing,
+            Utc.with_ymd_and_hms(2025, 3, 14, 15, 0, 0).unwrap(),
+            60,
+            "4A".to_string(),
+        );
+        event.minutes = Some(HearingMinutes {
+            appearances: vec![
+                Appearance {
+                    attorney_id: Some("atty-1".to_string()),
+                    name: "Jane Roe".to_string(),
+                    appearing_for: Some("the United States".to_string()),
+                },
+                Appearance { attorney_id: None, name: "John Doe".to_string(), appearing_for: None },
+            ],
+            court_reporter: Some("Ann Smith".to_string()),
+            rulings: vec!["Motion to suppress denied".to_string(), "Motion in limine granted".to_string()],
+            exhibits_admitted: vec!["Government Exhibit 1".to_string(), "Defense Exhibit A".to_string()],
+            next_scheduled_date: Some(Utc.with_ymd_and_hms(2025, 4, 15, 14, 30, 0).unwrap()),
+        });
+
+        let minutes = event.minutes.as_ref().unwrap();
+        assert_eq!(minutes.attorney_ids().collect::<Vec<_>>(), vec!["atty-1"]);
+
+        let entry = DocketService::generate_minute_entry(&event);
+        assert_eq!(
+            entry.description,
+            "Minute Entry for proceedings held before Judge on 03/14/2025. \
+             Appearances: Jane Roe for the United States; John Doe. \
+             Court Reporter: Ann Smith. \
+             Rulings: Motion to suppress denied; Motion in limine granted. \
+             Exhibits admitted: Government Exhibit 1, Defense Exhibit A. \
+             Next hearing set for 04/15/2025 02:30 PM."
+        );
+    }
+
+    #[test]
+    fn test_minutes_default_when_fields_omitted() {
+        let minutes: HearingMinutes = serde_json::from_value(serde_json::json!({
+            "appearances": [{"name": "Jane Roe"}]
+        }))
+        .unwrap();
+        assert_eq!(minutes.appearances[0].attorney_id, None);
+        assert!(minutes.rulings.is_empty());
+        assert_eq!(minutes.sentences(), vec!["Appearances: Jane Roe.".to_string()]);
+        assert!(HearingMinutes::default().sentences().is_empty());
+    }
+}
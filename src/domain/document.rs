@@ -1,7 +1,10 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use super::deadline::ComplianceReportDetail;
+
 // Re-export common ElectronicSignature so existing import paths work
 pub use super::common::ElectronicSignature;
 
@@ -54,6 +57,98 @@ impl JudgeName {
     }
 }
 
+/// Defendants named in a case caption
+///
+/// Requests may send one string, read as names separated by semicolons or
+/// new lines, or a list of names.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(from = "DefendantNamesInput", into = "Vec<String>")]
+pub struct DefendantNames(Vec<String>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DefendantNamesInput {
+    Joined(String),
+    List(Vec<String>),
+}
+
+impl From<DefendantNamesInput> for DefendantNames {
+    fn from(input: DefendantNamesInput) -> Self {
+        match input {
+            DefendantNamesInput::Joined(names) => Self::parse(&names),
+            DefendantNamesInput::List(names) => Self::new(names),
+        }
+    }
+}
+
+impl From<DefendantNames> for Vec<String> {
+    fn from(names: DefendantNames) -> Self {
+        names.0
+    }
+}
+
+impl DefendantNames {
+    /// Most defendants named in the caption itself; with more, the caption
+    /// names the first "et al." and the full list follows on a continuation
+    /// line
+    pub const CAPTION_LIMIT: usize = 2;
+
+    /// Names in order, trimmed, with blank ones dropped
+    pub fn new<S: Into<String>>(names: impl IntoIterator<Item = S>) -> Self {
+        Self(
+            names
+                .into_iter()
+                .map(|name| name.into().trim().to_string())
+                .filter(|name| !name.is_empty())
+                .collect(),
+        )
+    }
+
+    /// Names separated by semicolons or new lines; commas are kept, as in
+    /// "Smith, John"
+    pub fn parse(names: &str) -> Self {
+        Self::new(names.split([';', '\n']))
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.0
+    }
+
+    /// The defendants as the caption names them: "Smith", "Smith and
+    /// Jones", or "Smith, et al." past [`Self::CAPTION_LIMIT`]
+    pub fn caption_name(&self) -> String {
+        match self.0.as_slice() {
+            [] => String::new(),
+            [only] => only.clone(),
+            names if names.len() <= Self::CAPTION_LIMIT => {
+                let (rest, last) = names.split_at(names.len() - 1);
+                format!("{} and {}", rest.join(", "), last[0])
+            }
+            [first, ..] => format!("{}, et al.", first),
+        }
+    }
+
+    /// Short case title, e.g. "United States v. Smith, et al."
+    pub fn case_title(&self) -> String {
+        format!("United States v. {}", self.caption_name())
+    }
+
+    /// Every defendant, for the continuation line of a caption that
+    /// shortens them to "et al."
+    pub fn continuation(&self) -> Option<String> {
+        (self.0.len() > Self::CAPTION_LIMIT).then(|| format!("Defendants: {}", self.0.join("; ")))
+    }
+
+    /// "Defendant." or "Defendants." under the caption
+    pub fn designation(&self) -> &'static str {
+        if self.0.len() > 1 {
+            "Defendants."
+        } else {
+            "Defendant."
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct District(String);
 
@@ -78,6 +173,66 @@ pub enum DocumentType {
     WaiverIndictment,
     ConditionsRelease,
     CriminalJudgment,
+    Summons,
+    Subpoena,
+    ComplianceReport,
+    SlipOpinion,
+}
+
+impl DocumentType {
+    pub const ALL: [DocumentType; 10] = [
+        DocumentType::Rule16b,
+        DocumentType::CourtOrder,
+        DocumentType::MinuteEntry,
+        DocumentType::WaiverIndictment,
+        DocumentType::ConditionsRelease,
+        DocumentType::CriminalJudgment,
+        DocumentType::Summons,
+        DocumentType::Subpoena,
+        DocumentType::ComplianceReport,
+        DocumentType::SlipOpinion,
+    ];
+
+    /// Name used in API responses and the document index
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocumentType::Rule16b => "rule16b",
+            DocumentType::CourtOrder => "court_order",
+            DocumentType::MinuteEntry => "minute_entry",
+            DocumentType::WaiverIndictment => "waiver_indictment",
+            DocumentType::ConditionsRelease => "conditions_release",
+            DocumentType::CriminalJudgment => "criminal_judgment",
+            DocumentType::Summons => "summons",
+            DocumentType::Subpoena => "subpoena",
+            DocumentType::ComplianceReport => "compliance_report",
+            DocumentType::SlipOpinion => "slip_opinion",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.as_str() == value)
+    }
+
+    /// Metadata fields a document of this type is generated from
+    pub fn metadata_fields(&self) -> &'static [&'static str] {
+        match self {
+            DocumentType::Rule16b => &["defendant_names", "judge_name", "signed"],
+            DocumentType::CourtOrder => &["defendant_names", "judge_name", "order_title", "order_content", "signed"],
+            DocumentType::MinuteEntry => &["defendant_names", "judge_name", "minute_text"],
+            DocumentType::WaiverIndictment => &["defendant_name", "charges"],
+            DocumentType::ConditionsRelease => &["defendant_name", "judge_name", "conditions"],
+            DocumentType::CriminalJudgment => &["defendant_name", "judge_name", "plea", "counts", "sentence"],
+            DocumentType::Summons => &["party_name", "party_address", "response_days"],
+            DocumentType::Subpoena => &["recipient_name", "appearance_date", "location", "judge_name"],
+            DocumentType::ComplianceReport => &[
+                "period_start", "period_end", "deadlines_tracked", "completed_on_time",
+                "compliance_rate", "categories", "missed_jurisdictional", "trend",
+            ],
+            DocumentType::SlipOpinion => &[
+                "citation", "case_name", "title", "judge_name", "syllabus", "content", "published_at",
+            ],
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -88,26 +243,122 @@ pub struct CourtDocument {
     pub district: District,
     pub created_at: DateTime<Utc>,
     pub metadata: DocumentMetadata,
+    pub layout: PageLayout,
+}
+
+/// Paper size of a generated document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub enum PageSize {
+    /// 8.5 x 11 inches
+    #[default]
+    Letter,
+    /// 8.5 x 14 inches
+    Legal,
+}
+
+impl PageSize {
+    /// Width and height in points (72 per inch)
+    pub fn dimensions(&self) -> (f32, f32) {
+        match self {
+            PageSize::Letter => (612.0, 792.0),
+            PageSize::Legal => (612.0, 1008.0),
+        }
+    }
+}
+
+/// Page margins in points (72 per inch); omitted sides stay at one inch
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(default)]
+pub struct Margins {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Margins {
+    pub const DEFAULT: f32 = 72.0;
+    /// Narrowest margin accepted, a quarter inch
+    pub const MIN: f32 = 18.0;
+    /// Widest margin accepted, two inches
+    pub const MAX: f32 = 144.0;
+}
+
+impl Default for Margins {
+    fn default() -> Self {
+        Self {
+            top: Self::DEFAULT,
+            right: Self::DEFAULT,
+            bottom: Self::DEFAULT,
+            left: Self::DEFAULT,
+        }
+    }
+}
+
+/// Paper size and margins of a generated document, US Letter with one-inch
+/// margins unless the request says otherwise
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, ToSchema)]
+pub struct PageLayout {
+    #[serde(default)]
+    pub page_size: PageSize,
+    #[serde(default)]
+    pub margins: Margins,
+}
+
+impl PageLayout {
+    /// Check every margin is within [`Margins::MIN`] and [`Margins::MAX`]
+    pub fn validate(&self) -> Result<(), DocumentError> {
+        let margins = &self.margins;
+        let sides = [
+            ("top", margins.top),
+            ("right", margins.right),
+            ("bottom", margins.bottom),
+            ("left", margins.left),
+        ];
+        for (side, margin) in sides {
+            if !(Margins::MIN..=Margins::MAX).contains(&margin) {
+                return Err(DocumentError::InvalidLayout(format!(
+                    "{} margin must be between {} and {} points, got {}",
+                    side,
+                    Margins::MIN,
+                    Margins::MAX,
+                    margin
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Width and height of the page in points
+    pub fn dimensions(&self) -> (f32, f32) {
+        self.page_size.dimensions()
+    }
+
+    /// Width between the left and right margins
+    pub fn text_width(&self) -> f32 {
+        self.dimensions().0 - self.margins.left - self.margins.right
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum DocumentMetadata {
     Rule16b {
-        defendant_names: String,
+        defendant_names: DefendantNames,
         judge_name: JudgeName,
         signature: Option<ElectronicSignature>,
     },
     CourtOrder {
-        defendant_names: String,
+        defendant_names: DefendantNames,
         judge_name: JudgeName,
         order_title: String,
         order_content: String,
         signature: Option<ElectronicSignature>,
     },
     MinuteEntry {
-        defendant_names: String,
+        defendant_names: DefendantNames,
         judge_name: JudgeName,
         minute_text: String,
+        signature: Option<ElectronicSignature>,
     },
     WaiverIndictment {
         defendant_name: String,
@@ -125,6 +376,100 @@ pub enum DocumentMetadata {
         counts: String,
         sentence: String,
     },
+    Summons {
+        party_name: String,
+        party_address: Option<String>,
+        response_days: u32,
+    },
+    /// Subpoena to appear and testify; the case number is the document's
+    Subpoena {
+        recipient_name: String,
+        appearance_date: DateTime<Utc>,
+        location: String,
+        judge_name: JudgeName,
+    },
+    ComplianceReport {
+        report: ComplianceReportDetail,
+    },
+    /// Published opinion; the case number is the opinion's docket number
+    SlipOpinion {
+        citation: String,
+        case_name: String,
+        title: String,
+        judge_name: JudgeName,
+        syllabus: String,
+        content: String,
+        published_at: DateTime<Utc>,
+    },
+}
+
+impl DocumentMetadata {
+    /// Generation parameters as recorded in the document index
+    ///
+    /// Signatures are reduced to a `signed` flag so signature images never
+    /// end up in the index.
+    pub fn index_fields(&self) -> serde_json::Value {
+        use serde_json::json;
+        match self {
+            DocumentMetadata::Rule16b { defendant_names, judge_name, signature } => json!({
+                "defendant_names": defendant_names,
+                "judge_name": judge_name.as_str(),
+                "signed": signature.is_some(),
+            }),
+            DocumentMetadata::CourtOrder { defendant_names, judge_name, order_title, order_content, signature } => json!({
+                "defendant_names": defendant_names,
+                "judge_name": judge_name.as_str(),
+                "order_title": order_title,
+                "order_content": order_content,
+                "signed": signature.is_some(),
+            }),
+            DocumentMetadata::MinuteEntry { defendant_names, judge_name, minute_text, signature } => json!({
+                "defendant_names": defendant_names,
+                "judge_name": judge_name.as_str(),
+                "minute_text": minute_text,
+                "signed": signature.is_some(),
+            }),
+            DocumentMetadata::WaiverIndictment { defendant_name, charges } => json!({
+                "defendant_name": defendant_name,
+                "charges": charges,
+            }),
+            DocumentMetadata::ConditionsRelease { defendant_name, judge_name, conditions } => json!({
+                "defendant_name": defendant_name,
+                "judge_name": judge_name.as_str(),
+                "conditions": conditions,
+            }),
+            DocumentMetadata::CriminalJudgment { defendant_name, judge_name, plea, counts, sentence } => json!({
+                "defendant_name": defendant_name,
+                "judge_name": judge_name.as_str(),
+                "plea": plea,
+                "counts": counts,
+                "sentence": sentence,
+            }),
+            DocumentMetadata::Summons { party_name, party_address, response_days } => json!({
+                "party_name": party_name,
+                "party_address": party_address,
+                "response_days": response_days,
+            }),
+            DocumentMetadata::Subpoena { recipient_name, appearance_date, location, judge_name } => json!({
+                "recipient_name": recipient_name,
+                "appearance_date": appearance_date,
+                "location": location,
+                "judge_name": judge_name.as_str(),
+            }),
+            DocumentMetadata::ComplianceReport { report } => {
+                serde_json::to_value(report).unwrap_or_else(|_| json!({}))
+            }
+            DocumentMetadata::SlipOpinion { citation, case_name, title, judge_name, syllabus, content, published_at } => json!({
+                "citation": citation,
+                "case_name": case_name,
+                "title": title,
+                "judge_name": judge_name.as_str(),
+                "syllabus": syllabus,
+                "content": content,
+                "published_at": published_at,
+            }),
+        }
+    }
 }
 
 // ElectronicSignature is imported from common module
@@ -134,6 +479,7 @@ pub struct GeneratedDocument {
     pub document: CourtDocument,
     pub pdf_data: Vec<u8>,
     pub filename: String,
+    pub page_count: usize,
 }
 
 impl GeneratedDocument {
@@ -143,11 +489,161 @@ impl GeneratedDocument {
     }
 }
 
+/// Index record for a generated court document
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DocumentIndexEntry {
+    pub document_id: String,
+    /// Document type name, e.g. `summons`
+    pub document_type: String,
+    pub case_number: String,
+    /// Case the document was issued in, when the generator knew it
+    #[serde(default)]
+    pub case_id: Option<Uuid>,
+    pub district: String,
+    pub filename: String,
+    pub generated_at: DateTime<Utc>,
+    /// Parameters the document was generated from
+    #[schema(value_type = Object)]
+    pub metadata: serde_json::Value,
+}
+
+impl DocumentIndexEntry {
+    pub fn from_generated(generated: &GeneratedDocument, case_id: Option<Uuid>) -> Self {
+        let document = &generated.document;
+        Self {
+            document_id: document.id.as_uuid().to_string(),
+            document_type: document.document_type.as_str().to_string(),
+            case_number: document.case_number.as_str().to_string(),
+            case_id,
+            district: document.district.as_str().to_string(),
+            filename: generated.filename.clone(),
+            generated_at: document.created_at,
+            metadata: document.metadata.index_fields(),
+        }
+    }
+
+    /// Problems with the entry's type and metadata, without consulting other records
+    ///
+    /// The metadata must be an object with exactly the fields its document
+    /// type is generated from; anything else could not reproduce the document.
+    pub fn shape_anomalies(&self) -> Vec<IndexAnomaly> {
+        let anomaly = |kind, detail: String| IndexAnomaly {
+            document_id: self.document_id.clone(),
+            kind,
+            detail,
+        };
+
+        let Some(document_type) = DocumentType::from_name(&self.document_type) else {
+            return vec![anomaly(
+                IndexAnomalyKind::UnknownDocumentType,
+                format!("Unknown document type '{}'", self.document_type),
+            )];
+        };
+
+        let Some(fields) = self.metadata.as_object() else {
+            return vec![anomaly(
+                IndexAnomalyKind::MetadataMismatch,
+                format!("Metadata for {} is not an object", self.document_type),
+            )];
+        };
+
+        let expected = document_type.metadata_fields();
+        let mut anomalies = Vec::new();
+        let missing: Vec<&str> = expected.iter().copied().filter(|f| !fields.contains_key(*f)).collect();
+        if !missing.is_empty() {
+            anomalies.push(anomaly(
+                IndexAnomalyKind::MetadataMismatch,
+                format!("{} metadata is missing {}", self.document_type, missing.join(", ")),
+            ));
+        }
+        let mut unexpected: Vec<&str> = fields
+            .keys()
+            .map(String::as_str)
+            .filter(|f| !expected.contains(f))
+            .collect();
+        unexpected.sort_unstable();
+        if !unexpected.is_empty() {
+            anomalies.push(anomaly(
+                IndexAnomalyKind::MetadataMismatch,
+                format!("{} metadata has unexpected {}", self.document_type, unexpected.join(", ")),
+            ));
+        }
+        anomalies
+    }
+}
+
+/// What is wrong with a document index entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexAnomalyKind {
+    UnknownDocumentType,
+    MetadataMismatch,
+    CaseNotFound,
+}
+
+/// A document index entry that fails the integrity audit
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct IndexAnomaly {
+    pub document_id: String,
+    pub kind: IndexAnomalyKind,
+    pub detail: String,
+}
+
+/// Result of auditing the document index
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DocumentIndexAudit {
+    pub checked: usize,
+    pub anomaly_count: usize,
+    pub anomalies: Vec<IndexAnomaly>,
+}
+
+/// Check a signature image failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureCheck {
+    /// Not valid base64
+    Base64,
+    /// Larger than the district allows
+    ByteSize,
+    /// Neither a PNG nor a JPEG
+    Format,
+    /// PNG or JPEG header cut short or malformed
+    Header,
+    /// Width or height zero or larger than the district allows
+    Dimensions,
+}
+
+/// Why a signature image was refused
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct SignatureRejection {
+    pub check: SignatureCheck,
+    pub message: String,
+    /// Measured size, for the size checks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<u64>,
+    /// Limit the size was held to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<u64>,
+}
+
+impl SignatureRejection {
+    pub fn new(check: SignatureCheck, message: impl Into<String>) -> Self {
+        Self { check, message: message.into(), value: None, max: None }
+    }
+
+    /// A size check that measured `value` against `max`
+    pub fn over_limit(check: SignatureCheck, message: impl Into<String>, value: u64, max: u64) -> Self {
+        Self { check, message: message.into(), value: Some(value), max: Some(max) }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum DocumentError {
     InvalidCaseNumber,
     InvalidJudgeName,
     InvalidDistrict,
+    InvalidSignature(SignatureRejection),
+    InvalidLayout(String),
     GenerationFailed(String),
 }
 
@@ -157,9 +653,84 @@ impl std::fmt::Display for DocumentError {
             Self::InvalidCaseNumber => write!(f, "Invalid case number"),
             Self::InvalidJudgeName => write!(f, "Invalid judge name"),
             Self::InvalidDistrict => write!(f, "Invalid district"),
+            Self::InvalidSignature(rejection) => write!(f, "Invalid signature: {}", rejection.message),
+            Self::InvalidLayout(msg) => write!(f, "Invalid page layout: {}", msg),
             Self::GenerationFailed(msg) => write!(f, "Document generation failed: {}", msg),
         }
     }
 }
 
-impl std::error::Error for DocumentError {}
\ No newline at end of file
+impl DocumentError {
+    /// Machine-readable code for the error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidCaseNumber => "INVALID_CASE_NUMBER",
+            Self::InvalidJudgeName => "INVALID_JUDGE_NAME",
+            Self::InvalidDistrict => "INVALID_DISTRICT",
+            Self::InvalidSignature(_) => "INVALID_SIGNATURE",
+            Self::InvalidLayout(_) => "INVALID_LAYOUT",
+            Self::GenerationFailed(_) => "GENERATION_FAILED",
+        }
+    }
+
+    /// Whether trying again might succeed
+    ///
+    /// Invalid input fails the same way every time; only rendering is retried.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::GenerationFailed(_))
+    }
+}
+
+impl std::error::Error for DocumentError {}
+
+/// A document in a batch that could not be generated
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BatchFailure {
+    /// Zero-based position of the document in the request
+    pub index: usize,
+    pub case_number: String,
+    /// Machine-readable code, such as `INVALID_CASE_NUMBER`
+    pub code: String,
+    pub error: String,
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caption_for_one_defendant() {
+        let names = DefendantNames::new(["Smith"]);
+        assert_eq!(names.case_title(), "United States v. Smith");
+        assert_eq!(names.designation(), "Defendant.");
+        assert_eq!(names.continuation(), None);
+    }
+
+    #[test]
+    fn test_caption_for_two_defendants() {
+        let names = DefendantNames::new(["Smith", "Jones"]);
+        assert_eq!(names.case_title(), "United States v. Smith and Jones");
+        assert_eq!(names.designation(), "Defendants.");
+        assert_eq!(names.continuation(), None);
+    }
+
+    #[test]
+    fn test_caption_for_five_defendants() {
+        let names = DefendantNames::new(["Smith", "Jones", "Brown", "Garcia", "Lee"]);
+        assert_eq!(names.case_title(), "United States v. Smith, et al.");
+        assert_eq!(names.designation(), "Defendants.");
+        assert_eq!(names.continuation().as_deref(), Some("Defendants: Smith; Jones; Brown; Garcia; Lee"));
+    }
+
+    #[test]
+    fn test_names_from_string_or_list() {
+        let joined: DefendantNames = serde_json::from_value(serde_json::json!("Smith, John; Jones\n ;Brown")).unwrap();
+        assert_eq!(joined.names(), ["Smith, John", "Jones", "Brown"]);
+
+        let list: DefendantNames = serde_json::from_value(serde_json::json!(["Smith, John", " ", "Jones"])).unwrap();
+        assert_eq!(list.names(), ["Smith, John", "Jones"]);
+        assert_eq!(serde_json::to_value(&list).unwrap(), serde_json::json!(["Smith, John", "Jones"]));
+
+        // A plain name is one defendant, as before
+        assert_eq!(DefendantNames::parse("John Doe").names(), ["John Doe"]);
+    }
+}
@@ -2,9 +2,26 @@
 //!
 //! This module provides a centralized way to enable/disable features during development
 //! and allows for gradual rollout of new functionality.
+//!
+//! A feature can be rolled out district by district: a [`FeatureRollout`]
+//! denies or allows named districts outright and enables the feature for a
+//! percentage of the rest. Each district lands in a fixed bucket per feature,
+//! hashed from the feature path and the district, so raising the percentage
+//! only ever adds districts. A caller may instead give its own rollout key,
+//! such as a user ID, to bucket by that key rather than the district. A
+//! rollout may expire, after which the feature reverts to its configured
+//! default.
+//!
+//! Some features build on others, e.g. automated scheduling assigns judges.
+//! [`DependencyGraph`] records those prerequisites and refuses a cycle, and
+//! [`JudicialFeatures::enable`] won't turn a feature on while a prerequisite
+//! is off unless asked to cascade.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::fmt;
 use utoipa::ToSchema;
 
 /// Feature flags for the judicial system
@@ -67,11 +84,306 @@ impl Default for JudicialFeatures {
     }
 }
 
+/// Every configurable feature path
+pub const FEATURE_PATHS: [&str; 12] = [
+    "core.case_management",
+    "core.basic_docket",
+    "core.party_management",
+    "advanced.judge_assignment",
+    "advanced.automated_scheduling",
+    "advanced.sentencing_calculator",
+    "advanced.deadline_tracking",
+    "advanced.statistical_reporting",
+    "experimental.mdl_proceedings",
+    "experimental.ai_assisted_research",
+    "experimental.automated_transcription",
+    "experimental.predictive_analytics",
+];
+
+/// Features each feature needs enabled first
+pub const FEATURE_DEPENDENCIES: &[(&str, &[&str])] = &[
+    ("core.party_management", &["core.case_management"]),
+    ("advanced.judge_assignment", &["core.case_management"]),
+    ("advanced.automated_scheduling", &["advanced.judge_assignment", "core.basic_docket"]),
+    ("advanced.sentencing_calculator", &["core.case_management"]),
+    ("advanced.deadline_tracking", &["core.basic_docket"]),
+    ("advanced.statistical_reporting", &["core.case_management"]),
+    ("experimental.mdl_proceedings", &["core.party_management"]),
+    ("experimental.ai_assisted_research", &["core.basic_docket"]),
+    ("experimental.automated_transcription", &["advanced.automated_scheduling"]),
+    ("experimental.predictive_analytics", &["advanced.statistical_reporting"]),
+];
+
+/// Full path of a feature named by its path or by the short name
+/// `PATCH /api/features` has always accepted
+pub fn feature_path(name: &str) -> Option<&'static str> {
+    let path = match name {
+        "case_management" => "core.case_management",
+        "docket_management" => "core.basic_docket",
+        "party_management" => "core.party_management",
+        "judge_assignment" => "advanced.judge_assignment",
+        "calendar_scheduling" => "advanced.automated_scheduling",
+        "sentencing_calculator" => "advanced.sentencing_calculator",
+        "deadline_tracking" => "advanced.deadline_tracking",
+        "reporting" => "advanced.statistical_reporting",
+        "mdl_proceedings" => "experimental.mdl_proceedings",
+        "ai_assisted_research" => "experimental.ai_assisted_research",
+        "automated_transcription" => "experimental.automated_transcription",
+        "predictive_analytics" => "experimental.predictive_analytics",
+        other => return FEATURE_PATHS.iter().find(|p| **p == other).copied(),
+    };
+    Some(path)
+}
+
+/// Prerequisites that are declared in a loop
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyCycle(pub Vec<String>);
+
+impl fmt::Display for DependencyCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Feature dependencies form a cycle: {}", self.0.join(" -> "))
+    }
+}
+
+/// Which features need which others enabled first
+#[derive(Debug, Clone)]
+pub struct DependencyGraph {
+    requires: HashMap<String, Vec<String>>,
+}
+
+impl DependencyGraph {
+    /// Build a graph from each feature's direct prerequisites, rejecting a cycle
+    pub fn new<'a>(dependencies: impl IntoIterator<Item = (&'a str, &'a [&'a str])>) -> Result<Self, DependencyCycle> {
+        let requires = dependencies
+            .into_iter()
+            .map(|(feature, needs)| (feature.to_string(), needs.iter().map(|n| n.to_string()).collect()))
+            .collect();
+        let graph = Self { requires };
+        graph.check_acyclic()?;
+        Ok(graph)
+    }
+
+    /// The graph of [`FEATURE_DEPENDENCIES`]
+    pub fn standard() -> Self {
+        Self::new(FEATURE_DEPENDENCIES.iter().copied()).expect("FEATURE_DEPENDENCIES must not form a cycle")
+    }
+
+    /// Every feature `feature_path` needs, directly or through another,
+    /// with each listed after the features it needs
+    pub fn prerequisites(&self, feature_path: &str) -> Vec<String> {
+        let mut ordered = Vec::new();
+        self.collect(feature_path, &mut ordered);
+        ordered.retain(|f| f != feature_path);
+        ordered
+    }
+
+    fn collect(&self, feature: &str, ordered: &mut Vec<String>) {
+        for needed in self.requires.get(feature).into_iter().flatten() {
+            if !ordered.contains(needed) {
+                self.collect(needed, ordered);
+            }
+        }
+        if !ordered.iter().any(|f| f == feature) {
+            ordered.push(feature.to_string());
+        }
+    }
+
+    fn check_acyclic(&self) -> Result<(), DependencyCycle> {
+        let mut done: Vec<&str> = Vec::new();
+        let mut features: Vec<&String> = self.requires.keys().collect();
+        features.sort();
+        for feature in features {
+            self.visit(feature, &mut Vec::new(), &mut done)?;
+        }
+        Ok(())
+    }
+
+    /// Depth-first walk; `path` holds the features being visited
+    fn visit<'a>(&'a self, feature: &'a str, path: &mut Vec<&'a str>, done: &mut Vec<&'a str>) -> Result<(), DependencyCycle> {
+        if done.contains(&feature) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|f| *f == feature) {
+            let mut cycle: Vec<String> = path[start..].iter().map(|f| f.to_string()).collect();
+            cycle.push(feature.to_string());
+            return Err(DependencyCycle(cycle));
+        }
+        path.push(feature);
+        for needed in self.requires.get(feature).into_iter().flatten() {
+            self.visit(needed, path, done)?;
+        }
+        path.pop();
+        done.push(feature);
+        Ok(())
+    }
+}
+
+impl JudicialFeatures {
+    /// Whether a feature is enabled, or `None` for an unknown path
+    pub fn get(&self, feature_path: &str) -> Option<bool> {
+        let enabled = match feature_path {
+            "core.case_management" => self.core.case_management,
+            "core.basic_docket" => self.core.basic_docket,
+            "core.party_management" => self.core.party_management,
+            "advanced.judge_assignment" => self.advanced.judge_assignment,
+            "advanced.automated_scheduling" => self.advanced.automated_scheduling,
+            "advanced.sentencing_calculator" => self.advanced.sentencing_calculator,
+            "advanced.deadline_tracking" => self.advanced.deadline_tracking,
+            "advanced.statistical_reporting" => self.advanced.statistical_reporting,
+            "experimental.mdl_proceedings" => self.experimental.mdl_proceedings,
+            "experimental.ai_assisted_research" => self.experimental.ai_assisted_research,
+            "experimental.automated_transcription" => self.experimental.automated_transcription,
+            "experimental.predictive_analytics" => self.experimental.predictive_analytics,
+            _ => return None,
+        };
+        Some(enabled)
+    }
+
+    /// Set a feature, returning `false` for an unknown path
+    pub fn set(&mut self, feature_path: &str, enabled: bool) -> bool {
+        let flag = match feature_path {
+            "core.case_management" => &mut self.core.case_management,
+            "core.basic_docket" => &mut self.core.basic_docket,
+            "core.party_management" => &mut self.core.party_management,
+            "advanced.judge_assignment" => &mut self.advanced.judge_assignment,
+            "advanced.automated_scheduling" => &mut self.advanced.automated_scheduling,
+            "advanced.sentencing_calculator" => &mut self.advanced.sentencing_calculator,
+            "advanced.deadline_tracking" => &mut self.advanced.deadline_tracking,
+            "advanced.statistical_reporting" => &mut self.advanced.statistical_reporting,
+            "experimental.mdl_proceedings" => &mut self.experimental.mdl_proceedings,
+            "experimental.ai_assisted_research" => &mut self.experimental.ai_assisted_research,
+            "experimental.automated_transcription" => &mut self.experimental.automated_transcription,
+            "experimental.predictive_analytics" => &mut self.experimental.predictive_analytics,
+            _ => return false,
+        };
+        *flag = enabled;
+        true
+    }
+
+    /// Prerequisites of a feature that are currently disabled, each after
+    /// the features it needs
+    pub fn unmet_dependencies(&self, feature_path: &str, graph: &DependencyGraph) -> Vec<String> {
+        graph
+            .prerequisites(feature_path)
+            .into_iter()
+            .filter(|f| self.get(f) != Some(true))
+            .collect()
+    }
+
+    /// Enable a feature once its prerequisites are enabled
+    ///
+    /// With `cascade` the disabled prerequisites are enabled first and
+    /// returned; without it they are returned as the error and nothing
+    /// changes.
+    pub fn enable(&mut self, feature_path: &str, graph: &DependencyGraph, cascade: bool) -> Result<Vec<String>, Vec<String>> {
+        let unmet = self.unmet_dependencies(feature_path, graph);
+        if !unmet.is_empty() && !cascade {
+            return Err(unmet);
+        }
+        for feature in unmet.iter().map(String::as_str).chain([feature_path]) {
+            self.set(feature, true);
+        }
+        Ok(unmet)
+    }
+}
+
+/// Gradual rollout of one feature across districts
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FeatureRollout {
+    /// Percentage of districts, 0 to 100, that get the feature
+    #[serde(default)]
+    pub percentage: Option<u8>,
+    /// Districts that always get the feature, unless denied
+    #[serde(default)]
+    pub allow_districts: Vec<String>,
+    /// Districts that never get the feature; wins over every other rule
+    #[serde(default)]
+    pub deny_districts: Vec<String>,
+    /// When the rollout and the feature's override lapse
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl FeatureRollout {
+    /// Validate the percentage and normalize the district lists
+    ///
+    /// Districts are matched by tenant ID, which is lowercase.
+    pub fn normalized(mut self) -> Result<Self, String> {
+        if self.percentage.is_some_and(|p| p > 100) {
+            return Err("Rollout percentage must be between 0 and 100".to_string());
+        }
+        for districts in [&mut self.allow_districts, &mut self.deny_districts] {
+            *districts = districts
+                .iter()
+                .map(|d| d.trim().to_lowercase())
+                .filter(|d| !d.is_empty())
+                .collect();
+        }
+        Ok(self)
+    }
+
+    /// Whether the rollout has lapsed at `now`
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires| now >= expires)
+    }
+}
+
+/// The bucket, 0 to 99, a district falls in for a feature
+///
+/// Depends only on the feature path and the district, so every instance
+/// gives a district the same answer.
+pub fn rollout_bucket(feature_path: &str, district: &str) -> u8 {
+    let digest = Sha256::digest(format!("{}:{}", feature_path, district.to_lowercase()).as_bytes());
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&digest[..8]);
+    (u64::from_be_bytes(prefix) % 100) as u8
+}
+
+/// Whether a key falls within the first `rollout_percent` of a feature's
+/// buckets
+///
+/// The same key always gets the same answer, and raising the percentage
+/// only ever adds keys.
+pub fn is_enabled_for(feature_path: &str, key: &str, rollout_percent: u8) -> bool {
+    rollout_bucket(feature_path, key) < rollout_percent
+}
+
+/// Why a feature evaluated the way it did
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EvaluationReason {
+    /// The district is on the rollout's deny list
+    DistrictDenylist,
+    /// The district is on the rollout's allow list
+    DistrictAllowlist,
+    /// The district's bucket is, or isn't, below the rollout percentage
+    PercentageBucket,
+    /// A runtime override set the value
+    Override,
+    /// The rollout expired, so the configured default applies
+    Expired,
+    /// No override or rollout; the configured default applies
+    Default,
+}
+
+/// Outcome of evaluating a feature for a district
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FeatureEvaluation {
+    pub enabled: bool,
+    pub reason: EvaluationReason,
+    /// The district's or rollout key's bucket, when the percentage decided
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<u8>,
+}
+
 /// Feature flag manager for runtime feature toggling
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FeatureManager {
     pub features: JudicialFeatures,
     pub overrides: HashMap<String, bool>,
+    /// Per-feature rollouts, keyed like `overrides`
+    #[serde(default)]
+    pub rollouts: HashMap<String, FeatureRollout>,
 }
 
 impl FeatureManager {
@@ -79,6 +391,7 @@ impl FeatureManager {
         Self {
             features: JudicialFeatures::default(),
             overrides: HashMap::new(),
+            rollouts: HashMap::new(),
         }
     }
 
@@ -86,58 +399,82 @@ impl FeatureManager {
         Self {
             features,
             overrides: HashMap::new(),
+            rollouts: HashMap::new(),
         }
     }
 
-    pub fn is_enabled(&self, feature_path: &str) -> bool {
-        // Check for runtime overrides first
-        if let Some(&override_value) = self.overrides.get(feature_path) {
-            return override_value;
+    /// Evaluate a feature for a district, bucketing by `key` when given
+    ///
+    /// Rules apply in order: an expired rollout reverts to the configured
+    /// default, then the deny list, the allow list, the percentage bucket,
+    /// the override and finally the configured default. The district lists
+    /// apply to the district and the percentage to the key, or the district
+    /// without one. Without either only the override and default apply.
+    pub fn evaluate(
+        &self,
+        feature_path: &str,
+        district: Option<&str>,
+        key: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> FeatureEvaluation {
+        let decided = |enabled, reason| FeatureEvaluation { enabled, reason, bucket: None };
+        let rollout = self.rollouts.get(feature_path);
+
+        if rollout.is_some_and(|r| r.is_expired(now)) {
+            return decided(self.configured(feature_path), EvaluationReason::Expired);
+        }
+
+        if let Some(rollout) = rollout {
+            let district = district.map(str::to_lowercase);
+            if let Some(district) = &district {
+                if rollout.deny_districts.contains(district) {
+                    return decided(false, EvaluationReason::DistrictDenylist);
+                }
+                if rollout.allow_districts.contains(district) {
+                    return decided(true, EvaluationReason::DistrictAllowlist);
+                }
+            }
+            if let (Some(percentage), Some(bucket_key)) = (rollout.percentage, key.or(district.as_deref())) {
+                return FeatureEvaluation {
+                    enabled: is_enabled_for(feature_path, bucket_key, percentage),
+                    reason: EvaluationReason::PercentageBucket,
+                    bucket: Some(rollout_bucket(feature_path, bucket_key)),
+                };
+            }
         }
 
-        // Check the feature configuration
-        match feature_path {
-            "core.case_management" => self.features.core.case_management,
-            "core.basic_docket" => self.features.core.basic_docket,
-            "core.party_management" => self.features.core.party_management,
-            "advanced.judge_assignment" => self.features.advanced.judge_assignment,
-            "advanced.automated_scheduling" => self.features.advanced.automated_scheduling,
-            "advanced.sentencing_calculator" => self.features.advanced.sentencing_calculator,
-            "advanced.deadline_tracking" => self.features.advanced.deadline_tracking,
-            "advanced.statistical_reporting" => self.features.advanced.statistical_reporting,
-            "experimental.mdl_proceedings" => self.features.experimental.mdl_proceedings,
-            "experimental.ai_assisted_research" => self.features.experimental.ai_assisted_research,
-            "experimental.automated_transcription" => self.features.experimental.automated_transcription,
-            "experimental.predictive_analytics" => self.features.experimental.predictive_analytics,
-            _ => false,
+        match self.overrides.get(feature_path) {
+            Some(&enabled) => decided(enabled, EvaluationReason::Override),
+            None => decided(self.configured(feature_path), EvaluationReason::Default),
         }
     }
 
+    /// The feature's value in the configuration, ignoring overrides
+    fn configured(&self, feature_path: &str) -> bool {
+        self.features.get(feature_path).unwrap_or(false)
+    }
+
     pub fn set_override(&mut self, feature_path: &str, enabled: bool) {
         self.overrides.insert(feature_path.to_string(), enabled);
     }
 
+    /// Set or, with `None`, remove a feature's rollout
+    pub fn set_rollout(&mut self, feature_path: &str, rollout: Option<FeatureRollout>) {
+        match rollout {
+            Some(rollout) => self.rollouts.insert(feature_path.to_string(), rollout),
+            None => self.rollouts.remove(feature_path),
+        };
+    }
+
     pub fn clear_overrides(&mut self) {
         self.overrides.clear();
+        self.rollouts.clear();
     }
 
     pub fn get_enabled_features(&self) -> Vec<String> {
         let mut enabled = Vec::new();
 
-        let features = vec![
-            ("core.case_management", self.features.core.case_management),
-            ("core.basic_docket", self.features.core.basic_docket),
-            ("core.party_management", self.features.core.party_management),
-            ("advanced.judge_assignment", self.features.advanced.judge_assignment),
-            ("advanced.automated_scheduling", self.features.advanced.automated_scheduling),
-            ("advanced.sentencing_calculator", self.features.advanced.sentencing_calculator),
-            ("advanced.deadline_tracking", self.features.advanced.deadline_tracking),
-            ("advanced.statistical_reporting", self.features.advanced.statistical_reporting),
-            ("experimental.mdl_proceedings", self.features.experimental.mdl_proceedings),
-            ("experimental.ai_assisted_research", self.features.experimental.ai_assisted_research),
-            ("experimental.automated_transcription", self.features.experimental.automated_transcription),
-            ("experimental.predictive_analytics", self.features.experimental.predictive_analytics),
-        ];
+        let features = FEATURE_PATHS.map(|path| (path, self.configured(path)));
 
         for (name, is_enabled) in features {
             let final_enabled = self.overrides.get(name).copied().unwrap_or(is_enabled);
@@ -256,4 +593,204 @@ pub struct ImplementationSummary {
     pub overall_progress_percentage: u8,
     pub total_estimated_hours: u32,
     pub total_actual_hours: u32,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    const FEATURE: &str = "experimental.predictive_analytics";
+
+    fn manager_with(rollout: FeatureRollout) -> FeatureManager {
+        let mut manager = FeatureManager::new();
+        manager.set_rollout(FEATURE, Some(rollout.normalized().unwrap()));
+        manager
+    }
+
+    #[test]
+    fn test_buckets_are_deterministic() {
+        assert_eq!(rollout_bucket(FEATURE, "sdny"), rollout_bucket(FEATURE, "sdny"));
+        assert_eq!(rollout_bucket(FEATURE, "SDNY"), rollout_bucket(FEATURE, "sdny"));
+
+        // Buckets spread across districts and differ between features
+        let buckets: Vec<u8> = (0..200).map(|i| rollout_bucket(FEATURE, &format!("district{}", i))).collect();
+        assert!(buckets.iter().all(|&b| b < 100));
+        assert!(buckets.iter().any(|&b| b < 50) && buckets.iter().any(|&b| b >= 50));
+        assert!((0..200).any(|i| {
+            let district = format!("district{}", i);
+            rollout_bucket(FEATURE, &district) != rollout_bucket("advanced.judge_assignment", &district)
+        }));
+    }
+
+    #[test]
+    fn test_percentage_enables_districts_below_it() {
+        let manager = manager_with(FeatureRollout { percentage: Some(50), ..Default::default() });
+        let now = Utc::now();
+
+        for i in 0..50 {
+            let district = format!("district{}", i);
+            let evaluation = manager.evaluate(FEATURE, Some(&district), None, now);
+            let bucket = rollout_bucket(FEATURE, &district);
+            assert_eq!(evaluation.reason, EvaluationReason::PercentageBucket);
+            assert_eq!(evaluation.bucket, Some(bucket));
+            assert_eq!(evaluation.enabled, bucket < 50);
+        }
+
+        let everyone = manager_with(FeatureRollout { percentage: Some(100), ..Default::default() });
+        assert!(everyone.evaluate(FEATURE, Some("edny"), None, now).enabled);
+        let no_one = manager_with(FeatureRollout { percentage: Some(0), ..Default::default() });
+        assert!(!no_one.evaluate(FEATURE, Some("edny"), None, now).enabled);
+    }
+
+    #[test]
+    fn test_deny_list_beats_allow_list_and_percentage() {
+        let manager = manager_with(FeatureRollout {
+            percentage: Some(100),
+            allow_districts: vec!["SDNY".to_string()],
+            deny_districts: vec![" sdny ".to_string()],
+            expires_at: None,
+        });
+        let now = Utc::now();
+
+        let evaluation = manager.evaluate(FEATURE, Some("sdny"), None, now);
+        assert!(!evaluation.enabled);
+        assert_eq!(evaluation.reason, EvaluationReason::DistrictDenylist);
+
+        let allowed = manager_with(FeatureRollout {
+            percentage: Some(0),
+            allow_districts: vec!["edny".to_string()],
+            ..Default::default()
+        });
+        assert_eq!(allowed.evaluate(FEATURE, Some("EDNY"), None, now).reason, EvaluationReason::DistrictAllowlist);
+        assert!(allowed.evaluate(FEATURE, Some("EDNY"), None, now).enabled);
+    }
+
+    #[test]
+    fn test_expired_rollout_reverts_to_default() {
+        let now = Utc::now();
+        let mut manager = manager_with(FeatureRollout {
+            allow_districts: vec!["sdny".to_string()],
+            expires_at: Some(now - Duration::minutes(1)),
+            ..Default::default()
+        });
+        manager.set_override(FEATURE, true);
+
+        let evaluation = manager.evaluate(FEATURE, Some("sdny"), None, now);
+        assert!(!evaluation.enabled);
+        assert_eq!(evaluation.reason, EvaluationReason::Expired);
+        assert!(manager.evaluate(FEATURE, Some("sdny"), None, now - Duration::hours(1)).enabled);
+    }
+
+    #[test]
+    fn test_without_district_override_and_default_apply() {
+        let mut manager = manager_with(FeatureRollout { percentage: Some(100), ..Default::default() });
+        let now = Utc::now();
+        assert_eq!(manager.evaluate(FEATURE, None, None, now).reason, EvaluationReason::Default);
+
+        manager.set_override(FEATURE, true);
+        assert_eq!(manager.evaluate(FEATURE, None, None, now), FeatureEvaluation {
+            enabled: true,
+            reason: EvaluationReason::Override,
+            bucket: None,
+        });
+        assert!(FeatureManager::new().evaluate("core.case_management", None, None, now).enabled);
+    }
+
+    #[test]
+    fn test_key_rollout_is_stable_and_roughly_proportional() {
+        let keys: Vec<String> = (0..2000).map(|i| format!("user-{}", i)).collect();
+
+        for percent in [10u8, 25, 50, 90] {
+            let enabled = keys.iter().filter(|k| is_enabled_for(FEATURE, k, percent)).count();
+            let expected = keys.len() * percent as usize / 100;
+            assert!(enabled.abs_diff(expected) < keys.len() / 20, "{}% enabled {} of {}", percent, enabled, keys.len());
+        }
+
+        for key in &keys {
+            assert_eq!(is_enabled_for(FEATURE, key, 30), is_enabled_for(FEATURE, key, 30));
+            // Raising the percentage never drops a key
+            assert!(!is_enabled_for(FEATURE, key, 30) || is_enabled_for(FEATURE, key, 60));
+        }
+        assert!(keys.iter().all(|k| is_enabled_for(FEATURE, k, 100) && !is_enabled_for(FEATURE, k, 0)));
+    }
+
+    #[test]
+    fn test_key_buckets_instead_of_district() {
+        let manager = manager_with(FeatureRollout {
+            percentage: Some(50),
+            deny_districts: vec!["edny".to_string()],
+            ..Default::default()
+        });
+        let now = Utc::now();
+
+        for i in 0..50 {
+            let key = format!("user-{}", i);
+            let evaluation = manager.evaluate(FEATURE, Some("sdny"), Some(&key), now);
+            assert_eq!(evaluation.bucket, Some(rollout_bucket(FEATURE, &key)));
+            assert_eq!(evaluation.enabled, is_enabled_for(FEATURE, &key, 50));
+            assert_eq!(manager.evaluate(FEATURE, None, Some(&key), now), evaluation);
+        }
+        assert_eq!(
+            manager.evaluate(FEATURE, Some("EDNY"), Some("user-1"), now).reason,
+            EvaluationReason::DistrictDenylist
+        );
+    }
+
+    #[test]
+    fn test_rollout_percentage_is_validated() {
+        assert!(FeatureRollout { percentage: Some(101), ..Default::default() }.normalized().is_err());
+    }
+
+    #[test]
+    fn test_standard_graph_lists_prerequisites_before_dependents() {
+        let graph = DependencyGraph::standard();
+        assert_eq!(
+            graph.prerequisites("experimental.automated_transcription"),
+            vec!["core.case_management", "advanced.judge_assignment", "core.basic_docket", "advanced.automated_scheduling"]
+        );
+        assert!(graph.prerequisites("core.case_management").is_empty());
+        for (feature, _) in FEATURE_DEPENDENCIES {
+            assert!(FEATURE_PATHS.contains(feature), "{} isn't a feature", feature);
+        }
+    }
+
+    #[test]
+    fn test_dependency_cycle_is_rejected() {
+        let cycle = DependencyGraph::new([
+            ("a", &["b"][..]),
+            ("b", &["c"][..]),
+            ("c", &["a"][..]),
+        ])
+        .unwrap_err();
+        assert_eq!(cycle.0, vec!["a", "b", "c", "a"]);
+        assert!(cycle.to_string().contains("a -> b -> c -> a"));
+
+        assert!(DependencyGraph::new([("a", &["a"][..])]).is_err());
+        assert!(DependencyGraph::new([("a", &["b", "c"][..]), ("b", &["c"][..])]).is_ok());
+    }
+
+    #[test]
+    fn test_enable_requires_prerequisites_unless_cascading() {
+        let graph = DependencyGraph::standard();
+        let mut features = JudicialFeatures::default();
+
+        let unmet = features.enable("experimental.mdl_proceedings", &graph, false).unwrap_err();
+        assert_eq!(unmet, vec!["core.party_management"]);
+        assert_eq!(features.get("experimental.mdl_proceedings"), Some(false), "Nothing changes");
+
+        let enabled = features.enable("experimental.mdl_proceedings", &graph, true).unwrap();
+        assert_eq!(enabled, vec!["core.party_management"]);
+        assert_eq!(features.get("core.party_management"), Some(true));
+        assert_eq!(features.get("experimental.mdl_proceedings"), Some(true));
+
+        assert_eq!(features.enable("advanced.judge_assignment", &graph, false), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_short_names_resolve_to_paths() {
+        assert_eq!(feature_path("calendar_scheduling"), Some("advanced.automated_scheduling"));
+        assert_eq!(feature_path("advanced.deadline_tracking"), Some("advanced.deadline_tracking"));
+        assert_eq!(feature_path("time_travel"), None);
+    }
+}
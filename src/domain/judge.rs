@@ -3,7 +3,11 @@
 //! This module handles judge assignments, recusals, and administrative functions
 //! following the hexagonal architecture pattern.
 
+use super::judge_unavailability::{self, Unavailability};
+use super::magistrate_consent::MagistrateAuthority;
+use crate::utils::name_match;
 use chrono::{DateTime, Utc};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use utoipa::ToSchema;
@@ -99,6 +103,9 @@ pub struct JudgeAvailability {
     pub vacation_dates: Vec<DateRange>,
     pub blocked_dates: Vec<DateTime<Utc>>,
     pub preferred_hearing_days: Vec<Weekday>,
+    /// Vacations, CLE, conferences and medical leave, in start order
+    #[serde(default)]
+    pub unavailability: Vec<Unavailability>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -128,6 +135,30 @@ pub struct CaseAssignment {
     pub reason: String,
     pub previous_judge_id: Option<Uuid>,
     pub reassignment_reason: Option<String>,
+    /// Selection strategy that produced this assignment, kept for audit
+    #[serde(default)]
+    pub strategy: Option<AssignmentStrategy>,
+    /// When a reassignment took the case off this judge
+    #[serde(default)]
+    pub superseded_date: Option<DateTime<Utc>>,
+    /// Assignment that replaced this one, if a replacement was named
+    #[serde(default)]
+    pub superseded_by: Option<Uuid>,
+    /// Seed the server drew the judge with, so the draw can be replayed
+    #[serde(default)]
+    pub draw_seed: Option<u64>,
+    /// Case type the case was assigned under; absent on assignments
+    /// recorded before it was kept
+    #[serde(default)]
+    pub case_type: Option<CaseType>,
+}
+
+/// Judge screened out of an assignment because of conflicts with case parties
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConflictExclusion {
+    pub judge_id: Uuid,
+    pub judge_name: String,
+    pub conflicting_parties: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -141,6 +172,50 @@ pub enum AssignmentType {
     Emergency,
 }
 
+/// Method used to draw a judge for a new case
+///
+/// Selected per district through the `case_assignment.strategy` config override,
+/// and may be overridden on an individual assignment request. `random` is
+/// accepted as an alias for `random_draw`. A district that sets nothing gets
+/// `balanced`, the lowest caseload with ties drawn at random.
+///
+/// Every strategy draws only from eligible judges (see
+/// [`JudgeAssignmentService::eligible_judges`]). For `round_robin` the wheel
+/// is the district's panel for the case type; a judge with a conflict with any
+/// party, at capacity, or unavailable on the preferred date is skipped for
+/// that case only. They keep their seat, so the wheel resumes after the judge
+/// actually drawn and the skipped judge is next in line on a later case.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AssignmentStrategy {
+    /// The eligible judge with the lowest caseload, ties drawn at random
+    #[default]
+    Balanced,
+    /// Uniform draw from every eligible judge (the "wheel")
+    #[serde(alias = "random")]
+    RandomDraw,
+    /// Random draw weighted by each judge's remaining caseload capacity
+    WeightedRandom,
+    /// Next judge in rotation within the case-type panel
+    RoundRobin,
+}
+
+impl AssignmentStrategy {
+    /// Configuration path holding the district's strategy
+    pub const CONFIG_PATH: &'static str = "case_assignment.strategy";
+
+    /// Parse a strategy from its snake_case configuration value
+    pub fn from_config_value(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "balanced" => Some(Self::Balanced),
+            "random_draw" | "random" => Some(Self::RandomDraw),
+            "weighted_random" => Some(Self::WeightedRandom),
+            "round_robin" => Some(Self::RoundRobin),
+            _ => None,
+        }
+    }
+}
+
 /// Recusal motion
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RecusalMotion {
@@ -154,6 +229,9 @@ pub struct RecusalMotion {
     pub status: RecusalStatus,
     pub ruling_date: Option<DateTime<Utc>>,
     pub replacement_judge_id: Option<Uuid>,
+    /// Whether the case has a new judge after the recusal was granted
+    #[serde(default)]
+    pub reassignment_status: Option<ReassignmentStatus>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -177,6 +255,16 @@ pub enum RecusalStatus {
     Withdrawn,
 }
 
+/// Outcome of replacing a recused judge
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReassignmentStatus {
+    /// A replacement judge was assigned to the case
+    Reassigned,
+    /// No eligible judge was found; the case needs manual reassignment
+    AwaitingReassignment,
+}
+
 impl Judge {
     /// Create a new judge
     pub fn new(name: String, title: JudgeTitle, district: String, courtroom: String) -> Self {
@@ -210,6 +298,7 @@ impl Judge {
                     Weekday::Thursday,
                     Weekday::Friday,
                 ],
+                unavailability: Vec::new(),
             },
         }
     }
@@ -234,7 +323,24 @@ impl Judge {
             }
         }
 
-        true
+        self.unavailable_between(date, date).is_none()
+    }
+
+    /// The unavailability entry covering any day of the given span
+    pub fn unavailable_between(&self, start: &DateTime<Utc>, end: &DateTime<Utc>) -> Option<&Unavailability> {
+        judge_unavailability::unavailable_between(
+            &self.availability.unavailability,
+            start.date_naive(),
+            end.date_naive(),
+        )
+    }
+
+    /// Check whether the judge takes cases of the given type
+    pub fn accepts_case_type(&self, case_type: &CaseType) -> bool {
+        match case_type {
+            CaseType::Criminal => self.availability.accepts_criminal_cases,
+            CaseType::Civil => self.availability.accepts_civil_cases,
+        }
     }
 
     /// Add a conflict of interest
@@ -242,11 +348,12 @@ impl Judge {
         self.conflicts_of_interest.push(conflict);
     }
 
-    /// Check for conflicts with a specific party
+    /// Check for current conflicts with a specific party, matching names as
+    /// conflict checks do
     pub fn has_conflict_with(&self, party_name: &str) -> bool {
         self.conflicts_of_interest.iter().any(|c| {
-            c.party_name.as_ref().map_or(false, |name| {
-                name.to_lowercase().contains(&party_name.to_lowercase())
+            c.party_name.as_ref().is_some_and(|name| {
+                name_match::match_name(party_name, name, name_match::DEFAULT_THRESHOLD).is_some()
             }) && c.end_date.is_none()
         })
     }
@@ -276,51 +383,154 @@ impl Judge {
     }
 }
 
+/// What a case needs from the judge drawn for it
+#[derive(Debug, Clone)]
+pub struct AssignmentCriteria<'a> {
+    pub case_type: CaseType,
+    pub parties: &'a [String],
+    pub preferred_date: Option<DateTime<Utc>>,
+    /// Whether a magistrate judge may preside over the case
+    pub magistrate_authority: MagistrateAuthority,
+}
+
 /// Service for random judge assignment
 pub struct JudgeAssignmentService;
 
 impl JudgeAssignmentService {
-    /// Randomly assign a judge to a case, considering conflicts and availability
-    pub fn assign_judge(
-        available_judges: &[Judge],
-        case_type: CaseType,
-        parties: &[String],
-        preferred_date: Option<DateTime<Utc>>,
-    ) -> Result<Uuid, String> {
-        let mut eligible_judges: Vec<&Judge> = available_judges
+    /// Judges eligible to take a case: accepting new cases of this type,
+    /// free of conflicts with the parties, and not on vacation or blocked
+    /// on the preferred date
+    ///
+    /// An assignment makes the judge the presiding judge for dispositive
+    /// purposes, so magistrate judges are only eligible with the consent of
+    /// all parties under 28 U.S.C. § 636(c) or an override.
+    pub fn eligible_judges<'a>(
+        available_judges: &'a [Judge],
+        criteria: &AssignmentCriteria,
+    ) -> Vec<&'a Judge> {
+        available_judges
             .iter()
             .filter(|j| j.can_accept_new_cases())
-            .filter(|j| match case_type {
-                CaseType::Criminal => j.availability.accepts_criminal_cases,
-                CaseType::Civil => j.availability.accepts_civil_cases,
-            })
+            .filter(|j| j.accepts_case_type(&criteria.case_type))
             .filter(|j| {
                 // Check for conflicts with any party
-                !parties.iter().any(|party| j.has_conflict_with(party))
+                !criteria.parties.iter().any(|party| j.has_conflict_with(party))
             })
             .filter(|j| {
                 // Check availability on preferred date
-                preferred_date.map_or(true, |date| j.is_available_on(&date))
+                criteria.preferred_date.map_or(true, |date| j.is_available_on(&date))
             })
-            .collect();
+            .filter(|j| {
+                j.title != JudgeTitle::MagistrateJudge || criteria.magistrate_authority.permits_magistrate()
+            })
+            .collect()
+    }
+
+    /// Assign a judge to a case using the district's strategy
+    ///
+    /// `last_assigned` is the rotation cursor for the case-type panel and is
+    /// only consulted by [`AssignmentStrategy::RoundRobin`]. The random
+    /// source is injected so draws can be reproduced with a seeded RNG.
+    pub fn assign_judge<R: Rng + ?Sized>(
+        available_judges: &[Judge],
+        strategy: AssignmentStrategy,
+        criteria: &AssignmentCriteria,
+        last_assigned: Option<Uuid>,
+        rng: &mut R,
+    ) -> Result<Uuid, String> {
+        let eligible_judges = Self::eligible_judges(available_judges, criteria);
 
         if eligible_judges.is_empty() {
+            let with_consent = AssignmentCriteria {
+                magistrate_authority: MagistrateAuthority::Consented,
+                ..criteria.clone()
+            };
+            if !Self::eligible_judges(available_judges, &with_consent).is_empty() {
+                return Err(
+                    "Only magistrate judges are eligible, and a magistrate judge cannot preside \
+                     without the consent of all parties (28 U.S.C. § 636(c)) or an override reason"
+                        .to_string(),
+                );
+            }
             return Err("No eligible judges available".to_string());
         }
 
-        // Sort by caseload (ascending) to balance workload
-        eligible_judges.sort_by_key(|j| j.current_caseload);
+        let selected = match strategy {
+            AssignmentStrategy::Balanced => Self::lowest_caseload(&eligible_judges, rng),
+            AssignmentStrategy::RandomDraw => Self::random_draw(&eligible_judges, rng),
+            AssignmentStrategy::WeightedRandom => Self::weighted_draw(&eligible_judges, rng),
+            AssignmentStrategy::RoundRobin => {
+                Self::next_in_rotation(available_judges, &criteria.case_type, &eligible_judges, last_assigned)
+            }
+        };
 
-        // Select judge with lowest caseload (with some randomization for ties)
-        let min_caseload = eligible_judges[0].current_caseload;
-        let candidates: Vec<&Judge> = eligible_judges
-            .into_iter()
+        Ok(selected.id)
+    }
+
+    /// Draw among the eligible judges carrying the lowest caseload
+    fn lowest_caseload<'a, R: Rng + ?Sized>(eligible: &[&'a Judge], rng: &mut R) -> &'a Judge {
+        let min_caseload = eligible.iter().map(|j| j.current_caseload).min().unwrap_or(0);
+        let candidates: Vec<&Judge> = eligible
+            .iter()
+            .copied()
             .filter(|j| j.current_caseload == min_caseload)
             .collect();
+        Self::random_draw(&candidates, rng)
+    }
 
-        // Randomly select from candidates with minimum caseload
-        let selected_index = (rand::random::<usize>()) % candidates.len();
-        Ok(candidates[selected_index].id)
+    /// Uniform draw from the eligible judges
+    fn random_draw<'a, R: Rng + ?Sized>(eligible: &[&'a Judge], rng: &mut R) -> &'a Judge {
+        eligible[rng.gen_range(0..eligible.len())]
+    }
+
+    /// Draw weighted by remaining capacity so lighter dockets are favored
+    fn weighted_draw<'a, R: Rng + ?Sized>(eligible: &[&'a Judge], rng: &mut R) -> &'a Judge {
+        let capacity = |j: &Judge| u64::from(j.max_caseload.saturating_sub(j.current_caseload));
+        let total: u64 = eligible.iter().map(|j| capacity(j)).sum();
+        if total == 0 {
+            return Self::random_draw(eligible, rng);
+        }
+
+        let mut ticket = rng.gen_range(0..total);
+        for judge in eligible {
+            let weight = capacity(judge);
+            if ticket < weight {
+                return judge;
+            }
+            ticket -= weight;
+        }
+        eligible[eligible.len() - 1]
+    }
+
+    /// Next eligible judge after `last_assigned` in the case-type panel
+    ///
+    /// The panel is every judge accepting the case type, ordered by name then
+    /// id so the rotation is stable. Ineligible judges are passed over but
+    /// keep their place in the panel.
+    fn next_in_rotation<'a>(
+        available_judges: &'a [Judge],
+        case_type: &CaseType,
+        eligible: &[&'a Judge],
+        last_assigned: Option<Uuid>,
+    ) -> &'a Judge {
+        let mut panel: Vec<&Judge> = available_judges
+            .iter()
+            .filter(|j| j.accepts_case_type(case_type))
+            .collect();
+        panel.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+
+        let start = last_assigned
+            .and_then(|id| panel.iter().position(|j| j.id == id))
+            .map_or(0, |pos| pos + 1);
+
+        panel
+            .iter()
+            .cycle()
+            .skip(start)
+            .take(panel.len())
+            .find(|j| eligible.iter().any(|e| e.id == j.id))
+            .copied()
+            .unwrap_or(eligible[0])
     }
 
     /// Create a case assignment record
@@ -329,6 +539,8 @@ impl JudgeAssignmentService {
         judge_id: Uuid,
         assignment_type: AssignmentType,
         reason: String,
+        strategy: Option<AssignmentStrategy>,
+        case_type: Option<CaseType>,
     ) -> CaseAssignment {
         CaseAssignment {
             id: Uuid::new_v4(),
@@ -339,6 +551,11 @@ impl JudgeAssignmentService {
             reason,
             previous_judge_id: None,
             reassignment_reason: None,
+            strategy,
+            superseded_date: None,
+            superseded_by: None,
+            draw_seed: None,
+            case_type,
         }
     }
 
@@ -370,9 +587,269 @@ impl JudgeAssignmentService {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum CaseType {
     Criminal,
     Civil,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn judge(name: &str, caseload: u32) -> Judge {
+        let mut judge = Judge::new(
+            name.to_string(),
+            JudgeTitle::DistrictJudge,
+            "SDNY".to_string(),
+            "1A".to_string(),
+        );
+        judge.current_caseload = caseload;
+        judge
+    }
+
+    fn party_conflict(party: &str) -> ConflictOfInterest {
+        ConflictOfInterest {
+            id: Uuid::new_v4(),
+            party_name: Some(party.to_string()),
+            law_firm: None,
+            corporation: None,
+            conflict_type: JudgeConflictType::StockOwnership,
+            start_date: Utc::now(),
+            end_date: None,
+            notes: String::new(),
+        }
+    }
+
+    fn criteria(parties: &[String], preferred_date: Option<DateTime<Utc>>) -> AssignmentCriteria<'_> {
+        AssignmentCriteria {
+            case_type: CaseType::Civil,
+            parties,
+            preferred_date,
+            magistrate_authority: MagistrateAuthority::NotConsented,
+        }
+    }
+
+    fn draw(
+        judges: &[Judge],
+        strategy: AssignmentStrategy,
+        parties: &[String],
+        preferred_date: Option<DateTime<Utc>>,
+        last_assigned: Option<Uuid>,
+        seed: u64,
+    ) -> Result<Uuid, String> {
+        JudgeAssignmentService::assign_judge(
+            judges,
+            strategy,
+            &criteria(parties, preferred_date),
+            last_assigned,
+            &mut StdRng::seed_from_u64(seed),
+        )
+    }
+
+    fn magistrate(name: &str) -> Judge {
+        Judge::new(name.to_string(), JudgeTitle::MagistrateJudge, "SDNY".to_string(), "5C".to_string())
+    }
+
+    #[test]
+    fn test_strategy_from_config_value() {
+        assert_eq!(AssignmentStrategy::from_config_value("random_draw"), Some(AssignmentStrategy::RandomDraw));
+        assert_eq!(AssignmentStrategy::from_config_value("Round_Robin"), Some(AssignmentStrategy::RoundRobin));
+        assert_eq!(AssignmentStrategy::from_config_value("balanced"), Some(AssignmentStrategy::Balanced));
+        assert_eq!(AssignmentStrategy::from_config_value("weighted_random"), Some(AssignmentStrategy::WeightedRandom));
+        assert_eq!(AssignmentStrategy::from_config_value("random"), Some(AssignmentStrategy::RandomDraw));
+        assert_eq!(AssignmentStrategy::from_config_value("coin_flip"), None);
+        assert_eq!(AssignmentStrategy::default(), AssignmentStrategy::Balanced);
+    }
+
+    #[test]
+    fn test_random_draw_is_reproducible_with_seed() {
+        let judges: Vec<Judge> = (0..6).map(|i| judge(&format!("Judge {}", i), 10)).collect();
+
+        for seed in 0..20 {
+            let first = draw(&judges, AssignmentStrategy::RandomDraw, &[], None, None, seed).unwrap();
+            let second = draw(&judges, AssignmentStrategy::RandomDraw, &[], None, None, seed).unwrap();
+            assert_eq!(first, second);
+        }
+    }
+
+    #[test]
+    fn test_random_draw_excludes_conflicts_and_vacations() {
+        let mut conflicted = judge("Conflicted", 0);
+        conflicted.add_conflict(party_conflict("Acme Corp"));
+
+        let hearing = Utc::now() + chrono::Duration::days(10);
+        let mut vacationing = judge("Vacationing", 0);
+        vacationing.availability.vacation_dates.push(DateRange {
+            start: hearing - chrono::Duration::days(2),
+            end: hearing + chrono::Duration::days(2),
+        });
+
+        let available = judge("Available", 50);
+        let judges = vec![conflicted, vacationing, available.clone()];
+        let parties = vec!["Acme Corp".to_string()];
+
+        for seed in 0..50 {
+            let selected = draw(&judges, AssignmentStrategy::RandomDraw, &parties, Some(hearing), None, seed).unwrap();
+            assert_eq!(selected, available.id);
+        }
+    }
+
+    #[test]
+    fn test_has_conflict_with_matches_name_variants() {
+        let mut conflicted = judge("Conflicted", 0);
+        conflicted.add_conflict(party_conflict("ACME Corporation"));
+
+        assert!(conflicted.has_conflict_with("Acme Corp."));
+        assert!(conflicted.has_conflict_with("Acme, Inc"));
+        assert!(!conflicted.has_conflict_with("Globex Corporation"));
+
+        conflicted.conflicts_of_interest[0].end_date = Some(Utc::now());
+        assert!(!conflicted.has_conflict_with("Acme Corp."));
+    }
+
+    #[test]
+    fn test_draw_skips_judge_unavailable_on_preferred_date() {
+        use crate::domain::judge_unavailability::UnavailabilityType;
+
+        let hearing = Utc::now() + chrono::Duration::days(30);
+        let mut at_conference = judge("At Conference", 0);
+        at_conference.availability.unavailability.push(
+            Unavailability::new(
+                hearing.date_naive(),
+                hearing.date_naive(),
+                UnavailabilityType::JudicialConference,
+                String::new(),
+            )
+            .unwrap(),
+        );
+        let available = judge("Available", 50);
+        let judges = vec![at_conference.clone(), available.clone()];
+
+        for seed in 0..50 {
+            let selected = draw(&judges, AssignmentStrategy::RandomDraw, &[], Some(hearing), None, seed).unwrap();
+            assert_eq!(selected, available.id);
+        }
+        assert!(at_conference.is_available_on(&(hearing + chrono::Duration::days(1))));
+    }
+
+    #[test]
+    fn test_balanced_picks_lowest_caseload_and_breaks_ties_at_random() {
+        let busy = judge("Busy", 40);
+        let idle_a = judge("Idle A", 3);
+        let idle_b = judge("Idle B", 3);
+        let judges = vec![busy.clone(), idle_a.clone(), idle_b.clone()];
+
+        let drawn: std::collections::HashSet<Uuid> = (0..50)
+            .map(|seed| draw(&judges, AssignmentStrategy::Balanced, &[], None, None, seed).unwrap())
+            .collect();
+        assert_eq!(drawn, [idle_a.id, idle_b.id].into_iter().collect());
+    }
+
+    #[test]
+    fn test_weighted_random_favors_remaining_capacity() {
+        let light = judge("Light", 0);
+        let heavy = judge("Heavy", 99);
+        let judges = vec![light.clone(), heavy.clone()];
+
+        let light_draws = (0..200)
+            .filter(|seed| draw(&judges, AssignmentStrategy::WeightedRandom, &[], None, None, *seed).unwrap() == light.id)
+            .count();
+
+        // Capacity is 100 vs 1, so the heavy docket should rarely be drawn
+        assert!(light_draws > 180, "light judge drawn {} of 200 times", light_draws);
+    }
+
+    #[test]
+    fn test_round_robin_rotates_through_panel() {
+        let judges = vec![judge("Charlie", 0), judge("Alpha", 0), judge("Bravo", 0)];
+        let id_of = |name: &str| judges.iter().find(|j| j.name == name).unwrap().id;
+
+        let first = draw(&judges, AssignmentStrategy::RoundRobin, &[], None, None, 0).unwrap();
+        let second = draw(&judges, AssignmentStrategy::RoundRobin, &[], None, Some(first), 0).unwrap();
+        let third = draw(&judges, AssignmentStrategy::RoundRobin, &[], None, Some(second), 0).unwrap();
+        let fourth = draw(&judges, AssignmentStrategy::RoundRobin, &[], None, Some(third), 0).unwrap();
+
+        assert_eq!(first, id_of("Alpha"));
+        assert_eq!(second, id_of("Bravo"));
+        assert_eq!(third, id_of("Charlie"));
+        assert_eq!(fourth, id_of("Alpha"));
+    }
+
+    #[test]
+    fn test_round_robin_skips_ineligible_judges() {
+        let mut bravo = judge("Bravo", 0);
+        bravo.add_conflict(party_conflict("Acme Corp"));
+        let judges = vec![judge("Alpha", 0), bravo, judge("Charlie", 0)];
+        let parties = vec!["Acme Corp".to_string()];
+
+        let next = draw(&judges, AssignmentStrategy::RoundRobin, &parties, None, Some(judges[0].id), 0).unwrap();
+        assert_eq!(next, judges[2].id);
+
+        // Bravo keeps their seat and comes up again once the conflict is gone
+        let after = draw(&judges, AssignmentStrategy::RoundRobin, &[], None, Some(judges[0].id), 0).unwrap();
+        assert_eq!(after, judges[1].id);
+    }
+
+    #[test]
+    fn test_strategy_accepts_request_aliases() {
+        let balanced: AssignmentStrategy = serde_json::from_str("\"balanced\"").unwrap();
+        let random: AssignmentStrategy = serde_json::from_str("\"random\"").unwrap();
+        let wheel: AssignmentStrategy = serde_json::from_str("\"round_robin\"").unwrap();
+
+        assert_eq!(balanced, AssignmentStrategy::Balanced);
+        assert_eq!(random, AssignmentStrategy::RandomDraw);
+        assert_eq!(wheel, AssignmentStrategy::RoundRobin);
+    }
+
+    #[test]
+    fn test_no_eligible_judges() {
+        let mut full = judge("Full", 0);
+        full.current_caseload = full.max_caseload;
+
+        let result = draw(&[full], AssignmentStrategy::RandomDraw, &[], None, None, 0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_magistrate_needs_consent_to_preside() {
+        let magistrate = magistrate("Magistrate");
+        let district = judge("District", 99);
+        let judges = vec![magistrate.clone(), district.clone()];
+
+        for seed in 0..20 {
+            let selected = draw(&judges, AssignmentStrategy::RandomDraw, &[], None, None, seed).unwrap();
+            assert_eq!(selected, district.id);
+        }
+
+        let consented = AssignmentCriteria {
+            magistrate_authority: MagistrateAuthority::Consented,
+            ..criteria(&[], None)
+        };
+        let eligible = JudgeAssignmentService::eligible_judges(&judges, &consented);
+        assert!(eligible.iter().any(|j| j.id == magistrate.id));
+    }
+
+    #[test]
+    fn test_magistrate_only_panel_requires_consent_or_override() {
+        let judges = vec![magistrate("Magistrate")];
+
+        let err = draw(&judges, AssignmentStrategy::RandomDraw, &[], None, None, 0).unwrap_err();
+        assert!(err.contains("636(c)"), "{}", err);
+
+        let overridden = AssignmentCriteria {
+            magistrate_authority: MagistrateAuthority::Overridden("Emergency duty".to_string()),
+            ..criteria(&[], None)
+        };
+        let selected = JudgeAssignmentService::assign_judge(
+            &judges,
+            AssignmentStrategy::RandomDraw,
+            &overridden,
+            None,
+            &mut StdRng::seed_from_u64(0),
+        )
+        .unwrap();
+        assert_eq!(selected, judges[0].id);
+    }
+}
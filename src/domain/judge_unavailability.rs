@@ -0,0 +1,145 @@
+//! Periods when a judge is away from the bench
+//!
+//! Vacations, continuing legal education, judicial conferences and medical
+//! leave are recorded on the judge as whole days. Scheduling treats those
+//! days as closed for the judge, and case assignment passes over a judge who
+//! is away on the preferred date. Entries for one judge may not overlap; a
+//! change of plans is a cancellation followed by a new entry.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Why a judge is unavailable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UnavailabilityType {
+    Vacation,
+    /// Continuing legal education
+    Cle,
+    JudicialConference,
+    Medical,
+}
+
+/// A span of days, inclusive at both ends, when a judge can't sit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Unavailability {
+    pub id: Uuid,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub unavailability_type: UnavailabilityType,
+    pub notes: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Unavailability {
+    /// A new entry; the end may not fall before the start
+    pub fn new(
+        start: NaiveDate,
+        end: NaiveDate,
+        unavailability_type: UnavailabilityType,
+        notes: String,
+    ) -> Result<Self, String> {
+        if end < start {
+            return Err(format!("Unavailability end {} is before its start {}", end, start));
+        }
+        Ok(Self {
+            id: Uuid::new_v4(),
+            start,
+            end,
+            unavailability_type,
+            notes,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Whether any day from `start` to `end` falls in this period
+    pub fn overlaps(&self, start: NaiveDate, end: NaiveDate) -> bool {
+        self.start <= end && start <= self.end
+    }
+}
+
+/// Add an entry unless it overlaps one already recorded
+///
+/// Entries are kept in start order. On overlap the existing entry is
+/// returned so the caller can name it.
+pub fn add_unavailability(entries: &mut Vec<Unavailability>, entry: Unavailability) -> Result<(), Unavailability> {
+    if let Some(existing) = entries.iter().find(|e| e.overlaps(entry.start, entry.end)) {
+        return Err(existing.clone());
+    }
+    entries.push(entry);
+    entries.sort_by_key(|e| e.start);
+    Ok(())
+}
+
+/// The entry covering any day from `start` to `end`, if the judge is away then
+pub fn unavailable_between(entries: &[Unavailability], start: NaiveDate, end: NaiveDate) -> Option<&Unavailability> {
+    entries.iter().find(|e| e.overlaps(start, end))
+}
+
+/// Every day the judge is away on or after `from`, for skipping in scheduling
+pub fn unavailable_days_from(entries: &[Unavailability], from: NaiveDate) -> Vec<NaiveDate> {
+    let mut days = Vec::new();
+    for entry in entries.iter().filter(|e| e.end >= from) {
+        let mut day = entry.start.max(from);
+        while day <= entry.end {
+            days.push(day);
+            day += Duration::days(1);
+        }
+    }
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2031, 7, d).unwrap()
+    }
+
+    fn vacation(start: u32, end: u32) -> Unavailability {
+        Unavailability::new(day(start), day(end), UnavailabilityType::Vacation, String::new()).unwrap()
+    }
+
+    #[test]
+    fn test_end_before_start_is_rejected() {
+        assert!(Unavailability::new(day(10), day(9), UnavailabilityType::Cle, String::new()).is_err());
+        assert!(Unavailability::new(day(10), day(10), UnavailabilityType::Cle, String::new()).is_ok());
+    }
+
+    #[test]
+    fn test_overlapping_entries_are_rejected() {
+        let mut entries = Vec::new();
+        let first = vacation(1, 14);
+        add_unavailability(&mut entries, first.clone()).unwrap();
+
+        // Sharing a single day is an overlap, since both ends are inclusive
+        assert_eq!(add_unavailability(&mut entries, vacation(14, 20)), Err(first.clone()));
+        assert_eq!(add_unavailability(&mut entries, vacation(3, 5)), Err(first));
+
+        add_unavailability(&mut entries, vacation(15, 20)).unwrap();
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[test]
+    fn test_entries_are_kept_in_start_order() {
+        let mut entries = Vec::new();
+        add_unavailability(&mut entries, vacation(20, 21)).unwrap();
+        add_unavailability(&mut entries, vacation(1, 2)).unwrap();
+
+        assert_eq!(entries[0].start, day(1));
+        assert_eq!(entries[1].start, day(20));
+    }
+
+    #[test]
+    fn test_unavailable_between_and_days_from() {
+        let entries = vec![vacation(4, 6)];
+
+        assert!(unavailable_between(&entries, day(6), day(6)).is_some());
+        assert!(unavailable_between(&entries, day(7), day(9)).is_none());
+        assert_eq!(unavailable_days_from(&entries, day(5)), vec![day(5), day(6)]);
+        assert!(unavailable_days_from(&entries, day(7)).is_empty());
+    }
+}
@@ -0,0 +1,251 @@
+//! Consent to magistrate judge jurisdiction
+//!
+//! Under 28 U.S.C. § 636(c) a magistrate judge may conduct all proceedings
+//! in a case, including entry of judgment, only if every party consents.
+//! Each party's decision is tracked on the case, and the assignment logic
+//! consults it before a magistrate judge is made the presiding judge.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+/// The party that is always in a federal criminal case
+pub const GOVERNMENT_PARTY: &str = "United States";
+
+/// A party's answer to the request for consent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsentStatus {
+    /// The party hasn't been asked, or hasn't answered
+    #[default]
+    NotSolicited,
+    Consented,
+    Declined,
+}
+
+/// One party's consent record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PartyConsent {
+    pub party: String,
+    pub status: ConsentStatus,
+    /// Docket entry that solicited the party's consent
+    pub solicitation_entry_id: Option<Uuid>,
+    /// When consent was solicited
+    pub solicited_at: Option<DateTime<Utc>>,
+    /// When the party consented or declined
+    pub decided_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl PartyConsent {
+    fn not_solicited(party: &str) -> Self {
+        Self {
+            party: party.to_string(),
+            status: ConsentStatus::NotSolicited,
+            solicitation_entry_id: None,
+            solicited_at: None,
+            decided_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+/// Consent decisions recorded on a case
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct MagistrateConsent {
+    pub parties: Vec<PartyConsent>,
+}
+
+impl MagistrateConsent {
+    /// Record a party's consent status
+    ///
+    /// Party names are matched case-insensitively. A solicitation entry is
+    /// kept until a new one is given; setting the status back to
+    /// `not_solicited` clears the decision time.
+    pub fn record(
+        &mut self,
+        party: &str,
+        status: ConsentStatus,
+        solicitation_entry_id: Option<Uuid>,
+        now: DateTime<Utc>,
+    ) -> &PartyConsent {
+        let party = party.trim();
+        let index = match self.position(party) {
+            Some(index) => index,
+            None => {
+                self.parties.push(PartyConsent::not_solicited(party));
+                self.parties.len() - 1
+            }
+        };
+
+        let record = &mut self.parties[index];
+        if solicitation_entry_id.is_some() && solicitation_entry_id != record.solicitation_entry_id {
+            record.solicitation_entry_id = solicitation_entry_id;
+            record.solicited_at = Some(now);
+        }
+        if record.status != status {
+            record.status = status;
+            record.decided_at = match status {
+                ConsentStatus::NotSolicited => None,
+                _ => Some(now),
+            };
+        }
+        record.updated_at = Some(now);
+        record
+    }
+
+    /// Summarize consent across the case's parties
+    ///
+    /// Every party in `case_parties` must consent, as must any other party
+    /// with a record. Full consent needs at least one party.
+    pub fn summary(&self, case_parties: &[String]) -> ConsentSummary {
+        let mut parties: Vec<PartyConsent> = Vec::new();
+        for party in case_parties {
+            let party = party.trim();
+            if party.is_empty() || parties.iter().any(|p| p.party.eq_ignore_ascii_case(party)) {
+                continue;
+            }
+            parties.push(match self.position(party) {
+                Some(i) => self.parties[i].clone(),
+                None => PartyConsent::not_solicited(party),
+            });
+        }
+        for record in &self.parties {
+            if !parties.iter().any(|p| p.party.eq_ignore_ascii_case(&record.party)) {
+                parties.push(record.clone());
+            }
+        }
+
+        let names = |status: ConsentStatus| -> Vec<String> {
+            parties.iter().filter(|p| p.status == status).map(|p| p.party.clone()).collect()
+        };
+        let declined = names(ConsentStatus::Declined);
+        let outstanding = names(ConsentStatus::NotSolicited);
+
+        ConsentSummary {
+            full_consent: !parties.is_empty() && declined.is_empty() && outstanding.is_empty(),
+            declined,
+            outstanding,
+            parties,
+        }
+    }
+
+    fn position(&self, party: &str) -> Option<usize> {
+        self.parties.iter().position(|p| p.party.eq_ignore_ascii_case(party))
+    }
+}
+
+/// Whether every party has consented to a magistrate judge
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ConsentSummary {
+    pub full_consent: bool,
+    /// Parties that declined
+    pub declined: Vec<String>,
+    /// Parties that haven't answered
+    pub outstanding: Vec<String>,
+    pub parties: Vec<PartyConsent>,
+}
+
+/// Whether a magistrate judge may be drawn as the presiding judge
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum MagistrateAuthority {
+    /// Every party consented
+    Consented,
+    /// Consent is incomplete but the assignment gives a reason to proceed
+    Overridden(String),
+    /// Consent is incomplete; only Article III judges may preside
+    #[default]
+    NotConsented,
+}
+
+impl MagistrateAuthority {
+    /// Authority from a case's consent and an optional override reason
+    ///
+    /// A blank override reason doesn't count.
+    pub fn new(full_consent: bool, override_reason: Option<&str>) -> Self {
+        match override_reason.map(str::trim).filter(|r| !r.is_empty()) {
+            _ if full_consent => Self::Consented,
+            Some(reason) => Self::Overridden(reason.to_string()),
+            None => Self::NotConsented,
+        }
+    }
+
+    /// Whether magistrate judges may be drawn
+    pub fn permits_magistrate(&self) -> bool {
+        !matches!(self, Self::NotConsented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parties(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_full_consent_requires_every_party() {
+        let now = Utc::now();
+        let case_parties = parties(&[GOVERNMENT_PARTY, "John Doe"]);
+        let mut consent = MagistrateConsent::default();
+
+        consent.record(GOVERNMENT_PARTY, ConsentStatus::Consented, None, now);
+        let summary = consent.summary(&case_parties);
+        assert!(!summary.full_consent);
+        assert_eq!(summary.outstanding, vec!["John Doe"]);
+
+        consent.record("john doe", ConsentStatus::Consented, None, now);
+        let summary = consent.summary(&case_parties);
+        assert!(summary.full_consent);
+        assert_eq!(summary.parties.len(), 2);
+    }
+
+    #[test]
+    fn test_declined_party_blocks_consent() {
+        let now = Utc::now();
+        let mut consent = MagistrateConsent::default();
+        consent.record(GOVERNMENT_PARTY, ConsentStatus::Consented, None, now);
+        consent.record("Jane Roe", ConsentStatus::Declined, None, now);
+
+        let summary = consent.summary(&parties(&[GOVERNMENT_PARTY]));
+        assert!(!summary.full_consent, "recorded parties count even if not on the case");
+        assert_eq!(summary.declined, vec!["Jane Roe"]);
+        assert!(!MagistrateConsent::default().summary(&[]).full_consent);
+    }
+
+    #[test]
+    fn test_record_tracks_solicitation_and_decision_times() {
+        let solicited = Utc::now();
+        let decided = solicited + chrono::Duration::days(3);
+        let entry = Uuid::new_v4();
+        let mut consent = MagistrateConsent::default();
+
+        consent.record("John Doe", ConsentStatus::NotSolicited, Some(entry), solicited);
+        let record = consent.record("John Doe", ConsentStatus::Consented, None, decided).clone();
+
+        assert_eq!(record.solicitation_entry_id, Some(entry));
+        assert_eq!(record.solicited_at, Some(solicited));
+        assert_eq!(record.decided_at, Some(decided));
+        assert_eq!(consent.parties.len(), 1);
+        let summary = consent.summary(&["JOHN DOE".to_string(), "Jane Roe".to_string()]);
+        assert_eq!(summary.parties[0].status, ConsentStatus::Consented);
+        assert_eq!(summary.outstanding, vec!["Jane Roe".to_string()]);
+
+        consent.record("John Doe", ConsentStatus::NotSolicited, None, decided);
+        assert_eq!(consent.parties[0].decided_at, None);
+    }
+
+    #[test]
+    fn test_magistrate_authority() {
+        assert_eq!(MagistrateAuthority::new(true, None), MagistrateAuthority::Consented);
+        assert_eq!(
+            MagistrateAuthority::new(false, Some(" emergency duty ")),
+            MagistrateAuthority::Overridden("emergency duty".to_string())
+        );
+        assert_eq!(MagistrateAuthority::new(false, Some("  ")), MagistrateAuthority::NotConsented);
+        assert!(!MagistrateAuthority::NotConsented.permits_magistrate());
+        assert!(MagistrateAuthority::Overridden("x".to_string()).permits_magistrate());
+    }
+}
@@ -3,29 +3,40 @@
 //! This module contains the core domain models and business logic
 //! for the ToDo API and Criminal Case Management applications.
 
+pub mod appeal;
 pub mod attorney;
 pub mod attorney_case;
 pub mod attorney_conflict;
+pub mod case_archive;
+pub mod case_number;
 pub mod common;
 pub mod config;
+pub mod daily_calendar;
 pub mod criminal_case;
 pub mod deadline;
 pub mod defendant;
+pub mod district;
 pub mod docket;
 pub mod document;
 pub mod features;
 pub mod judge;
+pub mod judge_unavailability;
+pub mod magistrate_consent;
 pub mod opinion;
 pub mod order;
 pub mod pagination;
 pub mod rule;
 pub mod deadline_calc;
+pub mod delay_attribution;
 pub mod filing_pipeline;
 pub mod nef;
 pub mod privacy;
 pub mod sentencing;
+pub mod tenant_export;
 mod todo;
 pub mod victim;
+pub mod webhook;
+pub mod weekly_docket;
 
 pub use common::*;
 pub use todo::ToDo;
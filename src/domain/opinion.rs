@@ -5,6 +5,7 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
 use utoipa::ToSchema;
 
 /// Represents a judicial opinion in the system
@@ -30,11 +31,20 @@ pub struct JudicialOpinion {
     pub filed_at: Option<DateTime<Utc>>,
     pub published_at: Option<DateTime<Utc>>,
     pub joining_judges: Vec<JudgeVote>,
+    /// Judges sitting on the panel, the author included
+    #[serde(default)]
+    pub panel_judge_ids: Vec<String>,
     pub related_opinions: Vec<RelatedOpinion>,
     pub legal_citations: Vec<LegalCitation>,
     pub headnotes: Vec<Headnote>,
     pub keywords: Vec<String>,
     pub attachments: Vec<String>,
+    /// Corrections filed after publication; the published text never changes
+    #[serde(default)]
+    pub errata: Vec<OpinionErratum>,
+    /// Document index ID of the slip opinion PDF generated on publication
+    #[serde(default)]
+    pub slip_opinion_document_id: Option<String>,
 }
 
 /// Type of judicial opinion
@@ -81,7 +91,7 @@ pub enum OpinionStatus {
 }
 
 /// Citation information for an opinion
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
 pub struct Citation {
     pub federal_reporter: Option<String>,
     pub federal_supplement: Option<String>,
@@ -92,6 +102,39 @@ pub struct Citation {
     pub parallel_citations: Vec<String>,
 }
 
+impl Citation {
+    /// Court designation in neutral citations
+    pub const NEUTRAL_COURT: &'static str = "USDC";
+
+    /// Neutral citation for a district's `sequence`th opinion of a year,
+    /// e.g. `2024 USDC SDNY 123`
+    pub fn neutral(year: i32, district: &str, sequence: u64) -> String {
+        format!("{} {} {} {}", year, Self::NEUTRAL_COURT, district.to_uppercase(), sequence)
+    }
+
+    /// Key of the neutral citation counter for a district and year
+    pub fn counter_key(district: &str, year: i32) -> String {
+        format!("{}:{}", district.to_uppercase(), year)
+    }
+}
+
+/// Correction to a published opinion
+///
+/// Errata are appended to the opinion; the published text they correct is
+/// left as it was.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OpinionErratum {
+    pub id: String,
+    /// Where the correction applies, e.g. "Slip op. at 4, line 12"
+    pub location: Option<String>,
+    /// Text as published, when the correction replaces a passage
+    pub original_text: Option<String>,
+    pub corrected_text: String,
+    pub reason: String,
+    pub filed_by: String,
+    pub filed_at: DateTime<Utc>,
+}
+
 /// Judge vote on an opinion
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct JudgeVote {
@@ -114,6 +157,16 @@ pub enum VoteType {
     NotParticipating,
 }
 
+impl VoteType {
+    /// Whether the vote supports the judgment the opinion announces
+    pub fn supports_judgment(&self) -> bool {
+        matches!(
+            self,
+            VoteType::Joins | VoteType::JoinsInPart | VoteType::Concurs | VoteType::ConcursInResult
+        )
+    }
+}
+
 /// Related opinion reference
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RelatedOpinion {
@@ -145,10 +198,13 @@ pub struct LegalCitation {
     pub pin_cite: Option<String>,
     pub proposition: String,
     pub treatment: CitationTreatment,
+    /// ID of the cited opinion when it is held in this court's repository
+    #[serde(default)]
+    pub cited_opinion_id: Option<String>,
 }
 
 /// Treatment of a cited case
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub enum CitationTreatment {
     Followed,
     Distinguished,
@@ -161,6 +217,58 @@ pub enum CitationTreatment {
     Cited,
 }
 
+/// Directed edge in the citation graph: `citing_opinion_id` cites `cited_opinion_id`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CitationEdge {
+    pub citing_opinion_id: String,
+    pub cited_opinion_id: String,
+    pub treatment: CitationTreatment,
+    pub citation_text: String,
+}
+
+/// Opinion node in a citation graph
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CitationGraphNode {
+    pub opinion_id: String,
+    pub case_name: String,
+    pub title: String,
+    /// Number of hops from the root opinion
+    pub depth: usize,
+}
+
+/// Citation neighbourhood of an opinion, for visualization
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CitationGraph {
+    pub root_opinion_id: String,
+    pub max_depth: usize,
+    pub nodes: Vec<CitationGraphNode>,
+    pub edges: Vec<CitationEdge>,
+}
+
+/// Opinion matched by a ranked full-text search
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RankedOpinion {
+    /// Relevance to the query; higher is more relevant
+    pub score: u32,
+    pub opinion: JudicialOpinion,
+}
+
+/// Words too common in opinions to say anything about relevance
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "is", "it",
+    "its", "of", "on", "or", "that", "the", "this", "to", "was", "were", "with",
+];
+
+/// Lowercased words of `text`, split on anything that is not alphanumeric,
+/// with stopwords removed
+pub fn search_terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .filter(|word| !STOPWORDS.contains(&word.as_str()))
+        .collect()
+}
+
 /// Headnote for an opinion
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Headnote {
@@ -193,6 +301,9 @@ pub struct DraftComment {
     pub judge_id: String,
     pub judge_name: String,
     pub paragraph_ref: Option<String>,
+    /// 1-based line of the draft content the comment is attached to
+    #[serde(default)]
+    pub line: Option<usize>,
     pub comment_text: String,
     pub created_at: DateTime<Utc>,
     pub resolved: bool,
@@ -222,6 +333,7 @@ impl JudicialOpinion {
         title: String,
     ) -> Self {
         let now = Utc::now();
+        let panel_judge_ids = vec![author_judge_id.clone()];
         Self {
             id: uuid::Uuid::new_v4().to_string(),
             case_id,
@@ -243,11 +355,14 @@ impl JudicialOpinion {
             filed_at: None,
             published_at: None,
             joining_judges: Vec::new(),
+            panel_judge_ids,
             related_opinions: Vec::new(),
             legal_citations: Vec::new(),
             headnotes: Vec::new(),
             keywords: Vec::new(),
             attachments: Vec::new(),
+            errata: Vec::new(),
+            slip_opinion_document_id: None,
         }
     }
 
@@ -258,13 +373,27 @@ impl JudicialOpinion {
         self.updated_at = Utc::now();
     }
 
-    /// Publish the opinion
-    pub fn publish(&mut self, citation: Citation) {
+    /// Publish the opinion under its public citation
+    ///
+    /// A published opinion's text is fixed; corrections go through
+    /// [`JudicialOpinion::add_erratum`].
+    pub fn publish(&mut self, citation: Citation, published_at: DateTime<Utc>) {
         self.is_published = true;
         self.status = OpinionStatus::Published;
         self.citation = Some(citation);
-        self.published_at = Some(Utc::now());
-        self.updated_at = Utc::now();
+        self.published_at = Some(published_at);
+        self.updated_at = published_at;
+    }
+
+    /// Whether the opinion has been published and can no longer be edited
+    pub fn is_locked(&self) -> bool {
+        self.is_published || matches!(self.status, OpinionStatus::Published)
+    }
+
+    /// Append a correction without touching the published text
+    pub fn add_erratum(&mut self, erratum: OpinionErratum) {
+        self.updated_at = erratum.filed_at;
+        self.errata.push(erratum);
     }
 
     /// Add a joining judge
@@ -290,11 +419,95 @@ impl JudicialOpinion {
         matches!(self.opinion_type, OpinionType::Majority | OpinionType::PerCuriam)
     }
 
+    /// Seat the panel; the author always sits on it
+    pub fn set_panel(&mut self, judge_ids: Vec<String>) {
+        let mut panel = vec![self.author_judge_id.clone()];
+        for judge_id in judge_ids {
+            if !panel.contains(&judge_id) {
+                panel.push(judge_id);
+            }
+        }
+        self.panel_judge_ids = panel;
+        self.updated_at = Utc::now();
+    }
+
+    /// Judges sitting on the panel
+    ///
+    /// Opinions recorded before panels were kept are treated as the
+    /// author's alone.
+    pub fn panel(&self) -> Vec<&str> {
+        if self.panel_judge_ids.is_empty() {
+            vec![self.author_judge_id.as_str()]
+        } else {
+            self.panel_judge_ids.iter().map(String::as_str).collect()
+        }
+    }
+
+    /// Check whether the recorded votes form a majority of the panel
+    ///
+    /// Only votes from judges on the panel count, each once, using their
+    /// most recently recorded vote. A majority needs more than half of the
+    /// panel supporting the judgment; an evenly split panel has no majority.
+    pub fn has_majority(&self) -> bool {
+        let panel = self.panel();
+        let mut votes: HashMap<&str, &VoteType> = HashMap::new();
+        for vote in self.joining_judges.iter().filter(|v| panel.contains(&v.judge_id.as_str())) {
+            votes.insert(vote.judge_id.as_str(), &vote.vote);
+        }
+
+        let supporting = votes.values().filter(|v| v.supports_judgment()).count();
+        supporting * 2 > panel.len()
+    }
+
     /// Check if opinion creates binding precedent
     pub fn is_binding(&self) -> bool {
         self.is_published && self.is_precedential && self.is_majority()
     }
 
+    /// Citation edges from this opinion to other opinions in the system
+    pub fn citation_edges(&self) -> Vec<CitationEdge> {
+        self.legal_citations
+            .iter()
+            .filter_map(|c| {
+                c.cited_opinion_id.as_ref().map(|cited| CitationEdge {
+                    citing_opinion_id: self.id.clone(),
+                    cited_opinion_id: cited.clone(),
+                    treatment: c.treatment.clone(),
+                    citation_text: c.citation_text.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Relevance of the opinion to a search query
+    ///
+    /// Each query term scores one point per occurrence in the title, syllabus
+    /// and body, and two per occurrence in a headnote, since headnotes state
+    /// the holdings. Repeated query terms count once.
+    pub fn relevance_score(&self, query: &str) -> u32 {
+        let mut terms = search_terms(query);
+        terms.sort_unstable();
+        terms.dedup();
+        if terms.is_empty() {
+            return 0;
+        }
+
+        let count = |text: &str| -> u32 {
+            search_terms(text)
+                .iter()
+                .filter(|word| terms.contains(word))
+                .count() as u32
+        };
+
+        let body = count(&self.title) + count(&self.syllabus) + count(&self.content);
+        let headnotes: u32 = self
+            .headnotes
+            .iter()
+            .map(|h| count(&h.topic) + h.subtopic.as_deref().map_or(0, count) + count(&h.text))
+            .sum();
+        body + 2 * headnotes
+    }
+
     /// Calculate opinion statistics
     pub fn calculate_statistics(&self) -> OpinionStatistics {
         OpinionStatistics {
@@ -308,6 +521,86 @@ impl JudicialOpinion {
     }
 }
 
+impl CitationGraph {
+    /// Walk the citation graph outward from `root` in both directions
+    ///
+    /// Follows citations made by each opinion and citations made to it
+    /// (via `cited_by`) up to `max_depth` hops. Each opinion is visited once,
+    /// so citation cycles terminate. Edges pointing at opinions that no
+    /// longer exist are dropped.
+    pub fn build<E>(
+        root: &JudicialOpinion,
+        max_depth: usize,
+        mut load_opinion: impl FnMut(&str) -> Result<Option<JudicialOpinion>, E>,
+        mut cited_by: impl FnMut(&str) -> Result<Vec<CitationEdge>, E>,
+    ) -> Result<Self, E> {
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut seen_edges: HashSet<(String, String, String)> = HashSet::new();
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        let mut queue = VecDeque::new();
+
+        visited.insert(root.id.clone());
+        nodes.push(CitationGraphNode::from_opinion(root, 0));
+        queue.push_back((root.clone(), 0));
+
+        while let Some((opinion, depth)) = queue.pop_front() {
+            if depth >= max_depth {
+                continue;
+            }
+
+            let mut adjacent = opinion.citation_edges();
+            adjacent.extend(cited_by(&opinion.id)?);
+
+            for edge in adjacent {
+                let neighbor_id = if edge.citing_opinion_id == opinion.id {
+                    edge.cited_opinion_id.clone()
+                } else {
+                    edge.citing_opinion_id.clone()
+                };
+
+                if !visited.contains(&neighbor_id) {
+                    match load_opinion(&neighbor_id)? {
+                        Some(neighbor) => {
+                            visited.insert(neighbor_id);
+                            nodes.push(CitationGraphNode::from_opinion(&neighbor, depth + 1));
+                            queue.push_back((neighbor, depth + 1));
+                        }
+                        None => continue,
+                    }
+                }
+
+                let key = (
+                    edge.citing_opinion_id.clone(),
+                    edge.cited_opinion_id.clone(),
+                    edge.citation_text.clone(),
+                );
+                if seen_edges.insert(key) {
+                    edges.push(edge);
+                }
+            }
+        }
+
+        Ok(Self {
+            root_opinion_id: root.id.clone(),
+            max_depth,
+            nodes,
+            edges,
+        })
+    }
+}
+
+impl CitationGraphNode {
+    fn from_opinion(opinion: &JudicialOpinion, depth: usize) -> Self {
+        Self {
+            opinion_id: opinion.id.clone(),
+            case_name: opinion.case_name.clone(),
+            title: opinion.title.clone(),
+            depth,
+        }
+    }
+}
+
 impl OpinionDraft {
     /// Create a new draft version
     pub fn new(
@@ -387,4 +680,183 @@ mod tests {
         opinion.opinion_type = OpinionType::Dissenting;
         assert!(!opinion.is_binding());
     }
-}
\ No newline at end of file
+
+    fn vote(judge_id: &str, vote: VoteType) -> JudgeVote {
+        JudgeVote {
+            judge_id: judge_id.to_string(),
+            judge_name: format!("Hon. {}", judge_id),
+            vote,
+            opinion_id: None,
+            notes: String::new(),
+        }
+    }
+
+    fn panel_opinion(panel: &[&str]) -> JudicialOpinion {
+        let mut opinion = JudicialOpinion::new(
+            "CASE-123".to_string(),
+            "Smith v. Jones".to_string(),
+            "21-1234".to_string(),
+            "JUDGE-A".to_string(),
+            "Hon. Jane Doe".to_string(),
+            OpinionType::Majority,
+            "Opinion of the Court".to_string(),
+        );
+        opinion.set_panel(panel.iter().map(|id| id.to_string()).collect());
+        opinion
+    }
+
+    #[test]
+    fn test_three_judge_panel_majority() {
+        let mut opinion = panel_opinion(&["JUDGE-B", "JUDGE-C"]);
+        assert_eq!(opinion.panel(), vec!["JUDGE-A", "JUDGE-B", "JUDGE-C"]);
+        opinion.add_joining_judge(vote("JUDGE-A", VoteType::Joins));
+        assert!(!opinion.has_majority());
+
+        opinion.add_joining_judge(vote("JUDGE-B", VoteType::ConcursInResult));
+        opinion.add_joining_judge(vote("JUDGE-C", VoteType::Dissents));
+        assert!(opinion.has_majority(), "2 votes to 1 is a majority");
+
+        // A judge who changes their vote is only counted once
+        opinion.add_joining_judge(vote("JUDGE-B", VoteType::Dissents));
+        assert!(!opinion.has_majority());
+    }
+
+    #[test]
+    fn test_tied_panel_has_no_majority() {
+        let mut opinion = panel_opinion(&["JUDGE-B", "JUDGE-C", "JUDGE-D"]);
+        opinion.add_joining_judge(vote("JUDGE-A", VoteType::Joins));
+        opinion.add_joining_judge(vote("JUDGE-B", VoteType::Dissents));
+        opinion.add_joining_judge(vote("JUDGE-C", VoteType::TakesNoPart));
+        assert!(!opinion.has_majority());
+
+        opinion.add_joining_judge(vote("JUDGE-C", VoteType::Joins));
+        opinion.add_joining_judge(vote("JUDGE-D", VoteType::Dissents));
+        assert!(!opinion.has_majority(), "2 to 2 is a tie");
+    }
+
+    #[test]
+    fn test_only_panel_votes_count_toward_majority() {
+        let mut opinion = panel_opinion(&["JUDGE-B", "JUDGE-C"]);
+        opinion.add_joining_judge(vote("JUDGE-A", VoteType::Joins));
+        opinion.add_joining_judge(vote("JUDGE-X", VoteType::Joins));
+        opinion.add_joining_judge(vote("JUDGE-Y", VoteType::Joins));
+        assert!(!opinion.has_majority(), "judges off the panel have no vote");
+
+        // A single-judge opinion needs only its author
+        let mut opinion = panel_opinion(&[]);
+        opinion.panel_judge_ids.clear();
+        assert_eq!(opinion.panel(), vec!["JUDGE-A"]);
+        opinion.add_joining_judge(vote("JUDGE-A", VoteType::Joins));
+        assert!(opinion.has_majority());
+    }
+
+    fn cite(citing: &mut JudicialOpinion, cited: &JudicialOpinion, treatment: CitationTreatment) {
+        citing.add_citation(LegalCitation {
+            citation_text: format!("{} cites {}", citing.id, cited.id),
+            case_name: cited.case_name.clone(),
+            reporter: "F.4th".to_string(),
+            year: Some(2024),
+            court: None,
+            page: None,
+            pin_cite: None,
+            proposition: String::new(),
+            treatment,
+            cited_opinion_id: Some(cited.id.clone()),
+        });
+    }
+
+    #[test]
+    fn test_citation_graph_stops_on_cycles() {
+        let mut a = JudicialOpinion::new(
+            "CASE-A".to_string(), "A v. B".to_string(), "24-1".to_string(),
+            "JUDGE-1".to_string(), "Hon. A".to_string(), OpinionType::Majority, "A".to_string(),
+        );
+        let mut b = JudicialOpinion::new(
+            "CASE-B".to_string(), "B v. C".to_string(), "24-2".to_string(),
+            "JUDGE-1".to_string(), "Hon. A".to_string(), OpinionType::Majority, "B".to_string(),
+        );
+        let a_snapshot = a.clone();
+        cite(&mut a, &b, CitationTreatment::Followed);
+        cite(&mut b, &a_snapshot, CitationTreatment::Distinguished);
+
+        let store = [a.clone(), b.clone()];
+        let graph = CitationGraph::build(
+            &a,
+            10,
+            |id| Ok::<_, ()>(store.iter().find(|o| o.id == id).cloned()),
+            |id| Ok(store.iter().flat_map(|o| o.citation_edges()).filter(|e| e.cited_opinion_id == id).collect()),
+        )
+        .unwrap();
+
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.edges.len(), 2);
+        assert_eq!(graph.nodes[1].depth, 1);
+    }
+
+    #[test]
+    fn test_search_terms_drop_stopwords_and_punctuation() {
+        assert_eq!(
+            search_terms("The Fourth Amendment, and the exclusionary rule."),
+            vec!["fourth", "amendment", "exclusionary", "rule"]
+        );
+        assert!(search_terms("the and of").is_empty());
+    }
+
+    #[test]
+    fn test_relevance_weights_headnotes_and_ignores_stopwords() {
+        let mut opinion = JudicialOpinion::new(
+            "CASE-1".to_string(), "US v. Doe".to_string(), "24-1".to_string(),
+            "JUDGE-1".to_string(), "Hon. A".to_string(), OpinionType::Majority,
+            "Suppression of the evidence".to_string(),
+        );
+        opinion.content = "The search was unreasonable. The evidence from the search is suppressed.".to_string();
+        opinion.add_headnote(Headnote {
+            number: 1,
+            topic: "Search and Seizure".to_string(),
+            subtopic: None,
+            text: "A warrantless search of a home is presumptively unreasonable.".to_string(),
+            key_number: None,
+            cited_paragraphs: vec![],
+        });
+
+        // "search": 2 in the body, 2 in the headnote (counted double)
+        assert_eq!(opinion.relevance_score("search"), 6);
+        assert_eq!(opinion.relevance_score("the search"), 6);
+        assert_eq!(opinion.relevance_score("search search"), 6);
+        // "evidence": 1 in the title, 1 in the body
+        assert_eq!(opinion.relevance_score("Evidence"), 2);
+        assert_eq!(opinion.relevance_score("the and"), 0);
+        assert_eq!(opinion.relevance_score("habeas"), 0);
+    }
+
+    #[test]
+    fn test_neutral_citation_format() {
+        assert_eq!(Citation::neutral(2024, "sdny", 123), "2024 USDC SDNY 123");
+        assert_eq!(Citation::counter_key("sdny", 2024), Citation::counter_key("SDNY", 2024));
+        assert_ne!(Citation::counter_key("SDNY", 2024), Citation::counter_key("SDNY", 2025));
+    }
+
+    #[test]
+    fn test_erratum_leaves_published_text_unchanged() {
+        let mut opinion = panel_opinion(&[]);
+        opinion.content = "The judgment is affirmd.".to_string();
+        assert!(!opinion.is_locked());
+
+        let published_at = Utc::now();
+        opinion.publish(Citation::default(), published_at);
+        assert!(opinion.is_locked());
+        assert_eq!(opinion.published_at, Some(published_at));
+
+        opinion.add_erratum(OpinionErratum {
+            id: "ERRATUM-1".to_string(),
+            location: Some("Slip op. at 1".to_string()),
+            original_text: Some("affirmd".to_string()),
+            corrected_text: "affirmed".to_string(),
+            reason: "Typographical error".to_string(),
+            filed_by: "Clerk".to_string(),
+            filed_at: Utc::now(),
+        });
+        assert_eq!(opinion.content, "The judgment is affirmd.");
+        assert_eq!(opinion.errata.len(), 1);
+    }
+}
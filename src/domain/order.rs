@@ -31,6 +31,9 @@ pub struct JudicialOrder {
     pub attachments: Vec<String>,
     pub service_list: Vec<ServiceRecord>,
     pub metadata: OrderMetadata,
+    /// Judges who may sign on the assigned judge's behalf, e.g. the duty judge
+    #[serde(default)]
+    pub authorized_signers: Vec<String>,
 }
 
 /// Types of judicial orders
@@ -166,6 +169,7 @@ impl JudicialOrder {
             related_motions: Vec::new(),
             attachments: Vec::new(),
             service_list: Vec::new(),
+            authorized_signers: Vec::new(),
             metadata: OrderMetadata {
                 requires_compliance: false,
                 compliance_deadline: None,
@@ -179,6 +183,21 @@ impl JudicialOrder {
         }
     }
 
+    /// Check that a judge may sign this order
+    ///
+    /// Only the assigned judge or one of the order's authorized signers may
+    /// sign it.
+    pub fn authorize_signer(&self, judge_id: &str) -> Result<(), String> {
+        if judge_id == self.judge_id || self.authorized_signers.iter().any(|s| s == judge_id) {
+            Ok(())
+        } else {
+            Err(format!(
+                "Judge {} is not assigned to order {} and is not an authorized signer",
+                judge_id, self.id
+            ))
+        }
+    }
+
     /// Sign the order electronically
     pub fn sign(&mut self, signature: ElectronicSignature) {
         self.signature = Some(signature);
@@ -243,16 +262,338 @@ impl OrderTemplate {
         }
     }
 
-    /// Generate order content from template
-    pub fn generate_content(&self, values: &std::collections::HashMap<String, String>) -> String {
+    /// Placeholders in the template body with no declared variable
+    ///
+    /// Placeholders marked optional in the body, `{{name?}}`, declare
+    /// themselves and are not reported.
+    pub fn undeclared_placeholders(&self) -> Vec<String> {
+        let inline_optional = optional_placeholders(&self.template_content);
+        template_placeholders(&self.template_content)
+            .into_iter()
+            .filter(|p| !self.variables.iter().any(|v| &v.name == p) && !inline_optional.contains(p))
+            .collect()
+    }
+
+    /// Check that the body and the declared variables agree
+    ///
+    /// Every placeholder must be declared, variable names must be unique, and
+    /// defaults must match their variable's type.
+    pub fn check_consistency(&self) -> Result<(), String> {
+        let mut problems = Vec::new();
+
+        let undeclared = self.undeclared_placeholders();
+        if !undeclared.is_empty() {
+            problems.push(format!("undeclared placeholders: {}", undeclared.join(", ")));
+        }
+
+        for (i, variable) in self.variables.iter().enumerate() {
+            if self.variables[..i].iter().any(|v| v.name == variable.name) {
+                problems.push(format!("variable '{}' is declared more than once", variable.name));
+            }
+            if let Some(default) = &variable.default_value {
+                if !variable.variable_type.accepts(default) {
+                    problems.push(format!(
+                        "default for '{}' is not a valid {}",
+                        variable.name,
+                        variable.variable_type.label()
+                    ));
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Template is inconsistent: {}", problems.join("; ")))
+        }
+    }
+
+    /// Check supplied values against the template without rendering
+    ///
+    /// Declared variables fall back to their default. A variable is optional
+    /// when declared with `required: false` or written `{{name?}}` in the
+    /// body; optional variables with no value render empty. Placeholders
+    /// that are not declared at all must be supplied by the caller.
+    pub fn validate_values(&self, values: &std::collections::HashMap<String, String>) -> TemplateValidationReport {
+        self.resolve_values(values).1
+    }
+
+    /// Render the template, validating supplied values against the declared variables
+    ///
+    /// Nothing is rendered unless every required variable has a valid value,
+    /// so the output never contains literal braces.
+    pub fn render(&self, values: &std::collections::HashMap<String, String>) -> Result<String, TemplateValueErrors> {
+        let (resolved, report) = self.resolve_values(values);
+        if !report.valid {
+            return Err(TemplateValueErrors { missing: report.missing, invalid: report.invalid });
+        }
+
         let mut content = self.template_content.clone();
+        for name in template_placeholders(&self.template_content) {
+            let value = resolved.get(&name).map(String::as_str).unwrap_or("");
+            fill_placeholder(&mut content, &name, value);
+        }
+        Ok(content)
+    }
+
+    /// Value to substitute for each placeholder, and what is missing or mistyped
+    fn resolve_values(
+        &self,
+        values: &std::collections::HashMap<String, String>,
+    ) -> (std::collections::HashMap<String, String>, TemplateValidationReport) {
+        let inline_optional = optional_placeholders(&self.template_content);
+        let mut resolved = std::collections::HashMap::new();
+        let mut report = TemplateValidationReport::default();
+
         for variable in &self.variables {
-            if let Some(value) = values.get(&variable.name) {
-                content = content.replace(&format!("{{{{{}}}}}", variable.name), value);
+            let required = variable.required && !inline_optional.contains(&variable.name);
+            match values.get(&variable.name).or(variable.default_value.as_ref()) {
+                Some(value) if variable.variable_type.accepts(value) => {
+                    resolved.insert(variable.name.clone(), value.clone());
+                }
+                Some(value) => report.invalid.push(InvalidTemplateValue {
+                    name: variable.name.clone(),
+                    expected: variable.variable_type.label(),
+                    value: value.clone(),
+                }),
+                None if required => report.missing.push(variable.name.clone()),
+                None => report.unfilled_optional.push(variable.name.clone()),
             }
         }
-        content
+
+        let undeclared = self.undeclared_placeholders();
+        for name in template_placeholders(&self.template_content) {
+            if self.variables.iter().any(|v| v.name == name) {
+                continue;
+            }
+            match values.get(&name) {
+                Some(value) => {
+                    resolved.insert(name, value.clone());
+                }
+                None if undeclared.contains(&name) => report.missing.push(name),
+                None => report.unfilled_optional.push(name),
+            }
+        }
+
+        report.valid = report.missing.is_empty() && report.invalid.is_empty();
+        (resolved, report)
+    }
+
+    /// Render with each variable's default, or a sample value for its type
+    pub fn preview(&self) -> TemplatePreview {
+        let mut sample_values: std::collections::HashMap<String, String> = self
+            .variables
+            .iter()
+            .map(|v| {
+                let value = v.default_value.clone().unwrap_or_else(|| v.variable_type.sample_value(&v.name));
+                (v.name.clone(), value)
+            })
+            .collect();
+        for name in optional_placeholders(&self.template_content) {
+            sample_values.entry(name.clone()).or_insert_with(|| format!("[{}]", name));
+        }
+
+        let mut content = self.template_content.clone();
+        for (name, value) in &sample_values {
+            fill_placeholder(&mut content, name, value);
+        }
+
+        let placeholders = template_placeholders(&self.template_content);
+        TemplatePreview {
+            content,
+            sample_values,
+            undeclared_placeholders: self.undeclared_placeholders(),
+            unused_variables: self
+                .variables
+                .iter()
+                .filter(|v| !placeholders.contains(&v.name))
+                .map(|v| v.name.clone())
+                .collect(),
+        }
+    }
+}
+
+impl VariableType {
+    /// Whether a supplied value is valid for this type
+    pub fn accepts(&self, value: &str) -> bool {
+        let value = value.trim();
+        match self {
+            VariableType::Number => value.parse::<f64>().is_ok(),
+            VariableType::Date => {
+                chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok()
+                    || chrono::NaiveDate::parse_from_str(value, "%B %d, %Y").is_ok()
+                    || DateTime::parse_from_rfc3339(value).is_ok()
+            }
+            VariableType::Boolean => value == "true" || value == "false",
+            VariableType::Selection(options) => options.iter().any(|o| o == value),
+            VariableType::Text
+            | VariableType::CaseReference
+            | VariableType::PartyReference
+            | VariableType::JudgeReference => true,
+        }
+    }
+
+    /// Human-readable type name for validation messages
+    pub fn label(&self) -> String {
+        match self {
+            VariableType::Text => "text".to_string(),
+            VariableType::Number => "number".to_string(),
+            VariableType::Date => "date".to_string(),
+            VariableType::Boolean => "boolean (true or false)".to_string(),
+            VariableType::Selection(options) => format!("one of: {}", options.join(", ")),
+            VariableType::CaseReference => "case reference".to_string(),
+            VariableType::PartyReference => "party reference".to_string(),
+            VariableType::JudgeReference => "judge reference".to_string(),
+        }
+    }
+
+    fn sample_value(&self, name: &str) -> String {
+        match self {
+            VariableType::Number => "1".to_string(),
+            VariableType::Date => "January 15, 2025".to_string(),
+            VariableType::Boolean => "true".to_string(),
+            VariableType::Selection(options) if !options.is_empty() => options[0].clone(),
+            _ => format!("[{}]", name),
+        }
+    }
+}
+
+/// Names of the `{{placeholder}}`s in a template body, in order of first use
+///
+/// A trailing `?`, as in `{{note?}}`, marks a placeholder optional and is
+/// not part of its name.
+pub fn template_placeholders(content: &str) -> Vec<String> {
+    placeholder_tokens(content).into_iter().map(|(name, _)| name).collect()
+}
+
+/// Placeholders written `{{name?}}` in a template body
+pub fn optional_placeholders(content: &str) -> Vec<String> {
+    placeholder_tokens(content)
+        .into_iter()
+        .filter(|(_, optional)| *optional)
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/// Each distinct placeholder name, and whether any use of it is marked optional
+fn placeholder_tokens(content: &str) -> Vec<(String, bool)> {
+    let mut tokens: Vec<(String, bool)> = Vec::new();
+    let mut rest = content;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else { break };
+        let raw = &after[..end];
+        let (name, optional) = match raw.strip_suffix('?') {
+            Some(name) => (name, true),
+            None => (raw, false),
+        };
+        if !name.trim().is_empty() {
+            match tokens.iter_mut().find(|(n, _)| n == name) {
+                Some(token) => token.1 |= optional,
+                None => tokens.push((name.to_string(), optional)),
+            }
+        }
+        rest = &after[end + 2..];
+    }
+    tokens
+}
+
+/// Replace both the required and optional forms of a placeholder
+fn fill_placeholder(content: &mut String, name: &str, value: &str) {
+    *content = content
+        .replace(&format!("{{{{{}}}}}", name), value)
+        .replace(&format!("{{{{{}?}}}}", name), value);
+}
+
+/// Values that failed validation when rendering a template
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TemplateValueErrors {
+    /// Required variables, or undeclared placeholders, with no value
+    pub missing: Vec<String>,
+    /// Values that do not match their variable's type
+    pub invalid: Vec<InvalidTemplateValue>,
+}
+
+/// Result of checking supplied values against a template without rendering it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TemplateValidationReport {
+    /// Whether the values would render
+    pub valid: bool,
+    /// Required variables, or undeclared placeholders, with no value
+    pub missing: Vec<String>,
+    /// Values that do not match their variable's type
+    pub invalid: Vec<InvalidTemplateValue>,
+    /// Optional variables with no value or default; these render empty
+    pub unfilled_optional: Vec<String>,
+}
+
+/// A supplied value that does not match its declared type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct InvalidTemplateValue {
+    pub name: String,
+    pub expected: String,
+    pub value: String,
+}
+
+/// A template rendered with sample values
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TemplatePreview {
+    pub content: String,
+    /// Value used for each declared variable
+    pub sample_values: std::collections::HashMap<String, String>,
+    /// Placeholders in the body with no declared variable; left unrendered
+    pub undeclared_placeholders: Vec<String>,
+    /// Declared variables that never appear in the body
+    pub unused_variables: Vec<String>,
+}
+
+/// An expiring order as listed in a judge's notification
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExpiringOrderNotice {
+    pub order_id: String,
+    pub case_id: String,
+    pub title: String,
+    pub order_type: OrderType,
+    pub expiration_date: DateTime<Utc>,
+    /// Whole days remaining before the order expires
+    pub days_until_expiry: i64,
+}
+
+/// Expiring orders issued by one judge, soonest expiry first
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct JudgeExpiringOrders {
+    pub judge_id: String,
+    pub judge_name: Option<String>,
+    pub orders: Vec<ExpiringOrderNotice>,
+}
+
+/// Group expiring orders by issuing judge
+///
+/// Judges are returned in judge ID order; orders without an expiration date
+/// are skipped.
+pub fn group_expiring_by_judge(orders: Vec<JudicialOrder>, now: DateTime<Utc>) -> Vec<JudgeExpiringOrders> {
+    let mut by_judge: std::collections::BTreeMap<String, Vec<ExpiringOrderNotice>> = std::collections::BTreeMap::new();
+    for order in orders {
+        let Some(expiration_date) = order.expiration_date else {
+            continue;
+        };
+        by_judge.entry(order.judge_id).or_default().push(ExpiringOrderNotice {
+            order_id: order.id,
+            case_id: order.case_id,
+            title: order.title,
+            order_type: order.order_type,
+            expiration_date,
+            days_until_expiry: (expiration_date - now).num_days(),
+        });
     }
+
+    by_judge
+        .into_iter()
+        .map(|(judge_id, mut orders)| {
+            orders.sort_by_key(|o| o.expiration_date);
+            JudgeExpiringOrders { judge_id, judge_name: None, orders }
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -298,9 +639,149 @@ mod tests {
         values.insert("action".to_string(), "file a response".to_string());
         values.insert("deadline".to_string(), "January 15, 2025".to_string());
 
-        let content = template.generate_content(&values);
+        let content = template.render(&values).unwrap();
         assert!(content.contains("Defendant Smith"));
         assert!(content.contains("file a response"));
         assert!(content.contains("January 15, 2025"));
     }
-}
\ No newline at end of file
+
+    fn variable(name: &str, variable_type: VariableType, required: bool, default_value: Option<&str>) -> TemplateVariable {
+        TemplateVariable {
+            name: name.to_string(),
+            description: String::new(),
+            variable_type,
+            required,
+            default_value: default_value.map(str::to_string),
+            validation_rules: Vec::new(),
+        }
+    }
+
+    fn response_template() -> OrderTemplate {
+        let mut template = OrderTemplate::new(
+            "Response Order".to_string(),
+            OrderType::SchedulingOrder,
+            "Orders a response".to_string(),
+            "{{party_name}} shall respond within {{days}} days, by {{deadline}}. {{note}}".to_string(),
+        );
+        template.variables = vec![
+            variable("party_name", VariableType::Text, true, None),
+            variable("days", VariableType::Number, true, Some("14")),
+            variable("deadline", VariableType::Date, true, None),
+            variable("note", VariableType::Text, false, None),
+        ];
+        template
+    }
+
+    #[test]
+    fn test_render_uses_defaults_and_blanks_optional() {
+        let mut values = std::collections::HashMap::new();
+        values.insert("party_name".to_string(), "Defendant Smith".to_string());
+        values.insert("deadline".to_string(), "2025-01-15".to_string());
+
+        let content = response_template().render(&values).unwrap();
+        assert_eq!(content, "Defendant Smith shall respond within 14 days, by 2025-01-15. ");
+    }
+
+    #[test]
+    fn test_render_reports_missing_and_mistyped_values() {
+        let mut template = response_template();
+        template.template_content.push_str(" {{undeclared}}");
+
+        let mut values = std::collections::HashMap::new();
+        values.insert("days".to_string(), "fourteen".to_string());
+        values.insert("deadline".to_string(), "someday".to_string());
+
+        let errors = template.render(&values).unwrap_err();
+        assert_eq!(errors.missing, vec!["party_name".to_string(), "undeclared".to_string()]);
+        assert_eq!(
+            errors.invalid.iter().map(|i| i.name.as_str()).collect::<Vec<_>>(),
+            vec!["days", "deadline"]
+        );
+    }
+
+    #[test]
+    fn test_only_assigned_judge_or_delegate_may_sign() {
+        let mut order = JudicialOrder::new(
+            "CASE-123".to_string(),
+            "judge-assigned".to_string(),
+            OrderType::SchedulingOrder,
+            "Scheduling Order".to_string(),
+            "Content".to_string(),
+        );
+        assert!(order.authorize_signer("judge-assigned").is_ok());
+        assert!(order.authorize_signer("judge-duty").is_err());
+
+        order.authorized_signers.push("judge-duty".to_string());
+        assert!(order.authorize_signer("judge-duty").is_ok());
+        assert!(order.authorize_signer("judge-other").is_err());
+    }
+
+    #[test]
+    fn test_inline_optional_placeholders_render_empty() {
+        let mut template = response_template();
+        template.template_content = "{{party_name}} shall respond by {{deadline?}}.{{cc?}}".to_string();
+        assert!(template.check_consistency().is_ok(), "{{cc?}} declares itself");
+
+        let mut values = std::collections::HashMap::new();
+        values.insert("party_name".to_string(), "Defendant Smith".to_string());
+
+        let report = template.validate_values(&values);
+        assert!(report.valid);
+        assert!(report.missing.is_empty());
+        assert_eq!(report.unfilled_optional, vec!["deadline".to_string(), "note".to_string(), "cc".to_string()]);
+        assert_eq!(template.render(&values).unwrap(), "Defendant Smith shall respond by .");
+
+        values.remove("party_name");
+        let report = template.validate_values(&values);
+        assert!(!report.valid);
+        assert_eq!(report.missing, vec!["party_name".to_string()]);
+    }
+
+    #[test]
+    fn test_consistency_check_and_preview_flag_undeclared_placeholders() {
+        let mut template = response_template();
+        assert!(template.check_consistency().is_ok());
+
+        template.template_content.push_str(" Signed {{judge}}.");
+        template.variables.push(variable("courtroom", VariableType::Text, false, None));
+        let err = template.check_consistency().unwrap_err();
+        assert!(err.contains("judge"), "{}", err);
+
+        let preview = template.preview();
+        assert_eq!(preview.undeclared_placeholders, vec!["judge".to_string()]);
+        assert_eq!(preview.unused_variables, vec!["courtroom".to_string()]);
+        assert!(preview.content.starts_with("[party_name] shall respond within 14 days, by January 15, 2025."));
+
+        template.variables.push(variable("days", VariableType::Number, false, Some("soon")));
+        let err = template.check_consistency().unwrap_err();
+        assert!(err.contains("more than once") && err.contains("not a valid number"), "{}", err);
+    }
+
+    #[test]
+    fn test_group_expiring_by_judge_sorts_each_bucket() {
+        let now = Utc::now();
+        let expiring = |judge: &str, title: &str, days: i64| {
+            let mut order = JudicialOrder::new(
+                "CASE-123".to_string(),
+                judge.to_string(),
+                OrderType::ProtectiveOrder,
+                title.to_string(),
+                String::new(),
+            );
+            order.expiration_date = Some(now + chrono::Duration::days(days) + chrono::Duration::hours(1));
+            order
+        };
+
+        let groups = group_expiring_by_judge(vec![
+            expiring("JUDGE-B", "Later", 20),
+            expiring("JUDGE-A", "Only", 3),
+            expiring("JUDGE-B", "Sooner", 5),
+        ], now);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].judge_id, "JUDGE-A");
+        let titles: Vec<&str> = groups[1].orders.iter().map(|o| o.title.as_str()).collect();
+        assert_eq!(titles, vec!["Sooner", "Later"]);
+        assert_eq!(groups[1].orders[0].days_until_expiry, 5);
+    }
+}
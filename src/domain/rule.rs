@@ -203,8 +203,12 @@ pub enum RuleStatus {
 }
 
 /// Recursive condition tree for rule evaluation
+///
+/// `no_recursion` stops utoipa from collecting the nested conditions'
+/// schemas without end when the OpenAPI document is built.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
+#[schema(no_recursion)]
 pub enum RuleCondition {
     And { conditions: Vec<RuleCondition> },
     Or { conditions: Vec<RuleCondition> },
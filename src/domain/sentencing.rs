@@ -3,8 +3,10 @@
 //! This module handles federal sentencing calculations, guidelines,
 //! departures, variances, and supervised release conditions.
 
+use crate::domain::common::DateRange;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -15,6 +17,10 @@ pub struct Sentencing {
     pub case_id: String,
     pub defendant_id: String,
     pub judge_id: String,
+    /// Primary offense category, such as `drug_trafficking` or `fraud`, that
+    /// statistics are broken down by
+    #[serde(default)]
+    pub primary_offense_type: Option<String>,
 
     // Guidelines Calculation
     pub offense_level: OffenseLevel,
@@ -102,7 +108,7 @@ pub struct CriminalHistory {
 }
 
 /// Criminal history categories (I-VI)
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum CriminalHistoryCategory {
     I,
@@ -124,8 +130,128 @@ pub struct PriorSentence {
     pub controlled_substance: bool,
 }
 
-/// Guidelines sentencing range
+/// Points one prior sentence contributes under USSG §4A1.1(a)-(c)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PriorSentencePoints {
+    /// Position of the prior sentence on the record
+    pub index: usize,
+    pub offense: String,
+    pub sentence_length_months: i32,
+    /// Points recorded when the prior sentence was added
+    pub points_assigned: i32,
+    /// Points counted by the recalculation
+    pub points: i32,
+    /// Guideline subsection applied, e.g. "USSG §4A1.1(a)"
+    pub rule: String,
+    pub reason: String,
+}
+
+/// Itemized criminal history points computation
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CriminalHistoryPointsBreakdown {
+    pub total_points: i32,
+    pub prior_sentences: Vec<PriorSentencePoints>,
+    pub prior_sentence_points: i32,
+    pub status_points: i32,
+    pub recency_points: i32,
+    pub category: CriminalHistoryCategory,
+    /// Total of the points stored on the record
+    pub recorded_points: i32,
+    /// Whether the recalculated total equals the stored total
+    pub matches_recorded: bool,
+}
+
+/// Safety valve criterion under 18 U.S.C. § 3553(f)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SafetyValveCriterion {
+    CriminalHistoryPoints,
+    CareerOffender,
+    ArmedCareerCriminal,
+    ViolentPriorSentence,
+    AggravatingRole,
+}
+
+/// Outcome of one safety valve criterion
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SafetyValveCriterionResult {
+    pub criterion: SafetyValveCriterion,
+    pub passed: bool,
+    /// Why the criterion passed or failed, citing the record
+    pub reason: String,
+}
+
+/// Safety valve eligibility with the criterion-by-criterion evaluation
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SafetyValveEligibility {
+    /// True only when every criterion passes
+    pub eligible: bool,
+    pub criteria: Vec<SafetyValveCriterionResult>,
+}
+
+/// Evaluate the five safety valve criteria against a defendant's record
+///
+/// Criminal history points are taken as recorded. Requirements the record
+/// cannot show, such as a truthful proffer, are left to the court.
+pub fn evaluate_safety_valve(criminal_history: &CriminalHistory, offense_level: &OffenseLevel) -> SafetyValveEligibility {
+    let violent_priors = criminal_history
+        .prior_sentences
+        .iter()
+        .filter(|s| s.violence_involved)
+        .count();
+
+    let criterion = |criterion, passed: bool, reason: String| SafetyValveCriterionResult { criterion, passed, reason };
+    let criteria = vec![
+        criterion(
+            SafetyValveCriterion::CriminalHistoryPoints,
+            criminal_history.points <= 1,
+            format!("{} criminal history point(s); at most 1 allowed", criminal_history.points),
+        ),
+        criterion(
+            SafetyValveCriterion::CareerOffender,
+            !criminal_history.career_offender,
+            if criminal_history.career_offender {
+                "Defendant is a career offender under USSG §4B1.1".to_string()
+            } else {
+                "Defendant is not a career offender".to_string()
+            },
+        ),
+        criterion(
+            SafetyValveCriterion::ArmedCareerCriminal,
+            !criminal_history.armed_career_criminal,
+            if criminal_history.armed_career_criminal {
+                "Defendant is an armed career criminal under USSG §4B1.4".to_string()
+            } else {
+                "Defendant is not an armed career criminal".to_string()
+            },
+        ),
+        criterion(
+            SafetyValveCriterion::ViolentPriorSentence,
+            violent_priors == 0,
+            format!("{} prior sentence(s) involving violence", violent_priors),
+        ),
+        criterion(
+            SafetyValveCriterion::AggravatingRole,
+            offense_level.role_adjustment <= 0,
+            if offense_level.role_adjustment > 0 {
+                format!(
+                    "Aggravating role adjustment of +{} under USSG §3B1.1",
+                    offense_level.role_adjustment
+                )
+            } else {
+                "No aggravating role adjustment".to_string()
+            },
+        ),
+    ];
+
+    SafetyValveEligibility {
+        eligible: criteria.iter().all(|c| c.passed),
+        criteria,
+    }
+}
+
+/// Guidelines sentencing range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct GuidelinesRange {
     pub minimum_months: i32,
     pub maximum_months: i32,
@@ -134,8 +260,41 @@ pub struct GuidelinesRange {
     pub statutory_maximum: Option<i32>,
 }
 
+/// Lowest and highest offense levels on the sentencing table
+pub const MIN_TABLE_OFFENSE_LEVEL: i32 = 1;
+pub const MAX_TABLE_OFFENSE_LEVEL: i32 = 43;
+
+/// One sentencing table lookup: an offense level and criminal history category
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct GuidelinesTableLookup {
+    pub offense_level: i32,
+    pub criminal_history_category: CriminalHistoryCategory,
+}
+
+/// Sentencing table cell, before any statutory minimum or maximum is applied
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct GuidelinesTableRange {
+    pub offense_level: i32,
+    pub criminal_history_category: CriminalHistoryCategory,
+    pub minimum_months: i32,
+    pub maximum_months: i32,
+    pub zone: Zone,
+}
+
+/// Request to look up many sentencing table cells at once
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BatchGuidelinesRangeRequest {
+    pub lookups: Vec<GuidelinesTableLookup>,
+}
+
+/// Table cells for a batch lookup, in request order
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchGuidelinesRangeResponse {
+    pub ranges: Vec<GuidelinesTableRange>,
+}
+
 /// Sentencing zones (A, B, C, D)
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub enum Zone {
     A, // Probation eligible
     B, // Split sentence eligible
@@ -425,9 +584,63 @@ pub enum DefendantRole {
     Leader,
 }
 
+/// Stage of the guidelines calculation a step belongs to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CalculationStage {
+    BaseOffenseLevel,
+    OffenseCharacteristic,
+    Adjustment,
+    MultipleCount,
+    Departure,
+    OffenseLevelFloor,
+    CriminalHistoryPoints,
+    CriminalHistoryCategory,
+    TableLookup,
+    StatutoryClamp,
+}
+
+/// One step in the derivation of a guidelines range
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CalculationStep {
+    pub step: usize,
+    pub stage: CalculationStage,
+    pub description: String,
+    /// Guideline section, statute or rationale the step relies on
+    pub basis: Option<String>,
+    /// Levels, points or months added (negative when subtracted)
+    pub amount: Option<i32>,
+    /// Running value after the step, e.g. `level 24` or `51-63 months`
+    pub result: String,
+}
+
+/// Ordered derivation of a sentencing's guidelines range
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GuidelinesCalculationAudit {
+    pub sentencing_id: String,
+    pub steps: Vec<CalculationStep>,
+    pub final_offense_level: i32,
+    pub criminal_history_points: i32,
+    pub criminal_history_category: CriminalHistoryCategory,
+    pub guidelines_range: GuidelinesRange,
+    /// Whether the derived range equals the range stored on the record
+    pub matches_stored_range: bool,
+}
+
+/// An offense level change contributing to the final offense level
+struct LevelChange {
+    stage: CalculationStage,
+    description: String,
+    basis: Option<String>,
+    levels: i32,
+}
+
 /// Sentencing statistics for reporting
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SentencingStatistics {
+    /// Range of sentencing dates the statistics cover
+    #[serde(flatten)]
+    pub range: DateRange,
     pub total_cases: i32,
     pub within_guidelines: i32,
     pub upward_departures: i32,
@@ -439,6 +652,183 @@ pub struct SentencingStatistics {
     pub average_sentence_months: f64,
     pub median_sentence_months: f64,
     pub trial_penalty_percentage: f64,
+    /// Where imposed sentences fell against the guidelines range
+    pub guideline_compliance: GuidelineCompliance,
+    /// The same, per primary offense type; sentencings without one are
+    /// counted under `unspecified`
+    pub by_offense_type: BTreeMap<String, GuidelineCompliance>,
+}
+
+/// Where imposed custody fell against the guidelines range
+///
+/// Only sentencings with an imposed sentence count. How far a sentence fell
+/// outside the range is measured from the nearer bound as a percentage of
+/// that bound, the way the Sentencing Commission reports the extent of
+/// departures; a sentence below a zero-month minimum can't happen and one
+/// above a zero-month maximum has no percentage, so it is left out of the
+/// median.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct GuidelineCompliance {
+    /// Sentencings with imposed custody
+    pub sentenced: i32,
+    pub within_range: i32,
+    pub above_range: i32,
+    pub below_range: i32,
+    pub within_range_percent: f64,
+    pub above_range_percent: f64,
+    pub below_range_percent: f64,
+    /// Median percentage below the guidelines minimum, of below-range sentences
+    pub median_percent_below_range: Option<f64>,
+    /// Median percentage above the guidelines maximum, of above-range sentences
+    pub median_percent_above_range: Option<f64>,
+    /// Median imposed custody in months
+    pub median_custody_months: Option<f64>,
+}
+
+impl GuidelineCompliance {
+    /// Compliance of the given sentencings
+    pub fn of<'a>(sentencings: impl IntoIterator<Item = &'a Sentencing>) -> Self {
+        let mut custody = Vec::new();
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+        let mut within_range = 0;
+        let mut below_range = 0;
+        let mut above_range = 0;
+
+        for s in sentencings {
+            let Some(position) = s.guideline_position() else {
+                continue;
+            };
+            let months = s.imposed_sentence.as_ref().map_or(0, |i| i.custody_months);
+            custody.push(months as f64);
+            match position {
+                GuidelinePosition::Within => within_range += 1,
+                GuidelinePosition::Below => {
+                    below_range += 1;
+                    below.extend(s.percent_outside_range());
+                }
+                GuidelinePosition::Above => {
+                    above_range += 1;
+                    above.extend(s.percent_outside_range());
+                }
+            }
+        }
+
+        let sentenced = custody.len() as i32;
+        let percent = |count: i32| if sentenced > 0 { count as f64 * 100.0 / sentenced as f64 } else { 0.0 };
+        Self {
+            sentenced,
+            within_range,
+            above_range,
+            below_range,
+            within_range_percent: percent(within_range),
+            above_range_percent: percent(above_range),
+            below_range_percent: percent(below_range),
+            median_percent_below_range: median(below),
+            median_percent_above_range: median(above),
+            median_custody_months: median(custody),
+        }
+    }
+
+    /// Compliance per primary offense type
+    pub fn by_offense_type(sentencings: &[Sentencing]) -> BTreeMap<String, Self> {
+        let mut groups: BTreeMap<String, Vec<&Sentencing>> = BTreeMap::new();
+        for s in sentencings {
+            let offense = s.primary_offense_type.clone().unwrap_or_else(|| "unspecified".to_string());
+            groups.entry(offense).or_default().push(s);
+        }
+        groups.into_iter().map(|(offense, group)| (offense, Self::of(group))).collect()
+    }
+}
+
+/// Median of the values; the mean of the middle two when their number is even
+pub fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    Some(if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    })
+}
+
+/// Where an imposed sentence fell against the guidelines range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuidelinePosition {
+    Below,
+    Within,
+    Above,
+}
+
+/// A judge's guideline compliance set against the whole district's
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct JudgeSentencingComparison {
+    #[serde(flatten)]
+    pub range: DateRange,
+    pub judge_id: String,
+    pub judge: GuidelineCompliance,
+    /// Every sentencing in the district over the range, the judge's included
+    pub district: GuidelineCompliance,
+    /// Judge less district
+    pub deltas: ComplianceDeltas,
+}
+
+/// Differences between two [`GuidelineCompliance`]s
+///
+/// Percentages differ in percentage points. A median delta is absent when
+/// either side has no sentences to take it from.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ComplianceDeltas {
+    pub within_range_percent: f64,
+    pub above_range_percent: f64,
+    pub below_range_percent: f64,
+    pub median_percent_below_range: Option<f64>,
+    pub median_percent_above_range: Option<f64>,
+    pub median_custody_months: Option<f64>,
+}
+
+impl ComplianceDeltas {
+    /// `subject` less `baseline`
+    pub fn between(subject: &GuidelineCompliance, baseline: &GuidelineCompliance) -> Self {
+        let diff = |a: Option<f64>, b: Option<f64>| a.zip(b).map(|(a, b)| a - b);
+        Self {
+            within_range_percent: subject.within_range_percent - baseline.within_range_percent,
+            above_range_percent: subject.above_range_percent - baseline.above_range_percent,
+            below_range_percent: subject.below_range_percent - baseline.below_range_percent,
+            median_percent_below_range: diff(subject.median_percent_below_range, baseline.median_percent_below_range),
+            median_percent_above_range: diff(subject.median_percent_above_range, baseline.median_percent_above_range),
+            median_custody_months: diff(subject.median_custody_months, baseline.median_custody_months),
+        }
+    }
+}
+
+/// Departure and variance statistics over a date range, with their net
+/// effect, for the sentencing analytics dashboard
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AdjustmentStatistics {
+    #[serde(flatten)]
+    pub range: DateRange,
+    pub departures: SentencingStatistics,
+    pub variances: SentencingStatistics,
+    pub net_effect: AdjustmentNetEffect,
+}
+
+/// Combined effect of departures and variances on sentence length
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AdjustmentNetEffect {
+    /// Offense levels added by upward departures less those removed by downward ones
+    pub net_departure_levels: i32,
+    /// Months added by variances above the range less those removed below it
+    pub net_variance_months: i32,
+    /// Sentences with imposed custody, against which the range is compared
+    pub imposed_sentences: i32,
+    /// Total months imposed above the guidelines maximum less months below the minimum
+    pub net_months_from_range: i32,
+    /// `net_months_from_range` per imposed sentence
+    pub average_months_from_range: f64,
 }
 
 impl Sentencing {
@@ -450,6 +840,7 @@ impl Sentencing {
             case_id,
             defendant_id,
             judge_id,
+            primary_offense_type: None,
             offense_level: OffenseLevel {
                 base_offense_level: 0,
                 specific_offense_characteristics: Vec::new(),
@@ -508,62 +899,167 @@ impl Sentencing {
         }
     }
 
-    /// Calculate final offense level
-    pub fn calculate_final_offense_level(&mut self) {
-        let mut level = self.offense_level.base_offense_level;
-
-        // Add specific offense characteristics
-        for soc in &self.offense_level.specific_offense_characteristics {
-            level += soc.adjustment;
+    /// Offense level changes applied on top of the base offense level, in order
+    fn offense_level_changes(&self) -> Vec<LevelChange> {
+        let mut changes: Vec<LevelChange> = self.offense_level.specific_offense_characteristics
+            .iter()
+            .map(|soc| LevelChange {
+                stage: CalculationStage::OffenseCharacteristic,
+                description: soc.description.clone(),
+                basis: Some(format!("{}: {}", soc.guideline_section, soc.rationale)),
+                levels: soc.adjustment,
+            })
+            .collect();
+
+        let adjustments = [
+            ("Acceptance of responsibility", "USSG §3E1.1", self.offense_level.acceptance_of_responsibility),
+            ("Obstruction of justice", "USSG §3C1.1", self.offense_level.obstruction_enhancement),
+            ("Role in the offense", "USSG §3B1", self.offense_level.role_adjustment),
+        ];
+        for (description, section, levels) in adjustments {
+            if levels != 0 {
+                changes.push(LevelChange {
+                    stage: CalculationStage::Adjustment,
+                    description: description.to_string(),
+                    basis: Some(section.to_string()),
+                    levels,
+                });
+            }
         }
 
-        self.offense_level.adjusted_offense_level = level;
-
-        // Apply adjustments
-        level += self.offense_level.acceptance_of_responsibility;
-        level += self.offense_level.obstruction_enhancement;
-        level += self.offense_level.role_adjustment;
-
-        // Multiple count adjustment
         if let Some(ref mc) = self.offense_level.multiple_count_adjustment {
-            level += mc.adjustment;
+            changes.push(LevelChange {
+                stage: CalculationStage::MultipleCount,
+                description: format!("Multiple count adjustment ({} units)", mc.units),
+                basis: Some(if mc.grouping_rules.is_empty() {
+                    "USSG §3D1.4".to_string()
+                } else {
+                    mc.grouping_rules.join("; ")
+                }),
+                levels: mc.adjustment,
+            });
         }
 
-        // Apply departures
         for departure in &self.departures {
-            match departure.direction {
-                DepartureDirection::Upward => level += departure.levels,
-                DepartureDirection::Downward => level -= departure.levels,
-            }
+            let levels = match departure.direction {
+                DepartureDirection::Upward => departure.levels,
+                DepartureDirection::Downward => -departure.levels,
+            };
+            changes.push(LevelChange {
+                stage: CalculationStage::Departure,
+                description: format!("{:?} departure ({:?})", departure.direction, departure.departure_type),
+                basis: Some(format!("{}: {}", departure.guideline_section, departure.reason)),
+                levels,
+            });
         }
 
+        changes
+    }
+
+    /// Calculate final offense level
+    pub fn calculate_final_offense_level(&mut self) {
+        let changes = self.offense_level_changes();
+        let base = self.offense_level.base_offense_level;
+
+        // Specific offense characteristics give the adjusted offense level
+        self.offense_level.adjusted_offense_level = base + changes.iter()
+            .filter(|c| c.stage == CalculationStage::OffenseCharacteristic)
+            .map(|c| c.levels)
+            .sum::<i32>();
+
+        let level = base + changes.iter().map(|c| c.levels).sum::<i32>();
         self.offense_level.final_offense_level = level.max(1);
     }
 
-    /// Determine criminal history category from points
-    pub fn calculate_criminal_history_category(&mut self) {
-        let total_points = self.criminal_history.points
+    /// Total criminal history points including status and recency points
+    fn total_criminal_history_points(&self) -> i32 {
+        self.criminal_history.points
             + self.criminal_history.status_points
-            + self.criminal_history.recency_points;
+            + self.criminal_history.recency_points
+    }
 
-        self.criminal_history.category = match total_points {
-            0..=1 => CriminalHistoryCategory::I,
+    fn category_for_points(total_points: i32) -> CriminalHistoryCategory {
+        match total_points {
+            i32::MIN..=1 => CriminalHistoryCategory::I,
             2..=3 => CriminalHistoryCategory::II,
             4..=6 => CriminalHistoryCategory::III,
             7..=9 => CriminalHistoryCategory::IV,
             10..=12 => CriminalHistoryCategory::V,
             _ => CriminalHistoryCategory::VI,
-        };
+        }
     }
 
-    /// Look up guidelines range from sentencing table
-    pub fn lookup_guidelines_range(&mut self) {
+    /// Determine criminal history category from points
+    pub fn calculate_criminal_history_category(&mut self) {
+        self.criminal_history.category = Self::category_for_points(self.total_criminal_history_points());
+    }
+
+    /// Recalculate criminal history points, itemized by prior sentence
+    ///
+    /// Each prior sentence is scored from its length: 3 points for
+    /// imprisonment exceeding one year and one month, 2 points for at least
+    /// sixty days, and 1 point otherwise, with no more than 4 points in total
+    /// from one-point sentences. Status and recency points are taken as
+    /// recorded.
+    pub fn criminal_history_points_breakdown(&self) -> CriminalHistoryPointsBreakdown {
+        const ONE_POINT_CAP: i32 = 4;
+
+        let history = &self.criminal_history;
+        let mut one_point_total = 0;
+        let prior_sentences: Vec<PriorSentencePoints> = history
+            .prior_sentences
+            .iter()
+            .enumerate()
+            .map(|(index, prior)| {
+                let (points, rule, reason) = match prior.sentence_length_months {
+                    months if months > 13 => {
+                        (3, "USSG §4A1.1(a)", "Imprisonment exceeding one year and one month".to_string())
+                    }
+                    months if months >= 2 => {
+                        (2, "USSG §4A1.1(b)", "Imprisonment of at least sixty days".to_string())
+                    }
+                    _ if one_point_total < ONE_POINT_CAP => {
+                        one_point_total += 1;
+                        (1, "USSG §4A1.1(c)", "Sentence not counted under (a) or (b)".to_string())
+                    }
+                    _ => (
+                        0,
+                        "USSG §4A1.1(c)",
+                        format!("Not counted; one-point sentences are capped at {} points", ONE_POINT_CAP),
+                    ),
+                };
+                PriorSentencePoints {
+                    index,
+                    offense: prior.offense.clone(),
+                    sentence_length_months: prior.sentence_length_months,
+                    points_assigned: prior.points_assigned,
+                    points,
+                    rule: rule.to_string(),
+                    reason,
+                }
+            })
+            .collect();
+
+        let prior_sentence_points = prior_sentences.iter().map(|p| p.points).sum::<i32>();
+        let total_points = prior_sentence_points + history.status_points + history.recency_points;
+        let recorded_points = self.total_criminal_history_points();
+
+        CriminalHistoryPointsBreakdown {
+            total_points,
+            prior_sentences,
+            prior_sentence_points,
+            status_points: history.status_points,
+            recency_points: history.recency_points,
+            category: Self::category_for_points(total_points),
+            recorded_points,
+            matches_recorded: total_points == recorded_points,
+        }
+    }
+
+    /// Sentencing table range in months for an offense level and category
+    fn table_range(offense_level: i32, category: &CriminalHistoryCategory) -> (i32, i32) {
         // Simplified sentencing table lookup
         // In reality, this would use the full USSC sentencing table
-        let offense_level = self.offense_level.final_offense_level;
-        let category = &self.criminal_history.category;
-
-        // This is a simplified calculation - actual table is much more complex
         let base_months = match offense_level {
             1..=8 => 0,
             9..=11 => 4,
@@ -598,27 +1094,188 @@ impl Sentencing {
 
         let min = (base_months as f32 * multiplier) as i32;
         let max = (min as f32 * 1.25) as i32; // Typically 25% range
+        (min, max)
+    }
 
-        self.guidelines_range.minimum_months = min;
-        self.guidelines_range.maximum_months = max;
+    /// Restrict a table range to the statutory mandatory minimum and maximum
+    /// (USSG §5G1.1), returning `None` when neither limit changes it
+    fn statutory_clamp(&self, min: i32, max: i32) -> Option<(i32, i32)> {
+        let (mut clamped_min, mut clamped_max) = (min, max);
+        if let Some(floor) = self.guidelines_range.mandatory_minimum {
+            clamped_min = clamped_min.max(floor);
+            clamped_max = clamped_max.max(floor);
+        }
+        if let Some(ceiling) = self.guidelines_range.statutory_maximum {
+            clamped_min = clamped_min.min(ceiling);
+            clamped_max = clamped_max.min(ceiling);
+        }
+        ((clamped_min, clamped_max) != (min, max)).then_some((clamped_min, clamped_max))
+    }
 
-        // Determine zone
-        self.guidelines_range.zone = match min {
-            0..=6 => Zone::A,
+    fn zone_for(minimum_months: i32) -> Zone {
+        match minimum_months {
+            i32::MIN..=6 => Zone::A,
             7..=12 => Zone::B,
             13..=18 => Zone::C,
             _ => Zone::D,
+        }
+    }
+
+    /// Sentencing table cell for an offense level and category
+    ///
+    /// This is the bare table lookup; `lookup_guidelines_range` additionally
+    /// applies the record's statutory limits.
+    pub fn table_lookup(offense_level: i32, category: &CriminalHistoryCategory) -> GuidelinesTableRange {
+        let (minimum_months, maximum_months) = Self::table_range(offense_level, category);
+        GuidelinesTableRange {
+            offense_level,
+            criminal_history_category: category.clone(),
+            minimum_months,
+            maximum_months,
+            zone: Self::zone_for(minimum_months),
+        }
+    }
+
+    /// Look up guidelines range from sentencing table
+    pub fn lookup_guidelines_range(&mut self) {
+        let (table_min, table_max) = Self::table_range(
+            self.offense_level.final_offense_level,
+            &self.criminal_history.category,
+        );
+        let (min, max) = self.statutory_clamp(table_min, table_max).unwrap_or((table_min, table_max));
+
+        self.guidelines_range.minimum_months = min;
+        self.guidelines_range.maximum_months = max;
+        self.guidelines_range.zone = Self::zone_for(min);
+    }
+
+    /// Derive the guidelines range from the record's inputs, recording each step
+    ///
+    /// Runs the same calculations as `calculate_final_offense_level`,
+    /// `calculate_criminal_history_category` and `lookup_guidelines_range` on a
+    /// copy of the record, so the stored record is left untouched.
+    pub fn calculation_audit(&self) -> GuidelinesCalculationAudit {
+        let mut steps = Vec::new();
+        let mut push = |stage, description: String, basis: Option<String>, amount, result: String| {
+            steps.push(CalculationStep { step: steps.len() + 1, stage, description, basis, amount, result });
         };
+
+        let mut level = self.offense_level.base_offense_level;
+        push(
+            CalculationStage::BaseOffenseLevel,
+            "Base offense level".to_string(),
+            None,
+            None,
+            format!("level {}", level),
+        );
+        for change in self.offense_level_changes() {
+            level += change.levels;
+            push(change.stage, change.description, change.basis, Some(change.levels), format!("level {}", level));
+        }
+        if level < 1 {
+            push(
+                CalculationStage::OffenseLevelFloor,
+                "Offense level raised to the minimum of 1".to_string(),
+                None,
+                Some(1 - level),
+                "level 1".to_string(),
+            );
+        }
+
+        let history = &self.criminal_history;
+        let mut points = 0;
+        let point_sources = [
+            (format!("Prior sentences ({})", history.prior_sentences.len()), "USSG §4A1.1(a)-(c)", history.points),
+            ("Status points".to_string(), "USSG §4A1.1(d)", history.status_points),
+            ("Recency points".to_string(), "USSG §4A1.1(e)", history.recency_points),
+        ];
+        for (description, section, amount) in point_sources {
+            points += amount;
+            push(
+                CalculationStage::CriminalHistoryPoints,
+                description,
+                Some(section.to_string()),
+                Some(amount),
+                format!("{} points", points),
+            );
+        }
+
+        let mut worked = self.clone();
+        worked.calculate_final_offense_level();
+        worked.calculate_criminal_history_category();
+        let category = worked.criminal_history.category.clone();
+        push(
+            CalculationStage::CriminalHistoryCategory,
+            "Criminal history category from total points".to_string(),
+            Some("USSG Ch. 5, Pt. A".to_string()),
+            None,
+            format!("category {:?}", category),
+        );
+
+        let final_level = worked.offense_level.final_offense_level;
+        let (table_min, table_max) = Self::table_range(final_level, &category);
+        push(
+            CalculationStage::TableLookup,
+            format!("Sentencing table at level {}, category {:?}", final_level, category),
+            Some("USSG Ch. 5, Pt. A".to_string()),
+            None,
+            format!("{}-{} months", table_min, table_max),
+        );
+        if let Some((min, max)) = self.statutory_clamp(table_min, table_max) {
+            push(
+                CalculationStage::StatutoryClamp,
+                "Range limited by statutory minimum or maximum".to_string(),
+                Some(format!(
+                    "USSG §5G1.1; mandatory minimum {:?}, statutory maximum {:?}",
+                    self.guidelines_range.mandatory_minimum,
+                    self.guidelines_range.statutory_maximum,
+                )),
+                Some(min - table_min),
+                format!("{}-{} months", min, max),
+            );
+        }
+
+        worked.lookup_guidelines_range();
+        GuidelinesCalculationAudit {
+            sentencing_id: self.id.clone(),
+            steps,
+            final_offense_level: final_level,
+            criminal_history_points: points,
+            criminal_history_category: category,
+            matches_stored_range: worked.guidelines_range == self.guidelines_range,
+            guidelines_range: worked.guidelines_range,
+        }
     }
 
-    /// Check if eligible for safety valve
-    pub fn is_safety_valve_eligible(&self) -> bool {
-        // 18 U.S.C. § 3553(f) criteria
-        !self.criminal_history.career_offender
-            && !self.criminal_history.armed_career_criminal
-            && self.criminal_history.points <= 1
-            && self.criminal_history.prior_sentences.iter()
-                .filter(|s| s.violence_involved).count() == 0
+    /// Evaluate safety valve eligibility criterion by criterion
+    pub fn safety_valve_eligibility(&self) -> SafetyValveEligibility {
+        evaluate_safety_valve(&self.criminal_history, &self.offense_level)
+    }
+
+    /// Where the imposed custody fell against the guidelines range, once imposed
+    pub fn guideline_position(&self) -> Option<GuidelinePosition> {
+        let months = self.imposed_sentence.as_ref()?.custody_months;
+        let range = &self.guidelines_range;
+        Some(if months < range.minimum_months {
+            GuidelinePosition::Below
+        } else if months > range.maximum_months {
+            GuidelinePosition::Above
+        } else {
+            GuidelinePosition::Within
+        })
+    }
+
+    /// How far outside the range the imposed custody fell, as a percentage of
+    /// the nearer bound; `None` within the range or against a zero bound
+    pub fn percent_outside_range(&self) -> Option<f64> {
+        let months = self.imposed_sentence.as_ref()?.custody_months;
+        let range = &self.guidelines_range;
+        let (bound, distance) = match self.guideline_position()? {
+            GuidelinePosition::Within => return None,
+            GuidelinePosition::Below => (range.minimum_months, range.minimum_months - months),
+            GuidelinePosition::Above => (range.maximum_months, months - range.maximum_months),
+        };
+        (bound > 0).then(|| distance as f64 * 100.0 / bound as f64)
     }
 }
 
@@ -628,4 +1285,147 @@ pub struct CreateSentencingRequest {
     pub case_id: String,
     pub defendant_id: String,
     pub judge_id: String,
-}
\ No newline at end of file
+    #[serde(default)]
+    pub primary_offense_type: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prior(sentence_length_months: i32, points_assigned: i32) -> PriorSentence {
+        PriorSentence {
+            offense: "Possession of a controlled substance".to_string(),
+            sentence_date: Utc::now(),
+            sentence_length_months,
+            points_assigned,
+            violence_involved: false,
+            controlled_substance: true,
+        }
+    }
+
+    #[test]
+    fn test_one_point_sentences_are_capped_at_four() {
+        let mut sentencing = Sentencing::new("case".into(), "defendant".into(), "judge".into());
+        sentencing.criminal_history.prior_sentences = (0..6).map(|_| prior(0, 1)).collect();
+        sentencing.criminal_history.points = 6;
+
+        let breakdown = sentencing.criminal_history_points_breakdown();
+
+        assert_eq!(breakdown.total_points, 4);
+        assert_eq!(breakdown.prior_sentences[3].points, 1);
+        assert_eq!(breakdown.prior_sentences[4].points, 0);
+        assert_eq!(breakdown.prior_sentences[5].rule, "USSG §4A1.1(c)");
+        assert_eq!(breakdown.category, CriminalHistoryCategory::III);
+        assert_eq!(breakdown.recorded_points, 6);
+        assert!(!breakdown.matches_recorded);
+    }
+
+    #[test]
+    fn test_safety_valve_fails_only_on_criminal_history_points() {
+        let mut sentencing = Sentencing::new("case".into(), "defendant".into(), "judge".into());
+        sentencing.criminal_history.prior_sentences = vec![prior(3, 2)];
+        sentencing.criminal_history.points = 2;
+
+        let result = evaluate_safety_valve(&sentencing.criminal_history, &sentencing.offense_level);
+
+        assert!(!result.eligible);
+        assert_eq!(result.criteria.len(), 5);
+        let failed: Vec<_> = result.criteria.iter().filter(|c| !c.passed).collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].criterion, SafetyValveCriterion::CriminalHistoryPoints);
+        assert_eq!(failed[0].reason, "2 criminal history point(s); at most 1 allowed");
+        assert!(!sentencing.safety_valve_eligibility().eligible);
+    }
+
+    #[test]
+    fn test_safety_valve_eligible_when_every_criterion_passes() {
+        let mut sentencing = Sentencing::new("case".into(), "defendant".into(), "judge".into());
+        sentencing.criminal_history.points = 1;
+
+        let result = sentencing.safety_valve_eligibility();
+
+        assert!(result.eligible);
+        assert!(result.criteria.iter().all(|c| c.passed));
+
+        sentencing.offense_level.role_adjustment = 2;
+        let result = sentencing.safety_valve_eligibility();
+        assert!(!result.eligible);
+        let failed = result.criteria.iter().find(|c| !c.passed).unwrap();
+        assert_eq!(failed.criterion, SafetyValveCriterion::AggravatingRole);
+    }
+
+    fn sentenced(offense: Option<&str>, minimum: i32, maximum: i32, custody: i32) -> Sentencing {
+        let mut sentencing = Sentencing::new("case".into(), "defendant".into(), "judge".into());
+        sentencing.primary_offense_type = offense.map(str::to_string);
+        sentencing.guidelines_range.minimum_months = minimum;
+        sentencing.guidelines_range.maximum_months = maximum;
+        sentencing.imposed_sentence = Some(ImposedSentence {
+            custody_months: custody,
+            probation_months: 0,
+            home_confinement_months: 0,
+            intermittent_confinement: false,
+            fine_amount: None,
+            restitution_amount: None,
+            forfeiture_amount: None,
+            special_assessment: 100.0,
+            concurrent_consecutive: ConcurrentConsecutive::Concurrent,
+            credit_time_served: 0,
+            voluntary_surrender_date: None,
+        });
+        sentencing
+    }
+
+    #[test]
+    fn test_percent_outside_range_is_measured_from_nearer_bound() {
+        assert_eq!(sentenced(None, 40, 50, 30).percent_outside_range(), Some(25.0));
+        assert_eq!(sentenced(None, 40, 50, 60).percent_outside_range(), Some(20.0));
+        assert_eq!(sentenced(None, 40, 50, 50).percent_outside_range(), None);
+        assert_eq!(sentenced(None, 0, 0, 6).guideline_position(), Some(GuidelinePosition::Above));
+        assert_eq!(sentenced(None, 0, 0, 6).percent_outside_range(), None);
+
+        let unsentenced = Sentencing::new("case".into(), "defendant".into(), "judge".into());
+        assert_eq!(unsentenced.guideline_position(), None);
+    }
+
+    #[test]
+    fn test_guideline_compliance_percentages_and_medians() {
+        let sentencings = vec![
+            sentenced(Some("fraud"), 40, 50, 45),
+            sentenced(Some("fraud"), 40, 50, 30),
+            sentenced(Some("fraud"), 40, 50, 20),
+            sentenced(Some("drug_trafficking"), 100, 120, 150),
+            sentenced(None, 10, 16, 12),
+            Sentencing::new("case".into(), "defendant".into(), "judge".into()),
+        ];
+
+        let compliance = GuidelineCompliance::of(&sentencings);
+        assert_eq!(compliance.sentenced, 5);
+        assert_eq!((compliance.within_range, compliance.below_range, compliance.above_range), (2, 2, 1));
+        assert_eq!(compliance.within_range_percent, 40.0);
+        assert_eq!(compliance.below_range_percent, 40.0);
+        assert_eq!(compliance.above_range_percent, 20.0);
+        // Below-range sentences fell 25% and 50% under the minimum
+        assert_eq!(compliance.median_percent_below_range, Some(37.5));
+        assert_eq!(compliance.median_percent_above_range, Some(25.0));
+        assert_eq!(compliance.median_custody_months, Some(30.0));
+
+        let by_offense = GuidelineCompliance::by_offense_type(&sentencings);
+        assert_eq!(by_offense.keys().collect::<Vec<_>>(), ["drug_trafficking", "fraud", "unspecified"]);
+        assert_eq!(by_offense["fraud"].below_range, 2);
+        assert_eq!(by_offense["unspecified"].sentenced, 1);
+    }
+
+    #[test]
+    fn test_compliance_deltas() {
+        let judge = GuidelineCompliance::of(&[sentenced(None, 40, 50, 30)]);
+        let district = GuidelineCompliance::of(&[sentenced(None, 40, 50, 30), sentenced(None, 40, 50, 45)]);
+
+        let deltas = ComplianceDeltas::between(&judge, &district);
+        assert_eq!(deltas.below_range_percent, 50.0);
+        assert_eq!(deltas.within_range_percent, -50.0);
+        assert_eq!(deltas.median_percent_below_range, Some(0.0));
+        assert_eq!(deltas.median_percent_above_range, None);
+        assert_eq!(deltas.median_custody_months, Some(-7.5));
+    }
+}
@@ -0,0 +1,344 @@
+//! Tenant data export bundles
+//!
+//! An export gathers a district's records from every domain into one JSON
+//! document for backups and migrations. Each domain is optional so an
+//! export can be limited to the records that are needed. The same bundle is
+//! what an import reads, e.g. to seed a new district from a template.
+
+use crate::domain::attorney::Attorney;
+use crate::domain::criminal_case::CriminalCase;
+use crate::domain::deadline::Deadline;
+use crate::domain::docket::{CalendarEntry, DocketEntry};
+use crate::domain::judge::Judge;
+use crate::domain::opinion::JudicialOpinion;
+use crate::domain::order::JudicialOrder;
+use crate::domain::rule::Rule;
+use crate::domain::sentencing::Sentencing;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use utoipa::ToSchema;
+
+/// A group of records that can be exported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportDomain {
+    Attorneys,
+    Cases,
+    Judges,
+    Deadlines,
+    DocketEntries,
+    CalendarEvents,
+    Orders,
+    Opinions,
+    Rules,
+    Sentencings,
+}
+
+impl ExportDomain {
+    /// Every domain, in export order
+    pub const ALL: [ExportDomain; 10] = [
+        Self::Attorneys,
+        Self::Cases,
+        Self::Judges,
+        Self::Deadlines,
+        Self::DocketEntries,
+        Self::CalendarEvents,
+        Self::Orders,
+        Self::Opinions,
+        Self::Rules,
+        Self::Sentencings,
+    ];
+
+    /// Name used in the bundle and the `domains` filter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Attorneys => "attorneys",
+            Self::Cases => "cases",
+            Self::Judges => "judges",
+            Self::Deadlines => "deadlines",
+            Self::DocketEntries => "docket_entries",
+            Self::CalendarEvents => "calendar_events",
+            Self::Orders => "orders",
+            Self::Opinions => "opinions",
+            Self::Rules => "rules",
+            Self::Sentencings => "sentencings",
+        }
+    }
+
+    /// Parse a comma-separated `domains` filter, e.g. `attorneys,cases`
+    ///
+    /// Names are trimmed and deduplicated; the result is in export order.
+    pub fn parse_list(list: &str) -> Result<Vec<Self>, String> {
+        let mut domains = Vec::new();
+        for name in list.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let domain = Self::ALL.into_iter().find(|d| d.as_str() == name).ok_or_else(|| {
+                format!(
+                    "Unknown domain '{}', expected any of: {}",
+                    name,
+                    Self::ALL.map(|d| d.as_str()).join(", ")
+                )
+            })?;
+            domains.push(domain);
+        }
+        if domains.is_empty() {
+            return Err("domains must name at least one domain".to_string());
+        }
+        domains.sort();
+        domains.dedup();
+        Ok(domains)
+    }
+}
+
+/// All records of a tenant, or of the requested domains
+///
+/// Domains that weren't requested are left out of the JSON entirely, so an
+/// empty list always means the tenant has no records of that kind.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct TenantExport {
+    pub tenant_id: String,
+    pub exported_at: DateTime<Utc>,
+    pub domains: Vec<ExportDomain>,
+    /// Number of records exported per domain
+    pub counts: BTreeMap<String, usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attorneys: Option<Vec<Attorney>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cases: Option<Vec<CriminalCase>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub judges: Option<Vec<Judge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadlines: Option<Vec<Deadline>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docket_entries: Option<Vec<DocketEntry>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calendar_events: Option<Vec<CalendarEntry>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orders: Option<Vec<JudicialOrder>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opinions: Option<Vec<JudicialOpinion>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<Rule>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sentencings: Option<Vec<Sentencing>>,
+}
+
+impl TenantExport {
+    /// Check the bundle is consistent before anything is imported from it
+    ///
+    /// Every listed domain must carry its records and every domain with
+    /// records must be listed; counts, where given, must match; and record
+    /// IDs must be present and unique within their domain.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        self.check_domain(ExportDomain::Attorneys, &self.attorneys, &mut errors);
+        self.check_domain(ExportDomain::Cases, &self.cases, &mut errors);
+        self.check_domain(ExportDomain::Judges, &self.judges, &mut errors);
+        self.check_domain(ExportDomain::Deadlines, &self.deadlines, &mut errors);
+        self.check_domain(ExportDomain::DocketEntries, &self.docket_entries, &mut errors);
+        self.check_domain(ExportDomain::CalendarEvents, &self.calendar_events, &mut errors);
+        self.check_domain(ExportDomain::Orders, &self.orders, &mut errors);
+        self.check_domain(ExportDomain::Opinions, &self.opinions, &mut errors);
+        self.check_domain(ExportDomain::Rules, &self.rules, &mut errors);
+        self.check_domain(ExportDomain::Sentencings, &self.sentencings, &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn check_domain<T: ExportRecord>(&self, domain: ExportDomain, records: &Option<Vec<T>>, errors: &mut Vec<String>) {
+        let name = domain.as_str();
+        let listed = self.domains.contains(&domain);
+        let records = match records {
+            Some(records) => records,
+            None => {
+                if listed {
+                    errors.push(format!("{} is listed in domains but has no records field", name));
+                }
+                return;
+            }
+        };
+        if !listed {
+            errors.push(format!("{} has records but is not listed in domains", name));
+        }
+        if let Some(&count) = self.counts.get(name) {
+            if count != records.len() {
+                errors.push(format!("{} count is {} but {} records were given", name, count, records.len()));
+            }
+        }
+
+        let mut seen = HashSet::new();
+        for record in records {
+            let id = record.record_id();
+            if id.trim().is_empty() {
+                errors.push(format!("{} contains a record without an id", name));
+            } else if !seen.insert(id.clone()) {
+                errors.push(format!("{} contains id {} more than once", name, id));
+            }
+        }
+    }
+}
+
+/// A record that can be exported and imported by its ID
+pub trait ExportRecord {
+    /// The ID the record is stored under
+    fn record_id(&self) -> String;
+}
+
+macro_rules! export_record {
+    ($($record:ty),*) => {
+        $(impl ExportRecord for $record {
+            fn record_id(&self) -> String {
+                self.id.to_string()
+            }
+        })*
+    };
+}
+
+export_record!(
+    Attorney,
+    CriminalCase,
+    Judge,
+    Deadline,
+    DocketEntry,
+    CalendarEntry,
+    JudicialOrder,
+    JudicialOpinion,
+    Rule,
+    Sentencing
+);
+
+/// What an import does with a record whose ID already exists in the tenant
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictPolicy {
+    /// Keep the existing record and move on
+    Skip,
+    /// Replace the existing record with the imported one
+    Overwrite,
+    /// Import nothing from a domain that has any existing IDs
+    #[default]
+    Fail,
+}
+
+impl ConflictPolicy {
+    /// Parse the `conflict_policy` query parameter
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "skip" => Ok(Self::Skip),
+            "overwrite" => Ok(Self::Overwrite),
+            "fail" => Ok(Self::Fail),
+            other => Err(format!(
+                "Unknown conflict_policy '{}', expected one of: skip, overwrite, fail",
+                other
+            )),
+        }
+    }
+}
+
+/// Outcome of importing one domain
+///
+/// A domain is imported as a unit: when it fails, whatever was written for
+/// it is undone and all of its records count as failed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct DomainImportResult {
+    pub imported: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    /// Why the domain failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Per-domain outcome of importing a bundle into a tenant
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ImportReport {
+    pub tenant_id: String,
+    pub conflict_policy: ConflictPolicy,
+    pub domains: BTreeMap<String, DomainImportResult>,
+}
+
+impl ImportReport {
+    /// Whether any domain failed to import
+    pub fn has_failures(&self) -> bool {
+        self.domains.values().any(|d| d.error.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_domain_list() {
+        assert_eq!(
+            ExportDomain::parse_list("cases, attorneys,cases").unwrap(),
+            vec![ExportDomain::Attorneys, ExportDomain::Cases]
+        );
+        assert_eq!(
+            ExportDomain::parse_list("docket_entries").unwrap(),
+            vec![ExportDomain::DocketEntries]
+        );
+        assert!(ExportDomain::parse_list("cases,todos").unwrap_err().contains("todos"));
+        assert!(ExportDomain::parse_list(" , ").is_err());
+    }
+
+    #[test]
+    fn test_unrequested_domains_are_omitted() {
+        let export = TenantExport {
+            tenant_id: "sdny".to_string(),
+            domains: vec![ExportDomain::Judges],
+            judges: Some(Vec::new()),
+            ..TenantExport::default()
+        };
+        let json = serde_json::to_value(&export).unwrap();
+        assert_eq!(json["judges"], serde_json::json!([]));
+        assert!(json.get("cases").is_none());
+        assert_eq!(json["domains"], serde_json::json!(["judges"]));
+    }
+
+    fn judge() -> Judge {
+        Judge::new("Hon. Jane Smith".to_string(), crate::domain::judge::JudgeTitle::DistrictJudge, "SDNY".to_string(), "Courtroom 1".to_string())
+    }
+
+    #[test]
+    fn test_exported_bundle_validates() {
+        let export = TenantExport {
+            domains: vec![ExportDomain::Judges],
+            counts: BTreeMap::from([("judges".to_string(), 2)]),
+            judges: Some(vec![judge(), judge()]),
+            ..TenantExport::default()
+        };
+        assert!(export.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_inconsistent_bundle() {
+        let duplicate = judge();
+        let export = TenantExport {
+            domains: vec![ExportDomain::Judges, ExportDomain::Cases],
+            counts: BTreeMap::from([("judges".to_string(), 3)]),
+            judges: Some(vec![duplicate.clone(), duplicate]),
+            rules: Some(Vec::new()),
+            ..TenantExport::default()
+        };
+        let errors = export.validate().unwrap_err();
+        assert_eq!(errors.len(), 4, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.contains("more than once")));
+        assert!(errors.iter().any(|e| e.starts_with("judges count")));
+        assert!(errors.iter().any(|e| e.starts_with("cases is listed")));
+        assert!(errors.iter().any(|e| e.starts_with("rules has records")));
+    }
+
+    #[test]
+    fn test_parse_conflict_policy() {
+        assert_eq!(ConflictPolicy::parse("Skip").unwrap(), ConflictPolicy::Skip);
+        assert_eq!(ConflictPolicy::parse("overwrite").unwrap(), ConflictPolicy::Overwrite);
+        assert_eq!(ConflictPolicy::parse("fail").unwrap(), ConflictPolicy::Fail);
+        assert!(ConflictPolicy::parse("merge").is_err());
+        assert_eq!(ConflictPolicy::default(), ConflictPolicy::Fail);
+    }
+}
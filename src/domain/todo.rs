@@ -4,6 +4,7 @@
 //! for persisting and retrieving ToDo items from Spin's key-value store.
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use spin_sdk::key_value::Store;
 use uuid::Uuid;
@@ -26,6 +27,9 @@ pub struct ToDo {
     pub is_completed: bool,
     /// Soft delete flag - items are not physically removed from storage
     pub is_deleted: bool,
+    /// When the ToDo item is due, if it has a deadline
+    #[serde(default)]
+    pub due_date: Option<DateTime<Utc>>,
 }
 impl ToDo {
     /// Create a new ToDo item with the given contents
@@ -35,15 +39,55 @@ impl ToDo {
     /// - The provided contents
     /// - Marked as incomplete (is_completed = false)
     /// - Not deleted (is_deleted = false)
+    /// - No due date
     pub fn new(contents: String) -> Self {
         Self {
             id: Uuid::new_v4(),
             contents,
             is_completed: false,
             is_deleted: false,
+            due_date: None,
         }
     }
 
+    /// Whether the item is still open after its due date
+    ///
+    /// Items without a due date, and completed or deleted items, are never
+    /// overdue.
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        !self.is_completed && !self.is_deleted && self.due_date.is_some_and(|due| due < now)
+    }
+
+    /// Sort items by due date, earliest first, with undated items last
+    pub fn sort_by_due_date(todos: &mut [Self]) {
+        todos.sort_by_key(|t| (t.due_date.is_none(), t.due_date));
+    }
+
+    /// Flip the completion status of the stored item with the given ID
+    ///
+    /// Returns the updated item, or `None` if there is no item with that ID.
+    pub fn toggle_by_id(id: Uuid) -> Result<Option<Self>> {
+        let Some(mut todo) = Self::get_by_id(id)? else {
+            return Ok(None);
+        };
+        todo.is_completed = !todo.is_completed;
+        todo.save()?;
+        Ok(Some(todo))
+    }
+
+    /// Soft delete the stored item with the given ID
+    ///
+    /// The item stays in storage with `is_deleted` set. Returns the updated
+    /// item, or `None` if there is no item with that ID.
+    pub fn delete_by_id(id: Uuid) -> Result<Option<Self>> {
+        let Some(mut todo) = Self::get_by_id(id)? else {
+            return Ok(None);
+        };
+        todo.is_deleted = true;
+        todo.save()?;
+        Ok(Some(todo))
+    }
+
     /// Build a storage key for a ToDo item with the given ID
     ///
     /// Keys are formatted as "{KEY_PREFIX}{uuid}" (e.g., "todo-123e4567-e89b...")
@@ -100,3 +144,47 @@ impl ToDo {
             .collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn todo(contents: &str, due_date: Option<DateTime<Utc>>) -> ToDo {
+        ToDo { due_date, ..ToDo::new(contents.to_string()) }
+    }
+
+    #[test]
+    fn test_overdue_needs_a_past_due_date() {
+        let now = Utc::now();
+        assert!(todo("late", Some(now - Duration::hours(1))).is_overdue(now));
+        assert!(!todo("due later", Some(now + Duration::hours(1))).is_overdue(now));
+        assert!(!todo("due now", Some(now)).is_overdue(now));
+        assert!(!todo("no due date", None).is_overdue(now));
+    }
+
+    #[test]
+    fn test_completed_and_deleted_items_are_not_overdue() {
+        let now = Utc::now();
+        let mut done = todo("done", Some(now - Duration::days(1)));
+        done.is_completed = true;
+        assert!(!done.is_overdue(now));
+
+        let mut deleted = todo("deleted", Some(now - Duration::days(1)));
+        deleted.is_deleted = true;
+        assert!(!deleted.is_overdue(now));
+    }
+
+    #[test]
+    fn test_sort_by_due_date_puts_undated_last() {
+        let now = Utc::now();
+        let mut todos = vec![
+            todo("undated", None),
+            todo("later", Some(now + Duration::days(2))),
+            todo("sooner", Some(now + Duration::days(1))),
+        ];
+        ToDo::sort_by_due_date(&mut todos);
+        let order: Vec<&str> = todos.iter().map(|t| t.contents.as_str()).collect();
+        assert_eq!(order, vec!["sooner", "later", "undated"]);
+    }
+}
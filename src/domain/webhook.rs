@@ -0,0 +1,464 @@
+//! Webhook subscriptions and outbound notifications
+//!
+//! External agencies such as probation and pretrial services subscribe to
+//! court events with a target URL and a shared secret. Handlers enqueue an
+//! event for every matching subscription, and the queue is flushed by
+//! `POST /api/webhooks/deliver-pending`.
+//!
+//! A district may also configure its own webhook, which is posted to as soon
+//! as a case is created or changes status. A post that fails is queued and
+//! retried with the subscription notifications.
+//!
+//! Each delivery body is signed with HMAC-SHA256 over the raw JSON using the
+//! subscription's secret and sent in the `X-Webhook-Signature` header as
+//! `sha256=<hex>`, so receivers can verify the notification came from the
+//! court and was not altered.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::net::{Ipv4Addr, Ipv6Addr};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Shortest shared secret accepted for a subscription
+pub const MIN_SECRET_LENGTH: usize = 16;
+
+/// Delivery attempts made before a notification is abandoned
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 5;
+
+/// Court events a subscription can receive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum WebhookEventType {
+    #[serde(rename = "case.created")]
+    CaseCreated,
+    #[serde(rename = "case.status_changed")]
+    CaseStatusChanged,
+    #[serde(rename = "deadline.urgent")]
+    DeadlineUrgent,
+    #[serde(rename = "order.issued")]
+    OrderIssued,
+}
+
+impl WebhookEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEventType::CaseCreated => "case.created",
+            WebhookEventType::CaseStatusChanged => "case.status_changed",
+            WebhookEventType::DeadlineUrgent => "deadline.urgent",
+            WebhookEventType::OrderIssued => "order.issued",
+        }
+    }
+}
+
+/// A registered webhook endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    pub target_url: String,
+    pub event_types: Vec<WebhookEventType>,
+    /// Shared secret for signing deliveries; never returned by the API
+    pub secret: String,
+    pub description: Option<String>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A webhook subscription as returned by the API, without its secret
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSubscriptionSummary {
+    pub id: Uuid,
+    pub target_url: String,
+    pub event_types: Vec<WebhookEventType>,
+    pub description: Option<String>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    /// Validate and create a subscription
+    pub fn new(
+        target_url: &str,
+        event_types: Vec<WebhookEventType>,
+        secret: String,
+        description: Option<String>,
+    ) -> Result<Self, String> {
+        validate_target_url(target_url)?;
+        if event_types.is_empty() {
+            return Err("At least one event type is required".to_string());
+        }
+        if secret.chars().count() < MIN_SECRET_LENGTH {
+            return Err(format!("Secret must be at least {} characters", MIN_SECRET_LENGTH));
+        }
+
+        let mut deduped = Vec::new();
+        for event_type in event_types {
+            if !deduped.contains(&event_type) {
+                deduped.push(event_type);
+            }
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            target_url: target_url.to_string(),
+            event_types: deduped,
+            secret,
+            description,
+            active: true,
+            created_at: Utc::now(),
+        })
+    }
+
+    pub fn subscribes_to(&self, event_type: WebhookEventType) -> bool {
+        self.active && self.event_types.contains(&event_type)
+    }
+
+    pub fn summary(&self) -> WebhookSubscriptionSummary {
+        WebhookSubscriptionSummary {
+            id: self.id,
+            target_url: self.target_url.clone(),
+            event_types: self.event_types.clone(),
+            description: self.description.clone(),
+            active: self.active,
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// The district's own webhook endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistrictWebhook {
+    pub target_url: String,
+    /// Shared secret for signing deliveries; never returned by the API
+    pub secret: String,
+    pub updated_at: DateTime<Utc>,
+    pub last_delivery: Option<DirectDelivery>,
+}
+
+/// The district webhook as returned by the API, without its secret
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct DistrictWebhookSummary {
+    /// `None` when the district has no webhook
+    pub target_url: Option<String>,
+    pub secret_configured: bool,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub last_delivery: Option<DirectDelivery>,
+}
+
+/// Outcome of the latest post to the district webhook
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DirectDelivery {
+    pub event_id: Uuid,
+    pub event_type: WebhookEventType,
+    pub attempted_at: DateTime<Utc>,
+    /// HTTP status returned by the target, if it responded
+    pub status_code: Option<u16>,
+    /// Transport error, if the target could not be reached
+    pub error: Option<String>,
+    /// Whether the target answered with a 2xx status
+    pub delivered: bool,
+}
+
+impl DistrictWebhook {
+    /// Events posted to the district webhook
+    pub const EVENT_TYPES: [WebhookEventType; 2] =
+        [WebhookEventType::CaseCreated, WebhookEventType::CaseStatusChanged];
+
+    /// Validate and create the district webhook
+    pub fn new(target_url: &str, secret: String) -> Result<Self, String> {
+        validate_target_url(target_url)?;
+        if secret.chars().count() < MIN_SECRET_LENGTH {
+            return Err(format!("Secret must be at least {} characters", MIN_SECRET_LENGTH));
+        }
+        Ok(Self {
+            target_url: target_url.to_string(),
+            secret,
+            updated_at: Utc::now(),
+            last_delivery: None,
+        })
+    }
+
+    pub fn sends(&self, event_type: WebhookEventType) -> bool {
+        Self::EVENT_TYPES.contains(&event_type)
+    }
+
+    pub fn summary(&self) -> DistrictWebhookSummary {
+        DistrictWebhookSummary {
+            target_url: Some(self.target_url.clone()),
+            secret_configured: !self.secret.is_empty(),
+            updated_at: Some(self.updated_at),
+            last_delivery: self.last_delivery.clone(),
+        }
+    }
+}
+
+/// Target URLs must be absolute HTTPS URLs with a public host
+///
+/// Deliveries are made from inside the deployment, so a target naming
+/// localhost, a cloud metadata service, or a loopback, private, link-local
+/// or otherwise internal IP address is refused. Host names are not resolved
+/// here; a public name that points at an internal address is left to the
+/// outbound network policy.
+fn validate_target_url(target_url: &str) -> Result<(), String> {
+    let url = url::Url::parse(target_url).map_err(|e| format!("Invalid target URL '{}': {}", target_url, e))?;
+    if url.scheme() != "https" {
+        return Err(format!("Target URL must use https, got '{}'", url.scheme()));
+    }
+    let internal = match url.host() {
+        None => return Err("Target URL must include a host".to_string()),
+        Some(url::Host::Domain(name)) => is_internal_name(name),
+        Some(url::Host::Ipv4(ip)) => is_internal_ipv4(ip),
+        Some(url::Host::Ipv6(ip)) => is_internal_ipv6(ip),
+    };
+    if internal {
+        return Err(format!("Target URL must not point at an internal address, got '{}'", target_url));
+    }
+    Ok(())
+}
+
+/// Names that always reach the local host or a metadata service
+fn is_internal_name(name: &str) -> bool {
+    let name = name.trim_end_matches('.').to_ascii_lowercase();
+    name.is_empty()
+        || name == "localhost"
+        || name.ends_with(".localhost")
+        || name == "metadata.google.internal"
+}
+
+fn is_internal_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    ip.is_loopback()
+        || ip.is_private()
+        // Includes the 169.254.169.254 metadata endpoint
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        // Carrier-grade NAT, 100.64.0.0/10
+        || (a == 100 && (b & 0xC0) == 64)
+}
+
+fn is_internal_ipv6(ip: Ipv6Addr) -> bool {
+    if let Some(v4) = ip.to_ipv4_mapped() {
+        return is_internal_ipv4(v4);
+    }
+    let first = ip.segments()[0];
+    ip.is_loopback()
+        || ip.is_unspecified()
+        // Unique local, fc00::/7, which holds the fd00:ec2::254 metadata endpoint
+        || (first & 0xFE00) == 0xFC00
+        // Link-local, fe80::/10
+        || (first & 0xFFC0) == 0xFE80
+}
+
+/// A court event as delivered to subscribers
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookEvent {
+    pub id: Uuid,
+    pub event_type: WebhookEventType,
+    pub occurred_at: DateTime<Utc>,
+    #[schema(value_type = Object)]
+    pub data: serde_json::Value,
+}
+
+impl WebhookEvent {
+    pub fn new(event_type: WebhookEventType, data: serde_json::Value) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_type,
+            occurred_at: Utc::now(),
+            data,
+        }
+    }
+}
+
+/// A notification waiting in the outbound queue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingDelivery {
+    pub id: Uuid,
+    /// `None` for a notification to the district webhook
+    pub subscription_id: Option<Uuid>,
+    pub event: WebhookEvent,
+    /// Attempts made so far
+    pub attempts: u32,
+    /// Earliest time the next attempt may be made
+    pub next_attempt_at: DateTime<Utc>,
+}
+
+impl PendingDelivery {
+    pub fn new(subscription_id: Option<Uuid>, event: WebhookEvent) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            subscription_id,
+            next_attempt_at: event.occurred_at,
+            event,
+            attempts: 0,
+        }
+    }
+
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.next_attempt_at <= now
+    }
+
+    /// Record a failed attempt and schedule the next one
+    ///
+    /// Retries back off exponentially: 1, 2, 4 and 8 minutes. Returns `false`
+    /// once the attempts are used up and the delivery should be abandoned.
+    pub fn record_failure(&mut self, now: DateTime<Utc>) -> bool {
+        self.attempts += 1;
+        if self.attempts >= MAX_DELIVERY_ATTEMPTS {
+            return false;
+        }
+        self.next_attempt_at = now + Duration::minutes(1 << (self.attempts - 1));
+        true
+    }
+}
+
+/// Outcome of one delivery attempt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryOutcome {
+    Delivered,
+    /// Failed, and will be retried
+    Retrying,
+    /// Failed on the last allowed attempt
+    Abandoned,
+}
+
+/// Record of an attempt to deliver a notification
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DeliveryAttempt {
+    pub delivery_id: Uuid,
+    pub subscription_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: WebhookEventType,
+    /// Attempt number, starting at 1
+    pub attempt: u32,
+    pub attempted_at: DateTime<Utc>,
+    /// HTTP status returned by the target, if it responded
+    pub status_code: Option<u16>,
+    /// Transport error, if the target could not be reached
+    pub error: Option<String>,
+    pub outcome: DeliveryOutcome,
+}
+
+/// HMAC-SHA256 signature of a delivery body, as `sha256=<hex>`
+pub fn sign_payload(secret: &str, body: &[u8]) -> String {
+    const BLOCK_SIZE: usize = 64;
+
+    let mut key = if secret.len() > BLOCK_SIZE {
+        Sha256::digest(secret.as_bytes()).to_vec()
+    } else {
+        secret.as_bytes().to_vec()
+    };
+    key.resize(BLOCK_SIZE, 0);
+
+    let inner_pad: Vec<u8> = key.iter().map(|b| b ^ 0x36).collect();
+    let outer_pad: Vec<u8> = key.iter().map(|b| b ^ 0x5c).collect();
+
+    let mut inner = Sha256::new();
+    inner.update(&inner_pad);
+    inner.update(body);
+    let inner_hash = inner.finalize();
+
+    let mut outer = Sha256::new();
+    outer.update(&outer_pad);
+    outer.update(inner_hash);
+    format!("sha256={:x}", outer.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_payload_matches_rfc_4231() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            sign_payload("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_subscription_validation() {
+        let secret = "0123456789abcdef".to_string();
+        let events = vec![WebhookEventType::OrderIssued, WebhookEventType::OrderIssued];
+
+        let sub = WebhookSubscription::new("https://probation.example.gov/hooks", events.clone(), secret.clone(), None)
+            .unwrap();
+        assert_eq!(sub.event_types, vec![WebhookEventType::OrderIssued]);
+        assert!(sub.subscribes_to(WebhookEventType::OrderIssued));
+        assert!(!sub.subscribes_to(WebhookEventType::DeadlineUrgent));
+
+        assert!(WebhookSubscription::new("http://probation.example.gov", events.clone(), secret.clone(), None).is_err());
+        assert!(WebhookSubscription::new("not a url", events.clone(), secret.clone(), None).is_err());
+        assert!(WebhookSubscription::new("https://probation.example.gov", vec![], secret, None).is_err());
+        assert!(WebhookSubscription::new("https://probation.example.gov", events, "short".to_string(), None).is_err());
+    }
+
+    #[test]
+    fn test_internal_targets_are_rejected() {
+        let internal = [
+            "https://localhost/hooks",
+            "https://LOCALHOST./hooks",
+            "https://api.localhost/hooks",
+            "https://metadata.google.internal/computeMetadata/v1/",
+            "https://127.0.0.1/hooks",
+            "https://2130706433/hooks",
+            "https://10.0.0.8/hooks",
+            "https://172.16.4.2:8443/hooks",
+            "https://192.168.1.20/hooks",
+            "https://169.254.169.254/latest/meta-data/",
+            "https://100.64.0.1/hooks",
+            "https://0.0.0.0/hooks",
+            "https://[::1]/hooks",
+            "https://[::ffff:127.0.0.1]/hooks",
+            "https://[fd00:ec2::254]/latest/meta-data/",
+            "https://[fe80::1]/hooks",
+        ];
+        for target in internal {
+            assert!(validate_target_url(target).is_err(), "{} should be rejected", target);
+        }
+
+        for target in ["https://probation.example.gov/hooks", "https://93.184.216.34/hooks", "https://[2606:4700::1111]/"] {
+            assert!(validate_target_url(target).is_ok(), "{} should be accepted", target);
+        }
+    }
+
+    #[test]
+    fn test_district_webhook_validation() {
+        let secret = "0123456789abcdef".to_string();
+        let webhook = DistrictWebhook::new("https://clerk.example.gov/case-events", secret.clone()).unwrap();
+        assert!(webhook.sends(WebhookEventType::CaseCreated));
+        assert!(webhook.sends(WebhookEventType::CaseStatusChanged));
+        assert!(!webhook.sends(WebhookEventType::OrderIssued));
+
+        let summary = webhook.summary();
+        assert!(summary.secret_configured);
+        assert!(!serde_json::to_string(&summary).unwrap().contains(&secret));
+
+        assert!(DistrictWebhook::new("http://clerk.example.gov", secret.clone()).is_err());
+        assert!(DistrictWebhook::new("https://127.0.0.1/hooks", secret).is_err());
+        assert!(DistrictWebhook::new("https://clerk.example.gov", "short".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_retries_back_off_until_abandoned() {
+        let event = WebhookEvent::new(WebhookEventType::OrderIssued, serde_json::json!({}));
+        let mut delivery = PendingDelivery::new(Some(Uuid::new_v4()), event);
+        let now = Utc::now();
+        assert!(delivery.is_due(now));
+
+        assert!(delivery.record_failure(now));
+        assert_eq!(delivery.next_attempt_at, now + Duration::minutes(1));
+        assert!(!delivery.is_due(now));
+
+        assert!(delivery.record_failure(now));
+        assert_eq!(delivery.next_attempt_at, now + Duration::minutes(2));
+        assert!(delivery.record_failure(now));
+        assert!(delivery.record_failure(now));
+        assert_eq!(delivery.next_attempt_at, now + Duration::minutes(8));
+        assert!(!delivery.record_failure(now));
+        assert_eq!(delivery.attempts, MAX_DELIVERY_ATTEMPTS);
+    }
+}
@@ -0,0 +1,308 @@
+//! Weekly docket for a judge
+//!
+//! Collects a judge's calendar events and case deadlines for one Monday to
+//! Sunday week and buckets them by day in the district's local time, so a
+//! 9:00 PM Eastern hearing lands on the day it is actually held.
+
+use super::deadline::{Deadline, DeadlineStatus};
+use super::docket::{CalendarEntry, EventStatus};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+/// Kind of record a weekly docket item came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WeeklyDocketItemKind {
+    Event,
+    Deadline,
+}
+
+/// A single event or deadline on the weekly docket
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WeeklyDocketItem {
+    pub kind: WeeklyDocketItemKind,
+    /// Calendar event or deadline ID
+    pub id: Uuid,
+    pub case_id: Uuid,
+    pub time: DateTime<Utc>,
+    /// Time of day in district local time (HH:MM)
+    pub local_time: String,
+    /// Event type or deadline type, e.g. `motion_hearing` or `answer`
+    pub item_type: String,
+    pub description: String,
+    /// Courtroom for events; deadlines have none
+    pub courtroom: Option<String>,
+    pub status: String,
+}
+
+/// One day of the weekly docket
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WeeklyDocketDay {
+    pub date: NaiveDate,
+    pub weekday: String,
+    /// Items for the day, earliest first
+    pub items: Vec<WeeklyDocketItem>,
+}
+
+/// A judge's events and deadlines for one week, grouped by day
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WeeklyDocket {
+    pub judge_id: Uuid,
+    pub judge_name: String,
+    /// Monday of the week
+    pub week_start: NaiveDate,
+    /// Sunday of the week
+    pub week_end: NaiveDate,
+    /// District offset from UTC used for day grouping
+    pub utc_offset_hours: i32,
+    pub event_count: usize,
+    pub deadline_count: usize,
+    /// Seven days, Monday through Sunday, including empty ones
+    pub days: Vec<WeeklyDocketDay>,
+}
+
+impl WeeklyDocket {
+    /// Configuration path holding the district's offset from UTC in hours
+    pub const UTC_OFFSET_CONFIG_PATH: &'static str = "scheduling.utc_offset_hours";
+
+    /// Monday of the week containing `date`
+    pub fn week_start_for(date: NaiveDate) -> NaiveDate {
+        date - Duration::days(date.weekday().num_days_from_monday() as i64)
+    }
+
+    /// District offset from UTC, or `None` when out of range
+    pub fn offset(utc_offset_hours: i32) -> Option<FixedOffset> {
+        if !(-12..=14).contains(&utc_offset_hours) {
+            return None;
+        }
+        FixedOffset::east_opt(utc_offset_hours * 3600)
+    }
+
+    /// UTC bounds of the local week starting on `week_start`, end exclusive
+    pub fn week_range(week_start: NaiveDate, offset: FixedOffset) -> (DateTime<Utc>, DateTime<Utc>) {
+        (
+            Self::local_midnight(week_start, offset),
+            Self::local_midnight(week_start + Duration::days(7), offset),
+        )
+    }
+
+    /// The UTC instant local midnight starts `day`
+    pub fn local_midnight(day: NaiveDate, offset: FixedOffset) -> DateTime<Utc> {
+        offset
+            .from_local_datetime(&day.and_time(NaiveTime::MIN))
+            .single()
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|| day.and_time(NaiveTime::MIN).and_utc())
+    }
+
+    /// Build the week containing `week_of` from a judge's events and the
+    /// deadlines on the judge's cases
+    ///
+    /// Cancelled events and deadlines that are completed, waived or moot are
+    /// left off; everything else falling inside the local week is included.
+    pub fn build(
+        judge_id: Uuid,
+        judge_name: &str,
+        week_of: NaiveDate,
+        offset: FixedOffset,
+        events: &[CalendarEntry],
+        deadlines: &[Deadline],
+    ) -> Self {
+        let week_start = Self::week_start_for(week_of);
+        let (range_start, range_end) = Self::week_range(week_start, offset);
+        let in_week = |time: &DateTime<Utc>| *time >= range_start && *time < range_end;
+
+        let mut days: Vec<WeeklyDocketDay> = (0..7)
+            .map(|i| {
+                let date = week_start + Duration::days(i);
+                WeeklyDocketDay {
+                    date,
+                    weekday: date.format("%A").to_string(),
+                    items: Vec::new(),
+                }
+            })
+            .collect();
+
+        let mut place = |item: WeeklyDocketItem| {
+            let local_date = item.time.with_timezone(&offset).date_naive();
+            let index = (local_date - week_start).num_days() as usize;
+            days[index].items.push(item);
+        };
+
+        let mut event_count = 0;
+        for event in events {
+            if event.judge_id != judge_id
+                || matches!(event.status, EventStatus::Cancelled)
+                || !in_week(&event.scheduled_date)
+            {
+                continue;
+            }
+            event_count += 1;
+            place(WeeklyDocketItem {
+                kind: WeeklyDocketItemKind::Event,
+                id: event.id,
+                case_id: event.case_id,
+                time: event.scheduled_date,
+                local_time: event.scheduled_date.with_timezone(&offset).format("%H:%M").to_string(),
+                item_type: event.event_type.config_key(),
+                description: event.description.clone(),
+                courtroom: Some(event.courtroom.clone()),
+                status: snake_name(&event.status),
+            });
+        }
+
+        let mut deadline_count = 0;
+        for deadline in deadlines {
+            if matches!(
+                deadline.status,
+                DeadlineStatus::Completed | DeadlineStatus::Waived | DeadlineStatus::Moot
+            ) || !in_week(&deadline.due_date)
+            {
+                continue;
+            }
+            deadline_count += 1;
+            place(WeeklyDocketItem {
+                kind: WeeklyDocketItemKind::Deadline,
+                id: deadline.id,
+                case_id: deadline.case_id,
+                time: deadline.due_date,
+                local_time: deadline.due_date.with_timezone(&offset).format("%H:%M").to_string(),
+                item_type: snake_name(&deadline.deadline_type),
+                description: deadline.description.clone(),
+                courtroom: None,
+                status: snake_name(&deadline.status),
+            });
+        }
+
+        for day in &mut days {
+            day.items.sort_by_key(|item| item.time);
+        }
+
+        Self {
+            judge_id,
+            judge_name: judge_name.to_string(),
+            week_start,
+            week_end: week_start + Duration::days(6),
+            utc_offset_hours: offset.local_minus_utc() / 3600,
+            event_count,
+            deadline_count,
+            days,
+        }
+    }
+}
+
+/// Serialized snake_case name of a unit enum variant
+fn snake_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::deadline::DeadlineType;
+    use crate::domain::docket::CalendarEventType;
+
+    fn event(judge_id: Uuid, scheduled_date: DateTime<Utc>, status: EventStatus) -> CalendarEntry {
+        CalendarEntry {
+            id: Uuid::new_v4(),
+            case_id: Uuid::nil(),
+            judge_id,
+            event_type: CalendarEventType::MotionHearing,
+            scheduled_date,
+            duration_minutes: 60,
+            courtroom: "12C".to_string(),
+            description: "Hearing".to_string(),
+            participants: vec![],
+            court_reporter: None,
+            is_public: true,
+            call_time: None,
+            actual_start: None,
+            actual_end: None,
+            status,
+            notes: String::new(),
+            minutes: None,
+        }
+    }
+
+    fn deadline(due_date: DateTime<Utc>, status: DeadlineStatus) -> Deadline {
+        Deadline {
+            id: Uuid::new_v4(),
+            case_id: Uuid::nil(),
+            deadline_type: DeadlineType::Answer,
+            due_date,
+            triggering_event: "Service".to_string(),
+            triggering_date: due_date - Duration::days(21),
+            applicable_rule: "FRCP 12(a)".to_string(),
+            description: "Answer due".to_string(),
+            responsible_party: "Defendant".to_string(),
+            is_jurisdictional: false,
+            is_extendable: true,
+            status,
+            completion_date: None,
+            extension_requests: vec![],
+            reminders_sent: vec![],
+            due_date_history: vec![],
+            depends_on: None,
+            offset_days: None,
+        }
+    }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    #[test]
+    fn test_week_starts_on_monday() {
+        // March 11, 2026 is a Wednesday
+        assert_eq!(WeeklyDocket::week_start_for(day(11)), day(9));
+        assert_eq!(WeeklyDocket::week_start_for(day(9)), day(9));
+        assert_eq!(WeeklyDocket::week_start_for(day(15)), day(9));
+    }
+
+    #[test]
+    fn test_items_bucket_by_local_day() {
+        let judge_id = Uuid::new_v4();
+        let eastern = WeeklyDocket::offset(-5).unwrap();
+        let events = vec![
+            // Tuesday 02:00 UTC is Monday 21:00 Eastern
+            event(judge_id, Utc.with_ymd_and_hms(2026, 3, 10, 2, 0, 0).unwrap(), EventStatus::Scheduled),
+            event(judge_id, Utc.with_ymd_and_hms(2026, 3, 12, 15, 0, 0).unwrap(), EventStatus::Scheduled),
+            event(judge_id, Utc.with_ymd_and_hms(2026, 3, 12, 16, 0, 0).unwrap(), EventStatus::Cancelled),
+            event(Uuid::new_v4(), Utc.with_ymd_and_hms(2026, 3, 12, 17, 0, 0).unwrap(), EventStatus::Scheduled),
+            // Monday 03:00 UTC of the following week is still Sunday locally
+            event(judge_id, Utc.with_ymd_and_hms(2026, 3, 16, 3, 0, 0).unwrap(), EventStatus::Scheduled),
+        ];
+        let deadlines = vec![
+            deadline(Utc.with_ymd_and_hms(2026, 3, 13, 22, 0, 0).unwrap(), DeadlineStatus::Pending),
+            deadline(Utc.with_ymd_and_hms(2026, 3, 13, 22, 0, 0).unwrap(), DeadlineStatus::Completed),
+            deadline(Utc.with_ymd_and_hms(2026, 3, 20, 22, 0, 0).unwrap(), DeadlineStatus::Pending),
+        ];
+
+        let docket = WeeklyDocket::build(judge_id, "Judge Week", day(11), eastern, &events, &deadlines);
+
+        assert_eq!(docket.week_start, day(9));
+        assert_eq!(docket.week_end, day(15));
+        assert_eq!(docket.days.len(), 7);
+        assert_eq!(docket.event_count, 3);
+        assert_eq!(docket.deadline_count, 1);
+
+        let counts: Vec<usize> = docket.days.iter().map(|d| d.items.len()).collect();
+        assert_eq!(counts, vec![1, 0, 0, 1, 1, 0, 1]);
+        assert_eq!(docket.days[0].weekday, "Monday");
+        assert_eq!(docket.days[0].items[0].local_time, "21:00");
+        assert_eq!(docket.days[4].items[0].kind, WeeklyDocketItemKind::Deadline);
+        assert_eq!(docket.days[6].items[0].local_time, "22:00");
+    }
+
+    #[test]
+    fn test_offset_rejects_out_of_range_hours() {
+        assert!(WeeklyDocket::offset(-10).is_some());
+        assert!(WeeklyDocket::offset(15).is_none());
+        assert!(WeeklyDocket::offset(-13).is_none());
+    }
+}
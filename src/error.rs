@@ -3,21 +3,88 @@
 //! Provides custom error types and conversions for consistent error handling
 //! across the application.
 
+use crate::utils::request_id;
 use serde::{Deserialize, Serialize};
 use spin_sdk::http::{IntoResponse, Response};
 use std::fmt;
 use utoipa::ToSchema;
 
-/// Error response model for API errors
+/// Stable, machine-readable error codes
+///
+/// Clients branch on the code; the message is for people and may change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// The requested resource does not exist
+    NotFound,
+    /// The request was understood but its content is invalid
+    ValidationFailed,
+    /// The request body could not be parsed
+    MalformedRequest,
+    /// The caller may not perform the operation
+    Forbidden,
+    /// The operation conflicts with existing state
+    Conflict,
+    /// The resource existed but has been moved out of the live store
+    Gone,
+    /// The district or tenant header is missing or unknown
+    TenantInvalid,
+    /// The request body exceeds the allowed size
+    PayloadTooLarge,
+    /// The request body is not of a type the endpoint accepts
+    UnsupportedMediaType,
+    /// Too many requests in the current window
+    RateLimited,
+    /// Reading or writing the tenant store failed
+    StorageError,
+    /// Any other server-side failure
+    InternalError,
+}
+
+/// Error envelope returned by every failing request:
+/// `{"error": {"code", "message", "details", "request_id"}}`
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ErrorResponse {
-    /// Error message
-    pub error: String,
-    /// HTTP status code
-    pub status: u16,
-    /// Optional error details
+    pub error: ErrorBody,
+}
+
+/// The failure carried in an [`ErrorResponse`]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ErrorBody {
+    /// Machine-readable error code
+    pub code: ErrorCode,
+    /// Human-readable description of the failure
+    pub message: String,
+    /// Structured details, such as the fields that failed validation
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub details: Option<String>,
+    #[schema(value_type = Option<Object>)]
+    pub details: Option<serde_json::Value>,
+    /// Id of the request, also returned in the `X-Request-Id` header
+    pub request_id: String,
+}
+
+impl ErrorResponse {
+    /// Envelope for the request currently being handled
+    pub fn new(code: ErrorCode, message: impl Into<String>, details: Option<serde_json::Value>) -> Self {
+        Self {
+            error: ErrorBody {
+                code,
+                message: message.into(),
+                details,
+                request_id: request_id::current(),
+            },
+        }
+    }
+
+    /// JSON response carrying this envelope
+    pub fn into_response(self, status: u16) -> Response {
+        Response::builder()
+            .status(status)
+            .header("content-type", "application/json")
+            .header(request_id::HEADER, self.error.request_id.clone())
+            .body(serde_json::to_vec(&self).unwrap_or_default())
+            .build()
+    }
 }
 
 /// API Error type representing all possible errors in the application
@@ -43,6 +110,131 @@ pub enum ApiError {
     InvalidInput(String),
     /// Conflict - resource already exists (409)
     Conflict(String),
+    /// Resource archived or otherwise removed from the live store (410)
+    Gone(String),
+    /// Missing or unknown district (400)
+    TenantInvalid(String),
+    /// Well-formed request whose content cannot be processed (422)
+    Unprocessable(String),
+    /// Request body over the size limit (413)
+    PayloadTooLarge(String),
+    /// Request body of a type the endpoint does not accept (415)
+    UnsupportedMediaType(String),
+    /// Too many requests in the current window (429), with the seconds
+    /// until it resets for the `Retry-After` header
+    RateLimited(String, u64),
+    /// Another error with structured details for the client
+    WithDetails(Box<ApiError>, serde_json::Value),
+}
+
+impl ApiError {
+    /// Attach structured details, e.g. the list of fields that failed
+    pub fn with_details(self, details: impl Serialize) -> Self {
+        match serde_json::to_value(details) {
+            Ok(details) => ApiError::WithDetails(Box::new(self.without_details()), details),
+            Err(_) => self,
+        }
+    }
+
+    fn without_details(self) -> Self {
+        match self {
+            ApiError::WithDetails(error, _) => *error,
+            other => other,
+        }
+    }
+
+    /// HTTP status code for the error
+    pub fn status(&self) -> u16 {
+        match self {
+            ApiError::NotFound(_) => 404,
+            ApiError::BadRequest(_)
+            | ApiError::SerializationError(_)
+            | ApiError::ValidationError(_)
+            | ApiError::InvalidInput(_)
+            | ApiError::TenantInvalid(_) => 400,
+            ApiError::Forbidden(_) => 403,
+            ApiError::Conflict(_) => 409,
+            ApiError::Gone(_) => 410,
+            ApiError::PayloadTooLarge(_) => 413,
+            ApiError::UnsupportedMediaType(_) => 415,
+            ApiError::Unprocessable(_) => 422,
+            ApiError::RateLimited(..) => 429,
+            ApiError::Internal(_) | ApiError::InternalServerError(_) | ApiError::StorageError(_) => 500,
+            ApiError::WithDetails(error, _) => error.status(),
+        }
+    }
+
+    /// Machine-readable code for the error
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ApiError::NotFound(_) => ErrorCode::NotFound,
+            ApiError::BadRequest(_)
+            | ApiError::ValidationError(_)
+            | ApiError::InvalidInput(_)
+            | ApiError::Unprocessable(_) => ErrorCode::ValidationFailed,
+            ApiError::SerializationError(_) => ErrorCode::MalformedRequest,
+            ApiError::Forbidden(_) => ErrorCode::Forbidden,
+            ApiError::Conflict(_) => ErrorCode::Conflict,
+            ApiError::Gone(_) => ErrorCode::Gone,
+            ApiError::TenantInvalid(_) => ErrorCode::TenantInvalid,
+            ApiError::PayloadTooLarge(_) => ErrorCode::PayloadTooLarge,
+            ApiError::UnsupportedMediaType(_) => ErrorCode::UnsupportedMediaType,
+            ApiError::RateLimited(..) => ErrorCode::RateLimited,
+            ApiError::StorageError(_) => ErrorCode::StorageError,
+            ApiError::Internal(_) | ApiError::InternalServerError(_) => ErrorCode::InternalError,
+            ApiError::WithDetails(error, _) => error.code(),
+        }
+    }
+
+    /// Human-readable message, without the kind prefix `Display` adds
+    pub fn message(&self) -> &str {
+        match self {
+            ApiError::NotFound(msg)
+            | ApiError::BadRequest(msg)
+            | ApiError::Internal(msg)
+            | ApiError::InternalServerError(msg)
+            | ApiError::Forbidden(msg)
+            | ApiError::StorageError(msg)
+            | ApiError::SerializationError(msg)
+            | ApiError::ValidationError(msg)
+            | ApiError::InvalidInput(msg)
+            | ApiError::Conflict(msg)
+            | ApiError::Gone(msg)
+            | ApiError::TenantInvalid(msg)
+            | ApiError::Unprocessable(msg)
+            | ApiError::PayloadTooLarge(msg)
+            | ApiError::UnsupportedMediaType(msg)
+            | ApiError::RateLimited(msg, _) => msg,
+            ApiError::WithDetails(error, _) => error.message(),
+        }
+    }
+
+    /// Structured details, if any were attached
+    pub fn details(&self) -> Option<&serde_json::Value> {
+        match self {
+            ApiError::WithDetails(_, details) => Some(details),
+            _ => None,
+        }
+    }
+
+    /// Seconds the client should wait before retrying, if the error says
+    pub fn retry_after(&self) -> Option<u64> {
+        match self {
+            ApiError::RateLimited(_, seconds) => Some(*seconds),
+            ApiError::WithDetails(error, _) => error.retry_after(),
+            _ => None,
+        }
+    }
+
+    /// Error envelope response for the request currently being handled
+    pub fn to_response(&self) -> Response {
+        let mut response =
+            ErrorResponse::new(self.code(), self.message(), self.details().cloned()).into_response(self.status());
+        if let Some(seconds) = self.retry_after() {
+            response.set_header("retry-after", seconds.to_string());
+        }
+        response
+    }
 }
 
 impl fmt::Display for ApiError {
@@ -58,6 +250,13 @@ impl fmt::Display for ApiError {
             ApiError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
             ApiError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
             ApiError::Conflict(msg) => write!(f, "Conflict: {}", msg),
+            ApiError::Gone(msg) => write!(f, "Gone: {}", msg),
+            ApiError::TenantInvalid(msg) => write!(f, "Invalid tenant: {}", msg),
+            ApiError::Unprocessable(msg) => write!(f, "Unprocessable: {}", msg),
+            ApiError::PayloadTooLarge(msg) => write!(f, "Payload too large: {}", msg),
+            ApiError::UnsupportedMediaType(msg) => write!(f, "Unsupported media type: {}", msg),
+            ApiError::RateLimited(msg, _) => write!(f, "Rate limited: {}", msg),
+            ApiError::WithDetails(error, _) => error.fmt(f),
         }
     }
 }
@@ -66,50 +265,7 @@ impl std::error::Error for ApiError {}
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
-        let (status, message, details) = match self {
-            ApiError::NotFound(msg) => (404, "Not Found".to_string(), Some(msg)),
-            ApiError::BadRequest(msg) => (400, "Bad Request".to_string(), Some(msg)),
-            ApiError::Internal(msg) => (500, "Internal Server Error".to_string(), Some(msg)),
-            ApiError::InternalServerError(msg) => (500, "Internal Server Error".to_string(), Some(msg)),
-            ApiError::Forbidden(msg) => (403, "Forbidden".to_string(), Some(msg)),
-            ApiError::StorageError(msg) => (
-                500,
-                "Storage operation failed".to_string(),
-                Some(msg),
-            ),
-            ApiError::SerializationError(msg) => (
-                400,
-                "Invalid data format".to_string(),
-                Some(msg),
-            ),
-            ApiError::ValidationError(msg) => (
-                400,
-                "Validation error".to_string(),
-                Some(msg),
-            ),
-            ApiError::InvalidInput(msg) => (
-                400,
-                "Invalid input".to_string(),
-                Some(msg),
-            ),
-            ApiError::Conflict(msg) => (
-                409,
-                "Conflict".to_string(),
-                Some(msg),
-            ),
-        };
-
-        let error_response = ErrorResponse {
-            error: message,
-            status,
-            details,
-        };
-
-        Response::builder()
-            .status(status)
-            .header("content-type", "application/json")
-            .body(serde_json::to_vec(&error_response).unwrap_or_default())
-            .build()
+        self.to_response()
     }
 }
 
@@ -152,4 +308,86 @@ pub mod validation {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_codes_and_statuses() {
+        assert_eq!(ApiError::NotFound("x".into()).code(), ErrorCode::NotFound);
+        assert_eq!(ApiError::InvalidInput("x".into()).code(), ErrorCode::ValidationFailed);
+        assert_eq!(ApiError::TenantInvalid("x".into()).status(), 400);
+        assert_eq!(ApiError::StorageError("x".into()).status(), 500);
+        assert_eq!(ApiError::PayloadTooLarge("x".into()).status(), 413);
+        assert_eq!(ApiError::Gone("x".into()).status(), 410);
+        assert_eq!(ApiError::UnsupportedMediaType("x".into()).code(), ErrorCode::UnsupportedMediaType);
+        assert_eq!(serde_json::to_value(ErrorCode::TenantInvalid).unwrap(), json!("TENANT_INVALID"));
+    }
+
+    #[test]
+    fn test_every_variant_has_a_stable_code_and_status() {
+        let cases = [
+            (ApiError::NotFound("x".into()), "NOT_FOUND", 404),
+            (ApiError::BadRequest("x".into()), "VALIDATION_FAILED", 400),
+            (ApiError::Internal("x".into()), "INTERNAL_ERROR", 500),
+            (ApiError::InternalServerError("x".into()), "INTERNAL_ERROR", 500),
+            (ApiError::Forbidden("x".into()), "FORBIDDEN", 403),
+            (ApiError::StorageError("x".into()), "STORAGE_ERROR", 500),
+            (ApiError::SerializationError("x".into()), "MALFORMED_REQUEST", 400),
+            (ApiError::ValidationError("x".into()), "VALIDATION_FAILED", 400),
+            (ApiError::InvalidInput("x".into()), "VALIDATION_FAILED", 400),
+            (ApiError::Conflict("x".into()), "CONFLICT", 409),
+            (ApiError::Gone("x".into()), "GONE", 410),
+            (ApiError::TenantInvalid("x".into()), "TENANT_INVALID", 400),
+            (ApiError::Unprocessable("x".into()), "VALIDATION_FAILED", 422),
+            (ApiError::PayloadTooLarge("x".into()), "PAYLOAD_TOO_LARGE", 413),
+            (ApiError::UnsupportedMediaType("x".into()), "UNSUPPORTED_MEDIA_TYPE", 415),
+            (ApiError::RateLimited("x".into(), 30), "RATE_LIMITED", 429),
+            (ApiError::Conflict("x".into()).with_details(json!({})), "CONFLICT", 409),
+        ];
+
+        for (error, code, status) in cases {
+            assert_eq!(serde_json::to_value(error.code()).unwrap(), json!(code), "{:?}", error);
+            assert_eq!(error.status(), status, "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn test_error_response_nests_the_failure_under_error() {
+        let error = ApiError::Gone("Case archived".into()).with_details(json!({"archive_url": "/api/archive/1"}));
+        let response = crate::utils::json_response::error_response(&error);
+        let body: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+
+        assert_eq!(*response.status(), 410);
+        assert_eq!(body.as_object().unwrap().len(), 1, "only the error object: {}", body);
+        assert_eq!(body["error"]["code"], "GONE");
+        assert_eq!(body["error"]["message"], "Case archived");
+        assert_eq!(body["error"]["details"]["archive_url"], "/api/archive/1");
+        assert!(body["error"]["request_id"].is_string());
+    }
+
+    #[test]
+    fn test_details_keep_code_status_and_message() {
+        let error = ApiError::Unprocessable("Template values rejected".into())
+            .with_details(json!({"missing": ["party_name"]}))
+            .with_details(json!({"missing": ["days"]}));
+
+        assert_eq!(error.status(), 422);
+        assert_eq!(error.code(), ErrorCode::ValidationFailed);
+        assert_eq!(error.message(), "Template values rejected");
+        assert_eq!(error.details(), Some(&json!({"missing": ["days"]})));
+    }
+
+    #[test]
+    fn test_rate_limited_response_carries_retry_after() {
+        let response = ApiError::RateLimited("Slow down".into(), 42).to_response();
+        let retry_after = response.header("retry-after").and_then(|v| v.as_str()).map(str::to_string);
+
+        assert_eq!(*response.status(), 429);
+        assert_eq!(retry_after.as_deref(), Some("42"));
+        assert_eq!(ApiError::NotFound("x".into()).retry_after(), None);
+    }
+}
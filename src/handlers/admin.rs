@@ -1,27 +1,61 @@
 //! Administrative handlers for multi-tenant operations
 
 use crate::adapters::{
+    spin_kv_attorney_repository::SpinKvAttorneyRepository,
     spin_kv_case_repository::SpinKvCaseRepository,
     spin_kv_deadline_repository::SpinKvDeadlineRepository,
     spin_kv_docket_repository::SpinKvDocketRepository,
+    spin_kv_document_repository::SpinKvDocumentRepository,
     spin_kv_judge_repository::SpinKvJudgeRepository,
+    spin_kv_rules_repository::SpinKvRulesRepository,
+    spin_kv_sentencing_repository::SpinKvSentencingRepository,
+    store_utils::open_validated_store,
 };
-use crate::error::ApiResult;
-use crate::ports::case_repository::CaseRepository;
+use crate::domain::district::{self, DistrictCodeError, TenantKind};
+use crate::domain::document::{DocumentIndexAudit, IndexAnomaly, IndexAnomalyKind};
+use crate::domain::tenant_export::{ConflictPolicy, ExportDomain, ImportReport, TenantExport};
+use crate::domain::webhook::{DistrictWebhook, DistrictWebhookSummary};
+use crate::error::{ApiError, ApiResult};
+use crate::ports::attorney_repository::AttorneyRepository;
+use crate::ports::case_repository::{CaseArchiveRepository, CaseRepository};
 use crate::ports::deadline_repository::DeadlineRepository;
+use crate::ports::document_repository::{DocumentIndexRepository, DocumentRepository};
 use crate::ports::judge_repository::JudgeRepository;
-use crate::utils::query_parser;
+use crate::ports::sentencing_repository::SentencingRepository;
+use crate::ports::webhook_repository::WebhookRepository;
+use crate::services::case_number_service;
+use crate::services::tenant_export_service::{self, TenantRepositories};
+use crate::services::tenant_seed::{SeedReport, TenantSeeder};
+use crate::utils::{access, query_parser, repository_factory::RepositoryFactory, tenant};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
+use utoipa::ToSchema;
 
-/// Initialize a new tenant's data stores
+/// Initialize a tenant and seed its defaults
+///
+/// Seeds the deadline rule set, default feature flags, the reminder schedule
+/// and starter order templates. Safe to re-run: missing seeds are added,
+/// untouched seeds are upgraded, and anything the district has modified is
+/// left alone.
+///
+/// A code outside the district registry is rejected with its closest
+/// matches unless `register=true` is given with an administrator token, in
+/// which case it is seeded and registered as a custom tenant. Its store
+/// must already exist in the deployment.
 #[utoipa::path(
     post,
-    path = "/api/admin/tenants/init",
+    path = "/api/admin/init-tenant",
     params(
-        ("tenant_id" = Option<String>, Query, description = "Tenant identifier (defaults to 'default')")
+        ("X-Court-District" = Option<String>, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = Option<String>, Header, description = "Administrator token; required to register a custom tenant"),
+        ("tenant_id" = Option<String>, Query, description = "Tenant identifier (defaults to the request's district)"),
+        ("register" = Option<bool>, Query, description = "Register an unknown code as a custom tenant (default false)")
     ),
     responses(
-        (status = 200, description = "Tenant data stores initialized successfully"),
+        (status = 200, description = "Report of seeded, upgraded and skipped defaults", body = SeedReport),
+        (status = 400, description = "Missing, unknown or unprovisioned tenant; unknown codes list close matches"),
+        (status = 403, description = "Missing or invalid administrator token when registering"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Administration"
@@ -29,68 +63,568 @@ use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
 pub fn init_tenant(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
     let query_string = req.query();
     let params = query_parser::parse_query_string(query_string);
+    let register = query_parser::get_bool(&params, "register").unwrap_or(false);
 
-    let tenant_id = query_parser::get_string(&params, "tenant_id")
-        .unwrap_or_else(|| "default".to_string());
+    let (requested, district_id) = match query_parser::get_string(&params, "tenant_id") {
+        Some(tenant_id) => (tenant_id.clone(), tenant_id),
+        None => (tenant::requested_tenant(&req).unwrap_or_default(), tenant::get_config_district_id(&req)),
+    };
 
-    // Initialize repositories with tenant-specific stores
-    let _case_repo = SpinKvCaseRepository::with_store(format!("cases_{}", tenant_id));
-    let _deadline_repo = SpinKvDeadlineRepository::with_store(format!("deadlines_{}", tenant_id));
-    let _docket_repo = SpinKvDocketRepository::with_store(format!("docket_{}", tenant_id));
-    let _judge_repo = SpinKvJudgeRepository::with_store(format!("judges_{}", tenant_id));
+    let (store_name, custom_code) = match tenant::resolve_district(&requested) {
+        Ok(_) => (RepositoryFactory::district_store_name(&requested)?, None),
+        Err(DistrictCodeError::Unknown { code, .. }) if register => {
+            access::require_admin(&req)?;
+            let store_name = code.to_lowercase();
+            if !tenant::is_provisioned(&store_name) {
+                return Err(ApiError::TenantInvalid(format!(
+                    "District {} is not provisioned on this deployment", code
+                )));
+            }
+            (store_name, Some(code))
+        }
+        Err(e) => return Err(RepositoryFactory::district_error(e)),
+    };
+
+    let report = TenantSeeder::new(store_name.clone(), store_name, district_id).run()?;
+    if let Some(code) = custom_code {
+        tenant::register_tenant(&code)?;
+    }
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
-        .body(serde_json::to_vec(&serde_json::json!({
-            "tenant_id": tenant_id,
-            "stores_initialized": ["cases", "deadlines", "docket", "judges"]
-        }))?)
+        .body(serde_json::to_vec(&report)?)
         .build())
 }
 
+/// Record counts of a tenant, per domain
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+pub struct DomainCounts {
+    pub attorneys: usize,
+    pub cases: usize,
+    pub judges: usize,
+    pub deadlines: usize,
+    pub orders: usize,
+    pub opinions: usize,
+    pub sentencings: usize,
+}
+
+impl DomainCounts {
+    fn total(&self) -> usize {
+        self.attorneys + self.cases + self.judges + self.deadlines + self.orders + self.opinions + self.sentencings
+    }
+}
+
+/// How large a tenant is
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TenantStats {
+    pub tenant_id: String,
+    pub generated_at: DateTime<Utc>,
+    /// Whether archived cases are included in `counts.cases`
+    pub include_deleted: bool,
+    pub counts: DomainCounts,
+    /// Cases archived out of the live case list
+    pub archived_cases: usize,
+    /// Sum of `counts`
+    pub total_records: usize,
+    /// Approximate bytes of the tenant's stored keys and values
+    pub storage_bytes: u64,
+}
+
 /// Get tenant statistics
+///
+/// Counts the records of each domain for the tenant resolved from
+/// `X-Court-District`, along with the approximate size of its store, so
+/// administrators can gauge how large a district has grown. Archived cases
+/// are soft-deleted and left out of the case count unless `include_deleted`
+/// is true; they are always reported as `archived_cases`. Sizing the store
+/// reads every value, so this is a full scan of the tenant.
 #[utoipa::path(
     get,
-    path = "/api/admin/tenants/stats",
+    path = "/api/admin/tenant-stats",
     params(
-        ("tenant_id" = Option<String>, Query, description = "Tenant identifier (defaults to 'default')")
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = String, Header, description = "Administrator token"),
+        ("include_deleted" = Option<bool>, Query, description = "Count archived cases as cases (default false)")
     ),
     responses(
-        (status = 200, description = "Tenant statistics including counts of cases, deadlines, judges, etc."),
+        (status = 200, description = "Per-domain record counts and approximate storage footprint", body = TenantStats),
+        (status = 400, description = "Missing or invalid tenant"),
+        (status = 403, description = "Missing or invalid administrator token"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Administration"
 )]
 pub fn get_tenant_stats(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
     let query_string = req.query();
     let params = query_parser::parse_query_string(query_string);
+    let include_deleted = query_parser::get_bool(&params, "include_deleted").unwrap_or(false);
 
-    let tenant_id = query_parser::get_string(&params, "tenant_id")
-        .unwrap_or_else(|| "default".to_string());
+    let stores = TenantStores::open(&req)?;
+    let archived_cases = stores.cases.count_archives()?;
+    let mut counts = stores.domain_counts()?;
+    if include_deleted {
+        counts.cases += archived_cases;
+    }
 
-    // Use tenant-specific repositories
-    let case_repo = SpinKvCaseRepository::with_store(format!("cases_{}", tenant_id));
-    let deadline_repo = SpinKvDeadlineRepository::with_store(format!("deadlines_{}", tenant_id));
-    let _docket_repo = SpinKvDocketRepository::with_store(format!("docket_{}", tenant_id));
-    let judge_repo = SpinKvJudgeRepository::with_store(format!("judges_{}", tenant_id));
+    let store = open_validated_store(&RepositoryFactory::tenant_store_name(&req)?)?;
+    let storage_bytes = store.footprint_bytes().map_err(anyhow::Error::from)?;
 
-    // Get counts from each repository (these would be actual count methods in production)
-    let case_count = case_repo.find_all_cases().map(|cases| cases.len()).unwrap_or(0);
-    let deadline_count = deadline_repo.find_deadlines_by_status(crate::domain::deadline::DeadlineStatus::Pending)
-        .map(|d| d.len()).unwrap_or(0);
-    let docket_count = 0; // No find_all method for docket entries - would need to iterate cases
-    let judge_count = judge_repo.find_all_judges().map(|j| j.len()).unwrap_or(0);
+    let stats = TenantStats {
+        tenant_id: tenant::get_tenant_id(&req),
+        generated_at: Utc::now(),
+        include_deleted,
+        total_records: counts.total(),
+        counts,
+        archived_cases,
+        storage_bytes,
+    };
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
-        .body(serde_json::to_vec(&serde_json::json!({
-            "tenant_id": tenant_id,
-            "statistics": {
-                "cases": case_count,
-                "pending_deadlines": deadline_count,
-                "docket_entries": docket_count,
-                "judges": judge_count
-            }
-        }))?)
+        .body(serde_json::to_vec(&stats)?)
+        .build())
+}
+
+/// A tenant in the district registry
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DistrictSummary {
+    /// Canonical code
+    pub code: String,
+    pub name: String,
+    /// Circuit of a federal district
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circuit: Option<String>,
+    pub kind: TenantKind,
+    /// Whether the tenant's store exists in this deployment
+    pub provisioned: bool,
+    /// Live record counts, for provisioned tenants
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counts: Option<DomainCounts>,
+    /// Sum of `counts`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_records: Option<usize>,
+}
+
+/// Every tenant in the district registry
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DistrictListing {
+    pub generated_at: DateTime<Utc>,
+    pub districts: Vec<DistrictSummary>,
+}
+
+/// List registered districts
+///
+/// Lists the federal districts, the built-in tenants and the custom tenants
+/// registered through `init-tenant`, with live record counts for each
+/// tenant whose store exists in this deployment. Counting reads every
+/// provisioned tenant, so this is a scan of the whole deployment.
+#[utoipa::path(
+    get,
+    path = "/api/admin/districts",
+    params(
+        ("X-Admin-Token" = String, Header, description = "Administrator token"),
+        ("provisioned" = Option<bool>, Query, description = "Only list tenants whose store exists (default false)")
+    ),
+    responses(
+        (status = 200, description = "Registered tenants with record counts", body = DistrictListing),
+        (status = 403, description = "Missing or invalid administrator token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Administration"
+)]
+pub fn list_districts(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
+    let query_string = req.query();
+    let params = query_parser::parse_query_string(query_string);
+    let provisioned_only = query_parser::get_bool(&params, "provisioned").unwrap_or(false);
+
+    let federal = district::FEDERAL_DISTRICTS
+        .iter()
+        .map(|d| (d.code.to_string(), d.name.to_string(), Some(d.circuit.to_string()), TenantKind::Federal));
+    let built_in = district::BUILT_IN_TENANTS
+        .iter()
+        .map(|(code, name)| (code.to_string(), name.to_string(), None, TenantKind::BuiltIn));
+    let custom = tenant::registered_tenants()
+        .into_iter()
+        .map(|t| (t.code.clone(), t.code, None, TenantKind::Custom));
+
+    let mut districts = Vec::new();
+    for (code, name, circuit, kind) in federal.chain(built_in).chain(custom) {
+        let store_name = code.to_lowercase();
+        let provisioned = tenant::is_provisioned(&store_name);
+        if provisioned_only && !provisioned {
+            continue;
+        }
+        let counts = match provisioned {
+            true => Some(TenantStores::for_store(store_name).domain_counts()?),
+            false => None,
+        };
+        districts.push(DistrictSummary {
+            code,
+            name,
+            circuit,
+            kind,
+            provisioned,
+            total_records: counts.as_ref().map(DomainCounts::total),
+            counts,
+        });
+    }
+
+    let listing = DistrictListing { generated_at: Utc::now(), districts };
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&listing)?)
+        .build())
+}
+
+/// Audit the integrity of the document index
+///
+/// Checks every indexed document: its type must be known, its metadata must
+/// have exactly the fields that type is generated from, and the case it was
+/// issued in must still exist. Entries that fail are reported as anomalies;
+/// nothing is modified.
+#[utoipa::path(
+    get,
+    path = "/api/admin/documents/index-audit",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = String, Header, description = "Administrator token")
+    ),
+    responses(
+        (status = 200, description = "Indexed documents checked and anomalies found", body = DocumentIndexAudit),
+        (status = 400, description = "Missing or invalid tenant"),
+        (status = 403, description = "Missing or invalid administrator token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Administration"
+)]
+pub fn audit_document_index(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
+    let document_repo = RepositoryFactory::document_repo(&req)?;
+    let case_repo = RepositoryFactory::case_repo(&req)?;
+    let format = crate::handlers::criminal_case::case_number_format(&req)?;
+
+    let entries = document_repo.list_indexed_documents()?;
+    let mut anomalies = Vec::new();
+    for entry in &entries {
+        anomalies.extend(entry.shape_anomalies());
+
+        let case = match entry.case_id {
+            Some(case_id) => case_repo.find_by_id(case_id)?,
+            None => case_number_service::find_by_number(&case_repo, &format, &entry.case_number)?,
+        };
+        if case.is_none() {
+            let reference = entry
+                .case_id
+                .map(|id| id.to_string())
+                .unwrap_or_else(|| entry.case_number.clone());
+            anomalies.push(IndexAnomaly {
+                document_id: entry.document_id.clone(),
+                kind: IndexAnomalyKind::CaseNotFound,
+                detail: format!("Case {} not found", reference),
+            });
+        }
+    }
+
+    let audit = DocumentIndexAudit {
+        checked: entries.len(),
+        anomaly_count: anomalies.len(),
+        anomalies,
+    };
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&audit)?)
+        .build())
+}
+
+/// Commits resolved by a journal recovery run
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct JournalRecoveryReport {
+    /// Journal entries finished or rolled back
+    pub recovered: usize,
+}
+
+/// Resolve commits a crash left partway
+///
+/// Finishes or rolls back every multi-record commit whose journal entry was
+/// left behind, leaving entries under a minute old to the request still
+/// committing them. Finding the entries scans the tenant's store, so this
+/// is intended to be called on a schedule, not per request.
+#[utoipa::path(
+    post,
+    path = "/api/admin/recover-journals",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = String, Header, description = "Administrator token")
+    ),
+    responses(
+        (status = 200, description = "Number of journal entries resolved", body = JournalRecoveryReport),
+        (status = 400, description = "Missing or invalid tenant"),
+        (status = 403, description = "Missing or invalid administrator token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Administration"
+)]
+pub fn recover_journals(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
+    let store = open_validated_store(&RepositoryFactory::tenant_store_name(&req)?)?;
+    let report = JournalRecoveryReport { recovered: store.recover_journals()? };
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&report)?)
         .build())
-}
\ No newline at end of file
+}
+
+/// Export all of a tenant's records as one JSON bundle
+///
+/// Collects every record of each requested domain (all of them by default)
+/// for the tenant resolved from `X-Court-District`. The bundle is assembled
+/// in memory from full scans of the tenant's store, so on a busy district it
+/// is large and slow to produce. It is intended for offline backups and
+/// migrations; use the regular list endpoints for anything interactive.
+#[utoipa::path(
+    get,
+    path = "/api/admin/export",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = String, Header, description = "Administrator token"),
+        ("domains" = Option<String>, Query, description = "Comma-separated domains to export (e.g., attorneys,cases); defaults to all")
+    ),
+    responses(
+        (status = 200, description = "Records of the requested domains", body = TenantExport),
+        (status = 400, description = "Missing or invalid tenant, or unknown domain"),
+        (status = 403, description = "Missing or invalid administrator token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Administration"
+)]
+pub fn export_tenant(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
+    let query_string = req.query();
+    let params = query_parser::parse_query_string(query_string);
+    let domains = match query_parser::get_string(&params, "domains") {
+        Some(list) => ExportDomain::parse_list(&list).map_err(ApiError::BadRequest)?,
+        None => ExportDomain::ALL.to_vec(),
+    };
+
+    let stores = TenantStores::open(&req)?;
+    let export = tenant_export_service::export_tenant(&tenant::get_tenant_id(&req), &domains, &stores.repositories())?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&export)?)
+        .build())
+}
+
+/// Import an export bundle into a tenant
+///
+/// Takes the bundle produced by `GET /api/admin/export`, e.g. a template
+/// district's, and writes its records into the tenant resolved from
+/// `X-Court-District`, keeping their IDs. `conflict_policy` decides what
+/// happens to IDs the tenant already has: `skip` keeps the existing record,
+/// `overwrite` replaces it, and `fail` (the default) imports nothing from a
+/// domain with any existing ID. The bundle is validated before anything is
+/// written. Each domain is imported as a unit, undoing its writes if one
+/// fails; the response reports per domain and is a 409 if any domain failed.
+#[utoipa::path(
+    post,
+    path = "/api/admin/import",
+    request_body = TenantExport,
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = String, Header, description = "Administrator token"),
+        ("conflict_policy" = Option<ConflictPolicy>, Query, description = "What to do with IDs that already exist: skip, overwrite or fail (default)")
+    ),
+    responses(
+        (status = 200, description = "Every domain imported", body = ImportReport),
+        (status = 400, description = "Missing or invalid tenant, invalid bundle or unknown conflict policy"),
+        (status = 403, description = "Missing or invalid administrator token"),
+        (status = 409, description = "One or more domains failed and were not imported", body = ImportReport),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Administration"
+)]
+pub fn import_tenant(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
+    let query_string = req.query();
+    let params = query_parser::parse_query_string(query_string);
+    let policy = match query_parser::get_string(&params, "conflict_policy") {
+        Some(value) => ConflictPolicy::parse(&value).map_err(ApiError::BadRequest)?,
+        None => ConflictPolicy::default(),
+    };
+
+    let bundle: TenantExport = serde_json::from_slice(req.body())
+        .map_err(|e| ApiError::BadRequest(format!("Invalid export bundle: {}", e)))?;
+
+    let stores = TenantStores::open(&req)?;
+    let report = tenant_export_service::import_tenant(&tenant::get_tenant_id(&req), &bundle, policy, &stores.repositories())?;
+
+    let status = if report.has_failures() { 409 } else { 200 };
+    Ok(ResponseBuilder::new(status)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&report)?)
+        .build())
+}
+
+/// Request to set or clear the district webhook
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateDistrictWebhookRequest {
+    /// HTTPS endpoint for case events; `null` removes the district webhook
+    pub target_url: Option<String>,
+    /// Shared secret used to sign deliveries (at least 16 characters);
+    /// may be left out to keep the current one
+    pub secret: Option<String>,
+}
+
+/// Get the district webhook
+///
+/// The district webhook receives `case.created` and `case.status_changed`
+/// events as they happen. A post that fails is retried by
+/// `POST /api/webhooks/deliver-pending`. The secret is never returned.
+#[utoipa::path(
+    get,
+    path = "/api/admin/webhooks",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = String, Header, description = "Administrator token")
+    ),
+    responses(
+        (status = 200, description = "The district webhook and its latest delivery; empty when none is set", body = DistrictWebhookSummary),
+        (status = 403, description = "Missing or invalid administrator token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Administration"
+)]
+pub fn get_district_webhook(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
+    let repo = RepositoryFactory::webhook_repo(&req)?;
+    let summary = repo.get_district_webhook()?
+        .map(|webhook| webhook.summary())
+        .unwrap_or_default();
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&summary)?)
+        .build())
+}
+
+/// Set or clear the district webhook
+///
+/// Events are signed with HMAC-SHA256 of the body under the secret, sent as
+/// `X-Webhook-Signature: sha256=<hex>`. Changing only the URL keeps the
+/// current secret.
+#[utoipa::path(
+    put,
+    path = "/api/admin/webhooks",
+    request_body = UpdateDistrictWebhookRequest,
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = String, Header, description = "Administrator token")
+    ),
+    responses(
+        (status = 200, description = "The district webhook as saved; the secret is not echoed back", body = DistrictWebhookSummary),
+        (status = 400, description = "Invalid target URL, or a missing or short secret"),
+        (status = 403, description = "Missing or invalid administrator token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Administration"
+)]
+pub fn update_district_webhook(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
+    let request: UpdateDistrictWebhookRequest = serde_json::from_slice(req.body())
+        .map_err(|e| ApiError::BadRequest(format!("Invalid webhook request: {}", e)))?;
+    let repo = RepositoryFactory::webhook_repo(&req)?;
+
+    let summary = match request.target_url {
+        None => {
+            repo.delete_district_webhook()?;
+            DistrictWebhookSummary::default()
+        }
+        Some(target_url) => {
+            let secret = match request.secret {
+                Some(secret) => secret,
+                None => repo.get_district_webhook()?
+                    .map(|current| current.secret)
+                    .ok_or_else(|| ApiError::BadRequest("A secret is required".to_string()))?,
+            };
+            let webhook = DistrictWebhook::new(target_url.trim(), secret).map_err(ApiError::BadRequest)?;
+            repo.save_district_webhook(&webhook)?;
+            webhook.summary()
+        }
+    };
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&summary)?)
+        .build())
+}
+
+/// The tenant's repositories for export, import and statistics
+struct TenantStores {
+    attorneys: SpinKvAttorneyRepository,
+    cases: SpinKvCaseRepository,
+    judges: SpinKvJudgeRepository,
+    deadlines: SpinKvDeadlineRepository,
+    docket: SpinKvDocketRepository,
+    documents: SpinKvDocumentRepository,
+    rules: SpinKvRulesRepository,
+    sentencing: SpinKvSentencingRepository,
+}
+
+impl TenantStores {
+    fn open(req: &Request) -> ApiResult<Self> {
+        Ok(Self::for_store(RepositoryFactory::tenant_store_name(req)?))
+    }
+
+    /// The repositories of a provisioned tenant's store
+    fn for_store(store_name: String) -> Self {
+        Self {
+            attorneys: SpinKvAttorneyRepository::with_store(store_name.clone()),
+            cases: SpinKvCaseRepository::with_store(store_name.clone()),
+            judges: SpinKvJudgeRepository::with_store(store_name.clone()),
+            deadlines: SpinKvDeadlineRepository::with_store(store_name.clone()),
+            docket: SpinKvDocketRepository::with_store(store_name.clone()),
+            documents: SpinKvDocumentRepository::with_store(store_name.clone()),
+            rules: SpinKvRulesRepository::with_store(store_name.clone()),
+            sentencing: SpinKvSentencingRepository::with_store(store_name),
+        }
+    }
+
+    /// Live record counts of each domain; archived cases aren't counted
+    fn domain_counts(&self) -> ApiResult<DomainCounts> {
+        Ok(DomainCounts {
+            attorneys: self.attorneys.count()?,
+            cases: self.cases.count()?,
+            judges: self.judges.count()?,
+            deadlines: self.deadlines.count()?,
+            orders: self.documents.count_orders()?,
+            opinions: self.documents.count_opinions()?,
+            sentencings: self.sentencing.count()?,
+        })
+    }
+
+    fn repositories(&self) -> TenantRepositories<'_> {
+        TenantRepositories {
+            attorneys: &self.attorneys,
+            cases: &self.cases,
+            judges: &self.judges,
+            deadlines: &self.deadlines,
+            docket: &self.docket,
+            calendar: &self.docket,
+            documents: &self.documents,
+            rules: &self.rules,
+            sentencing: &self.sentencing,
+        }
+    }
+}
@@ -6,11 +6,16 @@
 #[allow(unused_imports)] // These types are used in JSON serialization/deserialization
 use crate::domain::attorney::{
     Attorney, AttorneyStatus, Party, PartyStatus, AttorneyRepresentation,
-    ConflictCheck, ServiceRecord, AttorneyMetrics, BarAdmission, FederalAdmission,
+    ConflictCheck, ConflictCheckTurnaround, ServiceRecord, AttorneyMetrics, BarAdmission, FederalAdmission,
     ProHacViceAdmission, CJAAppointment, ECFRegistration, DisciplinaryAction,
-    Address, RepresentationType, WithdrawalReason, ServiceMethod,
-    ConflictType, ConflictSeverity, ConflictResult,
-    CreateAttorneyRequest, UpdateAttorneyRequest, CreatePartyRequest, PartyType, PartyRole, EntityType
+    DisciplinaryActionResponse, AffectedCase, ReinstatementRequest,
+    ExpiringProHacVice, RenewProHacViceRequest, PRO_HAC_VICE_TERM_DAYS,
+    Address, RepresentationType, RepresentationContinuity, WithdrawalReason, ServiceMethod,
+    ConflictType, ConflictSeverity, ConflictResult, CompensationStatus, VoucherStatus,
+    BulkCJAAppointmentRequest, BulkCJAAppointmentResponse, CJAAppointee, SkippedCJAAttorney,
+    DEFAULT_CJA_OPEN_APPOINTMENT_CAP, cja_rotation_order,
+    AddVoucherLineItemRequest, ApproveVoucherRequest, CJAVoucherSummary,
+    CreateAttorneyRequest, UpdateAttorneyRequest, PatchAttorneyRequest, CreatePartyRequest, PartyType, PartyRole, EntityType
 };
 use crate::domain::attorney_conflict::{
     ConflictCheckRequest, ConflictCheckResult, ConflictDetails, ConflictRecommendation,
@@ -21,7 +26,9 @@ use crate::domain::attorney_case::{
 };
 use crate::error::ApiError;
 use crate::ports::attorney_repository::AttorneyRepository;
+use crate::utils::name_match::{self, NameMatch};
 use crate::utils::{json_response as json, query_parser, repository_factory::RepositoryFactory};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use spin_sdk::http::{Params, Request, Response};
 
 // Attorney Management Endpoints
@@ -170,18 +177,23 @@ pub fn get_attorney_by_bar_number(req: Request, params: Params) -> Response {
     }
 }
 
-/// Update attorney
+/// Replace attorney
+///
+/// A full replacement must carry the collection fields (admissions, CJA
+/// appointments, disciplinary history); use PATCH for partial updates.
+/// `force=true` accepts a body without them and keeps the stored values.
 #[utoipa::path(
     put,
     path = "/api/attorneys/{id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("id" = String, Path, description = "Attorney ID")
+        ("id" = String, Path, description = "Attorney ID"),
+        ("force" = Option<bool>, Query, description = "Accept a body that omits collection fields")
     ),
     request_body = UpdateAttorneyRequest,
     responses(
         (status = 200, description = "Attorney updated successfully", body = Attorney),
-        (status = 400, description = "Invalid update data"),
+        (status = 400, description = "Invalid update data, or collection fields omitted without force=true"),
         (status = 404, description = "Attorney not found"),
         (status = 409, description = "Conflict with existing attorney"),
         (status = 500, description = "Internal server error")
@@ -202,6 +214,16 @@ pub fn update_attorney(req: Request, params: Params) -> Response {
         Err(e) => return json::error_response(&e),
     };
 
+    let query = query_parser::parse_query_string(req.query());
+    let force = query_parser::get_bool(&query, "force").unwrap_or(false);
+    let missing = update_request.missing_collections();
+    if !missing.is_empty() && !force {
+        return json::error_response(&ApiError::BadRequest(format!(
+            "PUT replaces the whole attorney record but the body omits {}; use PATCH for a partial update or pass force=true",
+            missing.join(", ")
+        )));
+    }
+
     // Get the existing attorney
     let mut existing = match repo.find_attorney_by_id(&attorney_id) {
         Ok(Some(attorney)) => attorney,
@@ -331,6 +353,82 @@ pub fn update_attorney(req: Request, params: Params) -> Response {
     }
 }
 
+/// Patch attorney
+///
+/// Applies a JSON Merge Patch (RFC 7396): only supplied fields change and
+/// `null` clears an optional field. Admissions, CJA appointments and
+/// disciplinary actions have their own endpoints and cannot be patched, so a
+/// client holding a stale copy cannot wipe them.
+#[utoipa::path(
+    patch,
+    path = "/api/attorneys/{id}",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = String, Path, description = "Attorney ID")
+    ),
+    request_body(content = PatchAttorneyRequest, content_type = "application/merge-patch+json"),
+    responses(
+        (status = 200, description = "Attorney updated successfully", body = Attorney),
+        (status = 400, description = "Invalid patch, or a field that cannot be patched"),
+        (status = 404, description = "Attorney not found"),
+        (status = 409, description = "Conflict with existing attorney"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "attorneys",
+)]
+pub fn patch_attorney(req: Request, params: Params) -> Response {
+    let repo = match RepositoryFactory::attorney_repo(&req) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let attorney_id = params.get("id").unwrap_or_default().to_string();
+
+    let patch: PatchAttorneyRequest = match json::parse_body(req.body()) {
+        Ok(p) => p,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let mut existing = match repo.find_attorney_by_id(&attorney_id) {
+        Ok(Some(attorney)) => attorney,
+        Ok(None) => {
+            return json::error_response(&ApiError::NotFound(
+                format!("Attorney {} not found", attorney_id)
+            ));
+        }
+        Err(e) => return json::error_response(&ApiError::StorageError(e.to_string())),
+    };
+
+    if let Some(bar_number) = &patch.bar_number {
+        if &existing.bar_number != bar_number {
+            match repo.find_attorney_by_bar_number(bar_number) {
+                Ok(Some(_)) => {
+                    return json::error_response(&ApiError::Conflict(
+                        format!("Attorney with bar number {} already exists", bar_number)
+                    ));
+                }
+                Ok(None) => {}
+                Err(e) => return json::error_response(&ApiError::StorageError(e.to_string())),
+            }
+        }
+    }
+
+    if let Some(email) = &patch.email {
+        if !is_valid_email(email) {
+            return json::error_response(&ApiError::BadRequest(
+                "Invalid email format".to_string()
+            ));
+        }
+    }
+
+    existing.apply_patch(patch);
+
+    match repo.update_attorney(existing) {
+        Ok(updated) => json::success_response(&updated),
+        Err(e) => json::error_response(&ApiError::StorageError(e.to_string())),
+    }
+}
+
 /// Delete attorney
 #[utoipa::path(
     delete,
@@ -490,9 +588,9 @@ pub fn get_attorneys_by_status(req: Request, params: Params) -> Response {
     };
 
     let status_str = params.get("status").unwrap_or_default();
-    let status: AttorneyStatus = match serde_json::from_str(&format!("\"{}\"", status_str)) {
+    let status: AttorneyStatus = match query_parser::parse_enum_param("status", status_str) {
         Ok(s) => s,
-        Err(e) => return json::error_response(&ApiError::BadRequest(e.to_string())),
+        Err(e) => return json::error_response(&e),
     };
 
     match repo.find_attorneys_by_status(status) {
@@ -753,6 +851,7 @@ pub fn get_attorneys_admitted_to_court(req: Request, params: Params) -> Response
     request_body = ProHacViceAdmission,
     responses(
         (status = 204, description = "Pro hac vice admission added successfully"),
+        (status = 400, description = "Sponsoring local attorney missing or not admitted to the court"),
         (status = 404, description = "Attorney not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -768,17 +867,156 @@ pub fn add_pro_hac_vice(req: Request, params: Params) -> Response {
     };
 
     let id = params.get("id").unwrap_or_default();
-    let admission: ProHacViceAdmission = match json::parse_body(req.body()) {
+    let mut admission: ProHacViceAdmission = match json::parse_body(req.body()) {
         Ok(a) => a,
         Err(e) => return json::error_response(&e),
     };
 
+    match repo.find_attorney_by_id(id) {
+        Ok(Some(_)) => {}
+        Ok(None) => return json::error_response(&ApiError::NotFound(format!("Attorney {} not found", id))),
+        Err(e) => return json::error_response(&ApiError::StorageError(e.to_string())),
+    }
+    if let Err(e) = validate_pro_hac_vice_sponsor(&repo, id, &admission) {
+        return json::error_response(&e);
+    }
+
+    // Admissions run for one term unless the order says otherwise
+    admission.expiration_date.get_or_insert(
+        admission.admission_date + chrono::Duration::days(PRO_HAC_VICE_TERM_DAYS),
+    );
+
     match repo.add_pro_hac_vice(id, admission) {
         Ok(_) => Response::builder().status(204).build(),
         Err(e) => json::error_response(&ApiError::StorageError(e.to_string())),
     }
 }
 
+/// Check that a pro hac vice admission is sponsored by another attorney
+/// admitted to the court
+fn validate_pro_hac_vice_sponsor(
+    repo: &impl AttorneyRepository,
+    attorney_id: &str,
+    admission: &ProHacViceAdmission,
+) -> Result<(), ApiError> {
+    let sponsor_id = admission
+        .sponsoring_local_attorney_id
+        .as_deref()
+        .filter(|id| !id.is_empty())
+        .ok_or_else(|| ApiError::BadRequest("sponsoring_local_attorney_id is required".to_string()))?;
+    if sponsor_id == attorney_id {
+        return Err(ApiError::BadRequest("An attorney cannot sponsor their own pro hac vice admission".to_string()));
+    }
+
+    let sponsor = repo
+        .find_attorney_by_id(sponsor_id)
+        .map_err(|e| ApiError::StorageError(e.to_string()))?
+        .ok_or_else(|| ApiError::BadRequest(format!("Sponsoring attorney {} not found", sponsor_id)))?;
+    if sponsor.status != AttorneyStatus::Active || !sponsor.can_practice_federal(&admission.court, None) {
+        return Err(ApiError::BadRequest(format!(
+            "Sponsoring attorney {} is not admitted to practice in {}",
+            sponsor_id, admission.court
+        )));
+    }
+    Ok(())
+}
+
+/// Renew a pro hac vice admission
+#[utoipa::path(
+    post,
+    path = "/api/attorneys/{id}/pro-hac-vice/{case_id}/renew",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = String, Path, description = "Attorney ID"),
+        ("case_id" = String, Path, description = "Case ID")
+    ),
+    request_body = RenewProHacViceRequest,
+    responses(
+        (status = 200, description = "Admission renewed; the renewal is logged on the admission", body = ProHacViceAdmission),
+        (status = 400, description = "Admission cannot be renewed, or its sponsor is no longer admitted"),
+        (status = 404, description = "Attorney or admission not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "pro-hac-vice",
+)]
+pub fn renew_pro_hac_vice(req: Request, params: Params) -> Response {
+    let repo = match RepositoryFactory::attorney_repo(&req) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let id = params.get("id").unwrap_or_default();
+    let case_id = params.get("case_id").unwrap_or_default();
+    let request: RenewProHacViceRequest = if req.body().is_empty() {
+        RenewProHacViceRequest::default()
+    } else {
+        match json::parse_body(req.body()) {
+            Ok(r) => r,
+            Err(e) => return json::error_response(&e),
+        }
+    };
+
+    let mut attorney = match repo.find_attorney_by_id(id) {
+        Ok(Some(attorney)) => attorney,
+        Ok(None) => return json::error_response(&ApiError::NotFound(format!("Attorney {} not found", id))),
+        Err(e) => return json::error_response(&ApiError::StorageError(e.to_string())),
+    };
+    let Some(index) = attorney.pro_hac_vice_admissions.iter().position(|phv| phv.case_id == case_id) else {
+        return json::error_response(&ApiError::NotFound(format!(
+            "No pro hac vice admission for attorney {} in case {}",
+            id, case_id
+        )));
+    };
+
+    // Sponsorships recorded before sponsors were tracked are not re-checked
+    let admission = &attorney.pro_hac_vice_admissions[index];
+    if admission.sponsoring_local_attorney_id.is_some() {
+        if let Err(e) = validate_pro_hac_vice_sponsor(&repo, id, admission) {
+            return json::error_response(&e);
+        }
+    }
+
+    if let Err(e) = attorney.pro_hac_vice_admissions[index].renew(request, Utc::now()) {
+        return json::error_response(&ApiError::BadRequest(e));
+    }
+    let renewed = attorney.pro_hac_vice_admissions[index].clone();
+
+    match repo.update_attorney(attorney) {
+        Ok(_) => json::success_response(&renewed),
+        Err(e) => json::error_response(&ApiError::StorageError(e.to_string())),
+    }
+}
+
+/// List pro hac vice admissions nearing expiration
+#[utoipa::path(
+    get,
+    path = "/api/attorneys/pro-hac-vice/expiring",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("days" = Option<usize>, Query, description = "Days ahead to look (default 30)")
+    ),
+    responses(
+        (status = 200, description = "Active admissions expiring within the window, soonest first", body = Vec<ExpiringProHacVice>),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "pro-hac-vice",
+)]
+pub fn get_expiring_pro_hac_vice(req: Request, _params: Params) -> Response {
+    let repo = match RepositoryFactory::attorney_repo(&req) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let parsed = query_parser::parse_query_string(req.query());
+    let days = query_parser::get_usize(&parsed, "days").unwrap_or(30);
+    let before = Utc::now() + chrono::Duration::days(days.min(3650) as i64);
+
+    match repo.find_pro_hac_vice_expiring(before) {
+        Ok(expiring) => json::success_response(&expiring),
+        Err(e) => json::error_response(&ApiError::StorageError(e.to_string())),
+    }
+}
+
 /// Update pro hac vice status
 #[utoipa::path(
     patch,
@@ -1051,7 +1289,7 @@ pub fn get_cja_appointments(req: Request, params: Params) -> Response {
     get,
     path = "/api/attorneys/cja/pending-vouchers",
     responses(
-        (status = 200, description = "List of pending vouchers", body = Vec<CJAAppointment>),
+        (status = 200, description = "Submitted vouchers awaiting approval, with totals", body = Vec<CJAVoucherSummary>),
         (status = 500, description = "Internal server error")
     ),
     tag = "cja",
@@ -1074,6 +1312,289 @@ pub fn get_pending_cja_vouchers(req: Request, _params: Params) -> Response {
     }
 }
 
+/// Load a CJA appointment, apply a voucher change and save it
+///
+/// Responds with the voucher and its totals, 404 if the attorney or
+/// appointment is missing and 422 if the change breaks a voucher rule.
+fn update_voucher(
+    req: &Request,
+    params: &Params,
+    status: u16,
+    change: impl FnOnce(&mut CJAAppointment) -> Result<(), String>,
+) -> Response {
+    let repo = match RepositoryFactory::attorney_repo(req) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let attorney_id = params.get("id").unwrap_or_default();
+    let appointment_id = params.get("appointment_id").unwrap_or_default();
+
+    let attorney = match repo.find_attorney_by_id(attorney_id) {
+        Ok(Some(a)) => a,
+        Ok(None) => return json::error_response(&ApiError::NotFound(format!("Attorney {} not found", attorney_id))),
+        Err(e) => return json::error_response(&ApiError::StorageError(e.to_string())),
+    };
+    let Some(mut appointment) = attorney.cja_appointments.iter().find(|a| a.id == appointment_id).cloned() else {
+        return json::error_response(&ApiError::NotFound(format!("CJA appointment {} not found", appointment_id)));
+    };
+
+    if let Err(e) = change(&mut appointment) {
+        return json::error_response(&ApiError::Unprocessable(e));
+    }
+
+    if let Err(e) = repo.update_cja_appointment(attorney_id, appointment.clone()) {
+        return json::error_response(&ApiError::StorageError(e.to_string()));
+    }
+
+    Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&CJAVoucherSummary::new(&attorney, appointment)).unwrap_or_default())
+        .build()
+}
+
+/// Add a line item to a draft CJA voucher
+#[utoipa::path(
+    post,
+    path = "/api/attorneys/{id}/cja-appointments/{appointment_id}/voucher/line-items",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = String, Path, description = "Attorney ID"),
+        ("appointment_id" = String, Path, description = "CJA appointment ID")
+    ),
+    request_body = AddVoucherLineItemRequest,
+    responses(
+        (status = 201, description = "Line item added", body = CJAVoucherSummary),
+        (status = 400, description = "Invalid request data"),
+        (status = 404, description = "Attorney or appointment not found"),
+        (status = 422, description = "Voucher already submitted, or negative hours or expenses", body = crate::error::ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "cja",
+)]
+pub fn add_voucher_line_item(req: Request, params: Params) -> Response {
+    let request: AddVoucherLineItemRequest = match json::parse_body(req.body()) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+    update_voucher(&req, &params, 201, |appointment| appointment.add_line_item(request).map(|_| ()))
+}
+
+/// Submit a draft CJA voucher for court approval
+#[utoipa::path(
+    post,
+    path = "/api/attorneys/{id}/cja-appointments/{appointment_id}/voucher/submit",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = String, Path, description = "Attorney ID"),
+        ("appointment_id" = String, Path, description = "CJA appointment ID")
+    ),
+    responses(
+        (status = 200, description = "Voucher submitted", body = CJAVoucherSummary),
+        (status = 404, description = "Attorney or appointment not found"),
+        (status = 422, description = "Voucher is not a draft or has no line items", body = crate::error::ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "cja",
+)]
+pub fn submit_cja_voucher(req: Request, params: Params) -> Response {
+    update_voucher(&req, &params, 200, |appointment| appointment.submit_voucher(Utc::now()))
+}
+
+/// Approve a submitted CJA voucher in full or at a reduced amount
+#[utoipa::path(
+    post,
+    path = "/api/attorneys/{id}/cja-appointments/{appointment_id}/voucher/approve",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = String, Path, description = "Attorney ID"),
+        ("appointment_id" = String, Path, description = "CJA appointment ID")
+    ),
+    request_body = ApproveVoucherRequest,
+    responses(
+        (status = 200, description = "Voucher approved (CourtApproved) or reduced (Reduced)", body = CJAVoucherSummary),
+        (status = 400, description = "Invalid request data"),
+        (status = 404, description = "Attorney or appointment not found"),
+        (status = 422, description = "Voucher is not submitted, or the amount exceeds the claim", body = crate::error::ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "cja",
+)]
+pub fn approve_cja_voucher(req: Request, params: Params) -> Response {
+    let request: ApproveVoucherRequest = match json::parse_body(req.body()) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+    if request.judge_id.trim().is_empty() {
+        return json::error_response(&ApiError::BadRequest("judge_id is required".to_string()));
+    }
+    update_voucher(&req, &params, 200, |appointment| {
+        appointment.approve_voucher(&request.judge_id, request.approved_amount, Utc::now())
+    })
+}
+
+/// Record payment of an approved or reduced CJA voucher
+#[utoipa::path(
+    post,
+    path = "/api/attorneys/{id}/cja-appointments/{appointment_id}/voucher/pay",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = String, Path, description = "Attorney ID"),
+        ("appointment_id" = String, Path, description = "CJA appointment ID")
+    ),
+    responses(
+        (status = 200, description = "Voucher paid", body = CJAVoucherSummary),
+        (status = 404, description = "Attorney or appointment not found"),
+        (status = 422, description = "Voucher has not been approved", body = crate::error::ErrorResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "cja",
+)]
+pub fn pay_cja_voucher(req: Request, params: Params) -> Response {
+    update_voucher(&req, &params, 200, |appointment| appointment.mark_voucher_paid(Utc::now()))
+}
+
+/// Appoint several CJA panel attorneys to a case in rotation order
+#[utoipa::path(
+    post,
+    path = "/api/cja/bulk-appoint",
+    request_body = BulkCJAAppointmentRequest,
+    responses(
+        (status = 201, description = "Attorneys appointed", body = BulkCJAAppointmentResponse),
+        (status = 400, description = "Invalid request data"),
+        (status = 409, description = "Not enough eligible panel attorneys"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "cja",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+    ),
+)]
+pub fn bulk_appoint_cja(req: Request, _params: Params) -> Response {
+    let repo = match RepositoryFactory::attorney_repo(&req) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let request: BulkCJAAppointmentRequest = match json::parse_body(req.body()) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    if request.count == 0 {
+        return json::error_response(&ApiError::BadRequest(
+            "At least one attorney must be requested".to_string()
+        ));
+    }
+
+    match appoint_in_rotation(&request, &repo) {
+        Ok(result) => Response::builder()
+            .status(201)
+            .header("content-type", "application/json")
+            .body(serde_json::to_vec(&result).unwrap())
+            .build(),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+/// Select eligible panel attorneys in rotation order and record their appointments
+///
+/// Candidates are screened with the same conflict check used by
+/// `/api/attorneys/{attorney_id}/conflict-check`; nothing is persisted unless
+/// enough attorneys qualify.
+fn appoint_in_rotation(
+    request: &BulkCJAAppointmentRequest,
+    repo: &dyn AttorneyRepository,
+) -> Result<BulkCJAAppointmentResponse, ApiError> {
+    let storage = |e: anyhow::Error| ApiError::StorageError(e.to_string());
+    let cap = request.max_open_appointments.unwrap_or(DEFAULT_CJA_OPEN_APPOINTMENT_CAP);
+
+    let panel = repo.find_cja_panel_attorneys(&request.district).map_err(storage)?;
+    let cursor = repo.get_cja_rotation_cursor(&request.district).map_err(storage)?;
+
+    let conflict_request = ConflictCheckRequest::new(
+        request.parties.clone(),
+        request.adverse_parties.clone(),
+        request.case_caption.clone(),
+    )
+    .with_case_id(request.case_id.clone())
+    .with_jurisdiction(request.district.clone());
+
+    let mut selected = Vec::new();
+    let mut skipped = Vec::new();
+
+    for attorney in cja_rotation_order(panel, cursor.as_deref()) {
+        if selected.len() == request.count {
+            break;
+        }
+
+        let name = format!("{} {}", attorney.first_name, attorney.last_name);
+        let mut reason = attorney.cja_ineligibility(&request.district, &request.case_id, cap);
+
+        let has_parties = !conflict_request.parties_to_check.is_empty()
+            || !conflict_request.adverse_parties.is_empty();
+        if reason.is_none() && has_parties {
+            let check = perform_conflict_check(&attorney, &conflict_request, repo)
+                .map_err(|e| ApiError::StorageError(e.to_string()))?;
+            if check.has_conflicts {
+                reason = Some(format!("Conflict of interest ({:?})", check.recommendation));
+            }
+        }
+
+        match reason {
+            Some(reason) => skipped.push(SkippedCJAAttorney {
+                attorney_id: attorney.id.clone(),
+                attorney_name: name,
+                reason,
+            }),
+            None => selected.push((attorney, name)),
+        }
+    }
+
+    if selected.len() < request.count {
+        return Err(ApiError::Conflict(format!(
+            "Only {} of {} requested CJA attorneys are eligible in {}",
+            selected.len(), request.count, request.district
+        )));
+    }
+
+    let mut appointed = Vec::new();
+    for (attorney, name) in selected {
+        let appointment = CJAAppointment {
+            id: uuid::Uuid::new_v4().to_string(),
+            case_id: request.case_id.clone(),
+            case_caption: request.case_caption.clone(),
+            appointment_date: chrono::Utc::now(),
+            appointment_type: request.appointment_type.clone(),
+            compensation_status: CompensationStatus::Pending,
+            hours_claimed: 0.0,
+            amount_approved: None,
+            voucher_status: VoucherStatus::Draft,
+            line_items: Vec::new(),
+            submitted_at: None,
+            approved_by: None,
+            approved_at: None,
+            paid_at: None,
+        };
+
+        repo.add_cja_appointment(&attorney.id, appointment.clone()).map_err(storage)?;
+        repo.set_cja_rotation_cursor(&request.district, &attorney.id).map_err(storage)?;
+
+        appointed.push(CJAAppointee {
+            attorney_id: attorney.id,
+            attorney_name: name,
+            appointment,
+        });
+    }
+
+    Ok(BulkCJAAppointmentResponse {
+        case_id: request.case_id.clone(),
+        appointed,
+        skipped,
+    })
+}
+
 // ECF Registration Endpoints
 
 /// Update ECF registration
@@ -1116,7 +1637,7 @@ pub fn update_ecf_registration(req: Request, params: Params) -> Response {
 /// Check if attorney is in good standing
 #[utoipa::path(
     get,
-    path = "/api/attorneys/{id}/good-standing",
+    path = "/api/attorneys/{id}/is-in-good-standing",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("id" = String, Path, description = "Attorney ID")
@@ -1153,7 +1674,8 @@ pub fn check_good_standing(req: Request, params: Params) -> Response {
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("id" = String, Path, description = "Attorney ID"),
-        ("court" = String, Path, description = "Federal court abbreviation")
+        ("court" = String, Path, description = "Federal court abbreviation"),
+        ("case_id" = Option<String>, Query, description = "Case whose pro hac vice admission also qualifies")
     ),
     responses(
         (status = 200, description = "Practice eligibility", body = bool),
@@ -1173,9 +1695,11 @@ pub fn check_federal_practice(req: Request, params: Params) -> Response {
 
     let id = params.get("id").unwrap_or_default();
     let court = params.get("court").unwrap_or_default();
+    let parsed = query_parser::parse_query_string(req.query());
+    let case_id = query_parser::get_string(&parsed, "case_id");
 
     match repo.find_attorney_by_id(id) {
-        Ok(Some(attorney)) => json::success_response(&attorney.can_practice_federal(court)),
+        Ok(Some(attorney)) => json::success_response(&attorney.can_practice_federal(court, case_id.as_deref())),
         Ok(None) => json::error_response(&ApiError::NotFound(format!("Attorney {} not found", id))),
         Err(e) => json::error_response(&ApiError::StorageError(e.to_string())),
     }
@@ -1277,6 +1801,10 @@ pub fn revoke_ecf_access(req: Request, params: Params) -> Response {
 // Disciplinary Action Endpoints
 
 /// Add disciplinary action
+///
+/// A suspension, interim suspension or disbarment already in effect changes
+/// the attorney's status and ends their active representations; the cases
+/// affected are returned so the clerk can follow up with the parties.
 #[utoipa::path(
     post,
     path = "/api/attorneys/{id}/disciplinary-actions",
@@ -1286,7 +1814,7 @@ pub fn revoke_ecf_access(req: Request, params: Params) -> Response {
     ),
     request_body = DisciplinaryAction,
     responses(
-        (status = 204, description = "Disciplinary action added successfully"),
+        (status = 200, description = "Disciplinary action added", body = DisciplinaryActionResponse),
         (status = 404, description = "Attorney not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -1307,8 +1835,90 @@ pub fn add_disciplinary_action(req: Request, params: Params) -> Response {
         Err(e) => return json::error_response(&e),
     };
 
-    match repo.add_disciplinary_action(id, action) {
-        Ok(_) => Response::builder().status(204).build(),
+    let mut attorney = match repo.find_attorney_by_id(id) {
+        Ok(Some(attorney)) => attorney,
+        Ok(None) => return json::error_response(&ApiError::NotFound(format!("Attorney {} not found", id))),
+        Err(e) => return json::error_response(&ApiError::StorageError(e.to_string())),
+    };
+
+    let restricts_practice = attorney.impose_discipline(action.clone(), Utc::now());
+    let attorney_status = attorney.status.clone();
+    if let Err(e) = repo.update_attorney(attorney) {
+        return json::error_response(&ApiError::StorageError(e.to_string()));
+    }
+
+    let mut affected_cases = Vec::new();
+    if restricts_practice {
+        let representations = match repo.find_active_representations(id) {
+            Ok(reps) => reps,
+            Err(e) => return json::error_response(&ApiError::StorageError(e.to_string())),
+        };
+        for rep in representations {
+            if let Err(e) = repo.end_representation(&rep.id, Some("CourtOrder".to_string())) {
+                return json::error_response(&ApiError::StorageError(e.to_string()));
+            }
+            affected_cases.push(AffectedCase {
+                case_id: rep.case_id,
+                party_id: rep.party_id,
+                representation_id: rep.id,
+            });
+        }
+    }
+
+    json::success_response(&DisciplinaryActionResponse { action, attorney_status, affected_cases })
+}
+
+/// Reinstate a suspended or disbarred attorney
+///
+/// Records the reinstating order in the disciplinary history and restores
+/// the attorney to active status. Representations ended by the suspension
+/// are not restored.
+#[utoipa::path(
+    post,
+    path = "/api/attorneys/{id}/reinstatement",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = String, Path, description = "Attorney ID")
+    ),
+    request_body = ReinstatementRequest,
+    responses(
+        (status = 200, description = "Attorney reinstated", body = Attorney),
+        (status = 400, description = "Missing order number"),
+        (status = 404, description = "Attorney not found"),
+        (status = 409, description = "Attorney is not suspended or disbarred"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "discipline",
+)]
+pub fn reinstate_attorney(req: Request, params: Params) -> Response {
+    let repo = match RepositoryFactory::attorney_repo(&req) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let id = params.get("id").unwrap_or_default();
+    let request: ReinstatementRequest = match json::parse_body(req.body()) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+    if request.order_number.trim().is_empty() {
+        return json::error_response(&ApiError::BadRequest(
+            "Reinstatement requires the reinstating order number".to_string(),
+        ));
+    }
+
+    let mut attorney = match repo.find_attorney_by_id(id) {
+        Ok(Some(attorney)) => attorney,
+        Ok(None) => return json::error_response(&ApiError::NotFound(format!("Attorney {} not found", id))),
+        Err(e) => return json::error_response(&ApiError::StorageError(e.to_string())),
+    };
+
+    if let Err(e) = attorney.reinstate(request, Utc::now()) {
+        return json::error_response(&ApiError::Conflict(e));
+    }
+
+    match repo.update_attorney(attorney) {
+        Ok(attorney) => json::success_response(&attorney),
         Err(e) => json::error_response(&ApiError::StorageError(e.to_string())),
     }
 }
@@ -1628,9 +2238,9 @@ pub fn update_party_status(req: Request, params: Params) -> Response {
     let parsed = query_parser::parse_query_string(query);
     let status_str = query_parser::get_string(&parsed, "status").unwrap_or_default();
 
-    let status: PartyStatus = match serde_json::from_str(&format!("\"{}\"", status_str)) {
+    let status: PartyStatus = match query_parser::parse_enum_param("status", &status_str) {
         Ok(s) => s,
-        Err(e) => return json::error_response(&ApiError::BadRequest(e.to_string())),
+        Err(e) => return json::error_response(&e),
     };
 
     match repo.update_party_status(id, status) {
@@ -1973,6 +2583,38 @@ pub fn substitute_attorney(req: Request, _params: Params) -> Response {
     }
 }
 
+/// Get representation continuity for a case
+///
+/// Counts attorney substitutions and withdrawals over the case's representation
+/// history and scores continuity from 100 (no changes) downward, flagging cases
+/// with excessive attorney churn.
+#[utoipa::path(
+    get,
+    path = "/api/cases/{case_id}/continuity",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("case_id" = String, Path, description = "Case ID")
+    ),
+    responses(
+        (status = 200, description = "Representation continuity", body = RepresentationContinuity),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "representation",
+)]
+pub fn get_case_continuity(req: Request, params: Params) -> Response {
+    let repo = match RepositoryFactory::attorney_repo(&req) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let case_id = params.get("case_id").unwrap_or_default();
+
+    match repo.find_representations_by_case(case_id) {
+        Ok(reps) => json::success_response(&RepresentationContinuity::from_representations(case_id, &reps)),
+        Err(e) => json::error_response(&ApiError::StorageError(e.to_string())),
+    }
+}
+
 // Service Records Endpoints
 
 /// Create service record
@@ -2172,17 +2814,29 @@ pub fn get_attorney_conflicts(req: Request, params: Params) -> Response {
     }
 }
 
+/// A conflict check naming one of the parties, with how its names matched
+#[derive(Debug, serde::Serialize, utoipa::ToSchema)]
+pub struct PartyConflictMatch {
+    #[serde(flatten)]
+    pub check: ConflictCheck,
+    pub matches: Vec<NameMatch>,
+}
+
 /// Check conflicts for parties
+///
+/// Party names match after normalization and, above `threshold`, by
+/// similarity; each match says whether it was exact, normalized or fuzzy.
 #[utoipa::path(
     post,
     path = "/api/conflict-checks/check",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("attorney_id" = String, Query, description = "Attorney ID")
+        ("attorney_id" = String, Query, description = "Attorney ID"),
+        ("threshold" = Option<f64>, Query, description = "Similarity from 0 to 1 a fuzzy match needs (default 0.9)")
     ),
     request_body = Vec<String>,
     responses(
-        (status = 200, description = "Conflict check results", body = Vec<ConflictCheck>),
+        (status = 200, description = "Conflict check results", body = Vec<PartyConflictMatch>),
         (status = 400, description = "Invalid request"),
         (status = 500, description = "Internal server error")
     ),
@@ -2200,14 +2854,24 @@ pub fn check_party_conflicts(req: Request, _params: Params) -> Response {
     let query = req.query();
     let parsed = query_parser::parse_query_string(query);
     let attorney_id = query_parser::get_string(&parsed, "attorney_id").unwrap_or_default();
+    let threshold = match name_match::threshold_param(&parsed) {
+        Ok(t) => t,
+        Err(e) => return json::error_response(&e),
+    };
 
     let party_names: Vec<String> = match json::parse_body(req.body()) {
         Ok(names) => names,
         Err(e) => return json::error_response(&e),
     };
 
-    match repo.find_conflicts_for_parties(&attorney_id, party_names) {
-        Ok(conflicts) => json::success_response(&conflicts),
+    match repo.find_conflicts_for_parties(&attorney_id, party_names, threshold) {
+        Ok(conflicts) => {
+            let conflicts: Vec<PartyConflictMatch> = conflicts
+                .into_iter()
+                .map(|(check, matches)| PartyConflictMatch { check, matches })
+                .collect();
+            json::success_response(&conflicts)
+        }
         Err(e) => json::error_response(&ApiError::StorageError(e.to_string())),
     }
 }
@@ -2251,6 +2915,61 @@ pub fn clear_conflict(req: Request, params: Params) -> Response {
     }
 }
 
+/// Get conflict-check turnaround
+#[utoipa::path(
+    get,
+    path = "/api/conflict-checks/turnaround",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("start" = Option<String>, Query, description = "Only include checks opened on or after this date (YYYY-MM-DD)"),
+        ("end" = Option<String>, Query, description = "Only include checks opened on or before this date (YYYY-MM-DD)")
+    ),
+    responses(
+        (status = 200, description = "Average clearance turnaround and outstanding count", body = ConflictCheckTurnaround),
+        (status = 400, description = "Invalid date"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "conflicts",
+)]
+pub fn get_conflict_turnaround(req: Request, _params: Params) -> Response {
+    let repo = match RepositoryFactory::attorney_repo(&req) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let query = req.query();
+    let parsed = query_parser::parse_query_string(query);
+
+    let start = match parse_day_bound(&parsed, "start", NaiveTime::MIN) {
+        Ok(d) => d,
+        Err(e) => return json::error_response(&e),
+    };
+    let end = match parse_day_bound(&parsed, "end", NaiveTime::from_hms_opt(23, 59, 59).unwrap()) {
+        Ok(d) => d,
+        Err(e) => return json::error_response(&e),
+    };
+
+    match repo.find_all_conflict_checks() {
+        Ok(checks) => json::success_response(&ConflictCheckTurnaround::from_checks(&checks, start, end)),
+        Err(e) => json::error_response(&ApiError::StorageError(e.to_string())),
+    }
+}
+
+/// Parse an optional YYYY-MM-DD query parameter as a UTC timestamp at `time` on that day
+fn parse_day_bound(
+    parsed: &[(&str, &str)],
+    name: &str,
+    time: NaiveTime,
+) -> Result<Option<DateTime<Utc>>, ApiError> {
+    query_parser::get_string(parsed, name)
+        .map(|value| {
+            NaiveDate::parse_from_str(&value, "%Y-%m-%d")
+                .map(|date| date.and_time(time).and_utc())
+                .map_err(|_| ApiError::BadRequest(format!("Invalid {} date: {}", name, value)))
+        })
+        .transpose()
+}
+
 // Metrics Endpoints
 
 /// Get attorney metrics
@@ -2422,9 +3141,9 @@ pub fn bulk_update_status(req: Request, _params: Params) -> Response {
     let parsed = query_parser::parse_query_string(query);
     let status_str = query_parser::get_string(&parsed, "status").unwrap_or_default();
 
-    let status: AttorneyStatus = match serde_json::from_str(&format!("\"{}\"", status_str)) {
+    let status: AttorneyStatus = match query_parser::parse_enum_param("status", &status_str) {
         Ok(s) => s,
-        Err(e) => return json::error_response(&ApiError::BadRequest(e.to_string())),
+        Err(e) => return json::error_response(&e),
     };
 
     let attorney_ids: Vec<String> = match json::parse_body(req.body()) {
@@ -2776,8 +3495,7 @@ pub fn get_attorney_representation_history(req: Request, params: Params) -> Resp
         start_date: get_param("start_date"),
         end_date: get_param("end_date"),
         status: get_param("status"),
-        role: get_param("role")
-            .and_then(|r| serde_json::from_str(&format!("\"{}\"", r)).ok()),
+        role: query_parser::get_enum(&parsed_params, "role"),
         active_only: get_param("active_only")
             .and_then(|v| v.parse().ok()),
         page: get_param("page")
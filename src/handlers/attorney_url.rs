@@ -78,6 +78,20 @@ pub fn add_pro_hac_vice(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn renew_pro_hac_vice(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::attorney::renew_pro_hac_vice(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+pub fn get_expiring_pro_hac_vice(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::attorney::get_expiring_pro_hac_vice(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn add_representation(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::attorney::add_representation(req, params),
@@ -99,6 +113,13 @@ pub fn bulk_add_to_service(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn bulk_appoint_cja(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::attorney::bulk_appoint_cja(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn bulk_update_status(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::attorney::bulk_update_status(req, params),
@@ -162,6 +183,13 @@ pub fn clear_conflict(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_conflict_turnaround(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::attorney::get_conflict_turnaround(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn create_attorney(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::attorney::create_attorney(req, params),
@@ -316,6 +344,13 @@ pub fn get_case_representations(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_case_continuity(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::attorney::get_case_continuity(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn get_cja_appointments(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::attorney::get_cja_appointments(req, params),
@@ -358,6 +393,34 @@ pub fn get_pending_cja_vouchers(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn add_voucher_line_item(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::attorney::add_voucher_line_item(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+pub fn submit_cja_voucher(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::attorney::submit_cja_voucher(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+pub fn approve_cja_voucher(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::attorney::approve_cja_voucher(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+pub fn pay_cja_voucher(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::attorney::pay_cja_voucher(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn get_pro_hac_vice_by_case(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::attorney::get_pro_hac_vice_by_case(req, params),
@@ -435,6 +498,13 @@ pub fn migrate_representations(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn reinstate_attorney(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::attorney::reinstate_attorney(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn remove_bar_admission(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::attorney::remove_bar_admission(req, params),
@@ -484,6 +554,13 @@ pub fn update_attorney(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn patch_attorney(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::attorney::patch_attorney(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn update_ecf_registration(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::attorney::update_ecf_registration(req, params),
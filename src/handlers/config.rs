@@ -3,10 +3,13 @@
 //! Provides endpoints for retrieving and managing hierarchical configurations
 //! with support for district and judge-level overrides.
 
+use crate::domain::case_number::CaseNumberFormat;
+use crate::domain::config::{ConfigOverrideVersion, CourtClosure};
 use crate::error::ApiError;
 use crate::ports::config_repository::ConfigRepository;
 use crate::services::config_service::ConfigService;
-use crate::utils::{json_response as json, repository_factory::RepositoryFactory};
+use crate::utils::{json_response as json, query_parser, repository_factory::RepositoryFactory};
+use chrono::NaiveDate;
 use serde_json::Value;
 use spin_sdk::http::{Params, Request, Response};
 use std::collections::HashMap;
@@ -330,6 +333,103 @@ pub fn clear_district_overrides(req: Request, _params: Params) -> Response {
     }
 }
 
+/// List prior versions of the district overrides, newest first
+///
+/// Every update, clear and rollback adds a version; the first entry is the
+/// overrides currently in effect.
+#[utoipa::path(
+    get,
+    path = "/api/config/overrides/district/history",
+    responses(
+        (status = 200, description = "Override versions, newest first", body = Vec<ConfigOverrideVersion>),
+        (status = 400, description = "Invalid request"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "configuration",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+)]
+pub fn get_district_override_history(req: Request, _params: Params) -> Response {
+    let district_id = match req.header("x-court-district") {
+        Some(header) => match header.as_str() {
+            Some(value) if !value.is_empty() => value,
+            _ => return json::error_response(&ApiError::BadRequest(
+                "X-Court-District header is required".to_string()
+            )),
+        },
+        None => return json::error_response(&ApiError::BadRequest(
+            "X-Court-District header is required".to_string()
+        )),
+    };
+
+    let repo = match RepositoryFactory::config_repo(&req) {
+        Ok(r) => Arc::new(r) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&e),
+    };
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.district_override_history(district_id)) {
+        Ok(history) => json::success_response(&history),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+/// Restore a prior version of the district overrides
+///
+/// The rollback is recorded as a new version, so it can be undone too.
+#[utoipa::path(
+    post,
+    path = "/api/config/overrides/district/rollback/{version}",
+    responses(
+        (status = 200, description = "Version restored; returns the version the rollback recorded", body = ConfigOverrideVersion),
+        (status = 400, description = "Invalid version"),
+        (status = 404, description = "No such version"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "configuration",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY"),
+        ("version" = u32, Path, description = "Version to restore")
+    ),
+)]
+pub fn rollback_district_overrides(req: Request, params: Params) -> Response {
+    let district_id = match req.header("x-court-district") {
+        Some(header) => match header.as_str() {
+            Some(value) if !value.is_empty() => value,
+            _ => return json::error_response(&ApiError::BadRequest(
+                "X-Court-District header is required".to_string()
+            )),
+        },
+        None => return json::error_response(&ApiError::BadRequest(
+            "X-Court-District header is required".to_string()
+        )),
+    };
+
+    let version = match version_param(&params) {
+        Ok(v) => v,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let repo = match RepositoryFactory::config_repo(&req) {
+        Ok(r) => Arc::new(r) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&e),
+    };
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.rollback_district_overrides(district_id, version)) {
+        Ok(restored) => json::success_response(&restored),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+/// Parse the `version` path parameter of a rollback
+pub(crate) fn version_param(params: &Params) -> Result<u32, ApiError> {
+    let raw = params.get("version").unwrap_or_default();
+    raw.parse::<u32>()
+        .map_err(|_| ApiError::BadRequest(format!("Invalid version '{}', expected a positive whole number", raw)))
+}
+
 /// Clear judge overrides (revert to district configuration)
 #[utoipa::path(
     delete,
@@ -386,19 +486,23 @@ pub fn clear_judge_overrides(req: Request, _params: Params) -> Response {
 }
 
 /// Preview configuration with proposed changes
+///
+/// `?mode=diff` returns only the keys that would change, each with its
+/// current and previewed value. A proposed null removes the key.
 #[utoipa::path(
     post,
     path = "/api/config/preview",
     request_body = HashMap<String, Value>,
     responses(
-        (status = 200, description = "Configuration preview generated successfully"),
-        (status = 400, description = "Invalid configuration values"),
+        (status = 200, description = "Configuration preview generated successfully, or with mode=diff a ConfigDiff of the keys that would change"),
+        (status = 400, description = "Invalid configuration values or mode"),
         (status = 500, description = "Internal server error")
     ),
     tag = "configuration",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY"),
-        ("X-Judge-ID" = String, Header, description = "Optional judge identifier", example = "judge-123")
+        ("X-Judge-ID" = String, Header, description = "Optional judge identifier", example = "judge-123"),
+        ("mode" = Option<String>, Query, description = "full (default) or diff")
     ),
 )]
 pub fn preview_config(req: Request, _params: Params) -> Response {
@@ -418,6 +522,11 @@ pub fn preview_config(req: Request, _params: Params) -> Response {
         .and_then(|h| h.as_str())
         .filter(|s| !s.is_empty());
 
+    let diff = match preview_diff_mode(&req) {
+        Ok(diff) => diff,
+        Err(e) => return json::error_response(&e),
+    };
+
     // Parse request body
     let changes: HashMap<String, Value> = match json::parse_body(req.body()) {
         Ok(c) => c,
@@ -431,14 +540,270 @@ pub fn preview_config(req: Request, _params: Params) -> Response {
     let service = ConfigService::new(repo);
 
     // Validate proposed changes
-    if let Err(e) = service.validate_updates(&changes) {
+    if let Err(e) = service.validate_preview(&changes) {
         return json::error_response(&e);
     }
 
+    if diff {
+        return match futures::executor::block_on(service.preview_config_diff(district_id, judge_id, changes)) {
+            Ok(config_diff) => json::success_response(&config_diff),
+            Err(e) => json::error_response(&e),
+        };
+    }
+
     let result = futures::executor::block_on(service.preview_config_changes(district_id, judge_id, changes));
 
     match result {
         Ok(config_response) => json::success_response(&config_response),
         Err(e) => json::error_response(&e),
     }
-}
\ No newline at end of file
+}
+
+/// Whether a preview asks for `mode=diff` rather than the full configuration
+pub(crate) fn preview_diff_mode(req: &Request) -> Result<bool, ApiError> {
+    let query = query_parser::parse_query_string(req.query());
+    match query_parser::get_string(&query, "mode").as_deref() {
+        None | Some("full") => Ok(false),
+        Some("diff") => Ok(true),
+        Some(other) => Err(ApiError::BadRequest(format!(
+            "Invalid preview mode '{}', expected full or diff",
+            other
+        ))),
+    }
+}
+
+/// Parse the `date` query parameter of a closure deletion
+pub(crate) fn closure_date_param(req: &Request) -> Result<NaiveDate, ApiError> {
+    let query = query_parser::parse_query_string(req.query());
+    let date = query_parser::get_string(&query, "date")
+        .ok_or_else(|| ApiError::BadRequest("date query parameter is required".to_string()))?;
+    NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+        .map_err(|_| ApiError::BadRequest(format!("Invalid date '{}', expected YYYY-MM-DD", date)))
+}
+
+/// List the district's special court closure dates
+#[utoipa::path(
+    get,
+    path = "/api/config/closures",
+    responses(
+        (status = 200, description = "Closure dates, sorted by date", body = Vec<CourtClosure>),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "configuration",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+)]
+pub fn list_closures(req: Request, _params: Params) -> Response {
+    let district_id = match req.header("x-court-district") {
+        Some(header) => match header.as_str() {
+            Some(value) if !value.is_empty() => value,
+            _ => return json::error_response(&ApiError::BadRequest(
+                "X-Court-District header is required".to_string()
+            )),
+        },
+        None => return json::error_response(&ApiError::BadRequest(
+            "X-Court-District header is required".to_string()
+        )),
+    };
+
+    let repo = match RepositoryFactory::config_repo(&req) {
+        Ok(r) => Arc::new(r) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&e),
+    };
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.list_closures(district_id)) {
+        Ok(closures) => json::success_response(&closures),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+/// Add a special court closure date
+///
+/// Deadlines, notice periods and calendar slot searches treat the date like
+/// a federal holiday.
+#[utoipa::path(
+    post,
+    path = "/api/config/closures",
+    request_body = CourtClosure,
+    responses(
+        (status = 201, description = "Closure added; returns the updated list", body = Vec<CourtClosure>),
+        (status = 400, description = "Invalid closure"),
+        (status = 409, description = "Date is already a closure"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "configuration",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+)]
+pub fn add_closure(req: Request, _params: Params) -> Response {
+    let district_id = match req.header("x-court-district") {
+        Some(header) => match header.as_str() {
+            Some(value) if !value.is_empty() => value,
+            _ => return json::error_response(&ApiError::BadRequest(
+                "X-Court-District header is required".to_string()
+            )),
+        },
+        None => return json::error_response(&ApiError::BadRequest(
+            "X-Court-District header is required".to_string()
+        )),
+    };
+
+    let closure: CourtClosure = match json::parse_body(req.body()) {
+        Ok(c) => c,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let repo = match RepositoryFactory::config_repo(&req) {
+        Ok(r) => Arc::new(r) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&e),
+    };
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.add_closure(district_id, closure)) {
+        Ok(closures) => Response::builder()
+            .status(201)
+            .header("content-type", "application/json")
+            .body(serde_json::to_vec(&closures).unwrap_or_default())
+            .build(),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+/// Remove a special court closure date
+#[utoipa::path(
+    delete,
+    path = "/api/config/closures",
+    responses(
+        (status = 204, description = "Closure removed"),
+        (status = 400, description = "Missing or invalid date"),
+        (status = 404, description = "No closure on that date"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "configuration",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY"),
+        ("date" = String, Query, description = "Closure date to remove (YYYY-MM-DD)")
+    ),
+)]
+pub fn delete_closure(req: Request, _params: Params) -> Response {
+    let district_id = match req.header("x-court-district") {
+        Some(header) => match header.as_str() {
+            Some(value) if !value.is_empty() => value,
+            _ => return json::error_response(&ApiError::BadRequest(
+                "X-Court-District header is required".to_string()
+            )),
+        },
+        None => return json::error_response(&ApiError::BadRequest(
+            "X-Court-District header is required".to_string()
+        )),
+    };
+
+    let date = match closure_date_param(&req) {
+        Ok(d) => d,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let repo = match RepositoryFactory::config_repo(&req) {
+        Ok(r) => Arc::new(r) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&e),
+    };
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.remove_closure(district_id, date)) {
+        Ok(()) => Response::builder().status(204).build(),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+/// Get the district's case number format
+///
+/// Cases created without a case number are numbered `{YY}-{type}-{sequence}`
+/// using this padding and these case type abbreviations.
+#[utoipa::path(
+    get,
+    path = "/api/config/case-number-format",
+    responses(
+        (status = 200, description = "Case number format", body = CaseNumberFormat),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "configuration",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+)]
+pub fn get_case_number_format(req: Request, _params: Params) -> Response {
+    let district_id = match req.header("x-court-district") {
+        Some(header) => match header.as_str() {
+            Some(value) if !value.is_empty() => value,
+            _ => return json::error_response(&ApiError::BadRequest(
+                "X-Court-District header is required".to_string()
+            )),
+        },
+        None => return json::error_response(&ApiError::BadRequest(
+            "X-Court-District header is required".to_string()
+        )),
+    };
+
+    let repo = match RepositoryFactory::config_repo(&req) {
+        Ok(r) => Arc::new(r) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&e),
+    };
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.get_case_number_format(district_id)) {
+        Ok(format) => json::success_response(&format),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+/// Override the district's case number format
+///
+/// Saved as district configuration overrides, so clearing the district's
+/// overrides restores the default format. Existing case numbers and
+/// sequence counters are unchanged.
+#[utoipa::path(
+    put,
+    path = "/api/config/case-number-format",
+    request_body = CaseNumberFormat,
+    responses(
+        (status = 200, description = "Updated case number format", body = CaseNumberFormat),
+        (status = 400, description = "Invalid padding or abbreviation"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "configuration",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+)]
+pub fn update_case_number_format(req: Request, _params: Params) -> Response {
+    let district_id = match req.header("x-court-district") {
+        Some(header) => match header.as_str() {
+            Some(value) if !value.is_empty() => value,
+            _ => return json::error_response(&ApiError::BadRequest(
+                "X-Court-District header is required".to_string()
+            )),
+        },
+        None => return json::error_response(&ApiError::BadRequest(
+            "X-Court-District header is required".to_string()
+        )),
+    };
+
+    let format: CaseNumberFormat = match json::parse_body(req.body()) {
+        Ok(f) => f,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let repo = match RepositoryFactory::config_repo(&req) {
+        Ok(r) => Arc::new(r) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&e),
+    };
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.update_case_number_format(district_id, format)) {
+        Ok(format) => json::success_response(&format),
+        Err(e) => json::error_response(&e),
+    }
+}
@@ -3,6 +3,8 @@
 //! These handlers extract tenant information from the URL path instead of headers,
 //! supporting RESTful API design patterns like `/api/courts/sdny/config`
 
+use crate::domain::case_number::CaseNumberFormat;
+use crate::domain::config::CourtClosure;
 use crate::error::ApiError;
 use crate::ports::config_repository::ConfigRepository;
 use crate::services::config_service::ConfigService;
@@ -81,6 +83,20 @@ pub fn get_district_overrides(req: Request, params: Params) -> Response {
 /// Get judge-level configuration overrides from URL
 ///
 /// URL pattern: `/api/courts/{district}/config/overrides/judge/{judge_id}`
+#[utoipa::path(
+    get,
+    path = "/api/courts/{district}/config/overrides/judge/{judge_id}",
+    responses(
+        (status = 200, description = "Judge overrides retrieved successfully"),
+        (status = 404, description = "No judge overrides found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "configuration",
+    params(
+        ("district" = String, Path, description = "Federal court district", example = "sdny"),
+        ("judge_id" = String, Path, description = "Judge identifier", example = "judge-123")
+    ),
+)]
 pub fn get_judge_overrides(req: Request, params: Params) -> Response {
     let district_id = match params.get("district") {
         Some(district) if !district.is_empty() => district,
@@ -154,6 +170,21 @@ pub fn update_district_config(req: Request, params: Params) -> Response {
 /// Update judge configuration from URL
 ///
 /// URL pattern: `PUT /api/courts/{district}/config/overrides/judge/{judge_id}`
+#[utoipa::path(
+    put,
+    path = "/api/courts/{district}/config/overrides/judge/{judge_id}",
+    request_body = HashMap<String, Value>,
+    responses(
+        (status = 200, description = "Judge overrides updated successfully"),
+        (status = 400, description = "Invalid configuration values"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "configuration",
+    params(
+        ("district" = String, Path, description = "Federal court district", example = "sdny"),
+        ("judge_id" = String, Path, description = "Judge identifier", example = "judge-123")
+    ),
+)]
 pub fn update_judge_config(req: Request, params: Params) -> Response {
     let district_id = match params.get("district") {
         Some(district) if !district.is_empty() => district,
@@ -225,9 +256,75 @@ pub fn clear_district_overrides(req: Request, params: Params) -> Response {
     }
 }
 
+/// List prior versions of the district overrides from URL, newest first
+///
+/// URL pattern: `GET /api/courts/{district}/config/overrides/district/history`
+pub fn get_district_override_history(req: Request, params: Params) -> Response {
+    let district_id = match params.get("district") {
+        Some(district) if !district.is_empty() => district,
+        _ => return json::error_response(&ApiError::BadRequest(
+            "District parameter is required in URL".to_string()
+        )),
+    };
+
+    let repo = match RepositoryFactory::config_repo_from_url(&req) {
+        Ok(repo) => Arc::new(repo) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&ApiError::BadRequest(e)),
+    };
+
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.district_override_history(district_id)) {
+        Ok(history) => json::success_response(&history),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+/// Restore a prior version of the district overrides from URL
+///
+/// URL pattern: `POST /api/courts/{district}/config/overrides/district/rollback/{version}`
+pub fn rollback_district_overrides(req: Request, params: Params) -> Response {
+    let district_id = match params.get("district") {
+        Some(district) if !district.is_empty() => district,
+        _ => return json::error_response(&ApiError::BadRequest(
+            "District parameter is required in URL".to_string()
+        )),
+    };
+
+    let version = match crate::handlers::config::version_param(&params) {
+        Ok(v) => v,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let repo = match RepositoryFactory::config_repo_from_url(&req) {
+        Ok(repo) => Arc::new(repo) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&ApiError::BadRequest(e)),
+    };
+
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.rollback_district_overrides(district_id, version)) {
+        Ok(restored) => json::success_response(&restored),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 /// Clear judge overrides from URL
 ///
 /// URL pattern: `DELETE /api/courts/{district}/config/overrides/judge/{judge_id}`
+#[utoipa::path(
+    delete,
+    path = "/api/courts/{district}/config/overrides/judge/{judge_id}",
+    responses(
+        (status = 200, description = "Judge overrides cleared successfully"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "configuration",
+    params(
+        ("district" = String, Path, description = "Federal court district", example = "sdny"),
+        ("judge_id" = String, Path, description = "Judge identifier", example = "judge-123")
+    ),
+)]
 pub fn clear_judge_overrides(req: Request, params: Params) -> Response {
     let district_id = match params.get("district") {
         Some(district) if !district.is_empty() => district,
@@ -264,7 +361,8 @@ pub fn clear_judge_overrides(req: Request, params: Params) -> Response {
 
 /// Preview configuration with temporary overrides from URL
 ///
-/// URL pattern: `POST /api/courts/{district}/config/preview`
+/// URL pattern: `POST /api/courts/{district}/config/preview`, with
+/// `?mode=diff` for only the keys that would change
 pub fn preview_config(req: Request, params: Params) -> Response {
     let district_id = match params.get("district") {
         Some(district) if !district.is_empty() => district,
@@ -280,6 +378,11 @@ pub fn preview_config(req: Request, params: Params) -> Response {
         overrides: HashMap<String, Value>,
     }
 
+    let diff = match crate::handlers::config::preview_diff_mode(&req) {
+        Ok(diff) => diff,
+        Err(e) => return json::error_response(&e),
+    };
+
     let preview_req: PreviewRequest = match serde_json::from_slice(&req.body()) {
         Ok(p) => p,
         Err(e) => return json::error_response(&ApiError::BadRequest(
@@ -294,6 +397,15 @@ pub fn preview_config(req: Request, params: Params) -> Response {
 
     let service = ConfigService::new(repo);
 
+    if diff {
+        return match futures::executor::block_on(
+            service.preview_config_diff(district_id, preview_req.judge_id.as_deref(), preview_req.overrides)
+        ) {
+            Ok(config_diff) => json::success_response(&config_diff),
+            Err(e) => json::error_response(&e),
+        };
+    }
+
     let result = futures::executor::block_on(
         service.preview_config_changes(district_id, preview_req.judge_id.as_deref(), preview_req.overrides)
     );
@@ -302,4 +414,143 @@ pub fn preview_config(req: Request, params: Params) -> Response {
         Ok(preview) => json::success_response(&preview),
         Err(e) => json::error_response(&e),
     }
-}
\ No newline at end of file
+}
+
+/// List special court closure dates from URL
+///
+/// URL pattern: `/api/courts/{district}/config/closures`
+pub fn list_closures(req: Request, params: Params) -> Response {
+    let district_id = match params.get("district") {
+        Some(district) if !district.is_empty() => district,
+        _ => return json::error_response(&ApiError::BadRequest(
+            "District parameter is required in URL".to_string()
+        )),
+    };
+
+    let repo = match RepositoryFactory::config_repo_from_url(&req) {
+        Ok(repo) => Arc::new(repo) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&ApiError::BadRequest(e)),
+    };
+
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.list_closures(district_id)) {
+        Ok(closures) => json::success_response(&closures),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+/// Add a special court closure date from URL
+///
+/// URL pattern: `/api/courts/{district}/config/closures`
+pub fn add_closure(req: Request, params: Params) -> Response {
+    let district_id = match params.get("district") {
+        Some(district) if !district.is_empty() => district,
+        _ => return json::error_response(&ApiError::BadRequest(
+            "District parameter is required in URL".to_string()
+        )),
+    };
+
+    let closure: CourtClosure = match json::parse_body(req.body()) {
+        Ok(c) => c,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let repo = match RepositoryFactory::config_repo_from_url(&req) {
+        Ok(repo) => Arc::new(repo) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&ApiError::BadRequest(e)),
+    };
+
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.add_closure(district_id, closure)) {
+        Ok(closures) => Response::builder()
+            .status(201)
+            .header("content-type", "application/json")
+            .body(serde_json::to_vec(&closures).unwrap_or_default())
+            .build(),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+/// Remove a special court closure date from URL
+///
+/// URL pattern: `/api/courts/{district}/config/closures?date=YYYY-MM-DD`
+pub fn delete_closure(req: Request, params: Params) -> Response {
+    let district_id = match params.get("district") {
+        Some(district) if !district.is_empty() => district,
+        _ => return json::error_response(&ApiError::BadRequest(
+            "District parameter is required in URL".to_string()
+        )),
+    };
+
+    let date = match crate::handlers::config::closure_date_param(&req) {
+        Ok(d) => d,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let repo = match RepositoryFactory::config_repo_from_url(&req) {
+        Ok(repo) => Arc::new(repo) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&ApiError::BadRequest(e)),
+    };
+
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.remove_closure(district_id, date)) {
+        Ok(()) => Response::builder().status(204).build(),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+/// Get the district's case number format from URL
+///
+/// URL pattern: `/api/courts/{district}/config/case-number-format`
+pub fn get_case_number_format(req: Request, params: Params) -> Response {
+    let district_id = match params.get("district") {
+        Some(district) if !district.is_empty() => district,
+        _ => return json::error_response(&ApiError::BadRequest(
+            "District parameter is required in URL".to_string()
+        )),
+    };
+
+    let repo = match RepositoryFactory::config_repo_from_url(&req) {
+        Ok(repo) => Arc::new(repo) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&ApiError::BadRequest(e)),
+    };
+
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.get_case_number_format(district_id)) {
+        Ok(format) => json::success_response(&format),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+/// Override the district's case number format from URL
+///
+/// URL pattern: `/api/courts/{district}/config/case-number-format`
+pub fn update_case_number_format(req: Request, params: Params) -> Response {
+    let district_id = match params.get("district") {
+        Some(district) if !district.is_empty() => district,
+        _ => return json::error_response(&ApiError::BadRequest(
+            "District parameter is required in URL".to_string()
+        )),
+    };
+
+    let format: CaseNumberFormat = match json::parse_body(req.body()) {
+        Ok(f) => f,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let repo = match RepositoryFactory::config_repo_from_url(&req) {
+        Ok(repo) => Arc::new(repo) as Arc<dyn ConfigRepository>,
+        Err(e) => return json::error_response(&ApiError::BadRequest(e)),
+    };
+
+    let service = ConfigService::new(repo);
+
+    match futures::executor::block_on(service.update_case_number_format(district_id, format)) {
+        Ok(format) => json::success_response(&format),
+        Err(e) => json::error_response(&e),
+    }
+}
@@ -4,19 +4,32 @@
 //! for all data access, keeping the handlers decoupled from storage implementation.
 
 use crate::adapters::rules_engine_impl::SpinRulesEngine;
-use crate::domain::criminal_case::{CaseStatus, CasePriority, CrimeType, CriminalCase, EventType, MotionType, EvidenceType, EvidenceCondition};
+use crate::domain::appeal::{Appeal, AppealFilingParty, AppealStatus, NoticeOfAppeal};
+use crate::domain::case_archive::CaseArchive;
+use crate::domain::attorney::{Representation, RepresentationStatus};
+use crate::domain::case_number::{CaseNumberFormat, DEFAULT_CASE_TYPE};
+use crate::domain::criminal_case::{CaseStatus, CasePriority, CrimeType, CriminalCase, EventType, MotionType, EvidenceType, EvidenceCondition, LimitationsAtRisk, LimitationsTracker};
 use crate::domain::common::MotionStatus;
-use crate::domain::defendant::{CreateDefendantRequest, PleaType, AddCountRequest};
+use crate::domain::delay_attribution::DelayAttribution;
+use crate::domain::defendant::{BondInfo, CreateDefendantRequest, PleaType, AddCountRequest, CustodyReportEntry, CustodyStatus, Defendant, UpdateCustodyRequest};
 use crate::domain::docket::{DocketEntryType, DelayReason};
 use crate::domain::filing_pipeline::{ComplianceReport, FilingContext};
+use crate::domain::magistrate_consent::{ConsentStatus, ConsentSummary};
 use crate::domain::rule::TriggerEvent;
 use crate::domain::victim::{CreateVictimRequest, SendNotificationRequest, VictimType, NotificationMethod, NotificationType};
+use crate::domain::webhook::WebhookEventType;
 use crate::error::{ApiError, ApiResult};
-use crate::ports::case_repository::{CaseRepository, CaseQuery, CaseQueryRepository};
+use crate::ports::attorney_repository::AttorneyRepository;
+use crate::ports::case_repository::{CaseArchiveRepository, CaseRepository, CaseQuery, CaseQueryRepository};
+use crate::ports::config_repository::ConfigRepository;
+use crate::ports::deadline_repository::DeadlineRepository;
+use crate::ports::docket_repository::{CalendarRepository, SpeedyTrialRepository};
 use crate::ports::rules_engine::RulesEngine;
 use crate::ports::rules_repository::RulesRepository;
-use crate::utils::repository_factory::RepositoryFactory;
-use chrono::{DateTime, Utc};
+use crate::services::case_number_service::{self, CaseNumberGenerator};
+use crate::services::{appeal_service, case_archive_service, limitations_service, webhook_service};
+use crate::utils::{query_parser, repository_factory::RepositoryFactory};
+use chrono::{DateTime, Datelike, Utc};
 use serde::{Deserialize, Serialize};
 use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
 use uuid::Uuid;
@@ -30,8 +43,10 @@ use utoipa::ToSchema;
     "crimeType": "cybercrime",
     "districtCode": "SDNY",
     "assignedJudgeId": "550e8400-e29b-41d4-a716-446655440000",
-    "judgeInitials": "JMS",
-    "location": "New York, NY"
+    "caseType": "criminal",
+    "location": "New York, NY",
+    "offenseDate": "2024-03-15T00:00:00Z",
+    "offenseStatute": "18 U.S.C. § 1030"
 }))]
 pub struct CreateCaseRequest {
     pub title: String,
@@ -42,13 +57,20 @@ pub struct CreateCaseRequest {
     pub district_code: String,
     #[serde(rename = "assignedJudgeId")]
     pub assigned_judge_id: Option<Uuid>,
-    #[serde(rename = "judgeInitials", default = "default_judge_initials")]
-    pub judge_initials: String,
+    /// Case number to use instead of generating the next in sequence, in
+    /// any casing and padding (e.g. `24-CR-1`)
+    #[serde(rename = "caseNumber", default)]
+    pub case_number: Option<String>,
+    /// Case type to number the case under (defaults to `criminal`)
+    #[serde(rename = "caseType", default)]
+    pub case_type: Option<String>,
     pub location: String,
-}
-
-fn default_judge_initials() -> String {
-    "UNK".to_string()
+    /// When the offense was committed; starts the limitations clock
+    #[serde(rename = "offenseDate", default)]
+    pub offense_date: Option<DateTime<Utc>>,
+    /// Statute charged, e.g. "18 U.S.C. § 1343"; sets the limitations period
+    #[serde(rename = "offenseStatute", default)]
+    pub offense_statute: Option<String>,
 }
 
 /// Response model for a defendant in a case
@@ -118,6 +140,8 @@ pub struct VictimResponse {
 pub struct CaseResponse {
     pub id: Uuid,
     pub case_number: String,
+    /// Case number as first written; the canonical form when none was kept
+    pub display_case_number: String,
     pub title: String,
     pub description: String,
     pub crime_type: CrimeType,
@@ -140,6 +164,8 @@ pub struct CaseResponse {
     pub seal_reason: Option<String>,
     pub speedy_trial_status: Option<SpeedyTrialStatusResponse>,
     pub victims_count: usize,
+    pub limitations: Option<LimitationsTracker>,
+    pub has_pending_appeal: bool,
 }
 
 impl From<CriminalCase> for CaseResponse {
@@ -165,6 +191,7 @@ impl From<CriminalCase> for CaseResponse {
 
         Self {
             id: case.id,
+            display_case_number: case.display_number().to_string(),
             case_number: case.case_number,
             title: case.title,
             description: case.description,
@@ -195,6 +222,8 @@ impl From<CriminalCase> for CaseResponse {
             seal_reason: case.seal_reason,
             speedy_trial_status,
             victims_count: case.victims.len(),
+            limitations: case.limitations,
+            has_pending_appeal: case.has_pending_appeal,
         }
     }
 }
@@ -213,9 +242,18 @@ pub struct CreateCaseWithComplianceResponse {
 
 /// Create a new criminal case
 ///
+/// Without a `caseNumber` the case gets the next number in the district's
+/// sequence for its `caseType`, e.g. `24-cr-00123`. A supplied number is
+/// stored in canonical form, so `24-CR-123` is saved as `24-cr-00123` and
+/// shown as written.
+///
 /// Optionally runs a compliance check when the query parameter `compliance=true`
 /// is provided. The compliance check validates the case filing against configured
 /// court rules but does not block case creation.
+///
+/// A `case.created` event is posted to the district webhook, if one is set.
+/// A failed post is logged and queued for `POST /api/webhooks/deliver-pending`
+/// to retry; it never fails the request.
 #[utoipa::path(
     post,
     path = "/api/cases",
@@ -232,7 +270,8 @@ pub struct CreateCaseWithComplianceResponse {
     ),
     responses(
         (status = 201, description = "Case created successfully", body = CreateCaseWithComplianceResponse),
-        (status = 400, description = "Invalid request data"),
+        (status = 400, description = "Invalid request data, case number or unknown case type"),
+        (status = 409, description = "Case number already in use, or no sequence number could be reserved"),
         (status = 500, description = "Internal server error")
     )
 )]
@@ -249,22 +288,60 @@ pub fn create_case(req: Request, _p: Params) -> ApiResult<impl IntoResponse> {
         return Err(ApiError::BadRequest("District code is required".to_string()));
     }
 
-    let case = CriminalCase::new(
+    let repository = match RepositoryFactory::case_repo(&req) {
+        Ok(r) => r,
+        Err(e) => return Err(e),
+    };
+
+    let format = case_number_format(&req)?;
+    let supplied = request.case_number.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    let (case_number, display_case_number) = match supplied {
+        Some(display_case_number) => {
+            let case_number = format.normalize(&display_case_number).map_err(ApiError::BadRequest)?;
+            if case_number_service::find_by_number(&repository, &format, &case_number)?.is_some() {
+                return Err(ApiError::Conflict(format!("Case number {} is already in use", case_number)));
+            }
+            (case_number, display_case_number)
+        }
+        None => {
+            let case_type = request.case_type.as_deref().unwrap_or(DEFAULT_CASE_TYPE);
+            let generated = CaseNumberGenerator::new(&repository, format.clone())
+                .next(&request.district_code, case_type, Utc::now().year())?;
+            (format.normalize(&generated).map_err(ApiError::Internal)?, generated)
+        }
+    };
+
+    let mut case = CriminalCase::new(
         request.title,
         request.description,
         request.crime_type,
         request.district_code,
         request.assigned_judge_id,
-        &request.judge_initials,
+        case_number,
         request.location,
     );
-
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
-        Ok(r) => r,
-        Err(e) => return Err(e),
-    };
+    case.set_display_case_number(display_case_number);
+    match (request.offense_date, request.offense_statute) {
+        (Some(offense_date), Some(offense_statute)) => {
+            let deadlines = RepositoryFactory::deadline_repo(&req)?;
+            limitations_service::start_tracking(&mut case, offense_date, offense_statute, &deadlines)?;
+        }
+        (None, None) => {}
+        _ => {
+            return Err(ApiError::BadRequest(
+                "offenseDate and offenseStatute must be given together".to_string(),
+            ))
+        }
+    }
     repository.save(&case)?;
 
+    webhook_service::notify(&req, WebhookEventType::CaseCreated, serde_json::json!({
+        "case_id": case.id,
+        "case_number": case.case_number,
+        "title": case.title,
+        "status": case.status,
+    }));
+
     // Optional compliance check: evaluate rules without blocking creation
     let compliance_report = if run_compliance {
         run_case_compliance_check(&req, &case).ok()
@@ -284,6 +361,14 @@ pub fn create_case(req: Request, _p: Params) -> ApiResult<impl IntoResponse> {
         .build())
 }
 
+/// The district's case number format from its merged configuration
+pub(crate) fn case_number_format(req: &Request) -> ApiResult<CaseNumberFormat> {
+    let district_id = crate::utils::tenant::get_config_district_id(req);
+    let config_repo = RepositoryFactory::config_repo(req)?;
+    let config = futures::executor::block_on(config_repo.get_merged_config(&district_id, None))?;
+    Ok(CaseNumberFormat::from_config(&config))
+}
+
 /// Parse the ?compliance=true query parameter
 fn parse_compliance_flag(query: &str) -> bool {
     for pair in query.split('&') {
@@ -298,7 +383,7 @@ fn parse_compliance_flag(query: &str) -> bool {
 /// Run compliance check against configured rules for a newly created case.
 /// Returns a ComplianceReport or an error. Errors are non-fatal and logged.
 fn run_case_compliance_check(req: &Request, case: &CriminalCase) -> Result<ComplianceReport, ApiError> {
-    let rules_repo = RepositoryFactory::rules_repo_validated(req)?;
+    let rules_repo = RepositoryFactory::rules_repo(req)?;
 
     let context = FilingContext {
         case_type: format!("{:?}", case.crime_type).to_lowercase(),
@@ -342,6 +427,7 @@ fn run_case_compliance_check(req: &Request, case: &CriminalCase) -> Result<Compl
     responses(
         (status = 200, description = "Case found", body = CaseResponse),
         (status = 404, description = "Case not found"),
+        (status = 410, description = "Case archived; details.archive_url points to the archive", body = crate::error::ErrorResponse),
         (status = 400, description = "Invalid UUID")
     )
 )]
@@ -351,7 +437,7 @@ pub fn get_case_by_id(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
 
     let id = Uuid::parse_str(id_str)?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -361,7 +447,17 @@ pub fn get_case_by_id(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
             .header("content-type", "application/json")
             .body(serde_json::to_vec(&CaseResponse::from(case))?)
             .build()),
-        None => Err(ApiError::NotFound(format!("Case with id {} not found", id))),
+        None => match repository.find_tombstone(id)? {
+            Some(tombstone) => Err(ApiError::Gone(format!(
+                "Case {} was archived on {}", tombstone.case_number, tombstone.archived_at.format("%Y-%m-%d")
+            ))
+            .with_details(serde_json::json!({
+                "archive_url": tombstone.archive_path(),
+                "case_number": tombstone.case_number,
+                "archived_at": tombstone.archived_at,
+            }))),
+            None => Err(ApiError::NotFound(format!("Case with id {} not found", id))),
+        },
     }
 }
 
@@ -372,10 +468,11 @@ pub fn get_case_by_id(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
     tags = ["cases"],
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("status" = Option<String>, Query, description = "Filter by status"),
+        ("status" = Option<Vec<String>>, Query, description = "Filter by status; repeat or comma-separate to match any of several statuses"),
         ("priority" = Option<String>, Query, description = "Filter by priority"),
         ("judge" = Option<String>, Query, description = "Filter by assigned judge ID"),
         ("active" = Option<bool>, Query, description = "Filter by active status"),
+        ("q" = Option<String>, Query, description = "Text to match against case number, title or description"),
         ("page" = Option<usize>, Query, description = "Page number", minimum = 1),
         ("limit" = Option<usize>, Query, description = "Items per page", minimum = 1, maximum = 100)
     ),
@@ -389,7 +486,7 @@ pub fn search_cases(req: Request, _p: Params) -> ApiResult<impl IntoResponse> {
     let query_string = req.query();
     let params = parse_case_query(query_string)?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -418,20 +515,24 @@ pub struct CaseSearchResponse {
     get,
     path = "/api/cases/statistics",
     tags = ["cases"],
-    description = "Get criminal case statistics",
+    description = "Get statistics for criminal cases opened within a date range",
     params(
-        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("from" = Option<String>, Query, description = "Start of the date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to 12 months before `to`"),
+        ("to" = Option<String>, Query, description = "End of the date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to now")
     ),
     responses(
-        (status = 200, description = "Case statistics", body = crate::ports::case_repository::CaseStatistics)
+        (status = 200, description = "Case statistics", body = crate::ports::case_repository::CaseStatistics),
+        (status = 400, description = "Invalid date range", body = crate::error::ErrorResponse)
     )
 )]
 pub fn get_case_statistics(req: Request, _p: Params) -> ApiResult<impl IntoResponse> {
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
-    let stats = repository.get_statistics()?;
+    let range = query_parser::get_date_range(&query_parser::parse_query_string(req.query()))?;
+    let stats = repository.get_statistics(range)?;
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -440,6 +541,10 @@ pub fn get_case_statistics(req: Request, _p: Params) -> ApiResult<impl IntoRespo
 }
 
 /// Update case status
+///
+/// A `case.status_changed` event is posted to the district webhook, if one is
+/// set. A failed post is logged and queued for
+/// `POST /api/webhooks/deliver-pending` to retry; it never fails the request.
 #[utoipa::path(
     patch,
     path = "/api/cases/{id}/status",
@@ -467,7 +572,7 @@ pub fn update_case_status(req: Request, p: Params) -> ApiResult<impl IntoRespons
     let id = Uuid::parse_str(id_str)?;
     let update_req: UpdateStatusRequest = serde_json::from_slice(req.body())?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -475,9 +580,19 @@ pub fn update_case_status(req: Request, p: Params) -> ApiResult<impl IntoRespons
     let mut case = repository.find_by_id(id)?
         .ok_or_else(|| ApiError::NotFound(format!("Case with id {} not found", id)))?;
 
+    let previous_status = case.status.clone();
     case.update_status(update_req.status);
     repository.save(&case)?;
 
+    if case.status != previous_status {
+        webhook_service::notify(&req, WebhookEventType::CaseStatusChanged, serde_json::json!({
+            "case_id": case.id,
+            "case_number": case.case_number,
+            "previous_status": previous_status,
+            "status": case.status,
+        }));
+    }
+
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
         .body(serde_json::to_vec(&CaseResponse::from(case))?)
@@ -512,6 +627,9 @@ pub struct AddDefendantRequest {
     pub usm_number: Option<String>,
     #[serde(rename = "fbiNumber")]
     pub fbi_number: Option<String>,
+    /// Bail and conditions of release
+    #[serde(rename = "bondInfo", default)]
+    pub bond_info: Option<BondInfo>,
 }
 
 /// Request to add evidence
@@ -538,7 +656,7 @@ pub struct AddNoteRequest {
     tags = ["cases"],
     description = "Retrieve a criminal case by its case number",
     params(
-        ("case_number" = String, Path, description = "Case number (e.g., SDNY:26-CR-00123-JMS)"),
+        ("case_number" = String, Path, description = "Case number in any casing and padding (e.g., 26-cr-00123 or 26-CR-123)"),
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
     ),
     responses(
@@ -550,12 +668,13 @@ pub fn get_case_by_number(req: Request, p: Params) -> ApiResult<impl IntoRespons
     let case_number = p.get("case_number")
         .ok_or_else(|| ApiError::Internal("Missing path parameter 'case_number'".to_string()))?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
 
-    match repository.find_by_case_number(case_number)? {
+    let format = case_number_format(&req)?;
+    match case_number_service::find_by_number(&repository, &format, case_number)? {
         Some(case) => Ok(ResponseBuilder::new(200)
             .header("content-type", "application/json")
             .body(serde_json::to_vec(&CaseResponse::from(case))?)
@@ -591,7 +710,7 @@ pub fn update_case_priority(req: Request, p: Params) -> ApiResult<impl IntoRespo
     let id = Uuid::parse_str(id_str)?;
     let update_req: UpdatePriorityRequest = serde_json::from_slice(req.body())?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -640,7 +759,7 @@ pub fn add_defendant(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
         return Err(ApiError::BadRequest("Defendant name cannot be empty".to_string()));
     }
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -656,7 +775,7 @@ pub fn add_defendant(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
         date_of_birth: None,
         citizenship_status: None,
         custody_status: None,
-        bond_info: None,
+        bond_info: add_req.bond_info,
     };
 
     case.add_defendant(domain_request);
@@ -700,7 +819,7 @@ pub fn add_evidence(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
         return Err(ApiError::BadRequest("Evidence description cannot be empty".to_string()));
     }
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -749,7 +868,7 @@ pub fn add_note(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
         return Err(ApiError::BadRequest("Note content cannot be empty".to_string()));
     }
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -778,7 +897,8 @@ pub fn add_note(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
     ),
     responses(
         (status = 204, description = "Case deleted"),
-        (status = 404, description = "Case not found")
+        (status = 404, description = "Case not found"),
+        (status = 409, description = "Case has a pending appeal")
     )
 )]
 pub fn delete_case(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
@@ -787,11 +907,20 @@ pub fn delete_case(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
 
     let id = Uuid::parse_str(id_str)?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
 
+    if let Some(case) = repository.find_by_id(id)? {
+        if case.has_pending_appeal {
+            return Err(ApiError::Conflict(format!(
+                "Case {} has a pending appeal and cannot be deleted until the mandate issues",
+                id
+            )));
+        }
+    }
+
     if repository.delete(id)? {
         Ok(ResponseBuilder::new(204).build())
     } else {
@@ -821,7 +950,7 @@ pub fn get_cases_by_judge(req: Request, p: Params) -> ApiResult<impl IntoRespons
     let judge_id = Uuid::parse_str(judge_id_str)
         .map_err(|_| ApiError::BadRequest("Invalid judge ID format".to_string()))?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -839,6 +968,79 @@ pub fn get_cases_by_judge(req: Request, p: Params) -> ApiResult<impl IntoRespons
         .build())
 }
 
+/// Get cases an attorney represents a party in
+#[utoipa::path(
+    get,
+    path = "/api/cases/by-attorney/{attorney_id}",
+    tags = ["cases"],
+    description = "Get the cases where an attorney is counsel of record, from the attorney's representations. \
+                   Only active representations count unless active_only=false, which includes ended ones.",
+    params(
+        ("attorney_id" = String, Path, description = "Attorney ID"),
+        ("active_only" = Option<bool>, Query, description = "Only cases where the attorney is active counsel (default true)"),
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+    ),
+    responses(
+        (status = 200, description = "List of cases", body = CaseSearchResponse),
+        (status = 400, description = "active_only is not true or false"),
+        (status = 404, description = "Attorney not found")
+    )
+)]
+pub fn get_cases_by_attorney(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
+    let attorney_id = p.get("attorney_id")
+        .ok_or_else(|| ApiError::Internal("Missing path parameter 'attorney_id'".to_string()))?;
+
+    let query = query_parser::parse_query_string(req.query());
+    let active_only = match query_parser::get_string(&query, "active_only") {
+        None => true,
+        Some(value) => value.parse().map_err(|_| {
+            ApiError::BadRequest(format!("active_only must be true or false, got {:?}", value))
+        })?,
+    };
+
+    let attorneys = RepositoryFactory::attorney_repo(&req)?;
+    if attorneys.find_attorney_by_id(attorney_id).map_err(|e| ApiError::StorageError(e.to_string()))?.is_none() {
+        return Err(ApiError::NotFound(format!("Attorney {} not found", attorney_id)));
+    }
+    let representations = attorneys
+        .find_representations_by_attorney(attorney_id)
+        .map_err(|e| ApiError::StorageError(e.to_string()))?;
+
+    let mut case_ids: Vec<Uuid> = representations
+        .iter()
+        .filter(|rep| !active_only || is_active_counsel(rep))
+        .filter_map(|rep| Uuid::parse_str(&rep.case_id).ok())
+        .collect();
+    case_ids.sort();
+    case_ids.dedup();
+
+    let repository = RepositoryFactory::case_repo(&req)?;
+    let mut cases = Vec::new();
+    for case_id in case_ids {
+        // Representations can outlive a deleted case
+        if let Some(case) = repository.find_by_id(case_id)? {
+            cases.push(case);
+        }
+    }
+    cases.sort_by(|a, b| a.case_number.cmp(&b.case_number));
+
+    let total = cases.len();
+    let response = CaseSearchResponse {
+        cases: cases.into_iter().map(CaseResponse::from).collect(),
+        total,
+    };
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&response)?)
+        .build())
+}
+
+/// Whether a representation is still open
+fn is_active_counsel(rep: &Representation) -> bool {
+    rep.end_date.is_none() && matches!(rep.status, RepresentationStatus::Active)
+}
+
 /// Get case count by status
 #[utoipa::path(
     get,
@@ -857,9 +1059,9 @@ pub fn count_by_status(req: Request, p: Params) -> ApiResult<impl IntoResponse>
     let status_str = p.get("status")
         .ok_or_else(|| ApiError::Internal("Missing path parameter 'status'".to_string()))?;
 
-    let status: CaseStatus = serde_json::from_str(&format!("\"{}\"", status_str))?;
+    let status: CaseStatus = query_parser::parse_enum_param("status", status_str)?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -986,7 +1188,7 @@ pub fn enter_plea(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
 
     let plea_req: EnterPleaRequest = serde_json::from_slice(req.body())?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1037,7 +1239,7 @@ pub fn add_charge(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
 
     let charge_req: AddChargeRequest = serde_json::from_slice(req.body())?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1064,6 +1266,110 @@ pub fn add_charge(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
         .build())
 }
 
+/// Update a defendant's custody status
+#[utoipa::path(
+    patch,
+    path = "/api/cases/{id}/defendants/{defendant_id}/custody",
+    tags = ["cases"],
+    description = "Record detention or release for a defendant. Release on bond or to home confinement requires a release conditions reference; use released_on_own_recognizance for release without conditions.",
+    params(
+        ("id" = Uuid, Path, description = "Case ID"),
+        ("defendant_id" = Uuid, Path, description = "Defendant ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+    ),
+    request_body(
+        content = UpdateCustodyRequest,
+        description = "New custody status and detention or release details",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Custody updated", body = Defendant),
+        (status = 404, description = "Case or defendant not found"),
+        (status = 400, description = "Invalid request or release without a conditions reference")
+    )
+)]
+pub fn update_defendant_custody(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
+    let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
+    let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
+
+    let defendant_id_str = p.get("defendant_id")
+        .ok_or_else(|| ApiError::BadRequest("Missing defendant ID".to_string()))?;
+    let defendant_id = Uuid::parse_str(defendant_id_str)
+        .map_err(|_| ApiError::BadRequest("Invalid defendant ID format".to_string()))?;
+
+    let custody_req: UpdateCustodyRequest = serde_json::from_slice(req.body())?;
+
+    let repository = RepositoryFactory::case_repo(&req)?;
+
+    let mut case = repository.find_by_id(id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Case {} not found", id)))?;
+
+    let defendant = case.defendants.iter_mut().find(|d| d.id == defendant_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Defendant {} not found in case {}", defendant_id, id)))?;
+
+    defendant.update_custody(custody_req, Utc::now().date_naive())
+        .map_err(ApiError::BadRequest)?;
+    let updated = defendant.clone();
+
+    repository.save(&case)?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&updated)?)
+        .build())
+}
+
+/// District-wide custody report
+#[utoipa::path(
+    get,
+    path = "/api/cases/custody-report",
+    tags = ["cases"],
+    description = "List defendants in a custody status across the district, longest in custody first",
+    params(
+        ("status" = Option<String>, Query, description = "Custody status to report (default: detained, i.e. in_custody)"),
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+    ),
+    responses(
+        (status = 200, description = "Defendants in the requested status", body = Vec<CustodyReportEntry>),
+        (status = 400, description = "Unknown custody status")
+    )
+)]
+pub fn get_custody_report(req: Request, _p: Params) -> ApiResult<impl IntoResponse> {
+    let query = query_parser::parse_query_string(req.query());
+    let status = match query_parser::get_string(&query, "status").as_deref() {
+        None | Some("detained") => CustodyStatus::InCustody,
+        Some(other) => serde_json::from_value(serde_json::Value::String(other.to_string()))
+            .map_err(|_| ApiError::BadRequest(format!("Unknown custody status: {}", other)))?,
+    };
+
+    let repository = RepositoryFactory::case_repo(&req)?;
+    let today = Utc::now().date_naive();
+
+    let mut entries: Vec<CustodyReportEntry> = repository.find_all_cases()?
+        .iter()
+        .flat_map(|case| {
+            case.defendants.iter()
+                .filter(|d| d.custody_status == status)
+                .map(move |d| CustodyReportEntry {
+                    case_id: case.id,
+                    case_number: case.case_number.clone(),
+                    defendant_id: d.id,
+                    defendant_name: d.name.clone(),
+                    custody_status: d.custody_status.clone(),
+                    detention_facility: d.detention_facility.clone(),
+                    detention_date: d.detention_date,
+                    days_in_custody: d.days_in_custody(today),
+                })
+        })
+        .collect();
+    entries.sort_by_key(|e| std::cmp::Reverse(e.days_in_custody));
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&entries)?)
+        .build())
+}
+
 /// Schedule court event
 #[utoipa::path(
     post,
@@ -1088,7 +1394,7 @@ pub fn schedule_court_event(req: Request, p: Params) -> ApiResult<impl IntoRespo
     let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
 
     let event_req: ScheduleEventRequest = serde_json::from_slice(req.body())?;
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1129,7 +1435,7 @@ pub fn file_motion(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
     let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
 
     let motion_req: FileMotionRequest = serde_json::from_slice(req.body())?;
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1170,7 +1476,7 @@ pub fn rule_on_motion(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
     let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
 
     let ruling_req: RuleOnMotionRequest = serde_json::from_slice(req.body())?;
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1188,56 +1494,49 @@ pub fn rule_on_motion(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
 }
 
 /// Parse query parameters for case search
+///
+/// `status` may be repeated or comma-separated to match cases in any of the
+/// given statuses.
 fn parse_case_query(query: &str) -> ApiResult<CaseQuery> {
-    let mut params = CaseQuery {
-        offset: 0,
-        limit: 20,
-        ..Default::default()
-    };
+    let parsed = query_parser::parse_query_string(query);
 
-    if query.is_empty() {
-        return Ok(params);
-    }
+    let statuses = query_parser::get_json_vec(&parsed, "status")?;
 
-    for pair in query.split('&') {
-        if pair.is_empty() {
-            continue;
-        }
+    let priority = query_parser::get_string(&parsed, "priority")
+        .map(|priority| query_parser::parse_enum_param("priority", &priority))
+        .transpose()?;
 
-        let parts: Vec<&str> = pair.splitn(2, '=').collect();
-        if parts.len() != 2 || parts[1].is_empty() {
-            continue;
-        }
+    let judge_id = query_parser::get_string(&parsed, "judge")
+        .map(|judge| Uuid::parse_str(&judge)
+            .map_err(|_| ApiError::BadRequest("Invalid judge ID format".to_string())))
+        .transpose()?;
 
-        match parts[0] {
-            "status" => {
-                params.status = Some(serde_json::from_str(&format!("\"{}\"", parts[1]))?);
-            }
-            "priority" => {
-                params.priority = Some(serde_json::from_str(&format!("\"{}\"", parts[1]))?);
-            }
-            "judge" => {
-                params.judge_id = Some(Uuid::parse_str(parts[1])
-                    .map_err(|_| ApiError::BadRequest("Invalid judge ID format".to_string()))?);
-            }
-            "active" => {
-                params.is_active = Some(parts[1].parse()
-                    .map_err(|_| ApiError::BadRequest("Invalid active value".to_string()))?);
-            }
-            "page" => {
-                let page: usize = parts[1].parse()
-                    .map_err(|_| ApiError::BadRequest("Invalid page number".to_string()))?;
-                params.offset = (page - 1) * params.limit;
-            }
-            "limit" => {
-                params.limit = parts[1].parse()
-                    .map_err(|_| ApiError::BadRequest("Invalid limit value".to_string()))?;
-            }
-            _ => {}
-        }
-    }
+    let is_active = query_parser::get_string(&parsed, "active")
+        .map(|active| active.parse()
+            .map_err(|_| ApiError::BadRequest("Invalid active value".to_string())))
+        .transpose()?;
+
+    let limit = match query_parser::get_string(&parsed, "limit") {
+        Some(limit) => limit.parse()
+            .map_err(|_| ApiError::BadRequest("Invalid limit value".to_string()))?,
+        None => 20,
+    };
 
-    Ok(params)
+    let page: usize = match query_parser::get_string(&parsed, "page") {
+        Some(page) => page.parse()
+            .map_err(|_| ApiError::BadRequest("Invalid page number".to_string()))?,
+        None => 1,
+    };
+
+    Ok(CaseQuery {
+        statuses,
+        priority,
+        judge_id,
+        is_active,
+        search_text: query_parser::get_text(&parsed, "q"),
+        offset: page.saturating_sub(1) * limit,
+        limit,
+    })
 }
 
 // ============================================================================
@@ -1299,7 +1598,7 @@ pub fn add_custody_transfer(req: Request, p: Params) -> ApiResult<impl IntoRespo
         return Err(ApiError::BadRequest("Transferred to cannot be empty".to_string()));
     }
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1370,7 +1669,7 @@ pub fn add_docket_entry(req: Request, p: Params) -> ApiResult<impl IntoResponse>
         return Err(ApiError::BadRequest("Docket entry description cannot be empty".to_string()));
     }
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1406,7 +1705,7 @@ pub fn get_docket_entries(req: Request, p: Params) -> ApiResult<impl IntoRespons
     let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
     let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1491,7 +1790,7 @@ pub fn seal_case(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
         return Err(ApiError::BadRequest("Sealed by cannot be empty".to_string()));
     }
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1532,7 +1831,7 @@ pub fn unseal_case(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
 
     let unseal_req: UnsealCaseRequest = serde_json::from_slice(req.body())?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1550,6 +1849,106 @@ pub fn unseal_case(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
         .build())
 }
 
+/// Archive a closed case to cold storage
+#[utoipa::path(
+    post,
+    path = "/api/cases/{id}/archive",
+    tags = ["cases"],
+    description = "Move a dismissed or sentenced case and its docket, calendar, deadlines, assignments and orders into a single archive, leaving a tombstone in the live store",
+    params(
+        ("id" = Uuid, Path, description = "Case ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+    responses(
+        (status = 200, description = "Case archived", body = CaseArchive),
+        (status = 404, description = "Case not found"),
+        (status = 409, description = "Case already archived or has a pending appeal"),
+        (status = 400, description = "Case is not closed")
+    )
+)]
+pub fn archive_case(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
+    let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
+    let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
+
+    let archive = case_archive_service::archive_case(
+        &RepositoryFactory::case_repo(&req)?,
+        &RepositoryFactory::docket_repo(&req)?,
+        &RepositoryFactory::deadline_repo(&req)?,
+        &RepositoryFactory::judge_repo(&req)?,
+        &RepositoryFactory::document_repo(&req)?,
+        id,
+    )?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&archive)?)
+        .build())
+}
+
+/// Get the archive of an archived case
+#[utoipa::path(
+    get,
+    path = "/api/cases/{id}/archive",
+    tags = ["cases"],
+    description = "Retrieve the archive bundle of an archived case",
+    params(
+        ("id" = Uuid, Path, description = "Case ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+    responses(
+        (status = 200, description = "Case archive", body = CaseArchive),
+        (status = 404, description = "No archive for case")
+    )
+)]
+pub fn get_case_archive(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
+    let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
+    let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
+
+    let repository = RepositoryFactory::case_repo(&req)?;
+    let archive = repository.find_archive(id)?
+        .ok_or_else(|| ApiError::NotFound(format!("No archive for case {}", id)))?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&archive)?)
+        .build())
+}
+
+/// Restore an archived case to the live store
+#[utoipa::path(
+    post,
+    path = "/api/cases/{id}/unarchive",
+    tags = ["cases"],
+    description = "Restore an archived case and its records to the live store and remove the archive",
+    params(
+        ("id" = Uuid, Path, description = "Case ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+    responses(
+        (status = 200, description = "Case restored", body = CaseResponse),
+        (status = 404, description = "No archive for case"),
+        (status = 409, description = "Case number taken by another live case")
+    )
+)]
+pub fn unarchive_case(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
+    let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
+    let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
+
+    let case = case_archive_service::unarchive_case(
+        &RepositoryFactory::case_repo(&req)?,
+        &RepositoryFactory::docket_repo(&req)?,
+        &RepositoryFactory::deadline_repo(&req)?,
+        &RepositoryFactory::judge_repo(&req)?,
+        &RepositoryFactory::document_repo(&req)?,
+        id,
+    )?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&CaseResponse::from(case))?)
+        .build())
+}
+
 // ============================================================================
 // Phase 4: Speedy Trial Clock Handlers
 // ============================================================================
@@ -1630,7 +2029,7 @@ pub fn start_speedy_trial(req: Request, p: Params) -> ApiResult<impl IntoRespons
         .transpose()
         .map_err(|_| ApiError::BadRequest("Invalid arraignment date format".to_string()))?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1688,7 +2087,7 @@ pub fn add_case_excludable_delay(req: Request, p: Params) -> ApiResult<impl Into
         order_reference: delay_req.order_reference,
     };
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1725,7 +2124,7 @@ pub fn get_case_speedy_trial(req: Request, p: Params) -> ApiResult<impl IntoResp
     let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
     let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1751,6 +2150,149 @@ pub fn get_case_speedy_trial(req: Request, p: Params) -> ApiResult<impl IntoResp
         .build())
 }
 
+/// Request to toll the statute of limitations on a case
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "reason": "Defendant fled the district (18 U.S.C. § 3290)",
+    "startDate": "2025-01-10T00:00:00Z",
+    "endDate": "2025-07-10T00:00:00Z"
+}))]
+pub struct RecordTollingRequest {
+    pub reason: String,
+    #[serde(rename = "startDate")]
+    pub start_date: String,
+    /// End of the suspension, exclusive
+    #[serde(rename = "endDate")]
+    pub end_date: String,
+}
+
+/// Toll the statute of limitations on a case
+#[utoipa::path(
+    post,
+    path = "/api/cases/{id}/limitations/tolling",
+    tags = ["cases"],
+    description = "Record a period the limitations clock was suspended, extending the case's limitations deadline by the days tolled",
+    params(
+        ("id" = Uuid, Path, description = "Case ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+    request_body(content = RecordTollingRequest, description = "Tolling reason and period"),
+    responses(
+        (status = 200, description = "Tolling recorded", body = LimitationsTracker),
+        (status = 404, description = "Case not found"),
+        (status = 400, description = "Invalid period or limitations not tracked on the case")
+    )
+)]
+pub fn record_limitations_tolling(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
+    let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
+    let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
+
+    let tolling_req: RecordTollingRequest = serde_json::from_slice(req.body())?;
+
+    let start_date = DateTime::parse_from_rfc3339(&tolling_req.start_date)
+        .map(|d| d.with_timezone(&Utc))
+        .map_err(|_| ApiError::BadRequest("Invalid start date format".to_string()))?;
+    let end_date = DateTime::parse_from_rfc3339(&tolling_req.end_date)
+        .map(|d| d.with_timezone(&Utc))
+        .map_err(|_| ApiError::BadRequest("Invalid end date format".to_string()))?;
+
+    let repository = RepositoryFactory::case_repo(&req)?;
+    let deadlines = RepositoryFactory::deadline_repo(&req)?;
+    let tracker = limitations_service::record_tolling(
+        &repository,
+        &deadlines,
+        id,
+        tolling_req.reason,
+        start_date,
+        end_date,
+    )?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&tracker)?)
+        .build())
+}
+
+/// Cases at risk of the statute of limitations running
+#[utoipa::path(
+    get,
+    path = "/api/cases/limitations-at-risk",
+    tags = ["cases"],
+    description = "List cases with no indictment or information filed whose limitations deadline falls within the window, soonest first",
+    params(
+        ("days" = Option<usize>, Query, description = "Days ahead to look (default: 180)"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+    responses(
+        (status = 200, description = "Cases at risk", body = Vec<LimitationsAtRisk>),
+        (status = 400, description = "Invalid window")
+    )
+)]
+pub fn get_limitations_at_risk(req: Request, _p: Params) -> ApiResult<impl IntoResponse> {
+    let query = query_parser::parse_query_string(req.query());
+    let days = match query_parser::get_string(&query, "days") {
+        None => limitations_service::DEFAULT_AT_RISK_WINDOW_DAYS,
+        Some(value) => match value.parse::<i64>() {
+            Ok(days) if days > 0 => days,
+            _ => return Err(ApiError::BadRequest(format!("days must be a positive number, got '{}'", value))),
+        },
+    };
+
+    let repository = RepositoryFactory::case_repo(&req)?;
+    let docket = RepositoryFactory::docket_repo(&req)?;
+    let at_risk = limitations_service::at_risk(&repository, &docket, days, Utc::now())?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&at_risk)?)
+        .build())
+}
+
+/// Attribute a case's total delay to defense, prosecution, court or neutral causes
+#[utoipa::path(
+    get,
+    path = "/api/cases/{case_id}/delay-attribution",
+    tags = ["cases"],
+    description = "Combine continuances, excludable delays and granted extensions into days of delay per responsible party",
+    params(
+        ("case_id" = Uuid, Path, description = "Case ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+    responses(
+        (status = 200, description = "Delay attribution", body = DelayAttribution),
+        (status = 404, description = "Case not found")
+    )
+)]
+pub fn get_delay_attribution(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
+    let id_str = p.get("case_id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
+    let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
+
+    let repository = RepositoryFactory::case_repo(&req)?;
+    let case = repository.find_by_id(id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Case with id {} not found", id)))?;
+
+    let docket_repo = RepositoryFactory::docket_repo(&req)?;
+    let events = docket_repo.find_events_by_case(id)?;
+
+    // Prefer the clock kept on the case; fall back to a standalone Speedy Trial clock
+    let excludable_delays = match case.speedy_trial.as_ref() {
+        Some(clock) => clock.excludable_delays.clone(),
+        None => docket_repo
+            .find_clock_by_case(id)?
+            .map(|clock| clock.excludable_delays)
+            .unwrap_or_default(),
+    };
+
+    let deadlines = RepositoryFactory::deadline_repo(&req)?.find_deadlines_by_case(id)?;
+
+    let attribution = DelayAttribution::compute(id, &case.motions, &events, &excludable_delays, &deadlines);
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&attribution)?)
+        .build())
+}
+
 // ============================================================================
 // Phase 5: CVRA Victim Handlers
 // ============================================================================
@@ -1825,7 +2367,7 @@ pub fn add_victim(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
         return Err(ApiError::BadRequest("Victim name cannot be empty".to_string()));
     }
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1871,7 +2413,7 @@ pub fn get_victims(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
     let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
     let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1922,7 +2464,7 @@ pub fn send_victim_notification(req: Request, p: Params) -> ApiResult<impl IntoR
 
     let notif_req: SendVictimNotificationRequest = serde_json::from_slice(req.body())?;
 
-    let repository = match RepositoryFactory::case_repo_validated(&req) {
+    let repository = match RepositoryFactory::case_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -1950,3 +2492,255 @@ pub fn send_victim_notification(req: Request, p: Params) -> ApiResult<impl IntoR
         .body(serde_json::to_vec(&CaseResponse::from(case))?)
         .build())
 }
+
+/// Request to record a party's consent to magistrate judge jurisdiction
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "party": "United States",
+    "status": "consented",
+    "docketEntryId": "7c1e5f2a-3b4d-4e6f-8a9b-0c1d2e3f4a5b"
+}))]
+pub struct MagistrateConsentRequest {
+    pub party: String,
+    pub status: ConsentStatus,
+    /// Docket entry that solicited the party's consent
+    #[serde(rename = "docketEntryId", default)]
+    pub docket_entry_id: Option<Uuid>,
+}
+
+/// Record a party's consent to magistrate judge jurisdiction
+///
+/// Parties are the United States and each defendant. Until every one of
+/// them has consented, judge assignment won't make a magistrate judge the
+/// presiding judge without an override reason.
+#[utoipa::path(
+    post,
+    path = "/api/cases/{id}/magistrate-consent",
+    tags = ["cases"],
+    description = "Record a party's consent to a magistrate judge under 28 U.S.C. § 636(c)",
+    params(
+        ("id" = Uuid, Path, description = "Case ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+    request_body(content = MagistrateConsentRequest, description = "Party and consent decision"),
+    responses(
+        (status = 200, description = "Consent recorded", body = ConsentSummary),
+        (status = 400, description = "Unknown party or docket entry"),
+        (status = 404, description = "Case not found")
+    )
+)]
+pub fn record_magistrate_consent(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
+    let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
+    let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
+
+    let consent_req: MagistrateConsentRequest = serde_json::from_slice(req.body())?;
+
+    if consent_req.party.trim().is_empty() {
+        return Err(ApiError::BadRequest("Party cannot be empty".to_string()));
+    }
+
+    let repository = RepositoryFactory::case_repo(&req)?;
+
+    let mut case = repository.find_by_id(id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Case with id {} not found", id)))?;
+
+    let summary = case
+        .record_magistrate_consent(&consent_req.party, consent_req.status, consent_req.docket_entry_id)
+        .map_err(ApiError::BadRequest)?;
+    repository.save(&case)?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&summary)?)
+        .build())
+}
+
+/// Get magistrate judge consent for a case
+#[utoipa::path(
+    get,
+    path = "/api/cases/{id}/magistrate-consent",
+    tags = ["cases"],
+    description = "Summarize whether every party has consented to a magistrate judge",
+    params(
+        ("id" = Uuid, Path, description = "Case ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+    responses(
+        (status = 200, description = "Each party's consent and whether consent is complete", body = ConsentSummary),
+        (status = 404, description = "Case not found")
+    )
+)]
+pub fn get_magistrate_consent(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
+    let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
+    let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
+
+    let repository = RepositoryFactory::case_repo(&req)?;
+
+    let case = repository.find_by_id(id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Case with id {} not found", id)))?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&case.magistrate_consent_summary())?)
+        .build())
+}
+
+// ============================================================================
+// Notice of Appeal Handlers
+// ============================================================================
+
+/// Request to record a notice of appeal
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "filingParty": "defendant",
+    "filedBy": "Federal Defender",
+    "filedDate": "2025-04-08T15:00:00Z",
+    "appealedFrom": "Judgment (ECF No. 88)",
+    "judgmentEnteredDate": "2025-03-27T17:00:00Z"
+}))]
+pub struct FileAppealRequest {
+    #[serde(rename = "filingParty")]
+    pub filing_party: AppealFilingParty,
+    #[serde(rename = "filedBy", default)]
+    pub filed_by: Option<String>,
+    #[serde(rename = "filedDate")]
+    pub filed_date: String,
+    /// Judgment or order appealed from
+    #[serde(rename = "appealedFrom")]
+    pub appealed_from: String,
+    #[serde(rename = "judgmentEnteredDate")]
+    pub judgment_entered_date: String,
+}
+
+/// Request to move an appeal to a later status
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "status": "docketed"
+}))]
+pub struct UpdateAppealStatusRequest {
+    pub status: AppealStatus,
+}
+
+/// The district's special closure dates
+fn closure_dates(req: &Request) -> ApiResult<Vec<chrono::NaiveDate>> {
+    let district_id = crate::utils::tenant::get_config_district_id(req);
+    let config_repo = RepositoryFactory::config_repo(req)?;
+    futures::executor::block_on(config_repo.get_closure_dates(&district_id))
+}
+
+/// Record a notice of appeal on a case
+///
+/// The notice is due 14 days after judgment for a defendant (FRAP
+/// 4(b)(1)(A)) and 30 for the government (FRAP 4(b)(1)(B)); a late notice
+/// is recorded with `timely` false. The record transmission and briefing
+/// deadlines are scheduled and the case is held open until the mandate
+/// issues.
+#[utoipa::path(
+    post,
+    path = "/api/cases/{id}/appeals",
+    tags = ["cases"],
+    description = "Record a notice of appeal, docket it, and schedule the record and briefing deadlines",
+    params(
+        ("id" = Uuid, Path, description = "Case ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+    request_body(content = FileAppealRequest, description = "Filing party, dates, and the judgment or order appealed from"),
+    responses(
+        (status = 201, description = "Notice of appeal recorded", body = Appeal),
+        (status = 404, description = "Case not found"),
+        (status = 400, description = "Invalid dates or missing appealed order")
+    )
+)]
+pub fn file_appeal(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
+    let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
+    let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
+
+    let appeal_req: FileAppealRequest = serde_json::from_slice(req.body())?;
+
+    let filed_date = DateTime::parse_from_rfc3339(&appeal_req.filed_date)
+        .map(|d| d.with_timezone(&Utc))
+        .map_err(|_| ApiError::BadRequest("Invalid filed date format".to_string()))?;
+    let judgment_entered_date = DateTime::parse_from_rfc3339(&appeal_req.judgment_entered_date)
+        .map(|d| d.with_timezone(&Utc))
+        .map_err(|_| ApiError::BadRequest("Invalid judgment entered date format".to_string()))?;
+
+    let notice = NoticeOfAppeal {
+        filing_party: appeal_req.filing_party,
+        filed_by: appeal_req.filed_by,
+        filed_date,
+        appealed_from: appeal_req.appealed_from,
+        judgment_entered_date,
+    };
+
+    let repository = RepositoryFactory::case_repo(&req)?;
+    let deadlines = RepositoryFactory::deadline_repo(&req)?;
+    let closures = closure_dates(&req)?;
+    let appeal = appeal_service::file_notice(&repository, &deadlines, id, notice, &closures)?;
+
+    Ok(ResponseBuilder::new(201)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&appeal)?)
+        .build())
+}
+
+/// Get appeals for a case
+#[utoipa::path(
+    get,
+    path = "/api/cases/{id}/appeals",
+    tags = ["cases"],
+    description = "List the notices of appeal filed in a case, oldest first",
+    params(
+        ("id" = Uuid, Path, description = "Case ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+    responses(
+        (status = 200, description = "Appeals in the case", body = Vec<Appeal>),
+        (status = 404, description = "Case not found")
+    )
+)]
+pub fn get_appeals(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
+    let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing case ID".to_string()))?;
+    let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid case ID format".to_string()))?;
+
+    let repository = RepositoryFactory::case_repo(&req)?;
+
+    let case = repository.find_by_id(id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Case with id {} not found", id)))?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&case.appeals)?)
+        .build())
+}
+
+/// Move an appeal to a later status
+#[utoipa::path(
+    patch,
+    path = "/api/appeals/{id}/status",
+    tags = ["cases"],
+    description = "Advance an appeal through docketed, briefed, decided and mandate issued; the mandate clears the case's pending appeal",
+    params(
+        ("id" = Uuid, Path, description = "Appeal ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+    request_body(content = UpdateAppealStatusRequest, description = "New status"),
+    responses(
+        (status = 200, description = "Status updated", body = Appeal),
+        (status = 404, description = "Appeal not found"),
+        (status = 409, description = "Status is not later than the current one")
+    )
+)]
+pub fn update_appeal_status(req: Request, p: Params) -> ApiResult<impl IntoResponse> {
+    let id_str = p.get("id").ok_or_else(|| ApiError::BadRequest("Missing appeal ID".to_string()))?;
+    let id = Uuid::parse_str(id_str).map_err(|_| ApiError::BadRequest("Invalid appeal ID format".to_string()))?;
+
+    let status_req: UpdateAppealStatusRequest = serde_json::from_slice(req.body())?;
+
+    let repository = RepositoryFactory::case_repo(&req)?;
+    let appeal = appeal_service::update_status(&repository, id, status_req.status, Utc::now())?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&appeal)?)
+        .build())
+}
@@ -109,6 +109,17 @@ pub fn get_cases_by_judge(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_cases_by_attorney(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::get_cases_by_attorney(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
 pub fn count_by_status(req: Request, params: Params) -> Response {
     let req = match add_district_header(req, &params) {
         Ok(r) => r,
@@ -308,6 +319,39 @@ pub fn unseal_case(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn archive_case(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::archive_case(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+pub fn get_case_archive(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::get_case_archive(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+pub fn unarchive_case(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::unarchive_case(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
 // ============================================================================
 // Phase 4: Speedy Trial Clock URL wrappers
 // ============================================================================
@@ -345,6 +389,61 @@ pub fn get_case_speedy_trial(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn record_limitations_tolling(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::record_limitations_tolling(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+pub fn get_limitations_at_risk(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::get_limitations_at_risk(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+pub fn get_delay_attribution(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::get_delay_attribution(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+pub fn update_defendant_custody(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::update_defendant_custody(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+pub fn get_custody_report(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::get_custody_report(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
 // ============================================================================
 // Phase 5: CVRA Victim URL wrappers
 // ============================================================================
@@ -380,4 +479,67 @@ pub fn send_victim_notification(req: Request, params: Params) -> Response {
         Ok(resp) => resp.into_response(),
         Err(e) => crate::utils::json_response::error_response(&e),
     }
-}
\ No newline at end of file
+}
+
+// ============================================================================
+// Magistrate judge consent URL wrappers
+// ============================================================================
+
+pub fn record_magistrate_consent(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::record_magistrate_consent(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+pub fn get_magistrate_consent(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::get_magistrate_consent(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+// ============================================================================
+// Notice of appeal URL wrappers
+// ============================================================================
+
+pub fn file_appeal(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::file_appeal(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+pub fn get_appeals(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::get_appeals(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+pub fn update_appeal_status(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::criminal_case::update_appeal_status(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
@@ -5,16 +5,20 @@
 
 use crate::utils::repository_factory::RepositoryFactory;
 use crate::domain::deadline::{
-    Deadline, DeadlineType, DeadlineStatus, ExtensionRequest,
+    Deadline, DeadlineType, DeadlineStatus, DeadlineStatusTransition, ExtensionRequest,
     ExtensionStatus, DeadlineCalculator, DeadlineMonitor, FederalRule
 };
+use crate::domain::webhook::WebhookEventType;
 use crate::error::{ApiError, ApiResult};
 use crate::ports::deadline_repository::{
-    DeadlineRepository, ExtensionRepository, ReminderRepository,
-    DeadlineQuery, DeadlineComplianceRepository
+    DeadlineRepository, ExtensionRepository, ReminderRepository, DeadlineRuleRepository,
+    ComplianceReport, DeadlineQuery, DeadlineComplianceRepository
 };
-use crate::utils::query_parser;
-use chrono::{DateTime, Utc};
+use crate::ports::config_repository::ConfigRepository;
+use crate::services::pdf_service::create_pdf_service;
+use crate::services::{deadline_chain_service, deadline_service, webhook_service};
+use crate::utils::{access, json_response::Page, query_parser, tenant};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
 use uuid::Uuid;
@@ -26,13 +30,6 @@ pub struct StatusUpdate {
     pub status: DeadlineStatus,
 }
 
-/// Search response for deadlines
-#[derive(Serialize, ToSchema)]
-pub struct SearchResponse {
-    pub deadlines: Vec<Deadline>,
-    pub total: usize,
-}
-
 /// Response for reminder sending
 #[derive(Serialize, ToSchema)]
 pub struct ReminderResponse {
@@ -40,6 +37,18 @@ pub struct ReminderResponse {
     pub recipients: Vec<String>,
 }
 
+/// Result of recomputing deadline statuses
+#[derive(Serialize, ToSchema)]
+pub struct StatusRefreshResponse {
+    /// Deadlines whose status was recomputed
+    pub checked: usize,
+    /// Deadlines whose status changed and was saved
+    pub transitioned: usize,
+    /// Number of transitions into each status
+    pub by_status: std::collections::BTreeMap<String, usize>,
+    pub transitions: Vec<DeadlineStatusTransition>,
+}
+
 /// Response for pending extension requests
 #[derive(Serialize, ToSchema)]
 pub struct PendingExtensionsResponse {
@@ -52,7 +61,9 @@ pub struct PendingExtensionsResponse {
 pub struct CreateDeadlineRequest {
     pub case_id: Uuid,
     pub deadline_type: DeadlineType,
-    pub due_date: DateTime<Utc>,
+    /// Required unless `depends_on` is set, in which case it is derived
+    #[serde(default)]
+    pub due_date: Option<DateTime<Utc>>,
     pub triggering_event: String,
     pub triggering_date: DateTime<Utc>,
     pub applicable_rule: String,
@@ -60,6 +71,20 @@ pub struct CreateDeadlineRequest {
     pub responsible_party: String,
     pub is_jurisdictional: bool,
     pub is_extendable: bool,
+    /// Deadline of the same case this one is counted from
+    #[serde(default)]
+    pub depends_on: Option<Uuid>,
+    /// Days after the parent's completion or due date; required with `depends_on`
+    #[serde(default)]
+    pub offset_days: Option<i64>,
+}
+
+/// Request model for setting or clearing a deadline's dependency
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetDependencyRequest {
+    /// Parent deadline, or null to make the deadline independent
+    pub depends_on: Option<Uuid>,
+    pub offset_days: Option<i64>,
 }
 
 /// Request model for requesting an extension
@@ -118,6 +143,7 @@ impl ComplianceStatsResponse {
     responses(
         (status = 201, description = "Deadline created successfully", body = Deadline),
         (status = 400, description = "Invalid request data"),
+        (status = 422, description = "Dependency is incomplete, names a deadline of another case or unknown deadline, or would form a cycle", body = crate::error::ErrorResponse),
         (status = 500, description = "Internal server error")
     ),
     tag = "Deadline Management",
@@ -129,11 +155,20 @@ pub fn create_deadline(req: Request, _params: Params) -> ApiResult<impl IntoResp
     let body = req.body();
     let request: CreateDeadlineRequest = serde_json::from_slice(body)?;
 
-    let deadline = Deadline {
+    let due_date = match (request.due_date, request.depends_on) {
+        (Some(due_date), _) => due_date,
+        // Derived from the parent below
+        (None, Some(_)) => request.triggering_date,
+        (None, None) => {
+            return Err(ApiError::BadRequest("due_date is required unless depends_on is set".to_string()));
+        }
+    };
+
+    let mut deadline = Deadline {
         id: Uuid::new_v4(),
         case_id: request.case_id,
         deadline_type: request.deadline_type,
-        due_date: request.due_date,
+        due_date,
         triggering_event: request.triggering_event,
         triggering_date: request.triggering_date,
         applicable_rule: request.applicable_rule,
@@ -145,6 +180,9 @@ pub fn create_deadline(req: Request, _params: Params) -> ApiResult<impl IntoResp
         completion_date: None,
         extension_requests: Vec::new(),
         reminders_sent: Vec::new(),
+        due_date_history: Vec::new(),
+        depends_on: request.depends_on,
+        offset_days: request.offset_days,
     };
 
     let repo = match RepositoryFactory::deadline_repo(&req) {
@@ -157,6 +195,7 @@ pub fn create_deadline(req: Request, _params: Params) -> ApiResult<impl IntoResp
 
 
     };
+    deadline_chain_service::apply_dependency(&repo, &mut deadline, &closure_dates(&req)?)?;
     repo.save_deadline(&deadline)?;
 
     Ok(ResponseBuilder::new(201)
@@ -168,7 +207,7 @@ pub fn create_deadline(req: Request, _params: Params) -> ApiResult<impl IntoResp
 /// Get deadlines for a case
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/deadlines",
+    path = "/api/deadlines/case/{case_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID")
@@ -250,14 +289,14 @@ pub fn get_deadline(req: Request, params: Params) -> ApiResult<impl IntoResponse
 
 /// Mark deadline as completed
 #[utoipa::path(
-    patch,
+    post,
     path = "/api/deadlines/{id}/complete",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("id" = Uuid, Path, description = "Deadline ID")
     ),
     responses(
-        (status = 200, description = "Deadline marked as completed", body = Deadline),
+        (status = 200, description = "Deadline marked as completed; dependents are recounted from the completion date", body = Deadline),
         (status = 404, description = "Deadline not found"),
         (status = 400, description = "Invalid deadline ID")
     ),
@@ -284,6 +323,7 @@ pub fn complete_deadline(req: Request, params: Params) -> ApiResult<impl IntoRes
     let deadline = repo
         .find_deadline_by_id(id)?
         .ok_or_else(|| ApiError::NotFound("Deadline not found".to_string()))?;
+    deadline_chain_service::recalculate_dependents(&repo, &deadline, &closure_dates(&req)?, Utc::now())?;
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -368,8 +408,9 @@ pub fn request_extension(req: Request, params: Params) -> ApiResult<impl IntoRes
     ),
     request_body = RuleOnExtensionRequest,
     responses(
-        (status = 200, description = "Extension ruling recorded"),
+        (status = 200, description = "Extension ruling recorded; a grant also moves the deadline's dependents"),
         (status = 404, description = "Extension request not found"),
+        (status = 409, description = "Extension request has already been ruled on"),
         (status = 400, description = "Invalid request data")
     ),
     tag = "Extension Management",
@@ -393,28 +434,20 @@ pub fn rule_on_extension(req: Request, params: Params) -> ApiResult<impl IntoRes
 
 
     };
-    let status = request.status.clone();
-    repo.update_extension_status(extension_id, status)?;
-
-    // If there's order text, we could store it or process it
-    if let Some(order_text) = request.order_text {
-        // In a real system, this would be stored with the ruling
-        println!("Extension ruling order: {}", order_text);
-    }
+    let mut deadline = repo
+        .find_deadline_by_extension(extension_id)?
+        .ok_or_else(|| ApiError::NotFound("Extension request not found".to_string()))?;
+
+    // The ruling, due date change, and status update are saved as one record
+    deadline
+        .rule_on_extension(extension_id, request.status, request.order_text, Utc::now())
+        .map_err(ApiError::Conflict)?;
+    repo.save_deadline(&deadline)?;
 
-    // If granted, update the deadline's due date
-    if request.status == ExtensionStatus::Granted {
-        // Find the deadline with this extension
-        let deadlines = repo.find_deadlines_by_status(DeadlineStatus::Pending)?;
-        for mut deadline in deadlines {
-            if let Some(ext) = deadline.extension_requests.iter().find(|e| e.id == extension_id) {
-                if request.status == ExtensionStatus::Granted {
-                    deadline.due_date = ext.new_due_date;
-                    deadline.status = DeadlineStatus::Extended;
-                    repo.save_deadline(&deadline)?;
-                    break;
-                }
-            }
+    if let Some(extension) = deadline.extension_requests.iter().find(|e| e.id == extension_id) {
+        repo.save_extension(deadline.id, extension)?;
+        if extension.status == ExtensionStatus::Granted {
+            deadline_chain_service::recalculate_dependents(&repo, &deadline, &closure_dates(&req)?, Utc::now())?;
         }
     }
 
@@ -424,6 +457,93 @@ pub fn rule_on_extension(req: Request, params: Params) -> ApiResult<impl IntoRes
         .build())
 }
 
+/// Get the deadlines that depend on a deadline, directly or transitively
+#[utoipa::path(
+    get,
+    path = "/api/deadlines/{id}/dependents",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = Uuid, Path, description = "Deadline ID")
+    ),
+    responses(
+        (status = 200, description = "Dependent deadlines, nearest first", body = [Deadline]),
+        (status = 404, description = "Deadline not found"),
+        (status = 400, description = "Invalid deadline ID")
+    ),
+    tag = "Deadline Management",
+)]
+pub fn get_deadline_dependents(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let id = params
+        .get("id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid deadline ID".to_string()))?;
+
+    let repo = RepositoryFactory::deadline_repo(&req)?;
+    let deadline = repo
+        .find_deadline_by_id(id)?
+        .ok_or_else(|| ApiError::NotFound("Deadline not found".to_string()))?;
+    let dependents = deadline_chain_service::dependents(&repo, &deadline)?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&dependents)?)
+        .build())
+}
+
+/// Set or clear the deadline a deadline is counted from
+///
+/// Setting a parent recomputes the due date from it and moves this
+/// deadline's own dependents to match.
+#[utoipa::path(
+    put,
+    path = "/api/deadlines/{id}/dependency",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = Uuid, Path, description = "Deadline ID")
+    ),
+    request_body = SetDependencyRequest,
+    responses(
+        (status = 200, description = "Dependency updated", body = Deadline),
+        (status = 404, description = "Deadline not found"),
+        (status = 422, description = "Dependency is incomplete, names a deadline of another case or unknown deadline, or would form a cycle", body = crate::error::ErrorResponse)
+    ),
+    tag = "Deadline Management",
+)]
+pub fn set_deadline_dependency(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let id = params
+        .get("id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid deadline ID".to_string()))?;
+    let request: SetDependencyRequest = serde_json::from_slice(req.body())?;
+
+    let repo = RepositoryFactory::deadline_repo(&req)?;
+    let mut deadline = repo
+        .find_deadline_by_id(id)?
+        .ok_or_else(|| ApiError::NotFound("Deadline not found".to_string()))?;
+
+    let closures = closure_dates(&req)?;
+    let previous_due_date = deadline.due_date;
+    deadline.depends_on = request.depends_on;
+    deadline.offset_days = request.offset_days;
+    deadline_chain_service::apply_dependency(&repo, &mut deadline, &closures)?;
+    repo.save_deadline(&deadline)?;
+    if deadline.due_date != previous_due_date {
+        deadline_chain_service::recalculate_dependents(&repo, &deadline, &closures, Utc::now())?;
+    }
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&deadline)?)
+        .build())
+}
+
+/// District closure dates, which roll deadlines forward like federal holidays
+fn closure_dates(req: &Request) -> ApiResult<Vec<NaiveDate>> {
+    let district_id = tenant::get_config_district_id(req);
+    let config_repo = RepositoryFactory::config_repo(req)?;
+    futures::executor::block_on(config_repo.get_closure_dates(&district_id))
+}
+
 /// Get upcoming deadlines
 #[utoipa::path(
     get,
@@ -498,7 +618,7 @@ pub fn get_urgent_deadlines(req: Request, _params: Params) -> ApiResult<impl Int
 /// Calculate FRCP deadlines
 #[utoipa::path(
     post,
-    path = "/api/deadlines/calculate-frcp",
+    path = "/api/deadlines/calculate",
     request_body = CalculateDeadlinesRequest,
     responses(
         (status = 201, description = "FRCP deadlines calculated and created", body = [Deadline]),
@@ -514,9 +634,23 @@ pub fn calculate_frcp_deadlines(req: Request, _params: Params) -> ApiResult<impl
     let body = req.body();
     let request: CalculateDeadlinesRequest = serde_json::from_slice(body)?;
 
-    let mut deadlines = DeadlineCalculator::calculate_frcp_deadlines(
+    let repo = RepositoryFactory::deadline_repo(&req)?;
+
+    // Use the district's rule set, falling back to the built-in rules for
+    // districts that have not been initialized
+    let mut rules = repo.find_all_deadline_rules()?;
+    if rules.is_empty() {
+        rules = DeadlineCalculator::default_rule_set();
+    }
+
+    // District closures roll deadlines forward like federal holidays
+    let closures = closure_dates(&req)?;
+
+    let mut deadlines = DeadlineCalculator::calculate_from_rules(
+        &rules,
         &request.triggering_event,
         request.triggering_date,
+        &closures,
     );
 
     // Set the case ID for all calculated deadlines
@@ -525,13 +659,6 @@ pub fn calculate_frcp_deadlines(req: Request, _params: Params) -> ApiResult<impl
     }
 
     // Save all calculated deadlines
-    let repo = match RepositoryFactory::deadline_repo(&req) {
-
-        Ok(r) => r,
-
-        Err(e) => return Err(e),
-
-    };
     for deadline in &deadlines {
         repo.save_deadline(deadline)?;
     }
@@ -548,18 +675,22 @@ pub fn calculate_frcp_deadlines(req: Request, _params: Params) -> ApiResult<impl
     path = "/api/deadlines/search",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("case_id" = Option<Uuid>, Query, description = "Filter by case ID"),
-        ("type" = Option<String>, Query, description = "Filter by deadline type"),
-        ("status" = Option<String>, Query, description = "Filter by deadline status"),
-        ("party" = Option<String>, Query, description = "Filter by responsible party"),
-        ("jurisdictional" = Option<bool>, Query, description = "Filter by jurisdictional deadlines"),
-        ("from" = Option<String>, Query, description = "Due date from (RFC3339 format)"),
-        ("to" = Option<String>, Query, description = "Due date to (RFC3339 format)"),
+        ("case_id" = Option<Vec<Uuid>>, Query, description = "Filter by case ID; repeat or comma-separate to match any of several cases"),
+        ("type" = Option<Vec<String>>, Query, description = "Filter by deadline type; repeat or comma-separate to match any of several types"),
+        ("status" = Option<Vec<String>>, Query, description = "Filter by deadline status; repeat or comma-separate to match any of several statuses"),
+        ("responsible_party" = Option<String>, Query, description = "Filter by responsible party (case-insensitive substring); `party` is accepted as an alias"),
+        ("applicable_rule" = Option<String>, Query, description = "Filter by applicable rule (case-insensitive substring), e.g. `12(a)`"),
+        ("is_jurisdictional" = Option<bool>, Query, description = "Filter by jurisdictional deadlines; `jurisdictional` is accepted as an alias"),
+        ("due_after" = Option<String>, Query, description = "Due on or after (RFC3339 format); `from` is accepted as an alias"),
+        ("due_before" = Option<String>, Query, description = "Due on or before (RFC3339 format); `to` is accepted as an alias"),
+        ("sort_by" = Option<String>, Query, description = "Sort field: `due_date` (default), `created` or `case`"),
+        ("direction" = Option<String>, Query, description = "Sort direction: `asc` (default) or `desc`"),
         ("offset" = Option<usize>, Query, description = "Pagination offset"),
         ("limit" = Option<usize>, Query, description = "Pagination limit")
     ),
     responses(
-        (status = 200, description = "Search results with deadlines and total count"),
+        (status = 200, description = "A page of deadlines; also returned under the deprecated `deadlines` field", body = Page<Deadline>),
+        (status = 400, description = "Invalid filter value"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Deadline Management",
@@ -569,17 +700,31 @@ pub fn search_deadlines(req: Request, _params: Params) -> ApiResult<impl IntoRes
     let params = query_parser::parse_query_string(query_string);
 
     let query = DeadlineQuery {
-        case_id: query_parser::get_uuid(&params, "case_id"),
-        deadline_type: query_parser::get_json(&params, "type"),
-        status: query_parser::get_json(&params, "status"),
-        responsible_party: query_parser::get_string(&params, "party"),
-        is_jurisdictional: query_parser::get_bool(&params, "jurisdictional"),
-        due_date_from: query_parser::get_datetime(&params, "from"),
-        due_date_to: query_parser::get_datetime(&params, "to"),
+        case_ids: query_parser::get_uuid_vec(&params, "case_id")?,
+        deadline_types: query_parser::get_json_vec(&params, "type")?,
+        statuses: query_parser::get_json_vec(&params, "status")?,
+        responsible_party: query_parser::get_string(&params, "responsible_party")
+            .or_else(|| query_parser::get_string(&params, "party")),
+        applicable_rule: query_parser::get_string(&params, "applicable_rule"),
+        is_jurisdictional: query_parser::get_bool(&params, "is_jurisdictional")
+            .or_else(|| query_parser::get_bool(&params, "jurisdictional")),
+        due_date_from: query_parser::get_datetime(&params, "due_after")
+            .or_else(|| query_parser::get_datetime(&params, "from")),
+        due_date_to: query_parser::get_datetime(&params, "due_before")
+            .or_else(|| query_parser::get_datetime(&params, "to")),
+        sort_by: query_parser::get_string(&params, "sort_by")
+            .map(|v| query_parser::parse_enum_param("sort_by", &v))
+            .transpose()?
+            .unwrap_or_default(),
+        direction: query_parser::get_string(&params, "direction")
+            .map(|v| query_parser::parse_enum_param("direction", &v))
+            .transpose()?
+            .unwrap_or_default(),
         offset: query_parser::get_usize(&params, "offset").unwrap_or(0),
         limit: query_parser::get_usize(&params, "limit").unwrap_or(50),
     };
 
+    let (offset, limit) = (query.offset, query.limit);
     let repo = match RepositoryFactory::deadline_repo(&req) {
 
 
@@ -592,8 +737,7 @@ pub fn search_deadlines(req: Request, _params: Params) -> ApiResult<impl IntoRes
     };
     let (deadlines, total) = repo.search_deadlines(query)?;
 
-
-    let response = SearchResponse { deadlines, total };
+    let response = Page::new(deadlines, total, offset, limit).with_legacy_field("deadlines");
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -604,7 +748,7 @@ pub fn search_deadlines(req: Request, _params: Params) -> ApiResult<impl IntoRes
 /// Get compliance statistics
 #[utoipa::path(
     get,
-    path = "/api/deadlines/compliance-stats",
+    path = "/api/compliance/stats",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Option<Uuid>, Query, description = "Filter by case ID (optional)")
@@ -640,43 +784,65 @@ pub fn get_compliance_stats(req: Request, params: Params) -> ApiResult<impl Into
 }
 
 /// Generate compliance report
+///
+/// With `format=pdf` the report is rendered for submission to the
+/// Administrative Office: a summary table by deadline type, the trend
+/// against the prior period, and an appendix of missed jurisdictional
+/// deadlines.
 #[utoipa::path(
     get,
-    path = "/api/deadlines/compliance-report",
+    path = "/api/compliance/report",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("start" = Option<String>, Query, description = "Start date (RFC3339 format, defaults to 30 days ago)"),
-        ("end" = Option<String>, Query, description = "End date (RFC3339 format, defaults to now)")
+        ("period_start" = Option<String>, Query, description = "Start of the period (YYYY-MM-DD or RFC3339, defaults to 30 days ago)"),
+        ("period_end" = Option<String>, Query, description = "End of the period, inclusive (YYYY-MM-DD or RFC3339, defaults to now)"),
+        ("format" = Option<String>, Query, description = "json (default) or pdf")
     ),
     responses(
-        (status = 200, description = "Comprehensive compliance report"),
+        (status = 200, description = "Comprehensive compliance report", body = ComplianceReport),
+        (status = 200, description = "Compliance report for the Administrative Office", content_type = "application/pdf"),
+        (status = 400, description = "Invalid period or format"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Deadline Analytics",
 )]
-pub fn generate_compliance_report(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
-    let start_date = params
-        .get("start")
-        .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
-        .map(|d| d.with_timezone(&Utc))
-        .unwrap_or_else(|| Utc::now() - chrono::Duration::days(30));
-
-    let end_date = params
-        .get("end")
-        .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
-        .map(|d| d.with_timezone(&Utc))
-        .unwrap_or_else(Utc::now);
-
-    let repo = match RepositoryFactory::deadline_repo(&req) {
-
-
-        Ok(r) => r,
-
+pub fn generate_compliance_report(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    let query = query_parser::parse_query_string(req.query());
+    let as_pdf = match query_parser::get_string(&query, "format").as_deref() {
+        None | Some("json") => false,
+        Some("pdf") => true,
+        Some(other) => {
+            return Err(ApiError::BadRequest(format!(
+                "Invalid format '{}'. Expected json or pdf",
+                other
+            )))
+        }
+    };
 
-        Err(e) => return Err(e),
+    let start_date = report_period_bound(&query, "period_start", false)?
+        .unwrap_or_else(|| Utc::now() - chrono::Duration::days(30));
+    let end_date = report_period_bound(&query, "period_end", true)?.unwrap_or_else(Utc::now);
+    if end_date < start_date {
+        return Err(ApiError::BadRequest("period_end is before period_start".to_string()));
+    }
 
+    let repo = RepositoryFactory::deadline_repo(&req)?;
+
+    if as_pdf {
+        let report = deadline_service::load_compliance_report(&repo, start_date, end_date)?;
+        let request = deadline_service::compliance_report_document(report, &tenant::get_tenant_id(&req))
+            .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+        let generated = create_pdf_service(&tenant::get_tenant_id(&req))
+            .and_then(|service| service.generate_document_sync(request))
+            .map_err(|e| ApiError::Internal(e.to_string()))?;
+
+        return Ok(ResponseBuilder::new(200)
+            .header("content-type", "application/pdf")
+            .header("content-disposition", format!(r#"attachment; filename="{}""#, generated.filename))
+            .body(generated.pdf_data)
+            .build());
+    }
 
-    };
     let report = repo.generate_compliance_report(start_date, end_date)?;
 
     Ok(ResponseBuilder::new(200)
@@ -685,10 +851,30 @@ pub fn generate_compliance_report(req: Request, params: Params) -> ApiResult<imp
         .build())
 }
 
+/// Parse a report period bound given as a date or an RFC3339 timestamp
+///
+/// A bare date at the end of the period covers that whole day.
+fn report_period_bound(query: &[(&str, &str)], key: &str, end_of_day: bool) -> ApiResult<Option<DateTime<Utc>>> {
+    let Some(value) = query_parser::get_string(query, key) else {
+        return Ok(None);
+    };
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(&value) {
+        return Ok(Some(timestamp.with_timezone(&Utc)));
+    }
+    let date = chrono::NaiveDate::parse_from_str(&value, "%Y-%m-%d")
+        .map_err(|_| ApiError::BadRequest(format!("Invalid {}: {}", key, value)))?;
+    let time = if end_of_day {
+        chrono::NaiveTime::from_hms_opt(23, 59, 59)
+    } else {
+        chrono::NaiveTime::from_hms_opt(0, 0, 0)
+    };
+    Ok(time.map(|t| date.and_time(t).and_utc()))
+}
+
 /// Get performance metrics
 #[utoipa::path(
     get,
-    path = "/api/deadlines/performance-metrics",
+    path = "/api/compliance/performance",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("party" = Option<String>, Query, description = "Filter by responsible party")
@@ -723,7 +909,7 @@ pub fn get_performance_metrics(req: Request, params: Params) -> ApiResult<impl I
 /// Find missed jurisdictional deadlines
 #[utoipa::path(
     get,
-    path = "/api/deadlines/missed-jurisdictional",
+    path = "/api/compliance/missed-jurisdictional",
     responses(
         (status = 200, description = "List of missed jurisdictional deadlines", body = [Deadline]),
         (status = 500, description = "Internal server error")
@@ -752,7 +938,7 @@ pub fn get_missed_jurisdictional(req: Request, _params: Params) -> ApiResult<imp
 /// Get pending reminders
 #[utoipa::path(
     get,
-    path = "/api/deadlines/reminders/pending",
+    path = "/api/reminders/pending",
     responses(
         (status = 200, description = "List of pending deadline reminders"),
         (status = 500, description = "Internal server error")
@@ -770,7 +956,7 @@ pub fn get_pending_reminders(req: Request, _params: Params) -> ApiResult<impl In
         Err(e) => return Err(e),
 
     };
-    let reminders = repo.get_pending_reminders()?;
+    let reminders = repo.get_pending_reminders(&district_reminder_days(&req)?)?;
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -781,7 +967,7 @@ pub fn get_pending_reminders(req: Request, _params: Params) -> ApiResult<impl In
 /// Send reminders (typically called by a scheduled job)
 #[utoipa::path(
     post,
-    path = "/api/deadlines/reminders/send",
+    path = "/api/reminders/send",
     responses(
         (status = 200, description = "Reminders sent successfully"),
         (status = 500, description = "Internal server error")
@@ -799,7 +985,7 @@ pub fn send_reminders(req: Request, _params: Params) -> ApiResult<impl IntoRespo
         Err(e) => return Err(e),
 
     };
-    let reminders = repo.get_pending_reminders()?;
+    let reminders = repo.get_pending_reminders(&district_reminder_days(&req)?)?;
 
     // In a real system, this would send emails/notifications
     // For now, just save them as sent
@@ -817,10 +1003,22 @@ pub fn send_reminders(req: Request, _params: Params) -> ApiResult<impl IntoRespo
         .build())
 }
 
+/// Look up the district's reminder schedule from its merged configuration
+fn district_reminder_days(req: &Request) -> ApiResult<Vec<i64>> {
+    let district_id = crate::utils::tenant::get_config_district_id(req);
+    let config_repo = RepositoryFactory::config_repo(req)?;
+    let config = futures::executor::block_on(config_repo.get_merged_config(&district_id, None))?;
+
+    Ok(config
+        .get_array(DeadlineMonitor::REMINDER_DAYS_CONFIG_PATH)
+        .map(|days| days.iter().filter_map(|d| d.as_i64()).collect())
+        .unwrap_or_else(|| DeadlineMonitor::DEFAULT_REMINDER_DAYS.to_vec()))
+}
+
 /// Get deadlines by type for a case
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/deadlines/type/{type}",
+    path = "/api/deadlines/case/{case_id}/type/{type}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID"),
@@ -842,7 +1040,7 @@ pub fn get_deadlines_by_type(req: Request, params: Params) -> ApiResult<impl Int
         .get("type")
         .ok_or_else(|| ApiError::BadRequest("Deadline type required".to_string()))?;
 
-    let deadline_type: DeadlineType = serde_json::from_str(&format!("\"{}\"", deadline_type_str))?;
+    let deadline_type: DeadlineType = query_parser::parse_enum_param("type", deadline_type_str)?;
 
     let repo = match RepositoryFactory::deadline_repo(&req) {
 
@@ -898,7 +1096,22 @@ pub fn update_deadline_status(req: Request, params: Params) -> ApiResult<impl In
 
 
     };
-    repo.update_deadline_status(id, update.status)?;
+    let previous = repo.find_deadline_by_id(id)?;
+    repo.update_deadline_status(id, update.status.clone())?;
+
+    // Only the move into an urgent status is announced, not every update after it
+    if let Some(deadline) = previous.filter(|d| update.status.is_urgent() && !d.status.is_urgent()) {
+        webhook_service::notify(&req, WebhookEventType::DeadlineUrgent, serde_json::json!({
+            "deadline_id": deadline.id,
+            "case_id": deadline.case_id,
+            "deadline_type": deadline.deadline_type,
+            "description": deadline.description,
+            "due_date": deadline.due_date,
+            "is_jurisdictional": deadline.is_jurisdictional,
+            "previous_status": deadline.status,
+            "status": update.status,
+        }));
+    }
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -906,6 +1119,82 @@ pub fn update_deadline_status(req: Request, params: Params) -> ApiResult<impl In
         .build())
 }
 
+/// Recompute and persist deadline statuses
+///
+/// Reloads the deadlines for a case, or for the whole district when no case
+/// is given, recomputes each status against the current time, and saves the
+/// ones that changed. The district-wide refresh requires the admin token.
+#[utoipa::path(
+    post,
+    path = "/api/deadlines/refresh-statuses",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = Option<String>, Header, description = "Administrator token, required without case_id"),
+        ("case_id" = Option<Uuid>, Query, description = "Refresh only this case's deadlines")
+    ),
+    responses(
+        (status = 200, description = "Deadlines whose status changed", body = StatusRefreshResponse),
+        (status = 400, description = "Invalid case ID"),
+        (status = 403, description = "District-wide refresh without a valid admin token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Deadline Management",
+)]
+pub fn refresh_deadline_statuses(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    let query = query_parser::parse_query_string(req.query());
+    let case_id = match query_parser::get_string(&query, "case_id") {
+        Some(id) => Some(Uuid::parse_str(&id).map_err(|_| ApiError::BadRequest("Invalid case ID".to_string()))?),
+        None => {
+            access::require_admin(&req)?;
+            None
+        }
+    };
+
+    let repo = RepositoryFactory::deadline_repo(&req)?;
+    let (mut deadlines, _) = repo.search_deadlines(DeadlineQuery {
+        case_ids: case_id.into_iter().collect(),
+        limit: usize::MAX,
+        ..Default::default()
+    })?;
+
+    let transitions = DeadlineMonitor::refresh_statuses(&mut deadlines, Utc::now());
+
+    let mut by_status = std::collections::BTreeMap::new();
+    for transition in &transitions {
+        let status = serde_json::to_value(&transition.to)?.as_str().unwrap_or_default().to_string();
+        *by_status.entry(status).or_insert(0) += 1;
+
+        let Some(deadline) = deadlines.iter().find(|d| d.id == transition.deadline_id) else { continue };
+        repo.save_deadline(deadline)?;
+
+        // Announce moves into an urgent status, as a manual status update would
+        if transition.to.is_urgent() && !transition.from.is_urgent() {
+            webhook_service::notify(&req, WebhookEventType::DeadlineUrgent, serde_json::json!({
+                "deadline_id": deadline.id,
+                "case_id": deadline.case_id,
+                "deadline_type": deadline.deadline_type,
+                "description": deadline.description,
+                "due_date": deadline.due_date,
+                "is_jurisdictional": deadline.is_jurisdictional,
+                "previous_status": transition.from,
+                "status": transition.to,
+            }));
+        }
+    }
+
+    let response = StatusRefreshResponse {
+        checked: deadlines.len(),
+        transitioned: transitions.len(),
+        by_status,
+        transitions,
+    };
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&response)?)
+        .build())
+}
+
 /// Delete a deadline
 #[utoipa::path(
     delete,
@@ -948,7 +1237,7 @@ pub fn delete_deadline(req: Request, params: Params) -> ApiResult<impl IntoRespo
 /// Get reminders by deadline
 #[utoipa::path(
     get,
-    path = "/api/deadlines/{deadline_id}/reminders",
+    path = "/api/reminders/deadline/{deadline_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("deadline_id" = Uuid, Path, description = "Deadline ID")
@@ -986,7 +1275,7 @@ pub fn get_reminders_by_deadline(req: Request, params: Params) -> ApiResult<impl
 /// Get reminders by recipient
 #[utoipa::path(
     get,
-    path = "/api/deadlines/reminders/recipient/{recipient}",
+    path = "/api/reminders/recipient/{recipient}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("recipient" = String, Path, description = "Recipient name or email")
@@ -1022,8 +1311,8 @@ pub fn get_reminders_by_recipient(req: Request, params: Params) -> ApiResult<imp
 
 /// Acknowledge a reminder
 #[utoipa::path(
-    patch,
-    path = "/api/deadlines/reminders/{reminder_id}/acknowledge",
+    post,
+    path = "/api/reminders/{reminder_id}/acknowledge",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("reminder_id" = Uuid, Path, description = "Reminder ID")
@@ -1103,7 +1392,7 @@ pub fn get_extension_by_id(req: Request, params: Params) -> ApiResult<impl IntoR
 /// Get extensions by deadline
 #[utoipa::path(
     get,
-    path = "/api/deadlines/{deadline_id}/extensions",
+    path = "/api/extensions/deadline/{deadline_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("deadline_id" = Uuid, Path, description = "Deadline ID")
@@ -1176,7 +1465,7 @@ pub fn get_pending_extensions(req: Request, _params: Params) -> ApiResult<impl I
 /// Get federal rules database
 #[utoipa::path(
     get,
-    path = "/api/deadlines/federal-rules",
+    path = "/api/federal-rules",
     responses(
         (status = 200, description = "List of federal rules for deadline calculation", body = [FederalRule]),
         (status = 500, description = "Internal server error")
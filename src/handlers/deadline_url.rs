@@ -75,6 +75,24 @@ pub fn complete_deadline(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_deadline_dependents(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::deadline::get_deadline_dependents(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+pub fn set_deadline_dependency(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::deadline::set_deadline_dependency(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn get_upcoming_deadlines(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::deadline::get_upcoming_deadlines(req, params)
@@ -263,6 +281,15 @@ pub fn update_deadline_status(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn refresh_deadline_statuses(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::deadline::refresh_deadline_statuses(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn delete_deadline(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::deadline::delete_deadline(req, params)
@@ -3,22 +3,40 @@
 //! This module provides HTTP endpoints for managing docket entries,
 //! court calendar, and Speedy Trial Act compliance.
 
+use crate::adapters::deadline_engine_impl::FrcpDeadlineEngine;
 use crate::utils::repository_factory::RepositoryFactory;
 use crate::domain::docket::{
     DocketEntry, DocketEntryType, DocketAttachment, CalendarEntry,
     CalendarEventType, EventStatus, SpeedyTrialClock, ExcludableDelay,
-    DelayReason, DocketService, CalendarService, SpeedyTrialService
+    DelayReason, DocketService, CalendarService, SpeedyTrialService,
+    SpeedyTrialViolation, EventNotificationList, EventParticipant, HearingMinutes, CaseAttachmentSize
 };
+use crate::domain::daily_calendar::{title_case, CaseCaption, DailyCalendar};
+use crate::domain::document::DefendantNames;
+use crate::domain::judge_unavailability::{self, Unavailability};
 use crate::error::{ApiError, ApiResult};
+use crate::handlers::pdf_hexagonal::MinuteEntryRequest;
+use crate::ports::attorney_repository::AttorneyRepository;
+use crate::ports::case_repository::CaseRepository;
+use crate::ports::config_repository::ConfigRepository;
+use crate::ports::deadline_engine::DeadlineEngine;
+use crate::ports::document_repository::{AttachmentContentRepository, StoredAttachment};
+use crate::ports::judge_repository::JudgeRepository;
 use crate::ports::docket_repository::{
     DocketRepository, CalendarRepository, SpeedyTrialRepository,
     DocketQuery, DocketQueryRepository,
     CalendarSchedulingRepository
 };
+use crate::services::conditional_read_service::{self, Conditional};
+use crate::services::attachment_service::{self, AttachmentLimits};
+use crate::services::event_notification_service;
+use crate::utils::access::{self, AccessLevel};
+use crate::utils::json_response::Page;
 use crate::utils::query_parser;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
-use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
+use std::collections::{HashMap, HashSet};
+use spin_sdk::http::{IntoResponse, Params, Request, Response, ResponseBuilder};
 use uuid::Uuid;
 use utoipa::ToSchema;
 
@@ -30,13 +48,6 @@ pub struct InitSpeedyTrialRequest {
     pub arraignment_date: Option<DateTime<Utc>>,
 }
 
-/// Search response for docket entries
-#[derive(Serialize, ToSchema)]
-pub struct SearchResponse {
-    pub entries: Vec<DocketEntry>,
-    pub total: usize,
-}
-
 /// Response for available court slot
 #[derive(Serialize, ToSchema)]
 pub struct AvailableSlotResponse {
@@ -45,13 +56,6 @@ pub struct AvailableSlotResponse {
     pub duration_minutes: u32,
 }
 
-/// Search response for calendar entries
-#[derive(Serialize, ToSchema)]
-pub struct CalendarSearchResponse {
-    pub events: Vec<CalendarEntry>,
-    pub total: usize,
-}
-
 /// Request model for creating a docket entry
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateDocketEntryRequest {
@@ -63,6 +67,9 @@ pub struct CreateDocketEntryRequest {
     pub is_ex_parte: bool,
     pub page_count: Option<u32>,
     pub service_list: Vec<String>,
+    /// Entry numbers this entry responds to or rules on
+    #[serde(default)]
+    pub related_entries: Vec<u32>,
 }
 
 /// Request model for adding an attachment
@@ -84,8 +91,13 @@ pub struct ScheduleEventRequest {
     pub duration_minutes: u32,
     pub courtroom: String,
     pub description: String,
-    pub participants: Vec<String>,
+    /// Attorneys, parties and others expected at the event; plain names are
+    /// accepted for participants without an attorney or party record
+    pub participants: Vec<EventParticipant>,
     pub is_public: bool,
+    /// Schedule even if the district's minimum notice period is not met
+    #[serde(default)]
+    pub waive_notice: bool,
 }
 
 /// Request model for updating event status
@@ -95,6 +107,19 @@ pub struct UpdateEventStatusRequest {
     pub actual_start: Option<DateTime<Utc>>,
     pub actual_end: Option<DateTime<Utc>>,
     pub notes: Option<String>,
+    /// Hearing outcome, accepted only when completing the event
+    #[serde(default)]
+    pub minutes: Option<HearingMinutes>,
+}
+
+/// Response model for an event status update
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventStatusUpdateResponse {
+    #[serde(flatten)]
+    pub event: CalendarEntry,
+    /// Who to inform, included when the event is cancelled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_list: Option<EventNotificationList>,
 }
 
 /// Request model for adding excludable delay
@@ -161,6 +186,7 @@ pub fn create_docket_entry(req: Request, _params: Params) -> ApiResult<impl Into
     entry.is_ex_parte = request.is_ex_parte;
     entry.page_count = request.page_count;
     entry.service_list = request.service_list;
+    entry.related_entries = request.related_entries;
 
     let repo = RepositoryFactory::docket_repo(&req)?;
     repo.save_entry(&entry)?;
@@ -174,13 +200,16 @@ pub fn create_docket_entry(req: Request, _params: Params) -> ApiResult<impl Into
 /// Get docket entries for a case
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/docket",
+    path = "/api/docket/case/{case_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("case_id" = Uuid, Path, description = "Case ID")
+        ("case_id" = Uuid, Path, description = "Case ID"),
+        ("X-Access-Level" = Option<String>, Header, description = "Caller access level: public (default), attorney, chambers or sealed-authorized"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag from an earlier response; answered with 304 if the docket is unchanged")
     ),
     responses(
-        (status = 200, description = "List of docket entries for the case", body = [DocketEntry]),
+        (status = 200, description = "List of docket entries for the case visible at the caller's access level, with an ETag", body = [DocketEntry]),
+        (status = 304, description = "Docket unchanged since the ETag in If-None-Match"),
         (status = 400, description = "Invalid case ID"),
         (status = 500, description = "Internal server error")
     ),
@@ -192,13 +221,11 @@ pub fn get_case_docket(req: Request, params: Params) -> ApiResult<impl IntoRespo
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid case ID".to_string()))?;
 
+    let access = access::get_access_level(&req)?;
     let repo = RepositoryFactory::docket_repo(&req)?;
-    let entries = repo.find_entries_by_case(case_id)?;
+    let result = conditional_read_service::case_docket(&repo, case_id, access, if_none_match(&req))?;
 
-    Ok(ResponseBuilder::new(200)
-        .header("content-type", "application/json")
-        .body(serde_json::to_vec(&entries)?)
-        .build())
+    conditional_response(result)
 }
 
 /// Get a specific docket entry
@@ -207,10 +234,12 @@ pub fn get_case_docket(req: Request, params: Params) -> ApiResult<impl IntoRespo
     path = "/api/docket/entries/{id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("id" = Uuid, Path, description = "Docket entry ID")
+        ("id" = Uuid, Path, description = "Docket entry ID"),
+        ("X-Access-Level" = Option<String>, Header, description = "Caller access level: public (default), attorney, chambers or sealed-authorized")
     ),
     responses(
         (status = 200, description = "Docket entry found", body = DocketEntry),
+        (status = 403, description = "Entry is sealed or ex parte and the caller is not authorized; references the sealing order"),
         (status = 404, description = "Docket entry not found"),
         (status = 400, description = "Invalid entry ID")
     ),
@@ -222,17 +251,84 @@ pub fn get_docket_entry(req: Request, params: Params) -> ApiResult<impl IntoResp
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid entry ID".to_string()))?;
 
+    let access = access::get_access_level(&req)?;
     let repo = RepositoryFactory::docket_repo(&req)?;
     let entry = repo
         .find_entry_by_id(id)?
         .ok_or_else(|| ApiError::NotFound("Docket entry not found".to_string()))?;
 
+    if !access.can_view(entry.is_sealed, entry.is_ex_parte) {
+        return Err(restricted_entry_error(&repo, &entry, access)?);
+    }
+
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
         .body(serde_json::to_vec(&entry)?)
         .build())
 }
 
+/// The request's `If-None-Match` header, if any
+fn if_none_match(req: &Request) -> Option<&str> {
+    req.header("if-none-match").and_then(|v| v.as_str())
+}
+
+/// 304 with the current ETag, or the list tagged with it
+fn conditional_response<T: Serialize>(result: Conditional<T>) -> ApiResult<Response> {
+    Ok(match result {
+        Conditional::NotModified { etag } => ResponseBuilder::new(304)
+            .header("etag", etag)
+            .body(Vec::new())
+            .build(),
+        Conditional::Modified { etag, body } => ResponseBuilder::new(200)
+            .header("content-type", "application/json")
+            .header("etag", etag)
+            .body(serde_json::to_vec(&body)?)
+            .build(),
+    })
+}
+
+/// Drop entries the caller's access level does not cover
+fn visible_entries(mut entries: Vec<DocketEntry>, access: AccessLevel) -> Vec<DocketEntry> {
+    entries.retain(|e| access.can_view(e.is_sealed, e.is_ex_parte));
+    entries
+}
+
+/// 403 for a restricted entry requested by id
+///
+/// Sealed entries point at the sealing order that covers them (the one listing
+/// the entry in `related_entries`, otherwise the case's latest sealing order)
+/// so parties know the entry exists, as PACER does.
+fn restricted_entry_error(
+    repo: &impl DocketRepository,
+    entry: &DocketEntry,
+    access: AccessLevel,
+) -> ApiResult<ApiError> {
+    if entry.is_sealed && !access.can_view_sealed() {
+        let orders = repo.find_entries_by_type(entry.case_id, DocketEntryType::SealingOrder)?;
+        let order = orders
+            .iter()
+            .filter(|o| o.id != entry.id)
+            .find(|o| o.related_entries.contains(&entry.entry_number))
+            .or_else(|| orders.iter().filter(|o| o.id != entry.id).max_by_key(|o| o.entry_number));
+
+        let reference = match order {
+            Some(order) => format!("see sealing order at docket entry {}", order.entry_number),
+            None => "sealed by order of the court".to_string(),
+        };
+        return Ok(ApiError::Forbidden(format!(
+            "Docket entry {} is sealed; {}. Access level '{}' is not authorized to view sealed material",
+            entry.entry_number,
+            reference,
+            access.as_str()
+        )));
+    }
+
+    Ok(ApiError::Forbidden(format!(
+        "Docket entry {} is ex parte and restricted to chambers",
+        entry.entry_number
+    )))
+}
+
 /// Add attachment to docket entry
 #[utoipa::path(
     post,
@@ -245,7 +341,7 @@ pub fn get_docket_entry(req: Request, params: Params) -> ApiResult<impl IntoResp
     responses(
         (status = 201, description = "Attachment added successfully", body = DocketAttachment),
         (status = 404, description = "Docket entry not found"),
-        (status = 400, description = "Invalid request data")
+        (status = 400, description = "Invalid request data, or a size or page count that is zero or over the district's limit")
     ),
     tag = "Docket Management",
 )]
@@ -257,6 +353,7 @@ pub fn add_attachment(req: Request, params: Params) -> ApiResult<impl IntoRespon
 
     let body = req.body();
     let request: AddAttachmentRequest = serde_json::from_slice(body)?;
+    attachment_service::validate_declared(request.file_size_bytes, request.page_count, &attachment_limits(&req)?)?;
 
     let repo = RepositoryFactory::docket_repo(&req)?;
     let mut entry = repo
@@ -269,6 +366,9 @@ pub fn add_attachment(req: Request, params: Params) -> ApiResult<impl IntoRespon
         description: request.description,
         page_count: request.page_count,
         file_size_bytes: request.file_size_bytes,
+        file_name: None,
+        content_type: None,
+        sha256: None,
     };
 
     entry.attachments.push(attachment.clone());
@@ -280,6 +380,223 @@ pub fn add_attachment(req: Request, params: Params) -> ApiResult<impl IntoRespon
         .build())
 }
 
+/// The district's limits on a single attachment
+fn attachment_limits(req: &Request) -> ApiResult<AttachmentLimits> {
+    let district_id = crate::utils::tenant::get_config_district_id(req);
+    let config_repo = RepositoryFactory::config_repo(req)?;
+    let config = futures::executor::block_on(config_repo.get_merged_config(&district_id, None))?;
+    Ok(AttachmentLimits::from_config(&config))
+}
+
+/// Get the total size of a case's docket attachments
+#[utoipa::path(
+    get,
+    path = "/api/cases/{case_id}/attachment-size",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("case_id" = Uuid, Path, description = "Case ID")
+    ),
+    responses(
+        (status = 200, description = "Attachment count, bytes and pages across the case's docket", body = CaseAttachmentSize),
+        (status = 400, description = "Invalid case ID"),
+        (status = 404, description = "Case not found")
+    ),
+    tag = "Docket Management",
+)]
+pub fn get_case_attachment_size(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let case_id = params
+        .get("case_id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid case ID".to_string()))?;
+
+    RepositoryFactory::case_repo(&req)?
+        .find_by_id(case_id)?
+        .ok_or_else(|| ApiError::NotFound("Case not found".to_string()))?;
+
+    let repo = RepositoryFactory::docket_repo(&req)?;
+    let size = repo.case_attachment_size(case_id)?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&size)?)
+        .build())
+}
+
+/// Upload the file for a new docket entry attachment
+///
+/// The body is the raw file. Attachment details travel in headers so the
+/// upload is a single binary request.
+#[utoipa::path(
+    post,
+    path = "/api/docket/entries/{entry_id}/attachments/upload",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("entry_id" = Uuid, Path, description = "Docket entry ID"),
+        ("X-Attachment-Description" = String, Header, description = "Description of the attachment, e.g. `Exhibit A`"),
+        ("X-Attachment-Number" = Option<u32>, Header, description = "Attachment number; defaults to the next on the entry"),
+        ("X-Page-Count" = Option<u32>, Header, description = "Number of pages"),
+        ("X-File-Name" = Option<String>, Header, description = "File name offered on download"),
+        ("allow_non_pdf" = Option<bool>, Query, description = "Accept files that are not PDFs")
+    ),
+    request_body(content = Vec<u8>, content_type = "application/pdf"),
+    responses(
+        (status = 201, description = "Attachment stored; metadata includes its SHA-256 digest", body = DocketAttachment),
+        (status = 400, description = "Missing description, empty body, or a page count that is zero or over the district's limit", body = crate::error::ErrorResponse),
+        (status = 404, description = "Docket entry not found", body = crate::error::ErrorResponse),
+        (status = 413, description = "File exceeds the district's size limit", body = crate::error::ErrorResponse),
+        (status = 415, description = "File is not a PDF and the override is not set", body = crate::error::ErrorResponse)
+    ),
+    tag = "Docket Management",
+)]
+pub fn upload_attachment(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let entry_id = params
+        .get("entry_id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid entry ID".to_string()))?;
+
+    let header = |name: &str| {
+        req.header(name)
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
+    let number_header = |name: &str| -> ApiResult<Option<u32>> {
+        header(name)
+            .map(|v| v.parse::<u32>().map_err(|_| ApiError::BadRequest(format!("Invalid {} '{}'", name, v))))
+            .transpose()
+    };
+
+    let description = header("x-attachment-description")
+        .ok_or_else(|| ApiError::BadRequest("X-Attachment-Description header is required".to_string()))?;
+    let attachment_number = number_header("x-attachment-number")?;
+    let page_count = number_header("x-page-count")?.unwrap_or(0);
+    let content_type = header("content-type");
+    let query = query_parser::parse_query_string(req.query());
+    let allow_non_pdf = query_parser::get_bool(&query, "allow_non_pdf").unwrap_or(false);
+
+    let repo = RepositoryFactory::docket_repo(&req)?;
+    let mut entry = repo
+        .find_entry_by_id(entry_id)?
+        .ok_or_else(|| ApiError::NotFound("Docket entry not found".to_string()))?;
+
+    let content = req.body();
+    let limits = attachment_limits(&req)?;
+    attachment_service::validate_upload(content, content_type.as_deref(), limits.max_bytes, allow_non_pdf)?;
+    if page_count != 0 {
+        attachment_service::validate_page_count(page_count, &limits)?;
+    }
+
+    let attachment_number = attachment_number.unwrap_or_else(|| {
+        entry.attachments.iter().map(|a| a.attachment_number).max().unwrap_or(0) + 1
+    });
+    if entry.attachments.iter().any(|a| a.attachment_number == attachment_number) {
+        return Err(ApiError::Conflict(format!(
+            "Docket entry {} already has attachment {}",
+            entry.entry_number, attachment_number
+        )));
+    }
+
+    let content_type = content_type.unwrap_or_else(|| "application/octet-stream".to_string());
+    let file_name = header("x-file-name")
+        .map(|name| download_file_name(&name))
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| {
+            let extension = if content.starts_with(b"%PDF-") { ".pdf" } else { "" };
+            format!("entry-{}-attachment-{}{}", entry.entry_number, attachment_number, extension)
+        });
+
+    let attachment = DocketAttachment {
+        id: Uuid::new_v4(),
+        attachment_number,
+        description,
+        page_count,
+        file_size_bytes: content.len() as u64,
+        file_name: Some(file_name),
+        content_type: Some(content_type),
+        sha256: Some(attachment_service::sha256_hex(content)),
+    };
+
+    let document_repo = RepositoryFactory::document_repo(&req)?;
+    document_repo.save_attachment_content(&StoredAttachment {
+        entry_id,
+        attachment: attachment.clone(),
+        content: content.to_vec(),
+    })?;
+
+    entry.attachments.push(attachment.clone());
+    repo.save_entry(&entry)?;
+
+    Ok(ResponseBuilder::new(201)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&attachment)?)
+        .build())
+}
+
+/// Download an uploaded attachment file
+#[utoipa::path(
+    get,
+    path = "/api/docket/attachments/{id}/download",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = Uuid, Path, description = "Attachment ID"),
+        ("X-Access-Level" = Option<String>, Header, description = "Caller access level: public (default), attorney, chambers or sealed-authorized")
+    ),
+    responses(
+        (status = 200, description = "The attachment file", body = Vec<u8>, content_type = "application/pdf"),
+        (status = 403, description = "The attachment's entry is sealed or ex parte and the caller is not authorized", body = crate::error::ErrorResponse),
+        (status = 404, description = "No uploaded file for the attachment", body = crate::error::ErrorResponse),
+        (status = 500, description = "Stored file fails its SHA-256 check", body = crate::error::ErrorResponse)
+    ),
+    tag = "Docket Management",
+)]
+pub fn download_attachment(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let id = params
+        .get("id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid attachment ID".to_string()))?;
+
+    let access = access::get_access_level(&req)?;
+    let document_repo = RepositoryFactory::document_repo(&req)?;
+    let stored = document_repo
+        .find_attachment_content(id)?
+        .ok_or_else(|| ApiError::NotFound("Attachment file not found".to_string()))?;
+
+    let repo = RepositoryFactory::docket_repo(&req)?;
+    let entry = repo
+        .find_entry_by_id(stored.entry_id)?
+        .ok_or_else(|| ApiError::NotFound("Docket entry for attachment not found".to_string()))?;
+    if !access.can_view(entry.is_sealed, entry.is_ex_parte) {
+        return Err(restricted_entry_error(&repo, &entry, access)?);
+    }
+
+    attachment_service::verify(&stored)?;
+
+    let attachment = &stored.attachment;
+    let content_type = attachment.content_type.clone().unwrap_or_else(|| "application/octet-stream".to_string());
+    let file_name = attachment
+        .file_name
+        .clone()
+        .unwrap_or_else(|| format!("attachment-{}", attachment.attachment_number));
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", content_type)
+        .header("content-disposition", format!(r#"attachment; filename="{}""#, file_name))
+        .header("x-content-sha256", attachment.sha256.clone().unwrap_or_default())
+        .body(stored.content)
+        .build())
+}
+
+/// File name safe to place in a `Content-Disposition` header
+fn download_file_name(name: &str) -> String {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or(name);
+    base.chars()
+        .filter(|c| !c.is_control() && *c != '"')
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
 /// Search docket entries
 #[utoipa::path(
     get,
@@ -287,17 +604,19 @@ pub fn add_attachment(req: Request, params: Params) -> ApiResult<impl IntoRespon
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Option<Uuid>, Query, description = "Filter by case ID"),
-        ("entry_type" = Option<String>, Query, description = "Filter by entry type"),
+        ("entry_type" = Option<Vec<String>>, Query, description = "Filter by entry type; repeat or comma-separate to match any of several types"),
         ("filed_by" = Option<String>, Query, description = "Filter by who filed the entry"),
         ("sealed_only" = Option<bool>, Query, description = "Show only sealed entries"),
         ("date_from" = Option<String>, Query, description = "Start date filter (RFC3339 format)"),
         ("date_to" = Option<String>, Query, description = "End date filter (RFC3339 format)"),
         ("search" = Option<String>, Query, description = "Text search in description"),
         ("offset" = Option<usize>, Query, description = "Pagination offset"),
-        ("limit" = Option<usize>, Query, description = "Pagination limit")
+        ("limit" = Option<usize>, Query, description = "Pagination limit"),
+        ("X-Access-Level" = Option<String>, Header, description = "Caller access level: public (default), attorney, chambers or sealed-authorized")
     ),
     responses(
-        (status = 200, description = "Search results with entries and total count"),
+        (status = 200, description = "A page of docket entries; also returned under the deprecated `entries` field", body = Page<DocketEntry>),
+        (status = 400, description = "Invalid filter value"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Docket Management",
@@ -305,24 +624,27 @@ pub fn add_attachment(req: Request, params: Params) -> ApiResult<impl IntoRespon
 pub fn search_docket(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
     let query_string = req.query();
     let params = query_parser::parse_query_string(query_string);
+    let access = access::get_access_level(&req)?;
 
     let query = DocketQuery {
         case_id: query_parser::get_uuid(&params, "case_id"),
-        entry_type: query_parser::get_json(&params, "entry_type"),
+        entry_types: query_parser::get_json_vec(&params, "entry_type")?,
         filed_by: query_parser::get_string(&params, "filed_by"),
         sealed_only: query_parser::get_bool(&params, "sealed_only").unwrap_or(false),
         date_from: query_parser::get_datetime(&params, "date_from"),
         date_to: query_parser::get_datetime(&params, "date_to"),
         search_text: query_parser::get_string(&params, "search"),
+        include_sealed: access.can_view_sealed(),
+        include_ex_parte: access.can_view_ex_parte(),
         offset: query_parser::get_usize(&params, "offset").unwrap_or(0),
         limit: query_parser::get_usize(&params, "limit").unwrap_or(50),
     };
 
+    let (offset, limit) = (query.offset, query.limit);
     let repo = RepositoryFactory::docket_repo(&req)?;
     let (entries, total) = repo.search_docket(query)?;
 
-
-    let response = SearchResponse { entries, total };
+    let response = Page::new(entries, total, offset, limit).with_legacy_field("entries");
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -333,10 +655,11 @@ pub fn search_docket(req: Request, _params: Params) -> ApiResult<impl IntoRespon
 /// Generate docket sheet
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/docket-sheet",
+    path = "/api/docket/sheet/{case_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("case_id" = Uuid, Path, description = "Case ID")
+        ("case_id" = Uuid, Path, description = "Case ID"),
+        ("X-Access-Level" = Option<String>, Header, description = "Caller access level: public (default), attorney, chambers or sealed-authorized")
     ),
     responses(
         (status = 200, description = "Generated docket sheet", content_type = "text/plain"),
@@ -351,8 +674,9 @@ pub fn generate_docket_sheet(req: Request, params: Params) -> ApiResult<impl Int
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid case ID".to_string()))?;
 
+    let access = access::get_access_level(&req)?;
     let repo = RepositoryFactory::docket_repo(&req)?;
-    let docket_sheet = repo.generate_docket_sheet(case_id)?;
+    let docket_sheet = repo.generate_docket_sheet(case_id, access.can_view_sealed(), access.can_view_ex_parte())?;
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "text/plain")
@@ -367,7 +691,7 @@ pub fn generate_docket_sheet(req: Request, params: Params) -> ApiResult<impl Int
     request_body = ScheduleEventRequest,
     responses(
         (status = 201, description = "Event scheduled successfully", body = CalendarEntry),
-        (status = 400, description = "Invalid request data or schedule conflict"),
+        (status = 400, description = "Invalid request data, schedule conflict, judge unavailable, or insufficient notice"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Calendar Management",
@@ -379,6 +703,27 @@ pub fn schedule_event(req: Request, _params: Params) -> ApiResult<impl IntoRespo
     let body = req.body();
     let request: ScheduleEventRequest = serde_json::from_slice(body)?;
 
+    if !request.waive_notice {
+        let required = minimum_notice_days(&req, &request.event_type)?;
+        let engine = FrcpDeadlineEngine::with_closures(closure_dates(&req)?);
+        let given = CalendarService::business_days_of_notice(
+            Utc::now().date_naive(),
+            request.scheduled_date.date_naive(),
+            |day| engine.is_business_day(day),
+        );
+
+        if given < required {
+            return Err(ApiError::BadRequest(format!(
+                "Insufficient notice for {}: {} business days required, {} given (short by {}). \
+                 Set waive_notice to schedule anyway.",
+                request.event_type.config_key(),
+                required,
+                given,
+                required - given
+            )));
+        }
+    }
+
     let mut event = CalendarService::schedule_event(
         request.case_id,
         request.judge_id,
@@ -392,14 +737,18 @@ pub fn schedule_event(req: Request, _params: Params) -> ApiResult<impl IntoRespo
     event.participants = request.participants;
     event.is_public = request.is_public;
 
+    let end = request.scheduled_date + chrono::Duration::minutes(request.duration_minutes as i64);
+    if let Some(away) = judge_unavailable(&req, request.judge_id, request.scheduled_date, end)? {
+        return Err(ApiError::BadRequest(format!(
+            "Schedule conflict: the judge is unavailable ({:?}) from {} to {}",
+            away.unavailability_type, away.start, away.end
+        )));
+    }
+
     let repo = RepositoryFactory::docket_repo(&req)?;
 
     // Check for conflicts
-    let conflicts = repo.find_conflicts(
-        request.judge_id,
-        request.scheduled_date,
-        request.scheduled_date + chrono::Duration::minutes(request.duration_minutes as i64),
-    )?;
+    let conflicts = repo.find_conflicts(request.judge_id, request.scheduled_date, end)?;
 
     if !conflicts.is_empty() {
         return Err(ApiError::BadRequest(format!(
@@ -408,11 +757,12 @@ pub fn schedule_event(req: Request, _params: Params) -> ApiResult<impl IntoRespo
         )));
     }
 
-    repo.save_event(&event)?;
-
-    // Generate automatic docket entry
-    let docket_entry = DocketService::generate_minute_entry(&event);
-    repo.save_entry(&docket_entry)?;
+    // The event and its automatic minute entry land together or not at all
+    repo.transaction(|repo| -> ApiResult<()> {
+        repo.save_event(&event)?;
+        repo.save_entry(&DocketService::generate_minute_entry(&event))?;
+        Ok(())
+    })?;
 
     Ok(ResponseBuilder::new(201)
         .header("content-type", "application/json")
@@ -420,10 +770,48 @@ pub fn schedule_event(req: Request, _params: Params) -> ApiResult<impl IntoRespo
         .build())
 }
 
+/// The judge's unavailability entry overlapping a span, if any
+///
+/// Events may name a judge that isn't on the district's roster; such a
+/// judge has no recorded unavailability.
+fn judge_unavailable(
+    req: &Request,
+    judge_id: Uuid,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> ApiResult<Option<Unavailability>> {
+    let judge = RepositoryFactory::judge_repo(req)?.find_judge_by_id(judge_id)?;
+    Ok(judge.and_then(|j| j.unavailable_between(&start, &end).cloned()))
+}
+
+/// Minimum notice for an event type from the district's configuration,
+/// falling back to the built-in default
+fn minimum_notice_days(req: &Request, event_type: &CalendarEventType) -> ApiResult<i64> {
+    let district_id = crate::utils::tenant::get_config_district_id(req);
+    let config_repo = RepositoryFactory::config_repo(req)?;
+    let config = futures::executor::block_on(config_repo.get_merged_config(&district_id, None))?;
+
+    let path = format!(
+        "{}.{}",
+        CalendarEventType::MINIMUM_NOTICE_CONFIG_PATH,
+        event_type.config_key()
+    );
+    Ok(config
+        .get_i64(&path)
+        .unwrap_or_else(|| event_type.default_minimum_notice_days()))
+}
+
+/// The district's special closure dates
+fn closure_dates(req: &Request) -> ApiResult<Vec<NaiveDate>> {
+    let district_id = crate::utils::tenant::get_config_district_id(req);
+    let config_repo = RepositoryFactory::config_repo(req)?;
+    futures::executor::block_on(config_repo.get_closure_dates(&district_id))
+}
+
 /// Get calendar events for a case
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/calendar",
+    path = "/api/calendar/case/{case_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID")
@@ -453,14 +841,16 @@ pub fn get_case_calendar(req: Request, params: Params) -> ApiResult<impl IntoRes
 /// Get judge's schedule
 #[utoipa::path(
     get,
-    path = "/api/judges/{judge_id}/schedule",
+    path = "/api/calendar/judge/{judge_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("judge_id" = Uuid, Path, description = "Judge ID"),
-        ("date" = Option<String>, Query, description = "Date to get schedule for (RFC3339 format, defaults to today)")
+        ("date" = Option<String>, Query, description = "Date to get schedule for (RFC3339 format, defaults to today)"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag from an earlier response; answered with 304 if the schedule is unchanged")
     ),
     responses(
-        (status = 200, description = "Judge's schedule for the specified date", body = [CalendarEntry]),
+        (status = 200, description = "Judge's schedule for the specified date, with an ETag", body = [CalendarEntry]),
+        (status = 304, description = "Schedule unchanged since the ETag in If-None-Match"),
         (status = 400, description = "Invalid judge ID"),
         (status = 500, description = "Internal server error")
     ),
@@ -479,12 +869,9 @@ pub fn get_judge_schedule(req: Request, params: Params) -> ApiResult<impl IntoRe
         .unwrap_or_else(Utc::now);
 
     let repo = RepositoryFactory::docket_repo(&req)?;
-    let events = repo.get_judge_schedule(judge_id, date)?;
+    let result = conditional_read_service::judge_schedule(&repo, judge_id, date, if_none_match(&req))?;
 
-    Ok(ResponseBuilder::new(200)
-        .header("content-type", "application/json")
-        .body(serde_json::to_vec(&events)?)
-        .build())
+    conditional_response(result)
 }
 
 /// Update event status
@@ -497,9 +884,9 @@ pub fn get_judge_schedule(req: Request, params: Params) -> ApiResult<impl IntoRe
     ),
     request_body = UpdateEventStatusRequest,
     responses(
-        (status = 200, description = "Event status updated", body = CalendarEntry),
+        (status = 200, description = "Event status updated; cancellations include the notification list", body = EventStatusUpdateResponse),
         (status = 404, description = "Event not found"),
-        (status = 400, description = "Invalid request data")
+        (status = 400, description = "Invalid request data, minutes for an event that is not completing, or appearances naming unknown attorneys")
     ),
     tag = "Calendar Management",
 )]
@@ -512,6 +899,15 @@ pub fn update_event_status(req: Request, params: Params) -> ApiResult<impl IntoR
     let body = req.body();
     let request: UpdateEventStatusRequest = serde_json::from_slice(body)?;
 
+    if let Some(ref minutes) = request.minutes {
+        if request.status != EventStatus::Completed {
+            return Err(ApiError::BadRequest(
+                "Minutes can only be recorded when completing an event".to_string(),
+            ));
+        }
+        check_appearances(&req, minutes)?;
+    }
+
     let repo = RepositoryFactory::docket_repo(&req)?;
 
     // First update just the status using the dedicated method
@@ -531,9 +927,16 @@ pub fn update_event_status(req: Request, params: Params) -> ApiResult<impl IntoR
     if let Some(ref notes) = request.notes {
         event.notes = notes.clone();
     }
+    let has_minutes = request.minutes.is_some();
+    if let Some(minutes) = request.minutes {
+        if minutes.court_reporter.is_some() {
+            event.court_reporter = minutes.court_reporter.clone();
+        }
+        event.minutes = Some(minutes);
+    }
 
     // Save the event with additional updates if any were made
-    if request.actual_start.is_some() || request.actual_end.is_some() || request.notes.is_some() {
+    if request.actual_start.is_some() || request.actual_end.is_some() || request.notes.is_some() || has_minutes {
         repo.save_event(&event)?;
     }
 
@@ -543,16 +946,147 @@ pub fn update_event_status(req: Request, params: Params) -> ApiResult<impl IntoR
         repo.save_entry(&minute_entry)?;
     }
 
+    let notification_list = if event.status == EventStatus::Cancelled {
+        let attorney_repo = RepositoryFactory::attorney_repo(&req)?;
+        Some(event_notification_service::notification_list(&event, &attorney_repo)?)
+    } else {
+        None
+    };
+
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
-        .body(serde_json::to_vec(&event)?)
+        .body(serde_json::to_vec(&EventStatusUpdateResponse { event, notification_list })?)
+        .build())
+}
+
+/// Reject appearances naming attorneys the district doesn't know
+fn check_appearances(req: &Request, minutes: &HearingMinutes) -> ApiResult<()> {
+    let ids: Vec<&str> = minutes.attorney_ids().collect();
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    let attorney_repo = RepositoryFactory::attorney_repo(req)?;
+    let mut unknown = Vec::new();
+    for id in ids {
+        if attorney_repo.find_attorney_by_id(id)?.is_none() {
+            unknown.push(id);
+        }
+    }
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(ApiError::BadRequest("Appearances reference unknown attorneys".to_string())
+            .with_details(serde_json::json!({ "unknown_attorney_ids": unknown })))
+    }
+}
+
+/// Get a minute entry draft for a completed event
+///
+/// The body is ready to send to `POST /api/pdf/minute-entry`, with the
+/// hearing's minutes written into the minute text.
+#[utoipa::path(
+    get,
+    path = "/api/calendar/events/{event_id}/minute-entry-draft",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("event_id" = Uuid, Path, description = "Calendar event ID")
+    ),
+    responses(
+        (status = 200, description = "Minute entry request for the PDF endpoint", body = MinuteEntryRequest),
+        (status = 400, description = "Invalid event ID"),
+        (status = 404, description = "Event not found"),
+        (status = 409, description = "Event has not been completed")
+    ),
+    tag = "Calendar Management",
+)]
+pub fn get_minute_entry_draft(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let event_id = params
+        .get("event_id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid event ID".to_string()))?;
+
+    let repo = RepositoryFactory::docket_repo(&req)?;
+    let event = repo
+        .find_event_by_id(event_id)?
+        .ok_or_else(|| ApiError::NotFound("Event not found".to_string()))?;
+    if event.status != EventStatus::Completed {
+        return Err(ApiError::Conflict(
+            "A minute entry draft is only available once the event is completed".to_string(),
+        ));
+    }
+
+    let case = RepositoryFactory::case_repo(&req)?.find_by_id(event.case_id)?;
+    let judge = RepositoryFactory::judge_repo(&req)?.find_judge_by_id(event.judge_id)?;
+    let minutes = event.minutes.as_ref();
+
+    let draft = MinuteEntryRequest {
+        case_number: case.as_ref().map(|c| c.case_number.clone()).unwrap_or_default(),
+        defendant_names: case
+            .as_ref()
+            .map(|c| DefendantNames::new(c.defendants.iter().map(|d| d.name.as_str())))
+            .unwrap_or_default(),
+        judge_name: judge.map(|j| j.name).unwrap_or_default(),
+        minute_text: DocketService::minute_text(&event),
+        hearing_date: Some(event.scheduled_date.format("%m/%d/%Y").to_string()),
+        hearing_type: Some(title_case(&event.event_type.config_key())),
+        court_reporter: event.court_reporter.clone(),
+        next_hearing: minutes
+            .and_then(|m| m.next_scheduled_date)
+            .map(|d| d.format("%m/%d/%Y %I:%M %p").to_string()),
+        signature_base64: None,
+        judge_id: Some(event.judge_id.to_string()),
+        layout: Default::default(),
+    };
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&draft)?)
+        .build())
+}
+
+/// Get the notification list for an event
+#[utoipa::path(
+    get,
+    path = "/api/calendar/events/{event_id}/notification-list",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("event_id" = Uuid, Path, description = "Calendar event ID")
+    ),
+    responses(
+        (status = 200, description = "Participants to notify, with emails and parties needing postal service", body = EventNotificationList),
+        (status = 400, description = "Invalid event ID"),
+        (status = 404, description = "Event not found")
+    ),
+    tag = "Calendar Management",
+)]
+pub fn get_event_notification_list(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let event_id = params
+        .get("event_id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid event ID".to_string()))?;
+
+    let repo = RepositoryFactory::docket_repo(&req)?;
+    let event = repo
+        .find_event_by_id(event_id)?
+        .ok_or_else(|| ApiError::NotFound("Event not found".to_string()))?;
+
+    let attorney_repo = RepositoryFactory::attorney_repo(&req)?;
+    let list = event_notification_service::notification_list(&event, &attorney_repo)?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&list)?)
         .build())
 }
 
 /// Find next available slot
+///
+/// Weekends, court closures and days the judge is recorded as unavailable
+/// are skipped.
 #[utoipa::path(
     get,
-    path = "/api/judges/{judge_id}/available-slot",
+    path = "/api/calendar/available-slot/{judge_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("judge_id" = Uuid, Path, description = "Judge ID"),
@@ -572,19 +1106,24 @@ pub fn find_available_slot(req: Request, params: Params) -> ApiResult<impl IntoR
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid judge ID".to_string()))?;
 
-    let duration = params
-        .get("duration")
+    let query = query_parser::parse_query_string(req.query());
+    let duration = query_parser::get_string(&query, "duration")
         .and_then(|d| d.parse::<u32>().ok())
         .unwrap_or(60);
 
-    let earliest = params
-        .get("earliest")
-        .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
-        .map(|d| d.with_timezone(&Utc))
+    let earliest = query_parser::get_datetime(&query, "earliest")
         .unwrap_or_else(Utc::now);
 
+    // The judge's days away are closed days for this judge
+    let mut closures = closure_dates(&req)?;
+    if let Some(judge) = RepositoryFactory::judge_repo(&req)?.find_judge_by_id(judge_id)? {
+        closures.extend(judge_unavailability::unavailable_days_from(
+            &judge.availability.unavailability,
+            earliest.date_naive(),
+        ));
+    }
     let repo = RepositoryFactory::docket_repo(&req)?;
-    let next_slot = repo.find_available_slot(judge_id, duration, earliest)?;
+    let next_slot = repo.find_available_slot(judge_id, duration, earliest, &closures)?;
 
 
     let response = AvailableSlotResponse {
@@ -602,7 +1141,7 @@ pub fn find_available_slot(req: Request, params: Params) -> ApiResult<impl IntoR
 /// Initialize Speedy Trial clock
 #[utoipa::path(
     post,
-    path = "/api/cases/{case_id}/speedy-trial",
+    path = "/api/speedy-trial/{case_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID")
@@ -656,7 +1195,7 @@ pub fn init_speedy_trial(req: Request, params: Params) -> ApiResult<impl IntoRes
 /// Get Speedy Trial clock status
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/speedy-trial",
+    path = "/api/speedy-trial/{case_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID")
@@ -691,7 +1230,7 @@ pub fn get_speedy_trial(req: Request, params: Params) -> ApiResult<impl IntoResp
 /// Add excludable delay
 #[utoipa::path(
     post,
-    path = "/api/cases/{case_id}/speedy-trial/delays",
+    path = "/api/speedy-trial/{case_id}/delays",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID")
@@ -745,7 +1284,7 @@ pub fn add_excludable_delay(req: Request, params: Params) -> ApiResult<impl Into
 /// Get approaching Speedy Trial deadlines
 #[utoipa::path(
     get,
-    path = "/api/speedy-trial/deadlines/approaching",
+    path = "/api/speedy-trial/approaching",
     responses(
         (status = 200, description = "List of cases with approaching Speedy Trial deadlines", body = [SpeedyTrialClock]),
         (status = 500, description = "Internal server error")
@@ -768,7 +1307,7 @@ pub fn get_approaching_deadlines(req: Request, _params: Params) -> ApiResult<imp
 /// Get courtroom utilization
 #[utoipa::path(
     get,
-    path = "/api/courtrooms/utilization",
+    path = "/api/calendar/utilization",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("start" = Option<String>, Query, description = "Start date (RFC3339 format, defaults to 30 days ago)"),
@@ -805,11 +1344,12 @@ pub fn get_courtroom_utilization(req: Request, params: Params) -> ApiResult<impl
 /// Get docket entries by type
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/docket/type/{type}",
+    path = "/api/docket/case/{case_id}/type/{type}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID"),
-        ("type" = String, Path, description = "Docket entry type")
+        ("type" = String, Path, description = "Docket entry type"),
+        ("X-Access-Level" = Option<String>, Header, description = "Caller access level: public (default), attorney, chambers or sealed-authorized")
     ),
     responses(
         (status = 200, description = "List of docket entries of the specified type", body = [DocketEntry]),
@@ -827,10 +1367,11 @@ pub fn get_entries_by_type(req: Request, params: Params) -> ApiResult<impl IntoR
         .get("type")
         .ok_or_else(|| ApiError::BadRequest("Entry type required".to_string()))?;
 
-    let entry_type: DocketEntryType = serde_json::from_str(&format!("\"{}\"", entry_type_str))?;
+    let entry_type: DocketEntryType = query_parser::parse_enum_param("type", entry_type_str)?;
 
+    let access = access::get_access_level(&req)?;
     let repo = RepositoryFactory::docket_repo(&req)?;
-    let entries = repo.find_entries_by_type(case_id, entry_type)?;
+    let entries = visible_entries(repo.find_entries_by_type(case_id, entry_type)?, access);
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -841,13 +1382,14 @@ pub fn get_entries_by_type(req: Request, params: Params) -> ApiResult<impl IntoR
 /// Get sealed entries for a case
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/docket/sealed",
+    path = "/api/docket/case/{case_id}/sealed",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("case_id" = Uuid, Path, description = "Case ID")
+        ("case_id" = Uuid, Path, description = "Case ID"),
+        ("X-Access-Level" = Option<String>, Header, description = "Caller access level: public (default), attorney, chambers or sealed-authorized")
     ),
     responses(
-        (status = 200, description = "List of sealed docket entries for the case", body = [DocketEntry]),
+        (status = 200, description = "Sealed docket entries for the case; empty unless the caller may view sealed material", body = [DocketEntry]),
         (status = 400, description = "Invalid case ID")
     ),
     tag = "Docket Management",
@@ -858,8 +1400,9 @@ pub fn get_sealed_entries(req: Request, params: Params) -> ApiResult<impl IntoRe
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid case ID".to_string()))?;
 
+    let access = access::get_access_level(&req)?;
     let repo = RepositoryFactory::docket_repo(&req)?;
-    let entries = repo.find_sealed_entries(case_id)?;
+    let entries = visible_entries(repo.find_sealed_entries(case_id)?, access);
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -870,11 +1413,12 @@ pub fn get_sealed_entries(req: Request, params: Params) -> ApiResult<impl IntoRe
 /// Search entries by text
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/docket/search/{text}",
+    path = "/api/docket/case/{case_id}/search/{text}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID"),
-        ("text" = String, Path, description = "Text to search for in docket entries")
+        ("text" = String, Path, description = "Text to search for in docket entries"),
+        ("X-Access-Level" = Option<String>, Header, description = "Caller access level: public (default), attorney, chambers or sealed-authorized")
     ),
     responses(
         (status = 200, description = "List of docket entries matching the search text", body = [DocketEntry]),
@@ -892,8 +1436,9 @@ pub fn search_entries(req: Request, params: Params) -> ApiResult<impl IntoRespon
         .get("text")
         .ok_or_else(|| ApiError::BadRequest("Search text required".to_string()))?;
 
+    let access = access::get_access_level(&req)?;
     let repo = RepositoryFactory::docket_repo(&req)?;
-    let entries = repo.search_entries(case_id, search_text)?;
+    let entries = visible_entries(repo.search_entries(case_id, search_text)?, access);
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -934,7 +1479,7 @@ pub fn delete_entry(req: Request, params: Params) -> ApiResult<impl IntoResponse
 /// Get events by courtroom
 #[utoipa::path(
     get,
-    path = "/api/courtrooms/{courtroom}/events",
+    path = "/api/calendar/courtroom/{courtroom}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("courtroom" = String, Path, description = "Courtroom identifier"),
@@ -969,6 +1514,82 @@ pub fn get_events_by_courtroom(req: Request, params: Params) -> ApiResult<impl I
         .build())
 }
 
+/// Get the day's calendar for every courtroom, or one
+///
+/// Proceedings are grouped by courtroom in start order, with the case and
+/// presiding judge named and double-bookings flagged. The day follows the
+/// district's local time from `scheduling.utc_offset_hours`. `format=text`
+/// returns the printed fixed-width calendar instead of JSON.
+#[utoipa::path(
+    get,
+    path = "/api/calendar/daily",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("date" = Option<String>, Query, description = "Calendar date (YYYY-MM-DD, defaults to today)"),
+        ("courtroom" = Option<String>, Query, description = "Courtroom to limit the calendar to (defaults to all)"),
+        ("format" = Option<String>, Query, description = "json (default) or text")
+    ),
+    responses(
+        (status = 200, description = "The day's proceedings grouped by courtroom", body = DailyCalendar),
+        (status = 200, description = "Printable fixed-width daily calendar", content_type = "text/plain"),
+        (status = 400, description = "Invalid date or format")
+    ),
+    tag = "Calendar Management",
+)]
+pub fn get_daily_calendar(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    let query_params = query_parser::parse_query_string(req.query());
+    let as_text = match query_parser::get_string(&query_params, "format").as_deref() {
+        None | Some("json") => false,
+        Some("text") => true,
+        Some(other) => {
+            return Err(ApiError::BadRequest(format!(
+                "Invalid format '{}'. Expected json or text",
+                other
+            )))
+        }
+    };
+
+    let offset = crate::handlers::judge::district_utc_offset(&req)?;
+    let date = match query_parser::get_string(&query_params, "date") {
+        Some(value) => NaiveDate::parse_from_str(&value, "%Y-%m-%d")
+            .map_err(|_| ApiError::BadRequest(format!("Invalid date: {}", value)))?,
+        None => Utc::now().with_timezone(&offset).date_naive(),
+    };
+    let courtroom = query_parser::get_string(&query_params, "courtroom").filter(|c| !c.trim().is_empty());
+
+    let (day_start, day_end) = DailyCalendar::day_range(date, offset);
+    let events = RepositoryFactory::docket_repo(&req)?.find_events_in_range(day_start, day_end)?;
+
+    let case_repo = RepositoryFactory::case_repo(&req)?;
+    let judge_repo = RepositoryFactory::judge_repo(&req)?;
+    let mut captions = HashMap::new();
+    let mut judge_names = HashMap::new();
+    for case_id in events.iter().map(|e| e.case_id).collect::<HashSet<_>>() {
+        if let Some(case) = case_repo.find_by_id(case_id)? {
+            captions.insert(case_id, CaseCaption { case_number: case.case_number, caption: case.title });
+        }
+    }
+    for judge_id in events.iter().map(|e| e.judge_id).collect::<HashSet<_>>() {
+        if let Some(judge) = judge_repo.find_judge_by_id(judge_id)? {
+            judge_names.insert(judge_id, judge.name);
+        }
+    }
+
+    let calendar = DailyCalendar::build(date, offset, courtroom.as_deref(), &events, &captions, &judge_names);
+
+    if as_text {
+        return Ok(ResponseBuilder::new(200)
+            .header("content-type", "text/plain; charset=utf-8")
+            .body(calendar.render_text())
+            .build());
+    }
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&calendar)?)
+        .build())
+}
+
 /// Delete a calendar event
 #[utoipa::path(
     delete,
@@ -1002,7 +1623,7 @@ pub fn delete_event(req: Request, params: Params) -> ApiResult<impl IntoResponse
 /// Get filing statistics for a case
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/filing-stats",
+    path = "/api/docket/statistics/{case_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID")
@@ -1034,17 +1655,21 @@ pub fn get_filing_statistics(req: Request, params: Params) -> ApiResult<impl Int
     get,
     path = "/api/speedy-trial/violations",
     responses(
-        (status = 200, description = "List of cases with Speedy Trial Act violations", body = [SpeedyTrialClock]),
+        (status = 200, description = "Cases with Speedy Trial Act violations, most overdue first", body = [SpeedyTrialViolation]),
         (status = 500, description = "Internal server error")
     ),
     tag = "Speedy Trial Management",
     params(
-        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("min_days_over" = Option<usize>, Query, description = "Only include cases at least this many days past the deadline")
     ),
 )]
 pub fn get_violations(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    let query_params = query_parser::parse_query_string(req.query());
+    let min_days_over = query_parser::get_usize(&query_params, "min_days_over").unwrap_or(0) as i64;
+
     let repo = RepositoryFactory::docket_repo(&req)?;
-    let violations = repo.find_violations()?;
+    let violations = SpeedyTrialService::violation_report(repo.find_violations()?, min_days_over);
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -1054,8 +1679,8 @@ pub fn get_violations(req: Request, _params: Params) -> ApiResult<impl IntoRespo
 
 /// Update Speedy Trial clock
 #[utoipa::path(
-    put,
-    path = "/api/cases/{case_id}/speedy-trial",
+    patch,
+    path = "/api/speedy-trial/{case_id}/clock",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID")
@@ -1102,7 +1727,7 @@ pub fn update_clock(req: Request, params: Params) -> ApiResult<impl IntoResponse
         ("limit" = Option<usize>, Query, description = "Pagination limit")
     ),
     responses(
-        (status = 200, description = "Search results with calendar events and total count"),
+        (status = 200, description = "A page of calendar events; also returned under the deprecated `events` field", body = Page<CalendarEntry>),
         (status = 500, description = "Internal server error")
     ),
     tag = "Calendar Management",
@@ -1124,11 +1749,11 @@ pub fn search_calendar(req: Request, _params: Params) -> ApiResult<impl IntoResp
         limit: query_parser::get_usize(&params, "limit").unwrap_or(50),
     };
 
+    let (offset, limit) = (query.offset, query.limit);
     let repo = RepositoryFactory::docket_repo(&req)?;
     let (events, total) = repo.search_calendar(query)?;
 
-
-    let response = CalendarSearchResponse { events, total };
+    let response = Page::new(events, total, offset, limit).with_legacy_field("events");
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -1139,7 +1764,7 @@ pub fn search_calendar(req: Request, _params: Params) -> ApiResult<impl IntoResp
 /// Check if a docket entry requires immediate service
 #[utoipa::path(
     get,
-    path = "/api/docket/service-check/{entry_type}",
+    path = "/api/docket/immediate-service/{entry_type}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("entry_type" = String, Path, description = "Docket entry type to check")
@@ -1155,7 +1780,7 @@ pub fn check_immediate_service(_req: Request, params: Params) -> ApiResult<impl
         .get("entry_type")
         .ok_or_else(|| ApiError::BadRequest("Entry type required".to_string()))?;
 
-    let entry_type: DocketEntryType = serde_json::from_str(&format!("\"{}\"", entry_type_str))?;
+    let entry_type: DocketEntryType = query_parser::parse_enum_param("entry_type", entry_type_str)?;
 
     let requires_immediate = DocketService::requires_immediate_service(&entry_type);
 
@@ -1171,7 +1796,7 @@ pub fn check_immediate_service(_req: Request, params: Params) -> ApiResult<impl
 /// Check if Speedy Trial deadline is approaching
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/speedy-trial/deadline-check",
+    path = "/api/speedy-trial/approaching/{case_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID")
@@ -76,6 +76,24 @@ pub fn add_attachment(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn upload_attachment(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::docket::upload_attachment(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+pub fn download_attachment(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::docket::download_attachment(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn search_docket(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::docket::search_docket(req, params)
@@ -188,6 +206,24 @@ pub fn update_event_status(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_event_notification_list(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::docket::get_event_notification_list(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+pub fn get_minute_entry_draft(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::docket::get_minute_entry_draft(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn find_available_slot(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::docket::find_available_slot(req, params)
@@ -215,6 +251,15 @@ pub fn get_events_by_courtroom(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_daily_calendar(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::docket::get_daily_calendar(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn delete_event(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::docket::delete_event(req, params)
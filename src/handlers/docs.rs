@@ -9,7 +9,7 @@ use utoipa::OpenApi;
 /// This function dynamically generates the OpenAPI JSON specification,
 /// including the appropriate server URL based on the runtime environment.
 pub fn get_openapi_description(req: Request, _: Params) -> anyhow::Result<impl IntoResponse> {
-    let mut openapi_description = OpenApiDocs::openapi();
+    let mut openapi_description = openapi_document();
     let (url, description) = get_server_info(&req);
     openapi_description.servers = Some(vec![ServerBuilder::new()
         .url(url)
@@ -22,6 +22,11 @@ pub fn get_openapi_description(req: Request, _: Params) -> anyhow::Result<impl I
         .build())
 }
 
+/// The OpenAPI document for every annotated handler, without server info
+pub(crate) fn openapi_document() -> utoipa::openapi::OpenApi {
+    OpenApiDocs::openapi()
+}
+
 /// Determine the server URL and description based on the runtime environment
 ///
 /// Returns a tuple of (server_url, description) where the server URL
@@ -115,6 +120,8 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     (name = "todos", description = "Operations for managing ToDo items"),
     (name = "cases", description = "Criminal case management (hexagonal architecture example)"),
     (name = "monitoring", description = "Health and monitoring endpoints"),
+    (name = "Search", description = "Global search across cases, attorneys, judges and docket entries"),
+    (name = "Webhooks", description = "Outbound notifications of case, deadline and order events"),
     (name = "Judge Management", description = "Judge CRUD operations and management"),
     (name = "Judge Assignment", description = "Case assignment and workload management"),
     (name = "Judge Analytics", description = "Judge performance and workload analytics"),
@@ -158,15 +165,19 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
   paths(
     // ToDo API
     crate::handlers::todo::get_all,
+    crate::handlers::todo::get_overdue,
     crate::handlers::todo::get_by_id,
     crate::handlers::todo::create_todo,
     crate::handlers::todo::toggle_by_id,
+    crate::handlers::todo::bulk_toggle,
+    crate::handlers::todo::bulk_delete,
     crate::handlers::todo::delete_by_id,
     // Criminal Case API
     crate::handlers::criminal_case::create_case,
     crate::handlers::criminal_case::get_case_by_id,
     crate::handlers::criminal_case::get_case_by_number,
     crate::handlers::criminal_case::get_cases_by_judge,
+    crate::handlers::criminal_case::get_cases_by_attorney,
     crate::handlers::criminal_case::search_cases,
     crate::handlers::criminal_case::get_case_statistics,
     crate::handlers::criminal_case::count_by_status,
@@ -181,6 +192,8 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::criminal_case::rule_on_motion,
     crate::handlers::criminal_case::delete_case,
     crate::handlers::criminal_case::add_charge,
+    crate::handlers::criminal_case::update_defendant_custody,
+    crate::handlers::criminal_case::get_custody_report,
     // Evidence Chain of Custody
     crate::handlers::criminal_case::add_custody_transfer,
     // Docket Entries on Cases
@@ -189,21 +202,37 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     // Sealed Cases
     crate::handlers::criminal_case::seal_case,
     crate::handlers::criminal_case::unseal_case,
+    crate::handlers::criminal_case::archive_case,
+    crate::handlers::criminal_case::get_case_archive,
+    crate::handlers::criminal_case::unarchive_case,
     // Speedy Trial Clock on Cases
     crate::handlers::criminal_case::start_speedy_trial,
     crate::handlers::criminal_case::add_case_excludable_delay,
     crate::handlers::criminal_case::get_case_speedy_trial,
+    // Statute of Limitations
+    crate::handlers::criminal_case::record_limitations_tolling,
+    crate::handlers::criminal_case::get_limitations_at_risk,
+    crate::handlers::criminal_case::get_delay_attribution,
     // CVRA Victims
     crate::handlers::criminal_case::add_victim,
     crate::handlers::criminal_case::get_victims,
     crate::handlers::criminal_case::send_victim_notification,
+    crate::handlers::criminal_case::record_magistrate_consent,
+    crate::handlers::criminal_case::get_magistrate_consent,
+    // Notices of Appeal
+    crate::handlers::criminal_case::file_appeal,
+    crate::handlers::criminal_case::get_appeals,
+    crate::handlers::criminal_case::update_appeal_status,
     // Judge Management API
     crate::handlers::judge::create_judge,
     crate::handlers::judge::get_all_judges,
     crate::handlers::judge::get_judge_by_id,
+    crate::handlers::judge::get_action_queue,
+    crate::handlers::judge::get_weekly_docket,
     crate::handlers::judge::update_judge_status,
     crate::handlers::judge::get_available_judges,
     crate::handlers::judge::assign_case,
+    crate::handlers::judge::preview_assignment,
     crate::handlers::judge::get_case_assignment,
     crate::handlers::judge::file_recusal,
     crate::handlers::judge::rule_on_recusal,
@@ -211,6 +240,7 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::judge::add_conflict,
     crate::handlers::judge::check_conflicts,
     crate::handlers::judge::get_workload_stats,
+    crate::handlers::judge::get_workload_by_type,
     crate::handlers::judge::search_judges,
     crate::handlers::judge::get_judges_by_status,
     crate::handlers::judge::get_judges_by_district,
@@ -223,18 +253,26 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::judge::has_conflict,
     crate::handlers::judge::delete_conflict,
     crate::handlers::judge::get_judges_on_vacation,
+    crate::handlers::judge::add_unavailability,
+    crate::handlers::judge::get_unavailability,
+    crate::handlers::judge::cancel_unavailability,
     crate::handlers::judge::process_recusal,
     // Docket & Calendar API
     crate::handlers::docket::create_docket_entry,
     crate::handlers::docket::get_case_docket,
     crate::handlers::docket::get_docket_entry,
     crate::handlers::docket::add_attachment,
+    crate::handlers::docket::upload_attachment,
+    crate::handlers::docket::get_case_attachment_size,
+    crate::handlers::docket::download_attachment,
     crate::handlers::docket::search_docket,
     crate::handlers::docket::generate_docket_sheet,
     crate::handlers::docket::schedule_event,
     crate::handlers::docket::get_case_calendar,
     crate::handlers::docket::get_judge_schedule,
     crate::handlers::docket::update_event_status,
+    crate::handlers::docket::get_event_notification_list,
+    crate::handlers::docket::get_minute_entry_draft,
     crate::handlers::docket::find_available_slot,
     crate::handlers::docket::init_speedy_trial,
     crate::handlers::docket::get_speedy_trial,
@@ -246,6 +284,7 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::docket::search_entries,
     crate::handlers::docket::delete_entry,
     crate::handlers::docket::get_events_by_courtroom,
+    crate::handlers::docket::get_daily_calendar,
     crate::handlers::docket::delete_event,
     crate::handlers::docket::get_filing_statistics,
     crate::handlers::docket::get_violations,
@@ -258,6 +297,8 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::deadline::get_case_deadlines,
     crate::handlers::deadline::get_deadline,
     crate::handlers::deadline::complete_deadline,
+    crate::handlers::deadline::get_deadline_dependents,
+    crate::handlers::deadline::set_deadline_dependency,
     crate::handlers::deadline::request_extension,
     crate::handlers::deadline::rule_on_extension,
     crate::handlers::deadline::get_upcoming_deadlines,
@@ -272,6 +313,7 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::deadline::send_reminders,
     crate::handlers::deadline::get_deadlines_by_type,
     crate::handlers::deadline::update_deadline_status,
+    crate::handlers::deadline::refresh_deadline_statuses,
     crate::handlers::deadline::delete_deadline,
     crate::handlers::deadline::get_reminders_by_deadline,
     crate::handlers::deadline::get_reminders_by_recipient,
@@ -293,6 +335,13 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::features::clear_feature_overrides,
     crate::handlers::admin::init_tenant,
     crate::handlers::admin::get_tenant_stats,
+    crate::handlers::admin::list_districts,
+    crate::handlers::admin::audit_document_index,
+    crate::handlers::admin::recover_journals,
+    crate::handlers::admin::export_tenant,
+    crate::handlers::admin::import_tenant,
+    crate::handlers::admin::get_district_webhook,
+    crate::handlers::admin::update_district_webhook,
     // Judicial Orders
     crate::handlers::order::create_order,
     crate::handlers::order::get_order,
@@ -306,11 +355,19 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::order::get_orders_by_judge,
     crate::handlers::order::get_pending_signatures,
     crate::handlers::order::get_expiring_orders,
+    crate::handlers::order::get_expiring_order_notifications,
     crate::handlers::order::get_order_statistics,
     crate::handlers::order::create_from_template,
     crate::handlers::order::create_template,
     crate::handlers::order::get_template,
     crate::handlers::order::list_templates,
+    crate::handlers::order::generate_template_content,
+    crate::handlers::order::preview_template,
+    crate::handlers::order::find_active_templates,
+    crate::handlers::order::update_template,
+    crate::handlers::order::delete_template,
+    crate::handlers::order::check_order_expired,
+    crate::handlers::order::check_requires_attention,
     // Judicial Opinions
     crate::handlers::opinion::create_opinion,
     crate::handlers::opinion::get_opinion,
@@ -319,23 +376,29 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::opinion::list_opinions,
     crate::handlers::opinion::file_opinion,
     crate::handlers::opinion::publish_opinion,
+    crate::handlers::opinion::file_errata,
+    crate::handlers::opinion::get_slip_opinion,
     crate::handlers::opinion::add_judge_vote,
     crate::handlers::opinion::add_citation,
     crate::handlers::opinion::add_headnote,
     crate::handlers::opinion::get_opinions_by_case,
     crate::handlers::opinion::get_opinions_by_author,
     crate::handlers::opinion::search_opinions,
+    crate::handlers::opinion::search_opinions_ranked,
     crate::handlers::opinion::get_precedential_opinions,
     crate::handlers::opinion::get_opinion_statistics,
     crate::handlers::opinion::get_citation_statistics,
     crate::handlers::opinion::create_draft,
     crate::handlers::opinion::get_drafts,
     crate::handlers::opinion::get_current_draft,
+    crate::handlers::opinion::get_draft_diff,
     crate::handlers::opinion::is_majority_opinion,
     crate::handlers::opinion::is_binding_opinion,
     crate::handlers::opinion::calculate_opinion_statistics,
     crate::handlers::opinion::add_draft_comment,
     crate::handlers::opinion::resolve_draft_comment,
+    crate::handlers::opinion::get_cited_by,
+    crate::handlers::opinion::get_citation_graph,
     // Federal Sentencing API
     crate::handlers::sentencing::create_sentencing,
     crate::handlers::sentencing::get_sentencing,
@@ -348,6 +411,7 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::sentencing::calculate_guidelines,
     crate::handlers::sentencing::get_departure_stats,
     crate::handlers::sentencing::get_variance_stats,
+    crate::handlers::sentencing::get_adjustment_stats,
     crate::handlers::sentencing::add_departure,
     crate::handlers::sentencing::add_variance,
     crate::handlers::sentencing::get_substantial_assistance,
@@ -357,6 +421,7 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::sentencing::add_bop_designation,
     crate::handlers::sentencing::get_rdap_eligible,
     crate::handlers::sentencing::get_judge_stats,
+    crate::handlers::sentencing::get_judge_comparison,
     crate::handlers::sentencing::get_district_stats,
     crate::handlers::sentencing::get_trial_penalty,
     crate::handlers::sentencing::add_prior_sentence,
@@ -367,7 +432,9 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::sentencing::calculate_criminal_history_points,
     crate::handlers::sentencing::calculate_offense_level,
     crate::handlers::sentencing::lookup_guidelines_range,
+    crate::handlers::sentencing::lookup_guidelines_range_batch,
     crate::handlers::sentencing::check_safety_valve_eligible,
+    crate::handlers::sentencing::get_calculation_audit,
     // Rules Engine API
     crate::handlers::rules::create_rule,
     crate::handlers::rules::list_rules,
@@ -381,19 +448,26 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     // PDF Generation API (Hexagonal Architecture)
     crate::handlers::pdf_hexagonal::generate_rule16b,
     crate::handlers::pdf_hexagonal::generate_signed_rule16b,
+    crate::handlers::pdf_hexagonal::generate_signed_court_order,
+    crate::handlers::pdf_hexagonal::generate_signed_minute_entry,
     crate::handlers::pdf_hexagonal::generate_court_order,
     crate::handlers::pdf_hexagonal::generate_minute_entry,
     crate::handlers::pdf_hexagonal::generate_waiver_indictment,
     crate::handlers::pdf_hexagonal::generate_conditions_release,
     crate::handlers::pdf_hexagonal::generate_criminal_judgment,
+    crate::handlers::pdf_hexagonal::generate_subpoena,
+    crate::handlers::pdf_hexagonal::generate_summons,
     crate::handlers::pdf_hexagonal::generate_batch_pdfs,
+    crate::handlers::pdf_hexagonal::generate_batch_pdfs_zip,
     crate::handlers::pdf_hexagonal::store_signature,
     crate::handlers::pdf_hexagonal::get_signature,
+    crate::handlers::pdf_hexagonal::verify_signature,
     // Attorney Management API
     crate::handlers::attorney::create_attorney,
     crate::handlers::attorney::get_attorney,
     crate::handlers::attorney::get_attorney_by_bar_number,
     crate::handlers::attorney::update_attorney,
+    crate::handlers::attorney::patch_attorney,
     crate::handlers::attorney::delete_attorney,
     crate::handlers::attorney::list_attorneys,
     crate::handlers::attorney::search_attorneys,
@@ -409,12 +483,19 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::attorney::update_pro_hac_vice_status,
     crate::handlers::attorney::get_active_pro_hac_vice,
     crate::handlers::attorney::get_pro_hac_vice_by_case,
+    crate::handlers::attorney::renew_pro_hac_vice,
+    crate::handlers::attorney::get_expiring_pro_hac_vice,
     crate::handlers::attorney::add_to_cja_panel,
     crate::handlers::attorney::remove_from_cja_panel,
     crate::handlers::attorney::get_cja_panel_attorneys,
     crate::handlers::attorney::add_cja_appointment,
     crate::handlers::attorney::get_cja_appointments,
     crate::handlers::attorney::get_pending_cja_vouchers,
+    crate::handlers::attorney::add_voucher_line_item,
+    crate::handlers::attorney::submit_cja_voucher,
+    crate::handlers::attorney::approve_cja_voucher,
+    crate::handlers::attorney::pay_cja_voucher,
+    crate::handlers::attorney::bulk_appoint_cja,
     crate::handlers::attorney::update_ecf_registration,
     crate::handlers::attorney::check_good_standing,
     crate::handlers::attorney::check_federal_practice,
@@ -425,6 +506,7 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::attorney::add_disciplinary_action,
     crate::handlers::attorney::get_disciplinary_history,
     crate::handlers::attorney::get_attorneys_with_discipline,
+    crate::handlers::attorney::reinstate_attorney,
     // Party Management API
     crate::handlers::attorney::create_party,
     crate::handlers::attorney::get_party,
@@ -443,13 +525,29 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::attorney::end_representation,
     crate::handlers::attorney::substitute_attorney,
     crate::handlers::attorney::get_case_representations,
+    crate::handlers::attorney::get_case_continuity,
     crate::handlers::attorney::get_active_representations,
     crate::handlers::attorney::migrate_representations,
+    crate::handlers::attorney::assign_attorney_to_case,
+    crate::handlers::attorney::get_attorney_cases,
+    crate::handlers::attorney::remove_attorney_from_case,
+    crate::handlers::attorney::get_attorney_case_load,
+    crate::handlers::attorney::get_attorney_representation_history,
     // Conflict Management API
     crate::handlers::attorney::create_conflict_check,
     crate::handlers::attorney::get_attorney_conflicts,
     crate::handlers::attorney::check_party_conflicts,
     crate::handlers::attorney::clear_conflict,
+    crate::handlers::attorney::get_conflict_turnaround,
+    crate::handlers::attorney::check_attorney_conflicts,
+    // Global Search API
+    crate::handlers::search::global_search,
+    crate::handlers::timeline::get_case_timeline,
+    crate::handlers::webhook::create_webhook,
+    crate::handlers::webhook::list_webhooks,
+    crate::handlers::webhook::delete_webhook,
+    crate::handlers::webhook::list_deliveries,
+    crate::handlers::webhook::deliver_pending,
     // Service Management API
     crate::handlers::attorney::create_service_record,
     crate::handlers::attorney::get_service_by_document,
@@ -469,8 +567,18 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
     crate::handlers::config::update_district_config,
     crate::handlers::config::update_judge_config,
     crate::handlers::config::clear_district_overrides,
+    crate::handlers::config::get_district_override_history,
+    crate::handlers::config::rollback_district_overrides,
     crate::handlers::config::clear_judge_overrides,
     crate::handlers::config::preview_config,
+    crate::handlers::config::list_closures,
+    crate::handlers::config::add_closure,
+    crate::handlers::config::delete_closure,
+    crate::handlers::config::get_case_number_format,
+    crate::handlers::config::update_case_number_format,
+    crate::handlers::config_url::get_judge_overrides,
+    crate::handlers::config_url::update_judge_config,
+    crate::handlers::config_url::clear_judge_overrides,
     // Filing Pipeline API
     crate::handlers::filing::submit_filing,
     crate::handlers::filing::validate_filing,
@@ -484,11 +592,18 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       crate::domain::ToDo,
       crate::handlers::todo::ToDoModel,
       crate::handlers::todo::CreateToDoModel,
+      crate::handlers::todo::BulkToDoRequest,
+      crate::handlers::todo::BulkToDoStatus,
+      crate::handlers::todo::BulkToDoResult,
+      crate::handlers::todo::BulkToDoResponse,
       crate::handlers::todo::PaginatedResponse<crate::handlers::todo::ToDoModel>,
       crate::handlers::todo::PaginationParams,
       // Criminal Case Models
       crate::handlers::criminal_case::CreateCaseRequest,
       crate::handlers::criminal_case::CaseResponse,
+      crate::domain::case_archive::CaseArchive,
+      crate::domain::case_archive::OpinionReference,
+      crate::domain::case_archive::CaseTombstone,
       crate::handlers::criminal_case::CaseSearchResponse,
       crate::handlers::criminal_case::UpdateStatusRequest,
       crate::handlers::criminal_case::UpdatePriorityRequest,
@@ -516,6 +631,15 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       crate::handlers::criminal_case::UnsealCaseRequest,
       crate::handlers::criminal_case::StartSpeedyTrialRequest,
       crate::handlers::criminal_case::AddCaseExcludableDelayRequest,
+      crate::handlers::criminal_case::RecordTollingRequest,
+      crate::domain::criminal_case::LimitationsTracker,
+      crate::domain::criminal_case::TollingPeriod,
+      crate::domain::criminal_case::LimitationsAtRisk,
+      crate::domain::delay_attribution::DelayAttribution,
+      crate::domain::delay_attribution::DelayItem,
+      crate::domain::delay_attribution::DelayParty,
+      crate::domain::delay_attribution::DelaySource,
+      crate::domain::delay_attribution::PartyDelayTotals,
       crate::handlers::criminal_case::AddVictimRequest,
       crate::handlers::criminal_case::SendVictimNotificationRequest,
       // Victim domain types
@@ -525,6 +649,17 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       crate::domain::victim::NotificationType,
       crate::domain::victim::VictimNotification,
       crate::domain::victim::NotificationPreferences,
+      crate::domain::magistrate_consent::ConsentStatus,
+      crate::domain::magistrate_consent::PartyConsent,
+      crate::domain::magistrate_consent::MagistrateConsent,
+      crate::domain::magistrate_consent::ConsentSummary,
+      crate::handlers::criminal_case::MagistrateConsentRequest,
+      crate::handlers::criminal_case::FileAppealRequest,
+      crate::handlers::criminal_case::UpdateAppealStatusRequest,
+      crate::domain::appeal::Appeal,
+      crate::domain::appeal::AppealFilingParty,
+      crate::domain::appeal::AppealStatus,
+      crate::domain::appeal::AppealStatusChange,
       crate::domain::criminal_case::CaseStatus,
       crate::domain::criminal_case::CasePriority,
       crate::domain::criminal_case::CrimeType,
@@ -544,32 +679,46 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       crate::domain::defendant::Verdict,
       crate::domain::defendant::CreateDefendantRequest,
       crate::domain::defendant::AddCountRequest,
+      crate::domain::defendant::UpdateCustodyRequest,
+      crate::domain::defendant::CustodyReportEntry,
       crate::domain::common::MotionStatus,
       crate::ports::case_repository::CaseStatistics,
       // Judge Management Models
       crate::handlers::judge::CreateJudgeRequest,
       crate::handlers::judge::UpdateJudgeStatusRequest,
       crate::handlers::judge::AssignCaseRequest,
+      crate::handlers::judge::AssignCaseResponse,
+      crate::handlers::judge::AssignmentPreviewRequest,
+      crate::handlers::judge::AssignmentPreviewResponse,
       crate::handlers::judge::FileRecusalRequest,
       crate::handlers::judge::RuleOnRecusalRequest,
+      crate::handlers::judge::RecusalRulingResponse,
       crate::handlers::judge::AddConflictRequest,
+      crate::handlers::judge::AddUnavailabilityRequest,
+      crate::domain::judge_unavailability::Unavailability,
+      crate::domain::judge_unavailability::UnavailabilityType,
       crate::handlers::judge::WorkloadResponse,
+      crate::handlers::judge::CaseTypeWorkloadResponse,
       crate::domain::judge::Judge,
       crate::domain::judge::JudgeTitle,
       crate::domain::judge::JudgeStatus,
       crate::domain::judge::CaseAssignment,
+      crate::domain::judge::ConflictExclusion,
       crate::domain::judge::RecusalMotion,
       crate::domain::judge::ConflictOfInterest,
       crate::domain::judge::JudgeConflictType,
       crate::domain::judge::RecusalReason,
       crate::domain::judge::RecusalStatus,
+      crate::domain::judge::ReassignmentStatus,
       crate::domain::judge::AssignmentType,
+      crate::domain::judge::AssignmentStrategy,
       crate::domain::judge::CaseType,
       // Docket & Calendar Models
       crate::handlers::docket::CreateDocketEntryRequest,
       crate::handlers::docket::AddAttachmentRequest,
       crate::handlers::docket::ScheduleEventRequest,
       crate::handlers::docket::UpdateEventStatusRequest,
+      crate::handlers::docket::EventStatusUpdateResponse,
       crate::handlers::docket::AddExcludableDelayRequest,
       crate::handlers::docket::FilingStatsResponse,
       crate::domain::docket::DocketEntry,
@@ -577,27 +726,77 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       crate::domain::docket::DocketAttachment,
       crate::domain::docket::CalendarEntry,
       crate::domain::docket::CalendarEventType,
+      crate::domain::docket::EventParticipant,
+      crate::domain::docket::ParticipantKind,
+      crate::domain::docket::EventNotificationList,
+      crate::domain::docket::HearingMinutes,
+      crate::domain::docket::CaseAttachmentSize,
+      crate::domain::docket::Appearance,
+      crate::domain::docket::NotificationRecipient,
+      crate::domain::docket::NotificationDelivery,
       crate::domain::docket::EventStatus,
       crate::domain::docket::SpeedyTrialClock,
+      crate::domain::docket::SpeedyTrialViolation,
+      crate::domain::docket::JudicialActionItem,
+      crate::domain::daily_calendar::DailyCalendar,
+      crate::domain::daily_calendar::CourtroomCalendar,
+      crate::domain::daily_calendar::DailyCalendarEvent,
+      crate::domain::daily_calendar::CaseCaption,
+      crate::domain::weekly_docket::WeeklyDocket,
+      crate::domain::weekly_docket::WeeklyDocketDay,
+      crate::domain::weekly_docket::WeeklyDocketItem,
+      crate::domain::weekly_docket::WeeklyDocketItemKind,
       crate::domain::docket::ExcludableDelay,
       crate::domain::docket::DelayReason,
       // Deadline Management Models
       crate::handlers::deadline::CreateDeadlineRequest,
+      crate::handlers::deadline::SetDependencyRequest,
       crate::handlers::deadline::RequestExtensionRequest,
       crate::handlers::deadline::RuleOnExtensionRequest,
       crate::handlers::deadline::CalculateDeadlinesRequest,
+      crate::handlers::deadline::StatusRefreshResponse,
       crate::handlers::deadline::ComplianceStatsResponse,
       crate::domain::deadline::Deadline,
       crate::domain::deadline::DeadlineType,
       crate::domain::deadline::DeadlineStatus,
+      crate::domain::deadline::DeadlineStatusTransition,
       crate::domain::deadline::ExtensionRequest,
+      crate::domain::deadline::DueDateChange,
       crate::domain::deadline::ExtensionStatus,
       crate::domain::deadline::FederalRule,
       crate::domain::deadline::DeadlineReminder,
+      crate::domain::deadline::DeadlineRule,
+      // Tenant Administration Models
+      crate::services::tenant_seed::SeedReport,
+      crate::domain::document::DocumentIndexEntry,
+      crate::domain::document::DocumentIndexAudit,
+      crate::handlers::admin::JournalRecoveryReport,
+      crate::domain::tenant_export::TenantExport,
+      crate::domain::tenant_export::ExportDomain,
+      crate::domain::tenant_export::ConflictPolicy,
+      crate::domain::tenant_export::DomainImportResult,
+      crate::domain::tenant_export::ImportReport,
+      crate::handlers::admin::TenantStats,
+      crate::handlers::admin::DomainCounts,
+      crate::handlers::admin::DistrictSummary,
+      crate::handlers::admin::DistrictListing,
+      crate::domain::district::TenantKind,
+      crate::handlers::admin::UpdateDistrictWebhookRequest,
+      crate::domain::webhook::DistrictWebhookSummary,
+      crate::domain::webhook::DirectDelivery,
+      crate::domain::document::IndexAnomaly,
+      crate::domain::document::IndexAnomalyKind,
+      crate::services::tenant_seed::SkippedSeed,
+      crate::services::tenant_seed::SkipReason,
       // Feature Management Models
       crate::handlers::features::FeaturesResponse,
       crate::handlers::features::UpdateFeaturesRequest,
+      crate::handlers::features::UpdateFeaturesResponse,
       crate::domain::features::FeatureManager,
+      crate::domain::features::FeatureRollout,
+      crate::domain::features::FeatureEvaluation,
+      crate::domain::features::EvaluationReason,
+      crate::handlers::features::FeatureCheckResponse,
       crate::domain::features::JudicialFeatures,
       crate::domain::features::ImplementationTracker,
       crate::domain::features::FeatureStatus,
@@ -605,15 +804,18 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       // Internal Request/Response Types
       crate::handlers::deadline::StatusUpdate,
       crate::handlers::docket::InitSpeedyTrialRequest,
-      crate::handlers::docket::SearchResponse,
+      crate::utils::json_response::Page<crate::domain::docket::DocketEntry>,
       crate::handlers::docket::AvailableSlotResponse,
-      crate::handlers::docket::CalendarSearchResponse,
+      crate::utils::json_response::Page<crate::domain::docket::CalendarEntry>,
       crate::handlers::features::UpdateRequest,
       crate::handlers::features::OverrideRequest,
       crate::handlers::judge::ProcessRequest,
       crate::handlers::judge::ConflictCheckResponse,
-      crate::handlers::judge::SearchResponse,
-      crate::handlers::deadline::SearchResponse,
+      crate::handlers::judge::JudgeConflictMatch,
+      crate::utils::name_match::NameMatch,
+      crate::utils::name_match::MatchKind,
+      crate::utils::json_response::Page<crate::domain::judge::Judge>,
+      crate::utils::json_response::Page<crate::domain::deadline::Deadline>,
       crate::handlers::deadline::ReminderResponse,
       crate::handlers::deadline::PendingExtensionsResponse,
       // Judicial Order Models
@@ -626,6 +828,12 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       crate::domain::order::OrderMetadata,
       crate::domain::order::OrderTemplate,
       crate::domain::order::TemplateVariable,
+      crate::domain::order::TemplateValueErrors,
+      crate::domain::order::TemplateValidationReport,
+      crate::domain::order::InvalidTemplateValue,
+      crate::domain::order::TemplatePreview,
+      crate::domain::order::ExpiringOrderNotice,
+      crate::domain::order::JudgeExpiringOrders,
       crate::domain::order::VariableType,
       crate::handlers::order::CreateOrderRequest,
       crate::handlers::order::UpdateOrderRequest,
@@ -642,19 +850,31 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       crate::domain::opinion::OpinionStatus,
       crate::domain::opinion::Disposition,
       crate::domain::opinion::Citation,
+      crate::domain::opinion::OpinionErratum,
       crate::domain::opinion::JudgeVote,
       crate::domain::opinion::VoteType,
       crate::domain::opinion::RelatedOpinion,
       crate::domain::opinion::OpinionRelationship,
       crate::domain::opinion::LegalCitation,
       crate::domain::opinion::CitationTreatment,
+      crate::domain::opinion::CitationEdge,
+      crate::domain::opinion::CitationGraphNode,
+      crate::domain::opinion::CitationGraph,
+      crate::domain::opinion::RankedOpinion,
       crate::domain::opinion::Headnote,
       crate::domain::opinion::OpinionDraft,
       crate::domain::opinion::DraftComment,
+      crate::handlers::opinion::ChangedLineComment,
+      crate::handlers::opinion::DraftDiffResponse,
+      crate::utils::diff::LineDiff,
+      crate::utils::diff::DiffHunk,
+      crate::utils::diff::DiffLine,
+      crate::utils::diff::DiffLineKind,
       crate::domain::opinion::OpinionStatistics,
       crate::handlers::opinion::CreateOpinionRequest,
       crate::handlers::opinion::UpdateOpinionRequest,
       crate::handlers::opinion::PublishOpinionRequest,
+      crate::handlers::opinion::FileErratumRequest,
       crate::handlers::opinion::AddVoteRequest,
       crate::handlers::opinion::AddCitationRequest,
       crate::handlers::opinion::AddHeadnoteRequest,
@@ -670,6 +890,23 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       crate::domain::sentencing::Sentencing,
       crate::domain::sentencing::CreateSentencingRequest,
       crate::domain::sentencing::GuidelinesRange,
+      crate::domain::sentencing::GuidelinesCalculationAudit,
+      crate::domain::sentencing::CalculationStep,
+      crate::domain::sentencing::CalculationStage,
+      crate::domain::sentencing::CriminalHistoryPointsBreakdown,
+      crate::domain::sentencing::PriorSentencePoints,
+      crate::domain::sentencing::SafetyValveEligibility,
+      crate::domain::sentencing::SafetyValveCriterionResult,
+      crate::domain::sentencing::SafetyValveCriterion,
+      crate::domain::sentencing::GuidelinesTableLookup,
+      crate::domain::sentencing::GuidelinesTableRange,
+      crate::domain::sentencing::BatchGuidelinesRangeRequest,
+      crate::domain::sentencing::BatchGuidelinesRangeResponse,
+      crate::domain::sentencing::AdjustmentStatistics,
+      crate::domain::sentencing::AdjustmentNetEffect,
+      crate::domain::sentencing::GuidelineCompliance,
+      crate::domain::sentencing::JudgeSentencingComparison,
+      crate::domain::sentencing::ComplianceDeltas,
       crate::domain::sentencing::Departure,
       crate::domain::sentencing::DepartureType,
       crate::domain::sentencing::Variance,
@@ -694,12 +931,27 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       crate::domain::attorney::FederalAdmission,
       crate::domain::attorney::ProHacViceAdmission,
       crate::domain::attorney::ProHacViceStatus,
+      crate::domain::attorney::ProHacViceRenewal,
+      crate::domain::attorney::RenewProHacViceRequest,
+      crate::domain::attorney::ExpiringProHacVice,
       crate::domain::attorney::ECFRegistration,
       crate::domain::attorney::CJAAppointment,
+      crate::domain::attorney::BulkCJAAppointmentRequest,
+      crate::domain::attorney::BulkCJAAppointmentResponse,
+      crate::domain::attorney::CJAAppointee,
+      crate::domain::attorney::SkippedCJAAttorney,
       crate::domain::attorney::VoucherStatus,
+      crate::domain::attorney::CJARateCategory,
+      crate::domain::attorney::VoucherLineItem,
+      crate::domain::attorney::AddVoucherLineItemRequest,
+      crate::domain::attorney::ApproveVoucherRequest,
+      crate::domain::attorney::CJAVoucherSummary,
       crate::domain::attorney::CompensationStatus,
       crate::domain::attorney::DisciplinaryAction,
       crate::domain::attorney::DisciplineType,
+      crate::domain::attorney::ReinstatementRequest,
+      crate::domain::attorney::AffectedCase,
+      crate::domain::attorney::DisciplinaryActionResponse,
       crate::domain::attorney::DisciplineStatus,
       crate::domain::attorney::Party,
       crate::domain::attorney::CreatePartyRequest,
@@ -716,10 +968,30 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       crate::domain::common::ConflictType,
       crate::domain::common::ConflictSeverity,
       crate::domain::attorney::ConflictResult,
+      crate::domain::attorney::ConflictCheckTurnaround,
+      crate::domain::attorney::RepresentationContinuity,
+      // Global Search Models
+      crate::services::search_service::GlobalSearchResponse,
+      crate::services::search_service::SearchGroup,
+      crate::services::search_service::SearchHit,
+      crate::services::search_service::SearchType,
+      // Case Timeline Models
+      crate::services::timeline_service::CaseTimeline,
+      crate::services::timeline_service::TimelineEvent,
+      crate::services::timeline_service::TimelineSource,
+      crate::services::timeline_service::TimelineSourceFailure,
+      crate::handlers::webhook::CreateWebhookRequest,
+      crate::domain::webhook::WebhookEventType,
+      crate::domain::webhook::WebhookSubscriptionSummary,
+      crate::domain::webhook::WebhookEvent,
+      crate::domain::webhook::DeliveryAttempt,
+      crate::domain::webhook::DeliveryOutcome,
+      crate::services::webhook_service::DeliveryReport,
       crate::domain::attorney::ServiceRecord,
       crate::domain::common::ServiceMethod,
       crate::domain::common::ServiceStatus,
       crate::handlers::attorney::WinRateRequest,
+      crate::handlers::attorney::PartyConflictMatch,
       // PDF Generation Models (Hexagonal Architecture)
       crate::handlers::pdf_hexagonal::Rule16bRequest,
       crate::handlers::pdf_hexagonal::CourtOrderRequest,
@@ -727,16 +999,37 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       crate::handlers::pdf_hexagonal::WaiverIndictmentRequest,
       crate::handlers::pdf_hexagonal::ConditionsReleaseRequest,
       crate::handlers::pdf_hexagonal::CriminalJudgmentRequest,
+      crate::handlers::pdf_hexagonal::SubpoenaRequest,
+      crate::handlers::pdf_hexagonal::SummonsRequest,
+      crate::handlers::pdf_hexagonal::SummonsResponse,
       crate::handlers::pdf_hexagonal::PdfResponse,
       crate::handlers::pdf_hexagonal::BatchPdfRequest,
       crate::handlers::pdf_hexagonal::BatchPdfResponse,
+      crate::handlers::pdf_hexagonal::BatchItemResult,
+      crate::handlers::pdf_hexagonal::BatchItemStatus,
+      crate::domain::document::BatchFailure,
       crate::handlers::pdf_hexagonal::DocumentRequestDto,
       crate::handlers::pdf_hexagonal::StoreSignatureRequest,
+      crate::handlers::pdf_hexagonal::SignatureVerificationResponse,
+      crate::handlers::pdf_hexagonal::SignatureMetadata,
+      crate::domain::document::SignatureRejection,
+      crate::domain::document::SignatureCheck,
+      crate::domain::document::PageLayout,
+      crate::domain::document::DefendantNames,
+      crate::domain::document::PageSize,
+      crate::domain::document::Margins,
       // Configuration Models
       crate::domain::config::Configuration,
       crate::domain::config::ConfigOverride,
       crate::domain::config::ConfigResponse,
       crate::domain::config::ConfigMetadata,
+      crate::domain::config::ConfigDiff,
+      crate::domain::config::ConfigValueChange,
+      crate::domain::config::ConfigChangeKind,
+      crate::domain::config::ConfigOverrideVersion,
+      crate::domain::config::ConfigVersionAction,
+      crate::domain::config::CourtClosure,
+      crate::domain::case_number::CaseNumberFormat,
       // Rules Engine Models
       crate::domain::rule::Rule,
       crate::domain::rule::RuleSource,
@@ -775,6 +1068,8 @@ pub fn render_openapi_docs_ui(req: Request, _p: Params) -> anyhow::Result<impl I
       // Health & Error Models
       crate::handlers::health::HealthStatus,
       crate::error::ErrorResponse,
+      crate::error::ErrorBody,
+      crate::error::ErrorCode,
     )
   )
 )]
@@ -4,10 +4,12 @@
 //! and implementation tracking in the judicial system.
 
 use crate::domain::features::{
-    FeatureManager, JudicialFeatures, ImplementationTracker,
-    FeatureStatus, ImplementationStatus
+    self, DependencyGraph, FeatureManager, JudicialFeatures, ImplementationTracker,
+    FeatureStatus, ImplementationStatus, FeatureRollout, FeatureEvaluation
 };
 use crate::error::{ApiError, ApiResult};
+use crate::utils::{query_parser, tenant};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
 use spin_sdk::key_value::Store;
@@ -22,10 +24,50 @@ pub struct UpdateRequest {
 }
 
 /// Request to override feature flags
+///
+/// `enabled` applies wherever the rollout doesn't decide: to districts on
+/// neither list when no percentage is given, and to requests without a
+/// district. Giving none of the rollout fields removes any existing rollout.
 #[derive(Deserialize, ToSchema)]
 pub struct OverrideRequest {
     pub feature_path: String,
     pub enabled: bool,
+    /// Percentage of districts, or of rollout keys, 0 to 100, that get the
+    /// feature; also accepted as `rollout_percent`
+    #[serde(default, alias = "rollout_percent")]
+    pub percentage: Option<u8>,
+    /// Districts that always get the feature, unless denied
+    #[serde(default)]
+    pub allow_districts: Vec<String>,
+    /// Districts that never get the feature
+    #[serde(default)]
+    pub deny_districts: Vec<String>,
+    /// When the override and rollout lapse and the default returns
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl OverrideRequest {
+    /// The rollout the request describes, if it gives any rollout field
+    fn rollout(&self) -> Option<FeatureRollout> {
+        let rollout = FeatureRollout {
+            percentage: self.percentage,
+            allow_districts: self.allow_districts.clone(),
+            deny_districts: self.deny_districts.clone(),
+            expires_at: self.expires_at,
+        };
+        (rollout != FeatureRollout::default()).then_some(rollout)
+    }
+}
+
+/// Result of checking a feature, with the explanation when asked for
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FeatureCheckResponse {
+    pub feature_path: String,
+    pub enabled: bool,
+    /// Present with `?explain=true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<FeatureEvaluation>,
 }
 
 const FEATURES_KEY: &str = "features:config";
@@ -50,10 +92,21 @@ pub struct FeaturesResponse {
 /// Request to update feature flags
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateFeaturesRequest {
+    /// Feature path, e.g. `advanced.judge_assignment`, or its short name,
+    /// e.g. `judge_assignment`
     pub feature_path: String,
     pub enabled: bool,
 }
 
+/// Result of updating a feature flag
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UpdateFeaturesResponse {
+    pub success: bool,
+    pub feature_path: String,
+    /// Prerequisites enabled along with the feature by `?cascade=true`
+    pub enabled_dependencies: Vec<String>,
+}
+
 /// Get current feature flags
 #[utoipa::path(
     get,
@@ -98,13 +151,20 @@ pub fn get_features(_req: Request, _params: Params) -> ApiResult<impl IntoRespon
 }
 
 /// Update feature flags
+///
+/// A feature can only be enabled once the features it depends on are. With
+/// `?cascade=true` its disabled prerequisites are enabled too; otherwise the
+/// request is rejected and `details.unmet_dependencies` lists them.
 #[utoipa::path(
     patch,
     path = "/api/features",
     request_body = UpdateFeaturesRequest,
+    params(
+        ("cascade" = Option<bool>, Query, description = "Also enable the feature's disabled prerequisites (default false)")
+    ),
     responses(
-        (status = 200, description = "Feature flag updated successfully"),
-        (status = 400, description = "Invalid feature path or request data"),
+        (status = 200, description = "Feature flag updated successfully", body = UpdateFeaturesResponse),
+        (status = 400, description = "Invalid feature path, request data or unmet dependencies"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Feature Management"
@@ -112,6 +172,10 @@ pub fn get_features(_req: Request, _params: Params) -> ApiResult<impl IntoRespon
 pub fn update_feature(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
     let body = req.body();
     let request: UpdateFeaturesRequest = serde_json::from_slice(body)?;
+    let cascade = query_parser::get_bool(&query_parser::parse_query_string(req.query()), "cascade").unwrap_or(false);
+
+    let feature_path = features::feature_path(&request.feature_path)
+        .ok_or_else(|| ApiError::BadRequest(format!("Unknown feature: {}", request.feature_path)))?;
 
     let store = Store::open("default")
         .map_err(|e| ApiError::Internal(format!("Store error: {}", e)))?;
@@ -123,29 +187,32 @@ pub fn update_feature(req: Request, _params: Params) -> ApiResult<impl IntoRespo
         JudicialFeatures::default()
     };
 
-    // Update the appropriate feature based on the path
-    match request.feature_path.as_str() {
-        "case_management" => features.core.case_management = request.enabled,
-        "judge_assignment" => features.advanced.judge_assignment = request.enabled,
-        "docket_management" => features.core.basic_docket = request.enabled,
-        "calendar_scheduling" => features.advanced.automated_scheduling = request.enabled,
-        "deadline_tracking" => features.advanced.deadline_tracking = request.enabled,
-        "reporting" => features.advanced.statistical_reporting = request.enabled,
-        "party_management" => features.core.party_management = request.enabled,
-        "sentencing_calculator" => features.advanced.sentencing_calculator = request.enabled,
-        "mdl_proceedings" => features.experimental.mdl_proceedings = request.enabled,
-        "ai_assisted_research" => features.experimental.ai_assisted_research = request.enabled,
-        "automated_transcription" => features.experimental.automated_transcription = request.enabled,
-        "predictive_analytics" => features.experimental.predictive_analytics = request.enabled,
-        _ => return Err(ApiError::BadRequest(format!("Unknown feature: {}", request.feature_path))),
-    }
+    let enabled_dependencies = if request.enabled {
+        features.enable(feature_path, &DependencyGraph::standard(), cascade).map_err(|unmet| {
+            ApiError::BadRequest(format!(
+                "{} depends on disabled features: {}",
+                feature_path,
+                unmet.join(", ")
+            ))
+            .with_details(serde_json::json!({ "unmet_dependencies": unmet }))
+        })?
+    } else {
+        features.set(feature_path, false);
+        Vec::new()
+    };
 
     store.set_json(FEATURES_KEY, &features)
         .map_err(|e| ApiError::Internal(format!("Store error: {}", e)))?;
 
+    let response = UpdateFeaturesResponse {
+        success: true,
+        feature_path: feature_path.to_string(),
+        enabled_dependencies,
+    };
+
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
-        .body(b"{\"success\": true}".to_vec())
+        .body(serde_json::to_vec(&response)?)
         .build())
 }
 
@@ -356,20 +423,27 @@ pub fn create_feature_manager(_req: Request, _params: Params) -> ApiResult<impl
 }
 
 /// Check if a specific feature is enabled
+///
+/// The feature is evaluated for the district in the `X-Court-District`
+/// header, so rollout lists and percentages apply to it. A `key`, such as a
+/// user ID, is bucketed for the percentage instead of the district.
 #[utoipa::path(
     get,
-    path = "/api/features/{feature_path}/enabled",
+    path = "/api/features/enabled/{feature_path}",
     params(
-        ("feature_path" = String, Path, description = "Feature path to check")
+        ("feature_path" = String, Path, description = "Feature path to check"),
+        ("X-Court-District" = Option<String>, Header, description = "District to evaluate the rollout for"),
+        ("key" = Option<String>, Query, description = "Stable rollout key, such as a tenant or user ID, to bucket instead of the district"),
+        ("explain" = Option<bool>, Query, description = "Include the rule that decided the result")
     ),
     responses(
-        (status = 200, description = "Feature enablement status"),
+        (status = 200, description = "Feature enablement status", body = FeatureCheckResponse),
         (status = 400, description = "Feature path required"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Feature Management"
 )]
-pub fn is_feature_enabled(_req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+pub fn is_feature_enabled(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
     let feature_path = params
         .get("feature_path")
         .ok_or_else(|| ApiError::BadRequest("Feature path required".to_string()))?;
@@ -384,14 +458,22 @@ pub fn is_feature_enabled(_req: Request, params: Params) -> ApiResult<impl IntoR
         FeatureManager::new()
     };
 
-    let is_enabled = manager.is_enabled(feature_path);
+    let tenant_id = tenant::get_tenant_id(&req);
+    let district = Some(tenant_id.as_str()).filter(|t| *t != "TENANT_NOT_SPECIFIED");
+    let query = query_parser::parse_query_string(req.query());
+    let key = query_parser::get_string(&query, "key").filter(|k| !k.is_empty());
+    let evaluation = manager.evaluate(feature_path, district, key.as_deref(), Utc::now());
+    let explain = query_parser::get_bool(&query, "explain").unwrap_or(false);
+
+    let response = FeatureCheckResponse {
+        feature_path: feature_path.to_string(),
+        enabled: evaluation.enabled,
+        explanation: explain.then_some(evaluation),
+    };
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
-        .body(serde_json::to_vec(&serde_json::json!({
-            "feature_path": feature_path,
-            "enabled": is_enabled
-        }))?)
+        .body(serde_json::to_vec(&response)?)
         .build())
 }
 
@@ -411,6 +493,11 @@ pub fn set_feature_override(req: Request, _params: Params) -> ApiResult<impl Int
 
     let body = req.body();
     let request: OverrideRequest = serde_json::from_slice(body)?;
+    let rollout = request
+        .rollout()
+        .map(FeatureRollout::normalized)
+        .transpose()
+        .map_err(ApiError::BadRequest)?;
 
     let store = Store::open("default")
         .map_err(|e| ApiError::Internal(format!("Store error: {}", e)))?;
@@ -423,6 +510,7 @@ pub fn set_feature_override(req: Request, _params: Params) -> ApiResult<impl Int
     };
 
     manager.set_override(&request.feature_path, request.enabled);
+    manager.set_rollout(&request.feature_path, rollout);
 
     store.set_json("features:manager", &manager)
         .map_err(|e| ApiError::Internal(format!("Store error: {}", e)))?;
@@ -433,10 +521,10 @@ pub fn set_feature_override(req: Request, _params: Params) -> ApiResult<impl Int
         .build())
 }
 
-/// Clear all feature overrides
+/// Clear all feature overrides and rollouts
 #[utoipa::path(
     delete,
-    path = "/api/features/override",
+    path = "/api/features/overrides",
     responses(
         (status = 200, description = "All feature overrides cleared successfully"),
         (status = 500, description = "Internal server error")
@@ -14,6 +14,7 @@ use crate::domain::filing_pipeline::{
 use crate::domain::nef::{DeliveryMethod, NefRecipient, NoticeOfElectronicFiling};
 use crate::domain::rule::{RuleAction, TriggerEvent};
 use crate::error::{ApiError, ApiResult};
+use crate::ports::config_repository::ConfigRepository;
 use crate::ports::deadline_engine::DeadlineEngine;
 use crate::ports::privacy_engine::PrivacyEngine;
 use crate::ports::rules_engine::RulesEngine;
@@ -68,7 +69,7 @@ pub fn submit_filing(req: Request, _params: Params) -> ApiResult<impl IntoRespon
         .map_err(|e| ApiError::BadRequest(format!("Invalid JSON: {}", e)))?;
 
     // Get rules repository for the district
-    let rules_repo = RepositoryFactory::rules_repo_validated(&req)?;
+    let rules_repo = RepositoryFactory::rules_repo(&req)?;
 
     // Build filing context from submission
     let context = build_filing_context(&submission, &req)?;
@@ -106,7 +107,10 @@ pub fn submit_filing(req: Request, _params: Params) -> ApiResult<impl IntoRespon
     }
 
     // Step 3: Compute deadlines for GenerateDeadline actions
-    let deadline_engine = FrcpDeadlineEngine::new();
+    let district_id = crate::utils::tenant::get_config_district_id(&req);
+    let config_repo = RepositoryFactory::config_repo(&req)?;
+    let closures = futures::executor::block_on(config_repo.get_closure_dates(&district_id))?;
+    let deadline_engine = FrcpDeadlineEngine::with_closures(closures);
     let today = Utc::now().date_naive();
     let service_method = context
         .service_method
@@ -203,7 +207,7 @@ pub fn validate_filing(req: Request, _params: Params) -> ApiResult<impl IntoResp
     let submission: FilingSubmission = serde_json::from_slice(body)
         .map_err(|e| ApiError::BadRequest(format!("Invalid JSON: {}", e)))?;
 
-    let rules_repo = RepositoryFactory::rules_repo_validated(&req)?;
+    let rules_repo = RepositoryFactory::rules_repo(&req)?;
     let context = build_filing_context(&submission, &req)?;
 
     // Privacy scan
@@ -315,7 +319,7 @@ fn extract_district(req: &Request) -> Result<String, ApiError> {
             }
         }
     }
-    Err(ApiError::BadRequest(
+    Err(ApiError::TenantInvalid(
         "Missing required header: X-Court-District or X-Tenant-ID".to_string(),
     ))
 }
@@ -4,19 +4,28 @@ use crate::error::ApiResult;
 use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
 use spin_sdk::key_value::Store;
 use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Key the health check writes and reads back; nothing else uses it
+const HEALTH_PROBE_KEY: &str = "__health__:probe";
 
 /// Health status response
 #[derive(serde::Serialize, ToSchema)]
 pub struct HealthStatus {
+    /// "healthy", or "unhealthy" when the KV probe fails
     status: &'static str,
+    /// "ok" when a value could be written to the KV store and read back, else "error"
+    kv: &'static str,
     version: &'static str,
-    storage: &'static str,
     timestamp: String,
 }
 
 /// Health check endpoint
 ///
-/// Returns the health status of the API including storage connectivity
+/// Returns the health status of the API. The KV store is probed by writing
+/// a reserved key and reading it back; when that fails the response is a
+/// 503 so orchestrators can tell the backing store is broken. The probe
+/// touches only its own key and never lists the store, so it stays fast.
 #[utoipa::path(
     get,
     path = "/api/health",
@@ -24,33 +33,33 @@ pub struct HealthStatus {
     description = "Check the health status of the API",
     responses(
         (status = 200, description = "API is healthy", body = HealthStatus),
-        (status = 503, description = "API is unhealthy")
+        (status = 503, description = "KV store probe failed", body = HealthStatus)
     )
 )]
 pub(crate) fn health_check(_req: Request, _p: Params) -> ApiResult<impl IntoResponse> {
-    // Check if storage is accessible
-    let storage_status = match Store::open_default() {
-        Ok(store) => {
-            // Try to list keys to ensure storage is working
-            match store.get_keys() {
-                Ok(_) => "connected",
-                Err(_) => "error",
-            }
-        }
-        Err(_) => "disconnected",
-    };
+    let kv_ok = probe_kv();
 
     let health = HealthStatus {
-        status: if storage_status == "connected" { "healthy" } else { "degraded" },
+        status: if kv_ok { "healthy" } else { "unhealthy" },
+        kv: if kv_ok { "ok" } else { "error" },
         version: env!("CARGO_PKG_VERSION"),
-        storage: storage_status,
         timestamp: chrono::Utc::now().to_rfc3339(),
     };
 
-    let status_code = if storage_status == "connected" { 200 } else { 503 };
+    let status_code = if kv_ok { 200 } else { 503 };
 
     Ok(ResponseBuilder::new(status_code)
         .header("content-type", "application/json")
-        .body(serde_json::to_vec(&health).unwrap())
+        .body(serde_json::to_vec(&health)?)
         .build())
-}
\ No newline at end of file
+}
+
+/// Write a fresh value to the probe key and check it reads back unchanged
+fn probe_kv() -> bool {
+    let Ok(store) = Store::open_default() else {
+        return false;
+    };
+    let token = Uuid::new_v4().to_string();
+    store.set(HEALTH_PROBE_KEY, token.as_bytes()).is_ok()
+        && store.get(HEALTH_PROBE_KEY).ok().flatten().as_deref() == Some(token.as_bytes())
+}
@@ -6,15 +6,29 @@
 use crate::domain::judge::{
     Judge, JudgeTitle, JudgeStatus, CaseAssignment, RecusalMotion,
     ConflictOfInterest, JudgeConflictType, RecusalReason, RecusalStatus,
-    AssignmentType, JudgeAssignmentService, CaseType
+    AssignmentType, AssignmentStrategy, JudgeAssignmentService, CaseType,
+    ConflictExclusion, AssignmentCriteria
 };
+use crate::domain::judge_unavailability::{self, Unavailability, UnavailabilityType};
+use crate::domain::magistrate_consent::MagistrateAuthority;
+use crate::adapters::pdf_writer_adapter::PdfWriterAdapter;
+use crate::domain::docket::{DocketService, JudicialActionItem};
+use crate::domain::document::District;
+use crate::domain::weekly_docket::WeeklyDocket;
 use crate::error::{ApiError, ApiResult};
+use crate::ports::case_repository::CaseRepository;
+use crate::ports::config_repository::ConfigRepository;
+use crate::ports::deadline_repository::DeadlineRepository;
+use crate::ports::docket_repository::{CalendarRepository, DocketRepository};
+use crate::ports::document_generator::PdfRenderer;
 use crate::ports::judge_repository::{
     JudgeRepository, CaseAssignmentRepository, RecusalRepository,
     ConflictRepository, JudgeQuery, JudgeQueryRepository
 };
-use crate::utils::{query_parser, repository_factory::RepositoryFactory};
-use chrono::{DateTime, Utc};
+use crate::utils::name_match::{self, NameMatch};
+use crate::utils::{json_response::Page, query_parser, repository_factory::RepositoryFactory, tenant};
+use chrono::{DateTime, NaiveDate, Utc};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
 use serde::{Deserialize, Serialize};
 use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
 use uuid::Uuid;
@@ -26,19 +40,25 @@ pub struct ConflictCheckResponse {
     pub conflicts_found: bool,
     pub judges_with_conflicts: Vec<Uuid>,
     pub conflict_details: Vec<(Uuid, ConflictOfInterest)>,
+    /// How the party matched each conflict, in the order of `conflict_details`
+    pub matches: Vec<JudgeConflictMatch>,
 }
 
-/// Search response for judges
+/// A judge's conflict whose party name matched the one checked
 #[derive(Serialize, ToSchema)]
-pub struct SearchResponse {
-    pub judges: Vec<Judge>,
-    pub total: usize,
+pub struct JudgeConflictMatch {
+    pub judge_id: Uuid,
+    pub conflict_id: Uuid,
+    #[serde(flatten)]
+    pub name_match: NameMatch,
 }
 
 /// Request to process recusal
 #[derive(Deserialize, ToSchema)]
 pub struct ProcessRequest {
-    pub replacement_judge_id: Uuid,
+    /// Replacement judge; the least-loaded eligible judge when omitted
+    #[serde(default)]
+    pub replacement_judge_id: Option<Uuid>,
     pub parties: Vec<String>,
 }
 
@@ -66,6 +86,50 @@ pub struct AssignCaseRequest {
     pub preferred_date: Option<DateTime<Utc>>,
     pub assignment_type: AssignmentType,
     pub reason: String,
+    /// Overrides the district's configured strategy for this assignment
+    ///
+    /// There is no seed: the server draws one and keeps it on the
+    /// assignment, so a caller can't try seeds on the preview and submit
+    /// the one that picks the judge they want.
+    #[serde(default)]
+    pub strategy: Option<AssignmentStrategy>,
+    /// Lets a magistrate judge be drawn without every party's consent
+    #[serde(default)]
+    pub magistrate_override_reason: Option<String>,
+}
+
+/// Response for a case assignment, including judges screened out for conflicts
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssignCaseResponse {
+    #[serde(flatten)]
+    pub assignment: CaseAssignment,
+    pub excluded_judges: Vec<ConflictExclusion>,
+}
+
+/// Request model for previewing a case assignment draw
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AssignmentPreviewRequest {
+    pub case_type: CaseType,
+    #[serde(default)]
+    pub parties: Vec<String>,
+    pub preferred_date: Option<DateTime<Utc>>,
+    /// Seed for the random strategies so a draw can be reproduced
+    pub seed: Option<u64>,
+    /// Case whose magistrate judge consent applies to the draw
+    #[serde(default)]
+    pub case_id: Option<Uuid>,
+    /// Lets a magistrate judge be drawn without every party's consent
+    #[serde(default)]
+    pub magistrate_override_reason: Option<String>,
+}
+
+/// Outcome of a case assignment draw that was not persisted
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssignmentPreviewResponse {
+    pub strategy: AssignmentStrategy,
+    pub judge_id: Uuid,
+    pub judge_name: String,
+    pub eligible_judge_ids: Vec<Uuid>,
 }
 
 /// Request model for filing a recusal motion
@@ -78,10 +142,23 @@ pub struct FileRecusalRequest {
 }
 
 /// Request model for ruling on recusal
+///
+/// Granting without `replacement_judge_id` selects a replacement automatically.
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct RuleOnRecusalRequest {
     pub status: RecusalStatus,
     pub replacement_judge_id: Option<Uuid>,
+    /// Case parties an automatically selected replacement must be free of conflicts with
+    #[serde(default)]
+    pub parties: Vec<String>,
+}
+
+/// Ruling on a recusal motion, with the judge the case was reassigned to
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RecusalRulingResponse {
+    #[serde(flatten)]
+    pub motion: RecusalMotion,
+    pub replacement_judge: Option<Judge>,
 }
 
 /// Request model for adding conflict of interest
@@ -94,6 +171,19 @@ pub struct AddConflictRequest {
     pub notes: String,
 }
 
+/// Request model for recording a period a judge is unavailable
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AddUnavailabilityRequest {
+    /// First day away (YYYY-MM-DD)
+    pub start: NaiveDate,
+    /// Last day away, inclusive (YYYY-MM-DD)
+    pub end: NaiveDate,
+    #[serde(alias = "type")]
+    pub unavailability_type: UnavailabilityType,
+    #[serde(default)]
+    pub notes: String,
+}
+
 /// Response model for judge workload statistics
 #[derive(Debug, Serialize, ToSchema)]
 pub struct WorkloadResponse {
@@ -104,6 +194,13 @@ pub struct WorkloadResponse {
     pub available_capacity: usize,
 }
 
+/// Workload breakdown for one case type
+#[derive(Serialize, ToSchema)]
+pub struct CaseTypeWorkloadResponse {
+    pub case_type: CaseType,
+    pub workload: WorkloadResponse,
+}
+
 impl WorkloadResponse {
     pub fn from_statistics(stats: crate::ports::judge_repository::WorkloadStatistics) -> Self {
         Self {
@@ -142,7 +239,7 @@ pub fn create_judge(req: Request, _params: Params) -> ApiResult<impl IntoRespons
         request.courtroom,
     );
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -174,7 +271,7 @@ pub fn create_judge(req: Request, _params: Params) -> ApiResult<impl IntoRespons
     ),
 )]
 pub fn get_all_judges(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
         Ok(r) => r,
 
@@ -210,7 +307,7 @@ pub fn get_judge_by_id(req: Request, params: Params) -> ApiResult<impl IntoRespo
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid judge ID".to_string()))?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -230,6 +327,163 @@ pub fn get_judge_by_id(req: Request, params: Params) -> ApiResult<impl IntoRespo
         .build())
 }
 
+/// Get docket entries awaiting action from a judge
+#[utoipa::path(
+    get,
+    path = "/api/judges/{judge_id}/action-queue",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("judge_id" = Uuid, Path, description = "Judge ID")
+    ),
+    responses(
+        (status = 200, description = "Unaddressed filings on the judge's cases, oldest first", body = [JudicialActionItem]),
+        (status = 404, description = "Judge not found"),
+        (status = 400, description = "Invalid judge ID")
+    ),
+    tag = "Judge Management",
+)]
+pub fn get_action_queue(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let judge_id = params
+        .get("judge_id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid judge ID".to_string()))?;
+
+    let repo = RepositoryFactory::judge_repo(&req)?;
+    repo.find_judge_by_id(judge_id)?
+        .ok_or_else(|| ApiError::NotFound("Judge not found".to_string()))?;
+
+    // Only cases the judge still holds; reassigned cases belong to someone else's queue
+    let case_ids = current_case_ids(&repo, judge_id)?;
+
+    let docket_repo = RepositoryFactory::docket_repo(&req)?;
+    let dockets = case_ids
+        .iter()
+        .map(|case_id| docket_repo.find_entries_by_case(*case_id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let queue = DocketService::judicial_action_queue(&dockets, Utc::now());
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&queue)?)
+        .build())
+}
+
+/// Cases currently assigned to a judge, skipping ones since reassigned
+fn current_case_ids(repo: &impl CaseAssignmentRepository, judge_id: Uuid) -> ApiResult<Vec<Uuid>> {
+    let mut case_ids: Vec<Uuid> = Vec::new();
+    for assignment in repo.find_assignments_by_judge(judge_id)? {
+        if case_ids.contains(&assignment.case_id) {
+            continue;
+        }
+        let current = repo.find_assignment_by_case(assignment.case_id)?;
+        if current.is_some_and(|a| a.judge_id == judge_id) {
+            case_ids.push(assignment.case_id);
+        }
+    }
+    Ok(case_ids)
+}
+
+/// Get a judge's events and deadlines for one week, grouped by day
+///
+/// Days follow the district's local time from the `scheduling.utc_offset_hours`
+/// setting (UTC when unset). `format=pdf` returns a printable sheet instead of JSON.
+#[utoipa::path(
+    get,
+    path = "/api/judges/{judge_id}/weekly-docket",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("judge_id" = Uuid, Path, description = "Judge ID"),
+        ("week_of" = Option<String>, Query, description = "Any date in the week (YYYY-MM-DD, defaults to the current week)"),
+        ("format" = Option<String>, Query, description = "json (default) or pdf")
+    ),
+    responses(
+        (status = 200, description = "Events and deadlines for the week, Monday through Sunday", body = WeeklyDocket),
+        (status = 200, description = "Printable weekly docket", content_type = "application/pdf"),
+        (status = 404, description = "Judge not found"),
+        (status = 400, description = "Invalid judge ID, week_of or format")
+    ),
+    tag = "Judge Management",
+)]
+pub fn get_weekly_docket(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let judge_id = params
+        .get("judge_id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid judge ID".to_string()))?;
+
+    let query_params = query_parser::parse_query_string(req.query());
+    let as_pdf = match query_parser::get_string(&query_params, "format").as_deref() {
+        None | Some("json") => false,
+        Some("pdf") => true,
+        Some(other) => {
+            return Err(ApiError::BadRequest(format!(
+                "Invalid format '{}'. Expected json or pdf",
+                other
+            )))
+        }
+    };
+
+    let offset = district_utc_offset(&req)?;
+    let week_of = match query_parser::get_string(&query_params, "week_of") {
+        Some(value) => chrono::NaiveDate::parse_from_str(&value, "%Y-%m-%d")
+            .map_err(|_| ApiError::BadRequest(format!("Invalid week_of: {}", value)))?,
+        None => Utc::now().with_timezone(&offset).date_naive(),
+    };
+
+    let repo = RepositoryFactory::judge_repo(&req)?;
+    let judge = repo
+        .find_judge_by_id(judge_id)?
+        .ok_or_else(|| ApiError::NotFound("Judge not found".to_string()))?;
+
+    let docket_repo = RepositoryFactory::docket_repo(&req)?;
+    let events = docket_repo.find_events_by_judge(judge_id)?;
+
+    let deadline_repo = RepositoryFactory::deadline_repo(&req)?;
+    let mut deadlines = Vec::new();
+    for case_id in current_case_ids(&repo, judge_id)? {
+        deadlines.extend(deadline_repo.find_deadlines_by_case(case_id)?);
+    }
+
+    let docket = WeeklyDocket::build(judge_id, &judge.name, week_of, offset, &events, &deadlines);
+
+    if as_pdf {
+        let district = District::new(tenant::get_tenant_id(&req))
+            .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+        let pdf = PdfWriterAdapter::new()
+            .render_weekly_docket(&district, &docket)
+            .map_err(|e| ApiError::Internal(e.to_string()))?;
+        let filename = format!("weekly-docket-{}-{}.pdf", judge_id, docket.week_start);
+
+        return Ok(ResponseBuilder::new(200)
+            .header("content-type", "application/pdf")
+            .header("content-disposition", format!(r#"attachment; filename="{}""#, filename))
+            .body(pdf)
+            .build());
+    }
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&docket)?)
+        .build())
+}
+
+/// District offset from UTC for grouping calendar events into local days
+pub(crate) fn district_utc_offset(req: &Request) -> ApiResult<chrono::FixedOffset> {
+    let district_id = tenant::get_config_district_id(req);
+    let config_repo = RepositoryFactory::config_repo(req)?;
+    let config = futures::executor::block_on(config_repo.get_merged_config(&district_id, None))?;
+
+    let hours = config.get_i64(WeeklyDocket::UTC_OFFSET_CONFIG_PATH).unwrap_or(0);
+    i32::try_from(hours)
+        .ok()
+        .and_then(WeeklyDocket::offset)
+        .ok_or_else(|| ApiError::Internal(format!(
+            "Configured {} of {} is outside -12..=14",
+            WeeklyDocket::UTC_OFFSET_CONFIG_PATH,
+            hours
+        )))
+}
+
 /// Update judge status
 #[utoipa::path(
     patch,
@@ -255,7 +509,7 @@ pub fn update_judge_status(req: Request, params: Params) -> ApiResult<impl IntoR
     let body = req.body();
     let request: UpdateJudgeStatusRequest = serde_json::from_slice(body)?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -279,27 +533,46 @@ pub fn update_judge_status(req: Request, params: Params) -> ApiResult<impl IntoR
 }
 
 /// Get available judges for assignment
+///
+/// Judges whose vacation covers `on_date` (now, by default) are left out.
 #[utoipa::path(
     get,
     path = "/api/judges/available",
     responses(
         (status = 200, description = "List of available judges", body = [Judge]),
+        (status = 400, description = "Invalid on_date"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Judge Assignment",
     params(
-        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("on_date" = Option<String>, Query, description = "Date to check availability for (YYYY-MM-DD or RFC3339, defaults to now)")
     ),
 )]
 pub fn get_available_judges(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let query_params = query_parser::parse_query_string(req.query());
+    let (window_start, window_end) = match query_parser::get_string(&query_params, "on_date") {
+        Some(value) => availability_window(&value)?,
+        None => (Utc::now(), Utc::now()),
+    };
+
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
         Ok(r) => r,
 
         Err(e) => return Err(e),
 
     };
-    let judges = repo.find_available_judges()?;
+    let on_vacation: Vec<Uuid> = repo
+        .find_judges_on_vacation(window_start, window_end)?
+        .into_iter()
+        .map(|j| j.id)
+        .collect();
+    let judges: Vec<Judge> = repo
+        .find_available_judges()?
+        .into_iter()
+        .filter(|j| !on_vacation.contains(&j.id))
+        .collect();
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -307,14 +580,39 @@ pub fn get_available_judges(req: Request, _params: Params) -> ApiResult<impl Int
         .build())
 }
 
+/// Time window an `on_date` value covers: the whole day for a plain date,
+/// or the single instant for an RFC3339 timestamp
+fn availability_window(value: &str) -> ApiResult<(DateTime<Utc>, DateTime<Utc>)> {
+    if let Ok(instant) = DateTime::parse_from_rfc3339(value) {
+        let instant = instant.with_timezone(&Utc);
+        return Ok((instant, instant));
+    }
+
+    let day = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| ApiError::BadRequest(format!("Invalid on_date: {}", value)))?;
+    let start = day.and_time(chrono::NaiveTime::MIN).and_utc();
+    Ok((start, start + chrono::Duration::days(1) - chrono::Duration::seconds(1)))
+}
+
 /// Assign a case to a judge
+///
+/// Uses the request's `strategy` when given, otherwise the district's
+/// configured strategy. Round-robin assignments advance the district's
+/// wheel cursor for the case type. Judges with a conflict against any party
+/// are screened out first and reported in `excluded_judges`.
+///
+/// Magistrate judges are only drawn once every party on the case has
+/// consented (see `/api/cases/{id}/magistrate-consent`), or when
+/// `magistrate_override_reason` is given; the override is noted in the
+/// assignment's reason.
 #[utoipa::path(
     post,
-    path = "/api/judges/assignments",
+    path = "/api/assignments",
     request_body = AssignCaseRequest,
     responses(
-        (status = 201, description = "Case assigned successfully", body = CaseAssignment),
+        (status = 201, description = "Case assigned successfully", body = AssignCaseResponse),
         (status = 400, description = "Invalid assignment request"),
+        (status = 409, description = "Every available judge has a conflict with a party"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Judge Assignment",
@@ -326,7 +624,7 @@ pub fn assign_case(req: Request, _params: Params) -> ApiResult<impl IntoResponse
     let body = req.body();
     let request: AssignCaseRequest = serde_json::from_slice(body)?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -337,22 +635,79 @@ pub fn assign_case(req: Request, _params: Params) -> ApiResult<impl IntoResponse
 
     };
     let available_judges = repo.find_available_judges()?;
+    let (conflict_free, excluded_judges) =
+        screen_for_conflicts(&repo, &available_judges, &request.parties)?;
+
+    if conflict_free.is_empty() && !excluded_judges.is_empty() {
+        let reasons: Vec<String> = excluded_judges
+            .iter()
+            .map(|e| format!("{} ({})", e.judge_name, e.conflicting_parties.join(", ")))
+            .collect();
+        return Err(ApiError::Conflict(format!(
+            "Every available judge has a conflict with a party; excluded: {}",
+            reasons.join("; ")
+        )));
+    }
+
+    let strategy = match request.strategy {
+        Some(strategy) => strategy,
+        None => district_assignment_strategy(&req)?,
+    };
+    let last_assigned = match strategy {
+        AssignmentStrategy::RoundRobin => repo.get_rotation_cursor(&request.case_type)?,
+        _ => None,
+    };
+
+    let draw_seed = rand::thread_rng().next_u64();
+    let mut rng = StdRng::seed_from_u64(draw_seed);
+
+    let criteria = AssignmentCriteria {
+        case_type: request.case_type,
+        parties: &request.parties,
+        preferred_date: request.preferred_date,
+        magistrate_authority: case_magistrate_authority(
+            &req,
+            Some(request.case_id),
+            request.magistrate_override_reason.as_deref(),
+        )?,
+    };
 
-    // Use the assignment service to find the best judge
+    // Draw a judge using the requested or district assignment strategy. The
+    // full list is passed so excluded judges keep their seat on the
+    // round-robin wheel; the draw itself skips the same conflicts.
     let judge_id = JudgeAssignmentService::assign_judge(
         &available_judges,
-        request.case_type,
-        &request.parties,
-        request.preferred_date,
+        strategy,
+        &criteria,
+        last_assigned,
+        &mut rng,
     ).map_err(|e| ApiError::BadRequest(e))?;
 
+    // Keep the override on the record when it is what let a magistrate preside
+    let mut reason = request.reason;
+    if let MagistrateAuthority::Overridden(override_reason) = &criteria.magistrate_authority {
+        let drew_magistrate = available_judges
+            .iter()
+            .any(|j| j.id == judge_id && j.title == JudgeTitle::MagistrateJudge);
+        if drew_magistrate {
+            reason = format!("{} (magistrate consent overridden: {})", reason, override_reason);
+        }
+    }
+
+    if strategy == AssignmentStrategy::RoundRobin {
+        repo.set_rotation_cursor(&request.case_type, judge_id)?;
+    }
+
     // Create assignment record
-    let assignment = JudgeAssignmentService::create_assignment(
+    let mut assignment = JudgeAssignmentService::create_assignment(
         request.case_id,
         judge_id,
         request.assignment_type,
-        request.reason,
+        reason,
+        Some(strategy),
+        Some(request.case_type),
     );
+    assignment.draw_seed = Some(draw_seed);
 
     // Update judge's caseload
     if let Ok(Some(mut judge)) = repo.find_judge_by_id(judge_id) {
@@ -363,16 +718,160 @@ pub fn assign_case(req: Request, _params: Params) -> ApiResult<impl IntoResponse
     // Save assignment
     repo.save_assignment(&assignment)?;
 
+    let response = AssignCaseResponse { assignment, excluded_judges };
+
     Ok(ResponseBuilder::new(201)
         .header("content-type", "application/json")
-        .body(serde_json::to_vec(&assignment)?)
+        .body(serde_json::to_vec(&response)?)
         .build())
 }
 
+/// Split judges into those free of conflicts with every party and those
+/// excluded, recording which parties conflicted each judge out
+fn screen_for_conflicts<'a>(
+    repo: &impl ConflictRepository,
+    judges: &'a [Judge],
+    parties: &[String],
+) -> ApiResult<(Vec<&'a Judge>, Vec<ConflictExclusion>)> {
+    let mut eligible = Vec::new();
+    let mut excluded = Vec::new();
+
+    for judge in judges {
+        let mut conflicting_parties = Vec::new();
+        for party in parties {
+            if repo.has_conflict(judge.id, party)? {
+                conflicting_parties.push(party.clone());
+            }
+        }
+
+        if conflicting_parties.is_empty() {
+            eligible.push(judge);
+        } else {
+            excluded.push(ConflictExclusion {
+                judge_id: judge.id,
+                judge_name: judge.name.clone(),
+                conflicting_parties,
+            });
+        }
+    }
+
+    Ok((eligible, excluded))
+}
+
+/// Preview a case assignment draw without persisting it
+#[utoipa::path(
+    post,
+    path = "/api/assignments/preview",
+    request_body = AssignmentPreviewRequest,
+    responses(
+        (status = 200, description = "Judge that the district's strategy would draw", body = AssignmentPreviewResponse),
+        (status = 400, description = "No eligible judge or invalid request"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Judge Assignment",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+    ),
+)]
+pub fn preview_assignment(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    let request: AssignmentPreviewRequest = serde_json::from_slice(req.body())?;
+
+    let repo = RepositoryFactory::judge_repo(&req)?;
+    let available_judges = repo.find_available_judges()?;
+    let strategy = district_assignment_strategy(&req)?;
+    let last_assigned = match strategy {
+        AssignmentStrategy::RoundRobin => repo.get_rotation_cursor(&request.case_type)?,
+        _ => None,
+    };
+
+    let mut rng: Box<dyn RngCore> = match request.seed {
+        Some(seed) => Box::new(StdRng::seed_from_u64(seed)),
+        None => Box::new(rand::thread_rng()),
+    };
+
+    let criteria = AssignmentCriteria {
+        case_type: request.case_type,
+        parties: &request.parties,
+        preferred_date: request.preferred_date,
+        magistrate_authority: case_magistrate_authority(
+            &req,
+            request.case_id,
+            request.magistrate_override_reason.as_deref(),
+        )?,
+    };
+
+    let judge_id = JudgeAssignmentService::assign_judge(
+        &available_judges,
+        strategy,
+        &criteria,
+        last_assigned,
+        rng.as_mut(),
+    ).map_err(ApiError::BadRequest)?;
+
+    let eligible_judge_ids = JudgeAssignmentService::eligible_judges(&available_judges, &criteria)
+        .iter()
+        .map(|j| j.id)
+        .collect();
+
+    let judge_name = available_judges
+        .iter()
+        .find(|j| j.id == judge_id)
+        .map(|j| j.name.clone())
+        .unwrap_or_default();
+
+    let preview = AssignmentPreviewResponse {
+        strategy,
+        judge_id,
+        judge_name,
+        eligible_judge_ids,
+    };
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&preview)?)
+        .build())
+}
+
+/// Whether a magistrate judge may preside over the case being assigned
+///
+/// Needs every party's consent on the case under 28 U.S.C. § 636(c), or an
+/// override reason. A case that isn't on file has no consent.
+fn case_magistrate_authority(
+    req: &Request,
+    case_id: Option<Uuid>,
+    override_reason: Option<&str>,
+) -> ApiResult<MagistrateAuthority> {
+    let full_consent = match case_id {
+        Some(case_id) => RepositoryFactory::case_repo(req)?
+            .find_by_id(case_id)?
+            .is_some_and(|case| case.magistrate_consent_summary().full_consent),
+        None => false,
+    };
+    Ok(MagistrateAuthority::new(full_consent, override_reason))
+}
+
+/// Look up the district's assignment strategy from its merged configuration
+fn district_assignment_strategy(req: &Request) -> ApiResult<AssignmentStrategy> {
+    let district_id = tenant::get_config_district_id(req);
+
+    let config_repo = RepositoryFactory::config_repo(req)?;
+    let config = futures::executor::block_on(config_repo.get_merged_config(&district_id, None))?;
+
+    match config.get_string(AssignmentStrategy::CONFIG_PATH) {
+        Some(value) => AssignmentStrategy::from_config_value(&value).ok_or_else(|| {
+            ApiError::InternalServerError(format!(
+                "Unknown case assignment strategy '{}' configured for {}",
+                value, district_id
+            ))
+        }),
+        None => Ok(AssignmentStrategy::default()),
+    }
+}
+
 /// Get case assignment
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/assignment",
+    path = "/api/assignments/case/{case_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID")
@@ -390,7 +889,7 @@ pub fn get_case_assignment(req: Request, params: Params) -> ApiResult<impl IntoR
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid case ID".to_string()))?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -446,9 +945,10 @@ pub fn file_recusal(req: Request, params: Params) -> ApiResult<impl IntoResponse
         status: RecusalStatus::Pending,
         ruling_date: None,
         replacement_judge_id: None,
+        reassignment_status: None,
     };
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -467,6 +967,11 @@ pub fn file_recusal(req: Request, params: Params) -> ApiResult<impl IntoResponse
 }
 
 /// Rule on a recusal motion
+///
+/// When a motion is granted without a `replacement_judge_id`, the least-loaded
+/// available judge who is not on vacation and has no conflict with the given
+/// `parties` is selected. If no judge is eligible the motion is still granted
+/// and `reassignment_status` is `awaiting_reassignment`.
 #[utoipa::path(
     patch,
     path = "/api/recusals/{recusal_id}/ruling",
@@ -476,7 +981,7 @@ pub fn file_recusal(req: Request, params: Params) -> ApiResult<impl IntoResponse
     ),
     request_body = RuleOnRecusalRequest,
     responses(
-        (status = 200, description = "Recusal ruling updated", body = RecusalMotion),
+        (status = 200, description = "Recusal ruling updated", body = RecusalRulingResponse),
         (status = 404, description = "Recusal motion not found"),
         (status = 400, description = "Invalid request data")
     ),
@@ -491,7 +996,7 @@ pub fn rule_on_recusal(req: Request, params: Params) -> ApiResult<impl IntoRespo
     let body = req.body();
     let request: RuleOnRecusalRequest = serde_json::from_slice(body)?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -508,46 +1013,63 @@ pub fn rule_on_recusal(req: Request, params: Params) -> ApiResult<impl IntoRespo
     motion.status = request.status;
     motion.ruling_date = Some(Utc::now());
 
-    if request.status == RecusalStatus::Granted {
+    let replacement_id = if request.status == RecusalStatus::Granted {
         // If granted, handle judge replacement
-        if let Some(replacement_id) = request.replacement_judge_id {
-            motion.replacement_judge_id = Some(replacement_id);
-
-            // Update assignment
-            if let Ok(Some(_assignment)) = repo.find_assignment_by_case(motion.case_id) {
-                let new_assignment = CaseAssignment {
-                    id: Uuid::new_v4(),
-                    case_id: motion.case_id,
-                    judge_id: replacement_id,
-                    assignment_type: AssignmentType::Reassignment,
-                    assigned_date: Utc::now(),
-                    reason: format!("Reassigned due to recusal: {}", motion.detailed_grounds),
-                    previous_judge_id: Some(motion.judge_id),
-                    reassignment_reason: Some("Recusal granted".to_string()),
-                };
-                repo.save_assignment(&new_assignment)?;
-
-                // Update caseloads
-                if let Ok(Some(mut old_judge)) = repo.find_judge_by_id(motion.judge_id) {
-                    old_judge.unassign_case();
-                    repo.save_judge(&old_judge)?;
-                }
-                if let Ok(Some(mut new_judge)) = repo.find_judge_by_id(replacement_id) {
-                    let _ = new_judge.assign_case();
-                    repo.save_judge(&new_judge)?;
-                }
-            }
+        match request.replacement_judge_id {
+            Some(id) => Some(id),
+            None => select_replacement_judge(&repo, &motion, &request.parties)?,
         }
-    }
+    } else {
+        None
+    };
 
-    repo.save_recusal(&motion)?;
+    // The reassignment, the judges' caseloads and the ruling land together
+    repo.transaction(|repo| -> ApiResult<()> {
+        if request.status == RecusalStatus::Granted {
+            JudgeAssignmentService::execute_reassignment(repo, &mut motion, replacement_id, "Recusal granted")?;
+        }
+        repo.save_recusal(&motion)?;
+        Ok(())
+    })?;
+
+    let replacement_judge = match replacement_id {
+        Some(id) => repo.find_judge_by_id(id)?,
+        None => None,
+    };
+
+    let response = RecusalRulingResponse { motion, replacement_judge };
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
-        .body(serde_json::to_vec(&motion)?)
+        .body(serde_json::to_vec(&response)?)
         .build())
 }
 
+/// Pick a replacement for a recused judge among today's available judges,
+/// or `None` when every candidate is on vacation, at capacity or conflicted
+fn select_replacement_judge(
+    repo: &(impl JudgeQueryRepository + ConflictRepository),
+    motion: &RecusalMotion,
+    parties: &[String],
+) -> ApiResult<Option<Uuid>> {
+    let now = Utc::now();
+    let on_vacation: Vec<Uuid> = repo
+        .find_judges_on_vacation(now, now)?
+        .into_iter()
+        .map(|j| j.id)
+        .collect();
+    let available_judges: Vec<Judge> = repo
+        .find_available_judges()?
+        .into_iter()
+        .filter(|j| !on_vacation.contains(&j.id))
+        .collect();
+
+    let (conflict_free, _) = screen_for_conflicts(repo, &available_judges, parties)?;
+    let candidates: Vec<Judge> = conflict_free.into_iter().cloned().collect();
+
+    Ok(JudgeAssignmentService::process_recusal(motion, &candidates, parties).ok())
+}
+
 /// Get pending recusals
 #[utoipa::path(
     get,
@@ -562,7 +1084,7 @@ pub fn rule_on_recusal(req: Request, params: Params) -> ApiResult<impl IntoRespo
     ),
 )]
 pub fn get_pending_recusals(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
         Ok(r) => r,
 
@@ -613,7 +1135,7 @@ pub fn add_conflict(req: Request, params: Params) -> ApiResult<impl IntoResponse
         notes: request.notes,
     };
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -632,16 +1154,20 @@ pub fn add_conflict(req: Request, params: Params) -> ApiResult<impl IntoResponse
 }
 
 /// Check for conflicts with a party
+///
+/// Party names match after normalization and, above `threshold`, by
+/// similarity; each match says whether it was exact, normalized or fuzzy.
 #[utoipa::path(
     get,
-    path = "/api/conflicts/check/{party}",
+    path = "/api/judges/conflicts/check/{party}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("party" = String, Path, description = "Party name to check for conflicts")
+        ("party" = String, Path, description = "Party name to check for conflicts"),
+        ("threshold" = Option<f64>, Query, description = "Similarity from 0 to 1 a fuzzy match needs (default 0.9)")
     ),
     responses(
-        (status = 200, description = "Conflict check results"),
-        (status = 400, description = "Party name required")
+        (status = 200, description = "Conflict check results", body = ConflictCheckResponse),
+        (status = 400, description = "Party name required or invalid threshold")
     ),
     tag = "Conflict Management",
 )]
@@ -650,7 +1176,7 @@ pub fn check_conflicts(req: Request, params: Params) -> ApiResult<impl IntoRespo
         .get("party")
         .ok_or_else(|| ApiError::BadRequest("Party name required".to_string()))?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -660,13 +1186,22 @@ pub fn check_conflicts(req: Request, params: Params) -> ApiResult<impl IntoRespo
 
 
     };
-    let conflicts = repo.find_conflicts_by_party(party_name)?;
+    let threshold = name_match::threshold_param(&query_parser::parse_query_string(req.query()))?;
+    let conflicts = repo.find_conflicts_by_party(party_name, threshold)?;
 
 
     let response = ConflictCheckResponse {
         conflicts_found: !conflicts.is_empty(),
-        judges_with_conflicts: conflicts.iter().map(|(judge_id, _)| *judge_id).collect(),
-        conflict_details: conflicts,
+        judges_with_conflicts: conflicts.iter().map(|(judge_id, _, _)| *judge_id).collect(),
+        matches: conflicts
+            .iter()
+            .map(|(judge_id, conflict, name_match)| JudgeConflictMatch {
+                judge_id: *judge_id,
+                conflict_id: conflict.id,
+                name_match: name_match.clone(),
+            })
+            .collect(),
+        conflict_details: conflicts.into_iter().map(|(judge_id, conflict, _)| (judge_id, conflict)).collect(),
     };
 
     Ok(ResponseBuilder::new(200)
@@ -689,7 +1224,7 @@ pub fn check_conflicts(req: Request, params: Params) -> ApiResult<impl IntoRespo
     ),
 )]
 pub fn get_workload_stats(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
         Ok(r) => r,
 
@@ -705,6 +1240,36 @@ pub fn get_workload_stats(req: Request, _params: Params) -> ApiResult<impl IntoR
         .build())
 }
 
+/// Get judge workload statistics broken down by case type
+#[utoipa::path(
+    get,
+    path = "/api/judges/workload/by-type",
+    responses(
+        (status = 200, description = "Workload statistics per case type", body = [CaseTypeWorkloadResponse]),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Judge Analytics",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+    ),
+)]
+pub fn get_workload_by_type(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    let repo = RepositoryFactory::judge_repo(&req)?;
+    let response: Vec<CaseTypeWorkloadResponse> = repo
+        .workload_by_type()?
+        .into_iter()
+        .map(|bucket| CaseTypeWorkloadResponse {
+            case_type: bucket.case_type,
+            workload: WorkloadResponse::from_statistics(bucket.statistics),
+        })
+        .collect();
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&response)?)
+        .build())
+}
+
 /// Search judges with filters
 #[utoipa::path(
     get,
@@ -717,11 +1282,12 @@ pub fn get_workload_stats(req: Request, _params: Params) -> ApiResult<impl IntoR
         ("accepts_criminal" = Option<bool>, Query, description = "Filter by criminal case acceptance"),
         ("accepts_civil" = Option<bool>, Query, description = "Filter by civil case acceptance"),
         ("max_caseload" = Option<u32>, Query, description = "Maximum caseload percentage"),
+        ("q" = Option<String>, Query, description = "Text to match against judge name or courtroom"),
         ("offset" = Option<usize>, Query, description = "Pagination offset"),
         ("limit" = Option<usize>, Query, description = "Pagination limit")
     ),
     responses(
-        (status = 200, description = "Search results with judges and total count"),
+        (status = 200, description = "A page of judges; also returned under the deprecated `judges` field", body = Page<Judge>),
         (status = 500, description = "Internal server error")
     ),
     tag = "Judge Management",
@@ -737,11 +1303,13 @@ pub fn search_judges(req: Request, _params: Params) -> ApiResult<impl IntoRespon
         accepts_criminal: query_parser::get_bool(&params, "accepts_criminal"),
         accepts_civil: query_parser::get_bool(&params, "accepts_civil"),
         max_caseload_percentage: query_parser::get_usize(&params, "max_caseload").map(|u| u as f32),
+        search_text: query_parser::get_text(&params, "q"),
         offset: query_parser::get_usize(&params, "offset").unwrap_or(0),
         limit: query_parser::get_usize(&params, "limit").unwrap_or(50),
     };
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let (offset, limit) = (query.offset, query.limit);
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -753,8 +1321,7 @@ pub fn search_judges(req: Request, _params: Params) -> ApiResult<impl IntoRespon
     };
     let (judges, total) = repo.search_judges(query)?;
 
-
-    let response = SearchResponse { judges, total };
+    let response = Page::new(judges, total, offset, limit).with_legacy_field("judges");
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -781,9 +1348,9 @@ pub fn get_judges_by_status(req: Request, params: Params) -> ApiResult<impl Into
         .get("status")
         .ok_or_else(|| ApiError::BadRequest("Status required".to_string()))?;
 
-    let status: JudgeStatus = serde_json::from_str(&format!("\"{}\"", status_str))?;
+    let status: JudgeStatus = query_parser::parse_enum_param("status", status_str)?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -820,7 +1387,7 @@ pub fn get_judges_by_district(req: Request, params: Params) -> ApiResult<impl In
         .get("district")
         .ok_or_else(|| ApiError::BadRequest("District required".to_string()))?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -859,7 +1426,7 @@ pub fn delete_judge(req: Request, params: Params) -> ApiResult<impl IntoResponse
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid judge ID".to_string()))?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -880,7 +1447,7 @@ pub fn delete_judge(req: Request, params: Params) -> ApiResult<impl IntoResponse
 /// Get assignment history for a case
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/assignment-history",
+    path = "/api/assignments/history/{case_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID")
@@ -897,7 +1464,7 @@ pub fn get_assignment_history(req: Request, params: Params) -> ApiResult<impl In
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid case ID".to_string()))?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -936,7 +1503,7 @@ pub fn delete_assignment(req: Request, params: Params) -> ApiResult<impl IntoRes
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid assignment ID".to_string()))?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -957,7 +1524,7 @@ pub fn delete_assignment(req: Request, params: Params) -> ApiResult<impl IntoRes
 /// Get recusals by case
 #[utoipa::path(
     get,
-    path = "/api/cases/{case_id}/recusals",
+    path = "/api/recusals/case/{case_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("case_id" = Uuid, Path, description = "Case ID")
@@ -974,7 +1541,7 @@ pub fn get_recusals_by_case(req: Request, params: Params) -> ApiResult<impl Into
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid case ID".to_string()))?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -995,7 +1562,7 @@ pub fn get_recusals_by_case(req: Request, params: Params) -> ApiResult<impl Into
 /// Get recusals by judge
 #[utoipa::path(
     get,
-    path = "/api/judges/{judge_id}/recusals",
+    path = "/api/recusals/judge/{judge_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("judge_id" = Uuid, Path, description = "Judge ID")
@@ -1012,7 +1579,7 @@ pub fn get_recusals_by_judge(req: Request, params: Params) -> ApiResult<impl Int
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid judge ID".to_string()))?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -1050,7 +1617,7 @@ pub fn get_conflicts_by_judge(req: Request, params: Params) -> ApiResult<impl In
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid judge ID".to_string()))?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -1071,7 +1638,7 @@ pub fn get_conflicts_by_judge(req: Request, params: Params) -> ApiResult<impl In
 /// Check if judge has conflict with party
 #[utoipa::path(
     get,
-    path = "/api/judges/{judge_id}/conflicts/check/{party}",
+    path = "/api/judges/{judge_id}/conflicts/{party}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
         ("judge_id" = Uuid, Path, description = "Judge ID"),
@@ -1093,7 +1660,7 @@ pub fn has_conflict(req: Request, params: Params) -> ApiResult<impl IntoResponse
         .get("party")
         .ok_or_else(|| ApiError::BadRequest("Party name required".to_string()))?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -1138,7 +1705,7 @@ pub fn delete_conflict(req: Request, params: Params) -> ApiResult<impl IntoRespo
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid conflict ID".to_string()))?;
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -1156,6 +1723,129 @@ pub fn delete_conflict(req: Request, params: Params) -> ApiResult<impl IntoRespo
         .build())
 }
 
+/// Record a period when a judge is unavailable
+///
+/// Entries for the same judge may not overlap, including by a single day;
+/// cancel the existing entry first to change it.
+#[utoipa::path(
+    post,
+    path = "/api/judges/{judge_id}/unavailability",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("judge_id" = Uuid, Path, description = "Judge ID")
+    ),
+    request_body = AddUnavailabilityRequest,
+    responses(
+        (status = 201, description = "Unavailability recorded", body = Unavailability),
+        (status = 400, description = "Invalid request data or end before start"),
+        (status = 404, description = "Judge not found"),
+        (status = 409, description = "Overlaps an existing unavailability entry")
+    ),
+    tag = "Judge Management",
+)]
+pub fn add_unavailability(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let judge_id = params
+        .get("judge_id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid judge ID".to_string()))?;
+
+    let request: AddUnavailabilityRequest = serde_json::from_slice(req.body())?;
+    let entry = Unavailability::new(request.start, request.end, request.unavailability_type, request.notes)
+        .map_err(ApiError::BadRequest)?;
+
+    let repo = RepositoryFactory::judge_repo(&req)?;
+    let mut judge = repo
+        .find_judge_by_id(judge_id)?
+        .ok_or_else(|| ApiError::NotFound("Judge not found".to_string()))?;
+
+    judge_unavailability::add_unavailability(&mut judge.availability.unavailability, entry.clone())
+        .map_err(|existing| {
+            ApiError::Conflict(format!(
+                "Overlaps {:?} unavailability {} from {} to {}",
+                existing.unavailability_type, existing.id, existing.start, existing.end
+            ))
+        })?;
+    repo.save_judge(&judge)?;
+
+    Ok(ResponseBuilder::new(201)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&entry)?)
+        .build())
+}
+
+/// List a judge's unavailability, in start order
+#[utoipa::path(
+    get,
+    path = "/api/judges/{judge_id}/unavailability",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("judge_id" = Uuid, Path, description = "Judge ID")
+    ),
+    responses(
+        (status = 200, description = "Unavailability entries", body = Vec<Unavailability>),
+        (status = 400, description = "Invalid judge ID"),
+        (status = 404, description = "Judge not found")
+    ),
+    tag = "Judge Management",
+)]
+pub fn get_unavailability(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let judge_id = params
+        .get("judge_id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid judge ID".to_string()))?;
+
+    let judge = RepositoryFactory::judge_repo(&req)?
+        .find_judge_by_id(judge_id)?
+        .ok_or_else(|| ApiError::NotFound("Judge not found".to_string()))?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&judge.availability.unavailability)?)
+        .build())
+}
+
+/// Cancel an unavailability entry
+#[utoipa::path(
+    delete,
+    path = "/api/judges/{judge_id}/unavailability/{unavailability_id}",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("judge_id" = Uuid, Path, description = "Judge ID"),
+        ("unavailability_id" = Uuid, Path, description = "Unavailability entry ID")
+    ),
+    responses(
+        (status = 204, description = "Unavailability cancelled"),
+        (status = 400, description = "Invalid judge ID or unavailability ID"),
+        (status = 404, description = "Judge or unavailability entry not found")
+    ),
+    tag = "Judge Management",
+)]
+pub fn cancel_unavailability(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let judge_id = params
+        .get("judge_id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid judge ID".to_string()))?;
+    let unavailability_id = params
+        .get("unavailability_id")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| ApiError::BadRequest("Invalid unavailability ID".to_string()))?;
+
+    let repo = RepositoryFactory::judge_repo(&req)?;
+    let mut judge = repo
+        .find_judge_by_id(judge_id)?
+        .ok_or_else(|| ApiError::NotFound("Judge not found".to_string()))?;
+
+    let entries = &mut judge.availability.unavailability;
+    let before = entries.len();
+    entries.retain(|e| e.id != unavailability_id);
+    if entries.len() == before {
+        return Err(ApiError::NotFound("Unavailability entry not found".to_string()));
+    }
+    repo.save_judge(&judge)?;
+
+    Ok(ResponseBuilder::new(204).build())
+}
+
 /// Get judges on vacation
 #[utoipa::path(
     get,
@@ -1180,7 +1870,7 @@ pub fn get_judges_on_vacation(req: Request, _params: Params) -> ApiResult<impl I
     let end_date = query_parser::get_datetime(&query_params, "end")
         .unwrap_or_else(|| Utc::now() + chrono::Duration::days(30));
 
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
+    let repo = match RepositoryFactory::judge_repo(&req) {
 
 
         Ok(r) => r,
@@ -1224,46 +1914,31 @@ pub fn process_recusal(req: Request, params: Params) -> ApiResult<impl IntoRespo
     let body = req.body();
     let request: ProcessRequest = serde_json::from_slice(body)?;
 
-    // Log the requested replacement judge for audit purposes
-    let _replacement_id = request.replacement_judge_id;
-
-    let repo = match RepositoryFactory::judge_repo_validated(&req) {
-
-
-        Ok(r) => r,
-
-
-        Err(e) => return Err(e),
-
-
-    };
-    let motion = repo
+    let repo = RepositoryFactory::judge_repo(&req)?;
+    let mut motion = repo
         .find_recusal_by_id(recusal_id)?
         .ok_or_else(|| ApiError::NotFound("Recusal motion not found".to_string()))?;
 
-    // Get available judges
-    let available_judges = repo.find_available_judges()?;
-
-    // Process the recusal
-    let replacement_judge_id = JudgeAssignmentService::process_recusal(
-        &motion,
-        &available_judges,
-        &request.parties,
-    ).map_err(|e| ApiError::BadRequest(e))?;
-
-    // Create new assignment
-    let new_assignment = CaseAssignment {
-        id: Uuid::new_v4(),
-        case_id: motion.case_id,
-        judge_id: replacement_judge_id,
-        assignment_type: AssignmentType::Reassignment,
-        assigned_date: Utc::now(),
-        reason: format!("Reassigned due to recusal: {}", motion.detailed_grounds),
-        previous_judge_id: Some(motion.judge_id),
-        reassignment_reason: Some("Recusal processed".to_string()),
+    let replacement_judge_id = match request.replacement_judge_id {
+        Some(id) => id,
+        None => {
+            let available_judges = repo.find_available_judges()?;
+            JudgeAssignmentService::process_recusal(&motion, &available_judges, &request.parties)
+                .map_err(ApiError::BadRequest)?
+        }
     };
 
-    repo.save_assignment(&new_assignment)?;
+    let outcome = JudgeAssignmentService::execute_reassignment(
+        &repo,
+        &mut motion,
+        Some(replacement_judge_id),
+        "Recusal processed",
+    )?;
+    repo.save_recusal(&motion)?;
+
+    let new_assignment = outcome
+        .assignment
+        .ok_or_else(|| ApiError::Internal("Reassignment produced no assignment".to_string()))?;
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
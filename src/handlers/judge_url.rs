@@ -98,6 +98,17 @@ pub fn search_judges(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_workload_by_type(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::judge::get_workload_by_type(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
 pub fn get_judge_by_id(req: Request, params: Params) -> Response {
     let req = match add_district_header(req, &params) {
         Ok(r) => r,
@@ -109,6 +120,28 @@ pub fn get_judge_by_id(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_action_queue(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::judge::get_action_queue(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+pub fn get_weekly_docket(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::judge::get_weekly_docket(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
 pub fn update_judge_status(req: Request, params: Params) -> Response {
     let req = match add_district_header(req, &params) {
         Ok(r) => r,
@@ -157,6 +190,17 @@ pub fn assign_case(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn preview_assignment(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::judge::preview_assignment(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
 pub fn get_case_assignment(req: Request, params: Params) -> Response {
     let req = match add_district_header(req, &params) {
         Ok(r) => r,
@@ -203,4 +247,37 @@ pub fn get_pending_recusals(req: Request, params: Params) -> Response {
         Ok(resp) => resp.into_response(),
         Err(e) => crate::utils::json_response::error_response(&e),
     }
-}
\ No newline at end of file
+}
+
+pub fn add_unavailability(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::judge::add_unavailability(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+pub fn get_unavailability(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::judge::get_unavailability(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
+
+pub fn cancel_unavailability(req: Request, params: Params) -> Response {
+    let req = match add_district_header(req, &params) {
+        Ok(r) => r,
+        Err(e) => return crate::utils::json_response::error_response(&e),
+    };
+    match crate::handlers::judge::cancel_unavailability(req, params) {
+        Ok(resp) => resp.into_response(),
+        Err(e) => crate::utils::json_response::error_response(&e),
+    }
+}
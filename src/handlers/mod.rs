@@ -48,14 +48,20 @@ pub(crate) mod order_url;
 pub(crate) mod pdf_hexagonal;
 /// Rules engine management handlers
 pub(crate) mod rules;
+/// Global search handler
+pub(crate) mod search;
 /// URL-based rules engine handlers (for migration)
 pub(crate) mod rules_url;
 /// Federal sentencing management handlers
 pub(crate) mod sentencing;
 /// URL-based sentencing handlers (for migration)
 pub(crate) mod sentencing_url;
+/// Case timeline handler
+pub(crate) mod timeline;
 /// ToDo item CRUD operation handlers
 pub(crate) mod todo;
+/// Webhook subscription and delivery handlers
+pub(crate) mod webhook;
 /// Filing pipeline handlers
 pub(crate) mod filing;
 /// URL-based filing pipeline handlers (for migration)
@@ -6,17 +6,25 @@
 use crate::domain::opinion::{
     JudicialOpinion, OpinionDraft, OpinionType, OpinionStatus, Disposition,
     Citation, JudgeVote, VoteType, LegalCitation, CitationTreatment, Headnote,
-    DraftComment, OpinionStatistics as DomainOpinionStatistics
+    DraftComment, OpinionStatistics as DomainOpinionStatistics, CitationEdge, CitationGraph,
+    RankedOpinion, OpinionErratum
 };
+use crate::domain::pagination::PaginatedResponse;
 use crate::error::{ApiError, ApiResult};
 use crate::ports::document_repository::{
-    DocumentRepository, OpinionFilter, OpinionStatistics, CitationStatistics
+    DocumentIndexRepository, DocumentRepository, OpinionFilter, OpinionStatistics, CitationStatistics,
+    SlipOpinionRepository
 };
+use crate::domain::document::DocumentIndexEntry;
+use crate::services::opinion_publication_service;
+use crate::services::pdf_service::create_pdf_service;
+use crate::utils::diff::{diff_lines, LineDiff};
 use crate::utils::repository_factory::RepositoryFactory;
+use crate::utils::tenant;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use utoipa::ToSchema;
 
 /// Helper macro to get tenant-specific repository
@@ -38,6 +46,10 @@ pub struct CreateOpinionRequest {
     pub title: String,
     pub syllabus: String,
     pub content: String,
+    /// Judges sitting on the panel besides the author; left out for a
+    /// single-judge opinion
+    #[serde(default)]
+    pub panel_judge_ids: Vec<String>,
 }
 
 /// Request to update an opinion
@@ -55,9 +67,24 @@ pub struct UpdateOpinionRequest {
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct PublishOpinionRequest {
     pub is_precedential: bool,
+    /// Reporter citations, if already known; the neutral citation is
+    /// assigned on publication and any given here is replaced
+    #[serde(default)]
     pub citation: Citation,
 }
 
+/// Request to file an erratum against a published opinion
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct FileErratumRequest {
+    /// Where the correction applies, e.g. "Slip op. at 4, line 12"
+    pub location: Option<String>,
+    /// Text as published, when the correction replaces a passage
+    pub original_text: Option<String>,
+    pub corrected_text: String,
+    pub reason: String,
+    pub filed_by: String,
+}
+
 /// Request to add a judge vote
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct AddVoteRequest {
@@ -78,8 +105,16 @@ pub struct AddCitationRequest {
     pub court: Option<String>,
     pub proposition: String,
     pub treatment: CitationTreatment,
+    /// ID of the cited opinion, when it is held in this court's repository
+    pub cited_opinion_id: Option<String>,
 }
 
+/// Default number of hops returned by the citation graph endpoint
+const DEFAULT_CITATION_GRAPH_DEPTH: usize = 2;
+
+/// Upper bound on citation graph traversal depth
+const MAX_CITATION_GRAPH_DEPTH: usize = 5;
+
 /// Request to add a headnote
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct AddHeadnoteRequest {
@@ -104,9 +139,34 @@ pub struct AddCommentRequest {
     pub judge_id: String,
     pub judge_name: String,
     pub paragraph_ref: Option<String>,
+    /// 1-based line of the draft content to attach the comment to
+    pub line: Option<usize>,
     pub comment_text: String,
 }
 
+/// A comment attached to a line the diff changed
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ChangedLineComment {
+    pub draft_id: String,
+    pub comment: DraftComment,
+}
+
+/// Line diff between two drafts of an opinion
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DraftDiffResponse {
+    pub opinion_id: String,
+    /// The `against` draft, treated as the old text
+    pub from_draft_id: String,
+    pub from_version: i32,
+    /// The draft in the path, treated as the new text
+    pub to_draft_id: String,
+    pub to_version: i32,
+    #[serde(flatten)]
+    pub diff: LineDiff,
+    /// Comments on removed lines of the old draft and added lines of the new one
+    pub comments_on_changed_lines: Vec<ChangedLineComment>,
+}
+
 /// Response for opinion lists
 #[derive(Debug, Serialize, ToSchema)]
 pub struct OpinionListResponse {
@@ -147,6 +207,7 @@ pub fn create_opinion(req: Request, _params: Params) -> ApiResult<impl IntoRespo
     
     opinion.syllabus = request.syllabus;
     opinion.content = request.content;
+    opinion.set_panel(request.panel_judge_ids);
     
     let repo = get_tenant_repo!(&req);
     let created = repo.create_opinion(opinion)?;
@@ -198,6 +259,7 @@ pub fn get_opinion(req: Request, params: Params) -> ApiResult<impl IntoResponse>
     responses(
         (status = 200, description = "Opinion updated successfully", body = JudicialOpinion),
         (status = 404, description = "Opinion not found"),
+        (status = 409, description = "Opinion is published; file an erratum instead"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Judicial Opinions",
@@ -213,6 +275,13 @@ pub fn update_opinion(req: Request, params: Params) -> ApiResult<impl IntoRespon
     let mut opinion = repo.get_opinion(opinion_id)?
         .ok_or_else(|| ApiError::NotFound("Opinion not found".to_string()))?;
     
+    if opinion.is_locked() {
+        return Err(ApiError::Conflict(format!(
+            "Opinion {} is published and can no longer be edited; file a correction with POST /api/opinions/{}/errata",
+            opinion_id, opinion_id
+        )));
+    }
+    
     if let Some(title) = request.title {
         opinion.title = title;
     }
@@ -362,6 +431,9 @@ pub fn file_opinion(req: Request, params: Params) -> ApiResult<impl IntoResponse
 }
 
 /// Publish an opinion
+///
+/// The votes recorded by judges on the opinion's panel must form a majority
+/// of that panel; votes from judges off the panel are not counted.
 #[utoipa::path(
     post,
     path = "/api/opinions/{opinion_id}/publish",
@@ -371,9 +443,10 @@ pub fn file_opinion(req: Request, params: Params) -> ApiResult<impl IntoResponse
     ),
     request_body = PublishOpinionRequest,
     responses(
-        (status = 200, description = "Opinion published successfully", body = JudicialOpinion),
+        (status = 200, description = "Opinion published with its neutral citation and slip opinion", body = JudicialOpinion),
         (status = 404, description = "Opinion not found"),
-        (status = 400, description = "Opinion not filed"),
+        (status = 400, description = "Opinion not filed or recorded votes do not form a majority of the panel"),
+        (status = 409, description = "Opinion already published, or the citation counter is contended"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Judicial Opinions",
@@ -389,20 +462,136 @@ pub fn publish_opinion(req: Request, params: Params) -> ApiResult<impl IntoRespo
     let mut opinion = repo.get_opinion(opinion_id)?
         .ok_or_else(|| ApiError::NotFound("Opinion not found".to_string()))?;
     
+    if opinion.is_locked() {
+        return Err(ApiError::Conflict(format!("Opinion {} is already published", opinion_id)));
+    }
+    
     if !matches!(opinion.status, OpinionStatus::Filed) {
         return Err(ApiError::BadRequest("Opinion must be filed before publishing".to_string()));
     }
     
+    if !opinion.has_majority() {
+        return Err(ApiError::BadRequest(format!(
+            "Recorded votes do not form a majority of the {}-judge panel",
+            opinion.panel().len()
+        )));
+    }
+    
+    let district = tenant::get_tenant_id(&req).to_uppercase();
+    let pdf_service = create_pdf_service(&district).map_err(|e| ApiError::Internal(e.to_string()))?;
     opinion.is_precedential = request.is_precedential;
-    opinion.publish(request.citation);
+    let generated = opinion_publication_service::publish(
+        &repo,
+        |document| pdf_service.generate_document_sync(document),
+        &mut opinion,
+        request.citation,
+        &district,
+        Utc::now(),
+    )?;
     let updated = repo.update_opinion(opinion)?;
     
+    // Indexing is best effort; the slip opinion is already stored
+    let case_id = uuid::Uuid::parse_str(&updated.case_id).ok();
+    if let Err(e) = repo.index_document(&DocumentIndexEntry::from_generated(&generated, case_id)) {
+        crate::request_log!("document index: cannot index slip opinion {}: {}", updated.id, e);
+    }
+    
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
         .body(serde_json::to_vec(&updated)?)
         .build())
 }
 
+/// File an erratum against a published opinion
+///
+/// The correction is appended to the opinion's errata; the published text
+/// and slip opinion are left unchanged.
+#[utoipa::path(
+    post,
+    path = "/api/opinions/{opinion_id}/errata",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("opinion_id" = String, Path, description = "Opinion ID")
+    ),
+    request_body = FileErratumRequest,
+    responses(
+        (status = 201, description = "Erratum recorded", body = JudicialOpinion),
+        (status = 400, description = "Missing corrected text or reason"),
+        (status = 404, description = "Opinion not found"),
+        (status = 409, description = "Opinion is not published; edit it directly"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Judicial Opinions",
+)]
+pub fn file_errata(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let opinion_id = params.get("opinion_id")
+        .ok_or_else(|| ApiError::BadRequest("Opinion ID required".to_string()))?;
+    
+    let request: FileErratumRequest = serde_json::from_slice(req.body())?;
+    if request.corrected_text.trim().is_empty() || request.reason.trim().is_empty() {
+        return Err(ApiError::BadRequest("An erratum needs corrected_text and a reason".to_string()));
+    }
+    
+    let repo = get_tenant_repo!(&req);
+    let mut opinion = repo.get_opinion(opinion_id)?
+        .ok_or_else(|| ApiError::NotFound("Opinion not found".to_string()))?;
+    
+    if !opinion.is_locked() {
+        return Err(ApiError::Conflict(format!(
+            "Opinion {} is not published; update it with PATCH /api/opinions/{}",
+            opinion_id, opinion_id
+        )));
+    }
+    
+    opinion.add_erratum(OpinionErratum {
+        id: uuid::Uuid::new_v4().to_string(),
+        location: request.location,
+        original_text: request.original_text,
+        corrected_text: request.corrected_text,
+        reason: request.reason,
+        filed_by: request.filed_by,
+        filed_at: Utc::now(),
+    });
+    let updated = repo.update_opinion(opinion)?;
+    
+    Ok(ResponseBuilder::new(201)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&updated)?)
+        .build())
+}
+
+/// Download the slip opinion generated when an opinion was published
+#[utoipa::path(
+    get,
+    path = "/api/opinions/{opinion_id}/slip-opinion",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("opinion_id" = String, Path, description = "Opinion ID")
+    ),
+    responses(
+        (status = 200, description = "Slip opinion PDF", content_type = "application/pdf"),
+        (status = 404, description = "Opinion not found or not published"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Judicial Opinions",
+)]
+pub fn get_slip_opinion(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let opinion_id = params.get("opinion_id")
+        .ok_or_else(|| ApiError::BadRequest("Opinion ID required".to_string()))?;
+    
+    let repo = get_tenant_repo!(&req);
+    let opinion = repo.get_opinion(opinion_id)?
+        .ok_or_else(|| ApiError::NotFound("Opinion not found".to_string()))?;
+    let pdf = repo.find_slip_opinion(opinion_id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Opinion {} has no slip opinion; it is not published", opinion_id)))?;
+    
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/pdf")
+        .header("content-disposition", format!(r#"attachment; filename="slip-opinion-{}.pdf""#, opinion.docket_number))
+        .body(pdf)
+        .build())
+}
+
 /// Add a judge vote to an opinion
 #[utoipa::path(
     post,
@@ -474,6 +663,12 @@ pub fn add_citation(req: Request, params: Params) -> ApiResult<impl IntoResponse
     let mut opinion = repo.get_opinion(opinion_id)?
         .ok_or_else(|| ApiError::NotFound("Opinion not found".to_string()))?;
     
+    if let Some(cited_id) = &request.cited_opinion_id {
+        if repo.get_opinion(cited_id)?.is_none() {
+            return Err(ApiError::NotFound(format!("Cited opinion {} not found", cited_id)));
+        }
+    }
+    
     let citation = LegalCitation {
         citation_text: request.citation_text,
         case_name: request.case_name,
@@ -484,6 +679,7 @@ pub fn add_citation(req: Request, params: Params) -> ApiResult<impl IntoResponse
         pin_cite: None,
         proposition: request.proposition,
         treatment: request.treatment,
+        cited_opinion_id: request.cited_opinion_id,
     };
     
     opinion.add_citation(citation);
@@ -540,6 +736,100 @@ pub fn add_headnote(req: Request, params: Params) -> ApiResult<impl IntoResponse
         .build())
 }
 
+/// Get opinions that cite an opinion
+#[utoipa::path(
+    get,
+    path = "/api/opinions/{id}/cited-by",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = String, Path, description = "Opinion ID"),
+        ("treatment" = Option<String>, Query, description = "Filter by citation treatment (e.g., Followed, Distinguished, Overruled)"),
+        ("page" = Option<usize>, Query, description = "Page number (1-indexed)", example = 1),
+        ("limit" = Option<usize>, Query, description = "Items per page (max 100)", example = 20)
+    ),
+    responses(
+        (status = 200, description = "Paginated list of citing opinions"),
+        (status = 400, description = "Invalid treatment filter"),
+        (status = 404, description = "Opinion not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Judicial Opinions",
+)]
+pub fn get_cited_by(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    use crate::utils::query_parser::{parse_query_string, get_string, get_usize};
+
+    let id = params.get("id")
+        .ok_or_else(|| ApiError::BadRequest("Opinion ID required".to_string()))?;
+
+    let query_params = parse_query_string(req.query());
+    let treatment = get_string(&query_params, "treatment")
+        .map(|t| serde_json::from_value::<CitationTreatment>(serde_json::Value::String(t.clone()))
+            .map_err(|_| ApiError::BadRequest(format!("Unknown citation treatment: {}", t))))
+        .transpose()?;
+    let page = get_usize(&query_params, "page").unwrap_or(1).max(1);
+    let limit = get_usize(&query_params, "limit").unwrap_or(20).clamp(1, 100);
+
+    let repo = get_tenant_repo!(&req);
+    repo.get_opinion(id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Opinion {} not found", id)))?;
+
+    let citing: Vec<CitationEdge> = repo.find_citing_opinions(id)?
+        .into_iter()
+        .filter(|e| treatment.as_ref().map_or(true, |t| &e.treatment == t))
+        .collect();
+
+    let response = PaginatedResponse::from_full_list(citing, page, limit);
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&response)?)
+        .build())
+}
+
+/// Get the citation graph around an opinion
+#[utoipa::path(
+    get,
+    path = "/api/opinions/{id}/citation-graph",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = String, Path, description = "Opinion ID"),
+        ("depth" = Option<usize>, Query, description = "Maximum hops from the opinion (default 2, max 5)", example = 2)
+    ),
+    responses(
+        (status = 200, description = "Citation graph nodes and edges", body = CitationGraph),
+        (status = 404, description = "Opinion not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Judicial Opinions",
+)]
+pub fn get_citation_graph(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    use crate::utils::query_parser::{parse_query_string, get_usize};
+
+    let id = params.get("id")
+        .ok_or_else(|| ApiError::BadRequest("Opinion ID required".to_string()))?;
+
+    let query_params = parse_query_string(req.query());
+    let depth = get_usize(&query_params, "depth")
+        .unwrap_or(DEFAULT_CITATION_GRAPH_DEPTH)
+        .min(MAX_CITATION_GRAPH_DEPTH);
+
+    let repo = get_tenant_repo!(&req);
+    let root = repo.get_opinion(id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Opinion {} not found", id)))?;
+
+    let graph = CitationGraph::build(
+        &root,
+        depth,
+        |opinion_id| repo.get_opinion(opinion_id),
+        |opinion_id| repo.find_citing_opinions(opinion_id),
+    )?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&graph)?)
+        .build())
+}
+
 /// Get opinions by case
 #[utoipa::path(
     get,
@@ -631,6 +921,56 @@ pub fn search_opinions(req: Request, _params: Params) -> ApiResult<impl IntoResp
         .build())
 }
 
+/// Search opinions ranked by relevance
+///
+/// Scores opinions by how often the query's terms appear in the title,
+/// syllabus, body and headnotes, ignoring common words such as "the" and
+/// "and". Results come back most relevant first with their scores.
+#[utoipa::path(
+    get,
+    path = "/api/opinions/search/ranked",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("q" = String, Query, description = "Search query"),
+        ("limit" = Option<usize>, Query, description = "Maximum results to return (default 20, max 100)"),
+        ("min_score" = Option<u32>, Query, description = "Minimum relevance score to include (default 1)")
+    ),
+    responses(
+        (status = 200, description = "Matching opinions, most relevant first", body = Vec<RankedOpinion>),
+        (status = 400, description = "Search query required or has no searchable terms"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Judicial Opinions",
+)]
+pub fn search_opinions_ranked(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    use crate::utils::query_parser::{get_text, get_usize, parse_query_string};
+
+    let query_params = parse_query_string(req.query());
+    let search_query = get_text(&query_params, "q")
+        .ok_or_else(|| ApiError::BadRequest("Search query required".to_string()))?;
+    if crate::domain::opinion::search_terms(&search_query).is_empty() {
+        return Err(ApiError::BadRequest(
+            "Search query has no searchable terms once common words are removed".to_string(),
+        ));
+    }
+
+    let limit = get_usize(&query_params, "limit").unwrap_or(20).clamp(1, 100);
+    let min_score = match query_params.iter().find(|(k, _)| *k == "min_score") {
+        Some((_, v)) => v.parse::<u32>().map_err(|_| {
+            ApiError::BadRequest(format!("Invalid min_score '{}': expected a non-negative integer", v))
+        })?,
+        None => 1,
+    };
+
+    let repo = get_tenant_repo!(&req);
+    let results = repo.search_opinions_ranked(&search_query, limit, min_score)?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&results)?)
+        .build())
+}
+
 /// Get precedential opinions
 #[utoipa::path(
     get,
@@ -732,10 +1072,13 @@ pub fn get_drafts(req: Request, params: Params) -> ApiResult<impl IntoResponse>
     path = "/api/opinions/statistics",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("judge_id" = Option<String>, Query, description = "Filter by judge ID")
+        ("judge_id" = Option<String>, Query, description = "Filter by judge ID"),
+        ("from" = Option<String>, Query, description = "Start of the date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to 12 months before `to`"),
+        ("to" = Option<String>, Query, description = "End of the date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to now")
     ),
     responses(
         (status = 200, description = "Opinion statistics", body = OpinionStatistics),
+        (status = 400, description = "Invalid date range", body = crate::error::ErrorResponse),
         (status = 500, description = "Internal server error")
     ),
     tag = "Judicial Opinions",
@@ -751,9 +1094,10 @@ pub fn get_opinion_statistics(req: Request, _params: Params) -> ApiResult<impl I
     }
     
     let judge_id = query_map.get("judge_id").map(|s| *s);
+    let range = crate::utils::query_parser::get_date_range(&parse_query_string(query_str))?;
     
     let repo = get_tenant_repo!(&req);
-    let stats = repo.get_opinion_statistics(judge_id)?;
+    let stats = repo.get_opinion_statistics(judge_id, range)?;
     
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -832,6 +1176,73 @@ pub fn get_current_draft(req: Request, params: Params) -> ApiResult<impl IntoRes
         .build())
 }
 
+/// Compare two drafts of an opinion line by line
+#[utoipa::path(
+    get,
+    path = "/api/opinions/{opinion_id}/drafts/{draft_id}/diff",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("opinion_id" = String, Path, description = "Opinion ID"),
+        ("draft_id" = String, Path, description = "Draft ID, treated as the new text"),
+        ("against" = String, Query, description = "Draft ID to compare against, treated as the old text")
+    ),
+    responses(
+        (status = 200, description = "Diff of the two drafts", body = DraftDiffResponse),
+        (status = 400, description = "Missing or same draft, or drafts of different opinions"),
+        (status = 404, description = "Draft not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Opinion Drafts",
+)]
+pub fn get_draft_diff(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    use crate::utils::query_parser::{get_string, parse_query_string};
+
+    let opinion_id = params.get("opinion_id").unwrap_or("").to_string();
+    let draft_id = params.get("draft_id").unwrap_or("").to_string();
+    let against_id = get_string(&parse_query_string(req.query()), "against")
+        .ok_or_else(|| ApiError::BadRequest("against query parameter is required".to_string()))?;
+    if against_id == draft_id {
+        return Err(ApiError::BadRequest("Cannot diff a draft against itself".to_string()));
+    }
+
+    let repo = get_tenant_repo!(&req);
+    let new = repo.get_draft(&draft_id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Draft {} not found", draft_id)))?;
+    let old = repo.get_draft(&against_id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Draft {} not found", against_id)))?;
+    if new.opinion_id != opinion_id || old.opinion_id != opinion_id {
+        return Err(ApiError::BadRequest("Drafts must both belong to this opinion".to_string()));
+    }
+
+    let diff = diff_lines(&old.content, &new.content);
+    let removed: HashSet<usize> = diff.removed_lines().collect();
+    let added: HashSet<usize> = diff.added_lines().collect();
+    let on_lines = |draft: &OpinionDraft, lines: &HashSet<usize>| -> Vec<ChangedLineComment> {
+        draft.comments
+            .iter()
+            .filter(|c| c.line.is_some_and(|line| lines.contains(&line)))
+            .map(|c| ChangedLineComment { draft_id: draft.id.clone(), comment: c.clone() })
+            .collect()
+    };
+    let mut comments_on_changed_lines = on_lines(&old, &removed);
+    comments_on_changed_lines.extend(on_lines(&new, &added));
+
+    let response = DraftDiffResponse {
+        opinion_id,
+        from_draft_id: old.id,
+        from_version: old.version,
+        to_draft_id: new.id,
+        to_version: new.version,
+        diff,
+        comments_on_changed_lines,
+    };
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&response)?)
+        .build())
+}
+
 /// Check if opinion is majority opinion
 #[utoipa::path(
     get,
@@ -958,12 +1369,23 @@ pub fn add_draft_comment(req: Request, params: Params) -> ApiResult<impl IntoRes
         return Err(ApiError::BadRequest("Draft does not belong to this opinion".to_string()));
     }
 
+    if let Some(line) = comment_request.line {
+        let line_count = draft.content.lines().count();
+        if line == 0 || line > line_count {
+            return Err(ApiError::BadRequest(format!(
+                "line must be between 1 and {}, got {}",
+                line_count, line
+            )));
+        }
+    }
+
     // Create and add the comment
     let comment = DraftComment {
         id: uuid::Uuid::new_v4().to_string(),
         judge_id: comment_request.judge_id,
         judge_name: comment_request.judge_name,
         paragraph_ref: comment_request.paragraph_ref,
+        line: comment_request.line,
         comment_text: comment_request.comment_text,
         created_at: Utc::now(),
         resolved: false,
@@ -101,6 +101,24 @@ pub fn publish_opinion(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn file_errata(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::opinion::file_errata(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+pub fn get_slip_opinion(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::opinion::get_slip_opinion(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn add_judge_vote(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::opinion::add_judge_vote(req, params)
@@ -137,6 +155,15 @@ pub fn search_opinions(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn search_opinions_ranked(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::opinion::search_opinions_ranked(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 // Cross-Entity Opinion Queries - 3 endpoints
 
 pub fn get_opinions_by_case(req: Request, params: Params) -> Response {
@@ -195,6 +222,15 @@ pub fn get_current_draft(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_draft_diff(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::opinion::get_draft_diff(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn add_draft_comment(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::opinion::add_draft_comment(req, params)
@@ -258,4 +294,24 @@ pub fn calculate_opinion_statistics(req: Request, params: Params) -> Response {
             .unwrap_or_else(|e| json::error_response(&e)),
         Err(e) => json::error_response(&e),
     }
-}
\ No newline at end of file
+}
+
+// Citation Graph - 2 endpoints
+
+pub fn get_cited_by(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::opinion::get_cited_by(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
+pub fn get_citation_graph(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::opinion::get_citation_graph(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
@@ -4,15 +4,19 @@
 //! templates, and electronic signatures.
 
 use crate::domain::order::{
-    JudicialOrder, OrderTemplate, OrderType, OrderStatus, ElectronicSignature,
-    ServiceRecord, ServiceMethod, ServiceStatus, TemplateVariable
+    self as order_domain, JudgeExpiringOrders, JudicialOrder, OrderTemplate, OrderType, OrderStatus,
+    ElectronicSignature, ServiceRecord, ServiceMethod, ServiceStatus, TemplatePreview,
+    TemplateValueErrors, TemplateVariable
 };
+use crate::domain::webhook::WebhookEventType;
 use crate::error::{ApiError, ApiResult};
 use crate::ports::document_repository::{DocumentRepository, OrderFilter, OrderStatistics};
-use crate::utils::repository_factory::RepositoryFactory;
+use crate::ports::judge_repository::JudgeRepository;
+use crate::services::webhook_service;
+use crate::utils::{query_parser, repository_factory::RepositoryFactory};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
+use spin_sdk::http::{IntoResponse, Params, Request, Response, ResponseBuilder};
 use std::collections::HashMap;
 use utoipa::ToSchema;
 
@@ -23,6 +27,12 @@ macro_rules! get_tenant_repo {
     }}
 }
 
+/// 422 error listing template variables that were missing or mistyped
+fn template_values_rejected(errors: &TemplateValueErrors) -> ApiResult<Response> {
+    Err(ApiError::Unprocessable("Template variables are missing or do not match their types".to_string())
+        .with_details(errors))
+}
+
 /// Request to create a new judicial order
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateOrderRequest {
@@ -35,6 +45,9 @@ pub struct CreateOrderRequest {
     pub effective_date: Option<DateTime<Utc>>,
     pub expiration_date: Option<DateTime<Utc>>,
     pub related_motions: Vec<String>,
+    /// Judges who may sign on the assigned judge's behalf
+    #[serde(default)]
+    pub authorized_signers: Vec<String>,
 }
 
 /// Request to update an order
@@ -46,11 +59,13 @@ pub struct UpdateOrderRequest {
     pub is_sealed: Option<bool>,
     pub effective_date: Option<DateTime<Utc>>,
     pub expiration_date: Option<DateTime<Utc>>,
+    pub authorized_signers: Option<Vec<String>>,
 }
 
 /// Request to sign an order electronically
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct SignOrderRequest {
+    /// Judge applying the signature; must be the assigned judge or an authorized signer
     pub judge_id: String,
     pub judge_name: String,
     pub certificate_id: String,
@@ -126,6 +141,7 @@ pub fn create_order(req: Request, _params: Params) -> ApiResult<impl IntoRespons
     order.effective_date = request.effective_date;
     order.expiration_date = request.expiration_date;
     order.related_motions = request.related_motions;
+    order.authorized_signers = request.authorized_signers;
 
     // Use tenant-specific store
     let repo = get_tenant_repo!(&req);
@@ -211,6 +227,9 @@ pub fn update_order(req: Request, params: Params) -> ApiResult<impl IntoResponse
     if let Some(expiration) = request.expiration_date {
         order.expiration_date = Some(expiration);
     }
+    if let Some(signers) = request.authorized_signers {
+        order.authorized_signers = signers;
+    }
     
     order.updated_at = Utc::now();
     let updated = repo.update_order(order)?;
@@ -317,6 +336,7 @@ pub fn list_orders(req: Request, _params: Params) -> ApiResult<impl IntoResponse
         (status = 200, description = "Order signed successfully", body = JudicialOrder),
         (status = 404, description = "Order not found"),
         (status = 400, description = "Order already signed"),
+        (status = 403, description = "Judge is not assigned to the order or an authorized signer"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Judicial Orders",
@@ -335,6 +355,7 @@ pub fn sign_order(req: Request, params: Params) -> ApiResult<impl IntoResponse>
     if order.signature.is_some() {
         return Err(ApiError::BadRequest("Order already signed".to_string()));
     }
+    order.authorize_signer(&request.judge_id).map_err(ApiError::Forbidden)?;
     
     let signature = ElectronicSignature::for_judge(
         request.judge_id,
@@ -383,6 +404,17 @@ pub fn issue_order(req: Request, params: Params) -> ApiResult<impl IntoResponse>
     
     order.issue();
     let updated = repo.update_order(order)?;
+
+    // Sealed orders are announced without their title
+    webhook_service::notify(&req, WebhookEventType::OrderIssued, serde_json::json!({
+        "order_id": updated.id,
+        "case_id": updated.case_id,
+        "judge_id": updated.judge_id,
+        "order_type": updated.order_type,
+        "title": if updated.is_sealed { None } else { Some(&updated.title) },
+        "is_sealed": updated.is_sealed,
+        "issued_at": updated.issued_at,
+    }));
     
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -554,6 +586,48 @@ pub fn get_expiring_orders(req: Request, _params: Params) -> ApiResult<impl Into
         .build())
 }
 
+/// Get expiring orders grouped by issuing judge
+///
+/// Intended for a scheduled job that notifies each judge of their orders
+/// about to expire.
+#[utoipa::path(
+    get,
+    path = "/api/orders/expiring/notifications",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("within_days" = Option<usize>, Query, description = "Number of days to look ahead (default 30)")
+    ),
+    responses(
+        (status = 200, description = "Expiring orders per judge, each sorted by soonest expiry", body = Vec<JudgeExpiringOrders>),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Judicial Orders",
+)]
+pub fn get_expiring_order_notifications(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    use crate::utils::query_parser::{get_usize, parse_query_string};
+
+    let query = parse_query_string(req.query());
+    let within_days = get_usize(&query, "within_days").unwrap_or(30);
+
+    let repo = get_tenant_repo!(&req);
+    let orders = repo.find_expiring_orders(within_days as i64)?;
+    let mut notifications = order_domain::group_expiring_by_judge(orders, Utc::now());
+
+    // Judge names are a convenience for the notifier; unknown judges keep None
+    let judge_repo = RepositoryFactory::judge_repo(&req)?;
+    for group in &mut notifications {
+        group.judge_name = uuid::Uuid::parse_str(&group.judge_id)
+            .ok()
+            .and_then(|id| judge_repo.find_judge_by_id(id).ok().flatten())
+            .map(|judge| judge.name);
+    }
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&notifications)?)
+        .build())
+}
+
 /// Create a new order template
 #[utoipa::path(
     post,
@@ -561,7 +635,7 @@ pub fn get_expiring_orders(req: Request, _params: Params) -> ApiResult<impl Into
     request_body = CreateTemplateRequest,
     responses(
         (status = 201, description = "Template created successfully", body = OrderTemplate),
-        (status = 400, description = "Invalid request data"),
+        (status = 400, description = "Invalid request data, or placeholders that are not declared as variables"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Order Templates",
@@ -583,6 +657,7 @@ pub fn create_template(req: Request, _params: Params) -> ApiResult<impl IntoResp
     template.variables = request.variables;
     template.required_attachments = request.required_attachments;
     template.default_service_method = request.default_service_method;
+    template.check_consistency().map_err(ApiError::BadRequest)?;
     
     let repo = get_tenant_repo!(&req);
     let created = repo.create_template(template)?;
@@ -666,7 +741,7 @@ pub fn list_templates(req: Request, _params: Params) -> ApiResult<impl IntoRespo
     responses(
         (status = 201, description = "Order created from template", body = JudicialOrder),
         (status = 404, description = "Template not found"),
-        (status = 400, description = "Missing required variables"),
+        (status = 422, description = "Missing or mistyped template variables, listed in `details`", body = crate::error::ErrorResponse),
         (status = 500, description = "Internal server error")
     ),
     tag = "Judicial Orders",
@@ -682,14 +757,10 @@ pub fn create_from_template(req: Request, _params: Params) -> ApiResult<impl Int
     let template = repo.get_template(&request.template_id)?
         .ok_or_else(|| ApiError::NotFound("Template not found".to_string()))?;
     
-    // Check required variables
-    for var in &template.variables {
-        if var.required && !request.variables.contains_key(&var.name) {
-            return Err(ApiError::BadRequest(format!("Missing required variable: {}", var.name)));
-        }
-    }
-    
-    let content = template.generate_content(&request.variables);
+    let content = match template.render(&request.variables) {
+        Ok(content) => content,
+        Err(errors) => return template_values_rejected(&errors),
+    };
     
     let order = JudicialOrder::new(
         request.case_id,
@@ -713,10 +784,13 @@ pub fn create_from_template(req: Request, _params: Params) -> ApiResult<impl Int
     path = "/api/orders/statistics",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("judge_id" = Option<String>, Query, description = "Filter by judge ID")
+        ("judge_id" = Option<String>, Query, description = "Filter by judge ID"),
+        ("from" = Option<String>, Query, description = "Start of the date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to 12 months before `to`"),
+        ("to" = Option<String>, Query, description = "End of the date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to now")
     ),
     responses(
         (status = 200, description = "Order statistics", body = OrderStatistics),
+        (status = 400, description = "Invalid date range", body = crate::error::ErrorResponse),
         (status = 500, description = "Internal server error")
     ),
     tag = "Judicial Orders",
@@ -732,9 +806,10 @@ pub fn get_order_statistics(req: Request, _params: Params) -> ApiResult<impl Int
     }
     
     let judge_id = query_map.get("judge_id").map(|s| *s);
+    let range = crate::utils::query_parser::get_date_range(&parse_query_string(query_str))?;
     
     let repo = get_tenant_repo!(&req);
-    let stats = repo.get_order_statistics(judge_id)?;
+    let stats = repo.get_order_statistics(judge_id, range)?;
     
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
@@ -753,6 +828,7 @@ pub fn get_order_statistics(req: Request, _params: Params) -> ApiResult<impl Int
     request_body = OrderTemplate,
     responses(
         (status = 200, description = "Template updated", body = OrderTemplate),
+        (status = 400, description = "Placeholders that are not declared as variables"),
         (status = 404, description = "Template not found")
     ),
     tag = "Order Templates",
@@ -763,6 +839,7 @@ pub fn update_template(req: Request, params: Params) -> ApiResult<impl IntoRespo
 
     let template_id = params.get("template_id").unwrap_or("").to_string();
     template.id = template_id;
+    template.check_consistency().map_err(ApiError::BadRequest)?;
 
     let repo = get_tenant_repo!(&req);
     let updated = repo.update_template(template)?;
@@ -879,23 +956,32 @@ pub fn check_requires_attention(req: Request, params: Params) -> ApiResult<impl
 }
 
 /// Generate order content from template
+///
+/// Required variables without a value are rejected before anything is
+/// rendered. With `validate_only=true` the values are checked and the
+/// report returned without generating content.
 #[utoipa::path(
     post,
     path = "/api/templates/{template_id}/generate-content",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("template_id" = String, Path, description = "Template ID")
+        ("template_id" = String, Path, description = "Template ID"),
+        ("validate_only" = Option<bool>, Query, description = "Return the variable report without generating content")
     ),
     request_body = HashMap<String, String>,
     responses(
-        (status = 200, description = "Success", body = String),
+        (status = 200, description = "Generated content, or the variable report when validate_only=true", body = String),
+        (status = 400, description = "Required template variables were not supplied"),
         (status = 404, description = "Template not found"),
+        (status = 422, description = "Mistyped template variables, listed in `details`", body = crate::error::ErrorResponse),
         (status = 500, description = "Internal server error")
     ),
     tag = "Order Templates",
 )]
 pub fn generate_template_content(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
     let template_id = params.get("template_id").unwrap_or("").to_string();
+    let query = query_parser::parse_query_string(req.query());
+    let validate_only = query_parser::get_bool(&query, "validate_only").unwrap_or(false);
 
     let body_bytes = req.body();
     let body_str = std::str::from_utf8(body_bytes)
@@ -907,10 +993,58 @@ pub fn generate_template_content(req: Request, params: Params) -> ApiResult<impl
     let template = repo.get_template(&template_id)?
         .ok_or_else(|| ApiError::NotFound(format!("Template {} not found", template_id)))?;
 
-    let content = template.generate_content(&values);
+    let report = template.validate_values(&values);
+    if validate_only {
+        return Ok(ResponseBuilder::new(200)
+            .header("content-type", "application/json")
+            .body(serde_json::to_vec(&report)?)
+            .build());
+    }
+    if !report.missing.is_empty() {
+        return Err(ApiError::BadRequest(format!(
+            "Missing required template variables: {}",
+            report.missing.join(", ")
+        )));
+    }
+
+    let content = match template.render(&values) {
+        Ok(content) => content,
+        Err(errors) => return template_values_rejected(&errors),
+    };
 
     Ok(ResponseBuilder::new(200)
         .header("content-type", "application/json")
         .body(serde_json::to_vec(&content)?)
         .build())
 }
+
+/// Preview an order template with sample values
+///
+/// Renders each declared variable with its default or a sample for its type,
+/// and flags placeholders in the body that are not declared.
+#[utoipa::path(
+    post,
+    path = "/api/templates/orders/{template_id}/preview",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("template_id" = String, Path, description = "Template ID")
+    ),
+    responses(
+        (status = 200, description = "Rendered preview", body = TemplatePreview),
+        (status = 404, description = "Template not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Order Templates",
+)]
+pub fn preview_template(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let template_id = params.get("template_id").unwrap_or("").to_string();
+
+    let repo = get_tenant_repo!(&req);
+    let template = repo.get_template(&template_id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Template {} not found", template_id)))?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&template.preview())?)
+        .build())
+}
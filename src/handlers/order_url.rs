@@ -148,6 +148,15 @@ pub fn get_expiring_orders(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_expiring_order_notifications(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::order::get_expiring_order_notifications(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn get_order_statistics(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::order::get_order_statistics(req, params)
@@ -167,7 +176,7 @@ pub fn create_from_template(req: Request, params: Params) -> Response {
 }
 
 // ============================================================================
-// Order Template Management (7 endpoints)
+// Order Template Management (8 endpoints)
 // ============================================================================
 
 pub fn create_template(req: Request, params: Params) -> Response {
@@ -224,6 +233,15 @@ pub fn delete_template(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn preview_template(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::order::preview_template(req, params)
+            .map(|r| r.into_response())
+            .unwrap_or_else(|e| json::error_response(&e)),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn generate_template_content(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::order::generate_template_content(req, params)
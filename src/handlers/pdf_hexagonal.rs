@@ -5,10 +5,23 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use crate::domain::document::{
     CaseNumber, JudgeName, District,
-    DocumentType, DocumentMetadata, ElectronicSignature, DocumentError
+    DocumentType, DocumentMetadata, ElectronicSignature, DocumentError,
+    BatchFailure, DocumentIndexEntry, GeneratedDocument, PageLayout, DefendantNames, SignatureRejection
 };
+use crate::ports::signature_repository::JudgeSignature;
+use crate::domain::attorney::ServiceRecord;
+use crate::domain::common::ServiceMethod;
+use crate::ports::attorney_repository::AttorneyRepository;
+use crate::ports::case_repository::CaseRepository;
+use crate::ports::config_repository::ConfigRepository;
+use crate::ports::document_repository::DocumentIndexRepository;
 use crate::ports::document_generator::DocumentRequest;
-use crate::services::pdf_service::create_pdf_service;
+use crate::error::ApiError;
+use crate::utils::{json_response as json, query_parser};
+use crate::utils::repository_factory::RepositoryFactory;
+use crate::services::pdf_service::{
+    create_pdf_service, validate_signature_image, zip_documents, BatchItem, PdfService, SignatureLimits,
+};
 use crate::utils::tenant;
 
 /// Helper function to determine response format from URL parameter or Accept header
@@ -24,14 +37,29 @@ fn wants_pdf(req: &Request, params: &Params) -> bool {
         .unwrap_or(false)
 }
 
+/// Record a generated document in the tenant's document index
+///
+/// Indexing is best effort: the document has already been generated, so a
+/// failure is logged rather than failing the request.
+fn index_generated(req: &Request, generated: &GeneratedDocument, case_id: Option<uuid::Uuid>) {
+    let result = RepositoryFactory::document_repo(req)
+        .and_then(|repo| repo.index_document(&DocumentIndexEntry::from_generated(generated, case_id)));
+    if let Err(e) = result {
+        crate::request_log!("document index: cannot index {}: {}", generated.document.id.as_uuid(), e);
+    }
+}
+
 /// Helper function to build response based on format parameter or Accept header
-fn build_response(req: &Request, params: &Params, generated: crate::domain::document::GeneratedDocument, doc_type: &str, case_number: String) -> Response {
+fn build_response(req: &Request, params: &Params, generated: GeneratedDocument, doc_type: &str, case_number: String) -> Response {
+    index_generated(req, &generated, None);
+
     if wants_pdf(req, params) {
         // Return raw PDF
         Response::builder()
             .status(200)
             .header("content-type", "application/pdf")
             .header("content-disposition", format!(r#"attachment; filename="{}""#, generated.filename))
+            .header("x-page-count", generated.page_count.to_string())
             .body(generated.pdf_data)
             .build()
     } else {
@@ -42,6 +70,7 @@ fn build_response(req: &Request, params: &Params, generated: crate::domain::docu
             filename: generated.filename.clone(),
             pdf_base64: generated.to_base64(),
             size_bytes: generated.pdf_data.len(),
+            page_count: generated.page_count,
             document_id: generated.document.id.as_uuid().to_string(),
         };
 
@@ -56,7 +85,8 @@ fn build_response(req: &Request, params: &Params, generated: crate::domain::docu
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct Rule16bRequest {
     pub case_number: String,
-    pub defendant_names: String,
+    /// One name, names separated by semicolons, or a list of names
+    pub defendant_names: DefendantNames,
     pub judge_name: String,
     #[serde(default)]
     pub trial_date: Option<String>,
@@ -70,12 +100,16 @@ pub struct Rule16bRequest {
     pub signature_base64: Option<String>,
     #[serde(default)]
     pub judge_id: Option<String>,
+    /// Paper size and margins; US Letter with one-inch margins by default
+    #[serde(flatten)]
+    pub layout: PageLayout,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CourtOrderRequest {
     pub case_number: String,
-    pub defendant_names: String,
+    /// One name, names separated by semicolons, or a list of names
+    pub defendant_names: DefendantNames,
     pub judge_name: String,
     pub order_title: String,
     pub order_content: String,
@@ -85,12 +119,16 @@ pub struct CourtOrderRequest {
     pub signature_base64: Option<String>,
     #[serde(default)]
     pub judge_id: Option<String>,
+    /// Paper size and margins; US Letter with one-inch margins by default
+    #[serde(flatten)]
+    pub layout: PageLayout,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct MinuteEntryRequest {
     pub case_number: String,
-    pub defendant_names: String,
+    /// One name, names separated by semicolons, or a list of names
+    pub defendant_names: DefendantNames,
     pub judge_name: String,
     pub minute_text: String,
     #[serde(default)]
@@ -101,6 +139,13 @@ pub struct MinuteEntryRequest {
     pub court_reporter: Option<String>,
     #[serde(default)]
     pub next_hearing: Option<String>,
+    #[serde(default)]
+    pub signature_base64: Option<String>,
+    #[serde(default)]
+    pub judge_id: Option<String>,
+    /// Paper size and margins; US Letter with one-inch margins by default
+    #[serde(flatten)]
+    pub layout: PageLayout,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -108,14 +153,27 @@ pub struct WaiverIndictmentRequest {
     pub case_number: String,
     pub defendant_name: String,
     pub charges: String,
+    /// Paper size and margins; US Letter with one-inch margins by default
+    #[serde(flatten)]
+    pub layout: PageLayout,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ConditionsReleaseRequest {
     pub case_number: String,
+    /// Ignored when `defendant_id` is given
+    #[serde(default)]
     pub defendant_name: String,
     pub judge_name: String,
+    /// Ignored when `defendant_id` is given
+    #[serde(default)]
     pub conditions: Vec<String>,
+    /// Take the name and conditions from this defendant's record on the case
+    #[serde(default)]
+    pub defendant_id: Option<uuid::Uuid>,
+    /// Paper size and margins; US Letter with one-inch margins by default
+    #[serde(flatten)]
+    pub layout: PageLayout,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -126,6 +184,49 @@ pub struct CriminalJudgmentRequest {
     pub plea: String,
     pub counts: String,
     pub sentence: String,
+    /// Paper size and margins; US Letter with one-inch margins by default
+    #[serde(flatten)]
+    pub layout: PageLayout,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SubpoenaRequest {
+    pub case_number: String,
+    /// Witness commanded to appear
+    pub recipient_name: String,
+    pub appearance_date: chrono::DateTime<chrono::Utc>,
+    /// Courthouse and courtroom where the witness must appear
+    pub location: String,
+    pub judge_name: String,
+    /// Paper size and margins; US Letter with one-inch margins by default
+    #[serde(flatten)]
+    pub layout: PageLayout,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SummonsRequest {
+    /// Party the summons is directed to
+    pub party_id: String,
+    /// Days the party has to respond after service (FRCP 12(a) default of 21)
+    #[serde(default)]
+    pub response_days: Option<u32>,
+    /// Intended method of service for the pending service record
+    #[serde(default)]
+    pub service_method: Option<ServiceMethod>,
+    /// Person or office responsible for serving the summons
+    #[serde(default)]
+    pub served_by: Option<String>,
+    /// Paper size and margins; US Letter with one-inch margins by default
+    #[serde(flatten)]
+    pub layout: PageLayout,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SummonsResponse {
+    #[serde(flatten)]
+    pub document: PdfResponse,
+    pub party_id: String,
+    pub service_record_id: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -135,12 +236,20 @@ pub struct PdfResponse {
     pub filename: String,
     pub pdf_base64: String,
     pub size_bytes: usize,
+    pub page_count: usize,
     pub document_id: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct BatchPdfRequest {
     pub documents: Vec<DocumentRequestDto>,
+    /// Paper size and margins; US Letter with one-inch margins by default
+    #[serde(flatten)]
+    pub layout: PageLayout,
+    /// Fail the whole batch on the first invalid or failed document
+    /// (`/api/pdf/batch` only)
+    #[serde(default)]
+    pub fail_fast: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -149,14 +258,14 @@ pub enum DocumentRequestDto {
     #[serde(rename = "rule16b")]
     Rule16b {
         case_number: String,
-        defendant_names: String,
+        defendant_names: DefendantNames,
         judge_name: String,
         signature_base64: Option<String>,
     },
     #[serde(rename = "court_order")]
     CourtOrder {
         case_number: String,
-        defendant_names: String,
+        defendant_names: DefendantNames,
         judge_name: String,
         order_title: String,
         order_content: String,
@@ -165,9 +274,10 @@ pub enum DocumentRequestDto {
     #[serde(rename = "minute_entry")]
     MinuteEntry {
         case_number: String,
-        defendant_names: String,
+        defendant_names: DefendantNames,
         judge_name: String,
         minute_text: String,
+        signature_base64: Option<String>,
     },
     #[serde(rename = "waiver_indictment")]
     WaiverIndictment {
@@ -191,12 +301,72 @@ pub enum DocumentRequestDto {
         counts: String,
         sentence: String,
     },
+    #[serde(rename = "subpoena")]
+    Subpoena {
+        case_number: String,
+        recipient_name: String,
+        appearance_date: chrono::DateTime<chrono::Utc>,
+        location: String,
+        judge_name: String,
+    },
+}
+
+impl DocumentRequestDto {
+    /// Case number the document was requested for
+    pub fn case_number(&self) -> &str {
+        match self {
+            Self::Rule16b { case_number, .. }
+            | Self::CourtOrder { case_number, .. }
+            | Self::MinuteEntry { case_number, .. }
+            | Self::WaiverIndictment { case_number, .. }
+            | Self::ConditionsRelease { case_number, .. }
+            | Self::CriminalJudgment { case_number, .. }
+            | Self::Subpoena { case_number, .. } => case_number,
+        }
+    }
+
+    /// Inline signature image, for the document types that take one
+    pub fn signature_base64(&self) -> Option<&str> {
+        match self {
+            Self::Rule16b { signature_base64, .. }
+            | Self::CourtOrder { signature_base64, .. }
+            | Self::MinuteEntry { signature_base64, .. } => signature_base64.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct BatchPdfResponse {
     pub documents: Vec<PdfResponse>,
     pub total_generated: usize,
+    /// Documents that could not be generated
+    #[serde(default)]
+    pub failures: Vec<BatchFailure>,
+    #[serde(default)]
+    pub total_failed: usize,
+    /// Outcome of every requested document, in request order
+    #[serde(default)]
+    pub results: Vec<BatchItemResult>,
+}
+
+/// Whether one document of a batch was generated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchItemStatus {
+    Generated,
+    Failed,
+}
+
+/// Outcome of one document in a batch
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BatchItemResult {
+    /// Zero-based position of the document in the request
+    pub index: usize,
+    pub status: BatchItemStatus,
+    /// Id of the generated document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
@@ -205,10 +375,102 @@ pub struct StoreSignatureRequest {
     pub signature_base64: String,
 }
 
+/// Result of checking a stored signature
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SignatureVerificationResponse {
+    pub judge_id: String,
+    /// Whether the stored signature decodes to a usable PNG or JPEG image
+    pub valid: bool,
+    /// `png` or `jpeg`, when valid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// Whether the stored data still matches the SHA-256 hash taken at upload
+    pub hash_matches: bool,
+    /// Why the signature is unusable, when it is not valid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub uploaded_at: String,
+}
+
+/// A stored signature's details, without the image itself
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SignatureMetadata {
+    pub judge_id: String,
+    pub uploaded_at: String,
+    /// SHA-256 of the stored base64 data
+    pub signature_hash: String,
+    /// `png` or `jpeg`; absent on signatures stored before it was recorded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// Decoded image size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub byte_size: Option<u64>,
+}
+
+impl From<JudgeSignature> for SignatureMetadata {
+    fn from(signature: JudgeSignature) -> Self {
+        Self {
+            judge_id: signature.judge_id.to_string(),
+            uploaded_at: signature.uploaded_at,
+            signature_hash: signature.signature_hash,
+            format: signature.format,
+            width: signature.width,
+            height: signature.height,
+            byte_size: signature.byte_size,
+        }
+    }
+}
+
+/// The district's limits on signature images
+fn signature_limits(req: &Request) -> Result<SignatureLimits, ApiError> {
+    let district_id = tenant::get_config_district_id(req);
+    let config_repo = RepositoryFactory::config_repo(req)?;
+    let config = futures::executor::block_on(config_repo.get_merged_config(&district_id, None))?;
+    Ok(SignatureLimits::from_config(&config))
+}
+
+/// 422 naming the check a signature failed
+fn signature_error(rejection: SignatureRejection) -> ApiError {
+    ApiError::Unprocessable(format!("Invalid signature: {}", rejection.message))
+        .with_details(serde_json::json!(rejection))
+}
+
+/// Validate a signature sent inline with a document request
+fn check_inline_signature(req: &Request, signature_base64: Option<&str>) -> Result<(), ApiError> {
+    let Some(signature_base64) = signature_base64 else {
+        return Ok(());
+    };
+    let limits = signature_limits(req)?;
+    validate_signature_image(signature_base64, &limits).map(|_| ()).map_err(signature_error)
+}
+
+/// Document request for a batch item, with its inline signature validated
+fn checked_document_request(
+    dto: DocumentRequestDto,
+    district: District,
+    layout: PageLayout,
+    limits: &SignatureLimits,
+) -> Result<DocumentRequest, DocumentError> {
+    if let Some(signature_base64) = dto.signature_base64() {
+        validate_signature_image(signature_base64, limits).map_err(DocumentError::InvalidSignature)?;
+    }
+    create_document_request(dto, district, layout)
+}
+
 fn create_document_request(
     dto: DocumentRequestDto,
     district: District,
+    layout: PageLayout,
 ) -> Result<DocumentRequest, DocumentError> {
+    layout.validate()?;
     let (case_number, document_type, metadata) = match dto {
         DocumentRequestDto::Rule16b {
             case_number,
@@ -253,15 +515,20 @@ fn create_document_request(
             defendant_names,
             judge_name,
             minute_text,
-        } => (
-            CaseNumber::new(case_number)?,
-            DocumentType::MinuteEntry,
-            DocumentMetadata::MinuteEntry {
-                defendant_names,
-                judge_name: JudgeName::new(judge_name)?,
-                minute_text,
-            },
-        ),
+            signature_base64,
+        } => {
+            let signature = signature_base64.map(|sig| ElectronicSignature::new(judge_name.clone(), &sig));
+            (
+                CaseNumber::new(case_number)?,
+                DocumentType::MinuteEntry,
+                DocumentMetadata::MinuteEntry {
+                    defendant_names,
+                    judge_name: JudgeName::new(judge_name)?,
+                    minute_text,
+                    signature,
+                },
+            )
+        }
         DocumentRequestDto::WaiverIndictment {
             case_number,
             defendant_name,
@@ -306,6 +573,22 @@ fn create_document_request(
                 sentence,
             },
         ),
+        DocumentRequestDto::Subpoena {
+            case_number,
+            recipient_name,
+            appearance_date,
+            location,
+            judge_name,
+        } => (
+            CaseNumber::new(case_number)?,
+            DocumentType::Subpoena,
+            DocumentMetadata::Subpoena {
+                recipient_name,
+                appearance_date,
+                location,
+                judge_name: JudgeName::new(judge_name)?,
+            },
+        ),
     };
 
     Ok(DocumentRequest {
@@ -313,11 +596,37 @@ fn create_document_request(
         document_type,
         district,
         metadata,
+        layout,
     })
 }
 
+/// Signature image for a signed document
+///
+/// Looks up the stored signature of the request's `judge_id` and falls back
+/// to the inline `signature_base64` when there is no `judge_id` or nothing
+/// is stored for that judge. A `judge_id` that is not a UUID is a 400.
+fn resolve_signature(
+    service: &PdfService,
+    judge_id: Option<&str>,
+    inline: Option<String>,
+) -> Result<Option<String>, ApiError> {
+    let Some(judge_id) = judge_id else {
+        return Ok(inline);
+    };
+    let judge_id = uuid::Uuid::parse_str(judge_id)
+        .map_err(|_| ApiError::BadRequest(format!("judge_id must be a UUID, got {:?}", judge_id)))?;
+    match service.get_signature_sync(judge_id) {
+        Ok(Some(stored)) => Ok(Some(stored.signature_base64)),
+        Ok(None) => Ok(inline),
+        Err(e) => Err(ApiError::Internal(format!("Failed to retrieve signature: {}", e))),
+    }
+}
+
 /// Generate a signed Rule 16(b) order
 ///
+/// Signs with the stored signature of `judge_id`, or the inline
+/// `signature_base64` when none is stored.
+///
 /// Returns either raw PDF or JSON with base64 PDF based on Accept header:
 /// - Accept: application/pdf → Raw PDF binary
 /// - Accept: application/json → JSON with base64-encoded PDF
@@ -328,7 +637,8 @@ fn create_document_request(
     responses(
         (status = 200, description = "PDF generated successfully (JSON format)", body = PdfResponse, content_type = "application/json"),
         (status = 200, description = "PDF generated successfully (PDF format)", content_type = "application/pdf"),
-        (status = 400, description = "Invalid request"),
+        (status = 400, description = "Invalid request or judge_id"),
+        (status = 422, description = "Inline signature failed a check; details.check names it", body = SignatureRejection),
         (status = 500, description = "Internal server error")
     ),
     tag = "pdf-generation",
@@ -342,11 +652,7 @@ pub fn generate_signed_rule16b(req: Request, params: Params) -> Response {
     let district = match District::new(district_str.clone()) {
         Ok(d) => d,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
         }
     };
 
@@ -354,37 +660,25 @@ pub fn generate_signed_rule16b(req: Request, params: Params) -> Response {
     let mut request: Rule16bRequest = match serde_json::from_slice(&body) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Invalid request: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(format!("Invalid request: {}", e)));
         }
     };
-
-    // Try to fetch stored signature for the judge
-    // Extract judge ID from judge name (assuming format "Hon. John Smith" -> use initials or full name as ID)
-    // For demo, we'll use a hardcoded judge_id
-    let judge_id = uuid::Uuid::parse_str("123e4567-e89b-12d3-a456-426614174000").unwrap_or_else(|_| uuid::Uuid::new_v4());
+    if let Err(e) = check_inline_signature(&req, request.signature_base64.as_deref()) {
+        return json::error_response(&e);
+    }
 
     // Create PDF service to get signature
     let service = match create_pdf_service(&district_str) {
         Ok(s) => s,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Service initialization failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
         }
     };
 
-    // Try to get stored signature
-    if request.signature_base64.is_none() {
-        if let Ok(Some(signature)) = service.get_signature_sync(judge_id) {
-            request.signature_base64 = Some(signature.signature_base64);
-        }
-    }
+    request.signature_base64 = match resolve_signature(&service, request.judge_id.as_deref(), request.signature_base64) {
+        Ok(signature) => signature,
+        Err(e) => return json::error_response(&e),
+    };
 
     // Now create the document with the signature
     let doc_request = match create_document_request(
@@ -395,14 +689,11 @@ pub fn generate_signed_rule16b(req: Request, params: Params) -> Response {
             signature_base64: request.signature_base64,
         },
         district,
+        request.layout,
     ) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(e.to_string()));
         }
     };
 
@@ -410,11 +701,7 @@ pub fn generate_signed_rule16b(req: Request, params: Params) -> Response {
     let generated = match service.generate_document_sync(doc_request) {
         Ok(doc) => doc,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Failed to generate PDF: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Failed to generate PDF: {}", e)));
         }
     };
 
@@ -434,6 +721,7 @@ pub fn generate_signed_rule16b(req: Request, params: Params) -> Response {
         (status = 200, description = "PDF generated successfully (JSON format)", body = PdfResponse, content_type = "application/json"),
         (status = 200, description = "PDF generated successfully (PDF format)", content_type = "application/pdf"),
         (status = 400, description = "Invalid request"),
+        (status = 422, description = "Inline signature failed a check; details.check names it", body = SignatureRejection),
         (status = 500, description = "Internal server error")
     ),
     tag = "pdf-generation",
@@ -447,11 +735,7 @@ pub fn generate_court_order(req: Request, params: Params) -> Response {
     let district = match District::new(district_str.clone()) {
         Ok(d) => d,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
         }
     };
 
@@ -459,13 +743,12 @@ pub fn generate_court_order(req: Request, params: Params) -> Response {
     let request: CourtOrderRequest = match serde_json::from_slice(&body) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Invalid request: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(format!("Invalid request: {}", e)));
         }
     };
+    if let Err(e) = check_inline_signature(&req, request.signature_base64.as_deref()) {
+        return json::error_response(&e);
+    }
 
     let doc_request = match create_document_request(
         DocumentRequestDto::CourtOrder {
@@ -477,14 +760,11 @@ pub fn generate_court_order(req: Request, params: Params) -> Response {
             signature_base64: request.signature_base64,
         },
         district,
+        request.layout,
     ) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(e.to_string()));
         }
     };
 
@@ -492,11 +772,7 @@ pub fn generate_court_order(req: Request, params: Params) -> Response {
     let service = match create_pdf_service(&district_str) {
         Ok(s) => s,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Service initialization failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
         }
     };
 
@@ -504,11 +780,7 @@ pub fn generate_court_order(req: Request, params: Params) -> Response {
     let generated = match service.generate_document_sync(doc_request) {
         Ok(g) => g,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Generation failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Generation failed: {}", e)));
         }
     };
 
@@ -528,6 +800,7 @@ pub fn generate_court_order(req: Request, params: Params) -> Response {
         (status = 200, description = "PDF generated successfully (JSON format)", body = PdfResponse, content_type = "application/json"),
         (status = 200, description = "PDF generated successfully (PDF format)", content_type = "application/pdf"),
         (status = 400, description = "Invalid request"),
+        (status = 422, description = "Inline signature failed a check; details.check names it", body = SignatureRejection),
         (status = 500, description = "Internal server error")
     ),
     tag = "pdf-generation",
@@ -541,11 +814,7 @@ pub fn generate_minute_entry(req: Request, params: Params) -> Response {
     let district = match District::new(district_str.clone()) {
         Ok(d) => d,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
         }
     };
 
@@ -553,13 +822,12 @@ pub fn generate_minute_entry(req: Request, params: Params) -> Response {
     let request: MinuteEntryRequest = match serde_json::from_slice(&body) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Invalid request: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(format!("Invalid request: {}", e)));
         }
     };
+    if let Err(e) = check_inline_signature(&req, request.signature_base64.as_deref()) {
+        return json::error_response(&e);
+    }
 
     let doc_request = match create_document_request(
         DocumentRequestDto::MinuteEntry {
@@ -567,16 +835,14 @@ pub fn generate_minute_entry(req: Request, params: Params) -> Response {
             defendant_names: request.defendant_names,
             judge_name: request.judge_name,
             minute_text: request.minute_text,
+            signature_base64: request.signature_base64,
         },
         district,
+        request.layout,
     ) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(e.to_string()));
         }
     };
 
@@ -584,11 +850,7 @@ pub fn generate_minute_entry(req: Request, params: Params) -> Response {
     let service = match create_pdf_service(&district_str) {
         Ok(s) => s,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Service initialization failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
         }
     };
 
@@ -596,30 +858,30 @@ pub fn generate_minute_entry(req: Request, params: Params) -> Response {
     let generated = match service.generate_document_sync(doc_request) {
         Ok(g) => g,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Generation failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Generation failed: {}", e)));
         }
     };
 
     build_response(&req, &params, generated, "minute_entry", request.case_number)
 }
 
-/// Generate a Rule 16(b) scheduling order
+/// Generate a signed court order
+///
+/// Signs with the stored signature of `judge_id`, or the inline
+/// `signature_base64` when none is stored.
 ///
 /// Returns either raw PDF or JSON with base64 PDF based on Accept header:
 /// - Accept: application/pdf → Raw PDF binary
 /// - Accept: application/json → JSON with base64-encoded PDF
 #[utoipa::path(
     post,
-    path = "/api/pdf/rule16b/{format}",
-    request_body = Rule16bRequest,
+    path = "/api/pdf/signed/court-order/{format}",
+    request_body = CourtOrderRequest,
     responses(
         (status = 200, description = "PDF generated successfully (JSON format)", body = PdfResponse, content_type = "application/json"),
         (status = 200, description = "PDF generated successfully (PDF format)", content_type = "application/pdf"),
-        (status = 400, description = "Invalid request"),
+        (status = 400, description = "Invalid request or judge_id"),
+        (status = 422, description = "Inline signature failed a check; details.check names it", body = SignatureRejection),
         (status = 500, description = "Internal server error")
     ),
     tag = "pdf-generation",
@@ -628,90 +890,83 @@ pub fn generate_minute_entry(req: Request, params: Params) -> Response {
         ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
     ),
 )]
-pub fn generate_rule16b(req: Request, params: Params) -> Response {
+pub fn generate_signed_court_order(req: Request, params: Params) -> Response {
     let district_str = tenant::get_tenant_id(&req);
     let district = match District::new(district_str.clone()) {
         Ok(d) => d,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
         }
     };
 
     let body = req.body().to_vec();
-    let request: Rule16bRequest = match serde_json::from_slice(&body) {
+    let request: CourtOrderRequest = match serde_json::from_slice(&body) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Invalid request: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(format!("Invalid request: {}", e)));
+        }
+    };
+    if let Err(e) = check_inline_signature(&req, request.signature_base64.as_deref()) {
+        return json::error_response(&e);
+    }
+
+    let service = match create_pdf_service(&district_str) {
+        Ok(s) => s,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
         }
     };
 
+    let signature_base64 = match resolve_signature(&service, request.judge_id.as_deref(), request.signature_base64) {
+        Ok(signature) => signature,
+        Err(e) => return json::error_response(&e),
+    };
+
     let doc_request = match create_document_request(
-        DocumentRequestDto::Rule16b {
+        DocumentRequestDto::CourtOrder {
             case_number: request.case_number.clone(),
             defendant_names: request.defendant_names,
             judge_name: request.judge_name,
-            signature_base64: request.signature_base64,
+            order_title: request.order_title,
+            order_content: request.order_content,
+            signature_base64,
         },
         district,
+        request.layout,
     ) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
-        }
-    };
-
-    // Create PDF service with dependency injection
-    let service = match create_pdf_service(&district_str) {
-        Ok(s) => s,
-        Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Service initialization failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(e.to_string()));
         }
     };
 
-    // Use the service to generate the document
     let generated = match service.generate_document_sync(doc_request) {
-        Ok(g) => g,
+        Ok(doc) => doc,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Generation failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Failed to generate PDF: {}", e)));
         }
     };
 
-    build_response(&req, &params, generated, "rule16b", request.case_number)
+    build_response(&req, &params, generated, "court-order-signed", request.case_number)
 }
 
-/// Generate waiver of indictment
+/// Generate a signed minute entry
+///
+/// Signs with the stored signature of `judge_id`, or the inline
+/// `signature_base64` when none is stored.
 ///
 /// Returns either raw PDF or JSON with base64 PDF based on Accept header:
 /// - Accept: application/pdf → Raw PDF binary
 /// - Accept: application/json → JSON with base64-encoded PDF
 #[utoipa::path(
     post,
-    path = "/api/pdf/waiver-indictment/{format}",
-    request_body = WaiverIndictmentRequest,
+    path = "/api/pdf/signed/minute-entry/{format}",
+    request_body = MinuteEntryRequest,
     responses(
         (status = 200, description = "PDF generated successfully (JSON format)", body = PdfResponse, content_type = "application/json"),
         (status = 200, description = "PDF generated successfully (PDF format)", content_type = "application/pdf"),
-        (status = 400, description = "Invalid request"),
+        (status = 400, description = "Invalid request or judge_id"),
+        (status = 422, description = "Inline signature failed a check; details.check names it", body = SignatureRejection),
         (status = 500, description = "Internal server error")
     ),
     tag = "pdf-generation",
@@ -720,91 +975,79 @@ pub fn generate_rule16b(req: Request, params: Params) -> Response {
         ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
     ),
 )]
-pub fn generate_waiver_indictment(req: Request, params: Params) -> Response {
+pub fn generate_signed_minute_entry(req: Request, params: Params) -> Response {
     let district_str = tenant::get_tenant_id(&req);
     let district = match District::new(district_str.clone()) {
         Ok(d) => d,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
         }
     };
 
-    let case_id = params.get("case_id").unwrap_or("24-cr-00001");
     let body = req.body().to_vec();
-    let request: WaiverIndictmentRequest = match serde_json::from_slice(&body) {
+    let request: MinuteEntryRequest = match serde_json::from_slice(&body) {
         Ok(r) => r,
-        Err(_) => {
-            // Use default values for testing
-            WaiverIndictmentRequest {
-                case_number: case_id.to_string(),
-                defendant_name: "Test Defendant".to_string(),
-                charges: "18 U.S.C. § 1343 (Wire Fraud)".to_string(),
-            }
+        Err(e) => {
+            return json::error_response(&ApiError::BadRequest(format!("Invalid request: {}", e)));
+        }
+    };
+    if let Err(e) = check_inline_signature(&req, request.signature_base64.as_deref()) {
+        return json::error_response(&e);
+    }
+
+    let service = match create_pdf_service(&district_str) {
+        Ok(s) => s,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
         }
     };
 
+    let signature_base64 = match resolve_signature(&service, request.judge_id.as_deref(), request.signature_base64) {
+        Ok(signature) => signature,
+        Err(e) => return json::error_response(&e),
+    };
+
     let doc_request = match create_document_request(
-        DocumentRequestDto::WaiverIndictment {
+        DocumentRequestDto::MinuteEntry {
             case_number: request.case_number.clone(),
-            defendant_name: request.defendant_name,
-            charges: request.charges,
+            defendant_names: request.defendant_names,
+            judge_name: request.judge_name,
+            minute_text: request.minute_text,
+            signature_base64,
         },
         district,
+        request.layout,
     ) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
-        }
-    };
-
-    // Create PDF service with dependency injection
-    let service = match create_pdf_service(&district_str) {
-        Ok(s) => s,
-        Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Service initialization failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(e.to_string()));
         }
     };
 
-    // Use the service to generate the document
     let generated = match service.generate_document_sync(doc_request) {
-        Ok(g) => g,
+        Ok(doc) => doc,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Generation failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Failed to generate PDF: {}", e)));
         }
     };
 
-    build_response(&req, &params, generated, "waiver_indictment", request.case_number)
+    build_response(&req, &params, generated, "minute-entry-signed", request.case_number)
 }
 
-/// Generate conditions of release
+/// Generate a Rule 16(b) scheduling order
 ///
 /// Returns either raw PDF or JSON with base64 PDF based on Accept header:
 /// - Accept: application/pdf → Raw PDF binary
 /// - Accept: application/json → JSON with base64-encoded PDF
 #[utoipa::path(
     post,
-    path = "/api/pdf/conditions-release/{format}",
-    request_body = ConditionsReleaseRequest,
+    path = "/api/pdf/rule16b/{format}",
+    request_body = Rule16bRequest,
     responses(
         (status = 200, description = "PDF generated successfully (JSON format)", body = PdfResponse, content_type = "application/json"),
         (status = 200, description = "PDF generated successfully (PDF format)", content_type = "application/pdf"),
         (status = 400, description = "Invalid request"),
+        (status = 422, description = "Inline signature failed a check; details.check names it", body = SignatureRejection),
         (status = 500, description = "Internal server error")
     ),
     tag = "pdf-generation",
@@ -813,54 +1056,39 @@ pub fn generate_waiver_indictment(req: Request, params: Params) -> Response {
         ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
     ),
 )]
-pub fn generate_conditions_release(req: Request, params: Params) -> Response {
+pub fn generate_rule16b(req: Request, params: Params) -> Response {
     let district_str = tenant::get_tenant_id(&req);
     let district = match District::new(district_str.clone()) {
         Ok(d) => d,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
         }
     };
 
-    let case_id = params.get("case_id").unwrap_or("24-cr-00001");
     let body = req.body().to_vec();
-    let request: ConditionsReleaseRequest = match serde_json::from_slice(&body) {
+    let request: Rule16bRequest = match serde_json::from_slice(&body) {
         Ok(r) => r,
-        Err(_) => {
-            // Use default values for testing
-            ConditionsReleaseRequest {
-                case_number: case_id.to_string(),
-                defendant_name: "Test Defendant".to_string(),
-                judge_name: "Hon. Test Judge".to_string(),
-                conditions: vec![
-                    "Report to Pretrial Services as directed".to_string(),
-                    "Surrender passport".to_string(),
-                    "No contact with co-defendants".to_string(),
-                ],
-            }
+        Err(e) => {
+            return json::error_response(&ApiError::BadRequest(format!("Invalid request: {}", e)));
         }
     };
+    if let Err(e) = check_inline_signature(&req, request.signature_base64.as_deref()) {
+        return json::error_response(&e);
+    }
 
     let doc_request = match create_document_request(
-        DocumentRequestDto::ConditionsRelease {
+        DocumentRequestDto::Rule16b {
             case_number: request.case_number.clone(),
-            defendant_name: request.defendant_name,
+            defendant_names: request.defendant_names,
             judge_name: request.judge_name,
-            conditions: request.conditions,
+            signature_base64: request.signature_base64,
         },
         district,
+        request.layout,
     ) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(e.to_string()));
         }
     };
 
@@ -868,11 +1096,7 @@ pub fn generate_conditions_release(req: Request, params: Params) -> Response {
     let service = match create_pdf_service(&district_str) {
         Ok(s) => s,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Service initialization failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
         }
     };
 
@@ -880,26 +1104,225 @@ pub fn generate_conditions_release(req: Request, params: Params) -> Response {
     let generated = match service.generate_document_sync(doc_request) {
         Ok(g) => g,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Generation failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Generation failed: {}", e)));
         }
     };
 
-    build_response(&req, &params, generated, "conditions_release", request.case_number)
+    build_response(&req, &params, generated, "rule16b", request.case_number)
 }
 
-/// Generate criminal judgment
+/// Generate waiver of indictment
 ///
 /// Returns either raw PDF or JSON with base64 PDF based on Accept header:
 /// - Accept: application/pdf → Raw PDF binary
 /// - Accept: application/json → JSON with base64-encoded PDF
 #[utoipa::path(
     post,
-    path = "/api/pdf/criminal-judgment/{format}",
-    request_body = CriminalJudgmentRequest,
+    path = "/api/pdf/waiver-indictment/{format}",
+    request_body = WaiverIndictmentRequest,
+    responses(
+        (status = 200, description = "PDF generated successfully (JSON format)", body = PdfResponse, content_type = "application/json"),
+        (status = 200, description = "PDF generated successfully (PDF format)", content_type = "application/pdf"),
+        (status = 400, description = "Invalid request"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "pdf-generation",
+    params(
+        ("format" = String, Path, description = "Response format: 'pdf' for raw PDF, 'json' for base64-encoded JSON", example = "pdf"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+)]
+pub fn generate_waiver_indictment(req: Request, params: Params) -> Response {
+    let district_str = tenant::get_tenant_id(&req);
+    let district = match District::new(district_str.clone()) {
+        Ok(d) => d,
+        Err(e) => {
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
+        }
+    };
+
+    let case_id = params.get("case_id").unwrap_or("24-cr-00001");
+    let body = req.body().to_vec();
+    let request: WaiverIndictmentRequest = match serde_json::from_slice(&body) {
+        Ok(r) => r,
+        Err(_) => {
+            // Use default values for testing
+            WaiverIndictmentRequest {
+                case_number: case_id.to_string(),
+                defendant_name: "Test Defendant".to_string(),
+                charges: "18 U.S.C. § 1343 (Wire Fraud)".to_string(),
+                layout: PageLayout::default(),
+            }
+        }
+    };
+
+    let doc_request = match create_document_request(
+        DocumentRequestDto::WaiverIndictment {
+            case_number: request.case_number.clone(),
+            defendant_name: request.defendant_name,
+            charges: request.charges,
+        },
+        district,
+        request.layout,
+    ) {
+        Ok(r) => r,
+        Err(e) => {
+            return json::error_response(&ApiError::BadRequest(e.to_string()));
+        }
+    };
+
+    // Create PDF service with dependency injection
+    let service = match create_pdf_service(&district_str) {
+        Ok(s) => s,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
+        }
+    };
+
+    // Use the service to generate the document
+    let generated = match service.generate_document_sync(doc_request) {
+        Ok(g) => g,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Generation failed: {}", e)));
+        }
+    };
+
+    build_response(&req, &params, generated, "waiver_indictment", request.case_number)
+}
+
+/// Defendant name and conditions of release from the defendant's record
+fn release_conditions_from_record(
+    req: &Request,
+    case_number: &str,
+    defendant_id: uuid::Uuid,
+) -> Result<(String, Vec<String>), ApiError> {
+    let case_repo = RepositoryFactory::case_repo(req)?;
+    let format = crate::handlers::criminal_case::case_number_format(req)?;
+    let case = crate::services::case_number_service::find_by_number(&case_repo, &format, case_number)?
+        .ok_or_else(|| ApiError::NotFound(format!("Case {} not found", case_number)))?;
+    let defendant = case
+        .find_defendant(defendant_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Defendant {} not found in case {}", defendant_id, case_number)))?;
+
+    let conditions = defendant
+        .bond_info
+        .as_ref()
+        .map(|bond| bond.conditions_of_release.clone())
+        .unwrap_or_default();
+    if conditions.is_empty() {
+        return Err(ApiError::BadRequest(format!("Defendant {} has no conditions of release on record", defendant_id)));
+    }
+
+    Ok((defendant.name.clone(), conditions))
+}
+
+/// Generate conditions of release
+///
+/// Pass `defendant_id` to take the defendant's name and conditions from
+/// their record on the case instead of supplying them inline.
+///
+/// Returns either raw PDF or JSON with base64 PDF based on Accept header:
+/// - Accept: application/pdf → Raw PDF binary
+/// - Accept: application/json → JSON with base64-encoded PDF
+#[utoipa::path(
+    post,
+    path = "/api/pdf/conditions-release/{format}",
+    request_body = ConditionsReleaseRequest,
+    responses(
+        (status = 200, description = "PDF generated successfully (JSON format)", body = PdfResponse, content_type = "application/json"),
+        (status = 200, description = "PDF generated successfully (PDF format)", content_type = "application/pdf"),
+        (status = 400, description = "Invalid request, or the defendant has no conditions of release on record"),
+        (status = 404, description = "Case or defendant not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "pdf-generation",
+    params(
+        ("format" = String, Path, description = "Response format: 'pdf' for raw PDF, 'json' for base64-encoded JSON", example = "pdf"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+)]
+pub fn generate_conditions_release(req: Request, params: Params) -> Response {
+    let district_str = tenant::get_tenant_id(&req);
+    let district = match District::new(district_str.clone()) {
+        Ok(d) => d,
+        Err(e) => {
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
+        }
+    };
+
+    let case_id = params.get("case_id").unwrap_or("24-cr-00001");
+    let body = req.body().to_vec();
+    let request: ConditionsReleaseRequest = match serde_json::from_slice(&body) {
+        Ok(r) => r,
+        Err(_) => {
+            // Use default values for testing
+            ConditionsReleaseRequest {
+                case_number: case_id.to_string(),
+                defendant_name: "Test Defendant".to_string(),
+                judge_name: "Hon. Test Judge".to_string(),
+                conditions: vec![
+                    "Report to Pretrial Services as directed".to_string(),
+                    "Surrender passport".to_string(),
+                    "No contact with co-defendants".to_string(),
+                ],
+                defendant_id: None,
+                layout: PageLayout::default(),
+            }
+        }
+    };
+
+    let (defendant_name, conditions) = match request.defendant_id {
+        Some(defendant_id) => match release_conditions_from_record(&req, &request.case_number, defendant_id) {
+            Ok(from_record) => from_record,
+            Err(e) => return json::error_response(&e),
+        },
+        None => (request.defendant_name, request.conditions),
+    };
+
+    let doc_request = match create_document_request(
+        DocumentRequestDto::ConditionsRelease {
+            case_number: request.case_number.clone(),
+            defendant_name,
+            judge_name: request.judge_name,
+            conditions,
+        },
+        district,
+        request.layout,
+    ) {
+        Ok(r) => r,
+        Err(e) => {
+            return json::error_response(&ApiError::BadRequest(e.to_string()));
+        }
+    };
+
+    // Create PDF service with dependency injection
+    let service = match create_pdf_service(&district_str) {
+        Ok(s) => s,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
+        }
+    };
+
+    // Use the service to generate the document
+    let generated = match service.generate_document_sync(doc_request) {
+        Ok(g) => g,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Generation failed: {}", e)));
+        }
+    };
+
+    build_response(&req, &params, generated, "conditions_release", request.case_number)
+}
+
+/// Generate criminal judgment
+///
+/// Returns either raw PDF or JSON with base64 PDF based on Accept header:
+/// - Accept: application/pdf → Raw PDF binary
+/// - Accept: application/json → JSON with base64-encoded PDF
+#[utoipa::path(
+    post,
+    path = "/api/pdf/criminal-judgment/{format}",
+    request_body = CriminalJudgmentRequest,
     responses(
         (status = 200, description = "PDF generated successfully (JSON format)", body = PdfResponse, content_type = "application/json"),
         (status = 200, description = "PDF generated successfully (PDF format)", content_type = "application/pdf"),
@@ -917,11 +1340,7 @@ pub fn generate_criminal_judgment(req: Request, params: Params) -> Response {
     let district = match District::new(district_str.clone()) {
         Ok(d) => d,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
         }
     };
 
@@ -938,6 +1357,7 @@ pub fn generate_criminal_judgment(req: Request, params: Params) -> Response {
                 plea: "guilty".to_string(),
                 counts: "1-3".to_string(),
                 sentence: "60 months imprisonment, 3 years supervised release".to_string(),
+                layout: PageLayout::default(),
             }
         }
     };
@@ -952,14 +1372,11 @@ pub fn generate_criminal_judgment(req: Request, params: Params) -> Response {
             sentence: request.sentence,
         },
         district,
+        request.layout,
     ) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(e.to_string()));
         }
     };
 
@@ -967,11 +1384,7 @@ pub fn generate_criminal_judgment(req: Request, params: Params) -> Response {
     let service = match create_pdf_service(&district_str) {
         Ok(s) => s,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Service initialization failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
         }
     };
 
@@ -979,44 +1392,302 @@ pub fn generate_criminal_judgment(req: Request, params: Params) -> Response {
     let generated = match service.generate_document_sync(doc_request) {
         Ok(g) => g,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Generation failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Generation failed: {}", e)));
         }
     };
 
     build_response(&req, &params, generated, "criminal_judgment", request.case_number)
 }
 
+/// Generate a subpoena to appear and testify
+///
+/// Returns either raw PDF or JSON with base64 PDF based on Accept header:
+/// - Accept: application/pdf → Raw PDF binary
+/// - Accept: application/json → JSON with base64-encoded PDF
+#[utoipa::path(
+    post,
+    path = "/api/pdf/subpoena/{format}",
+    request_body = SubpoenaRequest,
+    responses(
+        (status = 200, description = "PDF generated successfully (JSON format)", body = PdfResponse, content_type = "application/json"),
+        (status = 200, description = "PDF generated successfully (PDF format)", content_type = "application/pdf"),
+        (status = 400, description = "Invalid request"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "pdf-generation",
+    params(
+        ("format" = String, Path, description = "Response format: 'pdf' for raw PDF, 'json' for base64-encoded JSON", example = "pdf"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+)]
+pub fn generate_subpoena(req: Request, params: Params) -> Response {
+    let district_str = tenant::get_tenant_id(&req);
+    let district = match District::new(district_str.clone()) {
+        Ok(d) => d,
+        Err(e) => {
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
+        }
+    };
+
+    let body = req.body().to_vec();
+    let request: SubpoenaRequest = match serde_json::from_slice(&body) {
+        Ok(r) => r,
+        Err(e) => {
+            return json::error_response(&ApiError::BadRequest(format!("Invalid request: {}", e)));
+        }
+    };
+
+    let doc_request = match create_document_request(
+        DocumentRequestDto::Subpoena {
+            case_number: request.case_number.clone(),
+            recipient_name: request.recipient_name,
+            appearance_date: request.appearance_date,
+            location: request.location,
+            judge_name: request.judge_name,
+        },
+        district,
+        request.layout,
+    ) {
+        Ok(r) => r,
+        Err(e) => {
+            return json::error_response(&ApiError::BadRequest(e.to_string()));
+        }
+    };
+
+    // Create PDF service with dependency injection
+    let service = match create_pdf_service(&district_str) {
+        Ok(s) => s,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
+        }
+    };
+
+    // Use the service to generate the document
+    let generated = match service.generate_document_sync(doc_request) {
+        Ok(g) => g,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Generation failed: {}", e)));
+        }
+    };
+
+    build_response(&req, &params, generated, "subpoena", request.case_number)
+}
+
+/// Issue a summons to a party in a case
+///
+/// Renders the summons and records a pending service record linking the
+/// generated document to the party, so service can be tracked through the
+/// `/api/service-records` endpoints.
+///
+/// Returns either raw PDF or JSON with base64 PDF based on the format parameter or Accept header.
+/// The raw PDF response carries the service record ID in the `x-service-record-id` header.
+#[utoipa::path(
+    post,
+    path = "/api/cases/{case_id}/summons/{format}",
+    request_body = SummonsRequest,
+    responses(
+        (status = 200, description = "Summons generated successfully (JSON format)", body = SummonsResponse, content_type = "application/json"),
+        (status = 200, description = "Summons generated successfully (PDF format)", content_type = "application/pdf"),
+        (status = 400, description = "Invalid request or party not on case"),
+        (status = 404, description = "Case or party not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "pdf-generation",
+    params(
+        ("case_id" = String, Path, description = "Case ID"),
+        ("format" = String, Path, description = "Response format: 'pdf' for raw PDF, 'json' for base64-encoded JSON", example = "pdf"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+)]
+pub fn generate_summons(req: Request, params: Params) -> Response {
+    let district_str = tenant::get_tenant_id(&req);
+    let district = match District::new(district_str.clone()) {
+        Ok(d) => d,
+        Err(e) => {
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
+        }
+    };
+
+    let request: SummonsRequest = match serde_json::from_slice(req.body()) {
+        Ok(r) => r,
+        Err(e) => {
+            return json::error_response(&ApiError::BadRequest(format!("Invalid request: {}", e)));
+        }
+    };
+    if let Err(e) = request.layout.validate() {
+        return json::error_response(&ApiError::BadRequest(e.to_string()));
+    }
+
+    let case_id_str = params.get("case_id").unwrap_or("");
+    let case_id = match uuid::Uuid::parse_str(case_id_str) {
+        Ok(id) => id,
+        Err(e) => {
+            return json::error_response(&ApiError::BadRequest(format!("Invalid case ID: {}", e)));
+        }
+    };
+
+    let case_repo = match RepositoryFactory::case_repo(&req) {
+        Ok(r) => r,
+        Err(e) => {
+            return json::error_response(&e);
+        }
+    };
+    let case = match case_repo.find_by_id(case_id) {
+        Ok(Some(c)) => c,
+        Ok(None) => {
+            return json::error_response(&ApiError::NotFound(format!("Case {} not found", case_id)));
+        }
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Failed to load case: {}", e)));
+        }
+    };
+
+    let attorney_repo = match RepositoryFactory::attorney_repo(&req) {
+        Ok(r) => r,
+        Err(e) => {
+            return json::error_response(&e);
+        }
+    };
+    let party = match attorney_repo.find_party_by_id(&request.party_id) {
+        Ok(Some(p)) => p,
+        Ok(None) => {
+            return json::error_response(&ApiError::NotFound(format!("Party {} not found", request.party_id)));
+        }
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Failed to load party: {}", e)));
+        }
+    };
+    if party.case_id != case_id.to_string() {
+        return json::error_response(&ApiError::BadRequest(format!("Party {} is not a party to case {}", party.id, case_id)));
+    }
+
+    let party_address = party.address.as_ref().map(|a| {
+        let mut lines = vec![a.street1.clone()];
+        if let Some(street2) = &a.street2 {
+            lines.push(street2.clone());
+        }
+        lines.push(format!("{}, {} {}", a.city, a.state, a.zip_code));
+        lines.join("\n")
+    });
+
+    let case_number = match CaseNumber::new(case.case_number.clone()) {
+        Ok(n) => n,
+        Err(e) => {
+            return json::error_response(&ApiError::BadRequest(e.to_string()));
+        }
+    };
+
+    let doc_request = DocumentRequest {
+        case_number,
+        document_type: DocumentType::Summons,
+        district,
+        metadata: DocumentMetadata::Summons {
+            party_name: party.name.clone(),
+            party_address,
+            response_days: request.response_days.unwrap_or(21),
+        },
+        layout: request.layout,
+    };
+
+    // Create PDF service with dependency injection
+    let service = match create_pdf_service(&district_str) {
+        Ok(s) => s,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
+        }
+    };
+
+    let generated = match service.generate_document_sync(doc_request) {
+        Ok(g) => g,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Generation failed: {}", e)));
+        }
+    };
+
+    index_generated(&req, &generated, Some(case.id));
+
+    // A freshly issued summons has not been served yet
+    let document_id = generated.document.id.as_uuid().to_string();
+    let service_record = ServiceRecord {
+        id: uuid::Uuid::new_v4().to_string(),
+        document_id: document_id.clone(),
+        party_id: party.id.clone(),
+        service_date: chrono::Utc::now(),
+        service_method: request.service_method.unwrap_or(ServiceMethod::PersonalService),
+        served_by: request.served_by.unwrap_or_default(),
+        proof_of_service_filed: false,
+        certificate_of_service: None,
+        successful: false,
+        attempts: 0,
+        notes: Some("Summons issued; service pending".to_string()),
+    };
+    let service_record_id = service_record.id.clone();
+    if let Err(e) = attorney_repo.save_service_record(service_record) {
+        return json::error_response(&ApiError::Internal(format!("Failed to record service: {}", e)));
+    }
+
+    if wants_pdf(&req, &params) {
+        return Response::builder()
+            .status(200)
+            .header("content-type", "application/pdf")
+            .header("content-disposition", format!(r#"attachment; filename="{}""#, generated.filename))
+            .header("x-service-record-id", service_record_id)
+            .body(generated.pdf_data)
+            .build();
+    }
+
+    let response = SummonsResponse {
+        document: PdfResponse {
+            case_number: case.case_number,
+            document_type: "summons".to_string(),
+            filename: generated.filename.clone(),
+            pdf_base64: generated.to_base64(),
+            size_bytes: generated.pdf_data.len(),
+            page_count: generated.page_count,
+            document_id,
+        },
+        party_id: party.id,
+        service_record_id,
+    };
+
+    Response::builder()
+        .status(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&response).unwrap_or_default())
+        .build()
+}
+
 /// Generate multiple PDFs in a single request
 ///
 /// Always returns JSON with base64-encoded PDFs (batch operations don't support raw PDF response)
+///
+/// Each document is generated independently, and rendering failures are
+/// retried a few times. Documents that still fail are listed in `failures`
+/// with their position, case number and error code, next to the ones that
+/// were generated; `results` gives the status of every document in request
+/// order. The request fails only when it is malformed or no document could be
+/// generated. With `fail_fast` the first failure fails the whole batch.
 #[utoipa::path(
     post,
     path = "/api/pdf/batch",
     request_body = BatchPdfRequest,
     responses(
-        (status = 200, description = "PDFs generated successfully", body = BatchPdfResponse),
-        (status = 400, description = "Invalid request"),
-        (status = 500, description = "Internal server error")
+        (status = 200, description = "PDFs generated, with any documents that failed listed in failures", body = BatchPdfResponse),
+        (status = 400, description = "Invalid request, or an invalid document with fail_fast"),
+        (status = 422, description = "No document in the batch could be generated, or an invalid signature with fail_fast"),
+        (status = 500, description = "Internal server error, or a failed document with fail_fast")
     ),
     tag = "pdf-generation",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
     ),
 )]
-pub fn generate_batch_pdfs(req: Request, params: Params) -> Response {
+pub fn generate_batch_pdfs(req: Request, _params: Params) -> Response {
     let district_str = tenant::get_tenant_id(&req);
     let district = match District::new(district_str.clone()) {
         Ok(d) => d,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "{}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
         }
     };
 
@@ -1024,87 +1695,134 @@ pub fn generate_batch_pdfs(req: Request, params: Params) -> Response {
     let request: BatchPdfRequest = match serde_json::from_slice(&body) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Invalid request: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(format!("Invalid request: {}", e)));
         }
     };
-
-    let mut documents = Vec::new();
-    for dto in request.documents {
-        let doc_request = match create_document_request(dto, district.clone()) {
-            Ok(r) => r,
-            Err(e) => {
-                return Response::builder()
-                    .status(400)
-                    .header("content-type", "application/json")
-                    .body(format!(r#"{{"error": "{}"}}"#, e))
-                    .build();
-            }
-        };
-        documents.push(doc_request.to_court_document());
+    if request.documents.is_empty() {
+        return json::error_response(&ApiError::BadRequest("At least one document is required".to_string()));
     }
 
     // Create PDF service with dependency injection
     let service = match create_pdf_service(&district_str) {
         Ok(s) => s,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Service initialization failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
         }
     };
 
-    // Use the service to generate documents
-    let doc_requests: Vec<DocumentRequest> = documents.into_iter()
-        .map(|d| DocumentRequest {
-            case_number: d.case_number,
-            document_type: d.document_type,
-            district: d.district,
-            metadata: d.metadata,
+    let limits = match signature_limits(&req) {
+        Ok(l) => l,
+        Err(e) => return json::error_response(&e),
+    };
+
+    if request.fail_fast {
+        return generate_batch_fail_fast(&req, &service, request, district, &limits);
+    }
+
+    let items = request.documents.into_iter()
+        .map(|dto| BatchItem {
+            case_number: dto.case_number().to_string(),
+            request: checked_document_request(dto, district.clone(), request.layout, &limits),
         })
         .collect();
+    let outcome = service.generate_batch_resilient(items);
+
+    if outcome.generated.is_empty() {
+        return json::error_response(
+            &ApiError::Unprocessable("No documents could be generated".to_string())
+                .with_details(serde_json::json!({ "failures": outcome.failures })),
+        );
+    }
+
+    let mut results: Vec<BatchItemResult> = outcome.failures.iter()
+        .map(|failure| BatchItemResult { index: failure.index, status: BatchItemStatus::Failed, document_id: None })
+        .collect();
+    let mut pdf_responses = Vec::new();
+    for (index, generated) in outcome.generated {
+        index_generated(&req, &generated, None);
+        let response = pdf_response(&generated);
+        results.push(BatchItemResult {
+            index,
+            status: BatchItemStatus::Generated,
+            document_id: Some(response.document_id.clone()),
+        });
+        pdf_responses.push(response);
+    }
+    results.sort_by_key(|result| result.index);
+
+    batch_response(BatchPdfResponse {
+        total_generated: pdf_responses.len(),
+        documents: pdf_responses,
+        total_failed: outcome.failures.len(),
+        failures: outcome.failures,
+        results,
+    })
+}
+
+/// Generate a batch as a unit: 400 for the first invalid document (422 for
+/// an invalid signature), 500 if any fails to render
+fn generate_batch_fail_fast(
+    req: &Request,
+    service: &PdfService,
+    request: BatchPdfRequest,
+    district: District,
+    limits: &SignatureLimits,
+) -> Response {
+    let mut doc_requests = Vec::new();
+    for dto in request.documents {
+        match checked_document_request(dto, district.clone(), request.layout, limits) {
+            Ok(r) => doc_requests.push(r),
+            Err(DocumentError::InvalidSignature(rejection)) => {
+                return json::error_response(&signature_error(rejection));
+            }
+            Err(e) => {
+                return json::error_response(&ApiError::BadRequest(e.to_string()));
+            }
+        }
+    }
 
     let generated_docs = match service.generate_batch_sync(doc_requests) {
         Ok(docs) => docs,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Batch generation failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Batch generation failed: {}", e)));
         }
     };
 
     let mut pdf_responses = Vec::new();
-    for generated in generated_docs {
-        pdf_responses.push(PdfResponse {
-            case_number: generated.document.case_number.as_str().to_string(),
-            document_type: match generated.document.document_type {
-                DocumentType::Rule16b => "rule16b",
-                DocumentType::CourtOrder => "court_order",
-                DocumentType::MinuteEntry => "minute_entry",
-                DocumentType::WaiverIndictment => "waiver_indictment",
-                DocumentType::ConditionsRelease => "conditions_release",
-                DocumentType::CriminalJudgment => "criminal_judgment",
-            }
-            .to_string(),
-            filename: generated.filename.clone(),
-            pdf_base64: generated.to_base64(),
-            size_bytes: generated.pdf_data.len(),
-            document_id: generated.document.id.as_uuid().to_string(),
+    let mut results = Vec::new();
+    for (index, generated) in generated_docs.iter().enumerate() {
+        index_generated(req, generated, None);
+        let response = pdf_response(generated);
+        results.push(BatchItemResult {
+            index,
+            status: BatchItemStatus::Generated,
+            document_id: Some(response.document_id.clone()),
         });
+        pdf_responses.push(response);
     }
 
-    let response = BatchPdfResponse {
+    batch_response(BatchPdfResponse {
         total_generated: pdf_responses.len(),
         documents: pdf_responses,
-    };
+        failures: Vec::new(),
+        total_failed: 0,
+        results,
+    })
+}
+
+fn pdf_response(generated: &GeneratedDocument) -> PdfResponse {
+    PdfResponse {
+        case_number: generated.document.case_number.as_str().to_string(),
+        document_type: generated.document.document_type.as_str().to_string(),
+        filename: generated.filename.clone(),
+        pdf_base64: generated.to_base64(),
+        size_bytes: generated.pdf_data.len(),
+        page_count: generated.page_count,
+        document_id: generated.document.id.as_uuid().to_string(),
+    }
+}
 
+fn batch_response(response: BatchPdfResponse) -> Response {
     Response::builder()
         .status(200)
         .header("content-type", "application/json")
@@ -1112,14 +1830,118 @@ pub fn generate_batch_pdfs(req: Request, params: Params) -> Response {
         .build()
 }
 
+/// Generate multiple PDFs in a single request as a ZIP archive
+///
+/// Each PDF is stored under its filename. A document that cannot be
+/// generated does not fail the batch: the archive holds the PDFs that were
+/// generated plus an `errors.txt` naming each failed document by its
+/// 1-based position, and the `x-batch-errors` header carries the count.
+/// The request fails only when no document could be generated.
+#[utoipa::path(
+    post,
+    path = "/api/pdf/batch/zip",
+    request_body = BatchPdfRequest,
+    responses(
+        (status = 200, description = "ZIP archive of the generated PDFs, with errors.txt listing any documents that failed", content_type = "application/zip"),
+        (status = 400, description = "Invalid request"),
+        (status = 422, description = "No document in the batch could be generated"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "pdf-generation",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+)]
+pub fn generate_batch_pdfs_zip(req: Request, _params: Params) -> Response {
+    let district_str = tenant::get_tenant_id(&req);
+    let district = match District::new(district_str.clone()) {
+        Ok(d) => d,
+        Err(e) => {
+            return json::error_response(&ApiError::TenantInvalid(e.to_string()));
+        }
+    };
+
+    let body = req.body().to_vec();
+    let request: BatchPdfRequest = match serde_json::from_slice(&body) {
+        Ok(r) => r,
+        Err(e) => {
+            return json::error_response(&ApiError::BadRequest(format!("Invalid request: {}", e)));
+        }
+    };
+    if request.documents.is_empty() {
+        return json::error_response(&ApiError::BadRequest("At least one document is required".to_string()));
+    }
+
+    let service = match create_pdf_service(&district_str) {
+        Ok(s) => s,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
+        }
+    };
+
+    let limits = match signature_limits(&req) {
+        Ok(l) => l,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let mut generated_docs = Vec::new();
+    let mut errors = Vec::new();
+    for (index, dto) in request.documents.into_iter().enumerate() {
+        let position = index + 1;
+        let doc_request = match checked_document_request(dto, district.clone(), request.layout, &limits) {
+            Ok(r) => r,
+            Err(e) => {
+                errors.push(format!("document {}: {}", position, e));
+                continue;
+            }
+        };
+        let label = format!("{}, {}", doc_request.document_type.as_str(), doc_request.case_number.as_str());
+        match service.generate_document_sync(doc_request) {
+            Ok(generated) => {
+                index_generated(&req, &generated, None);
+                generated_docs.push(generated);
+            }
+            Err(e) => errors.push(format!("document {} ({}): {}", position, label, e)),
+        }
+    }
+
+    if generated_docs.is_empty() {
+        return json::error_response(
+            &ApiError::Unprocessable("No documents could be generated".to_string())
+                .with_details(serde_json::json!({ "errors": errors })),
+        );
+    }
+
+    let archive = match zip_documents(&generated_docs, &errors) {
+        Ok(a) => a,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(e.to_string()));
+        }
+    };
+
+    Response::builder()
+        .status(200)
+        .header("content-type", "application/zip")
+        .header("content-disposition", format!(r#"attachment; filename="batch-{}.zip""#, generated_docs.len()))
+        .header("x-batch-errors", errors.len().to_string())
+        .body(archive)
+        .build()
+}
+
 /// Store a judge's signature for later use in document signing
+///
+/// The signature must be base64 of a PNG or JPEG image within the district's
+/// byte size and dimension limits (`signatures.max_bytes`,
+/// `signatures.max_dimension`). A signature that fails is a 422 whose
+/// `details.check` names the check.
 #[utoipa::path(
     post,
     path = "/api/signatures",
     request_body = StoreSignatureRequest,
     responses(
         (status = 200, description = "Signature stored successfully"),
-        (status = 400, description = "Invalid request"),
+        (status = 400, description = "Invalid request or judge ID"),
+        (status = 422, description = "Signature failed a check; details.check names it", body = SignatureRejection),
         (status = 500, description = "Internal server error")
     ),
     tag = "signature-management",
@@ -1134,37 +1956,30 @@ pub fn store_signature(req: Request, params: Params) -> Response {
     let request: StoreSignatureRequest = match serde_json::from_slice(&body) {
         Ok(r) => r,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Invalid request: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(format!("Invalid request: {}", e)));
         }
     };
 
     let judge_id = match uuid::Uuid::parse_str(&request.judge_id) {
         Ok(id) => id,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Invalid judge ID: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(format!("Invalid judge ID: {}", e)));
         }
     };
 
     let service = match create_pdf_service(&district_str) {
         Ok(s) => s,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Service initialization failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
         }
     };
 
-    match service.store_signature_sync(judge_id, &request.signature_base64) {
+    let limits = match signature_limits(&req) {
+        Ok(l) => l,
+        Err(e) => return json::error_response(&e),
+    };
+
+    match service.store_signature_sync(judge_id, &request.signature_base64, &limits) {
         Ok(_) => {
             Response::builder()
                 .status(200)
@@ -1172,28 +1987,32 @@ pub fn store_signature(req: Request, params: Params) -> Response {
                 .body(r#"{"message": "Signature stored successfully"}"#.as_bytes().to_vec())
                 .build()
         }
+        Err(DocumentError::InvalidSignature(rejection)) => {
+            json::error_response(&signature_error(rejection))
+        }
         Err(e) => {
-            Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Failed to store signature: {}"}}"#, e))
-                .build()
+            json::error_response(&ApiError::Internal(format!("Failed to store signature: {}", e)))
         }
     }
 }
 
 /// Retrieve a stored judge's signature
+///
+/// With `metadata_only=true` only the signature's hash, upload time and
+/// image details are returned, without the image.
 #[utoipa::path(
     get,
     path = "/api/signatures/{judge_id}",
     responses(
         (status = 200, description = "Signature retrieved successfully"),
+        (status = 200, description = "Signature details without the image, with metadata_only", body = SignatureMetadata),
         (status = 404, description = "Signature not found"),
         (status = 500, description = "Internal server error")
     ),
     tag = "signature-management",
     params(
         ("judge_id" = String, Path, description = "Judge ID"),
+        ("metadata_only" = Option<bool>, Query, description = "Leave out the image data"),
         ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
     ),
 )]
@@ -1204,46 +2023,102 @@ pub fn get_signature(req: Request, params: Params) -> Response {
     let judge_id = match uuid::Uuid::parse_str(judge_id_str) {
         Ok(id) => id,
         Err(e) => {
-            return Response::builder()
-                .status(400)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Invalid judge ID: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::BadRequest(format!("Invalid judge ID: {}", e)));
         }
     };
 
     let service = match create_pdf_service(&district_str) {
         Ok(s) => s,
         Err(e) => {
-            return Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Service initialization failed: {}"}}"#, e))
-                .build();
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
         }
     };
 
+    let query = query_parser::parse_query_string(req.query());
+    let metadata_only = query_parser::get_bool(&query, "metadata_only").unwrap_or(false);
+
     match service.get_signature_sync(judge_id) {
         Ok(Some(signature)) => {
+            let body = if metadata_only {
+                serde_json::to_vec(&SignatureMetadata::from(signature))
+            } else {
+                serde_json::to_vec(&signature)
+            };
             Response::builder()
                 .status(200)
                 .header("content-type", "application/json")
-                .body(serde_json::to_vec(&signature).unwrap_or_default())
+                .body(body.unwrap_or_default())
                 .build()
         }
         Ok(None) => {
-            Response::builder()
-                .status(404)
-                .header("content-type", "application/json")
-                .body(r#"{"error": "Signature not found"}"#.as_bytes().to_vec())
-                .build()
+            json::error_response(&ApiError::NotFound("Signature not found".to_string()))
         }
         Err(e) => {
-            Response::builder()
-                .status(500)
-                .header("content-type", "application/json")
-                .body(format!(r#"{{"error": "Failed to retrieve signature: {}"}}"#, e))
-                .build()
+            json::error_response(&ApiError::Internal(format!("Failed to retrieve signature: {}", e)))
         }
     }
-}
\ No newline at end of file
+}
+
+/// Check that a judge's stored signature exists and is decodable
+#[utoipa::path(
+    get,
+    path = "/api/signatures/{judge_id}/verify",
+    responses(
+        (status = 200, description = "Verification result; `valid` is false for a corrupt signature", body = SignatureVerificationResponse),
+        (status = 404, description = "Signature not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "signature-management",
+    params(
+        ("judge_id" = String, Path, description = "Judge ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district", example = "SDNY")
+    ),
+)]
+pub fn verify_signature(req: Request, params: Params) -> Response {
+    let district_str = tenant::get_tenant_id(&req);
+    let judge_id_str = params.get("judge_id").unwrap_or("");
+
+    let judge_id = match uuid::Uuid::parse_str(judge_id_str) {
+        Ok(id) => id,
+        Err(e) => {
+            return json::error_response(&ApiError::BadRequest(format!("Invalid judge ID: {}", e)));
+        }
+    };
+
+    let service = match create_pdf_service(&district_str) {
+        Ok(s) => s,
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Service initialization failed: {}", e)));
+        }
+    };
+
+    let signature = match service.get_signature_sync(judge_id) {
+        Ok(Some(signature)) => signature,
+        Ok(None) => return json::error_response(&ApiError::NotFound("Signature not found".to_string())),
+        Err(e) => {
+            return json::error_response(&ApiError::Internal(format!("Failed to retrieve signature: {}", e)));
+        }
+    };
+
+    use sha2::{Digest, Sha256};
+    let hash_matches = format!("{:x}", Sha256::digest(signature.signature_base64.as_bytes())) == signature.signature_hash;
+    // Signatures stored under older or looser limits are checked against today's
+    let limits = signature_limits(&req).unwrap_or_default();
+    let checked = validate_signature_image(&signature.signature_base64, &limits);
+    let response = SignatureVerificationResponse {
+        judge_id: judge_id.to_string(),
+        valid: checked.is_ok() && hash_matches,
+        format: checked.as_ref().ok().map(|image| image.format.to_string()),
+        width: checked.as_ref().ok().map(|image| image.width),
+        height: checked.as_ref().ok().map(|image| image.height),
+        hash_matches,
+        error: checked.err().map(|rejection| rejection.message),
+        uploaded_at: signature.uploaded_at,
+    };
+
+    Response::builder()
+        .status(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&response).unwrap_or_default())
+        .build()
+}
@@ -56,13 +56,7 @@ pub fn create_rule(req: Request, _params: Params) -> ApiResult<impl IntoResponse
     let body = req.body();
     let request: CreateRuleRequest = serde_json::from_slice(body)?;
 
-    let now = Utc::now();
     let rule = Rule {
-        id: Uuid::new_v4(),
-        name: request.name,
-        description: request.description,
-        source: request.source,
-        category: request.category,
         triggers: request.triggers,
         conditions: request.conditions,
         actions: request.actions,
@@ -73,12 +67,11 @@ pub fn create_rule(req: Request, _params: Params) -> ApiResult<impl IntoResponse
         effective_date: request.effective_date,
         expiration_date: request.expiration_date,
         supersedes_rule_id: request.supersedes_rule_id,
-        created_at: now,
-        updated_at: now,
         created_by: request.created_by,
+        ..Rule::new(request.name, request.description, request.source, request.category)
     };
 
-    let repo = match RepositoryFactory::rules_repo_validated(&req) {
+    let repo = match RepositoryFactory::rules_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -124,7 +117,7 @@ pub fn list_rules(req: Request, _params: Params) -> ApiResult<impl IntoResponse>
         limit: query_parser::get_usize(&params, "limit").unwrap_or(50),
     };
 
-    let repo = match RepositoryFactory::rules_repo_validated(&req) {
+    let repo = match RepositoryFactory::rules_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -157,9 +150,9 @@ pub fn get_rules_by_category(req: Request, params: Params) -> ApiResult<impl Int
         .get("category")
         .ok_or_else(|| ApiError::BadRequest("Category required".to_string()))?;
 
-    let category: RuleCategory = serde_json::from_str(&format!("\"{}\"", category_str))?;
+    let category: RuleCategory = query_parser::parse_enum_param("category", category_str)?;
 
-    let repo = match RepositoryFactory::rules_repo_validated(&req) {
+    let repo = match RepositoryFactory::rules_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -190,9 +183,9 @@ pub fn get_rules_by_trigger(req: Request, params: Params) -> ApiResult<impl Into
         .get("trigger")
         .ok_or_else(|| ApiError::BadRequest("Trigger required".to_string()))?;
 
-    let trigger: TriggerEvent = serde_json::from_str(&format!("\"{}\"", trigger_str))?;
+    let trigger: TriggerEvent = query_parser::parse_enum_param("trigger", trigger_str)?;
 
-    let repo = match RepositoryFactory::rules_repo_validated(&req) {
+    let repo = match RepositoryFactory::rules_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -223,7 +216,7 @@ pub fn get_active_rules_for_jurisdiction(req: Request, params: Params) -> ApiRes
         .get("jurisdiction")
         .ok_or_else(|| ApiError::BadRequest("Jurisdiction required".to_string()))?;
 
-    let repo = match RepositoryFactory::rules_repo_validated(&req) {
+    let repo = match RepositoryFactory::rules_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -282,7 +275,7 @@ pub fn get_rule(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid rule ID".to_string()))?;
 
-    let repo = match RepositoryFactory::rules_repo_validated(&req) {
+    let repo = match RepositoryFactory::rules_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -321,7 +314,7 @@ pub fn update_rule(req: Request, params: Params) -> ApiResult<impl IntoResponse>
     let body = req.body();
     let request: UpdateRuleRequest = serde_json::from_slice(body)?;
 
-    let repo = match RepositoryFactory::rules_repo_validated(&req) {
+    let repo = match RepositoryFactory::rules_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
@@ -374,7 +367,7 @@ pub fn delete_rule(req: Request, params: Params) -> ApiResult<impl IntoResponse>
         .and_then(|id| Uuid::parse_str(id).ok())
         .ok_or_else(|| ApiError::BadRequest("Invalid rule ID".to_string()))?;
 
-    let repo = match RepositoryFactory::rules_repo_validated(&req) {
+    let repo = match RepositoryFactory::rules_repo(&req) {
         Ok(r) => r,
         Err(e) => return Err(e),
     };
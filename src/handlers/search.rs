@@ -0,0 +1,76 @@
+//! REST API handler for global search
+//!
+//! A single search box endpoint that queries cases, attorneys, judges and
+//! docket entries in the requesting district and returns grouped results.
+
+use crate::error::{ApiError, ApiResult};
+use crate::services::search_service::{GlobalSearchResponse, SearchLimits, SearchService, SearchType};
+use crate::utils::{access, query_parser, repository_factory::RepositoryFactory};
+use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
+use std::sync::Arc;
+
+/// Largest per-type cap a caller may request
+const MAX_PER_TYPE: usize = 50;
+
+/// Largest overall limit a caller may request
+const MAX_LIMIT: usize = 100;
+
+/// Search across cases, attorneys, judges and docket entries
+///
+/// Sealed and ex parte docket entries are left out unless the caller's
+/// `X-Access-Level` allows them.
+#[utoipa::path(
+    get,
+    path = "/api/search",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("q" = String, Query, description = "Search text"),
+        ("types" = Option<String>, Query, description = "Types to search, comma-separated or repeated: cases, attorneys, judges, docket (defaults to all)"),
+        ("per_type" = Option<usize>, Query, description = "Maximum results per type (default 5)"),
+        ("limit" = Option<usize>, Query, description = "Maximum results overall (default 20)"),
+        ("X-Access-Level" = Option<String>, Header, description = "Caller access level; sealed and ex parte docket entries only match when it allows")
+    ),
+    responses(
+        (status = 200, description = "Grouped search results with counts", body = GlobalSearchResponse),
+        (status = 400, description = "Missing query or unknown search type"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Search",
+)]
+pub fn global_search(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    let params = query_parser::parse_query_string(req.query());
+
+    let query = query_parser::get_text(&params, "q")
+        .map(|q| q.trim().to_string())
+        .filter(|q| !q.is_empty())
+        .ok_or_else(|| ApiError::BadRequest("Query parameter 'q' is required".to_string()))?;
+
+    let types = match query_parser::get_string_vec(&params, "types") {
+        types if types.is_empty() => SearchType::ALL.to_vec(),
+        types => SearchType::parse_list(&types)?,
+    };
+
+    let defaults = SearchLimits::default();
+    let limits = SearchLimits {
+        per_type: query_parser::get_usize(&params, "per_type")
+            .unwrap_or(defaults.per_type)
+            .min(MAX_PER_TYPE),
+        overall: query_parser::get_usize(&params, "limit")
+            .unwrap_or(defaults.overall)
+            .min(MAX_LIMIT),
+    };
+
+    let service = SearchService::new(
+        Arc::new(RepositoryFactory::case_repo(&req)?),
+        Arc::new(RepositoryFactory::attorney_repo(&req)?),
+        Arc::new(RepositoryFactory::judge_repo(&req)?),
+        Arc::new(RepositoryFactory::docket_repo(&req)?),
+    )
+    .with_access_level(access::get_access_level(&req)?);
+    let response = service.search(&query, &types, limits)?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&response)?)
+        .build())
+}
@@ -1,9 +1,13 @@
 //! HTTP handlers for federal sentencing management
 
 use crate::domain::sentencing::*;
+use crate::error::ApiError;
 use crate::ports::sentencing_repository::SentencingRepository;
+use crate::services::sentencing_service;
+use crate::utils::query_parser;
 use crate::utils::repository_factory::RepositoryFactory;
-use crate::utils::json_response as json;use spin_sdk::http::{Params, Request, Response};
+use crate::utils::json_response as json;
+use spin_sdk::http::{Params, Request, Response};
 
 /// Create a new sentencing record
 #[utoipa::path(
@@ -32,18 +36,16 @@ pub fn create_sentencing(req: Request, _params: Params) -> Response {
 
     let request: CreateSentencingRequest = match serde_json::from_slice(&body) {
         Ok(s) => s,
-        Err(e) => return Response::builder()
-            .status(400)
-            .body(format!("Invalid JSON: {}", e))
-            .build()
+        Err(e) => return json::error_response(&ApiError::BadRequest(format!("Invalid JSON: {}", e)))
     };
 
     // Use the constructor to create a properly initialized sentencing
-    let sentencing = Sentencing::new(
+    let mut sentencing = Sentencing::new(
         request.case_id,
         request.defendant_id,
         request.judge_id
     );
+    sentencing.primary_offense_type = request.primary_offense_type;
 
     match repo.create_sentencing(sentencing) {
         Ok(created) => Response::builder()
@@ -51,10 +53,7 @@ pub fn create_sentencing(req: Request, _params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&created).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error creating sentencing: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error creating sentencing: {}", e)))
     }
 }
 
@@ -89,14 +88,8 @@ pub fn get_sentencing(req: Request, params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencing).unwrap())
             .build(),
-        Ok(None) => Response::builder()
-            .status(404)
-            .body("Sentencing not found")
-            .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Ok(None) => json::error_response(&ApiError::NotFound("Sentencing not found".to_string())),
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -128,10 +121,7 @@ pub fn update_sentencing(req: Request, params: Params) -> Response {
 
     let mut sentencing: Sentencing = match serde_json::from_slice(&body) {
         Ok(s) => s,
-        Err(e) => return Response::builder()
-            .status(400)
-            .body(format!("Invalid JSON: {}", e))
-            .build()
+        Err(e) => return json::error_response(&ApiError::BadRequest(format!("Invalid JSON: {}", e)))
     };
 
     let id = params.get("id").unwrap_or("").to_string();
@@ -143,10 +133,7 @@ pub fn update_sentencing(req: Request, params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&updated).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -179,10 +166,7 @@ pub fn delete_sentencing(req: Request, params: Params) -> Response {
         Ok(()) => Response::builder()
             .status(204)
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -216,10 +200,7 @@ pub fn find_by_case(req: Request, params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencings).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -253,10 +234,7 @@ pub fn find_by_defendant(req: Request, params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencings).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -290,10 +268,7 @@ pub fn find_by_judge(req: Request, params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencings).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -324,10 +299,7 @@ pub fn find_pending(req: Request, _params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencings).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -357,10 +329,7 @@ pub fn calculate_guidelines(req: Request, _params: Params) -> Response {
 
     let calculation: GuidelinesCalculation = match serde_json::from_slice(&body) {
         Ok(c) => c,
-        Err(e) => return Response::builder()
-            .status(400)
-            .body(format!("Invalid JSON: {}", e))
-            .build()
+        Err(e) => return json::error_response(&ApiError::BadRequest(format!("Invalid JSON: {}", e)))
     };
 
     match repo.calculate_guidelines(calculation) {
@@ -369,10 +338,7 @@ pub fn calculate_guidelines(req: Request, _params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&range).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -381,11 +347,15 @@ pub fn calculate_guidelines(req: Request, _params: Params) -> Response {
     get,
     path = "/api/sentencing/statistics/departures",
     responses(
-        (status = 200, description = "Departure statistics", body = SentencingStatistics)
+        (status = 200, description = "Departure statistics", body = SentencingStatistics),
+        (status = 400, description = "Invalid date range", body = crate::error::ErrorResponse)
     ),
     tag = "Sentencing",
     params(
-        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("from" = Option<String>, Query, description = "Start of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to 12 months before `to`"),
+        ("to" = Option<String>, Query, description = "End of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to now"),
+        ("fiscal_year" = Option<i32>, Query, description = "Federal fiscal year (October 1 through September 30) to cover instead of `from`/`to`")
     ),
 )]
 pub fn get_departure_stats(req: Request, _params: Params) -> Response {
@@ -397,16 +367,18 @@ pub fn get_departure_stats(req: Request, _params: Params) -> Response {
 
     };
 
-    match repo.get_departure_rates() {
+    let range = match query_parser::get_date_range(&query_parser::parse_query_string(req.query())) {
+        Ok(range) => range,
+        Err(e) => return json::error_response(&e),
+    };
+
+    match repo.get_departure_rates(range) {
         Ok(stats) => Response::builder()
             .status(200)
             .header("content-type", "application/json")
             .body(serde_json::to_string(&stats).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -415,11 +387,15 @@ pub fn get_departure_stats(req: Request, _params: Params) -> Response {
     get,
     path = "/api/sentencing/statistics/variances",
     responses(
-        (status = 200, description = "Variance statistics", body = SentencingStatistics)
+        (status = 200, description = "Variance statistics", body = SentencingStatistics),
+        (status = 400, description = "Invalid date range", body = crate::error::ErrorResponse)
     ),
     tag = "Sentencing",
     params(
-        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("from" = Option<String>, Query, description = "Start of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to 12 months before `to`"),
+        ("to" = Option<String>, Query, description = "End of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to now"),
+        ("fiscal_year" = Option<i32>, Query, description = "Federal fiscal year (October 1 through September 30) to cover instead of `from`/`to`")
     ),
 )]
 pub fn get_variance_stats(req: Request, _params: Params) -> Response {
@@ -431,16 +407,51 @@ pub fn get_variance_stats(req: Request, _params: Params) -> Response {
 
     };
 
-    match repo.get_variance_rates() {
+    let range = match query_parser::get_date_range(&query_parser::parse_query_string(req.query())) {
+        Ok(range) => range,
+        Err(e) => return json::error_response(&e),
+    };
+
+    match repo.get_variance_rates(range) {
         Ok(stats) => Response::builder()
             .status(200)
             .header("content-type", "application/json")
             .body(serde_json::to_string(&stats).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
+    }
+}
+
+/// Get departure and variance statistics together with their net effect
+#[utoipa::path(
+    get,
+    path = "/api/sentencing/statistics/adjustments",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("from" = Option<String>, Query, description = "Start of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to 12 months before `to`"),
+        ("to" = Option<String>, Query, description = "End of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to now"),
+        ("fiscal_year" = Option<i32>, Query, description = "Federal fiscal year (October 1 through September 30) to cover instead of `from`/`to`")
+    ),
+    responses(
+        (status = 200, description = "Departure and variance statistics for the range", body = AdjustmentStatistics),
+        (status = 400, description = "Invalid date range", body = crate::error::ErrorResponse)
+    ),
+    tag = "Sentencing",
+)]
+pub fn get_adjustment_stats(req: Request, _params: Params) -> Response {
+    let repo = match RepositoryFactory::sentencing_repo(&req) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let range = match query_parser::get_date_range(&query_parser::parse_query_string(req.query())) {
+        Ok(range) => range,
+        Err(e) => return json::error_response(&e),
+    };
+
+    match repo.get_adjustment_stats(range) {
+        Ok(stats) => json::success_response(&stats),
+        Err(e) => json::error_response(&e),
     }
 }
 
@@ -472,10 +483,7 @@ pub fn add_departure(req: Request, params: Params) -> Response {
 
     let departure: Departure = match serde_json::from_slice(&body) {
         Ok(d) => d,
-        Err(e) => return Response::builder()
-            .status(400)
-            .body(format!("Invalid JSON: {}", e))
-            .build()
+        Err(e) => return json::error_response(&ApiError::BadRequest(format!("Invalid JSON: {}", e)))
     };
 
     match repo.add_departure(&id, departure) {
@@ -484,10 +492,7 @@ pub fn add_departure(req: Request, params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencing).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -519,10 +524,7 @@ pub fn add_variance(req: Request, params: Params) -> Response {
 
     let variance: Variance = match serde_json::from_slice(&body) {
         Ok(v) => v,
-        Err(e) => return Response::builder()
-            .status(400)
-            .body(format!("Invalid JSON: {}", e))
-            .build()
+        Err(e) => return json::error_response(&ApiError::BadRequest(format!("Invalid JSON: {}", e)))
     };
 
     match repo.add_variance(&id, variance) {
@@ -531,10 +533,7 @@ pub fn add_variance(req: Request, params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencing).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -565,10 +564,7 @@ pub fn get_substantial_assistance(req: Request, _params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencings).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -600,10 +596,7 @@ pub fn add_special_condition(req: Request, params: Params) -> Response {
 
     let condition: SpecialCondition = match serde_json::from_slice(&body) {
         Ok(c) => c,
-        Err(e) => return Response::builder()
-            .status(400)
-            .body(format!("Invalid JSON: {}", e))
-            .build()
+        Err(e) => return json::error_response(&ApiError::BadRequest(format!("Invalid JSON: {}", e)))
     };
 
     match repo.add_special_condition(&id, condition) {
@@ -612,10 +605,7 @@ pub fn add_special_condition(req: Request, params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencing).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -647,10 +637,7 @@ pub fn update_supervised_release(req: Request, params: Params) -> Response {
 
     let release: SupervisedRelease = match serde_json::from_slice(&body) {
         Ok(r) => r,
-        Err(e) => return Response::builder()
-            .status(400)
-            .body(format!("Invalid JSON: {}", e))
-            .build()
+        Err(e) => return json::error_response(&ApiError::BadRequest(format!("Invalid JSON: {}", e)))
     };
 
     match repo.update_supervised_release(&id, release) {
@@ -659,10 +646,7 @@ pub fn update_supervised_release(req: Request, params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencing).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -693,10 +677,7 @@ pub fn find_active_supervision(req: Request, _params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencings).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -728,10 +709,7 @@ pub fn add_bop_designation(req: Request, params: Params) -> Response {
 
     let designation: BOPDesignation = match serde_json::from_slice(&body) {
         Ok(d) => d,
-        Err(e) => return Response::builder()
-            .status(400)
-            .body(format!("Invalid JSON: {}", e))
-            .build()
+        Err(e) => return json::error_response(&ApiError::BadRequest(format!("Invalid JSON: {}", e)))
     };
 
     match repo.add_bop_designation(&id, designation) {
@@ -740,10 +718,7 @@ pub fn add_bop_designation(req: Request, params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencing).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -774,10 +749,7 @@ pub fn get_rdap_eligible(req: Request, _params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencings).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -787,10 +759,14 @@ pub fn get_rdap_eligible(req: Request, _params: Params) -> Response {
     path = "/api/sentencing/statistics/judge/{judge_id}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("from" = Option<String>, Query, description = "Start of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to 12 months before `to`"),
+        ("to" = Option<String>, Query, description = "End of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to now"),
+        ("fiscal_year" = Option<i32>, Query, description = "Federal fiscal year (October 1 through September 30) to cover instead of `from`/`to`"),
         ("judge_id" = String, Path, description = "Judge ID")
     ),
     responses(
-        (status = 200, description = "Judge sentencing statistics", body = SentencingStatistics)
+        (status = 200, description = "Judge sentencing statistics", body = SentencingStatistics),
+        (status = 400, description = "Invalid date range", body = crate::error::ErrorResponse)
     ),
     tag = "Sentencing",
 )]
@@ -805,16 +781,62 @@ pub fn get_judge_stats(req: Request, params: Params) -> Response {
 
     let judge_id = params.get("judge_id").unwrap_or("").to_string();
 
-    match repo.get_judge_sentencing_stats(&judge_id) {
+    let range = match query_parser::get_date_range(&query_parser::parse_query_string(req.query())) {
+        Ok(range) => range,
+        Err(e) => return json::error_response(&e),
+    };
+
+    match repo.get_judge_sentencing_stats(&judge_id, range) {
         Ok(stats) => Response::builder()
             .status(200)
             .header("content-type", "application/json")
             .body(serde_json::to_string(&stats).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
+    }
+}
+
+/// Compare a judge's guideline compliance with the district's
+///
+/// Reports how often the judge sentenced within, above and below the
+/// guidelines range and how far outside it, next to the same figures for
+/// every sentencing in the district, with the judge-less-district deltas.
+#[utoipa::path(
+    get,
+    path = "/api/sentencing/statistics/judge/{judge_id}/comparison",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("from" = Option<String>, Query, description = "Start of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to 12 months before `to`"),
+        ("to" = Option<String>, Query, description = "End of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to now"),
+        ("fiscal_year" = Option<i32>, Query, description = "Federal fiscal year (October 1 through September 30) to cover instead of `from`/`to`"),
+        ("judge_id" = String, Path, description = "Judge ID")
+    ),
+    responses(
+        (status = 200, description = "Judge and district guideline compliance with their deltas", body = JudgeSentencingComparison),
+        (status = 400, description = "Invalid date range", body = crate::error::ErrorResponse)
+    ),
+    tag = "Sentencing",
+)]
+pub fn get_judge_comparison(req: Request, params: Params) -> Response {
+    let repo = match RepositoryFactory::sentencing_repo(&req) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let judge_id = params.get("judge_id").unwrap_or("").to_string();
+
+    let range = match query_parser::get_date_range(&query_parser::parse_query_string(req.query())) {
+        Ok(range) => range,
+        Err(e) => return json::error_response(&e),
+    };
+
+    match repo.get_judge_comparison(&judge_id, range) {
+        Ok(comparison) => Response::builder()
+            .status(200)
+            .header("content-type", "application/json")
+            .body(serde_json::to_string(&comparison).unwrap())
+            .build(),
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -823,11 +845,15 @@ pub fn get_judge_stats(req: Request, params: Params) -> Response {
     get,
     path = "/api/sentencing/statistics/district",
     responses(
-        (status = 200, description = "District sentencing statistics", body = SentencingStatistics)
+        (status = 200, description = "District sentencing statistics", body = SentencingStatistics),
+        (status = 400, description = "Invalid date range", body = crate::error::ErrorResponse)
     ),
     tag = "Sentencing",
     params(
-        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("from" = Option<String>, Query, description = "Start of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to 12 months before `to`"),
+        ("to" = Option<String>, Query, description = "End of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to now"),
+        ("fiscal_year" = Option<i32>, Query, description = "Federal fiscal year (October 1 through September 30) to cover instead of `from`/`to`")
     ),
 )]
 pub fn get_district_stats(req: Request, _params: Params) -> Response {
@@ -839,16 +865,18 @@ pub fn get_district_stats(req: Request, _params: Params) -> Response {
 
     };
 
-    match repo.get_district_stats() {
+    let range = match query_parser::get_date_range(&query_parser::parse_query_string(req.query())) {
+        Ok(range) => range,
+        Err(e) => return json::error_response(&e),
+    };
+
+    match repo.get_district_stats(range) {
         Ok(stats) => Response::builder()
             .status(200)
             .header("content-type", "application/json")
             .body(serde_json::to_string(&stats).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -857,11 +885,15 @@ pub fn get_district_stats(req: Request, _params: Params) -> Response {
     get,
     path = "/api/sentencing/statistics/trial-penalty",
     responses(
-        (status = 200, description = "Trial penalty analysis", body = SentencingStatistics)
+        (status = 200, description = "Trial penalty analysis", body = SentencingStatistics),
+        (status = 400, description = "Invalid date range", body = crate::error::ErrorResponse)
     ),
     tag = "Sentencing",
     params(
-        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("from" = Option<String>, Query, description = "Start of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to 12 months before `to`"),
+        ("to" = Option<String>, Query, description = "End of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to now"),
+        ("fiscal_year" = Option<i32>, Query, description = "Federal fiscal year (October 1 through September 30) to cover instead of `from`/`to`")
     ),
 )]
 pub fn get_trial_penalty(req: Request, _params: Params) -> Response {
@@ -873,16 +905,18 @@ pub fn get_trial_penalty(req: Request, _params: Params) -> Response {
 
     };
 
-    match repo.get_trial_penalty_analysis() {
+    let range = match query_parser::get_date_range(&query_parser::parse_query_string(req.query())) {
+        Ok(range) => range,
+        Err(e) => return json::error_response(&e),
+    };
+
+    match repo.get_trial_penalty_analysis(range) {
         Ok(stats) => Response::builder()
             .status(200)
             .header("content-type", "application/json")
             .body(serde_json::to_string(&stats).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -914,10 +948,7 @@ pub fn add_prior_sentence(req: Request, params: Params) -> Response {
 
     let prior: PriorSentence = match serde_json::from_slice(&body) {
         Ok(p) => p,
-        Err(e) => return Response::builder()
-            .status(400)
-            .body(format!("Invalid JSON: {}", e))
-            .build()
+        Err(e) => return json::error_response(&ApiError::BadRequest(format!("Invalid JSON: {}", e)))
     };
 
     match repo.add_prior_sentence(&id, prior) {
@@ -926,10 +957,7 @@ pub fn add_prior_sentence(req: Request, params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencing).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -964,10 +992,7 @@ pub fn find_upcoming(req: Request, params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencings).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -998,10 +1023,7 @@ pub fn find_appeal_deadlines(req: Request, _params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencings).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -1036,10 +1058,7 @@ pub fn find_by_date_range(req: Request, _params: Params) -> Response {
     let end = crate::utils::query_parser::get_string(&parsed_params, "end").unwrap_or_default();
 
     if start.is_empty() || end.is_empty() {
-        return Response::builder()
-            .status(400)
-            .body("Missing start or end date parameters")
-            .build();
+        return json::error_response(&ApiError::BadRequest("Missing start or end date parameters".to_string()));
     }
 
     match repo.find_by_date_range(&start, &end) {
@@ -1048,10 +1067,7 @@ pub fn find_by_date_range(req: Request, _params: Params) -> Response {
             .header("content-type", "application/json")
             .body(serde_json::to_string(&sentencings).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -1061,10 +1077,14 @@ pub fn find_by_date_range(req: Request, _params: Params) -> Response {
     path = "/api/sentencing/statistics/offense/{offense_type}",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("from" = Option<String>, Query, description = "Start of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to 12 months before `to`"),
+        ("to" = Option<String>, Query, description = "End of the sentencing date range, inclusive (RFC3339 or YYYY-MM-DD); defaults to now"),
+        ("fiscal_year" = Option<i32>, Query, description = "Federal fiscal year (October 1 through September 30) to cover instead of `from`/`to`"),
         ("offense_type" = String, Path, description = "Offense type (e.g., '2B1' for fraud)")
     ),
     responses(
-        (status = 200, description = "Offense type statistics", body = SentencingStatistics)
+        (status = 200, description = "Offense type statistics", body = SentencingStatistics),
+        (status = 400, description = "Invalid date range", body = crate::error::ErrorResponse)
     ),
     tag = "Sentencing",
 )]
@@ -1079,29 +1099,36 @@ pub fn get_offense_type_stats(req: Request, params: Params) -> Response {
 
     let offense_type = params.get("offense_type").unwrap_or("").to_string();
 
-    match repo.get_offense_type_stats(&offense_type) {
+    let range = match query_parser::get_date_range(&query_parser::parse_query_string(req.query())) {
+        Ok(range) => range,
+        Err(e) => return json::error_response(&e),
+    };
+
+    match repo.get_offense_type_stats(&offense_type, range) {
         Ok(stats) => Response::builder()
             .status(200)
             .header("content-type", "application/json")
             .body(serde_json::to_string(&stats).unwrap())
             .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
 /// Calculate criminal history points
+///
+/// Returns the recalculated total with each prior sentence's contribution and
+/// the guideline subsection applied. Pass `summary_only=true` for the bare total.
 #[utoipa::path(
     get,
     path = "/api/sentencing/{id}/criminal-history-points",
     params(
         ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
-        ("id" = String, Path, description = "Sentencing ID")
+        ("id" = String, Path, description = "Sentencing ID"),
+        ("summary_only" = Option<bool>, Query, description = "Return only the total points")
     ),
     responses(
-        (status = 200, description = "Criminal history points", body = i32)
+        (status = 200, description = "Itemized criminal history points, or the total when summary_only=true", body = CriminalHistoryPointsBreakdown),
+        (status = 404, description = "Sentencing not found")
     ),
     tag = "Sentencing",
 )]
@@ -1115,19 +1142,16 @@ pub fn calculate_criminal_history_points(req: Request, params: Params) -> Respon
     };
 
     let id = params.get("id").unwrap_or("").to_string();
+    let query = query_parser::parse_query_string(req.query());
+    let summary_only = query_parser::get_bool(&query, "summary_only").unwrap_or(false);
 
     match repo.calculate_criminal_history_points(&id) {
-        Ok(points) => Response::builder()
-            .status(200)
-            .header("content-type", "application/json")
-            .body(serde_json::to_string(&points).unwrap())
-            .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Ok(breakdown) if summary_only => json::success_response(&breakdown.total_points),
+        Ok(breakdown) => json::success_response(&breakdown),
+        Err(e) => json::error_response(&e),
     }
 }
+
 /// Calculate final offense level for sentencing
 #[utoipa::path(
     post,
@@ -1161,20 +1185,11 @@ pub fn calculate_offense_level(req: Request, params: Params) -> Response {
                     .header("content-type", "application/json")
                     .body(serde_json::to_string(&updated).unwrap())
                     .build(),
-                Err(e) => Response::builder()
-                    .status(500)
-                    .body(format!("Error updating sentencing: {}", e))
-                    .build()
+                Err(e) => json::error_response(&ApiError::Internal(format!("Error updating sentencing: {}", e)))
             }
         },
-        Ok(None) => Response::builder()
-            .status(404)
-            .body("Sentencing not found")
-            .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Ok(None) => json::error_response(&ApiError::NotFound("Sentencing not found".to_string())),
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
     }
 }
 
@@ -1211,20 +1226,41 @@ pub fn lookup_guidelines_range(req: Request, params: Params) -> Response {
                     .header("content-type", "application/json")
                     .body(serde_json::to_string(&updated).unwrap())
                     .build(),
-                Err(e) => Response::builder()
-                    .status(500)
-                    .body(format!("Error updating sentencing: {}", e))
-                    .build()
+                Err(e) => json::error_response(&ApiError::Internal(format!("Error updating sentencing: {}", e)))
             }
         },
-        Ok(None) => Response::builder()
-            .status(404)
-            .body("Sentencing not found")
-            .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Ok(None) => json::error_response(&ApiError::NotFound("Sentencing not found".to_string())),
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
+    }
+}
+
+/// Look up many sentencing table cells in one request
+///
+/// Returns the bare table range for each (offense level, criminal history
+/// category) pair, in request order. Statutory minimums and maximums are
+/// record-specific and are not applied.
+#[utoipa::path(
+    post,
+    path = "/api/sentencing/lookup-guidelines-range/batch",
+    request_body = BatchGuidelinesRangeRequest,
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY")
+    ),
+    responses(
+        (status = 200, description = "Table ranges in request order", body = BatchGuidelinesRangeResponse),
+        (status = 400, description = "Invalid JSON, offense level off the table, or batch too large")
+    ),
+    tag = "Sentencing",
+)]
+pub fn lookup_guidelines_range_batch(req: Request, _params: Params) -> Response {
+    let request: BatchGuidelinesRangeRequest = match json::parse_body(req.body()) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    match sentencing_service::lookup_batch(&request.lookups) {
+        Ok(ranges) => json::success_response(&BatchGuidelinesRangeResponse { ranges }),
+        Err(e) => json::error_response(&e),
     }
 }
 
@@ -1237,7 +1273,8 @@ pub fn lookup_guidelines_range(req: Request, params: Params) -> Response {
         ("id" = String, Path, description = "Sentencing ID")
     ),
     responses(
-        (status = 200, description = "Safety valve eligibility", body = bool)
+        (status = 200, description = "Safety valve eligibility with the result of each criterion", body = SafetyValveEligibility),
+        (status = 404, description = "Sentencing not found", body = crate::error::ErrorResponse)
     ),
     tag = "Sentencing",
 )]
@@ -1254,20 +1291,38 @@ pub fn check_safety_valve_eligible(req: Request, params: Params) -> Response {
 
     match repo.get_sentencing(&id) {
         Ok(Some(sentencing)) => {
-            let eligible = sentencing.is_safety_valve_eligible();
-            Response::builder()
-                .status(200)
-                .header("content-type", "application/json")
-                .body(serde_json::to_string(&eligible).unwrap())
-                .build()
+            json::success_response(&sentencing.safety_valve_eligibility())
         },
-        Ok(None) => Response::builder()
-            .status(404)
-            .body("Sentencing not found")
-            .build(),
-        Err(e) => Response::builder()
-            .status(500)
-            .body(format!("Error: {}", e))
-            .build()
+        Ok(None) => json::error_response(&ApiError::NotFound("Sentencing not found".to_string())),
+        Err(e) => json::error_response(&ApiError::Internal(format!("Error: {}", e)))
+    }
+}
+
+/// Get the step-by-step derivation of a sentencing's guidelines range
+#[utoipa::path(
+    get,
+    path = "/api/sentencing/{id}/calculation-audit",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("id" = String, Path, description = "Sentencing ID")
+    ),
+    responses(
+        (status = 200, description = "Ordered calculation steps from base offense level to final range", body = GuidelinesCalculationAudit),
+        (status = 404, description = "Sentencing not found")
+    ),
+    tag = "Sentencing",
+)]
+pub fn get_calculation_audit(req: Request, params: Params) -> Response {
+    let repo = match RepositoryFactory::sentencing_repo(&req) {
+        Ok(r) => r,
+        Err(e) => return json::error_response(&e),
+    };
+
+    let id = params.get("id").unwrap_or("").to_string();
+
+    match repo.get_sentencing(&id) {
+        Ok(Some(sentencing)) => json::success_response(&sentencing.calculation_audit()),
+        Ok(None) => json::error_response(&ApiError::NotFound("Sentencing not found".to_string())),
+        Err(e) => json::error_response(&e),
     }
 }
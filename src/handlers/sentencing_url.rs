@@ -124,6 +124,13 @@ pub fn lookup_guidelines_range(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn lookup_guidelines_range_batch(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::sentencing::lookup_guidelines_range_batch(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn check_safety_valve_eligible(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::sentencing::check_safety_valve_eligible(req, params),
@@ -131,6 +138,13 @@ pub fn check_safety_valve_eligible(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_calculation_audit(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::sentencing::get_calculation_audit(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 // Departures & Variances (4 endpoints)
 
 pub fn get_departure_stats(req: Request, params: Params) -> Response {
@@ -147,6 +161,13 @@ pub fn get_variance_stats(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_adjustment_stats(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::sentencing::get_adjustment_stats(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn add_departure(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::sentencing::add_departure(req, params),
@@ -214,7 +235,7 @@ pub fn get_rdap_eligible(req: Request, params: Params) -> Response {
     }
 }
 
-// Statistics & Reporting (5 endpoints)
+// Statistics & Reporting (6 endpoints)
 
 pub fn get_judge_stats(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
@@ -223,6 +244,13 @@ pub fn get_judge_stats(req: Request, params: Params) -> Response {
     }
 }
 
+pub fn get_judge_comparison(req: Request, params: Params) -> Response {
+    match add_district_header(req, &params) {
+        Ok(req) => crate::handlers::sentencing::get_judge_comparison(req, params),
+        Err(e) => json::error_response(&e),
+    }
+}
+
 pub fn get_district_stats(req: Request, params: Params) -> Response {
     match add_district_header(req, &params) {
         Ok(req) => crate::handlers::sentencing::get_district_stats(req, params),
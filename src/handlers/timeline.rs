@@ -0,0 +1,88 @@
+//! REST API handler for the case timeline
+//!
+//! One chronological view of a case: docket entries, calendar events,
+//! deadlines, motions and rulings, judge assignments and recusals, orders
+//! and opinions, read from each module in the requesting district.
+
+use crate::error::{ApiError, ApiResult};
+use crate::ports::case_repository::CaseRepository;
+use crate::services::timeline_service::{
+    CalendarAdapter, CaseAdapter, CaseTimeline, DeadlineAdapter, DocketAdapter, JudgeAdapter, OpinionAdapter,
+    OrderAdapter, TimelineQuery, TimelineService, TimelineSource,
+};
+use crate::utils::{access, query_parser, repository_factory::RepositoryFactory};
+use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Largest page a caller may request
+const MAX_LIMIT: usize = 500;
+
+/// Get a case's timeline
+///
+/// A source that can't be read is left out and named in `failed_sources`,
+/// with `partial` set, rather than failing the request. Sealed and ex parte
+/// records are left out unless the caller's `X-Access-Level` allows them.
+#[utoipa::path(
+    get,
+    path = "/api/cases/{id}/timeline",
+    params(
+        ("id" = String, Path, description = "Case ID"),
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("from" = Option<String>, Query, description = "Earliest event time (RFC3339 or YYYY-MM-DD)"),
+        ("to" = Option<String>, Query, description = "Latest event time (RFC3339 or YYYY-MM-DD)"),
+        ("sources" = Option<String>, Query, description = "Sources, comma-separated or repeated: case, docket, calendar, deadlines, judges, orders, opinions (defaults to all)"),
+        ("offset" = Option<usize>, Query, description = "Pagination offset"),
+        ("limit" = Option<usize>, Query, description = "Pagination limit (default 50)"),
+        ("X-Access-Level" = Option<String>, Header, description = "Caller access level; sealed and ex parte records only appear when it allows")
+    ),
+    responses(
+        (status = 200, description = "Case events in time order", body = CaseTimeline),
+        (status = 400, description = "Invalid case ID, date or source"),
+        (status = 404, description = "Case not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "cases",
+)]
+pub fn get_case_timeline(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    let id = params
+        .get("id")
+        .ok_or_else(|| ApiError::Internal("Missing path parameter 'id'".to_string()))?;
+    let id = Uuid::parse_str(id)?;
+
+    let query_params = query_parser::parse_query_string(req.query());
+    let (from, to) = query_parser::get_date_bounds(&query_params)?;
+    let defaults = TimelineQuery::default();
+    let query = TimelineQuery {
+        from,
+        to,
+        sources: match query_parser::get_string_vec(&query_params, "sources") {
+            sources if sources.is_empty() => defaults.sources,
+            sources => TimelineSource::parse_list(&sources)?,
+        },
+        offset: query_parser::get_usize(&query_params, "offset").unwrap_or(defaults.offset),
+        limit: query_parser::get_usize(&query_params, "limit").unwrap_or(defaults.limit).min(MAX_LIMIT),
+    };
+
+    let case = RepositoryFactory::case_repo(&req)?
+        .find_by_id(id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Case with id {} not found", id)))?;
+
+    let docket = Arc::new(RepositoryFactory::docket_repo(&req)?);
+    let documents = Arc::new(RepositoryFactory::document_repo(&req)?);
+    let service = TimelineService::new()
+        .with_adapter(CaseAdapter)
+        .with_adapter(DocketAdapter::new(docket.clone()))
+        .with_adapter(CalendarAdapter::new(docket))
+        .with_adapter(DeadlineAdapter::new(Arc::new(RepositoryFactory::deadline_repo(&req)?)))
+        .with_adapter(JudgeAdapter::new(Arc::new(RepositoryFactory::judge_repo(&req)?)))
+        .with_adapter(OrderAdapter::new(documents.clone()))
+        .with_adapter(OpinionAdapter::new(documents))
+        .with_access_level(access::get_access_level(&req)?);
+    let timeline = service.timeline(&case, &query);
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&timeline)?)
+        .build())
+}
@@ -1,6 +1,7 @@
 use crate::domain;
 use crate::error::{ApiError, ApiResult, validation};
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use spin_sdk::http::{
     conversions::IntoBody, IntoResponse, Params, Request, Response, ResponseBuilder,
@@ -21,6 +22,9 @@ pub struct PaginationParams {
     /// Filter by completion status
     #[serde(skip_serializing_if = "Option::is_none")]
     pub completed: Option<bool>,
+    /// Sort order; `due_date` lists the earliest due first and undated items last
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
 }
 
 fn default_page() -> usize { 1 }
@@ -56,7 +60,8 @@ pub struct PaginatedResponse<T> {
     params(
         ("page" = Option<usize>, Query, description = "Page number (1-indexed)", minimum = 1, example = 1),
         ("limit" = Option<usize>, Query, description = "Number of items per page", minimum = 1, maximum = 100, example = 20),
-        ("completed" = Option<bool>, Query, description = "Filter by completion status", example = false)
+        ("completed" = Option<bool>, Query, description = "Filter by completion status", example = false),
+        ("sort" = Option<String>, Query, description = "Sort order; `due_date` lists the earliest due first and undated items last", example = "due_date")
     ),
     description = "Retrieve all active ToDo items with pagination and filtering",
     responses(
@@ -88,6 +93,10 @@ pub(crate) fn get_all(req: Request, _p: Params) -> ApiResult<impl IntoResponse>
         todos.retain(|t| t.is_completed == completed);
     }
 
+    if params.sort.is_some() {
+        domain::ToDo::sort_by_due_date(&mut todos);
+    }
+
     // Calculate pagination
     let total = todos.len();
     let total_pages = (total + params.limit - 1) / params.limit;
@@ -123,6 +132,7 @@ fn parse_pagination_params(query: &str) -> ApiResult<PaginationParams> {
         page: 1,
         limit: 20,
         completed: None,
+        sort: None,
     };
 
     if query.is_empty() {
@@ -154,6 +164,12 @@ fn parse_pagination_params(query: &str) -> ApiResult<PaginationParams> {
                         .map_err(|_| ApiError::BadRequest("Invalid completed value (use true/false)".to_string()))?);
                 }
             }
+            "sort" => {
+                if parts[1] != "due_date" {
+                    return Err(ApiError::BadRequest("Invalid sort value (use due_date)".to_string()));
+                }
+                params.sort = Some(parts[1].to_string());
+            }
             _ => {} // Ignore unknown parameters
         }
     }
@@ -161,6 +177,33 @@ fn parse_pagination_params(query: &str) -> ApiResult<PaginationParams> {
     Ok(params)
 }
 
+/// Get overdue ToDo items
+///
+/// Returns active, incomplete ToDo items whose due date has passed, earliest
+/// due first. Items without a due date are never overdue.
+#[utoipa::path(
+    get,
+    path = "/api/todos/overdue",
+    tags = ["todos"],
+    description = "Retrieve incomplete ToDo items past their due date",
+    responses(
+        (status = 200, description = "Overdue ToDo items, earliest due first", body = Vec<ToDoModel>),
+        (status = 500, description = "Internal Server Error", body = crate::error::ErrorResponse)
+    )
+)]
+pub(crate) fn get_overdue(_req: Request, _p: Params) -> ApiResult<impl IntoResponse> {
+    let now = Utc::now();
+    let mut todos = domain::ToDo::get_all()?;
+    todos.retain(|t| t.is_overdue(now));
+    domain::ToDo::sort_by_due_date(&mut todos);
+
+    let items: Vec<ToDoModel> = todos.into_iter().map(ToDoModel::from).collect();
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&items)?)
+        .build())
+}
+
 /// Get a single ToDo item by ID
 ///
 /// Retrieves a specific ToDo item using its UUID identifier.
@@ -219,12 +262,9 @@ pub(crate) fn delete_by_id(_req: Request, p: Params) -> ApiResult<impl IntoRespo
 
     let id = Uuid::parse_str(id)?;
 
-    let mut todo = domain::ToDo::get_by_id(id)?
+    domain::ToDo::delete_by_id(id)?
         .ok_or_else(|| ApiError::NotFound(format!("ToDo item with id {} not found", id)))?;
 
-    todo.is_deleted = true;
-    todo.save()?;
-
     Ok(Response::new(204, ()))
 }
 
@@ -253,15 +293,102 @@ pub(crate) fn toggle_by_id(_req: Request, p: Params) -> ApiResult<impl IntoRespo
 
     let id = Uuid::parse_str(id)?;
 
-    let mut todo = domain::ToDo::get_by_id(id)?
+    domain::ToDo::toggle_by_id(id)?
         .ok_or_else(|| ApiError::NotFound(format!("ToDo item with id {} not found", id)))?;
 
-    todo.is_completed = !todo.is_completed;
-    todo.save()?;
-
     Ok(Response::new(204, ()))
 }
 
+/// Toggle the completion status of several ToDo items
+///
+/// Toggles each listed item as `POST /api/todos/{id}/toggle` would and
+/// reports what happened to each ID. Missing IDs don't fail the request.
+#[utoipa::path(
+    post,
+    path = "/api/todos/bulk/toggle",
+    tags = ["todos"],
+    description = "Toggle the completion status of several ToDo items",
+    request_body(
+        content = BulkToDoRequest,
+        description = "IDs of the ToDo items to toggle",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Result for each ID", body = BulkToDoResponse),
+        (status = 400, description = "Bad Request - Invalid request body or too many IDs", body = crate::error::ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = crate::error::ErrorResponse)
+    )
+)]
+pub(crate) fn bulk_toggle(req: Request, _p: Params) -> ApiResult<impl IntoResponse> {
+    let model: BulkToDoRequest = serde_json::from_slice(req.body())?;
+    let response = apply_bulk(&model.ids, BulkToDoStatus::Toggled, domain::ToDo::toggle_by_id)?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&response)?)
+        .build())
+}
+
+/// Delete several ToDo items
+///
+/// Soft deletes each listed item as `DELETE /api/todos/{id}` would and
+/// reports what happened to each ID. Missing IDs don't fail the request.
+#[utoipa::path(
+    post,
+    path = "/api/todos/bulk/delete",
+    tags = ["todos"],
+    description = "Delete several ToDo items",
+    request_body(
+        content = BulkToDoRequest,
+        description = "IDs of the ToDo items to delete",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "Result for each ID", body = BulkToDoResponse),
+        (status = 400, description = "Bad Request - Invalid request body or too many IDs", body = crate::error::ErrorResponse),
+        (status = 500, description = "Internal Server Error", body = crate::error::ErrorResponse)
+    )
+)]
+pub(crate) fn bulk_delete(req: Request, _p: Params) -> ApiResult<impl IntoResponse> {
+    let model: BulkToDoRequest = serde_json::from_slice(req.body())?;
+    let response = apply_bulk(&model.ids, BulkToDoStatus::Deleted, domain::ToDo::delete_by_id)?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&response)?)
+        .build())
+}
+
+/// Most IDs accepted by one bulk request
+const MAX_BULK_IDS: usize = 100;
+
+/// Apply `op` to each distinct ID in order, recording `done` for the IDs it
+/// found and `not_found` for the rest
+fn apply_bulk(
+    ids: &[Uuid],
+    done: BulkToDoStatus,
+    op: impl Fn(Uuid) -> anyhow::Result<Option<domain::ToDo>>,
+) -> ApiResult<BulkToDoResponse> {
+    if ids.is_empty() {
+        return Err(ApiError::BadRequest("At least one id is required".to_string()));
+    }
+    if ids.len() > MAX_BULK_IDS {
+        return Err(ApiError::BadRequest(format!("At most {} ids can be given at once", MAX_BULK_IDS)));
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    let mut results = Vec::new();
+    for &id in ids.iter().filter(|id| seen.insert(**id)) {
+        results.push(match op(id)? {
+            Some(todo) => BulkToDoResult { id, status: done, is_completed: Some(todo.is_completed) },
+            None => BulkToDoResult { id, status: BulkToDoStatus::NotFound, is_completed: None },
+        });
+    }
+
+    let not_found = results.iter().filter(|r| r.status == BulkToDoStatus::NotFound).count();
+    Ok(BulkToDoResponse { succeeded: results.len() - not_found, not_found, results })
+}
+
 /// Create a new ToDo item
 ///
 /// Creates a new ToDo item with the provided contents. The item will be
@@ -292,7 +419,8 @@ pub(crate) fn create_todo(req: Request, _p: Params) -> ApiResult<impl IntoRespon
     // Validate the input
     validation::validate_todo_content(&model.contents)?;
 
-    let new_todo = domain::ToDo::new(model.contents);
+    let mut new_todo = domain::ToDo::new(model.contents);
+    new_todo.due_date = model.due_date;
     new_todo.save()?;
 
     Ok(ResponseBuilder::new(201)
@@ -304,17 +432,62 @@ pub(crate) fn create_todo(req: Request, _p: Params) -> ApiResult<impl IntoRespon
 
 /// Request model for creating a new ToDo item
 #[derive(Deserialize, ToSchema)]
-#[schema(example = json!({ "contents": "Buy groceries" }))]
+#[schema(example = json!({ "contents": "Buy groceries", "dueDate": "2025-06-01T17:00:00Z" }))]
+#[serde(rename_all = "camelCase")]
 pub struct CreateToDoModel {
     /// The content/description of the ToDo item
     pub contents: String,
+    /// When the ToDo item is due, if it has a deadline
+    #[serde(default)]
+    pub due_date: Option<DateTime<Utc>>,
+}
+
+/// Request model for bulk ToDo operations
+#[derive(Deserialize, ToSchema)]
+#[schema(example = json!({ "ids": ["059c7906-ce72-4433-94df-441beb14d96a", "7d0f3c1e-2b4a-4f6e-9a8b-1c2d3e4f5a6b"] }))]
+pub struct BulkToDoRequest {
+    /// IDs of the ToDo items; repeated IDs are processed once
+    pub ids: Vec<Uuid>,
+}
+
+/// What a bulk operation did to one ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkToDoStatus {
+    Toggled,
+    Deleted,
+    NotFound,
+}
+
+/// Result of a bulk operation for one ID
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkToDoResult {
+    /// ToDo identifier
+    id: Uuid,
+    /// What happened to the item
+    status: BulkToDoStatus,
+    /// Completion status after the operation, if the item was found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_completed: Option<bool>,
 }
 
+/// Response model for bulk ToDo operations
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkToDoResponse {
+    /// One result per distinct ID, in request order
+    results: Vec<BulkToDoResult>,
+    /// Number of items updated
+    succeeded: usize,
+    /// Number of IDs with no ToDo item
+    not_found: usize,
+}
 
 /// Response model for a ToDo item
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-#[schema(example = json!({ "id": "059c7906-ce72-4433-94df-441beb14d96a", "contents": "Buy Milk", "isCompleted": false}))]
+#[schema(example = json!({ "id": "059c7906-ce72-4433-94df-441beb14d96a", "contents": "Buy Milk", "isCompleted": false, "dueDate": null}))]
 pub struct ToDoModel {
     /// Unique identifier of the ToDo item
     id: Uuid,
@@ -322,6 +495,8 @@ pub struct ToDoModel {
     contents: String,
     /// Indicates whether the ToDo item has been completed
     is_completed: bool,
+    /// When the ToDo item is due, if it has a deadline
+    due_date: Option<DateTime<Utc>>,
 }
 
 impl IntoBody for ToDoModel {
@@ -336,6 +511,7 @@ impl From<domain::ToDo> for ToDoModel {
             id: value.id,
             contents: value.contents.clone(),
             is_completed: value.is_completed,
+            due_date: value.due_date,
         }
     }
 }
@@ -0,0 +1,207 @@
+//! REST API handlers for webhook subscriptions
+//!
+//! Lets external agencies register an HTTPS endpoint for court events,
+//! flush the outbound notification queue, and review recent delivery
+//! attempts for a subscription.
+
+use crate::adapters::spin_http_webhook_delivery::SpinHttpWebhookDelivery;
+use crate::domain::webhook::{DeliveryAttempt, WebhookEventType, WebhookSubscription, WebhookSubscriptionSummary};
+use crate::error::{ApiError, ApiResult};
+use crate::ports::webhook_repository::WebhookRepository;
+use crate::services::webhook_service::{self, DeliveryReport};
+use crate::utils::{access, query_parser, repository_factory::RepositoryFactory};
+use chrono::Utc;
+use serde::Deserialize;
+use spin_sdk::http::{IntoResponse, Params, Request, ResponseBuilder};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Delivery attempts returned when no limit is given
+const DEFAULT_DELIVERY_LIMIT: usize = 20;
+
+/// Request to register a webhook
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateWebhookRequest {
+    /// HTTPS endpoint that receives notifications
+    pub target_url: String,
+    /// Events to receive, e.g. `case.created`, `case.status_changed`, `deadline.urgent`, `order.issued`
+    pub event_types: Vec<WebhookEventType>,
+    /// Shared secret used to sign deliveries (at least 16 characters)
+    pub secret: String,
+    pub description: Option<String>,
+}
+
+fn subscription_id(params: &Params) -> ApiResult<Uuid> {
+    let id = params.get("id")
+        .ok_or_else(|| ApiError::BadRequest("Webhook ID required".to_string()))?;
+    Uuid::parse_str(id).map_err(|_| ApiError::BadRequest(format!("Invalid webhook ID: {}", id)))
+}
+
+/// Register a webhook subscription
+#[utoipa::path(
+    post,
+    path = "/api/webhooks",
+    request_body = CreateWebhookRequest,
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = String, Header, description = "Administrator token")
+    ),
+    responses(
+        (status = 201, description = "Webhook registered; the secret is not echoed back", body = WebhookSubscriptionSummary),
+        (status = 400, description = "Invalid target URL, event types or secret"),
+        (status = 403, description = "Missing or invalid administrator token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Webhooks",
+)]
+pub fn create_webhook(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
+    let request: CreateWebhookRequest = serde_json::from_slice(req.body())
+        .map_err(|e| ApiError::BadRequest(format!("Invalid webhook request: {}", e)))?;
+
+    let subscription = WebhookSubscription::new(
+        request.target_url.trim(),
+        request.event_types,
+        request.secret,
+        request.description,
+    )
+    .map_err(ApiError::BadRequest)?;
+
+    let repo = RepositoryFactory::webhook_repo(&req)?;
+    repo.save_subscription(&subscription)?;
+
+    Ok(ResponseBuilder::new(201)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&subscription.summary())?)
+        .build())
+}
+
+/// List webhook subscriptions
+#[utoipa::path(
+    get,
+    path = "/api/webhooks",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = String, Header, description = "Administrator token")
+    ),
+    responses(
+        (status = 200, description = "Registered webhooks", body = Vec<WebhookSubscriptionSummary>),
+        (status = 403, description = "Missing or invalid administrator token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Webhooks",
+)]
+pub fn list_webhooks(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
+    let repo = RepositoryFactory::webhook_repo(&req)?;
+    let summaries: Vec<WebhookSubscriptionSummary> = repo.list_subscriptions()?
+        .iter()
+        .map(WebhookSubscription::summary)
+        .collect();
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&summaries)?)
+        .build())
+}
+
+/// Delete a webhook subscription
+///
+/// Notifications still queued for the subscription are dropped.
+#[utoipa::path(
+    delete,
+    path = "/api/webhooks/{id}",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = String, Header, description = "Administrator token"),
+        ("id" = String, Path, description = "Webhook ID")
+    ),
+    responses(
+        (status = 204, description = "Webhook deleted"),
+        (status = 403, description = "Missing or invalid administrator token"),
+        (status = 404, description = "Webhook not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Webhooks",
+)]
+pub fn delete_webhook(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
+    let id = subscription_id(&params)?;
+    let repo = RepositoryFactory::webhook_repo(&req)?;
+
+    if !repo.delete_subscription(id)? {
+        return Err(ApiError::NotFound(format!("Webhook {} not found", id)));
+    }
+
+    Ok(ResponseBuilder::new(204).build())
+}
+
+/// Recent delivery attempts for a webhook
+#[utoipa::path(
+    get,
+    path = "/api/webhooks/{id}/deliveries",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = String, Header, description = "Administrator token"),
+        ("id" = String, Path, description = "Webhook ID"),
+        ("limit" = Option<usize>, Query, description = "Maximum attempts to return (default 20)")
+    ),
+    responses(
+        (status = 200, description = "Delivery attempts, newest first, with response codes", body = Vec<DeliveryAttempt>),
+        (status = 403, description = "Missing or invalid administrator token"),
+        (status = 404, description = "Webhook not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Webhooks",
+)]
+pub fn list_deliveries(req: Request, params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
+    let id = subscription_id(&params)?;
+    let query = query_parser::parse_query_string(req.query());
+    let limit = query_parser::get_usize(&query, "limit").unwrap_or(DEFAULT_DELIVERY_LIMIT);
+
+    let repo = RepositoryFactory::webhook_repo(&req)?;
+    if repo.find_subscription(id)?.is_none() {
+        return Err(ApiError::NotFound(format!("Webhook {} not found", id)));
+    }
+    let attempts = repo.recent_attempts(id, limit)?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&attempts)?)
+        .build())
+}
+
+/// Deliver queued webhook notifications
+///
+/// Sends every queued notification that is due, signed with its
+/// subscription's secret. Intended to be called on a schedule.
+#[utoipa::path(
+    post,
+    path = "/api/webhooks/deliver-pending",
+    params(
+        ("X-Court-District" = String, Header, description = "Federal court district (e.g., SDNY, EDNY, NDCA, CDCA)", example = "SDNY"),
+        ("X-Admin-Token" = String, Header, description = "Administrator token")
+    ),
+    responses(
+        (status = 200, description = "Counts of delivered, retrying and abandoned notifications", body = DeliveryReport),
+        (status = 403, description = "Missing or invalid administrator token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Webhooks",
+)]
+pub fn deliver_pending(req: Request, _params: Params) -> ApiResult<impl IntoResponse> {
+    access::require_admin(&req)?;
+
+    let repo = RepositoryFactory::webhook_repo(&req)?;
+    let report = webhook_service::deliver_pending(&repo, &SpinHttpWebhookDelivery, Utc::now())?;
+
+    Ok(ResponseBuilder::new(200)
+        .header("content-type", "application/json")
+        .body(serde_json::to_vec(&report)?)
+        .build())
+}
@@ -2,6 +2,9 @@
 
 /// Helper macro to get tenant-specific repository for any repository type
 ///
+/// Returns early with the 400 from `RepositoryFactory::tenant_store_name`
+/// when the request names no registered district.
+///
 /// Usage:
 /// ```
 /// let repo = get_tenant_repo!(SpinKvDocumentRepository, &req);
@@ -10,8 +13,7 @@
 #[macro_export]
 macro_rules! get_tenant_repo {
     ($repo_type:ty, $req:expr) => {{
-        let tenant_id = $crate::utils::tenant::get_tenant_id($req);
-        let store_name = $crate::utils::tenant::get_store_name(&tenant_id);
+        let store_name = $crate::utils::repository_factory::RepositoryFactory::tenant_store_name($req)?;
         <$repo_type>::with_store(store_name)
     }};
 }
@@ -51,4 +53,17 @@ macro_rules! check_sealed_access {
             }
         }
     }};
-}
\ No newline at end of file
+}
+
+/// Write a line to stderr prefixed with the id of the request being handled
+///
+/// Usage:
+/// ```
+/// request_log!("rate limit: failing open for {}: {}", tenant_id, e);
+/// ```
+#[macro_export]
+macro_rules! request_log {
+    ($($arg:tt)*) => {
+        eprintln!("[{}] {}", $crate::utils::request_id::current(), format_args!($($arg)*))
+    };
+}
@@ -5,9 +5,11 @@
 use crate::domain::attorney::{
     Attorney, AttorneyStatus, Party, PartyStatus, AttorneyRepresentation,
     ConflictCheck, ServiceRecord, AttorneyMetrics, BarAdmission, FederalAdmission,
-    ProHacViceAdmission, CJAAppointment, ECFRegistration, DisciplinaryAction
+    ProHacViceAdmission, CJAAppointment, CJAVoucherSummary, ECFRegistration, DisciplinaryAction, ExpiringProHacVice
 };
+use crate::utils::name_match::NameMatch;
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 
 /// Repository for attorney and party management
 pub trait AttorneyRepository {
@@ -18,6 +20,7 @@ pub trait AttorneyRepository {
     fn find_attorneys_by_firm(&self, firm_name: &str) -> Result<Vec<Attorney>>;
     fn find_attorneys_by_status(&self, status: AttorneyStatus) -> Result<Vec<Attorney>>;
     fn find_all_attorneys(&self) -> Result<Vec<Attorney>>;
+    fn count(&self) -> Result<usize>;
     fn update_attorney(&self, attorney: Attorney) -> Result<Attorney>;
     fn delete_attorney(&self, id: &str) -> Result<()>;
     fn search_attorneys(&self, query: &str) -> Result<Vec<Attorney>>;
@@ -37,6 +40,8 @@ pub trait AttorneyRepository {
     fn update_pro_hac_vice_status(&self, attorney_id: &str, case_id: &str, status: String) -> Result<()>;
     fn find_active_pro_hac_vice(&self) -> Result<Vec<ProHacViceAdmission>>;
     fn find_pro_hac_vice_by_case(&self, case_id: &str) -> Result<Vec<ProHacViceAdmission>>;
+    /// Active admissions expiring by `before`, soonest first
+    fn find_pro_hac_vice_expiring(&self, before: DateTime<Utc>) -> Result<Vec<ExpiringProHacVice>>;
 
     // CJA Panel Management
     fn add_to_cja_panel(&self, attorney_id: &str, district: &str) -> Result<()>;
@@ -44,7 +49,12 @@ pub trait AttorneyRepository {
     fn find_cja_panel_attorneys(&self, district: &str) -> Result<Vec<Attorney>>;
     fn add_cja_appointment(&self, attorney_id: &str, appointment: CJAAppointment) -> Result<()>;
     fn find_cja_appointments_by_attorney(&self, attorney_id: &str) -> Result<Vec<CJAAppointment>>;
-    fn find_pending_cja_vouchers(&self) -> Result<Vec<CJAAppointment>>;
+    /// Replace an appointment (matched by id) on the attorney's record; false if absent
+    fn update_cja_appointment(&self, attorney_id: &str, appointment: CJAAppointment) -> Result<bool>;
+    /// Vouchers submitted but not yet approved or reduced, with their totals
+    fn find_pending_cja_vouchers(&self) -> Result<Vec<CJAVoucherSummary>>;
+    fn get_cja_rotation_cursor(&self, district: &str) -> Result<Option<String>>;
+    fn set_cja_rotation_cursor(&self, district: &str, attorney_id: &str) -> Result<()>;
 
     // ECF Registration
     fn update_ecf_registration(&self, attorney_id: &str, registration: ECFRegistration) -> Result<()>;
@@ -52,7 +62,6 @@ pub trait AttorneyRepository {
     fn revoke_ecf_access(&self, attorney_id: &str) -> Result<()>;
 
     // Discipline
-    fn add_disciplinary_action(&self, attorney_id: &str, action: DisciplinaryAction) -> Result<()>;
     fn find_disciplinary_history(&self, attorney_id: &str) -> Result<Vec<DisciplinaryAction>>;
     fn find_attorneys_with_discipline(&self) -> Result<Vec<Attorney>>;
 
@@ -71,6 +80,8 @@ pub trait AttorneyRepository {
     fn end_representation(&self, representation_id: &str, reason: Option<String>) -> Result<()>;
     fn find_representation_by_id(&self, id: &str) -> Result<Option<AttorneyRepresentation>>;
     fn find_active_representations(&self, attorney_id: &str) -> Result<Vec<AttorneyRepresentation>>;
+    /// Every representation by the attorney, ended ones included
+    fn find_representations_by_attorney(&self, attorney_id: &str) -> Result<Vec<AttorneyRepresentation>>;
     fn find_representations_by_case(&self, case_id: &str) -> Result<Vec<AttorneyRepresentation>>;
     fn substitute_attorney(&self, old_attorney_id: &str, new_attorney_id: &str, case_id: &str) -> Result<()>;
 
@@ -83,7 +94,15 @@ pub trait AttorneyRepository {
     // Conflict Checking
     fn save_conflict_check(&self, check: ConflictCheck) -> Result<()>;
     fn find_conflict_checks_by_attorney(&self, attorney_id: &str) -> Result<Vec<ConflictCheck>>;
-    fn find_conflicts_for_parties(&self, attorney_id: &str, party_names: Vec<String>) -> Result<Vec<ConflictCheck>>;
+    fn find_all_conflict_checks(&self) -> Result<Vec<ConflictCheck>>;
+    /// Checks naming any of the parties, each with how its recorded names
+    /// matched (see [`name_match`](crate::utils::name_match))
+    fn find_conflicts_for_parties(
+        &self,
+        attorney_id: &str,
+        party_names: Vec<String>,
+        threshold: f64,
+    ) -> Result<Vec<(ConflictCheck, Vec<NameMatch>)>>;
     fn clear_conflict(&self, check_id: &str, waiver_obtained: bool) -> Result<()>;
 
     // Attorney Metrics
@@ -3,6 +3,8 @@
 //! This trait defines the contract for storing and retrieving criminal cases,
 //! allowing the domain to be independent of the storage implementation.
 
+use crate::domain::case_archive::{CaseArchive, CaseTombstone};
+use crate::domain::common::DateRange;
 use crate::domain::criminal_case::{CaseStatus, CasePriority, CriminalCase};
 use anyhow::Result;
 use uuid::Uuid;
@@ -35,15 +37,51 @@ pub trait CaseRepository {
 
     /// Count cases by status
     fn count_by_status(&self, status: CaseStatus) -> Result<usize>;
+
+    /// Count live cases; archived cases are not included
+    fn count(&self) -> Result<usize>;
+}
+
+/// Repository for archived cases and the tombstones they leave behind
+///
+/// Archives and tombstones are kept apart from live cases, so listing and
+/// searching cases never sees them.
+pub trait CaseArchiveRepository {
+    /// Save an archive and its tombstone
+    fn save_archive(&self, archive: &CaseArchive) -> Result<()>;
+
+    /// Find the archive of a case
+    fn find_archive(&self, case_id: Uuid) -> Result<Option<CaseArchive>>;
+
+    /// Find the tombstone left by an archived case
+    fn find_tombstone(&self, case_id: Uuid) -> Result<Option<CaseTombstone>>;
+
+    /// Delete an archive and its tombstone (returns true if the archive existed)
+    fn delete_archive(&self, case_id: Uuid) -> Result<bool>;
+
+    /// Count archived cases
+    fn count_archives(&self) -> Result<usize>;
+}
+
+/// Repository for the sequence counters behind generated case numbers
+pub trait CaseNumberCounterRepository {
+    /// Advance a counter by one and return its new value
+    ///
+    /// The increment is atomic, so concurrent callers never get the same
+    /// value. A counter that has never been set starts at 1.
+    fn next_counter_value(&self, key: &str) -> Result<u64>;
 }
 
 /// Query parameters for searching cases
 #[derive(Debug, Default)]
 pub struct CaseQuery {
-    pub status: Option<CaseStatus>,
+    /// Matches a case in any of these statuses; empty matches every status
+    pub statuses: Vec<CaseStatus>,
     pub priority: Option<CasePriority>,
     pub judge_id: Option<Uuid>,
     pub is_active: Option<bool>,
+    /// Case-insensitive match against case number, title and description
+    pub search_text: Option<String>,
     pub offset: usize,
     pub limit: usize,
 }
@@ -53,13 +91,16 @@ pub trait CaseQueryRepository: CaseRepository {
     /// Search cases with filters and pagination
     fn search(&self, query: CaseQuery) -> Result<(Vec<CriminalCase>, usize)>;
 
-    /// Get case statistics
-    fn get_statistics(&self) -> Result<CaseStatistics>;
+    /// Get statistics for cases opened within the range
+    fn get_statistics(&self, range: DateRange) -> Result<CaseStatistics>;
 }
 
 /// Statistics about cases in the system
 #[derive(Debug, serde::Serialize, utoipa::ToSchema)]
 pub struct CaseStatistics {
+    /// Range of opening dates the statistics cover
+    #[serde(flatten)]
+    pub range: DateRange,
     pub total_cases: usize,
     pub open_cases: usize,
     pub under_investigation: usize,
@@ -3,9 +3,10 @@
 //! This port defines the interface for configuration storage and retrieval,
 //! allowing different implementations (TOML files, KV stores, etc.)
 
-use crate::domain::config::{Configuration, ConfigOverride};
+use crate::domain::config::{Configuration, ConfigOverride, ConfigOverrideVersion, CourtClosure};
 use crate::error::ApiError;
 use async_trait::async_trait;
+use chrono::NaiveDate;
 
 /// Repository trait for configuration management
 ///
@@ -34,6 +35,23 @@ pub trait ConfigRepository: Send + Sync {
     /// Delete judge-level configuration overrides
     async fn delete_judge_overrides(&self, district_id: &str, judge_id: &str) -> Result<(), ApiError>;
 
+    /// Record a version of the district's overrides in its history
+    async fn save_district_override_version(&self, district_id: &str, version: &ConfigOverrideVersion) -> Result<(), ApiError>;
+
+    /// The district's override history, oldest first
+    async fn list_district_override_versions(&self, district_id: &str) -> Result<Vec<ConfigOverrideVersion>, ApiError>;
+
+    /// Get the district's special closure dates, sorted by date
+    async fn get_closures(&self, district_id: &str) -> Result<Vec<CourtClosure>, ApiError>;
+
+    /// Replace the district's special closure dates
+    async fn save_closures(&self, district_id: &str, closures: &[CourtClosure]) -> Result<(), ApiError>;
+
+    /// Dates the district's court is closed, for deadline and calendar computations
+    async fn get_closure_dates(&self, district_id: &str) -> Result<Vec<NaiveDate>, ApiError> {
+        Ok(self.get_closures(district_id).await?.into_iter().map(|c| c.date).collect())
+    }
+
     /// Get merged configuration for a specific context
     /// This is a convenience method that merges base, district, and judge configs
     async fn get_merged_config(&self, district_id: &str, judge_id: Option<&str>) -> Result<Configuration, ApiError> {
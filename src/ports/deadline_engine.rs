@@ -22,10 +22,18 @@ pub trait DeadlineEngine {
     /// Check if a date is a federal holiday (including observed dates)
     fn is_federal_holiday(&self, date: NaiveDate) -> bool;
 
+    /// Check if a date is a district-specific court closure
+    fn is_court_closure(&self, date: NaiveDate) -> bool;
+
     /// Check if a date is a weekend (Saturday or Sunday)
     fn is_weekend(&self, date: NaiveDate) -> bool;
 
-    /// Get the next business day, skipping weekends and federal holidays
+    /// Check that a date is not a weekend, federal holiday or court closure
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_weekend(date) && !self.is_federal_holiday(date) && !self.is_court_closure(date)
+    }
+
+    /// Get the next business day, skipping weekends, federal holidays and closures
     fn next_business_day(&self, date: NaiveDate) -> NaiveDate;
 
     /// Get all federal holidays for a given year, including observed dates
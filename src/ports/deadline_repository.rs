@@ -3,9 +3,11 @@
 //! This trait defines the contract for storing and retrieving deadlines,
 //! extensions, and compliance data in the federal court system.
 
-use crate::domain::deadline::{Deadline, DeadlineType, DeadlineStatus, ExtensionRequest, DeadlineReminder};
+use crate::domain::deadline::{Deadline, DeadlineRule, DeadlineType, DeadlineStatus, ExtensionRequest, DeadlineReminder};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::cmp::Ordering;
 use uuid::Uuid;
 
 /// Repository trait for deadline persistence
@@ -16,6 +18,12 @@ pub trait DeadlineRepository {
     /// Find deadline by ID
     fn find_deadline_by_id(&self, id: Uuid) -> Result<Option<Deadline>>;
 
+    /// Find all deadlines
+    fn find_all_deadlines(&self) -> Result<Vec<Deadline>>;
+
+    /// Count deadlines
+    fn count(&self) -> Result<usize>;
+
     /// Find all deadlines for a case
     fn find_deadlines_by_case(&self, case_id: Uuid) -> Result<Vec<Deadline>>;
 
@@ -55,8 +63,8 @@ pub trait ExtensionRepository {
     /// Find pending extensions
     fn find_pending_extensions(&self) -> Result<Vec<(Uuid, ExtensionRequest)>>;
 
-    /// Update extension status
-    fn update_extension_status(&self, id: Uuid, status: crate::domain::deadline::ExtensionStatus) -> Result<()>;
+    /// Find the deadline that an extension request belongs to
+    fn find_deadline_by_extension(&self, extension_id: Uuid) -> Result<Option<Deadline>>;
 }
 
 /// Repository trait for reminder tracking
@@ -73,24 +81,112 @@ pub trait ReminderRepository {
     /// Mark reminder as acknowledged
     fn acknowledge_reminder(&self, reminder_id: Uuid) -> Result<()>;
 
-    /// Get unsent reminders
-    fn get_pending_reminders(&self) -> Result<Vec<DeadlineReminder>>;
+    /// Get unsent reminders due on the given schedule (days before the due date)
+    fn get_pending_reminders(&self, schedule_days: &[i64]) -> Result<Vec<DeadlineReminder>>;
+}
+
+/// Repository trait for a district's deadline calculation rules
+pub trait DeadlineRuleRepository {
+    /// Save (create or replace) a deadline rule
+    fn save_deadline_rule(&self, rule: &DeadlineRule) -> Result<()>;
+
+    /// Find a deadline rule by its identifier
+    fn find_deadline_rule(&self, id: &str) -> Result<Option<DeadlineRule>>;
+
+    /// Find all deadline rules for the district
+    fn find_all_deadline_rules(&self) -> Result<Vec<DeadlineRule>>;
+}
+
+/// Field deadline search results are ordered by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadlineSortField {
+    #[default]
+    DueDate,
+    /// When the deadline was first saved
+    Created,
+    /// Case ID, then due date within each case
+    Case,
+}
+
+/// Direction of a sort
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
 }
 
 /// Query parameters for searching deadlines
 #[derive(Debug, Default)]
 pub struct DeadlineQuery {
-    pub case_id: Option<Uuid>,
-    pub deadline_type: Option<DeadlineType>,
-    pub status: Option<DeadlineStatus>,
+    /// Matches deadlines on any of these cases; empty matches every case
+    pub case_ids: Vec<Uuid>,
+    /// Matches any of these types; empty matches every type
+    pub deadline_types: Vec<DeadlineType>,
+    /// Matches any of these statuses; empty matches every status
+    pub statuses: Vec<DeadlineStatus>,
+    /// Case-insensitive substring of the responsible party
     pub responsible_party: Option<String>,
+    /// Case-insensitive substring of the applicable rule, e.g. "12(a)"
+    pub applicable_rule: Option<String>,
     pub is_jurisdictional: Option<bool>,
+    /// Due on or after
     pub due_date_from: Option<DateTime<Utc>>,
+    /// Due on or before
     pub due_date_to: Option<DateTime<Utc>>,
+    pub sort_by: DeadlineSortField,
+    pub direction: SortDirection,
     pub offset: usize,
     pub limit: usize,
 }
 
+impl DeadlineQuery {
+    /// Whether a deadline passes every filter in the query
+    pub fn matches(&self, deadline: &Deadline) -> bool {
+        let contains = |field: &str, filter: &Option<String>| {
+            filter.as_ref().map_or(true, |f| field.to_lowercase().contains(&f.to_lowercase()))
+        };
+
+        (self.case_ids.is_empty() || self.case_ids.contains(&deadline.case_id))
+            && (self.deadline_types.is_empty()
+                || self.deadline_types.iter().any(|t| {
+                    std::mem::discriminant(&deadline.deadline_type) == std::mem::discriminant(t)
+                }))
+            && (self.statuses.is_empty() || self.statuses.contains(&deadline.status))
+            && contains(&deadline.responsible_party, &self.responsible_party)
+            && contains(&deadline.applicable_rule, &self.applicable_rule)
+            && self.is_jurisdictional.map_or(true, |j| deadline.is_jurisdictional == j)
+            && self.due_date_from.map_or(true, |from| deadline.due_date >= from)
+            && self.due_date_to.map_or(true, |to| deadline.due_date <= to)
+    }
+
+    /// Order matching deadlines by the query's sort field and direction
+    ///
+    /// `created_at` gives when each deadline was first saved; deadlines it
+    /// has no time for sort before the rest. Ties fall back to due date and
+    /// then ID, so pages are stable.
+    pub fn sort<F>(&self, deadlines: &mut [Deadline], created_at: F)
+    where
+        F: Fn(Uuid) -> Option<DateTime<Utc>>,
+    {
+        let by_due = |a: &Deadline, b: &Deadline| a.due_date.cmp(&b.due_date).then_with(|| a.id.cmp(&b.id));
+        deadlines.sort_by(|a, b| {
+            let ordering = match self.sort_by {
+                DeadlineSortField::DueDate => Ordering::Equal,
+                DeadlineSortField::Created => created_at(a.id).cmp(&created_at(b.id)),
+                DeadlineSortField::Case => a.case_id.cmp(&b.case_id),
+            }
+            .then_with(|| by_due(a, b));
+            match self.direction {
+                SortDirection::Asc => ordering,
+                SortDirection::Desc => ordering.reverse(),
+            }
+        });
+    }
+}
+
 /// Extended repository with advanced query and compliance features
 pub trait DeadlineComplianceRepository: DeadlineRepository {
     /// Search deadlines with filters
@@ -146,4 +242,78 @@ pub struct PerformanceMetrics {
     pub extension_requests: usize,
     pub violations: usize,
     pub trending: String, // "improving", "declining", "stable"
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::deadline::DeadlineCalculator;
+    use chrono::{Duration, TimeZone};
+
+    fn deadline(case_id: Uuid, due_in_days: i64, party: &str, rule: &str, jurisdictional: bool) -> Deadline {
+        let start = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        let rules = DeadlineCalculator::default_rule_set();
+        let mut deadline = DeadlineCalculator::calculate_from_rules(&rules, "complaint_filed", start, &[]).remove(0);
+        deadline.id = Uuid::new_v4();
+        deadline.case_id = case_id;
+        deadline.due_date = start + Duration::days(due_in_days);
+        deadline.responsible_party = party.to_string();
+        deadline.applicable_rule = rule.to_string();
+        deadline.is_jurisdictional = jurisdictional;
+        deadline.status = DeadlineStatus::Pending;
+        deadline
+    }
+
+    #[test]
+    fn test_filters_combine_party_rule_and_due_range() {
+        let case = Uuid::new_v4();
+        let query = DeadlineQuery {
+            responsible_party: Some("defendant".to_string()),
+            applicable_rule: Some("12(a)".to_string()),
+            due_date_to: Some(Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap()),
+            ..Default::default()
+        };
+
+        assert!(query.matches(&deadline(case, 21, "Defendant Smith", "FRCP 12(a)(1)(A)", false)));
+        assert!(!query.matches(&deadline(case, 21, "Plaintiff", "FRCP 12(a)(1)(A)", false)));
+        assert!(!query.matches(&deadline(case, 21, "Defendant Smith", "FRAP 4(a)(1)(A)", true)));
+        assert!(!query.matches(&deadline(case, 60, "Defendant Smith", "FRCP 12(a)(1)(A)", false)));
+
+        let jurisdictional = DeadlineQuery { is_jurisdictional: Some(true), ..Default::default() };
+        assert!(jurisdictional.matches(&deadline(case, 30, "Appellant", "FRAP 4(a)(1)(A)", true)));
+        assert!(!jurisdictional.matches(&deadline(case, 30, "Defendant", "FRCP 12(a)(1)(A)", false)));
+    }
+
+    #[test]
+    fn test_sort_by_case_created_and_direction() {
+        let (case_a, case_b) = (Uuid::from_u128(1), Uuid::from_u128(2));
+        let a_late = deadline(case_a, 30, "Defendant", "FRCP 12", false);
+        let a_early = deadline(case_a, 10, "Defendant", "FRCP 12", false);
+        let b_middle = deadline(case_b, 20, "Defendant", "FRCP 12", false);
+        let mut deadlines = vec![a_late.clone(), b_middle.clone(), a_early.clone()];
+        let ids = |deadlines: &[Deadline]| deadlines.iter().map(|d| d.id).collect::<Vec<_>>();
+
+        let by_case = DeadlineQuery { sort_by: DeadlineSortField::Case, ..Default::default() };
+        by_case.sort(&mut deadlines, |_| None);
+        assert_eq!(ids(&deadlines), vec![a_early.id, a_late.id, b_middle.id]);
+
+        let by_due_desc = DeadlineQuery { direction: SortDirection::Desc, ..Default::default() };
+        by_due_desc.sort(&mut deadlines, |_| None);
+        assert_eq!(ids(&deadlines), vec![a_late.id, b_middle.id, a_early.id]);
+
+        // Created order: b_middle first, then a_late; a_early was never timestamped
+        let start = Utc::now();
+        let created = |id: Uuid| {
+            if id == b_middle.id {
+                Some(start)
+            } else if id == a_late.id {
+                Some(start + Duration::seconds(1))
+            } else {
+                None
+            }
+        };
+        let by_created = DeadlineQuery { sort_by: DeadlineSortField::Created, ..Default::default() };
+        by_created.sort(&mut deadlines, created);
+        assert_eq!(ids(&deadlines), vec![a_early.id, b_middle.id, a_late.id]);
+    }
+}
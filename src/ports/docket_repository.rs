@@ -3,9 +3,12 @@
 //! This trait defines the contract for storing and retrieving docket entries,
 //! calendar events, and related data in the federal court system.
 
-use crate::domain::docket::{DocketEntry, CalendarEntry, SpeedyTrialClock, DocketEntryType, CalendarEventType, EventStatus};
+use crate::domain::docket::{
+    DocketEntry, CalendarEntry, SpeedyTrialClock, DocketEntryType, CalendarEventType, EventStatus,
+    CaseAttachmentSize,
+};
 use anyhow::Result;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use uuid::Uuid;
 
 /// Repository trait for docket entry persistence
@@ -16,6 +19,9 @@ pub trait DocketRepository {
     /// Find docket entry by ID
     fn find_entry_by_id(&self, id: Uuid) -> Result<Option<DocketEntry>>;
 
+    /// Find all entries
+    fn find_all_entries(&self) -> Result<Vec<DocketEntry>>;
+
     /// Find all entries for a case
     fn find_entries_by_case(&self, case_id: Uuid) -> Result<Vec<DocketEntry>>;
 
@@ -33,6 +39,12 @@ pub trait DocketRepository {
 
     /// Delete an entry (for administrative purposes)
     fn delete_entry(&self, id: Uuid) -> Result<bool>;
+
+    /// Version of a case's docket, bumped on every entry write; 0 before any
+    fn docket_version(&self, case_id: Uuid) -> Result<u64>;
+
+    /// Total size and page count of the attachments on a case's docket
+    fn case_attachment_size(&self, case_id: Uuid) -> Result<CaseAttachmentSize>;
 }
 
 /// Repository trait for calendar persistence
@@ -43,6 +55,9 @@ pub trait CalendarRepository {
     /// Find event by ID
     fn find_event_by_id(&self, id: Uuid) -> Result<Option<CalendarEntry>>;
 
+    /// Find all events
+    fn find_all_events(&self) -> Result<Vec<CalendarEntry>>;
+
     /// Find events by case
     fn find_events_by_case(&self, case_id: Uuid) -> Result<Vec<CalendarEntry>>;
 
@@ -63,6 +78,10 @@ pub trait CalendarRepository {
 
     /// Delete an event
     fn delete_event(&self, id: Uuid) -> Result<bool>;
+
+    /// Version of a judge's calendar, bumped on every write to one of the
+    /// judge's events; 0 before any
+    fn judge_calendar_version(&self, judge_id: Uuid) -> Result<u64>;
 }
 
 /// Repository trait for Speedy Trial tracking
@@ -81,18 +100,26 @@ pub trait SpeedyTrialRepository {
 
     /// Update clock status
     fn update_clock(&self, case_id: Uuid, clock: &SpeedyTrialClock) -> Result<()>;
+
+    /// Delete a case's clock (returns true if it existed)
+    fn delete_clock(&self, case_id: Uuid) -> Result<bool>;
 }
 
 /// Query parameters for searching docket entries
 #[derive(Debug, Default)]
 pub struct DocketQuery {
     pub case_id: Option<Uuid>,
-    pub entry_type: Option<DocketEntryType>,
+    /// Matches any of these entry types; empty matches every type
+    pub entry_types: Vec<DocketEntryType>,
     pub filed_by: Option<String>,
     pub sealed_only: bool,
     pub date_from: Option<DateTime<Utc>>,
     pub date_to: Option<DateTime<Utc>>,
     pub search_text: Option<String>,
+    /// Return sealed entries; hidden unless set
+    pub include_sealed: bool,
+    /// Return ex parte entries; hidden unless set
+    pub include_ex_parte: bool,
     pub offset: usize,
     pub limit: usize,
 }
@@ -118,8 +145,8 @@ pub trait DocketQueryRepository: DocketRepository {
     /// Get filing statistics
     fn get_filing_statistics(&self, case_id: Uuid) -> Result<FilingStatistics>;
 
-    /// Generate docket sheet
-    fn generate_docket_sheet(&self, case_id: Uuid) -> Result<String>;
+    /// Generate docket sheet, omitting sealed and ex parte entries unless included
+    fn generate_docket_sheet(&self, case_id: Uuid, include_sealed: bool, include_ex_parte: bool) -> Result<String>;
 }
 
 /// Extended calendar repository with scheduling features
@@ -127,8 +154,14 @@ pub trait CalendarSchedulingRepository: CalendarRepository {
     /// Search calendar with filters
     fn search_calendar(&self, query: CalendarQuery) -> Result<(Vec<CalendarEntry>, usize)>;
 
-    /// Find next available slot
-    fn find_available_slot(&self, judge_id: Uuid, duration_minutes: u32, earliest: DateTime<Utc>) -> Result<DateTime<Utc>>;
+    /// Find next available slot, skipping weekends and the given closure dates
+    fn find_available_slot(
+        &self,
+        judge_id: Uuid,
+        duration_minutes: u32,
+        earliest: DateTime<Utc>,
+        closures: &[NaiveDate],
+    ) -> Result<DateTime<Utc>>;
 
     /// Get judge's schedule
     fn get_judge_schedule(&self, judge_id: Uuid, date: DateTime<Utc>) -> Result<Vec<CalendarEntry>>;
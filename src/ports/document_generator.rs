@@ -1,8 +1,11 @@
 use async_trait::async_trait;
 use crate::domain::document::{
     CourtDocument, GeneratedDocument, DocumentError, DocumentMetadata,
-    CaseNumber, JudgeName, District, DocumentType, ElectronicSignature
+    CaseNumber, JudgeName, District, DocumentType, ElectronicSignature, PageLayout, DefendantNames
 };
+use crate::domain::deadline::ComplianceReportDetail;
+use crate::domain::weekly_docket::WeeklyDocket;
+use chrono::{DateTime, Utc};
 
 #[async_trait]
 pub trait DocumentGenerator: Send + Sync {
@@ -16,7 +19,7 @@ pub trait PdfRenderer: Send + Sync {
     fn render_rule16b(
         &self,
         case_number: &CaseNumber,
-        defendant_names: &str,
+        defendant_names: &DefendantNames,
         judge_name: &JudgeName,
         district: &District,
         signature: Option<&ElectronicSignature>
@@ -25,7 +28,7 @@ pub trait PdfRenderer: Send + Sync {
     fn render_court_order(
         &self,
         case_number: &CaseNumber,
-        defendant_names: &str,
+        defendant_names: &DefendantNames,
         judge_name: &JudgeName,
         district: &District,
         order_title: &str,
@@ -36,10 +39,11 @@ pub trait PdfRenderer: Send + Sync {
     fn render_minute_entry(
         &self,
         case_number: &CaseNumber,
-        defendant_names: &str,
+        defendant_names: &DefendantNames,
         judge_name: &JudgeName,
         district: &District,
-        minute_text: &str
+        minute_text: &str,
+        signature: Option<&ElectronicSignature>
     ) -> Result<Vec<u8>, DocumentError>;
 
     fn render_waiver_indictment(
@@ -69,6 +73,53 @@ pub trait PdfRenderer: Send + Sync {
         counts: &str,
         sentence: &str
     ) -> Result<Vec<u8>, DocumentError>;
+
+    fn render_summons(
+        &self,
+        case_number: &CaseNumber,
+        district: &District,
+        party_name: &str,
+        party_address: Option<&str>,
+        response_days: u32
+    ) -> Result<Vec<u8>, DocumentError>;
+
+    fn render_subpoena(
+        &self,
+        case_number: &CaseNumber,
+        district: &District,
+        recipient_name: &str,
+        appearance_date: &DateTime<Utc>,
+        location: &str,
+        judge_name: &JudgeName
+    ) -> Result<Vec<u8>, DocumentError>;
+
+    fn render_weekly_docket(
+        &self,
+        district: &District,
+        docket: &WeeklyDocket
+    ) -> Result<Vec<u8>, DocumentError>;
+
+    /// Multi-page report: summary table, trend, then the missed-deadline appendix
+    fn render_compliance_report(
+        &self,
+        district: &District,
+        report: &ComplianceReportDetail
+    ) -> Result<Vec<u8>, DocumentError>;
+
+    /// Published opinion with its citation stamped at the top of every page
+    #[allow(clippy::too_many_arguments)]
+    fn render_slip_opinion(
+        &self,
+        case_number: &CaseNumber,
+        district: &District,
+        citation: &str,
+        case_name: &str,
+        title: &str,
+        judge_name: &JudgeName,
+        syllabus: &str,
+        content: &str,
+        published_at: &DateTime<Utc>
+    ) -> Result<Vec<u8>, DocumentError>;
 }
 
 #[async_trait]
@@ -85,6 +136,7 @@ pub struct DocumentRequest {
     pub document_type: DocumentType,
     pub district: District,
     pub metadata: DocumentMetadata,
+    pub layout: PageLayout,
 }
 
 impl DocumentRequest {
@@ -96,6 +148,7 @@ impl DocumentRequest {
             district: self.district,
             created_at: chrono::Utc::now(),
             metadata: self.metadata,
+            layout: self.layout,
         }
     }
 }
\ No newline at end of file
@@ -3,12 +3,16 @@
 //! This module defines the repository interface for managing
 //! judicial orders and opinions with their associated metadata.
 
+use crate::domain::common::DateRange;
+use crate::domain::docket::DocketAttachment;
+use crate::domain::document::DocumentIndexEntry;
 use crate::domain::order::{JudicialOrder, OrderTemplate, OrderType, OrderStatus};
-use crate::domain::opinion::{JudicialOpinion, OpinionDraft};
+use crate::domain::opinion::{CitationEdge, JudicialOpinion, OpinionDraft, RankedOpinion};
 use crate::error::ApiResult;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use uuid::Uuid;
 
 /// Repository trait for managing judicial documents
 pub trait DocumentRepository: Send + Sync {
@@ -18,6 +22,7 @@ pub trait DocumentRepository: Send + Sync {
     fn update_order(&self, order: JudicialOrder) -> ApiResult<JudicialOrder>;
     fn delete_order(&self, order_id: &str) -> ApiResult<()>;
     fn list_orders(&self, filter: OrderFilter) -> ApiResult<Vec<JudicialOrder>>;
+    fn count_orders(&self) -> ApiResult<usize>;
     fn find_orders_by_case(&self, case_id: &str) -> ApiResult<Vec<JudicialOrder>>;
     fn find_orders_by_judge(&self, judge_id: &str) -> ApiResult<Vec<JudicialOrder>>;
     fn find_pending_signatures(&self, judge_id: &str) -> ApiResult<Vec<JudicialOrder>>;
@@ -37,11 +42,16 @@ pub trait DocumentRepository: Send + Sync {
     fn update_opinion(&self, opinion: JudicialOpinion) -> ApiResult<JudicialOpinion>;
     fn delete_opinion(&self, opinion_id: &str) -> ApiResult<()>;
     fn list_opinions(&self, filter: OpinionFilter) -> ApiResult<Vec<JudicialOpinion>>;
+    fn count_opinions(&self) -> ApiResult<usize>;
     fn find_opinions_by_case(&self, case_id: &str) -> ApiResult<Vec<JudicialOpinion>>;
     fn find_opinions_by_author(&self, judge_id: &str) -> ApiResult<Vec<JudicialOpinion>>;
     fn find_published_opinions(&self, start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> ApiResult<Vec<JudicialOpinion>>;
     fn search_opinions(&self, query: &str) -> ApiResult<Vec<JudicialOpinion>>;
+    /// Opinions scoring at least `min_score` for the query, most relevant first
+    fn search_opinions_ranked(&self, query: &str, limit: usize, min_score: u32) -> ApiResult<Vec<RankedOpinion>>;
     fn find_precedential_opinions(&self) -> ApiResult<Vec<JudicialOpinion>>;
+    /// Citations made to an opinion by other opinions (reverse-citation index)
+    fn find_citing_opinions(&self, opinion_id: &str) -> ApiResult<Vec<CitationEdge>>;
 
     // Draft operations
     fn create_draft(&self, draft: OpinionDraft) -> ApiResult<OpinionDraft>;
@@ -51,11 +61,48 @@ pub trait DocumentRepository: Send + Sync {
     fn get_current_draft(&self, opinion_id: &str) -> ApiResult<Option<OpinionDraft>>;
 
     // Statistics
-    fn get_order_statistics(&self, judge_id: Option<&str>) -> ApiResult<OrderStatistics>;
-    fn get_opinion_statistics(&self, judge_id: Option<&str>) -> ApiResult<OpinionStatistics>;
+    /// Statistics for orders created within the range
+    fn get_order_statistics(&self, judge_id: Option<&str>, range: DateRange) -> ApiResult<OrderStatistics>;
+    /// Statistics for opinions created within the range
+    fn get_opinion_statistics(&self, judge_id: Option<&str>, range: DateRange) -> ApiResult<OpinionStatistics>;
     fn get_citation_statistics(&self, start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> ApiResult<CitationStatistics>;
 }
 
+/// Index of generated court documents and the parameters they came from
+pub trait DocumentIndexRepository {
+    fn index_document(&self, entry: &DocumentIndexEntry) -> ApiResult<()>;
+    fn list_indexed_documents(&self) -> ApiResult<Vec<DocumentIndexEntry>>;
+}
+
+/// Sequence counters behind neutral citations
+pub trait CitationCounterRepository {
+    /// Advance a counter by one and return its new value
+    ///
+    /// The increment is atomic, so concurrent callers never get the same
+    /// value. A counter that has never been set starts at 1.
+    fn next_citation_counter_value(&self, key: &str) -> ApiResult<u64>;
+}
+
+/// Slip opinion PDFs generated on publication, keyed by opinion id
+pub trait SlipOpinionRepository {
+    fn save_slip_opinion(&self, opinion_id: &str, pdf: &[u8]) -> ApiResult<()>;
+    fn find_slip_opinion(&self, opinion_id: &str) -> ApiResult<Option<Vec<u8>>>;
+}
+
+/// Uploaded docket attachment files, keyed by attachment id
+pub trait AttachmentContentRepository {
+    fn save_attachment_content(&self, content: &StoredAttachment) -> ApiResult<()>;
+    fn find_attachment_content(&self, attachment_id: Uuid) -> ApiResult<Option<StoredAttachment>>;
+}
+
+/// An uploaded attachment file with the docket entry it belongs to
+#[derive(Debug, Clone)]
+pub struct StoredAttachment {
+    pub entry_id: Uuid,
+    pub attachment: DocketAttachment,
+    pub content: Vec<u8>,
+}
+
 /// Filter for querying orders
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct OrderFilter {
@@ -91,6 +138,9 @@ pub struct OpinionFilter {
 /// Statistics for orders
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct OrderStatistics {
+    /// Range of creation dates the statistics cover
+    #[serde(flatten)]
+    pub range: DateRange,
     pub total_orders: usize,
     pub orders_by_type: Vec<(String, usize)>,
     pub orders_by_status: Vec<(String, usize)>,
@@ -106,6 +156,9 @@ pub struct OrderStatistics {
 /// Statistics for opinions
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct OpinionStatistics {
+    /// Range of creation dates the statistics cover
+    #[serde(flatten)]
+    pub range: DateRange,
     pub total_opinions: usize,
     pub opinions_by_type: Vec<(String, usize)>,
     pub opinions_by_disposition: Vec<(String, usize)>,
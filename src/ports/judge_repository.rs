@@ -3,7 +3,8 @@
 //! This trait defines the contract for storing and retrieving judges,
 //! assignments, and recusals in the federal court system.
 
-use crate::domain::judge::{Judge, CaseAssignment, RecusalMotion, JudgeStatus, JudgeTitle, ConflictOfInterest};
+use crate::domain::judge::{Judge, CaseAssignment, CaseType, RecusalMotion, JudgeStatus, JudgeTitle, ConflictOfInterest};
+use crate::utils::name_match::NameMatch;
 use anyhow::Result;
 use uuid::Uuid;
 
@@ -18,6 +19,9 @@ pub trait JudgeRepository {
     /// Find all judges
     fn find_all_judges(&self) -> Result<Vec<Judge>>;
 
+    /// Count judges
+    fn count(&self) -> Result<usize>;
+
     /// Find judges by status
     fn find_judges_by_status(&self, status: JudgeStatus) -> Result<Vec<Judge>>;
 
@@ -47,6 +51,12 @@ pub trait CaseAssignmentRepository {
 
     /// Delete an assignment
     fn delete_assignment(&self, id: Uuid) -> Result<bool>;
+
+    /// Get the last judge drawn by round-robin for a case-type panel
+    fn get_rotation_cursor(&self, case_type: &CaseType) -> Result<Option<Uuid>>;
+
+    /// Record the last judge drawn by round-robin for a case-type panel
+    fn set_rotation_cursor(&self, case_type: &CaseType, judge_id: Uuid) -> Result<()>;
 }
 
 /// Repository trait for recusal motion persistence
@@ -75,8 +85,13 @@ pub trait ConflictRepository {
     /// Find conflicts for a judge
     fn find_conflicts_by_judge(&self, judge_id: Uuid) -> Result<Vec<ConflictOfInterest>>;
 
-    /// Find conflicts by party name
-    fn find_conflicts_by_party(&self, party_name: &str) -> Result<Vec<(Uuid, ConflictOfInterest)>>;
+    /// Find conflicts by party name, with how each recorded name matched
+    /// (see [`name_match`](crate::utils::name_match))
+    fn find_conflicts_by_party(
+        &self,
+        party_name: &str,
+        threshold: f64,
+    ) -> Result<Vec<(Uuid, ConflictOfInterest, NameMatch)>>;
 
     /// Check if judge has conflict with party
     fn has_conflict(&self, judge_id: Uuid, party_name: &str) -> Result<bool>;
@@ -94,6 +109,8 @@ pub struct JudgeQuery {
     pub accepts_criminal: Option<bool>,
     pub accepts_civil: Option<bool>,
     pub max_caseload_percentage: Option<f32>,
+    /// Case-insensitive match against name and courtroom
+    pub search_text: Option<String>,
     pub offset: usize,
     pub limit: usize,
 }
@@ -106,6 +123,9 @@ pub trait JudgeQueryRepository: JudgeRepository {
     /// Get workload statistics
     fn get_workload_statistics(&self) -> Result<WorkloadStatistics>;
 
+    /// Get workload statistics for each case type, over the judges accepting it
+    fn workload_by_type(&self) -> Result<Vec<CaseTypeWorkload>>;
+
     /// Find judges with upcoming vacations
     fn find_judges_on_vacation(&self, start_date: chrono::DateTime<chrono::Utc>, end_date: chrono::DateTime<chrono::Utc>) -> Result<Vec<Judge>>;
 }
@@ -120,4 +140,11 @@ pub struct WorkloadStatistics {
     pub total_cases: usize,
     pub overloaded_judges: usize, // Judges at >90% capacity
     pub available_capacity: usize, // Total available case slots
+}
+
+/// Workload statistics for the judges accepting one case type
+#[derive(Debug, serde::Serialize, utoipa::ToSchema)]
+pub struct CaseTypeWorkload {
+    pub case_type: CaseType,
+    pub statistics: WorkloadStatistics,
 }
\ No newline at end of file
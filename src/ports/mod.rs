@@ -18,4 +18,6 @@ pub mod rules_engine;
 pub mod rules_repository;
 pub mod sentencing_repository;
 pub mod privacy_engine;
-pub mod signature_repository;
\ No newline at end of file
+pub mod signature_repository;
+pub mod webhook_delivery;
+pub mod webhook_repository;
\ No newline at end of file
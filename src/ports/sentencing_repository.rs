@@ -1,5 +1,6 @@
 //! Repository trait for federal sentencing management
 
+use crate::domain::common::DateRange;
 use crate::domain::sentencing::*;
 use crate::ApiResult;
 
@@ -12,6 +13,8 @@ pub trait SentencingRepository: Send + Sync {
     fn delete_sentencing(&self, id: &str) -> ApiResult<()>;
 
     // Query operations
+    fn find_all_sentencings(&self) -> ApiResult<Vec<Sentencing>>;
+    fn count(&self) -> ApiResult<usize>;
     fn find_by_case(&self, case_id: &str) -> ApiResult<Vec<Sentencing>>;
     fn find_by_defendant(&self, defendant_id: &str) -> ApiResult<Vec<Sentencing>>;
     fn find_by_judge(&self, judge_id: &str) -> ApiResult<Vec<Sentencing>>;
@@ -20,8 +23,10 @@ pub trait SentencingRepository: Send + Sync {
 
     // Guidelines operations
     fn calculate_guidelines(&self, calculation: GuidelinesCalculation) -> ApiResult<GuidelinesRange>;
-    fn get_departure_rates(&self) -> ApiResult<SentencingStatistics>;
-    fn get_variance_rates(&self) -> ApiResult<SentencingStatistics>;
+    fn get_departure_rates(&self, range: DateRange) -> ApiResult<SentencingStatistics>;
+    fn get_variance_rates(&self, range: DateRange) -> ApiResult<SentencingStatistics>;
+    /// Departure and variance statistics for sentencings dated within the range
+    fn get_adjustment_stats(&self, range: DateRange) -> ApiResult<AdjustmentStatistics>;
 
     // Departures and variances
     fn add_departure(&self, sentencing_id: &str, departure: Departure) -> ApiResult<Sentencing>;
@@ -37,15 +42,17 @@ pub trait SentencingRepository: Send + Sync {
     fn add_bop_designation(&self, sentencing_id: &str, designation: BOPDesignation) -> ApiResult<Sentencing>;
     fn get_rdap_eligible(&self) -> ApiResult<Vec<Sentencing>>;
 
-    // Statistics and reporting
-    fn get_judge_sentencing_stats(&self, judge_id: &str) -> ApiResult<SentencingStatistics>;
-    fn get_district_stats(&self) -> ApiResult<SentencingStatistics>;
-    fn get_offense_type_stats(&self, offense_type: &str) -> ApiResult<SentencingStatistics>;
-    fn get_trial_penalty_analysis(&self) -> ApiResult<SentencingStatistics>;
+    // Statistics and reporting, over sentencings dated within the range
+    fn get_judge_sentencing_stats(&self, judge_id: &str, range: DateRange) -> ApiResult<SentencingStatistics>;
+    /// A judge's guideline compliance against the district's, for sentencings dated within the range
+    fn get_judge_comparison(&self, judge_id: &str, range: DateRange) -> ApiResult<JudgeSentencingComparison>;
+    fn get_district_stats(&self, range: DateRange) -> ApiResult<SentencingStatistics>;
+    fn get_offense_type_stats(&self, offense_type: &str, range: DateRange) -> ApiResult<SentencingStatistics>;
+    fn get_trial_penalty_analysis(&self, range: DateRange) -> ApiResult<SentencingStatistics>;
 
     // Criminal history
     fn add_prior_sentence(&self, sentencing_id: &str, prior: PriorSentence) -> ApiResult<Sentencing>;
-    fn calculate_criminal_history_points(&self, sentencing_id: &str) -> ApiResult<i32>;
+    fn calculate_criminal_history_points(&self, sentencing_id: &str) -> ApiResult<CriminalHistoryPointsBreakdown>;
 
     // Compliance and deadlines
     fn find_upcoming_sentencings(&self, days: i32) -> ApiResult<Vec<Sentencing>>;
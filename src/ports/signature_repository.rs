@@ -10,13 +10,21 @@ pub struct JudgeSignature {
     pub signature_base64: String,
     pub uploaded_at: String,
     pub signature_hash: String, // SHA256 hash for verification
+    /// `png` or `jpeg`; absent on signatures stored before it was recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// Decoded image size in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub byte_size: Option<u64>,
 }
 
 #[derive(Debug)]
 pub enum SignatureError {
     StorageError(String),
-    NotFound,
-    InvalidSignature,
     SerializationError(String),
 }
 
@@ -24,8 +32,6 @@ impl std::fmt::Display for SignatureError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             SignatureError::StorageError(e) => write!(f, "Storage error: {}", e),
-            SignatureError::NotFound => write!(f, "Signature not found"),
-            SignatureError::InvalidSignature => write!(f, "Invalid signature"),
             SignatureError::SerializationError(e) => write!(f, "Serialization error: {}", e),
         }
     }
@@ -36,18 +42,9 @@ impl std::error::Error for SignatureError {}
 /// Port for signature storage operations
 #[async_trait]
 pub trait SignatureRepository: Send + Sync {
-    /// Store a judge's signature
-    async fn store_signature(&self, judge_id: Uuid, signature_base64: &str) -> Result<(), SignatureError>;
+    /// Store a judge's signature, replacing any earlier one
+    async fn store_signature(&self, signature: &JudgeSignature) -> Result<(), SignatureError>;
 
     /// Retrieve a judge's signature
     async fn get_signature(&self, judge_id: Uuid) -> Result<Option<JudgeSignature>, SignatureError>;
-
-    /// Delete a judge's signature
-    async fn delete_signature(&self, judge_id: Uuid) -> Result<(), SignatureError>;
-
-    /// Verify signature integrity
-    async fn verify_signature(&self, judge_id: Uuid, signature_base64: &str) -> Result<bool, SignatureError>;
-
-    /// List all stored signatures for a tenant
-    async fn list_signatures(&self) -> Result<Vec<JudgeSignature>, SignatureError>;
 }
\ No newline at end of file
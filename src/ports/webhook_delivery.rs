@@ -0,0 +1,17 @@
+//! Port for sending webhook notifications to subscriber endpoints
+
+/// A signed notification ready to send
+#[derive(Debug, Clone)]
+pub struct OutboundWebhook {
+    pub target_url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Sends a webhook and reports the HTTP status the target responded with
+///
+/// Transport failures, where the target never responded, are returned as
+/// `Err` with a description.
+pub trait WebhookDelivery {
+    fn deliver(&self, webhook: &OutboundWebhook) -> Result<u16, String>;
+}
@@ -0,0 +1,29 @@
+//! Repository port for webhook subscriptions and their delivery queue
+
+use crate::domain::webhook::{DeliveryAttempt, DistrictWebhook, PendingDelivery, WebhookSubscription};
+use crate::error::ApiResult;
+use uuid::Uuid;
+
+/// Persistence for webhook subscriptions, queued notifications and delivery history
+pub trait WebhookRepository {
+    fn save_subscription(&self, subscription: &WebhookSubscription) -> ApiResult<()>;
+    fn find_subscription(&self, id: Uuid) -> ApiResult<Option<WebhookSubscription>>;
+    fn list_subscriptions(&self) -> ApiResult<Vec<WebhookSubscription>>;
+    /// Returns `false` when the subscription did not exist
+    fn delete_subscription(&self, id: Uuid) -> ApiResult<bool>;
+
+    /// Add a notification to the outbound queue
+    fn enqueue_delivery(&self, delivery: &PendingDelivery) -> ApiResult<()>;
+    /// Update a queued notification's retry count and schedule
+    fn update_delivery(&self, delivery: &PendingDelivery) -> ApiResult<()>;
+    fn remove_delivery(&self, id: Uuid) -> ApiResult<()>;
+    fn pending_deliveries(&self) -> ApiResult<Vec<PendingDelivery>>;
+
+    fn record_attempt(&self, attempt: &DeliveryAttempt) -> ApiResult<()>;
+    /// Most recent attempts for a subscription, newest first
+    fn recent_attempts(&self, subscription_id: Uuid, limit: usize) -> ApiResult<Vec<DeliveryAttempt>>;
+
+    fn get_district_webhook(&self) -> ApiResult<Option<DistrictWebhook>>;
+    fn save_district_webhook(&self, webhook: &DistrictWebhook) -> ApiResult<()>;
+    fn delete_district_webhook(&self) -> ApiResult<()>;
+}
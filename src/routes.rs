@@ -0,0 +1,1124 @@
+//! Every HTTP route the API serves
+//!
+//! Routes are declared once, in [`ROUTES`]. The router is built from that
+//! table and the OpenAPI consistency check reads the same table, so a route
+//! can't be served without the check knowing about it: registering a route
+//! whose handler isn't documented fails `test_every_route_is_documented`.
+
+use crate::handlers;
+use spin_sdk::http::{IntoResponse, Params, Request, Response, Router};
+#[cfg(test)]
+use utoipa::openapi::{path::PathItem, OpenApi};
+
+/// HTTP method a route answers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+#[cfg(test)]
+impl Method {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Patch => "PATCH",
+            Self::Delete => "DELETE",
+        }
+    }
+
+    /// Whether the OpenAPI path item documents this method
+    fn is_documented_in(&self, item: &PathItem) -> bool {
+        match self {
+            Self::Get => item.get.is_some(),
+            Self::Post => item.post.is_some(),
+            Self::Put => item.put.is_some(),
+            Self::Patch => item.patch.is_some(),
+            Self::Delete => item.delete.is_some(),
+        }
+    }
+}
+
+/// A registered route
+pub struct Route {
+    pub method: Method,
+    /// Router pattern, e.g. `/api/cases/:id`
+    pub path: &'static str,
+    pub handler: fn(Request, Params) -> Response,
+}
+
+/// Build the route table from `Method "path" => handler` entries
+macro_rules! routes {
+    ($($method:ident $path:literal => $handler:path),* $(,)?) => {
+        &[$(Route {
+            method: Method::$method,
+            path: $path,
+            handler: |req, params| $handler(req, params).into_response(),
+        }),*]
+    };
+}
+
+/// Every route, in registration order
+pub static ROUTES: &[Route] = routes![
+    // Health check endpoint
+    Get "/api/health" => handlers::health::health_check,
+
+    // Configuration Management endpoints (Header-based - DEPRECATED)
+    Get "/api/config" => handlers::config::get_config,
+    Get "/api/config/overrides/district" => handlers::config::get_district_overrides,
+    Get "/api/config/overrides/judge" => handlers::config::get_judge_overrides,
+    Put "/api/config/overrides/district" => handlers::config::update_district_config,
+    Put "/api/config/overrides/judge" => handlers::config::update_judge_config,
+    Delete "/api/config/overrides/district" => handlers::config::clear_district_overrides,
+    Get "/api/config/overrides/district/history" => handlers::config::get_district_override_history,
+    Post "/api/config/overrides/district/rollback/:version" => handlers::config::rollback_district_overrides,
+    Delete "/api/config/overrides/judge" => handlers::config::clear_judge_overrides,
+    Post "/api/config/preview" => handlers::config::preview_config,
+    Get "/api/config/closures" => handlers::config::list_closures,
+    Post "/api/config/closures" => handlers::config::add_closure,
+    Delete "/api/config/closures" => handlers::config::delete_closure,
+    Get "/api/config/case-number-format" => handlers::config::get_case_number_format,
+    Put "/api/config/case-number-format" => handlers::config::update_case_number_format,
+
+    // Configuration Management endpoints (URL-based - NEW)
+    Get "/api/courts/:district/config" => handlers::config_url::get_config,
+    Get "/api/courts/:district/config/overrides/district" => handlers::config_url::get_district_overrides,
+    Get "/api/courts/:district/config/overrides/judge/:judge_id" => handlers::config_url::get_judge_overrides,
+    Put "/api/courts/:district/config/overrides/district" => handlers::config_url::update_district_config,
+    Put "/api/courts/:district/config/overrides/judge/:judge_id" => handlers::config_url::update_judge_config,
+    Delete "/api/courts/:district/config/overrides/district" => handlers::config_url::clear_district_overrides,
+    Get "/api/courts/:district/config/overrides/district/history" => handlers::config_url::get_district_override_history,
+    Post "/api/courts/:district/config/overrides/district/rollback/:version" => handlers::config_url::rollback_district_overrides,
+    Delete "/api/courts/:district/config/overrides/judge/:judge_id" => handlers::config_url::clear_judge_overrides,
+    Post "/api/courts/:district/config/preview" => handlers::config_url::preview_config,
+    Get "/api/courts/:district/config/closures" => handlers::config_url::list_closures,
+    Post "/api/courts/:district/config/closures" => handlers::config_url::add_closure,
+    Delete "/api/courts/:district/config/closures" => handlers::config_url::delete_closure,
+    Get "/api/courts/:district/config/case-number-format" => handlers::config_url::get_case_number_format,
+    Put "/api/courts/:district/config/case-number-format" => handlers::config_url::update_case_number_format,
+
+    // ToDo API endpoints
+    Get "/api/todos" => handlers::todo::get_all,
+    Get "/api/todos/overdue" => handlers::todo::get_overdue,
+    Get "/api/todos/:id" => handlers::todo::get_by_id,
+    Post "/api/todos" => handlers::todo::create_todo,
+    Post "/api/todos/bulk/toggle" => handlers::todo::bulk_toggle,
+    Post "/api/todos/bulk/delete" => handlers::todo::bulk_delete,
+    Post "/api/todos/:id/toggle" => handlers::todo::toggle_by_id,
+    Delete "/api/todos/:id" => handlers::todo::delete_by_id,
+
+    // Criminal Case API endpoints (using hexagonal architecture)
+    Get "/api/cases" => handlers::criminal_case::search_cases,
+    Get "/api/cases/statistics" => handlers::criminal_case::get_case_statistics,
+    Get "/api/cases/by-number/:case_number" => handlers::criminal_case::get_case_by_number,
+    Get "/api/cases/by-judge/:judge_id" => handlers::criminal_case::get_cases_by_judge,
+    Get "/api/cases/by-attorney/:attorney_id" => handlers::criminal_case::get_cases_by_attorney,
+    Get "/api/cases/count-by-status/:status" => handlers::criminal_case::count_by_status,
+    Get "/api/cases/custody-report" => handlers::criminal_case::get_custody_report,
+    Get "/api/cases/limitations-at-risk" => handlers::criminal_case::get_limitations_at_risk,
+    Get "/api/cases/:id" => handlers::criminal_case::get_case_by_id,
+    Post "/api/cases" => handlers::criminal_case::create_case,
+    Post "/api/cases/:id/defendants" => handlers::criminal_case::add_defendant,
+    Post "/api/cases/:id/defendants/:defendant_id/charges" => handlers::criminal_case::add_charge,
+    Patch "/api/cases/:id/defendants/:defendant_id/custody" => handlers::criminal_case::update_defendant_custody,
+    Post "/api/cases/:id/evidence" => handlers::criminal_case::add_evidence,
+    Post "/api/cases/:id/notes" => handlers::criminal_case::add_note,
+    Post "/api/cases/:id/plea" => handlers::criminal_case::enter_plea,
+    Post "/api/cases/:id/events" => handlers::criminal_case::schedule_court_event,
+    Post "/api/cases/:id/motions" => handlers::criminal_case::file_motion,
+    Patch "/api/cases/:id/motions/ruling" => handlers::criminal_case::rule_on_motion,
+    Patch "/api/cases/:id/status" => handlers::criminal_case::update_case_status,
+    Patch "/api/cases/:id/priority" => handlers::criminal_case::update_case_priority,
+    Delete "/api/cases/:id" => handlers::criminal_case::delete_case,
+
+    // Evidence custody transfers (Phase 2)
+    Post "/api/cases/:id/evidence/:evidence_id/custody" => handlers::criminal_case::add_custody_transfer,
+
+    // Docket entries on cases (Phase 1)
+    Post "/api/cases/:id/docket" => handlers::criminal_case::add_docket_entry,
+    Get "/api/cases/:id/docket" => handlers::criminal_case::get_docket_entries,
+    Get "/api/cases/:case_id/attachment-size" => handlers::docket::get_case_attachment_size,
+
+    // Case timeline across modules
+    Get "/api/cases/:id/timeline" => handlers::timeline::get_case_timeline,
+
+    // Sealed case management (Phase 3)
+    Post "/api/cases/:id/seal" => handlers::criminal_case::seal_case,
+    Post "/api/cases/:id/unseal" => handlers::criminal_case::unseal_case,
+    Post "/api/cases/:id/archive" => handlers::criminal_case::archive_case,
+    Get "/api/cases/:id/archive" => handlers::criminal_case::get_case_archive,
+    Post "/api/cases/:id/unarchive" => handlers::criminal_case::unarchive_case,
+
+    // Speedy trial clock on cases (Phase 4)
+    Post "/api/cases/:id/speedy-trial/start" => handlers::criminal_case::start_speedy_trial,
+    Post "/api/cases/:id/speedy-trial/exclude" => handlers::criminal_case::add_case_excludable_delay,
+    Get "/api/cases/:id/speedy-trial" => handlers::criminal_case::get_case_speedy_trial,
+    Post "/api/cases/:id/limitations/tolling" => handlers::criminal_case::record_limitations_tolling,
+    Get "/api/cases/:case_id/delay-attribution" => handlers::criminal_case::get_delay_attribution,
+
+    // CVRA victim management (Phase 5)
+    Post "/api/cases/:id/victims" => handlers::criminal_case::add_victim,
+    Get "/api/cases/:id/victims" => handlers::criminal_case::get_victims,
+    Post "/api/cases/:id/victims/:victim_id/notifications" => handlers::criminal_case::send_victim_notification,
+
+    // Magistrate judge consent (28 U.S.C. § 636(c))
+    Post "/api/cases/:id/magistrate-consent" => handlers::criminal_case::record_magistrate_consent,
+    Get "/api/cases/:id/magistrate-consent" => handlers::criminal_case::get_magistrate_consent,
+
+    // Notices of appeal (FRAP 4(b))
+    Post "/api/cases/:id/appeals" => handlers::criminal_case::file_appeal,
+    Get "/api/cases/:id/appeals" => handlers::criminal_case::get_appeals,
+    Patch "/api/appeals/:id/status" => handlers::criminal_case::update_appeal_status,
+
+    // Criminal Case API endpoints (URL-based - NEW)
+    Get "/api/courts/:district/cases" => handlers::criminal_case_url::search_cases,
+    Get "/api/courts/:district/cases/statistics" => handlers::criminal_case_url::get_case_statistics,
+    Get "/api/courts/:district/cases/by-number/:case_number" => handlers::criminal_case_url::get_case_by_number,
+    Get "/api/courts/:district/cases/by-judge/:judge_id" => handlers::criminal_case_url::get_cases_by_judge,
+    Get "/api/courts/:district/cases/by-attorney/:attorney_id" => handlers::criminal_case_url::get_cases_by_attorney,
+    Get "/api/courts/:district/cases/count-by-status/:status" => handlers::criminal_case_url::count_by_status,
+    Get "/api/courts/:district/cases/custody-report" => handlers::criminal_case_url::get_custody_report,
+    Get "/api/courts/:district/cases/limitations-at-risk" => handlers::criminal_case_url::get_limitations_at_risk,
+    Get "/api/courts/:district/cases/:id" => handlers::criminal_case_url::get_case_by_id,
+    Post "/api/courts/:district/cases" => handlers::criminal_case_url::create_case,
+    Post "/api/courts/:district/cases/:id/defendants" => handlers::criminal_case_url::add_defendant,
+    Post "/api/courts/:district/cases/:id/defendants/:defendant_id/charges" => handlers::criminal_case_url::add_charge,
+    Patch "/api/courts/:district/cases/:id/defendants/:defendant_id/custody" => handlers::criminal_case_url::update_defendant_custody,
+    Post "/api/courts/:district/cases/:id/evidence" => handlers::criminal_case_url::add_evidence,
+    Post "/api/courts/:district/cases/:id/notes" => handlers::criminal_case_url::add_note,
+    Post "/api/courts/:district/cases/:id/plea" => handlers::criminal_case_url::enter_plea,
+    Post "/api/courts/:district/cases/:id/events" => handlers::criminal_case_url::schedule_court_event,
+    Post "/api/courts/:district/cases/:id/motions" => handlers::criminal_case_url::file_motion,
+    Patch "/api/courts/:district/cases/:id/motions/ruling" => handlers::criminal_case_url::rule_on_motion,
+    Patch "/api/courts/:district/cases/:id/status" => handlers::criminal_case_url::update_case_status,
+    Patch "/api/courts/:district/cases/:id/priority" => handlers::criminal_case_url::update_case_priority,
+    Delete "/api/courts/:district/cases/:id" => handlers::criminal_case_url::delete_case,
+
+    // Evidence custody transfers - URL-based (Phase 2)
+    Post "/api/courts/:district/cases/:id/evidence/:evidence_id/custody" => handlers::criminal_case_url::add_custody_transfer,
+
+    // Docket entries on cases - URL-based (Phase 1)
+    Post "/api/courts/:district/cases/:id/docket" => handlers::criminal_case_url::add_docket_entry,
+    Get "/api/courts/:district/cases/:id/docket" => handlers::criminal_case_url::get_docket_entries,
+
+    // Sealed case management - URL-based (Phase 3)
+    Post "/api/courts/:district/cases/:id/seal" => handlers::criminal_case_url::seal_case,
+    Post "/api/courts/:district/cases/:id/unseal" => handlers::criminal_case_url::unseal_case,
+    Post "/api/courts/:district/cases/:id/archive" => handlers::criminal_case_url::archive_case,
+    Get "/api/courts/:district/cases/:id/archive" => handlers::criminal_case_url::get_case_archive,
+    Post "/api/courts/:district/cases/:id/unarchive" => handlers::criminal_case_url::unarchive_case,
+
+    // Speedy trial clock on cases - URL-based (Phase 4)
+    Post "/api/courts/:district/cases/:id/speedy-trial/start" => handlers::criminal_case_url::start_speedy_trial,
+    Post "/api/courts/:district/cases/:id/speedy-trial/exclude" => handlers::criminal_case_url::add_case_excludable_delay,
+    Get "/api/courts/:district/cases/:id/speedy-trial" => handlers::criminal_case_url::get_case_speedy_trial,
+    Post "/api/courts/:district/cases/:id/limitations/tolling" => handlers::criminal_case_url::record_limitations_tolling,
+    Get "/api/courts/:district/cases/:case_id/delay-attribution" => handlers::criminal_case_url::get_delay_attribution,
+
+    // CVRA victim management - URL-based (Phase 5)
+    Post "/api/courts/:district/cases/:id/victims" => handlers::criminal_case_url::add_victim,
+    Get "/api/courts/:district/cases/:id/victims" => handlers::criminal_case_url::get_victims,
+    Post "/api/courts/:district/cases/:id/victims/:victim_id/notifications" => handlers::criminal_case_url::send_victim_notification,
+
+    // Magistrate judge consent - URL-based
+    Post "/api/courts/:district/cases/:id/magistrate-consent" => handlers::criminal_case_url::record_magistrate_consent,
+    Get "/api/courts/:district/cases/:id/magistrate-consent" => handlers::criminal_case_url::get_magistrate_consent,
+
+    // Notices of appeal - URL-based
+    Post "/api/courts/:district/cases/:id/appeals" => handlers::criminal_case_url::file_appeal,
+    Get "/api/courts/:district/cases/:id/appeals" => handlers::criminal_case_url::get_appeals,
+    Patch "/api/courts/:district/appeals/:id/status" => handlers::criminal_case_url::update_appeal_status,
+
+    // Judge Management API endpoints
+    Post "/api/judges" => handlers::judge::create_judge,
+    Get "/api/judges" => handlers::judge::get_all_judges,
+    Get "/api/judges/available" => handlers::judge::get_available_judges,
+    Get "/api/judges/workload" => handlers::judge::get_workload_stats,
+    Get "/api/judges/workload/by-type" => handlers::judge::get_workload_by_type,
+    Get "/api/judges/search" => handlers::judge::search_judges,
+    Get "/api/judges/:id" => handlers::judge::get_judge_by_id,
+    Get "/api/judges/:judge_id/action-queue" => handlers::judge::get_action_queue,
+    Get "/api/judges/:judge_id/weekly-docket" => handlers::judge::get_weekly_docket,
+    Patch "/api/judges/:id/status" => handlers::judge::update_judge_status,
+    Post "/api/judges/:judge_id/conflicts" => handlers::judge::add_conflict,
+    Get "/api/judges/conflicts/check/:party" => handlers::judge::check_conflicts,
+
+    // Case Assignment endpoints
+    Post "/api/assignments" => handlers::judge::assign_case,
+    Post "/api/assignments/preview" => handlers::judge::preview_assignment,
+    Get "/api/assignments/case/:case_id" => handlers::judge::get_case_assignment,
+
+    // Recusal endpoints
+    Post "/api/judges/:judge_id/recusals" => handlers::judge::file_recusal,
+    Patch "/api/recusals/:recusal_id/ruling" => handlers::judge::rule_on_recusal,
+    Get "/api/recusals/pending" => handlers::judge::get_pending_recusals,
+
+    // Judge Management API endpoints (URL-based - NEW)
+    Post "/api/courts/:district/judges" => handlers::judge_url::create_judge,
+    Get "/api/courts/:district/judges" => handlers::judge_url::get_all_judges,
+    Get "/api/courts/:district/judges/available" => handlers::judge_url::get_available_judges,
+    Get "/api/courts/:district/judges/workload" => handlers::judge_url::get_workload_stats,
+    Get "/api/courts/:district/judges/workload/by-type" => handlers::judge_url::get_workload_by_type,
+    Get "/api/courts/:district/judges/search" => handlers::judge_url::search_judges,
+    Get "/api/courts/:district/judges/:id" => handlers::judge_url::get_judge_by_id,
+    Get "/api/courts/:district/judges/:judge_id/action-queue" => handlers::judge_url::get_action_queue,
+    Get "/api/courts/:district/judges/:judge_id/weekly-docket" => handlers::judge_url::get_weekly_docket,
+    Patch "/api/courts/:district/judges/:id/status" => handlers::judge_url::update_judge_status,
+    Post "/api/courts/:district/judges/:judge_id/conflicts" => handlers::judge_url::add_conflict,
+    Get "/api/courts/:district/judges/conflicts/check/:party" => handlers::judge_url::check_conflicts,
+    Post "/api/courts/:district/judges/:judge_id/unavailability" => handlers::judge_url::add_unavailability,
+    Get "/api/courts/:district/judges/:judge_id/unavailability" => handlers::judge_url::get_unavailability,
+    Delete "/api/courts/:district/judges/:judge_id/unavailability/:unavailability_id" => handlers::judge_url::cancel_unavailability,
+
+    // Case Assignment endpoints (URL-based - NEW)
+    Post "/api/courts/:district/assignments" => handlers::judge_url::assign_case,
+    Post "/api/courts/:district/assignments/preview" => handlers::judge_url::preview_assignment,
+    Get "/api/courts/:district/assignments/case/:case_id" => handlers::judge_url::get_case_assignment,
+
+    // Recusal endpoints (URL-based - NEW)
+    Post "/api/courts/:district/judges/:judge_id/recusals" => handlers::judge_url::file_recusal,
+    Patch "/api/courts/:district/recusals/:recusal_id/ruling" => handlers::judge_url::rule_on_recusal,
+    Get "/api/courts/:district/recusals/pending" => handlers::judge_url::get_pending_recusals,
+
+    // Rules Engine API endpoints (Header-based)
+    Post "/api/rules" => handlers::rules::create_rule,
+    Get "/api/rules" => handlers::rules::list_rules,
+    Get "/api/rules/category/:category" => handlers::rules::get_rules_by_category,
+    Get "/api/rules/trigger/:trigger" => handlers::rules::get_rules_by_trigger,
+    Get "/api/rules/jurisdiction/:jurisdiction" => handlers::rules::get_active_rules_for_jurisdiction,
+    Post "/api/rules/evaluate" => handlers::rules::evaluate_rules,
+    Get "/api/rules/:id" => handlers::rules::get_rule,
+    Put "/api/rules/:id" => handlers::rules::update_rule,
+    Delete "/api/rules/:id" => handlers::rules::delete_rule,
+
+    // Rules Engine API endpoints (URL-based)
+    Post "/api/courts/:district/rules" => handlers::rules_url::create_rule,
+    Get "/api/courts/:district/rules" => handlers::rules_url::list_rules,
+    Get "/api/courts/:district/rules/category/:category" => handlers::rules_url::get_rules_by_category,
+    Get "/api/courts/:district/rules/trigger/:trigger" => handlers::rules_url::get_rules_by_trigger,
+    Get "/api/courts/:district/rules/jurisdiction/:jurisdiction" => handlers::rules_url::get_active_rules_for_jurisdiction,
+    Post "/api/courts/:district/rules/evaluate" => handlers::rules_url::evaluate_rules,
+    Get "/api/courts/:district/rules/:id" => handlers::rules_url::get_rule,
+    Put "/api/courts/:district/rules/:id" => handlers::rules_url::update_rule,
+    Delete "/api/courts/:district/rules/:id" => handlers::rules_url::delete_rule,
+
+    // Docket Management API endpoints
+    Post "/api/docket/entries" => handlers::docket::create_docket_entry,
+    Get "/api/docket/case/:case_id" => handlers::docket::get_case_docket,
+    Get "/api/docket/entries/:id" => handlers::docket::get_docket_entry,
+    Post "/api/docket/entries/:entry_id/attachments" => handlers::docket::add_attachment,
+    Post "/api/docket/entries/:entry_id/attachments/upload" => handlers::docket::upload_attachment,
+    Get "/api/docket/attachments/:id/download" => handlers::docket::download_attachment,
+    Get "/api/docket/search" => handlers::docket::search_docket,
+    Get "/api/docket/sheet/:case_id" => handlers::docket::generate_docket_sheet,
+
+    // Calendar Management endpoints
+    Post "/api/calendar/events" => handlers::docket::schedule_event,
+    Get "/api/calendar/case/:case_id" => handlers::docket::get_case_calendar,
+    Get "/api/calendar/judge/:judge_id" => handlers::docket::get_judge_schedule,
+    Patch "/api/calendar/events/:event_id/status" => handlers::docket::update_event_status,
+    Get "/api/calendar/events/:event_id/notification-list" => handlers::docket::get_event_notification_list,
+    Get "/api/calendar/events/:event_id/minute-entry-draft" => handlers::docket::get_minute_entry_draft,
+    Get "/api/calendar/available-slot/:judge_id" => handlers::docket::find_available_slot,
+    Get "/api/calendar/utilization" => handlers::docket::get_courtroom_utilization,
+
+    // Speedy Trial Act endpoints
+    Post "/api/speedy-trial/:case_id" => handlers::docket::init_speedy_trial,
+    Get "/api/speedy-trial/:case_id" => handlers::docket::get_speedy_trial,
+    Post "/api/speedy-trial/:case_id/delays" => handlers::docket::add_excludable_delay,
+    Get "/api/speedy-trial/approaching" => handlers::docket::get_approaching_deadlines,
+
+    // Deadline Management API endpoints
+    Post "/api/deadlines" => handlers::deadline::create_deadline,
+    Get "/api/deadlines/case/:case_id" => handlers::deadline::get_case_deadlines,
+    Get "/api/deadlines/:id" => handlers::deadline::get_deadline,
+    Post "/api/deadlines/:id/complete" => handlers::deadline::complete_deadline,
+    Get "/api/deadlines/:id/dependents" => handlers::deadline::get_deadline_dependents,
+    Put "/api/deadlines/:id/dependency" => handlers::deadline::set_deadline_dependency,
+    Get "/api/deadlines/upcoming" => handlers::deadline::get_upcoming_deadlines,
+    Get "/api/deadlines/urgent" => handlers::deadline::get_urgent_deadlines,
+    Get "/api/deadlines/search" => handlers::deadline::search_deadlines,
+    Post "/api/deadlines/refresh-statuses" => handlers::deadline::refresh_deadline_statuses,
+    Post "/api/deadlines/calculate" => handlers::deadline::calculate_frcp_deadlines,
+
+    // Extension Management endpoints
+    Post "/api/deadlines/:deadline_id/extensions" => handlers::deadline::request_extension,
+    Patch "/api/extensions/:extension_id/ruling" => handlers::deadline::rule_on_extension,
+
+    // Compliance and Reporting endpoints
+    Get "/api/compliance/stats" => handlers::deadline::get_compliance_stats,
+    Get "/api/compliance/report" => handlers::deadline::generate_compliance_report,
+    Get "/api/compliance/performance" => handlers::deadline::get_performance_metrics,
+    Get "/api/compliance/missed-jurisdictional" => handlers::deadline::get_missed_jurisdictional,
+
+    // Reminder endpoints
+    Get "/api/reminders/pending" => handlers::deadline::get_pending_reminders,
+    Post "/api/reminders/send" => handlers::deadline::send_reminders,
+    Get "/api/reminders/deadline/:deadline_id" => handlers::deadline::get_reminders_by_deadline,
+    Get "/api/reminders/recipient/:recipient" => handlers::deadline::get_reminders_by_recipient,
+    Post "/api/reminders/:reminder_id/acknowledge" => handlers::deadline::acknowledge_reminder,
+
+    // Additional Deadline endpoints
+    Get "/api/deadlines/case/:case_id/type/:type" => handlers::deadline::get_deadlines_by_type,
+    Patch "/api/deadlines/:id/status" => handlers::deadline::update_deadline_status,
+    Delete "/api/deadlines/:id" => handlers::deadline::delete_deadline,
+
+    // Additional Judge endpoints
+    Get "/api/judges/status/:status" => handlers::judge::get_judges_by_status,
+    Get "/api/judges/district/:district" => handlers::judge::get_judges_by_district,
+    Delete "/api/judges/:id" => handlers::judge::delete_judge,
+    Get "/api/judges/vacation" => handlers::judge::get_judges_on_vacation,
+    Get "/api/judges/:judge_id/conflicts" => handlers::judge::get_conflicts_by_judge,
+    Get "/api/judges/:judge_id/conflicts/:party" => handlers::judge::has_conflict,
+    Delete "/api/judges/:judge_id/conflicts/:conflict_id" => handlers::judge::delete_conflict,
+    Post "/api/judges/:judge_id/unavailability" => handlers::judge::add_unavailability,
+    Get "/api/judges/:judge_id/unavailability" => handlers::judge::get_unavailability,
+    Delete "/api/judges/:judge_id/unavailability/:unavailability_id" => handlers::judge::cancel_unavailability,
+
+    // Additional Assignment endpoints
+    Get "/api/assignments/history/:case_id" => handlers::judge::get_assignment_history,
+    Delete "/api/assignments/:id" => handlers::judge::delete_assignment,
+
+    // Additional Recusal endpoints
+    Get "/api/recusals/case/:case_id" => handlers::judge::get_recusals_by_case,
+    Get "/api/recusals/judge/:judge_id" => handlers::judge::get_recusals_by_judge,
+    Post "/api/recusals/:recusal_id/process" => handlers::judge::process_recusal,
+
+    // Additional Docket endpoints
+    Get "/api/docket/case/:case_id/type/:type" => handlers::docket::get_entries_by_type,
+    Get "/api/docket/case/:case_id/sealed" => handlers::docket::get_sealed_entries,
+    Get "/api/docket/case/:case_id/search/:text" => handlers::docket::search_entries,
+    Delete "/api/docket/entries/:id" => handlers::docket::delete_entry,
+    Get "/api/docket/statistics/:case_id" => handlers::docket::get_filing_statistics,
+
+    // Additional Calendar endpoints
+    Get "/api/calendar/courtroom/:courtroom" => handlers::docket::get_events_by_courtroom,
+    Get "/api/calendar/daily" => handlers::docket::get_daily_calendar,
+    Delete "/api/calendar/events/:id" => handlers::docket::delete_event,
+
+    // Additional Speedy Trial endpoints
+    Get "/api/speedy-trial/violations" => handlers::docket::get_violations,
+    Patch "/api/speedy-trial/:case_id/clock" => handlers::docket::update_clock,
+
+    // Feature Management endpoints
+    Get "/api/features" => handlers::features::get_features,
+    Patch "/api/features" => handlers::features::update_feature,
+    Get "/api/features/implementation" => handlers::features::get_implementation_status,
+    Patch "/api/features/implementation" => handlers::features::update_implementation,
+    Get "/api/features/blocked" => handlers::features::get_blocked_features,
+    Get "/api/features/ready" => handlers::features::get_ready_features,
+    Post "/api/features/manager" => handlers::features::create_feature_manager,
+    Get "/api/features/enabled/:feature_path" => handlers::features::is_feature_enabled,
+    Post "/api/features/override" => handlers::features::set_feature_override,
+    Delete "/api/features/overrides" => handlers::features::clear_feature_overrides,
+
+    // Additional Extension endpoints
+    Get "/api/extensions/:id" => handlers::deadline::get_extension_by_id,
+    Get "/api/extensions/deadline/:deadline_id" => handlers::deadline::get_extensions_by_deadline,
+    Get "/api/extensions/pending" => handlers::deadline::get_pending_extensions,
+
+    // Federal Rules endpoints
+    Get "/api/federal-rules" => handlers::deadline::get_federal_rules,
+
+    // Additional Calendar endpoints
+    Get "/api/calendar/search" => handlers::docket::search_calendar,
+
+    // Additional Docket service endpoints
+    Get "/api/docket/immediate-service/:entry_type" => handlers::docket::check_immediate_service,
+    Get "/api/speedy-trial/approaching/:case_id" => handlers::docket::check_deadline_approaching,
+
+    // Admin endpoints for multi-tenancy
+    Post "/api/admin/init-tenant" => handlers::admin::init_tenant,
+    Get "/api/admin/tenant-stats" => handlers::admin::get_tenant_stats,
+    Get "/api/admin/districts" => handlers::admin::list_districts,
+    Get "/api/admin/documents/index-audit" => handlers::admin::audit_document_index,
+    Post "/api/admin/recover-journals" => handlers::admin::recover_journals,
+    Get "/api/admin/export" => handlers::admin::export_tenant,
+    Post "/api/admin/import" => handlers::admin::import_tenant,
+    Get "/api/admin/webhooks" => handlers::admin::get_district_webhook,
+    Put "/api/admin/webhooks" => handlers::admin::update_district_webhook,
+
+    // Judicial Orders API endpoints
+    Post "/api/orders" => handlers::order::create_order,
+    Get "/api/orders" => handlers::order::list_orders,
+    Get "/api/orders/:order_id" => handlers::order::get_order,
+    Patch "/api/orders/:order_id" => handlers::order::update_order,
+    Delete "/api/orders/:order_id" => handlers::order::delete_order,
+    Post "/api/orders/:order_id/sign" => handlers::order::sign_order,
+    Post "/api/orders/:order_id/issue" => handlers::order::issue_order,
+    Post "/api/orders/:order_id/service" => handlers::order::add_service_record,
+    Get "/api/cases/:case_id/orders" => handlers::order::get_orders_by_case,
+    Get "/api/judges/:judge_id/orders" => handlers::order::get_orders_by_judge,
+    Get "/api/judges/:judge_id/orders/pending-signatures" => handlers::order::get_pending_signatures,
+    Get "/api/orders/expiring" => handlers::order::get_expiring_orders,
+    Get "/api/orders/expiring/notifications" => handlers::order::get_expiring_order_notifications,
+    Get "/api/orders/statistics" => handlers::order::get_order_statistics,
+    Post "/api/orders/from-template" => handlers::order::create_from_template,
+
+    // Order Templates endpoints
+    Post "/api/templates/orders" => handlers::order::create_template,
+    Get "/api/templates/orders" => handlers::order::list_templates,
+    Get "/api/templates/orders/active" => handlers::order::find_active_templates,
+    Get "/api/templates/orders/:template_id" => handlers::order::get_template,
+    Put "/api/templates/orders/:template_id" => handlers::order::update_template,
+    Delete "/api/templates/orders/:template_id" => handlers::order::delete_template,
+    Post "/api/templates/:template_id/generate-content" => handlers::order::generate_template_content,
+    Post "/api/templates/orders/:template_id/preview" => handlers::order::preview_template,
+
+    // Order helper method endpoints
+    Get "/api/orders/:id/is-expired" => handlers::order::check_order_expired,
+    Get "/api/orders/:id/requires-attention" => handlers::order::check_requires_attention,
+
+    // Judicial Opinions API endpoints
+    Post "/api/opinions" => handlers::opinion::create_opinion,
+    Get "/api/opinions" => handlers::opinion::list_opinions,
+    Get "/api/opinions/:opinion_id" => handlers::opinion::get_opinion,
+    Patch "/api/opinions/:opinion_id" => handlers::opinion::update_opinion,
+    Delete "/api/opinions/:opinion_id" => handlers::opinion::delete_opinion,
+    Post "/api/opinions/:opinion_id/file" => handlers::opinion::file_opinion,
+    Post "/api/opinions/:opinion_id/publish" => handlers::opinion::publish_opinion,
+    Post "/api/opinions/:opinion_id/errata" => handlers::opinion::file_errata,
+    Get "/api/opinions/:opinion_id/slip-opinion" => handlers::opinion::get_slip_opinion,
+    Post "/api/opinions/:opinion_id/votes" => handlers::opinion::add_judge_vote,
+    Post "/api/opinions/:opinion_id/citations" => handlers::opinion::add_citation,
+    Post "/api/opinions/:opinion_id/headnotes" => handlers::opinion::add_headnote,
+    Get "/api/cases/:case_id/opinions" => handlers::opinion::get_opinions_by_case,
+    Get "/api/judges/:judge_id/opinions" => handlers::opinion::get_opinions_by_author,
+    Get "/api/opinions/search" => handlers::opinion::search_opinions,
+    Get "/api/opinions/search/ranked" => handlers::opinion::search_opinions_ranked,
+    Get "/api/opinions/precedential" => handlers::opinion::get_precedential_opinions,
+    Get "/api/opinions/statistics" => handlers::opinion::get_opinion_statistics,
+    Get "/api/opinions/citations/statistics" => handlers::opinion::get_citation_statistics,
+
+    // Opinion Drafts endpoints
+    Post "/api/opinions/:opinion_id/drafts" => handlers::opinion::create_draft,
+    Get "/api/opinions/:opinion_id/drafts" => handlers::opinion::get_drafts,
+    Get "/api/opinions/:opinion_id/drafts/current" => handlers::opinion::get_current_draft,
+    Get "/api/opinions/:opinion_id/drafts/:draft_id/diff" => handlers::opinion::get_draft_diff,
+    Post "/api/opinions/:opinion_id/drafts/:draft_id/comments" => handlers::opinion::add_draft_comment,
+    Patch "/api/opinions/:opinion_id/drafts/:draft_id/comments/:comment_id/resolve" => handlers::opinion::resolve_draft_comment,
+
+    // Opinion helper method endpoints
+    Get "/api/opinions/:id/is-majority" => handlers::opinion::is_majority_opinion,
+    Get "/api/opinions/:id/is-binding" => handlers::opinion::is_binding_opinion,
+    Get "/api/opinions/:id/calculate-statistics" => handlers::opinion::calculate_opinion_statistics,
+
+    // Opinion citation graph endpoints
+    Get "/api/opinions/:id/cited-by" => handlers::opinion::get_cited_by,
+    Get "/api/opinions/:id/citation-graph" => handlers::opinion::get_citation_graph,
+
+    // Federal Sentencing System endpoints
+    Post "/api/sentencing" => handlers::sentencing::create_sentencing,
+    Get "/api/sentencing/:id" => handlers::sentencing::get_sentencing,
+    Put "/api/sentencing/:id" => handlers::sentencing::update_sentencing,
+    Delete "/api/sentencing/:id" => handlers::sentencing::delete_sentencing,
+    Get "/api/sentencing/case/:case_id" => handlers::sentencing::find_by_case,
+    Get "/api/sentencing/defendant/:defendant_id" => handlers::sentencing::find_by_defendant,
+    Get "/api/sentencing/judge/:judge_id" => handlers::sentencing::find_by_judge,
+    Get "/api/sentencing/pending" => handlers::sentencing::find_pending,
+    Post "/api/sentencing/calculate-guidelines" => handlers::sentencing::calculate_guidelines,
+    Post "/api/sentencing/lookup-guidelines-range/batch" => handlers::sentencing::lookup_guidelines_range_batch,
+    Get "/api/sentencing/statistics/departures" => handlers::sentencing::get_departure_stats,
+    Get "/api/sentencing/statistics/variances" => handlers::sentencing::get_variance_stats,
+    Get "/api/sentencing/statistics/adjustments" => handlers::sentencing::get_adjustment_stats,
+    Post "/api/sentencing/:id/departure" => handlers::sentencing::add_departure,
+    Post "/api/sentencing/:id/variance" => handlers::sentencing::add_variance,
+    Get "/api/sentencing/substantial-assistance" => handlers::sentencing::get_substantial_assistance,
+    Post "/api/sentencing/:id/special-condition" => handlers::sentencing::add_special_condition,
+    Put "/api/sentencing/:id/supervised-release" => handlers::sentencing::update_supervised_release,
+    Get "/api/sentencing/active-supervision" => handlers::sentencing::find_active_supervision,
+    Post "/api/sentencing/:id/bop-designation" => handlers::sentencing::add_bop_designation,
+    Get "/api/sentencing/rdap-eligible" => handlers::sentencing::get_rdap_eligible,
+    Get "/api/sentencing/statistics/judge/:judge_id" => handlers::sentencing::get_judge_stats,
+    Get "/api/sentencing/statistics/judge/:judge_id/comparison" => handlers::sentencing::get_judge_comparison,
+    Get "/api/sentencing/statistics/district" => handlers::sentencing::get_district_stats,
+    Get "/api/sentencing/statistics/trial-penalty" => handlers::sentencing::get_trial_penalty,
+    Post "/api/sentencing/:id/prior-sentence" => handlers::sentencing::add_prior_sentence,
+    Get "/api/sentencing/upcoming/:days" => handlers::sentencing::find_upcoming,
+    Get "/api/sentencing/appeal-deadlines" => handlers::sentencing::find_appeal_deadlines,
+    Get "/api/sentencing/date-range" => handlers::sentencing::find_by_date_range,
+    Get "/api/sentencing/statistics/offense/:offense_type" => handlers::sentencing::get_offense_type_stats,
+    Get "/api/sentencing/:id/criminal-history-points" => handlers::sentencing::calculate_criminal_history_points,
+    Post "/api/sentencing/:id/calculate-offense-level" => handlers::sentencing::calculate_offense_level,
+    Post "/api/sentencing/:id/lookup-guidelines-range" => handlers::sentencing::lookup_guidelines_range,
+    Get "/api/sentencing/:id/safety-valve-eligible" => handlers::sentencing::check_safety_valve_eligible,
+    Get "/api/sentencing/:id/calculation-audit" => handlers::sentencing::get_calculation_audit,
+
+    // Webhook subscriptions
+    Post "/api/webhooks" => handlers::webhook::create_webhook,
+    Get "/api/webhooks" => handlers::webhook::list_webhooks,
+    Post "/api/webhooks/deliver-pending" => handlers::webhook::deliver_pending,
+    Delete "/api/webhooks/:id" => handlers::webhook::delete_webhook,
+    Get "/api/webhooks/:id/deliveries" => handlers::webhook::list_deliveries,
+
+    // Attorney Management API endpoints
+    Post "/api/attorneys" => handlers::attorney::create_attorney,
+    Get "/api/attorneys" => handlers::attorney::list_attorneys,
+    Get "/api/attorneys/search" => handlers::attorney::search_attorneys,
+    Get "/api/search" => handlers::search::global_search,
+    Get "/api/attorneys/bar-number/:bar_number" => handlers::attorney::get_attorney_by_bar_number,
+    Get "/api/attorneys/:id" => handlers::attorney::get_attorney,
+    Put "/api/attorneys/:id" => handlers::attorney::update_attorney,
+    Patch "/api/attorneys/:id" => handlers::attorney::patch_attorney,
+    Delete "/api/attorneys/:id" => handlers::attorney::delete_attorney,
+    Get "/api/attorneys/status/:status" => handlers::attorney::get_attorneys_by_status,
+    Get "/api/attorneys/firm/:firm_name" => handlers::attorney::get_attorneys_by_firm,
+
+    // Bar Admission endpoints
+    Post "/api/attorneys/:id/bar-admissions" => handlers::attorney::add_bar_admission,
+    Delete "/api/attorneys/:id/bar-admissions/:state" => handlers::attorney::remove_bar_admission,
+    Get "/api/attorneys/bar-state/:state" => handlers::attorney::get_attorneys_by_bar_state,
+
+    // Federal Admission endpoints
+    Post "/api/attorneys/:id/federal-admissions" => handlers::attorney::add_federal_admission,
+    Delete "/api/attorneys/:id/federal-admissions/:court" => handlers::attorney::remove_federal_admission,
+    Get "/api/attorneys/federal-court/:court" => handlers::attorney::get_attorneys_admitted_to_court,
+
+    // Pro Hac Vice endpoints
+    Post "/api/attorneys/:id/pro-hac-vice" => handlers::attorney::add_pro_hac_vice,
+    Patch "/api/attorneys/:id/pro-hac-vice/:case_id/status" => handlers::attorney::update_pro_hac_vice_status,
+    Post "/api/attorneys/:id/pro-hac-vice/:case_id/renew" => handlers::attorney::renew_pro_hac_vice,
+    Get "/api/attorneys/pro-hac-vice/active" => handlers::attorney::get_active_pro_hac_vice,
+    Get "/api/attorneys/pro-hac-vice/expiring" => handlers::attorney::get_expiring_pro_hac_vice,
+    Get "/api/attorneys/pro-hac-vice/case/:case_id" => handlers::attorney::get_pro_hac_vice_by_case,
+
+    // CJA Panel endpoints
+    Post "/api/attorneys/:id/cja-panel/:district" => handlers::attorney::add_to_cja_panel,
+    Delete "/api/attorneys/:id/cja-panel/:district" => handlers::attorney::remove_from_cja_panel,
+    Get "/api/attorneys/cja-panel/:district" => handlers::attorney::get_cja_panel_attorneys,
+    Post "/api/attorneys/:id/cja-appointments" => handlers::attorney::add_cja_appointment,
+    Get "/api/attorneys/:id/cja-appointments" => handlers::attorney::get_cja_appointments,
+    Get "/api/attorneys/cja/pending-vouchers" => handlers::attorney::get_pending_cja_vouchers,
+    Post "/api/attorneys/:id/cja-appointments/:appointment_id/voucher/line-items" => handlers::attorney::add_voucher_line_item,
+    Post "/api/attorneys/:id/cja-appointments/:appointment_id/voucher/submit" => handlers::attorney::submit_cja_voucher,
+    Post "/api/attorneys/:id/cja-appointments/:appointment_id/voucher/approve" => handlers::attorney::approve_cja_voucher,
+    Post "/api/attorneys/:id/cja-appointments/:appointment_id/voucher/pay" => handlers::attorney::pay_cja_voucher,
+    Post "/api/cja/bulk-appoint" => handlers::attorney::bulk_appoint_cja,
+
+    // ECF Registration endpoints
+    Put "/api/attorneys/:id/ecf-registration" => handlers::attorney::update_ecf_registration,
+    Get "/api/attorneys/:id/is-in-good-standing" => handlers::attorney::check_good_standing,
+    Get "/api/attorneys/:id/can-practice/:court" => handlers::attorney::check_federal_practice,
+    Get "/api/attorneys/:id/has-ecf-privileges" => handlers::attorney::check_ecf_privileges,
+    Post "/api/attorneys/:id/calculate-win-rate" => handlers::attorney::calculate_attorney_win_rate,
+    Get "/api/attorneys/ecf-access" => handlers::attorney::get_attorneys_with_ecf,
+    Delete "/api/attorneys/:id/ecf-access" => handlers::attorney::revoke_ecf_access,
+
+    // Disciplinary Action endpoints
+    Post "/api/attorneys/:id/disciplinary-actions" => handlers::attorney::add_disciplinary_action,
+    Get "/api/attorneys/:id/disciplinary-actions" => handlers::attorney::get_disciplinary_history,
+    Get "/api/attorneys/with-discipline" => handlers::attorney::get_attorneys_with_discipline,
+    Post "/api/attorneys/:id/reinstatement" => handlers::attorney::reinstate_attorney,
+
+    // Attorney-Case Relationship endpoints
+    Post "/api/attorneys/:attorney_id/cases" => handlers::attorney::assign_attorney_to_case,
+    Get "/api/attorneys/:attorney_id/cases" => handlers::attorney::get_attorney_cases,
+    Delete "/api/attorneys/:attorney_id/cases/:case_id" => handlers::attorney::remove_attorney_from_case,
+    Get "/api/attorneys/:attorney_id/case-load" => handlers::attorney::get_attorney_case_load,
+    Get "/api/attorneys/:attorney_id/representation-history" => handlers::attorney::get_attorney_representation_history,
+
+    // Attorney Conflict Checking endpoints
+    Post "/api/attorneys/:attorney_id/conflict-check" => handlers::attorney::check_attorney_conflicts,
+
+    // Attorney Management API endpoints (URL-based - NEW)
+    Post "/api/courts/:district/attorneys" => handlers::attorney_url::create_attorney,
+    Get "/api/courts/:district/attorneys" => handlers::attorney_url::list_attorneys,
+    Get "/api/courts/:district/attorneys/search" => handlers::attorney_url::search_attorneys,
+    Get "/api/courts/:district/attorneys/bar-number/:bar_number" => handlers::attorney_url::get_attorney_by_bar_number,
+    Get "/api/courts/:district/attorneys/:id" => handlers::attorney_url::get_attorney,
+    Put "/api/courts/:district/attorneys/:id" => handlers::attorney_url::update_attorney,
+    Patch "/api/courts/:district/attorneys/:id" => handlers::attorney_url::patch_attorney,
+    Delete "/api/courts/:district/attorneys/:id" => handlers::attorney_url::delete_attorney,
+    Get "/api/courts/:district/attorneys/status/:status" => handlers::attorney_url::get_attorneys_by_status,
+    Get "/api/courts/:district/attorneys/firm/:firm_name" => handlers::attorney_url::get_attorneys_by_firm,
+    Post "/api/courts/:district/attorneys/:id/bar-admissions" => handlers::attorney_url::add_bar_admission,
+    Delete "/api/courts/:district/attorneys/:id/bar-admissions/:state" => handlers::attorney_url::remove_bar_admission,
+    Get "/api/courts/:district/attorneys/bar-state/:state" => handlers::attorney_url::get_attorneys_by_bar_state,
+    Post "/api/courts/:district/attorneys/:id/federal-admissions" => handlers::attorney_url::add_federal_admission,
+    Delete "/api/courts/:district/attorneys/:id/federal-admissions/:court" => handlers::attorney_url::remove_federal_admission,
+    Get "/api/courts/:district/attorneys/federal-court/:court" => handlers::attorney_url::get_attorneys_admitted_to_court,
+    Post "/api/courts/:district/attorneys/:id/pro-hac-vice" => handlers::attorney_url::add_pro_hac_vice,
+    Patch "/api/courts/:district/attorneys/:id/pro-hac-vice/:case_id/status" => handlers::attorney_url::update_pro_hac_vice_status,
+    Post "/api/courts/:district/attorneys/:id/pro-hac-vice/:case_id/renew" => handlers::attorney_url::renew_pro_hac_vice,
+    Get "/api/courts/:district/attorneys/pro-hac-vice/active" => handlers::attorney_url::get_active_pro_hac_vice,
+    Get "/api/courts/:district/attorneys/pro-hac-vice/expiring" => handlers::attorney_url::get_expiring_pro_hac_vice,
+    Get "/api/courts/:district/attorneys/pro-hac-vice/case/:case_id" => handlers::attorney_url::get_pro_hac_vice_by_case,
+    Post "/api/courts/:district/attorneys/:id/cja-panel/:district" => handlers::attorney_url::add_to_cja_panel,
+    Delete "/api/courts/:district/attorneys/:id/cja-panel/:district" => handlers::attorney_url::remove_from_cja_panel,
+    Get "/api/courts/:district/attorneys/cja-panel/:district" => handlers::attorney_url::get_cja_panel_attorneys,
+    Post "/api/courts/:district/attorneys/:id/cja-appointments" => handlers::attorney_url::add_cja_appointment,
+    Get "/api/courts/:district/attorneys/:id/cja-appointments" => handlers::attorney_url::get_cja_appointments,
+    Get "/api/courts/:district/attorneys/cja/pending-vouchers" => handlers::attorney_url::get_pending_cja_vouchers,
+    Post "/api/courts/:district/attorneys/:id/cja-appointments/:appointment_id/voucher/line-items" => handlers::attorney_url::add_voucher_line_item,
+    Post "/api/courts/:district/attorneys/:id/cja-appointments/:appointment_id/voucher/submit" => handlers::attorney_url::submit_cja_voucher,
+    Post "/api/courts/:district/attorneys/:id/cja-appointments/:appointment_id/voucher/approve" => handlers::attorney_url::approve_cja_voucher,
+    Post "/api/courts/:district/attorneys/:id/cja-appointments/:appointment_id/voucher/pay" => handlers::attorney_url::pay_cja_voucher,
+    Post "/api/courts/:district/cja/bulk-appoint" => handlers::attorney_url::bulk_appoint_cja,
+    Put "/api/courts/:district/attorneys/:id/ecf-registration" => handlers::attorney_url::update_ecf_registration,
+    Get "/api/courts/:district/attorneys/:id/is-in-good-standing" => handlers::attorney_url::check_good_standing,
+    Get "/api/courts/:district/attorneys/:id/can-practice/:court" => handlers::attorney_url::check_federal_practice,
+    Get "/api/courts/:district/attorneys/:id/has-ecf-privileges" => handlers::attorney_url::check_ecf_privileges,
+    Post "/api/courts/:district/attorneys/:id/calculate-win-rate" => handlers::attorney_url::calculate_attorney_win_rate,
+    Get "/api/courts/:district/attorneys/ecf-access" => handlers::attorney_url::get_attorneys_with_ecf,
+    Delete "/api/courts/:district/attorneys/:id/ecf-access" => handlers::attorney_url::revoke_ecf_access,
+    Post "/api/courts/:district/attorneys/:id/disciplinary-actions" => handlers::attorney_url::add_disciplinary_action,
+    Get "/api/courts/:district/attorneys/:id/disciplinary-actions" => handlers::attorney_url::get_disciplinary_history,
+    Get "/api/courts/:district/attorneys/with-discipline" => handlers::attorney_url::get_attorneys_with_discipline,
+    Post "/api/courts/:district/attorneys/:id/reinstatement" => handlers::attorney_url::reinstate_attorney,
+    Post "/api/courts/:district/parties" => handlers::attorney_url::create_party,
+    Get "/api/courts/:district/parties/:id" => handlers::attorney_url::get_party,
+    Put "/api/courts/:district/parties/:id" => handlers::attorney_url::update_party,
+    Delete "/api/courts/:district/parties/:id" => handlers::attorney_url::delete_party,
+    Get "/api/courts/:district/parties/case/:case_id" => handlers::attorney_url::list_parties_by_case,
+    Get "/api/courts/:district/parties/attorney/:attorney_id" => handlers::attorney_url::list_parties_by_attorney,
+    Patch "/api/courts/:district/parties/:id/status" => handlers::attorney_url::update_party_status,
+    Get "/api/courts/:district/parties/:id/needs-service" => handlers::attorney_url::check_party_needs_service,
+    Get "/api/courts/:district/parties/:id/lead-counsel" => handlers::attorney_url::get_party_lead_counsel,
+    Get "/api/courts/:district/parties/:id/is-represented" => handlers::attorney_url::check_party_represented,
+    Get "/api/courts/:district/parties/unrepresented" => handlers::attorney_url::get_unrepresented_parties,
+    Post "/api/courts/:district/representations" => handlers::attorney_url::add_representation,
+    Post "/api/courts/:district/representations/:id/end" => handlers::attorney_url::end_representation,
+    Get "/api/courts/:district/representations/:id" => handlers::attorney_url::get_representation,
+    Get "/api/courts/:district/representations/attorney/:attorney_id/active" => handlers::attorney_url::get_active_representations,
+    Get "/api/courts/:district/representations/case/:case_id" => handlers::attorney_url::get_case_representations,
+    Post "/api/courts/:district/representations/substitute" => handlers::attorney_url::substitute_attorney,
+    Get "/api/courts/:district/cases/:case_id/continuity" => handlers::attorney_url::get_case_continuity,
+    Post "/api/courts/:district/service-records" => handlers::attorney_url::create_service_record,
+    Get "/api/courts/:district/service-records/document/:document_id" => handlers::attorney_url::get_service_by_document,
+    Get "/api/courts/:district/service-records/party/:party_id" => handlers::attorney_url::get_service_by_party,
+    Post "/api/courts/:district/service-records/:id/complete" => handlers::attorney_url::mark_service_completed,
+    Post "/api/courts/:district/conflict-checks" => handlers::attorney_url::create_conflict_check,
+    Get "/api/courts/:district/conflict-checks/attorney/:attorney_id" => handlers::attorney_url::get_attorney_conflicts,
+    Post "/api/courts/:district/conflict-checks/check" => handlers::attorney_url::check_party_conflicts,
+    Post "/api/courts/:district/conflict-checks/:id/clear" => handlers::attorney_url::clear_conflict,
+    Get "/api/courts/:district/conflict-checks/turnaround" => handlers::attorney_url::get_conflict_turnaround,
+    Get "/api/courts/:district/attorneys/:id/metrics" => handlers::attorney_url::get_attorney_metrics,
+    Get "/api/courts/:district/attorneys/:id/win-rate" => handlers::attorney_url::get_attorney_win_rate,
+    Get "/api/courts/:district/attorneys/:id/case-count" => handlers::attorney_url::get_attorney_case_count,
+    Get "/api/courts/:district/attorneys/top-performers" => handlers::attorney_url::get_top_attorneys,
+    Post "/api/courts/:district/attorneys/bulk/update-status" => handlers::attorney_url::bulk_update_status,
+    Post "/api/courts/:district/service-records/bulk/:document_id" => handlers::attorney_url::bulk_add_to_service,
+    Post "/api/courts/:district/representations/migrate" => handlers::attorney_url::migrate_representations,
+
+    // Docket Management API endpoints (URL-based - NEW) - 27 endpoints total
+    // Docket Entry Management (12 endpoints)
+    Post "/api/courts/:district/docket/entries" => handlers::docket_url::create_docket_entry,
+    Get "/api/courts/:district/docket/case/:case_id" => handlers::docket_url::get_case_docket,
+    Get "/api/courts/:district/docket/entries/:id" => handlers::docket_url::get_docket_entry,
+    Post "/api/courts/:district/docket/entries/:entry_id/attachments" => handlers::docket_url::add_attachment,
+    Post "/api/courts/:district/docket/entries/:entry_id/attachments/upload" => handlers::docket_url::upload_attachment,
+    Get "/api/courts/:district/docket/attachments/:id/download" => handlers::docket_url::download_attachment,
+    Get "/api/courts/:district/docket/search" => handlers::docket_url::search_docket,
+    Get "/api/courts/:district/docket/sheet/:case_id" => handlers::docket_url::generate_docket_sheet,
+    Get "/api/courts/:district/docket/case/:case_id/type/:type" => handlers::docket_url::get_entries_by_type,
+    Get "/api/courts/:district/docket/case/:case_id/sealed" => handlers::docket_url::get_sealed_entries,
+    Get "/api/courts/:district/docket/case/:case_id/search/:text" => handlers::docket_url::search_entries,
+    Delete "/api/courts/:district/docket/entries/:id" => handlers::docket_url::delete_entry,
+    Get "/api/courts/:district/docket/statistics/:case_id" => handlers::docket_url::get_filing_statistics,
+    Get "/api/courts/:district/docket/immediate-service/:entry_type" => handlers::docket_url::check_immediate_service,
+
+    // Calendar Management (10 endpoints)
+    Post "/api/courts/:district/calendar/events" => handlers::docket_url::schedule_event,
+    Get "/api/courts/:district/calendar/case/:case_id" => handlers::docket_url::get_case_calendar,
+    Get "/api/courts/:district/calendar/judge/:judge_id" => handlers::docket_url::get_judge_schedule,
+    Patch "/api/courts/:district/calendar/events/:event_id/status" => handlers::docket_url::update_event_status,
+    Get "/api/courts/:district/calendar/events/:event_id/notification-list" => handlers::docket_url::get_event_notification_list,
+    Get "/api/courts/:district/calendar/events/:event_id/minute-entry-draft" => handlers::docket_url::get_minute_entry_draft,
+    Get "/api/courts/:district/calendar/available-slot/:judge_id" => handlers::docket_url::find_available_slot,
+    Get "/api/courts/:district/calendar/utilization" => handlers::docket_url::get_courtroom_utilization,
+    Get "/api/courts/:district/calendar/courtroom/:courtroom" => handlers::docket_url::get_events_by_courtroom,
+    Get "/api/courts/:district/calendar/daily" => handlers::docket_url::get_daily_calendar,
+    Delete "/api/courts/:district/calendar/events/:id" => handlers::docket_url::delete_event,
+    Get "/api/courts/:district/calendar/search" => handlers::docket_url::search_calendar,
+
+    // Speedy Trial Management (6 endpoints)
+    Post "/api/courts/:district/speedy-trial/:case_id" => handlers::docket_url::init_speedy_trial,
+    Get "/api/courts/:district/speedy-trial/:case_id" => handlers::docket_url::get_speedy_trial,
+    Post "/api/courts/:district/speedy-trial/:case_id/delays" => handlers::docket_url::add_excludable_delay,
+    Get "/api/courts/:district/speedy-trial/approaching" => handlers::docket_url::get_approaching_deadlines,
+    Get "/api/courts/:district/speedy-trial/violations" => handlers::docket_url::get_violations,
+    Patch "/api/courts/:district/speedy-trial/:case_id/clock" => handlers::docket_url::update_clock,
+    Get "/api/courts/:district/speedy-trial/approaching/:case_id" => handlers::docket_url::check_deadline_approaching,
+
+    // Order Management API endpoints (URL-based - NEW) - 23 endpoints total
+    // Order Management (14 endpoints)
+    Post "/api/courts/:district/orders" => handlers::order_url::create_order,
+    Get "/api/courts/:district/orders" => handlers::order_url::list_orders,
+    Get "/api/courts/:district/orders/:order_id" => handlers::order_url::get_order,
+    Patch "/api/courts/:district/orders/:order_id" => handlers::order_url::update_order,
+    Delete "/api/courts/:district/orders/:order_id" => handlers::order_url::delete_order,
+    Post "/api/courts/:district/orders/:order_id/sign" => handlers::order_url::sign_order,
+    Post "/api/courts/:district/orders/:order_id/issue" => handlers::order_url::issue_order,
+    Post "/api/courts/:district/orders/:order_id/service" => handlers::order_url::add_service_record,
+    Get "/api/courts/:district/cases/:case_id/orders" => handlers::order_url::get_orders_by_case,
+    Get "/api/courts/:district/judges/:judge_id/orders" => handlers::order_url::get_orders_by_judge,
+    Get "/api/courts/:district/judges/:judge_id/orders/pending-signatures" => handlers::order_url::get_pending_signatures,
+    Get "/api/courts/:district/orders/expiring" => handlers::order_url::get_expiring_orders,
+    Get "/api/courts/:district/orders/expiring/notifications" => handlers::order_url::get_expiring_order_notifications,
+    Get "/api/courts/:district/orders/statistics" => handlers::order_url::get_order_statistics,
+    Post "/api/courts/:district/orders/from-template" => handlers::order_url::create_from_template,
+
+    // Order Template Management (7 endpoints)
+    Post "/api/courts/:district/templates/orders" => handlers::order_url::create_template,
+    Get "/api/courts/:district/templates/orders" => handlers::order_url::list_templates,
+    Get "/api/courts/:district/templates/orders/active" => handlers::order_url::find_active_templates,
+    Get "/api/courts/:district/templates/orders/:template_id" => handlers::order_url::get_template,
+    Put "/api/courts/:district/templates/orders/:template_id" => handlers::order_url::update_template,
+    Delete "/api/courts/:district/templates/orders/:template_id" => handlers::order_url::delete_template,
+    Post "/api/courts/:district/templates/:template_id/generate-content" => handlers::order_url::generate_template_content,
+    Post "/api/courts/:district/templates/orders/:template_id/preview" => handlers::order_url::preview_template,
+
+    // Order Status Checks (2 endpoints)
+    Get "/api/courts/:district/orders/:id/is-expired" => handlers::order_url::check_order_expired,
+    Get "/api/courts/:district/orders/:id/requires-attention" => handlers::order_url::check_requires_attention,
+
+    // ====================================================================
+    // Opinion Management - URL-based routing (26 endpoints)
+    // ====================================================================
+
+    // Opinion Management (13 endpoints)
+    Post "/api/courts/:district/opinions" => handlers::opinion_url::create_opinion,
+    Get "/api/courts/:district/opinions" => handlers::opinion_url::list_opinions,
+    Get "/api/courts/:district/opinions/:opinion_id" => handlers::opinion_url::get_opinion,
+    Patch "/api/courts/:district/opinions/:opinion_id" => handlers::opinion_url::update_opinion,
+    Delete "/api/courts/:district/opinions/:opinion_id" => handlers::opinion_url::delete_opinion,
+    Post "/api/courts/:district/opinions/:opinion_id/file" => handlers::opinion_url::file_opinion,
+    Post "/api/courts/:district/opinions/:opinion_id/publish" => handlers::opinion_url::publish_opinion,
+    Post "/api/courts/:district/opinions/:opinion_id/errata" => handlers::opinion_url::file_errata,
+    Get "/api/courts/:district/opinions/:opinion_id/slip-opinion" => handlers::opinion_url::get_slip_opinion,
+    Post "/api/courts/:district/opinions/:opinion_id/votes" => handlers::opinion_url::add_judge_vote,
+    Post "/api/courts/:district/opinions/:opinion_id/citations" => handlers::opinion_url::add_citation,
+    Post "/api/courts/:district/opinions/:opinion_id/headnotes" => handlers::opinion_url::add_headnote,
+    Get "/api/courts/:district/opinions/search" => handlers::opinion_url::search_opinions,
+    Get "/api/courts/:district/opinions/search/ranked" => handlers::opinion_url::search_opinions_ranked,
+
+    // Cross-Entity Opinion Queries (3 endpoints)
+    Get "/api/courts/:district/cases/:case_id/opinions" => handlers::opinion_url::get_opinions_by_case,
+    Get "/api/courts/:district/judges/:judge_id/opinions" => handlers::opinion_url::get_opinions_by_author,
+    Get "/api/courts/:district/opinions/precedential" => handlers::opinion_url::get_precedential_opinions,
+
+    // Draft Management (5 endpoints)
+    Post "/api/courts/:district/opinions/:opinion_id/drafts" => handlers::opinion_url::create_draft,
+    Get "/api/courts/:district/opinions/:opinion_id/drafts" => handlers::opinion_url::get_drafts,
+    Get "/api/courts/:district/opinions/:opinion_id/drafts/current" => handlers::opinion_url::get_current_draft,
+    Get "/api/courts/:district/opinions/:opinion_id/drafts/:draft_id/diff" => handlers::opinion_url::get_draft_diff,
+    Post "/api/courts/:district/opinions/:opinion_id/drafts/:draft_id/comments" => handlers::opinion_url::add_draft_comment,
+    Patch "/api/courts/:district/opinions/:opinion_id/drafts/:draft_id/comments/:comment_id/resolve" => handlers::opinion_url::resolve_draft_comment,
+
+    // Statistics & Validation (5 endpoints)
+    Get "/api/courts/:district/opinions/statistics" => handlers::opinion_url::get_opinion_statistics,
+    Get "/api/courts/:district/opinions/citations/statistics" => handlers::opinion_url::get_citation_statistics,
+    Get "/api/courts/:district/opinions/:id/is-majority" => handlers::opinion_url::is_majority_opinion,
+    Get "/api/courts/:district/opinions/:id/is-binding" => handlers::opinion_url::is_binding_opinion,
+    Get "/api/courts/:district/opinions/:id/calculate-statistics" => handlers::opinion_url::calculate_opinion_statistics,
+
+    // Citation Graph (2 endpoints)
+    Get "/api/courts/:district/opinions/:id/cited-by" => handlers::opinion_url::get_cited_by,
+    Get "/api/courts/:district/opinions/:id/citation-graph" => handlers::opinion_url::get_citation_graph,
+
+    // ====================================================================
+    // Deadline Management - URL-based routing (26 endpoints)
+    // ====================================================================
+
+    // Core Deadline Management (8 endpoints)
+    Post "/api/courts/:district/deadlines" => handlers::deadline_url::create_deadline,
+    Get "/api/courts/:district/deadlines/case/:case_id" => handlers::deadline_url::get_case_deadlines,
+    Get "/api/courts/:district/deadlines/:id" => handlers::deadline_url::get_deadline,
+    Post "/api/courts/:district/deadlines/:id/complete" => handlers::deadline_url::complete_deadline,
+    Get "/api/courts/:district/deadlines/:id/dependents" => handlers::deadline_url::get_deadline_dependents,
+    Put "/api/courts/:district/deadlines/:id/dependency" => handlers::deadline_url::set_deadline_dependency,
+    Get "/api/courts/:district/deadlines/upcoming" => handlers::deadline_url::get_upcoming_deadlines,
+    Get "/api/courts/:district/deadlines/urgent" => handlers::deadline_url::get_urgent_deadlines,
+    Get "/api/courts/:district/deadlines/search" => handlers::deadline_url::search_deadlines,
+    Post "/api/courts/:district/deadlines/refresh-statuses" => handlers::deadline_url::refresh_deadline_statuses,
+    Post "/api/courts/:district/deadlines/calculate" => handlers::deadline_url::calculate_frcp_deadlines,
+
+    // Extension Management (5 endpoints)
+    Post "/api/courts/:district/deadlines/:deadline_id/extensions" => handlers::deadline_url::request_extension,
+    Patch "/api/courts/:district/extensions/:extension_id/ruling" => handlers::deadline_url::rule_on_extension,
+    Get "/api/courts/:district/extensions/:id" => handlers::deadline_url::get_extension_by_id,
+    Get "/api/courts/:district/extensions/deadline/:deadline_id" => handlers::deadline_url::get_extensions_by_deadline,
+    Get "/api/courts/:district/extensions/pending" => handlers::deadline_url::get_pending_extensions,
+
+    // Compliance & Reporting (4 endpoints)
+    Get "/api/courts/:district/compliance/stats" => handlers::deadline_url::get_compliance_stats,
+    Get "/api/courts/:district/compliance/report" => handlers::deadline_url::generate_compliance_report,
+    Get "/api/courts/:district/compliance/performance" => handlers::deadline_url::get_performance_metrics,
+    Get "/api/courts/:district/compliance/missed-jurisdictional" => handlers::deadline_url::get_missed_jurisdictional,
+
+    // Reminder Management (5 endpoints)
+    Get "/api/courts/:district/reminders/pending" => handlers::deadline_url::get_pending_reminders,
+    Post "/api/courts/:district/reminders/send" => handlers::deadline_url::send_reminders,
+    Get "/api/courts/:district/reminders/deadline/:deadline_id" => handlers::deadline_url::get_reminders_by_deadline,
+    Get "/api/courts/:district/reminders/recipient/:recipient" => handlers::deadline_url::get_reminders_by_recipient,
+    Post "/api/courts/:district/reminders/:reminder_id/acknowledge" => handlers::deadline_url::acknowledge_reminder,
+
+    // Additional Deadline Operations (4 endpoints)
+    Get "/api/courts/:district/deadlines/case/:case_id/type/:type" => handlers::deadline_url::get_deadlines_by_type,
+    Patch "/api/courts/:district/deadlines/:id/status" => handlers::deadline_url::update_deadline_status,
+    Delete "/api/courts/:district/deadlines/:id" => handlers::deadline_url::delete_deadline,
+    Get "/api/courts/:district/federal-rules" => handlers::deadline_url::get_federal_rules,
+
+    // ====================================================================
+    // Sentencing Management - URL-based routing (31 endpoints)
+    // ====================================================================
+
+    // Core Sentencing Management (8 endpoints)
+    Post "/api/courts/:district/sentencing" => handlers::sentencing_url::create_sentencing,
+    Get "/api/courts/:district/sentencing/:id" => handlers::sentencing_url::get_sentencing,
+    Put "/api/courts/:district/sentencing/:id" => handlers::sentencing_url::update_sentencing,
+    Delete "/api/courts/:district/sentencing/:id" => handlers::sentencing_url::delete_sentencing,
+    Get "/api/courts/:district/sentencing/case/:case_id" => handlers::sentencing_url::find_by_case,
+    Get "/api/courts/:district/sentencing/defendant/:defendant_id" => handlers::sentencing_url::find_by_defendant,
+    Get "/api/courts/:district/sentencing/judge/:judge_id" => handlers::sentencing_url::find_by_judge,
+    Get "/api/courts/:district/sentencing/pending" => handlers::sentencing_url::find_pending,
+
+    // Guidelines Calculation (5 endpoints)
+    Post "/api/courts/:district/sentencing/calculate-guidelines" => handlers::sentencing_url::calculate_guidelines,
+    Post "/api/courts/:district/sentencing/lookup-guidelines-range/batch" => handlers::sentencing_url::lookup_guidelines_range_batch,
+    Get "/api/courts/:district/sentencing/:id/criminal-history-points" => handlers::sentencing_url::calculate_criminal_history_points,
+    Post "/api/courts/:district/sentencing/:id/calculate-offense-level" => handlers::sentencing_url::calculate_offense_level,
+    Post "/api/courts/:district/sentencing/:id/lookup-guidelines-range" => handlers::sentencing_url::lookup_guidelines_range,
+    Get "/api/courts/:district/sentencing/:id/safety-valve-eligible" => handlers::sentencing_url::check_safety_valve_eligible,
+    Get "/api/courts/:district/sentencing/:id/calculation-audit" => handlers::sentencing_url::get_calculation_audit,
+
+    // Departures & Variances (4 endpoints)
+    Get "/api/courts/:district/sentencing/statistics/departures" => handlers::sentencing_url::get_departure_stats,
+    Get "/api/courts/:district/sentencing/statistics/variances" => handlers::sentencing_url::get_variance_stats,
+    Get "/api/courts/:district/sentencing/statistics/adjustments" => handlers::sentencing_url::get_adjustment_stats,
+    Post "/api/courts/:district/sentencing/:id/departure" => handlers::sentencing_url::add_departure,
+    Post "/api/courts/:district/sentencing/:id/variance" => handlers::sentencing_url::add_variance,
+
+    // Substantial Assistance & Special Conditions (3 endpoints)
+    Get "/api/courts/:district/sentencing/substantial-assistance" => handlers::sentencing_url::get_substantial_assistance,
+    Post "/api/courts/:district/sentencing/:id/special-condition" => handlers::sentencing_url::add_special_condition,
+    Post "/api/courts/:district/sentencing/:id/prior-sentence" => handlers::sentencing_url::add_prior_sentence,
+
+    // Supervised Release & BOP (4 endpoints)
+    Put "/api/courts/:district/sentencing/:id/supervised-release" => handlers::sentencing_url::update_supervised_release,
+    Get "/api/courts/:district/sentencing/active-supervision" => handlers::sentencing_url::find_active_supervision,
+    Post "/api/courts/:district/sentencing/:id/bop-designation" => handlers::sentencing_url::add_bop_designation,
+    Get "/api/courts/:district/sentencing/rdap-eligible" => handlers::sentencing_url::get_rdap_eligible,
+
+    // Statistics & Reporting (5 endpoints)
+    Get "/api/courts/:district/sentencing/statistics/judge/:judge_id" => handlers::sentencing_url::get_judge_stats,
+    Get "/api/courts/:district/sentencing/statistics/judge/:judge_id/comparison" => handlers::sentencing_url::get_judge_comparison,
+    Get "/api/courts/:district/sentencing/statistics/district" => handlers::sentencing_url::get_district_stats,
+    Get "/api/courts/:district/sentencing/statistics/trial-penalty" => handlers::sentencing_url::get_trial_penalty,
+    Get "/api/courts/:district/sentencing/statistics/offense/:offense_type" => handlers::sentencing_url::get_offense_type_stats,
+    Get "/api/courts/:district/sentencing/date-range" => handlers::sentencing_url::find_by_date_range,
+
+    // Upcoming & Appeals (2 endpoints)
+    Get "/api/courts/:district/sentencing/upcoming/:days" => handlers::sentencing_url::find_upcoming,
+    Get "/api/courts/:district/sentencing/appeal-deadlines" => handlers::sentencing_url::find_appeal_deadlines,
+
+    // Party Management endpoints
+    Post "/api/parties" => handlers::attorney::create_party,
+    Get "/api/parties/:id" => handlers::attorney::get_party,
+    Put "/api/parties/:id" => handlers::attorney::update_party,
+    Delete "/api/parties/:id" => handlers::attorney::delete_party,
+    Get "/api/parties/case/:case_id" => handlers::attorney::list_parties_by_case,
+    Get "/api/parties/attorney/:attorney_id" => handlers::attorney::list_parties_by_attorney,
+    Patch "/api/parties/:id/status" => handlers::attorney::update_party_status,
+    Get "/api/parties/:id/needs-service" => handlers::attorney::check_party_needs_service,
+    Get "/api/parties/:id/lead-counsel" => handlers::attorney::get_party_lead_counsel,
+    Get "/api/parties/:id/is-represented" => handlers::attorney::check_party_represented,
+    Get "/api/parties/unrepresented" => handlers::attorney::get_unrepresented_parties,
+
+    // Representation endpoints
+    Post "/api/representations" => handlers::attorney::add_representation,
+    Post "/api/representations/:id/end" => handlers::attorney::end_representation,
+    Get "/api/representations/:id" => handlers::attorney::get_representation,
+    Get "/api/representations/attorney/:attorney_id/active" => handlers::attorney::get_active_representations,
+    Get "/api/representations/case/:case_id" => handlers::attorney::get_case_representations,
+    Post "/api/representations/substitute" => handlers::attorney::substitute_attorney,
+    Get "/api/cases/:case_id/continuity" => handlers::attorney::get_case_continuity,
+
+    // Service Records endpoints
+    Post "/api/service-records" => handlers::attorney::create_service_record,
+    Get "/api/service-records/document/:document_id" => handlers::attorney::get_service_by_document,
+    Get "/api/service-records/party/:party_id" => handlers::attorney::get_service_by_party,
+    Post "/api/service-records/:id/complete" => handlers::attorney::mark_service_completed,
+
+    // Conflict Checking endpoints
+    Post "/api/conflict-checks" => handlers::attorney::create_conflict_check,
+    Get "/api/conflict-checks/attorney/:attorney_id" => handlers::attorney::get_attorney_conflicts,
+    Post "/api/conflict-checks/check" => handlers::attorney::check_party_conflicts,
+    Post "/api/conflict-checks/:id/clear" => handlers::attorney::clear_conflict,
+    Get "/api/conflict-checks/turnaround" => handlers::attorney::get_conflict_turnaround,
+
+    // Attorney Metrics endpoints
+    Get "/api/attorneys/:id/metrics" => handlers::attorney::get_attorney_metrics,
+    Get "/api/attorneys/:id/win-rate" => handlers::attorney::get_attorney_win_rate,
+    Get "/api/attorneys/:id/case-count" => handlers::attorney::get_attorney_case_count,
+    Get "/api/attorneys/top-performers" => handlers::attorney::get_top_attorneys,
+
+    // Bulk Operations endpoints
+    Post "/api/attorneys/bulk/update-status" => handlers::attorney::bulk_update_status,
+    Post "/api/service-records/bulk/:document_id" => handlers::attorney::bulk_add_to_service,
+    Post "/api/representations/migrate" => handlers::attorney::migrate_representations,
+
+    // PDF Generation endpoints - Court Orders (Hexagonal Architecture)
+    // Format parameter: 'pdf' for raw PDF, 'json' for base64-encoded JSON
+    Post "/api/pdf/rule16b/:format" => handlers::pdf_hexagonal::generate_rule16b,
+    Post "/api/pdf/rule16b" => handlers::pdf_hexagonal::generate_rule16b, // Default to JSON
+    Post "/api/pdf/signed/rule16b/:format" => handlers::pdf_hexagonal::generate_signed_rule16b,
+    Post "/api/pdf/signed/rule16b" => handlers::pdf_hexagonal::generate_signed_rule16b, // Default to JSON
+    Post "/api/pdf/signed/court-order/:format" => handlers::pdf_hexagonal::generate_signed_court_order,
+    Post "/api/pdf/signed/court-order" => handlers::pdf_hexagonal::generate_signed_court_order, // Default to JSON
+    Post "/api/pdf/signed/minute-entry/:format" => handlers::pdf_hexagonal::generate_signed_minute_entry,
+    Post "/api/pdf/signed/minute-entry" => handlers::pdf_hexagonal::generate_signed_minute_entry, // Default to JSON
+    Post "/api/pdf/court-order/:format" => handlers::pdf_hexagonal::generate_court_order,
+    Post "/api/pdf/court-order" => handlers::pdf_hexagonal::generate_court_order, // Default to JSON
+    Post "/api/pdf/minute-entry/:format" => handlers::pdf_hexagonal::generate_minute_entry,
+    Post "/api/pdf/minute-entry" => handlers::pdf_hexagonal::generate_minute_entry, // Default to JSON
+    // Auto-generation endpoints (TODO: implement in hexagonal)
+    // Get "/api/pdf/auto/rule-16b/:case_id" => auto_generate_rule_16b,
+    // Post "/api/pdf/judge-signature" => upload_judge_signature,
+
+    // PDF Generation endpoints - Federal Forms
+    Post "/api/pdf/waiver-indictment/:format" => handlers::pdf_hexagonal::generate_waiver_indictment,
+    Post "/api/pdf/waiver-indictment" => handlers::pdf_hexagonal::generate_waiver_indictment, // Default to JSON
+    Post "/api/pdf/conditions-release/:format" => handlers::pdf_hexagonal::generate_conditions_release,
+    Post "/api/pdf/conditions-release" => handlers::pdf_hexagonal::generate_conditions_release, // Default to JSON
+    Post "/api/pdf/criminal-judgment/:format" => handlers::pdf_hexagonal::generate_criminal_judgment,
+    Post "/api/pdf/criminal-judgment" => handlers::pdf_hexagonal::generate_criminal_judgment, // Default to JSON
+    Post "/api/pdf/subpoena/:format" => handlers::pdf_hexagonal::generate_subpoena,
+    Post "/api/pdf/subpoena" => handlers::pdf_hexagonal::generate_subpoena, // Default to JSON
+    Post "/api/cases/:case_id/summons/:format" => handlers::pdf_hexagonal::generate_summons,
+    Post "/api/cases/:case_id/summons" => handlers::pdf_hexagonal::generate_summons, // Default to JSON
+    // Auto-generation endpoints (TODO: migrate to hexagonal)
+    // Get "/api/pdf/auto/waiver-indictment/:case_id" => auto_generate_waiver,
+    // Get "/api/pdf/auto/conditions-release/:case_id" => auto_generate_conditions,
+    // Get "/api/pdf/auto/criminal-judgment/:case_id" => auto_generate_judgment,
+
+    // Batch PDF Generation (Hexagonal Architecture)
+    Post "/api/pdf/batch" => handlers::pdf_hexagonal::generate_batch_pdfs,
+    Post "/api/pdf/batch/zip" => handlers::pdf_hexagonal::generate_batch_pdfs_zip,
+
+    // Signature Management endpoints
+    Post "/api/signatures" => handlers::pdf_hexagonal::store_signature,
+    Get "/api/signatures/:judge_id" => handlers::pdf_hexagonal::get_signature,
+    Get "/api/signatures/:judge_id/verify" => handlers::pdf_hexagonal::verify_signature,
+
+    // Filing Pipeline API endpoints (Header-based)
+    Get "/api/filings/jurisdictions" => handlers::filing::list_jurisdictions,
+    Post "/api/filings/validate" => handlers::filing::validate_filing,
+    Post "/api/filings" => handlers::filing::submit_filing,
+
+    // Filing Pipeline API endpoints (URL-based)
+    Get "/api/courts/:district/filings/jurisdictions" => handlers::filing_url::list_jurisdictions,
+    Post "/api/courts/:district/filings/validate" => handlers::filing_url::validate_filing,
+    Post "/api/courts/:district/filings" => handlers::filing_url::submit_filing,
+
+    // Documentation endpoints
+    Get "/docs/openapi-description.json" => handlers::docs::get_openapi_description,
+    Get "/docs/*" => handlers::docs::render_openapi_docs_ui,
+];
+
+/// A router with every route in [`ROUTES`] registered
+pub fn router() -> Router {
+    let mut router = Router::default();
+    for route in ROUTES {
+        match route.method {
+            Method::Get => router.get(route.path, route.handler),
+            Method::Post => router.post(route.path, route.handler),
+            Method::Put => router.put(route.path, route.handler),
+            Method::Patch => router.patch(route.path, route.handler),
+            Method::Delete => router.delete(route.path, route.handler),
+        }
+    }
+    router
+}
+
+/// OpenAPI paths that document a router path
+///
+/// The path itself, with `:param` written as `{param}`, is always a match.
+/// URL-based routes, `/api/courts/:district/...`, are also documented by
+/// their header-based twin under `/api/...`. A PDF route without its
+/// trailing `:format` is documented by the form with it, since the format
+/// defaults to JSON.
+#[cfg(test)]
+pub fn documenting_paths(path: &str) -> Vec<String> {
+    let path = path
+        .split('/')
+        .map(|segment| match segment.strip_prefix(':') {
+            Some(name) => format!("{{{}}}", name),
+            None => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    let mut paths = vec![path.clone()];
+    if let Some(rest) = path.strip_prefix("/api/courts/{district}/") {
+        paths.push(format!("/api/{}", rest));
+    }
+    let with_format: Vec<String> = paths.iter().map(|p| format!("{}/{{format}}", p)).collect();
+    paths.extend(with_format);
+    paths
+}
+
+/// Routes the OpenAPI document doesn't describe, as `METHOD /path`
+///
+/// The documentation routes under `/docs/` are not API routes and are
+/// skipped.
+#[cfg(test)]
+pub fn undocumented_routes(openapi: &OpenApi) -> Vec<String> {
+    ROUTES
+        .iter()
+        .filter(|route| !route.path.starts_with("/docs/"))
+        .filter(|route| {
+            !documenting_paths(route.path).iter().any(|path| {
+                openapi
+                    .paths
+                    .paths
+                    .get(path)
+                    .is_some_and(|item| route.method.is_documented_in(item))
+            })
+        })
+        .map(|route| format!("{} {}", route.method.as_str(), route.path))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_route_is_documented() {
+        let missing = undocumented_routes(&handlers::docs::openapi_document());
+        assert!(
+            missing.is_empty(),
+            "{} route(s) are served but missing from the OpenAPI document. Add a \
+             #[utoipa::path] annotation to each handler and list it in paths(...) \
+             in src/handlers/docs.rs:\n  {}",
+            missing.len(),
+            missing.join("\n  ")
+        );
+    }
+
+    #[test]
+    fn test_documenting_paths() {
+        assert_eq!(documenting_paths("/api/cases/:id"), vec!["/api/cases/{id}", "/api/cases/{id}/{format}"]);
+        assert!(documenting_paths("/api/courts/:district/cases/:id").contains(&"/api/cases/{id}".to_string()));
+        assert!(documenting_paths("/api/pdf/rule16b").contains(&"/api/pdf/rule16b/{format}".to_string()));
+    }
+
+    #[test]
+    fn test_routes_are_unique() {
+        let mut seen = std::collections::HashSet::new();
+        for route in ROUTES {
+            assert!(
+                seen.insert((route.method, route.path)),
+                "{} {} is registered twice",
+                route.method.as_str(),
+                route.path
+            );
+        }
+    }
+}
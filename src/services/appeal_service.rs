@@ -0,0 +1,180 @@
+//! Notices of appeal
+//!
+//! Filing a notice records an [`Appeal`] on the case, dockets the notice,
+//! closes the filing party's own notice-of-appeal deadline, and schedules
+//! the record transmission and briefing deadlines that follow it. Each
+//! briefing deadline is chained to the one before so that moving one
+//! recalculates the rest. The case holds a pending appeal until the
+//! mandate issues.
+
+use crate::domain::appeal::{Appeal, AppealStatus, NoticeOfAppeal};
+use crate::domain::deadline::{Deadline, DeadlineCalculator, DeadlineRule, DeadlineStatus, DeadlineType};
+use crate::domain::docket::DocketEntryType;
+use crate::error::{ApiError, ApiResult};
+use crate::ports::case_repository::CaseRepository;
+use crate::ports::deadline_repository::{DeadlineRepository, DeadlineRuleRepository};
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+/// Events that follow a notice of appeal, each triggering the next
+/// deadline in the chain
+const APPELLATE_EVENTS: [&str; 4] = [
+    "notice_of_appeal_filed",
+    "record_on_appeal_filed",
+    "appellant_brief_filed",
+    "appellee_brief_filed",
+];
+
+/// Record a notice of appeal on a case and schedule its deadlines
+pub fn file_notice(
+    cases: &impl CaseRepository,
+    deadlines: &(impl DeadlineRepository + DeadlineRuleRepository),
+    case_id: Uuid,
+    notice: NoticeOfAppeal,
+    closures: &[NaiveDate],
+) -> ApiResult<Appeal> {
+    if notice.appealed_from.trim().is_empty() {
+        return Err(ApiError::BadRequest("Appealed order or judgment cannot be empty".to_string()));
+    }
+    if notice.filed_date < notice.judgment_entered_date {
+        return Err(ApiError::BadRequest(
+            "Notice of appeal cannot be filed before the judgment is entered".to_string(),
+        ));
+    }
+
+    let mut case = cases
+        .find_by_id(case_id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Case with id {} not found", case_id)))?;
+
+    let opposing = notice.filing_party.opposing();
+    let opposing_notices: Vec<DateTime<Utc>> = case
+        .appeals
+        .iter()
+        .filter(|a| a.filing_party == opposing && a.appealed_from == notice.appealed_from)
+        .map(|a| a.filed_date)
+        .collect();
+    let mut appeal = Appeal::file(case_id, notice, opposing_notices, closures);
+
+    let party = appeal.filing_party.label();
+    let entry_id = case.add_docket_entry(
+        DocketEntryType::NoticeOfAppeal,
+        format!("Notice of Appeal by {} from {}", party, appeal.appealed_from),
+        Some(appeal.filed_by.clone().unwrap_or_else(|| party.to_string())),
+    );
+    if let Some(entry) = case.docket_entries.iter_mut().find(|e| e.id == entry_id) {
+        entry.date_filed = appeal.filed_date;
+    }
+    appeal.docket_entry_id = Some(entry_id);
+
+    let rules = appellate_rules(deadlines)?;
+    for deadline in appellate_deadlines(&rules, &appeal, closures) {
+        deadlines.save_deadline(&deadline)?;
+        appeal.deadline_ids.push(deadline.id);
+    }
+    complete_notice_deadlines(deadlines, &appeal)?;
+
+    case.appeals.push(appeal.clone());
+    case.refresh_pending_appeal();
+    case.updated_at = Utc::now();
+    cases.save(&case)?;
+    Ok(appeal)
+}
+
+/// Move an appeal to a later status; the mandate clears the case's
+/// pending flag once no other appeal is open
+pub fn update_status(
+    cases: &impl CaseRepository,
+    appeal_id: Uuid,
+    status: AppealStatus,
+    now: DateTime<Utc>,
+) -> ApiResult<Appeal> {
+    let not_found = || ApiError::NotFound(format!("Appeal with id {} not found", appeal_id));
+    let mut case = cases
+        .find_all_cases()?
+        .into_iter()
+        .find(|c| c.appeals.iter().any(|a| a.id == appeal_id))
+        .ok_or_else(not_found)?;
+
+    let appeal = case.appeals.iter_mut().find(|a| a.id == appeal_id).ok_or_else(not_found)?;
+    appeal.advance(status, now).map_err(ApiError::Conflict)?;
+    let appeal = appeal.clone();
+
+    case.refresh_pending_appeal();
+    case.updated_at = now;
+    cases.save(&case)?;
+    Ok(appeal)
+}
+
+/// The district's rules for the appellate events, with the built-in FRAP
+/// rules standing in for events the district has none for
+fn appellate_rules(deadlines: &impl DeadlineRuleRepository) -> ApiResult<Vec<DeadlineRule>> {
+    let district = deadlines.find_all_deadline_rules()?;
+    let defaults = DeadlineCalculator::default_rule_set();
+
+    let mut rules = Vec::new();
+    for event in APPELLATE_EVENTS {
+        let for_event = |r: &&DeadlineRule| r.triggering_event == event;
+        if district.iter().any(|r| for_event(&r)) {
+            rules.extend(district.iter().filter(for_event).cloned());
+        } else {
+            rules.extend(defaults.iter().filter(for_event).cloned());
+        }
+    }
+    Ok(rules)
+}
+
+/// Record and briefing deadlines for an appeal, each after the first
+/// depending on the deadline for the event that triggers it
+fn appellate_deadlines(rules: &[DeadlineRule], appeal: &Appeal, closures: &[NaiveDate]) -> Vec<Deadline> {
+    let mut chain: Vec<Deadline> = Vec::new();
+
+    for event in APPELLATE_EVENTS {
+        let (trigger, parent) = match chain.last() {
+            Some(parent) => (parent.due_date, Some(parent.id)),
+            None => (appeal.filed_date, None),
+        };
+        let mut next = DeadlineCalculator::calculate_from_rules(rules, event, trigger, closures);
+        if next.is_empty() {
+            break;
+        }
+
+        for deadline in &mut next {
+            deadline.case_id = appeal.case_id;
+            deadline.description = format!("{} ({})", deadline.description, appeal.appealed_from);
+            deadline.responsible_party = appellate_party(&deadline.responsible_party, appeal);
+            if parent.is_some() {
+                deadline.depends_on = parent;
+                deadline.offset_days = rules
+                    .iter()
+                    .find(|r| r.triggering_event == event && r.deadline_type == deadline.deadline_type)
+                    .map(|r| r.days);
+            }
+        }
+        chain.extend(next);
+    }
+
+    chain
+}
+
+/// Name the appellant or appellee for this appeal
+fn appellate_party(responsible_party: &str, appeal: &Appeal) -> String {
+    match responsible_party {
+        "Appellant" => appeal.filing_party.label().to_string(),
+        "Appellee" => appeal.filing_party.opposing().label().to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Mark the filing party's open notice-of-appeal deadlines as met
+fn complete_notice_deadlines(deadlines: &impl DeadlineRepository, appeal: &Appeal) -> ApiResult<()> {
+    let party = appeal.filing_party.label();
+    for mut deadline in deadlines.find_deadlines_by_type(appeal.case_id, DeadlineType::NoticeOfAppeal)? {
+        if deadline.is_closed() || !deadline.responsible_party.eq_ignore_ascii_case(party) {
+            continue;
+        }
+        deadline.status = DeadlineStatus::Completed;
+        deadline.completion_date = Some(appeal.filed_date);
+        deadlines.save_deadline(&deadline)?;
+    }
+    Ok(())
+}
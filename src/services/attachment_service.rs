@@ -0,0 +1,232 @@
+//! Docket attachment uploads
+//!
+//! Checks uploaded attachment files before they are stored and after they
+//! are read back. Uploads must be PDFs unless the filer overrides the check,
+//! must fit the district's `document_rules.max_size_mb`, and are fingerprinted
+//! with a SHA-256 digest that every download is verified against. Attachments
+//! recorded without an upload declare their size and page count, which must
+//! be nonzero and within the same limits.
+
+use crate::domain::config::Configuration;
+use crate::error::{ApiError, ApiResult};
+use crate::ports::document_repository::StoredAttachment;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+/// Configuration path of the district's upload size limit, in megabytes
+pub const MAX_SIZE_CONFIG_PATH: &str = "document_rules.max_size_mb";
+
+/// Size limit when the district configures none
+pub const DEFAULT_MAX_SIZE_MB: i64 = 25;
+
+/// Configuration path of the district's page limit for one attachment
+pub const MAX_PAGES_CONFIG_PATH: &str = "document_rules.max_pages";
+
+/// Page limit when the district configures none
+pub const DEFAULT_MAX_PAGES: i64 = 500;
+
+/// A district's limits on a single attachment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttachmentLimits {
+    pub max_bytes: u64,
+    pub max_pages: u32,
+}
+
+impl AttachmentLimits {
+    /// Limits from the district's merged configuration, defaulting any
+    /// that are not set
+    pub fn from_config(config: &Configuration) -> Self {
+        let max_pages = config.get_i64(MAX_PAGES_CONFIG_PATH).unwrap_or(DEFAULT_MAX_PAGES);
+        Self {
+            max_bytes: max_size_bytes(config.get_i64(MAX_SIZE_CONFIG_PATH)),
+            max_pages: max_pages.clamp(0, i64::from(u32::MAX)) as u32,
+        }
+    }
+}
+
+/// Media type required of uploads without the override
+pub const PDF_CONTENT_TYPE: &str = "application/pdf";
+
+/// Leading bytes of every PDF file
+const PDF_MAGIC: &[u8] = b"%PDF-";
+
+/// Byte limit for a configured size in megabytes
+pub fn max_size_bytes(max_size_mb: Option<i64>) -> u64 {
+    max_size_mb.unwrap_or(DEFAULT_MAX_SIZE_MB).max(0) as u64 * 1024 * 1024
+}
+
+/// Check an upload against the size limit and, unless `allow_non_pdf` is
+/// set, that it is declared and recognizable as a PDF
+pub fn validate_upload(
+    content: &[u8],
+    content_type: Option<&str>,
+    max_bytes: u64,
+    allow_non_pdf: bool,
+) -> ApiResult<()> {
+    if content.is_empty() {
+        return Err(ApiError::BadRequest("Attachment content is empty".to_string()));
+    }
+    if content.len() as u64 > max_bytes {
+        return Err(ApiError::PayloadTooLarge(format!(
+            "Attachment is {} bytes; the limit is {} bytes",
+            content.len(),
+            max_bytes
+        )));
+    }
+    if allow_non_pdf {
+        return Ok(());
+    }
+
+    let declared = content_type
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_lowercase());
+    if declared.as_deref() != Some(PDF_CONTENT_TYPE) {
+        return Err(ApiError::UnsupportedMediaType(format!(
+            "Attachments must be sent as {}, got {}; set allow_non_pdf=true to upload other files",
+            PDF_CONTENT_TYPE,
+            content_type.unwrap_or("no content type")
+        )));
+    }
+    if !content.starts_with(PDF_MAGIC) {
+        return Err(ApiError::UnsupportedMediaType(
+            "Attachment content is not a PDF; set allow_non_pdf=true to upload other files".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Check the size and page count declared for an attachment recorded
+/// without an upload
+pub fn validate_declared(file_size_bytes: u64, page_count: u32, limits: &AttachmentLimits) -> ApiResult<()> {
+    if file_size_bytes == 0 {
+        return Err(ApiError::BadRequest("file_size_bytes must be greater than zero".to_string())
+            .with_details(json!({ "field": "file_size_bytes", "value": file_size_bytes })));
+    }
+    if file_size_bytes > limits.max_bytes {
+        return Err(ApiError::BadRequest(format!(
+            "file_size_bytes is {}; the limit is {} bytes",
+            file_size_bytes, limits.max_bytes
+        ))
+        .with_details(json!({ "field": "file_size_bytes", "value": file_size_bytes, "max": limits.max_bytes })));
+    }
+    validate_page_count(page_count, limits)
+}
+
+/// Check an attachment's page count is nonzero and within the limit
+pub fn validate_page_count(page_count: u32, limits: &AttachmentLimits) -> ApiResult<()> {
+    if page_count == 0 {
+        return Err(ApiError::BadRequest("page_count must be greater than zero".to_string())
+            .with_details(json!({ "field": "page_count", "value": page_count })));
+    }
+    if page_count > limits.max_pages {
+        return Err(ApiError::BadRequest(format!(
+            "page_count is {}; the limit is {} pages",
+            page_count, limits.max_pages
+        ))
+        .with_details(json!({ "field": "page_count", "value": page_count, "max": limits.max_pages })));
+    }
+    Ok(())
+}
+
+/// Hex SHA-256 digest of attachment content
+pub fn sha256_hex(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+/// Confirm stored content still matches the digest taken at upload
+pub fn verify(stored: &StoredAttachment) -> ApiResult<()> {
+    let expected = stored.attachment.sha256.as_deref().ok_or_else(|| {
+        ApiError::Internal(format!("Attachment {} has no recorded digest", stored.attachment.id))
+    })?;
+    if sha256_hex(&stored.content) != expected {
+        return Err(ApiError::Internal(format!(
+            "Content of attachment {} does not match its SHA-256 digest",
+            stored.attachment.id
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::docket::DocketAttachment;
+    use uuid::Uuid;
+
+    const PDF: &[u8] = b"%PDF-1.7\n%EOF";
+
+    #[test]
+    fn test_pdf_within_limit_is_accepted() {
+        assert!(validate_upload(PDF, Some("application/pdf"), 1024, false).is_ok());
+        assert!(validate_upload(PDF, Some("Application/PDF; charset=binary"), 1024, false).is_ok());
+    }
+
+    #[test]
+    fn test_upload_limits_and_types() {
+        assert!(matches!(
+            validate_upload(PDF, Some("application/pdf"), 4, false),
+            Err(ApiError::PayloadTooLarge(_))
+        ));
+        assert!(matches!(
+            validate_upload(b"PK\x03\x04", Some("application/pdf"), 1024, false),
+            Err(ApiError::UnsupportedMediaType(_))
+        ));
+        assert!(matches!(
+            validate_upload(PDF, Some("text/plain"), 1024, false),
+            Err(ApiError::UnsupportedMediaType(_))
+        ));
+        assert!(validate_upload(b"plain text", Some("text/plain"), 1024, true).is_ok());
+        assert!(matches!(validate_upload(b"", None, 1024, true), Err(ApiError::BadRequest(_))));
+        assert_eq!(max_size_bytes(None), 25 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_declared_size_and_pages_bounds() {
+        let limits = AttachmentLimits { max_bytes: 1024, max_pages: 10 };
+        assert!(validate_declared(1, 1, &limits).is_ok());
+        assert!(validate_declared(1024, 10, &limits).is_ok());
+
+        for (bytes, pages) in [(0, 1), (1025, 1), (u64::MAX, 1), (1, 0), (1, 11), (1, u32::MAX)] {
+            let error = validate_declared(bytes, pages, &limits).unwrap_err();
+            assert_eq!(error.status(), 400, "{} bytes, {} pages", bytes, pages);
+        }
+    }
+
+    #[test]
+    fn test_limits_follow_district_config() {
+        let mut config = Configuration::new();
+        assert_eq!(
+            AttachmentLimits::from_config(&config),
+            AttachmentLimits { max_bytes: 25 * 1024 * 1024, max_pages: 500 }
+        );
+
+        config.set(MAX_SIZE_CONFIG_PATH, json!(1));
+        config.set(MAX_PAGES_CONFIG_PATH, json!(50));
+        assert_eq!(
+            AttachmentLimits::from_config(&config),
+            AttachmentLimits { max_bytes: 1024 * 1024, max_pages: 50 }
+        );
+    }
+
+    #[test]
+    fn test_verify_detects_changed_content() {
+        let mut stored = StoredAttachment {
+            entry_id: Uuid::new_v4(),
+            attachment: DocketAttachment {
+                id: Uuid::new_v4(),
+                attachment_number: 1,
+                description: "Exhibit A".to_string(),
+                page_count: 1,
+                file_size_bytes: PDF.len() as u64,
+                file_name: None,
+                content_type: Some(PDF_CONTENT_TYPE.to_string()),
+                sha256: Some(sha256_hex(PDF)),
+            },
+            content: PDF.to_vec(),
+        };
+        assert!(verify(&stored).is_ok());
+
+        stored.content.push(b'\n');
+        assert!(verify(&stored).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+//! Case archival and restoration
+//!
+//! Archiving gathers a closed case and the records filed under it from each
+//! repository into one [`CaseArchive`], saves it with a tombstone, and only
+//! then removes the live records. A failure part way through therefore
+//! leaves the archive in place, and restoring it puts back whatever was
+//! removed. Restoring writes every record back and drops the archive.
+
+use crate::domain::case_archive::{CaseArchive, OpinionReference};
+use crate::domain::criminal_case::CriminalCase;
+use crate::error::{ApiError, ApiResult};
+use crate::ports::case_repository::{CaseArchiveRepository, CaseRepository};
+use crate::ports::deadline_repository::DeadlineRepository;
+use crate::ports::docket_repository::{CalendarRepository, DocketRepository, SpeedyTrialRepository};
+use crate::ports::document_repository::DocumentRepository;
+use crate::ports::judge_repository::CaseAssignmentRepository;
+use chrono::Utc;
+use uuid::Uuid;
+
+/// Archive a closed case, removing it and its records from the live store
+pub fn archive_case(
+    cases: &(impl CaseRepository + CaseArchiveRepository),
+    docket: &(impl DocketRepository + CalendarRepository + SpeedyTrialRepository),
+    deadlines: &impl DeadlineRepository,
+    assignments: &impl CaseAssignmentRepository,
+    documents: &impl DocumentRepository,
+    case_id: Uuid,
+) -> ApiResult<CaseArchive> {
+    let case = cases.find_by_id(case_id)?.ok_or_else(|| match cases.find_tombstone(case_id) {
+        Ok(Some(_)) => ApiError::Conflict(format!("Case {} is already archived", case_id)),
+        _ => ApiError::NotFound(format!("Case with id {} not found", case_id)),
+    })?;
+    if case.has_pending_appeal {
+        return Err(ApiError::Conflict(format!(
+            "Case {} has a pending appeal and cannot be archived until the mandate issues",
+            case_id
+        )));
+    }
+    CaseArchive::ensure_archivable(&case).map_err(ApiError::BadRequest)?;
+
+    let case_key = case_id.to_string();
+    let archive = CaseArchive {
+        case_id,
+        case_number: case.case_number.clone(),
+        archived_at: Utc::now(),
+        docket_entries: docket.find_entries_by_case(case_id)?,
+        calendar_events: docket.find_events_by_case(case_id)?,
+        speedy_trial: docket.find_clock_by_case(case_id)?,
+        deadlines: deadlines.find_deadlines_by_case(case_id)?,
+        assignments: assignments.find_assignment_history(case_id)?,
+        orders: documents.find_orders_by_case(&case_key)?,
+        opinions: documents
+            .find_opinions_by_case(&case_key)?
+            .iter()
+            .map(OpinionReference::from)
+            .collect(),
+        case,
+    };
+    cases.save_archive(&archive)?;
+
+    for entry in &archive.docket_entries {
+        docket.delete_entry(entry.id)?;
+    }
+    for event in &archive.calendar_events {
+        docket.delete_event(event.id)?;
+    }
+    docket.delete_clock(case_id)?;
+    for deadline in &archive.deadlines {
+        deadlines.delete_deadline(deadline.id)?;
+    }
+    for assignment in &archive.assignments {
+        assignments.delete_assignment(assignment.id)?;
+    }
+    for order in &archive.orders {
+        documents.delete_order(&order.id)?;
+    }
+    cases.delete(case_id)?;
+
+    Ok(archive)
+}
+
+/// Restore an archived case and its records to the live store
+pub fn unarchive_case(
+    cases: &(impl CaseRepository + CaseArchiveRepository),
+    docket: &(impl DocketRepository + CalendarRepository + SpeedyTrialRepository),
+    deadlines: &impl DeadlineRepository,
+    assignments: &impl CaseAssignmentRepository,
+    documents: &impl DocumentRepository,
+    case_id: Uuid,
+) -> ApiResult<CriminalCase> {
+    let archive = cases
+        .find_archive(case_id)?
+        .ok_or_else(|| ApiError::NotFound(format!("No archive for case {}", case_id)))?;
+    if let Some(live) = cases.find_by_case_number(&archive.case_number)? {
+        if live.id != case_id {
+            return Err(ApiError::Conflict(format!(
+                "Case number {} is now used by case {}",
+                archive.case_number, live.id
+            )));
+        }
+    }
+
+    cases.save(&archive.case)?;
+    for entry in &archive.docket_entries {
+        // Replace rather than duplicate entries left by an interrupted archive
+        docket.delete_entry(entry.id)?;
+        docket.save_entry(entry)?;
+    }
+    for event in &archive.calendar_events {
+        docket.save_event(event)?;
+    }
+    if let Some(clock) = &archive.speedy_trial {
+        docket.save_clock(clock)?;
+    }
+    for deadline in &archive.deadlines {
+        deadlines.save_deadline(deadline)?;
+    }
+    // Superseded assignments first, so the current one holds the case index
+    let (superseded, current): (Vec<_>, Vec<_>) =
+        archive.assignments.iter().partition(|a| a.superseded_date.is_some());
+    for assignment in superseded.into_iter().chain(current) {
+        assignments.save_assignment(assignment)?;
+    }
+    for order in &archive.orders {
+        documents.create_order(order.clone())?;
+    }
+    cases.delete_archive(case_id)?;
+
+    Ok(archive.case)
+}
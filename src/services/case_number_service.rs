@@ -0,0 +1,236 @@
+//! Sequential case number generation
+//!
+//! Each district numbers its cases per year and case type from a counter in
+//! the tenant's store. [`CaseNumberGenerator`] reserves the next value with
+//! an atomic increment, so concurrent requests never share a number. A
+//! number already held by a live case, for example one a client supplied,
+//! is skipped.
+//!
+//! Lookups by number go through [`find_by_number`], which tries the
+//! canonical form first so any way of writing a number finds its case.
+
+use crate::domain::case_number::CaseNumberFormat;
+use crate::domain::criminal_case::CriminalCase;
+use crate::error::{ApiError, ApiResult};
+use crate::ports::case_repository::{CaseNumberCounterRepository, CaseRepository};
+
+/// Numbers tried before giving up when each is already taken
+pub const MAX_ATTEMPTS: usize = 32;
+
+/// Hands out case numbers in a district's format
+pub struct CaseNumberGenerator<'a, R> {
+    repo: &'a R,
+    format: CaseNumberFormat,
+}
+
+impl<'a, R: CaseRepository + CaseNumberCounterRepository> CaseNumberGenerator<'a, R> {
+    /// Create a generator backed by the tenant's case repository
+    pub fn new(repo: &'a R, format: CaseNumberFormat) -> Self {
+        Self { repo, format }
+    }
+
+    /// Reserve the next case number for a district, case type and year
+    ///
+    /// Unknown case types are a 400; running into [`MAX_ATTEMPTS`] numbers
+    /// in a row that are already taken is a 409 the client can retry.
+    pub fn next(&self, district_code: &str, case_type: &str, year: i32) -> ApiResult<String> {
+        let abbreviation = self.format.abbreviation(case_type).ok_or_else(|| {
+            ApiError::BadRequest(format!(
+                "Unknown case type '{}', expected one of: {}",
+                case_type,
+                self.format.type_abbreviations.keys().cloned().collect::<Vec<_>>().join(", ")
+            ))
+        })?;
+        let key = CaseNumberFormat::counter_key(district_code, year, abbreviation);
+
+        for _ in 0..MAX_ATTEMPTS {
+            let next = self.repo.next_counter_value(&key)?;
+            let case_number = self.format.format(year, abbreviation, next);
+            if find_by_number(self.repo, &self.format, &case_number)?.is_none() {
+                return Ok(case_number);
+            }
+        }
+
+        Err(ApiError::Conflict(format!(
+            "Could not reserve a case number for {} after {} attempts, try again",
+            key, MAX_ATTEMPTS
+        )))
+    }
+}
+
+/// Find a case by its number however the client wrote it
+///
+/// Numbers that aren't in the case number format, such as ones recorded
+/// before numbers were normalized, are looked up as written.
+pub fn find_by_number(
+    repo: &impl CaseRepository,
+    format: &CaseNumberFormat,
+    case_number: &str,
+) -> ApiResult<Option<CriminalCase>> {
+    let case_number = case_number.trim();
+    if let Ok(canonical) = format.normalize(case_number) {
+        if let Some(case) = repo.find_by_case_number(&canonical)? {
+            return Ok(Some(case));
+        }
+    }
+    Ok(repo.find_by_case_number(case_number)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::criminal_case::{CaseStatus, CriminalCase, CrimeType};
+    use std::cell::RefCell;
+    use std::collections::{HashMap, HashSet};
+    use uuid::Uuid;
+
+    /// In-memory counters and case numbers
+    #[derive(Default)]
+    struct MemoryRepository {
+        counters: RefCell<HashMap<String, u64>>,
+        case_numbers: RefCell<HashSet<String>>,
+    }
+
+    impl CaseNumberCounterRepository for MemoryRepository {
+        fn next_counter_value(&self, key: &str) -> anyhow::Result<u64> {
+            let mut counters = self.counters.borrow_mut();
+            let value = counters.entry(key.to_string()).or_insert(0);
+            *value += 1;
+            Ok(*value)
+        }
+    }
+
+    impl CaseRepository for MemoryRepository {
+        fn save(&self, case: &CriminalCase) -> anyhow::Result<()> {
+            self.case_numbers.borrow_mut().insert(case.case_number.clone());
+            Ok(())
+        }
+
+        fn find_by_id(&self, _: Uuid) -> anyhow::Result<Option<CriminalCase>> {
+            unimplemented!()
+        }
+
+        fn find_by_case_number(&self, case_number: &str) -> anyhow::Result<Option<CriminalCase>> {
+            Ok(self.case_numbers.borrow().contains(case_number).then(|| case(case_number)))
+        }
+
+        fn find_all_cases(&self) -> anyhow::Result<Vec<CriminalCase>> {
+            unimplemented!()
+        }
+
+        fn find_by_status(&self, _: CaseStatus) -> anyhow::Result<Vec<CriminalCase>> {
+            unimplemented!()
+        }
+
+        fn find_by_judge(&self, _: Uuid) -> anyhow::Result<Vec<CriminalCase>> {
+            unimplemented!()
+        }
+
+        fn delete(&self, _: Uuid) -> anyhow::Result<bool> {
+            unimplemented!()
+        }
+
+        fn count_by_status(&self, _: CaseStatus) -> anyhow::Result<usize> {
+            unimplemented!()
+        }
+
+        fn count(&self) -> anyhow::Result<usize> {
+            unimplemented!()
+        }
+    }
+
+    fn case(case_number: &str) -> CriminalCase {
+        CriminalCase::new(
+            "Test".to_string(),
+            String::new(),
+            CrimeType::Fraud,
+            "SDNY".to_string(),
+            None,
+            case_number.to_string(),
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn test_numbers_are_sequential_per_district_year_and_type() {
+        let repo = MemoryRepository::default();
+        let generator = CaseNumberGenerator::new(&repo, CaseNumberFormat::default());
+
+        assert_eq!(generator.next("SDNY", "criminal", 2024).unwrap(), "24-cr-00001");
+        assert_eq!(generator.next("SDNY", "criminal", 2024).unwrap(), "24-cr-00002");
+        assert_eq!(generator.next("SDNY", "magistrate", 2024).unwrap(), "24-mj-00001");
+        assert_eq!(generator.next("SDNY", "criminal", 2025).unwrap(), "25-cr-00001");
+        assert_eq!(generator.next("EDNY", "criminal", 2024).unwrap(), "24-cr-00001");
+        assert_eq!(generator.next("sdny", "criminal", 2024).unwrap(), "24-cr-00003");
+    }
+
+    #[test]
+    fn test_no_duplicates_across_500_calls() {
+        let repo = MemoryRepository::default();
+        let generator = CaseNumberGenerator::new(&repo, CaseNumberFormat::default());
+
+        let mut seen = HashSet::new();
+        for _ in 0..500 {
+            let number = generator.next("SDNY", "criminal", 2024).unwrap();
+            assert!(seen.insert(number.clone()), "duplicate case number {}", number);
+            repo.save(&case(&number)).unwrap();
+        }
+        assert_eq!(seen.len(), 500);
+    }
+
+    #[test]
+    fn test_gives_up_after_max_attempts() {
+        let repo = MemoryRepository::default();
+        for n in 1..=MAX_ATTEMPTS {
+            repo.save(&case(&format!("24-cr-{:05}", n))).unwrap();
+        }
+        let generator = CaseNumberGenerator::new(&repo, CaseNumberFormat::default());
+
+        assert!(matches!(generator.next("SDNY", "criminal", 2024), Err(ApiError::Conflict(_))));
+        assert_eq!(generator.next("SDNY", "criminal", 2024).unwrap(), format!("24-cr-{:05}", MAX_ATTEMPTS + 1));
+    }
+
+    #[test]
+    fn test_skips_numbers_held_by_existing_cases() {
+        let repo = MemoryRepository::default();
+        repo.save(&case("24-cr-00001")).unwrap();
+        repo.save(&case("24-cr-00002")).unwrap();
+        let generator = CaseNumberGenerator::new(&repo, CaseNumberFormat::default());
+
+        assert_eq!(generator.next("SDNY", "criminal", 2024).unwrap(), "24-cr-00003");
+    }
+
+    #[test]
+    fn test_skips_numbers_held_under_canonical_form() {
+        let repo = MemoryRepository::default();
+        repo.save(&case("24-cr-00001")).unwrap();
+        let mut format = CaseNumberFormat::default();
+        format.type_abbreviations.insert("criminal".to_string(), "CR".to_string());
+        let generator = CaseNumberGenerator::new(&repo, format);
+
+        assert_eq!(generator.next("SDNY", "criminal", 2024).unwrap(), "24-CR-00002");
+    }
+
+    #[test]
+    fn test_find_by_number_normalizes() {
+        let repo = MemoryRepository::default();
+        repo.save(&case("24-cr-00001")).unwrap();
+        repo.save(&case("CV-LEGACY-7")).unwrap();
+        let format = CaseNumberFormat::default();
+
+        for input in ["24-cr-00001", "24-CR-1", "2024-cr-001", " 24-cr-1 "] {
+            let found = find_by_number(&repo, &format, input).unwrap();
+            assert_eq!(found.unwrap().case_number, "24-cr-00001", "{}", input);
+        }
+        assert!(find_by_number(&repo, &format, "24-cr-2").unwrap().is_none());
+        assert!(find_by_number(&repo, &format, "CV-LEGACY-7").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_unknown_case_type_is_rejected() {
+        let repo = MemoryRepository::default();
+        let generator = CaseNumberGenerator::new(&repo, CaseNumberFormat::default());
+
+        assert!(matches!(generator.next("SDNY", "admiralty", 2024), Err(ApiError::BadRequest(_))));
+    }
+}
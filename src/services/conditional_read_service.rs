@@ -0,0 +1,217 @@
+//! Conditional reads of docket and calendar lists
+//!
+//! Clients poll case dockets and judge schedules. Each list carries a weak
+//! ETag built from a version counter the docket repository bumps on writes:
+//! per case for docket entries, per judge for calendar events. A request
+//! whose `If-None-Match` still matches is answered without loading the list.
+//!
+//! The tag also covers whatever else shapes the response, such as the
+//! caller's access level or the schedule date, so two views of the same
+//! collection never share a tag.
+
+use crate::domain::docket::{CalendarEntry, DocketEntry};
+use crate::error::ApiResult;
+use crate::ports::docket_repository::{CalendarSchedulingRepository, DocketRepository};
+use crate::utils::access::AccessLevel;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Outcome of a conditional read, with the ETag of the current list
+#[derive(Debug)]
+pub enum Conditional<T> {
+    /// The client's copy is current; nothing was loaded
+    NotModified { etag: String },
+    /// The list as it is now
+    Modified { etag: String, body: T },
+}
+
+/// Weak ETag for the given parts, e.g. `W/"docket-<case>-3-public"`
+pub fn weak_etag(parts: &[&str]) -> String {
+    format!("W/\"{}\"", parts.join("-"))
+}
+
+/// Whether an `If-None-Match` header value matches `etag`
+///
+/// Uses weak comparison, so `W/` prefixes are ignored, and accepts a
+/// comma-separated list of tags or `*`.
+pub fn if_none_match_matches(if_none_match: Option<&str>, etag: &str) -> bool {
+    let Some(header) = if_none_match else {
+        return false;
+    };
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = opaque(etag);
+    header
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || opaque(candidate) == etag)
+}
+
+fn evaluate<T>(
+    etag: String,
+    if_none_match: Option<&str>,
+    load: impl FnOnce() -> ApiResult<T>,
+) -> ApiResult<Conditional<T>> {
+    if if_none_match_matches(if_none_match, &etag) {
+        return Ok(Conditional::NotModified { etag });
+    }
+    Ok(Conditional::Modified { body: load()?, etag })
+}
+
+/// Docket entries of a case visible at `access`, unless the client's copy is current
+pub fn case_docket(
+    repo: &impl DocketRepository,
+    case_id: Uuid,
+    access: AccessLevel,
+    if_none_match: Option<&str>,
+) -> ApiResult<Conditional<Vec<DocketEntry>>> {
+    let version = repo.docket_version(case_id)?;
+    let etag = weak_etag(&["docket", &case_id.to_string(), &version.to_string(), access.as_str()]);
+    evaluate(etag, if_none_match, || {
+        let mut entries = repo.find_entries_by_case(case_id)?;
+        entries.retain(|e| access.can_view(e.is_sealed, e.is_ex_parte));
+        Ok(entries)
+    })
+}
+
+/// A judge's events on the day of `date`, unless the client's copy is current
+pub fn judge_schedule(
+    repo: &impl CalendarSchedulingRepository,
+    judge_id: Uuid,
+    date: DateTime<Utc>,
+    if_none_match: Option<&str>,
+) -> ApiResult<Conditional<Vec<CalendarEntry>>> {
+    let version = repo.judge_calendar_version(judge_id)?;
+    let day = date.format("%Y%m%d").to_string();
+    let etag = weak_etag(&["schedule", &judge_id.to_string(), &version.to_string(), &day]);
+    evaluate(etag, if_none_match, || Ok(repo.get_judge_schedule(judge_id, date)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::docket::{CaseAttachmentSize, DocketEntryType, DocketService};
+    use std::cell::{Cell, RefCell};
+
+    /// Docket repository that counts full loads of a case's entries
+    #[derive(Default)]
+    struct CountingDocketRepository {
+        entries: RefCell<Vec<DocketEntry>>,
+        version: Cell<u64>,
+        loads: Cell<usize>,
+    }
+
+    impl DocketRepository for CountingDocketRepository {
+        fn save_entry(&self, entry: &DocketEntry) -> anyhow::Result<()> {
+            self.entries.borrow_mut().push(entry.clone());
+            self.version.set(self.version.get() + 1);
+            Ok(())
+        }
+
+        fn find_entry_by_id(&self, _: Uuid) -> anyhow::Result<Option<DocketEntry>> {
+            unimplemented!()
+        }
+
+        fn find_all_entries(&self) -> anyhow::Result<Vec<DocketEntry>> {
+            unimplemented!()
+        }
+
+        fn find_entries_by_case(&self, case_id: Uuid) -> anyhow::Result<Vec<DocketEntry>> {
+            self.loads.set(self.loads.get() + 1);
+            Ok(self.entries.borrow().iter().filter(|e| e.case_id == case_id).cloned().collect())
+        }
+
+        fn find_entries_by_type(&self, _: Uuid, _: DocketEntryType) -> anyhow::Result<Vec<DocketEntry>> {
+            unimplemented!()
+        }
+
+        fn get_next_entry_number(&self, _: Uuid) -> anyhow::Result<u32> {
+            unimplemented!()
+        }
+
+        fn find_sealed_entries(&self, _: Uuid) -> anyhow::Result<Vec<DocketEntry>> {
+            unimplemented!()
+        }
+
+        fn search_entries(&self, _: Uuid, _: &str) -> anyhow::Result<Vec<DocketEntry>> {
+            unimplemented!()
+        }
+
+        fn delete_entry(&self, _: Uuid) -> anyhow::Result<bool> {
+            unimplemented!()
+        }
+
+        fn docket_version(&self, _: Uuid) -> anyhow::Result<u64> {
+            Ok(self.version.get())
+        }
+
+        fn case_attachment_size(&self, _: Uuid) -> anyhow::Result<CaseAttachmentSize> {
+            unimplemented!()
+        }
+    }
+
+    fn file(repo: &CountingDocketRepository, case_id: Uuid, sealed: bool) {
+        let mut entry = DocketService::create_entry(case_id, DocketEntryType::Motion, "Motion".to_string(), None);
+        entry.is_sealed = sealed;
+        repo.save_entry(&entry).unwrap();
+    }
+
+    fn etag_of<T>(result: &Conditional<T>) -> String {
+        match result {
+            Conditional::NotModified { etag } | Conditional::Modified { etag, .. } => etag.clone(),
+        }
+    }
+
+    #[test]
+    fn test_matching_etag_skips_load() {
+        let repo = CountingDocketRepository::default();
+        let case_id = Uuid::new_v4();
+        file(&repo, case_id, false);
+
+        let first = case_docket(&repo, case_id, AccessLevel::Public, None).unwrap();
+        assert!(matches!(&first, Conditional::Modified { body, .. } if body.len() == 1));
+        assert_eq!(repo.loads.get(), 1);
+
+        let etag = etag_of(&first);
+        let second = case_docket(&repo, case_id, AccessLevel::Public, Some(&etag)).unwrap();
+        assert!(matches!(second, Conditional::NotModified { .. }));
+        assert_eq!(repo.loads.get(), 1, "304 path must not load the entries");
+    }
+
+    #[test]
+    fn test_write_invalidates_etag() {
+        let repo = CountingDocketRepository::default();
+        let case_id = Uuid::new_v4();
+        file(&repo, case_id, false);
+        let etag = etag_of(&case_docket(&repo, case_id, AccessLevel::Public, None).unwrap());
+
+        file(&repo, case_id, false);
+        let after_write = case_docket(&repo, case_id, AccessLevel::Public, Some(&etag)).unwrap();
+        assert!(matches!(&after_write, Conditional::Modified { body, .. } if body.len() == 2));
+        assert_ne!(etag_of(&after_write), etag);
+        assert_eq!(repo.loads.get(), 2);
+    }
+
+    #[test]
+    fn test_access_level_is_part_of_etag() {
+        let repo = CountingDocketRepository::default();
+        let case_id = Uuid::new_v4();
+        file(&repo, case_id, true);
+        let public_etag = etag_of(&case_docket(&repo, case_id, AccessLevel::Public, None).unwrap());
+
+        let chambers = case_docket(&repo, case_id, AccessLevel::Chambers, Some(&public_etag)).unwrap();
+        assert!(matches!(&chambers, Conditional::Modified { body, .. } if body.len() == 1));
+    }
+
+    #[test]
+    fn test_if_none_match_parsing() {
+        let etag = weak_etag(&["docket", "abc", "3", "public"]);
+        assert_eq!(etag, r#"W/"docket-abc-3-public""#);
+
+        assert!(if_none_match_matches(Some(&etag), &etag));
+        assert!(if_none_match_matches(Some(r#""docket-abc-3-public""#), &etag));
+        assert!(if_none_match_matches(Some(r#"W/"other", W/"docket-abc-3-public""#), &etag));
+        assert!(if_none_match_matches(Some("*"), &etag));
+        assert!(!if_none_match_matches(Some(r#"W/"docket-abc-2-public""#), &etag));
+        assert!(!if_none_match_matches(None, &etag));
+    }
+}
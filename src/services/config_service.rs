@@ -3,10 +3,16 @@
 //! This service handles the business logic for configuration management,
 //! including merging configurations, caching, and validation.
 
-use crate::domain::config::{ConfigMetadata, ConfigOverride, ConfigResponse};
+use crate::domain::case_number::CaseNumberFormat;
+use crate::domain::config::{
+    ConfigDiff, ConfigMetadata, ConfigOverride, ConfigOverrideVersion, ConfigResponse, ConfigVersionAction, Configuration,
+    CourtClosure,
+};
+use crate::domain::judge::AssignmentStrategy;
+use crate::domain::weekly_docket::WeeklyDocket;
 use crate::error::ApiError;
 use crate::ports::config_repository::ConfigRepository;
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -80,17 +86,16 @@ impl ConfigService {
         self.repository.get_judge_overrides(district_id, judge_id).await
     }
 
-    /// Update district-level configuration overrides
+    /// Update district-level configuration overrides, recording the result
+    /// as a new version in the district's history
     pub async fn update_district_config(
         &self,
         district_id: &str,
         updates: HashMap<String, Value>,
     ) -> Result<(), ApiError> {
         // Get existing overrides or create new
-        let mut overrides = self.repository
-            .get_district_overrides(district_id)
-            .await?
-            .unwrap_or_default();
+        let existing = self.repository.get_district_overrides(district_id).await?;
+        let mut overrides = existing.clone().unwrap_or_default();
 
         // Apply updates
         for (path, value) in updates {
@@ -98,7 +103,9 @@ impl ConfigService {
         }
 
         // Save back to repository
-        self.repository.save_district_overrides(district_id, &overrides).await
+        self.repository.save_district_overrides(district_id, &overrides).await?;
+        self.record_district_version(district_id, existing, ConfigVersionAction::Update, None, overrides).await?;
+        Ok(())
     }
 
     /// Update judge-level configuration overrides
@@ -125,7 +132,139 @@ impl ConfigService {
 
     /// Clear district-level overrides (revert to base config)
     pub async fn clear_district_overrides(&self, district_id: &str) -> Result<(), ApiError> {
-        self.repository.delete_district_overrides(district_id).await
+        let existing = self.repository.get_district_overrides(district_id).await?;
+        self.repository.delete_district_overrides(district_id).await?;
+        self.record_district_version(district_id, existing, ConfigVersionAction::Clear, None, ConfigOverride::new())
+            .await?;
+        Ok(())
+    }
+
+    /// Versions of the district's overrides, newest (the current one) first
+    pub async fn district_override_history(&self, district_id: &str) -> Result<Vec<ConfigOverrideVersion>, ApiError> {
+        let mut history = self.repository.list_district_override_versions(district_id).await?;
+        history.reverse();
+        Ok(history)
+    }
+
+    /// Restore a version of the district's overrides
+    ///
+    /// The rollback is itself recorded as a new version, so it can be undone
+    /// the same way.
+    pub async fn rollback_district_overrides(
+        &self,
+        district_id: &str,
+        version: u32,
+    ) -> Result<ConfigOverrideVersion, ApiError> {
+        let history = self.repository.list_district_override_versions(district_id).await?;
+        let target = history
+            .into_iter()
+            .find(|v| v.version == version)
+            .ok_or_else(|| ApiError::NotFound(format!("No version {} of district {} overrides", version, district_id)))?;
+
+        if target.overrides.overrides.is_empty() {
+            self.repository.delete_district_overrides(district_id).await?;
+        } else {
+            self.repository.save_district_overrides(district_id, &target.overrides).await?;
+        }
+        self.record_district_version(district_id, None, ConfigVersionAction::Rollback, Some(version), target.overrides)
+            .await
+    }
+
+    /// Append a version to the district's history
+    ///
+    /// On the first change a district with overrides saved before history
+    /// was kept gets them recorded as a baseline version, so that change
+    /// can be rolled back too.
+    async fn record_district_version(
+        &self,
+        district_id: &str,
+        previous: Option<ConfigOverride>,
+        action: ConfigVersionAction,
+        restored_from: Option<u32>,
+        overrides: ConfigOverride,
+    ) -> Result<ConfigOverrideVersion, ApiError> {
+        let mut latest = self
+            .repository
+            .list_district_override_versions(district_id)
+            .await?
+            .last()
+            .map_or(0, |v| v.version);
+
+        if latest == 0 {
+            if let Some(previous) = previous.filter(|p| !p.overrides.is_empty()) {
+                latest = 1;
+                let baseline = ConfigOverrideVersion {
+                    version: latest,
+                    saved_at: Utc::now(),
+                    action: ConfigVersionAction::Baseline,
+                    restored_from: None,
+                    overrides: previous,
+                };
+                self.repository.save_district_override_version(district_id, &baseline).await?;
+            }
+        }
+
+        let version = ConfigOverrideVersion {
+            version: latest + 1,
+            saved_at: Utc::now(),
+            action,
+            restored_from,
+            overrides,
+        };
+        self.repository.save_district_override_version(district_id, &version).await?;
+        Ok(version)
+    }
+
+    /// Special closure dates for a district
+    pub async fn list_closures(&self, district_id: &str) -> Result<Vec<CourtClosure>, ApiError> {
+        self.repository.get_closures(district_id).await
+    }
+
+    /// Add a closure date, rejecting dates already on the list
+    pub async fn add_closure(
+        &self,
+        district_id: &str,
+        closure: CourtClosure,
+    ) -> Result<Vec<CourtClosure>, ApiError> {
+        let mut closures = self.repository.get_closures(district_id).await?;
+        CourtClosure::insert(&mut closures, closure).map_err(ApiError::Conflict)?;
+        self.repository.save_closures(district_id, &closures).await?;
+        Ok(closures)
+    }
+
+    /// Remove a closure date
+    pub async fn remove_closure(&self, district_id: &str, date: NaiveDate) -> Result<(), ApiError> {
+        let mut closures = self.repository.get_closures(district_id).await?;
+        let before = closures.len();
+        closures.retain(|c| c.date != date);
+        if closures.len() == before {
+            return Err(ApiError::NotFound(format!("No court closure on {}", date)));
+        }
+        self.repository.save_closures(district_id, &closures).await
+    }
+
+    /// The district's case number format
+    pub async fn get_case_number_format(&self, district_id: &str) -> Result<CaseNumberFormat, ApiError> {
+        let config = self.repository.get_merged_config(district_id, None).await?;
+        Ok(CaseNumberFormat::from_config(&config))
+    }
+
+    /// Override the district's case number padding and type abbreviations
+    pub async fn update_case_number_format(
+        &self,
+        district_id: &str,
+        format: CaseNumberFormat,
+    ) -> Result<CaseNumberFormat, ApiError> {
+        format.validate().map_err(ApiError::BadRequest)?;
+        let updates = HashMap::from([
+            (CaseNumberFormat::PADDING_CONFIG_PATH.to_string(), Value::from(format.padding)),
+            (
+                CaseNumberFormat::TYPES_CONFIG_PATH.to_string(),
+                serde_json::to_value(&format.type_abbreviations)?,
+            ),
+        ]);
+        self.update_district_config(district_id, updates).await?;
+        self.get_case_number_format(district_id).await
     }
 
     /// Clear judge-level overrides (revert to district config)
@@ -138,6 +277,8 @@ impl ConfigService {
     }
 
     /// Preview what configuration would look like with proposed changes
+    ///
+    /// A change to null removes the key from the preview.
     pub async fn preview_config_changes(
         &self,
         district_id: &str,
@@ -148,21 +289,51 @@ impl ConfigService {
         let mut config = self.repository.get_merged_config(district_id, judge_id).await?;
 
         // Apply proposed changes
-        for (path, value) in proposed_changes {
-            config.set(&path, value);
+        Self::apply_changes(&mut config, proposed_changes);
+
+        Ok(ConfigResponse { config, metadata: Self::preview_metadata(district_id, judge_id) })
+    }
+
+    /// Preview only the keys the proposed changes would add, remove or change
+    pub async fn preview_config_diff(
+        &self,
+        district_id: &str,
+        judge_id: Option<&str>,
+        proposed_changes: HashMap<String, Value>,
+    ) -> Result<ConfigDiff, ApiError> {
+        let current = self.repository.get_merged_config(district_id, judge_id).await?;
+        let mut preview = current.clone();
+        Self::apply_changes(&mut preview, proposed_changes);
+
+        Ok(ConfigDiff { changes: current.diff(&preview), metadata: Self::preview_metadata(district_id, judge_id) })
+    }
+
+    fn apply_changes(config: &mut Configuration, changes: HashMap<String, Value>) {
+        for (path, value) in changes {
+            if value.is_null() {
+                config.remove(&path);
+            } else {
+                config.set(&path, value);
+            }
         }
+    }
 
-        // Create metadata
-        let metadata = ConfigMetadata {
+    fn preview_metadata(district_id: &str, judge_id: Option<&str>) -> ConfigMetadata {
+        ConfigMetadata {
             district: district_id.to_string(),
             judge: judge_id.map(|s| s.to_string()),
             base_config: "base.toml".to_string(),
             has_district_overrides: true, // Preview always shows as having overrides
             has_judge_overrides: judge_id.is_some(),
             cached_at: Utc::now(),
-        };
+        }
+    }
 
-        Ok(ConfigResponse { config, metadata })
+    /// Validate proposed preview changes; nulls remove keys and need no check
+    pub fn validate_preview(&self, changes: &HashMap<String, Value>) -> Result<(), ApiError> {
+        let set: HashMap<String, Value> =
+            changes.iter().filter(|(_, v)| !v.is_null()).map(|(k, v)| (k.clone(), v.clone())).collect();
+        self.validate_updates(&set)
     }
 
     /// Validate configuration updates before applying
@@ -182,6 +353,51 @@ impl ConfigService {
 
     /// Validate known configuration paths and their expected types
     fn validate_known_path(&self, path: &str, value: &Value) -> Result<(), ApiError> {
+        if path == AssignmentStrategy::CONFIG_PATH {
+            return match value.as_str().and_then(AssignmentStrategy::from_config_value) {
+                Some(_) => Ok(()),
+                None => Err(ApiError::BadRequest(format!(
+                    "Path '{}' expects one of balanced, random_draw, weighted_random, round_robin, got {:?}",
+                    path, value
+                ))),
+            };
+        }
+
+        if path == WeeklyDocket::UTC_OFFSET_CONFIG_PATH {
+            return match value.as_i64().and_then(|h| i32::try_from(h).ok()).and_then(WeeklyDocket::offset) {
+                Some(_) => Ok(()),
+                None => Err(ApiError::BadRequest(format!(
+                    "Path '{}' expects whole hours between -12 and 14, got {:?}",
+                    path, value
+                ))),
+            };
+        }
+
+        if path == CaseNumberFormat::PADDING_CONFIG_PATH {
+            return match value.as_u64().and_then(|p| usize::try_from(p).ok()) {
+                Some(padding) if CaseNumberFormat::PADDING_RANGE.contains(&padding) => Ok(()),
+                _ => Err(ApiError::BadRequest(format!(
+                    "Path '{}' expects a whole number between 1 and 10, got {:?}",
+                    path, value
+                ))),
+            };
+        }
+
+        if path == CaseNumberFormat::TYPES_CONFIG_PATH {
+            let valid = value.as_object().is_some_and(|types| {
+                !types.is_empty()
+                    && types.values().all(|a| a.as_str().is_some_and(CaseNumberFormat::valid_abbreviation))
+            });
+            return if valid {
+                Ok(())
+            } else {
+                Err(ApiError::BadRequest(format!(
+                    "Path '{}' expects an object of case types to 1-4 letter abbreviations, got {:?}",
+                    path, value
+                )))
+            };
+        }
+
         // Define expected types for known paths
         let expected_types = [
             ("deadline", "number"),
@@ -232,7 +448,9 @@ impl ConfigService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::config::{Configuration, CourtClosure};
     use serde_json::json;
+    use std::sync::Mutex;
 
     #[test]
     fn test_validate_updates() {
@@ -249,13 +467,110 @@ mod tests {
         assert!(service.validate_updates(&updates).is_err());
     }
 
+    #[test]
+    fn test_validate_assignment_strategy() {
+        let service = ConfigService::new(Arc::new(MockRepository));
+
+        let mut updates = HashMap::new();
+        updates.insert("case_assignment.strategy".to_string(), json!("round_robin"));
+        assert!(service.validate_updates(&updates).is_ok());
+
+        updates.insert("case_assignment.strategy".to_string(), json!("coin_flip"));
+        assert!(service.validate_updates(&updates).is_err());
+    }
+
+    #[test]
+    fn test_validate_case_number_format() {
+        let service = ConfigService::new(Arc::new(MockRepository));
+
+        let mut updates = HashMap::new();
+        updates.insert("case_numbering.padding".to_string(), json!(4));
+        updates.insert("case_numbering.types".to_string(), json!({"criminal": "CR"}));
+        assert!(service.validate_updates(&updates).is_ok());
+
+        updates.insert("case_numbering.padding".to_string(), json!(0));
+        assert!(service.validate_updates(&updates).is_err());
+
+        updates.insert("case_numbering.padding".to_string(), json!(4));
+        updates.insert("case_numbering.types".to_string(), json!({"criminal": "cr-x"}));
+        assert!(service.validate_updates(&updates).is_err());
+    }
+
+    #[test]
+    fn test_preview_config_diff() {
+        let service = ConfigService::new(Arc::new(MockRepository));
+
+        let mut changes = HashMap::new();
+        changes.insert("deadlines.default_response_days".to_string(), json!(14));
+        changes.insert("case_assignment.strategy".to_string(), Value::Null);
+        changes.insert("workflow.auto_docket_on_filing".to_string(), json!(true));
+        assert!(service.validate_preview(&changes).is_ok());
+
+        let diff = futures::executor::block_on(service.preview_config_diff("SDNY", None, changes.clone())).unwrap();
+        assert_eq!(
+            serde_json::to_value(&diff.changes).unwrap(),
+            json!({
+                "case_assignment.strategy": {"kind": "removed", "from": "random_draw", "to": null},
+                "deadlines.default_response_days": {"kind": "changed", "from": 21, "to": 14},
+                "workflow.auto_docket_on_filing": {"kind": "added", "from": null, "to": true},
+            })
+        );
+
+        let preview = futures::executor::block_on(service.preview_config_changes("SDNY", None, changes)).unwrap();
+        assert!(preview.config.get("case_assignment.strategy").is_none());
+    }
+
+    #[test]
+    fn test_update_history_rollback_round_trip() {
+        let repo = Arc::new(MemoryRepository::default());
+        let service = ConfigService::new(repo.clone());
+        let days = |repo: &MemoryRepository| {
+            repo.overrides.lock().unwrap().as_ref().and_then(|o| o.overrides.get("deadlines.default_response_days").cloned())
+        };
+
+        // Overrides saved before history was kept become the baseline
+        *repo.overrides.lock().unwrap() =
+            Some(ConfigOverride { overrides: HashMap::from([("deadlines.default_response_days".to_string(), json!(21))]) });
+
+        let update = |value| HashMap::from([("deadlines.default_response_days".to_string(), json!(value))]);
+        futures::executor::block_on(service.update_district_config("SDNY", update(28))).unwrap();
+        futures::executor::block_on(service.update_district_config("SDNY", update(35))).unwrap();
+        assert_eq!(days(&repo), Some(json!(35)));
+
+        let history = futures::executor::block_on(service.district_override_history("SDNY")).unwrap();
+        let summary: Vec<_> = history.iter().map(|v| (v.version, v.action)).collect();
+        assert_eq!(
+            summary,
+            vec![(3, ConfigVersionAction::Update), (2, ConfigVersionAction::Update), (1, ConfigVersionAction::Baseline)]
+        );
+
+        let restored = futures::executor::block_on(service.rollback_district_overrides("SDNY", 1)).unwrap();
+        assert_eq!((restored.version, restored.action, restored.restored_from), (4, ConfigVersionAction::Rollback, Some(1)));
+        assert_eq!(days(&repo), Some(json!(21)));
+
+        // Clearing is a version too, and rolling back to it clears again
+        futures::executor::block_on(service.clear_district_overrides("SDNY")).unwrap();
+        futures::executor::block_on(service.rollback_district_overrides("SDNY", 3)).unwrap();
+        assert_eq!(days(&repo), Some(json!(35)));
+        futures::executor::block_on(service.rollback_district_overrides("SDNY", 5)).unwrap();
+        assert!(repo.overrides.lock().unwrap().is_none());
+
+        assert!(matches!(
+            futures::executor::block_on(service.rollback_district_overrides("SDNY", 42)),
+            Err(ApiError::NotFound(_))
+        ));
+    }
+
     // Mock repository for testing
     struct MockRepository;
 
     #[async_trait::async_trait]
     impl ConfigRepository for MockRepository {
         async fn get_base_config(&self) -> Result<Configuration, ApiError> {
-            Ok(Configuration::new())
+            let mut config = Configuration::new();
+            config.set("deadlines.default_response_days", json!(21));
+            config.set("case_assignment.strategy", json!("random_draw"));
+            Ok(config)
         }
 
         async fn get_district_overrides(&self, _: &str) -> Result<Option<ConfigOverride>, ApiError> {
@@ -281,5 +596,78 @@ mod tests {
         async fn delete_judge_overrides(&self, _: &str, _: &str) -> Result<(), ApiError> {
             Ok(())
         }
+
+        async fn save_district_override_version(&self, _: &str, _: &ConfigOverrideVersion) -> Result<(), ApiError> {
+            Ok(())
+        }
+
+        async fn list_district_override_versions(&self, _: &str) -> Result<Vec<ConfigOverrideVersion>, ApiError> {
+            Ok(Vec::new())
+        }
+
+        async fn get_closures(&self, _: &str) -> Result<Vec<CourtClosure>, ApiError> {
+            Ok(Vec::new())
+        }
+
+        async fn save_closures(&self, _: &str, _: &[CourtClosure]) -> Result<(), ApiError> {
+            Ok(())
+        }
+    }
+
+    /// Keeps a single district's overrides and their history in memory
+    #[derive(Default)]
+    struct MemoryRepository {
+        overrides: Mutex<Option<ConfigOverride>>,
+        versions: Mutex<Vec<ConfigOverrideVersion>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ConfigRepository for MemoryRepository {
+        async fn get_base_config(&self) -> Result<Configuration, ApiError> {
+            Ok(Configuration::new())
+        }
+
+        async fn get_district_overrides(&self, _: &str) -> Result<Option<ConfigOverride>, ApiError> {
+            Ok(self.overrides.lock().unwrap().clone())
+        }
+
+        async fn get_judge_overrides(&self, _: &str, _: &str) -> Result<Option<ConfigOverride>, ApiError> {
+            Ok(None)
+        }
+
+        async fn save_district_overrides(&self, _: &str, overrides: &ConfigOverride) -> Result<(), ApiError> {
+            *self.overrides.lock().unwrap() = Some(overrides.clone());
+            Ok(())
+        }
+
+        async fn save_judge_overrides(&self, _: &str, _: &str, _: &ConfigOverride) -> Result<(), ApiError> {
+            Ok(())
+        }
+
+        async fn delete_district_overrides(&self, _: &str) -> Result<(), ApiError> {
+            *self.overrides.lock().unwrap() = None;
+            Ok(())
+        }
+
+        async fn delete_judge_overrides(&self, _: &str, _: &str) -> Result<(), ApiError> {
+            Ok(())
+        }
+
+        async fn save_district_override_version(&self, _: &str, version: &ConfigOverrideVersion) -> Result<(), ApiError> {
+            self.versions.lock().unwrap().push(version.clone());
+            Ok(())
+        }
+
+        async fn list_district_override_versions(&self, _: &str) -> Result<Vec<ConfigOverrideVersion>, ApiError> {
+            Ok(self.versions.lock().unwrap().clone())
+        }
+
+        async fn get_closures(&self, _: &str) -> Result<Vec<CourtClosure>, ApiError> {
+            Ok(Vec::new())
+        }
+
+        async fn save_closures(&self, _: &str, _: &[CourtClosure]) -> Result<(), ApiError> {
+            Ok(())
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,80 @@
+//! Deadline dependency chains
+//!
+//! Validates a deadline's dependency before it is saved and moves dependent
+//! deadlines when their parent's due date or completion changes. Chains stay
+//! within a case, so each operation loads only the case's deadlines and
+//! hands them to [`DeadlineChain`].
+
+use crate::domain::deadline::{Deadline, DeadlineChain};
+use crate::error::{ApiError, ApiResult};
+use crate::ports::deadline_repository::DeadlineRepository;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+/// Check a deadline's `depends_on` and `offset_days` and derive its due date
+///
+/// Both must be set together, the parent must be a deadline of the same
+/// case, and the link must not close a cycle. Failures are 422s.
+pub fn apply_dependency(
+    repo: &impl DeadlineRepository,
+    deadline: &mut Deadline,
+    closures: &[NaiveDate],
+) -> ApiResult<()> {
+    let (parent_id, offset_days) = match (deadline.depends_on, deadline.offset_days) {
+        (None, None) => return Ok(()),
+        (Some(parent_id), Some(offset_days)) => (parent_id, offset_days),
+        _ => {
+            return Err(ApiError::Unprocessable(
+                "depends_on and offset_days must be given together".to_string(),
+            ))
+        }
+    };
+
+    let parent = repo
+        .find_deadline_by_id(parent_id)?
+        .ok_or_else(|| ApiError::Unprocessable(format!("Parent deadline {} not found", parent_id)))?;
+    if parent.case_id != deadline.case_id {
+        return Err(ApiError::Unprocessable(format!(
+            "Parent deadline {} belongs to another case",
+            parent_id
+        )));
+    }
+
+    let case_deadlines = repo.find_deadlines_by_case(deadline.case_id)?;
+    DeadlineChain::check_dependency(deadline.id, parent_id, &case_deadlines).map_err(ApiError::Unprocessable)?;
+
+    deadline.due_date = DeadlineChain::due_date_after(&parent, offset_days, closures);
+    Ok(())
+}
+
+/// Transitive dependents of a deadline, nearest first
+pub fn dependents(repo: &impl DeadlineRepository, deadline: &Deadline) -> ApiResult<Vec<Deadline>> {
+    let case_deadlines = repo.find_deadlines_by_case(deadline.case_id)?;
+    Ok(DeadlineChain::dependents(deadline.id, &case_deadlines).into_iter().cloned().collect())
+}
+
+/// Recalculate and save the dependents of a deadline whose due date or
+/// completion changed, returning those that moved
+pub fn recalculate_dependents(
+    repo: &impl DeadlineRepository,
+    parent: &Deadline,
+    closures: &[NaiveDate],
+    now: DateTime<Utc>,
+) -> ApiResult<Vec<Deadline>> {
+    let mut case_deadlines = repo.find_deadlines_by_case(parent.case_id)?;
+    // The caller's copy is the one just saved
+    match case_deadlines.iter_mut().find(|d| d.id == parent.id) {
+        Some(stored) => *stored = parent.clone(),
+        None => case_deadlines.push(parent.clone()),
+    }
+
+    let moved: Vec<Uuid> = DeadlineChain::recalculate_dependents(parent.id, &mut case_deadlines, closures, now);
+    let mut saved = Vec::with_capacity(moved.len());
+    for id in moved {
+        if let Some(deadline) = case_deadlines.iter().find(|d| d.id == id) {
+            repo.save_deadline(deadline)?;
+            saved.push(deadline.clone());
+        }
+    }
+    Ok(saved)
+}
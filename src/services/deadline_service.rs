@@ -0,0 +1,256 @@
+//! Deadline compliance reporting
+//!
+//! Builds the compliance report court administrators submit to the
+//! Administrative Office. [`compliance_report`] works on plain deadlines so
+//! the figures can be checked without storage or the PDF adapter;
+//! [`compliance_report_document`] hands the result to the PDF pipeline.
+
+use crate::domain::deadline::{
+    CategoryCompliance, ComplianceReportDetail, ComplianceTrend, Deadline, DeadlineStatus,
+    MissedJurisdictionalDeadline,
+};
+use crate::domain::document::{CaseNumber, District, DocumentError, DocumentMetadata, DocumentType, PageLayout};
+use crate::error::ApiResult;
+use crate::ports::deadline_repository::{DeadlineComplianceRepository, DeadlineQuery};
+use crate::ports::document_generator::DocumentRequest;
+use chrono::{DateTime, Utc};
+
+/// Build the compliance report for a period from the district's deadlines
+///
+/// Deadlines are placed in a period by their effective due date, so a
+/// deadline moved by a granted extension counts where it finally fell due.
+/// The trend compares against the period of equal length just before.
+pub fn compliance_report(
+    deadlines: &[Deadline],
+    period_start: DateTime<Utc>,
+    period_end: DateTime<Utc>,
+) -> ComplianceReportDetail {
+    let in_period: Vec<&Deadline> = deadlines
+        .iter()
+        .filter(|d| d.effective_due_date() >= period_start && d.effective_due_date() <= period_end)
+        .collect();
+
+    let prior_period_start = period_start - (period_end - period_start);
+    let prior: Vec<&Deadline> = deadlines
+        .iter()
+        .filter(|d| d.effective_due_date() >= prior_period_start && d.effective_due_date() < period_start)
+        .collect();
+
+    let on_time = in_period.iter().filter(|d| completed_on_time(d)).count();
+    let compliance_rate = rate(on_time, in_period.len());
+    let prior_compliance_rate = rate(prior.iter().filter(|d| completed_on_time(d)).count(), prior.len());
+
+    let mut categories: Vec<CategoryCompliance> = Vec::new();
+    for deadline in &in_period {
+        let index = match categories.iter().position(|c| c.deadline_type == deadline.deadline_type) {
+            Some(index) => index,
+            None => {
+                categories.push(CategoryCompliance {
+                    deadline_type: deadline.deadline_type.clone(),
+                    tracked: 0,
+                    completed_on_time: 0,
+                    missed: 0,
+                    compliance_rate: 0.0,
+                });
+                categories.len() - 1
+            }
+        };
+        let category = &mut categories[index];
+        category.tracked += 1;
+        if completed_on_time(deadline) {
+            category.completed_on_time += 1;
+        }
+        if missed(deadline) {
+            category.missed += 1;
+        }
+    }
+    for category in &mut categories {
+        category.compliance_rate = rate(category.completed_on_time, category.tracked);
+    }
+    categories.sort_by(|a, b| {
+        b.tracked
+            .cmp(&a.tracked)
+            .then_with(|| format!("{:?}", a.deadline_type).cmp(&format!("{:?}", b.deadline_type)))
+    });
+
+    let mut missed_jurisdictional: Vec<MissedJurisdictionalDeadline> = in_period
+        .iter()
+        .filter(|d| d.is_jurisdictional && missed(d))
+        .map(|d| MissedJurisdictionalDeadline {
+            deadline_id: d.id,
+            case_id: d.case_id,
+            deadline_type: d.deadline_type.clone(),
+            applicable_rule: d.applicable_rule.clone(),
+            responsible_party: d.responsible_party.clone(),
+            due_date: d.effective_due_date(),
+            completion_date: d.completion_date,
+        })
+        .collect();
+    missed_jurisdictional.sort_by_key(|m| m.due_date);
+
+    ComplianceReportDetail {
+        period_start,
+        period_end,
+        deadlines_tracked: in_period.len(),
+        completed_on_time: on_time,
+        compliance_rate,
+        categories,
+        missed_jurisdictional,
+        trend: ComplianceTrend {
+            prior_period_start,
+            prior_period_end: period_start,
+            prior_deadlines_tracked: prior.len(),
+            prior_compliance_rate,
+            change: compliance_rate - prior_compliance_rate,
+        },
+    }
+}
+
+/// Load the district's deadlines and build the compliance report for a period
+pub fn load_compliance_report(
+    repo: &impl DeadlineComplianceRepository,
+    period_start: DateTime<Utc>,
+    period_end: DateTime<Utc>,
+) -> ApiResult<ComplianceReportDetail> {
+    let (deadlines, _) = repo.search_deadlines(DeadlineQuery {
+        limit: usize::MAX,
+        ..Default::default()
+    })?;
+    Ok(compliance_report(&deadlines, period_start, period_end))
+}
+
+/// Document request for rendering the report through the PDF pipeline
+///
+/// The report is district-wide, so the reporting period stands in for the
+/// case number on the document.
+pub fn compliance_report_document(
+    report: ComplianceReportDetail,
+    district: &str,
+) -> Result<DocumentRequest, DocumentError> {
+    let period = format!(
+        "{}-to-{}",
+        report.period_start.format("%Y-%m-%d"),
+        report.period_end.format("%Y-%m-%d")
+    );
+    Ok(DocumentRequest {
+        case_number: CaseNumber::new(period)?,
+        document_type: DocumentType::ComplianceReport,
+        district: District::new(district.to_string())?,
+        metadata: DocumentMetadata::ComplianceReport { report },
+        layout: PageLayout::default(),
+    })
+}
+
+fn completed_on_time(deadline: &Deadline) -> bool {
+    deadline.status == DeadlineStatus::Completed
+        && deadline.completion_date.is_some_and(|date| date <= deadline.effective_due_date())
+}
+
+fn missed(deadline: &Deadline) -> bool {
+    match deadline.status {
+        DeadlineStatus::Overdue => true,
+        DeadlineStatus::Completed => deadline.completion_date.is_some_and(|date| date > deadline.effective_due_date()),
+        _ => false,
+    }
+}
+
+/// Percent of `tracked` met on time; an empty period is fully compliant
+fn rate(on_time: usize, tracked: usize) -> f32 {
+    if tracked == 0 {
+        100.0
+    } else {
+        on_time as f32 / tracked as f32 * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::deadline::DeadlineType;
+    use chrono::{Duration, TimeZone};
+    use uuid::Uuid;
+
+    fn deadline(
+        deadline_type: DeadlineType,
+        due_date: DateTime<Utc>,
+        status: DeadlineStatus,
+        completed_days_after_due: Option<i64>,
+        is_jurisdictional: bool,
+    ) -> Deadline {
+        Deadline {
+            id: Uuid::new_v4(),
+            case_id: Uuid::new_v4(),
+            deadline_type,
+            due_date,
+            triggering_event: "verdict_entered".to_string(),
+            triggering_date: due_date - Duration::days(14),
+            applicable_rule: "FRAP 4(b)(1)(A)".to_string(),
+            description: String::new(),
+            responsible_party: "Defendant".to_string(),
+            is_jurisdictional,
+            is_extendable: !is_jurisdictional,
+            status,
+            completion_date: completed_days_after_due.map(|days| due_date + Duration::days(days)),
+            extension_requests: Vec::new(),
+            reminders_sent: Vec::new(),
+            due_date_history: Vec::new(),
+            depends_on: None,
+            offset_days: None,
+        }
+    }
+
+    #[test]
+    fn test_report_rates_categories_missed_and_trend() {
+        let start = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 4, 30, 23, 59, 59).unwrap();
+        let mid = start + Duration::days(10);
+        let prior = start - Duration::days(10);
+
+        let deadlines = vec![
+            deadline(DeadlineType::Motion, mid, DeadlineStatus::Completed, Some(-1), false),
+            deadline(DeadlineType::Motion, mid, DeadlineStatus::Completed, Some(2), false),
+            deadline(DeadlineType::Motion, mid, DeadlineStatus::Pending, None, false),
+            deadline(DeadlineType::NoticeOfAppeal, mid + Duration::days(5), DeadlineStatus::Overdue, None, true),
+            deadline(DeadlineType::NoticeOfAppeal, mid, DeadlineStatus::Completed, Some(0), true),
+            // Prior period: one of two on time
+            deadline(DeadlineType::Motion, prior, DeadlineStatus::Completed, Some(0), false),
+            deadline(DeadlineType::Motion, prior, DeadlineStatus::Overdue, None, false),
+            // Outside both periods
+            deadline(DeadlineType::Motion, end + Duration::days(1), DeadlineStatus::Overdue, None, true),
+        ];
+
+        let report = compliance_report(&deadlines, start, end);
+
+        assert_eq!(report.deadlines_tracked, 5);
+        assert_eq!(report.completed_on_time, 2);
+        assert_eq!(report.compliance_rate, 40.0);
+
+        assert_eq!(report.categories.len(), 2);
+        assert_eq!(report.categories[0].deadline_type, DeadlineType::Motion);
+        assert_eq!(report.categories[0].tracked, 3);
+        assert_eq!(report.categories[0].missed, 1);
+        assert!((report.categories[0].compliance_rate - 100.0 / 3.0).abs() < 0.01);
+        assert_eq!(report.categories[1].compliance_rate, 50.0);
+
+        assert_eq!(report.missed_jurisdictional.len(), 1);
+        assert_eq!(report.missed_jurisdictional[0].deadline_type, DeadlineType::NoticeOfAppeal);
+
+        assert_eq!(report.trend.prior_period_end, start);
+        assert_eq!(report.trend.prior_deadlines_tracked, 2);
+        assert_eq!(report.trend.prior_compliance_rate, 50.0);
+        assert_eq!(report.trend.change, -10.0);
+    }
+
+    #[test]
+    fn test_empty_period_is_fully_compliant() {
+        let start = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let report = compliance_report(&[], start, start + Duration::days(30));
+
+        assert_eq!(report.compliance_rate, 100.0);
+        assert!(report.categories.is_empty());
+        assert_eq!(report.trend.change, 0.0);
+
+        let request = compliance_report_document(report, "SDNY").unwrap();
+        assert_eq!(request.case_number.as_str(), "2025-04-01-to-2025-05-01");
+    }
+}
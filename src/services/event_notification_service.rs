@@ -0,0 +1,108 @@
+//! Court event notification lists
+//!
+//! Resolves an event's participants against the attorney repository to
+//! decide how each one is told of the event. Attorneys get electronic notice
+//! at the email on file, represented parties are noticed through their
+//! counsel, and parties without counsel are flagged for service by mail.
+
+use crate::domain::attorney::{Attorney, Party};
+use crate::domain::docket::{
+    CalendarEntry, EventNotificationList, EventParticipant, NotificationDelivery, NotificationRecipient,
+    ParticipantKind,
+};
+use crate::error::ApiResult;
+use crate::ports::attorney_repository::AttorneyRepository;
+
+/// Build the notification list for an event, in participant order
+pub fn notification_list(
+    event: &CalendarEntry,
+    repo: &impl AttorneyRepository,
+) -> ApiResult<EventNotificationList> {
+    let recipients = event
+        .participants
+        .iter()
+        .map(|participant| resolve(participant, repo))
+        .collect::<ApiResult<Vec<_>>>()?;
+    Ok(EventNotificationList::new(event, recipients))
+}
+
+fn resolve(participant: &EventParticipant, repo: &impl AttorneyRepository) -> ApiResult<NotificationRecipient> {
+    let mut recipient = NotificationRecipient {
+        kind: participant.kind,
+        id: participant.id.clone(),
+        name: participant.name.clone(),
+        required: participant.required,
+        delivery: NotificationDelivery::Unresolved,
+        email: None,
+        mailing_address: None,
+        note: None,
+    };
+
+    let Some(id) = participant.id.as_deref() else {
+        recipient.note = Some("No attorney or party id on file".to_string());
+        return Ok(recipient);
+    };
+
+    match participant.kind {
+        ParticipantKind::Attorney => match repo.find_attorney_by_id(id)? {
+            Some(attorney) => {
+                if recipient.name.is_empty() {
+                    recipient.name = attorney_name(&attorney);
+                }
+                recipient.delivery = NotificationDelivery::Email;
+                recipient.email = Some(attorney.email);
+            }
+            None => recipient.note = Some(format!("Attorney {} not found", id)),
+        },
+        ParticipantKind::Party => match repo.find_party_by_id(id)? {
+            Some(party) => resolve_party(&mut recipient, party, repo)?,
+            None => recipient.note = Some(format!("Party {} not found", id)),
+        },
+        ParticipantKind::Other => {
+            recipient.note = Some("No attorney or party id on file".to_string());
+        }
+    }
+    Ok(recipient)
+}
+
+/// Notice a party through counsel, or by mail when unrepresented
+fn resolve_party(
+    recipient: &mut NotificationRecipient,
+    party: Party,
+    repo: &impl AttorneyRepository,
+) -> ApiResult<()> {
+    if recipient.name.is_empty() {
+        recipient.name = party.name.clone();
+    }
+
+    if party.is_represented() {
+        let counsel = party
+            .get_lead_counsel()
+            .or_else(|| party.attorneys.iter().find(|a| a.end_date.is_none()));
+        if let Some(counsel) = counsel {
+            match repo.find_attorney_by_id(&counsel.attorney_id)? {
+                Some(attorney) => {
+                    recipient.delivery = NotificationDelivery::Email;
+                    recipient.note = Some(format!("Noticed through counsel {}", attorney_name(&attorney)));
+                    recipient.email = Some(attorney.email);
+                }
+                None => recipient.note = Some(format!("Counsel {} not found", counsel.attorney_id)),
+            }
+        }
+        return Ok(());
+    }
+
+    recipient.email = party.service_email.or(party.email);
+    recipient.mailing_address = party.service_address.or(party.address);
+    if recipient.mailing_address.is_some() {
+        recipient.delivery = NotificationDelivery::Postal;
+        recipient.note = Some("Unrepresented party; serve by mail".to_string());
+    } else {
+        recipient.note = Some("Unrepresented party has no mailing address".to_string());
+    }
+    Ok(())
+}
+
+fn attorney_name(attorney: &Attorney) -> String {
+    format!("{} {}", attorney.first_name, attorney.last_name)
+}
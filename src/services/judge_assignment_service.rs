@@ -0,0 +1,143 @@
+//! Repository-backed case reassignment
+//!
+//! Judge selection for [`JudgeAssignmentService`] lives in the domain. This
+//! module adds the step that persists a reassignment, so every handler that
+//! moves a case off a recused judge updates assignments and caseloads the
+//! same way.
+
+use crate::domain::judge::{
+    AssignmentType, CaseAssignment, JudgeAssignmentService, ReassignmentStatus, RecusalMotion,
+};
+use crate::error::{ApiError, ApiResult};
+use crate::ports::judge_repository::{CaseAssignmentRepository, JudgeRepository};
+use chrono::Utc;
+use uuid::Uuid;
+
+/// Assignment changes made by a reassignment
+#[derive(Debug, Default)]
+pub struct ReassignmentOutcome {
+    /// The replacement judge's assignment
+    pub assignment: Option<CaseAssignment>,
+}
+
+impl JudgeAssignmentService {
+    /// Move a case off the judge named in a recusal motion
+    ///
+    /// Supersedes the recused judge's current assignment and takes the case
+    /// off their caseload. With a replacement, a reassignment to that judge is
+    /// created and added to their caseload; without one the case awaits
+    /// reassignment. The motion's replacement and reassignment status are
+    /// updated, but saving the motion is left to the caller.
+    ///
+    /// Repeating a reassignment to the same replacement changes nothing.
+    pub fn execute_reassignment(
+        repo: &(impl JudgeRepository + CaseAssignmentRepository),
+        motion: &mut RecusalMotion,
+        replacement_id: Option<Uuid>,
+        reason: &str,
+    ) -> ApiResult<ReassignmentOutcome> {
+        if replacement_id == Some(motion.judge_id) {
+            return Err(ApiError::BadRequest(
+                "The recused judge cannot be assigned as the replacement".to_string(),
+            ));
+        }
+
+        let current = repo
+            .find_assignment_by_case(motion.case_id)?
+            .filter(|a| a.superseded_date.is_none());
+
+        if let Some(current) = &current {
+            if Some(current.judge_id) == replacement_id {
+                Self::record_outcome(motion, replacement_id);
+                return Ok(ReassignmentOutcome { assignment: Some(current.clone()) });
+            }
+            if current.judge_id != motion.judge_id {
+                return Err(ApiError::Conflict(format!(
+                    "Case {} is assigned to judge {}, not the recused judge {}",
+                    motion.case_id, current.judge_id, motion.judge_id
+                )));
+            }
+        }
+
+        if let Some(replacement_id) = replacement_id {
+            if let Some(judge) = repo.find_judge_by_id(replacement_id)? {
+                if !judge.can_accept_new_cases() {
+                    return Err(ApiError::Conflict(format!(
+                        "Replacement judge {} cannot accept new cases",
+                        replacement_id
+                    )));
+                }
+            }
+        }
+
+        let assignment = replacement_id.map(|judge_id| CaseAssignment {
+            previous_judge_id: Some(motion.judge_id),
+            reassignment_reason: Some(reason.to_string()),
+            ..Self::create_assignment(
+                motion.case_id,
+                judge_id,
+                AssignmentType::Reassignment,
+                format!("Reassigned due to recusal: {}", motion.detailed_grounds),
+                None,
+                current.as_ref().and_then(|c| c.case_type),
+            )
+        });
+
+        match current {
+            Some(mut old) => {
+                old.superseded_date = Some(Utc::now());
+                old.superseded_by = assignment.as_ref().map(|a| a.id);
+                repo.save_assignment(&old)?;
+                Self::adjust_caseload(repo, old.judge_id, false)?;
+            }
+            None => {
+                crate::request_log!(
+                    "reassignment: case {} has no current assignment; caseload of recused judge {} unchanged",
+                    motion.case_id, motion.judge_id
+                );
+            }
+        }
+
+        if let Some(new) = &assignment {
+            repo.save_assignment(new)?;
+            Self::adjust_caseload(repo, new.judge_id, true)?;
+        }
+
+        Self::record_outcome(motion, replacement_id);
+        Ok(ReassignmentOutcome { assignment })
+    }
+
+    fn record_outcome(motion: &mut RecusalMotion, replacement_id: Option<Uuid>) {
+        motion.replacement_judge_id = replacement_id;
+        motion.reassignment_status = Some(match replacement_id {
+            Some(_) => ReassignmentStatus::Reassigned,
+            None => ReassignmentStatus::AwaitingReassignment,
+        });
+    }
+
+    /// Add a case to, or take one off, a judge's caseload
+    ///
+    /// A missing judge record is logged rather than failing the reassignment,
+    /// since the assignment itself has already been saved.
+    fn adjust_caseload(repo: &impl JudgeRepository, judge_id: Uuid, taking_case: bool) -> ApiResult<()> {
+        let Some(mut judge) = repo.find_judge_by_id(judge_id)? else {
+            crate::request_log!(
+                "reassignment: judge {} not found; caseload not {}",
+                judge_id,
+                if taking_case { "incremented" } else { "decremented" }
+            );
+            return Ok(());
+        };
+
+        if taking_case {
+            if let Err(e) = judge.assign_case() {
+                crate::request_log!("reassignment: caseload of judge {} not incremented: {}", judge_id, e);
+                return Ok(());
+            }
+        } else {
+            judge.unassign_case();
+        }
+        repo.save_judge(&judge)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+//! Statute of limitations tracking
+//!
+//! A case created with its offense date and statute gets a
+//! [`LimitationsTracker`] and, when the statute sets a period, a
+//! jurisdictional [`Deadline`] to indict by. Recording a tolling period moves
+//! both together, with the old date kept in the deadline's history.
+
+use crate::domain::criminal_case::{CriminalCase, LimitationsAtRisk, LimitationsTracker};
+use crate::domain::deadline::{Deadline, DeadlineMonitor, DeadlineStatus, DeadlineType, DueDateChange};
+use crate::domain::docket::DocketEntryType;
+use crate::error::{ApiError, ApiResult};
+use crate::ports::case_repository::CaseRepository;
+use crate::ports::deadline_repository::DeadlineRepository;
+use crate::ports::docket_repository::DocketRepository;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// Days ahead the at-risk report looks unless asked otherwise
+pub const DEFAULT_AT_RISK_WINDOW_DAYS: i64 = 180;
+
+/// Start tracking limitations on a new case, saving its deadline
+pub fn start_tracking(
+    case: &mut CriminalCase,
+    offense_date: DateTime<Utc>,
+    offense_statute: String,
+    deadlines: &impl DeadlineRepository,
+) -> ApiResult<()> {
+    if offense_statute.trim().is_empty() {
+        return Err(ApiError::BadRequest("Offense statute cannot be empty".to_string()));
+    }
+
+    let mut tracker = LimitationsTracker::new(offense_date, offense_statute);
+    if let Some(due_date) = tracker.deadline {
+        let deadline = Deadline {
+            id: Uuid::new_v4(),
+            case_id: case.id,
+            deadline_type: DeadlineType::StatuteOfLimitations,
+            due_date,
+            triggering_event: "offense_committed".to_string(),
+            triggering_date: offense_date,
+            applicable_rule: tracker.authority.clone(),
+            description: format!("Indictment or information for {} must be filed", tracker.offense_statute),
+            responsible_party: "Government".to_string(),
+            is_jurisdictional: true,
+            is_extendable: false,
+            status: DeadlineStatus::Pending,
+            completion_date: None,
+            extension_requests: Vec::new(),
+            reminders_sent: Vec::new(),
+            due_date_history: Vec::new(),
+            depends_on: None,
+            offset_days: None,
+        };
+        deadlines.save_deadline(&deadline)?;
+        tracker.deadline_id = Some(deadline.id);
+    }
+
+    case.limitations = Some(tracker);
+    Ok(())
+}
+
+/// Toll a case's limitations clock, moving its deadline
+pub fn record_tolling(
+    cases: &impl CaseRepository,
+    deadlines: &impl DeadlineRepository,
+    case_id: Uuid,
+    reason: String,
+    start_date: DateTime<Utc>,
+    end_date: DateTime<Utc>,
+) -> ApiResult<LimitationsTracker> {
+    let mut case = cases
+        .find_by_id(case_id)?
+        .ok_or_else(|| ApiError::NotFound(format!("Case with id {} not found", case_id)))?;
+    let tracker = case.limitations.as_mut().ok_or_else(|| {
+        ApiError::BadRequest(format!("Case {} has no offense date and statute to track", case_id))
+    })?;
+
+    let previous_due_date = tracker.deadline;
+    tracker.toll(reason, start_date, end_date).map_err(ApiError::BadRequest)?;
+
+    if let (Some(id), Some(previous_due_date), Some(new_due_date)) =
+        (tracker.deadline_id, previous_due_date, tracker.deadline)
+    {
+        if let Some(mut deadline) = deadlines.find_deadline_by_id(id)? {
+            deadline.due_date_history.push(DueDateChange {
+                previous_due_date,
+                new_due_date,
+                extension_id: None,
+                recalculated_from: None,
+                ruling_date: Utc::now(),
+            });
+            deadline.due_date = new_due_date;
+            DeadlineMonitor::update_deadline_statuses(std::slice::from_mut(&mut deadline), Utc::now());
+            deadlines.save_deadline(&deadline)?;
+        }
+    }
+
+    let tracker = tracker.clone();
+    cases.save(&case)?;
+    Ok(tracker)
+}
+
+/// Unindicted cases whose limitations deadline falls within `window_days`,
+/// soonest first
+pub fn at_risk(
+    cases: &impl CaseRepository,
+    docket: &impl DocketRepository,
+    window_days: i64,
+    now: DateTime<Utc>,
+) -> ApiResult<Vec<LimitationsAtRisk>> {
+    let window_end = now + Duration::days(window_days);
+    let mut at_risk = Vec::new();
+
+    for case in cases.find_all_cases()? {
+        let Some(tracker) = &case.limitations else { continue };
+        let (Some(deadline), Some(days_remaining)) = (tracker.deadline, tracker.days_remaining(now)) else {
+            continue;
+        };
+        if deadline < now || deadline > window_end || is_charged(&case, docket)? {
+            continue;
+        }
+        at_risk.push(LimitationsAtRisk {
+            case_id: case.id,
+            case_number: case.case_number.clone(),
+            title: case.title.clone(),
+            offense_statute: tracker.offense_statute.clone(),
+            deadline,
+            days_remaining,
+        });
+    }
+
+    at_risk.sort_by_key(|c| c.deadline);
+    Ok(at_risk)
+}
+
+/// Whether an indictment or information has been filed in the case
+fn is_charged(case: &CriminalCase, docket: &impl DocketRepository) -> ApiResult<bool> {
+    let charging = |t: &DocketEntryType| matches!(t, DocketEntryType::Indictment | DocketEntryType::Information);
+    if case.docket_entries.iter().any(|e| charging(&e.entry_type)) {
+        return Ok(true);
+    }
+    for entry_type in [DocketEntryType::Indictment, DocketEntryType::Information] {
+        if !docket.find_entries_by_type(case.id, entry_type)?.is_empty() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
@@ -1,4 +1,21 @@
 //! Service layer for business logic and dependency injection
 
+pub mod appeal_service;
+pub mod attachment_service;
+pub mod case_archive_service;
+pub mod case_number_service;
+pub mod conditional_read_service;
 pub mod config_service;
-pub mod pdf_service;
\ No newline at end of file
+pub mod deadline_chain_service;
+pub mod deadline_service;
+pub mod event_notification_service;
+pub mod judge_assignment_service;
+pub mod limitations_service;
+pub mod opinion_publication_service;
+pub mod pdf_service;
+pub mod search_service;
+pub mod sentencing_service;
+pub mod tenant_export_service;
+pub mod tenant_seed;
+pub mod timeline_service;
+pub mod webhook_service;
\ No newline at end of file
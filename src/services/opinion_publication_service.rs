@@ -0,0 +1,183 @@
+//! Opinion publication
+//!
+//! Publishing gives an opinion its neutral citation, the district's next
+//! for the year, e.g. `2024 USDC SDNY 123`. The counter lives in the
+//! tenant's store and [`NeutralCitationGenerator`] advances it atomically,
+//! as case numbers do, so concurrent publications never share a citation.
+//! [`publish`] then renders the slip opinion with the citation stamped on
+//! every page and keeps the PDF with the opinion.
+
+use crate::domain::document::{
+    CaseNumber, District, DocumentError, DocumentMetadata, DocumentType, GeneratedDocument, JudgeName, PageLayout,
+};
+use crate::domain::opinion::{Citation, JudicialOpinion};
+use crate::error::{ApiError, ApiResult};
+use crate::ports::document_generator::DocumentRequest;
+use crate::ports::document_repository::{CitationCounterRepository, SlipOpinionRepository};
+use chrono::{DateTime, Datelike, Utc};
+
+/// Hands out a district's neutral citations in sequence
+pub struct NeutralCitationGenerator<'a, R> {
+    repo: &'a R,
+}
+
+impl<'a, R: CitationCounterRepository> NeutralCitationGenerator<'a, R> {
+    pub fn new(repo: &'a R) -> Self {
+        Self { repo }
+    }
+
+    /// Reserve the next neutral citation for a district and year
+    pub fn next(&self, district: &str, year: i32) -> ApiResult<String> {
+        let key = Citation::counter_key(district, year);
+        let next = self.repo.next_citation_counter_value(&key)?;
+        Ok(Citation::neutral(year, district, next))
+    }
+}
+
+/// Document request for the slip opinion of a published opinion
+///
+/// The opinion's docket number stands in for the case number.
+pub fn slip_opinion_document(opinion: &JudicialOpinion, district: &str) -> Result<DocumentRequest, DocumentError> {
+    let citation = opinion
+        .citation
+        .as_ref()
+        .and_then(|c| c.neutral_citation.clone())
+        .ok_or_else(|| DocumentError::GenerationFailed("Opinion has no neutral citation".to_string()))?;
+    let published_at = opinion
+        .published_at
+        .ok_or_else(|| DocumentError::GenerationFailed("Opinion has not been published".to_string()))?;
+
+    Ok(DocumentRequest {
+        case_number: CaseNumber::new(opinion.docket_number.clone())?,
+        document_type: DocumentType::SlipOpinion,
+        district: District::new(district.to_string())?,
+        metadata: DocumentMetadata::SlipOpinion {
+            citation,
+            case_name: opinion.case_name.clone(),
+            title: opinion.title.clone(),
+            judge_name: JudgeName::new(opinion.author_judge_name.clone())?,
+            syllabus: opinion.syllabus.clone(),
+            content: opinion.content.clone(),
+            published_at,
+        },
+        layout: PageLayout::default(),
+    })
+}
+
+/// Publish an opinion: assign its neutral citation, record the publication
+/// date and render and store its slip opinion
+///
+/// Any reporter citations already in `citation` are kept; the neutral
+/// citation is always the one assigned here. `render` is the document
+/// service's generator. The caller saves the opinion.
+pub fn publish<R, F>(
+    repo: &R,
+    render: F,
+    opinion: &mut JudicialOpinion,
+    mut citation: Citation,
+    district: &str,
+    now: DateTime<Utc>,
+) -> ApiResult<GeneratedDocument>
+where
+    R: CitationCounterRepository + SlipOpinionRepository,
+    F: FnOnce(DocumentRequest) -> Result<GeneratedDocument, DocumentError>,
+{
+    let neutral = NeutralCitationGenerator::new(repo).next(district, now.year())?;
+    citation.neutral_citation = Some(neutral);
+    opinion.publish(citation, now);
+
+    let request = slip_opinion_document(opinion, district).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let generated = render(request).map_err(|e| ApiError::Internal(e.to_string()))?;
+    repo.save_slip_opinion(&opinion.id, &generated.pdf_data)?;
+    opinion.slip_opinion_document_id = Some(generated.document.id.as_uuid().to_string());
+    Ok(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::pdf_writer_adapter::PdfWriterAdapter;
+    use crate::domain::opinion::OpinionType;
+    use chrono::TimeZone;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    /// In-memory counters and slip opinions
+    #[derive(Default)]
+    struct MemoryRepository {
+        counters: RefCell<HashMap<String, u64>>,
+        slips: RefCell<HashMap<String, Vec<u8>>>,
+    }
+
+    impl CitationCounterRepository for MemoryRepository {
+        fn next_citation_counter_value(&self, key: &str) -> ApiResult<u64> {
+            let mut counters = self.counters.borrow_mut();
+            let value = counters.entry(key.to_string()).or_insert(0);
+            *value += 1;
+            Ok(*value)
+        }
+    }
+
+    impl SlipOpinionRepository for MemoryRepository {
+        fn save_slip_opinion(&self, opinion_id: &str, pdf: &[u8]) -> ApiResult<()> {
+            self.slips.borrow_mut().insert(opinion_id.to_string(), pdf.to_vec());
+            Ok(())
+        }
+
+        fn find_slip_opinion(&self, opinion_id: &str) -> ApiResult<Option<Vec<u8>>> {
+            Ok(self.slips.borrow().get(opinion_id).cloned())
+        }
+    }
+
+    fn filed_opinion() -> JudicialOpinion {
+        let mut opinion = JudicialOpinion::new(
+            "CASE-123".to_string(),
+            "Smith v. Jones".to_string(),
+            "1:24-cv-00042".to_string(),
+            "JUDGE-A".to_string(),
+            "Hon. Jane Doe".to_string(),
+            OpinionType::Majority,
+            "Opinion and Order".to_string(),
+        );
+        opinion.content = "The motion for summary judgment is granted.".to_string();
+        opinion.file();
+        opinion
+    }
+
+    #[test]
+    fn test_citations_are_sequential_per_district_and_year() {
+        let repo = MemoryRepository::default();
+        let generator = NeutralCitationGenerator::new(&repo);
+
+        assert_eq!(generator.next("SDNY", 2024).unwrap(), "2024 USDC SDNY 1");
+        assert_eq!(generator.next("SDNY", 2024).unwrap(), "2024 USDC SDNY 2");
+        assert_eq!(generator.next("EDNY", 2024).unwrap(), "2024 USDC EDNY 1");
+        assert_eq!(generator.next("SDNY", 2025).unwrap(), "2025 USDC SDNY 1");
+        assert_eq!(generator.next("sdny", 2024).unwrap(), "2024 USDC SDNY 3");
+    }
+
+    #[test]
+    fn test_publish_assigns_citation_and_stores_slip_opinion() {
+        let repo = MemoryRepository::default();
+        let now = Utc.with_ymd_and_hms(2024, 3, 5, 15, 0, 0).unwrap();
+        let reporter = Citation { federal_supplement: Some("701 F. Supp. 3d 12".to_string()), ..Default::default() };
+
+        let render = |request: DocumentRequest| PdfWriterAdapter::new().generate_document_sync(request.to_court_document());
+
+        let mut first = filed_opinion();
+        publish(&repo, render, &mut first, reporter, "SDNY", now).unwrap();
+        let mut second = filed_opinion();
+        let generated = publish(&repo, render, &mut second, Citation::default(), "SDNY", now).unwrap();
+
+        let citation = first.citation.clone().unwrap();
+        assert_eq!(citation.neutral_citation.as_deref(), Some("2024 USDC SDNY 1"));
+        assert_eq!(citation.federal_supplement.as_deref(), Some("701 F. Supp. 3d 12"));
+        assert_eq!(first.published_at, Some(now));
+        assert!(first.is_locked());
+
+        assert_eq!(second.citation.unwrap().neutral_citation.as_deref(), Some("2024 USDC SDNY 2"));
+        assert_eq!(second.slip_opinion_document_id, Some(generated.document.id.as_uuid().to_string()));
+        let slip = repo.find_slip_opinion(&second.id).unwrap().unwrap();
+        assert!(slip.windows(16).any(|w| w == b"2024 USDC SDNY 2"));
+    }
+}
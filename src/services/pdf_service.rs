@@ -1,6 +1,8 @@
 use std::sync::Arc;
+use std::time::Duration;
+use crate::domain::config::Configuration;
 use crate::domain::document::{
-    CourtDocument, GeneratedDocument, DocumentError
+    BatchFailure, CourtDocument, GeneratedDocument, DocumentError, SignatureCheck, SignatureRejection
 };
 use crate::ports::document_generator::{DocumentGenerator, DocumentRequest};
 use crate::ports::document_repository::DocumentRepository;
@@ -17,7 +19,7 @@ use crate::adapters::spin_kv_signature_repository::SpinKvSignatureRepository;
 /// # Architecture
 /// - Uses dependency injection to work with abstract ports (traits)
 /// - Supports both synchronous and asynchronous operations
-/// - Multi-tenant by design (tenant_id determines which KV store to use)
+/// - Multi-tenant by design (the tenant ID passed to [`PdfService::new`] picks the KV store)
 ///
 /// # Example
 /// ```
@@ -31,8 +33,6 @@ pub struct PdfService {
     repository: Arc<dyn DocumentRepository>,
     /// Manages judge electronic signatures
     signature_repo: Arc<dyn SignatureRepository>,
-    /// Tenant ID for multi-tenant data isolation
-    tenant_id: String,
 }
 
 impl PdfService {
@@ -53,7 +53,6 @@ impl PdfService {
             generator,
             repository,
             signature_repo,
-            tenant_id: tenant_id.to_string(),
         })
     }
 
@@ -62,13 +61,11 @@ impl PdfService {
         generator: Arc<dyn DocumentGenerator>,
         repository: Arc<dyn DocumentRepository>,
         signature_repo: Arc<dyn SignatureRepository>,
-        tenant_id: String,
     ) -> Self {
         Self {
             generator,
             repository,
             signature_repo,
-            tenant_id,
         }
     }
 
@@ -90,6 +87,16 @@ impl PdfService {
         adapter.generate_batch_sync(documents)
     }
 
+    /// Generate each document in a batch independently
+    ///
+    /// A document that fails doesn't stop the rest: invalid items and
+    /// documents that still fail to render after [`MAX_RENDER_ATTEMPTS`]
+    /// are reported in the outcome's failures, in request order.
+    pub fn generate_batch_resilient(&self, items: Vec<BatchItem>) -> BatchOutcome {
+        let adapter = PdfWriterAdapter::new();
+        render_each(items, |document| adapter.generate_document_sync(document), std::thread::sleep)
+    }
+
     /// Generate a single document (async version for future use)
     pub async fn generate_document(&self, request: DocumentRequest) -> Result<GeneratedDocument, DocumentError> {
         let document = request.to_court_document();
@@ -118,19 +125,35 @@ impl PdfService {
     }
 
     /// Store a judge's signature (sync wrapper for Spin handlers)
-    pub fn store_signature_sync(&self, judge_id: uuid::Uuid, signature_base64: &str) -> Result<(), DocumentError> {
-        // Create a simple sync implementation that stores in KV store
-        use spin_sdk::key_value::Store;
-        use chrono::Utc;
+    ///
+    /// The signature must pass [`validate_signature_image`] under `limits`;
+    /// its format, size and hash are stored alongside it.
+    pub fn store_signature_sync(
+        &self,
+        judge_id: uuid::Uuid,
+        signature_base64: &str,
+        limits: &SignatureLimits,
+    ) -> Result<crate::ports::signature_repository::JudgeSignature, DocumentError> {
+        futures::executor::block_on(self.store_signature(judge_id, signature_base64, limits))
+    }
 
-        // CRITICAL: Use tenant-specific store, not default!
-        if self.tenant_id.is_empty() {
-            return Err(DocumentError::GenerationFailed("TENANT_NOT_SPECIFIED: tenant ID is required".to_string()));
-        }
-        let store = Store::open(&self.tenant_id)
-            .map_err(|e| DocumentError::GenerationFailed(format!("Failed to open tenant store '{}': {:?}", self.tenant_id, e)))?;
+    /// Get a judge's stored signature (sync wrapper for Spin handlers)
+    pub fn get_signature_sync(&self, judge_id: uuid::Uuid) -> Result<Option<crate::ports::signature_repository::JudgeSignature>, DocumentError> {
+        futures::executor::block_on(self.get_signature(judge_id))
+    }
 
+    /// Store a judge's signature
+    pub async fn store_signature(
+        &self,
+        judge_id: uuid::Uuid,
+        signature_base64: &str,
+        limits: &SignatureLimits,
+    ) -> Result<crate::ports::signature_repository::JudgeSignature, DocumentError> {
+        use chrono::Utc;
         use sha2::{Sha256, Digest};
+
+        let image = validate_signature_image(signature_base64, limits).map_err(DocumentError::InvalidSignature)?;
+
         let mut hasher = Sha256::new();
         hasher.update(signature_base64.as_bytes());
         let hash = hasher.finalize();
@@ -140,60 +163,452 @@ impl PdfService {
             signature_base64: signature_base64.to_string(),
             uploaded_at: Utc::now().to_rfc3339(),
             signature_hash: format!("{:x}", hash),
+            format: Some(image.format.to_string()),
+            width: Some(image.width),
+            height: Some(image.height),
+            byte_size: Some(image.byte_size),
         };
 
-        let key = format!("signature_{}", judge_id);
-        let value = serde_json::to_vec(&signature)
-            .map_err(|e| DocumentError::GenerationFailed(format!("Failed to serialize: {}", e)))?;
+        self.signature_repo
+            .store_signature(&signature)
+            .await
+            .map_err(|e| DocumentError::GenerationFailed(format!("Failed to store signature: {:?}", e)))?;
+        Ok(signature)
+    }
+
+    /// Get a judge's stored signature
+    pub async fn get_signature(&self, judge_id: uuid::Uuid) -> Result<Option<crate::ports::signature_repository::JudgeSignature>, DocumentError> {
+        self.signature_repo
+            .get_signature(judge_id)
+            .await
+            .map_err(|e| DocumentError::GenerationFailed(format!("Failed to get signature: {:?}", e)))
+    }
+}
+
+/// Factory function to create PDF service based on tenant
+pub fn create_pdf_service(tenant_id: &str) -> Result<PdfService, DocumentError> {
+    PdfService::new(tenant_id)
+}
 
-        store.set(&key, &value)
-            .map_err(|e| DocumentError::GenerationFailed(format!("Failed to store: {:?}", e)))?;
+/// Attempts at rendering one document before it is reported as failed
+pub const MAX_RENDER_ATTEMPTS: u32 = 3;
 
-        Ok(())
+/// Delay before the first retry; each later retry waits twice as long
+pub const RETRY_BACKOFF: Duration = Duration::from_millis(25);
+
+/// One document of a batch, or the reason it couldn't be built from the request
+pub struct BatchItem {
+    /// Case number as given, kept so a failure can be reported against it
+    pub case_number: String,
+    pub request: Result<DocumentRequest, DocumentError>,
+}
+
+/// Documents generated from a batch and the ones that failed
+#[derive(Default)]
+pub struct BatchOutcome {
+    /// Generated documents with their position in the request
+    pub generated: Vec<(usize, GeneratedDocument)>,
+    pub failures: Vec<BatchFailure>,
+}
+
+/// Render each item, retrying transient failures with exponential backoff
+fn render_each(
+    items: Vec<BatchItem>,
+    mut render: impl FnMut(CourtDocument) -> Result<GeneratedDocument, DocumentError>,
+    mut sleep: impl FnMut(Duration),
+) -> BatchOutcome {
+    let mut outcome = BatchOutcome::default();
+    for (index, BatchItem { case_number, request }) in items.into_iter().enumerate() {
+        let result = request.and_then(|request| {
+            let document = request.to_court_document();
+            let mut attempt = 1;
+            loop {
+                match render(document.clone()) {
+                    Err(e) if e.is_transient() && attempt < MAX_RENDER_ATTEMPTS => {
+                        sleep(RETRY_BACKOFF * 2u32.pow(attempt - 1));
+                        attempt += 1;
+                    }
+                    result => break result,
+                }
+            }
+        });
+        match result {
+            Ok(generated) => outcome.generated.push((index, generated)),
+            Err(e) => outcome.failures.push(BatchFailure {
+                index,
+                case_number,
+                code: e.code().to_string(),
+                error: e.to_string(),
+            }),
+        }
     }
+    outcome
+}
 
-    /// Get a judge's stored signature (sync wrapper for Spin handlers)
-    pub fn get_signature_sync(&self, judge_id: uuid::Uuid) -> Result<Option<crate::ports::signature_repository::JudgeSignature>, DocumentError> {
-        use spin_sdk::key_value::Store;
+/// Name of the archive entry listing documents that failed to generate
+pub const BATCH_ERRORS_FILENAME: &str = "errors.txt";
+
+/// Pack generated PDFs into an in-memory ZIP archive, one entry per filename
+///
+/// Repeated filenames get a numeric suffix so no entry is overwritten. When
+/// `errors` is non-empty the archive also holds an `errors.txt` with one
+/// failure per line.
+pub fn zip_documents(documents: &[GeneratedDocument], errors: &[String]) -> Result<Vec<u8>, DocumentError> {
+    use std::collections::HashSet;
+    use std::io::{Cursor, Write};
+    use zip::write::SimpleFileOptions;
 
-        // CRITICAL: Use tenant-specific store, not default!
-        if self.tenant_id.is_empty() {
-            return Err(DocumentError::GenerationFailed("TENANT_NOT_SPECIFIED: tenant ID is required".to_string()));
+    let zip_error = |e: &dyn std::fmt::Display| DocumentError::GenerationFailed(format!("Failed to write ZIP archive: {}", e));
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let mut names = HashSet::new();
+
+    for document in documents {
+        let stem = document.filename.strip_suffix(".pdf").unwrap_or(&document.filename);
+        let mut name = document.filename.clone();
+        let mut copy = 1;
+        while !names.insert(name.clone()) {
+            copy += 1;
+            name = format!("{}-{}.pdf", stem, copy);
+        }
+        writer.start_file(name, options).map_err(|e| zip_error(&e))?;
+        writer.write_all(&document.pdf_data).map_err(|e| zip_error(&e))?;
+    }
+
+    if !errors.is_empty() {
+        writer.start_file(BATCH_ERRORS_FILENAME, options).map_err(|e| zip_error(&e))?;
+        for error in errors {
+            writeln!(writer, "{}", error).map_err(|e| zip_error(&e))?;
         }
-        let store = Store::open(&self.tenant_id)
-            .map_err(|e| DocumentError::GenerationFailed(format!("Failed to open tenant store '{}': {:?}", self.tenant_id, e)))?;
+    }
+
+    writer.finish().map(Cursor::into_inner).map_err(|e| zip_error(&e))
+}
 
-        let key = format!("signature_{}", judge_id);
+/// Configuration path holding the largest signature image, in bytes
+pub const SIGNATURE_MAX_BYTES_CONFIG_PATH: &str = "signatures.max_bytes";
+/// Configuration path holding the largest signature width or height, in pixels
+pub const SIGNATURE_MAX_DIMENSION_CONFIG_PATH: &str = "signatures.max_dimension";
+/// Largest signature image accepted unless the district sets another
+pub const DEFAULT_SIGNATURE_MAX_BYTES: u64 = 512 * 1024;
+/// Largest width or height accepted unless the district sets another
+pub const MAX_SIGNATURE_DIMENSION: u32 = 2000;
 
-        match store.get(&key) {
-            Ok(Some(data)) => {
-                let signature = serde_json::from_slice(&data)
-                    .map_err(|e| DocumentError::GenerationFailed(format!("Failed to deserialize: {}", e)))?;
-                Ok(Some(signature))
-            }
-            Ok(None) => Ok(None),
-            Err(e) => Err(DocumentError::GenerationFailed(format!("Failed to retrieve: {:?}", e)))
+/// A district's limits on signature images
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignatureLimits {
+    pub max_bytes: u64,
+    pub max_dimension: u32,
+}
+
+impl Default for SignatureLimits {
+    fn default() -> Self {
+        Self { max_bytes: DEFAULT_SIGNATURE_MAX_BYTES, max_dimension: MAX_SIGNATURE_DIMENSION }
+    }
+}
+
+impl SignatureLimits {
+    /// Limits from the district's merged configuration, defaulting any
+    /// that are not set or not positive
+    pub fn from_config(config: &Configuration) -> Self {
+        let defaults = Self::default();
+        Self {
+            max_bytes: config
+                .get_i64(SIGNATURE_MAX_BYTES_CONFIG_PATH)
+                .and_then(|b| u64::try_from(b).ok())
+                .filter(|b| *b > 0)
+                .unwrap_or(defaults.max_bytes),
+            max_dimension: config
+                .get_i64(SIGNATURE_MAX_DIMENSION_CONFIG_PATH)
+                .and_then(|d| u32::try_from(d).ok())
+                .filter(|d| *d > 0)
+                .unwrap_or(defaults.max_dimension),
         }
     }
+}
 
-    /// Store a judge's signature (async version for future use)
-    pub async fn store_signature(&self, judge_id: uuid::Uuid, signature_base64: &str) -> Result<(), DocumentError> {
-        self.signature_repo
-            .store_signature(judge_id, signature_base64)
-            .await
-            .map_err(|e| DocumentError::GenerationFailed(format!("Failed to store signature: {:?}", e)))
+/// Format and size of a validated signature image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureImage {
+    /// `png` or `jpeg`
+    pub format: &'static str,
+    pub width: u32,
+    pub height: u32,
+    /// Decoded size in bytes
+    pub byte_size: u64,
+}
+
+/// Check a base64 signature is a PNG or JPEG within `limits`
+///
+/// A `data:image/...;base64,` prefix is accepted and ignored. Dimensions
+/// come from the image header, so the pixels are never decoded.
+pub fn validate_signature_image(
+    signature_base64: &str,
+    limits: &SignatureLimits,
+) -> Result<SignatureImage, SignatureRejection> {
+    use crate::utils::image_header;
+    use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+
+    let encoded = signature_base64.trim();
+    let encoded = match encoded.strip_prefix("data:") {
+        Some(data_url) => data_url.split_once(',').map(|(_, data)| data).unwrap_or(""),
+        None => encoded,
+    };
+
+    // Refuse an oversized payload before spending time decoding it; padding
+    // makes the decoded size up to two bytes less than three per four chars
+    let minimum = (encoded.len() as u64 / 4 * 3).saturating_sub(2);
+    if minimum > limits.max_bytes {
+        return Err(too_large(minimum, limits.max_bytes));
+    }
+    let bytes = BASE64
+        .decode(encoded)
+        .map_err(|e| SignatureRejection::new(SignatureCheck::Base64, format!("not valid base64: {}", e)))?;
+    let byte_size = bytes.len() as u64;
+    if byte_size > limits.max_bytes {
+        return Err(too_large(byte_size, limits.max_bytes));
     }
 
-    /// Get a judge's stored signature (async version for future use)
-    pub async fn get_signature(&self, judge_id: uuid::Uuid) -> Result<Option<crate::ports::signature_repository::JudgeSignature>, DocumentError> {
-        self.signature_repo
-            .get_signature(judge_id)
-            .await
-            .map_err(|e| DocumentError::GenerationFailed(format!("Failed to get signature: {:?}", e)))
+    if image_header::sniff(&bytes).is_none() {
+        return Err(SignatureRejection::new(SignatureCheck::Format, "must be a PNG or JPEG image"));
+    }
+    let header = image_header::read_header(&bytes).ok_or_else(|| {
+        SignatureRejection::new(SignatureCheck::Header, "image header is truncated or malformed")
+    })?;
+
+    let (width, height) = (header.width, header.height);
+    if width == 0 || height == 0 || width > limits.max_dimension || height > limits.max_dimension {
+        return Err(SignatureRejection::over_limit(
+            SignatureCheck::Dimensions,
+            format!(
+                "image is {}x{} pixels; width and height must be 1 to {}",
+                width, height, limits.max_dimension
+            ),
+            u64::from(width.max(height)),
+            u64::from(limits.max_dimension),
+        ));
     }
+
+    Ok(SignatureImage { format: header.format.as_str(), width, height, byte_size })
 }
 
-/// Factory function to create PDF service based on tenant
-pub fn create_pdf_service(tenant_id: &str) -> Result<PdfService, DocumentError> {
-    PdfService::new(tenant_id)
-}
\ No newline at end of file
+fn too_large(byte_size: u64, max_bytes: u64) -> SignatureRejection {
+    SignatureRejection::over_limit(
+        SignatureCheck::ByteSize,
+        format!("image is {} bytes; the limit is {} bytes", byte_size, max_bytes),
+        byte_size,
+        max_bytes,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 1x1 transparent PNG
+    const TINY_PNG: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+
+    fn check(signature_base64: &str, limits: &SignatureLimits) -> Option<SignatureCheck> {
+        validate_signature_image(signature_base64, limits).err().map(|rejection| rejection.check)
+    }
+
+    /// Base64 of a PNG signature, IHDR chunk included, `width` by `height`
+    fn png_header(width: u32, height: u32) -> String {
+        use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+        let mut bytes = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+        bytes.extend_from_slice(&width.to_be_bytes());
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+        BASE64.encode(bytes)
+    }
+
+    #[test]
+    fn test_tiny_png_is_accepted() {
+        let limits = SignatureLimits::default();
+        let image = validate_signature_image(TINY_PNG, &limits).unwrap();
+        assert_eq!(image, SignatureImage { format: "png", width: 1, height: 1, byte_size: 70 });
+
+        let data_url = format!("data:image/png;base64,{}", TINY_PNG);
+        assert!(validate_signature_image(&data_url, &limits).is_ok());
+    }
+
+    #[test]
+    fn test_garbage_is_rejected_with_the_failed_check() {
+        let limits = SignatureLimits::default();
+        assert_eq!(check("not a signature!", &limits), Some(SignatureCheck::Base64));
+        assert_eq!(check("AAAA", &limits), Some(SignatureCheck::Format));
+        assert_eq!(check("", &limits), Some(SignatureCheck::Format));
+        // PNG magic bytes with no header after them
+        assert_eq!(check("iVBORw0KGgo=", &limits), Some(SignatureCheck::Header));
+    }
+
+    #[test]
+    fn test_size_limits() {
+        let limits = SignatureLimits { max_bytes: 69, max_dimension: 2000 };
+        let rejection = validate_signature_image(TINY_PNG, &limits).unwrap_err();
+        assert_eq!(rejection.check, SignatureCheck::ByteSize);
+        assert_eq!((rejection.value, rejection.max), (Some(70), Some(69)));
+
+        // Payloads far over the limit are refused before decoding
+        let huge = "A".repeat(4 * 1024 * 1024);
+        assert_eq!(check(&huge, &SignatureLimits::default()), Some(SignatureCheck::ByteSize));
+
+        let limits = SignatureLimits::default();
+        assert!(validate_signature_image(&png_header(2000, 600), &limits).is_ok());
+        let rejection = validate_signature_image(&png_header(2001, 600), &limits).unwrap_err();
+        assert_eq!(rejection.check, SignatureCheck::Dimensions);
+        assert_eq!((rejection.value, rejection.max), (Some(2001), Some(2000)));
+        assert_eq!(check(&png_header(0, 600), &limits), Some(SignatureCheck::Dimensions));
+    }
+
+    #[test]
+    fn test_limits_from_config() {
+        use serde_json::json;
+
+        let mut config = Configuration::new();
+        assert_eq!(SignatureLimits::from_config(&config), SignatureLimits::default());
+
+        config.set(SIGNATURE_MAX_BYTES_CONFIG_PATH, json!(4096));
+        config.set(SIGNATURE_MAX_DIMENSION_CONFIG_PATH, json!(800));
+        assert_eq!(SignatureLimits::from_config(&config), SignatureLimits { max_bytes: 4096, max_dimension: 800 });
+
+        config.set(SIGNATURE_MAX_DIMENSION_CONFIG_PATH, json!(-1));
+        assert_eq!(SignatureLimits::from_config(&config).max_dimension, MAX_SIGNATURE_DIMENSION);
+    }
+
+    fn request(case_number: &str) -> DocumentRequest {
+        use crate::domain::document::{CaseNumber, District, DocumentMetadata, DocumentType, PageLayout};
+
+        DocumentRequest {
+            case_number: CaseNumber::new(case_number.to_string()).unwrap(),
+            document_type: DocumentType::WaiverIndictment,
+            district: District::new("SDNY".to_string()).unwrap(),
+            metadata: DocumentMetadata::WaiverIndictment {
+                defendant_name: "John Doe".to_string(),
+                charges: "18 U.S.C. 1343".to_string(),
+            },
+            layout: PageLayout::default(),
+        }
+    }
+
+    fn generated(case_number: &str) -> GeneratedDocument {
+        PdfWriterAdapter::new().generate_document_sync(request(case_number).to_court_document()).unwrap()
+    }
+
+    fn item(case_number: &str) -> BatchItem {
+        let request = match case_number {
+            "" => Err(DocumentError::InvalidCaseNumber),
+            _ => Ok(request(case_number)),
+        };
+        BatchItem { case_number: case_number.to_string(), request }
+    }
+
+    #[test]
+    fn test_invalid_item_does_not_stop_the_batch() {
+        let items = vec![item("1:24-cr-00001"), item(""), item("1:24-cr-00003")];
+        let outcome = render_each(items, |d| PdfWriterAdapter::new().generate_document_sync(d), |_| {});
+
+        let positions: Vec<usize> = outcome.generated.iter().map(|(i, _)| *i).collect();
+        assert_eq!(positions, vec![0, 2]);
+        assert_eq!(
+            outcome.failures,
+            vec![BatchFailure {
+                index: 1,
+                case_number: String::new(),
+                code: "INVALID_CASE_NUMBER".to_string(),
+                error: "Invalid case number".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_transient_failures_are_retried_with_backoff() {
+        let mut failures_left = 2;
+        let mut delays = Vec::new();
+        let outcome = render_each(
+            vec![item("1:24-cr-00001")],
+            |d| {
+                if failures_left > 0 {
+                    failures_left -= 1;
+                    return Err(DocumentError::GenerationFailed("font cache busy".to_string()));
+                }
+                PdfWriterAdapter::new().generate_document_sync(d)
+            },
+            |delay| delays.push(delay),
+        );
+
+        assert_eq!(outcome.generated.len(), 1);
+        assert!(outcome.failures.is_empty());
+        assert_eq!(delays, vec![RETRY_BACKOFF, RETRY_BACKOFF * 2]);
+    }
+
+    #[test]
+    fn test_gives_up_after_max_render_attempts() {
+        let mut attempts = 0;
+        let outcome = render_each(
+            vec![item("1:24-cr-00001")],
+            |_| {
+                attempts += 1;
+                Err(DocumentError::GenerationFailed("out of memory".to_string()))
+            },
+            |_| {},
+        );
+
+        assert_eq!(attempts, MAX_RENDER_ATTEMPTS);
+        assert!(outcome.generated.is_empty());
+        assert_eq!(outcome.failures[0].code, "GENERATION_FAILED");
+    }
+
+    #[test]
+    fn test_invalid_input_is_not_retried() {
+        let mut attempts = 0;
+        let outcome = render_each(
+            vec![item("1:24-cr-00001")],
+            |_| {
+                attempts += 1;
+                Err(DocumentError::InvalidSignature(SignatureRejection::new(SignatureCheck::Format, "not an image")))
+            },
+            |_| panic!("invalid input should not be retried"),
+        );
+
+        assert_eq!(attempts, 1);
+        assert_eq!(outcome.failures[0].code, "INVALID_SIGNATURE");
+    }
+
+    fn entry_names(archive: Vec<u8>) -> Vec<String> {
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(archive)).unwrap();
+        (0..archive.len()).map(|i| archive.by_index(i).unwrap().name().to_string()).collect()
+    }
+
+    #[test]
+    fn test_zip_holds_one_entry_per_document() {
+        let documents = vec![generated("1:24-cr-00001"), generated("1:24-cr-00002"), generated("1:24-cr-00001")];
+        let archive = zip_documents(&documents, &[]).unwrap();
+
+        let mut reader = zip::ZipArchive::new(std::io::Cursor::new(archive.clone())).unwrap();
+        let mut first = Vec::new();
+        std::io::Read::read_to_end(&mut reader.by_index(0).unwrap(), &mut first).unwrap();
+        assert_eq!(first, documents[0].pdf_data);
+
+        assert_eq!(
+            entry_names(archive),
+            vec![
+                "waiver-indictment-1:24-cr-00001.pdf",
+                "waiver-indictment-1:24-cr-00002.pdf",
+                "waiver-indictment-1:24-cr-00001-2.pdf",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_zip_lists_failures_in_errors_txt() {
+        let errors = vec!["document 2: Invalid judge name".to_string()];
+        let archive = zip_documents(&[generated("1:24-cr-00001")], &errors).unwrap();
+
+        let mut reader = zip::ZipArchive::new(std::io::Cursor::new(archive)).unwrap();
+        assert_eq!(reader.len(), 2);
+        let mut listing = String::new();
+        std::io::Read::read_to_string(&mut reader.by_name(BATCH_ERRORS_FILENAME).unwrap(), &mut listing).unwrap();
+        assert_eq!(listing, "document 2: Invalid judge name\n");
+    }
+}
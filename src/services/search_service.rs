@@ -0,0 +1,290 @@
+//! Global search across cases, attorneys, judges and docket entries
+//!
+//! Fans a single query out to each domain's existing search method and
+//! normalizes the hits into one envelope so the front end can render a
+//! single result list.
+
+use crate::domain::attorney::Attorney;
+use crate::domain::criminal_case::CriminalCase;
+use crate::domain::docket::DocketEntry;
+use crate::domain::judge::Judge;
+use crate::error::ApiError;
+use crate::ports::attorney_repository::AttorneyRepository;
+use crate::ports::case_repository::{CaseQuery, CaseQueryRepository};
+use crate::ports::docket_repository::{DocketQuery, DocketQueryRepository};
+use crate::ports::judge_repository::{JudgeQuery, JudgeQueryRepository};
+use crate::utils::access::AccessLevel;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+/// Maximum characters kept in a result snippet
+const SNIPPET_LENGTH: usize = 160;
+
+/// Kinds of record the global search covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchType {
+    Cases,
+    Attorneys,
+    Judges,
+    Docket,
+}
+
+impl SearchType {
+    /// Every searchable type, in the order results are returned
+    pub const ALL: [SearchType; 4] = [
+        SearchType::Cases,
+        SearchType::Attorneys,
+        SearchType::Judges,
+        SearchType::Docket,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchType::Cases => "cases",
+            SearchType::Attorneys => "attorneys",
+            SearchType::Judges => "judges",
+            SearchType::Docket => "docket",
+        }
+    }
+
+    /// Parse the names given for `types`, rejecting unknown ones
+    pub fn parse_list<S: AsRef<str>>(names: &[S]) -> Result<Vec<SearchType>, ApiError> {
+        let mut parsed = Vec::new();
+        for name in names.iter().map(|n| n.as_ref().trim()).filter(|n| !n.is_empty()) {
+            let search_type = Self::ALL
+                .into_iter()
+                .find(|t| t.as_str().eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    let supported: Vec<&str> = Self::ALL.iter().map(|t| t.as_str()).collect();
+                    ApiError::BadRequest(format!(
+                        "Unknown search type '{}'. Supported types: {}",
+                        name,
+                        supported.join(", ")
+                    ))
+                })?;
+            if !parsed.contains(&search_type) {
+                parsed.push(search_type);
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+/// A single search hit in the common envelope
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SearchHit {
+    #[serde(rename = "type")]
+    pub hit_type: SearchType,
+    pub id: String,
+    pub title: String,
+    pub snippet: String,
+    pub url: String,
+}
+
+/// Hits for one search type
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SearchGroup {
+    #[serde(rename = "type")]
+    pub group_type: SearchType,
+    /// Matches found before the per-type cap and overall limit were applied
+    pub total: usize,
+    pub results: Vec<SearchHit>,
+}
+
+/// Grouped results of a global search
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GlobalSearchResponse {
+    pub query: String,
+    /// Number of hits returned across all groups
+    pub returned: usize,
+    /// Number of matches across all groups before capping
+    pub total: usize,
+    pub groups: Vec<SearchGroup>,
+}
+
+/// Caps applied to a search
+#[derive(Debug, Clone, Copy)]
+pub struct SearchLimits {
+    pub per_type: usize,
+    pub overall: usize,
+}
+
+impl Default for SearchLimits {
+    fn default() -> Self {
+        Self { per_type: 5, overall: 20 }
+    }
+}
+
+/// Aggregates the per-domain search methods behind one query
+pub struct SearchService {
+    cases: Arc<dyn CaseQueryRepository>,
+    attorneys: Arc<dyn AttorneyRepository>,
+    judges: Arc<dyn JudgeQueryRepository>,
+    docket: Arc<dyn DocketQueryRepository>,
+    access: AccessLevel,
+}
+
+impl SearchService {
+    pub fn new(
+        cases: Arc<dyn CaseQueryRepository>,
+        attorneys: Arc<dyn AttorneyRepository>,
+        judges: Arc<dyn JudgeQueryRepository>,
+        docket: Arc<dyn DocketQueryRepository>,
+    ) -> Self {
+        Self { cases, attorneys, judges, docket, access: AccessLevel::default() }
+    }
+
+    /// Access level that decides whether sealed and ex parte docket entries match
+    pub fn with_access_level(mut self, access: AccessLevel) -> Self {
+        self.access = access;
+        self
+    }
+
+    /// Search the requested types, capping each group and the overall result count
+    pub fn search(
+        &self,
+        query: &str,
+        types: &[SearchType],
+        limits: SearchLimits,
+    ) -> Result<GlobalSearchResponse, ApiError> {
+        let mut remaining = limits.overall;
+        let mut groups = Vec::new();
+
+        for search_type in types {
+            let cap = limits.per_type.min(remaining);
+            let (results, total) = self.search_type(*search_type, query, cap)?;
+            remaining -= results.len();
+            groups.push(SearchGroup { group_type: *search_type, total, results });
+        }
+
+        Ok(GlobalSearchResponse {
+            query: query.to_string(),
+            returned: groups.iter().map(|g| g.results.len()).sum(),
+            total: groups.iter().map(|g| g.total).sum(),
+            groups,
+        })
+    }
+
+    fn search_type(
+        &self,
+        search_type: SearchType,
+        query: &str,
+        cap: usize,
+    ) -> Result<(Vec<SearchHit>, usize), ApiError> {
+        match search_type {
+            SearchType::Cases => {
+                let (cases, total) = self.cases.search(CaseQuery {
+                    search_text: Some(query.to_string()),
+                    limit: cap,
+                    ..Default::default()
+                })?;
+                Ok((cases.iter().map(case_hit).collect(), total))
+            }
+            SearchType::Attorneys => {
+                let attorneys = self.attorneys.search_attorneys(query)?;
+                let total = attorneys.len();
+                Ok((attorneys.iter().take(cap).map(attorney_hit).collect(), total))
+            }
+            SearchType::Judges => {
+                let (judges, total) = self.judges.search_judges(JudgeQuery {
+                    search_text: Some(query.to_string()),
+                    limit: cap,
+                    ..Default::default()
+                })?;
+                Ok((judges.iter().map(judge_hit).collect(), total))
+            }
+            SearchType::Docket => {
+                let (entries, total) = self.docket.search_docket(DocketQuery {
+                    search_text: Some(query.to_string()),
+                    include_sealed: self.access.can_view_sealed(),
+                    include_ex_parte: self.access.can_view_ex_parte(),
+                    limit: cap,
+                    ..Default::default()
+                })?;
+                Ok((entries.iter().map(docket_hit).collect(), total))
+            }
+        }
+    }
+}
+
+fn snippet(text: &str) -> String {
+    if text.chars().count() <= SNIPPET_LENGTH {
+        text.to_string()
+    } else {
+        let truncated: String = text.chars().take(SNIPPET_LENGTH).collect();
+        format!("{}...", truncated.trim_end())
+    }
+}
+
+fn case_hit(case: &CriminalCase) -> SearchHit {
+    SearchHit {
+        hit_type: SearchType::Cases,
+        id: case.id.to_string(),
+        title: format!("{} - {}", case.case_number, case.title),
+        snippet: snippet(&case.description),
+        url: format!("/api/cases/{}", case.id),
+    }
+}
+
+fn attorney_hit(attorney: &Attorney) -> SearchHit {
+    let firm = attorney.firm_name.as_deref().unwrap_or("Solo practice");
+    SearchHit {
+        hit_type: SearchType::Attorneys,
+        id: attorney.id.clone(),
+        title: format!("{} {}", attorney.first_name, attorney.last_name),
+        snippet: snippet(&format!("{} | Bar No. {} | {}", firm, attorney.bar_number, attorney.email)),
+        url: format!("/api/attorneys/{}", attorney.id),
+    }
+}
+
+fn judge_hit(judge: &Judge) -> SearchHit {
+    SearchHit {
+        hit_type: SearchType::Judges,
+        id: judge.id.to_string(),
+        title: judge.name.clone(),
+        snippet: snippet(&format!("{} | Courtroom {}", judge.district, judge.courtroom)),
+        url: format!("/api/judges/{}", judge.id),
+    }
+}
+
+fn docket_hit(entry: &DocketEntry) -> SearchHit {
+    SearchHit {
+        hit_type: SearchType::Docket,
+        id: entry.id.to_string(),
+        title: format!("Docket entry #{}", entry.entry_number),
+        snippet: snippet(&entry.description),
+        url: format!("/api/docket/entries/{}", entry.id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_list_dedupes_and_keeps_order() {
+        let types = SearchType::parse_list(&["judges", " cases", "judges"]).unwrap();
+        assert_eq!(types, vec![SearchType::Judges, SearchType::Cases]);
+    }
+
+    #[test]
+    fn test_parse_list_rejects_unknown_type_with_supported_list() {
+        let err = SearchType::parse_list(&["cases", "orders"]).unwrap_err();
+        match err {
+            ApiError::BadRequest(msg) => {
+                assert!(msg.contains("orders"));
+                assert!(msg.contains("cases, attorneys, judges, docket"));
+            }
+            other => panic!("expected BadRequest, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_snippet_truncates_long_text() {
+        let long = "x".repeat(SNIPPET_LENGTH + 40);
+        assert_eq!(snippet(&long).chars().count(), SNIPPET_LENGTH + 3);
+        assert_eq!(snippet("short"), "short");
+    }
+}
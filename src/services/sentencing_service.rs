@@ -0,0 +1,114 @@
+//! Sentencing table lookups
+//!
+//! Worksheets and what-if comparisons look up many (offense level, criminal
+//! history category) cells at once, usually with repeats. A
+//! [`GuidelinesRangeCache`] lives for one request and answers each distinct
+//! cell from the table once.
+
+use crate::domain::sentencing::{
+    CriminalHistoryCategory, GuidelinesTableLookup, GuidelinesTableRange, Sentencing,
+    MAX_TABLE_OFFENSE_LEVEL, MIN_TABLE_OFFENSE_LEVEL,
+};
+use crate::error::{ApiError, ApiResult};
+use std::collections::HashMap;
+
+/// Largest number of lookups accepted in one batch
+pub const MAX_BATCH_LOOKUPS: usize = 1000;
+
+/// Per-request memo of sentencing table cells
+#[derive(Debug, Default)]
+pub struct GuidelinesRangeCache {
+    cells: HashMap<(i32, CriminalHistoryCategory), GuidelinesTableRange>,
+}
+
+impl GuidelinesRangeCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Table cell for a level and category, computed on first use
+    pub fn lookup(&mut self, offense_level: i32, category: &CriminalHistoryCategory) -> GuidelinesTableRange {
+        self.cells
+            .entry((offense_level, category.clone()))
+            .or_insert_with(|| Sentencing::table_lookup(offense_level, category))
+            .clone()
+    }
+}
+
+/// Look up every requested cell, in request order
+///
+/// Offense levels must lie on the table (1 through 43); the first one that
+/// does not is reported by its position in the batch.
+pub fn lookup_batch(lookups: &[GuidelinesTableLookup]) -> ApiResult<Vec<GuidelinesTableRange>> {
+    if lookups.len() > MAX_BATCH_LOOKUPS {
+        return Err(ApiError::BadRequest(format!(
+            "A batch may contain at most {} lookups, got {}",
+            MAX_BATCH_LOOKUPS,
+            lookups.len()
+        )));
+    }
+    if let Some((index, lookup)) = lookups
+        .iter()
+        .enumerate()
+        .find(|(_, l)| !(MIN_TABLE_OFFENSE_LEVEL..=MAX_TABLE_OFFENSE_LEVEL).contains(&l.offense_level))
+    {
+        return Err(ApiError::BadRequest(format!(
+            "lookups[{}]: offense level {} is outside the sentencing table ({}-{})",
+            index, lookup.offense_level, MIN_TABLE_OFFENSE_LEVEL, MAX_TABLE_OFFENSE_LEVEL
+        )));
+    }
+
+    let mut cache = GuidelinesRangeCache::new();
+    Ok(lookups
+        .iter()
+        .map(|l| cache.lookup(l.offense_level, &l.criminal_history_category))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(offense_level: i32, criminal_history_category: CriminalHistoryCategory) -> GuidelinesTableLookup {
+        GuidelinesTableLookup { offense_level, criminal_history_category }
+    }
+
+    #[test]
+    fn test_batch_matches_individual_lookups() {
+        let lookups = vec![
+            lookup(12, CriminalHistoryCategory::I),
+            lookup(24, CriminalHistoryCategory::III),
+            lookup(12, CriminalHistoryCategory::I),
+            lookup(43, CriminalHistoryCategory::VI),
+            lookup(1, CriminalHistoryCategory::II),
+        ];
+
+        let ranges = lookup_batch(&lookups).unwrap();
+
+        assert_eq!(ranges.len(), lookups.len());
+        for (range, l) in ranges.iter().zip(&lookups) {
+            assert_eq!(range, &Sentencing::table_lookup(l.offense_level, &l.criminal_history_category));
+        }
+    }
+
+    #[test]
+    fn test_cache_computes_each_cell_once() {
+        let mut cache = GuidelinesRangeCache::new();
+        let first = cache.lookup(20, &CriminalHistoryCategory::IV);
+        let again = cache.lookup(20, &CriminalHistoryCategory::IV);
+        cache.lookup(20, &CriminalHistoryCategory::V);
+
+        assert_eq!(first, again);
+        assert_eq!(cache.cells.len(), 2);
+    }
+
+    #[test]
+    fn test_batch_rejects_levels_off_the_table() {
+        let err = lookup_batch(&[lookup(10, CriminalHistoryCategory::I), lookup(44, CriminalHistoryCategory::I)])
+            .unwrap_err();
+        assert!(matches!(err, ApiError::BadRequest(ref msg) if msg.starts_with("lookups[1]")));
+
+        assert!(lookup_batch(&[lookup(0, CriminalHistoryCategory::I)]).is_err());
+        assert!(lookup_batch(&[]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,550 @@
+//! Tenant data export and import
+//!
+//! Export reads every record of the requested domains from the tenant's
+//! repositories into one [`TenantExport`]. The whole bundle is built in
+//! memory and each domain is a full scan of the store, so a large district
+//! produces a large, slow response; exports are meant for offline backups
+//! and migrations, not for routine reads.
+//!
+//! Import writes such a bundle back into a tenant. The store has no
+//! transactions, so each domain is made as atomic as it can be: conflicts
+//! are found before anything is written, and if a write fails part way the
+//! domain's earlier writes are undone.
+
+use crate::domain::attorney::Attorney;
+use crate::domain::criminal_case::CriminalCase;
+use crate::domain::deadline::Deadline;
+use crate::domain::docket::{CalendarEntry, DocketEntry};
+use crate::domain::judge::Judge;
+use crate::domain::opinion::JudicialOpinion;
+use crate::domain::order::JudicialOrder;
+use crate::domain::rule::Rule;
+use crate::domain::sentencing::Sentencing;
+use crate::domain::tenant_export::{
+    ConflictPolicy, DomainImportResult, ExportDomain, ExportRecord, ImportReport, TenantExport,
+};
+use crate::error::{ApiError, ApiResult};
+use crate::ports::attorney_repository::AttorneyRepository;
+use crate::ports::case_repository::CaseRepository;
+use crate::ports::deadline_repository::DeadlineRepository;
+use crate::ports::docket_repository::{CalendarRepository, DocketRepository};
+use crate::ports::document_repository::{DocumentRepository, OpinionFilter, OrderFilter};
+use crate::ports::judge_repository::JudgeRepository;
+use crate::ports::rules_repository::RulesRepository;
+use crate::ports::sentencing_repository::SentencingRepository;
+use chrono::Utc;
+use std::collections::BTreeMap;
+
+/// The tenant's repositories an export reads from and an import writes to
+pub struct TenantRepositories<'a> {
+    pub attorneys: &'a dyn AttorneyRepository,
+    pub cases: &'a dyn CaseRepository,
+    pub judges: &'a dyn JudgeRepository,
+    pub deadlines: &'a dyn DeadlineRepository,
+    pub docket: &'a dyn DocketRepository,
+    pub calendar: &'a dyn CalendarRepository,
+    pub documents: &'a dyn DocumentRepository,
+    pub rules: &'a dyn RulesRepository,
+    pub sentencing: &'a dyn SentencingRepository,
+}
+
+/// Export every record of the given domains
+pub fn export_tenant(tenant_id: &str, domains: &[ExportDomain], repos: &TenantRepositories) -> ApiResult<TenantExport> {
+    let mut export = TenantExport {
+        tenant_id: tenant_id.to_string(),
+        exported_at: Utc::now(),
+        domains: domains.to_vec(),
+        ..TenantExport::default()
+    };
+
+    for domain in domains {
+        let count = match domain {
+            ExportDomain::Attorneys => set(&mut export.attorneys, repos.attorneys.find_all_attorneys()?),
+            ExportDomain::Cases => set(&mut export.cases, repos.cases.find_all_cases()?),
+            ExportDomain::Judges => set(&mut export.judges, repos.judges.find_all_judges()?),
+            ExportDomain::Deadlines => set(&mut export.deadlines, repos.deadlines.find_all_deadlines()?),
+            ExportDomain::DocketEntries => set(&mut export.docket_entries, repos.docket.find_all_entries()?),
+            ExportDomain::CalendarEvents => set(&mut export.calendar_events, repos.calendar.find_all_events()?),
+            ExportDomain::Orders => {
+                let filter = OrderFilter { limit: Some(usize::MAX), ..OrderFilter::default() };
+                set(&mut export.orders, repos.documents.list_orders(filter)?)
+            }
+            ExportDomain::Opinions => {
+                let filter = OpinionFilter { limit: Some(usize::MAX), ..OpinionFilter::default() };
+                set(&mut export.opinions, repos.documents.list_opinions(filter)?)
+            }
+            ExportDomain::Rules => set(&mut export.rules, repos.rules.find_all_rules()?),
+            ExportDomain::Sentencings => set(&mut export.sentencings, repos.sentencing.find_all_sentencings()?),
+        };
+        export.counts.insert(domain.as_str().to_string(), count);
+    }
+
+    Ok(export)
+}
+
+/// Store a domain's records in the bundle, returning how many there were
+fn set<T>(slot: &mut Option<Vec<T>>, records: Vec<T>) -> usize {
+    let count = records.len();
+    *slot = Some(records);
+    count
+}
+
+/// Import a bundle into the tenant
+///
+/// The bundle is validated first and rejected as a whole (400) if it is
+/// inconsistent. After that each domain succeeds or fails on its own; a
+/// failed domain is reported with its error rather than failing the call.
+pub fn import_tenant(
+    tenant_id: &str,
+    bundle: &TenantExport,
+    policy: ConflictPolicy,
+    repos: &TenantRepositories,
+) -> ApiResult<ImportReport> {
+    bundle
+        .validate()
+        .map_err(|errors| ApiError::BadRequest(format!("Invalid export bundle: {}", errors.join("; "))))?;
+
+    let mut domains = BTreeMap::new();
+    for domain in ExportDomain::ALL {
+        let result = match domain {
+            ExportDomain::Attorneys => bundle.attorneys.as_ref().map(|r| import_records(r, policy, repos.attorneys)),
+            ExportDomain::Cases => bundle.cases.as_ref().map(|r| import_records(r, policy, repos.cases)),
+            ExportDomain::Judges => bundle.judges.as_ref().map(|r| import_records(r, policy, repos.judges)),
+            ExportDomain::Deadlines => bundle.deadlines.as_ref().map(|r| import_records(r, policy, repos.deadlines)),
+            ExportDomain::DocketEntries => bundle.docket_entries.as_ref().map(|r| import_records(r, policy, repos.docket)),
+            ExportDomain::CalendarEvents => bundle.calendar_events.as_ref().map(|r| import_records(r, policy, repos.calendar)),
+            ExportDomain::Orders => bundle.orders.as_ref().map(|r| import_records(r, policy, repos.documents)),
+            ExportDomain::Opinions => bundle.opinions.as_ref().map(|r| import_records(r, policy, repos.documents)),
+            ExportDomain::Rules => bundle.rules.as_ref().map(|r| import_records(r, policy, repos.rules)),
+            ExportDomain::Sentencings => bundle.sentencings.as_ref().map(|r| import_records(r, policy, repos.sentencing)),
+        };
+        if let Some(result) = result {
+            domains.insert(domain.as_str().to_string(), result);
+        }
+    }
+
+    Ok(ImportReport {
+        tenant_id: tenant_id.to_string(),
+        conflict_policy: policy,
+        domains,
+    })
+}
+
+/// Where one domain's records are imported to
+pub trait RecordStore<T> {
+    /// The stored record with the same ID, if any
+    fn find_existing(&self, record: &T) -> ApiResult<Option<T>>;
+    /// Write a record that doesn't exist yet
+    fn insert(&self, record: &T) -> ApiResult<()>;
+    /// Write over an existing record
+    fn replace(&self, record: &T) -> ApiResult<()>;
+    /// Remove a record written by the import
+    fn remove(&self, record: &T) -> ApiResult<()>;
+}
+
+/// A write the import made, and how to take it back
+enum Undo<T> {
+    Remove(T),
+    Restore(T),
+}
+
+/// Import one domain's records as a unit
+fn import_records<T, S>(records: &[T], policy: ConflictPolicy, store: &S) -> DomainImportResult
+where
+    T: ExportRecord + Clone,
+    S: RecordStore<T> + ?Sized,
+{
+    let failed = |error: String| DomainImportResult {
+        failed: records.len(),
+        error: Some(error),
+        ..DomainImportResult::default()
+    };
+
+    let mut existing = Vec::with_capacity(records.len());
+    for record in records {
+        match store.find_existing(record) {
+            Ok(found) => existing.push(found),
+            Err(e) => return failed(e.to_string()),
+        }
+    }
+
+    if policy == ConflictPolicy::Fail {
+        let conflicts: Vec<String> = records
+            .iter()
+            .zip(&existing)
+            .filter(|(_, found)| found.is_some())
+            .map(|(record, _)| record.record_id())
+            .collect();
+        if !conflicts.is_empty() {
+            return failed(format!("{} record(s) already exist: {}", conflicts.len(), conflicts.join(", ")));
+        }
+    }
+
+    let mut result = DomainImportResult::default();
+    let mut written = Vec::new();
+    for (record, found) in records.iter().zip(existing) {
+        let write = match (found, policy) {
+            (None, _) => store.insert(record).map(|_| Undo::Remove(record.clone())),
+            (Some(_), ConflictPolicy::Skip) => {
+                result.skipped += 1;
+                continue;
+            }
+            (Some(previous), _) => store.replace(record).map(|_| Undo::Restore(previous)),
+        };
+
+        match write {
+            Ok(undo) => {
+                written.push(undo);
+                result.imported += 1;
+            }
+            Err(e) => {
+                let mut error = format!("Failed to write {}: {}", record.record_id(), e);
+                let undo_failures = written
+                    .into_iter()
+                    .rev()
+                    .filter(|undo| match undo {
+                        Undo::Remove(record) => store.remove(record).is_err(),
+                        Undo::Restore(previous) => store.replace(previous).is_err(),
+                    })
+                    .count();
+                if undo_failures > 0 {
+                    error.push_str(&format!("; {} earlier write(s) could not be undone", undo_failures));
+                }
+                return failed(error);
+            }
+        }
+    }
+
+    result
+}
+
+impl RecordStore<Attorney> for dyn AttorneyRepository + '_ {
+    fn find_existing(&self, record: &Attorney) -> ApiResult<Option<Attorney>> {
+        Ok(self.find_attorney_by_id(&record.id)?)
+    }
+
+    fn insert(&self, record: &Attorney) -> ApiResult<()> {
+        self.save_attorney(record.clone())?;
+        Ok(())
+    }
+
+    fn replace(&self, record: &Attorney) -> ApiResult<()> {
+        self.update_attorney(record.clone())?;
+        Ok(())
+    }
+
+    fn remove(&self, record: &Attorney) -> ApiResult<()> {
+        Ok(self.delete_attorney(&record.id)?)
+    }
+}
+
+impl RecordStore<CriminalCase> for dyn CaseRepository + '_ {
+    fn find_existing(&self, record: &CriminalCase) -> ApiResult<Option<CriminalCase>> {
+        Ok(self.find_by_id(record.id)?)
+    }
+
+    fn insert(&self, record: &CriminalCase) -> ApiResult<()> {
+        Ok(self.save(record)?)
+    }
+
+    fn replace(&self, record: &CriminalCase) -> ApiResult<()> {
+        Ok(self.save(record)?)
+    }
+
+    fn remove(&self, record: &CriminalCase) -> ApiResult<()> {
+        self.delete(record.id)?;
+        Ok(())
+    }
+}
+
+impl RecordStore<Judge> for dyn JudgeRepository + '_ {
+    fn find_existing(&self, record: &Judge) -> ApiResult<Option<Judge>> {
+        Ok(self.find_judge_by_id(record.id)?)
+    }
+
+    fn insert(&self, record: &Judge) -> ApiResult<()> {
+        Ok(self.save_judge(record)?)
+    }
+
+    fn replace(&self, record: &Judge) -> ApiResult<()> {
+        Ok(self.save_judge(record)?)
+    }
+
+    fn remove(&self, record: &Judge) -> ApiResult<()> {
+        self.delete_judge(record.id)?;
+        Ok(())
+    }
+}
+
+impl RecordStore<Deadline> for dyn DeadlineRepository + '_ {
+    fn find_existing(&self, record: &Deadline) -> ApiResult<Option<Deadline>> {
+        Ok(self.find_deadline_by_id(record.id)?)
+    }
+
+    fn insert(&self, record: &Deadline) -> ApiResult<()> {
+        Ok(self.save_deadline(record)?)
+    }
+
+    fn replace(&self, record: &Deadline) -> ApiResult<()> {
+        Ok(self.save_deadline(record)?)
+    }
+
+    fn remove(&self, record: &Deadline) -> ApiResult<()> {
+        self.delete_deadline(record.id)?;
+        Ok(())
+    }
+}
+
+impl RecordStore<DocketEntry> for dyn DocketRepository + '_ {
+    fn find_existing(&self, record: &DocketEntry) -> ApiResult<Option<DocketEntry>> {
+        Ok(self.find_entry_by_id(record.id)?)
+    }
+
+    fn insert(&self, record: &DocketEntry) -> ApiResult<()> {
+        Ok(self.save_entry(record)?)
+    }
+
+    fn replace(&self, record: &DocketEntry) -> ApiResult<()> {
+        Ok(self.save_entry(record)?)
+    }
+
+    fn remove(&self, record: &DocketEntry) -> ApiResult<()> {
+        self.delete_entry(record.id)?;
+        Ok(())
+    }
+}
+
+impl RecordStore<CalendarEntry> for dyn CalendarRepository + '_ {
+    fn find_existing(&self, record: &CalendarEntry) -> ApiResult<Option<CalendarEntry>> {
+        Ok(self.find_event_by_id(record.id)?)
+    }
+
+    fn insert(&self, record: &CalendarEntry) -> ApiResult<()> {
+        Ok(self.save_event(record)?)
+    }
+
+    fn replace(&self, record: &CalendarEntry) -> ApiResult<()> {
+        Ok(self.save_event(record)?)
+    }
+
+    fn remove(&self, record: &CalendarEntry) -> ApiResult<()> {
+        self.delete_event(record.id)?;
+        Ok(())
+    }
+}
+
+impl RecordStore<JudicialOrder> for dyn DocumentRepository + '_ {
+    fn find_existing(&self, record: &JudicialOrder) -> ApiResult<Option<JudicialOrder>> {
+        self.get_order(&record.id)
+    }
+
+    fn insert(&self, record: &JudicialOrder) -> ApiResult<()> {
+        self.create_order(record.clone())?;
+        Ok(())
+    }
+
+    fn replace(&self, record: &JudicialOrder) -> ApiResult<()> {
+        self.update_order(record.clone())?;
+        Ok(())
+    }
+
+    fn remove(&self, record: &JudicialOrder) -> ApiResult<()> {
+        self.delete_order(&record.id)
+    }
+}
+
+impl RecordStore<JudicialOpinion> for dyn DocumentRepository + '_ {
+    fn find_existing(&self, record: &JudicialOpinion) -> ApiResult<Option<JudicialOpinion>> {
+        self.get_opinion(&record.id)
+    }
+
+    fn insert(&self, record: &JudicialOpinion) -> ApiResult<()> {
+        self.create_opinion(record.clone())?;
+        Ok(())
+    }
+
+    fn replace(&self, record: &JudicialOpinion) -> ApiResult<()> {
+        self.update_opinion(record.clone())?;
+        Ok(())
+    }
+
+    fn remove(&self, record: &JudicialOpinion) -> ApiResult<()> {
+        self.delete_opinion(&record.id)
+    }
+}
+
+impl RecordStore<Rule> for dyn RulesRepository + '_ {
+    fn find_existing(&self, record: &Rule) -> ApiResult<Option<Rule>> {
+        Ok(self.find_rule_by_id(record.id)?)
+    }
+
+    fn insert(&self, record: &Rule) -> ApiResult<()> {
+        Ok(self.save_rule(record)?)
+    }
+
+    fn replace(&self, record: &Rule) -> ApiResult<()> {
+        Ok(self.save_rule(record)?)
+    }
+
+    fn remove(&self, record: &Rule) -> ApiResult<()> {
+        self.delete_rule(record.id)?;
+        Ok(())
+    }
+}
+
+impl RecordStore<Sentencing> for dyn SentencingRepository + '_ {
+    fn find_existing(&self, record: &Sentencing) -> ApiResult<Option<Sentencing>> {
+        self.get_sentencing(&record.id)
+    }
+
+    fn insert(&self, record: &Sentencing) -> ApiResult<()> {
+        self.create_sentencing(record.clone())?;
+        Ok(())
+    }
+
+    fn replace(&self, record: &Sentencing) -> ApiResult<()> {
+        // update_sentencing would restamp updated_at; keep the imported record as-is
+        self.create_sentencing(record.clone())?;
+        Ok(())
+    }
+
+    fn remove(&self, record: &Sentencing) -> ApiResult<()> {
+        self.delete_sentencing(&record.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Record {
+        id: String,
+        value: u32,
+    }
+
+    impl ExportRecord for Record {
+        fn record_id(&self) -> String {
+            self.id.clone()
+        }
+    }
+
+    /// In-memory store; `fail_at` makes the write with that index fail once
+    #[derive(Default)]
+    struct MemoryStore {
+        records: RefCell<HashMap<String, Record>>,
+        writes: Cell<usize>,
+        fail_at: Cell<Option<usize>>,
+    }
+
+    impl MemoryStore {
+        fn with(records: &[Record]) -> Self {
+            let store = Self::default();
+            for record in records {
+                store.records.borrow_mut().insert(record.id.clone(), record.clone());
+            }
+            store
+        }
+
+        fn write(&self, record: &Record) -> ApiResult<()> {
+            if self.fail_at.get() == Some(self.writes.get()) {
+                self.fail_at.set(None);
+                return Err(ApiError::StorageError("store unavailable".to_string()));
+            }
+            self.writes.set(self.writes.get() + 1);
+            self.records.borrow_mut().insert(record.id.clone(), record.clone());
+            Ok(())
+        }
+
+        fn value(&self, id: &str) -> Option<u32> {
+            self.records.borrow().get(id).map(|r| r.value)
+        }
+    }
+
+    impl RecordStore<Record> for MemoryStore {
+        fn find_existing(&self, record: &Record) -> ApiResult<Option<Record>> {
+            Ok(self.records.borrow().get(&record.id).cloned())
+        }
+
+        fn insert(&self, record: &Record) -> ApiResult<()> {
+            self.write(record)
+        }
+
+        fn replace(&self, record: &Record) -> ApiResult<()> {
+            self.write(record)
+        }
+
+        fn remove(&self, record: &Record) -> ApiResult<()> {
+            self.records.borrow_mut().remove(&record.id);
+            Ok(())
+        }
+    }
+
+    fn record(id: &str, value: u32) -> Record {
+        Record { id: id.to_string(), value }
+    }
+
+    fn bundle() -> Vec<Record> {
+        vec![record("a", 2), record("b", 2), record("c", 2)]
+    }
+
+    #[test]
+    fn test_skip_keeps_existing_records() {
+        let store = MemoryStore::with(&[record("a", 1)]);
+
+        let result = import_records(&bundle(), ConflictPolicy::Skip, &store);
+
+        assert_eq!(result, DomainImportResult { imported: 2, skipped: 1, ..Default::default() });
+        assert_eq!(store.value("a"), Some(1));
+        assert_eq!(store.value("c"), Some(2));
+    }
+
+    #[test]
+    fn test_overwrite_replaces_existing_records() {
+        let store = MemoryStore::with(&[record("a", 1)]);
+
+        let result = import_records(&bundle(), ConflictPolicy::Overwrite, &store);
+
+        assert_eq!(result, DomainImportResult { imported: 3, ..Default::default() });
+        assert_eq!(store.value("a"), Some(2));
+    }
+
+    #[test]
+    fn test_fail_writes_nothing_when_any_id_exists() {
+        let store = MemoryStore::with(&[record("b", 1)]);
+
+        let result = import_records(&bundle(), ConflictPolicy::Fail, &store);
+
+        assert_eq!(result.imported, 0);
+        assert_eq!(result.failed, 3);
+        assert!(result.error.unwrap().contains("already exist: b"));
+        assert_eq!(store.writes.get(), 0);
+        assert_eq!(store.value("a"), None);
+        assert_eq!(store.value("b"), Some(1));
+    }
+
+    #[test]
+    fn test_fail_imports_when_no_ids_exist() {
+        let store = MemoryStore::default();
+
+        let result = import_records(&bundle(), ConflictPolicy::Fail, &store);
+
+        assert_eq!(result, DomainImportResult { imported: 3, ..Default::default() });
+    }
+
+    #[test]
+    fn test_write_failure_undoes_the_domain() {
+        let store = MemoryStore::with(&[record("a", 1)]);
+        store.fail_at.set(Some(2));
+
+        let result = import_records(&bundle(), ConflictPolicy::Overwrite, &store);
+
+        assert_eq!(result.imported, 0);
+        assert_eq!(result.failed, 3);
+        assert!(result.error.unwrap().contains("Failed to write c"));
+        assert_eq!(store.value("a"), Some(1), "overwritten record should be restored");
+        assert_eq!(store.value("b"), None, "inserted record should be removed");
+    }
+}
@@ -0,0 +1,376 @@
+//! Tenant seeding service
+//!
+//! Seeds the defaults a newly initialized tenant needs before it can do any
+//! useful work: the deadline rule set, feature flags, the reminder schedule
+//! and a starter set of order templates.
+//!
+//! Every seed is recorded in a manifest kept in the tenant's own store with
+//! the seed version and a fingerprint of what was written. Re-running the
+//! seeder creates seeds that are missing, upgrades seeds that are still
+//! exactly as they were seeded, and leaves anything the district has since
+//! edited alone.
+
+use crate::adapters::spin_kv_config_repository::SpinKvConfigRepository;
+use crate::adapters::spin_kv_deadline_repository::SpinKvDeadlineRepository;
+use crate::adapters::spin_kv_document_repository::SpinKvDocumentRepository;
+use crate::adapters::store_utils::open_validated_store;
+use crate::domain::config::ConfigOverride;
+use crate::domain::deadline::{DeadlineCalculator, DeadlineMonitor};
+use crate::domain::order::{OrderTemplate, OrderType, TemplateVariable, VariableType};
+use crate::error::ApiError;
+use crate::ports::config_repository::ConfigRepository;
+use crate::ports::deadline_repository::DeadlineRuleRepository;
+use crate::ports::document_repository::DocumentRepository;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+/// Key under which the seed manifest is stored in the tenant store
+pub const SEED_MANIFEST_KEY: &str = "seed:manifest";
+
+/// Current version of the seed set. Bump when seeded content changes so
+/// untouched seeds on existing tenants pick up the new defaults.
+pub const SEED_VERSION: u32 = 1;
+
+/// Feature flags enabled for every new tenant
+const DEFAULT_FEATURE_FLAGS: &[&str] = &[
+    "features.core.case_management",
+    "features.core.basic_docket",
+    "features.core.party_management",
+    "features.advanced.judge_assignment",
+    "features.advanced.deadline_tracking",
+];
+
+/// Record of a single seed that has been written to a tenant
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeedEntry {
+    pub version: u32,
+    pub fingerprint: String,
+}
+
+/// Manifest of every seed written to a tenant
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SeedManifest {
+    pub entries: BTreeMap<String, SeedEntry>,
+}
+
+/// What the seeder should do with a single seed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedAction {
+    /// Nothing exists yet, write the seed
+    Create,
+    /// Existing value is untouched but seeded by an older version
+    Upgrade,
+    /// Existing value is untouched and current
+    SkipCurrent,
+    /// Existing value was changed by the district (or was never seeded)
+    SkipModified,
+}
+
+impl SeedManifest {
+    /// Decide what to do with a seed given the fingerprint of the value
+    /// currently stored under it, if any
+    pub fn plan(&self, key: &str, version: u32, existing: Option<&str>) -> SeedAction {
+        let Some(existing) = existing else {
+            return SeedAction::Create;
+        };
+
+        match self.entries.get(key) {
+            Some(entry) if entry.fingerprint == existing => {
+                if entry.version < version {
+                    SeedAction::Upgrade
+                } else {
+                    SeedAction::SkipCurrent
+                }
+            }
+            _ => SeedAction::SkipModified,
+        }
+    }
+
+    /// Record that a seed was written
+    pub fn record(&mut self, key: &str, version: u32, fingerprint: String) {
+        self.entries.insert(key.to_string(), SeedEntry { version, fingerprint });
+    }
+}
+
+/// Why a seed was not written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    UpToDate,
+    Modified,
+}
+
+/// A seed that was left as-is
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SkippedSeed {
+    pub key: String,
+    pub reason: SkipReason,
+}
+
+/// Report of a seeding run
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct SeedReport {
+    pub tenant_id: String,
+    pub seed_version: u32,
+    pub seeded: Vec<String>,
+    pub upgraded: Vec<String>,
+    pub skipped: Vec<SkippedSeed>,
+}
+
+impl SeedReport {
+    fn record(&mut self, key: &str, action: SeedAction) {
+        match action {
+            SeedAction::Create => self.seeded.push(key.to_string()),
+            SeedAction::Upgrade => self.upgraded.push(key.to_string()),
+            SeedAction::SkipCurrent => self.skipped.push(SkippedSeed {
+                key: key.to_string(),
+                reason: SkipReason::UpToDate,
+            }),
+            SeedAction::SkipModified => self.skipped.push(SkippedSeed {
+                key: key.to_string(),
+                reason: SkipReason::Modified,
+            }),
+        }
+    }
+}
+
+/// SHA-256 fingerprint of a value's JSON representation
+pub fn fingerprint<T: Serialize>(value: &T) -> String {
+    use sha2::{Digest, Sha256};
+    let bytes = serde_json::to_vec(value).unwrap_or_default();
+    format!("{:x}", Sha256::digest(&bytes))
+}
+
+/// Seeds default data into a single tenant
+pub struct TenantSeeder {
+    tenant_id: String,
+    store_name: String,
+    district_id: String,
+}
+
+impl TenantSeeder {
+    /// Create a seeder for a tenant store. `district_id` is the key used for
+    /// the tenant's configuration overrides.
+    pub fn new(tenant_id: String, store_name: String, district_id: String) -> Self {
+        Self { tenant_id, store_name, district_id }
+    }
+
+    /// Seed everything, returning a report of what was written
+    pub fn run(&self) -> Result<SeedReport, ApiError> {
+        let store = open_validated_store(&self.store_name)
+            .map_err(|e| ApiError::StorageError(e.to_string()))?;
+        let mut manifest = store
+            .get_json::<SeedManifest>(SEED_MANIFEST_KEY)
+            .map_err(|e| ApiError::StorageError(e.to_string()))?
+            .unwrap_or_default();
+
+        let mut report = SeedReport {
+            tenant_id: self.tenant_id.clone(),
+            seed_version: SEED_VERSION,
+            ..Default::default()
+        };
+
+        self.seed_deadline_rules(&mut manifest, &mut report)?;
+        self.seed_config(&mut manifest, &mut report)?;
+        self.seed_order_templates(&mut manifest, &mut report)?;
+
+        store
+            .set_json(SEED_MANIFEST_KEY, &manifest)
+            .map_err(|e| ApiError::StorageError(e.to_string()))?;
+
+        Ok(report)
+    }
+
+    fn seed_deadline_rules(
+        &self,
+        manifest: &mut SeedManifest,
+        report: &mut SeedReport,
+    ) -> Result<(), ApiError> {
+        let repo = SpinKvDeadlineRepository::with_store(self.store_name.clone());
+
+        for rule in DeadlineCalculator::default_rule_set() {
+            let key = format!("deadline_rule:{}", rule.id);
+            let existing = repo.find_deadline_rule(&rule.id)?.map(|r| fingerprint(&r));
+            let action = manifest.plan(&key, SEED_VERSION, existing.as_deref());
+
+            if matches!(action, SeedAction::Create | SeedAction::Upgrade) {
+                repo.save_deadline_rule(&rule)?;
+                manifest.record(&key, SEED_VERSION, fingerprint(&rule));
+            }
+            report.record(&key, action);
+        }
+
+        Ok(())
+    }
+
+    fn seed_config(
+        &self,
+        manifest: &mut SeedManifest,
+        report: &mut SeedReport,
+    ) -> Result<(), ApiError> {
+        let repo = SpinKvConfigRepository::with_store(self.store_name.clone());
+        let mut overrides = futures::executor::block_on(repo.get_district_overrides(&self.district_id))?
+            .unwrap_or_else(ConfigOverride::new);
+
+        let mut seeds: Vec<(String, Value)> = DEFAULT_FEATURE_FLAGS
+            .iter()
+            .map(|path| (path.to_string(), json!(true)))
+            .collect();
+        seeds.push((
+            DeadlineMonitor::REMINDER_DAYS_CONFIG_PATH.to_string(),
+            json!(DeadlineMonitor::DEFAULT_REMINDER_DAYS),
+        ));
+
+        let mut changed = false;
+        for (path, value) in seeds {
+            let key = format!("config:{}", path);
+            let existing = overrides.overrides.get(&path).map(fingerprint);
+            let action = manifest.plan(&key, SEED_VERSION, existing.as_deref());
+
+            if matches!(action, SeedAction::Create | SeedAction::Upgrade) {
+                manifest.record(&key, SEED_VERSION, fingerprint(&value));
+                overrides.add(path, value);
+                changed = true;
+            }
+            report.record(&key, action);
+        }
+
+        if changed {
+            futures::executor::block_on(repo.save_district_overrides(&self.district_id, &overrides))?;
+        }
+
+        Ok(())
+    }
+
+    fn seed_order_templates(
+        &self,
+        manifest: &mut SeedManifest,
+        report: &mut SeedReport,
+    ) -> Result<(), ApiError> {
+        let repo = SpinKvDocumentRepository::with_store(self.store_name.clone());
+
+        for template in starter_order_templates() {
+            let key = format!("order_template:{}", template.id);
+            let existing = repo.get_template(&template.id)?;
+            let action = manifest.plan(&key, SEED_VERSION, existing.as_ref().map(fingerprint).as_deref());
+
+            match action {
+                SeedAction::Create => {
+                    let saved = repo.create_template(template)?;
+                    manifest.record(&key, SEED_VERSION, fingerprint(&saved));
+                }
+                SeedAction::Upgrade => {
+                    let saved = repo.update_template(template)?;
+                    manifest.record(&key, SEED_VERSION, fingerprint(&saved));
+                }
+                SeedAction::SkipCurrent | SeedAction::SkipModified => {}
+            }
+            report.record(&key, action);
+        }
+
+        Ok(())
+    }
+}
+
+/// Starter order templates seeded for every tenant. Ids are stable so the
+/// manifest can track them across runs.
+pub fn starter_order_templates() -> Vec<OrderTemplate> {
+    let mut scheduling = OrderTemplate::new(
+        "Rule 16(b) Scheduling Order".to_string(),
+        OrderType::SchedulingOrder,
+        "Sets discovery, motion and trial dates under FRCP 16(b)".to_string(),
+        "In {{case_number}}, the Court ORDERS that discovery close by {{discovery_deadline}}, \
+         dispositive motions be filed by {{motion_deadline}}, and trial commence on {{trial_date}}."
+            .to_string(),
+    );
+    scheduling.id = "seed-scheduling-order".to_string();
+    scheduling.variables = vec![
+        template_variable("case_number", "Case number", VariableType::CaseReference),
+        template_variable("discovery_deadline", "Close of discovery", VariableType::Date),
+        template_variable("motion_deadline", "Dispositive motion deadline", VariableType::Date),
+        template_variable("trial_date", "Trial date", VariableType::Date),
+    ];
+
+    let mut extension = OrderTemplate::new(
+        "Order Granting Extension of Time".to_string(),
+        OrderType::MinuteOrder,
+        "Grants a party additional time to respond or file".to_string(),
+        "In {{case_number}}, the motion of {{party_name}} for an extension of time is GRANTED. \
+         The new deadline is {{new_due_date}}."
+            .to_string(),
+    );
+    extension.id = "seed-extension-order".to_string();
+    extension.variables = vec![
+        template_variable("case_number", "Case number", VariableType::CaseReference),
+        template_variable("party_name", "Moving party", VariableType::PartyReference),
+        template_variable("new_due_date", "Extended due date", VariableType::Date),
+    ];
+
+    let mut show_cause = OrderTemplate::new(
+        "Order to Show Cause".to_string(),
+        OrderType::ShowCauseOrder,
+        "Directs a party to show cause why sanctions or dismissal should not issue".to_string(),
+        "In {{case_number}}, {{party_name}} is ORDERED to show cause in writing by {{response_date}} \
+         why {{proposed_action}} should not be entered."
+            .to_string(),
+    );
+    show_cause.id = "seed-show-cause-order".to_string();
+    show_cause.variables = vec![
+        template_variable("case_number", "Case number", VariableType::CaseReference),
+        template_variable("party_name", "Party ordered to respond", VariableType::PartyReference),
+        template_variable("response_date", "Response due date", VariableType::Date),
+        template_variable("proposed_action", "Action the Court is considering", VariableType::Text),
+    ];
+
+    vec![scheduling, extension, show_cause]
+}
+
+fn template_variable(name: &str, description: &str, variable_type: VariableType) -> TemplateVariable {
+    TemplateVariable {
+        name: name.to_string(),
+        description: description.to_string(),
+        variable_type,
+        required: true,
+        default_value: None,
+        validation_rules: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_creates_missing_seed() {
+        let manifest = SeedManifest::default();
+        assert_eq!(manifest.plan("deadline_rule:x", 1, None), SeedAction::Create);
+    }
+
+    #[test]
+    fn test_plan_upgrades_untouched_older_seed() {
+        let mut manifest = SeedManifest::default();
+        manifest.record("k", 1, "abc".to_string());
+
+        assert_eq!(manifest.plan("k", 2, Some("abc")), SeedAction::Upgrade);
+        assert_eq!(manifest.plan("k", 1, Some("abc")), SeedAction::SkipCurrent);
+    }
+
+    #[test]
+    fn test_plan_skips_modified_or_unmanaged_values() {
+        let mut manifest = SeedManifest::default();
+        manifest.record("k", 1, "abc".to_string());
+
+        assert_eq!(manifest.plan("k", 2, Some("def")), SeedAction::SkipModified);
+        assert_eq!(manifest.plan("other", 1, Some("abc")), SeedAction::SkipModified);
+    }
+
+    #[test]
+    fn test_starter_templates_have_stable_ids() {
+        let first: Vec<String> = starter_order_templates().into_iter().map(|t| t.id).collect();
+        let second: Vec<String> = starter_order_templates().into_iter().map(|t| t.id).collect();
+        assert_eq!(first, second);
+        assert!(first.iter().all(|id| id.starts_with("seed-")));
+    }
+}
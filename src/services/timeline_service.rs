@@ -0,0 +1,672 @@
+//! Case timeline
+//!
+//! Merges everything recorded about a case into one chronological list.
+//! Each module the timeline reads is wrapped in a [`TimelineAdapter`] that
+//! normalizes its records into [`TimelineEvent`]s. A source that fails to
+//! load is reported in the response instead of failing the whole timeline,
+//! so the judge still sees what the other sources hold.
+//!
+//! The case record keeps when the case was opened, sealed and closed but not
+//! its intermediate status changes; those show up through the docket entries
+//! that accompany them.
+
+use crate::domain::criminal_case::{CriminalCase, MotionType};
+use crate::domain::judge::Judge;
+use crate::error::ApiError;
+use crate::ports::deadline_repository::DeadlineRepository;
+use crate::ports::docket_repository::{CalendarRepository, DocketRepository};
+use crate::ports::document_repository::DocumentRepository;
+use crate::ports::judge_repository::{CaseAssignmentRepository, JudgeRepository, RecusalRepository};
+use crate::utils::access::AccessLevel;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Modules the timeline draws events from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TimelineSource {
+    /// Opening, sealing and closing of the case, and its motions and rulings
+    Case,
+    Docket,
+    Calendar,
+    Deadlines,
+    /// Judge assignments, reassignments and recusals
+    Judges,
+    Orders,
+    Opinions,
+}
+
+impl TimelineSource {
+    /// Every source, in the order same-time events are listed
+    pub const ALL: [TimelineSource; 7] = [
+        TimelineSource::Case,
+        TimelineSource::Docket,
+        TimelineSource::Calendar,
+        TimelineSource::Deadlines,
+        TimelineSource::Judges,
+        TimelineSource::Orders,
+        TimelineSource::Opinions,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimelineSource::Case => "case",
+            TimelineSource::Docket => "docket",
+            TimelineSource::Calendar => "calendar",
+            TimelineSource::Deadlines => "deadlines",
+            TimelineSource::Judges => "judges",
+            TimelineSource::Orders => "orders",
+            TimelineSource::Opinions => "opinions",
+        }
+    }
+
+    /// Parse the names given for `sources`, rejecting unknown ones
+    pub fn parse_list<S: AsRef<str>>(names: &[S]) -> Result<Vec<TimelineSource>, ApiError> {
+        let mut parsed = Vec::new();
+        for name in names.iter().map(|n| n.as_ref().trim()).filter(|n| !n.is_empty()) {
+            let source = Self::ALL
+                .into_iter()
+                .find(|s| s.as_str().eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    let supported: Vec<&str> = Self::ALL.iter().map(|s| s.as_str()).collect();
+                    ApiError::BadRequest(format!(
+                        "Unknown timeline source '{}'. Supported sources: {}",
+                        name,
+                        supported.join(", ")
+                    ))
+                })?;
+            if !parsed.contains(&source) {
+                parsed.push(source);
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+/// One thing that happened in a case
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TimelineEvent {
+    pub timestamp: DateTime<Utc>,
+    pub source: TimelineSource,
+    /// What happened, e.g. `docket.entry`, `motion.ruled` or `order.issued`
+    pub event_type: String,
+    pub summary: String,
+    /// ID of the record the event comes from
+    pub reference_id: String,
+}
+
+impl TimelineEvent {
+    fn new(
+        timestamp: DateTime<Utc>,
+        source: TimelineSource,
+        event_type: &str,
+        summary: String,
+        reference_id: impl ToString,
+    ) -> Self {
+        Self { timestamp, source, event_type: event_type.to_string(), summary, reference_id: reference_id.to_string() }
+    }
+}
+
+/// A source whose events couldn't be loaded
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimelineSourceFailure {
+    pub source: TimelineSource,
+    pub error: String,
+}
+
+/// A page of a case's timeline
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CaseTimeline {
+    pub case_id: Uuid,
+    pub case_number: String,
+    pub items: Vec<TimelineEvent>,
+    /// Events across all pages
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+    /// Whether a source failed and its events are missing
+    pub partial: bool,
+    pub failed_sources: Vec<TimelineSourceFailure>,
+}
+
+/// Which events to return
+#[derive(Debug, Clone)]
+pub struct TimelineQuery {
+    /// Earliest event time, inclusive
+    pub from: Option<DateTime<Utc>>,
+    /// Latest event time, inclusive
+    pub to: Option<DateTime<Utc>>,
+    pub sources: Vec<TimelineSource>,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+impl Default for TimelineQuery {
+    fn default() -> Self {
+        Self { from: None, to: None, sources: TimelineSource::ALL.to_vec(), offset: 0, limit: 50 }
+    }
+}
+
+impl TimelineQuery {
+    fn includes(&self, timestamp: DateTime<Utc>) -> bool {
+        self.from.map_or(true, |from| timestamp >= from) && self.to.map_or(true, |to| timestamp <= to)
+    }
+}
+
+/// Normalizes one module's records into timeline events
+pub trait TimelineAdapter {
+    fn source(&self) -> TimelineSource;
+
+    /// Every event this source holds for the case that `access` may see
+    fn events(&self, case: &CriminalCase, access: AccessLevel) -> Result<Vec<TimelineEvent>, ApiError>;
+}
+
+/// Merges the events of each source into one timeline
+#[derive(Default)]
+pub struct TimelineService {
+    adapters: Vec<Box<dyn TimelineAdapter>>,
+    access: AccessLevel,
+}
+
+impl TimelineService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read events from another source
+    pub fn with_adapter(mut self, adapter: impl TimelineAdapter + 'static) -> Self {
+        self.adapters.push(Box::new(adapter));
+        self
+    }
+
+    /// Access level that decides whether sealed and ex parte records appear
+    pub fn with_access_level(mut self, access: AccessLevel) -> Self {
+        self.access = access;
+        self
+    }
+
+    /// Collect, filter, sort and page the case's events
+    ///
+    /// Only the requested sources are read. Events at the same time are
+    /// listed in [`TimelineSource::ALL`] order.
+    pub fn timeline(&self, case: &CriminalCase, query: &TimelineQuery) -> CaseTimeline {
+        let mut events = Vec::new();
+        let mut failed_sources = Vec::new();
+
+        for adapter in self.adapters.iter().filter(|a| query.sources.contains(&a.source())) {
+            match adapter.events(case, self.access) {
+                Ok(found) => events.extend(found.into_iter().filter(|e| query.includes(e.timestamp))),
+                Err(e) => failed_sources.push(TimelineSourceFailure { source: adapter.source(), error: e.to_string() }),
+            }
+        }
+
+        events.sort_by(|a, b| {
+            a.timestamp
+                .cmp(&b.timestamp)
+                .then(a.source.cmp(&b.source))
+                .then_with(|| a.event_type.cmp(&b.event_type))
+                .then_with(|| a.reference_id.cmp(&b.reference_id))
+        });
+
+        let total = events.len();
+        CaseTimeline {
+            case_id: case.id,
+            case_number: case.case_number.clone(),
+            items: events.into_iter().skip(query.offset).take(query.limit).collect(),
+            total,
+            offset: query.offset,
+            limit: query.limit,
+            partial: !failed_sources.is_empty(),
+            failed_sources,
+        }
+    }
+}
+
+/// Opening, sealing and closing of the case, and its motions and rulings
+pub struct CaseAdapter;
+
+impl TimelineAdapter for CaseAdapter {
+    fn source(&self) -> TimelineSource {
+        TimelineSource::Case
+    }
+
+    fn events(&self, case: &CriminalCase, _access: AccessLevel) -> Result<Vec<TimelineEvent>, ApiError> {
+        let source = self.source();
+        let mut events = vec![TimelineEvent::new(
+            case.opened_at,
+            source,
+            "case.opened",
+            format!("Case {} opened: {}", case.case_number, case.title),
+            case.id,
+        )];
+        if let Some(sealed) = case.sealed_date {
+            events.push(TimelineEvent::new(sealed, source, "case.sealed", "Case sealed".to_string(), case.id));
+        }
+        if let Some(closed) = case.closed_at {
+            let summary = format!("Case closed as {}", words(&case.status));
+            events.push(TimelineEvent::new(closed, source, "case.closed", summary, case.id));
+        }
+
+        for motion in &case.motions {
+            let kind = match &motion.motion_type {
+                MotionType::Other(kind) => kind.clone(),
+                kind => words(kind),
+            };
+            events.push(TimelineEvent::new(
+                motion.filed_date,
+                source,
+                "motion.filed",
+                format!("Motion ({}) filed by {}", kind, motion.filed_by),
+                motion.id,
+            ));
+            if let Some(ruled) = motion.ruling_date {
+                let summary = format!("Motion ({}) {}", kind, words(&motion.status));
+                events.push(TimelineEvent::new(ruled, source, "motion.ruled", summary, motion.id));
+            }
+        }
+        Ok(events)
+    }
+}
+
+/// Docket entries the caller may see, both those in the docket and those
+/// filed on the case record
+pub struct DocketAdapter {
+    repo: Arc<dyn DocketRepository>,
+}
+
+impl DocketAdapter {
+    pub fn new(repo: Arc<dyn DocketRepository>) -> Self {
+        Self { repo }
+    }
+}
+
+impl TimelineAdapter for DocketAdapter {
+    fn source(&self) -> TimelineSource {
+        TimelineSource::Docket
+    }
+
+    fn events(&self, case: &CriminalCase, access: AccessLevel) -> Result<Vec<TimelineEvent>, ApiError> {
+        let mut entries = self.repo.find_entries_by_case(case.id)?;
+        for entry in &case.docket_entries {
+            if !entries.iter().any(|e| e.id == entry.id) {
+                entries.push(entry.clone());
+            }
+        }
+
+        Ok(entries
+            .into_iter()
+            .filter(|e| access.can_view(e.is_sealed, e.is_ex_parte))
+            .map(|e| {
+                let summary = format!("#{} {}", e.entry_number, e.description);
+                TimelineEvent::new(e.date_filed, self.source(), "docket.entry", summary, e.id)
+            })
+            .collect())
+    }
+}
+
+/// Proceedings on the court calendar, at their scheduled time
+pub struct CalendarAdapter {
+    repo: Arc<dyn CalendarRepository>,
+}
+
+impl CalendarAdapter {
+    pub fn new(repo: Arc<dyn CalendarRepository>) -> Self {
+        Self { repo }
+    }
+}
+
+impl TimelineAdapter for CalendarAdapter {
+    fn source(&self) -> TimelineSource {
+        TimelineSource::Calendar
+    }
+
+    fn events(&self, case: &CriminalCase, _access: AccessLevel) -> Result<Vec<TimelineEvent>, ApiError> {
+        Ok(self
+            .repo
+            .find_events_by_case(case.id)?
+            .into_iter()
+            .map(|e| {
+                let summary = format!("{} in courtroom {} ({})", capitalize(&words(&e.event_type)), e.courtroom, words(&e.status));
+                TimelineEvent::new(e.scheduled_date, self.source(), "calendar.event", summary, e.id)
+            })
+            .collect())
+    }
+}
+
+/// Deadlines when they start running and when they're met
+///
+/// Deadlines don't record when they were created, so a deadline appears at
+/// its triggering date.
+pub struct DeadlineAdapter {
+    repo: Arc<dyn DeadlineRepository>,
+}
+
+impl DeadlineAdapter {
+    pub fn new(repo: Arc<dyn DeadlineRepository>) -> Self {
+        Self { repo }
+    }
+}
+
+impl TimelineAdapter for DeadlineAdapter {
+    fn source(&self) -> TimelineSource {
+        TimelineSource::Deadlines
+    }
+
+    fn events(&self, case: &CriminalCase, _access: AccessLevel) -> Result<Vec<TimelineEvent>, ApiError> {
+        let mut events = Vec::new();
+        for deadline in self.repo.find_deadlines_by_case(case.id)? {
+            events.push(TimelineEvent::new(
+                deadline.triggering_date,
+                self.source(),
+                "deadline.created",
+                format!(
+                    "{} due {} ({})",
+                    deadline.description,
+                    deadline.due_date.format("%Y-%m-%d"),
+                    deadline.applicable_rule
+                ),
+                deadline.id,
+            ));
+            if let Some(completed) = deadline.completion_date {
+                let summary = format!("{} completed", deadline.description);
+                events.push(TimelineEvent::new(completed, self.source(), "deadline.completed", summary, deadline.id));
+            }
+        }
+        Ok(events)
+    }
+}
+
+/// Judge assignments, reassignments and recusal motions, naming the judges
+pub struct JudgeAdapter {
+    judges: Arc<dyn JudgeRepository>,
+    assignments: Arc<dyn CaseAssignmentRepository>,
+    recusals: Arc<dyn RecusalRepository>,
+}
+
+impl JudgeAdapter {
+    pub fn new<R>(repo: Arc<R>) -> Self
+    where
+        R: JudgeRepository + CaseAssignmentRepository + RecusalRepository + 'static,
+    {
+        Self { judges: repo.clone(), assignments: repo.clone(), recusals: repo }
+    }
+}
+
+impl TimelineAdapter for JudgeAdapter {
+    fn source(&self) -> TimelineSource {
+        TimelineSource::Judges
+    }
+
+    fn events(&self, case: &CriminalCase, _access: AccessLevel) -> Result<Vec<TimelineEvent>, ApiError> {
+        let mut names: HashMap<Uuid, String> = HashMap::new();
+        let mut name = |id: Uuid| -> Result<String, ApiError> {
+            if let Some(name) = names.get(&id) {
+                return Ok(name.clone());
+            }
+            let found = self.judges.find_judge_by_id(id)?.map(|j: Judge| j.name).unwrap_or_else(|| id.to_string());
+            names.insert(id, found.clone());
+            Ok(found)
+        };
+
+        let mut events = Vec::new();
+        for assignment in self.assignments.find_assignment_history(case.id)? {
+            let judge = name(assignment.judge_id)?;
+            let (event_type, summary) = match assignment.previous_judge_id {
+                Some(previous) => (
+                    "judge.reassigned",
+                    format!(
+                        "Reassigned from {} to {}: {}",
+                        name(previous)?,
+                        judge,
+                        assignment.reassignment_reason.as_deref().unwrap_or(&assignment.reason)
+                    ),
+                ),
+                None => ("judge.assigned", format!("Assigned to {} ({})", judge, words(&assignment.assignment_type))),
+            };
+            events.push(TimelineEvent::new(assignment.assigned_date, self.source(), event_type, summary, assignment.id));
+        }
+
+        for recusal in self.recusals.find_recusals_by_case(case.id)? {
+            let judge = name(recusal.judge_id)?;
+            events.push(TimelineEvent::new(
+                recusal.filed_date,
+                self.source(),
+                "recusal.filed",
+                format!("Motion to recuse {} filed by {}", judge, recusal.filed_by),
+                recusal.id,
+            ));
+            if let Some(ruled) = recusal.ruling_date {
+                let summary = format!("Recusal of {} {}", judge, words(&recusal.status));
+                events.push(TimelineEvent::new(ruled, self.source(), "recusal.ruled", summary, recusal.id));
+            }
+        }
+        Ok(events)
+    }
+}
+
+/// Issued orders the caller may see
+pub struct OrderAdapter {
+    repo: Arc<dyn DocumentRepository>,
+}
+
+impl OrderAdapter {
+    pub fn new(repo: Arc<dyn DocumentRepository>) -> Self {
+        Self { repo }
+    }
+}
+
+impl TimelineAdapter for OrderAdapter {
+    fn source(&self) -> TimelineSource {
+        TimelineSource::Orders
+    }
+
+    fn events(&self, case: &CriminalCase, access: AccessLevel) -> Result<Vec<TimelineEvent>, ApiError> {
+        Ok(self
+            .repo
+            .find_orders_by_case(&case.id.to_string())?
+            .into_iter()
+            .filter(|o| access.can_view(o.is_sealed, false))
+            .filter_map(|o| {
+                let issued = o.issued_at?;
+                Some(TimelineEvent::new(issued, self.source(), "order.issued", o.title, o.id))
+            })
+            .collect())
+    }
+}
+
+/// Opinions when filed and when published
+pub struct OpinionAdapter {
+    repo: Arc<dyn DocumentRepository>,
+}
+
+impl OpinionAdapter {
+    pub fn new(repo: Arc<dyn DocumentRepository>) -> Self {
+        Self { repo }
+    }
+}
+
+impl TimelineAdapter for OpinionAdapter {
+    fn source(&self) -> TimelineSource {
+        TimelineSource::Opinions
+    }
+
+    fn events(&self, case: &CriminalCase, _access: AccessLevel) -> Result<Vec<TimelineEvent>, ApiError> {
+        let mut events = Vec::new();
+        for opinion in self.repo.find_opinions_by_case(&case.id.to_string())? {
+            if let Some(filed) = opinion.filed_at {
+                let summary = format!("{} by {}", opinion.title, opinion.author_judge_name);
+                events.push(TimelineEvent::new(filed, self.source(), "opinion.filed", summary, &opinion.id));
+            }
+            if let Some(published) = opinion.published_at {
+                let summary = match opinion.citation.as_ref().and_then(|c| c.neutral_citation.as_deref()) {
+                    Some(citation) => format!("{} published as {}", opinion.title, citation),
+                    None => format!("{} published", opinion.title),
+                };
+                events.push(TimelineEvent::new(published, self.source(), "opinion.published", summary, &opinion.id));
+            }
+        }
+        Ok(events)
+    }
+}
+
+/// Serialized snake_case name of a unit enum variant, as words
+fn words<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(|s| s.replace('_', " ")))
+        .unwrap_or_default()
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::common::MotionStatus;
+    use crate::domain::criminal_case::{CaseStatus, CrimeType, Motion};
+    use chrono::{Duration, TimeZone};
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, day, hour, 0, 0).unwrap()
+    }
+
+    fn case() -> CriminalCase {
+        let mut case = CriminalCase::new(
+            "United States v. Timeline".to_string(),
+            "Case for timeline tests".to_string(),
+            CrimeType::Fraud,
+            "SDNY".to_string(),
+            None,
+            "1:26-cr-00001".to_string(),
+            "New York, NY".to_string(),
+        );
+        case.opened_at = at(1, 9);
+        case
+    }
+
+    /// Returns fixed events, or fails when `events` is `None`
+    struct FixedAdapter {
+        source: TimelineSource,
+        events: Option<Vec<TimelineEvent>>,
+    }
+
+    impl FixedAdapter {
+        fn new(source: TimelineSource, times: &[DateTime<Utc>]) -> Self {
+            let events = times
+                .iter()
+                .enumerate()
+                .map(|(i, t)| TimelineEvent::new(*t, source, "test.event", format!("{} {}", source.as_str(), i), i))
+                .collect();
+            Self { source, events: Some(events) }
+        }
+
+        fn failing(source: TimelineSource) -> Self {
+            Self { source, events: None }
+        }
+    }
+
+    impl TimelineAdapter for FixedAdapter {
+        fn source(&self) -> TimelineSource {
+            self.source
+        }
+
+        fn events(&self, _case: &CriminalCase, _access: AccessLevel) -> Result<Vec<TimelineEvent>, ApiError> {
+            self.events.clone().ok_or_else(|| ApiError::StorageError("store unavailable".to_string()))
+        }
+    }
+
+    #[test]
+    fn test_sources_are_merged_in_time_order() {
+        let service = TimelineService::new()
+            .with_adapter(FixedAdapter::new(TimelineSource::Orders, &[at(4, 9), at(2, 9)]))
+            .with_adapter(FixedAdapter::new(TimelineSource::Docket, &[at(3, 9), at(2, 9)]));
+
+        let timeline = service.timeline(&case(), &TimelineQuery::default());
+        let order: Vec<(DateTime<Utc>, TimelineSource)> = timeline.items.iter().map(|e| (e.timestamp, e.source)).collect();
+        assert_eq!(
+            order,
+            vec![
+                (at(2, 9), TimelineSource::Docket),
+                (at(2, 9), TimelineSource::Orders),
+                (at(3, 9), TimelineSource::Docket),
+                (at(4, 9), TimelineSource::Orders),
+            ]
+        );
+        assert_eq!(timeline.total, 4);
+        assert!(!timeline.partial);
+    }
+
+    #[test]
+    fn test_range_sources_and_paging_are_applied() {
+        let service = TimelineService::new()
+            .with_adapter(FixedAdapter::new(TimelineSource::Docket, &[at(1, 9), at(2, 9), at(3, 9), at(4, 9)]))
+            .with_adapter(FixedAdapter::failing(TimelineSource::Orders));
+
+        let query = TimelineQuery {
+            from: Some(at(2, 0)),
+            to: Some(at(4, 9)),
+            sources: vec![TimelineSource::Docket],
+            offset: 1,
+            limit: 1,
+        };
+        let timeline = service.timeline(&case(), &query);
+        assert_eq!(timeline.total, 3);
+        assert_eq!(timeline.items.len(), 1);
+        assert_eq!(timeline.items[0].timestamp, at(3, 9));
+        assert!(!timeline.partial, "Sources left out of the query aren't read");
+    }
+
+    #[test]
+    fn test_failing_source_marks_timeline_partial() {
+        let service = TimelineService::new()
+            .with_adapter(FixedAdapter::new(TimelineSource::Docket, &[at(1, 9)]))
+            .with_adapter(FixedAdapter::failing(TimelineSource::Opinions));
+
+        let timeline = service.timeline(&case(), &TimelineQuery::default());
+        assert!(timeline.partial);
+        assert_eq!(timeline.items.len(), 1);
+        assert_eq!(timeline.failed_sources.len(), 1);
+        assert_eq!(timeline.failed_sources[0].source, TimelineSource::Opinions);
+        assert!(timeline.failed_sources[0].error.contains("store unavailable"));
+    }
+
+    #[test]
+    fn test_case_adapter_reports_motions_and_closing() {
+        let mut case = case();
+        case.motions.push(Motion {
+            id: Uuid::new_v4(),
+            motion_type: MotionType::SuppressEvidence,
+            filed_by: "Defense".to_string(),
+            description: "Suppress the search".to_string(),
+            filed_date: at(5, 10),
+            status: MotionStatus::GrantedInPart,
+            ruling_date: Some(at(9, 15)),
+        });
+        case.status = CaseStatus::Dismissed;
+        case.closed_at = Some(at(20, 12));
+
+        let events = CaseAdapter.events(&case, AccessLevel::Public).unwrap();
+        let kinds: Vec<&str> = events.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(kinds, vec!["case.opened", "case.closed", "motion.filed", "motion.ruled"]);
+        assert_eq!(events[1].summary, "Case closed as dismissed");
+        assert_eq!(events[2].summary, "Motion (suppress evidence) filed by Defense");
+        assert_eq!(events[3].summary, "Motion (suppress evidence) granted in part");
+        assert_eq!(events[3].timestamp - events[2].timestamp, Duration::days(4) + Duration::hours(5));
+    }
+
+    #[test]
+    fn test_parse_list_rejects_unknown_source() {
+        assert_eq!(
+            TimelineSource::parse_list(&["orders", " docket", "orders"]).unwrap(),
+            vec![TimelineSource::Orders, TimelineSource::Docket]
+        );
+        assert!(matches!(TimelineSource::parse_list(&["docket", "emails"]), Err(ApiError::BadRequest(_))));
+    }
+}
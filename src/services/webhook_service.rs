@@ -0,0 +1,543 @@
+//! Webhook notification queue and delivery
+//!
+//! Handlers call [`notify`] when something subscribers care about happens.
+//! That only queues a notification per matching subscription; nothing is sent
+//! until [`deliver_pending`] flushes the queue, so a slow or unreachable
+//! subscriber never holds up the request that raised the event.
+//!
+//! The district's own webhook is the exception: case events are posted to it
+//! straight away by [`send_to_district`]. A failed post is logged and queued,
+//! so [`deliver_pending`] retries it like a subscription notification.
+
+use crate::adapters::spin_http_webhook_delivery::SpinHttpWebhookDelivery;
+use crate::domain::webhook::{
+    sign_payload, DeliveryAttempt, DeliveryOutcome, DirectDelivery, DistrictWebhook, PendingDelivery,
+    WebhookEvent, WebhookEventType, WebhookSubscription,
+};
+use crate::error::ApiResult;
+use crate::ports::webhook_delivery::{OutboundWebhook, WebhookDelivery};
+use crate::ports::webhook_repository::WebhookRepository;
+use crate::utils::repository_factory::RepositoryFactory;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use spin_sdk::http::Request;
+use utoipa::ToSchema;
+
+/// Header carrying the `sha256=<hex>` HMAC of the body
+pub const SIGNATURE_HEADER: &str = "x-webhook-signature";
+
+/// Result of flushing the delivery queue
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct DeliveryReport {
+    /// Notifications sent this run
+    pub attempted: usize,
+    pub delivered: usize,
+    /// Failed and rescheduled
+    pub retrying: usize,
+    /// Failed on their last attempt and dropped
+    pub abandoned: usize,
+    /// Dropped because their subscription or the district webhook was
+    /// deleted or deactivated
+    pub discarded: usize,
+    /// Still waiting out a retry delay
+    pub deferred: usize,
+}
+
+/// Queue an event for every active subscription to its type
+///
+/// Returns the number of notifications queued.
+pub fn enqueue_event(repo: &dyn WebhookRepository, event: &WebhookEvent) -> ApiResult<usize> {
+    let mut queued = 0;
+    for subscription in repo.list_subscriptions()? {
+        if subscription.subscribes_to(event.event_type) {
+            repo.enqueue_delivery(&PendingDelivery::new(Some(subscription.id), event.clone()))?;
+            queued += 1;
+        }
+    }
+    Ok(queued)
+}
+
+/// Post an event to the district webhook, if one is configured for it
+///
+/// Makes one attempt and records its outcome on the district webhook. A
+/// failed attempt is queued for [`deliver_pending`] to retry. Returns `None`
+/// when there is no district webhook or it doesn't take this kind of event.
+pub fn send_to_district(
+    repo: &dyn WebhookRepository,
+    delivery: &dyn WebhookDelivery,
+    event: &WebhookEvent,
+    now: DateTime<Utc>,
+) -> ApiResult<Option<DirectDelivery>> {
+    let mut webhook = match repo.get_district_webhook()? {
+        Some(webhook) if webhook.sends(event.event_type) => webhook,
+        _ => return Ok(None),
+    };
+
+    let mut pending = PendingDelivery::new(None, event.clone());
+    let outbound = signed_webhook(&webhook.target_url, &webhook.secret, event, &pending.id.to_string())?;
+    let (status_code, error) = match delivery.deliver(&outbound) {
+        Ok(status) => (Some(status), None),
+        Err(e) => (None, Some(e)),
+    };
+    let result = DirectDelivery {
+        event_id: event.id,
+        event_type: event.event_type,
+        attempted_at: now,
+        status_code,
+        error,
+        delivered: matches!(status_code, Some(status) if (200..300).contains(&status)),
+    };
+
+    webhook.last_delivery = Some(result.clone());
+    repo.save_district_webhook(&webhook)?;
+    if !result.delivered && pending.record_failure(now) {
+        repo.enqueue_delivery(&pending)?;
+    }
+    Ok(Some(result))
+}
+
+/// Queue an event raised while handling a request, and post it to the
+/// district webhook
+///
+/// The change that raised the event has already been saved, so a failure to
+/// queue or deliver is logged rather than failing the request.
+pub fn notify(req: &Request, event_type: WebhookEventType, data: serde_json::Value) {
+    let event = WebhookEvent::new(event_type, data);
+    let repo = match RepositoryFactory::webhook_repo(req) {
+        Ok(repo) => repo,
+        Err(e) => {
+            crate::request_log!("webhooks: cannot queue {} {}: {}", event_type.as_str(), event.id, e);
+            return;
+        }
+    };
+
+    if let Err(e) = enqueue_event(&repo, &event) {
+        crate::request_log!("webhooks: cannot queue {} {}: {}", event_type.as_str(), event.id, e);
+    }
+
+    match send_to_district(&repo, &SpinHttpWebhookDelivery, &event, Utc::now()) {
+        Ok(Some(result)) if !result.delivered => crate::request_log!(
+            "webhooks: district webhook rejected {} {}: status {:?}, error {:?}; queued for retry",
+            event_type.as_str(),
+            event.id,
+            result.status_code,
+            result.error
+        ),
+        Err(e) => crate::request_log!(
+            "webhooks: cannot send {} {} to the district webhook: {}",
+            event_type.as_str(),
+            event.id,
+            e
+        ),
+        _ => {}
+    }
+}
+
+/// A JSON delivery of `event` to `target_url`, signed with `secret`
+fn signed_webhook(target_url: &str, secret: &str, event: &WebhookEvent, delivery_id: &str) -> ApiResult<OutboundWebhook> {
+    let body = serde_json::to_vec(event)?;
+    Ok(OutboundWebhook {
+        target_url: target_url.to_string(),
+        headers: vec![
+            ("content-type".to_string(), "application/json".to_string()),
+            ("x-webhook-event".to_string(), event.event_type.as_str().to_string()),
+            ("x-webhook-delivery".to_string(), delivery_id.to_string()),
+            (SIGNATURE_HEADER.to_string(), sign_payload(secret, &body)),
+        ],
+        body,
+    })
+}
+
+/// Where a queued notification goes
+enum Target {
+    Subscription(WebhookSubscription),
+    District(DistrictWebhook),
+}
+
+/// Send every queued notification that is due
+///
+/// A 2xx response counts as delivered. Anything else, including a transport
+/// error, is a failed attempt that is retried with backoff until the attempts
+/// run out. Every attempt is recorded against its subscription; for the
+/// district webhook, the latest attempt is kept as its last delivery.
+pub fn deliver_pending(
+    repo: &dyn WebhookRepository,
+    delivery: &dyn WebhookDelivery,
+    now: DateTime<Utc>,
+) -> ApiResult<DeliveryReport> {
+    let mut report = DeliveryReport::default();
+
+    for mut pending in repo.pending_deliveries()? {
+        if !pending.is_due(now) {
+            report.deferred += 1;
+            continue;
+        }
+
+        let target = match pending.subscription_id {
+            Some(id) => repo.find_subscription(id)?
+                .filter(|subscription| subscription.active)
+                .map(Target::Subscription),
+            None => repo.get_district_webhook()?
+                .filter(|webhook| webhook.sends(pending.event.event_type))
+                .map(Target::District),
+        };
+        let Some(target) = target else {
+            repo.remove_delivery(pending.id)?;
+            report.discarded += 1;
+            continue;
+        };
+
+        let (target_url, secret) = match &target {
+            Target::Subscription(subscription) => (&subscription.target_url, &subscription.secret),
+            Target::District(webhook) => (&webhook.target_url, &webhook.secret),
+        };
+        let webhook = signed_webhook(target_url, secret, &pending.event, &pending.id.to_string())?;
+
+        report.attempted += 1;
+        let result = delivery.deliver(&webhook);
+        let attempt_number = pending.attempts + 1;
+
+        let (status_code, error) = match &result {
+            Ok(status) => (Some(*status), None),
+            Err(e) => (None, Some(e.clone())),
+        };
+        let delivered = matches!(status_code, Some(status) if (200..300).contains(&status));
+
+        let outcome = if delivered {
+            repo.remove_delivery(pending.id)?;
+            report.delivered += 1;
+            DeliveryOutcome::Delivered
+        } else if pending.record_failure(now) {
+            repo.update_delivery(&pending)?;
+            report.retrying += 1;
+            DeliveryOutcome::Retrying
+        } else {
+            repo.remove_delivery(pending.id)?;
+            report.abandoned += 1;
+            DeliveryOutcome::Abandoned
+        };
+
+        match target {
+            Target::Subscription(subscription) => repo.record_attempt(&DeliveryAttempt {
+                delivery_id: pending.id,
+                subscription_id: subscription.id,
+                event_id: pending.event.id,
+                event_type: pending.event.event_type,
+                attempt: attempt_number,
+                attempted_at: now,
+                status_code,
+                error,
+                outcome,
+            })?,
+            Target::District(mut webhook) => {
+                webhook.last_delivery = Some(DirectDelivery {
+                    event_id: pending.event.id,
+                    event_type: pending.event.event_type,
+                    attempted_at: now,
+                    status_code,
+                    error,
+                    delivered,
+                });
+                repo.save_district_webhook(&webhook)?;
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::webhook::MAX_DELIVERY_ATTEMPTS;
+    use chrono::Duration;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    #[derive(Default)]
+    struct MemoryRepository {
+        subscriptions: RefCell<Vec<WebhookSubscription>>,
+        pending: RefCell<HashMap<Uuid, PendingDelivery>>,
+        attempts: RefCell<Vec<DeliveryAttempt>>,
+        district: RefCell<Option<DistrictWebhook>>,
+    }
+
+    impl WebhookRepository for MemoryRepository {
+        fn save_subscription(&self, subscription: &WebhookSubscription) -> ApiResult<()> {
+            self.subscriptions.borrow_mut().push(subscription.clone());
+            Ok(())
+        }
+        fn find_subscription(&self, id: Uuid) -> ApiResult<Option<WebhookSubscription>> {
+            Ok(self.subscriptions.borrow().iter().find(|s| s.id == id).cloned())
+        }
+        fn list_subscriptions(&self) -> ApiResult<Vec<WebhookSubscription>> {
+            Ok(self.subscriptions.borrow().clone())
+        }
+        fn delete_subscription(&self, id: Uuid) -> ApiResult<bool> {
+            let mut subscriptions = self.subscriptions.borrow_mut();
+            let before = subscriptions.len();
+            subscriptions.retain(|s| s.id != id);
+            Ok(subscriptions.len() != before)
+        }
+        fn enqueue_delivery(&self, delivery: &PendingDelivery) -> ApiResult<()> {
+            self.update_delivery(delivery)
+        }
+        fn update_delivery(&self, delivery: &PendingDelivery) -> ApiResult<()> {
+            self.pending.borrow_mut().insert(delivery.id, delivery.clone());
+            Ok(())
+        }
+        fn remove_delivery(&self, id: Uuid) -> ApiResult<()> {
+            self.pending.borrow_mut().remove(&id);
+            Ok(())
+        }
+        fn pending_deliveries(&self) -> ApiResult<Vec<PendingDelivery>> {
+            Ok(self.pending.borrow().values().cloned().collect())
+        }
+        fn record_attempt(&self, attempt: &DeliveryAttempt) -> ApiResult<()> {
+            self.attempts.borrow_mut().insert(0, attempt.clone());
+            Ok(())
+        }
+        fn recent_attempts(&self, subscription_id: Uuid, limit: usize) -> ApiResult<Vec<DeliveryAttempt>> {
+            Ok(self.attempts.borrow().iter().filter(|a| a.subscription_id == subscription_id).take(limit).cloned().collect())
+        }
+        fn get_district_webhook(&self) -> ApiResult<Option<DistrictWebhook>> {
+            Ok(self.district.borrow().clone())
+        }
+        fn save_district_webhook(&self, webhook: &DistrictWebhook) -> ApiResult<()> {
+            *self.district.borrow_mut() = Some(webhook.clone());
+            Ok(())
+        }
+        fn delete_district_webhook(&self) -> ApiResult<()> {
+            *self.district.borrow_mut() = None;
+            Ok(())
+        }
+    }
+
+    /// Delivery port that answers with scripted results and keeps what it was sent
+    struct MockDelivery {
+        responses: RefCell<Vec<Result<u16, String>>>,
+        sent: RefCell<Vec<OutboundWebhook>>,
+    }
+
+    impl MockDelivery {
+        fn new(responses: Vec<Result<u16, String>>) -> Self {
+            Self { responses: RefCell::new(responses), sent: RefCell::new(Vec::new()) }
+        }
+    }
+
+    impl WebhookDelivery for MockDelivery {
+        fn deliver(&self, webhook: &OutboundWebhook) -> Result<u16, String> {
+            self.sent.borrow_mut().push(webhook.clone());
+            self.responses.borrow_mut().remove(0)
+        }
+    }
+
+    fn subscribe(repo: &MemoryRepository, event_types: Vec<WebhookEventType>) -> WebhookSubscription {
+        let subscription = WebhookSubscription::new(
+            "https://pretrial.example.gov/hooks",
+            event_types,
+            "pretrial-shared-secret".to_string(),
+            None,
+        )
+        .unwrap();
+        repo.save_subscription(&subscription).unwrap();
+        subscription
+    }
+
+    fn header<'a>(webhook: &'a OutboundWebhook, name: &str) -> &'a str {
+        webhook.headers.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str()).unwrap()
+    }
+
+    #[test]
+    fn test_events_queue_only_for_matching_subscriptions() {
+        let repo = MemoryRepository::default();
+        subscribe(&repo, vec![WebhookEventType::CaseStatusChanged]);
+        subscribe(&repo, vec![WebhookEventType::CaseStatusChanged, WebhookEventType::OrderIssued]);
+        let mut inactive = subscribe(&repo, vec![WebhookEventType::OrderIssued]);
+        inactive.active = false;
+        repo.subscriptions.borrow_mut()[2] = inactive;
+
+        let event = WebhookEvent::new(WebhookEventType::OrderIssued, serde_json::json!({"order_id": "o-1"}));
+        assert_eq!(enqueue_event(&repo, &event).unwrap(), 1);
+
+        let event = WebhookEvent::new(WebhookEventType::CaseStatusChanged, serde_json::json!({}));
+        assert_eq!(enqueue_event(&repo, &event).unwrap(), 2);
+        assert_eq!(repo.pending.borrow().len(), 3);
+    }
+
+    #[test]
+    fn test_delivery_is_signed_with_subscription_secret() {
+        let repo = MemoryRepository::default();
+        let subscription = subscribe(&repo, vec![WebhookEventType::OrderIssued]);
+        let event = WebhookEvent::new(WebhookEventType::OrderIssued, serde_json::json!({"order_id": "o-1"}));
+        enqueue_event(&repo, &event).unwrap();
+
+        let delivery = MockDelivery::new(vec![Ok(204)]);
+        let report = deliver_pending(&repo, &delivery, Utc::now()).unwrap();
+
+        assert_eq!(report.delivered, 1);
+        assert!(repo.pending.borrow().is_empty());
+
+        let sent = delivery.sent.borrow();
+        assert_eq!(sent[0].target_url, subscription.target_url);
+        assert_eq!(header(&sent[0], "x-webhook-event"), "order.issued");
+        assert_eq!(header(&sent[0], SIGNATURE_HEADER), sign_payload(&subscription.secret, &sent[0].body));
+
+        let attempts = repo.recent_attempts(subscription.id, 10).unwrap();
+        assert_eq!(attempts.len(), 1);
+        assert_eq!(attempts[0].status_code, Some(204));
+        assert_eq!(attempts[0].outcome, DeliveryOutcome::Delivered);
+    }
+
+    #[test]
+    fn test_failed_delivery_is_retried_after_backoff() {
+        let repo = MemoryRepository::default();
+        let subscription = subscribe(&repo, vec![WebhookEventType::DeadlineUrgent]);
+        enqueue_event(&repo, &WebhookEvent::new(WebhookEventType::DeadlineUrgent, serde_json::json!({}))).unwrap();
+
+        let delivery = MockDelivery::new(vec![Ok(503), Err("connection refused".to_string()), Ok(200)]);
+        let now = Utc::now();
+
+        let first = deliver_pending(&repo, &delivery, now).unwrap();
+        assert_eq!(first.retrying, 1);
+
+        // Not due again until the backoff has passed
+        let early = deliver_pending(&repo, &delivery, now + Duration::seconds(30)).unwrap();
+        assert_eq!(early.deferred, 1);
+        assert_eq!(early.attempted, 0);
+
+        let second = deliver_pending(&repo, &delivery, now + Duration::minutes(1)).unwrap();
+        assert_eq!(second.retrying, 1);
+        let third = deliver_pending(&repo, &delivery, now + Duration::minutes(5)).unwrap();
+        assert_eq!(third.delivered, 1);
+
+        let attempts = repo.recent_attempts(subscription.id, 10).unwrap();
+        let numbers: Vec<u32> = attempts.iter().map(|a| a.attempt).collect();
+        assert_eq!(numbers, vec![3, 2, 1]);
+        assert_eq!(attempts[1].error.as_deref(), Some("connection refused"));
+        assert_eq!(attempts[2].status_code, Some(503));
+    }
+
+    #[test]
+    fn test_delivery_abandoned_after_max_attempts() {
+        let repo = MemoryRepository::default();
+        let subscription = subscribe(&repo, vec![WebhookEventType::OrderIssued]);
+        enqueue_event(&repo, &WebhookEvent::new(WebhookEventType::OrderIssued, serde_json::json!({}))).unwrap();
+
+        let delivery = MockDelivery::new((0..MAX_DELIVERY_ATTEMPTS).map(|_| Ok(500)).collect());
+        let mut now = Utc::now();
+        for _ in 0..MAX_DELIVERY_ATTEMPTS {
+            deliver_pending(&repo, &delivery, now).unwrap();
+            now += Duration::hours(1);
+        }
+
+        assert!(repo.pending.borrow().is_empty());
+        let attempts = repo.recent_attempts(subscription.id, 10).unwrap();
+        assert_eq!(attempts.len(), MAX_DELIVERY_ATTEMPTS as usize);
+        assert_eq!(attempts[0].outcome, DeliveryOutcome::Abandoned);
+    }
+
+    #[test]
+    fn test_deliveries_for_deleted_subscription_are_discarded() {
+        let repo = MemoryRepository::default();
+        let subscription = subscribe(&repo, vec![WebhookEventType::OrderIssued]);
+        enqueue_event(&repo, &WebhookEvent::new(WebhookEventType::OrderIssued, serde_json::json!({}))).unwrap();
+        repo.delete_subscription(subscription.id).unwrap();
+
+        let delivery = MockDelivery::new(vec![]);
+        let report = deliver_pending(&repo, &delivery, Utc::now()).unwrap();
+
+        assert_eq!(report.discarded, 1);
+        assert!(delivery.sent.borrow().is_empty());
+    }
+
+    fn district_webhook(repo: &MemoryRepository) -> DistrictWebhook {
+        let webhook = DistrictWebhook::new("https://clerk.example.gov/case-events", "district-shared-secret".to_string())
+            .unwrap();
+        repo.save_district_webhook(&webhook).unwrap();
+        webhook
+    }
+
+    #[test]
+    fn test_case_event_is_posted_to_district_webhook_signed() {
+        let repo = MemoryRepository::default();
+        let webhook = district_webhook(&repo);
+        let event = WebhookEvent::new(WebhookEventType::CaseCreated, serde_json::json!({"case_number": "1:24-cr-00001"}));
+
+        let delivery = MockDelivery::new(vec![Ok(202)]);
+        let result = send_to_district(&repo, &delivery, &event, Utc::now()).unwrap().unwrap();
+
+        assert!(result.delivered);
+        assert_eq!(result.status_code, Some(202));
+        let sent = delivery.sent.borrow();
+        assert_eq!(sent[0].target_url, webhook.target_url);
+        assert_eq!(header(&sent[0], "x-webhook-event"), "case.created");
+        assert_eq!(header(&sent[0], SIGNATURE_HEADER), sign_payload(&webhook.secret, &sent[0].body));
+        let body: WebhookEvent = serde_json::from_slice(&sent[0].body).unwrap();
+        assert_eq!(body.id, event.id);
+
+        let recorded = repo.get_district_webhook().unwrap().unwrap().last_delivery.unwrap();
+        assert_eq!(recorded.event_id, event.id);
+        assert!(repo.pending.borrow().is_empty(), "delivered posts are not queued");
+    }
+
+    #[test]
+    fn test_failed_district_post_is_recorded_and_queued_for_retry() {
+        let repo = MemoryRepository::default();
+        district_webhook(&repo);
+        let event = WebhookEvent::new(WebhookEventType::CaseStatusChanged, serde_json::json!({}));
+
+        let delivery = MockDelivery::new(vec![Err("connection refused".to_string()), Ok(200)]);
+        let now = Utc::now();
+        let result = send_to_district(&repo, &delivery, &event, now).unwrap().unwrap();
+
+        assert!(!result.delivered);
+        assert_eq!(result.error.as_deref(), Some("connection refused"));
+        assert!(!repo.get_district_webhook().unwrap().unwrap().last_delivery.unwrap().delivered);
+        let pending: Vec<PendingDelivery> = repo.pending.borrow().values().cloned().collect();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].subscription_id, None);
+        assert_eq!(pending[0].attempts, 1, "the immediate post counts as the first attempt");
+
+        let early = deliver_pending(&repo, &delivery, now).unwrap();
+        assert_eq!(early.deferred, 1);
+
+        let retry = deliver_pending(&repo, &delivery, now + Duration::minutes(1)).unwrap();
+        assert_eq!(retry.delivered, 1);
+        let sent = delivery.sent.borrow();
+        assert_eq!(header(&sent[0], "x-webhook-delivery"), header(&sent[1], "x-webhook-delivery"));
+        assert!(repo.get_district_webhook().unwrap().unwrap().last_delivery.unwrap().delivered);
+        assert!(repo.pending.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_district_webhook_only_takes_case_events() {
+        let repo = MemoryRepository::default();
+        let event = WebhookEvent::new(WebhookEventType::CaseCreated, serde_json::json!({}));
+        let delivery = MockDelivery::new(vec![]);
+        assert!(send_to_district(&repo, &delivery, &event, Utc::now()).unwrap().is_none(), "none configured");
+
+        district_webhook(&repo);
+        let event = WebhookEvent::new(WebhookEventType::OrderIssued, serde_json::json!({}));
+        assert!(send_to_district(&repo, &delivery, &event, Utc::now()).unwrap().is_none());
+        assert!(delivery.sent.borrow().is_empty());
+        assert!(repo.pending.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_district_retries_are_discarded_once_the_webhook_is_removed() {
+        let repo = MemoryRepository::default();
+        district_webhook(&repo);
+        let event = WebhookEvent::new(WebhookEventType::CaseCreated, serde_json::json!({}));
+        let now = Utc::now();
+        send_to_district(&repo, &MockDelivery::new(vec![Ok(500)]), &event, now).unwrap();
+        repo.delete_district_webhook().unwrap();
+
+        let delivery = MockDelivery::new(vec![]);
+        let report = deliver_pending(&repo, &delivery, now + Duration::minutes(1)).unwrap();
+
+        assert_eq!(report.discarded, 1);
+        assert!(delivery.sent.borrow().is_empty());
+    }
+}
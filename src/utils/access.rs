@@ -0,0 +1,160 @@
+//! Access levels for restricted court records
+//!
+//! Callers declare their access level in the `X-Access-Level` header. Handlers
+//! use it to decide whether sealed and ex parte material is visible:
+//!
+//! | Level               | Sealed | Ex parte |
+//! |---------------------|--------|----------|
+//! | `public`            | no     | no       |
+//! | `attorney`          | no     | no       |
+//! | `sealed-authorized` | yes    | no       |
+//! | `chambers`          | yes    | yes      |
+//!
+//! A request without the header is treated as `public`. Ex parte filings stay
+//! with chambers even for callers cleared for sealed material, since they are
+//! communications from one side that the other side may not see.
+//!
+//! Administrative endpoints are separate from record access: they require the
+//! deployment's `admin_token` variable in the `X-Admin-Token` header.
+
+use crate::error::ApiError;
+use spin_sdk::http::Request;
+
+/// Header carrying the caller's access level
+pub const ACCESS_LEVEL_HEADER: &str = "x-access-level";
+
+/// Header carrying the administrator token
+pub const ADMIN_TOKEN_HEADER: &str = "x-admin-token";
+
+/// Caller's clearance for restricted records
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AccessLevel {
+    /// Members of the public viewing the open record
+    #[default]
+    Public,
+    /// Counsel of record, who see the same docket as the public
+    Attorney,
+    /// The assigned judge and chambers staff
+    Chambers,
+    /// Parties or counsel granted access to sealed material by court order
+    SealedAuthorized,
+}
+
+impl AccessLevel {
+    /// Parse a header value, accepting `_` in place of `-`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().replace('_', "-").as_str() {
+            "public" => Some(Self::Public),
+            "attorney" => Some(Self::Attorney),
+            "chambers" => Some(Self::Chambers),
+            "sealed-authorized" => Some(Self::SealedAuthorized),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Attorney => "attorney",
+            Self::Chambers => "chambers",
+            Self::SealedAuthorized => "sealed-authorized",
+        }
+    }
+
+    pub fn can_view_sealed(&self) -> bool {
+        matches!(self, Self::Chambers | Self::SealedAuthorized)
+    }
+
+    pub fn can_view_ex_parte(&self) -> bool {
+        matches!(self, Self::Chambers)
+    }
+
+    /// Whether a record with the given restrictions is visible at this level
+    pub fn can_view(&self, is_sealed: bool, is_ex_parte: bool) -> bool {
+        (!is_sealed || self.can_view_sealed()) && (!is_ex_parte || self.can_view_ex_parte())
+    }
+}
+
+/// Access level declared on a request
+///
+/// Returns `public` when the header is absent and `400 Bad Request` when it
+/// names an unknown level, so a typo never silently downgrades access.
+pub fn get_access_level(req: &Request) -> Result<AccessLevel, ApiError> {
+    match req.header(ACCESS_LEVEL_HEADER).and_then(|v| v.as_str()) {
+        None => Ok(AccessLevel::Public),
+        Some(value) if value.trim().is_empty() => Ok(AccessLevel::Public),
+        Some(value) => AccessLevel::parse(value).ok_or_else(|| {
+            ApiError::BadRequest(format!(
+                "Invalid X-Access-Level '{}'. Expected one of: public, attorney, chambers, sealed-authorized",
+                value
+            ))
+        }),
+    }
+}
+
+/// Require the administrator token on a request
+///
+/// Returns `403 Forbidden` when the header is missing or wrong, and also when
+/// no `admin_token` is configured, so an unconfigured deployment keeps its
+/// administrative endpoints closed.
+pub fn require_admin(req: &Request) -> Result<(), ApiError> {
+    let expected = spin_sdk::variables::get("admin_token").unwrap_or_default();
+    let provided = req.header(ADMIN_TOKEN_HEADER).and_then(|v| v.as_str()).unwrap_or("");
+    if admin_token_matches(&expected, provided) {
+        Ok(())
+    } else {
+        Err(ApiError::Forbidden("A valid X-Admin-Token header is required".to_string()))
+    }
+}
+
+/// Compare tokens without short-circuiting on the first differing byte
+fn admin_token_matches(expected: &str, provided: &str) -> bool {
+    if expected.is_empty() || expected.len() != provided.len() {
+        return false;
+    }
+    expected
+        .bytes()
+        .zip(provided.bytes())
+        .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_accepts_known_levels() {
+        assert_eq!(AccessLevel::parse("public"), Some(AccessLevel::Public));
+        assert_eq!(AccessLevel::parse("Attorney"), Some(AccessLevel::Attorney));
+        assert_eq!(AccessLevel::parse("chambers"), Some(AccessLevel::Chambers));
+        assert_eq!(AccessLevel::parse("sealed-authorized"), Some(AccessLevel::SealedAuthorized));
+        assert_eq!(AccessLevel::parse("sealed_authorized"), Some(AccessLevel::SealedAuthorized));
+        assert_eq!(AccessLevel::parse("judge"), None);
+    }
+
+    #[test]
+    fn test_visibility_by_level() {
+        // (level, sealed visible, ex parte visible)
+        let cases = [
+            (AccessLevel::Public, false, false),
+            (AccessLevel::Attorney, false, false),
+            (AccessLevel::SealedAuthorized, true, false),
+            (AccessLevel::Chambers, true, true),
+        ];
+        for (level, sealed, ex_parte) in cases {
+            assert!(level.can_view(false, false), "{:?} should see open records", level);
+            assert_eq!(level.can_view(true, false), sealed, "{:?} sealed", level);
+            assert_eq!(level.can_view(false, true), ex_parte, "{:?} ex parte", level);
+            assert_eq!(level.can_view(true, true), sealed && ex_parte, "{:?} sealed ex parte", level);
+        }
+    }
+
+    #[test]
+    fn test_admin_token_must_match_configured_token() {
+        assert!(admin_token_matches("s3cret", "s3cret"));
+        assert!(!admin_token_matches("s3cret", "s3cre"));
+        assert!(!admin_token_matches("s3cret", "S3cret"));
+        assert!(!admin_token_matches("", ""));
+    }
+}
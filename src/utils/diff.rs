@@ -0,0 +1,363 @@
+//! Line-based text diff
+//!
+//! Finds a longest common subsequence of lines and reports everything else
+//! as removed from the old text or added in the new one, grouped into
+//! hunks with a few lines of context like a unified diff.
+//!
+//! The LCS is found with Hirschberg's divide and conquer, which keeps only
+//! two rows of the LCS table at a time, so memory stays linear even for
+//! long documents. Lines shared at the start and end are matched up front,
+//! which makes the common case of a few edits in a long draft cheap.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+/// Unchanged lines kept around each change
+pub const CONTEXT_LINES: usize = 3;
+
+/// Whether a line is only in the old text, only in the new, or in both
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffLineKind {
+    Added,
+    Removed,
+    Unchanged,
+}
+
+/// One line of a diff, with its 1-based number in each text it appears in
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub old_line: Option<usize>,
+    pub new_line: Option<usize>,
+    pub text: String,
+}
+
+/// A run of changes with surrounding context
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct DiffHunk {
+    /// First old line covered, 1-based (0 when the old text is empty)
+    pub old_start: usize,
+    pub old_lines: usize,
+    /// First new line covered, 1-based (0 when the new text is empty)
+    pub new_start: usize,
+    pub new_lines: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+/// Diff of two texts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct LineDiff {
+    pub hunks: Vec<DiffHunk>,
+    pub added: usize,
+    pub removed: usize,
+    pub unchanged: usize,
+}
+
+impl LineDiff {
+    /// Old line numbers that were removed or replaced
+    pub fn removed_lines(&self) -> impl Iterator<Item = usize> + '_ {
+        self.changed(DiffLineKind::Removed).filter_map(|line| line.old_line)
+    }
+
+    /// New line numbers that were added or rewritten
+    pub fn added_lines(&self) -> impl Iterator<Item = usize> + '_ {
+        self.changed(DiffLineKind::Added).filter_map(|line| line.new_line)
+    }
+
+    fn changed(&self, kind: DiffLineKind) -> impl Iterator<Item = &DiffLine> + '_ {
+        self.hunks
+            .iter()
+            .flat_map(|hunk| hunk.lines.iter())
+            .filter(move |line| line.kind == kind)
+    }
+}
+
+/// Diff `old` against `new` line by line
+pub fn diff_lines(old: &str, new: &str) -> LineDiff {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+
+    // Compare small integers rather than strings in the quadratic part
+    let mut ids: HashMap<&str, usize> = HashMap::new();
+    let [ia, ib] = [&a, &b].map(|lines| {
+        lines
+            .iter()
+            .map(|&line| {
+                let next = ids.len();
+                *ids.entry(line).or_insert(next)
+            })
+            .collect::<Vec<usize>>()
+    });
+
+    let mut matches = Vec::new();
+    let prefix = ia.iter().zip(&ib).take_while(|(x, y)| x == y).count();
+    let suffix = ia[prefix..]
+        .iter()
+        .rev()
+        .zip(ib[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    matches.extend((0..prefix).map(|i| (i, i)));
+    lcs(
+        &ia[prefix..ia.len() - suffix],
+        &ib[prefix..ib.len() - suffix],
+        prefix,
+        prefix,
+        &mut matches,
+    );
+    matches.extend((0..suffix).map(|i| (a.len() - suffix + i, b.len() - suffix + i)));
+
+    let lines = script(&a, &b, &matches);
+    let count = |kind| lines.iter().filter(|line| line.kind == kind).count();
+    LineDiff {
+        added: count(DiffLineKind::Added),
+        removed: count(DiffLineKind::Removed),
+        unchanged: count(DiffLineKind::Unchanged),
+        hunks: hunks(lines, CONTEXT_LINES),
+    }
+}
+
+/// Last row of the LCS length table of `a` against each prefix of `b`
+fn lcs_row<'a>(a: impl Iterator<Item = &'a usize>, b: &[usize]) -> Vec<usize> {
+    let mut row = vec![0; b.len() + 1];
+    for x in a {
+        let mut diagonal = 0;
+        for (j, y) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if x == y { diagonal + 1 } else { above.max(row[j]) };
+            diagonal = above;
+        }
+    }
+    row
+}
+
+/// Push matched index pairs of an LCS of `a` and `b`, offset by `ai`/`bi`, in order
+fn lcs(a: &[usize], b: &[usize], ai: usize, bi: usize, matches: &mut Vec<(usize, usize)>) {
+    if a.is_empty() || b.is_empty() {
+        return;
+    }
+    if a.len() == 1 {
+        if let Some(j) = b.iter().position(|y| *y == a[0]) {
+            matches.push((ai, bi + j));
+        }
+        return;
+    }
+
+    let mid = a.len() / 2;
+    let forward = lcs_row(a[..mid].iter(), b);
+    let reversed_b: Vec<usize> = b.iter().rev().copied().collect();
+    let backward = lcs_row(a[mid..].iter().rev(), &reversed_b);
+    let split = (0..=b.len())
+        .max_by_key(|&j| (forward[j] + backward[b.len() - j], std::cmp::Reverse(j)))
+        .unwrap_or(0);
+
+    lcs(&a[..mid], &b[..split], ai, bi, matches);
+    lcs(&a[mid..], &b[split..], ai + mid, bi + split, matches);
+}
+
+/// Every line of both texts in order, given the matched pairs
+fn script(a: &[&str], b: &[&str], matches: &[(usize, usize)]) -> Vec<DiffLine> {
+    let mut lines = Vec::with_capacity(a.len().max(b.len()));
+    let (mut i, mut j) = (0, 0);
+    let end = (a.len(), b.len());
+    for &(mi, mj) in matches.iter().chain(std::iter::once(&end)) {
+        lines.extend((i..mi).map(|i| DiffLine {
+            kind: DiffLineKind::Removed,
+            old_line: Some(i + 1),
+            new_line: None,
+            text: a[i].to_string(),
+        }));
+        lines.extend((j..mj).map(|j| DiffLine {
+            kind: DiffLineKind::Added,
+            old_line: None,
+            new_line: Some(j + 1),
+            text: b[j].to_string(),
+        }));
+        if (mi, mj) != end {
+            lines.push(DiffLine {
+                kind: DiffLineKind::Unchanged,
+                old_line: Some(mi + 1),
+                new_line: Some(mj + 1),
+                text: a[mi].to_string(),
+            });
+        }
+        (i, j) = (mi + 1, mj + 1);
+    }
+    lines
+}
+
+/// Group changes, with up to `context` unchanged lines either side, into hunks
+fn hunks(lines: Vec<DiffLine>, context: usize) -> Vec<DiffHunk> {
+    let changed: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.kind != DiffLineKind::Unchanged)
+        .map(|(i, _)| i)
+        .collect();
+
+    // Ranges of `lines` to show; changes closer than twice the context merge
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for &i in &changed {
+        let start = i.saturating_sub(context);
+        let end = (i + context + 1).min(lines.len());
+        match ranges.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    // Line numbers where each range starts, counted over the lines before it
+    let mut hunks = Vec::with_capacity(ranges.len());
+    let (mut old_seen, mut new_seen, mut cursor) = (0, 0, 0);
+    for (start, end) in ranges {
+        for line in &lines[cursor..start] {
+            old_seen += usize::from(line.old_line.is_some());
+            new_seen += usize::from(line.new_line.is_some());
+        }
+        let slice = &lines[start..end];
+        let old_lines = slice.iter().filter(|line| line.old_line.is_some()).count();
+        let new_lines = slice.iter().filter(|line| line.new_line.is_some()).count();
+        hunks.push(DiffHunk {
+            old_start: if old_lines == 0 { old_seen } else { old_seen + 1 },
+            old_lines,
+            new_start: if new_lines == 0 { new_seen } else { new_seen + 1 },
+            new_lines,
+            lines: slice.to_vec(),
+        });
+        old_seen += old_lines;
+        new_seen += new_lines;
+        cursor = end;
+    }
+    hunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(diff: &LineDiff) -> Vec<String> {
+        diff.hunks
+            .iter()
+            .flat_map(|hunk| hunk.lines.iter())
+            .map(|line| {
+                let marker = match line.kind {
+                    DiffLineKind::Added => '+',
+                    DiffLineKind::Removed => '-',
+                    DiffLineKind::Unchanged => ' ',
+                };
+                format!("{}{}", marker, line.text)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_texts_have_no_hunks() {
+        let diff = diff_lines("a\nb\nc", "a\nb\nc");
+        assert!(diff.hunks.is_empty());
+        assert_eq!((diff.added, diff.removed, diff.unchanged), (0, 0, 3));
+    }
+
+    #[test]
+    fn test_empty_texts() {
+        let diff = diff_lines("", "");
+        assert!(diff.hunks.is_empty());
+        assert_eq!((diff.added, diff.removed, diff.unchanged), (0, 0, 0));
+
+        let diff = diff_lines("", "one\ntwo");
+        assert_eq!(render(&diff), vec!["+one", "+two"]);
+        assert_eq!((diff.hunks[0].old_start, diff.hunks[0].old_lines), (0, 0));
+        assert_eq!((diff.hunks[0].new_start, diff.hunks[0].new_lines), (1, 2));
+
+        let diff = diff_lines("one\ntwo", "");
+        assert_eq!(render(&diff), vec!["-one", "-two"]);
+        assert_eq!(diff.removed, 2);
+    }
+
+    #[test]
+    fn test_replaced_line() {
+        let diff = diff_lines("a\nb\nc", "a\nB\nc");
+        assert_eq!(render(&diff), vec![" a", "-b", "+B", " c"]);
+        assert_eq!(diff.removed_lines().collect::<Vec<_>>(), vec![2]);
+        assert_eq!(diff.added_lines().collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[test]
+    fn test_classic_lcs_example() {
+        // LCS of ABCBDAB and BDCABA has length 4
+        let old = "A\nB\nC\nB\nD\nA\nB";
+        let new = "B\nD\nC\nA\nB\nA";
+        let diff = diff_lines(old, new);
+        assert_eq!(diff.unchanged, 4);
+        assert_eq!(diff.removed, 3);
+        assert_eq!(diff.added, 2);
+
+        // Applying the script to the old text yields the new one
+        let rebuilt: Vec<&str> = diff
+            .hunks
+            .iter()
+            .flat_map(|hunk| hunk.lines.iter())
+            .filter(|line| line.kind != DiffLineKind::Removed)
+            .map(|line| line.text.as_str())
+            .collect();
+        assert_eq!(rebuilt.join("\n"), new);
+    }
+
+    #[test]
+    fn test_distant_changes_form_separate_hunks() {
+        let old: Vec<String> = (1..=20).map(|i| format!("line {}", i)).collect();
+        let mut new = old.clone();
+        new[1] = "line two".to_string();
+        new[17] = "line eighteen".to_string();
+
+        let diff = diff_lines(&old.join("\n"), &new.join("\n"));
+        assert_eq!(diff.hunks.len(), 2);
+        let first = &diff.hunks[0];
+        assert_eq!(
+            (first.old_start, first.old_lines, first.new_start, first.new_lines),
+            (1, 5, 1, 5)
+        );
+        let second = &diff.hunks[1];
+        assert_eq!((second.old_start, second.old_lines), (15, 6));
+        assert_eq!(second.lines.first().unwrap().text, "line 15");
+        assert_eq!(second.lines.last().unwrap().text, "line 20");
+    }
+
+    #[test]
+    fn test_large_document_with_scattered_edits() {
+        let old: Vec<String> = (0..5000).map(|i| format!("Paragraph {} of the opinion.", i)).collect();
+        let mut new = old.clone();
+        for i in (250..5000).step_by(500) {
+            new[i] = format!("Revised paragraph {}.", i);
+        }
+        new.insert(1200, "An inserted footnote.".to_string());
+        new.remove(4000);
+
+        let diff = diff_lines(&old.join("\n"), &new.join("\n"));
+        assert_eq!(diff.added, 11);
+        assert_eq!(diff.removed, 11);
+        assert_eq!(diff.unchanged, 4989);
+        // Ten rewrites plus the insertion and the deletion, all far apart
+        assert_eq!(diff.hunks.len(), 12);
+    }
+
+    #[test]
+    fn test_large_unrelated_documents() {
+        let old: Vec<String> = (0..5000).map(|i| format!("old {}", i)).collect();
+        let new: Vec<String> = (0..5000)
+            .map(|i| {
+                if i % 2 == 0 {
+                    format!("old {}", i)
+                } else {
+                    format!("new {}", i)
+                }
+            })
+            .collect();
+
+        let diff = diff_lines(&old.join("\n"), &new.join("\n"));
+        assert_eq!(diff.unchanged, 2500);
+        assert_eq!(diff.added, 2500);
+        assert_eq!(diff.removed, 2500);
+    }
+}
@@ -0,0 +1,177 @@
+//! PNG and JPEG header parsing
+//!
+//! Reads an image's format from its magic bytes and its dimensions from the
+//! PNG `IHDR` chunk or the JPEG start-of-frame segment, without decoding
+//! any pixels. Anything that isn't a PNG or JPEG, or whose header is cut
+//! short, is reported as such rather than guessed at.
+
+/// Image formats accepted for signatures
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+}
+
+impl ImageFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpeg",
+        }
+    }
+}
+
+/// Format and pixel size read from an image header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageHeader {
+    pub format: ImageFormat,
+    pub width: u32,
+    pub height: u32,
+}
+
+const PNG_MAGIC: &[u8] = &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+const JPEG_MAGIC: &[u8] = &[0xFF, 0xD8, 0xFF];
+
+/// Format named by the leading magic bytes
+pub fn sniff(bytes: &[u8]) -> Option<ImageFormat> {
+    if bytes.starts_with(PNG_MAGIC) {
+        Some(ImageFormat::Png)
+    } else if bytes.starts_with(JPEG_MAGIC) {
+        Some(ImageFormat::Jpeg)
+    } else {
+        None
+    }
+}
+
+/// Format and dimensions, or `None` when the magic bytes match neither
+/// format or the header they start is truncated or malformed
+pub fn read_header(bytes: &[u8]) -> Option<ImageHeader> {
+    let format = sniff(bytes)?;
+    let (width, height) = match format {
+        ImageFormat::Png => png_dimensions(bytes)?,
+        ImageFormat::Jpeg => jpeg_dimensions(bytes)?,
+    };
+    Some(ImageHeader { format, width, height })
+}
+
+/// The first chunk of a PNG is always `IHDR`, holding width then height
+fn png_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    if bytes.get(12..16)? != b"IHDR" {
+        return None;
+    }
+    Some((be_u32(bytes.get(16..20)?), be_u32(bytes.get(20..24)?)))
+}
+
+/// Walk the JPEG segments up to the first start-of-frame marker
+fn jpeg_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let mut pos = 2;
+    loop {
+        if *bytes.get(pos)? != 0xFF {
+            return None;
+        }
+        // Markers may be preceded by any number of 0xFF fill bytes
+        while *bytes.get(pos)? == 0xFF {
+            pos += 1;
+        }
+        let marker = *bytes.get(pos)?;
+        pos += 1;
+
+        match marker {
+            // Markers without a length
+            0x01 | 0xD0..=0xD7 => continue,
+            // End of image or start of scan before any frame header
+            0xD9 | 0xDA => return None,
+            _ => {}
+        }
+
+        let length = usize::from(be_u16(bytes.get(pos..pos + 2)?));
+        if length < 2 {
+            return None;
+        }
+        if is_start_of_frame(marker) {
+            // Length, sample precision, then height and width
+            let height = be_u16(bytes.get(pos + 3..pos + 5)?);
+            let width = be_u16(bytes.get(pos + 5..pos + 7)?);
+            return Some((u32::from(width), u32::from(height)));
+        }
+        pos += length;
+    }
+}
+
+/// SOF0 to SOF15, less DHT (C4), JPG (C8) and DAC (CC)
+fn is_start_of_frame(marker: u8) -> bool {
+    matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC)
+}
+
+fn be_u16(bytes: &[u8]) -> u16 {
+    u16::from_be_bytes([bytes[0], bytes[1]])
+}
+
+fn be_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = PNG_MAGIC.to_vec();
+        bytes.extend_from_slice(&13u32.to_be_bytes());
+        bytes.extend_from_slice(b"IHDR");
+        bytes.extend_from_slice(&width.to_be_bytes());
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+        bytes
+    }
+
+    fn jpeg(width: u16, height: u16) -> Vec<u8> {
+        let mut bytes = vec![0xFF, 0xD8];
+        // APP0 (JFIF) segment
+        bytes.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x10]);
+        bytes.extend_from_slice(b"JFIF\0\x01\x01\x00\x00\x01\x00\x01\x00\x00");
+        // DHT segment, which shares the C_ range with frame markers
+        bytes.extend_from_slice(&[0xFF, 0xC4, 0x00, 0x04, 0x00, 0x00]);
+        // Fill bytes, then SOF2 (progressive)
+        bytes.extend_from_slice(&[0xFF, 0xFF, 0xC2, 0x00, 0x11, 0x08]);
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes.extend_from_slice(&width.to_be_bytes());
+        bytes.extend_from_slice(&[0x03, 0x01, 0x22, 0x00, 0x02, 0x11, 0x01, 0x03, 0x11, 0x01]);
+        bytes
+    }
+
+    #[test]
+    fn test_png_header() {
+        let header = read_header(&png(640, 200)).unwrap();
+        assert_eq!(header, ImageHeader { format: ImageFormat::Png, width: 640, height: 200 });
+        assert_eq!(header.format.as_str(), "png");
+    }
+
+    #[test]
+    fn test_jpeg_header() {
+        let header = read_header(&jpeg(300, 120)).unwrap();
+        assert_eq!(header, ImageHeader { format: ImageFormat::Jpeg, width: 300, height: 120 });
+    }
+
+    #[test]
+    fn test_truncated_headers() {
+        let png = png(640, 200);
+        assert_eq!(sniff(&png[..10]), Some(ImageFormat::Png));
+        assert!(read_header(&png[..20]).is_none());
+
+        let jpeg = jpeg(300, 120);
+        assert_eq!(sniff(&jpeg[..4]), Some(ImageFormat::Jpeg));
+        assert!(read_header(&jpeg[..jpeg.len() - 12]).is_none());
+
+        // Start of scan with no frame header
+        assert!(read_header(&[0xFF, 0xD8, 0xFF, 0xDA, 0x00, 0x02]).is_none());
+    }
+
+    #[test]
+    fn test_other_formats() {
+        assert_eq!(sniff(b"GIF89a\x01\x00\x01\x00"), None);
+        assert_eq!(sniff(b"%PDF-1.7"), None);
+        assert_eq!(sniff(b""), None);
+        assert!(read_header(b"BM\x00\x00").is_none());
+    }
+}
@@ -1,7 +1,52 @@
 //! JSON response helpers for HTTP handlers
 
 use crate::error::ApiError;
+use serde::ser::{Serialize, SerializeMap, Serializer};
 use spin_sdk::http::Response;
+use utoipa::ToSchema;
+
+/// One page of a list endpoint's results
+///
+/// Serializes as `{items, total, offset, limit}`. Endpoints that returned
+/// their own list field before the envelope (`entries`, `judges`, ...) also
+/// repeat the items under that name until clients have moved to `items`.
+#[derive(Debug, Clone, ToSchema)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Matches across all pages
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+    /// Deprecated name the items are also written under
+    #[schema(ignore)]
+    legacy_field: Option<&'static str>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, total: usize, offset: usize, limit: usize) -> Self {
+        Self { items, total, offset, limit, legacy_field: None }
+    }
+
+    /// Also write the items under the endpoint's deprecated list field
+    pub fn with_legacy_field(mut self, name: &'static str) -> Self {
+        self.legacy_field = Some(name);
+        self
+    }
+}
+
+impl<T: Serialize> Serialize for Page<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(if self.legacy_field.is_some() { 5 } else { 4 }))?;
+        map.serialize_entry("items", &self.items)?;
+        map.serialize_entry("total", &self.total)?;
+        map.serialize_entry("offset", &self.offset)?;
+        map.serialize_entry("limit", &self.limit)?;
+        if let Some(name) = self.legacy_field {
+            map.serialize_entry(name, &self.items)?;
+        }
+        map.end()
+    }
+}
 
 /// Create a success JSON response
 pub fn success_response<T: serde::Serialize>(data: &T) -> Response {
@@ -12,30 +57,34 @@ pub fn success_response<T: serde::Serialize>(data: &T) -> Response {
         .build()
 }
 
-/// Create an error JSON response
+/// Create an error JSON response in the standard error envelope
 pub fn error_response(error: &ApiError) -> Response {
-    let status = match error {
-        ApiError::NotFound(_) => 404,
-        ApiError::BadRequest(_) => 400,
-        ApiError::ValidationError(_) => 400,
-        ApiError::InvalidInput(_) => 400,
-        ApiError::SerializationError(_) => 400,
-        ApiError::Forbidden(_) => 403,
-        ApiError::Conflict(_) => 409,
-        ApiError::StorageError(_) => 500,
-        ApiError::Internal(_) => 500,
-        ApiError::InternalServerError(_) => 500,
-    };
-
-    Response::builder()
-        .status(status)
-        .header("content-type", "application/json")
-        .body(serde_json::to_vec(error).unwrap())
-        .build()
+    error.to_response()
 }
 
 /// Parse request body as JSON
 pub fn parse_body<T: for<'de> serde::Deserialize<'de>>(body: &[u8]) -> Result<T, ApiError> {
     serde_json::from_slice(body)
         .map_err(|e| ApiError::BadRequest(format!("Invalid JSON: {}", e)))
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_page_envelope_shape() {
+        let page = Page::new(vec![1, 2], 7, 2, 2);
+        assert_eq!(serde_json::to_value(&page).unwrap(), json!({"items": [1, 2], "total": 7, "offset": 2, "limit": 2}));
+    }
+
+    #[test]
+    fn test_page_repeats_items_under_legacy_field() {
+        let page = Page::new(vec!["a"], 1, 0, 50).with_legacy_field("entries");
+        assert_eq!(
+            serde_json::to_value(&page).unwrap(),
+            json!({"items": ["a"], "total": 1, "offset": 0, "limit": 50, "entries": ["a"]})
+        );
+    }
+}
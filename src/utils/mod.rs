@@ -1,7 +1,13 @@
 //! Utility functions for the application
 
+pub mod access;
+pub mod diff;
+pub mod image_header;
 pub mod json_response;
+pub mod name_match;
 pub mod query_parser;
+pub mod rate_limit;
 pub mod repository_factory;
+pub mod request_id;
 pub mod tenant;
 pub mod url_tenant;
\ No newline at end of file
@@ -0,0 +1,268 @@
+//! Party name matching for conflict checks
+//!
+//! Names are compared after normalization: case-folded, punctuation
+//! stripped, `&` spelled out and trailing corporate suffixes (Corp, Inc,
+//! LLC, Co, Ltd and their long forms) dropped, so "Acme Corp." and "ACME
+//! Corporation" are the same party. Names that still differ are scored
+//! with Jaro-Winkler similarity and match at or above a threshold.
+//!
+//! Dropping suffixes means "Acme Inc" and "Acme LLC" match too. For a
+//! conflict check a false hit a person can dismiss beats a missed one, so
+//! every match says how it was made and how confident it is.
+
+use crate::error::ApiError;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Similarity a fuzzy match needs unless the caller asks for another
+pub const DEFAULT_THRESHOLD: f64 = 0.9;
+
+/// Words dropped from the end of a name, after punctuation is stripped
+const CORPORATE_SUFFIXES: &[&str] = &[
+    "corp", "corporation", "inc", "incorporated", "llc", "co", "company", "ltd", "limited", "lp", "llp", "plc",
+];
+
+/// How a name matched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchKind {
+    /// Identical apart from case and spacing
+    Exact,
+    /// Identical once punctuation and corporate suffixes are dropped
+    Normalized,
+    /// Similar enough to be the same party, e.g. a typo
+    Fuzzy,
+}
+
+/// A name that matched, for a person to review
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct NameMatch {
+    /// The name that was checked
+    pub query: String,
+    /// The recorded name it matched
+    pub matched: String,
+    pub kind: MatchKind,
+    /// 1.0 for exact and normalized matches, the similarity for fuzzy ones
+    pub confidence: f64,
+}
+
+/// Normalized form of a name used for comparison
+pub fn normalize(name: &str) -> String {
+    let mut cleaned = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '.' | '\'' | '’' => {}
+            '&' => cleaned.push_str(" and "),
+            c if c.is_alphanumeric() => cleaned.extend(c.to_lowercase()),
+            _ => cleaned.push(' '),
+        }
+    }
+
+    let mut words: Vec<&str> = cleaned.split_whitespace().collect();
+    while words.len() > 1 && words.last().is_some_and(|w| CORPORATE_SUFFIXES.contains(w)) {
+        words.pop();
+    }
+    words.join(" ")
+}
+
+/// Match a name against a recorded one, or `None` below `threshold`
+pub fn match_name(query: &str, candidate: &str, threshold: f64) -> Option<NameMatch> {
+    let found = |kind, confidence| {
+        Some(NameMatch { query: query.to_string(), matched: candidate.to_string(), kind, confidence })
+    };
+
+    if fold(query) == fold(candidate) {
+        return found(MatchKind::Exact, 1.0);
+    }
+
+    let (a, b) = (normalize(query), normalize(candidate));
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    if a == b {
+        return found(MatchKind::Normalized, 1.0);
+    }
+
+    let score = jaro_winkler(&a, &b);
+    if score >= threshold {
+        found(MatchKind::Fuzzy, score)
+    } else {
+        None
+    }
+}
+
+/// The most confident match of any of `queries` against a recorded name
+pub fn best_match<'a>(
+    queries: impl IntoIterator<Item = &'a str>,
+    candidate: &str,
+    threshold: f64,
+) -> Option<NameMatch> {
+    queries
+        .into_iter()
+        .filter_map(|query| match_name(query, candidate, threshold))
+        .fold(None, |best: Option<NameMatch>, m| match best {
+            Some(b) if rank(&b) >= rank(&m) => Some(b),
+            _ => Some(m),
+        })
+}
+
+/// Read the `threshold` query parameter, a similarity from 0 to 1
+pub fn threshold_param(params: &[(&str, &str)]) -> Result<f64, ApiError> {
+    match params.iter().find(|(k, _)| *k == "threshold") {
+        None => Ok(DEFAULT_THRESHOLD),
+        Some((_, value)) => match value.parse::<f64>() {
+            Ok(t) if (0.0..=1.0).contains(&t) => Ok(t),
+            _ => Err(ApiError::BadRequest(format!(
+                "threshold must be a number between 0 and 1, got '{}'",
+                value
+            ))),
+        },
+    }
+}
+
+fn fold(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Orders matches by confidence, then exact over normalized over fuzzy
+fn rank(m: &NameMatch) -> (f64, u8) {
+    let kind = match m.kind {
+        MatchKind::Exact => 2,
+        MatchKind::Normalized => 1,
+        MatchKind::Fuzzy => 0,
+    };
+    (m.confidence, kind)
+}
+
+fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let jaro = jaro(a, b);
+    let prefix = a.chars().zip(b.chars()).take(4).take_while(|(x, y)| x == y).count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn jaro(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::new();
+
+    for (i, &c) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        if let Some(j) = (start..end).find(|&j| !b_matched[j] && b[j] == c) {
+            b_matched[j] = true;
+            a_matches.push(c);
+        }
+    }
+
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+
+    let b_matches = b.iter().zip(&b_matched).filter(|(_, &m)| m).map(|(&c, _)| c);
+    let transpositions = a_matches.iter().zip(b_matches).filter(|(x, y)| **x != *y).count() / 2;
+
+    let m = a_matches.len() as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn similarity(a: &str, b: &str) -> f64 {
+        jaro_winkler(&normalize(a), &normalize(b))
+    }
+
+    #[test]
+    fn test_normalize_strips_punctuation_and_suffixes() {
+        assert_eq!(normalize("Acme Corp."), "acme");
+        assert_eq!(normalize("ACME  Corporation"), "acme");
+        assert_eq!(normalize("Smith & Wesson, Inc."), "smith and wesson");
+        assert_eq!(normalize("Widgets L.L.C."), "widgets");
+        assert_eq!(normalize("O'Brien Holding Co. Ltd"), "obrien holding");
+        // A name that is only a suffix is kept
+        assert_eq!(normalize("Co."), "co");
+    }
+
+    #[test]
+    fn test_exact_match() {
+        let m = match_name("Acme Corp", " acme  corp ", DEFAULT_THRESHOLD).unwrap();
+        assert_eq!(m.kind, MatchKind::Exact);
+        assert_eq!(m.confidence, 1.0);
+        assert_eq!(m.matched, " acme  corp ");
+    }
+
+    #[test]
+    fn test_suffix_variants_match_as_normalized() {
+        for (query, recorded) in [
+            ("Acme Corp.", "ACME Corporation"),
+            ("Acme, Inc.", "Acme Incorporated"),
+            ("Acme LLC", "Acme"),
+            ("Acme Co", "Acme Company"),
+        ] {
+            let m = match_name(query, recorded, DEFAULT_THRESHOLD).unwrap();
+            assert_eq!(m.kind, MatchKind::Normalized, "{} vs {}", query, recorded);
+            assert_eq!(m.confidence, 1.0);
+        }
+    }
+
+    #[test]
+    fn test_typo_within_threshold_is_fuzzy() {
+        let m = match_name("Globex Industires", "Globex Industries, Inc.", DEFAULT_THRESHOLD).unwrap();
+        assert_eq!(m.kind, MatchKind::Fuzzy);
+        assert!(m.confidence >= DEFAULT_THRESHOLD && m.confidence < 1.0);
+
+        let m = match_name("Jonathon Smith", "Jonathan Smith", DEFAULT_THRESHOLD).unwrap();
+        assert_eq!(m.kind, MatchKind::Fuzzy);
+        assert!(m.confidence > 0.95);
+    }
+
+    #[test]
+    fn test_unrelated_names_fall_below_threshold() {
+        assert!(match_name("Acme Corp", "Globex Corporation", DEFAULT_THRESHOLD).is_none());
+        assert!(match_name("John Smith", "Jane Doe", DEFAULT_THRESHOLD).is_none());
+        assert!(match_name("Acme", "Acme Holdings Group", DEFAULT_THRESHOLD).is_none());
+        assert!(match_name("...", "Acme", 0.0).is_none());
+        assert!(similarity("Initech", "Umbrella") < 0.6);
+    }
+
+    #[test]
+    fn test_threshold_controls_fuzzy_matches() {
+        let score = similarity("Acme Holdings", "Acme Holding Group");
+        assert!(match_name("Acme Holdings", "Acme Holding Group", score).is_some());
+        assert!(match_name("Acme Holdings", "Acme Holding Group", (score + 1.0) / 2.0).is_none());
+    }
+
+    #[test]
+    fn test_jaro_winkler_reference_values() {
+        assert!((jaro_winkler("martha", "marhta") - 0.9611).abs() < 1e-4);
+        assert!((jaro_winkler("dwayne", "duane") - 0.84).abs() < 1e-4);
+        assert!((jaro_winkler("dixon", "dicksonx") - 0.8133).abs() < 1e-4);
+        assert_eq!(jaro_winkler("", ""), 1.0);
+    }
+
+    #[test]
+    fn test_best_match_prefers_highest_confidence() {
+        let m = best_match(["Globex", "Acme Corp.", "Acme Crop"], "ACME Corporation", DEFAULT_THRESHOLD).unwrap();
+        assert_eq!(m.query, "Acme Corp.");
+        assert_eq!(m.kind, MatchKind::Normalized);
+        assert!(best_match(["Globex"], "ACME Corporation", DEFAULT_THRESHOLD).is_none());
+    }
+
+    #[test]
+    fn test_threshold_param() {
+        assert_eq!(threshold_param(&[]).unwrap(), DEFAULT_THRESHOLD);
+        assert_eq!(threshold_param(&[("threshold", "0.8")]).unwrap(), 0.8);
+        assert!(threshold_param(&[("threshold", "1.5")]).is_err());
+        assert!(threshold_param(&[("threshold", "high")]).is_err());
+    }
+}
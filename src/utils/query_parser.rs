@@ -1,6 +1,14 @@
 //! Query string parsing utilities for Spin HTTP handlers
+//!
+//! A key may be repeated (`status=a&status=b`). The single-value getters
+//! (`get_string`, `get_uuid`, `get_usize`, ...) read its first occurrence;
+//! the list getters (`get_string_vec`, `get_uuid_vec`, `get_json_vec`)
+//! collect every occurrence and also split comma lists. `get_string_vec` is
+//! the string list getter: there is no separate `get_string_list`.
 
-use chrono::{DateTime, Utc};
+use crate::domain::common::DateRange;
+use crate::error::ApiError;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use uuid::Uuid;
 
 /// Parse a query string into key-value pairs
@@ -21,11 +29,94 @@ pub fn parse_query_string(query: &str) -> Vec<(&str, &str)> {
         .collect()
 }
 
-/// Get a string value from parsed query parameters
+/// Get a percent-decoded string value from parsed query parameters
+///
+/// A `+` is kept as itself, so RFC3339 offsets such as `+05:00` in the
+/// date parameters read this way survive; free text goes through
+/// [`get_text`], which reads `+` as a space.
 pub fn get_string<'a>(params: &'a [(&'a str, &'a str)], key: &str) -> Option<String> {
     params.iter()
         .find(|(k, _)| *k == key)
-        .map(|(_, v)| (*v).to_string())
+        .map(|(_, v)| percent_decode(v, false))
+}
+
+/// Get every value for a key, from repeated keys (`a=1&a=2`), comma lists
+/// (`a=1,2`) or both, in query string order
+pub fn get_string_vec(params: &[(&str, &str)], key: &str) -> Vec<String> {
+    raw_values(params, key).map(decode).collect()
+}
+
+/// Get every UUID for a key, rejecting the first value that isn't one
+pub fn get_uuid_vec(params: &[(&str, &str)], key: &str) -> Result<Vec<Uuid>, ApiError> {
+    raw_values(params, key)
+        .map(|v| Uuid::parse_str(v).map_err(|_| invalid_value(key, &decode(v))))
+        .collect()
+}
+
+/// Parse every value for a key as a JSON string, rejecting the first that doesn't parse
+pub fn get_json_vec<T: serde::de::DeserializeOwned>(params: &[(&str, &str)], key: &str) -> Result<Vec<T>, ApiError> {
+    raw_values(params, key)
+        .map(|v| parse_enum_param(key, &decode(v)))
+        .collect()
+}
+
+/// Get an enum value by its serialized name, ignoring a value that isn't one
+pub fn get_enum<T: serde::de::DeserializeOwned>(params: &[(&str, &str)], key: &str) -> Option<T> {
+    get_string(params, key).and_then(|v| parse_enum_param(key, &v).ok())
+}
+
+/// Parse a path or query string value as an enum by its serialized name
+pub fn parse_enum_param<T: serde::de::DeserializeOwned>(key: &str, value: &str) -> Result<T, ApiError> {
+    serde_json::from_value(serde_json::Value::String(value.to_string()))
+        .map_err(|_| invalid_value(key, value))
+}
+
+/// Split the raw values for a key on commas before decoding, so `%2C` stays in the value
+fn raw_values<'a>(params: &'a [(&'a str, &'a str)], key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    params.iter()
+        .filter(move |(k, _)| *k == key)
+        .flat_map(|(_, v)| v.split(','))
+        .filter(|v| !v.is_empty())
+}
+
+fn invalid_value(key: &str, value: &str) -> ApiError {
+    ApiError::BadRequest(format!("Invalid `{}` value '{}'", key, value))
+}
+
+/// Get a percent-decoded text value (with `+` as space) from parsed query parameters
+pub fn get_text(params: &[(&str, &str)], key: &str) -> Option<String> {
+    params.iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| decode(v))
+}
+
+/// Percent-decode a query string component, treating `+` as a space
+pub fn decode(value: &str) -> String {
+    percent_decode(value, true)
+}
+
+fn percent_decode(value: &str, plus_as_space: bool) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' if plus_as_space => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len()
+                && bytes[i + 1].is_ascii_hexdigit()
+                && bytes[i + 2].is_ascii_hexdigit() =>
+            {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("00");
+                decoded.push(u8::from_str_radix(hex, 16).unwrap_or(0));
+                i += 2;
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
 }
 
 /// Get a UUID value from parsed query parameters
@@ -71,10 +162,257 @@ pub fn get_datetime(params: &[(&str, &str)], key: &str) -> Option<DateTime<Utc>>
 
 /// Parse a JSON value from query parameters
 pub fn get_json<T: serde::de::DeserializeOwned>(params: &[(&str, &str)], key: &str) -> Option<T> {
-    params.iter()
-        .find(|(k, _)| *k == key)
-        .and_then(|(_, v)| {
-            // Try to parse as quoted JSON string first
-            serde_json::from_str(&format!("\"{}\"", v)).ok()
+    get_enum(params, key)
+}
+
+/// Get the `from`/`to` statistics range from parsed query parameters
+///
+/// Each bound is RFC3339 or a plain `YYYY-MM-DD` date; a plain `from` starts
+/// at the beginning of its day and a plain `to` runs to the end of its day.
+/// `to` defaults to now and `from` to twelve months before `to`. A
+/// `fiscal_year` selects the federal fiscal year instead and can't be
+/// combined with `from` or `to`.
+pub fn get_date_range(params: &[(&str, &str)]) -> Result<DateRange, ApiError> {
+    get_date_range_at(params, Utc::now())
+}
+
+/// [`get_date_range`] with the current time supplied
+pub fn get_date_range_at(params: &[(&str, &str)], now: DateTime<Utc>) -> Result<DateRange, ApiError> {
+    if let Some((_, year)) = params.iter().find(|(k, _)| *k == "fiscal_year") {
+        if params.iter().any(|(k, _)| *k == "from" || *k == "to") {
+            return Err(ApiError::BadRequest("`fiscal_year` can't be combined with `from` or `to`".to_string()));
+        }
+        return year
+            .parse()
+            .ok()
+            .filter(|year| (1000..=9999).contains(year))
+            .and_then(DateRange::fiscal_year)
+            .ok_or_else(|| ApiError::BadRequest(format!("Invalid `fiscal_year` '{}'; expected a four-digit year", year)));
+    }
+
+    let from = date_bound(params, "from", NaiveTime::MIN)?;
+    let end_of_day = NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap_or(NaiveTime::MIN);
+    let to = date_bound(params, "to", end_of_day)?.unwrap_or(now);
+
+    match from {
+        Some(from) => DateRange::new(from, to).map_err(ApiError::BadRequest),
+        None => Ok(DateRange::ending_at(to)),
+    }
+}
+
+/// Optional `from` and `to` of a query
+pub type DateBounds = (Option<DateTime<Utc>>, Option<DateTime<Utc>>);
+
+/// Get optional `from`/`to` bounds from parsed query parameters
+///
+/// Bounds are parsed as in [`get_date_range`], but neither has a default
+/// and the span isn't capped. A `from` after `to` is rejected.
+pub fn get_date_bounds(params: &[(&str, &str)]) -> Result<DateBounds, ApiError> {
+    let from = date_bound(params, "from", NaiveTime::MIN)?;
+    let end_of_day = NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap_or(NaiveTime::MIN);
+    let to = date_bound(params, "to", end_of_day)?;
+    if let (Some(from), Some(to)) = (from, to) {
+        if from > to {
+            return Err(ApiError::BadRequest("`from` must not be after `to`".to_string()));
+        }
+    }
+    Ok((from, to))
+}
+
+/// Parse one range bound, placing a plain date at `time_of_day`
+fn date_bound(params: &[(&str, &str)], key: &str, time_of_day: NaiveTime) -> Result<Option<DateTime<Utc>>, ApiError> {
+    let Some(raw) = params.iter().find(|(k, _)| *k == key).map(|(_, v)| *v) else {
+        return Ok(None);
+    };
+    // Try the raw value first: decoding would turn an unescaped `+` offset into a space
+    let decoded = decode(raw);
+    [raw, decoded.as_str()]
+        .iter()
+        .find_map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|d| d.with_timezone(&Utc))
+                .ok()
+                .or_else(|| {
+                    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                        .ok()
+                        .map(|date| date.and_time(time_of_day).and_utc())
+                })
+        })
+        .map(Some)
+        .ok_or_else(|| {
+            ApiError::BadRequest(format!(
+                "Invalid `{}` date '{}'; expected RFC3339 or YYYY-MM-DD",
+                key, decoded
+            ))
         })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 6, 15, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_repeated_keys_collect_into_a_list() {
+        let query = parse_query_string("status=filed&status=dismissed&status=arraigned");
+        assert_eq!(get_string_vec(&query, "status"), vec!["filed", "dismissed", "arraigned"]);
+        assert_eq!(get_string(&query, "status").as_deref(), Some("filed"));
+        assert!(get_string_vec(&query, "priority").is_empty());
+    }
+
+    #[test]
+    fn test_single_value_getters_read_the_first_occurrence() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let raw = format!("id={}&id={}&limit=5&limit=9&active=true&active=false&q=first+term&q=second", a, b);
+        let query = parse_query_string(&raw);
+        assert_eq!(get_uuid(&query, "id"), Some(a));
+        assert_eq!(get_usize(&query, "limit"), Some(5));
+        assert_eq!(get_bool(&query, "active"), Some(true));
+        assert_eq!(get_text(&query, "q").as_deref(), Some("first term"));
+        assert_eq!(get_uuid_vec(&query, "id").unwrap(), vec![a, b]);
+    }
+
+    #[test]
+    fn test_mixed_keys_keep_their_own_values() {
+        let query = parse_query_string("status=filed&priority=high&status=&status=dismissed&limit=5");
+        assert_eq!(get_string_vec(&query, "status"), vec!["filed", "dismissed"]);
+        assert_eq!(get_string_vec(&query, "priority"), vec!["high"]);
+        assert_eq!(get_usize(&query, "limit"), Some(5));
+    }
+
+    #[test]
+    fn test_comma_lists_combine_with_repeated_keys() {
+        let query = parse_query_string("status=pending,overdue&status=completed&tag=a%2Cb,,c");
+        assert_eq!(get_string_vec(&query, "status"), vec!["pending", "overdue", "completed"]);
+        assert_eq!(get_string_vec(&query, "tag"), vec!["a,b", "c"]);
+    }
+
+    #[test]
+    fn test_uuid_and_json_vecs_reject_bad_values() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let raw = format!("id={},{}&bad=not-a-uuid", a, b);
+        let query = parse_query_string(&raw);
+        assert_eq!(get_uuid_vec(&query, "id").unwrap(), vec![a, b]);
+        assert!(get_uuid_vec(&query, "missing").unwrap().is_empty());
+        assert!(matches!(get_uuid_vec(&query, "bad"), Err(ApiError::BadRequest(_))));
+
+        let query = parse_query_string("status=filed,dismissed&level=high%20priority");
+        let statuses: Vec<String> = get_json_vec(&query, "status").unwrap();
+        assert_eq!(statuses, vec!["filed", "dismissed"]);
+        assert!(matches!(get_json_vec::<bool>(&query, "status"), Err(ApiError::BadRequest(_))));
+        assert_eq!(get_json::<String>(&query, "level").as_deref(), Some("high priority"));
+    }
+
+    #[test]
+    fn test_enum_values_parse_by_serialized_name() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        enum Status {
+            Active,
+            OnLeave,
+        }
+
+        let query = parse_query_string("status=on_leave&status=active&other=retired");
+        assert_eq!(get_enum(&query, "status"), Some(Status::OnLeave));
+        assert_eq!(get_enum::<Status>(&query, "other"), None);
+        assert_eq!(get_enum::<Status>(&query, "missing"), None);
+
+        assert_eq!(parse_enum_param::<Status>("status", "active").unwrap(), Status::Active);
+        match parse_enum_param::<Status>("status", "Retired \"early\"") {
+            Err(ApiError::BadRequest(msg)) => assert_eq!(msg, "Invalid `status` value 'Retired \"early\"'"),
+            other => panic!("expected BadRequest, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_values_are_percent_decoded() {
+        let query = parse_query_string("search=motion%20to+dismiss&name=caf%C3%A9&odd=100%&sign=%+1");
+        assert_eq!(get_text(&query, "search").as_deref(), Some("motion to dismiss"));
+        assert_eq!(get_string(&query, "name").as_deref(), Some("café"));
+        assert_eq!(get_string(&query, "odd").as_deref(), Some("100%"));
+        assert_eq!(get_text(&query, "sign").as_deref(), Some("% 1"));
+    }
+
+    #[test]
+    fn test_get_string_keeps_plus_signs() {
+        let query = parse_query_string("start=2024-01-01T00:00:00+05:00&end=2024-12-31T23:59:59%2B05:00&q=a+b");
+        assert_eq!(get_string(&query, "start").as_deref(), Some("2024-01-01T00:00:00+05:00"));
+        assert_eq!(get_string(&query, "end").as_deref(), Some("2024-12-31T23:59:59+05:00"));
+        assert_eq!(get_string(&query, "q").as_deref(), Some("a+b"));
+        assert_eq!(get_text(&query, "q").as_deref(), Some("a b"));
+    }
+
+    #[test]
+    fn test_date_range_defaults_to_last_twelve_months() {
+        let range = get_date_range_at(&[], now()).unwrap();
+        assert_eq!(range.to, now());
+        assert_eq!(range.from, Utc.with_ymd_and_hms(2025, 6, 15, 12, 0, 0).unwrap());
+
+        let range = get_date_range_at(&parse_query_string("to=2026-01-31"), now()).unwrap();
+        assert_eq!(range.from, Utc.with_ymd_and_hms(2025, 1, 31, 23, 59, 59).unwrap() + chrono::Duration::nanoseconds(999_999_999));
+    }
+
+    #[test]
+    fn test_plain_dates_cover_whole_days() {
+        let range = get_date_range_at(&parse_query_string("from=2026-03-01&to=2026-03-31"), now()).unwrap();
+        assert!(range.contains(Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap()));
+        assert!(range.contains(Utc.with_ymd_and_hms(2026, 3, 31, 23, 59, 59).unwrap()));
+        assert!(!range.contains(Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap()));
+        assert!(!range.contains(Utc.with_ymd_and_hms(2026, 2, 28, 23, 59, 59).unwrap()));
+    }
+
+    #[test]
+    fn test_rfc3339_bounds_are_inclusive() {
+        let query = parse_query_string("from=2026-03-01T09:00:00Z&to=2026-03-01T17:00:00%2B00:00");
+        let range = get_date_range_at(&query, now()).unwrap();
+        assert!(range.contains(range.from));
+        assert!(range.contains(range.to));
+        assert_eq!(range.to, Utc.with_ymd_and_hms(2026, 3, 1, 17, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_ranges_are_rejected() {
+        for query in ["from=last-month", "to=2026-13-01", "from=2026-04-01&to=2026-03-01", "from=2010-01-01&to=2026-01-01"] {
+            assert!(
+                matches!(get_date_range_at(&parse_query_string(query), now()), Err(ApiError::BadRequest(_))),
+                "{} should be rejected",
+                query
+            );
+        }
+        assert!(get_date_range_at(&parse_query_string("from=2016-06-15T12:00:00Z"), now()).is_ok());
+    }
+
+    #[test]
+    fn test_fiscal_year_runs_october_to_september() {
+        let range = get_date_range_at(&parse_query_string("fiscal_year=2026"), now()).unwrap();
+        assert_eq!(range.from, Utc.with_ymd_and_hms(2025, 10, 1, 0, 0, 0).unwrap());
+        assert!(range.contains(Utc.with_ymd_and_hms(2026, 9, 30, 23, 59, 59).unwrap()));
+        assert!(!range.contains(Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap()));
+        assert!(!range.contains(Utc.with_ymd_and_hms(2025, 9, 30, 23, 59, 59).unwrap()));
+
+        for query in ["fiscal_year=FY26", "fiscal_year=26", "fiscal_year=2026&from=2025-10-01"] {
+            assert!(
+                matches!(get_date_range_at(&parse_query_string(query), now()), Err(ApiError::BadRequest(_))),
+                "{} should be rejected",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn test_date_bounds_are_optional_and_uncapped() {
+        assert_eq!(get_date_bounds(&parse_query_string("")).unwrap(), (None, None));
+
+        let (from, to) = get_date_bounds(&parse_query_string("from=2000-01-01&to=2026-06-15")).unwrap();
+        assert_eq!(from, Some(Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap()));
+        assert_eq!(to.map(|t| t.date_naive()), Some(Utc.with_ymd_and_hms(2026, 6, 15, 0, 0, 0).unwrap().date_naive()));
+
+        for query in ["from=2026-04-01&to=2026-03-01", "to=yesterday"] {
+            assert!(matches!(get_date_bounds(&parse_query_string(query)), Err(ApiError::BadRequest(_))), "{}", query);
+        }
+    }
+}
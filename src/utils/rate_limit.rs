@@ -0,0 +1,427 @@
+//! Fixed-window rate limiting per tenant
+//!
+//! Every request except the health check counts against its tenant's overall
+//! allowance, which keeps a runaway client from flooding the KV store. PDF
+//! generation, case statistics and compliance reports are expensive enough
+//! to have their own, tighter allowances on top. Each tenant gets one counter
+//! per route group, kept in the tenant's KV store under
+//! `ratelimit:{tenant}:{route}` with the window it counts; a request in a
+//! later window starts it over, so counters do not pile up.
+//!
+//! Limits come from the `rate_limits.{route}` section of `base_defaults.toml`,
+//! which a district may override through the config API, where
+//! `requests_per_minute` is the steady allowance and `burst` is extra headroom
+//! on top of it, so one window admits `requests_per_minute + burst` requests.
+//! The overall allowance is `rate_limits.tenant`.
+//!
+//! The limiter fails open: if the tenant store, configuration or counter cannot
+//! be read or written, the request goes through and the failure is logged.
+
+use crate::adapters::store_utils::{self, TenantStore};
+use crate::domain::config::{Configuration, ConfigOverride};
+use crate::error::ApiError;
+use crate::ports::config_repository::ConfigRepository;
+use crate::utils::{repository_factory::RepositoryFactory, tenant, url_tenant};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use spin_sdk::http::{Request, Response};
+
+/// Length of a rate limit window in seconds
+pub const WINDOW_SECONDS: i64 = 60;
+
+/// Route groups that are rate limited together
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitGroup {
+    /// Every route but the health check
+    Tenant,
+    /// `/api/pdf/*`
+    Pdf,
+    /// `/api/cases/statistics`
+    CaseStatistics,
+    /// `/api/compliance/*`
+    Compliance,
+}
+
+/// Allowance for a route group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub requests_per_minute: u64,
+    pub burst: u64,
+}
+
+impl RateLimit {
+    /// Requests admitted in a single window
+    pub fn capacity(&self) -> u64 {
+        self.requests_per_minute + self.burst
+    }
+}
+
+impl RateLimitGroup {
+    /// Groups a request path counts against: the tenant's overall allowance
+    /// and, for an expensive route, its own
+    pub fn all_for_path(path: &str) -> Vec<Self> {
+        if is_exempt(path) {
+            return Vec::new();
+        }
+        std::iter::once(Self::Tenant).chain(Self::for_path(path)).collect()
+    }
+
+    /// Expensive route group a request path belongs to, for both header- and
+    /// URL-based routes
+    pub fn for_path(path: &str) -> Option<Self> {
+        let path = strip_court_prefix(path);
+        if path.starts_with("/api/pdf/") {
+            Some(Self::Pdf)
+        } else if path == "/api/cases/statistics" {
+            Some(Self::CaseStatistics)
+        } else if path.starts_with("/api/compliance/") {
+            Some(Self::Compliance)
+        } else {
+            None
+        }
+    }
+
+    /// Name used in counter keys and configuration paths
+    pub fn route_key(&self) -> &'static str {
+        match self {
+            Self::Tenant => "tenant",
+            Self::Pdf => "pdf",
+            Self::CaseStatistics => "case_statistics",
+            Self::Compliance => "compliance",
+        }
+    }
+
+    /// Allowance when the district has not configured one, as in `base_defaults.toml`
+    pub fn default_limit(&self) -> RateLimit {
+        match self {
+            Self::Tenant => RateLimit { requests_per_minute: 600, burst: 100 },
+            Self::Pdf => RateLimit { requests_per_minute: 30, burst: 10 },
+            Self::CaseStatistics | Self::Compliance => RateLimit { requests_per_minute: 60, burst: 20 },
+        }
+    }
+}
+
+/// Window a timestamp falls in
+pub fn window_for(unix_seconds: i64) -> i64 {
+    unix_seconds.div_euclid(WINDOW_SECONDS)
+}
+
+/// Seconds until the window containing `unix_seconds` ends
+pub fn seconds_until_reset(unix_seconds: i64) -> i64 {
+    WINDOW_SECONDS - unix_seconds.rem_euclid(WINDOW_SECONDS)
+}
+
+/// KV key counting a tenant's requests to a route group
+pub fn counter_key(tenant_id: &str, group: RateLimitGroup) -> String {
+    format!("ratelimit:{}:{}", tenant_id, group.route_key())
+}
+
+/// Requests counted against a route group in one window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct WindowCount {
+    window: i64,
+    count: u64,
+}
+
+/// Rate limit a request before routing
+///
+/// Returns a `429 Too Many Requests` response with `Retry-After` when the
+/// tenant has used up the window's allowance for any group the request counts
+/// against, and `None` when the request may proceed.
+pub fn enforce(req: &Request) -> Option<Response> {
+    let groups = RateLimitGroup::all_for_path(req.path());
+    if groups.is_empty() {
+        return None;
+    }
+
+    let tenant_id = url_tenant::extract_tenant_from_path(req.path())
+        .unwrap_or_else(|| tenant::get_tenant_id(req));
+    let store_name = tenant::get_store_name(&tenant_id);
+    if !store_utils::is_valid_store_name(&store_name) {
+        // No tenant; the handler reports that itself
+        return None;
+    }
+
+    let store = match store_utils::open_validated_store(&store_name) {
+        Ok(store) => store,
+        Err(e) => {
+            crate::request_log!("rate limit: failing open for {}: {}", tenant_id, e);
+            return None;
+        }
+    };
+
+    let limits = configured_limits(req, &groups);
+    admit(&store, &tenant_id, &limits, Utc::now().timestamp())
+        .err()
+        .map(|e| e.to_response())
+}
+
+/// Count a request against each group's allowance for the window `now` falls in
+///
+/// Nothing is counted when any group is over its allowance. A counter that
+/// can't be read lets the request through.
+fn admit(store: &TenantStore, tenant_id: &str, limits: &[(RateLimitGroup, RateLimit)], now: i64) -> Result<(), ApiError> {
+    let window = window_for(now);
+    let mut counters = Vec::with_capacity(limits.len());
+    for &(group, limit) in limits {
+        let key = counter_key(tenant_id, group);
+        let count = match store.get(&key) {
+            Ok(value) => value
+                .and_then(|bytes| serde_json::from_slice::<WindowCount>(&bytes).ok())
+                .filter(|counted| counted.window == window)
+                .map_or(0, |counted| counted.count),
+            Err(e) => {
+                crate::request_log!("rate limit: failing open, cannot read {}: {}", key, e);
+                return Ok(());
+            }
+        };
+
+        if count >= limit.capacity() {
+            return Err(too_many_requests(group, limit, seconds_until_reset(now)));
+        }
+        counters.push((key, count));
+    }
+
+    for (key, count) in counters {
+        if let Err(e) = store.set_json(&key, &WindowCount { window, count: count + 1 }) {
+            crate::request_log!("rate limit: cannot update {}: {}", key, e);
+        }
+    }
+    Ok(())
+}
+
+/// District allowance for each route group, falling back to the built-in default
+///
+/// Only the district's stored overrides are read, one KV lookup, rather than
+/// the full merged configuration: the built-in defaults are the
+/// `rate_limits` section of `base_defaults.toml`.
+fn configured_limits(req: &Request, groups: &[RateLimitGroup]) -> Vec<(RateLimitGroup, RateLimit)> {
+    let (repo, district_id) = match court_segment(req.path()) {
+        Some(district) => (RepositoryFactory::config_repo_from_url(req).ok(), district.to_string()),
+        None => (RepositoryFactory::config_repo(req).ok(), tenant::get_config_district_id(req)),
+    };
+    let Some(repo) = repo else {
+        return limits_with_overrides(groups, None);
+    };
+
+    match futures::executor::block_on(repo.get_district_overrides(&district_id)) {
+        Ok(overrides) => limits_with_overrides(groups, overrides.as_ref()),
+        Err(e) => {
+            crate::request_log!("rate limit: using default limits, cannot load overrides for {}: {}", district_id, e);
+            limits_with_overrides(groups, None)
+        }
+    }
+}
+
+/// Built-in allowance for each group with any district overrides applied
+fn limits_with_overrides(groups: &[RateLimitGroup], overrides: Option<&ConfigOverride>) -> Vec<(RateLimitGroup, RateLimit)> {
+    let mut config = Configuration::new();
+    if let Some(overrides) = overrides {
+        overrides.apply_to(&mut config);
+    }
+
+    groups
+        .iter()
+        .map(|&group| {
+            let default = group.default_limit();
+            let read = |field: &str| {
+                config
+                    .get_i64(&format!("rate_limits.{}.{}", group.route_key(), field))
+                    .and_then(|v| u64::try_from(v).ok())
+            };
+            let limit = RateLimit {
+                requests_per_minute: read("requests_per_minute").unwrap_or(default.requests_per_minute),
+                burst: read("burst").unwrap_or(default.burst),
+            };
+            (group, limit)
+        })
+        .collect()
+}
+
+fn too_many_requests(group: RateLimitGroup, limit: RateLimit, retry_after: i64) -> ApiError {
+    let scope = match group {
+        RateLimitGroup::Tenant => "this district".to_string(),
+        _ => format!("{} endpoints", group.route_key()),
+    };
+    let message = format!(
+        "Rate limit of {} requests per minute (burst {}) exceeded for {}; retry in {} seconds",
+        limit.requests_per_minute, limit.burst, scope, retry_after
+    );
+    ApiError::RateLimited(message, retry_after.max(1) as u64)
+}
+
+/// Paths that are never rate limited, so monitoring keeps working
+fn is_exempt(path: &str) -> bool {
+    let path = strip_court_prefix(path);
+    path == "/api/health" || path.starts_with("/api/health/")
+}
+
+/// District segment of a `/api/courts/{district}/...` path
+fn court_segment(path: &str) -> Option<&str> {
+    path.strip_prefix("/api/courts/")?.split('/').next().filter(|s| !s.is_empty())
+}
+
+/// `/api/courts/{district}/rest` becomes `/api/rest`
+fn strip_court_prefix(path: &str) -> String {
+    match path.strip_prefix("/api/courts/").and_then(|rest| rest.split_once('/')) {
+        Some((_, rest)) => format!("/api/{}", rest),
+        None => path.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::store_utils::testing::MemoryBackend;
+    use crate::adapters::toml_config_loader::TomlConfigLoader;
+
+    #[test]
+    fn test_groups_match_header_and_url_routes() {
+        assert_eq!(RateLimitGroup::for_path("/api/pdf/batch"), Some(RateLimitGroup::Pdf));
+        assert_eq!(RateLimitGroup::for_path("/api/cases/statistics"), Some(RateLimitGroup::CaseStatistics));
+        assert_eq!(
+            RateLimitGroup::for_path("/api/courts/sdny/cases/statistics"),
+            Some(RateLimitGroup::CaseStatistics)
+        );
+        assert_eq!(
+            RateLimitGroup::for_path("/api/courts/sdny/compliance/report"),
+            Some(RateLimitGroup::Compliance)
+        );
+        assert_eq!(RateLimitGroup::for_path("/api/cases"), None);
+        assert_eq!(RateLimitGroup::for_path("/api/cases/statistics/extra"), None);
+    }
+
+    #[test]
+    fn test_every_route_but_health_counts_against_tenant() {
+        assert_eq!(RateLimitGroup::all_for_path("/api/cases"), vec![RateLimitGroup::Tenant]);
+        assert_eq!(
+            RateLimitGroup::all_for_path("/api/courts/sdny/pdf/batch"),
+            vec![RateLimitGroup::Tenant, RateLimitGroup::Pdf]
+        );
+        assert!(RateLimitGroup::all_for_path("/api/health").is_empty());
+        assert!(RateLimitGroup::all_for_path("/api/courts/sdny/health").is_empty());
+        assert_eq!(RateLimitGroup::all_for_path("/api/healthcare"), vec![RateLimitGroup::Tenant]);
+    }
+
+    #[test]
+    fn test_window_resets_each_minute() {
+        // 59 seconds into a window, one second from reset
+        // 1_800_000_000 falls on a minute boundary
+        let t = 1_800_000_000 + 59;
+        assert_eq!(window_for(t), window_for(t - 59));
+        assert_eq!(seconds_until_reset(t), 1);
+        assert_eq!(window_for(t + 1), window_for(t) + 1);
+        assert_eq!(seconds_until_reset(t + 1), 60);
+    }
+
+    #[test]
+    fn test_counter_keys_are_per_tenant() {
+        assert_ne!(counter_key("sdny", RateLimitGroup::Pdf), counter_key("edny", RateLimitGroup::Pdf));
+        assert_eq!(counter_key("sdny", RateLimitGroup::Pdf), "ratelimit:sdny:pdf");
+    }
+
+    fn counted(store: &TenantStore, group: RateLimitGroup) -> Option<WindowCount> {
+        store.get_json(counter_key("sdny", group)).unwrap()
+    }
+
+    const ALL_GROUPS: [RateLimitGroup; 4] = [
+        RateLimitGroup::Tenant,
+        RateLimitGroup::Pdf,
+        RateLimitGroup::CaseStatistics,
+        RateLimitGroup::Compliance,
+    ];
+
+    #[test]
+    fn test_default_limits_match_base_defaults() {
+        let base = TomlConfigLoader::new().unwrap().load_for_district("district", None).unwrap();
+        for group in ALL_GROUPS {
+            let read = |field: &str| {
+                base.get_i64(&format!("rate_limits.{}.{}", group.route_key(), field))
+                    .and_then(|v| u64::try_from(v).ok())
+            };
+            let expected = RateLimit {
+                requests_per_minute: read("requests_per_minute").unwrap(),
+                burst: read("burst").unwrap(),
+            };
+            assert_eq!(group.default_limit(), expected, "{}", group.route_key());
+        }
+    }
+
+    #[test]
+    fn test_district_overrides_replace_default_limits() {
+        let mut overrides = ConfigOverride::new();
+        overrides.add("rate_limits.pdf.requests_per_minute".to_string(), serde_json::json!(5));
+        overrides.add("rate_limits.compliance".to_string(), serde_json::json!({"burst": 0}));
+        overrides.add("rate_limits.tenant.burst".to_string(), serde_json::json!(-1));
+
+        let limits = limits_with_overrides(&ALL_GROUPS, Some(&overrides));
+        assert_eq!(limits[0], (RateLimitGroup::Tenant, RateLimitGroup::Tenant.default_limit()), "negative values are ignored");
+        assert_eq!(limits[1].1, RateLimit { requests_per_minute: 5, burst: 10 });
+        assert_eq!(limits[2], (RateLimitGroup::CaseStatistics, RateLimitGroup::CaseStatistics.default_limit()));
+        assert_eq!(limits[3].1, RateLimit { requests_per_minute: 60, burst: 0 });
+    }
+
+    fn limits(tenant: u64, pdf: u64) -> Vec<(RateLimitGroup, RateLimit)> {
+        vec![
+            (RateLimitGroup::Tenant, RateLimit { requests_per_minute: tenant, burst: 0 }),
+            (RateLimitGroup::Pdf, RateLimit { requests_per_minute: pdf, burst: 0 }),
+        ]
+    }
+
+    #[test]
+    fn test_requests_over_the_tenant_limit_are_rejected_until_the_window_ends() {
+        let store = TenantStore::with_backend("sdny", MemoryBackend::default());
+        // 45 seconds into a window; 1_800_000_000 falls on a minute boundary
+        let now = 1_800_000_000 + 45;
+        let tenant_only = &limits(3, 10)[..1];
+
+        for _ in 0..3 {
+            assert!(admit(&store, "sdny", tenant_only, now).is_ok());
+        }
+        let rejected = admit(&store, "sdny", tenant_only, now).unwrap_err();
+        assert_eq!(rejected.status(), 429);
+        assert_eq!(rejected.retry_after(), Some(15));
+
+        assert!(admit(&store, "sdny", tenant_only, now + 15).is_ok(), "next window starts over");
+    }
+
+    #[test]
+    fn test_a_new_window_reuses_the_counter_key() {
+        let store = TenantStore::with_backend("sdny", MemoryBackend::default());
+        let now = 1_800_000_000;
+
+        for minute in 0..5 {
+            assert!(admit(&store, "sdny", &limits(5, 5), now + minute * WINDOW_SECONDS).is_ok());
+        }
+
+        let keys = store.get_keys().unwrap();
+        assert_eq!(keys.len(), 2, "one counter per group, not per window: {:?}", keys);
+        assert_eq!(
+            counted(&store, RateLimitGroup::Pdf),
+            Some(WindowCount { window: window_for(now) + 4, count: 1 })
+        );
+    }
+
+    #[test]
+    fn test_rejected_request_counts_against_no_group() {
+        let store = TenantStore::with_backend("sdny", MemoryBackend::default());
+        let now = 1_800_000_000;
+
+        assert!(admit(&store, "sdny", &limits(5, 1), now).is_ok());
+        assert!(admit(&store, "sdny", &limits(5, 1), now).is_err(), "pdf allowance used up");
+
+        assert_eq!(
+            counted(&store, RateLimitGroup::Tenant),
+            Some(WindowCount { window: window_for(now), count: 1 })
+        );
+    }
+
+    #[test]
+    fn test_garbled_counter_starts_over() {
+        let store = TenantStore::with_backend("sdny", MemoryBackend::default());
+        let now = 1_800_000_000;
+        store.set(counter_key("sdny", RateLimitGroup::Tenant), b"not a number").unwrap();
+
+        assert!(admit(&store, "sdny", &limits(1, 1), now).is_ok());
+    }
+}
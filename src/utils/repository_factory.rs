@@ -49,7 +49,13 @@
 //! # Security
 //!
 //! - Tenant IDs are sanitized to prevent injection attacks
-//! - Each tenant's data is completely isolated
+//! - Each tenant's data is completely isolated: every tenant has its own
+//!   store, and every key in it is prefixed with the tenant ID (see
+//!   `adapters::store_utils::TenantStore`)
+//! - A request that names no known district is rejected with a 400 before
+//!   any repository is opened. Codes are trimmed and uppercased, then looked
+//!   up in the district registry (`domain::district`); an unknown code is
+//!   answered with its closest matches rather than opening a new namespace
 //! - Access control can be enforced via check_access()
 
 use crate::adapters::{
@@ -62,8 +68,10 @@ use crate::adapters::{
     spin_kv_judge_repository::SpinKvJudgeRepository,
     spin_kv_rules_repository::SpinKvRulesRepository,
     spin_kv_sentencing_repository::SpinKvSentencingRepository,
+    spin_kv_webhook_repository::SpinKvWebhookRepository,
     unified_config_feature_repository::UnifiedConfigFeatureRepository,
 };
+use crate::domain::district::DistrictCodeError;
 use crate::ports::feature_repository::FeatureRepository;
 use std::sync::Arc;
 use crate::utils::{tenant, url_tenant};
@@ -78,19 +86,48 @@ use crate::error::ApiError;
 pub struct RepositoryFactory;
 
 impl RepositoryFactory {
-    /// Validate that tenant is properly specified
-    fn validate_tenant(store_name: &str) -> Result<(), ApiError> {
-        if store_name == "tenant_not_specified" {
-            return Err(ApiError::BadRequest(
-                "Missing required header: X-Court-District or X-Tenant-ID".to_string()
-            ));
+    /// Store name for the request's tenant, or 400 if it names none
+    ///
+    /// Every repository is opened through this, and the adapters prefix
+    /// every key with the store name as well, so a handler can't reach
+    /// another tenant's data by forgetting the district header.
+    pub fn tenant_store_name(req: &Request) -> Result<String, ApiError> {
+        Self::district_store_name(tenant::requested_tenant(req).as_deref().unwrap_or_default())
+    }
+
+    /// Store name for a tenant named in the URL path, falling back to the
+    /// request's headers
+    pub fn url_tenant_store_name(req: &Request) -> Result<String, ApiError> {
+        match url_tenant::extract_tenant_from_path(&req.path()) {
+            Some(tenant_id) => Self::district_store_name(&tenant_id),
+            None => Self::tenant_store_name(req),
+        }
+    }
+
+    /// Store name for a requested district code
+    ///
+    /// The code must resolve in the district registry, and its store must
+    /// exist in this deployment. A code that doesn't resolve is a 400 whose
+    /// details list the closest codes as `suggestions`.
+    pub fn district_store_name(requested: &str) -> Result<String, ApiError> {
+        let code = tenant::resolve_district(requested).map_err(Self::district_error)?;
+        let store_name = code.to_lowercase();
+        if !tenant::is_provisioned(&store_name) {
+            return Err(ApiError::TenantInvalid(format!(
+                "District {} is not provisioned on this deployment", code
+            )));
         }
-        if store_name.starts_with("UNKNOWN_TENANT_") {
-            return Err(ApiError::BadRequest(
-                format!("Invalid district specified")
-            ));
+        Ok(store_name)
+    }
+
+    /// 400 for a district code the registry rejected
+    pub(crate) fn district_error(error: DistrictCodeError) -> ApiError {
+        let suggestions = error.suggestions().to_vec();
+        let api_error = ApiError::TenantInvalid(error.to_string());
+        match suggestions.is_empty() {
+            true => api_error,
+            false => api_error.with_details(serde_json::json!({ "suggestions": suggestions })),
         }
-        Ok(())
     }
 
     /// Creates a tenant-specific attorney repository.
@@ -110,9 +147,7 @@ impl RepositoryFactory {
     /// let attorney = repo.find_attorney_by_id("123")?;
     /// ```
     pub fn attorney_repo(req: &Request) -> Result<SpinKvAttorneyRepository, ApiError> {
-        let tenant_id = tenant::get_tenant_id(req);
-        let store_name = tenant::get_store_name(&tenant_id);
-        Self::validate_tenant(&store_name)?;
+        let store_name = Self::tenant_store_name(req)?;
         Ok(SpinKvAttorneyRepository::with_store(store_name))
     }
 
@@ -121,8 +156,7 @@ impl RepositoryFactory {
     /// Extracts tenant from URL path (e.g., /api/courts/sdny/attorneys)
     /// Falls back to header-based extraction for backward compatibility
     pub fn attorney_repo_from_url(req: &Request) -> Result<SpinKvAttorneyRepository, String> {
-        let tenant_id = url_tenant::get_tenant_from_request(req)?;
-        let store_name = tenant::get_store_name(&tenant_id);
+        let store_name = Self::url_tenant_store_name(req).map_err(|e| e.to_string())?;
         Ok(SpinKvAttorneyRepository::with_store(store_name))
     }
 
@@ -136,91 +170,58 @@ impl RepositoryFactory {
     ///
     /// A `SpinKvCaseRepository` instance scoped to the identified tenant
     pub fn case_repo(req: &Request) -> Result<SpinKvCaseRepository, ApiError> {
-        let tenant_id = tenant::get_tenant_id(req);
-        let store_name = tenant::get_store_name(&tenant_id);
-        Self::validate_tenant(&store_name)?;
-        Ok(SpinKvCaseRepository::with_store(store_name))
-    }
-
-    /// Creates a case repository with validation
-    pub fn case_repo_validated(req: &Request) -> Result<SpinKvCaseRepository, ApiError> {
-        let tenant_id = tenant::validate_tenant_id(req)
-            .map_err(|e| ApiError::BadRequest(e))?;
-        let store_name = tenant::get_store_name(&tenant_id);
+        let store_name = Self::tenant_store_name(req)?;
         Ok(SpinKvCaseRepository::with_store(store_name))
     }
 
     /// Creates case repository with URL-based tenant extraction
     pub fn case_repo_from_url(req: &Request) -> Result<SpinKvCaseRepository, String> {
-        let tenant_id = url_tenant::get_tenant_from_request(req)?;
-        let store_name = tenant::get_store_name(&tenant_id);
+        let store_name = Self::url_tenant_store_name(req).map_err(|e| e.to_string())?;
         Ok(SpinKvCaseRepository::with_store(store_name))
     }
 
     /// Get tenant-specific deadline repository
     pub fn deadline_repo(req: &Request) -> Result<SpinKvDeadlineRepository, ApiError> {
-        let tenant_id = tenant::get_tenant_id(req);
-        let store_name = tenant::get_store_name(&tenant_id);
-        Self::validate_tenant(&store_name)?;
+        let store_name = Self::tenant_store_name(req)?;
         Ok(SpinKvDeadlineRepository::with_store(store_name))
     }
 
     /// Get tenant-specific docket repository
     pub fn docket_repo(req: &Request) -> Result<SpinKvDocketRepository, ApiError> {
-        let tenant_id = tenant::get_tenant_id(req);
-        let store_name = tenant::get_store_name(&tenant_id);
-        Self::validate_tenant(&store_name)?;
+        let store_name = Self::tenant_store_name(req)?;
         Ok(SpinKvDocketRepository::with_store(store_name))
     }
 
     /// Get tenant-specific document repository
     pub fn document_repo(req: &Request) -> Result<SpinKvDocumentRepository, ApiError> {
-        let tenant_id = tenant::get_tenant_id(req);
-        let store_name = tenant::get_store_name(&tenant_id);
-        Self::validate_tenant(&store_name)?;
+        let store_name = Self::tenant_store_name(req)?;
         Ok(SpinKvDocumentRepository::with_store(store_name))
     }
 
     /// Get tenant-specific judge repository
     pub fn judge_repo(req: &Request) -> Result<SpinKvJudgeRepository, ApiError> {
-        let tenant_id = tenant::get_tenant_id(req);
-        let store_name = tenant::get_store_name(&tenant_id);
-        Self::validate_tenant(&store_name)?;
-        Ok(SpinKvJudgeRepository::with_store(store_name))
-    }
-
-    /// Creates a judge repository with validation
-    pub fn judge_repo_validated(req: &Request) -> Result<SpinKvJudgeRepository, ApiError> {
-        let tenant_id = tenant::validate_tenant_id(req)
-            .map_err(|e| ApiError::BadRequest(e))?;
-        let store_name = tenant::get_store_name(&tenant_id);
+        let store_name = Self::tenant_store_name(req)?;
         Ok(SpinKvJudgeRepository::with_store(store_name))
     }
 
     /// Get tenant-specific rules repository
     pub fn rules_repo(req: &Request) -> Result<SpinKvRulesRepository, ApiError> {
-        let tenant_id = tenant::get_tenant_id(req);
-        let store_name = tenant::get_store_name(&tenant_id);
-        Self::validate_tenant(&store_name)?;
-        Ok(SpinKvRulesRepository::with_store(store_name))
-    }
-
-    /// Creates a rules repository with validation
-    pub fn rules_repo_validated(req: &Request) -> Result<SpinKvRulesRepository, ApiError> {
-        let tenant_id = tenant::validate_tenant_id(req)
-            .map_err(|e| ApiError::BadRequest(e))?;
-        let store_name = tenant::get_store_name(&tenant_id);
+        let store_name = Self::tenant_store_name(req)?;
         Ok(SpinKvRulesRepository::with_store(store_name))
     }
 
     /// Get tenant-specific sentencing repository
     pub fn sentencing_repo(req: &Request) -> Result<SpinKvSentencingRepository, ApiError> {
-        let tenant_id = tenant::get_tenant_id(req);
-        let store_name = tenant::get_store_name(&tenant_id);
-        Self::validate_tenant(&store_name)?;
+        let store_name = Self::tenant_store_name(req)?;
         Ok(SpinKvSentencingRepository::with_store(store_name))
     }
 
+    /// Get tenant-specific webhook repository
+    pub fn webhook_repo(req: &Request) -> Result<SpinKvWebhookRepository, ApiError> {
+        let store_name = Self::tenant_store_name(req)?;
+        Ok(SpinKvWebhookRepository::with_store(store_name))
+    }
+
     /// Creates a tenant-specific configuration repository.
     ///
     /// # Arguments
@@ -239,8 +240,7 @@ impl RepositoryFactory {
     /// ```
     pub fn config_repo(req: &Request) -> Result<SpinKvConfigRepository, ApiError> {
         let tenant_id = tenant::get_tenant_id(req);
-        let store_name = tenant::get_store_name(&tenant_id);
-        Self::validate_tenant(&store_name)?;
+        let store_name = Self::tenant_store_name(req)?;
 
         // Determine court type from tenant ID or headers
         let court_type = Self::determine_court_type(req, &tenant_id);
@@ -251,7 +251,7 @@ impl RepositoryFactory {
     /// Creates config repository with URL-based tenant extraction
     pub fn config_repo_from_url(req: &Request) -> Result<SpinKvConfigRepository, String> {
         let tenant_id = url_tenant::get_tenant_from_request(req)?;
-        let store_name = tenant::get_store_name(&tenant_id);
+        let store_name = Self::url_tenant_store_name(req).map_err(|e| e.to_string())?;
 
         // Determine court type from URL or headers
         let court_type = Self::determine_court_type(req, &tenant_id);
@@ -272,8 +272,7 @@ impl RepositoryFactory {
     /// A boxed `FeatureRepository` trait object
     pub fn feature_repo(req: &Request) -> Result<Box<dyn FeatureRepository>, ApiError> {
         let config_repo = Arc::new(Self::config_repo(req)?);
-        let tenant_id = tenant::get_tenant_id(req);
-        let store_name = tenant::get_store_name(&tenant_id);
+        let store_name = Self::tenant_store_name(req)?;
 
         Ok(Box::new(UnifiedConfigFeatureRepository::new(
             config_repo,
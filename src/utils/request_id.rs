@@ -0,0 +1,85 @@
+//! Request ids for log correlation
+//!
+//! Every request gets an id that is returned in the `X-Request-Id` response
+//! header, embedded in error envelopes and prefixed to log lines. A caller
+//! tracing across services may send its own `X-Request-Id`, which is kept;
+//! otherwise one is generated. A component instance handles one request at a
+//! time, so the id of the request in flight is kept in a thread local set by
+//! the entry point.
+
+use spin_sdk::http::Response;
+use std::cell::RefCell;
+use uuid::Uuid;
+
+/// Request and response header carrying the request id
+pub const HEADER: &str = "x-request-id";
+
+/// Longest incoming id that is echoed rather than replaced
+const MAX_INCOMING_LEN: usize = 128;
+
+thread_local! {
+    static CURRENT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Start a new request with a generated id, returning it
+pub fn begin() -> String {
+    begin_with(None)
+}
+
+/// Start a new request, keeping the caller's id if it is usable
+///
+/// An incoming id is used as sent when it is at most 128 printable ASCII
+/// characters without spaces; anything else is replaced, so a client can't
+/// smuggle line breaks into the logs.
+pub fn begin_with(incoming: Option<&str>) -> String {
+    let id = incoming
+        .filter(|id| is_usable(id))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    CURRENT.with(|current| *current.borrow_mut() = Some(id.clone()));
+    id
+}
+
+fn is_usable(id: &str) -> bool {
+    !id.is_empty() && id.len() <= MAX_INCOMING_LEN && id.bytes().all(|b| b.is_ascii_graphic())
+}
+
+/// Set the `X-Request-Id` header on an outgoing response
+pub fn inject(response: &mut Response) {
+    response.set_header(HEADER, current());
+}
+
+/// Id of the request being handled, starting one if none is in flight
+pub fn current() -> String {
+    CURRENT
+        .with(|current| current.borrow().clone())
+        .unwrap_or_else(begin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_is_stable_until_next_request() {
+        let first = begin();
+        assert_eq!(current(), first);
+        assert_eq!(current(), first);
+
+        let second = begin();
+        assert_ne!(first, second);
+        assert_eq!(current(), second);
+    }
+
+    #[test]
+    fn test_usable_incoming_id_is_kept() {
+        assert_eq!(begin_with(Some("trace-abc.123")), "trace-abc.123");
+        assert_eq!(current(), "trace-abc.123");
+
+        for unusable in ["", "has space", "line\nbreak", "x".repeat(129).as_str()] {
+            let id = begin_with(Some(unusable));
+            assert_ne!(id, unusable);
+            assert!(Uuid::parse_str(&id).is_ok(), "{:?} should be replaced", unusable);
+        }
+    }
+}
@@ -24,8 +24,17 @@
 //! - Returns "TENANT_NOT_SPECIFIED" when no tenant is found
 //! - Invalid tenants map to "UNKNOWN_TENANT_xxx" stores that will fail to open
 //! - All tenant IDs are sanitized to prevent injection attacks
+//! - Repositories only open for codes in the district registry (see
+//!   [`resolve_district`]), so a typo is rejected rather than becoming a
+//!   new tenant
 
+use crate::domain::district::{self, DistrictCodeError, RegisteredTenant};
+use chrono::Utc;
 use spin_sdk::http::Request;
+use spin_sdk::key_value::Store;
+
+/// Key of the custom tenant registry in the shared `default` store
+const REGISTRY_KEY: &str = "tenants:registry";
 
 /// Extract tenant identifier from request
 ///
@@ -48,47 +57,100 @@ use spin_sdk::http::Request;
 /// }
 /// ```
 pub fn get_tenant_id(req: &Request) -> String {
-    // Check for explicit tenant header
-    if let Some(tenant_id) = req.header("x-tenant-id") {
-        if let Some(value) = tenant_id.as_str() {
-            if !value.is_empty() {
-                return sanitize_tenant_id(value);
-            }
-        }
+    match requested_tenant(req) {
+        Some(value) => sanitize_tenant_id(&value),
+        // CRITICAL SECURITY: No tenant specified - return a special value
+        // This MUST NOT default to any real tenant to prevent unauthorized data access
+        // The repository layer will reject "TENANT_NOT_SPECIFIED" with AccessDenied
+        None => "TENANT_NOT_SPECIFIED".to_string(),
     }
+}
 
-    // Check for court district header
-    if let Some(court) = req.header("x-court-district") {
-        if let Some(value) = court.as_str() {
-            if !value.is_empty() {
-                return sanitize_tenant_id(value);
-            }
-        }
-    }
+/// The tenant the request names, exactly as given apart from surrounding
+/// whitespace
+///
+/// Uses the same sources and order as [`get_tenant_id`] but doesn't
+/// sanitize, so [`resolve_district`] can reject a code that isn't canonical.
+pub fn requested_tenant(req: &Request) -> Option<String> {
+    let header = |name: &str| {
+        req.header(name)
+            .and_then(|h| h.as_str())
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
 
-    // Check host header for subdomain
-    if let Some(host) = req.header("host") {
-        if let Some(value) = host.as_str() {
-            if let Some(subdomain) = extract_subdomain(value) {
-                return sanitize_tenant_id(&subdomain);
-            }
-        }
+    header("x-tenant-id")
+        .or_else(|| header("x-court-district"))
+        .or_else(|| req.header("host").and_then(|h| h.as_str()).and_then(extract_subdomain))
+        .or_else(|| {
+            req.query()
+                .split('&')
+                .find_map(|param| param.strip_prefix("tenant="))
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        })
+}
+
+/// Resolve a requested district code against the district registry
+///
+/// The code is trimmed and uppercased; the federal districts and built-in
+/// tenants are checked first, and the custom tenants registered through
+/// `init_tenant` only for a code that is neither. Returns the canonical
+/// code, whose lowercase form names the tenant's store.
+pub fn resolve_district(requested: &str) -> Result<String, DistrictCodeError> {
+    match district::resolve(requested, &[]) {
+        Err(DistrictCodeError::Unknown { .. }) => district::resolve(requested, &registered_tenants()),
+        resolved => resolved,
     }
+}
+
+/// Custom tenants registered through `init_tenant`
+///
+/// An unreadable registry reads as empty, so only the static registry
+/// applies.
+pub fn registered_tenants() -> Vec<RegisteredTenant> {
+    Store::open("default")
+        .ok()
+        .and_then(|store| store.get_json::<Vec<RegisteredTenant>>(REGISTRY_KEY).ok().flatten())
+        .unwrap_or_default()
+}
 
-    // Check query parameter
-    let query = req.query();
-    for param in query.split('&') {
-        if let Some(value) = param.strip_prefix("tenant=") {
-            if !value.is_empty() {
-                return sanitize_tenant_id(value);
-            }
-        }
+/// Add a custom tenant to the registry
+///
+/// Returns `false` if it was already registered.
+pub fn register_tenant(code: &str) -> anyhow::Result<bool> {
+    let store = Store::open("default")?;
+    let mut tenants = store.get_json::<Vec<RegisteredTenant>>(REGISTRY_KEY)?.unwrap_or_default();
+    if tenants.iter().any(|t| t.code == code) {
+        return Ok(false);
     }
+    tenants.push(RegisteredTenant { code: code.to_string(), registered_at: Utc::now() });
+    store.set_json(REGISTRY_KEY, &tenants)?;
+    Ok(true)
+}
 
-    // CRITICAL SECURITY: No tenant specified - return a special value
-    // This MUST NOT default to any real tenant to prevent unauthorized data access
-    // The repository layer will reject "TENANT_NOT_SPECIFIED" with AccessDenied
-    "TENANT_NOT_SPECIFIED".to_string()
+/// Whether a tenant's store exists in this deployment
+///
+/// The stores declared in `spin.toml` are known up front; any other
+/// registered code is provisioned only if its store opens.
+pub fn is_provisioned(store_name: &str) -> bool {
+    FEDERAL_COURTS.contains(&store_name)
+        || TEST_DISTRICTS.contains(&store_name)
+        || Store::open(store_name).is_ok()
+}
+
+/// District identifier that configuration overrides are keyed by
+///
+/// The configuration endpoints store overrides under the raw
+/// `X-Court-District` header value, so prefer that and fall back to the
+/// resolved tenant ID.
+pub fn get_config_district_id(req: &Request) -> String {
+    req.header("x-court-district")
+        .and_then(|h| h.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| get_tenant_id(req))
 }
 
 /// Extract subdomain from host header
@@ -137,7 +199,11 @@ fn sanitize_tenant_id(id: &str) -> String {
         .to_lowercase()
 }
 
-/// List of valid federal court districts
+/// Production stores declared in `spin.toml`
+///
+/// Which codes are valid is up to the district registry in
+/// `domain::district`; these are the stores known to exist without opening
+/// them.
 ///
 /// ## Production Districts:
 /// - `sdny` - Southern District of New York
@@ -153,6 +219,7 @@ fn sanitize_tenant_id(id: &str) -> String {
 const FEDERAL_COURTS: &[&str] = &[
     "sdny", "edny", "ndca", "cdca", "sdtx", "ndil", "ddc",
     "ndny", "wdny", "nybk", "edtx", "fisa", "tax", "generic",
+    "default", "test",
 ];
 
 /// List of test districts (movie references)
@@ -170,18 +237,17 @@ const TEST_DISTRICTS: &[&str] = &[
 
 /// Check if a tenant ID is a valid district
 ///
-/// Validates against both production federal courts and test districts.
-/// Also allows "default" and "test" for backward compatibility.
+/// Validates against the federal districts and built-in tenants of the
+/// district registry, which include "default" and "test" for backward
+/// compatibility. Custom tenants need a store read, so only
+/// [`resolve_district`] accepts them.
 ///
 /// ## Returns
 /// - `true` if the tenant ID matches a known district
 /// - `false` for unknown or invalid districts
 fn is_valid_district(tenant_id: &str) -> bool {
-    let lower = tenant_id.to_lowercase();
-    FEDERAL_COURTS.contains(&lower.as_str()) ||
-    TEST_DISTRICTS.contains(&lower.as_str()) ||
-    lower == "default" ||
-    lower == "test"
+    let upper = tenant_id.to_uppercase();
+    district::known_codes().any(|code| code == upper)
 }
 
 /// Get store name for a tenant
@@ -229,50 +295,6 @@ pub fn get_store_name(tenant_id: &str) -> String {
     }
 }
 
-/// Validate that a tenant ID is specified and valid
-///
-/// Performs comprehensive validation of tenant ID from request.
-/// This should be called early in request processing to fail fast.
-///
-/// ## Validation Steps:
-/// 1. Extract tenant ID from request
-/// 2. Check if tenant was found (not empty or "TENANT_NOT_SPECIFIED")
-/// 3. Verify tenant maps to a valid store name
-/// 4. Ensure tenant is a known district
-///
-/// ## Returns
-/// - `Ok(tenant_id)` - Valid tenant ID ready for use
-/// - `Err(message)` - Validation failure with user-friendly error message
-///
-/// ## Example Usage:
-/// ```rust
-/// match validate_tenant_id(&req) {
-///     Ok(tenant) => proceed_with_tenant(tenant),
-///     Err(msg) => return error_response(&msg),
-/// }
-/// ```
-pub fn validate_tenant_id(req: &Request) -> Result<String, String> {
-    let tenant_id = get_tenant_id(req);
-
-    // Check if tenant was actually found
-    if tenant_id.is_empty() || tenant_id == "TENANT_NOT_SPECIFIED" {
-        return Err("Missing required header: X-Court-District or X-Tenant-ID".to_string());
-    }
-
-    // Check if it's a valid district
-    let store_name = get_store_name(&tenant_id);
-    let store_lower = store_name.to_lowercase();
-    if store_lower == "tenant_not_specified" || store_lower.starts_with("tenant_not_specified") {
-        return Err("Missing required header: X-Court-District or X-Tenant-ID".to_string());
-    }
-
-    if store_name.starts_with("UNKNOWN_TENANT_") || store_lower.starts_with("unknown_tenant_") {
-        return Err(format!("Invalid district: {}", tenant_id));
-    }
-
-    Ok(tenant_id)
-}
-
 /// Check if a user has access to a specific tenant
 ///
 /// ## Future Implementation:
@@ -0,0 +1,138 @@
+//! CJA bulk appointment tests
+//!
+//! Tests that bulk appointments walk the CJA panel in rotation order,
+//! skip conflicted attorneys, and persist the rotation cursor between calls.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a POST request with an optional JSON body
+fn post(path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&Method::Post).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+/// Helper to create an attorney and add them to the SDNY CJA panel
+fn create_panel_attorney(last_name: &str, bar_number: &str, district: &str) -> String {
+    let (status, attorney) = post("/api/attorneys", Some(json!({
+        "bar_number": bar_number,
+        "first_name": "Panel",
+        "last_name": last_name,
+        "email": format!("{}@cja.example.com", last_name.to_lowercase()),
+        "phone": "555-0100",
+        "address": {
+            "street1": "1 Federal Plaza",
+            "city": "New York",
+            "state": "NY",
+            "zip_code": "10007",
+            "country": "US"
+        }
+    })), district);
+    assert_eq!(status, 200, "Attorney creation should succeed: {}", attorney);
+
+    let id = attorney["id"].as_str().unwrap().to_string();
+    let (status, _) = post(&format!("/api/attorneys/{}/cja-panel/SDNY", id), None, district);
+    assert_eq!(status, 204);
+    id
+}
+
+fn bulk_appoint(case_id: &str, count: usize, parties: Vec<&str>, district: &str) -> (u16, Value) {
+    post("/api/cja/bulk-appoint", Some(json!({
+        "district": "SDNY",
+        "case_id": case_id,
+        "case_caption": "United States v. Multiple Defendants",
+        "count": count,
+        "appointment_type": "TrialLevel",
+        "parties": parties
+    })), district)
+}
+
+fn appointed_ids(body: &Value) -> Vec<String> {
+    body["appointed"].as_array().unwrap()
+        .iter()
+        .map(|a| a["attorney_id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// Test that bulk appointment follows rotation order and skips conflicted attorneys
+#[spin_test]
+fn test_bulk_appoint_rotation_and_conflict_avoidance() {
+    let _store = key_value::Store::open("district9");
+
+    let adams = create_panel_attorney("Adams", "CJA-0001", "district9");
+    let smith = create_panel_attorney("Smith", "CJA-0002", "district9");
+    let young = create_panel_attorney("Young", "CJA-0003", "district9");
+
+    // Smith is next in rotation after Adams but conflicts with defendant "John Smith"
+    let (status, body) = bulk_appoint("case-multi-1", 2, vec!["John Smith"], "district9");
+    assert_eq!(status, 201, "Bulk appointment should succeed: {}", body);
+    assert_eq!(appointed_ids(&body), vec![adams.clone(), young.clone()]);
+
+    let skipped = body["skipped"].as_array().unwrap();
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0]["attorney_id"], smith.as_str());
+
+    // The cursor sits on Young, so the next appointment wraps around to Adams
+    let (status, body) = bulk_appoint("case-multi-2", 1, vec![], "district9");
+    assert_eq!(status, 201, "Second bulk appointment should succeed: {}", body);
+    assert_eq!(appointed_ids(&body), vec![adams]);
+
+    let (_, appointments) = get_appointments(&young, "district9");
+    assert_eq!(appointments.as_array().unwrap().len(), 1);
+    assert_eq!(appointments[0]["case_id"], "case-multi-1");
+}
+
+/// Test that a request exceeding the eligible panel is rejected without appointing anyone
+#[spin_test]
+fn test_bulk_appoint_insufficient_panel() {
+    let _store = key_value::Store::open("district9");
+
+    let adams = create_panel_attorney("Adams", "CJA-0101", "district9");
+
+    let (status, _) = bulk_appoint("case-multi-3", 2, vec![], "district9");
+    assert_eq!(status, 409);
+
+    let (_, appointments) = get_appointments(&adams, "district9");
+    assert!(appointments.as_array().unwrap().is_empty());
+}
+
+/// Helper to list CJA appointments for an attorney
+fn get_appointments(attorney_id: &str, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&Method::Get).unwrap();
+    request.set_path_with_query(Some(&format!("/api/attorneys/{}/cja-appointments", attorney_id))).unwrap();
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+    (status, serde_json::from_str(&body).unwrap_or(json!(null)))
+}
@@ -0,0 +1,145 @@
+//! CJA voucher tests
+//!
+//! Tests that voucher line items add up to the claimed total, that a
+//! submitted voucher shows in the pending list and takes no more line items,
+//! and that approval records the judge and cannot exceed the claim.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Create an attorney with one trial-level CJA appointment; returns the voucher path
+fn appointed_attorney(bar_number: &str, appointment_id: &str) -> (String, String) {
+    let (status, attorney) = send(Method::Post, "/api/attorneys", Some(json!({
+        "bar_number": bar_number,
+        "first_name": "Voucher",
+        "last_name": "Counsel",
+        "email": format!("{}@cja.example.com", bar_number.to_lowercase()),
+        "phone": "555-0100",
+        "address": {
+            "street1": "1 Federal Plaza",
+            "city": "New York",
+            "state": "NY",
+            "zip_code": "10007",
+            "country": "US"
+        }
+    })));
+    assert_eq!(status, 200, "Attorney creation should succeed: {}", attorney);
+    let attorney_id = attorney["id"].as_str().unwrap().to_string();
+
+    let (status, _) = send(Method::Post, &format!("/api/attorneys/{}/cja-appointments", attorney_id), Some(json!({
+        "id": appointment_id,
+        "case_id": "case-voucher-1",
+        "case_caption": "United States v. Doe",
+        "appointment_date": "2030-01-07T09:00:00Z",
+        "appointment_type": "TrialLevel",
+        "compensation_status": "Pending",
+        "hours_claimed": 0.0,
+        "amount_approved": null,
+        "voucher_status": "Draft"
+    })));
+    assert_eq!(status, 204);
+
+    let voucher = format!("/api/attorneys/{}/cja-appointments/{}/voucher", attorney_id, appointment_id);
+    (attorney_id, voucher)
+}
+
+fn add_item(voucher: &str, hours: f64, rate_category: &str, expenses: f64) -> (u16, Value) {
+    send(Method::Post, &format!("{}/line-items", voucher), Some(json!({
+        "service_date": "2030-01-14",
+        "description": "Client meeting and detention hearing",
+        "hours": hours,
+        "rate_category": rate_category,
+        "expenses": expenses
+    })))
+}
+
+fn pending_ids() -> Vec<String> {
+    let (status, pending) = send(Method::Get, "/api/attorneys/cja/pending-vouchers", None);
+    assert_eq!(status, 200);
+    pending.as_array().unwrap().iter().map(|v| v["appointment"]["id"].as_str().unwrap().to_string()).collect()
+}
+
+#[spin_test]
+fn test_voucher_submit_and_reduce() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (_, voucher) = appointed_attorney("CJA-V001", "appt-voucher-1");
+
+    let (status, summary) = add_item(&voucher, 3.0, "InCourt", 0.0);
+    assert_eq!(status, 201, "Line item should be added: {}", summary);
+    let (status, summary) = add_item(&voucher, 2.0, "OutOfCourt", 40.0);
+    assert_eq!(status, 201);
+    assert_eq!(summary["appointment"]["line_items"].as_array().unwrap().len(), 2);
+    let claimed = summary["claimed_total"].as_f64().unwrap();
+    assert!(claimed > 40.0);
+    assert_eq!(summary["exceeds_cap"], false);
+    assert!(!pending_ids().contains(&"appt-voucher-1".to_string()), "Drafts are not pending");
+
+    let (status, summary) = send(Method::Post, &format!("{}/submit", voucher), None);
+    assert_eq!(status, 200);
+    assert_eq!(summary["appointment"]["voucher_status"], "Submitted");
+    assert!(pending_ids().contains(&"appt-voucher-1".to_string()));
+
+    let (status, _) = add_item(&voucher, 1.0, "InCourt", 0.0);
+    assert_eq!(status, 422, "Line items cannot be added after submission");
+
+    let (status, _) = send(Method::Post, &format!("{}/approve", voucher), Some(json!({
+        "judge_id": "judge-voucher",
+        "approved_amount": claimed + 1.0
+    })));
+    assert_eq!(status, 422, "Approved amount cannot exceed the claim");
+
+    let (status, summary) = send(Method::Post, &format!("{}/approve", voucher), Some(json!({
+        "judge_id": "judge-voucher",
+        "approved_amount": claimed - 40.0
+    })));
+    assert_eq!(status, 200);
+    assert_eq!(summary["appointment"]["voucher_status"], "Reduced");
+    assert_eq!(summary["appointment"]["approved_by"], "judge-voucher");
+    assert_eq!(summary["appointment"]["amount_approved"].as_f64(), Some(claimed - 40.0));
+    assert!(!pending_ids().contains(&"appt-voucher-1".to_string()));
+
+    let (status, summary) = send(Method::Post, &format!("{}/pay", voucher), None);
+    assert_eq!(status, 200);
+    assert_eq!(summary["appointment"]["voucher_status"], "Paid");
+}
+
+#[spin_test]
+fn test_voucher_over_cap_is_flagged() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (_, voucher) = appointed_attorney("CJA-V002", "appt-voucher-2");
+
+    let (status, summary) = add_item(&voucher, 120.0, "OutOfCourt", 0.0);
+    assert_eq!(status, 201);
+    assert_eq!(summary["exceeds_cap"], true);
+    assert!(summary["claimed_total"].as_f64().unwrap() > summary["compensation_cap"].as_f64().unwrap());
+
+    let (status, _) = send(Method::Post, "/api/attorneys/missing/cja-appointments/appt-voucher-2/voucher/submit", None);
+    assert_eq!(status, 404);
+}
@@ -0,0 +1,96 @@
+//! Conflict check name matching tests
+//!
+//! Tests that POST /api/conflict-checks/check finds an attorney's conflict
+//! checks recorded under a variant of a party's name, and says how each
+//! recorded name matched.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to record a conflict check for an attorney
+fn create_check(id: &str, parties: Value, adverse: Value, district: &str) {
+    let (status, body) = send(Method::Post, "/api/conflict-checks", Some(json!({
+        "id": id,
+        "attorney_id": "atty-names",
+        "check_date": "2030-03-04T09:00:00Z",
+        "case_id": null,
+        "party_names": parties,
+        "adverse_parties": adverse,
+        "conflicts_found": [],
+        "cleared": false,
+        "waiver_obtained": false,
+        "notes": null
+    })), district);
+    assert_eq!(status, 204, "Conflict check should be created: {:?}", body);
+}
+
+#[spin_test]
+fn test_check_distinguishes_exact_and_fuzzy_hits() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    create_check("names-1", json!(["ACME Corporation"]), json!(["Globex Industries"]), district);
+    create_check("names-2", json!(["Jane Roe"]), json!(["Initech"]), district);
+
+    let (status, body) = send(
+        Method::Post,
+        "/api/conflict-checks/check?attorney_id=atty-names",
+        Some(json!(["Acme Corp.", "Globex Industires"])),
+        district,
+    );
+    assert_eq!(status, 200, "{:?}", body);
+
+    let checks = body.as_array().unwrap();
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0]["id"], "names-1");
+    assert_eq!(checks[0]["party_names"], json!(["ACME Corporation"]));
+
+    let matches = checks[0]["matches"].as_array().unwrap();
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0]["query"], "Acme Corp.");
+    assert_eq!(matches[0]["matched"], "ACME Corporation");
+    assert_eq!(matches[0]["kind"], "normalized");
+    assert_eq!(matches[1]["matched"], "Globex Industries");
+    assert_eq!(matches[1]["kind"], "fuzzy");
+
+    let (_, body) = send(
+        Method::Post,
+        "/api/conflict-checks/check?attorney_id=atty-names",
+        Some(json!(["jane roe"])),
+        district,
+    );
+    assert_eq!(body[0]["matches"][0]["kind"], "exact");
+
+    let (status, _) = send(
+        Method::Post,
+        "/api/conflict-checks/check?attorney_id=atty-names&threshold=abc",
+        Some(json!(["Acme"])),
+        district,
+    );
+    assert_eq!(status, 400);
+}
@@ -0,0 +1,106 @@
+//! Conflict-check turnaround tests
+//!
+//! Tests for GET /api/conflict-checks/turnaround, which reports the average
+//! time from conflict-check creation to clearance and how many checks are
+//! still outstanding for checks opened in the requested period.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to record a conflict check opened at `check_date`, optionally already cleared
+fn create_check(id: &str, check_date: &str, cleared_at: Option<&str>, district: &str) {
+    let (status, body) = send(Method::Post, "/api/conflict-checks", Some(json!({
+        "id": id,
+        "attorney_id": "atty-turnaround",
+        "check_date": check_date,
+        "case_id": null,
+        "party_names": ["Acme Corp"],
+        "adverse_parties": [],
+        "conflicts_found": [],
+        "cleared": cleared_at.is_some(),
+        "waiver_obtained": false,
+        "notes": null,
+        "cleared_at": cleared_at
+    })), district);
+    assert_eq!(status, 204, "Conflict check should be created: {:?}", body);
+}
+
+#[spin_test]
+fn test_turnaround_averages_cleared_checks_and_counts_outstanding() {
+    let _store = key_value::Store::open("district9");
+
+    // Cleared in 24 and 72 hours: average 48 hours
+    create_check("cc-1", "2030-03-04T09:00:00Z", Some("2030-03-05T09:00:00Z"), "district9");
+    create_check("cc-2", "2030-03-06T09:00:00Z", Some("2030-03-09T09:00:00Z"), "district9");
+    // Still outstanding
+    create_check("cc-3", "2030-03-10T09:00:00Z", None, "district9");
+    create_check("cc-4", "2030-03-11T09:00:00Z", None, "district9");
+    // Outside the requested period
+    create_check("cc-5", "2030-05-01T09:00:00Z", Some("2030-05-01T10:00:00Z"), "district9");
+
+    let (status, body) = send(
+        Method::Get,
+        "/api/conflict-checks/turnaround?start=2030-03-01&end=2030-03-31",
+        None,
+        "district9",
+    );
+
+    assert_eq!(status, 200, "Turnaround should return 200: {:?}", body);
+    assert_eq!(body["total_checks"], 4);
+    assert_eq!(body["cleared_checks"], 2);
+    assert_eq!(body["outstanding_checks"], 2);
+    assert_eq!(body["average_turnaround_hours"].as_f64(), Some(48.0));
+}
+
+#[spin_test]
+fn test_clearing_a_check_removes_it_from_outstanding() {
+    let _store = key_value::Store::open("district9");
+
+    create_check("cc-open", "2030-03-04T09:00:00Z", None, "district9");
+
+    let path = "/api/conflict-checks/turnaround?start=2030-03-01&end=2030-03-31";
+    let (_, before) = send(Method::Get, path, None, "district9");
+    assert_eq!(before["outstanding_checks"], 1);
+    assert!(before["average_turnaround_hours"].is_null());
+
+    let (status, _) = send(Method::Post, "/api/conflict-checks/cc-open/clear?waiver=false", None, "district9");
+    assert_eq!(status, 204);
+
+    let (_, after) = send(Method::Get, path, None, "district9");
+    assert_eq!(after["outstanding_checks"], 0);
+    assert_eq!(after["cleared_checks"], 1);
+    assert!(after["average_turnaround_hours"].as_f64().unwrap() > 0.0);
+}
+
+#[spin_test]
+fn test_turnaround_rejects_invalid_dates() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, _) = send(Method::Get, "/api/conflict-checks/turnaround?start=March", None, "district9");
+    assert_eq!(status, 400);
+}
@@ -0,0 +1,111 @@
+//! Representation continuity tests
+//!
+//! Tests for GET /api/cases/:case_id/continuity, which counts attorney
+//! substitutions and withdrawals on a case and scores how stable its
+//! representation has been.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to record an attorney's active representation of the defendant in a case
+fn represent(attorney_id: &str, case_id: &str, district: &str) {
+    let (status, body) = send(Method::Post, "/api/representations", Some(json!({
+        "id": "",
+        "attorney_id": attorney_id,
+        "party_id": format!("{}-defendant", case_id),
+        "case_id": case_id,
+        "representation_type": "General",
+        "status": "Active",
+        "start_date": "2030-01-06T09:00:00Z",
+        "end_date": null,
+        "lead_counsel": true,
+        "local_counsel": false,
+        "limited_appearance": false,
+        "scope_of_representation": null,
+        "withdrawal_reason": null,
+        "court_appointed": false,
+        "cja_appointment_id": null,
+        "notes": null
+    })), district);
+    assert_eq!(status, 204, "Representation should be added: {:?}", body);
+}
+
+fn substitute(old_attorney_id: &str, new_attorney_id: &str, case_id: &str, district: &str) {
+    let path = format!(
+        "/api/representations/substitute?old_attorney_id={}&new_attorney_id={}&case_id={}",
+        old_attorney_id, new_attorney_id, case_id
+    );
+    let (status, body) = send(Method::Post, &path, None, district);
+    assert_eq!(status, 204, "Substitution should succeed: {:?}", body);
+}
+
+fn continuity(case_id: &str, district: &str) -> Value {
+    let (status, body) = send(Method::Get, &format!("/api/cases/{}/continuity", case_id), None, district);
+    assert_eq!(status, 200, "Continuity should be returned: {:?}", body);
+    body
+}
+
+#[spin_test]
+fn test_substitutions_lower_continuity_score() {
+    let _store = key_value::Store::open("district9");
+
+    represent("atty-stable", "case-stable", "district9");
+
+    represent("atty-first", "case-churn", "district9");
+    substitute("atty-first", "atty-second", "case-churn", "district9");
+    substitute("atty-second", "atty-third", "case-churn", "district9");
+
+    let stable = continuity("case-stable", "district9");
+    let churn = continuity("case-churn", "district9");
+
+    assert_eq!(stable["substitutions"], 0);
+    assert_eq!(stable["continuity_score"], 100);
+    assert_eq!(stable["excessive_churn"], false);
+
+    assert_eq!(churn["substitutions"], 2);
+    assert_eq!(churn["withdrawals"], 0);
+    assert_eq!(churn["total_representations"], 3);
+    assert_eq!(churn["active_representations"], 1);
+    assert_eq!(churn["excessive_churn"], true);
+    assert!(
+        churn["continuity_score"].as_u64().unwrap() < stable["continuity_score"].as_u64().unwrap(),
+        "A case with two substitutions should score lower than a stable case"
+    );
+}
+
+#[spin_test]
+fn test_case_without_representation_has_full_continuity() {
+    let _store = key_value::Store::open("district9");
+
+    let body = continuity("case-unrepresented", "district9");
+
+    assert_eq!(body["case_id"], "case-unrepresented");
+    assert_eq!(body["total_representations"], 0);
+    assert_eq!(body["continuity_score"], 100);
+    assert_eq!(body["excessive_churn"], false);
+}
@@ -0,0 +1,205 @@
+//! Attorney discipline tests
+//!
+//! Tests that a suspension or disbarment in effect takes away good standing,
+//! changes the attorney's status and ends their representations, that a
+//! suspension whose term has run does not, and that reinstatement restores
+//! the attorney.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+/// An attorney admitted to the state bar, so in good standing
+fn create_admitted_attorney(bar_number: &str) -> String {
+    let (status, attorney) = send(Method::Post, "/api/attorneys", Some(json!({
+        "bar_number": bar_number,
+        "first_name": "Counsel",
+        "last_name": "Disciplined",
+        "email": format!("{}@example.com", bar_number.to_lowercase()),
+        "phone": "555-0100",
+        "address": {
+            "street1": "500 Pearl St",
+            "city": "New York",
+            "state": "NY",
+            "zip_code": "10007",
+            "country": "US"
+        }
+    })));
+    assert_eq!(status, 200, "Attorney creation should succeed: {}", attorney);
+    let id = attorney["id"].as_str().unwrap().to_string();
+
+    let (status, _) = send(Method::Post, &format!("/api/attorneys/{}/bar-admissions", id), Some(json!({
+        "state": "NY",
+        "bar_number": bar_number,
+        "admission_date": "2010-01-15T00:00:00Z",
+        "status": "Active",
+        "expiration_date": null
+    })));
+    assert_eq!(status, 204);
+    assert!(in_good_standing(&id));
+    id
+}
+
+fn represent(attorney_id: &str, case_id: &str) {
+    let (status, body) = send(Method::Post, "/api/representations", Some(json!({
+        "id": "",
+        "attorney_id": attorney_id,
+        "party_id": format!("{}-defendant", case_id),
+        "case_id": case_id,
+        "representation_type": "General",
+        "status": "Active",
+        "start_date": "2024-01-08T09:00:00Z",
+        "end_date": null,
+        "lead_counsel": true,
+        "local_counsel": false,
+        "limited_appearance": false,
+        "scope_of_representation": null,
+        "withdrawal_reason": null,
+        "court_appointed": false,
+        "cja_appointment_id": null,
+        "notes": null
+    })));
+    assert_eq!(status, 204, "Representation should be added: {:?}", body);
+}
+
+fn discipline(action_type: &str, effective_date: &str, end_date: Option<&str>) -> Value {
+    json!({
+        "id": format!("{}-{}", action_type, effective_date),
+        "date": effective_date,
+        "jurisdiction": "New York",
+        "action_type": action_type,
+        "description": "Neglect of client matters",
+        "case_number": "M-2024-88",
+        "effective_date": effective_date,
+        "end_date": end_date,
+        "public_record": true
+    })
+}
+
+fn in_good_standing(attorney_id: &str) -> bool {
+    let (status, body) = send(Method::Get, &format!("/api/attorneys/{}/is-in-good-standing", attorney_id), None);
+    assert_eq!(status, 200);
+    body.as_bool().unwrap()
+}
+
+#[spin_test]
+fn test_active_suspension_ends_representations() {
+    key_value::Store::open(DISTRICT);
+    let id = create_admitted_attorney("NY-DISC-1");
+    represent(&id, "case-disc-1");
+    represent(&id, "case-disc-2");
+
+    let (status, body) = send(
+        Method::Post,
+        &format!("/api/attorneys/{}/disciplinary-actions", id),
+        Some(discipline("Suspension", "2024-06-01T00:00:00Z", Some("2099-06-01T00:00:00Z"))),
+    );
+
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body["attorney_status"], "Suspended");
+    let mut cases: Vec<&str> = body["affected_cases"].as_array().unwrap()
+        .iter()
+        .map(|c| c["case_id"].as_str().unwrap())
+        .collect();
+    cases.sort();
+    assert_eq!(cases, vec!["case-disc-1", "case-disc-2"]);
+    assert!(!in_good_standing(&id));
+}
+
+#[spin_test]
+fn test_expired_suspension_leaves_good_standing() {
+    key_value::Store::open(DISTRICT);
+    let id = create_admitted_attorney("NY-DISC-2");
+    represent(&id, "case-disc-3");
+
+    let (status, body) = send(
+        Method::Post,
+        &format!("/api/attorneys/{}/disciplinary-actions", id),
+        Some(discipline("Suspension", "2023-01-01T00:00:00Z", Some("2023-07-01T00:00:00Z"))),
+    );
+
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body["attorney_status"], "Active");
+    assert_eq!(body["affected_cases"], json!([]));
+    assert!(in_good_standing(&id));
+}
+
+#[spin_test]
+fn test_reprimand_does_not_affect_standing() {
+    key_value::Store::open(DISTRICT);
+    let id = create_admitted_attorney("NY-DISC-3");
+
+    let (status, body) = send(
+        Method::Post,
+        &format!("/api/attorneys/{}/disciplinary-actions", id),
+        Some(discipline("Reprimand", "2024-06-01T00:00:00Z", None)),
+    );
+
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body["attorney_status"], "Active");
+    assert!(in_good_standing(&id));
+}
+
+#[spin_test]
+fn test_reinstatement_restores_disbarred_attorney() {
+    key_value::Store::open(DISTRICT);
+    let id = create_admitted_attorney("NY-DISC-4");
+    let path = format!("/api/attorneys/{}/reinstatement", id);
+    let order = json!({ "order_number": "R-2025-12", "jurisdiction": "New York" });
+
+    let (status, _) = send(Method::Post, &path, Some(order.clone()));
+    assert_eq!(status, 409, "An attorney in good standing can't be reinstated");
+
+    let (status, body) = send(
+        Method::Post,
+        &format!("/api/attorneys/{}/disciplinary-actions", id),
+        Some(discipline("Disbarment", "2024-02-01T00:00:00Z", None)),
+    );
+    assert_eq!(status, 200);
+    assert_eq!(body["attorney_status"], "Disbarred");
+    assert!(!in_good_standing(&id));
+
+    let (status, _) = send(Method::Post, &path, Some(json!({ "order_number": " ", "jurisdiction": "New York" })));
+    assert_eq!(status, 400, "The reinstating order is required");
+
+    let (status, attorney) = send(Method::Post, &path, Some(order));
+    assert_eq!(status, 200, "{}", attorney);
+    assert_eq!(attorney["status"], "Active");
+    let history = attorney["discipline_history"].as_array().unwrap();
+    assert_eq!(history.last().unwrap()["action_type"], "Reinstatement");
+    assert_eq!(history.last().unwrap()["case_number"], "R-2025-12");
+    assert!(in_good_standing(&id));
+}
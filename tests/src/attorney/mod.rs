@@ -3,13 +3,20 @@
 //! This module contains tests for attorney management endpoints
 
 // Core CRUD operations
+pub mod attorney_case_tests;
+pub mod cja_bulk_appoint_tests;
+pub mod cja_voucher_tests;
+pub mod conflict_check_tests;
+pub mod conflict_name_matching_tests;
+pub mod conflict_turnaround_tests;
+pub mod continuity_tests;
 pub mod create_attorney;
-pub mod get_attorney_by_id;
-pub mod update_attorney;
 pub mod delete_attorney;
+pub mod discipline_tests;
+pub mod get_attorney_by_id;
 pub mod list_attorneys;
-pub mod search_attorneys;
 pub mod pagination_tests;
-pub mod attorney_case_tests;
+pub mod pro_hac_vice_tests;
 pub mod representation_history_tests;
-pub mod conflict_check_tests;
\ No newline at end of file
+pub mod search_attorneys;
+pub mod update_attorney;
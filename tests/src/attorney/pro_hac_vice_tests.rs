@@ -0,0 +1,207 @@
+//! Pro hac vice expiration tests
+//!
+//! Tests that pro hac vice admissions require a sponsoring local attorney
+//! admitted to the court, expire after their term, can be renewed, and that
+//! the clerk's expiring list and case-specific practice check honor the
+//! expiration.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const COURT: &str = "S.D.N.Y.";
+const CASE_ID: &str = "case-phv-1";
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+fn create_attorney(last_name: &str, bar_number: &str) -> String {
+    let (status, attorney) = send(Method::Post, "/api/attorneys", Some(json!({
+        "bar_number": bar_number,
+        "first_name": "Counsel",
+        "last_name": last_name,
+        "email": format!("{}@example.com", last_name.to_lowercase()),
+        "phone": "555-0100",
+        "address": {
+            "street1": "500 Pearl St",
+            "city": "New York",
+            "state": "NY",
+            "zip_code": "10007",
+            "country": "US"
+        }
+    })));
+    assert_eq!(status, 200, "Attorney creation should succeed: {}", attorney);
+    attorney["id"].as_str().unwrap().to_string()
+}
+
+/// A local attorney admitted to the court, able to sponsor admissions
+fn create_local_sponsor() -> String {
+    let id = create_attorney("Local", "NY-LOCAL-1");
+    let (status, _) = send(Method::Post, &format!("/api/attorneys/{}/federal-admissions", id), Some(json!({
+        "court": COURT,
+        "admission_date": "2010-01-15T00:00:00Z",
+        "sponsor_attorney": null,
+        "status": "Active"
+    })));
+    assert_eq!(status, 204);
+    id
+}
+
+fn admission(sponsor_id: Option<&str>, admission_date: &str, expiration_date: Option<&str>) -> Value {
+    json!({
+        "case_id": CASE_ID,
+        "case_caption": "United States v. Doe",
+        "court": COURT,
+        "admission_date": admission_date,
+        "expiration_date": expiration_date,
+        "local_counsel": "Counsel Local",
+        "local_counsel_bar_number": "NY-LOCAL-1",
+        "sponsoring_local_attorney_id": sponsor_id,
+        "status": "Granted",
+        "fee_paid": true
+    })
+}
+
+fn iso_days_from_now(days: i64) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+    let secs = now + days * 86_400;
+    // Civil date from days since the epoch
+    let (z, time) = (secs.div_euclid(86_400) + 719_468, secs.rem_euclid(86_400));
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year, month, day, time / 3600, time % 3600 / 60, time % 60
+    )
+}
+
+#[spin_test]
+fn test_sponsor_must_be_admitted_to_the_court() {
+    let _store = key_value::Store::open(DISTRICT);
+    let visiting = create_attorney("Visiting", "CA-VISIT-1");
+    let unadmitted = create_attorney("Unadmitted", "NY-OTHER-1");
+    let path = format!("/api/attorneys/{}/pro-hac-vice", visiting);
+    let today = iso_days_from_now(0);
+
+    let (status, body) = send(Method::Post, &path, Some(admission(None, &today, None)));
+    assert_eq!(status, 400, "{}", body);
+
+    let (status, body) = send(Method::Post, &path, Some(admission(Some(&unadmitted), &today, None)));
+    assert_eq!(status, 400);
+    assert!(body["error"]["message"].as_str().unwrap().contains("not admitted"));
+
+    let sponsor = create_local_sponsor();
+    let (status, _) = send(Method::Post, &path, Some(admission(Some(&sponsor), &today, None)));
+    assert_eq!(status, 204);
+
+    // Without an expiration in the order, the admission runs one year
+    let (_, admissions) = send(Method::Get, &format!("/api/attorneys/pro-hac-vice/case/{}", CASE_ID), None);
+    let expires = admissions[0]["expiration_date"].as_str().unwrap();
+    assert_eq!(&expires[..4], &iso_days_from_now(365)[..4]);
+}
+
+#[spin_test]
+fn test_expired_admission_is_inactive_until_renewed() {
+    let _store = key_value::Store::open(DISTRICT);
+    let sponsor = create_local_sponsor();
+    let visiting = create_attorney("Visiting", "CA-VISIT-2");
+    let (status, _) = send(Method::Post, &format!("/api/attorneys/{}/pro-hac-vice", visiting), Some(admission(
+        Some(&sponsor),
+        &iso_days_from_now(-400),
+        Some(&iso_days_from_now(-35)),
+    )));
+    assert_eq!(status, 204);
+
+    let (_, active) = send(Method::Get, "/api/attorneys/pro-hac-vice/active", None);
+    assert!(active.as_array().unwrap().is_empty(), "Expired admission should not be active");
+    let practice_path = format!("/api/attorneys/{}/can-practice/{}?case_id={}", visiting, "S.D.N.Y.", CASE_ID);
+    let (_, can_practice) = send(Method::Get, &practice_path, None);
+    assert_eq!(can_practice, false);
+
+    let (status, renewed) = send(
+        Method::Post,
+        &format!("/api/attorneys/{}/pro-hac-vice/{}/renew", visiting, CASE_ID),
+        Some(json!({"fee_paid": true, "notes": "Annual renewal"})),
+    );
+    assert_eq!(status, 200, "{}", renewed);
+    assert_eq!(renewed["renewals"].as_array().unwrap().len(), 1);
+    assert_eq!(renewed["renewals"][0]["notes"], "Annual renewal");
+
+    let (_, active) = send(Method::Get, "/api/attorneys/pro-hac-vice/active", None);
+    assert_eq!(active.as_array().unwrap().len(), 1);
+    let (_, can_practice) = send(Method::Get, &practice_path, None);
+    assert_eq!(can_practice, true);
+
+    let (_, other_case) = send(Method::Get, &format!("/api/attorneys/{}/can-practice/S.D.N.Y.?case_id=case-phv-2", visiting), None);
+    assert_eq!(other_case, false);
+}
+
+#[spin_test]
+fn test_expiring_list_for_clerk() {
+    let _store = key_value::Store::open(DISTRICT);
+    let sponsor = create_local_sponsor();
+    let soon = create_attorney("Soon", "CA-SOON-1");
+    let later = create_attorney("Later", "CA-LATER-1");
+    let today = iso_days_from_now(0);
+
+    for (attorney, expires) in [(&soon, 10), (&later, 90)] {
+        let (status, _) = send(Method::Post, &format!("/api/attorneys/{}/pro-hac-vice", attorney), Some(admission(
+            Some(&sponsor),
+            &today,
+            Some(&iso_days_from_now(expires)),
+        )));
+        assert_eq!(status, 204);
+    }
+
+    let (status, expiring) = send(Method::Get, "/api/attorneys/pro-hac-vice/expiring?days=30", None);
+    assert_eq!(status, 200);
+    let expiring = expiring.as_array().unwrap();
+    assert_eq!(expiring.len(), 1);
+    assert_eq!(expiring[0]["attorney_id"], soon.as_str());
+    assert_eq!(expiring[0]["attorney_name"], "Counsel Soon");
+    assert!(expiring[0]["days_remaining"].as_i64().unwrap() >= 9);
+
+    let (_, expiring) = send(Method::Get, "/api/attorneys/pro-hac-vice/expiring?days=120", None);
+    assert_eq!(expiring.as_array().unwrap().len(), 2);
+
+    let (status, _) = send(Method::Post, "/api/attorneys/missing/pro-hac-vice/case-phv-1/renew", None);
+    assert_eq!(status, 404);
+}
@@ -111,16 +111,16 @@ fn test_get_representation_history_basic() {
 
 #[spin_test]
 fn test_get_representation_history_with_active_filter() {
-    let _store = key_value::Store::open("district10");
+    let _store = key_value::Store::open("district12");
 
-    let attorney_id = match setup_test_attorney("district10") {
+    let attorney_id = match setup_test_attorney("district12") {
         Some(id) => id,
         None => return,
     };
 
     let headers = Headers::new();
     headers
-        .append(&"X-Court-District".to_string(), b"district10")
+        .append(&"X-Court-District".to_string(), b"district12")
         .unwrap();
 
     let request = OutgoingRequest::new(headers);
@@ -148,16 +148,16 @@ fn test_get_representation_history_with_active_filter() {
 
 #[spin_test]
 fn test_get_representation_history_with_role_filter() {
-    let _store = key_value::Store::open("district11");
+    let _store = key_value::Store::open("district12");
 
-    let attorney_id = match setup_test_attorney("district11") {
+    let attorney_id = match setup_test_attorney("district12") {
         Some(id) => id,
         None => return,
     };
 
     let headers = Headers::new();
     headers
-        .append(&"X-Court-District".to_string(), b"district11")
+        .append(&"X-Court-District".to_string(), b"district12")
         .unwrap();
 
     let request = OutgoingRequest::new(headers);
@@ -220,16 +220,16 @@ fn test_get_representation_history_with_pagination() {
 
 #[spin_test]
 fn test_get_representation_history_assignment_structure() {
-    let _store = key_value::Store::open("district13");
+    let _store = key_value::Store::open("district12");
 
-    let attorney_id = match setup_test_attorney("district13") {
+    let attorney_id = match setup_test_attorney("district12") {
         Some(id) => id,
         None => return,
     };
 
     let headers = Headers::new();
     headers
-        .append(&"X-Court-District".to_string(), b"district13")
+        .append(&"X-Court-District".to_string(), b"district12")
         .unwrap();
 
     let request = OutgoingRequest::new(headers);
@@ -316,16 +316,16 @@ fn test_get_representation_history_nonexistent_attorney() {
 
 #[spin_test]
 fn test_get_representation_history_empty_page() {
-    let _store = key_value::Store::open("district15");
+    let _store = key_value::Store::open("district12");
 
-    let attorney_id = match setup_test_attorney("district15") {
+    let attorney_id = match setup_test_attorney("district12") {
         Some(id) => id,
         None => return,
     };
 
     let headers = Headers::new();
     headers
-        .append(&"X-Court-District".to_string(), b"district15")
+        .append(&"X-Court-District".to_string(), b"district12")
         .unwrap();
 
     let request = OutgoingRequest::new(headers);
@@ -355,16 +355,16 @@ fn test_get_representation_history_empty_page() {
 
 #[spin_test]
 fn test_get_representation_history_with_date_range() {
-    let _store = key_value::Store::open("district16");
+    let _store = key_value::Store::open("district12");
 
-    let attorney_id = match setup_test_attorney("district16") {
+    let attorney_id = match setup_test_attorney("district12") {
         Some(id) => id,
         None => return,
     };
 
     let headers = Headers::new();
     headers
-        .append(&"X-Court-District".to_string(), b"district16")
+        .append(&"X-Court-District".to_string(), b"district12")
         .unwrap();
 
     let request = OutgoingRequest::new(headers);
@@ -396,16 +396,16 @@ fn test_get_representation_history_with_date_range() {
 
 #[spin_test]
 fn test_get_representation_history_summary_statistics() {
-    let _store = key_value::Store::open("district17");
+    let _store = key_value::Store::open("district12");
 
-    let attorney_id = match setup_test_attorney("district17") {
+    let attorney_id = match setup_test_attorney("district12") {
         Some(id) => id,
         None => return,
     };
 
     let headers = Headers::new();
     headers
-        .append(&"X-Court-District".to_string(), b"district17")
+        .append(&"X-Court-District".to_string(), b"district12")
         .unwrap();
 
     let request = OutgoingRequest::new(headers);
@@ -446,16 +446,16 @@ fn test_get_representation_history_summary_statistics() {
 
 #[spin_test]
 fn test_get_representation_history_invalid_date_format() {
-    let _store = key_value::Store::open("district18");
+    let _store = key_value::Store::open("district12");
 
-    let attorney_id = match setup_test_attorney("district18") {
+    let attorney_id = match setup_test_attorney("district12") {
         Some(id) => id,
         None => return,
     };
 
     let headers = Headers::new();
     headers
-        .append(&"X-Court-District".to_string(), b"district18")
+        .append(&"X-Court-District".to_string(), b"district12")
         .unwrap();
 
     let request = OutgoingRequest::new(headers);
@@ -1,6 +1,7 @@
 //! Attorney UPDATE endpoint tests
 //!
-//! Tests for PUT /api/attorneys/{id} endpoint
+//! Tests for PATCH and PUT /api/attorneys/{id}, including that a PATCH from a
+//! stale copy cannot wipe bar admissions added in the meantime
 
 use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
 use http::types::{Headers, Method, OutgoingRequest};
@@ -47,19 +48,24 @@ fn create_test_attorney(district: &str) -> Value {
     serde_json::from_str(&body).unwrap()
 }
 
-/// Helper to make PUT request to update attorney
+/// Helper to make PATCH request to update attorney
 fn update_attorney_request(id: &str, updates: Value, district: &str) -> (u16, Value) {
+    send(Method::Patch, &format!("/api/attorneys/{}", id), updates, district)
+}
+
+/// Helper to send a JSON body and return status + response body
+fn send(method: Method, path: &str, body: Value, district: &str) -> (u16, Value) {
     let headers = Headers::new();
     headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
     headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
 
     let request = OutgoingRequest::new(headers);
-    request.set_method(&Method::Put).unwrap();
-    request.set_path_with_query(Some(&format!("/api/attorneys/{}", id))).unwrap();
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
 
     let request_body = request.body().unwrap();
     let stream = request_body.write().unwrap();
-    stream.blocking_write_and_flush(serde_json::to_string(&updates).unwrap().as_bytes()).unwrap();
+    stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
     drop(stream);
     http::types::OutgoingBody::finish(request_body, None).unwrap();
 
@@ -235,4 +241,72 @@ fn test_update_attorney_change_bar_number_to_duplicate() {
         status == 409 || status == 400,
         "Should return 409 or 400 for duplicate bar number, got {}", status
     );
-}
\ No newline at end of file
+}
+#[spin_test]
+fn test_patch_from_stale_copy_keeps_new_bar_admission() {
+    let _store = key_value::Store::open("district9");
+
+    // Client A fetches the attorney before any admissions exist
+    let stale = create_test_attorney("district9");
+    let attorney_id = stale["id"].as_str().unwrap();
+    assert_eq!(stale["bar_admissions"], json!([]));
+
+    // Client B adds a bar admission
+    let (status, _) = send(Method::Post, &format!("/api/attorneys/{}/bar-admissions", attorney_id), json!({
+        "state": "NY",
+        "bar_number": "NY-778899",
+        "admission_date": "2015-06-01T00:00:00Z",
+        "status": "Active",
+        "expiration_date": null
+    }), "district9");
+    assert_eq!(status, 204, "Bar admission should be added");
+
+    // Client A saves its phone change; only the changed field is sent
+    let (status, response) = update_attorney_request(attorney_id, json!({"phone": "555-2222"}), "district9");
+    assert_eq!(status, 200);
+    assert_eq!(response["phone"], "555-2222");
+    assert_eq!(response["bar_admissions"].as_array().unwrap().len(), 1, "PATCH must not wipe the admission");
+
+    // Collection fields cannot be patched at all
+    let (status, _) = update_attorney_request(attorney_id, json!({"bar_admissions": []}), "district9");
+    assert_eq!(status, 400);
+}
+
+#[spin_test]
+fn test_patch_null_clears_optional_field() {
+    let _store = key_value::Store::open("district9");
+
+    let attorney = create_test_attorney("district9");
+    let attorney_id = attorney["id"].as_str().unwrap();
+
+    let (_, response) = update_attorney_request(attorney_id, json!({"middle_name": "Middle", "firm_name": "Firm LLP"}), "district9");
+    assert_eq!(response["middle_name"], "Middle");
+
+    let (status, response) = update_attorney_request(attorney_id, json!({"middle_name": null}), "district9");
+    assert_eq!(status, 200);
+    assert_eq!(response["middle_name"], json!(null), "null should clear middle_name");
+    assert_eq!(response["firm_name"], "Firm LLP", "Absent fields should be kept");
+}
+
+#[spin_test]
+fn test_put_requires_collections_unless_forced() {
+    let _store = key_value::Store::open("district9");
+
+    let attorney = create_test_attorney("district9");
+    let attorney_id = attorney["id"].as_str().unwrap();
+    let path = format!("/api/attorneys/{}", attorney_id);
+
+    let (status, response) = send(Method::Put, &path, json!({"first_name": "Partial"}), "district9");
+    assert_eq!(status, 400, "Partial PUT should be rejected: {:?}", response);
+    assert!(response.to_string().contains("bar_admissions"));
+
+    let (status, response) = send(Method::Put, &format!("{}?force=true", path), json!({"first_name": "Forced"}), "district9");
+    assert_eq!(status, 200);
+    assert_eq!(response["first_name"], "Forced");
+
+    let mut full = attorney.clone();
+    full["first_name"] = json!("Replaced");
+    let (status, response) = send(Method::Put, &path, full, "district9");
+    assert_eq!(status, 200, "A full record should replace: {:?}", response);
+    assert_eq!(response["first_name"], "Replaced");
+}
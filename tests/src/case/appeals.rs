@@ -0,0 +1,199 @@
+//! Notice of appeal tests
+//!
+//! Tests that POST /api/cases/:id/appeals records the notice with its FRAP
+//! 4(b) deadline, dockets it, and schedules the record and briefing
+//! deadlines; that a pending appeal blocks deleting and archiving the case;
+//! and that PATCH /api/appeals/:id/status only moves forward, with the
+//! mandate clearing the pending flag.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const JUDGMENT: &str = "2099-06-01T15:00:00Z";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case(title: &str) -> String {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": title,
+        "description": "Case for appeal tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })));
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    case["id"].as_str().unwrap().to_string()
+}
+
+fn file_appeal(case_id: &str, filing_party: &str, filed_date: &str) -> (u16, Value) {
+    send(Method::Post, &format!("/api/cases/{}/appeals", case_id), Some(json!({
+        "filingParty": filing_party,
+        "filedBy": "Counsel of record",
+        "filedDate": filed_date,
+        "appealedFrom": "Judgment (ECF No. 88)",
+        "judgmentEnteredDate": JUDGMENT
+    })))
+}
+
+fn set_status(appeal_id: &str, status: &str) -> (u16, Value) {
+    send(Method::Patch, &format!("/api/appeals/{}/status", appeal_id), Some(json!({"status": status})))
+}
+
+fn get_case(case_id: &str) -> Value {
+    let (status, case) = send(Method::Get, &format!("/api/cases/{}", case_id), None);
+    assert_eq!(status, 200, "{}", case);
+    case
+}
+
+fn deadlines_of_type(case_id: &str, deadline_type: &str) -> Vec<Value> {
+    let (status, deadlines) = send(Method::Get, &format!("/api/deadlines/case/{}", case_id), None);
+    assert_eq!(status, 200, "{}", deadlines);
+    deadlines
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["deadline_type"] == deadline_type)
+        .cloned()
+        .collect()
+}
+
+#[spin_test]
+fn test_notice_schedules_deadlines_and_dockets() {
+    let _store = key_value::Store::open(DISTRICT);
+    let case_id = create_case("United States v. Appellant");
+    let entries_before = get_case(&case_id)["docketEntriesCount"].as_u64().unwrap();
+
+    let (status, appeal) = file_appeal(&case_id, "defendant", "2099-06-10T16:00:00Z");
+    assert_eq!(status, 201, "{}", appeal);
+    assert_eq!(appeal["applicable_rule"], "FRAP 4(b)(1)(A)");
+    assert_eq!(appeal["notice_deadline"], "2099-06-15T15:00:00Z");
+    assert_eq!(appeal["timely"], true);
+    assert_eq!(appeal["status"], "filed");
+    assert!(appeal["docket_entry_id"].is_string());
+
+    let case = get_case(&case_id);
+    assert_eq!(case["docketEntriesCount"].as_u64().unwrap(), entries_before + 1);
+    assert_eq!(case["hasPendingAppeal"], true);
+
+    // Record due 30 days after the notice; briefs chain off it
+    let record = deadlines_of_type(&case_id, "record_on_appeal");
+    assert_eq!(record.len(), 1);
+    assert_eq!(record[0]["due_date"], "2099-07-10T16:00:00Z");
+    assert_eq!(record[0]["applicable_rule"], "FRAP 11(b)");
+
+    let briefs = deadlines_of_type(&case_id, "appellate_brief");
+    assert_eq!(briefs.len(), 2);
+    let opening = briefs.iter().find(|d| d["responsible_party"] == "Defendant").unwrap();
+    assert_eq!(opening["depends_on"], record[0]["id"]);
+    assert_eq!(opening["offset_days"], 40);
+    assert!(briefs.iter().any(|d| d["responsible_party"] == "Government"));
+    assert_eq!(deadlines_of_type(&case_id, "reply_brief").len(), 1);
+
+    let ids: Vec<&Value> = appeal["deadline_ids"].as_array().unwrap().iter().collect();
+    assert_eq!(ids.len(), 4);
+
+    let (status, appeals) = send(Method::Get, &format!("/api/cases/{}/appeals", case_id), None);
+    assert_eq!(status, 200);
+    assert_eq!(appeals.as_array().unwrap().len(), 1);
+    assert_eq!(appeals[0]["id"], appeal["id"]);
+}
+
+#[spin_test]
+fn test_filing_party_sets_notice_period() {
+    let _store = key_value::Store::open(DISTRICT);
+    let case_id = create_case("United States v. Sentenced");
+
+    // Three weeks out is late for a defendant but timely for the government
+    let (status, defendant) = file_appeal(&case_id, "defendant", "2099-06-22T15:00:00Z");
+    assert_eq!(status, 201, "{}", defendant);
+    assert_eq!(defendant["timely"], false);
+
+    let (status, government) = file_appeal(&case_id, "government", "2099-06-22T15:00:00Z");
+    assert_eq!(status, 201, "{}", government);
+    assert_eq!(government["applicable_rule"], "FRAP 4(b)(1)(B)");
+    assert_eq!(government["timely"], true);
+    // The defendant's notice restarts the government's 30 days
+    assert_eq!(government["notice_deadline"], "2099-07-22T15:00:00Z");
+}
+
+#[spin_test]
+fn test_pending_appeal_blocks_delete_until_mandate() {
+    let _store = key_value::Store::open(DISTRICT);
+    let case_id = create_case("United States v. Pending Appeal");
+    let (status, appeal) = file_appeal(&case_id, "defendant", "2099-06-08T15:00:00Z");
+    assert_eq!(status, 201, "{}", appeal);
+    let appeal_id = appeal["id"].as_str().unwrap();
+
+    let (status, _) = send(Method::Delete, &format!("/api/cases/{}", case_id), None);
+    assert_eq!(status, 409);
+    let (status, _) = send(Method::Post, &format!("/api/cases/{}/archive", case_id), None);
+    assert_eq!(status, 409);
+
+    for next in ["docketed", "briefed", "decided"] {
+        let (status, updated) = set_status(appeal_id, next);
+        assert_eq!(status, 200, "{}", updated);
+        assert_eq!(updated["status"], next);
+    }
+    assert_eq!(get_case(&case_id)["hasPendingAppeal"], true);
+
+    let (status, _) = set_status(appeal_id, "briefed");
+    assert_eq!(status, 409);
+
+    let (status, updated) = set_status(appeal_id, "mandate_issued");
+    assert_eq!(status, 200, "{}", updated);
+    assert_eq!(updated["status_history"].as_array().unwrap().len(), 4);
+    assert_eq!(get_case(&case_id)["hasPendingAppeal"], false);
+
+    let (status, _) = send(Method::Delete, &format!("/api/cases/{}", case_id), None);
+    assert_eq!(status, 204);
+}
+
+#[spin_test]
+fn test_invalid_appeal_requests() {
+    let _store = key_value::Store::open(DISTRICT);
+    let case_id = create_case("United States v. Invalid");
+
+    let (status, _) = file_appeal(&case_id, "defendant", "June 10");
+    assert_eq!(status, 400);
+
+    let (status, _) = file_appeal(&case_id, "defendant", "2099-05-01T15:00:00Z");
+    assert_eq!(status, 400, "Notice cannot precede the judgment");
+
+    let (status, _) = send(Method::Post, &format!("/api/cases/{}/appeals", case_id), Some(json!({
+        "filingParty": "defendant",
+        "filedDate": "2099-06-10T15:00:00Z",
+        "appealedFrom": "  ",
+        "judgmentEnteredDate": JUDGMENT
+    })));
+    assert_eq!(status, 400);
+
+    let (status, _) = file_appeal("00000000-0000-4000-8000-000000000000", "defendant", "2099-06-10T15:00:00Z");
+    assert_eq!(status, 404);
+
+    let (status, _) = set_status("00000000-0000-4000-8000-000000000000", "docketed");
+    assert_eq!(status, 404);
+}
@@ -0,0 +1,134 @@
+//! Docket attachment size tests
+//!
+//! Tests that POST /api/docket/entries/:entry_id/attachments rejects zero
+//! sizes and page counts and those over the district's limits, and that
+//! GET /api/cases/:case_id/attachment-size totals the attachments across the
+//! case's docket.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case() -> String {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. Exhibits",
+        "description": "Case for attachment size tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })));
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    case["id"].as_str().unwrap().to_string()
+}
+
+fn file_entry(case_id: &str) -> String {
+    let (status, entry) = send(Method::Post, "/api/docket/entries", Some(json!({
+        "case_id": case_id,
+        "entry_type": "motion",
+        "description": "Motion to suppress",
+        "filed_by": "Defense Counsel",
+        "is_sealed": false,
+        "is_ex_parte": false,
+        "page_count": null,
+        "service_list": []
+    })));
+    assert_eq!(status, 201, "Entry should be filed: {}", entry);
+    entry["id"].as_str().unwrap().to_string()
+}
+
+fn attach(entry_id: &str, number: u32, page_count: u64, file_size_bytes: u64) -> (u16, Value) {
+    send(
+        Method::Post,
+        &format!("/api/docket/entries/{}/attachments", entry_id),
+        Some(json!({
+            "attachment_number": number,
+            "description": format!("Exhibit {}", number),
+            "page_count": page_count,
+            "file_size_bytes": file_size_bytes
+        })),
+    )
+}
+
+#[spin_test]
+fn test_attachment_size_and_pages_are_bounded() {
+    let _store = key_value::Store::open(DISTRICT);
+    let entry_id = file_entry(&create_case());
+
+    let (status, error) = attach(&entry_id, 1, 10, 0);
+    assert_eq!(status, 400, "{}", error);
+    assert_eq!(error["error"]["details"]["field"], "file_size_bytes");
+
+    let (status, error) = attach(&entry_id, 1, 0, 2048);
+    assert_eq!(status, 400, "{}", error);
+    assert_eq!(error["error"]["details"]["field"], "page_count");
+
+    // The district allows 25 MB and 500 pages by default
+    let (status, error) = attach(&entry_id, 1, 10, 25 * 1024 * 1024 + 1);
+    assert_eq!(status, 400, "{}", error);
+    assert_eq!(error["error"]["details"]["max"], 25 * 1024 * 1024);
+
+    let (status, error) = attach(&entry_id, 1, 501, 2048);
+    assert_eq!(status, 400, "{}", error);
+    assert_eq!(error["error"]["details"]["max"], 500);
+
+    let (status, attachment) = attach(&entry_id, 1, 500, 25 * 1024 * 1024);
+    assert_eq!(status, 201, "{}", attachment);
+}
+
+#[spin_test]
+fn test_case_attachment_size_totals_the_docket() {
+    let _store = key_value::Store::open(DISTRICT);
+    let case_id = create_case();
+    let path = format!("/api/cases/{}/attachment-size", case_id);
+
+    let (status, empty) = send(Method::Get, &path, None);
+    assert_eq!(status, 200, "{}", empty);
+    assert_eq!(empty["attachment_count"], 0);
+    assert_eq!(empty["total_bytes"], 0);
+
+    let motion = file_entry(&case_id);
+    let reply = file_entry(&case_id);
+    assert_eq!(attach(&motion, 1, 12, 40_000).0, 201);
+    assert_eq!(attach(&motion, 2, 3, 9_000).0, 201);
+    assert_eq!(attach(&reply, 1, 5, 1_000).0, 201);
+    // Rejected attachments are not counted
+    assert_eq!(attach(&reply, 2, 0, 1_000).0, 400);
+
+    let (status, size) = send(Method::Get, &path, None);
+    assert_eq!(status, 200, "{}", size);
+    assert_eq!(size["case_id"], case_id.as_str());
+    assert_eq!(size["attachment_count"], 3);
+    assert_eq!(size["total_bytes"], 50_000);
+    assert_eq!(size["total_pages"], 20);
+
+    let (status, _) = send(Method::Get, "/api/cases/00000000-0000-4000-8000-000000000000/attachment-size", None);
+    assert_eq!(status, 404);
+    let (status, _) = send(Method::Get, "/api/cases/not-a-uuid/attachment-size", None);
+    assert_eq!(status, 400);
+}
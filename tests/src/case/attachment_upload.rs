@@ -0,0 +1,190 @@
+//! Docket attachment upload tests
+//!
+//! Tests that POST /api/docket/entries/:entry_id/attachments/upload stores
+//! the file bytes with a SHA-256 digest, rejects non-PDF uploads unless
+//! `allow_non_pdf=true`, and that GET /api/docket/attachments/:id/download
+//! returns the same bytes under the entry's access restrictions.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const CASE_ID: &str = "3b8f2a61-5c4d-4e7a-9f10-2d6c8e4b1a73";
+const PDF: &[u8] = b"%PDF-1.7\n1 0 obj << /Type /Catalog >> endobj\n%%EOF\n";
+const PDF_SHA256: &str = "ee2d14b09b1f174f566d039e4d1c5d6e250feb812d9aa4ca2e595550a2548461";
+
+/// Response status, selected headers and raw body
+struct RawResponse {
+    status: u16,
+    content_type: String,
+    disposition: String,
+    digest: String,
+    body: Vec<u8>,
+}
+
+/// Helper to send a request with the given headers and raw body
+fn send(method: Method, path: &str, headers: &[(&str, &str)], body: Option<&[u8]>) -> RawResponse {
+    let request_headers = Headers::new();
+    request_headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    for (name, value) in headers {
+        request_headers.append(&name.to_string(), value.as_bytes()).unwrap();
+    }
+
+    let request = OutgoingRequest::new(request_headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(data).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(&name.to_string())
+            .first()
+            .map(|v| String::from_utf8_lossy(v).to_string())
+            .unwrap_or_default()
+    };
+    RawResponse {
+        status: response.status(),
+        content_type: header("content-type"),
+        disposition: header("content-disposition"),
+        digest: header("x-content-sha256"),
+        body: response.body().unwrap_or_default(),
+    }
+}
+
+fn json_body(response: &RawResponse) -> Value {
+    serde_json::from_slice(&response.body).unwrap_or(json!(null))
+}
+
+fn file_entry(is_sealed: bool) -> String {
+    let body = serde_json::to_vec(&json!({
+        "case_id": CASE_ID,
+        "entry_type": "motion",
+        "description": "Motion to suppress",
+        "filed_by": "Defense Counsel",
+        "is_sealed": is_sealed,
+        "is_ex_parte": false,
+        "page_count": null,
+        "service_list": []
+    }))
+    .unwrap();
+    let response = send(Method::Post, "/api/docket/entries", &[("Content-Type", "application/json")], Some(&body));
+    assert_eq!(response.status, 201, "Entry should be filed");
+    json_body(&response)["id"].as_str().unwrap().to_string()
+}
+
+fn upload(entry_id: &str, query: &str, content_type: &str, content: &[u8]) -> RawResponse {
+    send(
+        Method::Post,
+        &format!("/api/docket/entries/{}/attachments/upload{}", entry_id, query),
+        &[
+            ("Content-Type", content_type),
+            ("X-Attachment-Description", "Exhibit A - Search warrant"),
+            ("X-Page-Count", "1"),
+            ("X-File-Name", "exhibit-a.pdf"),
+        ],
+        Some(content),
+    )
+}
+
+#[spin_test]
+fn test_upload_and_download_round_trip() {
+    let _store = key_value::Store::open(DISTRICT);
+    let entry_id = file_entry(false);
+
+    let response = upload(&entry_id, "", "application/pdf", PDF);
+    assert_eq!(response.status, 201);
+    let attachment = json_body(&response);
+    assert_eq!(attachment["attachment_number"], 1);
+    assert_eq!(attachment["file_size_bytes"], PDF.len());
+    assert_eq!(attachment["sha256"], PDF_SHA256);
+    assert_eq!(attachment["content_type"], "application/pdf");
+
+    let download = send(
+        Method::Get,
+        &format!("/api/docket/attachments/{}/download", attachment["id"].as_str().unwrap()),
+        &[],
+        None,
+    );
+    assert_eq!(download.status, 200);
+    assert_eq!(download.body, PDF);
+    assert_eq!(download.content_type, "application/pdf");
+    assert_eq!(download.disposition, r#"attachment; filename="exhibit-a.pdf""#);
+    assert_eq!(download.digest, PDF_SHA256);
+
+    let entry = send(Method::Get, &format!("/api/docket/entries/{}", entry_id), &[], None);
+    assert_eq!(json_body(&entry)["attachments"][0]["sha256"], PDF_SHA256);
+}
+
+#[spin_test]
+fn test_non_pdf_requires_override() {
+    let _store = key_value::Store::open(DISTRICT);
+    let entry_id = file_entry(false);
+
+    let rejected = upload(&entry_id, "", "application/pdf", b"Not really a PDF");
+    assert_eq!(rejected.status, 415);
+    assert_eq!(json_body(&rejected)["error"]["code"], "UNSUPPORTED_MEDIA_TYPE");
+
+    let rejected = upload(&entry_id, "", "text/plain", b"Transcript excerpt");
+    assert_eq!(rejected.status, 415);
+
+    let accepted = upload(&entry_id, "?allow_non_pdf=true", "text/plain", b"Transcript excerpt");
+    assert_eq!(accepted.status, 201);
+    assert_eq!(json_body(&accepted)["content_type"], "text/plain");
+}
+
+#[spin_test]
+fn test_upload_validation() {
+    let _store = key_value::Store::open(DISTRICT);
+    let entry_id = file_entry(false);
+
+    let missing_description = send(
+        Method::Post,
+        &format!("/api/docket/entries/{}/attachments/upload", entry_id),
+        &[("Content-Type", "application/pdf")],
+        Some(PDF),
+    );
+    assert_eq!(missing_description.status, 400);
+
+    let missing_entry = upload("00000000-0000-4000-8000-000000000000", "", "application/pdf", PDF);
+    assert_eq!(missing_entry.status, 404);
+
+    assert_eq!(upload(&entry_id, "", "application/pdf", PDF).status, 201);
+    let duplicate = send(
+        Method::Post,
+        &format!("/api/docket/entries/{}/attachments/upload", entry_id),
+        &[
+            ("Content-Type", "application/pdf"),
+            ("X-Attachment-Description", "Exhibit A"),
+            ("X-Attachment-Number", "1"),
+        ],
+        Some(PDF),
+    );
+    assert_eq!(duplicate.status, 409);
+}
+
+#[spin_test]
+fn test_sealed_attachment_download_requires_access() {
+    let _store = key_value::Store::open(DISTRICT);
+    let entry_id = file_entry(true);
+
+    let attachment = json_body(&upload(&entry_id, "", "application/pdf", PDF));
+    let path = format!("/api/docket/attachments/{}/download", attachment["id"].as_str().unwrap());
+
+    assert_eq!(send(Method::Get, &path, &[], None).status, 403);
+    let authorized = send(Method::Get, &path, &[("X-Access-Level", "sealed-authorized")], None);
+    assert_eq!(authorized.status, 200);
+    assert_eq!(authorized.body, PDF);
+
+    let missing = send(Method::Get, "/api/docket/attachments/00000000-0000-4000-8000-000000000000/download", &[], None);
+    assert_eq!(missing.status, 404);
+}
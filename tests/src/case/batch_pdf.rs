@@ -0,0 +1,111 @@
+//! Batch PDF generation tests
+//!
+//! Tests that POST /api/pdf/batch generates each document independently,
+//! reports documents that fail next to the ones that were generated, and
+//! keeps the all-or-nothing behaviour when `fail_fast` is set.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to send a batch request and return the status and JSON body
+fn send(body: Value) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&Method::Post).unwrap();
+    request.set_path_with_query(Some("/api/pdf/batch")).unwrap();
+
+    let request_body = request.body().unwrap();
+    let stream = request_body.write().unwrap();
+    stream
+        .blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes())
+        .unwrap();
+    drop(stream);
+    http::types::OutgoingBody::finish(request_body, None).unwrap();
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body().unwrap_or_default();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+fn waiver(case_number: &str) -> Value {
+    json!({
+        "type": "waiver_indictment",
+        "case_number": case_number,
+        "defendant_name": "John Doe",
+        "charges": "18 U.S.C. 1343 - Wire Fraud"
+    })
+}
+
+#[spin_test]
+fn test_batch_reports_invalid_document_alongside_generated_ones() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let (status, body) = send(json!({
+        "documents": [waiver("1:24-cr-00301"), waiver(""), waiver("1:24-cr-00303")]
+    }));
+    assert_eq!(status, 200, "One bad document should not fail the batch: {}", body);
+    assert_eq!(body["total_generated"], 2);
+    assert_eq!(body["total_failed"], 1);
+
+    let cases: Vec<&str> = body["documents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d["case_number"].as_str().unwrap())
+        .collect();
+    assert_eq!(cases, vec!["1:24-cr-00301", "1:24-cr-00303"]);
+
+    let failure = &body["failures"][0];
+    assert_eq!(failure["index"], 1);
+    assert_eq!(failure["case_number"], "");
+    assert_eq!(failure["code"], "INVALID_CASE_NUMBER");
+
+    let statuses: Vec<&str> = body["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["status"].as_str().unwrap())
+        .collect();
+    assert_eq!(statuses, vec!["generated", "failed", "generated"]);
+    assert!(body["results"][1].get("document_id").is_none());
+}
+
+#[spin_test]
+fn test_batch_with_nothing_generated_is_rejected() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let (status, body) = send(json!({ "documents": [waiver("")] }));
+    assert_eq!(status, 422);
+    assert_eq!(body["error"]["details"]["failures"][0]["code"], "INVALID_CASE_NUMBER");
+
+    let (status, _) = send(json!({ "documents": [] }));
+    assert_eq!(status, 400);
+    let (status, _) = send(json!({ "documents": "not a list" }));
+    assert_eq!(status, 400);
+}
+
+#[spin_test]
+fn test_fail_fast_rejects_the_whole_batch() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let (status, _) = send(json!({
+        "documents": [waiver("1:24-cr-00401"), waiver("")],
+        "fail_fast": true
+    }));
+    assert_eq!(status, 400);
+
+    let (status, body) = send(json!({
+        "documents": [waiver("1:24-cr-00401"), waiver("1:24-cr-00402")],
+        "fail_fast": true
+    }));
+    assert_eq!(status, 200);
+    assert_eq!(body["total_generated"], 2);
+    assert_eq!(body["total_failed"], 0);
+}
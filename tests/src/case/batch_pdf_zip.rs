@@ -0,0 +1,139 @@
+//! Batch PDF ZIP archive tests
+//!
+//! Tests that POST /api/pdf/batch/zip returns an `application/zip` archive
+//! holding one PDF per generated document, lists documents that fail in an
+//! `errors.txt` entry, and rejects a batch where nothing can be generated.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Response status, selected headers and raw body
+struct RawResponse {
+    status: u16,
+    content_type: String,
+    batch_errors: String,
+    body: Vec<u8>,
+}
+
+/// Helper to send a batch request and return the raw response
+fn send(documents: Value) -> RawResponse {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&Method::Post).unwrap();
+    request.set_path_with_query(Some("/api/pdf/batch/zip")).unwrap();
+
+    let request_body = request.body().unwrap();
+    let stream = request_body.write().unwrap();
+    stream
+        .blocking_write_and_flush(serde_json::to_string(&json!({ "documents": documents })).unwrap().as_bytes())
+        .unwrap();
+    drop(stream);
+    http::types::OutgoingBody::finish(request_body, None).unwrap();
+
+    let response = spin_test_sdk::perform_request(request);
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(&name.to_string())
+            .first()
+            .map(|v| String::from_utf8_lossy(v).to_string())
+            .unwrap_or_default()
+    };
+    RawResponse {
+        status: response.status(),
+        content_type: header("content-type"),
+        batch_errors: header("x-batch-errors"),
+        body: response.body().unwrap_or_default(),
+    }
+}
+
+fn waiver(case_number: &str) -> Value {
+    json!({
+        "type": "waiver_indictment",
+        "case_number": case_number,
+        "defendant_name": "John Doe",
+        "charges": "18 U.S.C. 1343 - Wire Fraud"
+    })
+}
+
+fn read_u16(bytes: &[u8], at: usize) -> usize {
+    u16::from_le_bytes([bytes[at], bytes[at + 1]]) as usize
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> usize {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]]) as usize
+}
+
+/// Entry names from the archive's central directory
+fn zip_entry_names(archive: &[u8]) -> Vec<String> {
+    let end = (0..=archive.len() - 22)
+        .rev()
+        .find(|&i| archive[i..i + 4] == [0x50, 0x4b, 0x05, 0x06])
+        .expect("ZIP end of central directory record");
+    let count = read_u16(archive, end + 10);
+    let mut at = read_u32(archive, end + 16);
+
+    let mut names = Vec::new();
+    for _ in 0..count {
+        assert_eq!(archive[at..at + 4], [0x50, 0x4b, 0x01, 0x02], "central directory header");
+        let name_len = read_u16(archive, at + 28);
+        let extra_len = read_u16(archive, at + 30);
+        let comment_len = read_u16(archive, at + 32);
+        names.push(String::from_utf8(archive[at + 46..at + 46 + name_len].to_vec()).unwrap());
+        at += 46 + name_len + extra_len + comment_len;
+    }
+    names
+}
+
+#[spin_test]
+fn test_batch_zip_holds_each_pdf() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let response = send(json!([waiver("1:24-cr-00101"), waiver("1:24-cr-00102"), waiver("1:24-cr-00103")]));
+    assert_eq!(response.status, 200);
+    assert_eq!(response.content_type, "application/zip");
+    assert_eq!(response.batch_errors, "0");
+    assert_eq!(&response.body[..4], b"PK\x03\x04");
+
+    assert_eq!(
+        zip_entry_names(&response.body),
+        vec![
+            "waiver-indictment-1:24-cr-00101.pdf",
+            "waiver-indictment-1:24-cr-00102.pdf",
+            "waiver-indictment-1:24-cr-00103.pdf",
+        ]
+    );
+}
+
+#[spin_test]
+fn test_batch_zip_lists_failures_in_errors_txt() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let response = send(json!([waiver("1:24-cr-00201"), waiver(""), waiver("1:24-cr-00201")]));
+    assert_eq!(response.status, 200);
+    assert_eq!(response.batch_errors, "1");
+
+    let names = zip_entry_names(&response.body);
+    assert_eq!(names.len(), 3);
+    assert_eq!(names[0], "waiver-indictment-1:24-cr-00201.pdf");
+    assert_eq!(names[1], "waiver-indictment-1:24-cr-00201-2.pdf", "Duplicate filenames should not collide");
+    assert_eq!(names[2], "errors.txt");
+}
+
+#[spin_test]
+fn test_batch_zip_rejects_batch_with_nothing_generated() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let response = send(json!([waiver("")]));
+    assert_eq!(response.status, 422);
+    let body: Value = serde_json::from_slice(&response.body).unwrap();
+    assert_eq!(body["error"]["details"]["errors"][0], "document 1: Invalid case number");
+
+    assert_eq!(send(json!([])).status, 400);
+}
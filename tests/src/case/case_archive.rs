@@ -0,0 +1,151 @@
+//! Case archival tests
+//!
+//! Tests that POST /api/cases/:id/archive only accepts closed cases, that an
+//! archived case answers GET /api/cases/:id with 410 Gone pointing at the
+//! archive and drops out of search, and that POST /api/cases/:id/unarchive
+//! restores the case with its docket entries intact.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case(title: &str) -> (String, String) {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": title,
+        "description": "Case created for archival testing",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })));
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    (case["id"].as_str().unwrap().to_string(), case["caseNumber"].as_str().unwrap().to_string())
+}
+
+fn file_entry(case_id: &str, description: &str) {
+    let (status, entry) = send(Method::Post, "/api/docket/entries", Some(json!({
+        "case_id": case_id,
+        "entry_type": "motion",
+        "description": description,
+        "filed_by": "Defense Counsel",
+        "is_sealed": false,
+        "is_ex_parte": false,
+        "page_count": null,
+        "service_list": []
+    })));
+    assert_eq!(status, 201, "Entry should be filed: {}", entry);
+}
+
+fn dismiss(case_id: &str) {
+    let (status, _) = send(Method::Patch, &format!("/api/cases/{}/status", case_id), Some(json!({"status": "dismissed"})));
+    assert_eq!(status, 200);
+}
+
+fn docket_descriptions(case_id: &str) -> Vec<String> {
+    let (status, entries) = send(Method::Get, &format!("/api/docket/case/{}", case_id), None);
+    assert_eq!(status, 200);
+    let mut descriptions: Vec<String> = entries
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["description"].as_str().unwrap().to_string())
+        .collect();
+    descriptions.sort();
+    descriptions
+}
+
+#[spin_test]
+fn test_archive_and_unarchive_round_trip() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (case_id, case_number) = create_case("Archive Round Trip");
+    file_entry(&case_id, "Indictment");
+    file_entry(&case_id, "Motion to dismiss");
+    dismiss(&case_id);
+
+    let (status, archive) = send(Method::Post, &format!("/api/cases/{}/archive", case_id), None);
+    assert_eq!(status, 200, "Dismissed case should archive: {}", archive);
+    assert_eq!(archive["case_number"], case_number.as_str());
+    assert_eq!(archive["docket_entries"].as_array().unwrap().len(), 2);
+
+    let (status, gone) = send(Method::Get, &format!("/api/cases/{}", case_id), None);
+    assert_eq!(status, 410);
+    assert_eq!(gone["error"]["code"], "GONE");
+    assert_eq!(gone["error"]["details"]["archive_url"], format!("/api/cases/{}/archive", case_id).as_str());
+
+    let (status, search) = send(Method::Get, "/api/cases?status=dismissed", None);
+    assert_eq!(status, 200);
+    assert!(
+        search["cases"].as_array().unwrap().iter().all(|c| c["id"] != case_id.as_str()),
+        "Archived case should not appear in search"
+    );
+    assert!(docket_descriptions(&case_id).is_empty(), "Live docket should be cleared");
+
+    let (status, stored) = send(Method::Get, &format!("/api/cases/{}/archive", case_id), None);
+    assert_eq!(status, 200);
+    assert_eq!(stored["docket_entries"].as_array().unwrap().len(), 2);
+
+    let (status, restored) = send(Method::Post, &format!("/api/cases/{}/unarchive", case_id), None);
+    assert_eq!(status, 200, "Archive should restore: {}", restored);
+    assert_eq!(restored["status"], "dismissed");
+
+    let (status, _) = send(Method::Get, &format!("/api/cases/{}", case_id), None);
+    assert_eq!(status, 200);
+    assert_eq!(docket_descriptions(&case_id), vec!["Indictment", "Motion to dismiss"]);
+
+    let (status, _) = send(Method::Get, &format!("/api/cases/{}/archive", case_id), None);
+    assert_eq!(status, 404, "Archive should be removed after restoring");
+}
+
+#[spin_test]
+fn test_archive_requires_closed_case() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (case_id, _) = create_case("Active Case");
+
+    let (status, _) = send(Method::Post, &format!("/api/cases/{}/archive", case_id), None);
+    assert_eq!(status, 400, "Active case should not archive");
+
+    let (status, _) = send(Method::Get, &format!("/api/cases/{}", case_id), None);
+    assert_eq!(status, 200);
+
+    let (status, _) = send(Method::Post, "/api/cases/00000000-0000-4000-8000-000000000000/archive", None);
+    assert_eq!(status, 404);
+    let (status, _) = send(Method::Post, "/api/cases/00000000-0000-4000-8000-000000000000/unarchive", None);
+    assert_eq!(status, 404);
+}
+
+#[spin_test]
+fn test_archive_twice_conflicts() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (case_id, _) = create_case("Archive Twice");
+    dismiss(&case_id);
+
+    let (status, _) = send(Method::Post, &format!("/api/cases/{}/archive", case_id), None);
+    assert_eq!(status, 200);
+    let (status, _) = send(Method::Post, &format!("/api/cases/{}/archive", case_id), None);
+    assert_eq!(status, 409);
+}
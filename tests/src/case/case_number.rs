@@ -0,0 +1,185 @@
+//! Case number generation tests
+//!
+//! Tests that POST /api/cases numbers cases without a caseNumber from the
+//! district's sequence, keeps a supplied caseNumber, and follows the format
+//! set through /api/config/case-number-format. Supplied numbers are stored
+//! in canonical form, so any casing or padding finds the same case.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+
+const DISTRICT: &str = "district9";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case(extra: Value) -> (u16, Value) {
+    let mut body = json!({
+        "title": "United States v. Sequence",
+        "description": "Case created for numbering tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    });
+    body.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+    send(Method::Post, "/api/cases", Some(body))
+}
+
+fn case_number(extra: Value) -> String {
+    let (status, case) = create_case(extra);
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    case["caseNumber"].as_str().unwrap().to_string()
+}
+
+/// Split `24-cr-00123` into its year, type and sequence parts
+fn parts(case_number: &str) -> (String, String, String) {
+    let parts: Vec<&str> = case_number.split('-').collect();
+    assert_eq!(parts.len(), 3, "Unexpected case number {}", case_number);
+    (parts[0].to_string(), parts[1].to_string(), parts[2].to_string())
+}
+
+#[spin_test]
+fn test_generated_numbers_are_sequential() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let first = case_number(json!({}));
+    let second = case_number(json!({}));
+    let magistrate = case_number(json!({"caseType": "magistrate"}));
+
+    let (year, case_type, sequence) = parts(&first);
+    assert_eq!(year.len(), 2);
+    assert_eq!(case_type, "cr");
+    assert_eq!(sequence, "00001");
+    assert_eq!(second, format!("{}-cr-00002", year));
+    assert_eq!(magistrate, format!("{}-mj-00001", year));
+
+    let (status, _) = send(Method::Get, &format!("/api/cases/by-number/{}", second), None);
+    assert_eq!(status, 200, "Generated number should be indexed");
+}
+
+#[spin_test]
+fn test_many_cases_get_distinct_numbers() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let mut seen = HashSet::new();
+    for _ in 0..50 {
+        let number = case_number(json!({}));
+        assert!(seen.insert(number.clone()), "Duplicate case number {}", number);
+    }
+    assert_eq!(seen.len(), 50);
+}
+
+#[spin_test]
+fn test_supplied_case_number_is_kept_and_skipped() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let generated = case_number(json!({}));
+    let (year, _, _) = parts(&generated);
+    let supplied = format!("{}-cr-00002", year);
+    assert_eq!(case_number(json!({"caseNumber": supplied})), supplied);
+
+    let (status, body) = create_case(json!({"caseNumber": supplied}));
+    assert_eq!(status, 409, "Duplicate supplied number should conflict: {}", body);
+
+    // The sequence steps over the number the client took
+    assert_eq!(case_number(json!({})), format!("{}-cr-00003", year));
+}
+
+#[spin_test]
+fn test_unknown_case_type_is_rejected() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let (status, _) = create_case(json!({"caseType": "admiralty"}));
+    assert_eq!(status, 400);
+}
+
+#[spin_test]
+fn test_district_format_override() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let (status, format) = send(Method::Get, "/api/config/case-number-format", None);
+    assert_eq!(status, 200);
+    assert_eq!(format["padding"], 5);
+    assert_eq!(format["type_abbreviations"]["criminal"], "cr");
+
+    let (status, format) = send(Method::Put, "/api/config/case-number-format", Some(json!({
+        "padding": 3,
+        "type_abbreviations": {"criminal": "CR"}
+    })));
+    assert_eq!(status, 200, "Format should update: {}", format);
+    assert_eq!(format["padding"], 3);
+    assert_eq!(format["type_abbreviations"]["criminal"], "CR");
+    assert_eq!(format["type_abbreviations"]["magistrate"], "mj", "Unnamed types keep their defaults");
+
+    // The district's abbreviation is kept for display; the case is stored
+    // under the lowercase canonical form
+    let (status, case) = create_case(json!({}));
+    assert_eq!(status, 201, "{}", case);
+    let (_, case_type, sequence) = parts(case["caseNumber"].as_str().unwrap());
+    assert_eq!(case_type, "cr");
+    assert_eq!(sequence, "001");
+    let (_, case_type, _) = parts(case["displayCaseNumber"].as_str().unwrap());
+    assert_eq!(case_type, "CR");
+
+    let (status, _) = send(Method::Put, "/api/config/case-number-format", Some(json!({
+        "padding": 0,
+        "type_abbreviations": {"criminal": "cr"}
+    })));
+    assert_eq!(status, 400);
+
+    let (status, _) = send(Method::Put, "/api/config/case-number-format", Some(json!({
+        "padding": 5,
+        "type_abbreviations": {"criminal": "crim-1"}
+    })));
+    assert_eq!(status, 400);
+}
+
+#[spin_test]
+fn test_equivalent_case_numbers_resolve_to_one_case() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let (status, case) = create_case(json!({"caseNumber": "93-CR-7"}));
+    assert_eq!(status, 201, "{}", case);
+    assert_eq!(case["caseNumber"], "93-cr-00007");
+    assert_eq!(case["displayCaseNumber"], "93-CR-7");
+    let id = case["id"].as_str().unwrap().to_string();
+
+    for written in ["93-cr-00007", "93-CR-7", "93-Cr-007", "1993-cr-7"] {
+        let (status, found) = send(Method::Get, &format!("/api/cases/by-number/{}", written), None);
+        assert_eq!(status, 200, "{} should find the case: {}", written, found);
+        assert_eq!(found["id"], id.as_str(), "{}", written);
+    }
+
+    for duplicate in ["93-cr-00007", "93-cr-7"] {
+        let (status, body) = create_case(json!({"caseNumber": duplicate}));
+        assert_eq!(status, 409, "{} is the same number: {}", duplicate, body);
+    }
+
+    for malformed in ["93-cr", "CR-93-7", "93-cr-seven"] {
+        let (status, body) = create_case(json!({"caseNumber": malformed}));
+        assert_eq!(status, 400, "{} is not a case number: {}", malformed, body);
+    }
+}
@@ -0,0 +1,146 @@
+//! Cases by attorney tests
+//!
+//! Tests that GET /api/cases/by-attorney/:attorney_id lists each case the
+//! attorney represents a party in once, leaves out ended representations
+//! unless `active_only=false`, and 404s for an unknown attorney.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case(title: &str) -> String {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": title,
+        "description": "Case for attorney case list tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })));
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    case["id"].as_str().unwrap().to_string()
+}
+
+fn create_attorney(bar_number: &str) -> String {
+    let (status, attorney) = send(Method::Post, "/api/attorneys", Some(json!({
+        "bar_number": bar_number,
+        "first_name": "Casey",
+        "last_name": "Counsel",
+        "email": format!("{}@example.com", bar_number.to_lowercase()),
+        "phone": "555-0100",
+        "address": {
+            "street1": "500 Pearl St",
+            "city": "New York",
+            "state": "NY",
+            "zip_code": "10007",
+            "country": "US"
+        }
+    })));
+    assert_eq!(status, 200, "Attorney should be created: {}", attorney);
+    attorney["id"].as_str().unwrap().to_string()
+}
+
+fn represent(id: &str, attorney_id: &str, party_id: &str, case_id: &str) {
+    let (status, body) = send(Method::Post, "/api/representations", Some(json!({
+        "id": id,
+        "attorney_id": attorney_id,
+        "party_id": party_id,
+        "case_id": case_id,
+        "representation_type": "General",
+        "status": "Active",
+        "start_date": "2030-01-06T09:00:00Z",
+        "end_date": null,
+        "lead_counsel": true,
+        "local_counsel": false,
+        "limited_appearance": false,
+        "scope_of_representation": null,
+        "withdrawal_reason": null,
+        "court_appointed": false,
+        "cja_appointment_id": null,
+        "notes": null
+    })));
+    assert_eq!(status, 204, "Representation should be added: {}", body);
+}
+
+fn case_ids(path: &str) -> Vec<String> {
+    let (status, body) = send(Method::Get, path, None);
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body["total"], body["cases"].as_array().unwrap().len());
+    body["cases"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[spin_test]
+fn test_cases_follow_the_attorneys_representations() {
+    let _store = key_value::Store::open(DISTRICT);
+    let attorney_id = create_attorney("NY-BYATTY-1");
+    let other_attorney_id = create_attorney("NY-BYATTY-2");
+    let trial = create_case("United States v. Trial");
+    let closed = create_case("United States v. Withdrawn");
+    let elsewhere = create_case("United States v. Elsewhere");
+
+    // Two defendants in one case still list it once
+    represent("rep-trial-1", &attorney_id, "party-trial-1", &trial);
+    represent("rep-trial-2", &attorney_id, "party-trial-2", &trial);
+    represent("rep-withdrawn", &attorney_id, "party-withdrawn", &closed);
+    represent("rep-elsewhere", &other_attorney_id, "party-elsewhere", &elsewhere);
+
+    let path = format!("/api/cases/by-attorney/{}", attorney_id);
+    let mut listed = case_ids(&path);
+    listed.sort();
+    let mut expected = vec![trial.clone(), closed.clone()];
+    expected.sort();
+    assert_eq!(listed, expected);
+
+    let (status, _) = send(Method::Post, "/api/representations/rep-withdrawn/end", None);
+    assert_eq!(status, 204);
+
+    assert_eq!(case_ids(&path), vec![trial.clone()]);
+    assert_eq!(case_ids(&format!("{}?active_only=true", path)), vec![trial.clone()]);
+    assert_eq!(case_ids(&format!("{}?active_only=false", path)).len(), 2);
+
+    assert_eq!(case_ids(&format!("/api/cases/by-attorney/{}", other_attorney_id)), vec![elsewhere]);
+}
+
+#[spin_test]
+fn test_unknown_attorney_and_bad_flag() {
+    let _store = key_value::Store::open(DISTRICT);
+    let attorney_id = create_attorney("NY-BYATTY-3");
+
+    assert!(case_ids(&format!("/api/cases/by-attorney/{}", attorney_id)).is_empty());
+
+    let (status, _) = send(Method::Get, "/api/cases/by-attorney/no-such-attorney", None);
+    assert_eq!(status, 404);
+
+    let (status, _) = send(Method::Get, &format!("/api/cases/by-attorney/{}?active_only=maybe", attorney_id), None);
+    assert_eq!(status, 400);
+}
@@ -0,0 +1,122 @@
+//! Conditional GET tests
+//!
+//! Tests that GET /api/docket/case/:case_id and GET /api/judges/:judge_id/schedule
+//! return an ETag, answer 304 Not Modified when `If-None-Match` carries the
+//! current tag, and hand out a new tag once the docket or calendar changes.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Response status, ETag and JSON body
+struct TaggedResponse {
+    status: u16,
+    etag: String,
+    body: Value,
+}
+
+/// Helper to send a request with optional extra headers
+fn send(method: Method, path: &str, headers: &[(&str, &str)], body: Option<Value>) -> TaggedResponse {
+    let request_headers = Headers::new();
+    request_headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    request_headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+    for (name, value) in headers {
+        request_headers.append(&name.to_string(), value.as_bytes()).unwrap();
+    }
+
+    let request = OutgoingRequest::new(request_headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let etag = response
+        .headers()
+        .get(&"etag".to_string())
+        .first()
+        .map(|v| String::from_utf8_lossy(v).to_string())
+        .unwrap_or_default();
+    TaggedResponse {
+        status: response.status(),
+        etag,
+        body: serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null)),
+    }
+}
+
+fn file_entry(case_id: &str, description: &str) {
+    let response = send(Method::Post, "/api/docket/entries", &[], Some(json!({
+        "case_id": case_id,
+        "entry_type": "motion",
+        "description": description,
+        "filed_by": "Defense Counsel",
+        "is_sealed": false,
+        "is_ex_parte": false,
+        "page_count": null,
+        "service_list": []
+    })));
+    assert_eq!(response.status, 201, "Entry should be filed: {}", response.body);
+}
+
+#[spin_test]
+fn test_docket_not_modified_until_written() {
+    let _store = key_value::Store::open(DISTRICT);
+    let case_id = "5e1d7c2a-8b3f-4a6e-9d10-4c2b8a7f6e31";
+    let path = format!("/api/docket/case/{}", case_id);
+    file_entry(case_id, "Motion to suppress");
+
+    let first = send(Method::Get, &path, &[], None);
+    assert_eq!(first.status, 200);
+    assert!(first.etag.starts_with("W/\""), "Docket should carry a weak ETag: {:?}", first.etag);
+    assert_eq!(first.body.as_array().unwrap().len(), 1);
+
+    let cached = send(Method::Get, &path, &[("If-None-Match", &first.etag)], None);
+    assert_eq!(cached.status, 304);
+    assert_eq!(cached.etag, first.etag);
+
+    file_entry(case_id, "Motion in limine");
+    let changed = send(Method::Get, &path, &[("If-None-Match", &first.etag)], None);
+    assert_eq!(changed.status, 200, "A new filing should invalidate the ETag");
+    assert_ne!(changed.etag, first.etag);
+    assert_eq!(changed.body.as_array().unwrap().len(), 2);
+
+    let other_view = send(Method::Get, &path, &[("If-None-Match", &changed.etag), ("X-Access-Level", "chambers")], None);
+    assert_eq!(other_view.status, 200, "Each access level gets its own ETag");
+}
+
+#[spin_test]
+fn test_judge_schedule_not_modified_until_event_scheduled() {
+    let _store = key_value::Store::open(DISTRICT);
+    let judge_id = "2f6a9c1e-4b7d-4e3a-8c5f-9d0e1b2a3c4d";
+    let path = format!("/api/judges/{}/schedule", judge_id);
+
+    let first = send(Method::Get, &path, &[], None);
+    assert_eq!(first.status, 200);
+    assert!(!first.etag.is_empty());
+    assert_eq!(send(Method::Get, &path, &[("If-None-Match", &first.etag)], None).status, 304);
+
+    let scheduled = send(Method::Post, "/api/calendar/events", &[], Some(json!({
+        "case_id": "5e1d7c2a-8b3f-4a6e-9d10-4c2b8a7f6e31",
+        "judge_id": judge_id,
+        "event_type": "status_conference",
+        "scheduled_date": "2099-06-01T10:00:00Z",
+        "duration_minutes": 30,
+        "courtroom": "12C",
+        "description": "Status conference",
+        "participants": [],
+        "is_public": true
+    })));
+    assert_eq!(scheduled.status, 201, "Event should be scheduled: {}", scheduled.body);
+
+    let changed = send(Method::Get, &path, &[("If-None-Match", &first.etag)], None);
+    assert_eq!(changed.status, 200, "Scheduling an event should invalidate the ETag");
+    assert_ne!(changed.etag, first.etag);
+}
@@ -0,0 +1,159 @@
+//! Defendant custody tests
+//!
+//! Tests for PATCH /api/cases/:id/defendants/:defendant_id/custody, the
+//! district-wide custody report, and generating conditions of release from
+//! a defendant's record.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to create a case with one defendant, returning (case_id, case_number, defendant_id)
+fn create_case_with_defendant(name: &str, bond_info: Value, district: &str) -> (String, String, String) {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": format!("United States v. {}", name),
+        "description": "Case for custody tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), district);
+    assert_eq!(status, 201);
+    let case_id = case["id"].as_str().unwrap().to_string();
+
+    let (status, case) = send(Method::Post, &format!("/api/cases/{}/defendants", case_id), Some(json!({
+        "name": name,
+        "bondInfo": bond_info
+    })), district);
+    assert_eq!(status, 200, "Defendant should be added: {:?}", case);
+
+    (
+        case_id,
+        case["caseNumber"].as_str().unwrap().to_string(),
+        case["defendants"][0]["id"].as_str().unwrap().to_string(),
+    )
+}
+
+fn update_custody(case_id: &str, defendant_id: &str, body: Value, district: &str) -> (u16, Value) {
+    send(
+        Method::Patch,
+        &format!("/api/cases/{}/defendants/{}/custody", case_id, defendant_id),
+        Some(body),
+        district,
+    )
+}
+
+#[spin_test]
+fn test_detained_defendants_appear_in_custody_report() {
+    let _store = key_value::Store::open("district9");
+
+    let (case_id, case_number, defendant_id) = create_case_with_defendant("John Doe", json!(null), "district9");
+    let (status, defendant) = update_custody(&case_id, &defendant_id, json!({
+        "custody_status": "in_custody",
+        "detention_facility": "MDC Brooklyn",
+        "detention_date": "2020-01-10"
+    }), "district9");
+    assert_eq!(status, 200, "Custody update should succeed: {:?}", defendant);
+    assert_eq!(defendant["custody_status"], "in_custody");
+
+    let (other_case, _, other_defendant) = create_case_with_defendant("Jane Roe", json!(null), "district9");
+    update_custody(&other_case, &other_defendant, json!({"custody_status": "fugitive"}), "district9");
+
+    let (status, report) = send(Method::Get, "/api/cases/custody-report?status=detained", None, "district9");
+    assert_eq!(status, 200);
+    let entries = report.as_array().unwrap();
+    assert_eq!(entries.len(), 1, "Only detained defendants should be listed: {:?}", report);
+    assert_eq!(entries[0]["defendant_id"], defendant_id.as_str());
+    assert_eq!(entries[0]["case_number"], case_number.as_str());
+    assert_eq!(entries[0]["detention_facility"], "MDC Brooklyn");
+    assert!(entries[0]["days_in_custody"].as_i64().unwrap() > 365);
+
+    let (status, fugitives) = send(Method::Get, "/api/cases/custody-report?status=fugitive", None, "district9");
+    assert_eq!(status, 200);
+    assert_eq!(fugitives.as_array().unwrap().len(), 1);
+
+    let (status, _) = send(Method::Get, "/api/cases/custody-report?status=on_vacation", None, "district9");
+    assert_eq!(status, 400);
+}
+
+#[spin_test]
+fn test_release_requires_conditions_reference_or_recognizance() {
+    let _store = key_value::Store::open("district9");
+
+    let (case_id, _, defendant_id) = create_case_with_defendant("John Doe", json!(null), "district9");
+    update_custody(&case_id, &defendant_id, json!({"custody_status": "in_custody"}), "district9");
+
+    let (status, _) = update_custody(&case_id, &defendant_id, json!({"custody_status": "released_on_bond"}), "district9");
+    assert_eq!(status, 400, "Release on bond needs a conditions reference");
+
+    let (status, defendant) = update_custody(&case_id, &defendant_id, json!({
+        "custody_status": "released_on_bond",
+        "release_conditions_ref": "ECF No. 12"
+    }), "district9");
+    assert_eq!(status, 200);
+    assert_eq!(defendant["release_conditions_ref"], "ECF No. 12");
+    assert_eq!(defendant["detention_date"], json!(null));
+
+    let (status, _) = update_custody(&case_id, &defendant_id, json!({
+        "custody_status": "released_on_own_recognizance"
+    }), "district9");
+    assert_eq!(status, 200, "Release on recognizance needs no conditions reference");
+}
+
+#[spin_test]
+fn test_conditions_of_release_pdf_from_defendant_record() {
+    let _store = key_value::Store::open("district9");
+
+    let (_, case_number, defendant_id) = create_case_with_defendant("John Doe", json!({
+        "bail_type": "unsecured_bond",
+        "bail_amount": 50000.0,
+        "conditions_of_release": ["Surrender passport", "Report to Pretrial Services weekly"],
+        "bond_posted_date": null,
+        "surety_name": null
+    }), "district9");
+    let (_, bare_case_number, bare_defendant_id) = create_case_with_defendant("Jane Roe", json!(null), "district9");
+
+    let (status, body) = send(Method::Post, "/api/pdf/conditions-release/json", Some(json!({
+        "case_number": case_number,
+        "judge_name": "Hon. Test Judge",
+        "defendant_id": defendant_id
+    })), "district9");
+    assert_eq!(status, 200, "Conditions should be taken from the record: {:?}", body);
+
+    let (status, _) = send(Method::Post, "/api/pdf/conditions-release/json", Some(json!({
+        "case_number": bare_case_number,
+        "judge_name": "Hon. Test Judge",
+        "defendant_id": bare_defendant_id
+    })), "district9");
+    assert_eq!(status, 400, "A defendant without recorded conditions cannot be used");
+
+    let (status, _) = send(Method::Post, "/api/pdf/conditions-release/json", Some(json!({
+        "case_number": case_number,
+        "judge_name": "Hon. Test Judge",
+        "defendant_id": bare_defendant_id
+    })), "district9");
+    assert_eq!(status, 404, "The defendant must be on the named case");
+}
@@ -0,0 +1,120 @@
+//! Delay attribution tests
+//!
+//! Tests that GET /api/cases/:case_id/delay-attribution combines excludable
+//! delays and continuances into per-party delay totals.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to create a case and return its ID
+fn create_case(district: &str) -> String {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. Delay",
+        "description": "Case for delay attribution testing",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), district);
+    assert_eq!(status, 201);
+    case["id"].as_str().unwrap().to_string()
+}
+
+/// Helper to schedule a status conference for a case, returning the event ID
+fn schedule_status_conference(case_id: &str, scheduled_date: &str, district: &str) -> String {
+    let (status, event) = send(Method::Post, "/api/calendar/events", Some(json!({
+        "case_id": case_id,
+        "judge_id": "9b2f1c3e-5d4a-4b8c-9e7f-1a2b3c4d5e6f",
+        "event_type": "status_conference",
+        "scheduled_date": scheduled_date,
+        "duration_minutes": 30,
+        "courtroom": "12C",
+        "description": "Status conference",
+        "participants": ["Government", "Defense Counsel"],
+        "is_public": true
+    })), district);
+    assert_eq!(status, 201, "Event should be scheduled: {:?}", event);
+    event["id"].as_str().unwrap().to_string()
+}
+
+#[spin_test]
+fn test_attributes_defense_and_court_delays() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+    let case_id = create_case(district);
+
+    // Defense: defendant unavailable for 21 days
+    let (status, _) = send(Method::Post, &format!("/api/cases/{}/speedy-trial/start", case_id), Some(json!({
+        "arrestDate": "2099-01-05T00:00:00Z",
+        "indictmentDate": "2099-01-10T00:00:00Z"
+    })), district);
+    assert_eq!(status, 200);
+    let (status, _) = send(Method::Post, &format!("/api/cases/{}/speedy-trial/exclude", case_id), Some(json!({
+        "startDate": "2099-02-01T00:00:00Z",
+        "endDate": "2099-02-22T00:00:00Z",
+        "reason": "defendant_unavailable",
+        "statutoryReference": "18 U.S.C. § 3161(h)(3)",
+        "daysExcluded": 21
+    })), district);
+    assert_eq!(status, 200);
+
+    // Court: status conference continued without a motion, reset 14 days later
+    let event_id = schedule_status_conference(&case_id, "2099-03-10T10:00:00Z", district);
+    let (status, _) = send(Method::Patch, &format!("/api/calendar/events/{}/status", event_id), Some(json!({
+        "status": "continued"
+    })), district);
+    assert_eq!(status, 200);
+    schedule_status_conference(&case_id, "2099-03-24T10:00:00Z", district);
+
+    let (status, body) = send(Method::Get, &format!("/api/cases/{}/delay-attribution", case_id), None, district);
+    assert_eq!(status, 200, "Attribution should be returned: {:?}", body);
+    assert_eq!(body["by_party"]["defense"], 21);
+    assert_eq!(body["by_party"]["court"], 14);
+    assert_eq!(body["by_party"]["prosecution"], 0);
+    assert_eq!(body["total_days"], 35);
+    assert_eq!(body["items"].as_array().unwrap().len(), 2);
+}
+
+#[spin_test]
+fn test_case_without_delays_has_zero_totals() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+    let case_id = create_case(district);
+
+    let (status, body) = send(Method::Get, &format!("/api/cases/{}/delay-attribution", case_id), None, district);
+    assert_eq!(status, 200);
+    assert_eq!(body["total_days"], 0);
+    assert!(body["items"].as_array().unwrap().is_empty());
+}
+
+#[spin_test]
+fn test_unknown_case_returns_404() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, _) = send(Method::Get, "/api/cases/7c9e6679-7425-40de-944b-e07fc1f90ae7/delay-attribution", None, "district9");
+    assert_eq!(status, 404);
+}
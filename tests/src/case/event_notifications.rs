@@ -0,0 +1,199 @@
+//! Court event notification list tests
+//!
+//! Tests that calendar events take structured participants, that
+//! GET /api/calendar/events/:id/notification-list resolves attorney emails
+//! and flags unrepresented parties for postal service, that cancelling an
+//! event returns the list, and that events stored with plain-name
+//! participants still load.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const CASE_ID: &str = "5e2a9c41-8b7d-4f3e-a1c6-0d9b8e7f6a52";
+const JUDGE_ID: &str = "c4d3e2f1-0a9b-4c8d-8e7f-6a5b4c3d2e1f";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_attorney() -> String {
+    let (status, attorney) = send(Method::Post, "/api/attorneys", Some(json!({
+        "bar_number": "NY-NOTICE-1",
+        "first_name": "Ada",
+        "last_name": "Counsel",
+        "email": "ada.counsel@example.com",
+        "phone": "555-0101",
+        "address": {
+            "street1": "1 Federal Plaza",
+            "city": "New York",
+            "state": "NY",
+            "zip_code": "10007",
+            "country": "US"
+        }
+    })));
+    assert_eq!(status, 200, "Attorney should be created: {}", attorney);
+    attorney["id"].as_str().unwrap().to_string()
+}
+
+fn create_unrepresented_party() -> String {
+    let (status, party) = send(Method::Post, "/api/parties", Some(json!({
+        "case_id": CASE_ID,
+        "party_type": "Defendant",
+        "party_role": null,
+        "name": "Sam Litigant",
+        "entity_type": "Individual",
+        "address": {
+            "street1": "22 Elm St",
+            "street2": null,
+            "city": "Albany",
+            "state": "NY",
+            "zip_code": "12207",
+            "country": "US"
+        }
+    })));
+    assert_eq!(status, 200, "Party should be created: {}", party);
+    party["id"].as_str().unwrap().to_string()
+}
+
+fn schedule(participants: Value) -> Value {
+    let (status, event) = send(Method::Post, "/api/calendar/events", Some(json!({
+        "case_id": CASE_ID,
+        "judge_id": JUDGE_ID,
+        "event_type": "status_conference",
+        "scheduled_date": "2099-09-14T14:00:00Z",
+        "duration_minutes": 30,
+        "courtroom": "9A",
+        "description": "Status conference",
+        "participants": participants,
+        "is_public": true
+    })));
+    assert_eq!(status, 201, "Event should be scheduled: {}", event);
+    event
+}
+
+#[spin_test]
+fn test_notification_list_resolves_participants() {
+    let _store = key_value::Store::open(DISTRICT);
+    let attorney_id = create_attorney();
+    let party_id = create_unrepresented_party();
+
+    let event = schedule(json!([
+        {"kind": "attorney", "id": attorney_id, "name": "", "required": true},
+        {"kind": "party", "id": party_id, "name": "", "required": true},
+        {"kind": "other", "name": "Pretrial Services", "required": false}
+    ]));
+    assert_eq!(event["participants"][0]["kind"], "attorney");
+
+    let path = format!("/api/calendar/events/{}/notification-list", event["id"].as_str().unwrap());
+    let (status, list) = send(Method::Get, &path, None);
+    assert_eq!(status, 200, "{}", list);
+
+    let recipients = list["recipients"].as_array().unwrap();
+    assert_eq!(recipients.len(), 3);
+    assert_eq!(recipients[0]["name"], "Ada Counsel");
+    assert_eq!(recipients[0]["delivery"], "email");
+    assert_eq!(recipients[0]["email"], "ada.counsel@example.com");
+    assert_eq!(recipients[1]["name"], "Sam Litigant");
+    assert_eq!(recipients[1]["delivery"], "postal");
+    assert_eq!(recipients[1]["mailing_address"]["city"], "Albany");
+    assert_eq!(recipients[2]["delivery"], "unresolved");
+    assert_eq!(recipients[2]["required"], false);
+    assert_eq!(list["postal_service_count"], 1);
+    assert_eq!(list["unresolved_count"], 1);
+
+    let (status, _) = send(Method::Get, "/api/calendar/events/00000000-0000-4000-8000-000000000000/notification-list", None);
+    assert_eq!(status, 404);
+}
+
+#[spin_test]
+fn test_cancelling_event_returns_notification_list() {
+    let _store = key_value::Store::open(DISTRICT);
+    let attorney_id = create_attorney();
+    let event = schedule(json!([{"kind": "attorney", "id": attorney_id, "name": "Ada Counsel", "required": true}]));
+    let status_path = format!("/api/calendar/events/{}/status", event["id"].as_str().unwrap());
+
+    let (status, confirmed) = send(Method::Patch, &status_path, Some(json!({"status": "confirmed"})));
+    assert_eq!(status, 200);
+    assert!(confirmed.get("notification_list").is_none());
+
+    let (status, cancelled) = send(Method::Patch, &status_path, Some(json!({"status": "cancelled"})));
+    assert_eq!(status, 200, "{}", cancelled);
+    assert_eq!(cancelled["status"], "cancelled");
+    assert_eq!(cancelled["notification_list"]["recipients"][0]["email"], "ada.counsel@example.com");
+}
+
+#[spin_test]
+fn test_plain_name_participants_are_migrated() {
+    let store = key_value::Store::open(DISTRICT);
+
+    // Scheduling still accepts the old string array
+    let event = schedule(json!(["Government", "Defense Counsel"]));
+    assert_eq!(event["participants"][0], json!({"kind": "other", "id": null, "name": "Government", "required": true}));
+
+    // An event stored before participants were structured
+    let legacy_id = "0b6f4e2d-3c1a-4d9e-8f7b-6a5c4d3e2b1a";
+    let legacy = json!({
+        "id": legacy_id,
+        "case_id": CASE_ID,
+        "judge_id": JUDGE_ID,
+        "event_type": "motion_hearing",
+        "scheduled_date": "2099-10-01T10:00:00Z",
+        "duration_minutes": 60,
+        "courtroom": "12C",
+        "description": "Hearing on motion to compel",
+        "participants": ["Plaintiff", "Defendant"],
+        "court_reporter": null,
+        "is_public": true,
+        "call_time": null,
+        "actual_start": null,
+        "actual_end": null,
+        "status": "scheduled",
+        "notes": ""
+    });
+    store.set(&format!("district9:calendar-{}", legacy_id), &serde_json::to_vec(&legacy).unwrap()).unwrap();
+
+    let (status, calendar) = send(Method::Get, &format!("/api/calendar/case/{}", CASE_ID), None);
+    assert_eq!(status, 200);
+    let migrated = calendar
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["id"] == legacy_id)
+        .expect("Legacy event should load");
+    assert_eq!(migrated["participants"][1]["name"], "Defendant");
+    assert_eq!(migrated["participants"][1]["kind"], "other");
+
+    let (status, list) = send(Method::Get, &format!("/api/calendar/events/{}/notification-list", legacy_id), None);
+    assert_eq!(status, 200);
+    assert_eq!(list["unresolved_count"], 2);
+
+    let (status, cancelled) = send(
+        Method::Patch,
+        &format!("/api/calendar/events/{}/status", legacy_id),
+        Some(json!({"status": "cancelled"})),
+    );
+    assert_eq!(status, 200);
+    assert_eq!(cancelled["notification_list"]["recipients"][0]["name"], "Plaintiff");
+}
@@ -0,0 +1,82 @@
+//! Hearing notice period tests
+//!
+//! Tests that POST /api/calendar/events rejects hearings scheduled with less
+//! than the minimum notice for their event type, unless notice is waived.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// A date that has already passed, so no notice at all has been given
+const NO_NOTICE_DATE: &str = "2020-03-02T10:00:00Z";
+
+/// Build a motion hearing request for `scheduled_date`
+fn motion_hearing(scheduled_date: &str, waive_notice: bool) -> Value {
+    json!({
+        "case_id": "7c9e6679-7425-40de-944b-e07fc1f90ae7",
+        "judge_id": "9b2f1c3e-5d4a-4b8c-9e7f-1a2b3c4d5e6f",
+        "event_type": "motion_hearing",
+        "scheduled_date": scheduled_date,
+        "duration_minutes": 60,
+        "courtroom": "12C",
+        "description": "Hearing on motion to compel",
+        "participants": ["Plaintiff", "Defendant"],
+        "is_public": true,
+        "waive_notice": waive_notice
+    })
+}
+
+#[spin_test]
+fn test_motion_hearing_with_insufficient_notice_is_rejected() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, body) = send(Method::Post, "/api/calendar/events", Some(motion_hearing(NO_NOTICE_DATE, false)), "district9");
+
+    assert_eq!(status, 400, "Short-notice hearing should be rejected: {:?}", body);
+    let message = body.to_string();
+    assert!(message.contains("Insufficient notice"), "Error should explain the notice rule: {}", message);
+    assert!(message.contains("short by"), "Error should report the shortfall: {}", message);
+}
+
+#[spin_test]
+fn test_waived_notice_allows_short_notice_hearing() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, body) = send(Method::Post, "/api/calendar/events", Some(motion_hearing(NO_NOTICE_DATE, true)), "district9");
+
+    assert_eq!(status, 201, "Waived notice should allow scheduling: {:?}", body);
+    assert_eq!(body["event_type"], "motion_hearing");
+}
+
+#[spin_test]
+fn test_motion_hearing_with_sufficient_notice_is_scheduled() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, body) = send(Method::Post, "/api/calendar/events", Some(motion_hearing("2099-06-01T10:00:00Z", false)), "district9");
+
+    assert_eq!(status, 201, "Hearing with ample notice should be scheduled: {:?}", body);
+}
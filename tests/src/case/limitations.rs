@@ -0,0 +1,182 @@
+//! Statute of limitations tests
+//!
+//! Tests that creating a case with an offense date and statute starts the
+//! limitations clock and a jurisdictional deadline, that
+//! POST /api/cases/:id/limitations/tolling extends both, and that
+//! GET /api/cases/limitations-at-risk lists only unindicted cases inside the
+//! window.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case(title: &str, offense_date: &str, offense_statute: &str) -> Value {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": title,
+        "description": "Case for limitations tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY",
+        "offenseDate": offense_date,
+        "offenseStatute": offense_statute
+    })));
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    case
+}
+
+fn limitations_deadline(case_id: &str) -> Value {
+    let (status, deadlines) = send(Method::Get, &format!("/api/deadlines/case/{}", case_id), None);
+    assert_eq!(status, 200, "{}", deadlines);
+    deadlines
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|d| d["deadline_type"] == "statute_of_limitations")
+        .cloned()
+        .expect("limitations deadline should be saved")
+}
+
+fn at_risk(days: u32) -> Vec<String> {
+    let (status, cases) = send(Method::Get, &format!("/api/cases/limitations-at-risk?days={}", days), None);
+    assert_eq!(status, 200, "{}", cases);
+    cases
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["case_id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[spin_test]
+fn test_default_period_and_statute_override() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let wire = create_case("United States v. Wire", "2090-03-15T00:00:00Z", "18 U.S.C. § 1343");
+    let limitations = &wire["limitations"];
+    assert_eq!(limitations["period_years"], 5);
+    assert_eq!(limitations["authority"], "18 U.S.C. § 3282");
+    assert_eq!(limitations["deadline"], "2095-03-15T00:00:00Z");
+
+    let deadline = limitations_deadline(wire["id"].as_str().unwrap());
+    assert_eq!(deadline["due_date"], "2095-03-15T00:00:00Z");
+    assert_eq!(deadline["is_jurisdictional"], true);
+    assert_eq!(deadline["applicable_rule"], "18 U.S.C. § 3282");
+    assert_eq!(deadline["id"], limitations["deadline_id"]);
+
+    let bank = create_case("United States v. Bank", "2090-03-15T00:00:00Z", "18 U.S.C. § 1344");
+    assert_eq!(bank["limitations"]["period_years"], 10);
+    assert_eq!(bank["limitations"]["deadline"], "2100-03-15T00:00:00Z");
+
+    // No period for capital offenses, so no deadline to track
+    let murder = create_case("United States v. Capital", "2090-03-15T00:00:00Z", "18 U.S.C. § 1111");
+    assert!(murder["limitations"]["period_years"].is_null());
+    assert!(murder["limitations"]["deadline"].is_null());
+
+    // Offense date and statute come together
+    let (status, _) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. Partial",
+        "description": "Missing statute",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY",
+        "offenseDate": "2090-03-15T00:00:00Z"
+    })));
+    assert_eq!(status, 400);
+}
+
+#[spin_test]
+fn test_tolling_extends_deadline() {
+    let _store = key_value::Store::open(DISTRICT);
+    let case = create_case("United States v. Fugitive", "2090-03-15T00:00:00Z", "18 U.S.C. § 1343");
+    let case_id = case["id"].as_str().unwrap();
+    let path = format!("/api/cases/{}/limitations/tolling", case_id);
+
+    let (status, tracker) = send(Method::Post, &path, Some(json!({
+        "reason": "Defendant fled the district",
+        "startDate": "2091-01-01T00:00:00Z",
+        "endDate": "2091-01-31T00:00:00Z"
+    })));
+    assert_eq!(status, 200, "{}", tracker);
+    assert_eq!(tracker["deadline"], "2095-04-14T00:00:00Z");
+
+    // Overlapping days are only tolled once
+    let (status, tracker) = send(Method::Post, &path, Some(json!({
+        "reason": "Evidence sought abroad",
+        "startDate": "2091-01-21T00:00:00Z",
+        "endDate": "2091-02-10T00:00:00Z"
+    })));
+    assert_eq!(status, 200, "{}", tracker);
+    assert_eq!(tracker["deadline"], "2095-04-24T00:00:00Z");
+    assert_eq!(tracker["tolling"].as_array().unwrap().len(), 2);
+
+    let deadline = limitations_deadline(case_id);
+    assert_eq!(deadline["due_date"], "2095-04-24T00:00:00Z");
+    assert_eq!(deadline["due_date_history"].as_array().unwrap().len(), 2);
+
+    let (status, _) = send(Method::Post, &path, Some(json!({
+        "reason": "Backwards",
+        "startDate": "2091-03-01T00:00:00Z",
+        "endDate": "2091-02-01T00:00:00Z"
+    })));
+    assert_eq!(status, 400);
+
+    let (status, _) = send(Method::Post, "/api/cases/00000000-0000-4000-8000-000000000000/limitations/tolling", Some(json!({
+        "reason": "No case",
+        "startDate": "2091-01-01T00:00:00Z",
+        "endDate": "2091-01-31T00:00:00Z"
+    })));
+    assert_eq!(status, 404);
+}
+
+#[spin_test]
+fn test_at_risk_lists_unindicted_cases_in_window() {
+    let _store = key_value::Store::open(DISTRICT);
+    let pending = create_case("United States v. Pending", "2090-03-15T00:00:00Z", "18 U.S.C. § 1343");
+    let indicted = create_case("United States v. Indicted", "2090-03-15T00:00:00Z", "18 U.S.C. § 1343");
+    let pending_id = pending["id"].as_str().unwrap().to_string();
+    let indicted_id = indicted["id"].as_str().unwrap().to_string();
+
+    let (status, _) = send(Method::Post, &format!("/api/cases/{}/docket", indicted_id), Some(json!({
+        "entryType": "indictment",
+        "description": "Grand Jury Indictment",
+        "filedBy": "Grand Jury"
+    })));
+    assert_eq!(status, 200);
+
+    // Both deadlines are decades away, so only a wide window reaches them
+    let wide = at_risk(36500);
+    assert!(wide.contains(&pending_id));
+    assert!(!wide.contains(&indicted_id), "Indicted cases are not at risk");
+    assert!(!at_risk(180).contains(&pending_id));
+
+    let (status, _) = send(Method::Get, "/api/cases/limitations-at-risk?days=0", None);
+    assert_eq!(status, 400);
+    let (status, _) = send(Method::Get, "/api/cases/limitations-at-risk?days=soon", None);
+    assert_eq!(status, 400);
+}
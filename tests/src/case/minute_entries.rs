@@ -0,0 +1,212 @@
+//! Minute entry tests
+//!
+//! Tests that completing a calendar event writes a minute entry to the
+//! docket, that hearing minutes sent with the completion (appearances,
+//! court reporter, rulings, exhibits and the next date) are written into it,
+//! that appearances naming unknown attorneys are rejected, and that
+//! GET /api/calendar/events/:id/minute-entry-draft returns a body for
+//! POST /api/pdf/minute-entry.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case() -> String {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. Minutes",
+        "description": "Case for minute entry tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })));
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    case["id"].as_str().unwrap().to_string()
+}
+
+fn create_judge() -> String {
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": "Hon. Minute Judge",
+        "title": "district_judge",
+        "district": DISTRICT,
+        "courtroom": "11B"
+    })));
+    assert_eq!(status, 201, "Judge should be created: {}", judge);
+    judge["id"].as_str().unwrap().to_string()
+}
+
+fn create_attorney() -> String {
+    let (status, attorney) = send(Method::Post, "/api/attorneys", Some(json!({
+        "bar_number": "NY-MINUTES-1",
+        "first_name": "Ada",
+        "last_name": "Counsel",
+        "email": "ada.counsel@example.com",
+        "phone": "555-0101",
+        "address": {
+            "street1": "1 Federal Plaza",
+            "city": "New York",
+            "state": "NY",
+            "zip_code": "10007",
+            "country": "US"
+        }
+    })));
+    assert_eq!(status, 200, "Attorney should be created: {}", attorney);
+    attorney["id"].as_str().unwrap().to_string()
+}
+
+fn schedule(case_id: &str, judge_id: &str) -> String {
+    let (status, event) = send(Method::Post, "/api/calendar/events", Some(json!({
+        "case_id": case_id,
+        "judge_id": judge_id,
+        "event_type": "motion_hearing",
+        "scheduled_date": "2099-03-16T15:00:00Z",
+        "duration_minutes": 60,
+        "courtroom": "11B",
+        "description": "Hearing on motion to suppress.",
+        "participants": [],
+        "is_public": true
+    })));
+    assert_eq!(status, 201, "Event should be scheduled: {}", event);
+    event["id"].as_str().unwrap().to_string()
+}
+
+fn minute_entries(case_id: &str) -> Vec<String> {
+    let (status, entries) = send(Method::Get, &format!("/api/docket/case/{}", case_id), None);
+    assert_eq!(status, 200);
+    entries
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|e| e["entry_type"] == "minute_order")
+        .map(|e| e["description"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[spin_test]
+fn test_completion_without_minutes_writes_bare_entry() {
+    let _store = key_value::Store::open(DISTRICT);
+    let case_id = create_case();
+    let event_id = schedule(&case_id, &create_judge());
+
+    let path = format!("/api/calendar/events/{}/status", event_id);
+    let (status, event) = send(Method::Patch, &path, Some(json!({"status": "completed"})));
+    assert_eq!(status, 200, "{}", event);
+    assert!(event.get("minutes").is_none());
+
+    let entries = minute_entries(&case_id);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(
+        entries[0],
+        "Minute Entry for proceedings held before Judge on 03/16/2099. Hearing on motion to suppress."
+    );
+
+    let (status, draft) = send(Method::Get, &format!("/api/calendar/events/{}/minute-entry-draft", event_id), None);
+    assert_eq!(status, 200, "{}", draft);
+    assert_eq!(draft["minute_text"], entries[0].as_str());
+    assert_eq!(draft["judge_name"], "Hon. Minute Judge");
+    assert!(draft["next_hearing"].is_null());
+}
+
+#[spin_test]
+fn test_completion_with_minutes_fills_entry_and_draft() {
+    let _store = key_value::Store::open(DISTRICT);
+    let case_id = create_case();
+    let attorney_id = create_attorney();
+    let event_id = schedule(&case_id, &create_judge());
+
+    let draft_path = format!("/api/calendar/events/{}/minute-entry-draft", event_id);
+    let (status, _) = send(Method::Get, &draft_path, None);
+    assert_eq!(status, 409, "No draft before the event is completed");
+
+    let path = format!("/api/calendar/events/{}/status", event_id);
+    let minutes = json!({
+        "appearances": [
+            {"attorney_id": attorney_id, "name": "Ada Counsel", "appearing_for": "the United States"},
+            {"name": "Sam Defender", "appearing_for": "Defendant"}
+        ],
+        "court_reporter": "Ann Smith",
+        "rulings": ["Motion to suppress denied"],
+        "exhibits_admitted": ["Government Exhibit 1", "Defense Exhibit A"],
+        "next_scheduled_date": "2099-04-20T14:30:00Z"
+    });
+
+    // Minutes belong to a completed hearing
+    let (status, _) = send(Method::Patch, &path, Some(json!({"status": "in_progress", "minutes": minutes})));
+    assert_eq!(status, 400);
+
+    let (status, event) = send(Method::Patch, &path, Some(json!({"status": "completed", "minutes": minutes})));
+    assert_eq!(status, 200, "{}", event);
+    assert_eq!(event["court_reporter"], "Ann Smith");
+    assert_eq!(event["minutes"]["rulings"][0], "Motion to suppress denied");
+
+    let entries = minute_entries(&case_id);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(
+        entries[0],
+        "Minute Entry for proceedings held before Judge on 03/16/2099. Hearing on motion to suppress. \
+         Appearances: Ada Counsel for the United States; Sam Defender for Defendant. \
+         Court Reporter: Ann Smith. \
+         Rulings: Motion to suppress denied. \
+         Exhibits admitted: Government Exhibit 1, Defense Exhibit A. \
+         Next hearing set for 04/20/2099 02:30 PM."
+    );
+
+    let (status, draft) = send(Method::Get, &draft_path, None);
+    assert_eq!(status, 200, "{}", draft);
+    assert_eq!(draft["minute_text"], entries[0].as_str());
+    assert_eq!(draft["hearing_type"], "Motion Hearing");
+    assert_eq!(draft["hearing_date"], "03/16/2099");
+    assert_eq!(draft["court_reporter"], "Ann Smith");
+    assert_eq!(draft["next_hearing"], "04/20/2099 02:30 PM");
+
+    // The draft can be sent straight to the PDF endpoint
+    let (status, _) = send(Method::Post, "/api/pdf/minute-entry", Some(draft));
+    assert_eq!(status, 200);
+}
+
+#[spin_test]
+fn test_appearances_must_name_known_attorneys() {
+    let _store = key_value::Store::open(DISTRICT);
+    let case_id = create_case();
+    let event_id = schedule(&case_id, &create_judge());
+
+    let path = format!("/api/calendar/events/{}/status", event_id);
+    let (status, error) = send(Method::Patch, &path, Some(json!({
+        "status": "completed",
+        "minutes": {
+            "appearances": [{"attorney_id": "no-such-attorney", "name": "Nobody"}]
+        }
+    })));
+    assert_eq!(status, 400, "{}", error);
+    assert_eq!(error["error"]["details"]["unknown_attorney_ids"][0], "no-such-attorney");
+
+    // The event was left as it was
+    assert!(minute_entries(&case_id).is_empty());
+    let (status, _) = send(Method::Get, &format!("/api/calendar/events/{}/minute-entry-draft", event_id), None);
+    assert_eq!(status, 409);
+}
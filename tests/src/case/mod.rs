@@ -21,4 +21,23 @@ pub mod docket_entries;
 pub mod evidence;
 pub mod sealed_cases;
 pub mod speedy_trial;
-pub mod victims;
\ No newline at end of file
+pub mod victims;
+pub mod summons;
+pub mod hearing_notice;
+pub mod delay_attribution;
+pub mod sealed_docket_access;
+pub mod custody;
+pub mod attachment_upload;
+pub mod event_notifications;
+pub mod subpoena;
+pub mod case_archive;
+pub mod case_number;
+pub mod batch_pdf;
+pub mod batch_pdf_zip;
+pub mod conditional_get;
+pub mod timeline;
+pub mod minute_entries;
+pub mod attachment_size;
+pub mod limitations;
+pub mod cases_by_attorney;
+pub mod appeals;
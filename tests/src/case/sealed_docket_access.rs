@@ -0,0 +1,232 @@
+//! Sealed docket access tests
+//!
+//! Tests that the docket endpoints honor the X-Access-Level header: sealed
+//! entries are visible to sealed-authorized callers and chambers, ex parte
+//! entries only to chambers, and fetching a restricted entry by id returns
+//! 403 with a reference to the sealing order instead of 404.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const CASE_ID: &str = "7c9e6679-7425-40de-944b-e07fc1f90ae7";
+
+/// Helper to send a request with an optional access level, returning status + raw body
+fn send(method: Method, path: &str, body: Option<Value>, access_level: Option<&str>) -> (u16, String) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+    if let Some(level) = access_level {
+        headers.append(&"X-Access-Level".to_string(), level.as_bytes()).unwrap();
+    }
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    (response.status(), response.body_as_string().unwrap_or_default())
+}
+
+fn get_json(path: &str, access_level: Option<&str>) -> (u16, Value) {
+    let (status, body) = send(Method::Get, path, None, access_level);
+    (status, serde_json::from_str(&body).unwrap_or(json!(null)))
+}
+
+fn file_entry(entry_type: &str, description: &str, is_sealed: bool, is_ex_parte: bool, related_entries: Vec<u32>) -> Value {
+    let (status, body) = send(Method::Post, "/api/docket/entries", Some(json!({
+        "case_id": CASE_ID,
+        "entry_type": entry_type,
+        "description": description,
+        "filed_by": "Defense Counsel",
+        "is_sealed": is_sealed,
+        "is_ex_parte": is_ex_parte,
+        "page_count": null,
+        "service_list": [],
+        "related_entries": related_entries
+    })), Some("chambers"));
+    assert_eq!(status, 201, "Entry should be filed: {}", body);
+    serde_json::from_str(&body).unwrap()
+}
+
+/// Seeds an open motion, a sealed exhibit, the order sealing it, and an ex
+/// parte application. Returns (open, sealed, sealing order, ex parte) entries.
+fn seed_docket() -> (Value, Value, Value, Value) {
+    let open = file_entry("motion", "Motion to compel discovery", false, false, vec![]);
+    let sealed = file_entry("exhibit", "Cooperation agreement", true, false, vec![]);
+    let sealed_number = sealed["entry_number"].as_u64().unwrap() as u32;
+    let order = file_entry("sealing_order", "Order sealing exhibit", false, false, vec![sealed_number]);
+    let ex_parte = file_entry("motion", "Ex parte application for CJA funds", false, true, vec![]);
+    (open, sealed, order, ex_parte)
+}
+
+fn ids(entries: &Value) -> Vec<String> {
+    entries.as_array().unwrap().iter().map(|e| e["id"].as_str().unwrap().to_string()).collect()
+}
+
+fn id(entry: &Value) -> String {
+    entry["id"].as_str().unwrap().to_string()
+}
+
+#[spin_test]
+fn test_case_docket_filters_by_level() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (open, sealed, order, ex_parte) = seed_docket();
+    let path = format!("/api/docket/case/{}", CASE_ID);
+
+    for level in [None, Some("public"), Some("attorney")] {
+        let (status, body) = get_json(&path, level);
+        assert_eq!(status, 200);
+        let visible = ids(&body);
+        assert_eq!(visible.len(), 2, "{:?} should see only open entries", level);
+        assert!(visible.contains(&id(&open)) && visible.contains(&id(&order)));
+    }
+
+    let (_, body) = get_json(&path, Some("sealed-authorized"));
+    let visible = ids(&body);
+    assert_eq!(visible.len(), 3);
+    assert!(visible.contains(&id(&sealed)));
+    assert!(!visible.contains(&id(&ex_parte)));
+
+    let (_, body) = get_json(&path, Some("chambers"));
+    assert_eq!(ids(&body).len(), 4);
+}
+
+#[spin_test]
+fn test_sealed_entries_endpoint_by_level() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (_, sealed, _, _) = seed_docket();
+    let path = format!("/api/docket/case/{}/sealed", CASE_ID);
+
+    for level in [None, Some("public"), Some("attorney")] {
+        let (status, body) = get_json(&path, level);
+        assert_eq!(status, 200);
+        assert!(ids(&body).is_empty(), "{:?} should not see sealed entries", level);
+    }
+
+    for level in ["sealed-authorized", "chambers"] {
+        let (status, body) = get_json(&path, Some(level));
+        assert_eq!(status, 200);
+        assert_eq!(ids(&body), vec![id(&sealed)], "{} should see the sealed entry", level);
+    }
+}
+
+#[spin_test]
+fn test_get_sealed_entry_by_id_by_level() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (_, sealed, order, _) = seed_docket();
+    let path = format!("/api/docket/entries/{}", id(&sealed));
+
+    for level in [None, Some("public"), Some("attorney")] {
+        let (status, body) = get_json(&path, level);
+        assert_eq!(status, 403, "{:?} should be refused, not told the entry is missing", level);
+        let details = body["error"]["message"].as_str().unwrap();
+        assert!(details.contains("sealed"));
+        assert!(
+            details.contains(&format!("sealing order at docket entry {}", order["entry_number"])),
+            "Error should reference the sealing order: {}", details
+        );
+    }
+
+    for level in ["sealed-authorized", "chambers"] {
+        let (status, body) = get_json(&path, Some(level));
+        assert_eq!(status, 200);
+        assert_eq!(body["description"], "Cooperation agreement");
+    }
+}
+
+#[spin_test]
+fn test_get_ex_parte_entry_by_id_by_level() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (_, _, _, ex_parte) = seed_docket();
+    let path = format!("/api/docket/entries/{}", id(&ex_parte));
+
+    for level in [None, Some("public"), Some("attorney"), Some("sealed-authorized")] {
+        let (status, body) = get_json(&path, level);
+        assert_eq!(status, 403, "{:?} should not see ex parte entries", level);
+        assert!(body["error"]["message"].as_str().unwrap().contains("ex parte"));
+    }
+
+    let (status, _) = get_json(&path, Some("chambers"));
+    assert_eq!(status, 200);
+}
+
+#[spin_test]
+fn test_docket_search_by_level() {
+    let _store = key_value::Store::open(DISTRICT);
+    seed_docket();
+    let path = format!("/api/docket/search?case_id={}", CASE_ID);
+
+    for (level, expected) in [(None, 2), (Some("public"), 2), (Some("attorney"), 2), (Some("sealed-authorized"), 3), (Some("chambers"), 4)] {
+        let (status, body) = get_json(&path, level);
+        assert_eq!(status, 200);
+        assert_eq!(body["total"], expected, "{:?} search total", level);
+        assert_eq!(body["entries"].as_array().unwrap().len(), expected);
+    }
+}
+
+#[spin_test]
+fn test_docket_search_by_several_entry_types() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (open, sealed, order, ex_parte) = seed_docket();
+
+    let path = format!("/api/docket/search?case_id={}&entry_type=motion&entry_type=sealing_order", CASE_ID);
+    let (status, body) = get_json(&path, Some("chambers"));
+    assert_eq!(status, 200);
+    let found = ids(&body["entries"]);
+    assert_eq!(found.len(), 3);
+    assert!(found.contains(&id(&open)) && found.contains(&id(&order)) && found.contains(&id(&ex_parte)));
+
+    let path = format!("/api/docket/search?case_id={}&entry_type=exhibit,sealing_order", CASE_ID);
+    let (_, body) = get_json(&path, Some("chambers"));
+    let found = ids(&body["entries"]);
+    assert_eq!(found.len(), 2);
+    assert!(found.contains(&id(&sealed)) && found.contains(&id(&order)));
+
+    // Search text is percent-decoded
+    let path = format!("/api/docket/search?case_id={}&search=compel%20discovery", CASE_ID);
+    let (_, body) = get_json(&path, None);
+    assert_eq!(ids(&body["entries"]), vec![id(&open)]);
+
+    let (status, _) = get_json("/api/docket/search?entry_type=motion,memo_to_self", None);
+    assert_eq!(status, 400);
+}
+
+#[spin_test]
+fn test_docket_sheet_by_level() {
+    let _store = key_value::Store::open(DISTRICT);
+    seed_docket();
+    let path = format!("/api/docket/sheet/{}", CASE_ID);
+
+    for level in [None, Some("public"), Some("attorney")] {
+        let (status, sheet) = send(Method::Get, &path, None, level);
+        assert_eq!(status, 200);
+        assert!(sheet.contains("Motion to compel discovery"));
+        assert!(!sheet.contains("Cooperation agreement"), "{:?} sheet leaked a sealed entry", level);
+        assert!(!sheet.contains("CJA funds"), "{:?} sheet leaked an ex parte entry", level);
+    }
+
+    let (_, sheet) = send(Method::Get, &path, None, Some("sealed-authorized"));
+    assert!(sheet.contains("Cooperation agreement"));
+    assert!(!sheet.contains("CJA funds"));
+
+    let (_, sheet) = send(Method::Get, &path, None, Some("chambers"));
+    assert!(sheet.contains("Cooperation agreement") && sheet.contains("CJA funds"));
+}
+
+#[spin_test]
+fn test_invalid_access_level_is_rejected() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (status, body) = get_json(&format!("/api/docket/case/{}", CASE_ID), Some("judge"));
+    assert_eq!(status, 400);
+    assert!(body["error"]["message"].as_str().unwrap().contains("sealed-authorized"));
+}
@@ -226,6 +226,35 @@ fn test_search_cases_multiple_filters() {
     }
 }
 
+#[spin_test]
+fn test_search_cases_by_repeated_status() {
+    let _store = key_value::Store::open("district12");
+
+    let case_id = create_test_case_with_properties("Repeated Status Case", "fraud", "district12");
+
+    let (status, response) = search_cases_request("status=dismissed&status=filed", "district12");
+    assert_eq!(status, 200, "Should return 200 for repeated status filter");
+    let cases = response["cases"].as_array().unwrap();
+    assert!(cases.iter().any(|c| c["id"] == case_id.as_str()), "Filed case should match either status");
+    for case in cases {
+        assert!(case["status"] == "filed" || case["status"] == "dismissed");
+    }
+
+    let (status, response) = search_cases_request("status=dismissed&status=arraigned", "district12");
+    assert_eq!(status, 200);
+    assert!(
+        !response["cases"].as_array().unwrap().iter().any(|c| c["id"] == case_id.as_str()),
+        "Filed case should not match other statuses"
+    );
+
+    let (status, response) = search_cases_request("status=arraigned,filed", "district12");
+    assert_eq!(status, 200, "Should return 200 for comma-separated statuses");
+    assert!(response["cases"].as_array().unwrap().iter().any(|c| c["id"] == case_id.as_str()));
+
+    let (status, _) = search_cases_request("status=filed&status=invalid_status", "district12");
+    assert!(status == 400 || status == 422, "Any invalid status should be rejected, got {}", status);
+}
+
 #[spin_test]
 fn test_search_cases_invalid_status() {
     let _store = key_value::Store::open("district9");
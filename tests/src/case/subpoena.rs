@@ -0,0 +1,94 @@
+//! Subpoena generation tests
+//!
+//! Tests for POST /api/pdf/subpoena/:format and subpoenas in the batch
+//! generator.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const CASE_NUMBER: &str = "1:26-cr-00412";
+
+/// Helper to send a JSON request and return status, content disposition and raw body
+fn send_raw(method: Method, path: &str, body: Option<Value>) -> (u16, String, Vec<u8>) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), b"district9").unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let disposition = response
+        .headers()
+        .get(&"content-disposition".to_string())
+        .first()
+        .map(|v| String::from_utf8_lossy(v).to_string())
+        .unwrap_or_default();
+    let bytes = response.body().unwrap_or_default();
+    (status, disposition, bytes)
+}
+
+fn subpoena() -> Value {
+    json!({
+        "case_number": CASE_NUMBER,
+        "recipient_name": "Jordan Witness",
+        "appearance_date": "2026-11-02T09:30:00Z",
+        "location": "Courtroom 14A, 500 Pearl Street, New York, NY",
+        "judge_name": "Hon. Maria Alvarez"
+    })
+}
+
+#[spin_test]
+fn test_subpoena_pdf_is_generated() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, disposition, pdf) = send_raw(Method::Post, "/api/pdf/subpoena/pdf", Some(subpoena()));
+    assert_eq!(status, 200);
+    assert!(pdf.starts_with(b"%PDF"), "Response should be a PDF");
+    assert!(disposition.contains(CASE_NUMBER), "Filename should include the case number: {}", disposition);
+
+    let (status, _, body) = send_raw(Method::Post, "/api/pdf/subpoena/json", Some(subpoena()));
+    assert_eq!(status, 200);
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["document_type"], "subpoena");
+    assert!(body["size_bytes"].as_u64().unwrap() > 0);
+    assert!(!body["pdf_base64"].as_str().unwrap().is_empty());
+    assert_eq!(body["filename"], format!("subpoena-{}.pdf", CASE_NUMBER));
+}
+
+#[spin_test]
+fn test_subpoena_requires_appearance_details() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, _, _) = send_raw(Method::Post, "/api/pdf/subpoena/json", Some(json!({
+        "case_number": CASE_NUMBER,
+        "recipient_name": "Jordan Witness"
+    })));
+    assert_eq!(status, 400);
+}
+
+#[spin_test]
+fn test_subpoena_in_batch() {
+    let _store = key_value::Store::open("district9");
+
+    let mut document = subpoena();
+    document["type"] = json!("subpoena");
+    let (status, _, body) = send_raw(Method::Post, "/api/pdf/batch", Some(json!({"documents": [document]})));
+    assert_eq!(status, 200);
+
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["total_generated"], 1);
+    assert_eq!(body["documents"][0]["document_type"], "subpoena");
+    assert!(body["documents"][0]["filename"].as_str().unwrap().contains(CASE_NUMBER));
+}
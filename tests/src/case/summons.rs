@@ -0,0 +1,169 @@
+//! Summons issuance tests
+//!
+//! Tests for POST /api/cases/:case_id/summons, which renders a summons for a
+//! party and records a pending service record for it.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a JSON request and return status, content type and raw body
+fn send_raw(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, String, Vec<u8>) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(&"content-type".to_string())
+        .first()
+        .map(|v| String::from_utf8_lossy(v).to_string())
+        .unwrap_or_default();
+    let bytes = response.body().unwrap_or_default();
+    (status, content_type, bytes)
+}
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let (status, _, bytes) = send_raw(method, path, body, district);
+    let body: Value = serde_json::from_slice(&bytes).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to create a case with a defendant party, returning (case_id, party_id)
+fn create_case_with_party(district: &str) -> (String, String) {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "Summons Test Case",
+        "description": "Case for summons testing",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), district);
+    assert_eq!(status, 201);
+    let case_id = case["id"].as_str().unwrap().to_string();
+
+    let (status, party) = send(Method::Post, "/api/parties", Some(json!({
+        "case_id": case_id,
+        "party_type": "Defendant",
+        "name": "Jane Roe",
+        "entity_type": "Individual",
+        "address": {
+            "street1": "1 Centre Street",
+            "city": "New York",
+            "state": "NY",
+            "zip_code": "10007",
+            "country": "USA"
+        }
+    })), district);
+    assert_eq!(status, 200);
+    let party_id = party["id"].as_str().unwrap().to_string();
+
+    (case_id, party_id)
+}
+
+#[spin_test]
+fn test_summons_generates_pdf_and_pending_service_record() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+    let (case_id, party_id) = create_case_with_party(district);
+
+    let (status, body) = send(
+        Method::Post,
+        &format!("/api/cases/{}/summons/json", case_id),
+        Some(json!({ "party_id": party_id })),
+        district,
+    );
+    assert_eq!(status, 200);
+    assert_eq!(body["document_type"], "summons");
+    assert_eq!(body["party_id"], party_id);
+    assert!(body["size_bytes"].as_u64().unwrap() > 0);
+    assert!(!body["pdf_base64"].as_str().unwrap().is_empty());
+
+    let document_id = body["document_id"].as_str().unwrap();
+    let (status, records) = send(
+        Method::Get,
+        &format!("/api/service-records/document/{}", document_id),
+        None,
+        district,
+    );
+    assert_eq!(status, 200);
+
+    let records = records.as_array().unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0]["id"], body["service_record_id"]);
+    assert_eq!(records[0]["party_id"], party_id);
+    assert_eq!(records[0]["successful"], false);
+    assert_eq!(records[0]["proof_of_service_filed"], false);
+    assert_eq!(records[0]["attempts"], 0);
+}
+
+#[spin_test]
+fn test_summons_raw_pdf_format() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+    let (case_id, party_id) = create_case_with_party(district);
+
+    let (status, content_type, bytes) = send_raw(
+        Method::Post,
+        &format!("/api/cases/{}/summons/pdf", case_id),
+        Some(json!({ "party_id": party_id })),
+        district,
+    );
+    assert_eq!(status, 200);
+    assert_eq!(content_type, "application/pdf");
+    assert!(bytes.starts_with(b"%PDF"));
+
+    let (status, records) = send(
+        Method::Get,
+        &format!("/api/service-records/party/{}", party_id),
+        None,
+        district,
+    );
+    assert_eq!(status, 200);
+    assert_eq!(records.as_array().unwrap().len(), 1);
+}
+
+#[spin_test]
+fn test_summons_rejects_party_from_another_case() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+    let (_, party_id) = create_case_with_party(district);
+    let (other_case_id, _) = create_case_with_party(district);
+
+    let (status, _) = send(
+        Method::Post,
+        &format!("/api/cases/{}/summons", other_case_id),
+        Some(json!({ "party_id": party_id })),
+        district,
+    );
+    assert_eq!(status, 400);
+}
+
+#[spin_test]
+fn test_summons_unknown_party_returns_not_found() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+    let (case_id, _) = create_case_with_party(district);
+
+    let (status, _) = send(
+        Method::Post,
+        &format!("/api/cases/{}/summons", case_id),
+        Some(json!({ "party_id": "missing-party" })),
+        district,
+    );
+    assert_eq!(status, 404);
+}
@@ -0,0 +1,171 @@
+//! Case timeline tests
+//!
+//! Tests that GET /api/cases/{id}/timeline merges the case record, docket,
+//! calendar and deadlines into one list in time order, and that `from`,
+//! `to`, `sources` and paging narrow it.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case() -> String {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. Chronology",
+        "description": "Case for timeline tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })));
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    case["id"].as_str().unwrap().to_string()
+}
+
+/// Seeds a docket entry, a hearing and a deadline in 2099, and a motion
+fn seed(case_id: &str) {
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": "Hon. Timeline Judge",
+        "title": "district_judge",
+        "district": DISTRICT,
+        "courtroom": "7C"
+    })));
+    assert_eq!(status, 201, "judge: {}", judge);
+
+    let (status, entry) = send(Method::Post, "/api/docket/entries", Some(json!({
+        "case_id": case_id,
+        "entry_type": "motion",
+        "description": "Motion to continue trial",
+        "filed_by": "Defense Counsel",
+        "is_sealed": false,
+        "is_ex_parte": false,
+        "page_count": null,
+        "service_list": []
+    })));
+    assert_eq!(status, 201, "docket entry: {}", entry);
+
+    let (status, event) = send(Method::Post, "/api/calendar/events", Some(json!({
+        "case_id": case_id,
+        "judge_id": judge["id"],
+        "event_type": "status_conference",
+        "scheduled_date": "2099-05-01T14:00:00Z",
+        "duration_minutes": 30,
+        "courtroom": "7C",
+        "description": "Status conference",
+        "participants": [],
+        "is_public": true
+    })));
+    assert_eq!(status, 201, "calendar event: {}", event);
+
+    let (status, deadline) = send(Method::Post, "/api/deadlines", Some(json!({
+        "case_id": case_id,
+        "deadline_type": "pretrial_motions",
+        "due_date": "2099-03-15T00:00:00Z",
+        "triggering_event": "arraignment",
+        "triggering_date": "2099-03-01T00:00:00Z",
+        "applicable_rule": "FRCrP 12(c)",
+        "description": "Pretrial motions",
+        "responsible_party": "Defendant",
+        "is_jurisdictional": false,
+        "is_extendable": true
+    })));
+    assert_eq!(status, 201, "deadline: {}", deadline);
+
+    let (status, _) = send(Method::Post, &format!("/api/cases/{}/motions", case_id), Some(json!({
+        "motionType": "suppress_evidence",
+        "filedBy": "Defense Counsel",
+        "description": "Motion to suppress"
+    })));
+    assert_eq!(status, 200);
+}
+
+fn event_types(timeline: &Value) -> Vec<String> {
+    timeline["items"].as_array().unwrap().iter().map(|e| e["event_type"].as_str().unwrap().to_string()).collect()
+}
+
+#[spin_test]
+fn test_timeline_merges_sources_in_time_order() {
+    let _store = key_value::Store::open(DISTRICT);
+    let case_id = create_case();
+    seed(&case_id);
+
+    let (status, timeline) = send(Method::Get, &format!("/api/cases/{}/timeline", case_id), None);
+    assert_eq!(status, 200, "{}", timeline);
+    assert_eq!(timeline["partial"], false);
+    assert_eq!(timeline["failed_sources"], json!([]));
+
+    let items = timeline["items"].as_array().unwrap();
+    let times: Vec<&str> = items.iter().map(|e| e["timestamp"].as_str().unwrap()).collect();
+    let mut sorted = times.clone();
+    sorted.sort();
+    assert_eq!(times, sorted, "Events are in time order");
+
+    let types = event_types(&timeline);
+    for expected in ["case.opened", "motion.filed", "docket.entry", "calendar.event", "deadline.created"] {
+        assert!(types.iter().any(|t| t == expected), "{} missing from {:?}", expected, types);
+    }
+    // The 2099 hearing is the latest event
+    assert_eq!(items.last().unwrap()["source"], "calendar");
+
+    // A ruling appears once the motion is decided
+    let motion = items.iter().find(|e| e["event_type"] == "motion.filed").unwrap();
+    let (status, _) = send(Method::Patch, &format!("/api/cases/{}/motions/ruling", case_id), Some(json!({
+        "motionId": motion["reference_id"],
+        "ruling": "denied"
+    })));
+    assert_eq!(status, 200);
+    let (_, timeline) = send(Method::Get, &format!("/api/cases/{}/timeline?sources=case", case_id), None);
+    let ruled = timeline["items"].as_array().unwrap().iter().find(|e| e["event_type"] == "motion.ruled").cloned();
+    assert!(ruled.unwrap()["summary"].as_str().unwrap().ends_with("denied"), "{}", timeline);
+}
+
+#[spin_test]
+fn test_timeline_filters_and_pages() {
+    let _store = key_value::Store::open(DISTRICT);
+    let case_id = create_case();
+    seed(&case_id);
+
+    let (status, timeline) = send(Method::Get, &format!("/api/cases/{}/timeline?sources=calendar,deadlines", case_id), None);
+    assert_eq!(status, 200);
+    assert_eq!(event_types(&timeline), vec!["deadline.created", "calendar.event"]);
+
+    let (_, timeline) = send(Method::Get, &format!("/api/cases/{}/timeline?from=2099-01-01&to=2099-04-30", case_id), None);
+    assert_eq!(event_types(&timeline), vec!["deadline.created"]);
+
+    let (_, first) = send(Method::Get, &format!("/api/cases/{}/timeline?limit=2", case_id), None);
+    let (_, second) = send(Method::Get, &format!("/api/cases/{}/timeline?limit=2&offset=2", case_id), None);
+    assert_eq!(first["items"].as_array().unwrap().len(), 2);
+    assert_eq!(first["total"], second["total"]);
+    assert_ne!(first["items"][0], second["items"][0]);
+
+    let (status, _) = send(Method::Get, &format!("/api/cases/{}/timeline?sources=docket,emails", case_id), None);
+    assert_eq!(status, 400);
+    let (status, _) = send(Method::Get, &format!("/api/cases/{}/timeline?from=2099-05-01&to=2099-04-01", case_id), None);
+    assert_eq!(status, 400);
+    let (status, _) = send(Method::Get, "/api/cases/0f4e8d2c-6b1a-4e9f-8d7c-3b2a1f0e9d8c/timeline", None);
+    assert_eq!(status, 404);
+}
@@ -0,0 +1,6 @@
+//! Configuration domain tests
+//!
+//! This module contains tests for the hierarchical configuration endpoints
+
+pub mod preview_diff;
+pub mod override_history;
@@ -0,0 +1,106 @@
+//! District override history tests
+//!
+//! Tests that PUT /api/config/overrides/district records each change in a
+//! versioned history listed by GET /api/config/overrides/district/history,
+//! and that POST /api/config/overrides/district/rollback/{version} restores
+//! a version and records the rollback as a new one.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+    let body_json: Value = serde_json::from_str(&body).unwrap_or(json!(null));
+    (status, body_json)
+}
+
+fn set_response_days(days: u64, district: &str) {
+    let (status, body) = send(Method::Put, "/api/config/overrides/district", Some(json!({
+        "deadlines.default_response_days": days
+    })), district);
+    assert_eq!(status, 200, "Config override should be saved: {}", body);
+}
+
+fn response_days(district: &str) -> Value {
+    let (status, overrides) = send(Method::Get, "/api/config/overrides/district", None, district);
+    assert_eq!(status, 200);
+    overrides["overrides"]["deadlines.default_response_days"].clone()
+}
+
+#[spin_test]
+fn test_update_history_rollback_round_trip() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    set_response_days(28, district);
+    set_response_days(35, district);
+
+    let (status, history) = send(Method::Get, "/api/config/overrides/district/history", None, district);
+    assert_eq!(status, 200);
+    let versions = history.as_array().unwrap();
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[0]["version"], 2);
+    assert_eq!(versions[0]["action"], "update");
+    assert_eq!(versions[0]["overrides"]["overrides"]["deadlines.default_response_days"], 35);
+    assert_eq!(versions[1]["version"], 1);
+    assert_eq!(versions[1]["overrides"]["overrides"]["deadlines.default_response_days"], 28);
+    assert!(versions[0]["saved_at"].is_string());
+
+    let (status, restored) = send(Method::Post, "/api/config/overrides/district/rollback/1", None, district);
+    assert_eq!(status, 200, "Rollback should succeed: {}", restored);
+    assert_eq!(restored["version"], 3);
+    assert_eq!(restored["action"], "rollback");
+    assert_eq!(restored["restored_from"], 1);
+    assert_eq!(response_days(district), 28);
+
+    // The rollback can itself be undone
+    let (status, _) = send(Method::Post, "/api/config/overrides/district/rollback/2", None, district);
+    assert_eq!(status, 200);
+    assert_eq!(response_days(district), 35);
+
+    let (_, history) = send(Method::Get, "/api/config/overrides/district/history", None, district);
+    assert_eq!(history.as_array().unwrap().len(), 4);
+}
+
+#[spin_test]
+fn test_clear_is_versioned_and_bad_versions_are_rejected() {
+    let district = "district12";
+    let _store = key_value::Store::open(district);
+
+    set_response_days(30, district);
+    let (status, _) = send(Method::Delete, "/api/config/overrides/district", None, district);
+    assert_eq!(status, 200);
+
+    let (_, history) = send(Method::Get, "/api/courts/district12/config/overrides/district/history", None, district);
+    assert_eq!(history[0]["action"], "clear");
+    assert_eq!(history[0]["overrides"]["overrides"], json!({}));
+
+    let (status, _) = send(Method::Post, "/api/courts/district12/config/overrides/district/rollback/1", None, district);
+    assert_eq!(status, 200);
+    assert_eq!(response_days(district), 30);
+
+    let (status, _) = send(Method::Post, "/api/config/overrides/district/rollback/99", None, district);
+    assert_eq!(status, 404);
+    let (status, _) = send(Method::Post, "/api/config/overrides/district/rollback/latest", None, district);
+    assert_eq!(status, 400);
+}
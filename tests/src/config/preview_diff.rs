@@ -0,0 +1,81 @@
+//! Configuration preview diff tests
+//!
+//! Tests that POST /api/config/preview?mode=diff and its URL-based twin
+//! return only the keys the proposed overrides would add, remove or change,
+//! each with its current and previewed value.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+    let body_json: Value = serde_json::from_str(&body).unwrap_or(json!(null));
+    (status, body_json)
+}
+
+fn save_district_overrides(district: &str) {
+    let (status, body) = send(Method::Put, "/api/config/overrides/district", Some(json!({
+        "deadlines.default_response_days": 28,
+        "workflow.preview_check_enabled": true
+    })), district);
+    assert_eq!(status, 200, "Config override should be saved: {}", body);
+}
+
+#[spin_test]
+fn test_preview_diff_lists_only_changed_keys() {
+    let _store = key_value::Store::open("district9");
+    save_district_overrides("district9");
+
+    let (status, body) = send(Method::Post, "/api/config/preview?mode=diff", Some(json!({
+        "deadlines.default_response_days": 14,
+        "workflow.preview_check_enabled": null,
+        "workflow.preview_added_enabled": true
+    })), "district9");
+    assert_eq!(status, 200, "Diff preview should succeed: {}", body);
+
+    let changes = &body["changes"];
+    assert_eq!(changes["deadlines.default_response_days"], json!({"kind": "changed", "from": 28, "to": 14}));
+    assert_eq!(changes["workflow.preview_check_enabled"], json!({"kind": "removed", "from": true, "to": null}));
+    assert_eq!(changes["workflow.preview_added_enabled"], json!({"kind": "added", "from": null, "to": true}));
+    assert!(changes.get("workflow.auto_docket_on_filing").is_none(), "Unchanged keys are left out: {}", changes);
+    assert_eq!(body["_metadata"]["district"], "district9");
+
+    // Nothing was applied
+    let (_, config) = send(Method::Get, "/api/config", None, "district9");
+    assert_eq!(config["deadlines"]["default_response_days"], 28);
+}
+
+#[spin_test]
+fn test_preview_diff_by_url_and_invalid_mode() {
+    let _store = key_value::Store::open("district12");
+    save_district_overrides("district12");
+
+    let (status, body) = send(Method::Post, "/api/courts/district12/config/preview?mode=diff", Some(json!({
+        "overrides": { "deadlines.default_response_days": 28 }
+    })), "district12");
+    assert_eq!(status, 200, "Diff preview should succeed: {}", body);
+    assert_eq!(body["changes"], json!({}), "Same value is no change");
+
+    let (status, _) = send(Method::Post, "/api/config/preview?mode=patch", Some(json!({})), "district12");
+    assert_eq!(status, 400);
+}
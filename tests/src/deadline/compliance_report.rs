@@ -0,0 +1,115 @@
+//! Compliance report tests
+//!
+//! Tests GET /api/compliance/report in JSON and as the multi-page PDF
+//! submitted to the Administrative Office.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const CASE_ID: &str = "6f1c2d3e-4a5b-4c6d-8e7f-9a0b1c2d3e4f";
+
+/// Helper to send a JSON request and return status, content type and raw body
+fn send_raw(method: Method, path: &str, body: Option<Value>) -> (u16, String, Vec<u8>) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(&"content-type".to_string())
+        .first()
+        .map(|v| String::from_utf8_lossy(v).to_string())
+        .unwrap_or_default();
+    let bytes = response.body().unwrap_or_default();
+    (status, content_type, bytes)
+}
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let (status, _, bytes) = send_raw(method, path, body);
+    let body: Value = serde_json::from_slice(&bytes).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn add_deadline(deadline_type: &str, due_date: &str, rule: &str, is_jurisdictional: bool) -> String {
+    let (status, body) = send(Method::Post, "/api/deadlines", Some(json!({
+        "case_id": CASE_ID,
+        "deadline_type": deadline_type,
+        "due_date": due_date,
+        "triggering_event": "criminal_judgment_entered",
+        "triggering_date": "2025-03-27T17:00:00Z",
+        "applicable_rule": rule,
+        "description": "Compliance report fixture",
+        "responsible_party": "Defendant",
+        "is_jurisdictional": is_jurisdictional,
+        "is_extendable": !is_jurisdictional
+    })));
+    assert_eq!(status, 201, "Deadline should be created: {}", body);
+    body["id"].as_str().unwrap().to_string()
+}
+
+fn mark_overdue(id: &str) {
+    let (status, body) = send(Method::Patch, &format!("/api/deadlines/{}/status", id), Some(json!({"status": "overdue"})));
+    assert_eq!(status, 200, "Status should update: {}", body);
+}
+
+#[spin_test]
+fn test_compliance_report_pdf_has_summary_and_appendix() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let missed = add_deadline("notice_of_appeal", "2025-04-10T21:00:00Z", "FRAP 4(b)(1)(A)", true);
+    mark_overdue(&missed);
+    add_deadline("motion", "2025-04-15T21:00:00Z", "FRCrP 33(b)(2)", false);
+
+    let (status, content_type, bytes) = send_raw(
+        Method::Get,
+        "/api/compliance/report?format=pdf&period_start=2025-04-01&period_end=2025-04-30",
+        None,
+    );
+    assert_eq!(status, 200);
+    assert_eq!(content_type, "application/pdf");
+    assert!(bytes.starts_with(b"%PDF"));
+
+    let text = String::from_utf8_lossy(&bytes);
+    assert!(text.contains("/Count 2"), "Summary and appendix should be separate pages");
+    assert!(text.contains("DEADLINE COMPLIANCE REPORT"));
+    assert!(text.contains("notice of appeal"));
+    assert!(text.contains("APPENDIX A - MISSED JURISDICTIONAL DEADLINES"));
+    assert!(text.contains(&format!("Deadline {}", missed)), "Missed deadline should be listed in the appendix");
+}
+
+#[spin_test]
+fn test_compliance_report_json_and_validation() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let missed = add_deadline("notice_of_appeal", "2025-04-10T21:00:00Z", "FRAP 4(b)(1)(A)", true);
+    mark_overdue(&missed);
+
+    let (status, body) = send(Method::Get, "/api/compliance/report?period_start=2025-04-01&period_end=2025-04-30", None);
+    assert_eq!(status, 200);
+    assert_eq!(body["deadlines_tracked"], 1);
+    assert_eq!(body["jurisdictional_violations"], 1);
+
+    let (status, _) = send(Method::Get, "/api/compliance/report?format=docx", None);
+    assert_eq!(status, 400);
+    let (status, _) = send(Method::Get, "/api/compliance/report?period_start=April", None);
+    assert_eq!(status, 400);
+    let (status, _) = send(Method::Get, "/api/compliance/report?period_start=2025-05-01&period_end=2025-04-01", None);
+    assert_eq!(status, 400);
+}
@@ -0,0 +1,127 @@
+//! Court closure calendar tests
+//!
+//! Tests for GET/POST/DELETE /api/config/closures and that closure dates
+//! are treated like holidays by deadline calculation and the calendar slot
+//! finder.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+/// FRCrP 29(c)(1) deadline, 14 calendar days after a verdict on Mon, March 3, 2025
+fn acquittal_motion_due_date(district: &str) -> String {
+    let (status, body) = send(Method::Post, "/api/deadlines/calculate", Some(json!({
+        "triggering_event": "verdict_entered",
+        "triggering_date": "2025-03-03T12:00:00Z",
+        "case_id": "550e8400-e29b-41d4-a716-446655440000"
+    })), district);
+    assert_eq!(status, 201, "Deadline calculation should succeed: {}", body);
+
+    let deadlines = body.as_array().unwrap();
+    let motion = deadlines.iter()
+        .find(|d| d["applicable_rule"] == "FRCrP 29(c)(1)")
+        .expect("Rule 29 deadline should be calculated");
+    motion["due_date"].as_str().unwrap()[..10].to_string()
+}
+
+#[spin_test]
+fn test_manage_closures() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, body) = send(Method::Get, "/api/config/closures", None, "district9");
+    assert_eq!(status, 200);
+    assert_eq!(body, json!([]));
+
+    let (status, _) = send(Method::Post, "/api/config/closures", Some(json!({
+        "date": "2025-03-17", "reason": "Snow emergency"
+    })), "district9");
+    assert_eq!(status, 201);
+
+    let (status, body) = send(Method::Post, "/api/config/closures", Some(json!({
+        "date": "2025-02-03", "reason": "Power outage"
+    })), "district9");
+    assert_eq!(status, 201);
+    assert_eq!(body[0]["date"], "2025-02-03", "Closures should be sorted by date");
+
+    let (status, _) = send(Method::Post, "/api/config/closures", Some(json!({
+        "date": "2025-03-17", "reason": "Still snowing"
+    })), "district9");
+    assert_eq!(status, 409, "Duplicate closure dates should be rejected");
+
+    let (status, _) = send(Method::Delete, "/api/config/closures?date=2025-02-03", None, "district9");
+    assert_eq!(status, 204);
+    let (status, _) = send(Method::Delete, "/api/config/closures?date=2025-02-03", None, "district9");
+    assert_eq!(status, 404);
+    let (status, _) = send(Method::Delete, "/api/config/closures?date=March", None, "district9");
+    assert_eq!(status, 400);
+
+    let (_, body) = send(Method::Get, "/api/config/closures", None, "district9");
+    assert_eq!(body.as_array().unwrap().len(), 1);
+}
+
+#[spin_test]
+fn test_deadline_landing_on_closure_rolls_forward() {
+    let _store = key_value::Store::open("district9");
+
+    assert_eq!(acquittal_motion_due_date("district9"), "2025-03-17");
+
+    let (status, _) = send(Method::Post, "/api/config/closures", Some(json!({
+        "date": "2025-03-17", "reason": "Snow emergency"
+    })), "district9");
+    assert_eq!(status, 201);
+
+    assert_eq!(acquittal_motion_due_date("district9"), "2025-03-18");
+}
+
+#[spin_test]
+fn test_slot_finder_skips_closure() {
+    let _store = key_value::Store::open("district9");
+
+    let path = "/api/calendar/available-slot/550e8400-e29b-41d4-a716-446655440001?earliest=2025-03-17T09:00:00Z";
+    let (status, body) = send(Method::Get, path, None, "district9");
+    assert_eq!(status, 200);
+    assert!(body["available_date"].as_str().unwrap().starts_with("2025-03-17"));
+
+    send(Method::Post, "/api/config/closures", Some(json!({
+        "date": "2025-03-17", "reason": "Snow emergency"
+    })), "district9");
+
+    let (status, body) = send(Method::Get, path, None, "district9");
+    assert_eq!(status, 200);
+    assert!(
+        body["available_date"].as_str().unwrap().starts_with("2025-03-18"),
+        "Slot finder should skip the closure: {}",
+        body
+    );
+}
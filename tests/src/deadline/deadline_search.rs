@@ -0,0 +1,165 @@
+//! Deadline search tests
+//!
+//! Tests for GET /api/deadlines/search with repeated and comma-separated
+//! filter values, which match any of the given values within a field, and
+//! with party, rule and due-date filters combined with sorting and
+//! pagination.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const CASE_A: &str = "3b8f2c4d-6e1a-4f7b-9c2d-5a6b7c8d9e0f";
+const CASE_B: &str = "8e1d4a2b-3c5f-4d6e-a7b8-c9d0e1f2a3b4";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to create a deadline and return its ID
+fn create_deadline(case_id: &str, deadline_type: &str, due_date: &str) -> String {
+    create_deadline_for(case_id, deadline_type, due_date, "Defendant", "FRCP 12(a)(1)(A)")
+}
+
+/// Helper to create a deadline for a party under a rule and return its ID
+fn create_deadline_for(case_id: &str, deadline_type: &str, due_date: &str, party: &str, rule: &str) -> String {
+    let (status, body) = send(Method::Post, "/api/deadlines", Some(json!({
+        "case_id": case_id,
+        "deadline_type": deadline_type,
+        "due_date": due_date,
+        "triggering_event": "complaint_filed",
+        "triggering_date": "2020-01-02T00:00:00Z",
+        "applicable_rule": rule,
+        "description": "Search test deadline",
+        "responsible_party": party,
+        "is_jurisdictional": false,
+        "is_extendable": true
+    })));
+    assert_eq!(status, 201, "Deadline should be created: {:?}", body);
+    body["id"].as_str().unwrap().to_string()
+}
+
+/// Searches deadlines, returning the IDs on the page and the total matched
+fn search_page(query: &str) -> (Vec<String>, u64) {
+    let (status, body) = send(Method::Get, &format!("/api/deadlines/search?{}", query), None);
+    assert_eq!(status, 200, "Search `{}` should succeed: {:?}", query, body);
+    let ids: Vec<String> = body["deadlines"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d["id"].as_str().unwrap().to_string())
+        .collect();
+    (ids, body["total"].as_u64().unwrap())
+}
+
+/// Searches deadlines, returning the matching IDs
+fn search(query: &str) -> Vec<String> {
+    let (ids, total) = search_page(query);
+    assert_eq!(total, ids.len() as u64);
+    ids
+}
+
+#[spin_test]
+fn test_search_deadlines_with_multi_valued_filters() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let overdue_answer = create_deadline(CASE_A, "answer", "2020-01-23T00:00:00Z");
+    let pending_motion = create_deadline(CASE_A, "motion", "2099-01-23T00:00:00Z");
+    let other_case = create_deadline(CASE_B, "reply", "2099-02-23T00:00:00Z");
+
+    let (status, _) = send(Method::Post, &format!("/api/deadlines/refresh-statuses?case_id={}", CASE_A), None);
+    assert_eq!(status, 200);
+
+    // Repeated keys and comma lists both OR within the field
+    let mut matched = search("status=overdue&status=pending");
+    matched.sort();
+    let mut all = vec![overdue_answer.clone(), pending_motion.clone(), other_case.clone()];
+    all.sort();
+    assert_eq!(matched, all);
+    assert_eq!(search("status=overdue,completed"), vec![overdue_answer.clone()]);
+
+    let mut matched = search("type=answer,reply");
+    matched.sort();
+    let mut expected = vec![overdue_answer.clone(), other_case.clone()];
+    expected.sort();
+    assert_eq!(matched, expected);
+
+    // Different fields still AND together
+    assert_eq!(search(&format!("case_id={}&type=answer&type=reply", CASE_A)), vec![overdue_answer.clone()]);
+    assert_eq!(search(&format!("case_id={},{}&status=pending", CASE_A, CASE_B)).len(), 2);
+    assert_eq!(search(&format!("case_id={}", CASE_B)), vec![other_case]);
+}
+
+#[spin_test]
+fn test_search_deadlines_rejects_invalid_filter_values() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    for query in [
+        "status=pending,someday",
+        "type=answer&type=nap",
+        "case_id=not-a-uuid",
+        "sort_by=priority",
+        "sort_by=due_date&direction=sideways",
+    ] {
+        let (status, body) = send(Method::Get, &format!("/api/deadlines/search?{}", query), None);
+        assert_eq!(status, 400, "`{}` should be rejected: {:?}", query, body);
+    }
+}
+
+#[spin_test]
+fn test_search_deadlines_combines_party_rule_and_due_date_with_pagination() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    // Five matches, created out of due-date order
+    let matching: Vec<String> = ["2099-03-05", "2099-03-01", "2099-03-04", "2099-03-02", "2099-03-03"]
+        .iter()
+        .map(|day| create_deadline_for(CASE_A, "answer", &format!("{}T00:00:00Z", day), "Defendant Acme Corp", "FRCP 12(a)(1)(A)"))
+        .collect();
+    create_deadline_for(CASE_A, "answer", "2099-03-01T00:00:00Z", "Plaintiff", "FRCP 12(a)(1)(A)");
+    create_deadline_for(CASE_A, "discovery", "2099-03-01T00:00:00Z", "Defendant Acme Corp", "FRCP 26(f)");
+    create_deadline_for(CASE_B, "answer", "2099-04-01T00:00:00Z", "Defendant Acme Corp", "FRCP 12(a)(1)(A)");
+
+    let filters = "responsible_party=acme&applicable_rule=12(a)&due_before=2099-03-31T00:00:00Z";
+    let by_due = [&matching[1], &matching[3], &matching[4], &matching[2], &matching[0]];
+
+    let (page, total) = search_page(&format!("{}&sort_by=due_date&limit=2", filters));
+    assert_eq!(total, 5);
+    assert_eq!(page, vec![by_due[0].clone(), by_due[1].clone()]);
+
+    let (page, total) = search_page(&format!("{}&sort_by=due_date&offset=2&limit=2", filters));
+    assert_eq!(total, 5);
+    assert_eq!(page, vec![by_due[2].clone(), by_due[3].clone()]);
+
+    let (page, _) = search_page(&format!("{}&sort_by=due_date&direction=desc&limit=2", filters));
+    assert_eq!(page, vec![by_due[4].clone(), by_due[3].clone()]);
+
+    // Creation order is the order the deadlines were filed above
+    let (page, total) = search_page(&format!("{}&sort_by=created&limit=3", filters));
+    assert_eq!(total, 5);
+    assert_eq!(page, matching[..3].to_vec());
+
+    let (page, total) = search_page(&format!("{}&due_after=2099-03-03T00:00:00Z&limit=10", filters));
+    assert_eq!(total, 3);
+    assert_eq!(page, vec![by_due[2].clone(), by_due[3].clone(), by_due[4].clone()]);
+}
@@ -0,0 +1,170 @@
+//! Deadline dependency chain tests
+//!
+//! Tests that a deadline created with `depends_on` and `offset_days` takes
+//! its due date from its parent, that GET /api/deadlines/:id/dependents lists
+//! the whole chain, that an extension granted on the parent moves every
+//! transitive dependent, and that a dependency closing a cycle is a 422.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const CASE_ID: &str = "8d3e6f1a-2b4c-4d5e-9f60-7a8b9c0d1e2f";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Create a brief deadline, either with its own due date or counted from a parent
+fn create_deadline(description: &str, due_date: Option<&str>, parent: Option<(&str, i64)>) -> Value {
+    let mut body = json!({
+        "case_id": CASE_ID,
+        "deadline_type": "response",
+        "triggering_event": "motion_filed",
+        "triggering_date": "2030-02-18T17:00:00Z",
+        "applicable_rule": "Local Rule 7.1",
+        "description": description,
+        "responsible_party": "Government",
+        "is_jurisdictional": false,
+        "is_extendable": true
+    });
+    if let Some(due_date) = due_date {
+        body["due_date"] = json!(due_date);
+    }
+    if let Some((parent_id, offset_days)) = parent {
+        body["depends_on"] = json!(parent_id);
+        body["offset_days"] = json!(offset_days);
+    }
+    let (status, deadline) = send(Method::Post, "/api/deadlines", Some(body));
+    assert_eq!(status, 201, "Deadline should be created: {}", deadline);
+    deadline
+}
+
+/// Response brief due Monday, March 4, 2030, a reply 7 days after it and a
+/// hearing memo 14 days after the reply
+fn briefing_chain() -> (String, String, String) {
+    let response = create_deadline("Response brief", Some("2030-03-04T17:00:00Z"), None);
+    let response_id = response["id"].as_str().unwrap().to_string();
+
+    let reply = create_deadline("Reply brief", None, Some((&response_id, 7)));
+    assert_eq!(reply["due_date"], "2030-03-11T17:00:00Z");
+    let reply_id = reply["id"].as_str().unwrap().to_string();
+
+    let memo = create_deadline("Hearing memo", None, Some((&reply_id, 14)));
+    assert_eq!(memo["due_date"], "2030-03-25T17:00:00Z");
+    let memo_id = memo["id"].as_str().unwrap().to_string();
+
+    (response_id, reply_id, memo_id)
+}
+
+fn due_date(deadline_id: &str) -> Value {
+    let (status, deadline) = send(Method::Get, &format!("/api/deadlines/{}", deadline_id), None);
+    assert_eq!(status, 200);
+    deadline["due_date"].clone()
+}
+
+#[spin_test]
+fn test_three_level_chain_lists_dependents() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (response_id, reply_id, memo_id) = briefing_chain();
+
+    let (status, dependents) = send(Method::Get, &format!("/api/deadlines/{}/dependents", response_id), None);
+    assert_eq!(status, 200);
+    let ids: Vec<&str> = dependents.as_array().unwrap().iter().map(|d| d["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, vec![reply_id.as_str(), memo_id.as_str()], "Nearest dependents come first");
+
+    let (status, dependents) = send(Method::Get, &format!("/api/deadlines/{}/dependents", memo_id), None);
+    assert_eq!(status, 200);
+    assert!(dependents.as_array().unwrap().is_empty());
+}
+
+#[spin_test]
+fn test_parent_extension_ripples_through_chain() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (response_id, reply_id, memo_id) = briefing_chain();
+
+    let (status, extension) = send(
+        Method::Post,
+        &format!("/api/deadlines/{}/extensions", response_id),
+        Some(json!({
+            "requested_by": "Government",
+            "new_due_date": "2030-03-18T17:00:00Z",
+            "reason": "Voluminous record",
+            "opposed_by": []
+        })),
+    );
+    assert_eq!(status, 201);
+    let (status, _) = send(
+        Method::Patch,
+        &format!("/api/extensions/{}/ruling", extension["id"].as_str().unwrap()),
+        Some(json!({"status": "granted", "order_text": "Granted"})),
+    );
+    assert_eq!(status, 200);
+
+    assert_eq!(due_date(&response_id), "2030-03-18T17:00:00Z");
+    assert_eq!(due_date(&reply_id), "2030-03-25T17:00:00Z");
+    assert_eq!(due_date(&memo_id), "2030-04-08T17:00:00Z");
+
+    let (_, memo) = send(Method::Get, &format!("/api/deadlines/{}", memo_id), None);
+    let history = memo["due_date_history"].as_array().unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0]["previous_due_date"], "2030-03-25T17:00:00Z");
+    assert_eq!(history[0]["recalculated_from"], reply_id.as_str());
+}
+
+#[spin_test]
+fn test_cycle_is_rejected() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (response_id, _, memo_id) = briefing_chain();
+
+    let (status, body) = send(
+        Method::Put,
+        &format!("/api/deadlines/{}/dependency", response_id),
+        Some(json!({"depends_on": memo_id, "offset_days": 3})),
+    );
+    assert_eq!(status, 422, "A dependency closing a cycle should be rejected: {}", body);
+    assert!(body["error"]["message"].as_str().unwrap().contains("cycle"));
+    assert_eq!(due_date(&response_id), "2030-03-04T17:00:00Z", "Rejected dependency should not be saved");
+
+    let (status, _) = send(
+        Method::Put,
+        &format!("/api/deadlines/{}/dependency", response_id),
+        Some(json!({"depends_on": response_id, "offset_days": 3})),
+    );
+    assert_eq!(status, 422);
+
+    let (status, _) = send(Method::Post, "/api/deadlines", Some(json!({
+        "case_id": CASE_ID,
+        "deadline_type": "reply",
+        "triggering_event": "motion_filed",
+        "triggering_date": "2030-02-18T17:00:00Z",
+        "applicable_rule": "Local Rule 7.1",
+        "description": "Reply without an offset",
+        "responsible_party": "Defendant",
+        "is_jurisdictional": false,
+        "is_extendable": true,
+        "depends_on": memo_id
+    })));
+    assert_eq!(status, 422, "depends_on without offset_days is incomplete");
+}
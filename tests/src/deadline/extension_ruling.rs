@@ -0,0 +1,150 @@
+//! Extension ruling integration tests
+//!
+//! Tests for PATCH /api/extensions/{extension_id}/ruling.
+//! Granting an extension moves the parent deadline's due date and records
+//! the change in `due_date_history`; denying leaves the deadline untouched.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to create an extendable deadline and return its ID
+fn create_deadline(due_date: &str, district: &str) -> String {
+    let (status, body) = send(Method::Post, "/api/deadlines", Some(json!({
+        "case_id": "5b0c7a4e-1f2d-4e3c-9a8b-7c6d5e4f3a2b",
+        "deadline_type": "answer",
+        "due_date": due_date,
+        "triggering_event": "complaint_filed",
+        "triggering_date": "2030-01-02T00:00:00Z",
+        "applicable_rule": "FRCP 12(a)(1)(A)",
+        "description": "Answer to complaint",
+        "responsible_party": "Defendant",
+        "is_jurisdictional": false,
+        "is_extendable": true
+    })), district);
+    assert_eq!(status, 201);
+    body["id"].as_str().unwrap().to_string()
+}
+
+/// Helper to request an extension and return its ID
+fn request_extension(deadline_id: &str, new_due_date: &str, district: &str) -> String {
+    let (status, body) = send(
+        Method::Post,
+        &format!("/api/deadlines/{}/extensions", deadline_id),
+        Some(json!({
+            "requested_by": "Defendant",
+            "new_due_date": new_due_date,
+            "reason": "Counsel recently retained",
+            "opposed_by": []
+        })),
+        district,
+    );
+    assert_eq!(status, 201);
+    body["id"].as_str().unwrap().to_string()
+}
+
+/// Helper to rule on an extension and return the status code
+fn rule(extension_id: &str, ruling: &str, district: &str) -> u16 {
+    let (status, _) = send(
+        Method::Patch,
+        &format!("/api/extensions/{}/ruling", extension_id),
+        Some(json!({ "status": ruling, "order_text": null })),
+        district,
+    );
+    status
+}
+
+fn get_deadline(deadline_id: &str, district: &str) -> Value {
+    let (status, body) = send(Method::Get, &format!("/api/deadlines/{}", deadline_id), None, district);
+    assert_eq!(status, 200);
+    body
+}
+
+#[spin_test]
+fn test_granted_extension_updates_due_date() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let deadline_id = create_deadline("2030-01-23T00:00:00Z", district);
+    let extension_id = request_extension(&deadline_id, "2030-02-20T00:00:00Z", district);
+
+    assert_eq!(rule(&extension_id, "granted", district), 200);
+
+    let deadline = get_deadline(&deadline_id, district);
+    assert_eq!(deadline["due_date"], "2030-02-20T00:00:00Z");
+    assert_eq!(deadline["status"], "pending");
+
+    let history = deadline["due_date_history"].as_array().unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0]["previous_due_date"], "2030-01-23T00:00:00Z");
+    assert_eq!(history[0]["new_due_date"], "2030-02-20T00:00:00Z");
+    assert_eq!(history[0]["extension_id"], extension_id);
+    assert!(history[0]["ruling_date"].is_string());
+}
+
+#[spin_test]
+fn test_denied_extension_leaves_deadline_untouched() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let deadline_id = create_deadline("2030-01-23T00:00:00Z", district);
+    let extension_id = request_extension(&deadline_id, "2030-02-20T00:00:00Z", district);
+
+    assert_eq!(rule(&extension_id, "denied", district), 200);
+
+    let deadline = get_deadline(&deadline_id, district);
+    assert_eq!(deadline["due_date"], "2030-01-23T00:00:00Z");
+    assert_eq!(deadline["status"], "pending");
+    assert!(deadline["due_date_history"].as_array().unwrap().is_empty());
+    assert_eq!(deadline["extension_requests"][0]["status"], "denied");
+}
+
+#[spin_test]
+fn test_sequential_extensions_on_one_deadline() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let deadline_id = create_deadline("2030-01-23T00:00:00Z", district);
+
+    let first = request_extension(&deadline_id, "2030-02-20T00:00:00Z", district);
+    assert_eq!(rule(&first, "granted", district), 200);
+
+    let second = request_extension(&deadline_id, "2030-03-20T00:00:00Z", district);
+    assert_eq!(rule(&second, "granted", district), 200);
+
+    let deadline = get_deadline(&deadline_id, district);
+    assert_eq!(deadline["due_date"], "2030-03-20T00:00:00Z");
+
+    let history = deadline["due_date_history"].as_array().unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0]["extension_id"], first);
+    assert_eq!(history[1]["extension_id"], second);
+    assert_eq!(history[1]["previous_due_date"], "2030-02-20T00:00:00Z");
+
+    // A request that has already been ruled on cannot be ruled on again
+    assert_eq!(rule(&first, "denied", district), 409);
+}
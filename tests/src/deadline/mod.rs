@@ -6,3 +6,22 @@
 
 // FRCP deadline calculation tests
 pub mod frcp_calculate;
+
+// Extension ruling tests
+pub mod extension_ruling;
+
+// Tenant initialization seeding tests
+pub mod tenant_seed;
+
+// Bulk status refresh tests
+pub mod status_refresh;
+
+// Court closure calendar tests
+pub mod court_closures;
+
+// Compliance report tests
+pub mod compliance_report;
+
+// Multi-valued search filter tests
+pub mod deadline_search;
+pub mod dependency_chain;
@@ -0,0 +1,98 @@
+//! Deadline status refresh tests
+//!
+//! Tests for POST /api/deadlines/refresh-statuses, which recomputes deadline
+//! statuses against the current time and persists the ones that changed.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const CASE_ID: &str = "7d2e9c1a-4b3f-4a5e-8c6d-1e2f3a4b5c6d";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to create a pending deadline and return its ID
+fn create_deadline(due_date: &str, district: &str) -> String {
+    let (status, body) = send(Method::Post, "/api/deadlines", Some(json!({
+        "case_id": CASE_ID,
+        "deadline_type": "answer",
+        "due_date": due_date,
+        "triggering_event": "complaint_filed",
+        "triggering_date": "2020-01-02T00:00:00Z",
+        "applicable_rule": "FRCP 12(a)(1)(A)",
+        "description": "Answer to complaint",
+        "responsible_party": "Defendant",
+        "is_jurisdictional": false,
+        "is_extendable": true
+    })), district);
+    assert_eq!(status, 201);
+    assert_eq!(body["status"], "pending");
+    body["id"].as_str().unwrap().to_string()
+}
+
+#[spin_test]
+fn test_past_due_deadline_transitions_to_overdue() {
+    let _store = key_value::Store::open("district9");
+
+    let past_due = create_deadline("2020-01-23T00:00:00Z", "district9");
+    create_deadline("2099-01-23T00:00:00Z", "district9");
+
+    let (status, body) = send(
+        Method::Post,
+        &format!("/api/deadlines/refresh-statuses?case_id={}", CASE_ID),
+        None,
+        "district9",
+    );
+    assert_eq!(status, 200, "Refresh should succeed: {:?}", body);
+    assert_eq!(body["checked"], 2);
+    assert_eq!(body["transitioned"], 1);
+    assert_eq!(body["by_status"], json!({"overdue": 1}));
+    assert_eq!(body["transitions"][0]["deadline_id"], past_due.as_str());
+    assert_eq!(body["transitions"][0]["from"], "pending");
+
+    // The new status is persisted, not just reported
+    let (_, deadline) = send(Method::Get, &format!("/api/deadlines/{}", past_due), None, "district9");
+    assert_eq!(deadline["status"], "overdue");
+
+    // Nothing left to change on a second run
+    let (_, body) = send(
+        Method::Post,
+        &format!("/api/deadlines/refresh-statuses?case_id={}", CASE_ID),
+        None,
+        "district9",
+    );
+    assert_eq!(body["transitioned"], 0);
+}
+
+#[spin_test]
+fn test_district_wide_refresh_requires_admin() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, _) = send(Method::Post, "/api/deadlines/refresh-statuses", None, "district9");
+    assert_eq!(status, 403);
+
+    let (status, _) = send(Method::Post, "/api/deadlines/refresh-statuses?case_id=not-a-uuid", None, "district9");
+    assert_eq!(status, 400);
+}
@@ -0,0 +1,113 @@
+//! Tenant seeding integration tests
+//!
+//! Tests for POST /api/admin/init-tenant.
+//! Initialization seeds the deadline rule set, feature flags, reminder
+//! schedule and starter order templates, and is safe to re-run: seeds that
+//! are current or were modified by the district are reported as skipped.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to initialize the tenant and return the seed report
+fn init_tenant(district: &str) -> Value {
+    let (status, report) = send(Method::Post, "/api/admin/init-tenant", None, district);
+    assert_eq!(status, 200, "init-tenant should return 200, got {}: {:?}", status, report);
+    report
+}
+
+/// Collect the keys listed under a report section
+fn keys(report: &Value, section: &str) -> Vec<String> {
+    report[section]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry.as_str().or(entry["key"].as_str()).unwrap().to_string())
+        .collect()
+}
+
+#[spin_test]
+fn test_init_tenant_seeds_rules_usable_by_calculate() {
+    let _store = key_value::Store::open("district9");
+
+    let report = init_tenant("district9");
+    let seeded = keys(&report, "seeded");
+    assert!(seeded.contains(&"deadline_rule:frcp-12-a-1-a".to_string()), "seeded: {:?}", seeded);
+    assert!(seeded.contains(&"config:deadlines.reminder_days".to_string()), "seeded: {:?}", seeded);
+    assert!(seeded.contains(&"order_template:seed-scheduling-order".to_string()), "seeded: {:?}", seeded);
+    assert!(report["skipped"].as_array().unwrap().is_empty());
+
+    let (status, deadlines) = send(Method::Post, "/api/deadlines/calculate", Some(json!({
+        "triggering_event": "complaint_filed",
+        "triggering_date": "2030-01-10T12:00:00Z",
+        "case_id": "550e8400-e29b-41d4-a716-446655440001"
+    })), "district9");
+    assert_eq!(status, 201, "calculate should return 201, got {}: {:?}", status, deadlines);
+    assert!(!deadlines.as_array().unwrap().is_empty(), "Seeded rules should produce deadlines");
+}
+
+#[spin_test]
+fn test_reinit_skips_current_seeds() {
+    let _store = key_value::Store::open("district9");
+
+    let first = init_tenant("district9");
+    let second = init_tenant("district9");
+
+    assert!(keys(&second, "seeded").is_empty());
+    assert!(keys(&second, "upgraded").is_empty());
+    assert_eq!(keys(&second, "skipped").len(), keys(&first, "seeded").len());
+    assert!(second["skipped"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|s| s["reason"] == "up_to_date"));
+}
+
+#[spin_test]
+fn test_reinit_preserves_modified_template() {
+    let _store = key_value::Store::open("district9");
+
+    init_tenant("district9");
+
+    let (status, mut template) = send(Method::Get, "/api/templates/orders/seed-scheduling-order", None, "district9");
+    assert_eq!(status, 200);
+    template["description"] = json!("District-specific scheduling order");
+    let (status, _) = send(Method::Put, "/api/templates/orders/seed-scheduling-order", Some(template), "district9");
+    assert_eq!(status, 200);
+
+    let report = init_tenant("district9");
+    let modified = report["skipped"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|s| s["key"] == "order_template:seed-scheduling-order")
+        .expect("Modified template should be reported as skipped");
+    assert_eq!(modified["reason"], "modified");
+
+    let (_, template) = send(Method::Get, "/api/templates/orders/seed-scheduling-order", None, "district9");
+    assert_eq!(template["description"], "District-specific scheduling order");
+}
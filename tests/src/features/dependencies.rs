@@ -0,0 +1,89 @@
+//! Feature dependency tests
+//!
+//! Tests that PATCH /api/features refuses to enable a feature whose
+//! prerequisites are disabled, listing them, and that `?cascade=true`
+//! enables the prerequisites along with it.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), b"district9").unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn enabled_features() -> Vec<String> {
+    let (status, features) = send(Method::Get, "/api/features", None);
+    assert_eq!(status, 200);
+    serde_json::from_value(features["enabled_features"].clone()).unwrap()
+}
+
+#[spin_test]
+fn test_enabling_with_missing_dependency_is_rejected() {
+    let _store = key_value::Store::open("default");
+
+    let (status, error) = send(Method::Patch, "/api/features", Some(json!({
+        "feature_path": "calendar_scheduling",
+        "enabled": true
+    })));
+    assert_eq!(status, 400, "{}", error);
+    assert_eq!(error["error"]["details"]["unmet_dependencies"], json!(["advanced.judge_assignment"]));
+    assert!(!enabled_features().contains(&"advanced.automated_scheduling".to_string()));
+
+    // Once the prerequisite is on, the feature can be enabled
+    let (status, _) = send(Method::Patch, "/api/features", Some(json!({
+        "feature_path": "judge_assignment",
+        "enabled": true
+    })));
+    assert_eq!(status, 200);
+    let (status, body) = send(Method::Patch, "/api/features", Some(json!({
+        "feature_path": "calendar_scheduling",
+        "enabled": true
+    })));
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body["enabled_dependencies"], json!([]));
+
+    let (status, _) = send(Method::Patch, "/api/features", Some(json!({
+        "feature_path": "time_travel",
+        "enabled": true
+    })));
+    assert_eq!(status, 400);
+}
+
+#[spin_test]
+fn test_cascade_enables_prerequisites() {
+    let _store = key_value::Store::open("default");
+
+    let (status, body) = send(Method::Patch, "/api/features?cascade=true", Some(json!({
+        "feature_path": "experimental.automated_transcription",
+        "enabled": true
+    })));
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body["feature_path"], "experimental.automated_transcription");
+    assert_eq!(body["enabled_dependencies"], json!(["advanced.judge_assignment", "advanced.automated_scheduling"]));
+
+    let enabled = enabled_features();
+    for feature in ["advanced.judge_assignment", "advanced.automated_scheduling", "experimental.automated_transcription"] {
+        assert!(enabled.contains(&feature.to_string()), "{} should be enabled: {:?}", feature, enabled);
+    }
+}
@@ -0,0 +1,9 @@
+//! Feature flag tests
+//!
+//! This module contains tests for feature flag evaluation and rollouts
+
+// Percentage rollout and district targeting tests
+pub mod rollout;
+
+// Feature dependency enforcement tests
+pub mod dependencies;
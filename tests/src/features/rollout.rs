@@ -0,0 +1,141 @@
+//! Feature rollout tests
+//!
+//! Tests that POST /api/features/override accepts a percentage, district
+//! allow and deny lists and an expiry, and that
+//! GET /api/features/enabled/:feature_path evaluates them for the requesting
+//! district, explaining the decision with `?explain=true`. A `?key=`
+//! buckets that key for the percentage instead of the district.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const FEATURE: &str = "experimental.predictive_analytics";
+
+/// Helper to send a request as a district
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn set_override(body: Value) -> u16 {
+    send(Method::Post, "/api/features/override", Some(body), "district9").0
+}
+
+fn explain(district: &str) -> Value {
+    let (status, body) = send(Method::Get, &format!("/api/features/enabled/{}?explain=true", FEATURE), None, district);
+    assert_eq!(status, 200, "{}", body);
+    body
+}
+
+#[spin_test]
+fn test_deny_list_beats_percentage() {
+    let _store = key_value::Store::open("default");
+    assert_eq!(set_override(json!({
+        "feature_path": FEATURE,
+        "enabled": false,
+        "percentage": 100,
+        "deny_districts": ["DISTRICT9"]
+    })), 200);
+
+    let denied = explain("district9");
+    assert_eq!(denied["enabled"], false);
+    assert_eq!(denied["explanation"]["reason"], "district_denylist");
+
+    let bucketed = explain("district12");
+    assert_eq!(bucketed["enabled"], true);
+    assert_eq!(bucketed["explanation"]["reason"], "percentage_bucket");
+    assert!(bucketed["explanation"]["bucket"].as_u64().unwrap() < 100);
+}
+
+#[spin_test]
+fn test_percentage_bucket_is_deterministic() {
+    let _store = key_value::Store::open("default");
+    assert_eq!(set_override(json!({"feature_path": FEATURE, "enabled": false, "percentage": 50})), 200);
+
+    let first = explain("sdny");
+    for _ in 0..3 {
+        assert_eq!(explain("sdny"), first, "The same district gets the same answer");
+    }
+    let bucket = first["explanation"]["bucket"].as_u64().unwrap();
+    assert_eq!(first["enabled"], bucket < 50);
+}
+
+#[spin_test]
+fn test_allow_list_and_expiry() {
+    let _store = key_value::Store::open("default");
+    assert_eq!(set_override(json!({
+        "feature_path": FEATURE,
+        "enabled": false,
+        "percentage": 0,
+        "allow_districts": ["edny"]
+    })), 200);
+    assert_eq!(explain("edny")["explanation"]["reason"], "district_allowlist");
+    assert_eq!(explain("edny")["enabled"], true);
+
+    assert_eq!(set_override(json!({
+        "feature_path": FEATURE,
+        "enabled": true,
+        "allow_districts": ["edny"],
+        "expires_at": "2020-01-01T00:00:00Z"
+    })), 200);
+    let expired = explain("edny");
+    assert_eq!(expired["enabled"], false, "Expired rollouts revert to the default: {}", expired);
+    assert_eq!(expired["explanation"]["reason"], "expired");
+}
+
+#[spin_test]
+fn test_explanation_only_when_asked_and_percentage_validated() {
+    let _store = key_value::Store::open("default");
+
+    let (status, body) = send(Method::Get, &format!("/api/features/enabled/{}", FEATURE), None, "district9");
+    assert_eq!(status, 200);
+    assert_eq!(body["enabled"], false);
+    assert!(body.get("explanation").is_none(), "{}", body);
+    assert_eq!(explain("district9")["explanation"]["reason"], "default");
+
+    assert_eq!(set_override(json!({"feature_path": FEATURE, "enabled": true, "percentage": 120})), 400);
+}
+
+#[spin_test]
+fn test_rollout_key_is_bucketed_stably() {
+    let _store = key_value::Store::open("default");
+    assert_eq!(set_override(json!({"feature_path": FEATURE, "enabled": false, "rollout_percent": 50})), 200);
+
+    let check = |key: &str, district: &str| {
+        let path = format!("/api/features/enabled/{}?key={}&explain=true", FEATURE, key);
+        let (status, body) = send(Method::Get, &path, None, district);
+        assert_eq!(status, 200, "{}", body);
+        body
+    };
+
+    let enabled: Vec<bool> = (0..40)
+        .map(|i| {
+            let key = format!("user-{}", i);
+            let first = check(&key, "sdny");
+            assert_eq!(first["explanation"]["reason"], "percentage_bucket");
+            assert_eq!(first["enabled"], first["explanation"]["bucket"].as_u64().unwrap() < 50);
+            assert_eq!(check(&key, "edny"), first, "The key, not the district, decides");
+            first["enabled"].as_bool().unwrap()
+        })
+        .collect();
+    assert!(enabled.contains(&true) && enabled.contains(&false));
+}
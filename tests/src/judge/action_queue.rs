@@ -0,0 +1,98 @@
+//! Judicial action queue tests
+//!
+//! Tests that filings awaiting a ruling surface on the assigned judge's queue.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to file a docket entry on a case
+fn file_entry(case_id: &str, entry_type: &str, description: &str, district: &str) {
+    let (status, _) = send(Method::Post, "/api/docket/entries", Some(json!({
+        "case_id": case_id,
+        "entry_type": entry_type,
+        "description": description,
+        "filed_by": "Defense Counsel",
+        "is_sealed": false,
+        "is_ex_parte": false,
+        "page_count": null,
+        "service_list": []
+    })), district);
+    assert_eq!(status, 201);
+}
+
+#[spin_test]
+fn test_action_queue_only_includes_filings_awaiting_judge() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": "Judge Action",
+        "title": "district_judge",
+        "district": district,
+        "courtroom": "4B"
+    })), district);
+    assert_eq!(status, 201);
+    let judge_id = judge["id"].as_str().unwrap().to_string();
+
+    let case_id = "7f1c2d3e-4a5b-4c6d-8e9f-0a1b2c3d4e5f";
+    let (status, assignment) = send(Method::Post, "/api/assignments", Some(json!({
+        "case_id": case_id,
+        "case_type": "civil",
+        "parties": [],
+        "preferred_date": null,
+        "assignment_type": "random",
+        "reason": "Initial assignment"
+    })), district);
+    assert_eq!(status, 201);
+    assert_eq!(assignment["judge_id"], judge_id);
+
+    file_entry(case_id, "motion", "Motion to dismiss", district);
+    file_entry(case_id, "notice", "Notice of appearance", district);
+
+    let (status, queue) = send(Method::Get, &format!("/api/judges/{}/action-queue", judge_id), None, district);
+    assert_eq!(status, 200);
+
+    let items = queue.as_array().unwrap();
+    assert_eq!(items.len(), 1, "only the motion should await the judge: {}", queue);
+    assert_eq!(items[0]["entry"]["entry_type"], "motion");
+    assert_eq!(items[0]["entry"]["case_id"], case_id);
+}
+
+#[spin_test]
+fn test_action_queue_unknown_judge_returns_404() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let (status, _) = send(
+        Method::Get,
+        "/api/judges/00000000-0000-0000-0000-000000000000/action-queue",
+        None,
+        district,
+    );
+    assert_eq!(status, 404);
+}
@@ -0,0 +1,120 @@
+//! Assignment conflict screening tests
+//!
+//! Tests that POST /api/assignments screens every available judge against
+//! the case's parties, reports the judges it excluded, and returns 409 when
+//! no conflict-free judge remains.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to create a judge and return its ID
+fn create_judge(name: &str, district: &str) -> String {
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": name,
+        "title": "district_judge",
+        "district": district,
+        "courtroom": "5C"
+    })), district);
+    assert_eq!(status, 201);
+    judge["id"].as_str().unwrap().to_string()
+}
+
+/// Helper to record a judge's conflict with a party
+fn add_conflict(judge_id: &str, party: &str, district: &str) {
+    let (status, _) = send(Method::Post, &format!("/api/judges/{}/conflicts", judge_id), Some(json!({
+        "party_name": party,
+        "conflict_type": "stock_ownership",
+        "notes": "Holds shares"
+    })), district);
+    assert_eq!(status, 201);
+}
+
+fn assignment_request(parties: Value) -> Value {
+    json!({
+        "case_id": "00000000-0000-4000-8000-0000000000c1",
+        "case_type": "civil",
+        "parties": parties,
+        "preferred_date": null,
+        "assignment_type": "random",
+        "reason": "Initial assignment",
+        "strategy": "random"
+    })
+}
+
+#[spin_test]
+fn test_assignment_goes_to_only_conflict_free_judge() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let alpha = create_judge("Alpha Judge", district);
+    let bravo = create_judge("Bravo Judge", district);
+    let charlie = create_judge("Charlie Judge", district);
+    add_conflict(&alpha, "Acme Corp", district);
+    add_conflict(&charlie, "Acme Corp", district);
+
+    let (status, body) = send(
+        Method::Post,
+        "/api/assignments",
+        Some(assignment_request(json!(["Acme Corp", "Jane Roe"]))),
+        district,
+    );
+
+    assert_eq!(status, 201, "Assignment should succeed: {:?}", body);
+    assert_eq!(body["judge_id"], bravo.as_str());
+
+    let excluded = body["excluded_judges"].as_array().unwrap();
+    let mut excluded_ids: Vec<&str> = excluded.iter().map(|e| e["judge_id"].as_str().unwrap()).collect();
+    excluded_ids.sort();
+    let mut expected = vec![alpha.as_str(), charlie.as_str()];
+    expected.sort();
+    assert_eq!(excluded_ids, expected);
+    assert!(excluded.iter().all(|e| e["conflicting_parties"] == json!(["Acme Corp"])));
+}
+
+#[spin_test]
+fn test_assignment_returns_409_when_every_judge_conflicts() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let alpha = create_judge("Alpha Judge", district);
+    let bravo = create_judge("Bravo Judge", district);
+    add_conflict(&alpha, "Acme Corp", district);
+    add_conflict(&bravo, "Globex", district);
+
+    let (status, body) = send(
+        Method::Post,
+        "/api/assignments",
+        Some(assignment_request(json!(["Acme Corp", "Globex"]))),
+        district,
+    );
+
+    assert_eq!(status, 409, "Assignment should conflict: {:?}", body);
+    let details = body["error"]["message"].as_str().unwrap();
+    assert!(details.contains("Alpha Judge (Acme Corp)"), "details: {}", details);
+    assert!(details.contains("Bravo Judge (Globex)"), "details: {}", details);
+}
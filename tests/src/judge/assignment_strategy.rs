@@ -0,0 +1,121 @@
+//! Case assignment strategy tests
+//!
+//! Tests for the per-request `strategy` override on POST /api/assignments.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+
+/// Helper to create a judge and return its ID
+fn create_judge(name: &str, district: &str) -> String {
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": name,
+        "title": "district_judge",
+        "district": district,
+        "courtroom": "4A"
+    })), district);
+    assert_eq!(status, 201);
+    judge["id"].as_str().unwrap().to_string()
+}
+
+/// Helper to assign a fresh civil case with the given strategy, returning the judge ID
+fn assign(strategy: &str, parties: Value, district: &str) -> String {
+    let (status, assignment) = send(Method::Post, "/api/assignments", Some(json!({
+        "case_id": uuid_for_test(),
+        "case_type": "civil",
+        "parties": parties,
+        "preferred_date": null,
+        "assignment_type": "random",
+        "reason": "Initial assignment",
+        "strategy": strategy
+    })), district);
+    assert_eq!(status, 201, "assignment failed: {}", assignment);
+    let recorded = match strategy {
+        "random" => "random_draw",
+        other => other,
+    };
+    assert_eq!(assignment["strategy"], recorded);
+    assert!(assignment["draw_seed"].is_u64(), "the server records the seed it drew with");
+    assignment["judge_id"].as_str().unwrap().to_string()
+}
+
+/// Distinct case IDs so assignments do not overwrite one another
+fn uuid_for_test() -> String {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    static NEXT: AtomicU32 = AtomicU32::new(1);
+    format!("00000000-0000-4000-8000-{:012x}", NEXT.fetch_add(1, Ordering::SeqCst))
+}
+
+#[spin_test]
+fn test_round_robin_request_walks_the_wheel() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let alpha = create_judge("Alpha Judge", district);
+    let bravo = create_judge("Bravo Judge", district);
+    let charlie = create_judge("Charlie Judge", district);
+
+    assert_eq!(assign("round_robin", json!([]), district), alpha);
+    assert_eq!(assign("round_robin", json!([]), district), bravo);
+    assert_eq!(assign("round_robin", json!([]), district), charlie);
+    assert_eq!(assign("round_robin", json!([]), district), alpha);
+}
+
+#[spin_test]
+fn test_round_robin_skips_conflicted_judge() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let alpha = create_judge("Alpha Judge", district);
+    let bravo = create_judge("Bravo Judge", district);
+    let charlie = create_judge("Charlie Judge", district);
+
+    let (status, _) = send(Method::Post, &format!("/api/judges/{}/conflicts", bravo), Some(json!({
+        "party_name": "Acme Corp",
+        "conflict_type": "stock_ownership",
+        "notes": "Holds shares"
+    })), district);
+    assert_eq!(status, 201);
+
+    assert_eq!(assign("round_robin", json!([]), district), alpha);
+    assert_eq!(assign("round_robin", json!(["Acme Corp"]), district), charlie);
+    // Bravo kept their seat on the wheel and comes up after the next lap
+    assert_eq!(assign("round_robin", json!([]), district), alpha);
+    assert_eq!(assign("round_robin", json!([]), district), bravo);
+}
+
+#[spin_test]
+fn test_random_and_balanced_strategies_are_accepted() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let judge_id = create_judge("Only Judge", district);
+
+    assert_eq!(assign("random", json!([]), district), judge_id);
+    assert_eq!(assign("balanced", json!([]), district), judge_id);
+}
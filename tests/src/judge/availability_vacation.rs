@@ -0,0 +1,98 @@
+//! Judge availability vacation tests
+//!
+//! Tests that GET /api/judges/available leaves out judges whose vacation
+//! covers the requested `on_date` (or now, when omitted).
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Create a judge and return its ID
+fn create_judge(name: &str, district: &str) -> String {
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": name,
+        "title": "district_judge",
+        "district": district,
+        "courtroom": "7A"
+    })), district);
+    assert_eq!(status, 201);
+    judge["id"].as_str().unwrap().to_string()
+}
+
+/// Give a stored judge a vacation window (there is no endpoint for this yet)
+fn set_vacation(store: &key_value::Store, judge_id: &str, start: &str, end: &str) {
+    let key = format!("district9:judge-{}", judge_id);
+    let mut judge: Value = serde_json::from_slice(&store.get(&key).unwrap().unwrap()).unwrap();
+    judge["availability"]["vacation_dates"] = json!([{ "start": start, "end": end }]);
+    store.set(&key, &serde_json::to_vec(&judge).unwrap()).unwrap();
+}
+
+fn available_ids(path: &str, district: &str) -> Vec<String> {
+    let (status, body) = send(Method::Get, path, None, district);
+    assert_eq!(status, 200, "Available judges should return 200: {:?}", body);
+    body.as_array()
+        .unwrap()
+        .iter()
+        .map(|j| j["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[spin_test]
+fn test_judge_on_vacation_now_is_not_available() {
+    let district = "district9";
+    let store = key_value::Store::open(district);
+
+    let present = create_judge("Judge Present", district);
+    let away = create_judge("Judge Away", district);
+    set_vacation(&store, &away, "2000-01-01T00:00:00Z", "2999-12-31T23:59:59Z");
+
+    let ids = available_ids("/api/judges/available", district);
+    assert!(ids.contains(&present));
+    assert!(!ids.contains(&away), "Judge on vacation should not be available");
+}
+
+#[spin_test]
+fn test_on_date_checks_future_vacation() {
+    let district = "district9";
+    let store = key_value::Store::open(district);
+
+    let judge = create_judge("Judge Summer", district);
+    set_vacation(&store, &judge, "2031-07-01T00:00:00Z", "2031-07-14T23:59:59Z");
+
+    assert!(available_ids("/api/judges/available", district).contains(&judge));
+    assert!(!available_ids("/api/judges/available?on_date=2031-07-04", district).contains(&judge));
+    assert!(available_ids("/api/judges/available?on_date=2031-07-15", district).contains(&judge));
+}
+
+#[spin_test]
+fn test_invalid_on_date_is_rejected() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let (status, _) = send(Method::Get, "/api/judges/available?on_date=next-week", None, district);
+    assert_eq!(status, 400);
+}
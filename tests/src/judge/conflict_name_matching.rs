@@ -0,0 +1,88 @@
+//! Conflict name matching tests
+//!
+//! Tests that GET /api/judges/conflicts/check/{party} finds conflicts
+//! recorded under a variant of the party's name, saying whether each hit
+//! was exact, normalized or fuzzy, and honours the `threshold` parameter.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to create a judge with a conflict recorded against `party`
+fn judge_with_conflict(name: &str, party: &str, district: &str) -> String {
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": name,
+        "title": "district_judge",
+        "district": district,
+        "courtroom": "5C"
+    })), district);
+    assert_eq!(status, 201);
+    let judge_id = judge["id"].as_str().unwrap().to_string();
+
+    let (status, _) = send(Method::Post, &format!("/api/judges/{}/conflicts", judge_id), Some(json!({
+        "party_name": party,
+        "conflict_type": "stock_ownership",
+        "notes": "Holds shares"
+    })), district);
+    assert_eq!(status, 201);
+    judge_id
+}
+
+#[spin_test]
+fn test_check_matches_name_variants() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let acme = judge_with_conflict("Alpha Judge", "ACME Corporation", district);
+    let globex = judge_with_conflict("Bravo Judge", "Globex Industries", district);
+    judge_with_conflict("Charlie Judge", "Initech", district);
+
+    let (status, body) = send(Method::Get, "/api/judges/conflicts/check/Acme%20Corp.", None, district);
+    assert_eq!(status, 200);
+    assert_eq!(body["conflicts_found"], true);
+    assert_eq!(body["judges_with_conflicts"], json!([acme]));
+    let hit = &body["matches"][0];
+    assert_eq!(hit["kind"], "normalized");
+    assert_eq!(hit["matched"], "ACME Corporation");
+    assert_eq!(hit["confidence"], 1.0);
+
+    let (_, body) = send(Method::Get, "/api/judges/conflicts/check/Globex%20Industires", None, district);
+    assert_eq!(body["judges_with_conflicts"], json!([globex]));
+    assert_eq!(body["matches"][0]["kind"], "fuzzy");
+    assert!(body["matches"][0]["confidence"].as_f64().unwrap() < 1.0);
+
+    // A stricter threshold drops the typo
+    let (_, body) = send(Method::Get, "/api/judges/conflicts/check/Globex%20Industires?threshold=0.995", None, district);
+    assert_eq!(body["conflicts_found"], false);
+
+    let (_, body) = send(Method::Get, "/api/judges/conflicts/check/Umbrella%20LLC", None, district);
+    assert_eq!(body["conflicts_found"], false);
+    assert_eq!(body["matches"], json!([]));
+
+    let (status, _) = send(Method::Get, "/api/judges/conflicts/check/Acme?threshold=2", None, district);
+    assert_eq!(status, 400);
+}
@@ -0,0 +1,157 @@
+//! Daily calendar tests
+//!
+//! Tests that GET /api/calendar/daily groups a day's proceedings by courtroom
+//! in start order, names the case and judge, and flags double-bookings.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to send a JSON request and return status, content type and raw body
+fn send_raw(method: Method, path: &str, body: Option<Value>) -> (u16, String, Vec<u8>) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(&"content-type".to_string())
+        .first()
+        .map(|v| String::from_utf8_lossy(v).to_string())
+        .unwrap_or_default();
+    let bytes = response.body().unwrap_or_default();
+    (status, content_type, bytes)
+}
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let (status, _, bytes) = send_raw(method, path, body);
+    let body: Value = serde_json::from_slice(&bytes).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_judge(name: &str, courtroom: &str) -> String {
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": name,
+        "title": "district_judge",
+        "district": DISTRICT,
+        "courtroom": courtroom
+    })));
+    assert_eq!(status, 201, "Judge should be created: {}", judge);
+    judge["id"].as_str().unwrap().to_string()
+}
+
+/// Creates a case, returning its ID and case number
+fn create_case(title: &str) -> (String, String) {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": title,
+        "description": "Case for daily calendar tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })));
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    (case["id"].as_str().unwrap().to_string(), case["caseNumber"].as_str().unwrap().to_string())
+}
+
+fn schedule(case_id: &str, judge_id: &str, scheduled_date: &str, duration_minutes: u32, courtroom: &str) -> String {
+    let (status, event) = send(Method::Post, "/api/calendar/events", Some(json!({
+        "case_id": case_id,
+        "judge_id": judge_id,
+        "event_type": "status_conference",
+        "scheduled_date": scheduled_date,
+        "duration_minutes": duration_minutes,
+        "courtroom": courtroom,
+        "description": "Status conference",
+        "participants": ["Government", "Defense"],
+        "is_public": true
+    })));
+    assert_eq!(status, 201, "Event should be scheduled: {}", event);
+    event["id"].as_str().unwrap().to_string()
+}
+
+fn event_ids(courtroom: &Value) -> Vec<&str> {
+    courtroom["events"].as_array().unwrap().iter().map(|e| e["event_id"].as_str().unwrap()).collect()
+}
+
+#[spin_test]
+fn test_daily_calendar_groups_sorts_and_flags_conflicts() {
+    let _store = key_value::Store::open(DISTRICT);
+    let judge_a = create_judge("Judge Alder", "12A");
+    let judge_b = create_judge("Judge Birch", "9B");
+    let (case_1, case_number_1) = create_case("United States v. Alder");
+    let (case_2, _) = create_case("United States v. Birch");
+    let (case_3, _) = create_case("United States v. Cedar");
+
+    // Two judges booked into 12A for overlapping times, scheduled out of order
+    let later = schedule(&case_2, &judge_b, "2099-06-01T10:00:00Z", 60, "12A");
+    let earlier = schedule(&case_1, &judge_a, "2099-06-01T09:30:00Z", 60, "12A");
+    let other_room = schedule(&case_3, &judge_a, "2099-06-01T14:00:00Z", 30, "9B");
+    schedule(&case_3, &judge_a, "2099-06-02T09:00:00Z", 30, "9B");
+
+    let (status, calendar) = send(Method::Get, "/api/calendar/daily?date=2099-06-01", None);
+    assert_eq!(status, 200, "{}", calendar);
+    assert_eq!(calendar["event_count"], 3);
+    assert_eq!(calendar["conflict_count"], 2);
+
+    let rooms = calendar["courtrooms"].as_array().unwrap();
+    assert_eq!(rooms.len(), 2);
+    assert_eq!(rooms[0]["courtroom"], "12A");
+    assert_eq!(event_ids(&rooms[0]), vec![earlier.as_str(), later.as_str()]);
+    assert_eq!(rooms[1]["courtroom"], "9B");
+    assert_eq!(event_ids(&rooms[1]), vec![other_room.as_str()]);
+
+    let first = &rooms[0]["events"][0];
+    assert_eq!(first["case_number"], case_number_1.as_str());
+    assert_eq!(first["caption"], "United States v. Alder");
+    assert_eq!(first["judge_name"], "Judge Alder");
+    assert_eq!(first["conflict"], true);
+    assert_eq!(first["conflicts_with"], json!([later]));
+    assert_eq!(rooms[0]["events"][1]["conflict"], true);
+    assert_eq!(rooms[1]["events"][0]["conflict"], false);
+    assert_eq!(rooms[1]["events"][0]["judge_name"], "Judge Alder");
+
+    let (status, only_9b) = send(Method::Get, "/api/calendar/daily?date=2099-06-01&courtroom=9B", None);
+    assert_eq!(status, 200);
+    assert_eq!(only_9b["courtrooms"].as_array().unwrap().len(), 1);
+    assert_eq!(event_ids(&only_9b["courtrooms"][0]), vec![other_room.as_str()]);
+}
+
+#[spin_test]
+fn test_daily_calendar_text_format() {
+    let _store = key_value::Store::open(DISTRICT);
+    let judge = create_judge("Judge Text", "4D");
+    let (case_id, case_number) = create_case("United States v. Printer");
+    schedule(&case_id, &judge, "2099-06-01T15:00:00Z", 30, "4D");
+
+    let (status, content_type, body) = send_raw(Method::Get, "/api/calendar/daily?date=2099-06-01&format=text", None);
+    assert_eq!(status, 200);
+    assert!(content_type.starts_with("text/plain"), "{}", content_type);
+    let text = String::from_utf8(body).unwrap();
+    assert!(text.contains("DAILY CALENDAR"));
+    assert!(text.contains("Monday, June 1, 2099"));
+    assert!(text.contains("COURTROOM 4D"));
+    let row = text.lines().find(|l| l.starts_with("03:00 PM")).expect("proceeding row");
+    assert!(row.contains(&case_number) && row.contains("Status Conference") && row.contains("Judge Text"));
+
+    let (status, _) = send(Method::Get, "/api/calendar/daily?date=2099-06-01&format=pdf", None);
+    assert_eq!(status, 400);
+    let (status, _) = send(Method::Get, "/api/calendar/daily?date=June-1", None);
+    assert_eq!(status, 400);
+}
@@ -0,0 +1,182 @@
+//! Magistrate judge consent tests
+//!
+//! Tests for /api/cases/:id/magistrate-consent and the assignment guard that
+//! keeps a magistrate judge from presiding until every party consents under
+//! 28 U.S.C. § 636(c), unless an override reason is given.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to create a case with one defendant, returning (case_id, docket_entry_id)
+fn create_case_with_defendant(district: &str) -> (String, String) {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. Doe",
+        "description": "Case for magistrate consent tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), district);
+    assert_eq!(status, 201);
+    let case_id = case["id"].as_str().unwrap().to_string();
+
+    let (status, _) = send(Method::Post, &format!("/api/cases/{}/defendants", case_id), Some(json!({
+        "name": "John Doe"
+    })), district);
+    assert_eq!(status, 200);
+
+    let (status, _) = send(Method::Post, &format!("/api/cases/{}/docket", case_id), Some(json!({
+        "entryType": "notice",
+        "description": "Notice of availability of a magistrate judge"
+    })), district);
+    assert_eq!(status, 200);
+    let (_, entries) = send(Method::Get, &format!("/api/cases/{}/docket", case_id), None, district);
+    let entry_id = entries[0]["id"].as_str().unwrap().to_string();
+
+    (case_id, entry_id)
+}
+
+fn create_magistrate(district: &str) -> String {
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": "Magistrate Judge Lee",
+        "title": "magistrate_judge",
+        "district": district,
+        "courtroom": "9B"
+    })), district);
+    assert_eq!(status, 201);
+    judge["id"].as_str().unwrap().to_string()
+}
+
+fn record_consent(case_id: &str, body: Value, district: &str) -> (u16, Value) {
+    send(Method::Post, &format!("/api/cases/{}/magistrate-consent", case_id), Some(body), district)
+}
+
+fn assign(case_id: &str, override_reason: Option<&str>, district: &str) -> (u16, Value) {
+    send(Method::Post, "/api/assignments", Some(json!({
+        "case_id": case_id,
+        "case_type": "criminal",
+        "parties": [],
+        "preferred_date": null,
+        "assignment_type": "random",
+        "reason": "Initial assignment",
+        "strategy": "random",
+        "seed": 3,
+        "magistrate_override_reason": override_reason
+    })), district)
+}
+
+#[spin_test]
+fn test_consent_summary_tracks_each_party() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+    let (case_id, entry_id) = create_case_with_defendant(district);
+
+    let (status, summary) = send(Method::Get, &format!("/api/cases/{}/magistrate-consent", case_id), None, district);
+    assert_eq!(status, 200);
+    assert_eq!(summary["full_consent"], false);
+    assert_eq!(summary["outstanding"], json!(["United States", "John Doe"]));
+
+    let (status, summary) = record_consent(&case_id, json!({
+        "party": "united states",
+        "status": "consented",
+        "docketEntryId": entry_id
+    }), district);
+    assert_eq!(status, 200, "Consent should be recorded: {}", summary);
+    assert_eq!(summary["full_consent"], false);
+    let government = &summary["parties"][0];
+    assert_eq!(government["party"], "United States");
+    assert_eq!(government["status"], "consented");
+    assert_eq!(government["solicitation_entry_id"], entry_id.as_str());
+    assert!(government["solicited_at"].is_string());
+    assert!(government["decided_at"].is_string());
+
+    let (status, summary) = record_consent(&case_id, json!({ "party": "John Doe", "status": "declined" }), district);
+    assert_eq!(status, 200);
+    assert_eq!(summary["full_consent"], false);
+    assert_eq!(summary["declined"], json!(["John Doe"]));
+
+    let (status, summary) = record_consent(&case_id, json!({ "party": "John Doe", "status": "consented" }), district);
+    assert_eq!(status, 200);
+    assert_eq!(summary["full_consent"], true);
+    assert_eq!(summary["outstanding"], json!([]));
+}
+
+#[spin_test]
+fn test_consent_rejects_unknown_party_and_docket_entry() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+    let (case_id, _) = create_case_with_defendant(district);
+
+    let (status, _) = record_consent(&case_id, json!({ "party": "Jane Roe", "status": "consented" }), district);
+    assert_eq!(status, 400, "Non-party should be rejected");
+
+    let (status, _) = record_consent(&case_id, json!({
+        "party": "John Doe",
+        "status": "consented",
+        "docketEntryId": "00000000-0000-4000-8000-0000000000d1"
+    }), district);
+    assert_eq!(status, 400, "Docket entry from elsewhere should be rejected");
+
+    let (status, _) = record_consent("00000000-0000-4000-8000-0000000000c9", json!({ "party": "John Doe", "status": "consented" }), district);
+    assert_eq!(status, 404);
+}
+
+#[spin_test]
+fn test_magistrate_presides_only_after_full_consent() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+    let (case_id, _) = create_case_with_defendant(district);
+    let magistrate_id = create_magistrate(district);
+
+    let (status, body) = assign(&case_id, None, district);
+    assert_eq!(status, 400, "Magistrate should not preside without consent: {}", body);
+
+    record_consent(&case_id, json!({ "party": "United States", "status": "consented" }), district);
+    let (status, _) = assign(&case_id, None, district);
+    assert_eq!(status, 400, "Partial consent is not enough");
+
+    record_consent(&case_id, json!({ "party": "John Doe", "status": "consented" }), district);
+    let (status, assignment) = assign(&case_id, None, district);
+    assert_eq!(status, 201, "Full consent should allow the magistrate: {}", assignment);
+    assert_eq!(assignment["judge_id"], magistrate_id.as_str());
+}
+
+#[spin_test]
+fn test_override_reason_allows_magistrate_without_consent() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+    let (case_id, _) = create_case_with_defendant(district);
+    let magistrate_id = create_magistrate(district);
+
+    let (status, _) = assign(&case_id, Some("   "), district);
+    assert_eq!(status, 400, "A blank override reason should not count");
+
+    let (status, assignment) = assign(&case_id, Some("Emergency duty magistrate"), district);
+    assert_eq!(status, 201, "Override should allow the magistrate: {}", assignment);
+    assert_eq!(assignment["judge_id"], magistrate_id.as_str());
+    assert!(assignment["reason"].as_str().unwrap().contains("Emergency duty magistrate"));
+}
@@ -0,0 +1,18 @@
+//! Judge domain tests
+//!
+//! This module contains tests for judge workflow endpoints
+
+pub mod action_queue;
+pub mod assignment_strategy;
+pub mod workload_by_type;
+pub mod availability_vacation;
+pub mod assignment_conflicts;
+pub mod conflict_name_matching;
+pub mod magistrate_consent;
+pub mod unavailability;
+
+pub mod recusal_caseload;
+pub mod recusal_reassignment;
+pub mod weekly_docket;
+pub mod daily_calendar;
+pub mod signature_verification;
@@ -0,0 +1,204 @@
+//! Recusal caseload tests
+//!
+//! Tests that granting or processing a recusal takes the case off the recused
+//! judge's caseload, adds it to the replacement's, and marks the recused
+//! judge's assignment superseded, whichever path performs the reassignment.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to create a judge and return its ID
+fn create_judge(name: &str, district: &str) -> String {
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": name,
+        "title": "district_judge",
+        "district": district,
+        "courtroom": "5C"
+    })), district);
+    assert_eq!(status, 201);
+    judge["id"].as_str().unwrap().to_string()
+}
+
+/// Helper to record a judge's conflict with a party
+fn add_conflict(judge_id: &str, party: &str, district: &str) {
+    let (status, _) = send(Method::Post, &format!("/api/judges/{}/conflicts", judge_id), Some(json!({
+        "party_name": party,
+        "conflict_type": "stock_ownership",
+        "notes": "Holds shares"
+    })), district);
+    assert_eq!(status, 201);
+}
+
+const CASE_ID: &str = "00000000-0000-4000-8000-0000000000d1";
+
+/// Helper to assign the test case to the only judge on the bench
+fn assign_case(district: &str) {
+    let (status, _) = send(Method::Post, "/api/assignments", Some(json!({
+        "case_id": CASE_ID,
+        "case_type": "civil",
+        "parties": ["Acme Corp"],
+        "preferred_date": null,
+        "assignment_type": "random",
+        "reason": "Initial assignment",
+        "strategy": "random",
+        "seed": 3
+    })), district);
+    assert_eq!(status, 201);
+}
+
+/// Helper to file a recusal motion against a judge and return its ID
+fn file_recusal(judge_id: &str, district: &str) -> String {
+    let (status, motion) = send(Method::Post, &format!("/api/judges/{}/recusals", judge_id), Some(json!({
+        "case_id": CASE_ID,
+        "filed_by": "Defense Counsel",
+        "reason": "financial_interest",
+        "detailed_grounds": "Judge owns stock in a party"
+    })), district);
+    assert_eq!(status, 201);
+    motion["id"].as_str().unwrap().to_string()
+}
+
+fn caseload(judge_id: &str, district: &str) -> u64 {
+    let (status, judge) = send(Method::Get, &format!("/api/judges/{}", judge_id), None, district);
+    assert_eq!(status, 200);
+    judge["current_caseload"].as_u64().unwrap()
+}
+
+/// Assignments for the test case, keyed by whether they are superseded
+fn assignment_history(district: &str) -> (Vec<Value>, Vec<Value>) {
+    let (status, history) = send(Method::Get, &format!("/api/assignments/history/{}", CASE_ID), None, district);
+    assert_eq!(status, 200);
+    history
+        .as_array()
+        .unwrap()
+        .iter()
+        .cloned()
+        .partition(|a| a["superseded_date"].is_null())
+}
+
+#[spin_test]
+fn test_grant_with_replacement_moves_caseload() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let recused = create_judge("Recused Judge", district);
+    assign_case(district);
+    assert_eq!(caseload(&recused, district), 1);
+    let supplied = create_judge("Supplied Judge", district);
+    let recusal_id = file_recusal(&recused, district);
+
+    let ruling = json!({"status": "granted", "replacement_judge_id": supplied});
+    let (status, body) = send(Method::Patch, &format!("/api/recusals/{}/ruling", recusal_id), Some(ruling.clone()), district);
+    assert_eq!(status, 200, "Ruling should succeed: {:?}", body);
+
+    assert_eq!(caseload(&recused, district), 0);
+    assert_eq!(caseload(&supplied, district), 1);
+
+    let (current, superseded) = assignment_history(district);
+    assert_eq!(current.len(), 1);
+    assert_eq!(current[0]["judge_id"], supplied.as_str());
+    assert_eq!(superseded.len(), 1);
+    assert_eq!(superseded[0]["judge_id"], recused.as_str());
+    assert_eq!(superseded[0]["superseded_by"], current[0]["id"]);
+
+    // Ruling again does not move the case or the counters a second time
+    let (status, _) = send(Method::Patch, &format!("/api/recusals/{}/ruling", recusal_id), Some(ruling), district);
+    assert_eq!(status, 200);
+    assert_eq!(caseload(&recused, district), 0);
+    assert_eq!(caseload(&supplied, district), 1);
+    assert_eq!(assignment_history(district).0.len(), 1);
+}
+
+#[spin_test]
+fn test_grant_without_replacement_moves_caseload_to_selected_judge() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let recused = create_judge("Recused Judge", district);
+    assign_case(district);
+    let selected = create_judge("Selected Judge", district);
+    let recusal_id = file_recusal(&recused, district);
+
+    let (status, body) = send(Method::Patch, &format!("/api/recusals/{}/ruling", recusal_id), Some(json!({
+        "status": "granted",
+        "parties": ["Acme Corp"]
+    })), district);
+    assert_eq!(status, 200, "Ruling should succeed: {:?}", body);
+    assert_eq!(body["replacement_judge_id"], selected.as_str());
+
+    assert_eq!(caseload(&recused, district), 0);
+    assert_eq!(caseload(&selected, district), 1);
+}
+
+#[spin_test]
+fn test_grant_awaiting_reassignment_releases_recused_judge() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let recused = create_judge("Recused Judge", district);
+    assign_case(district);
+    let conflicted = create_judge("Conflicted Judge", district);
+    add_conflict(&conflicted, "Acme Corp", district);
+    let recusal_id = file_recusal(&recused, district);
+
+    let (status, body) = send(Method::Patch, &format!("/api/recusals/{}/ruling", recusal_id), Some(json!({
+        "status": "granted",
+        "parties": ["Acme Corp"]
+    })), district);
+    assert_eq!(status, 200);
+    assert_eq!(body["reassignment_status"], "awaiting_reassignment");
+
+    assert_eq!(caseload(&recused, district), 0);
+    assert_eq!(caseload(&conflicted, district), 0);
+    let (current, superseded) = assignment_history(district);
+    assert!(current.is_empty());
+    assert!(superseded[0]["superseded_by"].is_null());
+}
+
+#[spin_test]
+fn test_process_recusal_moves_caseload() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let recused = create_judge("Recused Judge", district);
+    assign_case(district);
+    let replacement = create_judge("Replacement Judge", district);
+    let recusal_id = file_recusal(&recused, district);
+
+    let (status, assignment) = send(Method::Post, &format!("/api/recusals/{}/process", recusal_id), Some(json!({
+        "parties": ["Acme Corp"]
+    })), district);
+    assert_eq!(status, 200, "Processing should succeed: {:?}", assignment);
+    assert_eq!(assignment["judge_id"], replacement.as_str());
+    assert_eq!(assignment["previous_judge_id"], recused.as_str());
+
+    assert_eq!(caseload(&recused, district), 0);
+    assert_eq!(caseload(&replacement, district), 1);
+    let (_, superseded) = assignment_history(district);
+    assert_eq!(superseded[0]["superseded_by"], assignment["id"]);
+}
@@ -0,0 +1,176 @@
+//! Recusal reassignment tests
+//!
+//! Tests that PATCH /api/recusals/:recusal_id/ruling uses a supplied
+//! replacement judge, auto-selects a conflict-free judge when none is
+//! supplied, and marks the case as awaiting reassignment when no judge is
+//! eligible.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Helper to create a judge and return its ID
+fn create_judge(name: &str, district: &str) -> String {
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": name,
+        "title": "district_judge",
+        "district": district,
+        "courtroom": "5C"
+    })), district);
+    assert_eq!(status, 201);
+    judge["id"].as_str().unwrap().to_string()
+}
+
+/// Helper to record a judge's conflict with a party
+fn add_conflict(judge_id: &str, party: &str, district: &str) {
+    let (status, _) = send(Method::Post, &format!("/api/judges/{}/conflicts", judge_id), Some(json!({
+        "party_name": party,
+        "conflict_type": "stock_ownership",
+        "notes": "Holds shares"
+    })), district);
+    assert_eq!(status, 201);
+}
+
+const CASE_ID: &str = "00000000-0000-4000-8000-0000000000d1";
+
+/// Helper to assign the test case to the only judge on the bench
+fn assign_case(district: &str) {
+    let (status, _) = send(Method::Post, "/api/assignments", Some(json!({
+        "case_id": CASE_ID,
+        "case_type": "civil",
+        "parties": ["Acme Corp"],
+        "preferred_date": null,
+        "assignment_type": "random",
+        "reason": "Initial assignment",
+        "strategy": "random"
+    })), district);
+    assert_eq!(status, 201);
+}
+
+/// Helper to file a recusal motion against a judge and return its ID
+fn file_recusal(judge_id: &str, district: &str) -> String {
+    let (status, motion) = send(Method::Post, &format!("/api/judges/{}/recusals", judge_id), Some(json!({
+        "case_id": CASE_ID,
+        "filed_by": "Defense Counsel",
+        "reason": "financial_interest",
+        "detailed_grounds": "Judge owns stock in a party"
+    })), district);
+    assert_eq!(status, 201);
+    motion["id"].as_str().unwrap().to_string()
+}
+
+#[spin_test]
+fn test_granted_recusal_uses_supplied_replacement() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let recused = create_judge("Recused Judge", district);
+    let supplied = create_judge("Supplied Judge", district);
+    let recusal_id = file_recusal(&recused, district);
+
+    let (status, body) = send(Method::Patch, &format!("/api/recusals/{}/ruling", recusal_id), Some(json!({
+        "status": "granted",
+        "replacement_judge_id": supplied
+    })), district);
+
+    assert_eq!(status, 200, "Ruling should succeed: {:?}", body);
+    assert_eq!(body["status"], "granted");
+    assert_eq!(body["replacement_judge_id"], supplied.as_str());
+    assert_eq!(body["reassignment_status"], "reassigned");
+    assert_eq!(body["replacement_judge"]["id"], supplied.as_str());
+}
+
+#[spin_test]
+fn test_granted_recusal_auto_selects_conflict_free_judge() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let recused = create_judge("Recused Judge", district);
+    assign_case(district);
+    let conflicted = create_judge("Conflicted Judge", district);
+    let clean = create_judge("Clean Judge", district);
+    add_conflict(&conflicted, "Acme Corp", district);
+    let recusal_id = file_recusal(&recused, district);
+
+    let (status, body) = send(Method::Patch, &format!("/api/recusals/{}/ruling", recusal_id), Some(json!({
+        "status": "granted",
+        "parties": ["Acme Corp"]
+    })), district);
+
+    assert_eq!(status, 200, "Ruling should succeed: {:?}", body);
+    assert_eq!(body["replacement_judge_id"], clean.as_str());
+    assert_eq!(body["replacement_judge"]["name"], "Clean Judge");
+    assert_eq!(body["reassignment_status"], "reassigned");
+
+    // The case is reassigned away from the recused judge
+    let (status, assignment) = send(Method::Get, &format!("/api/assignments/case/{}", CASE_ID), None, district);
+    assert_eq!(status, 200);
+    assert_eq!(assignment["judge_id"], clean.as_str());
+    assert_eq!(assignment["previous_judge_id"], recused.as_str());
+    assert_eq!(assignment["assignment_type"], "reassignment");
+}
+
+#[spin_test]
+fn test_granted_recusal_without_eligible_judge_awaits_reassignment() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let recused = create_judge("Recused Judge", district);
+    let conflicted = create_judge("Conflicted Judge", district);
+    add_conflict(&conflicted, "Acme Corp", district);
+    let recusal_id = file_recusal(&recused, district);
+
+    let (status, body) = send(Method::Patch, &format!("/api/recusals/{}/ruling", recusal_id), Some(json!({
+        "status": "granted",
+        "parties": ["Acme Corp"]
+    })), district);
+
+    assert_eq!(status, 200, "Motion should stay granted: {:?}", body);
+    assert_eq!(body["status"], "granted");
+    assert_eq!(body["reassignment_status"], "awaiting_reassignment");
+    assert!(body["replacement_judge_id"].is_null());
+    assert!(body["replacement_judge"].is_null());
+}
+
+#[spin_test]
+fn test_denied_recusal_does_not_reassign() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let recused = create_judge("Recused Judge", district);
+    create_judge("Other Judge", district);
+    let recusal_id = file_recusal(&recused, district);
+
+    let (status, body) = send(Method::Patch, &format!("/api/recusals/{}/ruling", recusal_id), Some(json!({
+        "status": "denied"
+    })), district);
+
+    assert_eq!(status, 200);
+    assert_eq!(body["status"], "denied");
+    assert!(body["reassignment_status"].is_null());
+    assert!(body["replacement_judge"].is_null());
+}
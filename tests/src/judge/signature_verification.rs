@@ -0,0 +1,205 @@
+//! Judge signature validation tests
+//!
+//! Tests that POST /api/signatures only stores base64 PNG or JPEG images
+//! within the district's size limits, naming the failed check in a 422, that
+//! inline signatures in PDF requests get the same checks, that
+//! GET /api/signatures/:judge_id?metadata_only=true leaves out the image, and
+//! that GET /api/signatures/:judge_id/verify reports whether a stored
+//! signature is decodable.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const JUDGE_ID: &str = "7d2c9e14-3b6a-4f8d-9c1e-5a4b3c2d1e0f";
+
+/// 1x1 transparent PNG
+const TINY_PNG: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+
+/// PNG header claiming 2400x600 pixels
+const WIDE_PNG: &str = "iVBORw0KGgoAAAANSUhEUgAACWAAAAJYCAYAAAA=";
+
+/// 1x1 GIF
+const GIF: &str = "R0lGODlhAQABAAAAAA==";
+
+/// PNG magic bytes with nothing after them
+const TRUNCATED_PNG: &str = "iVBORw0KGgoAAA==";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+#[spin_test]
+fn test_png_signature_is_stored_and_verified() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let (status, body) = send(Method::Post, "/api/signatures", Some(json!({
+        "judge_id": JUDGE_ID,
+        "signature_base64": TINY_PNG
+    })));
+    assert_eq!(status, 200, "PNG signature should be stored: {}", body);
+
+    let (status, verification) = send(Method::Get, &format!("/api/signatures/{}/verify", JUDGE_ID), None);
+    assert_eq!(status, 200);
+    assert_eq!(verification["valid"], true);
+    assert_eq!(verification["format"], "png");
+    assert_eq!(verification["width"], 1);
+    assert_eq!(verification["hash_matches"], true);
+}
+
+#[spin_test]
+fn test_garbage_signature_is_rejected() {
+    let _store = key_value::Store::open(DISTRICT);
+    let judge_id = "8e3dae25-4c7b-4a9e-8d2f-6b5c4d3e2f10";
+
+    let (status, body) = send(Method::Post, "/api/signatures", Some(json!({
+        "judge_id": judge_id,
+        "signature_base64": "definitely not an image"
+    })));
+    assert_eq!(status, 422);
+    assert!(body["error"]["message"].as_str().unwrap().contains("Invalid signature"));
+    assert_eq!(body["error"]["details"]["check"], "base64");
+
+    let (status, _) = send(Method::Get, &format!("/api/signatures/{}/verify", judge_id), None);
+    assert_eq!(status, 404, "Rejected signature should not be stored");
+}
+
+#[spin_test]
+fn test_verify_flags_corrupt_stored_signature() {
+    let store = key_value::Store::open(DISTRICT);
+    let judge_id = "9f4ebf36-5d8c-4b0f-9e3a-7c6d5e4f3a21";
+
+    // Written directly, as signatures stored before validation may be
+    let legacy = json!({
+        "judge_id": judge_id,
+        "signature_base64": "AAAA",
+        "uploaded_at": "2025-01-15T10:00:00+00:00",
+        "signature_hash": "unknown"
+    });
+    store.set(&format!("district9:signature_{}", judge_id), &serde_json::to_vec(&legacy).unwrap());
+
+    let (status, verification) = send(Method::Get, &format!("/api/signatures/{}/verify", judge_id), None);
+    assert_eq!(status, 200);
+    assert_eq!(verification["valid"], false);
+    assert_eq!(verification["hash_matches"], false);
+    assert!(verification["error"].is_string());
+}
+
+fn store(judge_id: &str, signature_base64: &str) -> (u16, Value) {
+    send(Method::Post, "/api/signatures", Some(json!({
+        "judge_id": judge_id,
+        "signature_base64": signature_base64
+    })))
+}
+
+#[spin_test]
+fn test_rejection_names_the_failed_check() {
+    let _store = key_value::Store::open(DISTRICT);
+    let judge_id = "a05fc047-6e9d-4c1a-8f4b-8d7e6f5a4b32";
+
+    let (status, body) = store(judge_id, GIF);
+    assert_eq!(status, 422, "{}", body);
+    assert_eq!(body["error"]["details"]["check"], "format");
+
+    let (status, body) = store(judge_id, TRUNCATED_PNG);
+    assert_eq!(status, 422, "{}", body);
+    assert_eq!(body["error"]["details"]["check"], "header");
+
+    let (status, body) = store(judge_id, WIDE_PNG);
+    assert_eq!(status, 422, "{}", body);
+    assert_eq!(body["error"]["details"]["check"], "dimensions");
+    assert_eq!(body["error"]["details"]["value"], 2400);
+    assert_eq!(body["error"]["details"]["max"], 2000);
+
+    // The district can lower the byte limit below the 70-byte PNG
+    let (status, _) = send(Method::Put, "/api/config/overrides/district", Some(json!({
+        "signatures.max_bytes": 64
+    })));
+    assert_eq!(status, 200);
+    let (status, body) = store(judge_id, TINY_PNG);
+    assert_eq!(status, 422, "{}", body);
+    assert_eq!(body["error"]["details"]["check"], "byte_size");
+    assert_eq!(body["error"]["details"]["value"], 70);
+    assert_eq!(body["error"]["details"]["max"], 64);
+
+    let (status, _) = send(Method::Get, &format!("/api/signatures/{}", judge_id), None);
+    assert_eq!(status, 404, "Rejected signatures should not be stored");
+}
+
+#[spin_test]
+fn test_metadata_only_leaves_out_the_image() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (status, body) = store(JUDGE_ID, TINY_PNG);
+    assert_eq!(status, 200, "{}", body);
+
+    let (status, full) = send(Method::Get, &format!("/api/signatures/{}", JUDGE_ID), None);
+    assert_eq!(status, 200);
+    assert_eq!(full["signature_base64"], TINY_PNG);
+
+    let (status, metadata) = send(Method::Get, &format!("/api/signatures/{}?metadata_only=true", JUDGE_ID), None);
+    assert_eq!(status, 200);
+    assert!(metadata.get("signature_base64").is_none());
+    assert_eq!(metadata["signature_hash"], full["signature_hash"]);
+    assert_eq!(metadata["uploaded_at"], full["uploaded_at"]);
+    assert_eq!(metadata["format"], "png");
+    assert_eq!(metadata["width"], 1);
+    assert_eq!(metadata["height"], 1);
+    assert_eq!(metadata["byte_size"], 70);
+}
+
+#[spin_test]
+fn test_inline_pdf_signatures_are_validated() {
+    let _store = key_value::Store::open(DISTRICT);
+    let court_order = |signature_base64: &str| json!({
+        "case_number": "2030-CR-00417",
+        "defendant_names": "John Doe",
+        "judge_name": "Hon. Patricia Johnson",
+        "order_title": "ORDER",
+        "order_content": "So ordered.",
+        "signature_base64": signature_base64
+    });
+
+    let (status, body) = send(Method::Post, "/api/pdf/court-order/json", Some(court_order(GIF)));
+    assert_eq!(status, 422, "{}", body);
+    assert_eq!(body["error"]["details"]["check"], "format");
+
+    let (status, body) = send(Method::Post, "/api/pdf/signed/court-order/json", Some(court_order("not base64!")));
+    assert_eq!(status, 422, "{}", body);
+    assert_eq!(body["error"]["details"]["check"], "base64");
+
+    let (status, body) = send(Method::Post, "/api/pdf/court-order/json", Some(court_order(TINY_PNG)));
+    assert_eq!(status, 200, "{}", body);
+
+    // In a batch the bad signature fails only its own document
+    let (status, batch) = send(Method::Post, "/api/pdf/batch", Some(json!({
+        "documents": [
+            {"type": "rule16b", "case_number": "2030-CR-00417", "defendant_names": "John Doe", "judge_name": "Hon. Patricia Johnson", "signature_base64": WIDE_PNG},
+            {"type": "rule16b", "case_number": "2030-CR-00418", "defendant_names": "Jane Roe", "judge_name": "Hon. Patricia Johnson", "signature_base64": TINY_PNG}
+        ]
+    })));
+    assert_eq!(status, 200, "{}", batch);
+    assert_eq!(batch["total_generated"], 1);
+    assert_eq!(batch["failures"][0]["index"], 0);
+    assert_eq!(batch["failures"][0]["code"], "INVALID_SIGNATURE");
+}
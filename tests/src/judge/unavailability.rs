@@ -0,0 +1,144 @@
+//! Judge unavailability tests
+//!
+//! Tests for recording, listing and cancelling periods a judge is away via
+//! /api/judges/:judge_id/unavailability, rejection of overlapping entries,
+//! and that scheduling and the available-slot search honor them.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Create a judge and return its ID
+fn create_judge(name: &str) -> String {
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": name,
+        "title": "district_judge",
+        "district": DISTRICT,
+        "courtroom": "7A"
+    })));
+    assert_eq!(status, 201, "Judge should be created: {}", judge);
+    judge["id"].as_str().unwrap().to_string()
+}
+
+fn add(judge_id: &str, start: &str, end: &str, kind: &str) -> (u16, Value) {
+    send(Method::Post, &format!("/api/judges/{}/unavailability", judge_id), Some(json!({
+        "start": start,
+        "end": end,
+        "type": kind,
+        "notes": "Test entry"
+    })))
+}
+
+#[spin_test]
+fn test_record_list_and_cancel_unavailability() {
+    let _store = key_value::Store::open(DISTRICT);
+    let judge_id = create_judge("Judge Traveler");
+
+    let (status, cle) = add(&judge_id, "2099-10-05", "2099-10-06", "cle");
+    assert_eq!(status, 201, "Entry should be recorded: {}", cle);
+    assert_eq!(cle["unavailability_type"], "cle");
+    let (status, _) = add(&judge_id, "2099-09-14", "2099-09-18", "vacation");
+    assert_eq!(status, 201);
+
+    let (status, list) = send(Method::Get, &format!("/api/judges/{}/unavailability", judge_id), None);
+    assert_eq!(status, 200);
+    let starts: Vec<&str> = list.as_array().unwrap().iter().map(|e| e["start"].as_str().unwrap()).collect();
+    assert_eq!(starts, vec!["2099-09-14", "2099-10-05"], "Entries should be in start order");
+
+    let path = format!("/api/judges/{}/unavailability/{}", judge_id, cle["id"].as_str().unwrap());
+    assert_eq!(send(Method::Delete, &path, None).0, 204);
+    assert_eq!(send(Method::Delete, &path, None).0, 404, "A cancelled entry is gone");
+
+    let (_, list) = send(Method::Get, &format!("/api/judges/{}/unavailability", judge_id), None);
+    assert_eq!(list.as_array().unwrap().len(), 1);
+}
+
+#[spin_test]
+fn test_overlapping_and_invalid_entries_are_rejected() {
+    let _store = key_value::Store::open(DISTRICT);
+    let judge_id = create_judge("Judge Overlap");
+
+    assert_eq!(add(&judge_id, "2099-09-14", "2099-09-18", "vacation").0, 201);
+    assert_eq!(add(&judge_id, "2099-09-18", "2099-09-19", "medical").0, 409, "Sharing one day is an overlap");
+    assert_eq!(add(&judge_id, "2099-09-01", "2099-09-30", "judicial_conference").0, 409);
+    assert_eq!(add(&judge_id, "2099-09-19", "2099-09-19", "medical").0, 201);
+
+    assert_eq!(add(&judge_id, "2099-12-10", "2099-12-01", "vacation").0, 400, "End before start");
+    assert_eq!(add(&judge_id, "2099-12-01", "2099-12-02", "sabbatical").0, 400, "Unknown type");
+    assert_eq!(add("00000000-0000-4000-8000-000000000000", "2099-12-01", "2099-12-02", "vacation").0, 404);
+}
+
+#[spin_test]
+fn test_scheduling_treats_unavailability_as_conflict() {
+    let _store = key_value::Store::open(DISTRICT);
+    let judge_id = create_judge("Judge Away");
+    assert_eq!(add(&judge_id, "2099-09-14", "2099-09-18", "vacation").0, 201);
+
+    // Monday through Friday are taken, so the next slot is the following Monday
+    let (status, slot) = send(
+        Method::Get,
+        &format!("/api/calendar/available-slot/{}?earliest=2099-09-14T09:00:00Z", judge_id),
+        None,
+    );
+    assert_eq!(status, 200, "Slot search should succeed: {}", slot);
+    assert!(slot["available_date"].as_str().unwrap().starts_with("2099-09-21"), "Unexpected slot: {}", slot);
+
+    let event = |date: &str| json!({
+        "case_id": "00000000-0000-4000-8000-000000000001",
+        "judge_id": judge_id,
+        "event_type": "status_conference",
+        "scheduled_date": date,
+        "duration_minutes": 30,
+        "courtroom": "7A",
+        "description": "Status conference",
+        "participants": [],
+        "is_public": true
+    });
+    let (status, body) = send(Method::Post, "/api/calendar/events", Some(event("2099-09-16T14:00:00Z")));
+    assert_eq!(status, 400, "Judge is away that day: {}", body);
+    assert!(body["error"]["message"].as_str().unwrap().contains("unavailable"));
+
+    let (status, body) = send(Method::Post, "/api/calendar/events", Some(event("2099-09-21T14:00:00Z")));
+    assert_eq!(status, 201, "Judge is back: {}", body);
+}
+
+#[spin_test]
+fn test_vacation_entries_show_in_judges_on_vacation() {
+    let _store = key_value::Store::open(DISTRICT);
+    let judge_id = create_judge("Judge Holiday");
+    assert_eq!(add(&judge_id, "2099-09-14", "2099-09-18", "vacation").0, 201);
+
+    let (status, judges) = send(
+        Method::Get,
+        "/api/judges/vacation?start=2099-09-15T00:00:00Z&end=2099-09-16T00:00:00Z",
+        None,
+    );
+    assert_eq!(status, 200, "Vacation list should succeed: {}", judges);
+    assert!(judges.to_string().contains(&judge_id), "Judge should be listed: {}", judges);
+}
@@ -0,0 +1,171 @@
+//! Weekly docket tests
+//!
+//! Tests that GET /api/judges/:judge_id/weekly-docket buckets a judge's
+//! events and deadlines into the right local day of the requested week.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const CASE_ID: &str = "3d2c1b0a-9f8e-4d7c-8b6a-5f4e3d2c1b0a";
+
+/// Helper to send a JSON request and return status, content type and raw body
+fn send_raw(method: Method, path: &str, body: Option<Value>) -> (u16, String, Vec<u8>) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(&"content-type".to_string())
+        .first()
+        .map(|v| String::from_utf8_lossy(v).to_string())
+        .unwrap_or_default();
+    let bytes = response.body().unwrap_or_default();
+    (status, content_type, bytes)
+}
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let (status, _, bytes) = send_raw(method, path, body);
+    let body: Value = serde_json::from_slice(&bytes).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Creates a judge and assigns the test case to them, returning the judge ID
+fn judge_with_case() -> String {
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": "Judge Weekly",
+        "title": "district_judge",
+        "district": DISTRICT,
+        "courtroom": "12C"
+    })));
+    assert_eq!(status, 201);
+    let judge_id = judge["id"].as_str().unwrap().to_string();
+
+    let (status, assignment) = send(Method::Post, "/api/assignments", Some(json!({
+        "case_id": CASE_ID,
+        "case_type": "civil",
+        "parties": [],
+        "preferred_date": null,
+        "assignment_type": "random",
+        "reason": "Initial assignment"
+    })));
+    assert_eq!(status, 201);
+    assert_eq!(assignment["judge_id"], judge_id);
+
+    judge_id
+}
+
+fn schedule(judge_id: &str, scheduled_date: &str, description: &str) {
+    let (status, body) = send(Method::Post, "/api/calendar/events", Some(json!({
+        "case_id": CASE_ID,
+        "judge_id": judge_id,
+        "event_type": "status_conference",
+        "scheduled_date": scheduled_date,
+        "duration_minutes": 30,
+        "courtroom": "12C",
+        "description": description,
+        "participants": ["Plaintiff", "Defendant"],
+        "is_public": true
+    })));
+    assert_eq!(status, 201, "Event should be scheduled: {}", body);
+}
+
+fn add_deadline(due_date: &str, description: &str) {
+    let (status, body) = send(Method::Post, "/api/deadlines", Some(json!({
+        "case_id": CASE_ID,
+        "deadline_type": "answer",
+        "due_date": due_date,
+        "triggering_event": "Service of summons",
+        "triggering_date": "2099-05-12T17:00:00Z",
+        "applicable_rule": "FRCP 12(a)(1)(A)",
+        "description": description,
+        "responsible_party": "Defendant",
+        "is_jurisdictional": false,
+        "is_extendable": true
+    })));
+    assert_eq!(status, 201, "Deadline should be created: {}", body);
+}
+
+fn descriptions(day: &Value) -> Vec<String> {
+    day["items"].as_array().unwrap().iter().map(|i| i["description"].as_str().unwrap().to_string()).collect()
+}
+
+#[spin_test]
+fn test_weekly_docket_groups_items_by_local_day() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (status, _) = send(Method::Put, "/api/config/overrides/district", Some(json!({
+        "scheduling.utc_offset_hours": -5
+    })));
+    assert_eq!(status, 200);
+
+    let judge_id = judge_with_case();
+    // June 1, 2099 is a Monday; 02:00 UTC on Tuesday is 21:00 Monday Eastern
+    schedule(&judge_id, "2099-06-02T02:00:00Z", "Evening status call");
+    schedule(&judge_id, "2099-06-03T15:00:00Z", "Wednesday conference");
+    // 03:00 UTC next Monday is still Sunday night locally
+    schedule(&judge_id, "2099-06-08T03:00:00Z", "Sunday duty matter");
+    schedule(&judge_id, "2099-06-10T15:00:00Z", "Following week conference");
+    add_deadline("2099-06-05T22:00:00Z", "Answer due");
+
+    let (status, docket) = send(Method::Get, &format!("/api/judges/{}/weekly-docket?week_of=2099-06-03", judge_id), None);
+    assert_eq!(status, 200, "{}", docket);
+
+    assert_eq!(docket["week_start"], "2099-06-01");
+    assert_eq!(docket["week_end"], "2099-06-07");
+    assert_eq!(docket["utc_offset_hours"], -5);
+    assert_eq!(docket["event_count"], 3);
+    assert_eq!(docket["deadline_count"], 1);
+
+    let days = docket["days"].as_array().unwrap();
+    assert_eq!(days.len(), 7);
+    assert_eq!(days[0]["weekday"], "Monday");
+    assert_eq!(descriptions(&days[0]), vec!["Evening status call"]);
+    assert_eq!(days[0]["items"][0]["local_time"], "21:00");
+    assert!(descriptions(&days[1]).is_empty());
+    assert_eq!(descriptions(&days[2]), vec!["Wednesday conference"]);
+    assert_eq!(descriptions(&days[4]), vec!["Answer due"]);
+    assert_eq!(days[4]["items"][0]["kind"], "deadline");
+    assert_eq!(descriptions(&days[6]), vec!["Sunday duty matter"]);
+}
+
+#[spin_test]
+fn test_weekly_docket_pdf_format() {
+    let _store = key_value::Store::open(DISTRICT);
+    let judge_id = judge_with_case();
+    schedule(&judge_id, "2099-06-03T15:00:00Z", "Wednesday conference");
+
+    let (status, content_type, bytes) = send_raw(
+        Method::Get,
+        &format!("/api/judges/{}/weekly-docket?week_of=2099-06-03&format=pdf", judge_id),
+        None,
+    );
+    assert_eq!(status, 200);
+    assert_eq!(content_type, "application/pdf");
+    assert!(bytes.starts_with(b"%PDF"));
+}
+
+#[spin_test]
+fn test_weekly_docket_rejects_bad_week_of() {
+    let _store = key_value::Store::open(DISTRICT);
+    let judge_id = judge_with_case();
+
+    let (status, _) = send(Method::Get, &format!("/api/judges/{}/weekly-docket?week_of=next-week", judge_id), None);
+    assert_eq!(status, 400);
+}
@@ -0,0 +1,75 @@
+//! Judge workload by case type tests
+//!
+//! Tests for GET /api/judges/workload/by-type.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a JSON request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+#[spin_test]
+fn test_workload_by_type_returns_bucket_per_case_type() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    for name in ["Judge Criminal", "Judge Civil"] {
+        let (status, _) = send(Method::Post, "/api/judges", Some(json!({
+            "name": name,
+            "title": "district_judge",
+            "district": district,
+            "courtroom": "2C"
+        })), district);
+        assert_eq!(status, 201);
+    }
+
+    let (status, assignment) = send(Method::Post, "/api/assignments", Some(json!({
+        "case_id": "7d3f2c1e-0000-4000-8000-000000000001",
+        "case_type": "criminal",
+        "parties": [],
+        "preferred_date": null,
+        "assignment_type": "random",
+        "reason": "Initial assignment"
+    })), district);
+    assert_eq!(status, 201, "assignment failed: {}", assignment);
+
+    let (status, body) = send(Method::Get, "/api/judges/workload/by-type", None, district);
+    assert_eq!(status, 200);
+
+    let buckets = body.as_array().unwrap();
+    assert_eq!(buckets.len(), 2);
+    assert_eq!(buckets[0]["case_type"], "criminal");
+    assert_eq!(buckets[1]["case_type"], "civil");
+
+    // New judges accept both case types, so both appear in each bucket
+    for bucket in buckets {
+        assert_eq!(bucket["workload"]["total_judges"], 2);
+        assert_eq!(bucket["workload"]["overloaded_judges"], 0);
+    }
+
+    // The criminal case counts only under criminal
+    assert_eq!(buckets[0]["workload"]["average_caseload"], 0.5);
+    assert_eq!(buckets[1]["workload"]["average_caseload"], 0.0);
+}
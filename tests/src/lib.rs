@@ -9,4 +9,13 @@ pub mod attorney;
 pub mod case;
 pub mod deadline;
 pub mod rules;
-pub mod filing;
\ No newline at end of file
+pub mod filing;
+pub mod opinion;
+pub mod judge;
+pub mod search;
+pub mod webhook;
+pub mod order;
+pub mod sentencing;
+pub mod features;
+pub mod todo;
+pub mod config;
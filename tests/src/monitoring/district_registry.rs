@@ -0,0 +1,106 @@
+//! District registry tests
+//!
+//! Tests that district codes are trimmed and uppercased before keying
+//! storage, that unknown codes are rejected with close matches instead of
+//! opening a new tenant, and that GET /api/admin/districts lists the
+//! registry with record counts.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::{key_value, variables}}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const ADMIN_TOKEN: &str = "test-admin-token";
+
+/// Helper to send a request as `district`, optionally with an admin token
+fn send(district: &str, method: Method, path: &str, body: Option<Value>, admin_token: Option<&str>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+    if let Some(token) = admin_token {
+        headers.append(&"X-Admin-Token".to_string(), token.as_bytes()).unwrap();
+    }
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case(district: &str, title: &str) -> (u16, Value) {
+    send(district, Method::Post, "/api/cases", Some(json!({
+        "title": title,
+        "description": "Case for district registry tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), None)
+}
+
+#[spin_test]
+fn test_district_codes_are_normalized() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, case) = create_case("District9", "United States v. Mixed Case");
+    assert_eq!(status, 201, "Mixed-case code should resolve: {}", case);
+    let id = case["id"].as_str().unwrap();
+
+    let (status, found) = send("DISTRICT9", Method::Get, &format!("/api/cases/{}", id), None, None);
+    assert_eq!(status, 200, "Same tenant under its canonical code: {}", found);
+    let (status, _) = send("district9", Method::Get, &format!("/api/cases/{}", id), None, None);
+    assert_eq!(status, 200);
+}
+
+#[spin_test]
+fn test_unknown_district_is_rejected_with_suggestions() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, error) = create_case("SDNX", "United States v. Typo");
+    assert_eq!(status, 400, "Unknown code should be rejected: {}", error);
+    let suggestions = error["error"]["details"]["suggestions"].as_array().expect("suggestions");
+    assert!(suggestions.contains(&json!("SDNY")), "{}", error);
+
+    let (status, error) = send("DISTRCT9", Method::Get, "/api/cases", None, None);
+    assert_eq!(status, 400);
+    assert!(error["error"]["details"]["suggestions"].as_array().unwrap().contains(&json!("DISTRICT9")), "{}", error);
+
+    let (status, _) = send("district 9", Method::Get, "/api/cases", None, None);
+    assert_eq!(status, 400, "Codes with spaces are never canonical");
+}
+
+#[spin_test]
+fn test_list_districts_requires_admin_and_counts_records() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+    create_case("district9", "United States v. Listed");
+
+    let (status, _) = send("district9", Method::Get, "/api/admin/districts", None, None);
+    assert_eq!(status, 403);
+
+    let (status, listing) = send("district9", Method::Get, "/api/admin/districts", None, Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "{}", listing);
+    let districts = listing["districts"].as_array().unwrap();
+    let federal = districts.iter().filter(|d| d["kind"] == "federal").count();
+    assert_eq!(federal, 94);
+
+    let sdny = districts.iter().find(|d| d["code"] == "SDNY").expect("SDNY listed");
+    assert_eq!(sdny["name"], "Southern District of New York");
+    assert_eq!(sdny["circuit"], "2nd");
+
+    let district9 = districts.iter().find(|d| d["code"] == "DISTRICT9").expect("DISTRICT9 listed");
+    assert_eq!(district9["kind"], "built_in");
+    assert_eq!(district9["provisioned"], true);
+    assert_eq!(district9["counts"]["cases"], 1);
+    assert!(district9["total_records"].as_u64().unwrap() >= 1);
+}
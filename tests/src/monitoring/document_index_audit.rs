@@ -0,0 +1,168 @@
+//! Document index audit tests
+//!
+//! Tests for GET /api/admin/documents/index-audit, which checks indexed
+//! documents against their type's metadata shape and the cases they belong to.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::{key_value, variables}}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const ADMIN_TOKEN: &str = "test-admin-token";
+
+/// Helper to send a request, optionally with an admin token
+fn send(method: Method, path: &str, body: Option<Value>, district: &str, admin_token: Option<&str>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+    if let Some(token) = admin_token {
+        headers.append(&"X-Admin-Token".to_string(), token.as_bytes()).unwrap();
+    }
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case(district: &str) -> (String, String) {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. Index",
+        "description": "Case for document index audit",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), district, None);
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    (case["id"].as_str().unwrap().to_string(), case["caseNumber"].as_str().unwrap().to_string())
+}
+
+/// Write an index entry straight into the tenant store
+fn seed_index_entry(store: &key_value::Store, document_id: &str, document_type: &str, case_number: &str, case_id: Option<&str>, metadata: Value) {
+    let entry = json!({
+        "document_id": document_id,
+        "document_type": document_type,
+        "case_number": case_number,
+        "case_id": case_id,
+        "district": "SDNY",
+        "filename": format!("{}.pdf", document_id),
+        "generated_at": "2026-03-02T15:00:00Z",
+        "metadata": metadata
+    });
+    store.set(&format!("district9:document-index:{}", document_id), &serde_json::to_vec(&entry).unwrap()).unwrap();
+
+    let mut ids: Vec<String> = store
+        .get("index:documents")
+        .unwrap()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default();
+    ids.push(document_id.to_string());
+    store.set("district9:index:documents", &serde_json::to_vec(&ids).unwrap()).unwrap();
+}
+
+fn audit(district: &str) -> Value {
+    let (status, body) = send(Method::Get, "/api/admin/documents/index-audit", None, district, Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "Audit should succeed: {}", body);
+    body
+}
+
+fn anomalies_for<'a>(audit: &'a Value, document_id: &str) -> Vec<&'a Value> {
+    audit["anomalies"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|a| a["document_id"] == document_id)
+        .collect()
+}
+
+#[spin_test]
+fn test_audit_flags_entry_for_deleted_case() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let store = key_value::Store::open("district9");
+
+    let (case_id, case_number) = create_case("district9");
+    seed_index_entry(&store, "doc-deleted-case", "summons", &case_number, Some(&case_id), json!({
+        "party_name": "John Doe",
+        "party_address": "1 Main St",
+        "response_days": 21
+    }));
+
+    let before = audit("district9");
+    assert!(anomalies_for(&before, "doc-deleted-case").is_empty(), "Entry should be clean while the case exists: {}", before);
+
+    let (status, _) = send(Method::Delete, &format!("/api/cases/{}", case_id), None, "district9", None);
+    assert!(status == 200 || status == 204, "Case should be deleted, got {}", status);
+
+    let after = audit("district9");
+    let anomalies = anomalies_for(&after, "doc-deleted-case");
+    assert_eq!(anomalies.len(), 1, "Deleted case should be flagged: {}", after);
+    assert_eq!(anomalies[0]["kind"], "case_not_found");
+    assert!(anomalies[0]["detail"].as_str().unwrap().contains(&case_id));
+}
+
+#[spin_test]
+fn test_audit_flags_metadata_that_does_not_match_type() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let store = key_value::Store::open("district9");
+
+    let (_, case_number) = create_case("district9");
+    seed_index_entry(&store, "doc-wrong-shape", "waiver_indictment", &case_number, None, json!({
+        "defendant_name": "John Doe",
+        "minute_text": "Hearing held"
+    }));
+    seed_index_entry(&store, "doc-unknown-type", "warrant", &case_number, None, json!({}));
+
+    let result = audit("district9");
+
+    let wrong_shape = anomalies_for(&result, "doc-wrong-shape");
+    assert_eq!(wrong_shape.len(), 2, "Missing and unexpected fields should both be flagged: {}", result);
+    assert!(wrong_shape.iter().all(|a| a["kind"] == "metadata_mismatch"));
+
+    let unknown = anomalies_for(&result, "doc-unknown-type");
+    assert_eq!(unknown.len(), 1);
+    assert_eq!(unknown[0]["kind"], "unknown_document_type");
+}
+
+#[spin_test]
+fn test_generated_documents_are_indexed_and_pass_audit() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let (_, case_number) = create_case("district9");
+    let (status, pdf) = send(Method::Post, "/api/pdf/court-order", Some(json!({
+        "case_number": case_number,
+        "defendant_names": "John Doe",
+        "judge_name": "Hon. Jane Smith",
+        "order_title": "Scheduling Order",
+        "order_content": "Trial is set for June 1."
+    })), "district9", None);
+    assert_eq!(status, 200, "Court order should be generated: {}", pdf);
+    let document_id = pdf["document_id"].as_str().unwrap();
+
+    let result = audit("district9");
+    assert!(result["checked"].as_u64().unwrap() >= 1);
+    assert!(anomalies_for(&result, document_id).is_empty(), "Generated order should pass audit: {}", result);
+}
+
+#[spin_test]
+fn test_audit_requires_admin_token() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let (status, _) = send(Method::Get, "/api/admin/documents/index-audit", None, "district9", None);
+    assert_eq!(status, 403, "Missing token should be rejected");
+
+    let (status, _) = send(Method::Get, "/api/admin/documents/index-audit", None, "district9", Some("wrong-token"));
+    assert_eq!(status, 403, "Wrong token should be rejected");
+}
@@ -0,0 +1,128 @@
+//! Error envelope tests
+//!
+//! Tests that failures from every handler family share one JSON shape,
+//! `{"error": {code, message, details?, request_id}}`, and that the request id
+//! matches the `X-Request-Id` response header.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status, X-Request-Id and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: Option<&str>) -> (u16, Option<String>, Value) {
+    let headers = Headers::new();
+    if let Some(district) = district {
+        headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    }
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let request_id = response
+        .headers()
+        .get(&"x-request-id".to_string())
+        .first()
+        .map(|v| String::from_utf8_lossy(v).to_string());
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, request_id, body)
+}
+
+/// Assert a response is an error envelope with the given status and code
+fn assert_envelope(response: (u16, Option<String>, Value), status: u16, code: &str) {
+    let (actual_status, request_id, body) = response;
+    assert_eq!(actual_status, status, "Unexpected status: {}", body);
+    assert_eq!(body.as_object().map(|fields| fields.len()), Some(1), "Envelope should carry only `error`: {}", body);
+
+    let error = &body["error"];
+    assert_eq!(error["code"], code, "Unexpected code: {}", body);
+    assert!(error["message"].as_str().is_some_and(|m| !m.is_empty()), "Message should be set: {}", body);
+
+    let header = request_id.expect("X-Request-Id header");
+    assert!(!header.is_empty());
+    assert_eq!(error["request_id"], header.as_str(), "Body and header request ids should match");
+}
+
+#[spin_test]
+fn test_not_found_envelope_across_handler_families() {
+    let _store = key_value::Store::open("district9");
+    let missing = "7d3f2c1e-0000-4000-8000-000000000000";
+
+    assert_envelope(send(Method::Get, &format!("/api/cases/{}", missing), None, Some("district9")), 404, "NOT_FOUND");
+    assert_envelope(send(Method::Get, "/api/attorneys/missing-attorney", None, Some("district9")), 404, "NOT_FOUND");
+    assert_envelope(send(Method::Get, "/api/sentencing/missing-sentencing", None, Some("district9")), 404, "NOT_FOUND");
+    assert_envelope(send(Method::Get, &format!("/api/signatures/{}", missing), None, Some("district9")), 404, "NOT_FOUND");
+}
+
+#[spin_test]
+fn test_validation_envelope_for_pdf_and_signature_handlers() {
+    let _store = key_value::Store::open("district9");
+
+    assert_envelope(
+        send(Method::Post, "/api/pdf/court-order/json", Some(json!({"case_number": "1:25-cr-00001"})), Some("district9")),
+        400,
+        "VALIDATION_FAILED",
+    );
+    assert_envelope(
+        send(Method::Post, "/api/signatures", Some(json!({"judge_id": "not-a-uuid", "signature_base64": "AAAA"})), Some("district9")),
+        400,
+        "VALIDATION_FAILED",
+    );
+}
+
+#[spin_test]
+fn test_structured_details_for_template_values() {
+    let _store = key_value::Store::open("district9");
+
+    let variable = |name: &str, variable_type: &str| json!({
+        "name": name,
+        "description": name,
+        "variable_type": variable_type,
+        "required": true,
+        "default_value": null,
+        "validation_rules": []
+    });
+    let (status, _, template) = send(Method::Post, "/api/templates/orders", Some(json!({
+        "name": "Response Order",
+        "order_type": "SchedulingOrder",
+        "description": "Orders a party to respond",
+        "template_content": "{{party_name}} shall respond within {{days}} days.",
+        "variables": [variable("party_name", "Text"), variable("days", "Number")],
+        "required_attachments": [],
+        "default_service_method": "ElectronicFiling"
+    })), Some("district9"));
+    assert_eq!(status, 201, "Template should be created: {}", template);
+
+    let path = format!("/api/templates/{}/generate-content", template["id"].as_str().unwrap());
+    let response = send(Method::Post, &path, Some(json!({"party_name": "Defendant", "days": "ten"})), Some("district9"));
+    let details = response.2["error"]["details"].clone();
+    assert_envelope(response, 422, "VALIDATION_FAILED");
+    assert_eq!(details["invalid"][0]["name"], "days");
+}
+
+#[spin_test]
+fn test_missing_district_envelope() {
+    assert_envelope(send(Method::Get, "/api/attorneys", None, None), 400, "TENANT_INVALID");
+}
+
+#[spin_test]
+fn test_successful_responses_carry_request_id() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, first, _) = send(Method::Get, "/api/attorneys", None, Some("district9"));
+    assert_eq!(status, 200);
+    let (_, second, _) = send(Method::Get, "/api/attorneys", None, Some("district9"));
+    assert!(first.is_some() && second.is_some());
+    assert_ne!(first, second, "Each request should get its own id");
+}
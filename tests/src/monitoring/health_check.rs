@@ -5,7 +5,7 @@
 //!
 //! Expected responses:
 //! - 200 OK: API is healthy, returns HealthStatus object
-//! - 503 Service Unavailable: API is unhealthy
+//! - 503 Service Unavailable: the KV store probe failed
 
 use spin_test_sdk::{spin_test, bindings::wasi::http};
 use http::types::{Headers, Method, OutgoingRequest};
@@ -55,9 +55,10 @@ fn test_health_check_returns_ok_when_healthy() {
         "Response should include version field"
     );
 
-    assert!(
-        health_response.get("storage").is_some(),
-        "Response should include storage field"
+    assert_eq!(
+        health_response["kv"],
+        "ok",
+        "KV probe should succeed when the store is working"
     );
 
     assert!(
@@ -90,8 +91,8 @@ fn test_health_check_response_structure() {
     );
 
     assert!(
-        health_response["storage"].is_string(),
-        "storage field should be a string"
+        health_response["kv"].is_string(),
+        "kv field should be a string"
     );
 
     assert!(
@@ -99,11 +100,11 @@ fn test_health_check_response_structure() {
         "timestamp field should be a string"
     );
 
-    // Validate storage status is one of the expected values
-    let storage_status = health_response["storage"].as_str().unwrap();
+    // Validate KV status is one of the expected values
+    let kv_status = health_response["kv"].as_str().unwrap();
     assert!(
-        ["connected", "disconnected", "error"].contains(&storage_status),
-        "Storage status should be 'connected', 'disconnected', or 'error'"
+        ["ok", "error"].contains(&kv_status),
+        "KV status should be 'ok' or 'error'"
     );
 }
 
@@ -170,4 +171,28 @@ fn test_health_check_no_authentication_required() {
         status, 200,
         "Health check should return 200 without authentication headers"
     );
-}
\ No newline at end of file
+}
+
+/// Version of the application crate, read from its manifest
+fn crate_version() -> &'static str {
+    include_str!("../../../Cargo.toml")
+        .lines()
+        .find_map(|line| line.strip_prefix("version = "))
+        .map(|version| version.trim().trim_matches('"'))
+        .expect("Cargo.toml should declare a version")
+}
+
+#[spin_test]
+fn test_health_check_reports_crate_version() {
+    let (status, body) = make_health_request();
+    assert_eq!(status, 200, "Health check should return 200");
+
+    let health_response: Value = serde_json::from_str(&body)
+        .expect("Response should be valid JSON");
+
+    assert_eq!(
+        health_response["version"],
+        crate_version(),
+        "Health check should report the crate version"
+    );
+}
@@ -0,0 +1,72 @@
+//! Journal recovery tests
+//!
+//! Tests for POST /api/admin/recover-journals, which finishes or rolls back
+//! multi-record commits whose journal entries a crash left behind.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::{key_value, variables}}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const ADMIN_TOKEN: &str = "test-admin-token";
+/// `landed`, base64 encoded as journal entries store values
+const LANDED: &str = "bGFuZGVk";
+
+/// Helper to send a request, optionally with an admin token
+fn send(path: &str, admin_token: Option<&str>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), b"district9").unwrap();
+    if let Some(token) = admin_token {
+        headers.append(&"X-Admin-Token".to_string(), token.as_bytes()).unwrap();
+    }
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&Method::Post).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Write a journal entry for a commit of one key straight into the tenant store
+fn seed_journal(store: &key_value::Store, id: &str, started_at: &str, key: &str) {
+    let journal = json!({
+        "started_at": started_at,
+        "writes": [{"key": key, "before": null, "after": LANDED}]
+    });
+    store.set(&format!("district9:journal-{}", id), &serde_json::to_vec(&journal).unwrap()).unwrap();
+}
+
+#[spin_test]
+fn test_recover_journals_requires_admin() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let (status, _) = send("/api/admin/recover-journals", None);
+    assert_eq!(status, 403);
+}
+
+#[spin_test]
+fn test_abandoned_commits_are_finished_or_rolled_back() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let store = key_value::Store::open("district9");
+
+    // Every write landed: only the journal entry goes
+    store.set("district9:probe-landed", b"landed").unwrap();
+    seed_journal(&store, "landed", "2026-01-05T10:00:00Z", "district9:probe-landed");
+    // The write never landed: nothing to undo, the journal entry goes
+    seed_journal(&store, "missing", "2026-01-05T10:00:00Z", "district9:probe-missing");
+    // Too recent: the commit may still be running
+    seed_journal(&store, "running", "2999-01-01T00:00:00Z", "district9:probe-running");
+
+    let (status, report) = send("/api/admin/recover-journals", Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "Recovery should succeed: {}", report);
+    assert_eq!(report["recovered"], 2);
+
+    assert_eq!(store.get("district9:probe-landed").unwrap(), Some(b"landed".to_vec()));
+    assert_eq!(store.get("district9:probe-missing").unwrap(), None);
+    assert!(store.get("district9:journal-landed").unwrap().is_none());
+    assert!(store.get("district9:journal-missing").unwrap().is_none());
+    assert!(store.get("district9:journal-running").unwrap().is_some());
+}
@@ -2,4 +2,14 @@
 //!
 //! This module contains tests for monitoring endpoints like health checks
 
-pub mod health_check;
\ No newline at end of file
+pub mod district_registry;
+pub mod document_index_audit;
+pub mod error_envelope;
+pub mod health_check;
+pub mod journal_recovery;
+pub mod rate_limit;
+pub mod request_id;
+pub mod tenant_export;
+pub mod tenant_import;
+pub mod tenant_isolation;
+pub mod tenant_stats;
\ No newline at end of file
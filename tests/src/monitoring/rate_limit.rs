@@ -0,0 +1,128 @@
+//! Rate limiting tests
+//!
+//! Tests that requests are limited per tenant in fixed one-minute windows,
+//! with tighter allowances for expensive endpoints, returning 429 with
+//! Retry-After once an allowance is used up. The health check is exempt.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status, Retry-After and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Option<String>, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let retry_after = response
+        .headers()
+        .get(&"retry-after".to_string())
+        .first()
+        .map(|v| String::from_utf8_lossy(v).to_string());
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, retry_after, body)
+}
+
+/// Limit case statistics to `requests_per_minute` with no burst for a district
+fn limit_statistics(district: &str, requests_per_minute: u64) {
+    let (status, _, body) = send(Method::Put, "/api/config/overrides/district", Some(json!({
+        "rate_limits.case_statistics.requests_per_minute": requests_per_minute,
+        "rate_limits.case_statistics.burst": 0
+    })), district);
+    assert_eq!(status, 200, "Config override should be saved: {}", body);
+}
+
+fn statistics_status(district: &str) -> (u16, Option<String>) {
+    let (status, retry_after, _) = send(Method::Get, "/api/cases/statistics", None, district);
+    (status, retry_after)
+}
+
+#[spin_test]
+fn test_over_limit_returns_429_with_retry_after() {
+    let _store = key_value::Store::open("district9");
+    limit_statistics("district9", 2);
+
+    assert_eq!(statistics_status("district9").0, 200);
+    assert_eq!(statistics_status("district9").0, 200);
+
+    let (status, retry_after, body) = send(Method::Get, "/api/cases/statistics", None, "district9");
+    assert_eq!(status, 429);
+    assert_eq!(body["error"]["code"], "RATE_LIMITED");
+    let seconds: i64 = retry_after.expect("Retry-After header").parse().unwrap();
+    assert!((1..=60).contains(&seconds), "Retry-After should fall within the window: {}", seconds);
+}
+
+#[spin_test]
+fn test_tenants_do_not_share_a_bucket() {
+    let _store = key_value::Store::open("district9");
+    let _other = key_value::Store::open("district12");
+    limit_statistics("district9", 1);
+    limit_statistics("district12", 1);
+
+    assert_eq!(statistics_status("district9").0, 200);
+    assert_eq!(statistics_status("district9").0, 429);
+
+    assert_eq!(statistics_status("district12").0, 200, "district12 has its own allowance");
+}
+
+#[spin_test]
+fn test_counts_from_earlier_windows_do_not_apply() {
+    let store = key_value::Store::open("district9");
+    limit_statistics("district9", 1);
+
+    // A maxed-out counter from a window long past
+    let counter = json!({"window": 1, "count": 1000});
+    store.set(&"district9:ratelimit:district9:case_statistics".to_string(), counter.to_string().as_bytes()).unwrap();
+
+    assert_eq!(statistics_status("district9").0, 200, "a new window starts from zero");
+}
+
+#[spin_test]
+fn test_tenant_limit_covers_every_route_but_health() {
+    let _store = key_value::Store::open("district9");
+    // The override itself is the window's first request
+    let (status, _, body) = send(Method::Put, "/api/config/overrides/district", Some(json!({
+        "rate_limits.tenant.requests_per_minute": 3,
+        "rate_limits.tenant.burst": 0
+    })), "district9");
+    assert_eq!(status, 200, "Config override should be saved: {}", body);
+
+    assert_eq!(send(Method::Get, "/api/cases", None, "district9").0, 200);
+    assert_eq!(send(Method::Get, "/api/judges", None, "district9").0, 200);
+
+    let (status, retry_after, body) = send(Method::Get, "/api/cases", None, "district9");
+    assert_eq!(status, 429);
+    assert_eq!(body["error"]["code"], "RATE_LIMITED");
+    assert!(retry_after.is_some(), "Retry-After header");
+
+    for _ in 0..3 {
+        let (status, _, _) = send(Method::Get, "/api/health", None, "district9");
+        assert_eq!(status, 200, "the health check is never limited");
+    }
+}
+
+#[spin_test]
+fn test_other_routes_do_not_use_a_groups_allowance() {
+    let _store = key_value::Store::open("district9");
+    limit_statistics("district9", 1);
+
+    for _ in 0..3 {
+        let (status, _, _) = send(Method::Get, "/api/cases", None, "district9");
+        assert_eq!(status, 200);
+    }
+    assert_eq!(statistics_status("district9").0, 200);
+}
@@ -0,0 +1,70 @@
+//! Request id tests
+//!
+//! Tests that every response carries an `X-Request-Id` header, generated
+//! when the caller sends none and echoed when it sends a usable one.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a GET, optionally with an incoming request id, returning
+/// status, the response's X-Request-Id and the parsed body
+fn send(path: &str, request_id: Option<&str>) -> (u16, Option<String>, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), b"district9").unwrap();
+    if let Some(id) = request_id {
+        headers.append(&"X-Request-Id".to_string(), id.as_bytes()).unwrap();
+    }
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&Method::Get).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let request_id = response
+        .headers()
+        .get(&"x-request-id".to_string())
+        .first()
+        .map(|v| String::from_utf8_lossy(v).to_string());
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, request_id, body)
+}
+
+#[spin_test]
+fn test_generated_id_when_none_supplied() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, first, _) = send("/api/cases", None);
+    assert_eq!(status, 200);
+    let first = first.expect("X-Request-Id header");
+    assert!(!first.is_empty());
+
+    let (_, second, _) = send("/api/cases", None);
+    assert_ne!(Some(first), second, "Each request gets its own id");
+}
+
+#[spin_test]
+fn test_supplied_id_is_echoed_on_success_and_error() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, id, _) = send("/api/cases", Some("upstream-trace-42"));
+    assert_eq!(status, 200);
+    assert_eq!(id.as_deref(), Some("upstream-trace-42"));
+
+    let (status, id, body) = send("/api/cases/7d3f2c1e-0000-4000-8000-000000000000", Some("upstream-trace-43"));
+    assert_eq!(status, 404);
+    assert_eq!(id.as_deref(), Some("upstream-trace-43"));
+    assert_eq!(body["error"]["request_id"], "upstream-trace-43", "Error envelope should carry the same id: {}", body);
+}
+
+#[spin_test]
+fn test_unusable_supplied_id_is_replaced() {
+    let _store = key_value::Store::open("district9");
+
+    let too_long = "x".repeat(200);
+    let (_, id, _) = send("/api/cases", Some(&too_long));
+    let id = id.expect("X-Request-Id header");
+    assert_ne!(id, too_long);
+    assert!(!id.is_empty());
+}
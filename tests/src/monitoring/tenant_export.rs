@@ -0,0 +1,109 @@
+//! Tenant export tests
+//!
+//! Tests for GET /api/admin/export, which returns a tenant's records from
+//! every domain, or the ones named in `domains`, as a single JSON bundle.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::{key_value, variables}}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const ADMIN_TOKEN: &str = "test-admin-token";
+
+/// Helper to send a request, optionally with an admin token
+fn send(method: Method, path: &str, body: Option<Value>, district: &str, admin_token: Option<&str>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+    if let Some(token) = admin_token {
+        headers.append(&"X-Admin-Token".to_string(), token.as_bytes()).unwrap();
+    }
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case(district: &str) -> String {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. Export",
+        "description": "Case for tenant export",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), district, None);
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    case["id"].as_str().unwrap().to_string()
+}
+
+#[spin_test]
+fn test_export_includes_every_domain() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let case_id = create_case("district9");
+
+    let (status, export) = send(Method::Get, "/api/admin/export", None, "district9", Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "Export should succeed: {}", export);
+    assert_eq!(export["tenant_id"], "district9");
+
+    for domain in ["attorneys", "cases", "judges", "deadlines", "docket_entries", "calendar_events", "orders", "opinions", "rules", "sentencings"] {
+        assert!(export[domain].is_array(), "{} should be exported: {}", domain, export);
+        assert_eq!(
+            export["counts"][domain].as_u64().unwrap() as usize,
+            export[domain].as_array().unwrap().len(),
+            "Count for {} should match its records",
+            domain
+        );
+    }
+    assert!(export["cases"].as_array().unwrap().iter().any(|c| c["id"] == case_id.as_str()));
+}
+
+#[spin_test]
+fn test_export_limited_to_requested_domains() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    create_case("district9");
+
+    let (status, export) = send(Method::Get, "/api/admin/export?domains=cases,attorneys", None, "district9", Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "Export should succeed: {}", export);
+    assert_eq!(export["domains"], json!(["attorneys", "cases"]));
+    assert!(export["cases"].as_array().unwrap().len() >= 1);
+    assert!(export["attorneys"].is_array());
+    assert!(export.get("judges").is_none(), "Unrequested domains should be left out: {}", export);
+    assert!(export["counts"].get("judges").is_none());
+}
+
+#[spin_test]
+fn test_export_rejects_unknown_domain() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let (status, body) = send(Method::Get, "/api/admin/export?domains=cases,todos", None, "district9", Some(ADMIN_TOKEN));
+    assert_eq!(status, 400, "Unknown domain should be rejected: {}", body);
+}
+
+#[spin_test]
+fn test_export_requires_admin_token() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let (status, _) = send(Method::Get, "/api/admin/export", None, "district9", None);
+    assert_eq!(status, 403, "Missing token should be rejected");
+
+    let (status, _) = send(Method::Get, "/api/admin/export", None, "district9", Some("wrong-token"));
+    assert_eq!(status, 403, "Wrong token should be rejected");
+}
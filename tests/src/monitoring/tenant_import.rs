@@ -0,0 +1,190 @@
+//! Tenant import tests
+//!
+//! Tests for POST /api/admin/import, which writes an export bundle into the
+//! tenant and resolves existing IDs by the `conflict_policy` query parameter.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::{key_value, variables}}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const ADMIN_TOKEN: &str = "test-admin-token";
+
+/// Helper to send a request, optionally with an admin token
+fn send(method: Method, path: &str, body: Option<Value>, district: &str, admin_token: Option<&str>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+    if let Some(token) = admin_token {
+        headers.append(&"X-Admin-Token".to_string(), token.as_bytes()).unwrap();
+    }
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Create a case and return it as it appears in an export
+fn exported_case(district: &str) -> Value {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. Import",
+        "description": "Case for tenant import",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), district, None);
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    let case_id = case["id"].as_str().unwrap();
+
+    let (status, export) = send(Method::Get, "/api/admin/export?domains=cases", None, district, Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "Export should succeed: {}", export);
+    export["cases"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["id"] == case_id)
+        .cloned()
+        .expect("Created case should be exported")
+}
+
+fn bundle(cases: Vec<Value>) -> Value {
+    json!({
+        "tenant_id": "template",
+        "exported_at": "2026-03-02T15:00:00Z",
+        "domains": ["cases"],
+        "counts": { "cases": cases.len() },
+        "cases": cases
+    })
+}
+
+fn import(policy: &str, bundle: Value) -> (u16, Value) {
+    send(Method::Post, &format!("/api/admin/import?conflict_policy={}", policy), Some(bundle), "district9", Some(ADMIN_TOKEN))
+}
+
+fn case_title(case_id: &str) -> Option<String> {
+    let (status, case) = send(Method::Get, &format!("/api/cases/{}", case_id), None, "district9", None);
+    (status == 200).then(|| case["title"].as_str().unwrap().to_string())
+}
+
+/// A copy of an exported case under a new ID and case number
+fn new_case_from(case: &Value, id: &str, case_number: &str) -> Value {
+    let mut copy = case.clone();
+    copy["id"] = json!(id);
+    copy["case_number"] = json!(case_number);
+    copy["title"] = json!("United States v. Template");
+    copy
+}
+
+#[spin_test]
+fn test_import_skip_keeps_existing_records() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let mut case = exported_case("district9");
+    let case_id = case["id"].as_str().unwrap().to_string();
+    case["title"] = json!("United States v. Renamed");
+    let fresh = new_case_from(&case, "5b0f3c1e-8f55-4d7a-9a3e-0c6f1b2d4e01", "26-cr-90001");
+
+    let (status, report) = import("skip", bundle(vec![case, fresh]));
+    assert_eq!(status, 200, "Import should succeed: {}", report);
+    assert_eq!(report["conflict_policy"], "skip");
+    assert_eq!(report["domains"]["cases"]["imported"], 1);
+    assert_eq!(report["domains"]["cases"]["skipped"], 1);
+    assert_eq!(report["domains"]["cases"]["failed"], 0);
+
+    assert_eq!(case_title(&case_id).unwrap(), "United States v. Import");
+    assert_eq!(case_title("5b0f3c1e-8f55-4d7a-9a3e-0c6f1b2d4e01").unwrap(), "United States v. Template");
+}
+
+#[spin_test]
+fn test_import_overwrite_replaces_existing_records() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let mut case = exported_case("district9");
+    let case_id = case["id"].as_str().unwrap().to_string();
+    case["title"] = json!("United States v. Renamed");
+
+    let (status, report) = import("overwrite", bundle(vec![case]));
+    assert_eq!(status, 200, "Import should succeed: {}", report);
+    assert_eq!(report["domains"]["cases"]["imported"], 1);
+    assert_eq!(report["domains"]["cases"]["skipped"], 0);
+
+    assert_eq!(case_title(&case_id).unwrap(), "United States v. Renamed");
+}
+
+#[spin_test]
+fn test_import_fail_writes_nothing_from_conflicting_domain() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let mut case = exported_case("district9");
+    let case_id = case["id"].as_str().unwrap().to_string();
+    case["title"] = json!("United States v. Renamed");
+    let fresh = new_case_from(&case, "5b0f3c1e-8f55-4d7a-9a3e-0c6f1b2d4e02", "26-cr-90002");
+
+    let (status, report) = import("fail", bundle(vec![fresh, case]));
+    assert_eq!(status, 409, "Conflicting import should fail: {}", report);
+    assert_eq!(report["domains"]["cases"]["imported"], 0);
+    assert_eq!(report["domains"]["cases"]["failed"], 2);
+    assert!(report["domains"]["cases"]["error"].as_str().unwrap().contains(&case_id));
+
+    assert_eq!(case_title(&case_id).unwrap(), "United States v. Import");
+    assert!(case_title("5b0f3c1e-8f55-4d7a-9a3e-0c6f1b2d4e02").is_none(), "New case should not be written");
+}
+
+#[spin_test]
+fn test_import_fail_imports_new_records() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let case = exported_case("district9");
+    let fresh = new_case_from(&case, "5b0f3c1e-8f55-4d7a-9a3e-0c6f1b2d4e03", "26-cr-90003");
+
+    // fail is the default policy
+    let (status, report) = send(Method::Post, "/api/admin/import", Some(bundle(vec![fresh])), "district9", Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "Import without conflicts should succeed: {}", report);
+    assert_eq!(report["conflict_policy"], "fail");
+    assert_eq!(report["domains"]["cases"]["imported"], 1);
+    assert_eq!(case_title("5b0f3c1e-8f55-4d7a-9a3e-0c6f1b2d4e03").unwrap(), "United States v. Template");
+}
+
+#[spin_test]
+fn test_import_rejects_invalid_bundle_before_writing() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let case = exported_case("district9");
+    let fresh = new_case_from(&case, "5b0f3c1e-8f55-4d7a-9a3e-0c6f1b2d4e04", "26-cr-90004");
+
+    let (status, body) = import("overwrite", bundle(vec![fresh.clone(), fresh]));
+    assert_eq!(status, 400, "Duplicate IDs should be rejected: {}", body);
+    assert!(case_title("5b0f3c1e-8f55-4d7a-9a3e-0c6f1b2d4e04").is_none());
+
+    let (status, body) = import("overwrite", json!({ "cases": [] }));
+    assert_eq!(status, 400, "Malformed bundle should be rejected: {}", body);
+
+    let (status, body) = import("merge", bundle(Vec::new()));
+    assert_eq!(status, 400, "Unknown policy should be rejected: {}", body);
+}
+
+#[spin_test]
+fn test_import_requires_admin_token() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let (status, _) = send(Method::Post, "/api/admin/import", Some(bundle(Vec::new())), "district9", None);
+    assert_eq!(status, 403, "Missing token should be rejected");
+}
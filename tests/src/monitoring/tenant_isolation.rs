@@ -0,0 +1,89 @@
+//! Tenant isolation tests
+//!
+//! Tests that every key a repository writes is prefixed with the tenant ID,
+//! that a record which ends up in another tenant's store stays invisible
+//! there, and that tenant-scoped endpoints reject requests without a
+//! district.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status and parsed body
+fn send(method: Method, path: &str, body: Option<Value>, district: Option<&str>) -> (u16, Value) {
+    let headers = Headers::new();
+    if let Some(district) = district {
+        headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    }
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case(district: &str) -> String {
+    let (status, body) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. Isolation",
+        "description": "Tenant isolation test case",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), Some(district));
+    assert_eq!(status, 201, "Case should be created: {}", body);
+    body["id"].as_str().unwrap().to_string()
+}
+
+#[spin_test]
+fn test_keys_are_prefixed_with_tenant() {
+    let store = key_value::Store::open("district9");
+
+    let case_id = create_case("district9");
+
+    assert!(store.get(&format!("district9:case-{}", case_id)).unwrap().is_some(), "Key should carry the tenant prefix");
+    assert!(store.get(&format!("case-{}", case_id)).unwrap().is_none(), "No key should be written without a prefix");
+}
+
+#[spin_test]
+fn test_data_written_under_one_tenant_is_invisible_to_another() {
+    let store9 = key_value::Store::open("district9");
+    let store12 = key_value::Store::open("district12");
+
+    let case_id = create_case("district9");
+    assert_eq!(send(Method::Get, &format!("/api/cases/{}", case_id), None, Some("district12")).0, 404);
+
+    // Even a record that lands in the wrong store stays under its own tenant's prefix
+    let key = format!("district9:case-{}", case_id);
+    store12.set(&key, &store9.get(&key).unwrap().unwrap()).unwrap();
+
+    assert_eq!(send(Method::Get, &format!("/api/cases/{}", case_id), None, Some("district12")).0, 404);
+    let (status, body) = send(Method::Get, "/api/cases", None, Some("district12"));
+    assert_eq!(status, 200);
+    assert!(!body.to_string().contains(&case_id), "district12 should not list district9's case: {}", body);
+
+    assert_eq!(send(Method::Get, &format!("/api/cases/{}", case_id), None, Some("district9")).0, 200);
+}
+
+#[spin_test]
+fn test_missing_district_is_rejected() {
+    let _store = key_value::Store::open("district9");
+
+    for path in ["/api/cases", "/api/judges", "/api/attorneys"] {
+        let (status, body) = send(Method::Get, path, None, None);
+        assert_eq!(status, 400, "{} should require a district: {}", path, body);
+        assert_eq!(body["error"]["code"], "TENANT_INVALID");
+    }
+}
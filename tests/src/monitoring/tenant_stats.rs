@@ -0,0 +1,102 @@
+//! Tenant statistics tests
+//!
+//! Tests for GET /api/admin/tenant-stats, which counts a tenant's records per
+//! domain, leaving archived cases out unless `include_deleted` is set.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::{key_value, variables}}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const ADMIN_TOKEN: &str = "test-admin-token";
+
+/// Helper to send a request, optionally with an admin token
+fn send(method: Method, path: &str, body: Option<Value>, admin_token: Option<&str>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), b"district9").unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+    if let Some(token) = admin_token {
+        headers.append(&"X-Admin-Token".to_string(), token.as_bytes()).unwrap();
+    }
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_case(title: &str) -> String {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": title,
+        "description": "Case for tenant statistics",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), None);
+    assert_eq!(status, 201, "Case should be created: {}", case);
+    case["id"].as_str().unwrap().to_string()
+}
+
+#[spin_test]
+fn test_stats_count_each_domain() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    create_case("United States v. Counted");
+    create_case("United States v. Also Counted");
+
+    let (status, stats) = send(Method::Get, "/api/admin/tenant-stats", None, Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "Stats should succeed: {}", stats);
+    assert_eq!(stats["tenant_id"], "district9");
+    assert!(stats["generated_at"].is_string());
+    assert_eq!(stats["counts"]["cases"], 2);
+    for domain in ["attorneys", "judges", "deadlines", "orders", "opinions", "sentencings"] {
+        assert!(stats["counts"][domain].is_u64(), "{} should be counted: {}", domain, stats);
+    }
+    assert!(stats["total_records"].as_u64().unwrap() >= 2);
+    assert!(stats["storage_bytes"].as_u64().unwrap() > 0, "Stored cases take space: {}", stats);
+}
+
+#[spin_test]
+fn test_archived_cases_excluded_by_default() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    create_case("United States v. Live");
+    let archived = create_case("United States v. Archived");
+    let (status, _) = send(Method::Patch, &format!("/api/cases/{}/status", archived), Some(json!({"status": "dismissed"})), None);
+    assert_eq!(status, 200);
+    let (status, archive) = send(Method::Post, &format!("/api/cases/{}/archive", archived), None, None);
+    assert_eq!(status, 200, "Dismissed case should archive: {}", archive);
+
+    let (status, stats) = send(Method::Get, "/api/admin/tenant-stats", None, Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "Stats should succeed: {}", stats);
+    assert_eq!(stats["counts"]["cases"], 1);
+    assert_eq!(stats["archived_cases"], 1);
+    assert_eq!(stats["include_deleted"], false);
+
+    let (status, stats) = send(Method::Get, "/api/admin/tenant-stats?include_deleted=true", None, Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "Stats should succeed: {}", stats);
+    assert_eq!(stats["counts"]["cases"], 2);
+    assert_eq!(stats["include_deleted"], true);
+}
+
+#[spin_test]
+fn test_stats_require_admin_token() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let (status, _) = send(Method::Get, "/api/admin/tenant-stats", None, None);
+    assert_eq!(status, 403);
+}
@@ -0,0 +1,256 @@
+//! Opinion citation graph tests
+//!
+//! Tests for the reverse-citation index (`cited-by`) and the depth-limited
+//! citation graph traversal built on top of it.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+/// Helper to create an opinion and return its ID
+fn create_opinion(case_name: &str, district: &str) -> String {
+    let (status, body) = send(Method::Post, "/api/opinions", Some(json!({
+        "case_id": format!("case-{}", case_name),
+        "case_name": case_name,
+        "docket_number": "24-cv-0001",
+        "author_judge_id": "judge-1",
+        "author_judge_name": "Hon. Test Judge",
+        "opinion_type": "Majority",
+        "title": format!("Opinion in {}", case_name),
+        "syllabus": "",
+        "content": "Opinion text"
+    })), district);
+    assert_eq!(status, 201, "Opinion creation should succeed: {}", body);
+    body["id"].as_str().unwrap().to_string()
+}
+
+/// Helper to record that `citing` cites `cited` with the given treatment
+fn cite(citing: &str, cited: &str, treatment: &str, district: &str) {
+    let (status, body) = send(Method::Post, &format!("/api/opinions/{}/citations", citing), Some(json!({
+        "citation_text": format!("{} F.4th 1", cited),
+        "case_name": "Cited v. Case",
+        "reporter": "F.4th",
+        "year": 2024,
+        "court": null,
+        "proposition": "Test proposition",
+        "treatment": treatment,
+        "cited_opinion_id": cited
+    })), district);
+    assert_eq!(status, 200, "Adding citation should succeed: {}", body);
+}
+
+/// Builds the six-opinion graph used by these tests and returns the IDs.
+///
+/// ```text
+/// o2 -Followed-> o1 <-Distinguished- o3
+///  ^ |           ^
+///  | v           Overruled
+/// o5             o4
+///  ^
+///  Followed
+/// o6
+/// ```
+/// o2 and o5 cite each other, forming a cycle.
+fn build_graph(district: &str) -> Vec<String> {
+    let ids: Vec<String> = (1..=6)
+        .map(|n| create_opinion(&format!("Party{} v. United States", n), district))
+        .collect();
+
+    cite(&ids[1], &ids[0], "Followed", district);
+    cite(&ids[2], &ids[0], "Distinguished", district);
+    cite(&ids[3], &ids[0], "Overruled", district);
+    cite(&ids[4], &ids[1], "Followed", district);
+    cite(&ids[1], &ids[4], "Followed", district);
+    cite(&ids[5], &ids[4], "Followed", district);
+
+    ids
+}
+
+fn node_ids(graph: &Value) -> Vec<String> {
+    graph["nodes"].as_array().unwrap()
+        .iter()
+        .map(|n| n["opinion_id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[spin_test]
+fn test_cited_by_lists_citing_opinions() {
+    let _store = key_value::Store::open("district9");
+    let ids = build_graph("district9");
+
+    let (status, body) = send(Method::Get, &format!("/api/opinions/{}/cited-by", ids[0]), None, "district9");
+    assert_eq!(status, 200);
+    assert_eq!(body["meta"]["total"], 3);
+
+    let citing: Vec<&str> = body["data"].as_array().unwrap()
+        .iter()
+        .map(|e| e["citing_opinion_id"].as_str().unwrap())
+        .collect();
+    assert!(citing.contains(&ids[1].as_str()));
+    assert!(citing.contains(&ids[2].as_str()));
+    assert!(citing.contains(&ids[3].as_str()));
+}
+
+#[spin_test]
+fn test_cited_by_uncited_opinion_is_empty() {
+    let _store = key_value::Store::open("district9");
+    let ids = build_graph("district9");
+
+    // o6 cites o5 but nothing cites o6
+    let (status, body) = send(Method::Get, &format!("/api/opinions/{}/cited-by", ids[5]), None, "district9");
+    assert_eq!(status, 200);
+    assert_eq!(body["meta"]["total"], 0);
+    assert!(body["data"].as_array().unwrap().is_empty());
+}
+
+#[spin_test]
+fn test_cited_by_opinion_not_found() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, _) = send(Method::Get, "/api/opinions/nonexistent/cited-by", None, "district9");
+    assert_eq!(status, 404);
+}
+
+#[spin_test]
+fn test_cited_by_filters_by_treatment() {
+    let _store = key_value::Store::open("district9");
+    let ids = build_graph("district9");
+
+    let (status, body) = send(
+        Method::Get,
+        &format!("/api/opinions/{}/cited-by?treatment=Overruled", ids[0]),
+        None,
+        "district9",
+    );
+    assert_eq!(status, 200);
+    assert_eq!(body["meta"]["total"], 1);
+    assert_eq!(body["data"][0]["citing_opinion_id"], ids[3].as_str());
+    assert_eq!(body["data"][0]["treatment"], "Overruled");
+}
+
+#[spin_test]
+fn test_cited_by_is_paginated() {
+    let _store = key_value::Store::open("district9");
+    let ids = build_graph("district9");
+
+    let (status, body) = send(
+        Method::Get,
+        &format!("/api/opinions/{}/cited-by?page=2&limit=2", ids[0]),
+        None,
+        "district9",
+    );
+    assert_eq!(status, 200);
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+    assert_eq!(body["meta"]["total_pages"], 2);
+    assert_eq!(body["meta"]["has_prev"], true);
+}
+
+#[spin_test]
+fn test_cited_by_rejects_unknown_treatment() {
+    let _store = key_value::Store::open("district9");
+    let ids = build_graph("district9");
+
+    let (status, _) = send(
+        Method::Get,
+        &format!("/api/opinions/{}/cited-by?treatment=Ignored", ids[0]),
+        None,
+        "district9",
+    );
+    assert_eq!(status, 400);
+}
+
+#[spin_test]
+fn test_citation_graph_respects_depth() {
+    let _store = key_value::Store::open("district9");
+    let ids = build_graph("district9");
+
+    let (status, graph) = send(
+        Method::Get,
+        &format!("/api/opinions/{}/citation-graph?depth=1", ids[0]),
+        None,
+        "district9",
+    );
+    assert_eq!(status, 200);
+    let nodes = node_ids(&graph);
+    assert_eq!(nodes.len(), 4, "Depth 1 should reach o1-o4: {:?}", nodes);
+    assert!(!nodes.contains(&ids[4]));
+
+    let (_, graph) = send(
+        Method::Get,
+        &format!("/api/opinions/{}/citation-graph?depth=2", ids[0]),
+        None,
+        "district9",
+    );
+    let nodes = node_ids(&graph);
+    assert_eq!(nodes.len(), 5, "Depth 2 should add o5: {:?}", nodes);
+    assert!(nodes.contains(&ids[4]));
+    assert!(!nodes.contains(&ids[5]));
+
+    let (_, graph) = send(
+        Method::Get,
+        &format!("/api/opinions/{}/citation-graph?depth=3", ids[0]),
+        None,
+        "district9",
+    );
+    assert_eq!(node_ids(&graph).len(), 6);
+    assert_eq!(graph["edges"].as_array().unwrap().len(), 6, "Every edge appears once despite the o2/o5 cycle");
+}
+
+#[spin_test]
+fn test_citation_graph_follows_outgoing_citations() {
+    let _store = key_value::Store::open("district9");
+    let ids = build_graph("district9");
+
+    let (status, graph) = send(
+        Method::Get,
+        &format!("/api/opinions/{}/citation-graph?depth=1", ids[5]),
+        None,
+        "district9",
+    );
+    assert_eq!(status, 200);
+    assert_eq!(node_ids(&graph), vec![ids[5].clone(), ids[4].clone()]);
+
+    let edge = &graph["edges"][0];
+    assert_eq!(edge["citing_opinion_id"], ids[5].as_str());
+    assert_eq!(edge["cited_opinion_id"], ids[4].as_str());
+    assert_eq!(edge["treatment"], "Followed");
+}
+
+#[spin_test]
+fn test_citation_graph_opinion_not_found() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, _) = send(Method::Get, "/api/opinions/nonexistent/citation-graph", None, "district9");
+    assert_eq!(status, 404);
+}
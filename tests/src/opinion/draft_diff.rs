@@ -0,0 +1,144 @@
+//! Opinion draft diff tests
+//!
+//! Tests that GET /api/opinions/:opinion_id/drafts/:draft_id/diff reports
+//! the lines changed between two drafts, lists the comments on those lines,
+//! and rejects comparing a draft with itself or with another opinion's draft.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), b"district9").unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+    let body_json: Value = serde_json::from_str(&body).unwrap_or(json!(null));
+    (status, body_json)
+}
+
+fn create_opinion() -> String {
+    let (status, body) = send(Method::Post, "/api/opinions", Some(json!({
+        "case_id": "case-drafts",
+        "case_name": "United States v. Drafts",
+        "docket_number": "24-2002",
+        "author_judge_id": "judge-1",
+        "author_judge_name": "Hon. Test Judge",
+        "opinion_type": "Majority",
+        "title": "Opinion of the Court",
+        "syllabus": "",
+        "content": "Opinion text"
+    })));
+    assert_eq!(status, 201, "Opinion creation should succeed: {}", body);
+    body["id"].as_str().unwrap().to_string()
+}
+
+fn create_draft(opinion_id: &str, content: &str) -> String {
+    let (status, body) = send(Method::Post, &format!("/api/opinions/{}/drafts", opinion_id), Some(json!({
+        "content": content,
+        "changes_summary": "Revision",
+        "created_by": "judge-1"
+    })));
+    assert_eq!(status, 201, "Draft creation should succeed: {}", body);
+    body["id"].as_str().unwrap().to_string()
+}
+
+fn comment(opinion_id: &str, draft_id: &str, line: Option<usize>, text: &str) -> u16 {
+    let (status, _) = send(Method::Post, &format!("/api/opinions/{}/drafts/{}/comments", opinion_id, draft_id), Some(json!({
+        "judge_id": "judge-2",
+        "judge_name": "Hon. Second Judge",
+        "paragraph_ref": null,
+        "line": line,
+        "comment_text": text
+    })));
+    status
+}
+
+fn diff(opinion_id: &str, draft_id: &str, against: &str) -> (u16, Value) {
+    send(Method::Get, &format!("/api/opinions/{}/drafts/{}/diff?against={}", opinion_id, draft_id, against), None)
+}
+
+#[spin_test]
+fn test_diff_reports_changed_lines_and_their_comments() {
+    let _store = key_value::Store::open("district9");
+    let opinion_id = create_opinion();
+    let first = create_draft(&opinion_id, "Background.\nThe statute is ambiguous.\nWe reverse.");
+    let second = create_draft(&opinion_id, "Background.\nThe statute is clear.\nWe affirm.\nSo ordered.");
+
+    assert_eq!(comment(&opinion_id, &first, Some(2), "Is it, though?"), 200);
+    assert_eq!(comment(&opinion_id, &first, Some(1), "Fine as is"), 200);
+    assert_eq!(comment(&opinion_id, &second, Some(4), "Needed?"), 200);
+
+    let (status, body) = diff(&opinion_id, &second, &first);
+    assert_eq!(status, 200, "Diff should succeed: {}", body);
+    assert_eq!(body["from_draft_id"], first.as_str());
+    assert_eq!(body["to_draft_id"], second.as_str());
+    assert_eq!(body["added"], 3);
+    assert_eq!(body["removed"], 2);
+    assert_eq!(body["unchanged"], 1);
+
+    let hunks = body["hunks"].as_array().unwrap();
+    assert_eq!(hunks.len(), 1);
+    let kinds: Vec<&str> = hunks[0]["lines"].as_array().unwrap().iter().map(|l| l["kind"].as_str().unwrap()).collect();
+    assert_eq!(kinds, vec!["unchanged", "removed", "removed", "added", "added", "added"]);
+
+    let comments: Vec<&str> = body["comments_on_changed_lines"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["comment"]["comment_text"].as_str().unwrap())
+        .collect();
+    assert_eq!(comments, vec!["Is it, though?", "Needed?"]);
+}
+
+#[spin_test]
+fn test_diff_with_empty_draft() {
+    let _store = key_value::Store::open("district9");
+    let opinion_id = create_opinion();
+    let empty = create_draft(&opinion_id, "");
+    let written = create_draft(&opinion_id, "First line.\nSecond line.");
+
+    let (status, body) = diff(&opinion_id, &written, &empty);
+    assert_eq!(status, 200, "Diff against an empty draft should succeed: {}", body);
+    assert_eq!(body["added"], 2);
+    assert_eq!(body["removed"], 0);
+}
+
+#[spin_test]
+fn test_diff_rejects_invalid_pairs() {
+    let _store = key_value::Store::open("district9");
+    let opinion_id = create_opinion();
+    let other_opinion_id = create_opinion();
+    let draft = create_draft(&opinion_id, "Text.");
+    let other_draft = create_draft(&other_opinion_id, "Other text.");
+
+    let (status, _) = diff(&opinion_id, &draft, &draft);
+    assert_eq!(status, 400, "Same draft should be rejected");
+
+    let (status, _) = diff(&opinion_id, &draft, &other_draft);
+    assert_eq!(status, 400, "Draft of another opinion should be rejected");
+
+    let (status, _) = send(Method::Get, &format!("/api/opinions/{}/drafts/{}/diff", opinion_id, draft), None);
+    assert_eq!(status, 400, "Missing against should be rejected");
+
+    let (status, _) = diff(&opinion_id, &draft, "missing-draft");
+    assert_eq!(status, 404);
+
+    assert_eq!(comment(&opinion_id, &draft, Some(5), "Out of range"), 400);
+}
@@ -0,0 +1,8 @@
+//! Judicial opinion domain tests
+//!
+//! This module contains tests for judicial opinion endpoints
+
+pub mod citation_graph;
+pub mod ranked_search;
+pub mod publication;
+pub mod draft_diff;
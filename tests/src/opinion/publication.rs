@@ -0,0 +1,215 @@
+//! Opinion publication tests
+//!
+//! Tests that an opinion is only published once the votes recorded by its
+//! panel form a majority, that publication assigns the district's next neutral
+//! citation and a slip opinion, and that a published opinion is only
+//! corrected through errata.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+/// Helper to create and file an opinion with a three-judge panel,
+/// returning its ID
+fn create_filed_opinion(district: &str) -> String {
+    create_filed_panel_opinion(&["judge-2", "judge-3"], district)
+}
+
+/// Helper to create and file an opinion by judge-1 sitting with `panel`
+fn create_filed_panel_opinion(panel: &[&str], district: &str) -> String {
+    let (status, body) = send(Method::Post, "/api/opinions", Some(json!({
+        "case_id": "case-panel",
+        "case_name": "United States v. Panel",
+        "docket_number": "24-1001",
+        "author_judge_id": "judge-1",
+        "author_judge_name": "Hon. Test Judge",
+        "opinion_type": "Majority",
+        "title": "Opinion of the Court",
+        "syllabus": "",
+        "content": "Opinion text",
+        "panel_judge_ids": panel
+    })), district);
+    assert_eq!(status, 201, "Opinion creation should succeed: {}", body);
+    let id = body["id"].as_str().unwrap().to_string();
+
+    let (status, _) = send(Method::Post, &format!("/api/opinions/{}/file", id), None, district);
+    assert_eq!(status, 200);
+    id
+}
+
+fn vote(opinion_id: &str, judge_id: &str, vote: &str, district: &str) {
+    let (status, body) = send(Method::Post, &format!("/api/opinions/{}/votes", opinion_id), Some(json!({
+        "judge_id": judge_id,
+        "judge_name": format!("Hon. {}", judge_id),
+        "vote": vote,
+        "opinion_id": null,
+        "notes": ""
+    })), district);
+    assert_eq!(status, 200, "Vote should be recorded: {}", body);
+}
+
+fn publish(opinion_id: &str, district: &str) -> (u16, Value) {
+    let body = json!({
+        "is_precedential": true,
+        "citation": {
+            "federal_reporter": "101 F.4th 1",
+            "federal_supplement": null,
+            "federal_appendix": null,
+            "lexis": null,
+            "westlaw": null,
+            "neutral_citation": null,
+            "parallel_citations": []
+        }
+    });
+    send(Method::Post, &format!("/api/opinions/{}/publish", opinion_id), Some(body), district)
+}
+
+#[spin_test]
+fn test_publish_requires_majority_of_three_judge_panel() {
+    let _store = key_value::Store::open("district9");
+
+    let id = create_filed_opinion("district9");
+    vote(&id, "judge-1", "Joins", "district9");
+
+    let (status, body) = publish(&id, "district9");
+    assert_eq!(status, 400, "One vote is not a majority: {}", body);
+
+    vote(&id, "judge-2", "Joins", "district9");
+    vote(&id, "judge-3", "Dissents", "district9");
+
+    let (status, body) = publish(&id, "district9");
+    assert_eq!(status, 200, "2 to 1 should publish: {}", body);
+    assert_eq!(body["is_published"], true);
+}
+
+#[spin_test]
+fn test_publish_rejects_tied_panel() {
+    let _store = key_value::Store::open("district9");
+
+    let id = create_filed_panel_opinion(&["judge-2", "judge-3", "judge-4"], "district9");
+    vote(&id, "judge-1", "Joins", "district9");
+    vote(&id, "judge-2", "Concurs", "district9");
+    vote(&id, "judge-3", "Dissents", "district9");
+    vote(&id, "judge-4", "Dissents", "district9");
+
+    let (status, _) = publish(&id, "district9");
+    assert_eq!(status, 400, "An evenly divided panel has no majority");
+}
+
+#[spin_test]
+fn test_publish_ignores_votes_from_judges_off_the_panel() {
+    let _store = key_value::Store::open("district9");
+
+    let id = create_filed_opinion("district9");
+    vote(&id, "judge-1", "Joins", "district9");
+    vote(&id, "judge-8", "Joins", "district9");
+    vote(&id, "judge-9", "Joins", "district9");
+
+    let body = json!({ "is_precedential": true, "panel_size": 1 });
+    let (status, body) = send(Method::Post, &format!("/api/opinions/{}/publish", id), Some(body), "district9");
+    assert_eq!(status, 400, "Only the panel's votes count, whatever size the client claims: {}", body);
+}
+
+/// Create, file and publish an opinion with a 2-1 majority
+fn published_opinion(district: &str) -> Value {
+    let id = create_filed_opinion(district);
+    vote(&id, "judge-1", "Joins", district);
+    vote(&id, "judge-2", "Joins", district);
+    let (status, body) = publish(&id, district);
+    assert_eq!(status, 200, "Opinion should publish: {}", body);
+    body
+}
+
+/// Sequence number at the end of a neutral citation
+fn sequence(opinion: &Value) -> u64 {
+    let citation = opinion["citation"]["neutral_citation"].as_str().unwrap();
+    citation.rsplit(' ').next().unwrap().parse().unwrap()
+}
+
+#[spin_test]
+fn test_publication_assigns_sequential_neutral_citations() {
+    let _store = key_value::Store::open("district9");
+
+    let first = published_opinion("district9");
+    let second = published_opinion("district9");
+
+    let year = &first["published_at"].as_str().unwrap()[..4];
+    let citation = first["citation"]["neutral_citation"].as_str().unwrap();
+    assert!(citation.starts_with(&format!("{} USDC DISTRICT9 ", year)), "{}", citation);
+    assert_eq!(first["citation"]["federal_reporter"], "101 F.4th 1", "Reporter citations are kept");
+    assert_eq!(sequence(&second), sequence(&first) + 1);
+    assert!(first["slip_opinion_document_id"].is_string());
+
+    let id = first["id"].as_str().unwrap();
+    let (status, _) = publish(id, None, "district9");
+    assert_eq!(status, 409, "An opinion is only published once");
+
+    let (status, body) = send(Method::Get, &format!("/api/opinions/{}/slip-opinion", id), None, "district9");
+    assert_eq!(status, 200, "Slip opinion should be stored: {}", body);
+}
+
+#[spin_test]
+fn test_published_opinion_is_corrected_through_errata() {
+    let _store = key_value::Store::open("district9");
+
+    let draft = create_filed_opinion("district9");
+    let (status, body) = send(Method::Post, &format!("/api/opinions/{}/errata", draft), Some(json!({
+        "corrected_text": "Corrected",
+        "reason": "Typo",
+        "filed_by": "Clerk"
+    })), "district9");
+    assert_eq!(status, 409, "Unpublished opinions are edited directly: {}", body);
+
+    let opinion = published_opinion("district9");
+    let id = opinion["id"].as_str().unwrap();
+
+    let (status, body) = send(Method::Patch, &format!("/api/opinions/{}", id), Some(json!({
+        "content": "Rewritten opinion text"
+    })), "district9");
+    assert_eq!(status, 409, "Published opinions are immutable: {}", body);
+    assert!(body.to_string().contains(&format!("/api/opinions/{}/errata", id)), "{}", body);
+
+    let (status, body) = send(Method::Post, &format!("/api/opinions/{}/errata", id), Some(json!({
+        "location": "Slip op. at 1",
+        "original_text": "Opinion text",
+        "corrected_text": "Opinion of the court text",
+        "reason": "Clerical error",
+        "filed_by": "Clerk of Court"
+    })), "district9");
+    assert_eq!(status, 201, "Erratum should be recorded: {}", body);
+    assert_eq!(body["content"], "Opinion text", "The published text is unchanged");
+    assert_eq!(body["errata"].as_array().unwrap().len(), 1);
+    assert_eq!(body["errata"][0]["corrected_text"], "Opinion of the court text");
+    assert_eq!(body["citation"], opinion["citation"]);
+}
@@ -0,0 +1,133 @@
+//! Ranked opinion search tests
+//!
+//! Tests for GET /api/opinions/search/ranked, which scores opinions by term
+//! frequency across headnotes and body and returns the best matches first.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+/// Helper to create an opinion with the given body text and return its ID
+fn create_opinion(case_name: &str, content: &str, district: &str) -> String {
+    let (status, body) = send(Method::Post, "/api/opinions", Some(json!({
+        "case_id": format!("case-{}", case_name),
+        "case_name": case_name,
+        "docket_number": "24-cv-0001",
+        "author_judge_id": "judge-1",
+        "author_judge_name": "Hon. Test Judge",
+        "opinion_type": "Majority",
+        "title": "Opinion of the Court",
+        "syllabus": "",
+        "content": content
+    })), district);
+    assert_eq!(status, 201, "Opinion creation should succeed: {}", body);
+    body["id"].as_str().unwrap().to_string()
+}
+
+fn add_headnote(opinion_id: &str, topic: &str, text: &str, district: &str) {
+    let (status, body) = send(Method::Post, &format!("/api/opinions/{}/headnotes", opinion_id), Some(json!({
+        "topic": topic,
+        "subtopic": null,
+        "text": text,
+        "key_number": null,
+        "cited_paragraphs": []
+    })), district);
+    assert_eq!(status, 200, "Adding headnote should succeed: {}", body);
+}
+
+fn ranked(query: &str, district: &str) -> Vec<(String, u64)> {
+    let (status, body) = send(Method::Get, &format!("/api/opinions/search/ranked?{}", query), None, district);
+    assert_eq!(status, 200, "Ranked search should succeed: {}", body);
+    body.as_array()
+        .unwrap()
+        .iter()
+        .map(|r| (r["opinion"]["id"].as_str().unwrap().to_string(), r["score"].as_u64().unwrap()))
+        .collect()
+}
+
+#[spin_test]
+fn test_results_are_sorted_by_score() {
+    let _store = key_value::Store::open("district9");
+
+    let passing = create_opinion("Passing v. Mention", "The entrapment defense was not raised.", "district9");
+    let central = create_opinion(
+        "Central v. Holding",
+        "Entrapment requires inducement. The entrapment defense fails where the defendant was predisposed.",
+        "district9",
+    );
+    add_headnote(&central, "Entrapment", "Predisposition defeats an entrapment defense.", "district9");
+    create_opinion("Unrelated v. Case", "Venue was proper in this district.", "district9");
+
+    let results = ranked("q=entrapment+defense", "district9");
+
+    assert_eq!(results.len(), 2, "Only matching opinions should be returned: {:?}", results);
+    assert_eq!(results[0].0, central);
+    assert_eq!(results[1].0, passing);
+    // central: 3 in the body plus 3 in the headnote counted double; passing: 2 in the body
+    assert_eq!(results[0].1, 9);
+    assert_eq!(results[1].1, 2);
+}
+
+#[spin_test]
+fn test_stopwords_do_not_score() {
+    let _store = key_value::Store::open("district9");
+
+    let id = create_opinion("Stop v. Words", "The motion to suppress is denied and the case proceeds.", "district9");
+
+    let with_stopwords = ranked("q=the+motion+and+the+case", "district9");
+    let without = ranked("q=motion+case", "district9");
+    assert_eq!(with_stopwords, without);
+    assert_eq!(with_stopwords, vec![(id, 2)]);
+
+    let (status, _) = send(Method::Get, "/api/opinions/search/ranked?q=the+and", None, "district9");
+    assert_eq!(status, 400, "A query of only stopwords should be rejected");
+}
+
+#[spin_test]
+fn test_limit_and_min_score() {
+    let _store = key_value::Store::open("district9");
+
+    let strong = create_opinion("Strong v. Match", "Forfeiture forfeiture forfeiture.", "district9");
+    let medium = create_opinion("Medium v. Match", "Forfeiture forfeiture.", "district9");
+    create_opinion("Weak v. Match", "Forfeiture.", "district9");
+
+    let top = ranked("q=forfeiture&limit=1", "district9");
+    assert_eq!(top, vec![(strong.clone(), 3)]);
+
+    let above = ranked("q=forfeiture&min_score=2", "district9");
+    assert_eq!(above, vec![(strong, 3), (medium, 2)]);
+
+    let (status, _) = send(Method::Get, "/api/opinions/search/ranked?q=forfeiture&min_score=high", None, "district9");
+    assert_eq!(status, 400);
+}
@@ -0,0 +1,110 @@
+//! Expiring order notification feed tests
+//!
+//! Tests that GET /api/orders/expiring/notifications groups orders by
+//! issuing judge, honours `within_days`, and sorts each judge's orders by
+//! soonest expiry.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// RFC 3339 timestamp `days` days and one hour from now
+fn days_from_now(days: i64) -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64 + days * 86_400 + 3_600;
+    let (day_count, rem) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+    // Civil date from days since the epoch
+    let z = day_count + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, rem / 3_600, rem % 3_600 / 60, rem % 60)
+}
+
+fn create_order(judge_id: &str, title: &str, expires_in_days: i64, district: &str) {
+    let (status, body) = send(Method::Post, "/api/orders", Some(json!({
+        "case_id": "case-expiring",
+        "judge_id": judge_id,
+        "order_type": "ProtectiveOrder",
+        "title": title,
+        "content": "It is hereby ordered...",
+        "is_sealed": false,
+        "effective_date": null,
+        "expiration_date": days_from_now(expires_in_days),
+        "related_motions": []
+    })), district);
+    assert_eq!(status, 201, "Order should be created: {}", body);
+}
+
+#[spin_test]
+fn test_notifications_grouped_by_judge_and_sorted() {
+    let _store = key_value::Store::open("district9");
+
+    create_order("judge-b", "Later order", 20, "district9");
+    create_order("judge-a", "Only order", 3, "district9");
+    create_order("judge-b", "Sooner order", 5, "district9");
+    create_order("judge-a", "Distant order", 90, "district9");
+
+    let (status, body) = send(Method::Get, "/api/orders/expiring/notifications", None, "district9");
+    assert_eq!(status, 200);
+
+    let groups = body.as_array().unwrap();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0]["judge_id"], "judge-a");
+    assert_eq!(groups[0]["orders"].as_array().unwrap().len(), 1, "Orders past 30 days are excluded by default");
+    assert_eq!(groups[0]["orders"][0]["days_until_expiry"], 3);
+
+    let titles: Vec<&str> = groups[1]["orders"].as_array().unwrap()
+        .iter()
+        .map(|o| o["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, vec!["Sooner order", "Later order"]);
+}
+
+#[spin_test]
+fn test_notifications_within_days() {
+    let _store = key_value::Store::open("district9");
+
+    create_order("judge-a", "This week", 4, "district9");
+    create_order("judge-b", "Next month", 40, "district9");
+
+    let (status, body) = send(Method::Get, "/api/orders/expiring/notifications?within_days=7", None, "district9");
+    assert_eq!(status, 200);
+    let groups = body.as_array().unwrap();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0]["orders"][0]["title"], "This week");
+
+    let (_, body) = send(Method::Get, "/api/orders/expiring/notifications?within_days=60", None, "district9");
+    assert_eq!(body.as_array().unwrap().len(), 2);
+}
@@ -0,0 +1,8 @@
+//! Order domain tests
+//!
+//! This module contains tests for judicial order and order template endpoints
+
+pub mod template_validation;
+pub mod expiring_notifications;
+pub mod signing;
+pub mod signed_pdf;
@@ -0,0 +1,151 @@
+//! Signed court order and minute entry PDF tests
+//!
+//! Tests that POST /api/pdf/signed/rule16b/:format,
+//! /api/pdf/signed/court-order/:format and
+//! /api/pdf/signed/minute-entry/:format sign with the stored signature of
+//! the request's judge_id, fall back to an inline signature, and reject a
+//! judge_id that is not a UUID.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const JUDGE_ID: &str = "2f6b8d0c-4e1a-4c3b-8d5e-9a7f6e5d4c3b";
+
+/// 1x1 transparent PNG
+const TINY_PNG: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+
+/// Helper to POST a JSON body and return status + raw response body
+fn post(path: &str, body: Value) -> (u16, Vec<u8>) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&Method::Post).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    let request_body = request.body().unwrap();
+    let stream = request_body.write().unwrap();
+    stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+    drop(stream);
+    http::types::OutgoingBody::finish(request_body, None).unwrap();
+
+    let response = spin_test_sdk::perform_request(request);
+    (response.status(), response.body().unwrap_or_default())
+}
+
+fn store_signature() {
+    let (status, _) = post("/api/signatures", json!({
+        "judge_id": JUDGE_ID,
+        "signature_base64": TINY_PNG
+    }));
+    assert_eq!(status, 200);
+}
+
+fn is_signed(pdf: &[u8]) -> bool {
+    pdf.windows(b"ELECTRONICALLY SIGNED".len()).any(|w| w == b"ELECTRONICALLY SIGNED")
+}
+
+fn court_order(judge_id: Option<&str>) -> Value {
+    json!({
+        "case_number": "2030-CR-00417",
+        "defendant_names": "John Doe",
+        "judge_name": "Hon. Patricia Johnson",
+        "order_title": "ORDER CONTINUING SENTENCING",
+        "order_content": "Sentencing is continued to July 1, 2030.",
+        "judge_id": judge_id
+    })
+}
+
+fn minute_entry(judge_id: Option<&str>, signature_base64: Option<&str>) -> Value {
+    json!({
+        "case_number": "2030-CR-00417",
+        "defendant_names": "John Doe",
+        "judge_name": "Hon. Patricia Johnson",
+        "minute_text": "Status conference held. Defendant present with counsel.",
+        "judge_id": judge_id,
+        "signature_base64": signature_base64
+    })
+}
+
+#[spin_test]
+fn test_signed_court_order_uses_stored_signature() {
+    let _store = key_value::Store::open(DISTRICT);
+    store_signature();
+
+    let (status, pdf) = post("/api/pdf/signed/court-order/pdf", court_order(Some(JUDGE_ID)));
+    assert_eq!(status, 200);
+    assert!(pdf.starts_with(b"%PDF"));
+    assert!(is_signed(&pdf), "Stored signature should be applied");
+
+    let (status, body) = post("/api/pdf/signed/court-order/json", court_order(Some(JUDGE_ID)));
+    assert_eq!(status, 200);
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["document_type"], "court-order-signed");
+}
+
+#[spin_test]
+fn test_signed_minute_entry_uses_stored_signature() {
+    let _store = key_value::Store::open(DISTRICT);
+    store_signature();
+
+    let (status, pdf) = post("/api/pdf/signed/minute-entry/pdf", minute_entry(Some(JUDGE_ID), None));
+    assert_eq!(status, 200);
+    assert!(is_signed(&pdf), "Stored signature should be applied");
+
+    let (status, pdf) = post("/api/pdf/minute-entry/pdf", minute_entry(None, None));
+    assert_eq!(status, 200);
+    assert!(!is_signed(&pdf), "Unsigned minute entry has no signature block");
+}
+
+#[spin_test]
+fn test_signed_minute_entry_falls_back_to_inline_signature() {
+    let _store = key_value::Store::open(DISTRICT);
+    let unknown_judge = "00000000-0000-4000-8000-000000000000";
+
+    let (status, pdf) = post("/api/pdf/signed/minute-entry/pdf", minute_entry(Some(unknown_judge), Some(TINY_PNG)));
+    assert_eq!(status, 200);
+    assert!(is_signed(&pdf), "Inline signature should be used when none is stored");
+}
+
+#[spin_test]
+fn test_invalid_judge_id_is_rejected() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let (status, _) = post("/api/pdf/signed/court-order/pdf", court_order(Some("not-a-uuid")));
+    assert_eq!(status, 400);
+    let (status, _) = post("/api/pdf/signed/minute-entry/pdf", minute_entry(Some("not-a-uuid"), None));
+    assert_eq!(status, 400);
+}
+
+#[spin_test]
+fn test_signed_rule16b_uses_judges_stored_signature() {
+    let _store = key_value::Store::open(DISTRICT);
+    let rule16b = |judge_id: &str| json!({
+        "case_number": "2030-CR-00417",
+        "defendant_names": "John Doe",
+        "judge_name": "Hon. Patricia Johnson",
+        "judge_id": judge_id
+    });
+
+    // No signature stored yet and none inline: the standard signature line is used
+    let (status, pdf) = post("/api/pdf/signed/rule16b/pdf", rule16b(JUDGE_ID));
+    assert_eq!(status, 200);
+    assert!(!is_signed(&pdf));
+
+    store_signature();
+    let (status, pdf) = post("/api/pdf/signed/rule16b/pdf", rule16b(JUDGE_ID));
+    assert_eq!(status, 200);
+    assert!(is_signed(&pdf), "The judge's stored signature should be applied");
+
+    let (status, pdf) = post("/api/pdf/signed/rule16b/pdf", rule16b("00000000-0000-4000-8000-000000000000"));
+    assert_eq!(status, 200);
+    assert!(!is_signed(&pdf), "Another judge's signature must not be used");
+
+    let (status, body) = post("/api/pdf/signed/rule16b/json", rule16b("123e4567"));
+    assert_eq!(status, 400);
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert!(body["error"]["message"].as_str().unwrap().contains("judge_id"));
+}
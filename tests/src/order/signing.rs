@@ -0,0 +1,101 @@
+//! Order signing authorization tests
+//!
+//! Tests that POST /api/orders/:order_id/sign only accepts a signature from
+//! the order's assigned judge or one of its authorized signers.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const ASSIGNED_JUDGE: &str = "judge-hellerstein";
+const DUTY_JUDGE: &str = "judge-duty-part-one";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_order(authorized_signers: Value, district: &str) -> String {
+    let (status, body) = send(Method::Post, "/api/orders", Some(json!({
+        "case_id": "CASE-2025-0042",
+        "judge_id": ASSIGNED_JUDGE,
+        "order_type": "SchedulingOrder",
+        "title": "Scheduling Order",
+        "content": "Trial is set for June 2, 2025.",
+        "is_sealed": false,
+        "effective_date": null,
+        "expiration_date": null,
+        "related_motions": [],
+        "authorized_signers": authorized_signers
+    })), district);
+    assert_eq!(status, 201, "Order should be created: {:?}", body);
+    body["id"].as_str().unwrap().to_string()
+}
+
+fn sign(order_id: &str, judge_id: &str, district: &str) -> (u16, Value) {
+    send(Method::Post, &format!("/api/orders/{}/sign", order_id), Some(json!({
+        "judge_id": judge_id,
+        "judge_name": "Hon. Signing Judge",
+        "certificate_id": "CERT-001"
+    })), district)
+}
+
+#[spin_test]
+fn test_assigned_judge_can_sign() {
+    let _store = key_value::Store::open("district9");
+    let order_id = create_order(json!([]), "district9");
+
+    let (status, body) = sign(&order_id, ASSIGNED_JUDGE, "district9");
+    assert_eq!(status, 200, "Assigned judge should sign: {:?}", body);
+    assert_eq!(body["status"], "Signed");
+    assert_eq!(body["signature"]["signer_id"], ASSIGNED_JUDGE);
+}
+
+#[spin_test]
+fn test_wrong_judge_is_forbidden() {
+    let _store = key_value::Store::open("district9");
+    let order_id = create_order(json!([]), "district9");
+
+    let (status, body) = sign(&order_id, "judge-unrelated", "district9");
+    assert_eq!(status, 403, "Unassigned judge should be refused: {:?}", body);
+
+    let (_, order) = send(Method::Get, &format!("/api/orders/{}", order_id), None, "district9");
+    assert_eq!(order["signature"], json!(null), "A refused signature must not be applied");
+}
+
+#[spin_test]
+fn test_authorized_delegate_can_sign() {
+    let _store = key_value::Store::open("district9");
+    let order_id = create_order(json!([]), "district9");
+
+    let (status, _) = sign(&order_id, DUTY_JUDGE, "district9");
+    assert_eq!(status, 403, "Duty judge is not yet a delegate");
+
+    let (status, _) = send(Method::Patch, &format!("/api/orders/{}", order_id), Some(json!({
+        "authorized_signers": [DUTY_JUDGE]
+    })), "district9");
+    assert_eq!(status, 200);
+
+    let (status, body) = sign(&order_id, DUTY_JUDGE, "district9");
+    assert_eq!(status, 200, "Delegate should sign: {:?}", body);
+    assert_eq!(body["signature"]["signer_id"], DUTY_JUDGE);
+}
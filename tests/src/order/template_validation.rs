@@ -0,0 +1,156 @@
+//! Order template validation tests
+//!
+//! Tests that template generation validates supplied values against the
+//! declared variables, that `validate_only` reports without generating,
+//! that templates with undeclared placeholders are rejected, and that the
+//! preview endpoint renders sample values.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn variable(name: &str, variable_type: Value, required: bool, default_value: Option<&str>) -> Value {
+    json!({
+        "name": name,
+        "description": "",
+        "variable_type": variable_type,
+        "required": required,
+        "default_value": default_value,
+        "validation_rules": []
+    })
+}
+
+fn template_body(content: &str) -> Value {
+    json!({
+        "name": "Response Order",
+        "order_type": "SchedulingOrder",
+        "description": "Orders a party to respond",
+        "template_content": content,
+        "variables": [
+            variable("party_name", json!("Text"), true, None),
+            variable("days", json!("Number"), true, Some("14")),
+            variable("deadline", json!("Date"), true, None)
+        ],
+        "required_attachments": [],
+        "default_service_method": "ElectronicFiling"
+    })
+}
+
+const CONTENT: &str = "{{party_name}} shall respond within {{days}} days, by {{deadline}}.";
+
+fn create_template(district: &str) -> String {
+    let (status, body) = send(Method::Post, "/api/templates/orders", Some(template_body(CONTENT)), district);
+    assert_eq!(status, 201, "Template should be created: {:?}", body);
+    body["id"].as_str().unwrap().to_string()
+}
+
+#[spin_test]
+fn test_generate_content_rejects_missing_and_mistyped_values() {
+    let _store = key_value::Store::open("district9");
+    let id = create_template("district9");
+    let path = format!("/api/templates/{}/generate-content", id);
+
+    let (status, body) = send(Method::Post, &path, Some(json!({
+        "days": "fourteen",
+        "deadline": "2030-01-15"
+    })), "district9");
+    assert_eq!(status, 400, "Missing required values should be rejected: {:?}", body);
+    assert!(body["error"]["message"].as_str().unwrap().contains("party_name"), "{:?}", body);
+
+    let (status, body) = send(Method::Post, &path, Some(json!({
+        "party_name": "Defendant Smith",
+        "days": "fourteen",
+        "deadline": "2030-01-15"
+    })), "district9");
+    assert_eq!(status, 422, "Mistyped values should be rejected: {:?}", body);
+    assert_eq!(body["error"]["code"], "VALIDATION_FAILED");
+    assert_eq!(body["error"]["details"]["invalid"][0]["name"], "days");
+    assert_eq!(body["error"]["details"]["invalid"][0]["expected"], "number");
+
+    // Defaults fill in omitted values
+    let (status, body) = send(Method::Post, &path, Some(json!({
+        "party_name": "Defendant Smith",
+        "deadline": "2030-01-15"
+    })), "district9");
+    assert_eq!(status, 200, "Valid values should render: {:?}", body);
+    assert_eq!(body, "Defendant Smith shall respond within 14 days, by 2030-01-15.");
+}
+
+#[spin_test]
+fn test_validate_only_reports_without_generating() {
+    let _store = key_value::Store::open("district9");
+
+    let content = format!("{} cc: {{{{copies?}}}}", CONTENT);
+    let (status, body) = send(Method::Post, "/api/templates/orders", Some(template_body(&content)), "district9");
+    assert_eq!(status, 201, "Inline optional placeholders should be accepted: {:?}", body);
+    let path = format!("/api/templates/{}/generate-content", body["id"].as_str().unwrap());
+
+    let (status, body) = send(Method::Post, &format!("{}?validate_only=true", path), Some(json!({
+        "deadline": "2030-01-15"
+    })), "district9");
+    assert_eq!(status, 200, "validate_only should report: {:?}", body);
+    assert_eq!(body["valid"], false);
+    assert_eq!(body["missing"], json!(["party_name"]));
+    assert_eq!(body["unfilled_optional"], json!(["copies"]));
+
+    let (status, body) = send(Method::Post, &path, Some(json!({
+        "party_name": "Defendant Smith",
+        "deadline": "2030-01-15"
+    })), "district9");
+    assert_eq!(status, 200);
+    assert_eq!(body, "Defendant Smith shall respond within 14 days, by 2030-01-15. cc: ");
+}
+
+#[spin_test]
+fn test_templates_with_undeclared_placeholders_are_rejected() {
+    let _store = key_value::Store::open("district9");
+
+    let content = format!("{} Signed {{{{judge_name}}}}.", CONTENT);
+    let (status, body) = send(Method::Post, "/api/templates/orders", Some(template_body(&content)), "district9");
+    assert_eq!(status, 400, "Undeclared placeholders should be rejected: {:?}", body);
+
+    let id = create_template("district9");
+    let (_, mut template) = send(Method::Get, &format!("/api/templates/orders/{}", id), None, "district9");
+    template["template_content"] = json!(content);
+    let (status, _) = send(Method::Put, &format!("/api/templates/orders/{}", id), Some(template), "district9");
+    assert_eq!(status, 400, "Updates should run the same check");
+}
+
+#[spin_test]
+fn test_preview_renders_sample_values() {
+    let _store = key_value::Store::open("district9");
+    let id = create_template("district9");
+
+    let (status, body) = send(Method::Post, &format!("/api/templates/orders/{}/preview", id), None, "district9");
+    assert_eq!(status, 200, "Preview should render: {:?}", body);
+    assert_eq!(body["content"], "[party_name] shall respond within 14 days, by January 15, 2025.");
+    assert_eq!(body["undeclared_placeholders"], json!([]));
+    assert_eq!(body["sample_values"]["days"], "14");
+
+    let (status, _) = send(Method::Post, "/api/templates/orders/missing/preview", None, "district9");
+    assert_eq!(status, 404);
+}
@@ -0,0 +1,161 @@
+//! Global search integration tests
+//!
+//! Tests for GET /api/search, which fans a query out to cases, attorneys,
+//! judges and docket entries and returns grouped, typed results.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Seed a case, an attorney, a judge and a docket entry that all mention "Halvorsen"
+fn seed_shared_term(district: &str) -> (String, String, String) {
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. Halvorsen",
+        "description": "Wire fraud scheme involving shell companies",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), district);
+    assert_eq!(status, 201, "case: {:?}", case);
+    let case_id = case["id"].as_str().unwrap().to_string();
+
+    let (status, attorney) = send(Method::Post, "/api/attorneys", Some(json!({
+        "bar_number": "NY998877",
+        "first_name": "Ingrid",
+        "last_name": "Halvorsen",
+        "email": "ingrid@halvorsen-law.com",
+        "phone": "555-0199",
+        "address": {
+            "street1": "1 Centre St",
+            "city": "New York",
+            "state": "NY",
+            "zip_code": "10007",
+            "country": "US"
+        }
+    })), district);
+    assert_eq!(status, 201, "attorney: {:?}", attorney);
+
+    let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+        "name": "Hon. Erik Halvorsen",
+        "title": "district_judge",
+        "district": district,
+        "courtroom": "9D"
+    })), district);
+    assert_eq!(status, 201, "judge: {:?}", judge);
+    let judge_id = judge["id"].as_str().unwrap().to_string();
+
+    let (status, _) = send(Method::Post, "/api/docket/entries", Some(json!({
+        "case_id": case_id,
+        "entry_type": "motion",
+        "description": "Motion to suppress statements of defendant Halvorsen",
+        "filed_by": "Defense Counsel",
+        "is_sealed": false,
+        "is_ex_parte": false,
+        "page_count": null,
+        "service_list": []
+    })), district);
+    assert_eq!(status, 201);
+
+    (case_id, attorney["id"].as_str().unwrap_or_default().to_string(), judge_id)
+}
+
+fn group<'a>(body: &'a Value, group_type: &str) -> &'a Value {
+    body["groups"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|g| g["type"] == group_type)
+        .unwrap_or_else(|| panic!("missing {} group in {:?}", group_type, body))
+}
+
+#[spin_test]
+fn test_shared_term_surfaces_hits_from_each_domain() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+    let (case_id, _, judge_id) = seed_shared_term(district);
+
+    let (status, body) = send(Method::Get, "/api/search?q=halvorsen", None, district);
+    assert_eq!(status, 200, "search: {:?}", body);
+
+    for (group_type, expected_url) in [
+        ("cases", Some(format!("/api/cases/{}", case_id))),
+        ("attorneys", None),
+        ("judges", Some(format!("/api/judges/{}", judge_id))),
+        ("docket", None),
+    ] {
+        let hits = group(&body, group_type)["results"].as_array().unwrap();
+        assert!(!hits.is_empty(), "expected {} hits", group_type);
+        assert!(hits.iter().all(|h| h["type"] == group_type), "{} hits mistyped: {:?}", group_type, hits);
+        if let Some(url) = expected_url {
+            assert!(hits.iter().any(|h| h["url"] == url.as_str()), "{} hits: {:?}", group_type, hits);
+        }
+    }
+
+    assert_eq!(body["returned"], 4);
+}
+
+#[spin_test]
+fn test_types_filter_and_limit() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+    seed_shared_term(district);
+
+    let (status, body) = send(Method::Get, "/api/search?q=Halvorsen&types=judges,docket", None, district);
+    assert_eq!(status, 200);
+    let groups: Vec<&str> = body["groups"].as_array().unwrap().iter().map(|g| g["type"].as_str().unwrap()).collect();
+    assert_eq!(groups, vec!["judges", "docket"]);
+
+    let (status, repeated) = send(Method::Get, "/api/search?q=Halvorsen&types=judges&types=docket", None, district);
+    assert_eq!(status, 200);
+    assert_eq!(repeated["groups"], body["groups"], "repeated keys select the same types");
+
+    let (_, limited) = send(Method::Get, "/api/search?q=Halvorsen&limit=2", None, district);
+    assert_eq!(limited["returned"], 2);
+    assert_eq!(limited["total"], 4);
+}
+
+#[spin_test]
+fn test_search_is_scoped_to_district() {
+    let _store = key_value::Store::open("district9");
+    let _other = key_value::Store::open("district12");
+    seed_shared_term("district9");
+
+    let (status, body) = send(Method::Get, "/api/search?q=Halvorsen", None, "district12");
+    assert_eq!(status, 200);
+    assert_eq!(body["returned"], 0);
+}
+
+#[spin_test]
+fn test_unknown_type_lists_supported_types() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, body) = send(Method::Get, "/api/search?q=anything&types=cases,orders", None, "district9");
+    assert_eq!(status, 400);
+    let details = body["error"]["message"].as_str().unwrap();
+    assert!(details.contains("orders"));
+    assert!(details.contains("cases, attorneys, judges, docket"));
+}
@@ -0,0 +1,7 @@
+//! Search domain tests
+//!
+//! This module contains tests for the global search endpoint and the
+//! pagination envelope shared by the search endpoints
+
+pub mod global_search;
+pub mod pagination_envelope;
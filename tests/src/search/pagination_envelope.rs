@@ -0,0 +1,120 @@
+//! Pagination envelope tests
+//!
+//! Tests that the docket, calendar, judge and deadline search endpoints
+//! return `{items, total, offset, limit}` and still repeat the items under
+//! their deprecated list field.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const CASE_ID: &str = "0f4e8d2c-6b1a-4e9f-8d7c-3b2a1f0e9d8c";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Seed two of each record the search endpoints return
+fn seed() {
+    for n in 1..=2 {
+        let (status, judge) = send(Method::Post, "/api/judges", Some(json!({
+            "name": format!("Hon. Envelope Judge {}", n),
+            "title": "district_judge",
+            "district": DISTRICT,
+            "courtroom": "9D"
+        })));
+        assert_eq!(status, 201, "judge: {:?}", judge);
+        let judge_id = judge["id"].as_str().unwrap();
+
+        let (status, entry) = send(Method::Post, "/api/docket/entries", Some(json!({
+            "case_id": CASE_ID,
+            "entry_type": "motion",
+            "description": format!("Envelope motion {}", n),
+            "filed_by": "Defense Counsel",
+            "is_sealed": false,
+            "is_ex_parte": false,
+            "page_count": null,
+            "service_list": []
+        })));
+        assert_eq!(status, 201, "docket entry: {:?}", entry);
+
+        let (status, event) = send(Method::Post, "/api/calendar/events", Some(json!({
+            "case_id": CASE_ID,
+            "judge_id": judge_id,
+            "event_type": "status_conference",
+            "scheduled_date": format!("2099-09-1{}T14:00:00Z", n),
+            "duration_minutes": 30,
+            "courtroom": "9A",
+            "description": "Status conference",
+            "participants": [],
+            "is_public": true
+        })));
+        assert_eq!(status, 201, "calendar event: {:?}", event);
+
+        let (status, deadline) = send(Method::Post, "/api/deadlines", Some(json!({
+            "case_id": CASE_ID,
+            "deadline_type": "answer",
+            "due_date": format!("2099-01-2{}T00:00:00Z", n),
+            "triggering_event": "complaint_filed",
+            "triggering_date": "2099-01-02T00:00:00Z",
+            "applicable_rule": "FRCP 12(a)(1)(A)",
+            "description": "Answer to complaint",
+            "responsible_party": "Defendant",
+            "is_jurisdictional": false,
+            "is_extendable": true
+        })));
+        assert_eq!(status, 201, "deadline: {:?}", deadline);
+    }
+}
+
+#[spin_test]
+fn test_search_endpoints_share_the_page_envelope() {
+    let _store = key_value::Store::open(DISTRICT);
+    seed();
+
+    // Scheduling an event also dockets a minute entry, so count only the motions
+    for (path, legacy_field) in [
+        ("/api/docket/search?entry_type=motion&", "entries"),
+        ("/api/calendar/search?", "events"),
+        ("/api/judges/search?", "judges"),
+        ("/api/deadlines/search?", "deadlines"),
+    ] {
+        let (status, body) = send(Method::Get, &format!("{}offset=1&limit=1", path), None);
+        assert_eq!(status, 200, "{}: {:?}", path, body);
+        assert_eq!(body["items"].as_array().unwrap().len(), 1, "{} should return one item", path);
+        assert_eq!(body["total"], 2, "{} total counts every match", path);
+        assert_eq!(body["offset"], 1, "{} echoes offset", path);
+        assert_eq!(body["limit"], 1, "{} echoes limit", path);
+        assert_eq!(body[legacy_field], body["items"], "{} keeps `{}` during deprecation", path, legacy_field);
+    }
+}
+
+#[spin_test]
+fn test_page_envelope_defaults() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    let (status, body) = send(Method::Get, "/api/deadlines/search", None);
+    assert_eq!(status, 200);
+    assert_eq!(body, json!({"items": [], "total": 0, "offset": 0, "limit": 50, "deadlines": []}));
+}
@@ -0,0 +1,155 @@
+//! Adjustment statistics tests
+//!
+//! Tests that GET /api/sentencing/statistics/adjustments returns departure
+//! and variance statistics in one payload, with their net effect on
+//! sentence length, limited to the `from`/`to` date range.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+/// Creates a sentencing dated `sentencing_date` with a 30-37 month range,
+/// applying the given departure, variance and custody term
+fn create_sentenced(sentencing_date: &str, departure: Option<Value>, variance: Option<Value>, custody_months: i32, district: &str) {
+    let (status, mut sentencing) = send(Method::Post, "/api/sentencing", Some(json!({
+        "case_id": format!("case-{}", sentencing_date),
+        "defendant_id": "defendant-adjustments",
+        "judge_id": "judge-adjustments"
+    })), district);
+    assert_eq!(status, 201);
+    let id = sentencing["id"].as_str().unwrap().to_string();
+
+    sentencing["sentencing_date"] = json!(sentencing_date);
+    sentencing["guidelines_range"]["minimum_months"] = json!(30);
+    sentencing["guidelines_range"]["maximum_months"] = json!(37);
+    sentencing["departures"] = json!(departure.into_iter().collect::<Vec<_>>());
+    sentencing["variance"] = variance.unwrap_or(json!(null));
+    sentencing["imposed_sentence"] = json!({
+        "custody_months": custody_months,
+        "probation_months": 0,
+        "home_confinement_months": 0,
+        "intermittent_confinement": false,
+        "fine_amount": null,
+        "restitution_amount": null,
+        "forfeiture_amount": null,
+        "special_assessment": 100.0,
+        "concurrent_consecutive": "Concurrent",
+        "credit_time_served": 0,
+        "voluntary_surrender_date": null
+    });
+
+    let (status, body) = send(Method::Put, &format!("/api/sentencing/{}", id), Some(sentencing), district);
+    assert_eq!(status, 200, "{}", body);
+}
+
+fn departure(direction: &str, levels: i32) -> Value {
+    json!({
+        "departure_type": "SubstantialAssistance",
+        "direction": direction,
+        "levels": levels,
+        "guideline_section": "5K1.1",
+        "reason": "Cooperation",
+        "government_motion": true
+    })
+}
+
+fn variance(direction: &str, from_months: i32, to_months: i32) -> Value {
+    json!({
+        "variance_type": "Section3553a",
+        "direction": direction,
+        "from_months": from_months,
+        "to_months": to_months,
+        "percent_change": 0.0,
+        "factors_considered": ["defendant_history"],
+        "rationale": "History and characteristics of the defendant"
+    })
+}
+
+#[spin_test]
+fn test_adjustments_combine_departures_variances_and_net_effect() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    create_sentenced("2025-03-03T00:00:00Z", Some(departure("Downward", 4)), None, 24, district);
+    create_sentenced("2025-03-10T00:00:00Z", None, Some(variance("Above", 37, 46)), 46, district);
+    create_sentenced("2025-03-17T00:00:00Z", Some(departure("Upward", 1)), None, 34, district);
+    // Outside the range
+    create_sentenced("2024-06-01T00:00:00Z", None, Some(variance("Below", 30, 12)), 12, district);
+
+    let (status, body) = send(
+        Method::Get,
+        "/api/sentencing/statistics/adjustments?from=2025-03-01T00:00:00Z&to=2025-03-31T23:59:59Z",
+        None,
+        district,
+    );
+    assert_eq!(status, 200, "{}", body);
+
+    assert_eq!(body["departures"]["total_cases"], 3);
+    assert_eq!(body["departures"]["downward_departures"], 1);
+    assert_eq!(body["departures"]["upward_departures"], 1);
+    assert_eq!(body["variances"]["total_cases"], 3);
+    assert_eq!(body["variances"]["upward_variances"], 1);
+    assert_eq!(body["variances"]["downward_variances"], 0);
+
+    let net = &body["net_effect"];
+    assert_eq!(net["net_departure_levels"], -3);
+    assert_eq!(net["net_variance_months"], 9);
+    assert_eq!(net["imposed_sentences"], 3);
+    // 6 months below the minimum, 9 above the maximum, one within range
+    assert_eq!(net["net_months_from_range"], 3);
+    assert_eq!(net["average_months_from_range"], 1.0);
+
+    let (status, all) = send(Method::Get, "/api/sentencing/statistics/adjustments?from=2024-01-01&to=2025-12-31", None, district);
+    assert_eq!(status, 200);
+    assert_eq!(all["variances"]["total_cases"], 4);
+    assert_eq!(all["net_effect"]["net_variance_months"], -9);
+}
+
+#[spin_test]
+fn test_adjustments_reject_invalid_range() {
+    let district = "district9";
+    let _store = key_value::Store::open(district);
+
+    let (status, body) = send(Method::Get, "/api/sentencing/statistics/adjustments?from=last-month", None, district);
+    assert_eq!(status, 400);
+    assert_eq!(body["error"]["code"], "VALIDATION_FAILED");
+
+    let (status, _) = send(
+        Method::Get,
+        "/api/sentencing/statistics/adjustments?from=2025-04-01T00:00:00Z&to=2025-03-01T00:00:00Z",
+        None,
+        district,
+    );
+    assert_eq!(status, 400);
+}
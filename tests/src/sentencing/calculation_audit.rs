@@ -0,0 +1,134 @@
+//! Guidelines calculation audit tests
+//!
+//! Tests that GET /api/sentencing/:id/calculation-audit reproduces the stored
+//! guidelines range and lists every step of the derivation in order.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+/// Creates a sentencing with a base offense level, one specific offense
+/// characteristic, acceptance of responsibility and a mandatory minimum,
+/// then runs the stored calculations. Returns the stored record.
+fn create_calculated_sentencing(district: &str) -> Value {
+    let (status, mut sentencing) = send(Method::Post, "/api/sentencing", Some(json!({
+        "case_id": "case-audit",
+        "defendant_id": "defendant-audit",
+        "judge_id": "judge-audit"
+    })), district);
+    assert_eq!(status, 201);
+    let id = sentencing["id"].as_str().unwrap().to_string();
+
+    sentencing["offense_level"]["base_offense_level"] = json!(20);
+    sentencing["offense_level"]["specific_offense_characteristics"] = json!([{
+        "guideline_section": "2D1.1(b)(1)",
+        "description": "Dangerous weapon possessed",
+        "adjustment": 2,
+        "rationale": "Firearm recovered at the stash house"
+    }]);
+    sentencing["offense_level"]["acceptance_of_responsibility"] = json!(-3);
+    sentencing["guidelines_range"]["mandatory_minimum"] = json!(60);
+    let (status, _) = send(Method::Put, &format!("/api/sentencing/{}", id), Some(sentencing), district);
+    assert_eq!(status, 200);
+
+    let (status, _) = send(Method::Post, &format!("/api/sentencing/{}/prior-sentence", id), Some(json!({
+        "offense": "Possession with intent to distribute",
+        "sentence_date": "2015-03-01T00:00:00Z",
+        "sentence_length_months": 18,
+        "points_assigned": 3,
+        "violence_involved": false,
+        "controlled_substance": true
+    })), district);
+    assert_eq!(status, 200);
+
+    let (status, _) = send(Method::Post, &format!("/api/sentencing/{}/calculate-offense-level", id), None, district);
+    assert_eq!(status, 200);
+    let (status, stored) = send(Method::Post, &format!("/api/sentencing/{}/lookup-guidelines-range", id), None, district);
+    assert_eq!(status, 200);
+    stored
+}
+
+#[spin_test]
+fn test_audit_matches_stored_guidelines_range() {
+    let _store = key_value::Store::open("district9");
+
+    let stored = create_calculated_sentencing("district9");
+    let id = stored["id"].as_str().unwrap();
+
+    let (status, audit) = send(Method::Get, &format!("/api/sentencing/{}/calculation-audit", id), None, "district9");
+    assert_eq!(status, 200, "Audit should be returned: {}", audit);
+
+    assert_eq!(audit["final_offense_level"], stored["offense_level"]["final_offense_level"]);
+    assert_eq!(audit["final_offense_level"], 19);
+    assert_eq!(audit["criminal_history_category"], stored["criminal_history"]["category"]);
+    assert_eq!(audit["guidelines_range"], stored["guidelines_range"]);
+    assert_eq!(audit["guidelines_range"]["minimum_months"], 60, "Mandatory minimum should clamp the range");
+    assert_eq!(audit["matches_stored_range"], true);
+}
+
+#[spin_test]
+fn test_audit_lists_each_step_in_order() {
+    let _store = key_value::Store::open("district9");
+
+    let stored = create_calculated_sentencing("district9");
+    let id = stored["id"].as_str().unwrap();
+
+    let (_, audit) = send(Method::Get, &format!("/api/sentencing/{}/calculation-audit", id), None, "district9");
+    let steps = audit["steps"].as_array().unwrap();
+    let stages: Vec<&str> = steps.iter().map(|s| s["stage"].as_str().unwrap()).collect();
+    assert_eq!(stages, vec![
+        "base_offense_level",
+        "offense_characteristic",
+        "adjustment",
+        "criminal_history_points",
+        "criminal_history_points",
+        "criminal_history_points",
+        "criminal_history_category",
+        "table_lookup",
+        "statutory_clamp",
+    ]);
+
+    for (i, step) in steps.iter().enumerate() {
+        assert_eq!(step["step"], i + 1);
+    }
+    assert_eq!(steps[1]["amount"], 2);
+    assert!(steps[1]["basis"].as_str().unwrap().contains("2D1.1(b)(1)"));
+    assert_eq!(steps[2]["amount"], -3);
+    assert_eq!(steps[2]["result"], "level 19");
+    assert_eq!(steps[6]["result"], "category II");
+    assert_eq!(steps[8]["result"], "60-60 months");
+
+    let (status, _) = send(Method::Get, "/api/sentencing/missing/calculation-audit", None, "district9");
+    assert_eq!(status, 404);
+}
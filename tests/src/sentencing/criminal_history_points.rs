@@ -0,0 +1,117 @@
+//! Criminal history points breakdown tests
+//!
+//! Tests that GET /api/sentencing/:id/criminal-history-points itemizes each
+//! prior sentence under USSG §4A1.1(a)-(c) and that `summary_only=true`
+//! returns just the total.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+/// Creates a sentencing with prior sentences of the given lengths in months,
+/// recording the points the guidelines assign to each
+fn create_with_priors(lengths: &[(i32, i32)], district: &str) -> String {
+    let (status, sentencing) = send(Method::Post, "/api/sentencing", Some(json!({
+        "case_id": "case-history",
+        "defendant_id": "defendant-history",
+        "judge_id": "judge-history"
+    })), district);
+    assert_eq!(status, 201);
+    let id = sentencing["id"].as_str().unwrap().to_string();
+
+    for (months, points) in lengths {
+        let (status, _) = send(Method::Post, &format!("/api/sentencing/{}/prior-sentence", id), Some(json!({
+            "offense": format!("Prior offense, {} months", months),
+            "sentence_date": "2016-05-01T00:00:00Z",
+            "sentence_length_months": months,
+            "points_assigned": points,
+            "violence_involved": false,
+            "controlled_substance": false
+        })), district);
+        assert_eq!(status, 200);
+    }
+    id
+}
+
+#[spin_test]
+fn test_priors_at_point_thresholds_are_itemized() {
+    let _store = key_value::Store::open("district9");
+    // Just over and at one year and one month, at sixty days, and under it
+    let id = create_with_priors(&[(14, 3), (13, 2), (2, 2), (1, 1)], "district9");
+
+    let (status, body) = send(Method::Get, &format!("/api/sentencing/{}/criminal-history-points", id), None, "district9");
+    assert_eq!(status, 200, "{}", body);
+
+    let priors = body["prior_sentences"].as_array().unwrap();
+    let scored: Vec<(i64, &str)> = priors
+        .iter()
+        .map(|p| (p["points"].as_i64().unwrap(), p["rule"].as_str().unwrap()))
+        .collect();
+    assert_eq!(scored, vec![
+        (3, "USSG §4A1.1(a)"),
+        (2, "USSG §4A1.1(b)"),
+        (2, "USSG §4A1.1(b)"),
+        (1, "USSG §4A1.1(c)"),
+    ]);
+    assert_eq!(priors[0]["points_assigned"], 3);
+    assert_eq!(body["prior_sentence_points"], 8);
+    assert_eq!(body["total_points"], 8);
+    assert_eq!(body["category"], "IV");
+    assert_eq!(body["recorded_points"], 8);
+    assert_eq!(body["matches_recorded"], true);
+}
+
+#[spin_test]
+fn test_summary_only_returns_total() {
+    let _store = key_value::Store::open("district9");
+    let id = create_with_priors(&[(24, 3), (6, 2)], "district9");
+
+    let (status, body) = send(
+        Method::Get,
+        &format!("/api/sentencing/{}/criminal-history-points?summary_only=true", id),
+        None,
+        "district9",
+    );
+    assert_eq!(status, 200);
+    assert_eq!(body, json!(5));
+}
+
+#[spin_test]
+fn test_missing_sentencing_is_not_found() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, body) = send(Method::Get, "/api/sentencing/missing/criminal-history-points", None, "district9");
+    assert_eq!(status, 404);
+    assert_eq!(body["error"]["code"], "NOT_FOUND");
+}
@@ -0,0 +1,193 @@
+//! Guideline compliance statistics tests
+//!
+//! Tests that sentencing statistics report how often sentences fell within,
+//! above and below the guidelines range and the median percentage outside
+//! it, broken down by primary offense type, that a judge can be compared
+//! with the district, and that `fiscal_year` selects the federal fiscal year.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+/// Creates a sentencing by `judge_id` dated `sentencing_date` with a 40-50
+/// month range and the given custody term
+fn create_sentenced(judge_id: &str, offense: &str, sentencing_date: &str, custody_months: i32) {
+    let (status, mut sentencing) = send(Method::Post, "/api/sentencing", Some(json!({
+        "case_id": format!("case-{}-{}", judge_id, sentencing_date),
+        "defendant_id": "defendant-compliance",
+        "judge_id": judge_id,
+        "primary_offense_type": offense
+    })));
+    assert_eq!(status, 201, "{}", sentencing);
+    assert_eq!(sentencing["primary_offense_type"], offense);
+    let id = sentencing["id"].as_str().unwrap().to_string();
+
+    sentencing["sentencing_date"] = json!(sentencing_date);
+    sentencing["guidelines_range"]["minimum_months"] = json!(40);
+    sentencing["guidelines_range"]["maximum_months"] = json!(50);
+    sentencing["imposed_sentence"] = json!({
+        "custody_months": custody_months,
+        "probation_months": 0,
+        "home_confinement_months": 0,
+        "intermittent_confinement": false,
+        "fine_amount": null,
+        "restitution_amount": null,
+        "forfeiture_amount": null,
+        "special_assessment": 100.0,
+        "concurrent_consecutive": "Concurrent",
+        "credit_time_served": 0,
+        "voluntary_surrender_date": null
+    });
+
+    let (status, body) = send(Method::Put, &format!("/api/sentencing/{}", id), Some(sentencing));
+    assert_eq!(status, 200, "{}", body);
+}
+
+/// Twenty sentencings: eighteen in fiscal year 2025 and one either side of it
+///
+/// Judge A: four within the range, three below (25%, 50% and 10% under the
+/// minimum) and three drug trafficking sentences above (20%, 10% and 50%
+/// over the maximum). Judge B: six within and two below (50% and 20%).
+fn seed_sentencings() {
+    for (day, custody) in [(1, 45), (2, 45), (3, 45), (4, 45), (5, 30), (6, 20), (7, 36)] {
+        create_sentenced("judge-a", "fraud", &format!("2025-01-{:02}T12:00:00Z", day), custody);
+    }
+    for (day, custody) in [(8, 60), (9, 55), (10, 75)] {
+        create_sentenced("judge-a", "drug_trafficking", &format!("2025-01-{:02}T12:00:00Z", day), custody);
+    }
+    for (day, custody) in [(1, 40), (2, 50), (3, 42), (4, 44), (5, 46), (6, 48), (7, 20), (8, 32)] {
+        create_sentenced("judge-b", "fraud", &format!("2025-06-{:02}T12:00:00Z", day), custody);
+    }
+    // Fiscal years 2024 and 2026
+    create_sentenced("judge-a", "fraud", "2024-09-30T23:00:00Z", 10);
+    create_sentenced("judge-a", "fraud", "2025-10-01T01:00:00Z", 10);
+}
+
+fn assert_close(actual: &Value, expected: f64) {
+    let actual = actual.as_f64().unwrap_or_else(|| panic!("{} is not a number", actual));
+    assert!((actual - expected).abs() < 0.01, "expected {}, got {}", expected, actual);
+}
+
+#[spin_test]
+fn test_judge_comparison_reports_deltas_against_district() {
+    let _store = key_value::Store::open(DISTRICT);
+    seed_sentencings();
+
+    let (status, body) = send(Method::Get, "/api/sentencing/statistics/judge/judge-a/comparison?fiscal_year=2025", None);
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body["judge_id"], "judge-a");
+    assert_eq!(body["from"], "2024-10-01T00:00:00Z");
+
+    let judge = &body["judge"];
+    assert_eq!(judge["sentenced"], 10);
+    assert_close(&judge["within_range_percent"], 40.0);
+    assert_close(&judge["below_range_percent"], 30.0);
+    assert_close(&judge["above_range_percent"], 30.0);
+    assert_close(&judge["median_percent_below_range"], 25.0);
+    assert_close(&judge["median_percent_above_range"], 20.0);
+    assert_close(&judge["median_custody_months"], 45.0);
+
+    let district = &body["district"];
+    assert_eq!(district["sentenced"], 18);
+    assert_eq!(district["within_range"], 10);
+    assert_eq!(district["below_range"], 5);
+    assert_eq!(district["above_range"], 3);
+    assert_close(&district["within_range_percent"], 55.56);
+    assert_close(&district["median_percent_below_range"], 25.0);
+
+    let deltas = &body["deltas"];
+    assert_close(&deltas["within_range_percent"], -15.56);
+    assert_close(&deltas["below_range_percent"], 2.22);
+    assert_close(&deltas["above_range_percent"], 13.33);
+    assert_close(&deltas["median_percent_below_range"], 0.0);
+    assert_close(&deltas["median_custody_months"], 0.0);
+
+    let (status, body) = send(Method::Get, "/api/sentencing/statistics/judge/judge-b/comparison?fiscal_year=2025", None);
+    assert_eq!(status, 200, "{}", body);
+    assert_close(&body["judge"]["within_range_percent"], 75.0);
+    assert_close(&body["judge"]["median_percent_below_range"], 35.0);
+    assert!(body["judge"]["median_percent_above_range"].is_null());
+    assert!(body["deltas"]["median_percent_above_range"].is_null(), "judge B never sentenced above the range");
+}
+
+#[spin_test]
+fn test_statistics_break_down_by_offense_type() {
+    let _store = key_value::Store::open(DISTRICT);
+    seed_sentencings();
+
+    let (status, body) = send(Method::Get, "/api/sentencing/statistics/departures?fiscal_year=2025", None);
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body["total_cases"], 18);
+    assert_eq!(body["guideline_compliance"]["sentenced"], 18);
+
+    let drugs = &body["by_offense_type"]["drug_trafficking"];
+    assert_eq!(drugs["sentenced"], 3);
+    assert_close(&drugs["above_range_percent"], 100.0);
+    assert_close(&drugs["median_percent_above_range"], 20.0);
+
+    let fraud = &body["by_offense_type"]["fraud"];
+    assert_eq!(fraud["sentenced"], 15);
+    assert_eq!(fraud["within_range"], 10);
+    assert_eq!(fraud["below_range"], 5);
+    assert!(fraud["median_percent_above_range"].is_null());
+}
+
+#[spin_test]
+fn test_fiscal_year_selects_october_through_september() {
+    let _store = key_value::Store::open(DISTRICT);
+    seed_sentencings();
+
+    for (fiscal_year, expected) in [(2024, 1), (2025, 18), (2026, 1)] {
+        let (status, body) = send(Method::Get, &format!("/api/sentencing/statistics/district?fiscal_year={}", fiscal_year), None);
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(body["total_cases"], expected, "fiscal year {}", fiscal_year);
+    }
+
+    let (status, body) = send(Method::Get, "/api/sentencing/statistics/judge/judge-a?fiscal_year=2024", None);
+    assert_eq!(status, 200, "{}", body);
+    assert_close(&body["guideline_compliance"]["below_range_percent"], 100.0);
+    assert_close(&body["guideline_compliance"]["median_percent_below_range"], 75.0);
+}
+
+#[spin_test]
+fn test_invalid_fiscal_year_is_rejected() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    for query in ["fiscal_year=FY25", "fiscal_year=2025&from=2024-10-01"] {
+        let (status, _) = send(Method::Get, &format!("/api/sentencing/statistics/judge/judge-a/comparison?{}", query), None);
+        assert_eq!(status, 400, "{} should be rejected", query);
+    }
+}
@@ -0,0 +1,108 @@
+//! Batch guidelines range lookup tests
+//!
+//! Tests that POST /api/sentencing/lookup-guidelines-range/batch returns the
+//! same table ranges as looking each cell up on a sentencing record.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+/// Range stored on a fresh sentencing record for a final offense level and
+/// criminal history category, via the individual lookup endpoint
+fn individual_lookup(offense_level: i32, category: &str, district: &str) -> Value {
+    let (status, mut sentencing) = send(Method::Post, "/api/sentencing", Some(json!({
+        "case_id": "case-batch",
+        "defendant_id": "defendant-batch",
+        "judge_id": "judge-batch"
+    })), district);
+    assert_eq!(status, 201);
+    let id = sentencing["id"].as_str().unwrap().to_string();
+
+    sentencing["offense_level"]["final_offense_level"] = json!(offense_level);
+    sentencing["criminal_history"]["category"] = json!(category);
+    let (status, _) = send(Method::Put, &format!("/api/sentencing/{}", id), Some(sentencing), district);
+    assert_eq!(status, 200);
+
+    let (status, stored) = send(Method::Post, &format!("/api/sentencing/{}/lookup-guidelines-range", id), None, district);
+    assert_eq!(status, 200);
+    stored["guidelines_range"].clone()
+}
+
+#[spin_test]
+fn test_batch_matches_individual_lookups() {
+    let _store = key_value::Store::open("district9");
+
+    let cells = [(12, "I"), (24, "III"), (12, "I"), (31, "VI"), (43, "II")];
+    let lookups: Vec<Value> = cells
+        .iter()
+        .map(|(level, category)| json!({"offense_level": level, "criminal_history_category": category}))
+        .collect();
+
+    let (status, body) = send(
+        Method::Post,
+        "/api/sentencing/lookup-guidelines-range/batch",
+        Some(json!({"lookups": lookups})),
+        "district9",
+    );
+    assert_eq!(status, 200, "Batch lookup should succeed: {}", body);
+    let ranges = body["ranges"].as_array().unwrap();
+    assert_eq!(ranges.len(), cells.len(), "One range per lookup, duplicates included");
+
+    for ((level, category), range) in cells.iter().zip(ranges) {
+        assert_eq!(range["offense_level"], *level);
+        assert_eq!(range["criminal_history_category"], *category);
+
+        let individual = individual_lookup(*level, category, "district9");
+        assert_eq!(range["minimum_months"], individual["minimum_months"], "level {} category {}", level, category);
+        assert_eq!(range["maximum_months"], individual["maximum_months"], "level {} category {}", level, category);
+        assert_eq!(range["zone"], individual["zone"], "level {} category {}", level, category);
+    }
+}
+
+#[spin_test]
+fn test_batch_rejects_level_off_the_table() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, body) = send(
+        Method::Post,
+        "/api/sentencing/lookup-guidelines-range/batch",
+        Some(json!({"lookups": [
+            {"offense_level": 20, "criminal_history_category": "I"},
+            {"offense_level": 44, "criminal_history_category": "I"}
+        ]})),
+        "district9",
+    );
+    assert_eq!(status, 400);
+    assert!(body["error"]["message"].as_str().unwrap().contains("lookups[1]"), "{}", body);
+}
@@ -0,0 +1,24 @@
+//! Sentencing domain tests
+//!
+//! This module contains tests for sentencing guidelines calculations
+
+// Guidelines calculation audit trail tests
+pub mod calculation_audit;
+
+// Batch guidelines range lookup tests
+pub mod guidelines_batch;
+
+// Criminal history points breakdown tests
+pub mod criminal_history_points;
+
+// Safety valve eligibility tests
+pub mod safety_valve;
+
+// Combined departure and variance statistics tests
+pub mod adjustment_statistics;
+
+// Statistics date range tests
+pub mod statistics_date_range;
+
+// Guideline compliance, offense breakdown and judge comparison tests
+pub mod guideline_compliance;
@@ -0,0 +1,103 @@
+//! Safety valve eligibility tests
+//!
+//! Tests that GET /api/sentencing/:id/safety-valve-eligible reports overall
+//! eligibility at the top level alongside a pass/fail and reason for each
+//! criterion.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+/// Helper to perform a request with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+fn create_sentencing(district: &str) -> String {
+    let (status, sentencing) = send(Method::Post, "/api/sentencing", Some(json!({
+        "case_id": "case-safety-valve",
+        "defendant_id": "defendant-safety-valve",
+        "judge_id": "judge-safety-valve"
+    })), district);
+    assert_eq!(status, 201);
+    sentencing["id"].as_str().unwrap().to_string()
+}
+
+#[spin_test]
+fn test_eligible_defendant_passes_every_criterion() {
+    let _store = key_value::Store::open("district9");
+    let id = create_sentencing("district9");
+
+    let (status, body) = send(Method::Get, &format!("/api/sentencing/{}/safety-valve-eligible", id), None, "district9");
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body["eligible"], true);
+
+    let criteria = body["criteria"].as_array().unwrap();
+    assert_eq!(criteria.len(), 5);
+    assert!(criteria.iter().all(|c| c["passed"] == true && c["reason"].as_str().is_some()));
+}
+
+#[spin_test]
+fn test_failing_only_criminal_history_points_is_explained() {
+    let _store = key_value::Store::open("district9");
+    let id = create_sentencing("district9");
+
+    let (status, _) = send(Method::Post, &format!("/api/sentencing/{}/prior-sentence", id), Some(json!({
+        "offense": "Theft",
+        "sentence_date": "2018-03-01T00:00:00Z",
+        "sentence_length_months": 6,
+        "points_assigned": 2,
+        "violence_involved": false,
+        "controlled_substance": false
+    })), "district9");
+    assert_eq!(status, 200);
+
+    let (status, body) = send(Method::Get, &format!("/api/sentencing/{}/safety-valve-eligible", id), None, "district9");
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body["eligible"], false);
+
+    let failed: Vec<&Value> = body["criteria"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|c| c["passed"] == false)
+        .collect();
+    assert_eq!(failed.len(), 1, "Only one criterion should fail: {}", body);
+    assert_eq!(failed[0]["criterion"], "criminal_history_points");
+    assert_eq!(failed[0]["reason"], "2 criminal history point(s); at most 1 allowed");
+}
+
+#[spin_test]
+fn test_missing_sentencing_is_not_found() {
+    let _store = key_value::Store::open("district9");
+
+    let (status, body) = send(Method::Get, "/api/sentencing/missing-sentencing/safety-valve-eligible", None, "district9");
+    assert_eq!(status, 404);
+    assert_eq!(body["error"]["code"], "NOT_FOUND");
+}
@@ -0,0 +1,118 @@
+//! Statistics date range tests
+//!
+//! Tests that statistics endpoints accept `from`/`to` as RFC3339 or plain
+//! dates, count records dated exactly on either bound, echo the effective
+//! range, and default to the last twelve months.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Creates a sentencing dated `sentencing_date`
+fn create_sentencing(sentencing_date: &str) {
+    let (status, mut sentencing) = send(Method::Post, "/api/sentencing", Some(json!({
+        "case_id": format!("case-{}", sentencing_date),
+        "defendant_id": "defendant-range",
+        "judge_id": "judge-range"
+    })));
+    assert_eq!(status, 201);
+    let id = sentencing["id"].as_str().unwrap().to_string();
+
+    sentencing["sentencing_date"] = json!(sentencing_date);
+    let (status, body) = send(Method::Put, &format!("/api/sentencing/{}", id), Some(sentencing));
+    assert_eq!(status, 200, "{}", body);
+}
+
+#[spin_test]
+fn test_range_bounds_are_inclusive() {
+    let _store = key_value::Store::open(DISTRICT);
+    create_sentencing("2025-02-28T23:59:59Z");
+    create_sentencing("2025-03-01T00:00:00Z");
+    create_sentencing("2025-03-31T23:59:59Z");
+    create_sentencing("2025-04-01T00:00:00Z");
+
+    let (status, stats) = send(
+        Method::Get,
+        "/api/sentencing/statistics/district?from=2025-03-01T00:00:00Z&to=2025-03-31T23:59:59Z",
+        None,
+    );
+    assert_eq!(status, 200, "{}", stats);
+    assert_eq!(stats["total_cases"], 2, "Records exactly on `from` and `to` should be counted");
+    assert_eq!(stats["from"], "2025-03-01T00:00:00Z");
+    assert_eq!(stats["to"], "2025-03-31T23:59:59Z");
+
+    let (status, stats) = send(Method::Get, "/api/sentencing/statistics/district?from=2025-03-01&to=2025-03-31", None);
+    assert_eq!(status, 200);
+    assert_eq!(stats["total_cases"], 2, "Plain dates should cover the whole of both days");
+
+    let (status, stats) = send(Method::Get, "/api/sentencing/statistics/departures?from=2025-03-31T23:59:59Z&to=2025-04-01T00:00:00Z", None);
+    assert_eq!(status, 200);
+    assert_eq!(stats["total_cases"], 2);
+}
+
+#[spin_test]
+fn test_default_range_is_last_twelve_months() {
+    let _store = key_value::Store::open(DISTRICT);
+    let (status, case) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "Statistics Range Case",
+        "description": "Case opened for statistics range testing",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })));
+    assert_eq!(status, 201, "{}", case);
+
+    let (status, stats) = send(Method::Get, "/api/cases/statistics", None);
+    assert_eq!(status, 200);
+    assert!(stats["total_cases"].as_u64().unwrap() >= 1);
+    let from = stats["from"].as_str().expect("Effective `from` should be echoed");
+    let to = stats["to"].as_str().expect("Effective `to` should be echoed");
+    let year = |date: &str| date[..4].parse::<i32>().unwrap();
+    assert_eq!(year(to) - year(from), 1);
+    assert_eq!(from[4..10], to[4..10], "Default range should span twelve months");
+
+    let (status, stats) = send(Method::Get, "/api/cases/statistics?from=2000-01-01&to=2000-12-31", None);
+    assert_eq!(status, 200);
+    assert_eq!(stats["total_cases"], 0);
+}
+
+#[spin_test]
+fn test_invalid_ranges_are_rejected() {
+    let _store = key_value::Store::open(DISTRICT);
+
+    for path in [
+        "/api/cases/statistics?from=2025-04-01&to=2025-03-01",
+        "/api/orders/statistics?from=2010-01-01&to=2025-01-01",
+        "/api/opinions/statistics?to=yesterday",
+        "/api/sentencing/statistics/district?from=2025-02-30",
+    ] {
+        let (status, body) = send(Method::Get, path, None);
+        assert_eq!(status, 400, "{} should be rejected: {}", path, body);
+    }
+}
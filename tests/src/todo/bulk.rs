@@ -0,0 +1,101 @@
+//! Bulk ToDo tests
+//!
+//! Tests POST /api/todos/bulk/toggle and POST /api/todos/bulk/delete with a
+//! mix of existing and missing IDs, which are reported per ID rather than
+//! failing the request. Deleted items stay in storage, flagged as deleted.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::key_value}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const MISSING: &str = "5f0e9a1b-2c3d-4e5f-8a9b-0c1d2e3f4a5b";
+
+/// Helper to send a request and return status + response body
+fn send(method: Method, path: &str, body: Option<Value>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+fn create_todo(contents: &str) -> String {
+    let (status, todo) = send(Method::Post, "/api/todos", Some(json!({ "contents": contents })));
+    assert_eq!(status, 201, "ToDo should be created: {}", todo);
+    todo["id"].as_str().unwrap().to_string()
+}
+
+#[spin_test]
+fn test_bulk_toggle_reports_each_id() {
+    let _store = key_value::Store::open("default");
+    let first = create_todo("File the answer");
+    let second = create_todo("Serve the summons");
+
+    let (status, body) = send(Method::Post, "/api/todos/bulk/toggle", Some(json!({
+        "ids": [first, MISSING, second, first]
+    })));
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body["succeeded"], 2);
+    assert_eq!(body["notFound"], 1);
+    assert_eq!(body["results"], json!([
+        { "id": first, "status": "toggled", "isCompleted": true },
+        { "id": MISSING, "status": "not_found" },
+        { "id": second, "status": "toggled", "isCompleted": true }
+    ]), "Repeated IDs are toggled once");
+
+    let (_, todo) = send(Method::Get, &format!("/api/todos/{}", first), None);
+    assert_eq!(todo["isCompleted"], true);
+
+    let (status, body) = send(Method::Post, "/api/todos/bulk/toggle", Some(json!({ "ids": [second] })));
+    assert_eq!(status, 200);
+    assert_eq!(body["results"][0]["isCompleted"], false);
+}
+
+#[spin_test]
+fn test_bulk_delete_soft_deletes_existing_ids() {
+    let _store = key_value::Store::open("default");
+    let kept = create_todo("Draft the brief");
+    let doomed = create_todo("Book the courtroom");
+
+    let (status, body) = send(Method::Post, "/api/todos/bulk/delete", Some(json!({ "ids": [doomed, MISSING] })));
+    assert_eq!(status, 200, "{}", body);
+    assert_eq!(body["succeeded"], 1);
+    assert_eq!(body["notFound"], 1);
+    assert_eq!(body["results"][0]["status"], "deleted");
+    assert_eq!(body["results"][1]["status"], "not_found");
+
+    let (_, page) = send(Method::Get, "/api/todos", None);
+    let listed: Vec<&str> = page["items"].as_array().unwrap().iter().map(|t| t["id"].as_str().unwrap()).collect();
+    assert_eq!(listed, vec![kept.as_str()]);
+
+    // Soft deleted, so still in storage
+    let (status, _) = send(Method::Get, &format!("/api/todos/{}", doomed), None);
+    assert_eq!(status, 200);
+}
+
+#[spin_test]
+fn test_bulk_requests_are_validated() {
+    let _store = key_value::Store::open("default");
+
+    let (status, _) = send(Method::Post, "/api/todos/bulk/delete", Some(json!({ "ids": [] })));
+    assert_eq!(status, 400, "An empty list is rejected");
+    let (status, _) = send(Method::Post, "/api/todos/bulk/toggle", Some(json!({ "ids": ["not-a-uuid"] })));
+    assert_eq!(status, 400);
+    let too_many: Vec<String> = (0..101).map(|i| format!("00000000-0000-4000-8000-{:012}", i)).collect();
+    let (status, _) = send(Method::Post, "/api/todos/bulk/toggle", Some(json!({ "ids": too_many })));
+    assert_eq!(status, 400);
+}
@@ -0,0 +1,5 @@
+//! ToDo domain tests
+//!
+//! This module contains tests for the bulk ToDo endpoints
+
+pub mod bulk;
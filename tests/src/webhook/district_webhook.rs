@@ -0,0 +1,160 @@
+//! District webhook tests
+//!
+//! Tests managing the district webhook through /api/admin/webhooks and that
+//! creating a case or changing its status posts to it straight away, with
+//! the outbound endpoint mocked. A target that rejects the post must not
+//! fail the case operation, and the post is queued for retry.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::{self, key_value, variables}}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const DISTRICT: &str = "district9";
+const ADMIN_TOKEN: &str = "test-admin-token";
+const SECRET: &str = "district-shared-secret";
+
+/// Helper to send a request, optionally with an admin token
+fn send(method: Method, path: &str, body: Option<Value>, admin_token: Option<&str>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), DISTRICT.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+    if let Some(token) = admin_token {
+        headers.append(&"X-Admin-Token".to_string(), token.as_bytes()).unwrap();
+    }
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(data) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&data).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body: Value = serde_json::from_str(&response.body_as_string().unwrap_or_default()).unwrap_or(json!(null));
+    (status, body)
+}
+
+/// Answer posts to `url` with `status`
+fn mock_target(url: &str, status: u16) {
+    let response = http::types::OutgoingResponse::new(Headers::new());
+    response.set_status_code(status).unwrap();
+    spin_test_virt::http_handler::set_response(url, spin_test_virt::http_handler::ResponseHandler::Response(response));
+}
+
+fn set_webhook(target_url: &str) {
+    let (status, body) = send(Method::Put, "/api/admin/webhooks", Some(json!({
+        "target_url": target_url,
+        "secret": SECRET
+    })), Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "District webhook should be set: {}", body);
+}
+
+fn create_case() -> (u16, String) {
+    let (status, body) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. District Hook",
+        "description": "Case for district webhook tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), None);
+    (status, body["id"].as_str().unwrap_or_default().to_string())
+}
+
+/// Flush the notification queue and return the delivery report
+fn deliver_pending() -> Value {
+    let (status, report) = send(Method::Post, "/api/webhooks/deliver-pending", None, Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "Queue should flush: {}", report);
+    report
+}
+
+fn last_delivery() -> Value {
+    let (status, webhook) = send(Method::Get, "/api/admin/webhooks", None, Some(ADMIN_TOKEN));
+    assert_eq!(status, 200);
+    webhook["last_delivery"].clone()
+}
+
+#[spin_test]
+fn test_district_webhook_requires_admin_and_hides_secret() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open(DISTRICT);
+
+    let (status, _) = send(Method::Get, "/api/admin/webhooks", None, None);
+    assert_eq!(status, 403);
+
+    let (status, webhook) = send(Method::Get, "/api/admin/webhooks", None, Some(ADMIN_TOKEN));
+    assert_eq!(status, 200);
+    assert_eq!(webhook["target_url"], json!(null));
+
+    let (status, _) = send(Method::Put, "/api/admin/webhooks", Some(json!({
+        "target_url": "http://clerk.example.gov/case-events",
+        "secret": SECRET
+    })), Some(ADMIN_TOKEN));
+    assert_eq!(status, 400, "Plain HTTP targets are rejected");
+
+    let (status, _) = send(Method::Put, "/api/admin/webhooks", Some(json!({
+        "target_url": "https://clerk.example.gov/case-events"
+    })), Some(ADMIN_TOKEN));
+    assert_eq!(status, 400, "The first URL needs a secret");
+
+    set_webhook("https://clerk.example.gov/case-events");
+    let (status, webhook) = send(Method::Put, "/api/admin/webhooks", Some(json!({
+        "target_url": "https://clerk.example.gov/v2/case-events"
+    })), Some(ADMIN_TOKEN));
+    assert_eq!(status, 200, "Changing the URL keeps the secret: {}", webhook);
+    assert_eq!(webhook["target_url"], "https://clerk.example.gov/v2/case-events");
+    assert_eq!(webhook["secret_configured"], true);
+    assert!(!webhook.to_string().contains(SECRET));
+
+    let (status, webhook) = send(Method::Put, "/api/admin/webhooks", Some(json!({ "target_url": null })), Some(ADMIN_TOKEN));
+    assert_eq!(status, 200);
+    assert_eq!(webhook["target_url"], json!(null));
+}
+
+#[spin_test]
+fn test_case_events_are_posted_to_district_webhook() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open(DISTRICT);
+    let target = "https://clerk.example.gov/case-events";
+    mock_target(target, 202);
+    set_webhook(target);
+
+    let (status, case_id) = create_case();
+    assert_eq!(status, 201);
+    let delivery = last_delivery();
+    assert_eq!(delivery["event_type"], "case.created");
+    assert_eq!(delivery["status_code"], 202);
+    assert_eq!(delivery["delivered"], true);
+
+    let (status, _) = send(Method::Patch, &format!("/api/cases/{}/status", case_id), Some(json!({"status": "discovery"})), None);
+    assert_eq!(status, 200);
+    let delivery = last_delivery();
+    assert_eq!(delivery["event_type"], "case.status_changed");
+    assert_eq!(delivery["delivered"], true);
+}
+
+#[spin_test]
+fn test_rejected_post_does_not_fail_the_case_operation() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open(DISTRICT);
+    let target = "https://broken.example.gov/case-events";
+    mock_target(target, 500);
+    set_webhook(target);
+
+    let (status, case_id) = create_case();
+    assert_eq!(status, 201, "Case creation succeeds even though the webhook failed");
+    let delivery = last_delivery();
+    assert_eq!(delivery["status_code"], 500);
+    assert_eq!(delivery["delivered"], false);
+
+    // The rejected post waits out its backoff in the retry queue
+    assert_eq!(deliver_pending()["deferred"], 1);
+
+    let (status, case) = send(Method::Get, &format!("/api/cases/{}", case_id), None, None);
+    assert_eq!(status, 200, "The case was saved: {}", case);
+}
@@ -0,0 +1,7 @@
+//! Webhook domain tests
+//!
+//! This module contains tests for webhook subscription endpoints and the
+//! events handlers queue for subscribers or post to the district webhook
+
+pub mod subscriptions;
+pub mod district_webhook;
@@ -0,0 +1,189 @@
+//! Webhook subscription tests
+//!
+//! Tests registering, listing and deleting webhooks, and that handlers queue
+//! notifications for matching subscriptions. Delivery itself, signing and
+//! retries, is covered by the webhook service unit tests with a mock
+//! delivery port.
+
+use spin_test_sdk::{spin_test, bindings::{wasi::http, fermyon::spin_test_virt::{key_value, variables}}};
+use http::types::{Headers, Method, OutgoingRequest};
+use serde_json::{json, Value};
+
+const SECRET: &str = "probation-shared-secret";
+const ADMIN_TOKEN: &str = "test-admin-token";
+
+/// Helper to perform a request as an administrator with an optional JSON body
+fn send(method: Method, path: &str, body: Option<Value>, district: &str) -> (u16, Value) {
+    send_as(method, path, body, district, Some(ADMIN_TOKEN))
+}
+
+/// Helper to perform a request, optionally with an admin token
+fn send_as(method: Method, path: &str, body: Option<Value>, district: &str, admin_token: Option<&str>) -> (u16, Value) {
+    let headers = Headers::new();
+    headers.append(&"X-Court-District".to_string(), district.as_bytes()).unwrap();
+    headers.append(&"Content-Type".to_string(), b"application/json").unwrap();
+    if let Some(token) = admin_token {
+        headers.append(&"X-Admin-Token".to_string(), token.as_bytes()).unwrap();
+    }
+
+    let request = OutgoingRequest::new(headers);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+
+    if let Some(body) = body {
+        let request_body = request.body().unwrap();
+        let stream = request_body.write().unwrap();
+        stream.blocking_write_and_flush(serde_json::to_string(&body).unwrap().as_bytes()).unwrap();
+        drop(stream);
+        http::types::OutgoingBody::finish(request_body, None).unwrap();
+    }
+
+    let response = spin_test_sdk::perform_request(request);
+    let status = response.status();
+    let body = response.body_as_string().unwrap_or_default();
+
+    let body_json: Value = if body.is_empty() {
+        json!(null)
+    } else {
+        serde_json::from_str(&body).unwrap_or(json!({"raw": body}))
+    };
+
+    (status, body_json)
+}
+
+fn create_webhook(event_types: Value, district: &str) -> String {
+    let (status, body) = send(Method::Post, "/api/webhooks", Some(json!({
+        "target_url": "https://probation.example.gov/hooks",
+        "event_types": event_types,
+        "secret": SECRET,
+        "description": "Probation office"
+    })), district);
+    assert_eq!(status, 201, "Webhook should be created: {}", body);
+    body["id"].as_str().unwrap().to_string()
+}
+
+fn create_case(district: &str) -> String {
+    let (status, body) = send(Method::Post, "/api/cases", Some(json!({
+        "title": "United States v. Webhook",
+        "description": "Case for webhook tests",
+        "crimeType": "fraud",
+        "districtCode": "SDNY",
+        "location": "New York, NY"
+    })), district);
+    assert_eq!(status, 201, "Case should be created: {}", body);
+    body["id"].as_str().unwrap().to_string()
+}
+
+/// Queued notifications in the tenant store
+fn pending_count(store: &key_value::Store) -> usize {
+    store
+        .get("index:webhook-pending")
+        .unwrap()
+        .and_then(|bytes| serde_json::from_slice::<Vec<String>>(&bytes).ok())
+        .map_or(0, |ids| ids.len())
+}
+
+#[spin_test]
+fn test_webhook_endpoints_require_admin() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let id = create_webhook(json!(["order.issued"]), "district9");
+
+    let body = json!({"target_url": "https://probation.example.gov/hooks", "event_types": ["order.issued"], "secret": SECRET});
+    let requests = [
+        (Method::Post, "/api/webhooks".to_string(), Some(body)),
+        (Method::Get, "/api/webhooks".to_string(), None),
+        (Method::Get, format!("/api/webhooks/{}/deliveries", id), None),
+        (Method::Delete, format!("/api/webhooks/{}", id), None),
+        (Method::Post, "/api/webhooks/deliver-pending".to_string(), None),
+    ];
+    for (method, path, body) in requests {
+        let (status, _) = send_as(method, &path, body.clone(), "district9", None);
+        assert_eq!(status, 403, "{} should require an admin token", path);
+    }
+    let (status, _) = send_as(Method::Get, "/api/webhooks", None, "district9", Some("wrong-token"));
+    assert_eq!(status, 403);
+
+    let (status, list) = send(Method::Get, "/api/webhooks", None, "district9");
+    assert_eq!(status, 200);
+    assert_eq!(list.as_array().unwrap().len(), 1, "Rejected requests must not change anything");
+}
+
+#[spin_test]
+fn test_create_webhook_does_not_echo_secret() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let id = create_webhook(json!(["case.status_changed", "order.issued"]), "district9");
+
+    let (status, list) = send(Method::Get, "/api/webhooks", None, "district9");
+    assert_eq!(status, 200);
+    let webhooks = list.as_array().unwrap();
+    assert_eq!(webhooks.len(), 1);
+    assert_eq!(webhooks[0]["id"], id.as_str());
+    assert_eq!(webhooks[0]["event_types"], json!(["case.status_changed", "order.issued"]));
+    assert!(webhooks[0].get("secret").is_none(), "Secret must never be returned");
+}
+
+#[spin_test]
+fn test_create_webhook_validation() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let _store = key_value::Store::open("district9");
+
+    let invalid = [
+        json!({"target_url": "http://probation.example.gov", "event_types": ["order.issued"], "secret": SECRET}),
+        json!({"target_url": "https://probation.example.gov", "event_types": ["case.created"], "secret": SECRET}),
+        json!({"target_url": "https://probation.example.gov", "event_types": [], "secret": SECRET}),
+        json!({"target_url": "https://probation.example.gov", "event_types": ["order.issued"], "secret": "short"}),
+    ];
+    for body in invalid {
+        let (status, response) = send(Method::Post, "/api/webhooks", Some(body.clone()), "district9");
+        assert_eq!(status, 400, "{} should be rejected: {}", body, response);
+    }
+}
+
+#[spin_test]
+fn test_case_status_change_queues_notification() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let store = key_value::Store::open("district9");
+
+    create_webhook(json!(["case.status_changed"]), "district9");
+    create_webhook(json!(["order.issued"]), "district9");
+    let case_id = create_case("district9");
+
+    let path = format!("/api/cases/{}/status", case_id);
+    let (status, _) = send(Method::Patch, &path, Some(json!({"status": "arraigned"})), "district9");
+    assert_eq!(status, 200);
+    assert_eq!(pending_count(&store), 1, "Only the case.status_changed subscriber should be queued");
+
+    // Setting the same status again is not a change
+    let (status, _) = send(Method::Patch, &path, Some(json!({"status": "arraigned"})), "district9");
+    assert_eq!(status, 200);
+    assert_eq!(pending_count(&store), 1);
+}
+
+#[spin_test]
+fn test_deliveries_and_delete() {
+    variables::set("admin_token", ADMIN_TOKEN);
+    let store = key_value::Store::open("district9");
+
+    let id = create_webhook(json!(["case.status_changed"]), "district9");
+
+    let (status, deliveries) = send(Method::Get, &format!("/api/webhooks/{}/deliveries", id), None, "district9");
+    assert_eq!(status, 200);
+    assert_eq!(deliveries, json!([]));
+
+    let case_id = create_case("district9");
+    send(Method::Patch, &format!("/api/cases/{}/status", case_id), Some(json!({"status": "discovery"})), "district9");
+    assert_eq!(pending_count(&store), 1);
+
+    let (status, _) = send(Method::Delete, &format!("/api/webhooks/{}", id), None, "district9");
+    assert_eq!(status, 204);
+    assert_eq!(pending_count(&store), 0, "Deleting a webhook should drop its queued notifications");
+
+    let (status, _) = send(Method::Get, &format!("/api/webhooks/{}/deliveries", id), None, "district9");
+    assert_eq!(status, 404);
+    let (status, _) = send(Method::Delete, &format!("/api/webhooks/{}", id), None, "district9");
+    assert_eq!(status, 404);
+}